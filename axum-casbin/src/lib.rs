@@ -1,4 +1,9 @@
 pub use casbin;
-pub use middleware::{CasbinAxumLayer, CasbinAxumMiddleware, CasbinVals};
+pub use middleware::{
+    action_for_method, normalize_action, CasbinAxumLayer, CasbinAxumMiddleware, CasbinVals,
+    ACTION_READ, ACTION_WRITE,
+};
 
 pub mod middleware;
+#[cfg(feature = "test-support")]
+pub mod test_support;
@@ -29,6 +29,48 @@ use http_body_util::Full;
 use tokio::sync::RwLock;
 use tower::{Layer, Service};
 
+/** Policy action for read-only requests */
+pub const ACTION_READ: &str = "read";
+
+/** Policy action for requests that modify resources, implies `read` in the model matcher */
+pub const ACTION_WRITE: &str = "write";
+
+/**
+ * Maps an HTTP method to a read/write policy action
+ * 
+ * # Arguments
+ * * `method` - The HTTP method name
+ * 
+ * # Returns
+ * * `&'static str` - `read` for GET and HEAD, `write` for every other method
+ */
+pub fn action_for_method(method: &str) -> &'static str {
+    if method.eq_ignore_ascii_case("GET") || method.eq_ignore_ascii_case("HEAD") {
+        ACTION_READ
+    } else {
+        ACTION_WRITE
+    }
+}
+
+/**
+ * Normalizes a caller-supplied action to a read/write policy action
+ * 
+ * `read` and `write` are kept as-is, anything else is treated as an HTTP method.
+ * 
+ * # Arguments
+ * * `action` - A policy action or an HTTP method name
+ * 
+ * # Returns
+ * * `&str` - `read` or `write`
+ */
+pub fn normalize_action(action: &str) -> &str {
+    if action == ACTION_READ || action == ACTION_WRITE {
+        action
+    } else {
+        action_for_method(action)
+    }
+}
+
 /**
  * Values used for Casbin policy enforcement
  * 
@@ -41,6 +83,13 @@ pub struct CasbinVals {
     pub subject: Vec<String>,
     /** Optional domain for domain-specific policies */
     pub domain: Option<String>,
+    /**
+     * Optional subject that must be allowed as well
+     * 
+     * Narrows the subject(s) to the intersection of both permission sets,
+     * e.g. an access token limited to a subset of its owner's endpoints.
+     */
+    pub scope: Option<String>,
 }
 
 /**
@@ -53,6 +102,8 @@ pub struct CasbinVals {
 pub struct CasbinAxumLayer {
     /** The Casbin enforcer wrapped in a thread-safe reference */
     enforcer: Arc<RwLock<CachedEnforcer>>,
+    /** Whether the request action is derived from the method as `read`/`write` */
+    read_write_actions: bool,
 }
 
 impl CasbinAxumLayer {
@@ -70,6 +121,7 @@ impl CasbinAxumLayer {
         let enforcer: CachedEnforcer = CachedEnforcer::new(m, a).await?;
         Ok(CasbinAxumLayer {
             enforcer: Arc::new(RwLock::new(enforcer)),
+            read_write_actions: false,
         })
     }
 
@@ -93,7 +145,24 @@ impl CasbinAxumLayer {
      * * `CasbinAxumLayer` - A new layer with the specified enforcer
      */
     pub fn set_enforcer(e: Arc<RwLock<CachedEnforcer>>) -> CasbinAxumLayer {
-        CasbinAxumLayer { enforcer: e }
+        CasbinAxumLayer {
+            enforcer: e,
+            read_write_actions: false,
+        }
+    }
+
+    /**
+     * Enforces with `read`/`write` actions instead of the raw HTTP method
+     * 
+     * See `action_for_method` for the mapping. Policies must then use
+     * `read` or `write` as their action.
+     * 
+     * # Returns
+     * * `CasbinAxumLayer` - The layer with read/write actions enabled
+     */
+    pub fn with_read_write_actions(mut self) -> CasbinAxumLayer {
+        self.read_write_actions = true;
+        self
     }
 }
 
@@ -112,6 +181,7 @@ impl<S> Layer<S> for CasbinAxumLayer {
     fn layer(&self, inner: S) -> Self::Service {
         CasbinAxumMiddleware {
             enforcer: self.enforcer.clone(),
+            read_write_actions: self.read_write_actions,
             inner,
         }
     }
@@ -143,6 +213,8 @@ pub struct CasbinAxumMiddleware<S> {
     inner: S,
     /** The Casbin enforcer wrapped in a thread-safe reference */
     enforcer: Arc<RwLock<CachedEnforcer>>,
+    /** Whether the request action is derived from the method as `read`/`write` */
+    read_write_actions: bool,
 }
 
 impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CasbinAxumMiddleware<S>
@@ -178,9 +250,9 @@ where
      * Processes a request through the middleware
      * 
      * This function:
-     * 1. Extracts the path and method from the request
+     * 1. Extracts the path and action (the method, or `read`/`write`) from the request
      * 2. Gets the subject and domain from the request extensions
-     * 3. Enforces the Casbin policy
+     * 3. Enforces the Casbin policy, and the scope policy when a scope is present
      * 4. Returns appropriate responses based on the policy decision
      * 
      * # Arguments
@@ -191,12 +263,18 @@ where
      */
     fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
         let cloned_enforcer = self.enforcer.clone();
+        let read_write_actions = self.read_write_actions;
         let not_ready_inner = self.inner.clone();
         let mut inner = std::mem::replace(&mut self.inner, not_ready_inner);
 
         Box::pin(async move {
             let path = req.uri().path().to_string();
-            let action = req.method().as_str().to_string();
+            let method = req.method().as_str();
+            let action = if read_write_actions {
+                action_for_method(method).to_string()
+            } else {
+                method.to_string()
+            };
             let option_vals = req.extensions().get::<CasbinVals>().map(|x| x.to_owned());
             let vals = match option_vals {
                 Some(value) => value,
@@ -235,6 +313,13 @@ where
                         }
                     }
 
+                    if let (true, Some(scope)) = (authorized, vals.scope) {
+                        match lock.enforce_mut(vec![scope, domain, path, action]) {
+                            Ok(allowed) => authorized = allowed,
+                            Err(_) => enforcement_error = true,
+                        }
+                    }
+
                     drop(lock);
 
                     if enforcement_error {
@@ -269,6 +354,13 @@ where
                         }
                     }
 
+                    if let (true, Some(scope)) = (authorized, vals.scope) {
+                        match lock.enforce_mut(vec![scope, path, action]) {
+                            Ok(allowed) => authorized = allowed,
+                            Err(_) => enforcement_error = true,
+                        }
+                    }
+
                     drop(lock);
 
                     if enforcement_error {
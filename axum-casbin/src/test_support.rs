@@ -0,0 +1,248 @@
+/**
+ * Test support for running `CasbinAxumLayer` in-process
+ *
+ * Enabled by the `test-support` feature. Builds an in-memory enforcer from a model
+ * and fixture policies, and injects arbitrary `CasbinVals` in front of the layer the
+ * way an authentication middleware would.
+ *
+ * ```ignore
+ * let layer = CasbinHarness::new(MODEL)
+ *     .with_read_write_actions()
+ *     .policy(policy().role("admin").domain("built-in").endpoint("GET", "/user"))
+ *     .assign("alice", "admin", Some("built-in"))
+ *     .build()
+ *     .await
+ *     .unwrap();
+ *
+ * let app = protect(router, layer, vals(&["alice"], Some("built-in")));
+ * assert_eq!(status(&app, "GET", "/user").await, StatusCode::OK);
+ * ```
+ */
+use axum::{body::Body, Router};
+use casbin::{
+    function_map::key_match2, CoreApi, DefaultModel, MemoryAdapter, MgmtApi,
+    Result as CasbinResult,
+};
+use http::{Request, StatusCode};
+use tower::{util::MapRequestLayer, ServiceExt};
+
+use crate::middleware::{action_for_method, CasbinAxumLayer, CasbinVals};
+
+/**
+ * Starts a policy fixture
+ *
+ * # Returns
+ * * `PolicyFixture` - An empty fixture, chain `role`, `domain` and `endpoint` on it
+ */
+pub fn policy() -> PolicyFixture {
+    PolicyFixture::default()
+}
+
+/**
+ * Policy fixture granting a role access to a set of endpoints
+ */
+#[derive(Clone, Debug, Default)]
+pub struct PolicyFixture {
+    role: String,
+    domain: Option<String>,
+    endpoints: Vec<(String, String)>,
+}
+
+impl PolicyFixture {
+    /** Sets the policy subject */
+    pub fn role(mut self, role: &str) -> Self {
+        self.role = role.to_string();
+        self
+    }
+
+    /** Sets the policy domain, leave unset for models without domains */
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    /** Grants access to an endpoint */
+    pub fn endpoint(mut self, method: &str, path: &str) -> Self {
+        self.endpoints.push((method.to_uppercase(), path.to_string()));
+        self
+    }
+
+    /**
+     * Expands the fixture into policy rules
+     *
+     * # Arguments
+     * * `read_write_actions` - Whether methods are stored as `read`/`write` actions
+     *
+     * # Returns
+     * * `Vec<Vec<String>>` - One `sub, [dom,] obj, act` rule per endpoint
+     */
+    pub fn rules(&self, read_write_actions: bool) -> Vec<Vec<String>> {
+        self.endpoints
+            .iter()
+            .map(|(method, path)| {
+                let action = if read_write_actions {
+                    action_for_method(method).to_string()
+                } else {
+                    method.clone()
+                };
+                let mut rule = vec![self.role.clone()];
+                rule.extend(self.domain.clone());
+                rule.push(path.clone());
+                rule.push(action);
+                rule
+            })
+            .collect()
+    }
+}
+
+/**
+ * Builder for a `CasbinAxumLayer` backed by an in-memory enforcer
+ */
+#[derive(Clone, Debug)]
+pub struct CasbinHarness {
+    model: String,
+    policies: Vec<PolicyFixture>,
+    groupings: Vec<(String, Vec<String>)>,
+    read_write_actions: bool,
+    pattern_matching: bool,
+}
+
+impl CasbinHarness {
+    /**
+     * Creates a harness for a model
+     *
+     * # Arguments
+     * * `model` - The model definition, usually loaded with `include_str!`
+     */
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            policies: Vec::new(),
+            groupings: Vec::new(),
+            read_write_actions: false,
+            pattern_matching: false,
+        }
+    }
+
+    /** Adds a policy fixture */
+    pub fn policy(mut self, policy: PolicyFixture) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /** Assigns a role to a user, optionally within a domain */
+    pub fn assign(self, user: &str, role: &str, domain: Option<&str>) -> Self {
+        let mut rule = vec![user, role];
+        rule.extend(domain);
+        self.grouping("g", &rule)
+    }
+
+    /** Adds a grouping rule for a named role definition such as `g2` */
+    pub fn grouping(mut self, ptype: &str, rule: &[&str]) -> Self {
+        let rule = rule.iter().map(|value| value.to_string()).collect();
+        self.groupings.push((ptype.to_string(), rule));
+        self
+    }
+
+    /** Stores policy actions as `read`/`write`, see `CasbinAxumLayer::with_read_write_actions` */
+    pub fn with_read_write_actions(mut self) -> Self {
+        self.read_write_actions = true;
+        self
+    }
+
+    /** Matches role names with `keyMatch2`, so groupings may use path patterns */
+    pub fn with_pattern_matching(mut self) -> Self {
+        self.pattern_matching = true;
+        self
+    }
+
+    /**
+     * Builds the layer
+     *
+     * # Returns
+     * * `CasbinResult<CasbinAxumLayer>` - The layer, or an error for an invalid model or rule
+     */
+    pub async fn build(&self) -> CasbinResult<CasbinAxumLayer> {
+        let model = DefaultModel::from_str(&self.model).await?;
+        let mut layer = CasbinAxumLayer::new(model, MemoryAdapter::default()).await?;
+        if self.read_write_actions {
+            layer = layer.with_read_write_actions();
+        }
+
+        {
+            let mut enforcer = layer.write().await;
+            for policy in &self.policies {
+                for rule in policy.rules(self.read_write_actions) {
+                    enforcer.add_policy(rule).await?;
+                }
+            }
+            for (ptype, rule) in &self.groupings {
+                enforcer.add_named_grouping_policy(ptype, rule.clone()).await?;
+            }
+            if self.pattern_matching {
+                enforcer.get_role_manager().write().matching_fn(Some(key_match2), None);
+            }
+        }
+
+        Ok(layer)
+    }
+}
+
+/**
+ * Creates the values an authentication layer would attach to a request
+ *
+ * # Arguments
+ * * `subject` - The subjects to enforce for
+ * * `domain` - The domain, `None` for models without domains
+ */
+pub fn vals(subject: &[&str], domain: Option<&str>) -> CasbinVals {
+    CasbinVals {
+        subject: subject.iter().map(|value| value.to_string()).collect(),
+        domain: domain.map(str::to_string),
+        scope: None,
+    }
+}
+
+/**
+ * Layer that inserts fixed `CasbinVals` into every request
+ *
+ * Stands in for the authentication middleware in front of `CasbinAxumLayer`.
+ */
+pub fn fake_auth(
+    vals: CasbinVals,
+) -> MapRequestLayer<impl Fn(Request<Body>) -> Request<Body> + Clone + Send + Sync + 'static> {
+    MapRequestLayer::new(move |mut request: Request<Body>| {
+        request.extensions_mut().insert(vals.clone());
+        request
+    })
+}
+
+/**
+ * Wraps a router with the Casbin layer and a fake authentication layer
+ *
+ * # Arguments
+ * * `router` - The routes under test
+ * * `layer` - The Casbin layer, usually from `CasbinHarness::build`
+ * * `vals` - The values injected into every request
+ */
+pub fn protect(router: Router, layer: CasbinAxumLayer, vals: CasbinVals) -> Router {
+    router.layer(layer).layer(fake_auth(vals))
+}
+
+/**
+ * Sends an empty request through a router
+ *
+ * # Returns
+ * * `StatusCode` - The response status
+ */
+pub async fn status(app: &Router, method: &str, uri: &str) -> StatusCode {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(Body::empty())
+        .expect("invalid test request");
+    match app.clone().oneshot(request).await {
+        Ok(response) => response.status(),
+        Err(never) => match never {},
+    }
+}
@@ -1,67 +1,8 @@
-use std::{
-    convert::Infallible,
-    task::{Context, Poll},
-};
+use axum::{routing::get, Router};
+use axum_casbin::test_support::{policy, protect, status, vals, CasbinHarness};
+use http::StatusCode;
 
-use axum::{body::Body, response::Response, routing::get, BoxError, Router};
-use axum_casbin::{CasbinAxumLayer, CasbinVals};
-use bytes::Bytes;
-use casbin::{function_map::key_match2, CoreApi, DefaultModel, FileAdapter};
-use futures::future::BoxFuture;
-use http::{Request, StatusCode};
-use http_body::Body as HttpBody;
-use tower::{Layer, Service, ServiceExt};
-
-#[derive(Clone)]
-struct FakeAuthLayer;
-
-impl<S> Layer<S> for FakeAuthLayer {
-    type Service = FakeAuthMiddleware<S>;
-
-    fn layer(&self, inner: S) -> Self::Service {
-        FakeAuthMiddleware { inner }
-    }
-}
-
-#[derive(Clone)]
-struct FakeAuthMiddleware<S> {
-    inner: S,
-}
-
-impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for FakeAuthMiddleware<S>
-where
-    S: Service<Request<ReqBody>, Response = Response<ResBody>, Error = Infallible>
-        + Clone
-        + Send
-        + 'static,
-    S::Future: Send + 'static,
-    ReqBody: Send + 'static,
-    Infallible: From<<S as Service<Request<ReqBody>>>::Error>,
-    ResBody: HttpBody<Data = Bytes> + Send + 'static,
-    ResBody::Error: Into<BoxError>,
-{
-    type Error = S::Error;
-    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
-    type Response = S::Response;
-
-    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.inner.poll_ready(cx)
-    }
-
-    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
-        let not_ready_inner = self.inner.clone();
-        let mut inner = std::mem::replace(&mut self.inner, not_ready_inner);
-
-        Box::pin(async move {
-            let vals = CasbinVals {
-                subject: vec!["alice".to_string()],
-                domain: None,
-            };
-            req.extensions_mut().insert(vals);
-            inner.call(req).await
-        })
-    }
-}
+const MODEL: &str = include_str!("../examples/rbac_with_pattern_model.conf");
 
 // Handler that immediately returns an empty `200 OK` response.
 async fn handler() {}
@@ -69,48 +10,26 @@ async fn handler() {}
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 async fn test_middleware() {
-    let m = DefaultModel::from_file("examples/rbac_with_pattern_model.conf")
+    let casbin_middleware = CasbinHarness::new(MODEL)
+        .policy(policy().role("alice").endpoint("GET", "/pen/1"))
+        .policy(policy().role("book_admin").endpoint("GET", "book_group"))
+        .policy(policy().role("pen_admin").endpoint("GET", "pen_group"))
+        .assign("alice", "book_admin", None)
+        .assign("bob", "pen_admin", None)
+        .grouping("g2", &["/book/:id", "book_group"])
+        .grouping("g2", &["/pen/:id", "pen_group"])
+        .with_pattern_matching()
+        .build()
         .await
         .unwrap();
 
-    let a = FileAdapter::new("examples/rbac_with_pattern_policy.csv");
-
-    let casbin_middleware = CasbinAxumLayer::new(m, a).await.unwrap();
-
-    casbin_middleware
-        .write()
-        .await
-        .get_role_manager()
-        .write()
-        .matching_fn(Some(key_match2), None);
-
-    let app = Router::new()
+    let router = Router::new()
         .route("/pen/1", get(handler))
         .route("/pen/2", get(handler))
-        .route("/book/:id", get(handler))
-        .layer(casbin_middleware)
-        .layer(FakeAuthLayer);
-
-    let service = tower::ServiceBuilder::new().service(app);
-
-    let resp_pen_1 = service
-        .clone()
-        .oneshot(Request::builder().uri("/pen/1").body(Body::empty()).unwrap())
-        .await
-        .unwrap();
-    assert_eq!(resp_pen_1.status(), StatusCode::OK);
-
-    let resp_book = service
-        .clone()
-        .oneshot(Request::builder().uri("/book/2").body(Body::empty()).unwrap())
-        .await
-        .unwrap();
-    assert_eq!(resp_book.status(), StatusCode::OK);
+        .route("/book/{id}", get(handler));
+    let app = protect(router, casbin_middleware, vals(&["alice"], None));
 
-    let resp_pen_2 = service
-        .clone()
-        .oneshot(Request::builder().uri("/pen/2").body(Body::empty()).unwrap())
-        .await
-        .unwrap();
-    assert_eq!(resp_pen_2.status(), StatusCode::FORBIDDEN);
+    assert_eq!(status(&app, "GET", "/pen/1").await, StatusCode::OK);
+    assert_eq!(status(&app, "GET", "/book/2").await, StatusCode::OK);
+    assert_eq!(status(&app, "GET", "/pen/2").await, StatusCode::FORBIDDEN);
 }
@@ -61,6 +61,7 @@ where
             let vals = CasbinVals {
                 subject: vec!["alice".to_string()],
                 domain: None,
+                scope: None,
             };
             req.extensions_mut().insert(vals);
             inner.call(req).await
@@ -1,4 +1,7 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::{ignore_existing, seed_time, status};
+use crate::schemas::m20240815_082808_create_enum_status::Status;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,33 +9,50 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_domain (id, code, name, description, status, created_at, created_by, updated_at, updated_by)
-            VALUES ('1', 'built-in', 'built-in', '内置域,请勿进行任何操作', 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL)
-            "#.to_string(),
-        );
-
-        db.execute(insert_stmt).await?;
-
-        Ok(())
+        let insert = Query::insert()
+            .into_table(SysDomain::Table)
+            .columns([
+                SysDomain::Id,
+                SysDomain::Code,
+                SysDomain::Name,
+                SysDomain::Description,
+                SysDomain::Status,
+                SysDomain::CreatedAt,
+                SysDomain::CreatedBy,
+            ])
+            .values_panic([
+                "1".into(),
+                "built-in".into(),
+                "built-in".into(),
+                "内置域,请勿进行任何操作".into(),
+                status(Status::Enabled),
+                seed_time(),
+                "-1".into(),
+            ])
+            .on_conflict(ignore_existing())
+            .to_owned();
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        // 当表被删除时，这些数据自然会被删除，所以这里不需要额外的操作
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let delete = Query::delete()
+            .from_table(SysDomain::Table)
+            .and_where(Expr::col(SysDomain::Id).eq("1"))
+            .to_owned();
 
-        // 如果需要回滚，可以在这里删除插入的默认数据
-        // let db = manager.get_connection();
-
-        // let delete_stmt = Statement::from_string(
-        //     manager.get_database_backend(),
-        //     "DELETE FROM sys_domain WHERE id = '1'".to_string(),
-        // );
-
-        // db.execute(delete_stmt).await?;
-        Ok(())
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysDomain {
+    Table,
+    Id,
+    Code,
+    Name,
+    Description,
+    Status,
+    CreatedAt,
+    CreatedBy,
+}
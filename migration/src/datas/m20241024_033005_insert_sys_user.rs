@@ -1,4 +1,25 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::{ignore_existing, seed_demo_data, seed_time, status};
+use crate::schemas::m20240815_082808_create_enum_status::Status;
+
+/** 种子用户的默认密码哈希 */
+const SEED_PASSWORD_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$8TC8kz2KUf0ytBWeFn5CZA$UgL+qvhpeNyijDBfL4A90KjdXOJ7tNP77RrufQhOkgg";
+
+/** 种子用户头像 */
+const SEED_AVATAR: &str = "https://minio.bytebytebrew.com/default/Ugly%20Avatar%20Face.png";
+
+/**
+ * 种子用户
+ *
+ * 依次为用户ID、用户名、邮箱、手机号、昵称和是否为演示用户，演示用户只在启用演示数据时写入
+ */
+const SEED_USERS: [(&str, &str, &str, &str, &str, bool); 3] = [
+    ("1", "alion", "111@gmail.com", "18511111111", "alion", false),
+    ("2", "Administrator", "222@gmail.com", "18522222222", "Admin", true),
+    ("3", "GeneralUser", "333@gmail.com", "18533333333", "User", true),
+];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,25 +27,79 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_user (id, username, password, domain, built_in, avatar, email, phone_number, nick_name, status, created_at, created_by, updated_at, updated_by)
-            VALUES
-            ('1', 'alion', '$argon2id$v=19$m=19456,t=2,p=1$8TC8kz2KUf0ytBWeFn5CZA$UgL+qvhpeNyijDBfL4A90KjdXOJ7tNP77RrufQhOkgg', 'built-in', true, 'https://minio.bytebytebrew.com/default/Ugly%20Avatar%20Face.png', '111@gmail.com', '18511111111', 'alion', 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('2', 'Administrator', '$argon2id$v=19$m=19456,t=2,p=1$8TC8kz2KUf0ytBWeFn5CZA$UgL+qvhpeNyijDBfL4A90KjdXOJ7tNP77RrufQhOkgg', 'built-in', true, 'https://minio.bytebytebrew.com/default/Ugly%20Avatar%20Face.png', '222@gmail.com', '18522222222', 'Admin', 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('3', 'GeneralUser', '$argon2id$v=19$m=19456,t=2,p=1$8TC8kz2KUf0ytBWeFn5CZA$UgL+qvhpeNyijDBfL4A90KjdXOJ7tNP77RrufQhOkgg', 'built-in', true, 'https://minio.bytebytebrew.com/default/Ugly%20Avatar%20Face.png', '333@gmail.com', '18533333333', 'User', 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL)
-            "#.to_string(),
-        );
-
-        db.execute(insert_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(SysUser::Table).columns([
+            SysUser::Id,
+            SysUser::Username,
+            SysUser::Password,
+            SysUser::Domain,
+            SysUser::BuiltIn,
+            SysUser::Avatar,
+            SysUser::Email,
+            SysUser::PhoneNumber,
+            SysUser::NickName,
+            SysUser::Status,
+            SysUser::CreatedAt,
+            SysUser::CreatedBy,
+        ]);
+
+        let demo = seed_demo_data();
+        for (id, username, email, phone_number, nick_name, _) in
+            SEED_USERS.into_iter().filter(|(.., is_demo)| demo || !is_demo)
+        {
+            insert.values_panic([
+                id.into(),
+                username.into(),
+                SEED_PASSWORD_HASH.into(),
+                "built-in".into(),
+                true.into(),
+                SEED_AVATAR.into(),
+                email.into(),
+                phone_number.into(),
+                nick_name.into(),
+                status(Status::Disabled),
+                seed_time(),
+                "-1".into(),
+            ]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Ok(())
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ids: Vec<&str> = SEED_USERS.iter().map(|(id, ..)| *id).collect();
+
+        // 先删除种子用户的可访问域关联，该表由之后的架构迁移创建
+        if manager.has_table("sys_user_domain").await? {
+            let delete = Query::delete()
+                .from_table(Alias::new("sys_user_domain"))
+                .and_where(Expr::col(Alias::new("user_id")).is_in(ids.clone()))
+                .to_owned();
+            manager.exec_stmt(delete).await?;
+        }
+
+        let delete = Query::delete()
+            .from_table(SysUser::Table)
+            .and_where(Expr::col(SysUser::Id).is_in(ids))
+            .to_owned();
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    Id,
+    Username,
+    Password,
+    Domain,
+    BuiltIn,
+    Avatar,
+    Email,
+    PhoneNumber,
+    NickName,
+    Status,
+    CreatedAt,
+    CreatedBy,
+}
@@ -1,4 +1,14 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::{ignore_existing, seed_demo_data};
+
+/**
+ * 种子用户的角色
+ *
+ * 依次为用户ID、角色ID和是否属于演示用户，演示用户的角色只在启用演示数据时写入
+ */
+const SEED_USER_ROLES: [(&str, &str, bool); 3] =
+    [("1", "1", false), ("2", "2", true), ("3", "3", true)];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,26 +16,40 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_user_role_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_user_role (user_id, role_id)
-            VALUES
-            ('1', '1'),
-            ('2', '2'),
-            ('3', '3')
-            "#
-            .to_string(),
-        );
-
-        db.execute(insert_user_role_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(SysUserRole::Table).columns([SysUserRole::UserId, SysUserRole::RoleId]);
+
+        let demo = seed_demo_data();
+        for (user_id, role_id, _) in
+            SEED_USER_ROLES.into_iter().filter(|(.., is_demo)| demo || !is_demo)
+        {
+            insert.values_panic([user_id.into(), role_id.into()]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Ok(())
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 随后回滚的迁移删除种子用户和角色，先删除外键引用它们的全部关联，包括部署后新增的
+        let user_ids: Vec<&str> = SEED_USER_ROLES.iter().map(|(user_id, ..)| *user_id).collect();
+        let role_ids: Vec<&str> = SEED_USER_ROLES.iter().map(|(_, role_id, _)| *role_id).collect();
+        let delete = Query::delete()
+            .from_table(SysUserRole::Table)
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col(SysUserRole::UserId).is_in(user_ids))
+                    .add(Expr::col(SysUserRole::RoleId).is_in(role_ids)),
+            )
+            .to_owned();
+
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysUserRole {
+    Table,
+    UserId,
+    RoleId,
+}
@@ -1,4 +1,27 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::ignore_existing;
+
+/**
+ * 内置角色的菜单
+ *
+ * 依次为角色ID和菜单ID，均属于内置域
+ */
+const SEED_ROLE_MENUS: [(&str, i32); 13] = [
+    ("1", 50),
+    ("1", 54),
+    ("1", 62),
+    ("1", 63),
+    ("1", 64),
+    ("1", 65),
+    ("3", 50),
+    ("2", 50),
+    ("2", 62),
+    ("1", 51),
+    ("1", 52),
+    ("1", 71),
+    ("1", 72),
+];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,36 +29,43 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_role_menu_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_role_menu (role_id, menu_id, domain)
-            VALUES
-            ('1', '50', 'built-in'),
-            ('1', '54', 'built-in'),
-            ('1', '62', 'built-in'),
-            ('1', '63', 'built-in'),
-            ('1', '64', 'built-in'),
-            ('1', '65', 'built-in'),
-            ('3', '50', 'built-in'),
-            ('2', '50', 'built-in'),
-            ('2', '62', 'built-in'),
-            ('1', '51', 'built-in'),
-            ('1', '52', 'built-in'),
-            ('1', '71', 'built-in'),
-            ('1', '72', 'built-in')
-            "#
-            .to_string(),
-        );
-
-        db.execute(insert_role_menu_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(SysRoleMenu::Table).columns([
+            SysRoleMenu::RoleId,
+            SysRoleMenu::MenuId,
+            SysRoleMenu::Domain,
+        ]);
+
+        for (role_id, menu_id) in SEED_ROLE_MENUS {
+            insert.values_panic([role_id.into(), menu_id.into(), "built-in".into()]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Ok(())
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let mut condition = Cond::any();
+        for (role_id, menu_id) in SEED_ROLE_MENUS {
+            condition = condition.add(
+                Expr::col(SysRoleMenu::RoleId)
+                    .eq(role_id)
+                    .and(Expr::col(SysRoleMenu::MenuId).eq(menu_id)),
+            );
+        }
+        let delete = Query::delete()
+            .from_table(SysRoleMenu::Table)
+            .cond_where(condition)
+            .to_owned();
+
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysRoleMenu {
+    Table,
+    RoleId,
+    MenuId,
+    Domain,
+}
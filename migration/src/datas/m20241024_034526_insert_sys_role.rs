@@ -1,4 +1,18 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::{ignore_existing, seed_time, status};
+use crate::schemas::m20240815_082808_create_enum_status::Status;
+
+/**
+ * 内置角色
+ *
+ * 依次为角色ID、角色代码、名称和父角色ID
+ */
+const SEED_ROLES: [(&str, &str, &str, &str); 3] = [
+    ("1", "ROLE_SUPER", "超级管理员", "0"),
+    ("2", "ROLE_ADMIN", "管理员", "1"),
+    ("3", "ROLE_USER", "用户", "1"),
+];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,25 +20,55 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_role_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_role (id, code, name, description, pid, status, created_at, created_by, updated_at, updated_by)
-            VALUES
-            ('1', 'ROLE_SUPER', '超级管理员', '超级管理员', 0, 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('2', 'ROLE_ADMIN', '管理员', '管理员', 1, 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('3', 'ROLE_USER', '用户', '用户', 1, 'enabled', '2024-05-15 00:00:00.000', '-1', NULL, NULL)
-            "#.to_string(),
-        );
-
-        db.execute(insert_role_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(SysRole::Table).columns([
+            SysRole::Id,
+            SysRole::Code,
+            SysRole::Name,
+            SysRole::Description,
+            SysRole::Pid,
+            SysRole::Status,
+            SysRole::CreatedAt,
+            SysRole::CreatedBy,
+        ]);
+
+        for (id, code, name, pid) in SEED_ROLES {
+            insert.values_panic([
+                id.into(),
+                code.into(),
+                name.into(),
+                name.into(),
+                pid.into(),
+                status(Status::Enabled),
+                seed_time(),
+                "-1".into(),
+            ]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Ok(())
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ids: Vec<&str> = SEED_ROLES.iter().map(|(id, ..)| *id).collect();
+        let delete = Query::delete()
+            .from_table(SysRole::Table)
+            .and_where(Expr::col(SysRole::Id).is_in(ids))
+            .to_owned();
+
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysRole {
+    Table,
+    Id,
+    Code,
+    Name,
+    Description,
+    Pid,
+    Status,
+    CreatedAt,
+    CreatedBy,
+}
@@ -1,4 +1,240 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::prelude::*;
+
+use super::{ignore_existing, menu_type, seed_time, status};
+use crate::schemas::m20240815_082808_create_enum_status::{MenuType, Status};
+
+/**
+ * 种子菜单
+ *
+ * 菜单名称与路由名称相同，国际化键为 `route.<路由名称>`
+ */
+struct SeedMenu {
+    id: i32,
+    menu_type: MenuType,
+    route_name: &'static str,
+    icon: &'static str,
+    route_path: &'static str,
+    component: &'static str,
+    active_menu: &'static str,
+    hide_in_menu: bool,
+    pid: &'static str,
+    sequence: i32,
+    keep_alive: bool,
+    constant: bool,
+}
+
+const SEED_MENUS: [SeedMenu; 15] = [
+    SeedMenu {
+        id: 1,
+        menu_type: MenuType::Menu,
+        route_name: "login",
+        icon: "",
+        route_path: "/login/:module(pwd-login|code-login|register|reset-pwd|bind-wechat)?",
+        component: "layout.blank$view.login",
+        active_menu: "",
+        hide_in_menu: true,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: true,
+    },
+    SeedMenu {
+        id: 2,
+        menu_type: MenuType::Menu,
+        route_name: "403",
+        icon: "",
+        route_path: "/403",
+        component: "layout.blank$view.403",
+        active_menu: "",
+        hide_in_menu: true,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: true,
+    },
+    SeedMenu {
+        id: 3,
+        menu_type: MenuType::Menu,
+        route_name: "404",
+        icon: "",
+        route_path: "/404",
+        component: "layout.blank$view.404",
+        active_menu: "",
+        hide_in_menu: true,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: true,
+    },
+    SeedMenu {
+        id: 4,
+        menu_type: MenuType::Menu,
+        route_name: "500",
+        icon: "",
+        route_path: "/500",
+        component: "layout.blank$view.500",
+        active_menu: "",
+        hide_in_menu: true,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: true,
+    },
+    SeedMenu {
+        id: 5,
+        menu_type: MenuType::Menu,
+        route_name: "iframe-page",
+        icon: "",
+        route_path: "/iframe-page/:url",
+        component: "layout.base$view.iframe-page",
+        active_menu: "",
+        hide_in_menu: true,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: true,
+    },
+    SeedMenu {
+        id: 62,
+        menu_type: MenuType::Menu,
+        route_name: "manage_menu",
+        icon: "material-symbols:route",
+        route_path: "/manage/menu",
+        component: "view.manage_menu",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "54",
+        sequence: 2,
+        keep_alive: true,
+        constant: false,
+    },
+    SeedMenu {
+        id: 65,
+        menu_type: MenuType::Menu,
+        route_name: "manage_user-detail",
+        icon: "",
+        route_path: "/manage/user-detail/:id",
+        component: "view.manage_user-detail",
+        active_menu: "manage_user",
+        hide_in_menu: true,
+        pid: "54",
+        sequence: 3,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 50,
+        menu_type: MenuType::Menu,
+        route_name: "home",
+        icon: "mdi:monitor-dashboard",
+        route_path: "/home",
+        component: "layout.base$view.home",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 54,
+        menu_type: MenuType::Directory,
+        route_name: "manage",
+        icon: "carbon:cloud-service-management",
+        route_path: "/manage",
+        component: "layout.base",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "0",
+        sequence: 4,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 64,
+        menu_type: MenuType::Menu,
+        route_name: "manage_user",
+        icon: "ic:round-manage-accounts",
+        route_path: "/manage/user",
+        component: "view.manage_user",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "54",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 63,
+        menu_type: MenuType::Menu,
+        route_name: "manage_role",
+        icon: "carbon:user-role",
+        route_path: "/manage/role",
+        component: "view.manage_role",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "54",
+        sequence: 1,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 71,
+        menu_type: MenuType::Menu,
+        route_name: "log_login",
+        icon: "carbon:login",
+        route_path: "/log/login",
+        component: "view.log_login",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "52",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 72,
+        menu_type: MenuType::Menu,
+        route_name: "log_operation",
+        icon: "carbon:operations-record",
+        route_path: "/log/operation",
+        component: "view.log_operation",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "52",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 52,
+        menu_type: MenuType::Directory,
+        route_name: "log",
+        icon: "carbon:cloud-logging",
+        route_path: "/log",
+        component: "layout.base",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+    SeedMenu {
+        id: 51,
+        menu_type: MenuType::Menu,
+        route_name: "access-key",
+        icon: "carbon:document-signed",
+        route_path: "/access-key",
+        component: "layout.base$view.access-key",
+        active_menu: "",
+        hide_in_menu: false,
+        pid: "0",
+        sequence: 0,
+        keep_alive: false,
+        constant: false,
+    },
+];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,37 +242,94 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-
-        let insert_menu_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO sys_menu (id, menu_type, menu_name, icon_type, icon, route_name, route_path, component, path_param, status, active_menu, hide_in_menu, pid, sequence, i18n_key, keep_alive, constant, href, multi_tab, created_at, created_by, updated_at, updated_by)
-            VALUES
-            ('1', 'menu', 'login', '1', '', 'login', '/login/:module(pwd-login|code-login|register|reset-pwd|bind-wechat)?', 'layout.blank$view.login', '', 'enabled', '', 'true', '0', '0', 'route.login', 'false', 'true', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('2', 'menu', '403', '1', '', '403', '/403', 'layout.blank$view.403', '', 'enabled', '', 'true', '0', '0', 'route.403', 'false', 'true', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('3', 'menu', '404', '1', '', '404', '/404', 'layout.blank$view.404', '', 'enabled', '', 'true', '0', '0', 'route.404', 'false', 'true', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('4', 'menu', '500', '1', '', '500', '/500', 'layout.blank$view.500', '', 'enabled', '', 'true', '0', '0', 'route.500', 'false', 'true', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('5', 'menu', 'iframe-page', '1', '', 'iframe-page', '/iframe-page/:url', 'layout.base$view.iframe-page', '', 'enabled', '', 'true', '0', '0', 'route.iframe-page', 'false', 'true', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('62', 'menu', 'manage_menu', '1', 'material-symbols:route', 'manage_menu', '/manage/menu', 'view.manage_menu', '', 'enabled', '', 'false', '54', '2', 'route.manage_menu', 'true', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('65', 'menu', 'manage_user-detail', '1', '', 'manage_user-detail', '/manage/user-detail/:id', 'view.manage_user-detail', '', 'enabled', 'manage_user', 'true', '54', '3', 'route.manage_user-detail', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('50', 'menu', 'home', '1', 'mdi:monitor-dashboard', 'home', '/home', 'layout.base$view.home', '', 'enabled', '', 'false', '0', '0', 'route.home', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('54', 'directory', 'manage', '1', 'carbon:cloud-service-management', 'manage', '/manage', 'layout.base', '', 'enabled', '', 'false', '0', '4', 'route.manage', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('64', 'menu', 'manage_user', '1', 'ic:round-manage-accounts', 'manage_user', '/manage/user', 'view.manage_user', '', 'enabled', '', 'false', '54', '0', 'route.manage_user', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('63', 'menu', 'manage_role', '1', 'carbon:user-role', 'manage_role', '/manage/role', 'view.manage_role', '', 'enabled', '', 'false', '54', '1', 'route.manage_role', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('71', 'menu', 'log_login', '1', 'carbon:login', 'log_login', '/log/login', 'view.log_login', '', 'enabled', '', 'false', '52', '0', 'route.log_login', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('72', 'menu', 'log_operation', '1', 'carbon:operations-record', 'log_operation', '/log/operation', 'view.log_operation', '', 'enabled', '', 'false', '52', '0', 'route.log_operation', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('52', 'directory', 'log', '1', 'carbon:cloud-logging', 'log', '/log', 'layout.base', '', 'enabled', '', 'false', '0', '0', 'route.log', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL),
-            ('51', 'menu', 'access-key', '1', 'carbon:document-signed', 'access-key', '/access-key', 'layout.base$view.access-key', '', 'enabled', '', 'false', '0', '0', 'route.access-key', 'false', 'false', '', 'false', '2024-05-15 00:00:00.000', '-1', NULL, NULL)
-            "#.to_string(),
-        );
-
-        db.execute(insert_menu_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(SysMenu::Table).columns([
+            SysMenu::Id,
+            SysMenu::MenuType,
+            SysMenu::MenuName,
+            SysMenu::IconType,
+            SysMenu::Icon,
+            SysMenu::RouteName,
+            SysMenu::RoutePath,
+            SysMenu::Component,
+            SysMenu::PathParam,
+            SysMenu::Status,
+            SysMenu::ActiveMenu,
+            SysMenu::HideInMenu,
+            SysMenu::Pid,
+            SysMenu::Sequence,
+            SysMenu::I18nKey,
+            SysMenu::KeepAlive,
+            SysMenu::Constant,
+            SysMenu::Href,
+            SysMenu::MultiTab,
+            SysMenu::CreatedAt,
+            SysMenu::CreatedBy,
+        ]);
+
+        for menu in SEED_MENUS {
+            insert.values_panic([
+                menu.id.into(),
+                menu_type(menu.menu_type),
+                menu.route_name.into(),
+                1.into(),
+                menu.icon.into(),
+                menu.route_name.into(),
+                menu.route_path.into(),
+                menu.component.into(),
+                "".into(),
+                status(Status::Enabled),
+                menu.active_menu.into(),
+                menu.hide_in_menu.into(),
+                menu.pid.into(),
+                menu.sequence.into(),
+                format!("route.{}", menu.route_name).into(),
+                menu.keep_alive.into(),
+                menu.constant.into(),
+                "".into(),
+                false.into(),
+                seed_time(),
+                "-1".into(),
+            ]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
-    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
-        Ok(())
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ids: Vec<i32> = SEED_MENUS.iter().map(|menu| menu.id).collect();
+        let delete = Query::delete()
+            .from_table(SysMenu::Table)
+            .and_where(Expr::col(SysMenu::Id).is_in(ids))
+            .to_owned();
+
+        manager.exec_stmt(delete).await
     }
 }
+
+#[derive(DeriveIden)]
+enum SysMenu {
+    Table,
+    Id,
+    MenuType,
+    MenuName,
+    IconType,
+    Icon,
+    RouteName,
+    RoutePath,
+    Component,
+    PathParam,
+    Status,
+    ActiveMenu,
+    HideInMenu,
+    Pid,
+    Sequence,
+    I18nKey,
+    KeepAlive,
+    Constant,
+    Href,
+    MultiTab,
+    CreatedAt,
+    CreatedBy,
+}
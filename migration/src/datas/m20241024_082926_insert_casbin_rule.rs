@@ -1,4 +1,57 @@
-use sea_orm_migration::{prelude::*, sea_orm::Statement};
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{DbBackend, Statement},
+};
+
+use super::ignore_existing;
+
+/**
+ * 内置角色的接口权限
+ *
+ * 依次为角色、域、接口路径和请求方法，未使用的 v4、v5 写入空字符串
+ */
+const SEED_POLICIES: [(&str, &str, &str, &str); 40] = [
+    ("ROLE_SUPER", "built-in", "/domain", "GET"),
+    ("ROLE_SUPER", "built-in", "/domain", "POST"),
+    ("ROLE_SUPER", "built-in", "/domain/:id", "GET"),
+    ("ROLE_SUPER", "built-in", "/domain", "PUT"),
+    ("ROLE_SUPER", "built-in", "/domain/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/route", "GET"),
+    ("ROLE_SUPER", "built-in", "/route", "POST"),
+    ("ROLE_SUPER", "built-in", "/route/:id", "GET"),
+    ("ROLE_SUPER", "built-in", "/route", "PUT"),
+    ("ROLE_SUPER", "built-in", "/route/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/route/tree", "GET"),
+    ("ROLE_SUPER", "built-in", "/route/auth-route/:roleId", "GET"),
+    ("ROLE_SUPER", "built-in", "/role", "GET"),
+    ("ROLE_SUPER", "built-in", "/role", "POST"),
+    ("ROLE_SUPER", "built-in", "/role/:id", "GET"),
+    ("ROLE_SUPER", "built-in", "/role", "PUT"),
+    ("ROLE_SUPER", "built-in", "/role/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/api/user/users", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/user", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/user", "POST"),
+    ("ROLE_SUPER", "built-in", "/api/user/:id", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/user", "PUT"),
+    ("ROLE_SUPER", "built-in", "/api/user/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/api-endpoint", "GET"),
+    ("ROLE_SUPER", "built-in", "/api-endpoint/auth-api-endpoint/:roleCode", "GET"),
+    ("ROLE_SUPER", "built-in", "/api-endpoint/tree", "GET"),
+    ("ROLE_SUPER", "built-in", "/access-key", "GET"),
+    ("ROLE_SUPER", "built-in", "/access-key", "POST"),
+    ("ROLE_SUPER", "built-in", "/access-key/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/login-log", "GET"),
+    ("ROLE_SUPER", "built-in", "/operation-log", "GET"),
+    ("ROLE_SUPER", "built-in", "/authorization/assign-permission", "POST"),
+    ("ROLE_SUPER", "built-in", "/authorization/assign-routes", "POST"),
+    ("ROLE_SUPER", "built-in", "/api/menu", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/menu", "POST"),
+    ("ROLE_SUPER", "built-in", "/api/menu/:id", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/menu", "PUT"),
+    ("ROLE_SUPER", "built-in", "/api/menu/:id", "DELETE"),
+    ("ROLE_SUPER", "built-in", "/api/menu/tree", "GET"),
+    ("ROLE_SUPER", "built-in", "/api/menu/auth-route/:roleId", "GET"),
+];
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -11,82 +64,59 @@ impl MigrationTrait for Migration {
         // 执行架构迁移
         sea_orm_adapter::up(db).await?;
 
-        let insert_casbin_rules_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-            INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5)
-            VALUES
-            ('p', 'ROLE_SUPER', 'built-in', '/domain', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/domain', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/domain/:id', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/domain', 'PUT', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/domain/:id', 'DELETE', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/route', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route/:id', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route', 'PUT', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route/:id', 'DELETE', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route/tree', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/route/auth-route/:roleId', 'GET', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/role', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/role', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/role/:id', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/role', 'PUT', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/role/:id', 'DELETE', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user/users', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user/:id', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user', 'PUT', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/user/:id', 'DELETE', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/api-endpoint', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api-endpoint/auth-api-endpoint/:roleCode', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api-endpoint/tree', 'GET', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/access-key', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/access-key', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/access-key/:id', 'DELETE', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/login-log', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/operation-log', 'GET', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/authorization/assign-permission', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/authorization/assign-routes', 'POST', '', ''),
-
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu', 'POST', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu/:id', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu', 'PUT', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu/:id', 'DELETE', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu/tree', 'GET', '', ''),
-            ('p', 'ROLE_SUPER', 'built-in', '/api/menu/auth-route/:roleId', 'GET', '', '')
-        "#
-            .to_string(),
-        );
-
-        db.execute(insert_casbin_rules_stmt).await?;
-
-        Ok(())
+        let mut insert = Query::insert();
+        insert.into_table(CasbinRule::Table).columns([
+            CasbinRule::Ptype,
+            CasbinRule::V0,
+            CasbinRule::V1,
+            CasbinRule::V2,
+            CasbinRule::V3,
+            CasbinRule::V4,
+            CasbinRule::V5,
+        ]);
+
+        for (role, domain, path, method) in SEED_POLICIES {
+            insert.values_panic([
+                "p".into(),
+                role.into(),
+                domain.into(),
+                path.into(),
+                method.into(),
+                "".into(),
+                "".into(),
+            ]);
+        }
+        // 唯一索引覆盖全部规则列，已存在的策略直接跳过
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
+        // 删除所有数据
+        manager.exec_stmt(Query::delete().from_table(CasbinRule::Table).to_owned()).await?;
+
+        // PostgreSQL 重置自增序列，SQLite 删除数据后由 AUTOINCREMENT 继续递增即可
+        if manager.get_database_backend() == DbBackend::Postgres {
+            let reset_sequence = Statement::from_string(
+                DbBackend::Postgres,
+                "ALTER SEQUENCE casbin_rule_id_seq RESTART WITH 1".to_string(),
+            );
+            manager.get_connection().execute(reset_sequence).await?;
+        }
 
-        // 删除所有数据并重置序列
-        let truncate_and_reset_stmt = Statement::from_string(
-            manager.get_database_backend(),
-            r#"
-        TRUNCATE TABLE casbin_rule;
-        ALTER SEQUENCE casbin_rule_id_seq RESTART WITH 1;
-        "#
-            .to_string(),
-        );
-
-        db.execute(truncate_and_reset_stmt).await?;
         Ok(())
     }
 }
+
+#[derive(DeriveIden)]
+enum CasbinRule {
+    Table,
+    Ptype,
+    V0,
+    V1,
+    V2,
+    V3,
+    V4,
+    V5,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+/**
+ * 接口操作回填
+ *
+ * 策略的操作由请求方法改为 read/write：GET、HEAD 为 read，其余方法为 write，
+ * 旧版本的 rw 视为 write。同一路径下多个写方法合并为一条策略。
+ * 使用标准SQL，PostgreSQL 和 SQLite 均可执行。
+ */
+const UP_STATEMENTS: [&str; 3] = [
+    "UPDATE sys_endpoint \
+     SET action = CASE WHEN method IN ('GET', 'HEAD') THEN 'read' ELSE 'write' END",
+    "INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5) \
+     SELECT DISTINCT r.ptype, r.v0, r.v1, r.v2, \
+         CASE WHEN r.v3 IN ('GET', 'HEAD') THEN 'read' ELSE 'write' END, r.v4, r.v5 \
+     FROM casbin_rule r \
+     WHERE r.ptype = 'p' \
+         AND r.v3 IN ('GET', 'HEAD', 'POST', 'PUT', 'PATCH', 'DELETE', 'rw') \
+         AND NOT EXISTS ( \
+             SELECT 1 FROM casbin_rule x \
+             WHERE x.ptype = r.ptype AND x.v0 = r.v0 AND x.v1 = r.v1 AND x.v2 = r.v2 \
+                 AND x.v3 = CASE WHEN r.v3 IN ('GET', 'HEAD') THEN 'read' ELSE 'write' END)",
+    "DELETE FROM casbin_rule \
+     WHERE ptype = 'p' AND v3 IN ('GET', 'HEAD', 'POST', 'PUT', 'PATCH', 'DELETE', 'rw')",
+];
+
+/**
+ * 回滚为按请求方法授权
+ *
+ * 按已登记接口展开 read/write 策略，未登记的路径无法还原请求方法，随策略一并删除
+ */
+const DOWN_STATEMENTS: [&str; 3] = [
+    "INSERT INTO casbin_rule (ptype, v0, v1, v2, v3, v4, v5) \
+     SELECT DISTINCT r.ptype, r.v0, r.v1, r.v2, e.method, r.v4, r.v5 \
+     FROM casbin_rule r \
+     JOIN sys_endpoint e ON e.path = r.v2 AND e.action = r.v3 \
+     WHERE r.ptype = 'p' \
+         AND NOT EXISTS ( \
+             SELECT 1 FROM casbin_rule x \
+             WHERE x.ptype = r.ptype AND x.v0 = r.v0 AND x.v1 = r.v1 AND x.v2 = r.v2 \
+                 AND x.v3 = e.method)",
+    "DELETE FROM casbin_rule WHERE ptype = 'p' AND v3 IN ('read', 'write')",
+    "UPDATE sys_endpoint SET action = 'rw'",
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &UP_STATEMENTS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &DOWN_STATEMENTS).await
+    }
+}
+
+async fn execute_all(manager: &SchemaManager<'_>, statements: &[&str]) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let backend = manager.get_database_backend();
+    for sql in statements {
+        db.execute(Statement::from_string(backend, sql.to_string())).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+/**
+ * 接口路径添加版本前缀
+ *
+ * 路由统一挂载在 /api/v1 下，已登记接口和策略中的 /api/... 路径改写为 /api/v1/...，
+ * 已带版本前缀的路径保持不变。接口ID不变，同步接口时按路径匹配已有记录。
+ * 使用标准SQL，PostgreSQL 和 SQLite 均可执行。
+ */
+const UP_STATEMENTS: [&str; 2] = [
+    "UPDATE sys_endpoint SET path = '/api/v1' || SUBSTR(path, 5) \
+     WHERE path LIKE '/api/%' AND path NOT LIKE '/api/v1/%'",
+    "UPDATE casbin_rule SET v2 = '/api/v1' || SUBSTR(v2, 5) \
+     WHERE ptype = 'p' AND v2 LIKE '/api/%' AND v2 NOT LIKE '/api/v1/%'",
+];
+
+/**
+ * 回滚为未带版本前缀的路径
+ */
+const DOWN_STATEMENTS: [&str; 2] = [
+    "UPDATE sys_endpoint SET path = '/api' || SUBSTR(path, 8) WHERE path LIKE '/api/v1/%'",
+    "UPDATE casbin_rule SET v2 = '/api' || SUBSTR(v2, 8) \
+     WHERE ptype = 'p' AND v2 LIKE '/api/v1/%'",
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &UP_STATEMENTS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &DOWN_STATEMENTS).await
+    }
+}
+
+async fn execute_all(manager: &SchemaManager<'_>, statements: &[&str]) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let backend = manager.get_database_backend();
+    for sql in statements {
+        db.execute(Statement::from_string(backend, sql.to_string())).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::{prelude::*, sea_orm::JsonValue};
+
+use super::{ignore_existing, seed_time};
+use crate::schemas::m20261016_000014_create_sys_role_template::SysRoleTemplate;
+
+/**
+ * 内置角色模板
+ *
+ * 依次为模板ID、名称、描述和权限描述。权限描述与角色权限导出文档的格式相同，
+ * 接口按路径和请求方法描述，菜单按路由名称描述，从模板创建角色时在当前环境中解析。
+ */
+const SEED_TEMPLATES: [(&str, &str, &str, &str); 2] = [
+    (
+        "1",
+        "审计员",
+        "只读查看登录日志和操作日志",
+        r#"{
+            "endpoints": [
+                {"path": "/api/v1/login-log/", "method": "GET"},
+                {"path": "/api/v1/operation-log/", "method": "GET"},
+                {"path": "/api/v1/operation-log/cursor", "method": "GET"}
+            ],
+            "menus": ["home", "log", "log_login", "log_operation"]
+        }"#,
+    ),
+    (
+        "2",
+        "用户管理员",
+        "管理本域用户及查看角色",
+        r#"{
+            "endpoints": [
+                {"path": "/api/v1/user/", "method": "GET"},
+                {"path": "/api/v1/user/", "method": "POST"},
+                {"path": "/api/v1/user/", "method": "PUT"},
+                {"path": "/api/v1/user/{id}", "method": "GET"},
+                {"path": "/api/v1/user/{id}", "method": "DELETE"},
+                {"path": "/api/v1/user/{id}/roles", "method": "GET"},
+                {"path": "/api/v1/role/", "method": "GET"}
+            ],
+            "menus": ["home", "manage", "manage_user"]
+        }"#,
+    ),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let mut insert = Query::insert();
+        insert.into_table(SysRoleTemplate::Table).columns([
+            SysRoleTemplate::Id,
+            SysRoleTemplate::Name,
+            SysRoleTemplate::Description,
+            SysRoleTemplate::Permissions,
+            SysRoleTemplate::CreatedAt,
+            SysRoleTemplate::CreatedBy,
+        ]);
+
+        for (id, name, description, permissions) in SEED_TEMPLATES {
+            let permissions: JsonValue = permissions
+                .parse()
+                .map_err(|e| DbErr::Custom(format!("Invalid seed template {}: {}", name, e)))?;
+            insert.values_panic([
+                id.into(),
+                name.into(),
+                description.into(),
+                permissions.into(),
+                seed_time(),
+                "-1".into(),
+            ]);
+        }
+        insert.on_conflict(ignore_existing());
+
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let ids: Vec<&str> = SEED_TEMPLATES.iter().map(|(id, ..)| *id).collect();
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(SysRoleTemplate::Table)
+                    .and_where(Expr::col(SysRoleTemplate::Id).is_in(ids))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::OnceLock;
+
+use sea_orm_migration::{prelude::*, sea_orm::DbBackend};
+
+/** 升级前数据写入时使用的UTC偏移（秒），未设置时不转换 */
+static LEGACY_LOCAL_OFFSET: OnceLock<i32> = OnceLock::new();
+
+/** 应用写入的时间戳列 */
+const COLUMNS: [(&str, &[&str]); 14] = [
+    ("sys_access_key", &["created_at", "last_used_at"]),
+    ("sys_domain", &["created_at", "updated_at"]),
+    ("sys_domain_feature", &["updated_at"]),
+    ("sys_endpoint", &["created_at", "updated_at"]),
+    ("sys_invitation", &["expires_at", "created_at"]),
+    ("sys_login_log", &["login_time", "created_at"]),
+    ("sys_menu", &["created_at", "updated_at"]),
+    ("sys_operation_log", &["start_time", "end_time", "created_at"]),
+    ("sys_organization", &["created_at", "updated_at"]),
+    ("sys_role", &["created_at", "updated_at"]),
+    ("sys_role_template", &["created_at", "updated_at"]),
+    ("sys_security_event", &["created_at"]),
+    ("sys_tokens", &["login_time", "created_at", "expires_at", "last_seen_at"]),
+    ("sys_user", &["created_at", "updated_at"]),
+];
+
+/**
+ * 设置升级前数据写入时使用的UTC偏移
+ *
+ * 由 `migrate` 子命令按 `time.legacy_local_offset` 配置在执行迁移前调用
+ *
+ * @param seconds 本地时间相对UTC的偏移秒数，东八区为 28800
+ */
+pub fn set_legacy_local_offset(seconds: i32) {
+    let _ = LEGACY_LOCAL_OFFSET.set(seconds);
+}
+
+/**
+ * 将已有时间戳从服务器本地时间转换为UTC
+ *
+ * 旧版本按服务器本地时间写入时间戳，之后的版本统一写入UTC。
+ * 设置了升级前的UTC偏移时，各时间戳列减去该偏移；未设置时视为已有数据按UTC写入，不做修改。
+ * 迁移只执行一次，执行记录即为已有数据是否完成转换的标记，跨升级时间点的查询不会相差数小时。
+ * 使用固定偏移转换，升级前经历过夏令时切换的数据会有一小时误差。
+ */
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        match LEGACY_LOCAL_OFFSET.get() {
+            Some(&offset) => shift_all(manager, -offset).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        match LEGACY_LOCAL_OFFSET.get() {
+            Some(&offset) => shift_all(manager, offset).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/**
+ * 将所有时间戳列平移指定秒数
+ */
+async fn shift_all(manager: &SchemaManager<'_>, seconds: i32) -> Result<(), DbErr> {
+    if seconds == 0 {
+        return Ok(());
+    }
+
+    let db = manager.get_connection();
+    let backend = manager.get_database_backend();
+    for (table, columns) in COLUMNS {
+        let assignments = columns
+            .iter()
+            .map(|column| format!("{column} = {}", shifted(backend, column, seconds)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        db.execute_unprepared(&format!("UPDATE {table} SET {assignments}")).await?;
+    }
+    Ok(())
+}
+
+/**
+ * 生成平移后的列表达式，空值保持为空
+ */
+fn shifted(backend: DbBackend, column: &str, seconds: i32) -> String {
+    match backend {
+        DbBackend::Postgres => format!("{column} + INTERVAL '{seconds} seconds'"),
+        DbBackend::MySql => format!("{column} + INTERVAL {seconds} SECOND"),
+        DbBackend::Sqlite => {
+            format!("strftime('%Y-%m-%d %H:%M:%f', {column}, '{seconds:+} seconds')")
+        },
+    }
+}
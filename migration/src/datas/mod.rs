@@ -1,3 +1,7 @@
+use sea_orm_migration::prelude::*;
+
+use crate::schemas::m20240815_082808_create_enum_status::{MenuType, Status};
+
 pub mod m20241023_102950_insert_sys_domain;
 pub mod m20241024_033005_insert_sys_user;
 pub mod m20241024_033933_insert_sys_user_role;
@@ -5,3 +9,59 @@ pub mod m20241024_034305_insert_sys_role_menu;
 pub mod m20241024_034526_insert_sys_role;
 pub mod m20241024_034744_insert_sys_menu;
 pub mod m20241024_082926_insert_casbin_rule;
+pub mod m20261016_000006_backfill_endpoint_action;
+pub mod m20261016_000009_versioned_api_paths;
+pub mod m20261016_000015_insert_sys_role_template;
+pub mod m20261016_000017_convert_legacy_local_timestamps;
+
+/** 控制是否写入演示数据的环境变量 */
+pub const SEED_DEMO_DATA_ENV: &str = "SEED_DEMO_DATA";
+
+/**
+ * 是否写入演示数据
+ *
+ * 执行迁移时读取 `SEED_DEMO_DATA` 环境变量，取值为 `true`、`1` 或 `yes` 时写入演示用户及其角色，
+ * 未设置时不写入，生产环境只保留内置域、超级管理员和控制台自身的菜单。
+ */
+pub(crate) fn seed_demo_data() -> bool {
+    std::env::var(SEED_DEMO_DATA_ENV)
+        .map(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes"))
+        .unwrap_or(false)
+}
+
+/**
+ * 忽略已存在的种子数据
+ *
+ * 生成不带冲突目标的 `ON CONFLICT DO NOTHING`，与任一唯一约束冲突的行都会被跳过，
+ * 迁移部分失败后重新执行时不会因重复键报错。PostgreSQL 和 SQLite 均支持该语法。
+ */
+pub(crate) fn ignore_existing() -> OnConflict {
+    OnConflict::new().do_nothing().to_owned()
+}
+
+/**
+ * 种子数据的创建时间
+ *
+ * 以字面量写入，PostgreSQL 按列类型转换为时间戳，SQLite 按文本保存
+ */
+pub(crate) fn seed_time() -> SimpleExpr {
+    Expr::cust("'2024-05-15 00:00:00'")
+}
+
+/**
+ * 状态枚举值
+ *
+ * PostgreSQL 下转换为 status 类型，其他数据库直接写入文本
+ */
+pub(crate) fn status(value: Status) -> SimpleExpr {
+    Expr::val(value.to_string()).as_enum(Status::Enum)
+}
+
+/**
+ * 菜单类型枚举值
+ *
+ * PostgreSQL 下转换为 menu_type 类型，其他数据库直接写入文本
+ */
+pub(crate) fn menu_type(value: MenuType) -> SimpleExpr {
+    Expr::val(value.to_string()).as_enum(MenuType::Enum)
+}
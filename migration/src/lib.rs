@@ -2,6 +2,11 @@ pub use sea_orm_migration::prelude::*;
 
 mod datas;
 mod schemas;
+
+pub use datas::{
+    m20261016_000017_convert_legacy_local_timestamps::set_legacy_local_offset, SEED_DEMO_DATA_ENV,
+};
+
 pub struct Migrator;
 
 #[async_trait::async_trait]
@@ -22,6 +27,26 @@ impl MigratorTrait for Migrator {
             Box::new(schemas::m20241023_091204_create_sys_tokens::Migration),
             Box::new(schemas::m20241023_091210_create_sys_user_role::Migration),
             Box::new(schemas::m20241023_091159_create_sys_role_menu::Migration),
+            Box::new(schemas::m20261016_000001_add_sys_operation_log_cursor_index::Migration),
+            Box::new(schemas::m20261016_000002_add_sys_access_key_last_used_at::Migration),
+            Box::new(schemas::m20261016_000003_create_sys_domain_feature::Migration),
+            Box::new(schemas::m20261016_000004_add_sys_user_org_id::Migration),
+            Box::new(schemas::m20261016_000005_create_sys_invitation::Migration),
+            Box::new(schemas::m20261016_000007_create_sys_security_event::Migration),
+            Box::new(schemas::m20261016_000008_domain_scoped_unique::Migration),
+            Box::new(schemas::m20261016_000010_add_sys_access_key_verification_window::Migration),
+            Box::new(schemas::m20261016_000011_add_sys_tokens_session_columns::Migration),
+            Box::new(schemas::m20261016_000012_add_sys_security_event_login_columns::Migration),
+            Box::new(schemas::m20261016_000013_add_menu_type_button::Migration),
+            Box::new(schemas::m20261016_000014_create_sys_role_template::Migration),
+            Box::new(schemas::m20261016_000016_utc_created_at_default::Migration),
+            Box::new(schemas::m20261016_000018_create_sys_personal_token::Migration),
+            Box::new(schemas::m20261016_000019_create_sys_user_domain::Migration),
+            Box::new(schemas::m20261016_000020_add_sys_operation_log_timed_out::Migration),
+            Box::new(schemas::m20261016_000021_add_sys_access_key_allowed_ips::Migration),
+            Box::new(schemas::m20261016_000022_add_sys_operation_log_status_code::Migration),
+            Box::new(schemas::m20261016_000023_add_sys_login_log_result::Migration),
+            Box::new(schemas::m20261016_000024_add_sys_menu_domain::Migration),
             // 数据迁移
             Box::new(datas::m20241023_102950_insert_sys_domain::Migration),
             Box::new(datas::m20241024_033005_insert_sys_user::Migration),
@@ -30,6 +55,10 @@ impl MigratorTrait for Migrator {
             Box::new(datas::m20241024_033933_insert_sys_user_role::Migration),
             Box::new(datas::m20241024_034305_insert_sys_role_menu::Migration),
             Box::new(datas::m20241024_082926_insert_casbin_rule::Migration),
+            Box::new(datas::m20261016_000006_backfill_endpoint_action::Migration),
+            Box::new(datas::m20261016_000009_versioned_api_paths::Migration),
+            Box::new(datas::m20261016_000015_insert_sys_role_template::Migration),
+            Box::new(datas::m20261016_000017_convert_legacy_local_timestamps::Migration),
         ]
     }
 }
@@ -61,6 +61,47 @@ impl MigrationTrait for Migration {
     }
 }
 
+/**
+ * 状态列定义
+ *
+ * PostgreSQL 和 MySQL 使用 status 枚举类型；SQLite 没有枚举类型，使用文本列加 CHECK 约束
+ */
+pub fn status_column<T: IntoIden>(backend: DbBackend, column: T) -> ColumnDef {
+    enum_column(backend, column, Status::Enum, [Status::Enabled, Status::Disabled, Status::Banned])
+}
+
+/**
+ * 菜单类型列定义
+ *
+ * PostgreSQL 和 MySQL 使用 menu_type 枚举类型；SQLite 使用文本列加 CHECK 约束
+ */
+pub fn menu_type_column<T: IntoIden>(backend: DbBackend, column: T) -> ColumnDef {
+    enum_column(backend, column, MenuType::Enum, [MenuType::Directory, MenuType::Menu])
+}
+
+fn enum_column<T, E, V, I>(backend: DbBackend, column: T, name: E, variants: I) -> ColumnDef
+where
+    T: IntoIden,
+    E: IntoIden,
+    V: IntoIden,
+    I: IntoIterator<Item = V>,
+{
+    let column = column.into_iden();
+    let variants: Vec<DynIden> = variants.into_iter().map(IntoIden::into_iden).collect();
+
+    let mut def = ColumnDef::new(column.clone());
+    match backend {
+        DbBackend::Sqlite => {
+            def.string()
+                .check(Expr::col(column).is_in(variants.iter().map(|variant| variant.to_string())));
+        },
+        DbBackend::MySql | DbBackend::Postgres => {
+            def.enumeration(name, variants);
+        },
+    }
+    def
+}
+
 #[derive(DeriveIden, EnumIter)]
 pub enum Status {
     #[sea_orm(iden = "status")]
@@ -81,4 +122,6 @@ pub enum MenuType {
     Directory,
     #[sea_orm(iden = "menu")]
     Menu,
+    #[sea_orm(iden = "button")]
+    Button,
 }
@@ -1,7 +1,6 @@
-use sea_orm::Iterable;
 use sea_orm_migration::prelude::*;
 
-use super::m20240815_082808_create_enum_status::Status;
+use super::m20240815_082808_create_enum_status::status_column;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -66,8 +65,7 @@ impl MigrationTrait for Migration {
                             .comment("昵称"),
                     )
                     .col(
-                        ColumnDef::new(SysUser::Status)
-                            .enumeration(Alias::new("status"), Status::iter())
+                        status_column(manager.get_database_backend(), SysUser::Status)
                             .not_null()
                             .comment("用户状态"),
                     )
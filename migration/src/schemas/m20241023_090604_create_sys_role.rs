@@ -1,7 +1,6 @@
-use sea_orm::Iterable;
 use sea_orm_migration::prelude::*;
 
-use super::m20240815_082808_create_enum_status::Status;
+use super::m20240815_082808_create_enum_status::status_column;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -30,8 +29,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(SysRole::Description).string().null())
                     .col(ColumnDef::new(SysRole::Pid).string().not_null())
                     .col(
-                        ColumnDef::new(SysRole::Status)
-                            .enumeration(Alias::new("status"), Status::iter())
+                        status_column(manager.get_database_backend(), SysRole::Status)
                             .not_null(),
                     )
                     .col(
@@ -1,7 +1,6 @@
-use sea_orm::Iterable;
 use sea_orm_migration::prelude::*;
 
-use super::m20240815_082808_create_enum_status::Status;
+use super::m20240815_082808_create_enum_status::status_column;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -34,8 +33,7 @@ impl MigrationTrait for Migration {
                             .unique_key(),
                     )
                     .col(
-                        ColumnDef::new(SysAccessKey::Status)
-                            .enumeration(Alias::new("status"), Status::iter())
+                        status_column(manager.get_database_backend(), SysAccessKey::Status)
                             .not_null(),
                     )
                     .col(ColumnDef::new(SysAccessKey::Description).string().null())
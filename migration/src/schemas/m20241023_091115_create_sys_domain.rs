@@ -1,7 +1,6 @@
-use sea_orm::Iterable;
 use sea_orm_migration::prelude::*;
 
-use super::m20240815_082808_create_enum_status::Status;
+use super::m20240815_082808_create_enum_status::status_column;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -29,8 +28,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(SysDomain::Name).string().not_null())
                     .col(ColumnDef::new(SysDomain::Description).string().null())
                     .col(
-                        ColumnDef::new(SysDomain::Status)
-                            .enumeration(Alias::new("status"), Status::iter())
+                        status_column(manager.get_database_backend(), SysDomain::Status)
                             .not_null(),
                     )
                     .col(
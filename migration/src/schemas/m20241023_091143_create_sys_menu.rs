@@ -1,7 +1,6 @@
-use sea_orm::Iterable;
 use sea_orm_migration::prelude::*;
 
-use super::m20240815_082808_create_enum_status::{MenuType, Status};
+use super::m20240815_082808_create_enum_status::{menu_type_column, status_column};
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -22,8 +21,7 @@ impl MigrationTrait for Migration {
                             .primary_key(),
                     )
                     .col(
-                        ColumnDef::new(SysMenu::MenuType)
-                            .enumeration(Alias::new("menu_type"), MenuType::iter())
+                        menu_type_column(manager.get_database_backend(), SysMenu::MenuType)
                             .not_null(),
                     )
                     .col(ColumnDef::new(SysMenu::MenuName).string().not_null())
@@ -39,8 +37,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(SysMenu::Component).string().not_null())
                     .col(ColumnDef::new(SysMenu::PathParam).string().null())
                     .col(
-                        ColumnDef::new(SysMenu::Status)
-                            .enumeration(Alias::new("status"), Status::iter())
+                        status_column(manager.get_database_backend(), SysMenu::Status)
                             .not_null(),
                     )
                     .col(ColumnDef::new(SysMenu::ActiveMenu).string().null())
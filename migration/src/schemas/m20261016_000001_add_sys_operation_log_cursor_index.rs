@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+/** 操作日志游标分页索引名称 */
+const IDX_SYS_OPERATION_LOG_CREATED_AT_ID: &str = "idx_sys_operation_log_created_at_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 游标分页按 (created_at, id) 排序及过滤，复合索引避免全表扫描
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_OPERATION_LOG_CREATED_AT_ID)
+                    .table(SysOperationLog::Table)
+                    .col(SysOperationLog::CreatedAt)
+                    .col(SysOperationLog::Id)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_SYS_OPERATION_LOG_CREATED_AT_ID)
+                    .table(SysOperationLog::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysOperationLog {
+    Table,
+    Id,
+    CreatedAt,
+}
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+/** 域功能开关唯一索引名称 */
+const IDX_SYS_DOMAIN_FEATURE_DOMAIN_CODE_FEATURE_KEY: &str =
+    "idx_sys_domain_feature_domain_code_feature_key";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysDomainFeature::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysDomainFeature::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysDomainFeature::DomainCode).string().not_null())
+                    .col(ColumnDef::new(SysDomainFeature::FeatureKey).string().not_null())
+                    .col(
+                        ColumnDef::new(SysDomainFeature::Enabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(ColumnDef::new(SysDomainFeature::UpdatedAt).timestamp().null())
+                    .col(ColumnDef::new(SysDomainFeature::UpdatedBy).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 每个域的每个功能只保留一条开关记录
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_DOMAIN_FEATURE_DOMAIN_CODE_FEATURE_KEY)
+                    .table(SysDomainFeature::Table)
+                    .col(SysDomainFeature::DomainCode)
+                    .col(SysDomainFeature::FeatureKey)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysDomainFeature::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysDomainFeature {
+    Table,
+    Id,
+    DomainCode,
+    FeatureKey,
+    Enabled,
+    UpdatedAt,
+    UpdatedBy,
+}
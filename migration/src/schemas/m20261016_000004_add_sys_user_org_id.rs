@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+/** 用户所属组织索引名称 */
+const IDX_SYS_USER_ORG_ID: &str = "idx_sys_user_org_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 记录用户所属组织，删除组织前需检查是否仍有关联用户
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysUser::Table)
+                    .add_column_if_not_exists(ColumnDef::new(SysUser::OrgId).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_USER_ORG_ID)
+                    .table(SysUser::Table)
+                    .col(SysUser::OrgId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(IDX_SYS_USER_ORG_ID).table(SysUser::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter().table(SysUser::Table).drop_column(SysUser::OrgId).to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    OrgId,
+}
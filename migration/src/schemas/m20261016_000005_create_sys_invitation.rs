@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+/** 邀请码唯一索引名称 */
+const IDX_SYS_INVITATION_CODE: &str = "idx_sys_invitation_code";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysInvitation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysInvitation::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysInvitation::Code).string().not_null())
+                    .col(ColumnDef::new(SysInvitation::Domain).string().not_null())
+                    .col(ColumnDef::new(SysInvitation::RoleId).string().not_null())
+                    .col(ColumnDef::new(SysInvitation::MaxUses).integer().not_null())
+                    .col(
+                        ColumnDef::new(SysInvitation::UsedCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(SysInvitation::ExpiresAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysInvitation::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysInvitation::CreatedBy).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 注册时按邀请码查找并原子递增使用次数
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_INVITATION_CODE)
+                    .table(SysInvitation::Table)
+                    .col(SysInvitation::Code)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysInvitation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysInvitation {
+    Table,
+    Id,
+    Code,
+    Domain,
+    RoleId,
+    MaxUses,
+    UsedCount,
+    ExpiresAt,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+/** 安全事件查询索引名称 */
+const IDX_SYS_SECURITY_EVENT_IP_CREATED_AT: &str = "idx_sys_security_event_ip_created_at";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysSecurityEvent::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysSecurityEvent::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysSecurityEvent::EventType).string().not_null())
+                    .col(ColumnDef::new(SysSecurityEvent::Ip).string().not_null())
+                    .col(ColumnDef::new(SysSecurityEvent::KeyPrefix).string().null())
+                    .col(ColumnDef::new(SysSecurityEvent::Path).string().not_null())
+                    .col(
+                        ColumnDef::new(SysSecurityEvent::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 排查时按来源IP查询最近的失败记录
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_SECURITY_EVENT_IP_CREATED_AT)
+                    .table(SysSecurityEvent::Table)
+                    .col(SysSecurityEvent::Ip)
+                    .col(SysSecurityEvent::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysSecurityEvent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysSecurityEvent {
+    Table,
+    Id,
+    EventType,
+    Ip,
+    KeyPrefix,
+    Path,
+    CreatedAt,
+}
@@ -0,0 +1,287 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{DbBackend, Statement},
+};
+
+/** 已有角色归属的域，与初始化数据中的内置域一致 */
+const BUILT_IN_DOMAIN: &str = "built-in";
+
+/** 原用户名唯一索引名称 */
+const IDX_SYS_USER_USERNAME: &str = "idx_sys_user_username";
+
+/** 域内用户名唯一索引名称 */
+const IDX_SYS_USER_DOMAIN_USERNAME: &str = "idx_sys_user_domain_username";
+
+/** 回滚时重建的角色代码唯一索引名称 */
+const IDX_SYS_ROLE_CODE: &str = "idx_sys_role_code";
+
+/** 域内角色代码唯一索引名称 */
+const IDX_SYS_ROLE_DOMAIN_CODE: &str = "idx_sys_role_domain_code";
+
+/**
+ * 用户名和角色代码按域唯一
+ *
+ * 用户名由全局唯一改为 (domain, username) 唯一；角色增加 domain 列，
+ * 已有角色归属内置域，角色代码由全局唯一改为 (domain, code) 唯一。
+ *
+ * 建表时列上的 UNIQUE 约束在 PostgreSQL 中按约束名删除；SQLite 无法删除列约束，
+ * 按官方建议的步骤重建表（关闭外键检查、复制数据、重命名并重建索引）。
+ * 已有数据违反新约束时迁移直接失败，并列出冲突的值。
+ */
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysRole::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(SysRole::Domain)
+                            .string()
+                            .not_null()
+                            .default(BUILT_IN_DOMAIN),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        ensure_unique(manager, "sys_user", &["domain", "username"]).await?;
+        ensure_unique(manager, "sys_role", &["domain", "code"]).await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_SYS_USER_USERNAME)
+                    .table(SysUser::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+        drop_column_unique(manager, "sys_user", "username").await?;
+        drop_column_unique(manager, "sys_role", "code").await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_USER_DOMAIN_USERNAME)
+                    .table(SysUser::Table)
+                    .col(SysUser::Domain)
+                    .col(SysUser::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_ROLE_DOMAIN_CODE)
+                    .table(SysRole::Table)
+                    .col(SysRole::Domain)
+                    .col(SysRole::Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        ensure_unique(manager, "sys_user", &["username"]).await?;
+        ensure_unique(manager, "sys_role", &["code"]).await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_SYS_USER_DOMAIN_USERNAME)
+                    .table(SysUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop().name(IDX_SYS_ROLE_DOMAIN_CODE).table(SysRole::Table).to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_USER_USERNAME)
+                    .table(SysUser::Table)
+                    .col(SysUser::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_ROLE_CODE)
+                    .table(SysRole::Table)
+                    .col(SysRole::Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter().table(SysRole::Table).drop_column(SysRole::Domain).to_owned(),
+            )
+            .await
+    }
+}
+
+/**
+ * 检查已有数据是否满足唯一约束
+ *
+ * 存在重复值时返回错误，错误信息列出所有冲突的值
+ */
+async fn ensure_unique(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    columns: &[&str],
+) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let backend = manager.get_database_backend();
+    let column_list = columns.join(", ");
+    let sql = format!(
+        "SELECT {column_list} FROM {table} GROUP BY {column_list} HAVING COUNT(*) > 1"
+    );
+    let rows = db.query_all(Statement::from_string(backend, sql)).await?;
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let duplicates: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| row.try_get::<String>("", column).unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .collect();
+    Err(DbErr::Migration(format!(
+        "{} has duplicate ({}) values, resolve them before migrating: {}",
+        table,
+        column_list,
+        duplicates.join(", ")
+    )))
+}
+
+/**
+ * 删除建表时列上的 UNIQUE 约束
+ */
+async fn drop_column_unique(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    column: &str,
+) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    match manager.get_database_backend() {
+        DbBackend::Postgres => {
+            db.execute_unprepared(&format!(
+                r#"ALTER TABLE "{table}" DROP CONSTRAINT IF EXISTS "{table}_{column}_key""#
+            ))
+            .await?;
+        },
+        DbBackend::MySql => {
+            db.execute_unprepared(&format!("ALTER TABLE `{table}` DROP INDEX `{column}`")).await?;
+        },
+        DbBackend::Sqlite => {
+            rebuild_sqlite_table(manager, table, |sql| strip_column_unique(sql, column)).await?
+        },
+    }
+    Ok(())
+}
+
+/**
+ * 按改写后的建表语句重建 SQLite 表
+ *
+ * SQLite 无法修改列约束，`rewrite` 返回新的建表语句，返回 None 时表示无需重建。
+ * 所有语句在同一次调用中执行，保证外键开关和事务作用于同一个连接
+ */
+pub(crate) async fn rebuild_sqlite_table(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    rewrite: impl FnOnce(&str) -> Option<String>,
+) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    let backend = manager.get_database_backend();
+    let master = |kind: &str| {
+        Statement::from_string(
+            backend,
+            format!(
+                "SELECT sql FROM sqlite_master \
+                 WHERE type = '{kind}' AND tbl_name = '{table}' AND sql IS NOT NULL"
+            ),
+        )
+    };
+
+    let Some(row) = db.query_one(master("table")).await? else {
+        return Ok(());
+    };
+    let create_sql: String = row.try_get("", "sql")?;
+    let Some(create_sql) = rewrite(&create_sql) else {
+        return Ok(());
+    };
+    let rebuilt = format!("{table}_rebuild");
+    let create_sql = create_sql.replacen(&format!(r#""{table}""#), &format!(r#""{rebuilt}""#), 1);
+
+    let indexes = db
+        .query_all(master("index"))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "sql"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut statements = vec![
+        "PRAGMA foreign_keys = OFF".to_string(),
+        "BEGIN".to_string(),
+        create_sql,
+        format!(r#"INSERT INTO "{rebuilt}" SELECT * FROM "{table}""#),
+        format!(r#"DROP TABLE "{table}""#),
+        format!(r#"ALTER TABLE "{rebuilt}" RENAME TO "{table}""#),
+    ];
+    statements.extend(indexes);
+    statements.push("COMMIT".to_string());
+    statements.push("PRAGMA foreign_keys = ON".to_string());
+
+    db.execute_unprepared(&statements.join(";\n")).await?;
+    Ok(())
+}
+
+/**
+ * 去掉建表语句中指定列定义上的 UNIQUE
+ *
+ * 列定义上没有 UNIQUE 时返回 None
+ */
+fn strip_column_unique(create_sql: &str, column: &str) -> Option<String> {
+    let start = create_sql.find(&format!(r#""{column}" "#))?;
+    let end = create_sql[start..].find(',').map_or(create_sql.len(), |offset| start + offset);
+    let definition = &create_sql[start..end];
+    let unique = definition.find(" UNIQUE")?;
+
+    let mut sql = create_sql.to_string();
+    sql.replace_range(start + unique..start + unique + " UNIQUE".len(), "");
+    Some(sql)
+}
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    Domain,
+    Username,
+}
+
+#[derive(DeriveIden)]
+enum SysRole {
+    Table,
+    Domain,
+    Code,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 按访问密钥配置签名验证的时间戳窗口和 nonce 保留时间，为空时使用默认值
+        // SQLite 每条 ALTER TABLE 只能包含一个修改，逐列添加
+        for column in [SysAccessKey::TimestampToleranceMs, SysAccessKey::NonceTtlSecs] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysAccessKey::Table)
+                        .add_column_if_not_exists(ColumnDef::new(column).big_integer().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [SysAccessKey::TimestampToleranceMs, SysAccessKey::NonceTtlSecs] {
+            manager
+                .alter_table(
+                    Table::alter().table(SysAccessKey::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysAccessKey {
+    Table,
+    TimestampToleranceMs,
+    NonceTtlSecs,
+}
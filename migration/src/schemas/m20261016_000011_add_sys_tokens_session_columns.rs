@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+/** 令牌 JWT ID 索引名称 */
+const IDX_SYS_TOKENS_JTI: &str = "idx_sys_tokens_jti";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 记录访问令牌的 JWT ID、过期时间和最近使用时间，用于用户查看和撤销本人的登录设备
+        // SQLite 每条 ALTER TABLE 只能包含一个修改，逐列添加
+        let columns = [
+            ColumnDef::new(SysTokens::Jti).string().null().to_owned(),
+            ColumnDef::new(SysTokens::ExpiresAt).timestamp().null().to_owned(),
+            ColumnDef::new(SysTokens::LastSeenAt).timestamp().null().to_owned(),
+        ];
+        for column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysTokens::Table)
+                        .add_column_if_not_exists(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 每个请求按 JWT ID 检查令牌是否已撤销
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_TOKENS_JTI)
+                    .table(SysTokens::Table)
+                    .col(SysTokens::Jti)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(IDX_SYS_TOKENS_JTI).table(SysTokens::Table).to_owned())
+            .await?;
+        for column in [SysTokens::Jti, SysTokens::ExpiresAt, SysTokens::LastSeenAt] {
+            manager
+                .alter_table(Table::alter().table(SysTokens::Table).drop_column(column).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysTokens {
+    Table,
+    Jti,
+    ExpiresAt,
+    LastSeenAt,
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 异常登录等与用户相关的安全事件需要记录用户、域和事件详情
+        // SQLite 每条 ALTER TABLE 只能包含一个修改，逐列添加
+        let columns = [
+            ColumnDef::new(SysSecurityEvent::UserId).string().null().to_owned(),
+            ColumnDef::new(SysSecurityEvent::Domain).string().null().to_owned(),
+            ColumnDef::new(SysSecurityEvent::Detail).text().null().to_owned(),
+        ];
+        for column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(SysSecurityEvent::Table)
+                        .add_column_if_not_exists(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in
+            [SysSecurityEvent::UserId, SysSecurityEvent::Domain, SysSecurityEvent::Detail]
+        {
+            manager
+                .alter_table(
+                    Table::alter().table(SysSecurityEvent::Table).drop_column(column).to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysSecurityEvent {
+    Table,
+    UserId,
+    Domain,
+    Detail,
+}
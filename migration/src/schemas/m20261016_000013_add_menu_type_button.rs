@@ -0,0 +1,78 @@
+use sea_orm_migration::{prelude::*, sea_orm::DbBackend};
+
+use super::m20261016_000008_domain_scoped_unique::rebuild_sqlite_table;
+
+/** 原菜单类型取值列表，SQLite 的 CHECK 约束中按此格式生成 */
+const MENU_TYPES: &str = "IN ('directory', 'menu')";
+
+/** 增加按钮后的菜单类型取值列表 */
+const MENU_TYPES_WITH_BUTTON: &str = "IN ('directory', 'menu', 'button')";
+
+/**
+ * 菜单类型增加按钮
+ *
+ * 按钮类型的菜单只用于界面上的权限控制，不生成路由。
+ * PostgreSQL 向 menu_type 枚举追加取值；MySQL 修改列的枚举定义；
+ * SQLite 的取值由列上的 CHECK 约束限制，重建表以修改约束。
+ */
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        match manager.get_database_backend() {
+            DbBackend::Postgres => {
+                db.execute_unprepared("ALTER TYPE menu_type ADD VALUE IF NOT EXISTS 'button'")
+                    .await?;
+            },
+            DbBackend::MySql => {
+                db.execute_unprepared(
+                    "ALTER TABLE `sys_menu` MODIFY COLUMN `menu_type` \
+                     ENUM('directory', 'menu', 'button') NOT NULL",
+                )
+                .await?;
+            },
+            DbBackend::Sqlite => {
+                rebuild_sqlite_table(manager, "sys_menu", |sql| {
+                    sql.contains(MENU_TYPES)
+                        .then(|| sql.replacen(MENU_TYPES, MENU_TYPES_WITH_BUTTON, 1))
+                })
+                .await?;
+            },
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 先删除按钮及其角色授权，否则无法收紧取值范围
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "DELETE FROM sys_role_menu WHERE menu_id IN \
+             (SELECT id FROM sys_menu WHERE menu_type = 'button')",
+        )
+        .await?;
+        db.execute_unprepared("DELETE FROM sys_menu WHERE menu_type = 'button'").await?;
+
+        match manager.get_database_backend() {
+            // PostgreSQL 不支持删除枚举取值，保留 'button'
+            DbBackend::Postgres => {},
+            DbBackend::MySql => {
+                db.execute_unprepared(
+                    "ALTER TABLE `sys_menu` MODIFY COLUMN `menu_type` \
+                     ENUM('directory', 'menu') NOT NULL",
+                )
+                .await?;
+            },
+            DbBackend::Sqlite => {
+                rebuild_sqlite_table(manager, "sys_menu", |sql| {
+                    sql.contains(MENU_TYPES_WITH_BUTTON)
+                        .then(|| sql.replacen(MENU_TYPES_WITH_BUTTON, MENU_TYPES, 1))
+                })
+                .await?;
+            },
+        }
+        Ok(())
+    }
+}
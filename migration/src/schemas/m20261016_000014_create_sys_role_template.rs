@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+/** 模板名称唯一索引名称 */
+const IDX_SYS_ROLE_TEMPLATE_NAME: &str = "idx_sys_role_template_name";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysRoleTemplate::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysRoleTemplate::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysRoleTemplate::Name).string().not_null())
+                    .col(ColumnDef::new(SysRoleTemplate::Description).string().null())
+                    .col(ColumnDef::new(SysRoleTemplate::Permissions).json_binary().not_null())
+                    .col(ColumnDef::new(SysRoleTemplate::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysRoleTemplate::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysRoleTemplate::UpdatedAt).timestamp().null())
+                    .col(ColumnDef::new(SysRoleTemplate::UpdatedBy).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_ROLE_TEMPLATE_NAME)
+                    .table(SysRoleTemplate::Table)
+                    .col(SysRoleTemplate::Name)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysRoleTemplate::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum SysRoleTemplate {
+    Table,
+    Id,
+    Name,
+    Description,
+    Permissions,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
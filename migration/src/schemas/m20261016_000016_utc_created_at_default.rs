@@ -0,0 +1,59 @@
+use sea_orm_migration::{prelude::*, sea_orm::DbBackend};
+
+/** created_at 列默认值为当前时间的表 */
+const TABLES: [&str; 11] = [
+    "sys_access_key",
+    "sys_domain",
+    "sys_endpoint",
+    "sys_login_log",
+    "sys_menu",
+    "sys_operation_log",
+    "sys_organization",
+    "sys_role",
+    "sys_security_event",
+    "sys_tokens",
+    "sys_user",
+];
+
+/**
+ * created_at 默认值改为 UTC 时间
+ *
+ * 应用写入的时间戳统一为 UTC（见 `server_model::admin::timestamps`），列默认值随之调整：
+ * - PostgreSQL 的 CURRENT_TIMESTAMP 写入 timestamp 列时按会话时区转换，改为 `now() AT TIME ZONE 'utc'`
+ * - SQLite 的 CURRENT_TIMESTAMP 本身即为 UTC，无需修改
+ * - MySQL 的 timestamp 列按会话时区转换，需将连接的 time_zone 设置为 '+00:00'，不修改列定义
+ *
+ * 已有数据按服务器本地时间写入，本迁移不做转换，
+ * 按 `time.legacy_local_offset` 配置转换已有数据见 `m20261016_000017_convert_legacy_local_timestamps`。
+ */
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        set_default(manager, "(now() AT TIME ZONE 'utc')").await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        set_default(manager, "CURRENT_TIMESTAMP").await
+    }
+}
+
+/**
+ * 修改 PostgreSQL 下各表 created_at 列的默认值，其他数据库不做处理
+ */
+async fn set_default(manager: &SchemaManager<'_>, default: &str) -> Result<(), DbErr> {
+    if manager.get_database_backend() != DbBackend::Postgres {
+        return Ok(());
+    }
+
+    let db = manager.get_connection();
+    for table in TABLES {
+        db.execute_unprepared(&format!(
+            "ALTER TABLE {table} ALTER COLUMN created_at SET DEFAULT {default}"
+        ))
+        .await?;
+    }
+    Ok(())
+}
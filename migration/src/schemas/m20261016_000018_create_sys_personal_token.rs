@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+/** 令牌哈希唯一索引名称 */
+const IDX_SYS_PERSONAL_TOKEN_HASH: &str = "idx_sys_personal_token_hash";
+/** 用户令牌索引名称 */
+const IDX_SYS_PERSONAL_TOKEN_USER_ID: &str = "idx_sys_personal_token_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPersonalToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPersonalToken::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysPersonalToken::UserId).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::Domain).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::Name).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::TokenHash).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::TokenPrefix).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::Scopes).json_binary().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::ExpiresAt).timestamp().null())
+                    .col(ColumnDef::new(SysPersonalToken::LastUsedAt).timestamp().null())
+                    .col(ColumnDef::new(SysPersonalToken::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::CreatedBy).string().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_PERSONAL_TOKEN_HASH)
+                    .table(SysPersonalToken::Table)
+                    .col(SysPersonalToken::TokenHash)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SYS_PERSONAL_TOKEN_USER_ID)
+                    .table(SysPersonalToken::Table)
+                    .col(SysPersonalToken::UserId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPersonalToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum SysPersonalToken {
+    Table,
+    Id,
+    UserId,
+    Domain,
+    Name,
+    TokenHash,
+    TokenPrefix,
+    Scopes,
+    ExpiresAt,
+    LastUsedAt,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysUserDomain::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SysUserDomain::UserId).string().not_null())
+                    .col(ColumnDef::new(SysUserDomain::Domain).string().not_null())
+                    .col(ColumnDef::new(SysUserDomain::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(SysUserDomain::CreatedBy).string().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(SysUserDomain::UserId)
+                            .col(SysUserDomain::Domain),
+                    )
+                    // 外键随建表创建，SQLite 不支持向已有表添加约束
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sys_user_domain_user_id")
+                            .from(SysUserDomain::Table, SysUserDomain::UserId)
+                            .to(Alias::new("sys_user"), Alias::new("id")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysUserDomain::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysUserDomain {
+    Table,
+    UserId,
+    Domain,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 菜单所属域，为空表示所有域共用；已有菜单均为全局菜单
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysMenu::Table)
+                    .add_column_if_not_exists(ColumnDef::new(SysMenu::Domain).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_sys_menu_domain")
+                    .table(SysMenu::Table)
+                    .col(SysMenu::Domain)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .if_exists()
+                    .name("idx_sys_menu_domain")
+                    .table(SysMenu::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysMenu::Table)
+                    .drop_column(SysMenu::Domain)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysMenu {
+    Table,
+    Domain,
+}
@@ -11,3 +11,23 @@ pub mod m20241023_091155_create_sys_organization;
 pub mod m20241023_091159_create_sys_role_menu;
 pub mod m20241023_091204_create_sys_tokens;
 pub mod m20241023_091210_create_sys_user_role;
+pub mod m20261016_000001_add_sys_operation_log_cursor_index;
+pub mod m20261016_000002_add_sys_access_key_last_used_at;
+pub mod m20261016_000003_create_sys_domain_feature;
+pub mod m20261016_000004_add_sys_user_org_id;
+pub mod m20261016_000005_create_sys_invitation;
+pub mod m20261016_000007_create_sys_security_event;
+pub mod m20261016_000008_domain_scoped_unique;
+pub mod m20261016_000010_add_sys_access_key_verification_window;
+pub mod m20261016_000011_add_sys_tokens_session_columns;
+pub mod m20261016_000012_add_sys_security_event_login_columns;
+pub mod m20261016_000013_add_menu_type_button;
+pub mod m20261016_000014_create_sys_role_template;
+pub mod m20261016_000016_utc_created_at_default;
+pub mod m20261016_000018_create_sys_personal_token;
+pub mod m20261016_000019_create_sys_user_domain;
+pub mod m20261016_000020_add_sys_operation_log_timed_out;
+pub mod m20261016_000021_add_sys_access_key_allowed_ips;
+pub mod m20261016_000022_add_sys_operation_log_status_code;
+pub mod m20261016_000023_add_sys_login_log_result;
+pub mod m20261016_000024_add_sys_menu_domain;
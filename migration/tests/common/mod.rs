@@ -0,0 +1,65 @@
+//! 迁移集成测试的公共函数
+//!
+//! 各测试文件只使用其中一部分函数
+#![allow(dead_code)]
+
+use migration::{MigrationName, Migrator, MigratorTrait};
+use sea_orm_migration::sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+
+/** 写入种子数据的表 */
+pub const SEED_TABLES: [&str; 8] = [
+    "sys_domain",
+    "sys_user",
+    "sys_role",
+    "sys_menu",
+    "sys_user_role",
+    "sys_role_menu",
+    "casbin_rule",
+    "sys_role_template",
+];
+
+pub async fn count(db: &DatabaseConnection, table: &str) -> i64 {
+    let backend = db.get_database_backend();
+    let row = db
+        .query_one(Statement::from_string(backend, format!("SELECT COUNT(*) AS cnt FROM {table}")))
+        .await
+        .unwrap()
+        .unwrap();
+    row.try_get("", "cnt").unwrap()
+}
+
+pub async fn seed_counts(db: &DatabaseConnection) -> Vec<i64> {
+    let mut counts = Vec::new();
+    for table in SEED_TABLES {
+        counts.push(count(db, table).await);
+    }
+    counts
+}
+
+/**
+ * 模拟迁移部分失败后重新执行
+ *
+ * 在空数据库上执行到内置策略写入为止，删除写入种子数据的迁移记录后再次执行，
+ * 种子数据不应重复或报错，之后的迁移仍可继续执行
+ */
+pub async fn assert_seed_migrations_rerunnable(db: &DatabaseConnection) {
+    let seeded = Migrator::migrations()
+        .iter()
+        .position(|migration| migration.name() == "m20241024_082926_insert_casbin_rule")
+        .unwrap()
+        + 1;
+    Migrator::up(db, Some(seeded as u32)).await.unwrap();
+    let before = seed_counts(db).await;
+
+    let backend = db.get_database_backend();
+    let reset = Statement::from_string(
+        backend,
+        "DELETE FROM seaql_migrations WHERE version LIKE '%insert%'".to_string(),
+    );
+    let rerun = db.execute(reset).await.unwrap().rows_affected();
+    assert_eq!(rerun, 7);
+    Migrator::up(db, Some(rerun as u32)).await.unwrap();
+    assert_eq!(seed_counts(db).await, before);
+
+    Migrator::up(db, None).await.unwrap();
+}
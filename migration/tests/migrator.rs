@@ -0,0 +1,126 @@
+//! SQLite 迁移集成测试
+//!
+//! 在内存数据库上执行全部迁移，确认架构和初始数据在 SQLite 上可用。
+//! 本文件的测试依赖演示用户，统一启用演示数据；未启用时的种子数据见 `seed_data.rs`。
+
+mod common;
+
+use common::{assert_seed_migrations_rerunnable, count, seed_counts};
+use migration::{MigrationName, Migrator, MigratorTrait, SEED_DEMO_DATA_ENV};
+use sea_orm_migration::sea_orm::{ConnectionTrait, Database, DatabaseConnection, Statement};
+
+async fn connect() -> DatabaseConnection {
+    std::env::set_var(SEED_DEMO_DATA_ENV, "true");
+    Database::connect("sqlite::memory:").await.unwrap()
+}
+
+#[async_std::test]
+async fn test_migrator_up_on_sqlite() {
+    let db = connect().await;
+
+    Migrator::up(&db, None).await.unwrap();
+
+    assert_eq!(count(&db, "sys_domain").await, 1);
+    assert_eq!(count(&db, "sys_user").await, 3);
+    assert_eq!(count(&db, "sys_role").await, 3);
+    assert_eq!(count(&db, "sys_menu").await, 15);
+    // 种子策略按 read/write 合并后，同一路径的多个写方法只保留一条
+    assert_eq!(count(&db, "casbin_rule").await, 35);
+
+    // SQLite 没有枚举类型，状态列由 CHECK 约束限制取值
+    let backend = db.get_database_backend();
+    let invalid_status =
+        Statement::from_string(backend, "UPDATE sys_user SET status = 'unknown'".to_string());
+    assert!(db.execute(invalid_status).await.is_err());
+}
+
+#[async_std::test]
+async fn test_username_and_role_code_unique_per_domain() {
+    let db = connect().await;
+    Migrator::up(&db, None).await.unwrap();
+
+    let backend = db.get_database_backend();
+    let execute = |sql: &str| db.execute(Statement::from_string(backend, sql.to_string()));
+
+    // 同一域内仍然唯一
+    assert!(execute("UPDATE sys_user SET username = 'alion' WHERE id = '2'").await.is_err());
+    assert!(
+        execute("UPDATE sys_role SET code = 'ROLE_SUPER' WHERE code = 'ROLE_ADMIN'").await.is_err()
+    );
+
+    // 不同域可以使用相同的用户名和角色代码
+    execute(
+        "INSERT INTO sys_domain (id, code, name, status, created_by) \
+         VALUES ('2', 'tenant', 'Tenant', 'enabled', '-1')",
+    )
+    .await
+    .unwrap();
+    execute("UPDATE sys_user SET domain = 'tenant', username = 'alion' WHERE id = '2'")
+        .await
+        .unwrap();
+    execute("UPDATE sys_role SET domain = 'tenant', code = 'ROLE_SUPER' WHERE code = 'ROLE_ADMIN'")
+        .await
+        .unwrap();
+
+    // 重建后的表保留其他列上的唯一约束
+    assert!(execute("UPDATE sys_user SET email = '111@gmail.com' WHERE id = '3'").await.is_err());
+}
+
+#[async_std::test]
+async fn test_policy_paths_are_versioned() {
+    let db = connect().await;
+    Migrator::up(&db, None).await.unwrap();
+
+    let backend = db.get_database_backend();
+    let count_where = |condition: &str| {
+        let sql =
+            format!("SELECT COUNT(*) AS cnt FROM casbin_rule WHERE ptype = 'p' AND {condition}");
+        let db = &db;
+        async move {
+            let row = db.query_one(Statement::from_string(backend, sql)).await.unwrap().unwrap();
+            row.try_get::<i64>("", "cnt").unwrap()
+        }
+    };
+
+    assert_eq!(count_where("v2 = '/api/v1/user/users'").await, 1);
+    assert_eq!(count_where("v2 LIKE '/api/%' AND v2 NOT LIKE '/api/v1/%'").await, 0);
+
+    // 回滚后恢复未带版本前缀的路径
+    Migrator::down(&db, Some(1)).await.unwrap();
+    assert_eq!(count_where("v2 = '/api/user/users'").await, 1);
+    assert_eq!(count_where("v2 LIKE '/api/v1/%'").await, 0);
+}
+
+#[async_std::test]
+async fn test_seed_migrations_rerunnable() {
+    let db = connect().await;
+    assert_seed_migrations_rerunnable(&db).await;
+
+    assert_eq!(count(&db, "sys_user").await, 3);
+    assert_eq!(count(&db, "sys_user_role").await, 3);
+    assert_eq!(count(&db, "casbin_rule").await, 35);
+}
+
+#[async_std::test]
+async fn test_seed_migrations_down_removes_seed_data() {
+    let db = connect().await;
+    Migrator::up(&db, None).await.unwrap();
+    let seeded = seed_counts(&db).await;
+
+    // 回滚全部数据迁移，部署后新增的角色关联同样被删除
+    let backend = db.get_database_backend();
+    let assign = "INSERT INTO sys_user_role (user_id, role_id) VALUES ('2', '1')";
+    db.execute(Statement::from_string(backend, assign.to_string())).await.unwrap();
+    let migrations = Migrator::migrations();
+    let first_seed = migrations
+        .iter()
+        .position(|migration| migration.name() == "m20241023_102950_insert_sys_domain")
+        .unwrap();
+    Migrator::down(&db, Some((migrations.len() - first_seed) as u32)).await.unwrap();
+    for count in seed_counts(&db).await {
+        assert_eq!(count, 0);
+    }
+
+    Migrator::up(&db, None).await.unwrap();
+    assert_eq!(seed_counts(&db).await, seeded);
+}
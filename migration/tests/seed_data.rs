@@ -0,0 +1,25 @@
+//! 种子数据集成测试
+//!
+//! 未启用演示数据时只写入内置域、超级管理员和控制台菜单。
+//! 环境变量在进程内共享，与启用演示数据的测试分属不同的测试文件。
+
+mod common;
+
+use common::{assert_seed_migrations_rerunnable, count};
+use migration::{Migrator, MigratorTrait, SEED_DEMO_DATA_ENV};
+use sea_orm_migration::sea_orm::Database;
+
+#[async_std::test]
+async fn test_demo_data_skipped_by_default() {
+    std::env::remove_var(SEED_DEMO_DATA_ENV);
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    assert_seed_migrations_rerunnable(&db).await;
+    // 已执行的迁移不会再次执行
+    Migrator::up(&db, None).await.unwrap();
+
+    assert_eq!(count(&db, "sys_domain").await, 1);
+    assert_eq!(count(&db, "sys_user").await, 1);
+    assert_eq!(count(&db, "sys_user_role").await, 1);
+    assert_eq!(count(&db, "sys_role").await, 3);
+    assert_eq!(count(&db, "sys_menu").await, 15);
+}
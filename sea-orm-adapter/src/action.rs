@@ -3,6 +3,24 @@
  * 
  * This module provides the core functions for managing Casbin rules in the database,
  * including adding, removing, and querying rules.
+ * 
+ * # Filter semantics
+ * 
+ * Filter values are positional: the value at index `i` is compared with column `v{i}`,
+ * always starting at `v0`. An empty string is a wildcard for its position, so a filter
+ * can constrain any column without constraining the ones before it. This matches the
+ * reference casbin adapters. With the domain model used by the server, `p` rules keep
+ * the domain in `v1` and `g` rules keep it in `v2`, so a per-tenant filter is:
+ * 
+ * ```text
+ * Filter {
+ *     p: vec!["", "domain1"],
+ *     g: vec!["", "", "domain1"],
+ * }
+ * ```
+ * 
+ * An empty filter vector loads every rule of that section, and values beyond `v5`
+ * are ignored.
  */
 
 use casbin::{error::AdapterError, Error as CasbinError, Filter, Result};
@@ -172,6 +190,9 @@ pub(crate) async fn load_policy<C: ConnectionTrait>(conn: &C) -> Result<Vec<enti
 /**
  * Loads filtered policy rules from the database
  * 
+ * `g` rules are matched against `filter.g` and `p` rules against `filter.p`, see the
+ * module documentation for the positional semantics.
+ * 
  * # Arguments
  * * `conn` - Database connection
  * * `filter` - Filter criteria
@@ -199,6 +220,9 @@ pub(crate) async fn load_filtered_policy<'conn, 'filter, C: ConnectionTrait>(
 /**
  * Creates a database condition from a rule
  * 
+ * Matches rules whose policy type starts with `prefix` (so `g` also covers `g2`) and
+ * whose column `v{i}` equals the rule value at index `i`; empty values match anything.
+ * 
  * # Arguments
  * * `prefix` - Policy type prefix
  * * `rule` - Rule to create condition from
@@ -318,3 +342,92 @@ pub(crate) async fn clear_policy<C: ConnectionTrait>(conn: &C) -> Result<()> {
         .map_err(|err| CasbinError::from(AdapterError(Box::new(err))))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection};
+
+    use super::*;
+    use crate::migration;
+
+    /** Rules from the casbin `rbac_with_domains` example */
+    static RULES: [(&str, [&str; 4]); 6] = [
+        ("p", ["admin", "domain1", "data1", "read"]),
+        ("p", ["admin", "domain1", "data1", "write"]),
+        ("p", ["admin", "domain2", "data2", "read"]),
+        ("p", ["admin", "domain2", "data2", "write"]),
+        ("g", ["alice", "admin", "domain1", ""]),
+        ("g", ["bob", "admin", "domain2", ""]),
+    ];
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        migration::up(&db).await.unwrap();
+
+        let rules = RULES
+            .iter()
+            .map(|(ptype, values)| RuleWithType::from_rule(ptype, Rule::from_slice(values)))
+            .collect();
+        add_policies(&db, rules).await.unwrap();
+        db
+    }
+
+    async fn load(db: &DatabaseConnection, p: Vec<&str>, g: Vec<&str>) -> Vec<(String, String)> {
+        let mut rules: Vec<(String, String)> = load_filtered_policy(db, Filter { p, g })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|rule| (rule.ptype, format!("{},{},{},{}", rule.v0, rule.v1, rule.v2, rule.v3)))
+            .collect();
+        rules.sort();
+        rules
+    }
+
+    fn expected(rules: &[(&str, &str)]) -> Vec<(String, String)> {
+        let mut rules: Vec<(String, String)> = rules
+            .iter()
+            .map(|(ptype, values)| (ptype.to_string(), values.to_string()))
+            .collect();
+        rules.sort();
+        rules
+    }
+
+    #[tokio::test]
+    async fn test_filter_by_domain() {
+        let db = setup_db().await;
+
+        let rules = load(&db, vec!["", "domain1"], vec!["", "", "domain1"]).await;
+
+        assert_eq!(
+            rules,
+            expected(&[
+                ("p", "admin,domain1,data1,read"),
+                ("p", "admin,domain1,data1,write"),
+                ("g", "alice,admin,domain1,"),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filter_wildcard_between_values() {
+        let db = setup_db().await;
+
+        let rules = load(&db, vec!["admin", "", "data2", "read"], vec!["bob"]).await;
+
+        assert_eq!(
+            rules,
+            expected(&[("p", "admin,domain2,data2,read"), ("g", "bob,admin,domain2,")])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_filter_loads_whole_section() {
+        let db = setup_db().await;
+
+        let rules = load(&db, vec![], vec!["", "", "domain2"]).await;
+
+        assert_eq!(rules.len(), 5);
+        assert!(rules.contains(&("g".to_string(), "bob,admin,domain2,".to_string())));
+        assert!(!rules.contains(&("g".to_string(), "alice,admin,domain1,".to_string())));
+    }
+}
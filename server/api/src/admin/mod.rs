@@ -4,14 +4,26 @@
  * 该模块包含所有管理后台相关的API实现，包括：
  * - 访问密钥管理 (SysAccessKeyApi)
  * - 认证管理 (SysAuthenticationApi)
+ * - 合规审计导出 (SysComplianceApi)
  * - 域管理 (SysDomainApi)
+ * - 域功能开关管理 (SysDomainFeatureApi)
+ * - 邮件管理 (SysEmailApi)
  * - 端点管理 (SysEndpointApi)
+ * - 事件管理 (SysEventApi)
+ * - 邀请码管理 (SysInvitationApi)
+ * - 后台任务管理 (SysJobApi)
  * - 登录日志管理 (SysLoginLogApi)
+ * - 维护模式管理 (SysMaintenanceApi)
  * - 菜单管理 (SysMenuApi)
  * - 操作日志管理 (SysOperationLogApi)
  * - 组织管理 (SysOrganizationApi)
+ * - 策略查看 (SysPolicyApi)
  * - 角色管理 (SysRoleApi)
+ * - 角色模板管理 (SysRoleTemplateApi)
  * - 沙箱管理 (SysSandboxApi)
+ * - 统计概览 (SysStatisticsApi)
+ * - 对象存储 (SysStorageApi)
+ * - 系统信息 (SysSystemApi)
  * - 用户管理 (SysUserApi)
  * 
  * 每个API模块都实现了相应的业务逻辑，并通过统一的错误处理和响应格式
@@ -20,24 +32,48 @@
 
 pub mod sys_access_key_api;
 pub mod sys_authentication_api;
+pub mod sys_compliance_api;
 pub mod sys_domain_api;
+pub mod sys_domain_feature_api;
+pub mod sys_email_api;
 pub mod sys_endpoint_api;
+pub mod sys_event_api;
+pub mod sys_invitation_api;
+pub mod sys_job_api;
 pub mod sys_login_log_api;
+pub mod sys_maintenance_api;
 pub mod sys_menu_api;
 pub mod sys_operation_log_api;
 pub mod sys_organization_api;
+pub mod sys_policy_api;
 pub mod sys_role_api;
+pub mod sys_role_template_api;
 pub mod sys_sandbox_api;
+pub mod sys_statistics_api;
+pub mod sys_storage_api;
+pub mod sys_system_api;
 pub mod sys_user_api;
 
 pub use sys_access_key_api::SysAccessKeyApi;
 pub use sys_authentication_api::SysAuthenticationApi;
+pub use sys_compliance_api::SysComplianceApi;
 pub use sys_domain_api::SysDomainApi;
+pub use sys_domain_feature_api::SysDomainFeatureApi;
+pub use sys_email_api::SysEmailApi;
 pub use sys_endpoint_api::SysEndpointApi;
+pub use sys_event_api::SysEventApi;
+pub use sys_invitation_api::SysInvitationApi;
+pub use sys_job_api::SysJobApi;
 pub use sys_login_log_api::SysLoginLogApi;
+pub use sys_maintenance_api::SysMaintenanceApi;
 pub use sys_menu_api::SysMenuApi;
 pub use sys_operation_log_api::SysOperationLogApi;
 pub use sys_organization_api::SysOrganizationApi;
+pub use sys_policy_api::SysPolicyApi;
 pub use sys_role_api::SysRoleApi;
+pub use sys_role_template_api::SysRoleTemplateApi;
 pub use sys_sandbox_api::SysSandboxApi;
+pub use sys_statistics_api::SysStatisticsApi;
+pub use sys_storage_api::SysStorageApi;
+pub use sys_system_api::SysSystemApi;
 pub use sys_user_api::SysUserApi;
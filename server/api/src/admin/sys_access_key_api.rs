@@ -2,9 +2,12 @@
  * 访问密钥管理API
  * 
  * 提供访问密钥的CRUD操作接口，包括：
- * - 分页查询访问密钥列表
- * - 创建新的访问密钥
+ * - 分页查询访问密钥列表（密钥已脱敏）
+ * - 获取访问密钥详情（密钥已脱敏）
+ * - 创建新的访问密钥（仅此处返回明文密钥）
+ * - 更新访问密钥的状态和描述
  * - 删除指定的访问密钥
+ * - 查询未使用的访问密钥
  */
 use std::sync::Arc;
 
@@ -12,10 +15,15 @@ use axum::{
     extract::{Path, Query},
     Extension,
 };
-use server_core::web::{error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm};
+use server_core::web::{
+    error::AppError,
+    page::PaginatedData,
+    res::Res,
+    validator::{ValidatedForm, ValidatedQuery},
+};
 use server_service::admin::{
-    AccessKeyPageRequest, CreateAccessKeyInput, SysAccessKeyModel, SysAccessKeyService,
-    TAccessKeyService,
+    AccessKeyOutput, AccessKeyPageRequest, CreateAccessKeyInput, SysAccessKeyModel,
+    SysAccessKeyService, TAccessKeyService, UnusedAccessKeyRequest, UpdateAccessKeyInput,
 };
 
 pub struct SysAccessKeyApi;
@@ -29,18 +37,35 @@ impl SysAccessKeyApi {
      * - service: 访问密钥服务实例
      * 
      * # 返回
-     * 返回分页后的访问密钥列表数据
+     * 返回分页后的访问密钥列表数据，密钥已脱敏
      */
     pub async fn get_paginated_access_keys(
-        Query(params): Query<AccessKeyPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<AccessKeyPageRequest>,
         Extension(service): Extension<Arc<SysAccessKeyService>>,
-    ) -> Result<Res<PaginatedData<SysAccessKeyModel>>, AppError> {
+    ) -> Result<Res<PaginatedData<AccessKeyOutput>>, AppError> {
         service
             .find_paginated_access_keys(params)
             .await
             .map(Res::new_data)
     }
 
+    /**
+     * 获取访问密钥详情
+     * 
+     * # 参数
+     * - id: 访问密钥记录ID
+     * - service: 访问密钥服务实例
+     * 
+     * # 返回
+     * 返回访问密钥详情，密钥已脱敏
+     */
+    pub async fn get_access_key(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAccessKeyService>>,
+    ) -> Result<Res<AccessKeyOutput>, AppError> {
+        service.get_access_key(&id).await.map(Res::new_data)
+    }
+
     /**
      * 创建新的访问密钥
      * 
@@ -49,7 +74,7 @@ impl SysAccessKeyApi {
      * - input: 创建访问密钥的输入参数
      * 
      * # 返回
-     * 返回新创建的访问密钥信息
+     * 返回新创建的访问密钥信息，包含明文密钥
      */
     pub async fn create_access_key(
         Extension(service): Extension<Arc<SysAccessKeyService>>,
@@ -58,6 +83,23 @@ impl SysAccessKeyApi {
         service.create_access_key(input).await.map(Res::new_data)
     }
 
+    /**
+     * 更新访问密钥
+     * 
+     * # 参数
+     * - service: 访问密钥服务实例
+     * - input: 更新访问密钥的输入参数
+     * 
+     * # 返回
+     * 返回更新后的访问密钥信息，密钥已脱敏
+     */
+    pub async fn update_access_key(
+        Extension(service): Extension<Arc<SysAccessKeyService>>,
+        ValidatedForm(input): ValidatedForm<UpdateAccessKeyInput>,
+    ) -> Result<Res<AccessKeyOutput>, AppError> {
+        service.update_access_key(input).await.map(Res::new_data)
+    }
+
     /**
      * 删除指定的访问密钥
      * 
@@ -74,4 +116,24 @@ impl SysAccessKeyApi {
     ) -> Result<Res<()>, AppError> {
         service.delete_access_key(&id).await.map(Res::new_data)
     }
+
+    /**
+     * 查询未使用的访问密钥
+     * 
+     * # 参数
+     * - params: 查询参数，days 为时间窗口天数，默认90天
+     * - service: 访问密钥服务实例
+     * 
+     * # 返回
+     * 返回时间窗口内未被使用的访问密钥列表
+     */
+    pub async fn get_unused_access_keys(
+        Query(params): Query<UnusedAccessKeyRequest>,
+        Extension(service): Extension<Arc<SysAccessKeyService>>,
+    ) -> Result<Res<Vec<AccessKeyOutput>>, AppError> {
+        service
+            .find_unused_access_keys(params)
+            .await
+            .map(Res::new_data)
+    }
 }
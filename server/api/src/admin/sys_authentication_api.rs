@@ -3,23 +3,38 @@
  * 
  * 提供用户认证和授权相关的接口，包括：
  * - 用户登录
+ * - 邀请码自助注册
  * - 获取用户信息
  * - 获取用户路由
+ * - 获取本人登录历史
+ * - 查看、撤销本人的登录令牌
+ * - 查看本人可操作的域、切换当前域
  * - 角色权限分配
  * - 角色路由分配
+ * - 角色用户分配
+ * - 按角色预览路由
  */
 use std::{net::SocketAddr, sync::Arc};
 
-use axum::{extract::ConnectInfo, http::HeaderMap, Extension};
+use axum::{
+    extract::{ConnectInfo, Path, Query},
+    http::HeaderMap,
+    Extension,
+};
 use axum_casbin::CasbinAxumLayer;
 use axum_extra::{headers::UserAgent, TypedHeader};
 use server_core::web::{
-    auth::User, error::AppError, res::Res, util::ClientIp, validator::ValidatedForm, RequestId,
+    auth::User, error::AppError, page::PaginatedData, res::Res, util::ClientIp,
+    validator::{ValidatedForm, ValidatedQuery}, with_dependency_timeout, RequestId,
 };
 use server_service::{
     admin::{
-        dto::sys_auth_dto::LoginContext, AssignPermissionDto, AssignRouteDto, AuthOutput,
-        LoginInput, SysAuthService, SysAuthorizationService, TAuthService, TAuthorizationService,
+        dto::sys_auth_dto::LoginContext, AssignPermissionDto, AssignRouteDto, AssignUserDto,
+        AssignmentResult, AuthOutput,
+        DomainOptionOutput, LoginHistoryOutput, LoginHistoryRequest, LoginInput,
+        PreviewRoutesRequest, RegisterInput, RoutePreviewOutput, SysAuthService, SysAuthorizationService, SysDomainService, SysLoginLogService,
+        SwitchDomainInput, SysTokenService, TAuthService, TAuthorizationService, TDomainService,
+        TLoginLogService, TTokenService, TokenOutput, TokenPageRequest, UserDomainOutput,
         UserInfoOutput, UserRoute,
     },
     Audience,
@@ -42,10 +57,7 @@ impl SysAuthenticationApi {
         let pools = GLOBAL_DB_POOL.read().await;
         match pools.get("default") {
             Some(pool) => Ok(pool.clone()),
-            None => Err(AppError {
-                code: 500,
-                message: "Database connection not found".to_string(),
-            }),
+            None => Err(AppError::new(500, "Database connection not found")),
         }
     }
 
@@ -71,19 +83,88 @@ impl SysAuthenticationApi {
         Extension(service): Extension<Arc<SysAuthService>>,
         ValidatedForm(input): ValidatedForm<LoginInput>,
     ) -> Result<Res<AuthOutput>, AppError> {
-        let client_ip = {
-            let header_ip = ClientIp::get_real_ip(&headers);
-            if header_ip == "unknown" {
-                addr.ip().to_string()
-            } else {
-                header_ip
-            }
-        };
+        let login_context = Self::login_context(addr, &headers, &user_agent, &request_id).await;
+
+        Ok(service
+            .pwd_login(input, login_context)
+            .await
+            .map(Res::new_data)?)
+    }
+
+    /**
+     * 处理邀请码自助注册请求
+     * 
+     * # 参数
+     * - addr: 客户端地址信息
+     * - headers: HTTP请求头
+     * - user_agent: 用户代理信息
+     * - request_id: 请求ID
+     * - service: 认证服务实例
+     * - cache_enforcer: Casbin执行器，用于写入新用户的分组规则
+     * - input: 注册输入参数，包含用户名、密码、昵称和邀请码
+     * 
+     * # 返回
+     * 注册成功后直接登录，返回与登录接口相同的认证结果
+     */
+    pub async fn register_handler(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(service): Extension<Arc<SysAuthService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        ValidatedForm(input): ValidatedForm<RegisterInput>,
+    ) -> Result<Res<AuthOutput>, AppError> {
+        let login_context = Self::login_context(addr, &headers, &user_agent, &request_id).await;
+
+        let db = Self::get_db_connection().await?;
+        let enforcer = cache_enforcer.get_enforcer();
+        Ok(service
+            .register(db, input, login_context, enforcer)
+            .await
+            .map(Res::new_data)?)
+    }
+
+    /**
+     * 获取登录页可选域列表
+     * 
+     * 公开接口，只返回启用的域的代码和名称
+     * 
+     * # 参数
+     * - service: 域服务实例
+     * 
+     * # 返回
+     * 返回可选域列表
+     */
+    pub async fn list_domains(
+        Extension(service): Extension<Arc<SysDomainService>>,
+    ) -> Result<Res<Vec<DomainOptionOutput>>, AppError> {
+        service.list_enabled_domains().await.map(Res::new_data)
+    }
+
+    /**
+     * 构建登录上下文
+     * 
+     * 按可信代理配置解析真实客户端IP，并解析IP归属地；
+     * 归属地查询在阻塞线程池中执行，失败或超过依赖超时时间时记为未知
+     */
+    async fn login_context(
+        addr: SocketAddr,
+        headers: &HeaderMap,
+        user_agent: &UserAgent,
+        request_id: &RequestId,
+    ) -> LoginContext {
+        let client_ip = ClientIp::get_real_ip(Some(addr.ip()), headers);
 
-        let address = xdb::searcher::search_by_ip(client_ip.as_str())
-            .unwrap_or_else(|_| "Unknown Location".to_string());
+        let ip = client_ip.clone();
+        let lookup =
+            tokio::task::spawn_blocking(move || xdb::searcher::search_by_ip(ip.as_str()).ok());
+        let address = match with_dependency_timeout("xdb", lookup).await {
+            Ok(Ok(Some(address))) => address,
+            _ => "Unknown Location".to_string(),
+        };
 
-        let login_context = LoginContext {
+        LoginContext {
             client_ip,
             client_port: Some(addr.port() as i32),
             address,
@@ -92,36 +173,156 @@ impl SysAuthenticationApi {
             audience: Audience::ManagementPlatform,
             login_type: "PC".to_string(),
             domain: "built-in".to_string(),
-        };
-
-        let db = Self::get_db_connection().await?;
-        Ok(service
-            .pwd_login(db, input, login_context)
-            .await
-            .map(Res::new_data)?)
+        }
     }
 
     /**
      * 获取当前用户信息
      * 
      * # 参数
+     * - service: 认证服务实例
      * - user: 当前认证用户信息
      * 
      * # 返回
-     * 返回用户详细信息
+     * 返回用户详细信息及按钮权限标识，界面无需再单独请求权限
      */
     pub async fn get_user_info(
+        Extension(service): Extension<Arc<SysAuthService>>,
         Extension(user): Extension<User>,
     ) -> Result<Res<UserInfoOutput>, AppError> {
+        let db = Self::get_db_connection().await?;
+        let roles = user.subject();
+        let permissions = service.get_user_permissions(db, &roles, &user.domain()).await?;
         let user_info = UserInfoOutput {
             user_id: user.user_id(),
             user_name: user.username(),
-            roles: user.subject(),
+            roles,
+            permissions,
         };
 
         Ok(Res::new_data(user_info))
     }
 
+    /**
+     * 获取当前用户本人的登录历史
+     * 
+     * # 参数
+     * - params: 查询参数，limit 默认20，最多100
+     * - service: 登录日志服务实例
+     * - user: 当前认证用户信息，只查询该用户的记录
+     * 
+     * # 返回
+     * 返回最近的登录记录，异地登录标记为可疑
+     */
+    pub async fn get_login_history(
+        Query(params): Query<LoginHistoryRequest>,
+        Extension(service): Extension<Arc<SysLoginLogService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<LoginHistoryOutput>>, AppError> {
+        service
+            .find_login_history(&user.user_id(), params)
+            .await
+            .map(Res::new_data)
+    }
+
+    /**
+     * 分页查询当前用户本人的登录令牌
+     * 
+     * # 参数
+     * - params: 分页和关键字参数
+     * - service: 登录令牌服务实例
+     * - user: 当前认证用户信息，只查询该用户的令牌
+     * 
+     * # 返回
+     * 返回未撤销且未过期的令牌，本次请求使用的令牌标记为 current
+     */
+    pub async fn list_tokens(
+        ValidatedQuery(params): ValidatedQuery<TokenPageRequest>,
+        Extension(service): Extension<Arc<SysTokenService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<PaginatedData<TokenOutput>>, AppError> {
+        let result =
+            service.find_user_tokens(&user.user_id(), user.jti().as_deref(), params).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 撤销当前用户本人的登录令牌
+     * 
+     * # 参数
+     * - id: 令牌记录ID
+     * - service: 登录令牌服务实例
+     * - user: 当前认证用户信息，只能撤销该用户的令牌
+     * 
+     * # 返回
+     * 撤销成功返回空数据；令牌不存在或属于其他用户时返回404，
+     * 使用个人访问令牌调用时返回403
+     */
+    pub async fn revoke_token(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTokenService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.revoke_user_token(&user, &id).await?;
+        Ok(Res::new_data(()))
+    }
+
+    /**
+     * 获取当前用户本人可操作的域
+     * 
+     * # 参数
+     * - service: 认证服务实例
+     * - user: 当前认证用户信息
+     * 
+     * # 返回
+     * 返回所属的域和已加入的域，当前令牌所在的域标记为 current
+     */
+    pub async fn my_domains(
+        Extension(service): Extension<Arc<SysAuthService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<UserDomainOutput>>, AppError> {
+        Ok(service
+            .get_user_domains(&user.user_id(), &user.domain())
+            .await
+            .map(Res::new_data)?)
+    }
+
+    /**
+     * 切换当前用户操作的域
+     * 
+     * # 参数
+     * - addr: 客户端地址信息
+     * - headers: HTTP请求头
+     * - user_agent: 用户代理信息
+     * - request_id: 请求ID
+     * - service: 认证服务实例
+     * - user: 当前认证用户信息
+     * - input: 目标域代码
+     * 
+     * # 返回
+     * 返回目标域的新令牌对，与登录接口的认证结果相同；原令牌不会被撤销。
+     * 使用个人访问令牌调用时返回403
+     */
+    pub async fn switch_domain(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(service): Extension<Arc<SysAuthService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<SwitchDomainInput>,
+    ) -> Result<Res<AuthOutput>, AppError> {
+        let mut login_context =
+            Self::login_context(addr, &headers, &user_agent, &request_id).await;
+        login_context.login_type = "DomainSwitch".to_string();
+        login_context.domain = input.code.clone();
+
+        Ok(service
+            .switch_domain(&user, &input.code, login_context)
+            .await
+            .map(Res::new_data)?)
+    }
+
     /**
      * 获取用户可访问的路由列表
      * 
@@ -149,19 +350,28 @@ impl SysAuthenticationApi {
      * # 参数
      * - service: 授权服务实例
      * - cache_enforcer: Casbin执行器
+     * - user: 当前用户，非内置域超级管理员只能分配自己持有的接口
      * - input: 权限分配参数
      * 
      * # 返回
-     * 返回权限分配操作的结果
+     * 返回授予角色的接口数量及各选择器匹配的接口数量
      */
     pub async fn assign_permissions(
         Extension(service): Extension<Arc<SysAuthorizationService>>,
         Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
         ValidatedForm(input): ValidatedForm<AssignPermissionDto>,
-    ) -> Result<Res<()>, AppError> {
+    ) -> Result<Res<AssignmentResult>, AppError> {
         let enforcer = cache_enforcer.get_enforcer();
         Ok(service
-            .assign_permissions(input.domain, input.role_id, input.permissions, enforcer)
+            .assign_permissions(
+                input.domain,
+                input.role_id,
+                input.permissions,
+                input.selectors,
+                &user,
+                enforcer,
+            )
             .await
             .map(Res::new_data)?)
     }
@@ -171,6 +381,8 @@ impl SysAuthenticationApi {
      * 
      * # 参数
      * - service: 授权服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前用户，非内置域超级管理员只能分配自己持有的路由
      * - input: 路由分配参数
      * 
      * # 返回
@@ -178,10 +390,60 @@ impl SysAuthenticationApi {
      */
     pub async fn assign_routes(
         Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
         ValidatedForm(input): ValidatedForm<AssignRouteDto>,
     ) -> Result<Res<()>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        Ok(service
+            .assign_routes(input.domain, input.role_id, input.route_ids, &user, enforcer)
+            .await
+            .map(Res::new_data)?)
+    }
+
+    /**
+     * 为角色分配用户
+     * 
+     * # 参数
+     * - service: 授权服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，只能分配自己持有全部权限的角色
+     * - input: 用户分配参数
+     * 
+     * # 返回
+     * 返回用户分配操作的结果
+     */
+    pub async fn assign_users(
+        Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<AssignUserDto>,
+    ) -> Result<Res<()>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        Ok(service
+            .assign_users(input.role_id, input.user_ids, &user, enforcer)
+            .await
+            .map(Res::new_data)?)
+    }
+
+    /**
+     * 预览角色可见的路由
+     * 
+     * # 参数
+     * - service: 授权服务实例
+     * - user: 当前用户，非内置域用户只能预览本域的角色
+     * - params: 角色ID列表和域代码
+     * 
+     * # 返回
+     * 返回拥有这些角色的用户登录后看到的路由，以及已分配但未启用的菜单ID
+     */
+    pub async fn preview_routes(
+        Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(user): Extension<User>,
+        ValidatedQuery(params): ValidatedQuery<PreviewRoutesRequest>,
+    ) -> Result<Res<RoutePreviewOutput>, AppError> {
         Ok(service
-            .assign_routes(input.domain, input.role_id, input.route_ids)
+            .preview_routes(params.role_ids, params.domain, &user.domain())
             .await
             .map(Res::new_data)?)
     }
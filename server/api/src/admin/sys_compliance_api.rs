@@ -0,0 +1,58 @@
+/**
+ * 合规审计API
+ * 
+ * 提供合规审计数据导出接口，包括：
+ * - 创建用户审计数据导出任务
+ * - 查询导出任务状态和下载链接
+ */
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    ComplianceExportInput, ComplianceExportOutput, SysComplianceService, TComplianceService,
+};
+
+pub struct SysComplianceApi;
+
+impl SysComplianceApi {
+    /**
+     * 创建导出任务
+     * 
+     * # 参数
+     * - service: 合规审计导出服务实例
+     * - user: 当前用户，只有内置域用户可以导出
+     * - input: 导出的用户ID和时间范围
+     * 
+     * # 返回
+     * 返回等待执行的任务，通过查询接口获取执行结果
+     */
+    pub async fn create_export(
+        Extension(service): Extension<Arc<SysComplianceService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ComplianceExportInput>,
+    ) -> Result<Res<ComplianceExportOutput>, AppError> {
+        let result = service.create_export(input, &user.domain(), &user.username()).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 查询导出任务
+     * 
+     * # 参数
+     * - job_id: 任务ID
+     * - service: 合规审计导出服务实例
+     * - user: 当前用户，只有内置域用户可以查询
+     * 
+     * # 返回
+     * 返回任务状态，已完成的任务包含1小时内有效的下载链接
+     */
+    pub async fn get_export(
+        Path(job_id): Path<String>,
+        Extension(service): Extension<Arc<SysComplianceService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<ComplianceExportOutput>, AppError> {
+        let result = service.get_export(&job_id, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+}
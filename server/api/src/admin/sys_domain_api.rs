@@ -7,17 +7,24 @@
  * - 获取指定域信息
  * - 更新域信息
  * - 删除指定的域
+ * - 按模板开通域
  */
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Query},
+    extract::Path,
     Extension,
 };
-use server_core::web::{error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm};
+use axum_casbin::CasbinAxumLayer;
+use server_core::web::{
+    error::AppError,
+    page::PaginatedData,
+    res::Res,
+    validator::{ValidatedForm, ValidatedQuery},
+};
 use server_service::admin::{
-    CreateDomainInput, DomainPageRequest, SysDomainModel, SysDomainService, TDomainService,
-    UpdateDomainInput,
+    CreateDomainInput, DomainPageRequest, DomainProvisionOutput, ProvisionDomainInput,
+    SysDomainModel, SysDomainService, TDomainService, UpdateDomainInput, WithOperatorNames,
 };
 
 pub struct SysDomainApi;
@@ -31,12 +38,12 @@ impl SysDomainApi {
      * - service: 域服务实例
      * 
      * # 返回
-     * 返回分页后的域列表数据
+     * 返回分页后的域列表数据，包含创建人和更新人用户名
      */
     pub async fn get_paginated_domains(
-        Query(params): Query<DomainPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<DomainPageRequest>,
         Extension(service): Extension<Arc<SysDomainService>>,
-    ) -> Result<Res<PaginatedData<SysDomainModel>>, AppError> {
+    ) -> Result<Res<PaginatedData<WithOperatorNames<SysDomainModel>>>, AppError> {
         service
             .find_paginated_domains(params)
             .await
@@ -110,4 +117,26 @@ impl SysDomainApi {
     ) -> Result<Res<()>, AppError> {
         service.delete_domain(&id).await.map(Res::new_data)
     }
+
+    /**
+     * 按模板开通域
+     * 
+     * 一次性创建域、模板角色、角色菜单、管理员用户和权限策略，任一步骤失败时全部回滚
+     * 
+     * # 参数
+     * - service: 域服务实例
+     * - cache_enforcer: Casbin执行器
+     * - input: 域开通参数
+     * 
+     * # 返回
+     * 返回创建的域、角色、管理员用户和写入的策略数量
+     */
+    pub async fn provision_domain(
+        Extension(service): Extension<Arc<SysDomainService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        ValidatedForm(input): ValidatedForm<ProvisionDomainInput>,
+    ) -> Result<Res<DomainProvisionOutput>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        service.provision_domain(input, enforcer).await.map(Res::new_data)
+    }
 }
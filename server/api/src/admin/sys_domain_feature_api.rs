@@ -0,0 +1,84 @@
+/**
+ * 域功能开关管理API
+ * 
+ * 提供按域管理功能开关的接口，包括：
+ * - 查询域下的功能开关
+ * - 设置功能开关
+ * - 删除功能开关
+ */
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    SysDomainFeatureModel, SysDomainFeatureService, TDomainFeatureService,
+    UpsertDomainFeatureInput,
+};
+
+pub struct SysDomainFeatureApi;
+
+impl SysDomainFeatureApi {
+    /**
+     * 查询域下的功能开关
+     * 
+     * # 参数
+     * - code: 域代码
+     * - service: 域功能开关服务实例
+     * 
+     * # 返回
+     * 返回域下已设置的功能开关列表
+     */
+    pub async fn get_domain_features(
+        Path(code): Path<String>,
+        Extension(service): Extension<Arc<SysDomainFeatureService>>,
+    ) -> Result<Res<Vec<SysDomainFeatureModel>>, AppError> {
+        service.find_domain_features(&code).await.map(Res::new_data)
+    }
+
+    /**
+     * 设置功能开关
+     * 
+     * # 参数
+     * - code: 域代码
+     * - service: 域功能开关服务实例
+     * - user: 当前认证用户信息，记录为更新人
+     * - input: 功能开关参数
+     * 
+     * # 返回
+     * 返回设置后的功能开关
+     */
+    pub async fn upsert_domain_feature(
+        Path(code): Path<String>,
+        Extension(service): Extension<Arc<SysDomainFeatureService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpsertDomainFeatureInput>,
+    ) -> Result<Res<SysDomainFeatureModel>, AppError> {
+        service
+            .upsert_domain_feature(&code, input, &user.username())
+            .await
+            .map(Res::new_data)
+    }
+
+    /**
+     * 删除功能开关
+     * 
+     * 删除后该功能在域内恢复为默认启用
+     * 
+     * # 参数
+     * - code: 域代码
+     * - key: 功能标识
+     * - service: 域功能开关服务实例
+     * 
+     * # 返回
+     * 返回删除操作的结果
+     */
+    pub async fn delete_domain_feature(
+        Path((code, key)): Path<(String, String)>,
+        Extension(service): Extension<Arc<SysDomainFeatureService>>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .delete_domain_feature(&code, &key)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,30 @@
+/**
+ * 邮件管理API
+ * 
+ * 提供邮件通知相关的管理接口，包括：
+ * - 发送测试邮件以确认SMTP配置
+ */
+use server_core::web::{error::AppError, res::Res, validator::ValidatedForm};
+use server_service::{admin::SendTestEmailInput, helper::notification_service::NotificationService};
+
+pub struct SysEmailApi;
+
+impl SysEmailApi {
+    /**
+     * 发送测试邮件
+     * 
+     * 邮件入队后即返回，实际发送结果记录在服务日志中
+     * 
+     * # 参数
+     * - input: 测试邮件参数，包含收件人地址
+     * 
+     * # 返回
+     * 返回入队结果，未配置邮件时返回503
+     */
+    pub async fn send_test_email(
+        ValidatedForm(input): ValidatedForm<SendTestEmailInput>,
+    ) -> Result<Res<()>, AppError> {
+        NotificationService::send_test_mail(&input.to).await?;
+        Ok(Res::new_data(()))
+    }
+}
@@ -5,6 +5,7 @@
  * - 分页查询端点列表
  * - 获取角色的授权端点
  * - 获取端点树形结构
+ * - 预览和执行端点同步
  */
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -13,9 +14,15 @@ use axum::{
     Extension,
 };
 use axum_casbin::{casbin::MgmtApi, CasbinAxumLayer};
-use server_core::web::{auth::User, error::AppError, page::PaginatedData, res::Res};
+use serde_json::Value;
+use server_core::web::{
+    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedQuery,
+    FieldsQuery,
+};
+use server_global::global::get_collected_routes;
 use server_service::admin::{
-    EndpointPageRequest, EndpointTree, SysEndpointModel, SysEndpointService, TEndpointService,
+    endpoints_from_routes, EndpointPageRequest, EndpointTree, EndpointTreeRequest,
+    SysEndpointModel, SysEndpointService, SyncReport, TEndpointService,
 };
 
 pub struct SysEndpointApi;
@@ -32,7 +39,7 @@ impl SysEndpointApi {
      * 返回分页后的端点列表数据
      */
     pub async fn get_paginated_endpoints(
-        Query(params): Query<EndpointPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<EndpointPageRequest>,
         Extension(service): Extension<Arc<SysEndpointService>>,
     ) -> Result<Res<PaginatedData<SysEndpointModel>>, AppError> {
         service
@@ -82,14 +89,54 @@ impl SysEndpointApi {
      * 获取端点树形结构
      * 
      * # 参数
+     * - params: 可选的角色ID和域代码，同时指定时标记该角色已授权的端点；分组方式 controller 或 resource
+     * - query: 可选的字段选择，只返回指定的字段
      * - service: 端点服务实例
+     * - cache_enforcer: Casbin执行器
      * 
      * # 返回
      * 返回端点的树形结构数据
      */
     pub async fn tree_endpoint(
+        Query(params): Query<EndpointTreeRequest>,
+        ValidatedQuery(query): ValidatedQuery<FieldsQuery<EndpointTree>>,
+        Extension(service): Extension<Arc<SysEndpointService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+    ) -> Result<Res<Value>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        let tree = service.tree_endpoint(params, enforcer).await?;
+        query.fields.project(&tree).map(Res::new_data)
+    }
+
+    /**
+     * 预览端点同步
+     * 
+     * # 参数
+     * - service: 端点服务实例
+     * 
+     * # 返回
+     * 返回当前收集到的路由与端点表的差异，不修改数据
+     */
+    pub async fn sync_preview(
+        Extension(service): Extension<Arc<SysEndpointService>>,
+    ) -> Result<Res<SyncReport>, AppError> {
+        let endpoints = endpoints_from_routes(get_collected_routes().await);
+        service.sync_endpoints_dry_run(endpoints).await.map(Res::new_data)
+    }
+
+    /**
+     * 执行端点同步
+     * 
+     * # 参数
+     * - service: 端点服务实例
+     * 
+     * # 返回
+     * 返回已执行的变更
+     */
+    pub async fn sync_apply(
         Extension(service): Extension<Arc<SysEndpointService>>,
-    ) -> Result<Res<Vec<EndpointTree>>, AppError> {
-        service.tree_endpoint().await.map(Res::new_data)
+    ) -> Result<Res<SyncReport>, AppError> {
+        let endpoints = endpoints_from_routes(get_collected_routes().await);
+        service.sync_endpoints(endpoints).await.map(Res::new_data)
     }
 }
@@ -0,0 +1,30 @@
+/**
+ * 事件管理API
+ * 
+ * 提供事件处理相关的管理接口，包括：
+ * - 查询重试后仍处理失败的死信事件
+ */
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{DeadLetterEvent, SysDeadLetterService, TDeadLetterService};
+
+pub struct SysEventApi;
+
+impl SysEventApi {
+    /**
+     * 查询死信事件
+     * 
+     * # 参数
+     * - service: 死信事件服务实例
+     * 
+     * # 返回
+     * 返回死信缓冲区中的事件，最近失败的在前
+     */
+    pub async fn list_dead_letters(
+        Extension(service): Extension<Arc<SysDeadLetterService>>,
+    ) -> Result<Res<Vec<DeadLetterEvent>>, AppError> {
+        Ok(Res::new_data(service.list_dead_letters().await))
+    }
+}
@@ -0,0 +1,89 @@
+/**
+ * 邀请码管理API
+ * 
+ * 提供域邀请码管理的接口，包括：
+ * - 分页查询邀请码列表
+ * - 创建新邀请码
+ * - 撤销指定邀请码
+ */
+use std::sync::Arc;
+
+use axum::extract::{Extension, Path};
+use server_core::web::{
+    auth::User,
+    error::AppError,
+    page::PaginatedData,
+    res::Res,
+    validator::{ValidatedForm, ValidatedQuery},
+};
+use server_service::admin::{
+    CreateInvitationInput, InvitationPageRequest, SysInvitationModel, SysInvitationService,
+    TInvitationService,
+};
+
+pub struct SysInvitationApi;
+
+impl SysInvitationApi {
+    /**
+     * 分页查询邀请码列表
+     * 
+     * # 参数
+     * - params: 分页查询参数，可按域过滤
+     * - service: 邀请码服务实例
+     * - user: 当前认证用户信息，非内置域用户只能查询本域的邀请码
+     * 
+     * # 返回
+     * 返回分页后的邀请码列表数据
+     */
+    pub async fn get_paginated_invitations(
+        ValidatedQuery(params): ValidatedQuery<InvitationPageRequest>,
+        Extension(service): Extension<Arc<SysInvitationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<PaginatedData<SysInvitationModel>>, AppError> {
+        service
+            .find_paginated_invitations(params, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    /**
+     * 创建新邀请码
+     * 
+     * # 参数
+     * - service: 邀请码服务实例
+     * - user: 当前认证用户信息，用户ID记录为创建人，非内置域用户只能创建本域的邀请码
+     * - input: 创建邀请码的输入参数
+     * 
+     * # 返回
+     * 返回新创建的邀请码信息，包含服务端生成的邀请码
+     */
+    pub async fn create_invitation(
+        Extension(service): Extension<Arc<SysInvitationService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateInvitationInput>,
+    ) -> Result<Res<SysInvitationModel>, AppError> {
+        service
+            .create_invitation(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    /**
+     * 撤销指定邀请码
+     * 
+     * # 参数
+     * - id: 要撤销的邀请码ID
+     * - service: 邀请码服务实例
+     * - user: 当前认证用户信息，非内置域用户只能撤销本域的邀请码
+     * 
+     * # 返回
+     * 返回撤销操作的结果
+     */
+    pub async fn revoke_invitation(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysInvitationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.revoke_invitation(&id, &user).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,51 @@
+/**
+ * 后台任务管理API
+ * 
+ * 提供后台周期任务的管理接口，包括：
+ * - 查询任务执行状态
+ * - 立即执行任务
+ */
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::{
+    scheduler::JobStatus,
+    web::{error::AppError, res::Res},
+};
+use server_service::admin::{SysJobService, TJobService};
+
+pub struct SysJobApi;
+
+impl SysJobApi {
+    /**
+     * 查询后台任务
+     * 
+     * # 参数
+     * - service: 后台任务服务实例
+     * 
+     * # 返回
+     * 返回所有任务的最近执行状态
+     */
+    pub async fn list_jobs(
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<Vec<JobStatus>>, AppError> {
+        Ok(Res::new_data(service.list_jobs().await))
+    }
+
+    /**
+     * 立即执行后台任务
+     * 
+     * # 参数
+     * - name: 任务名称
+     * - service: 后台任务服务实例
+     * 
+     * # 返回
+     * 返回触发后的任务状态，任务在后台执行
+     */
+    pub async fn run_job(
+        Path(name): Path<String>,
+        Extension(service): Extension<Arc<SysJobService>>,
+    ) -> Result<Res<JobStatus>, AppError> {
+        service.run_job(&name).await.map(Res::new_data)
+    }
+}
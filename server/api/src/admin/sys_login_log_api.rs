@@ -6,10 +6,12 @@
  */
 use std::sync::Arc;
 
-use axum::extract::{Extension, Query};
-use server_core::web::{error::AppError, page::PaginatedData, res::Res};
+use axum::extract::Extension;
+use server_core::web::{
+    error::AppError, page::PaginatedData, res::Res, validator::ValidatedQuery,
+};
 use server_service::admin::{
-    LoginLogPageRequest, SysLoginLogModel, SysLoginLogService, TLoginLogService,
+    LoginLogOutput, LoginLogPageRequest, SysLoginLogService, TLoginLogService,
 };
 
 pub struct SysLoginLogApi;
@@ -26,9 +28,9 @@ impl SysLoginLogApi {
      * 返回分页后的登录日志列表数据
      */
     pub async fn get_paginated_login_logs(
-        Query(params): Query<LoginLogPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<LoginLogPageRequest>,
         Extension(service): Extension<Arc<SysLoginLogService>>,
-    ) -> Result<Res<PaginatedData<SysLoginLogModel>>, AppError> {
+    ) -> Result<Res<PaginatedData<LoginLogOutput>>, AppError> {
         service
             .find_paginated_login_logs(params)
             .await
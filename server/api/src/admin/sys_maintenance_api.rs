@@ -0,0 +1,55 @@
+/**
+ * 维护模式管理API
+ * 
+ * 提供全局维护模式的接口，包括：
+ * - 查询维护模式状态
+ * - 开启或关闭维护模式
+ */
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    MaintenanceState, SysMaintenanceService, TMaintenanceService, UpdateMaintenanceInput,
+};
+
+pub struct SysMaintenanceApi;
+
+impl SysMaintenanceApi {
+    /**
+     * 查询维护模式状态
+     * 
+     * # 参数
+     * - service: 维护模式服务实例
+     * 
+     * # 返回
+     * 返回当前维护模式状态
+     */
+    pub async fn get_maintenance(
+        Extension(service): Extension<Arc<SysMaintenanceService>>,
+    ) -> Result<Res<MaintenanceState>, AppError> {
+        Ok(Res::new_data(service.get_maintenance_state().await))
+    }
+
+    /**
+     * 设置维护模式
+     * 
+     * # 参数
+     * - service: 维护模式服务实例
+     * - user: 当前认证用户信息，记录为更新人
+     * - input: 维护模式参数
+     * 
+     * # 返回
+     * 返回设置后的维护模式状态
+     */
+    pub async fn update_maintenance(
+        Extension(service): Extension<Arc<SysMaintenanceService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateMaintenanceInput>,
+    ) -> Result<Res<MaintenanceState>, AppError> {
+        service
+            .update_maintenance_state(input, &user.username())
+            .await
+            .map(Res::new_data)
+    }
+}
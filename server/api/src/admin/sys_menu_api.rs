@@ -8,20 +8,27 @@
  * - 创建菜单
  * - 获取菜单详情
  * - 更新菜单
- * - 删除菜单
+ * - 删除菜单（可选级联删除子孙菜单及角色关联）
  * - 获取角色关联的菜单ID列表
  */
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Extension},
+    extract::{Path, Extension, Query},
     Json,
 };
-use server_core::web::{error::AppError, res::Res};
+use axum_casbin::CasbinAxumLayer;
+use serde_json::Value;
+use server_core::web::{
+    error::AppError,
+    res::Res,
+    validator::{ValidatedForm, ValidatedQuery},
+    FieldsQuery,
+};
 use server_model::admin::{
     entities::sys_menu::Model as SysMenuModel,
-    input::{CreateMenuInput, UpdateMenuInput},
-    output::{MenuRoute, MenuTree},
+    input::{ConstantRoutesQuery, CreateMenuInput, MenuDeleteRequest, UpdateMenuInput},
+    output::{MenuDeleteOutput, MenuRoute, MenuTree},
 };
 use server_service::admin::{
     SysMenuService,
@@ -35,47 +42,53 @@ impl SysMenuApi {
      * 获取菜单树形结构
      * 
      * # 参数
+     * - query: 可选的字段选择，只返回指定的字段
      * - service: 菜单服务实例
      * 
      * # 返回
      * 返回菜单的树形结构数据
      */
     pub async fn tree_menu(
+        ValidatedQuery(query): ValidatedQuery<FieldsQuery<MenuTree>>,
         Extension(service): Extension<Arc<SysMenuService>>,
-    ) -> Result<Json<Res<Vec<MenuTree>>>, AppError> {
+    ) -> Result<Json<Res<Value>>, AppError> {
         let result = service.tree_menu().await?;
-        Ok(Json(Res::new_data(result)))
+        Ok(Json(Res::new_data(query.fields.project(&result)?)))
     }
 
     /**
      * 获取菜单列表
      * 
      * # 参数
+     * - query: 可选的字段选择，只返回指定的字段
      * - service: 菜单服务实例
      * 
      * # 返回
      * 返回菜单列表数据
      */
     pub async fn get_menu_list(
+        ValidatedQuery(query): ValidatedQuery<FieldsQuery<MenuTree>>,
         Extension(service): Extension<Arc<SysMenuService>>,
-    ) -> Result<Json<Res<Vec<MenuTree>>>, AppError> {
+    ) -> Result<Json<Res<Value>>, AppError> {
         let result = service.get_menu_list().await?;
-        Ok(Json(Res::new_data(result)))
+        Ok(Json(Res::new_data(query.fields.project(&result)?)))
     }
 
     /**
      * 获取常量路由
      * 
      * # 参数
+     * - query: 可选的域代码，指定时同时返回该域的常量路由
      * - service: 菜单服务实例
      * 
      * # 返回
      * 返回常量路由列表
      */
     pub async fn get_constant_routes(
+        ValidatedQuery(query): ValidatedQuery<ConstantRoutesQuery>,
         Extension(service): Extension<Arc<SysMenuService>>,
     ) -> Result<Json<Res<Vec<MenuRoute>>>, AppError> {
-        let result = service.get_constant_routes().await?;
+        let result = service.get_constant_routes(query.domain.as_deref()).await?;
         Ok(Json(Res::new_data(result)))
     }
 
@@ -91,7 +104,7 @@ impl SysMenuApi {
      */
     pub async fn create_menu(
         Extension(service): Extension<Arc<SysMenuService>>,
-        Json(input): Json<CreateMenuInput>,
+        ValidatedForm(input): ValidatedForm<CreateMenuInput>,
     ) -> Result<Json<Res<SysMenuModel>>, AppError> {
         let result = service.create_menu(input).await?;
         Ok(Json(Res::new_data(result)))
@@ -129,7 +142,7 @@ impl SysMenuApi {
     pub async fn update_menu(
         Extension(service): Extension<Arc<SysMenuService>>,
         Path(id): Path<i32>,
-        Json(input): Json<UpdateMenuInput>,
+        ValidatedForm(input): ValidatedForm<UpdateMenuInput>,
     ) -> Result<Json<Res<SysMenuModel>>, AppError> {
         let result = service.update_menu(id, input).await?;
         Ok(Json(Res::new_data(result)))
@@ -138,19 +151,32 @@ impl SysMenuApi {
     /**
      * 删除菜单
      * 
+     * 默认存在子菜单或角色关联时拒绝删除；`cascade=true` 时级联删除子孙菜单及其角色关联，
+     * 并在有角色失去菜单时刷新权限策略
+     * 
      * # 参数
      * - service: 菜单服务实例
+     * - cache_enforcer: Casbin权限执行器
      * - id: 要删除的菜单ID
+     * - params: 删除参数
      * 
      * # 返回
-     * 返回删除操作的结果
+     * 级联删除时返回被删除的菜单ID及受影响的角色代码，否则返回空数据
      */
     pub async fn delete_menu(
         Extension(service): Extension<Arc<SysMenuService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
         Path(id): Path<i32>,
-    ) -> Result<Json<Res<()>>, AppError> {
-        let result = service.delete_menu(id).await?;
-        Ok(Json(Res::new_data(result)))
+        Query(params): Query<MenuDeleteRequest>,
+    ) -> Result<Json<Res<Option<MenuDeleteOutput>>>, AppError> {
+        if !params.cascade {
+            service.delete_menu(id).await?;
+            return Ok(Json(Res::new_data(None)));
+        }
+
+        let enforcer = cache_enforcer.get_enforcer();
+        let result = service.delete_menu_cascade(id, enforcer).await?;
+        Ok(Json(Res::new_data(Some(result))))
     }
 
     /**
@@ -1,15 +1,24 @@
 /**
  * 操作日志管理API
  * 
- * 提供操作日志的查询接口，包括：
+ * 提供操作日志的查询和清理接口，包括：
  * - 分页查询操作日志列表
+ * - 游标分页查询操作日志列表
+ * - 清理指定时间之前的操作日志
  */
 use std::sync::Arc;
 
 use axum::extract::{Extension, Query};
-use server_core::web::{error::AppError, page::PaginatedData, res::Res};
+use server_core::web::{
+    error::AppError,
+    page::{CursorPage, PaginatedData},
+    res::Res,
+    validator::ValidatedQuery,
+};
 use server_service::admin::{
-    OperationLogPageRequest, SysOperationLogModel, SysOperationLogService, TOperationLogService,
+    OperationLogCursorRequest, OperationLogOutput, OperationLogPageRequest,
+    OperationLogPurgeOutput, OperationLogPurgeRequest, SysOperationLogService,
+    TOperationLogService,
 };
 
 pub struct SysOperationLogApi;
@@ -26,12 +35,50 @@ impl SysOperationLogApi {
      * 返回分页后的操作日志列表数据
      */
     pub async fn get_paginated_operation_logs(
-        Query(params): Query<OperationLogPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<OperationLogPageRequest>,
         Extension(service): Extension<Arc<SysOperationLogService>>,
-    ) -> Result<Res<PaginatedData<SysOperationLogModel>>, AppError> {
+    ) -> Result<Res<PaginatedData<OperationLogOutput>>, AppError> {
         service
             .find_paginated_operation_logs(params)
             .await
             .map(Res::new_data)
     }
+
+    /**
+     * 游标分页查询操作日志列表
+     * 
+     * 导出和滚动加载场景优先使用该接口，偏移分页仅用于界面跳页。
+     * 
+     * # 参数
+     * - params: 游标分页参数（limit、after、keywords）
+     * - service: 操作日志服务实例
+     * 
+     * # 返回
+     * 返回当前页操作日志及下一页游标
+     */
+    pub async fn get_operation_logs_by_cursor(
+        Query(params): Query<OperationLogCursorRequest>,
+        Extension(service): Extension<Arc<SysOperationLogService>>,
+    ) -> Result<Res<CursorPage<OperationLogOutput>>, AppError> {
+        service
+            .find_operation_logs_by_cursor(params)
+            .await
+            .map(Res::new_data)
+    }
+    /**
+     * 清理操作日志
+     * 
+     * # 参数
+     * - params: 清理参数，before 为截止时间，dry_run 为 true 时只返回将被删除的记录数
+     * - service: 操作日志服务实例
+     * 
+     * # 返回
+     * 返回删除（或将被删除）的记录数
+     */
+    pub async fn purge_operation_logs(
+        Query(params): Query<OperationLogPurgeRequest>,
+        Extension(service): Extension<Arc<SysOperationLogService>>,
+    ) -> Result<Res<OperationLogPurgeOutput>, AppError> {
+        service.purge_operation_logs(params).await.map(Res::new_data)
+    }
 }
@@ -1,15 +1,27 @@
 /**
  * 组织管理API
  * 
- * 提供组织管理的查询接口，包括：
+ * 提供组织管理的接口，包括：
  * - 分页查询组织列表
+ * - 获取组织详情
+ * - 创建新组织
+ * - 更新组织信息
+ * - 删除指定组织
+ * - 转移组织用户
  */
 use std::sync::Arc;
 
-use axum::extract::{Extension, Query};
-use server_core::web::{error::AppError, page::PaginatedData, res::Res};
+use axum::extract::{Extension, Path, Query};
+use server_core::web::{
+    error::AppError,
+    page::PaginatedData,
+    res::Res,
+    validator::{ValidatedForm, ValidatedQuery},
+};
 use server_service::admin::{
-    OrganizationPageRequest, SysOrganizationModel, SysOrganizationService, TOrganizationService,
+    CreateOrganizationInput, OrganizationDeleteRequest, OrganizationPageRequest,
+    OrganizationTransferOutput, SysOrganizationModel, SysOrganizationService,
+    TOrganizationService, TransferOrganizationUsersInput, UpdateOrganizationInput,
 };
 
 pub struct SysOrganizationApi;
@@ -26,7 +38,7 @@ impl SysOrganizationApi {
      * 返回分页后的组织列表数据
      */
     pub async fn get_paginated_organizations(
-        Query(params): Query<OrganizationPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<OrganizationPageRequest>,
         Extension(service): Extension<Arc<SysOrganizationService>>,
     ) -> Result<Res<PaginatedData<SysOrganizationModel>>, AppError> {
         service
@@ -34,4 +46,97 @@ impl SysOrganizationApi {
             .await
             .map(Res::new_data)
     }
+
+    /**
+     * 获取组织详情
+     * 
+     * # 参数
+     * - id: 组织ID
+     * - service: 组织服务实例
+     * 
+     * # 返回
+     * 返回组织详情
+     */
+    pub async fn get_organization(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+    ) -> Result<Res<SysOrganizationModel>, AppError> {
+        service.get_organization(&id).await.map(Res::new_data)
+    }
+
+    /**
+     * 创建新组织
+     * 
+     * # 参数
+     * - service: 组织服务实例
+     * - input: 创建组织的输入参数
+     * 
+     * # 返回
+     * 返回新创建的组织信息，创建人为当前认证用户
+     */
+    pub async fn create_organization(
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+        ValidatedForm(input): ValidatedForm<CreateOrganizationInput>,
+    ) -> Result<Res<SysOrganizationModel>, AppError> {
+        service.create_organization(input).await.map(Res::new_data)
+    }
+
+    /**
+     * 更新组织
+     * 
+     * # 参数
+     * - service: 组织服务实例
+     * - input: 更新组织的输入参数
+     * 
+     * # 返回
+     * 返回更新后的组织信息，更新人为当前认证用户
+     */
+    pub async fn update_organization(
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+        ValidatedForm(input): ValidatedForm<UpdateOrganizationInput>,
+    ) -> Result<Res<SysOrganizationModel>, AppError> {
+        service.update_organization(input).await.map(Res::new_data)
+    }
+
+    /**
+     * 删除指定组织
+     * 
+     * # 参数
+     * - id: 要删除的组织ID
+     * - params: 删除参数，`transfer_to` 为用户转移的目标组织ID
+     * - service: 组织服务实例
+     * 
+     * # 返回
+     * 指定目标组织时返回用户转移结果，否则返回空数据；
+     * 存在子组织，或存在关联用户且未指定目标组织时返回错误
+     */
+    pub async fn delete_organization(
+        Path(id): Path<String>,
+        Query(params): Query<OrganizationDeleteRequest>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+    ) -> Result<Res<Option<OrganizationTransferOutput>>, AppError> {
+        service
+            .delete_organization(&id, params.transfer_to)
+            .await
+            .map(Res::new_data)
+    }
+
+    /**
+     * 转移组织用户
+     * 
+     * # 参数
+     * - id: 来源组织ID
+     * - service: 组织服务实例
+     * - input: 目标组织
+     * 
+     * # 返回
+     * 返回来源、目标组织及转移的用户数量
+     */
+    pub async fn transfer_users(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+        ValidatedForm(input): ValidatedForm<TransferOrganizationUsersInput>,
+    ) -> Result<Res<OrganizationTransferOutput>, AppError> {
+        service.transfer_users(&id, input).await.map(Res::new_data)
+    }
 }
@@ -0,0 +1,86 @@
+/**
+ * 策略查看API
+ * 
+ * 提供运行中Casbin策略的只读接口，包括：
+ * - 按类型和位置过滤查询策略规则
+ * - 权限判定试算
+ * - 从适配器重新加载策略
+ * 
+ * 策略的修改仍通过授权相关接口完成。
+ */
+use std::sync::Arc;
+
+use axum::{extract::Query, Extension};
+use axum_casbin::CasbinAxumLayer;
+use server_core::web::{auth::User, error::AppError, res::Res};
+use server_service::admin::{
+    PolicyCheckOutput, PolicyCheckRequest, PolicyListRequest, PolicyRuleOutput,
+    SysPolicyService, TPolicyService,
+};
+
+pub struct SysPolicyApi;
+
+impl SysPolicyApi {
+    /**
+     * 查询策略规则
+     * 
+     * # 参数
+     * - params: 查询参数，包含策略类型和按位置过滤的值
+     * - service: 策略查看服务实例
+     * - cache_enforcer: Casbin执行器
+     * 
+     * # 返回
+     * 返回匹配的策略规则列表
+     */
+    pub async fn get_policies(
+        Query(params): Query<PolicyListRequest>,
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+    ) -> Result<Res<Vec<PolicyRuleOutput>>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        service.find_policies(params, enforcer).await.map(Res::new_data)
+    }
+
+    /**
+     * 权限判定试算
+     * 
+     * # 参数
+     * - params: 判定参数，对应 sub、dom、obj、act
+     * - service: 策略查看服务实例
+     * - cache_enforcer: Casbin执行器
+     * 
+     * # 返回
+     * 返回判定结果及命中的策略
+     */
+    pub async fn check_policy(
+        Query(params): Query<PolicyCheckRequest>,
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+    ) -> Result<Res<PolicyCheckOutput>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        service.check_policy(params, enforcer).await.map(Res::new_data)
+    }
+
+    /**
+     * 从适配器重新加载策略
+     * 
+     * # 参数
+     * - service: 策略查看服务实例
+     * - user: 当前认证用户信息，记录为操作人
+     * - cache_enforcer: Casbin执行器
+     * 
+     * # 返回
+     * 返回重新加载的结果
+     */
+    pub async fn reload_policies(
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(user): Extension<User>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+    ) -> Result<Res<()>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        service
+            .reload_policies(enforcer, &user.username())
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -7,25 +7,41 @@
  * - 获取角色详情
  * - 更新角色信息
  * - 删除角色
+ * - 分页查询角色下的用户
+ * - 导出、导入角色权限
+ * - 从角色模板创建角色
  */
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Query},
+    extract::Path,
     Extension,
     Json,
 };
+use axum_casbin::CasbinAxumLayer;
 use server_core::web::{
+    auth::User,
     error::AppError,
     page::PaginatedData,
     res::Res,
+    validator::{ValidJson, ValidatedForm, ValidatedQuery},
 };
 use server_model::admin::{
     entities::sys_role::Model as SysRoleModel,
-    input::{CreateRoleInput, RolePageRequest, UpdateRoleInput},
+    ids::{DomainCode, RoleId},
+    input::{
+        CreateRoleFromTemplateInput, CreateRoleInput, ImportRolePermissionInput, RolePageRequest,
+        RoleUserPageRequest, UpdateRoleInput,
+    },
+    output::{
+        RoleDetailOutput, RoleFromTemplateOutput, RolePermissionDocument,
+        RolePermissionImportOutput, RoleUserOutput, WithOperatorNames,
+    },
 };
 use server_service::admin::{
+    SysAuthorizationService,
     SysRoleService,
+    TAuthorizationService,
     TRoleService,
 };
 
@@ -40,12 +56,12 @@ impl SysRoleApi {
      * - params: 分页查询参数
      * 
      * # 返回
-     * 返回分页后的角色列表数据
+     * 返回分页后的角色列表数据，包含创建人和更新人用户名
      */
     pub async fn find_paginated_roles(
         Extension(service): Extension<Arc<SysRoleService>>,
-        Query(params): Query<RolePageRequest>,
-    ) -> Result<Json<Res<PaginatedData<SysRoleModel>>>, AppError> {
+        ValidatedQuery(params): ValidatedQuery<RolePageRequest>,
+    ) -> Result<Json<Res<PaginatedData<WithOperatorNames<SysRoleModel>>>>, AppError> {
         let result = service.find_paginated_roles(params).await?;
         Ok(Json(Res::new_data(result)))
     }
@@ -55,6 +71,7 @@ impl SysRoleApi {
      * 
      * # 参数
      * - service: 角色服务实例
+     * - user: 当前认证用户信息，新角色归属用户所属域
      * - input: 创建角色的输入参数
      * 
      * # 返回
@@ -62,9 +79,10 @@ impl SysRoleApi {
      */
     pub async fn create_role(
         Extension(service): Extension<Arc<SysRoleService>>,
-        Json(input): Json<CreateRoleInput>,
+        Extension(user): Extension<User>,
+        ValidJson(input): ValidJson<CreateRoleInput>,
     ) -> Result<Json<Res<SysRoleModel>>, AppError> {
-        let result = service.create_role(input).await?;
+        let result = service.create_role(input, &user.domain()).await?;
         Ok(Json(Res::new_data(result)))
     }
 
@@ -73,16 +91,40 @@ impl SysRoleApi {
      * 
      * # 参数
      * - service: 角色服务实例
+     * - user: 当前认证用户信息，用户数量只统计同一域中的用户
      * - id: 角色ID
      * 
      * # 返回
-     * 返回指定角色的详细信息
+     * 返回指定角色的详细信息及分配了该角色的用户数量
      */
     pub async fn get_role(
         Extension(service): Extension<Arc<SysRoleService>>,
-        Path(id): Path<i32>,
-    ) -> Result<Json<Res<SysRoleModel>>, AppError> {
-        let result = service.get_role(&id.to_string()).await?;
+        Extension(user): Extension<User>,
+        Path(id): Path<String>,
+    ) -> Result<Json<Res<RoleDetailOutput>>, AppError> {
+        let result = service.get_role_detail(&id, &user.domain()).await?;
+        Ok(Json(Res::new_data(result)))
+    }
+
+    /**
+     * 分页查询角色下的用户
+     * 
+     * # 参数
+     * - service: 角色服务实例
+     * - user: 当前认证用户信息，只返回同一域中的用户
+     * - id: 角色ID
+     * - params: 分页查询参数
+     * 
+     * # 返回
+     * 返回分页后的用户列表数据
+     */
+    pub async fn find_paginated_role_users(
+        Extension(service): Extension<Arc<SysRoleService>>,
+        Extension(user): Extension<User>,
+        Path(id): Path<String>,
+        ValidatedQuery(params): ValidatedQuery<RoleUserPageRequest>,
+    ) -> Result<Json<Res<PaginatedData<RoleUserOutput>>>, AppError> {
+        let result = service.find_paginated_role_users(&id, &user.domain(), params).await?;
         Ok(Json(Res::new_data(result)))
     }
 
@@ -98,7 +140,7 @@ impl SysRoleApi {
      */
     pub async fn update_role(
         Extension(service): Extension<Arc<SysRoleService>>,
-        Json(input): Json<UpdateRoleInput>,
+        ValidJson(input): ValidJson<UpdateRoleInput>,
     ) -> Result<Json<Res<SysRoleModel>>, AppError> {
         let result = service.update_role(input).await?;
         Ok(Json(Res::new_data(result)))
@@ -121,4 +163,74 @@ impl SysRoleApi {
         let result = service.delete_role(&id.to_string()).await?;
         Ok(Json(Res::new_data(result)))
     }
+
+    /**
+     * 导出角色权限
+     * 
+     * # 参数
+     * - service: 授权服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，导出该用户所属域中的权限
+     * - id: 角色ID
+     * 
+     * # 返回
+     * 返回不包含数据库ID的角色权限文档
+     */
+    pub async fn export_role_permissions(
+        Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        Path(id): Path<RoleId>,
+    ) -> Result<Json<Res<RolePermissionDocument>>, AppError> {
+        let domain: DomainCode = user.domain().parse()?;
+        let enforcer = cache_enforcer.get_enforcer();
+        let result = service.export_role_permissions(domain, id, enforcer).await?;
+        Ok(Json(Res::new_data(result)))
+    }
+
+    /**
+     * 导入角色权限
+     * 
+     * # 参数
+     * - service: 授权服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，只能授予自己持有的权限
+     * - input: 角色权限文档及是否只预览
+     * 
+     * # 返回
+     * 返回无法解析的条目和计划（或已执行）的变更
+     */
+    pub async fn import_role_permissions(
+        Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ImportRolePermissionInput>,
+    ) -> Result<Json<Res<RolePermissionImportOutput>>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        let result = service.import_role_permissions(input, &user, enforcer).await?;
+        Ok(Json(Res::new_data(result)))
+    }
+
+    /**
+     * 从角色模板创建角色
+     * 
+     * # 参数
+     * - service: 授权服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，非内置域用户只能在本域中创建角色，且只能授予自己持有的权限
+     * - input: 模板ID、目标域及角色编码和名称
+     * 
+     * # 返回
+     * 返回新创建的角色和模板中无法解析的条目
+     */
+    pub async fn create_role_from_template(
+        Extension(service): Extension<Arc<SysAuthorizationService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateRoleFromTemplateInput>,
+    ) -> Result<Json<Res<RoleFromTemplateOutput>>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        let result = service.create_role_from_template(input, &user, enforcer).await?;
+        Ok(Json(Res::new_data(result)))
+    }
 }
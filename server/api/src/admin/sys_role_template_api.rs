@@ -0,0 +1,120 @@
+/**
+ * 角色模板管理API
+ * 
+ * 提供角色模板管理的CRUD操作接口，只有内置域用户可以调用，包括：
+ * - 查询角色模板列表
+ * - 创建新角色模板
+ * - 获取角色模板详情
+ * - 更新角色模板
+ * - 删除角色模板
+ */
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    CreateRoleTemplateInput, SysRoleTemplateModel, SysRoleTemplateService, TRoleTemplateService,
+    UpdateRoleTemplateInput,
+};
+
+pub struct SysRoleTemplateApi;
+
+impl SysRoleTemplateApi {
+    /**
+     * 查询角色模板列表
+     * 
+     * # 参数
+     * - service: 角色模板服务实例
+     * - user: 当前认证用户信息，必须属于内置域
+     * 
+     * # 返回
+     * 返回按名称排序的全部角色模板
+     */
+    pub async fn find_role_templates(
+        Extension(service): Extension<Arc<SysRoleTemplateService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<SysRoleTemplateModel>>, AppError> {
+        let result = service.find_role_templates(&user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 创建角色模板
+     * 
+     * # 参数
+     * - service: 角色模板服务实例
+     * - user: 当前认证用户信息，必须属于内置域
+     * - input: 创建角色模板的输入参数
+     * 
+     * # 返回
+     * 返回新创建的角色模板
+     */
+    pub async fn create_role_template(
+        Extension(service): Extension<Arc<SysRoleTemplateService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateRoleTemplateInput>,
+    ) -> Result<Res<SysRoleTemplateModel>, AppError> {
+        let result = service.create_role_template(input, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 获取角色模板详情
+     * 
+     * # 参数
+     * - service: 角色模板服务实例
+     * - user: 当前认证用户信息，必须属于内置域
+     * - id: 角色模板ID
+     * 
+     * # 返回
+     * 返回指定的角色模板
+     */
+    pub async fn get_role_template(
+        Extension(service): Extension<Arc<SysRoleTemplateService>>,
+        Extension(user): Extension<User>,
+        Path(id): Path<String>,
+    ) -> Result<Res<SysRoleTemplateModel>, AppError> {
+        let result = service.get_role_template(&id, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 更新角色模板
+     * 
+     * # 参数
+     * - service: 角色模板服务实例
+     * - user: 当前认证用户信息，必须属于内置域
+     * - input: 更新角色模板的输入参数
+     * 
+     * # 返回
+     * 返回更新后的角色模板，已从模板创建的角色不受影响
+     */
+    pub async fn update_role_template(
+        Extension(service): Extension<Arc<SysRoleTemplateService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateRoleTemplateInput>,
+    ) -> Result<Res<SysRoleTemplateModel>, AppError> {
+        let result = service.update_role_template(input, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+
+    /**
+     * 删除角色模板
+     * 
+     * # 参数
+     * - service: 角色模板服务实例
+     * - user: 当前认证用户信息，必须属于内置域
+     * - id: 要删除的角色模板ID
+     * 
+     * # 返回
+     * 返回删除操作的结果
+     */
+    pub async fn delete_role_template(
+        Extension(service): Extension<Arc<SysRoleTemplateService>>,
+        Extension(user): Extension<User>,
+        Path(id): Path<String>,
+    ) -> Result<Res<()>, AppError> {
+        let result = service.delete_role_template(&id, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+}
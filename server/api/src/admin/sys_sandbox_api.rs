@@ -4,8 +4,18 @@
  * 提供沙箱环境的测试接口，包括：
  * - 简单API密钥测试
  * - 复杂API密钥测试
+ * - 复杂API密钥签名调试
  */
-use server_core::web::{error::AppError, res::Res};
+use std::sync::Arc;
+
+use axum::Extension;
+use server_config::SandboxConfig;
+use server_core::{
+    sign::{build_signing_string, ComplexApiKeyConfig, ComplexApiKeyValidator},
+    web::{error::AppError, res::Res, validator::ValidatedForm},
+};
+use server_global::global::get_config;
+use server_service::admin::{SignDebugInput, SignDebugOutput};
 
 pub struct SysSandboxApi;
 
@@ -29,4 +39,54 @@ impl SysSandboxApi {
     pub async fn test_complex_api_key() -> Result<Res<String>, AppError> {
         Ok(Res::new_data("ComplexApiKey".to_string()))
     }
+
+    /**
+     * 复杂API密钥签名调试
+     * 
+     * 按签名中间件相同的规则构建规范签名字符串，并计算期望签名。
+     * 仅在 `sandbox.debug_enabled` 为 true 时可用，响应中不包含密钥。
+     * 只能为 `sandbox.access_keys` 中配置的测试密钥计算签名，数据库中的访问密钥不参与，
+     * 避免该接口成为任意访问密钥的签名工具。
+     * 
+     * # 参数
+     * - config: 复杂API密钥验证配置，决定 access_key、timestamp、nonce 的参数名
+     * - input: 签名调试参数
+     * 
+     * # 返回
+     * 返回规范签名字符串和期望签名；调试未启用时返回404，访问密钥不是沙箱测试密钥时返回400
+     */
+    pub async fn sign_debug(
+        Extension(config): Extension<Arc<ComplexApiKeyConfig>>,
+        ValidatedForm(input): ValidatedForm<SignDebugInput>,
+    ) -> Result<Res<SignDebugOutput>, AppError> {
+        let sandbox = get_config::<SandboxConfig>()
+            .await
+            .filter(|sandbox| sandbox.debug_enabled)
+            .ok_or_else(|| AppError::new(404, "Not Found"))?;
+
+        let reserved = [&config.key_name, &config.timestamp_name, &config.nonce_name];
+        let mut params: Vec<(String, String)> = input
+            .params
+            .into_iter()
+            .filter(|(k, _)| !reserved.contains(&k))
+            .collect();
+        params.push((config.key_name.clone(), input.access_key.clone()));
+        params.push((config.timestamp_name.clone(), input.timestamp.to_string()));
+        params.push((config.nonce_name.clone(), input.nonce));
+
+        let signing_string = build_signing_string(&config.signing_params(&params));
+        // 独立的验证器只包含沙箱测试密钥；错误消息不区分密钥是否存在
+        let validator = ComplexApiKeyValidator::new(None);
+        for access_key in &sandbox.access_keys {
+            validator.add_key_secret(access_key.access_key_id.clone(), access_key.secret.as_str());
+        }
+        let signature = validator
+            .expected_signature(&input.access_key, &signing_string)
+            .ok_or_else(|| AppError::new(400, "Invalid access key"))?;
+
+        Ok(Res::new_data(SignDebugOutput {
+            signing_string,
+            signature,
+        }))
+    }
 }
@@ -0,0 +1,37 @@
+/**
+ * 统计API
+ * 
+ * 提供管理后台首页使用的统计接口，包括：
+ * - 获取域统计概览
+ */
+use std::sync::Arc;
+
+use axum::{extract::Query, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res};
+use server_service::admin::{
+    OverviewOutput, StatisticsOverviewRequest, SysStatisticsService, TStatisticsService,
+};
+
+pub struct SysStatisticsApi;
+
+impl SysStatisticsApi {
+    /**
+     * 获取域统计概览
+     * 
+     * # 参数
+     * - params: 查询参数，domain 为空时统计当前用户所属域，只有内置域用户可以指定其他域
+     * - service: 统计服务实例
+     * - user: 当前认证用户信息
+     * 
+     * # 返回
+     * 返回用户、登录、操作日志、会话、角色和菜单的统计数据，结果最多延迟60秒
+     */
+    pub async fn get_overview(
+        Query(params): Query<StatisticsOverviewRequest>,
+        Extension(service): Extension<Arc<SysStatisticsService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<OverviewOutput>, AppError> {
+        let result = service.get_overview(params, &user.domain()).await?;
+        Ok(Res::new_data(result))
+    }
+}
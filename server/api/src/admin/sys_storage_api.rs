@@ -0,0 +1,108 @@
+/**
+ * 对象存储API
+ * 
+ * 提供由本服务返回内容的文件下载接口，包括：
+ * - 按预签名链接下载文件
+ * - 代理下载文件，支持单段的 `Range` 请求
+ */
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Path, Query},
+    http::{header, HeaderMap, StatusCode},
+    response::{AppendHeaders, IntoResponse, Response},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError};
+use server_service::admin::{ByteRange, PresignedFileRequest, SysStorageService, TStorageService};
+use tokio_util::io::ReaderStream;
+
+pub struct SysStorageApi;
+
+impl SysStorageApi {
+    /**
+     * 按预签名链接下载文件
+     * 
+     * 本地存储生成的预签名链接指向该接口，链接本身即为访问凭证，不需要登录。
+     * 
+     * # 参数
+     * - key: 对象键
+     * - request: 链接携带的过期时间和签名
+     * - service: 对象存储服务实例
+     * 
+     * # 返回
+     * 流式返回文件内容，不把整个文件读入内存；链接过期或签名错误时返回403，文件不存在时返回404
+     */
+    pub async fn download_file(
+        Path(key): Path<String>,
+        Query(request): Query<PresignedFileRequest>,
+        Extension(service): Extension<Arc<SysStorageService>>,
+    ) -> Result<Response, AppError> {
+        let reader = service.read_presigned_file(&key, request).await?;
+        let body = Body::from_stream(ReaderStream::new(reader));
+        Ok((
+            [
+                (header::CONTENT_TYPE, "application/octet-stream"),
+                (header::CACHE_CONTROL, "private, no-store"),
+            ],
+            body,
+        )
+            .into_response())
+    }
+
+    /**
+     * 代理下载文件
+     * 
+     * 存储服务不对外开放时，前端通过该接口下载文件。需要登录，非内置域的用户只能下载本域的文件。
+     * 单段的 `Range` 请求头按范围读取，返回206；多段或格式错误的 `Range` 请求头被忽略，返回整个文件。
+     * 
+     * # 参数
+     * - key: 对象键，以所属域的编码开头
+     * - headers: 请求头，读取其中的 `Range`
+     * - user: 当前用户
+     * - service: 对象存储服务实例
+     * 
+     * # 返回
+     * 流式返回文件内容，不把整个文件读入内存；范围超出文件大小时返回416，
+     * 并在 `Content-Range` 中返回文件大小
+     */
+    pub async fn proxy_file(
+        Path(key): Path<String>,
+        headers: HeaderMap,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysStorageService>>,
+    ) -> Result<Response, AppError> {
+        let range = headers
+            .get(header::RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(ByteRange::parse);
+        let object = match service.open_file(&user, &key, range).await {
+            Err(err) if err.status == StatusCode::RANGE_NOT_SATISFIABLE => {
+                let size = err.args.first().cloned().unwrap_or_default();
+                let content_range = format!("bytes */{}", size);
+                return Ok(([(header::CONTENT_RANGE, content_range)], err).into_response());
+            },
+            result => result?,
+        };
+
+        let content_type =
+            object.content_type.clone().unwrap_or_else(|| "application/octet-stream".to_string());
+        let mut response_headers = vec![
+            (header::CONTENT_TYPE, content_type),
+            (header::CONTENT_LENGTH, object.content_length().to_string()),
+            (header::ACCEPT_RANGES, "bytes".to_string()),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ];
+        let status = match object.range {
+            Some((start, end)) => {
+                let content_range = format!("bytes {}-{}/{}", start, end, object.size);
+                response_headers.push((header::CONTENT_RANGE, content_range));
+                StatusCode::PARTIAL_CONTENT
+            },
+            None => StatusCode::OK,
+        };
+        let body = Body::from_stream(ReaderStream::new(object.reader));
+        Ok((status, AppendHeaders(response_headers), body).into_response())
+    }
+}
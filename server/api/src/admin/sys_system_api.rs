@@ -0,0 +1,53 @@
+/**
+ * 系统信息API
+ * 
+ * 提供服务自身信息的查询接口，包括：
+ * - 查询版本信息和可选子系统的初始化情况
+ * - 主库故障转移后重新连接主库
+ */
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{DbReconnectInput, SysSystemService, TSystemService, VersionOutput};
+
+pub struct SysSystemApi;
+
+impl SysSystemApi {
+    /**
+     * 查询版本信息
+     * 
+     * # 参数
+     * - service: 系统信息服务实例
+     * 
+     * # 返回
+     * 返回版本号、Git提交、构建时间、rustc版本及Redis、MongoDB、S3、XDB的初始化情况
+     */
+    pub async fn get_version(
+        Extension(service): Extension<Arc<SysSystemService>>,
+    ) -> Result<Res<VersionOutput>, AppError> {
+        Ok(Res::new_data(service.get_version().await))
+    }
+
+    /**
+     * 重新连接主库
+     * 
+     * 新连接验证可用后替换主库连接，之后的请求使用新连接，旧连接在宽限期后关闭
+     * 
+     * # 参数
+     * - service: 系统信息服务实例
+     * - user: 当前用户，只有内置域用户可以执行
+     * - input: 新的主库连接URL，为空时使用配置的故障转移地址或当前地址
+     * 
+     * # 返回
+     * 新连接无法建立时返回502，主库连接保持不变
+     */
+    pub async fn reconnect_db(
+        Extension(service): Extension<Arc<SysSystemService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<DbReconnectInput>,
+    ) -> Result<Res<()>, AppError> {
+        service.reconnect_primary_db(input, &user.domain()).await?;
+        Ok(Res::new_data(()))
+    }
+}
@@ -8,12 +8,14 @@
  * - 获取用户详情
  * - 更新用户信息
  * - 删除用户
+ * - 查询用户的角色
  * - 权限策略管理（添加/删除）
+ * - 本人个人访问令牌管理（创建/查看/撤销）
  */
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Query},
+    extract::Path,
     Extension,
 };
 use axum_casbin::{casbin::MgmtApi, CasbinAxumLayer};
@@ -22,14 +24,17 @@ use server_core::web::{
     error::AppError,
     page::PaginatedData,
     res::Res,
-    validator::ValidatedForm,
+    validator::{ValidatedForm, ValidatedQuery},
 };
 use server_model::admin::{
-    input::{CreateUserInput, UpdateUserInput, UserPageRequest},
-    output::UserWithoutPassword,
+    ids::UserId,
+    input::{CreatePersonalTokenInput, CreateUserInput, UpdateUserInput, UserPageRequest},
+    output::{
+        PersonalTokenCreatedOutput, PersonalTokenOutput, UserRoleOutput, UserWithoutPassword,
+    },
 };
 use server_service::admin::{
-    SysUserService,
+    PersonalTokenError, SysPersonalTokenService, SysUserService, TPersonalTokenService,
     TUserService,
 };
 
@@ -63,7 +68,7 @@ impl SysUserApi {
      * 返回分页后的用户列表数据（不包含密码信息）
      */
     pub async fn get_paginated_users(
-        Query(params): Query<UserPageRequest>,
+        ValidatedQuery(params): ValidatedQuery<UserPageRequest>,
         Extension(service): Extension<Arc<SysUserService>>,
         user: User,
     ) -> Result<Res<PaginatedData<UserWithoutPassword>>, AppError> {
@@ -151,7 +156,7 @@ impl SysUserApi {
      * 返回指定用户的详细信息（不包含密码）
      */
     pub async fn get_user(
-        Path(id): Path<String>,
+        Path(id): Path<UserId>,
         Extension(service): Extension<Arc<SysUserService>>,
     ) -> Result<Res<UserWithoutPassword>, AppError> {
         service.get_user(&id).await.map_err(AppError::from).map(Res::new_data)
@@ -185,9 +190,109 @@ impl SysUserApi {
      * 返回删除操作的结果
      */
     pub async fn delete_user(
-        Path(id): Path<String>,
+        Path(id): Path<UserId>,
         Extension(service): Extension<Arc<SysUserService>>,
     ) -> Result<Res<()>, AppError> {
         service.delete_user(&id).await.map_err(AppError::from).map(Res::new_data)
     }
+
+    /**
+     * 查询用户的角色
+     * 
+     * # 参数
+     * - id: 用户ID
+     * - service: 用户服务实例
+     * - user: 当前认证用户信息，只能查询同一域中的用户
+     * 
+     * # 返回
+     * 返回用户被分配的角色及用户所属域信息
+     */
+    pub async fn get_user_roles(
+        Path(id): Path<UserId>,
+        Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<UserRoleOutput>>, AppError> {
+        service
+            .find_user_roles(&id, &user.domain())
+            .await
+            .map_err(AppError::from)
+            .map(Res::new_data)
+    }
+
+    /**
+     * 创建当前用户本人的个人访问令牌
+     * 
+     * # 参数
+     * - service: 个人访问令牌服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，权限范围不能超出该用户的权限
+     * - input: 令牌名称、权限范围和过期时间
+     * 
+     * # 返回
+     * 返回令牌信息和令牌明文，明文只在此时返回一次
+     */
+    pub async fn create_personal_token(
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreatePersonalTokenInput>,
+    ) -> Result<Res<PersonalTokenCreatedOutput>, AppError> {
+        Self::reject_personal_token(&user)?;
+        let enforcer = cache_enforcer.get_enforcer();
+        let output = service.create_token(&user, input, enforcer).await?;
+        Ok(Res::new_data(output))
+    }
+
+    /**
+     * 查询当前用户本人的个人访问令牌
+     * 
+     * # 参数
+     * - service: 个人访问令牌服务实例
+     * - user: 当前认证用户信息，只查询该用户的令牌
+     * 
+     * # 返回
+     * 返回令牌列表，不包含令牌明文
+     */
+    pub async fn list_personal_tokens(
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<PersonalTokenOutput>>, AppError> {
+        Self::reject_personal_token(&user)?;
+        let tokens = service.find_user_tokens(&user.user_id()).await?;
+        Ok(Res::new_data(tokens))
+    }
+
+    /**
+     * 撤销当前用户本人的个人访问令牌
+     * 
+     * # 参数
+     * - id: 令牌记录ID
+     * - service: 个人访问令牌服务实例
+     * - cache_enforcer: Casbin执行器
+     * - user: 当前认证用户信息，只能撤销该用户的令牌
+     * 
+     * # 返回
+     * 撤销成功返回空数据；令牌不存在或属于其他用户时返回404
+     */
+    pub async fn revoke_personal_token(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        Self::reject_personal_token(&user)?;
+        let enforcer = cache_enforcer.get_enforcer();
+        service.revoke_token(&user.user_id(), &id, enforcer).await?;
+        Ok(Res::new_data(()))
+    }
+
+    /**
+     * 个人访问令牌只能通过登录令牌管理，避免泄露的令牌为自己续期或扩散
+     */
+    fn reject_personal_token(user: &User) -> Result<(), PersonalTokenError> {
+        match user.personal_token() {
+            Some(_) => Err(PersonalTokenError::ManagedWithPersonalToken),
+            None => Ok(()),
+        }
+    }
 }
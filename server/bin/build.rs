@@ -0,0 +1,10 @@
+/**
+ * 构建脚本
+ *
+ * 启用 `grpc` 特性时从 proto 文件生成gRPC服务代码
+ */
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "grpc")]
+    tonic_build::compile_protos("proto/authz.proto")?;
+    Ok(())
+}
@@ -0,0 +1,89 @@
+/**
+ * 命令行参数定义
+ *
+ * 不带子命令时等同于 `serve`，维护类子命令只初始化配置和主数据库，
+ * 执行完成后退出。
+ */
+use clap::{Parser, Subcommand};
+
+/** 默认分配给 create-admin 创建的用户的角色 */
+const DEFAULT_ADMIN_ROLE: &str = "ROLE_SUPER";
+
+/**
+ * 命令行参数
+ */
+#[derive(Debug, Parser)]
+#[command(name = "server", about = "Alion admin server")]
+pub struct Cli {
+    /** 仅校验配置及数据库、Redis、JWT、XDB连通性，完成后退出，不启动服务器 */
+    #[arg(long)]
+    pub check_config: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/**
+ * 子命令
+ */
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /** 启动HTTP服务器（默认） */
+    Serve,
+
+    /** 执行数据库迁移 */
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+
+    /** 直接在数据库中创建管理员账号，不经过HTTP接口 */
+    CreateAdmin {
+        /** 域代码 */
+        #[arg(long)]
+        domain: String,
+        /** 用户名 */
+        #[arg(long)]
+        username: String,
+        /** 密码 */
+        #[arg(long)]
+        password: String,
+        /** 分配的角色代码 */
+        #[arg(long, default_value = DEFAULT_ADMIN_ROLE)]
+        role: String,
+    },
+
+    /** 重置用户密码，未指定密码时生成随机密码并输出 */
+    ResetPassword {
+        /** 用户名 */
+        #[arg(long)]
+        username: String,
+        /** 域代码 */
+        #[arg(long)]
+        domain: String,
+        /** 新密码 */
+        #[arg(long)]
+        password: Option<String>,
+    },
+}
+
+/**
+ * 迁移操作
+ */
+#[derive(Debug, Subcommand)]
+pub enum MigrateAction {
+    /** 执行未应用的迁移 */
+    Up {
+        /** 执行的迁移数量，默认全部 */
+        #[arg(long)]
+        steps: Option<u32>,
+    },
+    /** 回滚已应用的迁移 */
+    Down {
+        /** 回滚的迁移数量 */
+        #[arg(long, default_value_t = 1)]
+        steps: u32,
+    },
+    /** 查看迁移状态 */
+    Status,
+}
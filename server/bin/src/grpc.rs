@@ -0,0 +1,206 @@
+/**
+ * 内部gRPC鉴权服务
+ *
+ * 仅在启用 `grpc` 特性时编译，供其他内部服务查询权限而无需自行嵌入Casbin：
+ * - AuthzService.Check: 使用与HTTP路由相同的Casbin执行器判定权限
+ * - TokenService.Introspect: 使用JWT配置校验访问令牌，并查询令牌是否已撤销
+ *
+ * 请求ID和追踪Span与HTTP服务一致：优先读取 `x-request-id` 元数据，缺失时生成新的ID。
+ */
+use std::{future::Future, net::SocketAddr, sync::Arc};
+
+use axum_casbin::{
+    casbin::{CachedEnforcer, CoreApi},
+    normalize_action,
+};
+use server_constant::definition::Audience;
+use server_core::web::{
+    auth::{Claims, User},
+    jwt::JwtUtils,
+    request_id_from_headers, RequestId,
+};
+use server_initialize::{make_request_span, project_info, InitError};
+use server_service::{admin::SysAuthService, helper::db_helper};
+use tokio::sync::RwLock;
+use tonic::{body::Body, transport::Server, Request, Response, Status};
+use tower::ServiceBuilder;
+use tower_http::trace::TraceLayer;
+
+use self::proto::{
+    authz_service_server::{AuthzService, AuthzServiceServer},
+    token_service_server::{TokenService, TokenServiceServer},
+    CheckRequest, CheckResponse, IntrospectRequest, IntrospectResponse,
+};
+
+mod proto {
+    tonic::include_proto!("alion.authz.v1");
+}
+
+/**
+ * 权限判定服务
+ */
+struct AuthzGrpcService {
+    enforcer: Arc<RwLock<CachedEnforcer>>,
+}
+
+#[tonic::async_trait]
+impl AuthzService for AuthzGrpcService {
+    async fn check(
+        &self,
+        request: Request<CheckRequest>,
+    ) -> Result<Response<CheckResponse>, Status> {
+        let CheckRequest { subject, domain, object, action } = request.into_inner();
+        if [&subject, &domain, &object, &action].iter().any(|value| value.is_empty()) {
+            return Err(Status::invalid_argument(
+                "subject, domain, object and action are required",
+            ));
+        }
+
+        // 策略操作为 read/write，兼容传入HTTP方法的调用方
+        let action = normalize_action(&action).to_string();
+
+        // 与 CasbinAxumLayer 相同，缓存执行器的判定需要写锁
+        let allowed = self
+            .enforcer
+            .write()
+            .await
+            .enforce_mut(vec![subject, domain, object, action])
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(CheckResponse { allowed }))
+    }
+}
+
+/**
+ * 令牌服务
+ */
+struct TokenGrpcService;
+
+#[tonic::async_trait]
+impl TokenService for TokenGrpcService {
+    async fn introspect(
+        &self,
+        request: Request<IntrospectRequest>,
+    ) -> Result<Response<IntrospectResponse>, Status> {
+        let token = request.into_inner().token;
+        let claims =
+            match JwtUtils::validate_token(&token, Audience::ManagementPlatform.as_str()).await {
+                Ok(data) => data.claims,
+                Err(_) => return Ok(Response::new(IntrospectResponse::default())),
+            };
+
+        let db = db_helper::get_db_connection()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        if SysAuthService::is_token_revoked(&db, &token)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+        {
+            return Ok(Response::new(IntrospectResponse::default()));
+        }
+
+        Ok(Response::new(active_response(claims).await))
+    }
+}
+
+/**
+ * 构建未撤销令牌的内省结果
+ *
+ * 声明超过大小上限的令牌只携带角色引用，需要先取回角色列表；
+ * 与JWT中间件一致，取回失败时按无效令牌返回，不能以空角色列表报告为有效
+ */
+async fn active_response(claims: Claims) -> IntrospectResponse {
+    let exp = claims.exp().unwrap_or_default() as i64;
+    let user = match SysAuthService::resolve_token_roles(User::from(claims)).await {
+        Ok(user) => user,
+        Err(_) => return IntrospectResponse::default(),
+    };
+    IntrospectResponse {
+        active: true,
+        user_id: user.user_id(),
+        username: user.username(),
+        domain: user.domain(),
+        roles: user.subject(),
+        exp,
+    }
+}
+
+/**
+ * 写入请求ID
+ *
+ * 在追踪层之前执行，使gRPC请求的Span与HTTP请求一样携带请求ID
+ */
+fn assign_request_id(mut request: http::Request<Body>) -> http::Request<Body> {
+    let request_id = request_id_from_headers(request.headers());
+    request.extensions_mut().insert(RequestId(request_id));
+    request
+}
+
+/**
+ * 启动gRPC服务
+ *
+ * 未配置 `server.grpc_port` 时直接返回，不影响HTTP服务
+ *
+ * # 参数
+ * - enforcer: 与HTTP路由共用的Casbin执行器
+ * - shutdown: 关闭信号，完成后停止接收新请求并等待进行中的请求结束
+ */
+pub async fn serve(
+    enforcer: Arc<RwLock<CachedEnforcer>>,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), InitError> {
+    let Some(addr) = server_initialize::get_grpc_address().await? else {
+        project_info!("gRPC port is not configured, skipping gRPC server");
+        return Ok(());
+    };
+    let addr: SocketAddr = addr
+        .parse()
+        .map_err(|e| InitError::Server(format!("Invalid gRPC address {}: {}", addr, e)))?;
+
+    let layer = ServiceBuilder::new()
+        .map_request(assign_request_id)
+        .layer(TraceLayer::new_for_grpc().make_span_with(make_request_span::<Body>));
+
+    project_info!("Starting gRPC server on {}", addr);
+    Server::builder()
+        .layer(layer)
+        .add_service(AuthzServiceServer::new(AuthzGrpcService { enforcer }))
+        .add_service(TokenServiceServer::new(TokenGrpcService))
+        .serve_with_shutdown(addr, shutdown)
+        .await
+        .map_err(|e| InitError::Server(format!("gRPC server failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(roles: Vec<String>) -> Claims {
+        Claims::new(
+            "user".to_string(),
+            Audience::ManagementPlatform.as_str().to_string(),
+            "user".to_string(),
+            roles,
+            "built-in".to_string(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_active_response_reports_roles() {
+        let response = active_response(claims(vec!["R_USER".to_string()])).await;
+        assert!(response.active);
+        assert_eq!(response.user_id, "user");
+        assert_eq!(response.domain, "built-in");
+        assert_eq!(response.roles, vec!["R_USER"]);
+    }
+
+    #[tokio::test]
+    async fn test_overflowed_token_without_roles_is_inactive() {
+        // 声明超限的令牌不带角色列表，只带Redis中的引用；引用无法取回时不能报告为有效
+        let mut claims = claims(vec![]);
+        claims.set_roles_ref("01J00000000000000000000000".to_string());
+        let response = active_response(claims).await;
+        assert!(!response.active);
+        assert!(response.roles.is_empty());
+    }
+}
@@ -7,29 +7,55 @@
  * 3. 初始化数据库连接
  * 4. 初始化Redis连接
  * 5. 设置路由和中间件
- * 6. 启动HTTP服务器
+ * 6. 启动HTTP服务器，启用 `grpc` 特性时同时启动内部gRPC服务
  * 
  * 初始化流程：
- * 1. 根据运行环境选择配置文件
+ * 1. 记录服务版本，根据运行环境选择配置文件
  * 2. 初始化日志和追踪系统
  * 3. 加载应用程序配置
- * 4. 初始化数据库连接池
- * 5. 初始化JWT和访问密钥
- * 6. 初始化Redis连接池
- * 7. 构建应用程序路由
- * 8. 启动HTTP服务器
+ * 4. 初始化主数据库连接
+ * 5. 并行初始化XDB、数据库连接池、JWT、Redis、S3、邮件和访问密钥
+ * 6. 启动后台任务调度（数据库健康检查、操作日志保留）并初始化事件通道
+ * 7. 初始化Casbin，HTTP路由与gRPC服务共用同一个执行器
+ * 8. 构建应用程序路由（依赖数据库与Casbin）
+ * 9. 启动HTTP服务器及gRPC服务，收到 Ctrl+C 或 SIGTERM 后一起优雅关闭
+ * 10. 服务停止后关闭事件通道，等待监听器处理完剩余事件
  * 
  * 错误处理：
- * - 所有初始化步骤都有适当的错误处理
- * - 关键初始化失败会导致程序退出
- * - 错误信息会被记录到日志系统
+ * - 所有初始化步骤返回 `InitError`，并记录各步骤耗时
+ * - 任一步骤（包括并行步骤）失败时记录带步骤名称的错误日志并以非零状态码退出
+ * 
+ * 命令行参数：
+ * - `serve`: 启动HTTP服务器，未指定子命令时的默认行为
+ * - `--check-config`: 仅校验配置及数据库、Redis、JWT、XDB连通性，完成后退出，不启动服务器
+ * - `migrate up|down|status`: 执行数据库迁移
+ * - `create-admin --domain --username --password [--role]`: 直接在数据库中创建管理员
+ * - `reset-password --username --domain [--password]`: 重置用户密码
+ * 
+ * 维护类子命令只初始化配置和主数据库，失败时记录错误并以非零状态码退出。
  */
-use std::net::SocketAddr;
+use std::{net::SocketAddr, process::ExitCode};
+
+use clap::Parser;
+use migration::{Migrator, MigratorTrait};
+use server_initialize::{init_step, project_error, project_info, server_version, InitError};
+use server_service::admin::SysAdminAccountService;
+use server_utils::SecureUtil;
+use tokio::{net::TcpListener, sync::watch};
 
-use tokio::net::TcpListener;
+use crate::cli::{Cli, Command, MigrateAction};
+
+mod cli;
+#[cfg(feature = "grpc")]
+mod grpc;
+
+/** reset-password 未指定密码时生成的随机密码长度 */
+const GENERATED_PASSWORD_LENGTH: usize = 16;
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
     // 根据运行环境选择配置文件路径
     let config_path = if cfg!(debug_assertions) {
         "server/resources/application-test.yaml"
@@ -39,52 +65,264 @@ async fn main() {
 
     // 初始化日志和追踪系统
     server_initialize::initialize_log_tracing().await;
-    
-    // 从配置文件初始化应用程序配置
-    server_initialize::initialize_config(config_path).await;
-    
-    // 初始化数据库连接
-    if let Err(e) = server_initialize::init_xdb().await {
-        eprintln!("Failed to initialize XDB: {}", e);
-        return;
-    }
-    if let Err(e) = server_initialize::init_primary_connection().await {
-        eprintln!("Failed to initialize primary database connection: {}", e);
-        return;
-    }
-    server_initialize::init_db_pools().await;
-    
-    // 初始化密钥和验证器
-    if let Err(e) = server_initialize::init_jwt().await {
-        eprintln!("Failed to initialize JWT: {}", e);
-        return;
+
+    let result = match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve if cli.check_config => check(config_path).await,
+        Command::Serve => run(config_path).await,
+        Command::Migrate { action } => migrate(config_path, action).await,
+        Command::CreateAdmin { domain, username, password, role } => {
+            create_admin(config_path, &domain, &username, &password, &role).await
+        },
+        Command::ResetPassword { username, domain, password } => {
+            reset_password(config_path, &domain, &username, password).await
+        },
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            project_error!("{}", e);
+            ExitCode::FAILURE
+        },
     }
-    server_initialize::initialize_access_key().await;
+}
+
+/**
+ * 校验配置与外部依赖连通性
+ * 
+ * 加载配置与主数据库后，并行校验XDB、数据库连接池、JWT和Redis，任一失败即返回错误。
+ */
+async fn check(config_path: &str) -> Result<(), InitError> {
+    init_step("config", server_initialize::initialize_config(config_path)).await?;
+    init_step("primary database", server_initialize::init_primary_connection()).await?;
+
+    tokio::try_join!(
+        init_step("xdb", server_initialize::init_xdb()),
+        init_step("database pools", server_initialize::init_db_pools()),
+        init_step("jwt", server_initialize::init_jwt()),
+        init_step("redis", init_redis()),
+    )?;
+    server_initialize::get_server_address().await?;
+
+    project_info!("Configuration check passed");
+    Ok(())
+}
+
+/**
+ * 初始化各组件并启动HTTP服务器
+ * 
+ * 启用 `grpc` 特性时同时启动gRPC服务，任一服务启动失败即返回错误
+ */
+async fn run(config_path: &str) -> Result<(), InitError> {
+    project_info!(
+        "Starting alion-admin-api {} ({})",
+        server_version::version_string(),
+        server_version::RUSTC_VERSION.unwrap_or("unknown rustc")
+    );
+
+    // 从配置文件初始化应用程序配置
+    init_step("config", server_initialize::initialize_config(config_path)).await?;
+
+    // 初始化主数据库连接，后续步骤依赖该连接
+    init_step("primary database", server_initialize::init_primary_connection()).await?;
+
+    // 并行初始化相互独立的组件
+    tokio::try_join!(
+        init_step("xdb", server_initialize::init_xdb()),
+        init_step("database pools", server_initialize::init_db_pools()),
+        init_step("jwt", server_initialize::init_jwt()),
+        init_step("redis", init_redis()),
+        init_step("s3", init_s3()),
+        init_step("email", server_initialize::initialize_email()),
+        init_step("access key", server_initialize::initialize_access_key()),
+    )?;
+
+    // 域内没有启用的管理员时按配置创建
+    init_step("bootstrap admin", server_initialize::initialize_bootstrap_admin()).await?;
+
+    // 注册周期任务并启动调度
+    init_step("scheduler", server_initialize::initialize_scheduler()).await?;
     server_initialize::initialize_event_channel().await;
 
-    // 初始化Redis连接
-    server_initialize::init_primary_redis().await;
-    server_initialize::init_redis_pools().await;
+    // HTTP路由和gRPC服务共用同一个Casbin执行器
+    let casbin = init_step("casbin", server_initialize::init_casbin()).await?;
+    #[cfg(feature = "grpc")]
+    let enforcer = casbin.clone().get_enforcer();
 
     // 构建应用程序路由
-    let app = server_initialize::initialize_admin_router().await;
+    let app = init_step("router", async {
+        Ok(server_initialize::initialize_admin_router(casbin).await)
+    })
+    .await?;
 
     // 获取服务器地址
-    let addr = match server_initialize::get_server_address().await {
-        Ok(addr) => addr,
-        Err(e) => {
-            eprintln!("Failed to get server address: {}", e);
-            return;
-        },
-    };
+    let addr = server_initialize::get_server_address().await?;
+
+    // 收到关闭信号后通知所有服务
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        let _ = shutdown_tx.send(());
+    });
 
     // 启动HTTP服务器
-    let listener = TcpListener::bind(&addr).await.unwrap();
-    // tracing::debug!("listening on {}", listener.local_addr().unwrap());
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .await
-    .unwrap();
+    let listener = TcpListener::bind(&addr)
+        .await
+        .map_err(|e| InitError::Server(format!("Failed to bind {}: {}", addr, e)))?;
+    let http = async {
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(wait_for_shutdown(shutdown_rx.clone()))
+        .await
+        .map_err(|e| InitError::Server(e.to_string()))
+    };
+
+    #[cfg(feature = "grpc")]
+    tokio::try_join!(http, grpc::serve(enforcer, wait_for_shutdown(shutdown_rx.clone())))?;
+    #[cfg(not(feature = "grpc"))]
+    http.await?;
+
+    // 写入事件队列和操作日志缓冲区中剩余的数据
+    server_initialize::shutdown_event_channel().await;
+
+    project_info!("Server stopped");
+    Ok(())
+}
+
+/**
+ * 等待 Ctrl+C 或 SIGTERM
+ */
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            project_error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            },
+            Err(e) => {
+                project_error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            },
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+    project_info!("Shutdown signal received, stopping servers");
+}
+
+/**
+ * 等待关闭通知
+ * 
+ * 发送端被丢弃时同样视为关闭
+ */
+async fn wait_for_shutdown(mut shutdown: watch::Receiver<()>) {
+    let _ = shutdown.changed().await;
+}
+
+/**
+ * 执行数据库迁移
+ * 
+ * 配置了 `time.legacy_local_offset` 时，转换已有时间戳的迁移按该偏移将数据改写为UTC
+ */
+async fn migrate(config_path: &str, action: MigrateAction) -> Result<(), InitError> {
+    init_step("config", server_initialize::initialize_config(config_path)).await?;
+    let db = init_step("primary database", server_initialize::init_primary_connection()).await?;
+
+    if let Some(offset) = server_initialize::get_legacy_local_offset().await {
+        project_info!("Existing timestamps are treated as written at UTC offset {}s", offset);
+        migration::set_legacy_local_offset(offset);
+    }
+
+    let result = match action {
+        MigrateAction::Up { steps } => Migrator::up(&db, steps).await,
+        MigrateAction::Down { steps } => Migrator::down(&db, Some(steps)).await,
+        MigrateAction::Status => Migrator::status(&db).await,
+    };
+    result.map_err(|e| InitError::Command(format!("Migration failed: {}", e)))
+}
+
+/**
+ * 直接在数据库中创建管理员账号
+ */
+async fn create_admin(
+    config_path: &str,
+    domain: &str,
+    username: &str,
+    password: &str,
+    role: &str,
+) -> Result<(), InitError> {
+    init_step("config", server_initialize::initialize_config(config_path)).await?;
+    let db = init_step("primary database", server_initialize::init_primary_connection()).await?;
+
+    let user = SysAdminAccountService::create_admin(&db, domain, username, password, role)
+        .await
+        .map_err(|e| InitError::Command(format!("Failed to create admin '{}': {}", username, e)))?;
+    project_info!(
+        "Created admin '{}' ({}) in domain '{}' with role {}",
+        username,
+        user.id,
+        domain,
+        role
+    );
+    Ok(())
+}
+
+/**
+ * 重置用户密码
+ *
+ * 未指定密码时生成随机密码，并只输出到标准输出，不写入日志
+ */
+async fn reset_password(
+    config_path: &str,
+    domain: &str,
+    username: &str,
+    password: Option<String>,
+) -> Result<(), InitError> {
+    init_step("config", server_initialize::initialize_config(config_path)).await?;
+    let db = init_step("primary database", server_initialize::init_primary_connection()).await?;
+
+    let generated = password.is_none();
+    let password =
+        password.unwrap_or_else(|| SecureUtil::generate_password(GENERATED_PASSWORD_LENGTH));
+    SysAdminAccountService::reset_password(&db, domain, username, &password)
+        .await
+        .map_err(|e| {
+            InitError::Command(format!("Failed to reset password of '{}': {}", username, e))
+        })?;
+
+    project_info!("Password of '{}' in domain '{}' has been reset", username, domain);
+    if generated {
+        println!("New password: {}", password);
+    }
+    Ok(())
+}
+
+/**
+ * 初始化主Redis连接及Redis连接池
+ */
+async fn init_redis() -> Result<(), InitError> {
+    server_initialize::init_primary_redis().await?;
+    server_initialize::init_redis_pools().await
+}
+
+/**
+ * 初始化主S3客户端及S3连接池
+ */
+async fn init_s3() -> Result<(), InitError> {
+    server_initialize::init_primary_s3().await?;
+    server_initialize::init_s3_pools().await
 }
@@ -12,8 +12,11 @@ use tokio::fs;
 
 use crate::{
     model::{Config, OptionalConfigs},
-    project_error, project_info, DatabaseConfig, DatabasesInstancesConfig, JwtConfig,
-    RedisConfig, RedisInstancesConfig, S3Config, S3InstancesConfig, ServerConfig,
+    project_error, project_info, BootstrapConfig, CasbinConfig, DatabaseConfig,
+    DatabasesInstancesConfig, EmailConfig, EndpointSyncConfig, EventConfig, HttpConfig, JwtConfig,
+    LoginConfig, MenuConfig,
+    OperationLogConfig, RateLimitConfig, RedisConfig, RedisInstancesConfig, SandboxConfig, S3Config,
+    S3InstancesConfig, SecurityConfig, ServerConfig, TimeConfig, TrustedProxyConfig, XdbConfig,
 };
 
 /**
@@ -179,6 +182,55 @@ pub async fn init_from_file(file_path: &str) -> Result<(), ConfigError> {
     }
     global::init_config::<OptionalConfigs<S3InstancesConfig>>(config.s3_instances.into()).await;
 
+    // 初始化速率限制配置
+    if let Some(rate_limit_config) = config.rate_limit {
+        global::init_config::<RateLimitConfig>(rate_limit_config).await;
+    }
+
+    // 初始化沙箱配置
+    global::init_config::<SandboxConfig>(config.sandbox.unwrap_or_default()).await;
+
+    // 初始化Casbin与IP地址解析数据库配置
+    global::init_config::<CasbinConfig>(config.casbin.unwrap_or_default()).await;
+    global::init_config::<XdbConfig>(config.xdb.unwrap_or_default()).await;
+
+    // 初始化事件通道配置
+    global::init_config::<EventConfig>(config.events.unwrap_or_default()).await;
+
+    // 初始化操作日志配置
+    global::init_config::<OperationLogConfig>(config.operation_log.unwrap_or_default()).await;
+
+    // 初始化邮件配置
+    if let Some(email_config) = config.email {
+        global::init_config::<EmailConfig>(email_config).await;
+    }
+
+    // 初始化可信代理配置
+    global::init_config::<TrustedProxyConfig>(config.trusted_proxy.unwrap_or_default()).await;
+
+    // 初始化登录页配置
+    global::init_config::<LoginConfig>(config.login.unwrap_or_default()).await;
+
+    // 初始化接口同步配置
+    global::init_config::<EndpointSyncConfig>(config.endpoint_sync.unwrap_or_default()).await;
+
+    // 初始化安全配置
+    global::init_config::<SecurityConfig>(config.security.unwrap_or_default()).await;
+
+    // 初始化时间配置
+    global::init_config::<TimeConfig>(config.time.unwrap_or_default()).await;
+
+    // 初始化HTTP配置
+    global::init_config::<HttpConfig>(config.http.unwrap_or_default()).await;
+
+    // 初始化菜单配置
+    global::init_config::<MenuConfig>(config.menu.unwrap_or_default()).await;
+
+    // 初始化初始管理员配置
+    if let Some(bootstrap_config) = config.bootstrap {
+        global::init_config::<BootstrapConfig>(bootstrap_config).await;
+    }
+
     project_info!("Configuration initialized successfully");
     Ok(())
 }
@@ -38,7 +38,7 @@
  * 提供从文件加载和解析配置的功能
  * 支持YAML、TOML和JSON格式的配置文件
  */
-pub use config_init::init_from_file;
+pub use config_init::{init_from_file, ConfigError};
 
 /**
  * 重新导出配置模型
@@ -47,9 +47,15 @@ pub use config_init::init_from_file;
  * 用于定义和访问应用程序的配置
  */
 pub use model::{
-    Config, DatabaseConfig, DatabasesInstancesConfig, JwtConfig, OptionalConfigs,
-    RedisConfig, RedisInstancesConfig, RedisMode, S3Config, S3InstancesConfig,
-    ServerConfig,
+    BootstrapConfig, CasbinConfig, Config, DatabaseConfig, DatabasesInstancesConfig, EmailConfig, EmailTlsMode,
+    EndpointSyncConfig, EndpointSyncMode,
+    CompressionAlgorithm, CompressionConfig, EventConfig, ForwardedHeader, HttpConfig, JwtConfig,
+    JwtDomainOverride,
+    LimitConfig, LimitStorage, LoginConfig, MenuConfig, OperationLogConfig, OptionalConfigs, OversizePolicy, PaginationConfig, RateLimitConfig, RedisConfig, RedisInstancesConfig,
+    PasswordHashConfig, RedisMode, SandboxConfig, S3Config, S3InstancesConfig, S3Provider, SecurityConfig,
+    ServerConfig, TimeConfig, TimeoutConfig, TrustedProxyConfig, UnusualLoginConfig,
+    XdbConfig,
+    SandboxAccessKey,
 };
 
 /**
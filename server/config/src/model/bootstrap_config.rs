@@ -0,0 +1,86 @@
+/**
+ * 初始管理员配置模块
+ * 
+ * 定义了启动时创建超级管理员账号的相关参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * 初始管理员配置结构体
+ * 
+ * 启动时如果目标域内没有拥有管理员角色的启用用户，按该配置创建一个。
+ * 已存在时不做任何修改，修改配置中的密码不会重置已有账号的密码。
+ * 
+ * 配置示例（YAML）：
+ * ```yaml
+ * bootstrap:
+ *     admin_username: admin
+ *     admin_password_env: ALION_ADMIN_PASSWORD
+ *     domain: built-in
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct BootstrapConfig {
+    /**
+     * 管理员用户名
+     */
+    pub admin_username: String,
+
+    /**
+     * 管理员密码
+     * 
+     * 明文写在配置文件中，优先于 `admin_password_env`
+     */
+    pub admin_password: Option<String>,
+
+    /**
+     * 保存管理员密码的环境变量名
+     * 
+     * 避免在配置文件中保存明文密码
+     */
+    pub admin_password_env: Option<String>,
+
+    /**
+     * 管理员所在的域代码
+     * 
+     * 默认为 `built-in`
+     */
+    #[serde(default = "default_domain")]
+    pub domain: String,
+
+    /**
+     * 分配给管理员的角色代码
+     * 
+     * 默认为 `ROLE_SUPER`
+     */
+    #[serde(default = "default_admin_role")]
+    pub admin_role: String,
+}
+
+impl BootstrapConfig {
+    /**
+     * 获取管理员密码
+     * 
+     * # 返回
+     * 优先返回配置中的明文密码，其次读取环境变量；都未设置或为空时返回None
+     */
+    pub fn admin_password(&self) -> Option<String> {
+        self.admin_password
+            .clone()
+            .or_else(|| {
+                self.admin_password_env
+                    .as_deref()
+                    .and_then(|name| std::env::var(name).ok())
+            })
+            .filter(|password| !password.is_empty())
+    }
+}
+
+fn default_domain() -> String {
+    "built-in".to_string()
+}
+
+fn default_admin_role() -> String {
+    "ROLE_SUPER".to_string()
+}
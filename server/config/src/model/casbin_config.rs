@@ -0,0 +1,24 @@
+/**
+ * Casbin配置模块
+ * 
+ * 定义了Casbin权限模型的加载参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * Casbin配置结构体
+ * 
+ * 指定RBAC模型文件路径。
+ * 未配置或文件不存在时使用编译进程序的内置模型。
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct CasbinConfig {
+    /**
+     * RBAC模型文件路径
+     * 
+     * 相对路径基于进程工作目录解析
+     */
+    #[serde(default)]
+    pub model_path: Option<String>,
+}
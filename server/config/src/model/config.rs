@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
 use super::{
-    DatabaseConfig, DatabasesInstancesConfig, JwtConfig, RedisConfig, RedisInstancesConfig, S3Config, S3InstancesConfig, ServerConfig,
+    BootstrapConfig, CasbinConfig, DatabaseConfig, DatabasesInstancesConfig, EmailConfig, EndpointSyncConfig, EventConfig, HttpConfig, JwtConfig, LoginConfig, OperationLogConfig, RateLimitConfig, RedisConfig, RedisInstancesConfig, SandboxConfig, S3Config, S3InstancesConfig, SecurityConfig, ServerConfig, TimeConfig, TrustedProxyConfig, XdbConfig,
 };
 
 /**
@@ -112,4 +112,94 @@ pub struct Config {
      * 用于配置多个命名的 S3 连接
      */
     pub s3_instances: Option<Vec<S3InstancesConfig>>,
+
+    /**
+     * 速率限制配置
+     * 未配置时不启用限流
+     */
+    pub rate_limit: Option<RateLimitConfig>,
+
+    /**
+     * 沙箱配置
+     * 未配置时关闭调试接口
+     */
+    pub sandbox: Option<SandboxConfig>,
+
+    /**
+     * Casbin配置
+     * 未配置时使用默认模型文件路径，文件不存在则使用内置模型
+     */
+    pub casbin: Option<CasbinConfig>,
+
+    /**
+     * IP地址解析数据库配置
+     * 未配置时使用默认xdb文件路径
+     */
+    pub xdb: Option<XdbConfig>,
+
+    /**
+     * 事件通道配置
+     * 未配置时所有通道使用默认容量
+     */
+    pub events: Option<EventConfig>,
+
+    /**
+     * 操作日志配置
+     * 未配置时不清理操作日志
+     */
+    pub operation_log: Option<OperationLogConfig>,
+
+    /**
+     * 邮件配置
+     * 未配置时不发送邮件通知
+     */
+    pub email: Option<EmailConfig>,
+
+    /**
+     * 可信代理配置
+     * 未配置时忽略转发请求头，使用连接对端地址作为客户端IP
+     */
+    pub trusted_proxy: Option<TrustedProxyConfig>,
+
+    /**
+     * 登录页配置
+     * 未配置时登录页域列表包含内置域
+     */
+    pub login: Option<LoginConfig>,
+
+    /**
+     * 初始管理员配置
+     * 未配置时启动时不创建管理员账号
+     */
+    pub bootstrap: Option<BootstrapConfig>,
+
+    /**
+     * 接口同步配置
+     * 未配置时启动时自动同步接口表
+     */
+    pub endpoint_sync: Option<EndpointSyncConfig>,
+
+    /**
+     * 安全配置
+     * 未配置时使用 Argon2 默认参数哈希密码
+     */
+    pub security: Option<SecurityConfig>,
+
+    /**
+     * 时间配置
+     * 未配置时按UTC展示时间，已有数据视为按UTC写入
+     */
+    pub time: Option<TimeConfig>,
+
+    /**
+     * HTTP配置
+     * 未配置时按默认参数启用响应压缩和请求解压
+     */
+    pub http: Option<HttpConfig>,
+
+    /**
+     * 菜单配置
+     * 未配置时组件只允许 `layout.*` 和 `view.*`
+     */
+    pub menu: Option<MenuConfig>,
 }
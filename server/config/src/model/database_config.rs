@@ -56,6 +56,37 @@ pub struct DatabaseConfig {
      * 连接在连接池中的最大存活时间
      */
     pub max_lifetime: Option<u64>,
+
+    /**
+     * 语句超时时间（毫秒）
+     * 
+     * 仅对PostgreSQL生效，超时的语句由数据库取消，未配置时不限制
+     */
+    pub statement_timeout_ms: Option<u64>,
+
+    /**
+     * 慢查询阈值（毫秒）
+     * 
+     * 执行时间超过该值的查询记录日志，默认1000
+     */
+    pub slow_query_threshold_ms: Option<u64>,
+
+    /**
+     * 故障转移连接URL
+     * 
+     * 仅对主库生效。配置后健康检查连续失败达到 `failover_threshold` 次时，
+     * 自动切换到该地址，其余连接参数沿用当前配置
+     */
+    #[serde(default)]
+    pub failover_url: Option<String>,
+
+    /**
+     * 触发故障转移的连续健康检查失败次数
+     * 
+     * 默认3
+     */
+    #[serde(default)]
+    pub failover_threshold: Option<u32>,
 }
 
 /**
@@ -73,6 +104,13 @@ pub struct DatabasesInstancesConfig {
      * 数据库配置
      */
     pub database: DatabaseConfig,
+    /**
+     * 作为只读副本所属的主库
+     * 
+     * 设置为 `primary` 时该实例作为主库的只读副本参与读请求分流
+     */
+    #[serde(default)]
+    pub replica_of: Option<String>,
 }
 
 /**
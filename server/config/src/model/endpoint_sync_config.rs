@@ -0,0 +1,46 @@
+/**
+ * 接口同步配置模块
+ * 
+ * 定义了启动时同步接口表的方式
+ */
+
+use serde::Deserialize;
+
+/**
+ * 接口同步配置结构体
+ * 
+ * 控制启动时是否自动把收集到的路由写入接口表。
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct EndpointSyncConfig {
+    /**
+     * 同步方式
+     * 
+     * 默认自动同步
+     */
+    #[serde(default)]
+    pub mode: EndpointSyncMode,
+}
+
+/**
+ * 接口同步方式枚举
+ */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum EndpointSyncMode {
+    /**
+     * 自动同步
+     * 
+     * 启动时直接按收集到的路由新增、更新和删除接口
+     */
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+
+    /**
+     * 手动同步
+     * 
+     * 启动时只记录同步预览，由管理员通过 `POST /endpoint/sync-apply` 执行
+     */
+    #[serde(rename = "manual")]
+    Manual,
+}
@@ -0,0 +1,58 @@
+/**
+ * 事件通道配置模块
+ * 
+ * 定义了事件通道队列容量的相关参数
+ */
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/**
+ * 事件通道配置结构体
+ * 
+ * 按通道名称配置队列容量，未单独配置的通道使用默认容量。
+ * 通道名称与系统事件名称一致（如 `audit_operation_logged_event`），
+ * 字符串事件通道名称为 `string_event`。
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct EventConfig {
+    /**
+     * 默认队列容量
+     * 
+     * 默认1024
+     */
+    #[serde(default = "default_capacity")]
+    pub default_capacity: usize,
+
+    /**
+     * 按通道名称配置的队列容量
+     */
+    #[serde(default)]
+    pub capacities: HashMap<String, usize>,
+}
+
+impl EventConfig {
+    /**
+     * 获取指定通道的队列容量
+     */
+    pub fn capacity(&self, name: &str) -> usize {
+        self.capacities
+            .get(name)
+            .copied()
+            .unwrap_or(self.default_capacity)
+    }
+}
+
+impl Default for EventConfig {
+    fn default() -> Self {
+        Self {
+            default_capacity: default_capacity(),
+            capacities: HashMap::new(),
+        }
+    }
+}
+
+fn default_capacity() -> usize {
+    1024
+}
@@ -0,0 +1,262 @@
+/**
+ * HTTP配置模块
+ *
+ * 定义了HTTP层的通用参数，目前包括响应压缩、请求解压、JSON请求体的未知字段检查和请求超时
+ */
+
+use std::{collections::HashMap, time::Duration};
+
+use serde::Deserialize;
+
+/**
+ * HTTP配置结构体
+ *
+ * 所有字段均可省略，省略时使用默认值。
+ *
+ * 配置示例（YAML）：
+ * ```yaml
+ * http:
+ *     compression:
+ *         enabled: true
+ *         min_size: 1024
+ *         algorithms:
+ *             - gzip
+ *             - br
+ *         exclude_paths:
+ *             - /metrics
+ *     deny_unknown_fields: false
+ *     timeout:
+ *         default_secs: 30
+ *         groups:
+ *             auth: 10
+ *         exclude_paths:
+ *             - /api/v1/file
+ *         dependency_ms: 3000
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct HttpConfig {
+    /**
+     * 压缩配置
+     *
+     * 按请求的 Accept-Encoding 压缩响应，并按 Content-Encoding 解压请求体
+     */
+    pub compression: CompressionConfig,
+
+    /**
+     * 是否拒绝JSON请求体中的未知字段
+     *
+     * 开启后请求体包含输入类型没有的字段时返回422并列出这些字段，
+     * 便于发现前端字段名拼写错误；默认关闭，未知字段被忽略
+     */
+    pub deny_unknown_fields: bool,
+
+    /**
+     * 超时配置
+     *
+     * 按路由组限制请求的处理时间，并限制Redis等内部依赖调用的等待时间
+     */
+    pub timeout: TimeoutConfig,
+}
+
+/**
+ * 压缩配置结构体
+ */
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CompressionConfig {
+    /**
+     * 是否启用
+     *
+     * 默认启用，关闭后既不压缩响应也不解压请求体
+     */
+    pub enabled: bool,
+
+    /**
+     * 压缩的最小响应大小（字节）
+     *
+     * 小于该大小的响应压缩收益有限，直接返回原文，默认 1024
+     */
+    pub min_size: u16,
+
+    /**
+     * 启用的压缩算法
+     *
+     * 同时用于响应压缩和请求解压，默认 gzip 和 br
+     */
+    pub algorithms: Vec<CompressionAlgorithm>,
+
+    /**
+     * 不压缩响应的路径前缀
+     *
+     * 指标采集、文件代理等流式接口逐块输出，压缩会缓冲数据，应列在这里；
+     * `text/event-stream` 和 `application/octet-stream` 响应始终不压缩
+     */
+    pub exclude_paths: Vec<String>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_size: 1024,
+            algorithms: vec![CompressionAlgorithm::Gzip, CompressionAlgorithm::Br],
+            exclude_paths: Vec::new(),
+        }
+    }
+}
+
+impl CompressionConfig {
+    /**
+     * 是否启用指定的压缩算法
+     *
+     * # 参数
+     * * `algorithm` - 压缩算法
+     */
+    pub fn supports(&self, algorithm: CompressionAlgorithm) -> bool {
+        self.algorithms.contains(&algorithm)
+    }
+
+    /**
+     * 判断路径是否不压缩响应
+     *
+     * # 参数
+     * * `path` - 请求路径
+     */
+    pub fn is_excluded(&self, path: &str) -> bool {
+        self.exclude_paths.iter().any(|prefix| path.starts_with(prefix.as_str()))
+    }
+}
+
+/**
+ * 超时配置结构体
+ */
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct TimeoutConfig {
+    /**
+     * 是否启用请求超时
+     *
+     * 默认启用；关闭后请求不受处理时间限制，内部依赖调用的超时仍然生效
+     */
+    pub enabled: bool,
+
+    /**
+     * 默认的请求处理时间上限（秒）
+     *
+     * 未在 `groups` 中配置的路由组使用该值，默认 30
+     */
+    pub default_secs: u64,
+
+    /**
+     * 各路由组的请求处理时间上限（秒）
+     *
+     * 键为路由组名称（与速率限制的路由组一致，如 auth），0 表示不限制；
+     * 默认登录、注册所在的 auth 组为 10 秒
+     */
+    pub groups: HashMap<String, u64>,
+
+    /**
+     * 不限制处理时间的路径前缀
+     *
+     * 文件代理、下载等长时间流式输出的接口应列在这里
+     */
+    pub exclude_paths: Vec<String>,
+
+    /**
+     * 内部依赖调用的等待上限（毫秒）
+     *
+     * Redis nonce 存储、IP归属地查询等调用超过该时间时放弃等待，默认 3000
+     */
+    pub dependency_ms: u64,
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_secs: 30,
+            groups: HashMap::from([("auth".to_string(), 10)]),
+            exclude_paths: Vec::new(),
+            dependency_ms: 3000,
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /**
+     * 获取路由组的请求处理时间上限
+     *
+     * # 参数
+     * * `group` - 路由组名称
+     *
+     * # 返回
+     * 未启用或该组配置为 0 时返回None
+     */
+    pub fn budget(&self, group: &str) -> Option<Duration> {
+        if !self.enabled {
+            return None;
+        }
+        let secs = self.groups.get(group).copied().unwrap_or(self.default_secs);
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+
+    /**
+     * 内部依赖调用的等待上限
+     */
+    pub fn dependency_timeout(&self) -> Duration {
+        Duration::from_millis(self.dependency_ms)
+    }
+}
+
+/**
+ * 压缩算法
+ */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionAlgorithm {
+    /** gzip */
+    Gzip,
+
+    /** Brotli */
+    Br,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_config_defaults() {
+        let config: HttpConfig = serde_yaml::from_str("compression:\n    min_size: 2048\n").unwrap();
+        assert!(!config.deny_unknown_fields);
+        let compression = config.compression;
+        assert!(compression.enabled);
+        assert_eq!(compression.min_size, 2048);
+        assert!(compression.supports(CompressionAlgorithm::Gzip));
+        assert!(compression.supports(CompressionAlgorithm::Br));
+
+        let config: CompressionConfig =
+            serde_yaml::from_str("algorithms: [gzip]\nexclude_paths: [/metrics]\n").unwrap();
+        assert!(!config.supports(CompressionAlgorithm::Br));
+        assert!(config.is_excluded("/metrics/prometheus"));
+        assert!(!config.is_excluded("/api/v1/menu/tree"));
+    }
+
+    #[test]
+    fn test_timeout_config_budgets() {
+        let config = HttpConfig::default().timeout;
+        assert_eq!(config.budget("default"), Some(Duration::from_secs(30)));
+        assert_eq!(config.budget("auth"), Some(Duration::from_secs(10)));
+        assert_eq!(config.dependency_timeout(), Duration::from_secs(3));
+
+        let config: TimeoutConfig =
+            serde_yaml::from_str("default_secs: 60\ngroups:\n    sandbox: 0\n").unwrap();
+        assert_eq!(config.budget("auth"), Some(Duration::from_secs(60)));
+        assert_eq!(config.budget("sandbox"), None);
+
+        let config: TimeoutConfig = serde_yaml::from_str("enabled: false\n").unwrap();
+        assert_eq!(config.budget("default"), None);
+    }
+}
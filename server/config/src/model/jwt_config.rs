@@ -13,6 +13,9 @@ use serde::Deserialize;
  * - 密钥配置
  * - 令牌过期时间
  * - 刷新令牌配置
+ * - 按域覆盖的过期时间和受众
+ * - 请求时的用户状态复查
+ * - 令牌声明的大小上限
  */
 #[derive(Deserialize, Debug, Clone)]
 pub struct JwtConfig {
@@ -53,4 +56,159 @@ pub struct JwtConfig {
      * JWT令牌的目标接收者，通常为应用程序名称或域名
      */
     pub audience: String,
+
+    /**
+     * 按域覆盖的令牌配置
+     * 
+     * 用户所属域命中时使用覆盖值签发令牌，未配置的字段回退到全局配置。
+     * 修改后只影响新签发的令牌，已签发令牌的过期时间写在令牌中不受影响。
+     */
+    #[serde(default)]
+    pub domains: Vec<JwtDomainOverride>,
+
+    /**
+     * 是否在每次请求时复查用户和域状态
+     * 
+     * 开启后JWT认证中间件在令牌有效的基础上再查询一次用户和所属域，
+     * 用户或域被禁用后已签发的令牌立即失效，代价是每个请求多一次数据库查询。
+     * 默认为 `false`
+     */
+    #[serde(default)]
+    pub strict_status_check: bool,
+
+    /**
+     * 令牌声明序列化后的最大字节数
+     * 
+     * 角色较多导致声明超过上限时，令牌中只保存角色引用，角色列表存入Redis，
+     * 认证中间件按引用取回。默认为 4096
+     */
+    #[serde(default = "default_max_claims_bytes")]
+    pub max_claims_bytes: usize,
+}
+
+fn default_max_claims_bytes() -> usize {
+    4096
+}
+
+/**
+ * 按域覆盖的JWT配置
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct JwtDomainOverride {
+    /**
+     * 域代码
+     */
+    pub code: String,
+
+    /**
+     * 访问令牌过期时间（秒）
+     */
+    pub expire_secs: Option<u64>,
+
+    /**
+     * 刷新令牌过期时间（秒）
+     */
+    pub refresh_expire_secs: Option<u64>,
+
+    /**
+     * 令牌接收者
+     * 
+     * 配置后该域签发的令牌使用此受众，验证时与路由受众一并接受
+     */
+    pub audience: Option<String>,
+}
+
+impl JwtConfig {
+    /**
+     * 查找域的覆盖配置
+     */
+    pub fn domain_override(&self, domain: &str) -> Option<&JwtDomainOverride> {
+        self.domains.iter().find(|item| item.code == domain)
+    }
+
+    /**
+     * 获取域的访问令牌过期时间（秒）
+     */
+    pub fn access_token_expire_for(&self, domain: &str) -> u64 {
+        self.domain_override(domain)
+            .and_then(|item| item.expire_secs)
+            .unwrap_or(self.access_token_expire)
+    }
+
+    /**
+     * 获取域的刷新令牌过期时间（秒）
+     */
+    pub fn refresh_token_expire_for(&self, domain: &str) -> u64 {
+        self.domain_override(domain)
+            .and_then(|item| item.refresh_expire_secs)
+            .unwrap_or(self.refresh_token_expire)
+    }
+
+    /**
+     * 获取域覆盖的令牌接收者，未覆盖时返回None
+     */
+    pub fn audience_for(&self, domain: &str) -> Option<&str> {
+        self.domain_override(domain).and_then(|item| item.audience.as_deref())
+    }
+
+    /**
+     * 所有按域覆盖的令牌接收者
+     */
+    pub fn domain_audiences(&self) -> impl Iterator<Item = &str> {
+        self.domains.iter().filter_map(|item| item.audience.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> JwtConfig {
+        JwtConfig {
+            secret: "secret".to_string(),
+            access_token_expire: 7200,
+            refresh_token_expire: 604800,
+            issuer: "issuer".to_string(),
+            audience: "audience".to_string(),
+            domains: vec![
+                JwtDomainOverride {
+                    code: "tenant-a".to_string(),
+                    expire_secs: Some(900),
+                    refresh_expire_secs: None,
+                    audience: Some("tenant-a-portal".to_string()),
+                },
+                JwtDomainOverride {
+                    code: "staff".to_string(),
+                    expire_secs: Some(28800),
+                    refresh_expire_secs: Some(86400),
+                    audience: None,
+                },
+            ],
+            strict_status_check: false,
+            max_claims_bytes: 4096,
+        }
+    }
+
+    #[test]
+    fn test_domain_override_falls_back_per_field() {
+        let config = config();
+
+        assert_eq!(config.access_token_expire_for("tenant-a"), 900);
+        assert_eq!(config.refresh_token_expire_for("tenant-a"), 604800);
+        assert_eq!(config.audience_for("tenant-a"), Some("tenant-a-portal"));
+
+        assert_eq!(config.access_token_expire_for("staff"), 28800);
+        assert_eq!(config.refresh_token_expire_for("staff"), 86400);
+        assert_eq!(config.audience_for("staff"), None);
+
+        assert_eq!(config.access_token_expire_for("tenant-b"), 7200);
+        assert_eq!(config.refresh_token_expire_for("tenant-b"), 604800);
+        assert_eq!(config.audience_for("tenant-b"), None);
+    }
+
+    #[test]
+    fn test_domain_audiences() {
+        let audiences: Vec<&str> = config().domain_audiences().collect();
+        assert_eq!(audiences, vec!["tenant-a-portal"]);
+    }
 }
@@ -0,0 +1,24 @@
+/**
+ * 登录页配置模块
+ * 
+ * 定义了登录页公开接口的相关参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * 登录页配置结构体
+ * 
+ * 控制登录页域下拉列表返回的内容。
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct LoginConfig {
+    /**
+     * 是否在登录页域列表中隐藏内置域
+     * 
+     * 启用后 `GET /auth/domains` 不返回 `built-in` 域
+     * 默认关闭
+     */
+    #[serde(default)]
+    pub hide_built_in_domain: bool,
+}
@@ -0,0 +1,39 @@
+/**
+ * 菜单配置模块
+ * 
+ * 定义了创建和更新菜单时的校验参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * 菜单配置结构体
+ * 
+ * 配置示例（YAML）：
+ * ```yaml
+ * menu:
+ *     component_patterns:
+ *         - layout.*
+ *         - view.*
+ *         - iframe-page
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MenuConfig {
+    /**
+     * 允许的组件模式
+     * 
+     * `*` 匹配任意字符，如 `view.*`；组件不匹配任何模式时拒绝保存菜单，
+     * 默认 `layout.*` 和 `view.*`
+     */
+    pub component_patterns: Vec<String>,
+}
+
+impl Default for MenuConfig {
+    fn default() -> Self {
+        Self {
+            component_patterns: vec!["layout.*".to_string(), "view.*".to_string()],
+        }
+    }
+}
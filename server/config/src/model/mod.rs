@@ -18,6 +18,20 @@
  */
 pub use config::Config;
 
+/**
+ * 重新导出初始管理员配置
+ * 
+ * 用于启动时创建超级管理员账号
+ */
+pub use bootstrap_config::BootstrapConfig;
+
+/**
+ * 重新导出Casbin配置
+ * 
+ * 用于指定RBAC模型文件路径
+ */
+pub use casbin_config::CasbinConfig;
+
 /**
  * 重新导出数据库相关配置
  * 
@@ -25,12 +39,76 @@ pub use config::Config;
  */
 pub use database_config::{DatabaseConfig, DatabasesInstancesConfig};
 
+/**
+ * 重新导出接口同步配置
+ * 
+ * 用于控制启动时自动或手动同步接口表
+ */
+pub use endpoint_sync_config::{EndpointSyncConfig, EndpointSyncMode};
+
+/**
+ * 重新导出邮件配置
+ * 
+ * 用于配置SMTP邮件发送服务
+ */
+pub use email_config::{EmailConfig, EmailTlsMode};
+
+/**
+ * 重新导出事件通道配置
+ * 
+ * 用于按通道配置事件队列容量
+ */
+pub use event_config::EventConfig;
+
+/**
+ * 重新导出HTTP配置
+ * 
+ * 用于配置响应压缩和请求解压
+ */
+pub use http_config::{CompressionAlgorithm, CompressionConfig, HttpConfig, TimeoutConfig};
+
 /**
  * 重新导出JWT认证配置
  * 
  * 用于配置JWT令牌的生成和验证
  */
-pub use jwt_config::JwtConfig;
+pub use jwt_config::{JwtConfig, JwtDomainOverride};
+
+/**
+ * 重新导出登录页配置
+ * 
+ * 用于控制登录页域列表的内容
+ */
+pub use login_config::LoginConfig;
+
+/**
+ * 重新导出菜单配置
+ * 
+ * 用于配置菜单组件的允许模式
+ */
+pub use menu_config::MenuConfig;
+
+/**
+ * 重新导出操作日志配置
+ * 
+ * 用于配置操作日志的保留天数和归档
+ */
+pub use operation_log_config::OperationLogConfig;
+
+/**
+ * 重新导出分页配置
+ * 
+ * 用于控制分页查询的默认每页数量和上限
+ */
+pub use pagination_config::{OversizePolicy, PaginationConfig};
+
+/**
+ * 重新导出速率限制配置
+ * 
+ * 用于按路由组配置令牌桶限流
+ * 支持内存和Redis存储
+ */
+pub use rate_limit_config::{LimitConfig, LimitStorage, RateLimitConfig};
 
 /**
  * 重新导出Redis相关配置
@@ -40,13 +118,27 @@ pub use jwt_config::JwtConfig;
  */
 pub use redis_config::{RedisConfig, RedisInstancesConfig, RedisMode};
 
+/**
+ * 重新导出沙箱配置
+ * 
+ * 用于控制沙箱接口和测试密钥
+ */
+pub use sandbox_config::{SandboxAccessKey, SandboxConfig};
+
 /**
  * 重新导出S3存储相关配置
  * 
  * 用于配置对象存储服务
  * 支持多个存储实例
  */
-pub use s3_config::{S3Config, S3InstancesConfig};
+pub use s3_config::{S3Config, S3InstancesConfig, S3Provider};
+
+/**
+ * 重新导出安全配置
+ * 
+ * 用于配置密码哈希参数等安全相关参数
+ */
+pub use security_config::{PasswordHashConfig, SecurityConfig, UnusualLoginConfig};
 
 /**
  * 重新导出服务器配置
@@ -56,6 +148,27 @@ pub use s3_config::{S3Config, S3InstancesConfig};
  */
 pub use server_config::ServerConfig;
 
+/**
+ * 重新导出时间配置
+ * 
+ * 用于配置时间戳的展示时区和升级前数据的时区
+ */
+pub use time_config::TimeConfig;
+
+/**
+ * 重新导出可信代理配置
+ * 
+ * 用于控制客户端IP解析时信任的转发请求头
+ */
+pub use trusted_proxy_config::{ForwardedHeader, TrustedProxyConfig};
+
+/**
+ * 重新导出IP地址解析数据库配置
+ * 
+ * 用于指定ip2region xdb文件路径
+ */
+pub use xdb_config::XdbConfig;
+
 /**
  * 可选配置集合的包装类
  * 
@@ -116,6 +229,20 @@ impl<T> From<Option<Vec<T>>> for OptionalConfigs<T> {
  */
 pub mod config;
 
+/**
+ * 初始管理员配置模块
+ * 
+ * 定义启动时创建的管理员账号参数
+ */
+pub mod bootstrap_config;
+
+/**
+ * Casbin配置模块
+ * 
+ * 定义RBAC模型文件的加载参数
+ */
+pub mod casbin_config;
+
 /**
  * 数据库配置模块
  * 
@@ -124,6 +251,34 @@ pub mod config;
  */
 pub mod database_config;
 
+/**
+ * 邮件配置模块
+ * 
+ * 定义SMTP服务器、发件人和重试参数
+ */
+pub mod email_config;
+
+/**
+ * 接口同步配置模块
+ * 
+ * 定义启动时同步接口表的方式
+ */
+pub mod endpoint_sync_config;
+
+/**
+ * 事件通道配置模块
+ * 
+ * 定义事件队列容量参数
+ */
+pub mod event_config;
+
+/**
+ * HTTP配置模块
+ * 
+ * 定义响应压缩、请求解压等HTTP层参数
+ */
+pub mod http_config;
+
 /**
  * JWT认证配置模块
  * 
@@ -132,6 +287,42 @@ pub mod database_config;
  */
 pub mod jwt_config;
 
+/**
+ * 登录页配置模块
+ * 
+ * 定义登录页公开接口的参数
+ */
+pub mod login_config;
+
+/**
+ * 菜单配置模块
+ * 
+ * 定义菜单保存时的校验参数
+ */
+pub mod menu_config;
+
+/**
+ * 操作日志配置模块
+ * 
+ * 定义操作日志的保留和归档参数
+ */
+pub mod operation_log_config;
+
+/**
+ * 分页配置模块
+ * 
+ * 定义分页查询每页数量的默认值和上限
+ */
+pub mod pagination_config;
+
+/**
+ * 速率限制配置模块
+ * 
+ * 定义按路由组的令牌桶限流参数
+ * 包括时间窗口、请求数和突发容量
+ */
+pub mod rate_limit_config;
+
 /**
  * Redis配置模块
  * 
@@ -140,6 +331,14 @@ pub mod jwt_config;
  */
 pub mod redis_config;
 
+/**
+ * 沙箱配置模块
+ * 
+ * 定义沙箱测试接口的配置参数
+ * 包括签名调试开关
+ */
+pub mod sandbox_config;
+
 /**
  * S3存储配置模块
  * 
@@ -148,6 +347,13 @@ pub mod redis_config;
  */
 pub mod s3_config;
 
+/**
+ * 安全配置模块
+ * 
+ * 定义密码哈希、密码策略等安全相关参数
+ */
+pub mod security_config;
+
 /**
  * 服务器配置模块
  * 
@@ -155,3 +361,24 @@ pub mod s3_config;
  * 包括主机地址和端口号
  */
 pub mod server_config;
+
+/**
+ * 时间配置模块
+ * 
+ * 定义时间戳展示时区和升级前数据的时区偏移
+ */
+pub mod time_config;
+
+/**
+ * 可信代理配置模块
+ * 
+ * 定义可信代理网段和转发请求头类型
+ */
+pub mod trusted_proxy_config;
+
+/**
+ * IP地址解析数据库配置模块
+ * 
+ * 定义ip2region xdb文件的加载参数
+ */
+pub mod xdb_config;
@@ -0,0 +1,98 @@
+/**
+ * 操作日志配置模块
+ * 
+ * 定义了操作日志批量写入、保留、归档和敏感字段脱敏的相关参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * 操作日志配置结构体
+ * 
+ * 配置保留天数后，后台任务每天删除超过保留期的操作日志；
+ * 开启归档时，删除前先以gzip压缩的NDJSON格式上传到主对象存储（S3存储桶或本地存储目录）。
+ * 记录请求参数、请求体和响应体前，将敏感字段的值替换为 `***`。
+ * 操作日志先在内存中缓冲，攒满一批或到达刷新间隔时用一条 INSERT 写入。
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct OperationLogConfig {
+    /**
+     * 保留天数
+     * 
+     * 未配置时不启动清理任务，操作日志永久保留
+     */
+    pub retention_days: Option<u32>,
+
+    /**
+     * 是否在删除前归档到S3
+     * 
+     * 需同时配置主S3，默认关闭
+     */
+    #[serde(default)]
+    pub archive_to_s3: bool,
+
+    /**
+     * 归档对象键前缀
+     * 
+     * 默认为 `operation-log`
+     */
+    #[serde(default = "default_archive_prefix")]
+    pub archive_prefix: String,
+
+    /**
+     * 需要脱敏的JSON字段名
+     * 
+     * 在任意层级的对象和数组中匹配，忽略大小写和下划线，
+     * 因此 `old_password` 同时匹配 `oldPassword`
+     */
+    #[serde(default = "default_redact_fields")]
+    pub redact_fields: Vec<String>,
+
+    /**
+     * 单批写入的最大条数
+     * 
+     * 缓冲的日志达到该数量时立即写入，默认100
+     */
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /**
+     * 缓冲区刷新间隔（毫秒）
+     * 
+     * 未攒满一批的日志最多等待该时间后写入，默认1000
+     */
+    #[serde(default = "default_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+impl Default for OperationLogConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: None,
+            archive_to_s3: false,
+            archive_prefix: default_archive_prefix(),
+            redact_fields: default_redact_fields(),
+            batch_size: default_batch_size(),
+            flush_interval_ms: default_flush_interval_ms(),
+        }
+    }
+}
+
+fn default_archive_prefix() -> String {
+    "operation-log".to_string()
+}
+
+fn default_redact_fields() -> Vec<String> {
+    ["password", "old_password", "new_password", "secret", "access_key_secret", "token"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn default_batch_size() -> usize {
+    100
+}
+
+fn default_flush_interval_ms() -> u64 {
+    1000
+}
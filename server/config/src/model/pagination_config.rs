@@ -0,0 +1,87 @@
+/**
+ * 分页配置模块
+ *
+ * 定义了分页查询的默认每页数量和每页数量上限
+ */
+
+use serde::Deserialize;
+
+/**
+ * 分页配置结构体
+ *
+ * 所有分页查询的 `size` 参数统一按该配置处理，
+ * 防止客户端一次请求过多数据。
+ *
+ * 配置示例（YAML）：
+ * ```yaml
+ * server:
+ *     pagination:
+ *         default_size: 10
+ *         max_size: 100
+ *         oversize: reject
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct PaginationConfig {
+    /**
+     * 默认每页数量
+     *
+     * 请求未携带 `size` 时使用，超过上限时按上限处理，默认10
+     */
+    #[serde(default = "default_size")]
+    pub default_size: u64,
+
+    /**
+     * 每页数量上限
+     *
+     * 默认100
+     */
+    #[serde(default = "default_max_size")]
+    pub max_size: u64,
+
+    /**
+     * 超过上限时的处理方式
+     *
+     * 默认拒绝请求
+     */
+    #[serde(default)]
+    pub oversize: OversizePolicy,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            default_size: default_size(),
+            max_size: default_max_size(),
+            oversize: OversizePolicy::default(),
+        }
+    }
+}
+
+/**
+ * 每页数量超过上限时的处理方式
+ */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OversizePolicy {
+    /**
+     * 拒绝请求
+     *
+     * 返回422，错误信息中包含允许的上限
+     */
+    #[default]
+    Reject,
+
+    /**
+     * 按上限查询
+     */
+    Clamp,
+}
+
+fn default_size() -> u64 {
+    10
+}
+
+fn default_max_size() -> u64 {
+    100
+}
@@ -1,175 +1,138 @@
 /**
  * 速率限制配置模块
- * 
+ *
  * 定义了API请求速率限制的相关参数
- * 用于防止API滥用和DoS攻击
+ * 用于防止API滥用（如脚本化的暴力登录）
  */
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /**
  * 速率限制配置结构体
- * 
- * 包含API请求速率限制所需的所有参数，包括：
- * - 全局限制
- * - IP限制
- * - 用户限制
- * - 自定义限制
+ *
+ * 按路由组配置令牌桶参数，包括：
+ * - 是否启用
+ * - 令牌桶存储类型
+ * - 各路由组的限制参数
+ *
+ * 配置示例（YAML）：
+ * ```yaml
+ * rate_limit:
+ *     enabled: true
+ *     storage: redis
+ *     groups:
+ *         auth:
+ *             window: 60
+ *             max_requests: 10
+ *             burst: 5
+ * ```
  */
 #[derive(Deserialize, Debug, Clone)]
 pub struct RateLimitConfig {
     /**
      * 是否启用速率限制
-     * 
+     *
      * 控制是否启用API请求速率限制功能
      * 建议在生产环境中启用
      */
+    #[serde(default)]
     pub enabled: bool,
 
     /**
-     * 全局请求限制
-     * 
-     * 所有请求共享的限制配置
-     * 用于限制整个API的总体请求速率
-     */
-    pub global: Option<LimitConfig>,
-
-    /**
-     * IP地址限制
-     * 
-     * 基于客户端IP地址的限制配置
-     * 用于防止单个IP地址的滥用
-     */
-    pub ip: Option<LimitConfig>,
-
-    /**
-     * 用户限制
-     * 
-     * 基于用户身份的限制配置
-     * 用于限制已认证用户的请求速率
+     * 限制存储类型
+     *
+     * 指定用于存储令牌桶的存储类型
+     * 支持内存存储和Redis存储，默认内存存储
      */
-    pub user: Option<LimitConfig>,
+    #[serde(default)]
+    pub storage: LimitStorage,
 
     /**
-     * 自定义限制规则
-     * 
-     * 针对特定路径或方法的自定义限制配置
-     * 可以设置不同的限制规则
+     * 路由组限制规则
+     *
+     * 键为路由组名称（如 auth、sandbox），值为该组的限制参数
+     * 未配置的路由组不做限制
      */
-    pub custom: Vec<CustomLimitConfig>,
+    #[serde(default)]
+    pub groups: HashMap<String, LimitConfig>,
+}
 
-    /**
-     * 限制存储类型
-     * 
-     * 指定用于存储限制计数器的存储类型
-     * 支持内存存储和Redis存储
-     */
-    pub storage: LimitStorage,
+impl RateLimitConfig {
+    /**
+     * 获取指定路由组的限制参数
+     *
+     * # 参数
+     * * `group` - 路由组名称
+     *
+     * # 返回
+     * 启用且配置了该路由组时返回限制参数，否则返回None
+     */
+    pub fn group(&self, group: &str) -> Option<&LimitConfig> {
+        if !self.enabled {
+            return None;
+        }
+        self.groups.get(group)
+    }
 }
 
 /**
  * 限制配置结构体
- * 
- * 定义了基本的速率限制参数
+ *
+ * 定义了单个令牌桶的参数
  */
 #[derive(Deserialize, Debug, Clone)]
 pub struct LimitConfig {
     /**
      * 时间窗口（秒）
-     * 
-     * 限制计数的时间窗口大小
-     * 例如：60表示每分钟的限制
+     *
+     * 令牌补充的时间窗口大小
+     * 例如：60表示每分钟补充 max_requests 个令牌
      */
     pub window: u32,
 
     /**
      * 最大请求数
-     * 
-     * 在时间窗口内允许的最大请求数
-     * 超过此限制的请求将被拒绝
+     *
+     * 在时间窗口内允许的请求数，即令牌补充速率
      */
     pub max_requests: u32,
 
-    /**
-     * 是否启用突发限制
-     * 
-     * 控制是否允许请求突发
-     * 启用后可以更好地处理流量峰值
-     */
-    pub burst: bool,
-
     /**
      * 突发请求数
-     * 
-     * 允许的突发请求数量
-     * 仅在启用突发限制时有效
+     *
+     * 令牌桶容量，允许短时间内连续发出的请求数量
+     * 未配置时等于 max_requests
      */
-    pub burst_size: Option<u32>,
-}
-
-/**
- * 自定义限制配置结构体
- * 
- * 定义了针对特定路径或方法的自定义限制规则
- */
-#[derive(Deserialize, Debug, Clone)]
-pub struct CustomLimitConfig {
-    /**
-     * 限制规则名称
-     * 
-     * 用于标识此限制规则的唯一名称
-     */
-    pub name: String,
-
-    /**
-     * 匹配的路径模式
-     * 
-     * 使用通配符匹配的URL路径
-     * 例如：
-     * - "/api/v1/*"
-     * - "/api/v1/users/*"
-     */
-    pub path_pattern: String,
-
-    /**
-     * 匹配的HTTP方法
-     * 
-     * 指定此规则适用的HTTP方法
-     * 例如：["GET", "POST"]
-     * 如果为None，则适用于所有方法
-     */
-    pub methods: Option<Vec<String>>,
-
-    /**
-     * 限制配置
-     * 
-     * 此规则的具体限制参数
-     */
-    pub limit: LimitConfig,
+    pub burst: Option<u32>,
 }
 
 /**
  * 限制存储类型枚举
- * 
- * 定义了用于存储限制计数器的存储类型
+ *
+ * 定义了用于存储令牌桶的存储类型
  */
-#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
 pub enum LimitStorage {
     /**
      * 内存存储
-     * 
-     * 使用内存存储限制计数器
+     *
+     * 使用内存存储令牌桶
      * 优点：快速、简单
      * 缺点：不适用于多实例部署
      */
+    #[default]
+    #[serde(rename = "memory")]
     Memory,
 
     /**
      * Redis存储
-     * 
-     * 使用Redis存储限制计数器
+     *
+     * 使用Redis存储令牌桶，多个副本共享同一份限制
      * 优点：支持分布式部署
      * 缺点：需要额外的Redis服务
      */
+    #[serde(rename = "redis")]
     Redis,
-} 
\ No newline at end of file
+}
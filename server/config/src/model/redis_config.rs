@@ -58,6 +58,30 @@ pub struct RedisConfig {
      * - 建议配置多个节点以提高可用性
      */
     pub urls: Option<Vec<String>>,
+
+    /**
+     * Redis键前缀中的应用名称
+     * 
+     * 键格式为 `{key_app}:{key_env}:...`，默认为 alion
+     */
+    #[serde(default = "default_key_app")]
+    pub key_app: String,
+
+    /**
+     * Redis键前缀中的环境名称
+     * 
+     * 多个环境共用同一个Redis时必须设置为不同的值，例如 dev、staging、prod，默认为 dev
+     */
+    #[serde(default = "default_key_env")]
+    pub key_env: String,
+}
+
+fn default_key_app() -> String {
+    "alion".to_string()
+}
+
+fn default_key_env() -> String {
+    "dev".to_string()
 }
 
 /**
@@ -1,7 +1,8 @@
 /**
  * S3配置模块
  * 
- * 定义了对象存储服务（如AWS S3、MinIO等）的连接参数
+ * 定义了对象存储服务（如AWS S3、MinIO等）的连接参数，
+ * 以及用于测试和隔离部署的本地文件系统存储
  */
 
 use serde::Deserialize;
@@ -14,9 +15,46 @@ use serde::Deserialize;
  * - 认证信息
  * - 存储桶配置
  * - 区域设置
+ * - 存储实现（S3或本地文件系统）
+ *
+ * 使用本地存储时只需配置 `provider: local`、`base_dir` 和 `signing_key`，其余字段可以省略
  */
 #[derive(Deserialize, Debug, Clone)]
 pub struct S3Config {
+    /**
+     * 存储实现
+     *
+     * 默认为 `aws`
+     */
+    #[serde(default)]
+    pub provider: S3Provider,
+
+    /**
+     * 本地存储的基础目录
+     *
+     * `provider` 为 `local` 时必填，目录不存在时自动创建
+     */
+    #[serde(default)]
+    pub base_dir: Option<String>,
+
+    /**
+     * 本地存储预签名链接的地址前缀
+     *
+     * 省略时使用本服务的文件下载接口 `/api/v1/storage/files`，
+     * 经反向代理对外提供服务时可配置为完整地址
+     */
+    #[serde(default)]
+    pub public_url: Option<String>,
+
+    /**
+     * 本地存储预签名链接的签名密钥
+     *
+     * `provider` 为 `local` 时必填，至少32字节。多个实例共享同一目录时配置相同的密钥，
+     * 服务重启后此前生成的链接仍然有效
+     */
+    #[serde(default)]
+    pub signing_key: Option<String>,
+
     /**
      * 服务端点URL
      * 
@@ -25,6 +63,7 @@ pub struct S3Config {
      * - AWS S3: https://s3.amazonaws.com
      * - MinIO: http://localhost:9000
      */
+    #[serde(default)]
     pub endpoint: String,
 
     /**
@@ -33,6 +72,7 @@ pub struct S3Config {
      * 用于认证的访问密钥ID
      * 需要具有适当的权限来访问存储桶
      */
+    #[serde(default)]
     pub access_key_id: String,
 
     /**
@@ -41,6 +81,7 @@ pub struct S3Config {
      * 与访问密钥ID配对的密钥密码
      * 用于签名请求
      */
+    #[serde(default)]
     pub access_key_secret: String,
 
     /**
@@ -49,6 +90,7 @@ pub struct S3Config {
      * 用于存储对象的容器名称
      * 需要预先创建并配置适当的访问权限
      */
+    #[serde(default)]
     pub bucket: String,
 
     /**
@@ -67,6 +109,7 @@ pub struct S3Config {
      * 控制是否使用HTTPS协议访问服务
      * 建议在生产环境中启用
      */
+    #[serde(default)]
     pub use_ssl: bool,
 
     /**
@@ -101,3 +144,21 @@ pub struct S3InstancesConfig {
      */
     pub s3: S3Config,
 }
+
+/**
+ * 对象存储实现
+ */
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum S3Provider {
+    /**
+     * AWS S3或兼容S3协议的服务（如MinIO）
+     */
+    #[default]
+    Aws,
+
+    /**
+     * 本地文件系统，预签名链接由本服务提供下载
+     */
+    Local,
+}
@@ -0,0 +1,69 @@
+/**
+ * 沙箱配置模块
+ *
+ * 定义了沙箱测试接口的相关参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * 沙箱配置结构体
+ *
+ * 控制沙箱测试接口是否注册以及测试使用的密钥。
+ * 未启用时不注册沙箱路由，也不向验证器添加任何测试密钥，生产环境应保持关闭。
+ *
+ * 配置示例（YAML）：
+ * ```yaml
+ * sandbox:
+ *     enabled: true
+ *     debug_enabled: true
+ *     api_keys:
+ *         - test-api-key
+ *     access_keys:
+ *         - access_key_id: test-access-key
+ *           secret: test-secret-key
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct SandboxConfig {
+    /**
+     * 是否启用沙箱接口
+     *
+     * 默认关闭，关闭时以下配置均不生效
+     */
+    #[serde(default)]
+    pub enabled: bool,
+
+    /**
+     * 是否启用签名调试接口
+     *
+     * 启用后 `POST /sandbox/sign-debug` 返回规范签名字符串和期望签名，
+     * 只为 `access_keys` 中的测试密钥计算签名
+     * 默认关闭
+     */
+    #[serde(default)]
+    pub debug_enabled: bool,
+
+    /**
+     * 简单验证使用的测试API密钥
+     */
+    #[serde(default)]
+    pub api_keys: Vec<String>,
+
+    /**
+     * 复杂验证使用的测试访问密钥
+     */
+    #[serde(default)]
+    pub access_keys: Vec<SandboxAccessKey>,
+}
+
+/**
+ * 沙箱测试访问密钥
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct SandboxAccessKey {
+    /** 访问密钥ID */
+    pub access_key_id: String,
+    /** 签名密钥 */
+    pub secret: String,
+}
@@ -2,7 +2,7 @@
  * 安全配置模块
  * 
  * 定义了应用程序安全相关的参数
- * 包括密码策略、会话管理、安全头部等配置
+ * 包括密码哈希参数、密码策略、会话管理、安全头部等配置
  */
 
 use serde::Deserialize;
@@ -11,13 +11,45 @@ use serde::Deserialize;
  * 安全配置结构体
  * 
  * 包含应用程序安全所需的所有参数，包括：
+ * - 密码哈希参数
+ * - 异常登录提醒
  * - 密码策略
  * - 会话管理
  * - 安全头部
  * - 其他安全设置
+ * 
+ * 所有字段均可省略，省略时使用默认值。
+ * 
+ * 配置示例（YAML）：
+ * ```yaml
+ * security:
+ *     password_hash:
+ *         memory_kib: 65536
+ *         iterations: 3
+ *         parallelism: 1
+ *     unusual_login:
+ *         enabled: true
+ *         history_size: 10
+ * ```
  */
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct SecurityConfig {
+    /**
+     * 密码哈希参数
+     * 
+     * 新密码使用 Argon2id 和该参数哈希；
+     * 已有哈希的参数低于该参数时，在用户下次登录成功后重新哈希
+     */
+    pub password_hash: PasswordHashConfig,
+
+    /**
+     * 异常登录提醒配置
+     * 
+     * 登录国家或设备在最近的登录记录中未出现过时记录安全事件并提醒用户
+     */
+    pub unusual_login: UnusualLoginConfig,
+
     /**
      * 密码策略配置
      * 
@@ -88,12 +120,135 @@ pub struct SecurityConfig {
     pub enable_audit_log: bool,
 }
 
+/**
+ * 密码哈希参数配置结构体
+ * 
+ * 默认值与 Argon2 的默认参数相同，提高参数会增加每次登录验证的耗时和内存占用
+ */
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHashConfig {
+    /**
+     * 内存开销（KiB）
+     * 
+     * 默认19456（19 MiB）
+     */
+    #[serde(default = "default_memory_kib")]
+    pub memory_kib: u32,
+
+    /**
+     * 迭代次数
+     * 
+     * 默认2
+     */
+    #[serde(default = "default_iterations")]
+    pub iterations: u32,
+
+    /**
+     * 并行度
+     * 
+     * 默认1
+     */
+    #[serde(default = "default_parallelism")]
+    pub parallelism: u32,
+}
+
+impl Default for PasswordHashConfig {
+    fn default() -> Self {
+        Self {
+            memory_kib: default_memory_kib(),
+            iterations: default_iterations(),
+            parallelism: default_parallelism(),
+        }
+    }
+}
+
+fn default_memory_kib() -> u32 {
+    19456
+}
+
+fn default_iterations() -> u32 {
+    2
+}
+
+fn default_parallelism() -> u32 {
+    1
+}
+
+/**
+ * 异常登录提醒配置结构体
+ * 
+ * 登录日志写入后，将本次登录的国家和用户代理指纹与该用户最近的登录记录比较，
+ * 任一项从未出现过时记录 `unusual_login` 安全事件；用户没有历史登录记录时不提醒
+ */
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UnusualLoginConfig {
+    /**
+     * 是否启用
+     * 
+     * 默认启用
+     */
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /**
+     * 参与比较的最近登录记录数（不含本次登录）
+     * 
+     * 默认10
+     */
+    #[serde(default = "default_history_size")]
+    pub history_size: u64,
+
+    /**
+     * 是否检查登录国家
+     * 
+     * 默认启用，无法解析国家的登录（如内网IP）不参与比较
+     */
+    #[serde(default = "default_true")]
+    pub check_location: bool,
+
+    /**
+     * 是否检查用户代理
+     * 
+     * 默认启用，比较时忽略版本号，浏览器升级不视为新设备
+     */
+    #[serde(default = "default_true")]
+    pub check_user_agent: bool,
+
+    /**
+     * 是否发送邮件提醒用户
+     * 
+     * 默认启用，仅在配置了邮件服务且用户设置了邮箱时发送
+     */
+    #[serde(default = "default_true")]
+    pub notify_user: bool,
+}
+
+impl Default for UnusualLoginConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            history_size: default_history_size(),
+            check_location: true,
+            check_user_agent: true,
+            notify_user: true,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_history_size() -> u64 {
+    10
+}
+
 /**
  * 密码策略配置结构体
  * 
  * 定义了用户密码的复杂度要求和有效期
  */
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct PasswordPolicy {
     /**
      * 最小密码长度
@@ -175,7 +330,7 @@ pub struct PasswordPolicy {
  * 
  * 定义了用户会话的管理策略
  */
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct SessionConfig {
     /**
      * 会话超时时间（分钟）
@@ -254,7 +409,7 @@ pub struct SessionConfig {
  * 
  * 定义了HTTP响应头中的安全相关头部
  */
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct SecurityHeaders {
     /**
      * 是否启用HSTS
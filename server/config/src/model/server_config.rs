@@ -1,11 +1,13 @@
 /**
  * 服务器配置模块
  * 
- * 定义了HTTP服务器的基本配置参数
+ * 定义了HTTP服务器及内部gRPC服务的基本配置参数
  */
 
 use serde::Deserialize;
 
+use super::pagination_config::PaginationConfig;
+
 /**
  * 服务器配置结构体
  * 
@@ -32,4 +34,39 @@ pub struct ServerConfig {
      * - 443 用于HTTPS生产环境
      */
     pub port: u32,
+
+    /**
+     * gRPC服务端口号
+     * 
+     * 仅在启用 `grpc` 特性时使用，未配置时不启动gRPC服务。
+     * 与HTTP服务使用相同的主机地址。
+     */
+    #[serde(default)]
+    pub grpc_port: Option<u32>,
+
+    /**
+     * 是否允许按请求返回 snake_case 字段名
+     * 
+     * 开启后请求携带 `X-Naming: snake_case` 时，JSON响应的字段名转换为 snake_case。
+     * 转换需要重新序列化响应体，默认关闭。
+     */
+    #[serde(default)]
+    pub snake_case_response: bool,
+
+    /**
+     * 是否同时响应未带版本前缀的旧路径
+     * 
+     * 开启后 `/api/...` 的请求在路由前改写为 `/api/v1/...`，响应携带 `Deprecation: true`，
+     * 供尚未迁移的客户端过渡使用，默认关闭。
+     */
+    #[serde(default)]
+    pub legacy_unversioned_routes: bool,
+
+    /**
+     * 分页配置
+     * 
+     * 控制所有分页查询的默认每页数量和每页数量上限
+     */
+    #[serde(default)]
+    pub pagination: PaginationConfig,
 }
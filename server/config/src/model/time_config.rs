@@ -0,0 +1,89 @@
+/**
+ * 时间配置模块
+ * 
+ * 定义了时间戳展示时区和升级前数据的时区参数
+ */
+
+use std::str::FromStr;
+
+use chrono::FixedOffset;
+use serde::{Deserialize, Deserializer};
+
+/**
+ * 时间配置结构体
+ * 
+ * 数据库中的时间戳统一按UTC写入，接口输出带 `Z` 后缀的RFC3339时间，由客户端转换为本地时间。
+ * 邮件正文等需要直接展示本地时间的场景按 `display_timezone` 格式化。
+ * 
+ * 配置示例（YAML）：
+ * ```yaml
+ * time:
+ *     display_timezone: "+08:00"
+ *     legacy_local_offset: "+08:00"
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone)]
+pub struct TimeConfig {
+    /**
+     * 展示时区的UTC偏移
+     * 
+     * 格式为 `+HH:MM` 或 `-HH:MM`，默认 `+00:00`
+     */
+    #[serde(default = "default_offset", deserialize_with = "deserialize_offset")]
+    pub display_timezone: FixedOffset,
+
+    /**
+     * 升级前数据写入时服务器所在时区的UTC偏移
+     * 
+     * 旧版本按服务器本地时间写入时间戳。配置后执行 `migrate up` 时，
+     * 将已有数据的时间戳减去该偏移转换为UTC，避免跨升级时间点的查询偏移数小时；
+     * 未配置时视为已有数据按UTC写入，不做转换。转换只在迁移首次执行时进行一次
+     */
+    #[serde(default, deserialize_with = "deserialize_optional_offset")]
+    pub legacy_local_offset: Option<FixedOffset>,
+}
+
+impl Default for TimeConfig {
+    fn default() -> Self {
+        Self { display_timezone: default_offset(), legacy_local_offset: None }
+    }
+}
+
+fn default_offset() -> FixedOffset {
+    FixedOffset::east_opt(0).expect("zero offset is valid")
+}
+
+fn deserialize_offset<'de, D>(deserializer: D) -> Result<FixedOffset, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    FixedOffset::from_str(&value).map_err(|_| {
+        serde::de::Error::custom(format!("invalid UTC offset '{}', expected +HH:MM", value))
+    })
+}
+
+fn deserialize_optional_offset<'de, D>(deserializer: D) -> Result<Option<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_offset(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_offsets() {
+        let config: TimeConfig = serde_yaml::from_str("display_timezone: \"+08:00\"").unwrap();
+        assert_eq!(config.display_timezone.local_minus_utc(), 8 * 3600);
+        assert!(config.legacy_local_offset.is_none());
+
+        let config: TimeConfig = serde_yaml::from_str("legacy_local_offset: \"-05:30\"").unwrap();
+        assert_eq!(config.display_timezone, default_offset());
+        assert_eq!(config.legacy_local_offset.unwrap().local_minus_utc(), -(5 * 3600 + 1800));
+
+        assert!(serde_yaml::from_str::<TimeConfig>("display_timezone: Asia/Shanghai").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+/**
+ * 可信代理配置模块
+ *
+ * 定义了客户端IP解析时信任的反向代理
+ * 用于防止客户端伪造 X-Forwarded-For 等请求头
+ */
+
+use ipnet::IpNet;
+use serde::Deserialize;
+
+/**
+ * 可信代理配置结构体
+ *
+ * 只有直接对端地址位于可信网段内时才读取转发请求头，
+ * 否则直接使用对端地址作为客户端IP。
+ *
+ * 配置示例（YAML）：
+ * ```yaml
+ * trusted_proxy:
+ *     enabled: true
+ *     trusted_proxies:
+ *         - 10.0.0.0/8
+ *         - 127.0.0.1/32
+ *     header: x_forwarded_for
+ * ```
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct TrustedProxyConfig {
+    /**
+     * 是否启用
+     *
+     * 未启用时忽略所有转发请求头，始终使用对端地址
+     */
+    #[serde(default)]
+    pub enabled: bool,
+
+    /**
+     * 可信代理网段
+     *
+     * CIDR格式，单个地址使用 /32 或 /128
+     */
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpNet>,
+
+    /**
+     * 读取客户端IP的请求头
+     *
+     * 默认 X-Forwarded-For
+     */
+    #[serde(default)]
+    pub header: ForwardedHeader,
+}
+
+impl TrustedProxyConfig {
+    /**
+     * 判断地址是否为可信代理
+     *
+     * # 参数
+     * * `ip` - 待判断的IP地址
+     *
+     * # 返回
+     * 启用且地址位于任一可信网段内时返回true
+     */
+    pub fn is_trusted(&self, ip: &std::net::IpAddr) -> bool {
+        self.enabled && self.trusted_proxies.iter().any(|net| net.contains(ip))
+    }
+}
+
+/**
+ * 转发请求头类型
+ */
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForwardedHeader {
+    /**
+     * X-Forwarded-For
+     *
+     * 从右向左跳过可信代理，取第一个不可信的地址
+     */
+    #[default]
+    XForwardedFor,
+
+    /**
+     * X-Real-IP
+     *
+     * 单个地址，由最近一层代理设置
+     */
+    XRealIp,
+
+    /**
+     * Forwarded（RFC 7239）
+     *
+     * 读取各元素的 for 参数，处理方式同 X-Forwarded-For
+     */
+    Forwarded,
+}
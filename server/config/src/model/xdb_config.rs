@@ -0,0 +1,23 @@
+/**
+ * IP地址解析数据库配置模块
+ * 
+ * 定义了ip2region xdb文件的加载参数
+ */
+
+use serde::Deserialize;
+
+/**
+ * IP地址解析数据库配置结构体
+ * 
+ * 指定ip2region xdb文件路径，未配置时使用默认路径。
+ */
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct XdbConfig {
+    /**
+     * xdb文件路径
+     * 
+     * 相对路径基于进程工作目录解析
+     */
+    #[serde(default)]
+    pub path: Option<String>,
+}
@@ -1,5 +1,12 @@
 use strum_macros::{AsRefStr, Display, EnumString};
 
+/**
+ * 系统操作人
+ * 
+ * 没有请求上下文的代码路径（迁移、事件处理器等）写入审计字段时使用
+ */
+pub const SYSTEM_ACTOR: &str = "system";
+
 /**
  * Token 状态枚举
  */
@@ -54,6 +61,10 @@ pub enum SystemEvent {
      * 用户认证登录事件
      */
     AuthLoggedInEvent,
+    /**
+     * 用户登录失败事件
+     */
+    AuthLoginFailedEvent,
     /**
      * 系统操作日志事件
      */
@@ -62,4 +73,20 @@ pub enum SystemEvent {
      * API密钥验证事件
      */
     AuthApiKeyValidatedEvent,
+    /**
+     * 邮件通知投递事件
+     */
+    NotificationEmailRequestedEvent,
+    /**
+     * 菜单分配变更事件
+     */
+    MenuAssignmentChangedEvent,
+    /**
+     * 安全审计事件
+     */
+    AuditSecurityEvent,
+    /**
+     * 合规审计导出事件
+     */
+    ComplianceExportRequestedEvent,
 }
@@ -5,6 +5,7 @@
  * - 签名验证：支持简单和复杂的API密钥验证机制
  * - Web服务：提供完整的Web服务功能，包括认证、JWT、分页等
  * - 宏定义：提供简化代码编写的通用宏
+ * - 任务调度：按间隔或 cron 表达式执行后台周期任务
  * 
  * # 模块结构
  * 
@@ -25,6 +26,13 @@
  * - validator: 请求输入验证
  * - operation_log: 操作日志记录
  * - request_id: 请求ID生成和追踪
+ * - i18n: 语言协商和本地化消息
+ * 
+ * ## scheduler 模块
+ * 提供后台周期任务调度：
+ * - 固定间隔和 cron 表达式计划
+ * - 随机抖动、防重叠执行和 panic 隔离
+ * - 任务执行状态查询和立即执行
  * 
  * ## macros 模块
  * 提供简化代码编写的宏：
@@ -57,6 +65,7 @@ pub mod sign;
  * - validator: 请求输入数据的验证
  * - operation_log: 操作日志的记录和查询
  * - request_id: 请求ID的生成和追踪
+ * - i18n: 语言协商和本地化消息
  */
 pub mod web;
 
@@ -71,6 +80,16 @@ pub mod web;
  */
 pub mod macros;
 
+/**
+ * 后台任务调度模块
+ * 
+ * 提供进程内的周期任务调度，包括：
+ * - 按固定间隔或 cron 表达式注册具名任务
+ * - 随机抖动、防重叠执行和 panic 隔离
+ * - 任务执行状态查询和立即执行
+ */
+pub mod scheduler;
+
 /**
  * 输入验证trait
  * 
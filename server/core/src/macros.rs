@@ -56,6 +56,7 @@ macro_rules! validated_struct {
  * 分页请求定义宏
  * 
  * 用于快速定义分页请求结构体，自动包含分页参数和关键词搜索字段。
+ * 调用方需引入 PageRequest 和 deserialize_keywords。
  * 
  * # 参数
  * * `$name` - 结构体名称
@@ -73,7 +74,7 @@ macro_rules! page_request {
         pub struct $name {
             #[serde(flatten)]
             pub page_details: PageRequest,
-            #[serde(default)]
+            #[serde(default, deserialize_with = "deserialize_keywords")]
             pub keywords: Option<String>,
         }
     };
@@ -83,7 +84,7 @@ macro_rules! page_request {
         pub struct $name {
             #[serde(flatten)]
             pub page_details: PageRequest,
-            #[serde(default)]
+            #[serde(default, deserialize_with = "deserialize_keywords")]
             pub keywords: Option<String>,
             $(
                 #[serde(default)]
@@ -130,7 +131,7 @@ macro_rules! update_input {
     ($name:ident, $id_ty:ty) => {
         #[derive(Deserialize, Validate)]
         pub struct Update$name {
-            #[validate(required(message = "ID is required"))]
+            #[validate(required(message = "validation.required"))]
             pub id: $id_ty,
             
             #[serde(flatten)]
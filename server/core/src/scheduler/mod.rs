@@ -0,0 +1,537 @@
+/**
+ * 后台任务调度模块
+ *
+ * 提供进程内的周期任务调度，包括：
+ * - 按固定间隔或 cron 表达式注册具名任务
+ * - 随机抖动，避免多个实例同时触发
+ * - 防重叠：上一次执行尚未结束时跳过本次触发
+ * - panic 隔离：任务 panic 只记录为该次执行失败，不影响调度器和其他任务
+ * - 记录每个任务的最近执行状态，并支持立即执行
+ *
+ * 调度状态保存在内存中，服务重启后清空；多实例部署时每个实例各自执行任务。
+ *
+ * # 使用示例
+ *
+ * ```rust,ignore
+ * let job = Job::new("cleanup", Schedule::every(Duration::from_secs(60)), || async {
+ *     cleanup().await.map_err(|e| e.to_string())
+ * })
+ * .with_jitter(Duration::from_secs(5));
+ * scheduler().register(job)?;
+ * scheduler().start();
+ * ```
+ */
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt::Display,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local, Utc};
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::Serialize;
+use server_global::{project_error, project_info};
+use thiserror::Error;
+
+pub use schedule::{CronExpr, Schedule};
+
+mod schedule;
+
+/** 全局调度器 */
+static SCHEDULER: Lazy<Scheduler> = Lazy::new(Scheduler::new);
+
+/**
+ * 获取全局调度器
+ */
+pub fn scheduler() -> &'static Scheduler {
+    &SCHEDULER
+}
+
+/**
+ * 调度器错误
+ */
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SchedulerError {
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+
+    #[error("Job '{0}' is already registered")]
+    DuplicateJob(String),
+
+    #[error("Job '{0}' not found")]
+    JobNotFound(String),
+
+    #[error("Job '{0}' is already running")]
+    JobRunning(String),
+}
+
+/** 任务函数 */
+type JobFn = Arc<dyn Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+/**
+ * 具名周期任务
+ */
+pub struct Job {
+    name: String,
+    schedule: Schedule,
+    jitter: Duration,
+    run_on_start: bool,
+    task: JobFn,
+}
+
+impl Job {
+    /**
+     * 创建任务
+     *
+     * # 参数
+     * * `name` - 任务名称，在调度器内唯一
+     * * `schedule` - 调度计划
+     * * `task` - 每次触发时调用，返回错误时记录为该次执行失败
+     */
+    pub fn new<F, Fut, E>(name: impl Into<String>, schedule: Schedule, task: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        let task: JobFn = Arc::new(move || {
+            let future = task();
+            Box::pin(async move { future.await.map_err(|e| e.to_string()) })
+        });
+        Self {
+            name: name.into(),
+            schedule,
+            jitter: Duration::ZERO,
+            run_on_start: false,
+            task,
+        }
+    }
+
+    /**
+     * 设置随机抖动
+     *
+     * 每次触发前额外等待 0 到 `jitter` 之间的随机时长
+     */
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /**
+     * 调度器启动后立即执行一次，之后按计划执行
+     */
+    pub fn run_on_start(mut self) -> Self {
+        self.run_on_start = true;
+        self
+    }
+}
+
+/**
+ * 任务执行状态
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatus {
+    /** 任务名称 */
+    pub name: String,
+    /** 调度计划描述，如 `every 30s`、`cron 30 3 * * *` */
+    pub schedule: String,
+    /** 是否正在执行 */
+    pub running: bool,
+    /** 最近一次开始执行的时间 */
+    pub last_started_at: Option<DateTime<Utc>>,
+    /** 最近一次执行结束的时间 */
+    pub last_finished_at: Option<DateTime<Utc>>,
+    /** 最近一次执行耗时（毫秒） */
+    pub last_duration_ms: Option<u64>,
+    /** 最近一次执行的错误，成功时为空 */
+    pub last_error: Option<String>,
+    /** 下一次计划触发的时间 */
+    pub next_run_at: Option<DateTime<Utc>>,
+    /** 累计执行次数 */
+    pub run_count: u64,
+    /** 累计失败次数 */
+    pub failure_count: u64,
+    /** 因上一次执行未结束而跳过的次数 */
+    pub skipped_count: u64,
+}
+
+/**
+ * 已注册的任务及其运行状态
+ */
+struct JobEntry {
+    job: Job,
+    running: AtomicBool,
+    status: Mutex<JobStatus>,
+}
+
+impl JobEntry {
+    fn new(job: Job) -> Self {
+        let status = JobStatus {
+            name: job.name.clone(),
+            schedule: job.schedule.to_string(),
+            running: false,
+            last_started_at: None,
+            last_finished_at: None,
+            last_duration_ms: None,
+            last_error: None,
+            next_run_at: None,
+            run_count: 0,
+            failure_count: 0,
+            skipped_count: 0,
+        };
+        Self {
+            job,
+            running: AtomicBool::new(false),
+            status: Mutex::new(status),
+        }
+    }
+
+    /**
+     * 尝试标记为执行中
+     *
+     * # 返回
+     * 上一次执行尚未结束时返回 false
+     */
+    fn try_acquire(&self) -> bool {
+        self.running.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire).is_ok()
+    }
+
+    /**
+     * 执行一次任务，调用前需已通过 `try_acquire` 标记为执行中
+     *
+     * 任务在独立的 tokio 任务中运行，panic 会被记录为执行错误
+     */
+    async fn execute(self: Arc<Self>) {
+        let started = Instant::now();
+        {
+            let mut status = self.status.lock();
+            status.running = true;
+            status.last_started_at = Some(Utc::now());
+        }
+
+        let error = match tokio::spawn((self.job.task)()).await {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e),
+            Err(e) if e.is_panic() => Some(format!("panicked: {}", panic_message(e.into_panic()))),
+            Err(e) => Some(e.to_string()),
+        };
+
+        if let Some(error) = &error {
+            project_error!("Job '{}' failed: {}", self.job.name, error);
+        }
+
+        {
+            let mut status = self.status.lock();
+            status.running = false;
+            status.last_finished_at = Some(Utc::now());
+            status.last_duration_ms = Some(started.elapsed().as_millis() as u64);
+            status.run_count += 1;
+            if error.is_some() {
+                status.failure_count += 1;
+            }
+            status.last_error = error;
+        }
+        self.running.store(false, Ordering::Release);
+    }
+}
+
+/**
+ * 后台任务调度器
+ */
+pub struct Scheduler {
+    jobs: RwLock<BTreeMap<String, Arc<JobEntry>>>,
+    started: AtomicBool,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    /**
+     * 创建空的调度器
+     */
+    pub fn new() -> Self {
+        Self {
+            jobs: RwLock::new(BTreeMap::new()),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /**
+     * 注册任务
+     *
+     * 调度器已启动时立即开始调度该任务
+     *
+     * # 返回
+     * 同名任务已存在时返回 `SchedulerError::DuplicateJob`
+     */
+    pub fn register(&self, job: Job) -> Result<(), SchedulerError> {
+        let mut jobs = self.jobs.write();
+        if jobs.contains_key(&job.name) {
+            return Err(SchedulerError::DuplicateJob(job.name));
+        }
+        let entry = Arc::new(JobEntry::new(job));
+        jobs.insert(entry.job.name.clone(), entry.clone());
+
+        // 持有写锁时检查启动状态，避免与 start 同时调度同一个任务
+        if self.started.load(Ordering::Acquire) {
+            spawn_job_loop(entry);
+        }
+        Ok(())
+    }
+
+    /**
+     * 启动调度，重复调用不会重复启动
+     */
+    pub fn start(&self) {
+        let jobs = self.jobs.write();
+        if self.started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        for entry in jobs.values() {
+            spawn_job_loop(entry.clone());
+        }
+    }
+
+    /**
+     * 立即执行任务
+     *
+     * 任务在后台执行，本方法不等待执行结束
+     *
+     * # 返回
+     * 任务不存在或正在执行时返回错误
+     */
+    pub fn run_now(&self, name: &str) -> Result<(), SchedulerError> {
+        let entry = self
+            .jobs
+            .read()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| SchedulerError::JobNotFound(name.to_string()))?;
+
+        if !entry.try_acquire() {
+            return Err(SchedulerError::JobRunning(name.to_string()));
+        }
+        project_info!("Job '{}' triggered manually", name);
+        tokio::spawn(entry.execute());
+        Ok(())
+    }
+
+    /**
+     * 查询所有任务的执行状态，按任务名称排序
+     */
+    pub fn statuses(&self) -> Vec<JobStatus> {
+        self.jobs.read().values().map(|entry| entry.status.lock().clone()).collect()
+    }
+
+    /**
+     * 查询单个任务的执行状态
+     */
+    pub fn status(&self, name: &str) -> Option<JobStatus> {
+        self.jobs.read().get(name).map(|entry| entry.status.lock().clone())
+    }
+}
+
+/**
+ * 按计划循环触发任务
+ */
+fn spawn_job_loop(entry: Arc<JobEntry>) {
+    project_info!("Job '{}' scheduled: {}", entry.job.name, entry.job.schedule);
+    tokio::spawn(async move {
+        if entry.job.run_on_start {
+            trigger(&entry);
+        }
+        loop {
+            // cron 表达式按服务器本地时间解释，状态中的时间使用 UTC
+            let now = Local::now().naive_local();
+            let Some(delay) = entry.job.schedule.next_delay(now) else {
+                project_error!("Job '{}' has no upcoming run, stop scheduling", entry.job.name);
+                entry.status.lock().next_run_at = None;
+                return;
+            };
+            let delay = delay + random_jitter(entry.job.jitter);
+            entry.status.lock().next_run_at =
+                chrono::Duration::from_std(delay).ok().map(|delay| Utc::now() + delay);
+
+            tokio::time::sleep(delay).await;
+            trigger(&entry);
+        }
+    });
+}
+
+/**
+ * 触发一次任务，上一次执行尚未结束时跳过
+ */
+fn trigger(entry: &Arc<JobEntry>) {
+    if !entry.try_acquire() {
+        project_info!("Job '{}' is still running, skipping this run", entry.job.name);
+        entry.status.lock().skipped_count += 1;
+        return;
+    }
+    tokio::spawn(entry.clone().execute());
+}
+
+/**
+ * 生成 0 到 `max` 之间的随机时长
+ */
+fn random_jitter(max: Duration) -> Duration {
+    let max_ms = max.as_millis() as u64;
+    if max_ms == 0 {
+        return Duration::ZERO;
+    }
+    let mut bytes = [0u8; 8];
+    if SystemRandom::new().fill(&mut bytes).is_err() {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(u64::from_le_bytes(bytes) % (max_ms + 1))
+}
+
+/**
+ * 提取 panic 信息
+ */
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+
+    use tokio::sync::Notify;
+
+    use super::*;
+
+    /**
+     * 等待任务累计执行指定次数
+     */
+    async fn wait_runs(scheduler: &Scheduler, name: &str, runs: u64) -> JobStatus {
+        for _ in 0..200 {
+            let status = scheduler.status(name).unwrap();
+            if !status.running && status.run_count >= runs {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("job '{}' did not finish", name);
+    }
+
+    fn hourly() -> Schedule {
+        Schedule::every(Duration::from_secs(3600))
+    }
+
+    async fn succeed() -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn explode() -> Result<(), String> {
+        panic!("job exploded")
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_duplicate_names() {
+        let scheduler = Scheduler::new();
+        scheduler.register(Job::new("job", hourly(), succeed)).unwrap();
+        let result = scheduler.register(Job::new("job", hourly(), succeed));
+        assert_eq!(result, Err(SchedulerError::DuplicateJob("job".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_run_now_records_status() {
+        let scheduler = Scheduler::new();
+        scheduler.register(Job::new("ok", hourly(), succeed)).unwrap();
+        scheduler
+            .register(Job::new("fail", hourly(), || async { Err::<(), _>("boom") }))
+            .unwrap();
+
+        assert_eq!(
+            scheduler.run_now("missing"),
+            Err(SchedulerError::JobNotFound("missing".to_string()))
+        );
+
+        scheduler.run_now("ok").unwrap();
+        let status = wait_runs(&scheduler, "ok", 1).await;
+        assert_eq!(status.last_error, None);
+        assert_eq!(status.failure_count, 0);
+        assert!(status.last_started_at.is_some() && status.last_finished_at.is_some());
+
+        scheduler.run_now("fail").unwrap();
+        let status = wait_runs(&scheduler, "fail", 1).await;
+        assert_eq!(status.last_error.as_deref(), Some("boom"));
+        assert_eq!(status.failure_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_isolated() {
+        let scheduler = Scheduler::new();
+        scheduler.register(Job::new("panic", hourly(), explode)).unwrap();
+
+        scheduler.run_now("panic").unwrap();
+        let status = wait_runs(&scheduler, "panic", 1).await;
+        assert_eq!(status.last_error.as_deref(), Some("panicked: job exploded"));
+
+        // panic 后仍可再次执行
+        scheduler.run_now("panic").unwrap();
+        assert_eq!(wait_runs(&scheduler, "panic", 2).await.failure_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_runs_are_skipped() {
+        let scheduler = Scheduler::new();
+        let release = Arc::new(Notify::new());
+        let runs = Arc::new(AtomicU32::new(0));
+        {
+            let release = release.clone();
+            let runs = runs.clone();
+            scheduler
+                .register(Job::new("slow", Schedule::every(Duration::from_millis(10)), move || {
+                    let release = release.clone();
+                    let runs = runs.clone();
+                    async move {
+                        runs.fetch_add(1, Ordering::SeqCst);
+                        release.notified().await;
+                        Ok::<_, String>(())
+                    }
+                }))
+                .unwrap();
+        }
+
+        scheduler.start();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            scheduler.run_now("slow"),
+            Err(SchedulerError::JobRunning("slow".to_string()))
+        );
+        let status = scheduler.status("slow").unwrap();
+        assert!(status.running);
+        assert!(status.skipped_count > 0);
+        assert!(status.next_run_at.is_some());
+    }
+
+    #[test]
+    fn test_random_jitter_within_bounds() {
+        assert_eq!(random_jitter(Duration::ZERO), Duration::ZERO);
+        for _ in 0..100 {
+            assert!(random_jitter(Duration::from_millis(50)) <= Duration::from_millis(50));
+        }
+    }
+}
@@ -0,0 +1,279 @@
+/**
+ * 任务调度计划
+ *
+ * 支持两种计划：
+ * - 固定间隔：上次触发后经过指定时长再次触发
+ * - cron 表达式：`分 时 日 月 周` 五个字段，按本地时间触发
+ *
+ * cron 字段支持 `*`、数字、范围 `a-b`、步长 `a-b/n`（`*` 和单个数字同样可以带步长）以及逗号分隔的列表；
+ * 周字段 0 和 7 都表示周日。日和周字段同时受限时，满足任意一个即触发（与标准 cron 一致）。
+ */
+
+use std::{fmt, time::Duration};
+
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, Timelike};
+
+use super::SchedulerError;
+
+/** 查找下一次触发时间时最多向后检查的天数 */
+const CRON_SEARCH_DAYS: i64 = 366 * 5;
+
+/**
+ * 任务调度计划
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /** 固定间隔 */
+    Interval(Duration),
+    /** cron 表达式 */
+    Cron(CronExpr),
+}
+
+impl Schedule {
+    /**
+     * 创建固定间隔计划
+     *
+     * # 参数
+     * * `interval` - 触发间隔
+     */
+    pub fn every(interval: Duration) -> Self {
+        Self::Interval(interval)
+    }
+
+    /**
+     * 解析 cron 表达式计划
+     *
+     * # 参数
+     * * `expr` - `分 时 日 月 周` 格式的表达式，如 `30 3 * * *` 表示每天 03:30
+     *
+     * # 返回
+     * 表达式格式错误时返回 `SchedulerError::InvalidSchedule`
+     */
+    pub fn cron(expr: &str) -> Result<Self, SchedulerError> {
+        CronExpr::parse(expr).map(Self::Cron)
+    }
+
+    /**
+     * 计算从当前时间到下一次触发的等待时长
+     *
+     * # 参数
+     * * `now` - 当前本地时间
+     *
+     * # 返回
+     * cron 表达式在可查找范围内没有匹配时间（如 `0 0 31 2 *`）时返回 None
+     */
+    pub fn next_delay(&self, now: NaiveDateTime) -> Option<Duration> {
+        match self {
+            Self::Interval(interval) => Some(*interval),
+            Self::Cron(expr) => {
+                let next = expr.next_after(now)?;
+                (next - now).to_std().ok()
+            },
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Interval(interval) => write!(f, "every {}s", interval.as_secs()),
+            Self::Cron(expr) => write!(f, "cron {}", expr.source),
+        }
+    }
+}
+
+/**
+ * 解析后的 cron 表达式
+ *
+ * 各字段以位图保存允许的取值
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronExpr {
+    source: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronExpr {
+    /**
+     * 解析 cron 表达式
+     *
+     * # 参数
+     * * `expr` - `分 时 日 月 周` 格式的表达式
+     */
+    pub fn parse(expr: &str) -> Result<Self, SchedulerError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(SchedulerError::InvalidSchedule(format!(
+                "expected 5 fields in '{}'",
+                expr
+            )));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7)?;
+        // 7 与 0 都表示周日
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            source: fields.join(" "),
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays,
+            days_restricted: day != "*",
+            weekdays_restricted: weekday != "*",
+        })
+    }
+
+    /**
+     * 查找严格晚于指定时间的下一次触发时间（精确到分钟）
+     */
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut time = after.with_second(0)?.with_nanosecond(0)? + ChronoDuration::minutes(1);
+        let limit = after + ChronoDuration::days(CRON_SEARCH_DAYS);
+
+        while time <= limit {
+            if !contains(self.months, time.month()) {
+                let (year, month) = if time.month() == 12 {
+                    (time.year() + 1, 1)
+                } else {
+                    (time.year(), time.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+            } else if !self.matches_day(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !contains(self.hours, time.hour()) {
+                time = time.with_minute(0)? + ChronoDuration::hours(1);
+            } else if !contains(self.minutes, time.minute()) {
+                time += ChronoDuration::minutes(1);
+            } else {
+                return Some(time);
+            }
+        }
+        None
+    }
+
+    /**
+     * 判断日期是否匹配日和周字段
+     */
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = contains(self.days, date.day());
+        let weekday = contains(self.weekdays, date.weekday().num_days_from_sunday());
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+}
+
+/**
+ * 判断位图中是否包含取值
+ */
+fn contains(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+/**
+ * 解析单个 cron 字段为位图
+ *
+ * # 参数
+ * * `field` - 字段内容
+ * * `min` - 允许的最小值
+ * * `max` - 允许的最大值
+ */
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, SchedulerError> {
+    let invalid = || SchedulerError::InvalidSchedule(format!("invalid cron field '{}'", field));
+    let number = |value: &str| -> Result<u32, SchedulerError> {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(invalid)
+    };
+
+    let mut bits = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|s| *s > 0)),
+            None => (item, Some(1)),
+        };
+        let step = step.ok_or_else(invalid)?;
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (number(start)?, number(end)?),
+                // 单个数字带步长时表示从该值到最大值
+                None if item.contains('/') => (number(range)?, max),
+                None => {
+                    let value = number(range)?;
+                    (value, value)
+                },
+            },
+        };
+        if start > end {
+            return Err(invalid());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_expressions() {
+        assert!(Schedule::cron("* * * *").is_err());
+        assert!(Schedule::cron("60 * * * *").is_err());
+        assert!(Schedule::cron("*/0 * * * *").is_err());
+        assert!(Schedule::cron("5-1 * * * *").is_err());
+        assert!(Schedule::cron("0 3 * * 1-5").is_ok());
+    }
+
+    #[test]
+    fn test_next_after() {
+        let daily = CronExpr::parse("30 3 * * *").unwrap();
+        assert_eq!(daily.next_after(time("2026-10-16 02:00")), Some(time("2026-10-16 03:30")));
+        assert_eq!(daily.next_after(time("2026-10-16 03:30")), Some(time("2026-10-17 03:30")));
+
+        let quarter = CronExpr::parse("*/15 * * * *").unwrap();
+        assert_eq!(quarter.next_after(time("2026-10-16 10:07")), Some(time("2026-10-16 10:15")));
+        assert_eq!(quarter.next_after(time("2026-10-16 23:50")), Some(time("2026-10-17 00:00")));
+
+        // 2026-10-16 是周五，下一个周一为 10-19
+        let monday = CronExpr::parse("0 9 * * 1").unwrap();
+        assert_eq!(monday.next_after(time("2026-10-16 12:00")), Some(time("2026-10-19 09:00")));
+
+        let sunday = CronExpr::parse("0 0 * * 7").unwrap();
+        assert_eq!(sunday.next_after(time("2026-10-16 12:00")), Some(time("2026-10-18 00:00")));
+
+        let yearly = CronExpr::parse("0 0 1 1 *").unwrap();
+        assert_eq!(yearly.next_after(time("2026-10-16 12:00")), Some(time("2027-01-01 00:00")));
+
+        let never = CronExpr::parse("0 0 31 2 *").unwrap();
+        assert_eq!(never.next_after(time("2026-10-16 12:00")), None);
+    }
+
+    #[test]
+    fn test_day_and_weekday_match_either() {
+        // 每月 20 日或每个周日
+        let expr = CronExpr::parse("0 0 20 * 0").unwrap();
+        assert_eq!(expr.next_after(time("2026-10-16 12:00")), Some(time("2026-10-18 00:00")));
+        assert_eq!(expr.next_after(time("2026-10-19 12:00")), Some(time("2026-10-20 00:00")));
+    }
+}
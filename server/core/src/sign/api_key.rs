@@ -8,18 +8,22 @@
  * - 时间戳验证
  * - Nonce验证
  * - URL参数签名
+ * - 按密钥限制客户端IP
  */
 
+use ipnet::IpNet;
 use md5::{Digest, Md5};
 use parking_lot::RwLock;
-use ring::{digest, hmac};
+use ring::{constant_time, digest, hmac, rand::SystemRandom};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    net::IpAddr,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use crate::sign::nonce_store::{create_memory_store_factory, NonceStore, NonceStoreFactory, NonceStoreImpl};
+use crate::web::DependencyError;
 
 /**
  * 支持的签名算法
@@ -85,43 +89,284 @@ impl Default for ApiKeyConfig {
 
 /**
  * 验证超时和过期常量
+ *
+ * 访问密钥未单独配置时使用的默认值
  */
 pub const NONCE_TTL_SECS: u64 = 600; // 10分钟
 pub const TIMESTAMP_DISPARITY_MS: i64 = 300_000; // 5分钟
 
+/**
+ * 复杂验证器中单个API密钥的密钥及验证设置
+ *
+ * 时钟偏差较大的调用方可以单独放宽时间戳窗口，nonce 的保留时间随之调整
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeySecret {
+    /**
+     * 用于签名的密钥
+     */
+    pub secret: String,
+    /**
+     * 允许的时间戳偏差（毫秒）
+     */
+    pub timestamp_tolerance_ms: i64,
+    /**
+     * nonce 的保留时间（秒），保留期内同一 nonce 不能重复使用
+     */
+    pub nonce_ttl_secs: u64,
+    /**
+     * 允许调用的客户端IP网段，为空时不限制
+     */
+    pub allowed_ips: Vec<IpNet>,
+}
+
+impl ApiKeySecret {
+    /**
+     * 创建密钥设置
+     *
+     * # 参数
+     * * `secret` - 用于签名的密钥
+     * * `timestamp_tolerance_ms` - 允许的时间戳偏差（毫秒），为None时使用默认的5分钟
+     * * `nonce_ttl_secs` - nonce 的保留时间（秒），为None时使用默认的10分钟，
+     *   时间戳窗口更大时延长到能覆盖整个窗口
+     */
+    pub fn new(
+        secret: impl Into<String>,
+        timestamp_tolerance_ms: Option<i64>,
+        nonce_ttl_secs: Option<u64>,
+    ) -> Self {
+        let timestamp_tolerance_ms = timestamp_tolerance_ms.unwrap_or(TIMESTAMP_DISPARITY_MS);
+        let nonce_ttl_secs = nonce_ttl_secs.unwrap_or_else(|| {
+            NONCE_TTL_SECS.max(Self::min_nonce_ttl_secs(timestamp_tolerance_ms))
+        });
+        Self {
+            secret: secret.into(),
+            timestamp_tolerance_ms,
+            nonce_ttl_secs,
+            allowed_ips: Vec::new(),
+        }
+    }
+
+    /**
+     * 设置允许调用的客户端IP网段
+     *
+     * # 参数
+     * * `allowed_ips` - IP网段，为空时不限制
+     */
+    pub fn with_allowed_ips(mut self, allowed_ips: Vec<IpNet>) -> Self {
+        self.allowed_ips = allowed_ips;
+        self
+    }
+
+    /**
+     * 防止重放所需的最短 nonce 保留时间（秒）
+     *
+     * 时间戳在前后两个方向上各允许 `timestamp_tolerance_ms` 的偏差，
+     * nonce 至少要保留整个窗口的长度，否则过期后可在窗口内重放同一请求
+     */
+    pub fn min_nonce_ttl_secs(timestamp_tolerance_ms: i64) -> u64 {
+        (timestamp_tolerance_ms.max(0) as u64 * 2).div_ceil(1000)
+    }
+}
+
+impl From<String> for ApiKeySecret {
+    /**
+     * 使用默认的时间戳窗口和 nonce 保留时间
+     */
+    fn from(secret: String) -> Self {
+        Self::new(secret, None, None)
+    }
+}
+
+impl From<&str> for ApiKeySecret {
+    fn from(secret: &str) -> Self {
+        Self::new(secret, None, None)
+    }
+}
+
+/**
+ * 解析IP白名单中的一项
+ *
+ * 接受CIDR格式的网段，单个地址视为 /32 或 /128 网段
+ *
+ * # 参数
+ * * `value` - 网段或地址
+ *
+ * # 返回
+ * 格式错误时返回None
+ */
+pub fn parse_allowed_ip(value: &str) -> Option<IpNet> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .ok()
+}
+
+/**
+ * 客户端IP是否在白名单内
+ *
+ * 白名单为空时不限制；客户端IP未知时只有不限制的密钥可以调用
+ *
+ * # 参数
+ * * `allowed_ips` - IP白名单
+ * * `client_ip` - 客户端IP
+ */
+fn ip_allowed(allowed_ips: &[IpNet], client_ip: Option<IpAddr>) -> bool {
+    if allowed_ips.is_empty() {
+        return true;
+    }
+    // IPv4映射的IPv6地址按IPv4匹配，双栈监听时IPv4客户端以这种形式出现
+    let client_ip = client_ip.map(|ip| ip.to_canonical());
+    client_ip.is_some_and(|ip| allowed_ips.iter().any(|net| net.contains(&ip)))
+}
+
 /**
  * 集合的容量提示
  */
 const DEFAULT_CAPACITY: usize = 32;
 
+/**
+ * 解析参与签名的查询字符串
+ *
+ * 按 `application/x-www-form-urlencoded` 规则解码（`+` 视为空格），与处理器看到的参数一致。
+ * 没有 `=` 的参数视为空值。解码后出现重名参数时拒绝，避免签名覆盖的值与处理器读取的值不一致。
+ *
+ * # 参数
+ * * `query` - 原始查询字符串（不含 `?`）
+ *
+ * # 返回
+ * * `Ok(Vec<(String, String)>)` - 解码后的参数，保持原始顺序
+ * * `Err(&'static str)` - 存在重名参数
+ */
+pub fn parse_signing_query(query: &str) -> Result<Vec<(String, String)>, &'static str> {
+    let mut seen = HashSet::new();
+    let mut params = Vec::new();
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        if !seen.insert(key.clone()) {
+            return Err("Duplicate query parameter");
+        }
+        params.push((key.into_owned(), value.into_owned()));
+    }
+    Ok(params)
+}
+
+/**
+ * 构建规范签名字符串
+ *
+ * 参数名和参数值按 RFC 3986 百分号编码（仅保留 `A-Z a-z 0-9 - . _ ~`，十六进制大写），
+ * 按编码后的参数名排序，再以 `k=v` 形式用 `&` 连接。空值参数同样参与签名。
+ * 签名验证和签名调试接口共用此函数，保证两处构建规则一致。
+ *
+ * # 参数
+ * * `params` - 参与签名的已解码参数（不含签名参数本身）
+ *
+ * # 返回
+ * 规范签名字符串
+ */
+pub fn build_signing_string(params: &[(String, String)]) -> String {
+    let mut encoded: Vec<(String, String)> = params
+        .iter()
+        .map(|(k, v)| (urlencoding::encode(k).into_owned(), urlencoding::encode(v).into_owned()))
+        .collect();
+    encoded.sort();
+
+    encoded.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join("&")
+}
+
+/**
+ * 查询字符串规范化用例
+ *
+ * `(原始查询字符串, 期望的规范签名字符串)`，`None` 表示应被拒绝。
+ * 签名计算与中间件的测试共用这组用例，防止两处规则出现偏差。
+ */
+#[cfg(test)]
+pub(crate) const CANONICALIZATION_CASES: &[(&str, Option<&str>)] = &[
+    ("b=2&a=1", Some("a=1&b=2")),
+    ("name=hello+world", Some("name=hello%20world")),
+    ("name=hello%20world", Some("name=hello%20world")),
+    ("plus=a%2Bb", Some("plus=a%2Bb")),
+    ("raw=a/b:c", Some("raw=a%2Fb%3Ac")),
+    ("lower=%e4%b8%ad", Some("lower=%E4%B8%AD")),
+    ("tilde=a~b-c.d_e", Some("tilde=a~b-c.d_e")),
+    ("empty=&a=1", Some("a=1&empty=")),
+    ("flag&a=1", Some("a=1&flag=")),
+    ("a-b=1&a=2", Some("a=2&a-b=1")),
+    ("a=1&a=2", None),
+    ("a%20b=1&a+b=2", None),
+    ("a=1&%61=2", None),
+];
+
 /**
  * 简单API密钥验证器
  *
  * 该验证器通过比较预定义的有效密钥集合来提供基本的API密钥验证。
  * 密钥永久存储，只能通过显式API调用修改。
+ *
+ * 只保存密钥经服务端pepper计算的HMAC，验证时与所有已存储的HMAC逐一做常量时间比较，
+ * 不因密钥是否存在或匹配位置不同而产生可观测的耗时差异。
  */
 #[derive(Clone)]
 pub struct SimpleApiKeyValidator {
     /**
-     * 存储有效API密钥的映射
+     * 计算密钥HMAC的服务端pepper
      */
-    keys: Arc<RwLock<HashMap<String, ()>>>,
+    pepper: Arc<hmac::Key>,
+    /**
+     * 有效API密钥的HMAC
+     */
+    keys: Arc<RwLock<Vec<Vec<u8>>>>,
+    /**
+     * 按密钥HMAC保存的IP白名单，只包含设置了白名单的密钥
+     */
+    allowed_ips: Arc<RwLock<HashMap<Vec<u8>, Vec<IpNet>>>>,
 }
 
 impl SimpleApiKeyValidator {
     /**
      * 创建一个新的SimpleApiKeyValidator实例，初始为空
+     *
+     * pepper在进程启动时随机生成，密钥仅保存在内存中，无需跨进程保持一致
      */
     #[inline]
     pub fn new() -> Self {
+        let pepper = hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+            .expect("failed to generate API key pepper");
+        Self::from_key(pepper)
+    }
+
+    /**
+     * 使用指定的pepper创建SimpleApiKeyValidator实例
+     *
+     * # 参数
+     * * `pepper` - 计算密钥HMAC的服务端pepper
+     */
+    #[inline]
+    pub fn with_pepper(pepper: &[u8]) -> Self {
+        Self::from_key(hmac::Key::new(hmac::HMAC_SHA256, pepper))
+    }
+
+    fn from_key(pepper: hmac::Key) -> Self {
         Self {
-            keys: Arc::new(RwLock::new(HashMap::with_capacity(DEFAULT_CAPACITY))),
+            pepper: Arc::new(pepper),
+            keys: Arc::new(RwLock::new(Vec::with_capacity(DEFAULT_CAPACITY))),
+            allowed_ips: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /**
+     * 计算API密钥的HMAC
+     */
+    #[inline]
+    fn hash_key(&self, key: &str) -> Vec<u8> {
+        hmac::sign(&self.pepper, key.as_bytes()).as_ref().to_vec()
+    }
+
     /**
      * 验证API密钥是否有效
      *
+     * 与所有已存储的HMAC比较，匹配后不提前返回
+     *
      * # 参数
      * * `key` - 要验证的API密钥
      *
@@ -129,9 +374,11 @@ impl SimpleApiKeyValidator {
      * * `true` - 如果密钥有效
      * * `false` - 如果密钥无效
      */
-    #[inline]
     pub fn validate_key(&self, key: &str) -> bool {
-        self.keys.read().contains_key(key)
+        let presented = self.hash_key(key);
+        self.keys.read().iter().fold(false, |valid, stored| {
+            valid | constant_time::verify_slices_are_equal(stored, &presented).is_ok()
+        })
     }
 
     /**
@@ -140,9 +387,50 @@ impl SimpleApiKeyValidator {
      * # 参数
      * * `key` - 要添加的API密钥
      */
-    #[inline]
     pub fn add_key(&self, key: String) {
-        self.keys.write().insert(key, ());
+        self.add_key_with_allowed_ips(key, Vec::new());
+    }
+
+    /**
+     * 添加新的有效API密钥，并限制调用的客户端IP
+     *
+     * 密钥已存在时替换其IP白名单
+     *
+     * # 参数
+     * * `key` - 要添加的API密钥
+     * * `allowed_ips` - 允许调用的IP网段，为空时不限制
+     */
+    pub fn add_key_with_allowed_ips(&self, key: String, allowed_ips: Vec<IpNet>) {
+        let hashed = self.hash_key(&key);
+        {
+            let mut keys = self.keys.write();
+            if !keys.contains(&hashed) {
+                keys.push(hashed.clone());
+            }
+        }
+        let mut allowed = self.allowed_ips.write();
+        if allowed_ips.is_empty() {
+            allowed.remove(&hashed);
+        } else {
+            allowed.insert(hashed, allowed_ips);
+        }
+    }
+
+    /**
+     * 客户端IP是否允许使用该密钥
+     *
+     * 未知密钥返回true，由 `validate_key` 拒绝
+     *
+     * # 参数
+     * * `key` - API密钥
+     * * `client_ip` - 客户端IP
+     */
+    pub fn allows_ip(&self, key: &str, client_ip: Option<IpAddr>) -> bool {
+        let hashed = self.hash_key(key);
+        self.allowed_ips
+            .read()
+            .get(&hashed)
+            .is_none_or(|allowed_ips| ip_allowed(allowed_ips, client_ip))
     }
 
     /**
@@ -151,9 +439,10 @@ impl SimpleApiKeyValidator {
      * # 参数
      * * `key` - 要移除的API密钥
      */
-    #[inline]
     pub fn remove_key(&self, key: &str) {
-        self.keys.write().remove(key);
+        let hashed = self.hash_key(key);
+        self.keys.write().retain(|stored| stored != &hashed);
+        self.allowed_ips.write().remove(&hashed);
     }
 }
 
@@ -181,9 +470,9 @@ impl Default for SimpleApiKeyValidator {
 #[derive(Clone)]
 pub struct ComplexApiKeyValidator {
     /**
-     * 存储API密钥及其对应密钥的映射
+     * 存储API密钥及其对应密钥和验证设置的映射
      */
-    secrets: Arc<RwLock<HashMap<String, String>>>,
+    secrets: Arc<RwLock<HashMap<String, ApiKeySecret>>>,
     /**
      * Nonce存储实例
      */
@@ -239,15 +528,19 @@ impl ComplexApiKeyValidator {
     }
 
     /**
-     * 验证时间戳是否在允许的5分钟窗口内
+     * 验证时间戳是否在允许的窗口内
+     *
+     * # 参数
+     * * `timestamp` - 请求时间戳（UNIX纪元以来的毫秒数）
+     * * `tolerance_ms` - 允许的偏差（毫秒）
      */
     #[inline]
-    fn validate_timestamp(&self, timestamp: i64) -> bool {
+    fn validate_timestamp(&self, timestamp: i64, tolerance_ms: i64) -> bool {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_millis() as i64;
-        (now - timestamp).abs() < TIMESTAMP_DISPARITY_MS
+        (now - timestamp).abs() < tolerance_ms
     }
 
     /**
@@ -298,8 +591,9 @@ impl ComplexApiKeyValidator {
      * * `nonce` - 用于防止重放攻击的唯一请求标识符
      *
      * # 返回
-     * * `true` - 如果请求有效
-     * * `false` - 如果任何验证检查失败
+     * * `Ok(true)` - 如果请求有效
+     * * `Ok(false)` - 如果任何验证检查失败
+     * * `Err(DependencyError)` - 如果nonce存储在依赖超时时间内没有响应
      */
     pub fn validate_signature(
         &self,
@@ -308,37 +602,66 @@ impl ComplexApiKeyValidator {
         signature: &str,
         timestamp: i64,
         nonce: &str,
-    ) -> bool {
-        if !self.validate_timestamp(timestamp) {
-            return false;
+    ) -> Result<bool, DependencyError> {
+        let Some(entry) = self.secrets.read().get(api_key).cloned() else {
+            return Ok(false);
+        };
+
+        if !self.validate_timestamp(timestamp, entry.timestamp_tolerance_ms) {
+            return Ok(false);
         }
 
+        let ttl = Duration::from_secs(entry.nonce_ttl_secs);
         let check_result = tokio::task::block_in_place(|| {
             tokio::runtime::Handle::current()
-                .block_on(async { self.nonce_store.check_and_set(nonce).await })
-        });
+                .block_on(async { self.nonce_store.check_and_set(api_key, nonce, ttl).await })
+        })?;
 
         if !check_result {
-            return false;
+            return Ok(false);
         }
 
-        let secrets_guard = self.secrets.read();
-        let secret = match secrets_guard.get(api_key) {
-            Some(secret) => secret,
-            None => return false,
-        };
-
-        let mut sorted_params = params.to_vec();
-        sorted_params.sort_by(|a, b| a.0.cmp(&b.0));
+        // 与简单密钥一样使用常数时间比较，避免按响应时间逐字节猜测签名
+        let signing_string = build_signing_string(params);
+        let expected = self.calculate_signature(&signing_string, &entry.secret);
+        let matched =
+            constant_time::verify_slices_are_equal(expected.as_bytes(), signature.as_bytes());
+        Ok(matched.is_ok())
+    }
 
-        let signing_string = sorted_params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join("&");
+    /**
+     * 使用存储的密钥计算签名字符串的期望签名
+     *
+     * 不校验时间戳和Nonce，也不会暴露密钥本身，供签名调试使用
+     *
+     * # 参数
+     * * `api_key` - API密钥
+     * * `signing_string` - 规范签名字符串
+     *
+     * # 返回
+     * * `Some(String)` - 期望的签名
+     * * `None` - 如果API密钥不存在
+     */
+    pub fn expected_signature(&self, api_key: &str, signing_string: &str) -> Option<String> {
+        let secrets_guard = self.secrets.read();
+        let entry = secrets_guard.get(api_key)?;
+        Some(self.calculate_signature(signing_string, &entry.secret))
+    }
 
-        let calculated_signature = self.calculate_signature(&signing_string, secret);
-        calculated_signature == signature
+    /**
+     * 客户端IP是否允许使用该密钥
+     *
+     * 未知密钥返回true，由 `validate_signature` 拒绝
+     *
+     * # 参数
+     * * `api_key` - API密钥
+     * * `client_ip` - 客户端IP
+     */
+    pub fn allows_ip(&self, api_key: &str, client_ip: Option<IpAddr>) -> bool {
+        self.secrets
+            .read()
+            .get(api_key)
+            .is_none_or(|entry| ip_allowed(&entry.allowed_ips, client_ip))
     }
 
     /**
@@ -346,10 +669,10 @@ impl ComplexApiKeyValidator {
      *
      * # 参数
      * * `key` - API密钥
-     * * `secret` - 对应的密钥
+     * * `secret` - 对应的密钥，传入字符串时使用默认的时间戳窗口和 nonce 保留时间
      */
-    pub fn add_key_secret(&self, key: String, secret: String) {
-        self.secrets.write().insert(key, secret);
+    pub fn add_key_secret(&self, key: String, secret: impl Into<ApiKeySecret>) {
+        self.secrets.write().insert(key, secret.into());
     }
 
     /**
@@ -390,6 +713,28 @@ mod tests {
         assert!(!validator.validate_key("test_key"));
     }
 
+    /**
+     * 测试简单验证器只保存密钥的HMAC
+     */
+    #[test]
+    fn test_simple_validator_stores_hashes() {
+        let validator = SimpleApiKeyValidator::with_pepper(b"pepper");
+        validator.add_key("test_key".to_string());
+        validator.add_key("test_key".to_string());
+        validator.add_key("other_key".to_string());
+
+        let keys = validator.keys.read();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.iter().all(|stored| stored.as_slice() != b"test_key".as_slice()));
+        drop(keys);
+
+        // 相同pepper计算的HMAC一致，不同pepper的验证器互不认可
+        let same = SimpleApiKeyValidator::with_pepper(b"pepper");
+        assert_eq!(same.hash_key("test_key"), validator.hash_key("test_key"));
+        let other = SimpleApiKeyValidator::with_pepper(b"another");
+        assert_ne!(other.hash_key("test_key"), validator.hash_key("test_key"));
+    }
+
     /**
      * 测试Nonce存储
      */
@@ -397,8 +742,10 @@ mod tests {
     async fn test_nonce_store() {
         let validator = ComplexApiKeyValidator::new(None);
         let nonce = "test_nonce";
-        assert!(validator.nonce_store.check_and_set(nonce).await);
-        assert!(!validator.nonce_store.check_and_set(nonce).await);
+        let ttl = Duration::from_secs(NONCE_TTL_SECS);
+        assert!(validator.nonce_store.check_and_set("key_a", nonce, ttl).await.unwrap());
+        assert!(!validator.nonce_store.check_and_set("key_a", nonce, ttl).await.unwrap());
+        assert!(validator.nonce_store.check_and_set("key_b", nonce, ttl).await.unwrap());
     }
 
     /**
@@ -423,13 +770,151 @@ mod tests {
         let signing_string = "param1=value1&param2=value2";
         let signature = validator.calculate_signature(signing_string, "test_secret");
 
-        assert!(validator.validate_signature(
-            "test_key",
-            &params,
-            &signature,
-            timestamp,
-            nonce
-        ));
+        assert!(validator
+            .validate_signature("test_key", &params, &signature, timestamp, nonce)
+            .unwrap());
+
+        // 长度相同但内容不同的签名必须被拒绝
+        let mut forged = signature.clone().into_bytes();
+        forged[0] = if forged[0] == b'A' { b'B' } else { b'A' };
+        let forged = String::from_utf8(forged).unwrap();
+        assert!(!validator
+            .validate_signature("test_key", &params, &forged, timestamp, "test_nonce_2")
+            .unwrap());
+    }
+
+    /**
+     * 测试按密钥配置的时间戳窗口和 nonce 保留时间
+     */
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_per_key_timestamp_and_nonce_windows() {
+        let validator = ComplexApiKeyValidator::new(None);
+        validator
+            .add_key_secret("strict_key".to_string(), ApiKeySecret::new("s1", Some(60_000), None));
+        validator.add_key_secret(
+            "drifting_key".to_string(),
+            ApiKeySecret::new("s2", Some(900_000), Some(1)),
+        );
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+        let params = vec![("param".to_string(), "value".to_string())];
+        let signing_string = build_signing_string(&params);
+        let sign = |secret: &str| validator.calculate_signature(&signing_string, secret);
+        let validate = |key: &str, secret: &str, timestamp: i64, nonce: &str| {
+            validator.validate_signature(key, &params, &sign(secret), timestamp, nonce).unwrap()
+        };
+
+        // 时钟偏差10分钟：超出严格密钥的1分钟窗口，在另一个密钥的15分钟窗口内
+        let drifted = now - 600_000;
+        assert!(!validate("strict_key", "s1", drifted, "n1"));
+        assert!(validate("drifting_key", "s2", drifted, "n1"));
+        assert!(validate("strict_key", "s1", now - 30_000, "n2"));
+        assert!(!validate("drifting_key", "s2", now - 1_000_000, "n3"));
+
+        // nonce 保留期内不能重复使用，保留期过后可以再次使用
+        assert!(!validate("drifting_key", "s2", now, "n1"));
+        tokio::time::sleep(Duration::from_millis(1_100)).await;
+        assert!(validate("drifting_key", "s2", now, "n1"));
+        assert!(!validate("strict_key", "s1", now, "n2"));
+
+        // 未配置的密钥使用默认值，未配置 nonce 保留时间时覆盖整个时间戳窗口
+        assert_eq!(
+            ApiKeySecret::from("secret"),
+            ApiKeySecret::new("secret", Some(TIMESTAMP_DISPARITY_MS), Some(NONCE_TTL_SECS))
+        );
+        assert_eq!(ApiKeySecret::new("secret", Some(900_000), None).nonce_ttl_secs, 1_800);
+        assert_eq!(ApiKeySecret::new("secret", Some(60_000), None).nonce_ttl_secs, NONCE_TTL_SECS);
+    }
+
+    /**
+     * 测试按密钥配置的IP白名单
+     */
+    #[test]
+    fn test_allowed_ips() {
+        let ip = |value: &str| Some(value.parse::<IpAddr>().unwrap());
+        let allowed_ips: Vec<IpNet> = ["203.0.113.0/24", "2001:db8::/32", "198.51.100.7"]
+            .into_iter()
+            .map(|value| parse_allowed_ip(value).unwrap())
+            .collect();
+        assert_eq!(allowed_ips[2].to_string(), "198.51.100.7/32");
+        assert!(parse_allowed_ip("203.0.113.0/33").is_none());
+        assert!(parse_allowed_ip("example.com").is_none());
+
+        let validator = ComplexApiKeyValidator::new(None);
+        validator.add_key_secret(
+            "restricted".to_string(),
+            ApiKeySecret::new("s1", None, None).with_allowed_ips(allowed_ips.clone()),
+        );
+        validator.add_key_secret("open".to_string(), "s2".to_string());
+        assert!(validator.allows_ip("restricted", ip("203.0.113.200")));
+        assert!(validator.allows_ip("restricted", ip("::ffff:203.0.113.9")));
+        assert!(validator.allows_ip("restricted", ip("2001:db8:1::5")));
+        assert!(validator.allows_ip("restricted", ip("198.51.100.7")));
+        assert!(!validator.allows_ip("restricted", ip("198.51.100.8")));
+        assert!(!validator.allows_ip("restricted", ip("2001:db9::1")));
+        assert!(!validator.allows_ip("restricted", None));
+        assert!(validator.allows_ip("open", None));
+        assert!(validator.allows_ip("unknown", ip("192.0.2.1")));
+
+        let validator = SimpleApiKeyValidator::new();
+        validator.add_key_with_allowed_ips("restricted".to_string(), allowed_ips);
+        assert!(validator.validate_key("restricted"));
+        assert!(validator.allows_ip("restricted", ip("2001:db8::1")));
+        assert!(!validator.allows_ip("restricted", ip("192.0.2.1")));
+        validator.add_key("restricted".to_string());
+        assert!(validator.allows_ip("restricted", ip("192.0.2.1")));
+    }
+
+    /**
+     * 测试规范签名字符串与期望签名
+     */
+    #[test]
+    fn test_expected_signature() {
+        let validator = ComplexApiKeyValidator::new(None);
+        validator.add_key_secret("test_key".to_string(), "test_secret".to_string());
+
+        let params = vec![
+            ("b".to_string(), "2".to_string()),
+            ("a".to_string(), "1".to_string()),
+        ];
+        let signing_string = build_signing_string(&params);
+        assert_eq!(signing_string, "a=1&b=2");
+
+        assert_eq!(
+            validator.expected_signature("test_key", &signing_string),
+            Some(validator.calculate_signature(&signing_string, "test_secret"))
+        );
+        assert_eq!(validator.expected_signature("unknown", &signing_string), None);
+    }
+
+    /**
+     * 测试查询字符串规范化用例与签名计算一致
+     */
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_canonicalization_cases() {
+        let validator = ComplexApiKeyValidator::new(None);
+        validator.add_key_secret("test_key".to_string(), "test_secret".to_string());
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64;
+
+        for (index, (query, expected)) in CANONICALIZATION_CASES.iter().enumerate() {
+            let parsed = parse_signing_query(query);
+            let Some(expected) = expected else {
+                assert!(parsed.is_err(), "{} should be rejected", query);
+                continue;
+            };
+            let params = parsed.unwrap();
+            assert_eq!(build_signing_string(&params), *expected, "{}", query);
+
+            let signature = validator.calculate_signature(expected, "test_secret");
+            let nonce = format!("nonce-{}", index);
+            assert!(
+                validator
+                    .validate_signature("test_key", &params, &signature, now, &nonce)
+                    .unwrap(),
+                "{}",
+                query
+            );
+        }
     }
 
     /**
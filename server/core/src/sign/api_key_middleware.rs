@@ -7,23 +7,33 @@
  * - 路由保护
  * - 请求参数解析
  * - 事件通知
+ * - 按客户端IP限制验证失败次数
+ * - 按密钥的IP白名单限制调用方地址
+ * - 拒绝重名参数，按 RFC 3986 规范化签名参数，可选对请求体摘要签名
  */
 
 use axum::{
-    body::Body,
-    extract::Request,
+    body::{to_bytes, Body},
+    extract::{ConnectInfo, Request},
     http::{HeaderMap, StatusCode, Uri},
     middleware::Next,
     response::IntoResponse,
 };
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
+use ring::digest;
 use server_constant::definition::consts::SystemEvent;
 use server_global::global;
-use std::{collections::HashSet, sync::RwLock};
+use std::{
+    collections::HashSet,
+    net::{IpAddr, SocketAddr},
+    sync::RwLock,
+};
 
-use crate::web::res::Res;
+use crate::web::{res::Res, util::ClientIp, DependencyError, FailureLimiter};
 
-use super::{ApiKeyEvent, ComplexApiKeyValidator, SimpleApiKeyValidator};
+use super::{
+    parse_signing_query, ApiKeyEvent, ComplexApiKeyValidator, SecurityEvent, SimpleApiKeyValidator,
+};
 
 /**
  * 受保护路径的全局集合
@@ -32,6 +42,37 @@ use super::{ApiKeyEvent, ComplexApiKeyValidator, SimpleApiKeyValidator};
  */
 static PROTECTED_PATHS: Lazy<RwLock<HashSet<String>>> = Lazy::new(|| RwLock::new(HashSet::new()));
 
+/**
+ * 验证失败次数限制器，服务启动时设置
+ *
+ * 未设置时不限制失败次数，仍会发送安全事件
+ */
+static FAILURE_LIMITER: OnceCell<FailureLimiter> = OnceCell::new();
+
+/**
+ * 验证失败时的响应消息
+ *
+ * 未知密钥、已禁用密钥和签名错误使用同一消息，避免通过响应枚举有效密钥
+ */
+const UNAUTHORIZED_MESSAGE: &str = "Invalid API key or signature";
+
+/**
+ * 客户端IP不在密钥白名单内时的响应消息
+ *
+ * 不包含客户端IP和白名单内容
+ */
+const FORBIDDEN_MESSAGE: &str = "Access denied for this API key";
+
+/**
+ * 安全事件中记录的密钥前缀长度（字符数）
+ */
+const KEY_PREFIX_LEN: usize = 4;
+
+/**
+ * 启用请求体摘要签名时允许缓冲的最大请求体（字节）
+ */
+const MAX_SIGNED_BODY_BYTES: usize = 2 * 1024 * 1024;
+
 /**
  * API密钥的来源位置
  *
@@ -101,6 +142,34 @@ pub struct ComplexApiKeyConfig {
      * 签名参数名称
      */
     pub signature_name: String,
+    /**
+     * 请求体摘要参数名称
+     *
+     * 为 `None` 时请求体不参与签名。设置后调用方必须在查询参数中提交该参数，
+     * 值为请求体 SHA-256 摘要的十六进制字符串，摘要随其他查询参数一起签名
+     */
+    pub body_digest_name: Option<String>,
+}
+
+impl ComplexApiKeyConfig {
+    /**
+     * 从请求参数中筛选参与签名的参数
+     *
+     * 仅排除签名参数本身，空值参数同样参与签名。中间件验证和签名调试接口共用此函数
+     *
+     * # 参数
+     * * `params` - 请求参数
+     *
+     * # 返回
+     * * `Vec<(String, String)>` - 参与签名的参数
+     */
+    pub fn signing_params(&self, params: &[(String, String)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .filter(|(k, _)| k != &self.signature_name)
+            .cloned()
+            .collect()
+    }
 }
 
 impl Default for ComplexApiKeyConfig {
@@ -113,6 +182,7 @@ impl Default for ComplexApiKeyConfig {
             timestamp_name: "timestamp".to_string(),
             nonce_name: "nonce".to_string(),
             signature_name: "signature".to_string(),
+            body_digest_name: None,
         }
     }
 }
@@ -147,6 +217,18 @@ pub fn protect_route(path: &str) {
     }
 }
 
+/**
+ * 设置验证失败次数限制器
+ *
+ * 应在服务启动时调用一次
+ *
+ * # 参数
+ * * `limiter` - 失败次数限制器
+ */
+pub fn init_failure_limiter(limiter: FailureLimiter) {
+    let _ = FAILURE_LIMITER.set(limiter);
+}
+
 /**
  * 检查URI路径是否需要API密钥验证
  *
@@ -167,10 +249,23 @@ fn is_protected_path(uri: &Uri) -> bool {
     }
 }
 
+/**
+ * 已验证的API密钥
+ *
+ * 验证通过后写入请求扩展，供后续中间件（如速率限制）识别调用方
+ */
+#[derive(Debug, Clone)]
+pub struct ApiKeyIdentity(pub String);
+
 /**
  * API密钥验证中间件
  *
- * 该中间件检查请求的API密钥是否有效
+ * 该中间件检查请求的API密钥是否有效，验证通过后发送 `ApiKeyEvent` 事件，
+ * 并在请求扩展中写入 `ApiKeyIdentity`。密钥设置了IP白名单时，在验证签名之前检查客户端IP，
+ * 客户端IP按可信代理配置解析。验证失败时计入客户端IP的失败次数，
+ * 并发送 `SecurityEvent::ApiKeyAuthFailed` 安全事件。
+ *
+ * 复杂签名只使用查询参数，请求体仅在配置了 `body_digest_name` 时以摘要形式参与签名
  *
  * # 参数
  * * `validator` - API密钥验证策略
@@ -180,29 +275,136 @@ fn is_protected_path(uri: &Uri) -> bool {
  * # 返回
  * * 如果验证通过，返回下一个中间件的响应
  * * 如果验证失败，返回401 Unauthorized响应
- * * 如果请求格式错误，返回400 Bad Request响应
+ * * 如果客户端IP不在密钥的白名单内，返回403 Forbidden响应
+ * * 如果客户端IP的失败次数超出限制，返回429 Too Many Requests响应
+ * * 如果请求格式错误或存在重名参数，返回400 Bad Request响应
+ * * 如果需要计算摘要的请求体过大，返回413 Payload Too Large响应
  */
-#[inline]
 pub async fn api_key_middleware(
     validator: ApiKeyValidation,
-    req: Request<Body>,
+    mut req: Request<Body>,
     next: Next,
 ) -> impl IntoResponse {
     if !is_protected_path(req.uri()) {
         return next.run(req).await.into_response();
     }
 
-    match validate_request(&validator, &req) {
-        Ok(true) => next.run(req).await.into_response(),
-        Ok(false) => Res::<()>::new_error(
-            StatusCode::UNAUTHORIZED.as_u16(),
-            "Invalid API key or signature",
-        )
-        .into_response(),
-        Err(e) => Res::<()>::new_error(StatusCode::BAD_REQUEST.as_u16(), e).into_response(),
+    let peer = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let client_ip = ClientIp::client_ip(peer, req.headers());
+    let ip = ClientIp::get_real_ip(peer, req.headers());
+    if let Some(limiter) = FAILURE_LIMITER.get() {
+        if let Err(response) = limiter.check(&ip).await {
+            return response;
+        }
+    }
+
+    let body_digest = match &validator {
+        ApiKeyValidation::Complex(_, config) if config.body_digest_name.is_some() => {
+            let (parts, body) = req.into_parts();
+            let Ok(bytes) = to_bytes(body, MAX_SIGNED_BODY_BYTES).await else {
+                return Res::<()>::new_error(
+                    StatusCode::PAYLOAD_TOO_LARGE.as_u16(),
+                    "Request body too large",
+                )
+                .into_response();
+            };
+            let digest = hex::encode(digest::digest(&digest::SHA256, &bytes));
+            req = Request::from_parts(parts, Body::from(bytes));
+            Some(digest)
+        },
+        _ => None,
+    };
+
+    match validate_request(&validator, &req, client_ip, body_digest.as_deref()) {
+        Ok((true, api_key)) => {
+            // 仅在验证通过后发送事件，用于记录密钥最近使用时间
+            // 队列已满时丢弃，丢弃数量计入通道统计
+            let _ = global::send_typed_event(ApiKeyEvent {
+                api_key: api_key.clone(),
+            });
+            req.extensions_mut().insert(ApiKeyIdentity(api_key));
+            next.run(req).await.into_response()
+        },
+        Ok((false, api_key)) => {
+            record_failure(ip, &api_key, &req).await;
+            Res::<()>::new_error(StatusCode::UNAUTHORIZED.as_u16(), UNAUTHORIZED_MESSAGE)
+                .into_response()
+        },
+        Err(RequestError::Forbidden(api_key)) => {
+            record_failure(ip, &api_key, &req).await;
+            Res::<()>::new_error(StatusCode::FORBIDDEN.as_u16(), FORBIDDEN_MESSAGE).into_response()
+        },
+        Err(RequestError::Malformed(e)) => {
+            Res::<()>::new_error(StatusCode::BAD_REQUEST.as_u16(), e).into_response()
+        },
+        // 依赖超时不计入验证失败次数，客户端可以原样重试
+        Err(RequestError::Dependency(e)) => e.into_response(),
+    }
+}
+
+/**
+ * 记录一次验证失败
+ *
+ * 计入客户端IP的失败次数，并发送 `SecurityEvent::ApiKeyAuthFailed` 安全事件
+ */
+async fn record_failure(ip: String, api_key: &str, req: &Request<Body>) {
+    if let Some(limiter) = FAILURE_LIMITER.get() {
+        limiter.record_failure(&ip).await;
+    }
+    global::send_dyn_event(
+        SystemEvent::AuditSecurityEvent.as_ref(),
+        Box::new(SecurityEvent::ApiKeyAuthFailed {
+            ip,
+            key_prefix: key_prefix(api_key),
+            path: req.uri().path().to_string(),
+        }),
+    );
+}
+
+/**
+ * 请求验证错误
+ */
+#[derive(Debug)]
+enum RequestError {
+    /** 请求缺少参数或参数格式错误，返回400 */
+    Malformed(&'static str),
+
+    /** 客户端IP不在密钥的白名单内，返回403 */
+    Forbidden(String),
+
+    /** nonce存储等依赖没有响应，返回503 */
+    Dependency(DependencyError),
+}
+
+impl From<&'static str> for RequestError {
+    fn from(message: &'static str) -> Self {
+        RequestError::Malformed(message)
+    }
+}
+
+impl From<DependencyError> for RequestError {
+    fn from(err: DependencyError) -> Self {
+        RequestError::Dependency(err)
     }
 }
 
+/**
+ * 截取安全事件中记录的密钥前缀
+ *
+ * # 参数
+ * * `api_key` - 请求中提交的API密钥
+ *
+ * # 返回
+ * * `String` - 密钥的前 `KEY_PREFIX_LEN` 个字符
+ */
+#[inline]
+fn key_prefix(api_key: &str) -> String {
+    api_key.chars().take(KEY_PREFIX_LEN).collect()
+}
+
 /**
  * 从请求头中获取值
  *
@@ -244,45 +446,57 @@ fn get_query_value<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a
  * # 参数
  * * `validator` - API密钥验证策略
  * * `req` - 请求对象
+ * * `client_ip` - 客户端IP
+ * * `body_digest` - 请求体的SHA-256摘要，仅在配置了 `body_digest_name` 时计算
  *
  * # 返回
- * * `Ok(true)` - 如果验证通过
- * * `Ok(false)` - 如果验证失败
- * * `Err(&str)` - 如果请求格式错误
+ * * `Ok((true, api_key))` - 如果验证通过
+ * * `Ok((false, api_key))` - 如果验证失败
+ * * `Err(RequestError)` - 如果请求格式错误、客户端IP不在白名单内或依赖没有响应
  */
 #[inline]
 fn validate_request(
     validator: &ApiKeyValidation,
     req: &Request<Body>,
-) -> Result<bool, &'static str> {
+    client_ip: Option<IpAddr>,
+    body_digest: Option<&str>,
+) -> Result<(bool, String), RequestError> {
     let headers = req.headers();
     let query = req.uri().query().unwrap_or("");
-    let params = if !query.is_empty() {
-        parse_query(query)
-    } else {
-        Vec::new()
-    };
 
     match validator {
         ApiKeyValidation::Simple(validator, config) => {
+            let params = if !query.is_empty() {
+                parse_query(query)
+            } else {
+                Vec::new()
+            };
             let api_key = match config.source {
                 ApiKeySource::Header => get_header_value(headers, &config.key_name),
                 ApiKeySource::Query => get_query_value(&params, &config.key_name),
             }
             .ok_or("Missing API key")?;
 
-            global::send_dyn_event(
-                SystemEvent::AuthApiKeyValidatedEvent.as_ref(),
-                Box::new(ApiKeyEvent {
-                    api_key: api_key.to_owned(),
-                }),
-            );
-            Ok(validator.validate_key(api_key))
+            // 简单密钥本身是凭据，先校验密钥再检查白名单，
+            // 无效密钥无论来源IP都返回相同的 401，不能据此探测密钥是否存在
+            if !validator.validate_key(api_key) {
+                return Ok((false, api_key.to_owned()));
+            }
+            if !validator.allows_ip(api_key, client_ip) {
+                return Err(RequestError::Forbidden(api_key.to_owned()));
+            }
+            Ok((true, api_key.to_owned()))
         },
         ApiKeyValidation::Complex(validator, config) => {
+            let params = parse_signing_query(query)?;
             let api_key =
                 get_query_value(&params, &config.key_name).ok_or("Missing AccessKeyId")?;
 
+            // 在消耗 nonce 之前检查，白名单外的请求不占用调用方的 nonce
+            if !validator.allows_ip(api_key, client_ip) {
+                return Err(RequestError::Forbidden(api_key.to_owned()));
+            }
+
             let timestamp = get_query_value(&params, &config.timestamp_name)
                 .ok_or("Missing timestamp")?
                 .parse::<i64>()
@@ -293,25 +507,24 @@ fn validate_request(
             let signature =
                 get_query_value(&params, &config.signature_name).ok_or("Missing signature")?;
 
-            let params_for_signing: Vec<(String, String)> = params
-                .iter()
-                .filter(|(k, _)| k != &config.signature_name)
-                .map(|(k, v)| (k.clone(), v.clone()))
-                .collect();
-
-            global::send_dyn_event(
-                SystemEvent::AuthApiKeyValidatedEvent.as_ref(),
-                Box::new(ApiKeyEvent {
-                    api_key: api_key.to_owned(),
-                }),
-            );
-            Ok(validator.validate_signature(
+            // 摘要不一致时直接判定失败，不消耗 nonce
+            if let Some(digest_name) = &config.body_digest_name {
+                let submitted =
+                    get_query_value(&params, digest_name).ok_or("Missing body digest")?;
+                if !body_digest.is_some_and(|digest| digest.eq_ignore_ascii_case(submitted)) {
+                    return Ok((false, api_key.to_owned()));
+                }
+            }
+
+            let params_for_signing = config.signing_params(&params);
+            let valid = validator.validate_signature(
                 api_key,
                 &params_for_signing,
                 signature,
                 timestamp,
                 nonce,
-            ))
+            )?;
+            Ok((valid, api_key.to_owned()))
         },
     }
 }
@@ -319,6 +532,8 @@ fn validate_request(
 /**
  * 解析查询字符串为键值对
  *
+ * 仅用于简单API密钥验证，复杂签名验证使用 `parse_signing_query`
+ *
  * # 参数
  * * `query` - 查询字符串
  *
@@ -344,7 +559,227 @@ fn parse_query(query: &str) -> Vec<(String, String)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::{body::to_bytes, routing::get, Router};
+    use serde_json::Value;
     use std::time::{SystemTime, UNIX_EPOCH};
+    use tower::ServiceExt;
+
+    /**
+     * 测试密钥前缀截取
+     */
+    #[test]
+    fn test_key_prefix() {
+        assert_eq!(key_prefix("AK123456"), "AK12");
+        assert_eq!(key_prefix("AK"), "AK");
+        assert_eq!(key_prefix("密钥前缀测试"), "密钥前缀");
+    }
+
+    /**
+     * 测试未知密钥和已移除密钥的响应一致
+     */
+    #[tokio::test]
+    async fn test_unknown_and_removed_keys_share_response() {
+        protect_route("/test/api-key-enumeration");
+        let validator = SimpleApiKeyValidator::new();
+        validator.add_key("removed-key".to_string());
+        validator.remove_key("removed-key");
+
+        let validation = ApiKeyValidation::Simple(validator, SimpleApiKeyConfig::default());
+        let app = Router::new()
+            .route("/test/api-key-enumeration", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(move |req, next| {
+                api_key_middleware(validation.clone(), req, next)
+            }));
+
+        let mut bodies = Vec::new();
+        for key in ["unknown-key", "removed-key"] {
+            let request = Request::builder()
+                .uri("/test/api-key-enumeration")
+                .header("x-api-key", key)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let mut value: Value = serde_json::from_slice(&bytes).unwrap();
+            value.as_object_mut().unwrap().remove("timestamp");
+            bodies.push(value);
+        }
+        assert_eq!(bodies[0], bodies[1]);
+    }
+
+    /**
+     * 测试IP白名单按可信代理解析客户端IP
+     */
+    #[tokio::test]
+    async fn test_allowed_ips_use_trusted_proxy_resolution() {
+        use crate::sign::parse_allowed_ip;
+        use server_config::TrustedProxyConfig;
+
+        ClientIp::init_trusted_proxy(TrustedProxyConfig {
+            enabled: true,
+            trusted_proxies: vec!["192.0.2.0/24".parse().unwrap()],
+            header: Default::default(),
+        });
+        protect_route("/test/api-key-allowed-ips");
+        let validator = SimpleApiKeyValidator::new();
+        let allowed_ips = ["203.0.113.0/24", "2001:db8::/32"]
+            .into_iter()
+            .map(|value| parse_allowed_ip(value).unwrap())
+            .collect();
+        validator.add_key_with_allowed_ips("partner-key".to_string(), allowed_ips);
+
+        let validation = ApiKeyValidation::Simple(validator, SimpleApiKeyConfig::default());
+        let app = Router::new()
+            .route("/test/api-key-allowed-ips", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(move |req, next| {
+                api_key_middleware(validation.clone(), req, next)
+            }));
+        let send_key = |key: &str, peer: &str, forwarded_for: Option<&str>| {
+            let mut request = Request::builder()
+                .uri("/test/api-key-allowed-ips")
+                .header("x-api-key", key)
+                .extension(ConnectInfo(SocketAddr::new(peer.parse().unwrap(), 40000)));
+            if let Some(forwarded_for) = forwarded_for {
+                request = request.header("X-Forwarded-For", forwarded_for);
+            }
+            let app = app.clone();
+            async move { app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap() }
+        };
+        let send =
+            |peer: &str, forwarded_for: Option<&str>| send_key("partner-key", peer, forwarded_for);
+
+        assert_eq!(send("203.0.113.10", None).await.status(), StatusCode::OK);
+        assert_eq!(send("2001:db8::10", None).await.status(), StatusCode::OK);
+        assert_eq!(send("2001:db9::10", None).await.status(), StatusCode::FORBIDDEN);
+
+        // 通过可信代理转发时按转发请求头中的地址匹配
+        let response = send("192.0.2.1", Some("203.0.113.10")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send("192.0.2.1", Some("198.51.100.7")).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // 不可信的对端伪造转发请求头无效，响应中不包含客户端IP
+        let response = send("198.51.100.7", Some("203.0.113.10")).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["message"], FORBIDDEN_MESSAGE);
+        assert!(!String::from_utf8_lossy(&bytes).contains("198.51.100.7"));
+
+        // 白名单外的无效密钥与未知密钥一样返回 401
+        let response = send_key("unknown-key", "198.51.100.7", None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    /**
+     * 构建使用复杂签名验证的测试路由
+     */
+    fn complex_app(path: &'static str, config: ComplexApiKeyConfig) -> Router {
+        protect_route(path);
+        let validator = ComplexApiKeyValidator::new(None);
+        validator.add_key_secret("test-access-key".to_string(), "test-secret-key".to_string());
+        let validation = ApiKeyValidation::Complex(validator, config);
+        Router::new()
+            .route(path, get(|| async { "ok" }).post(|body: String| async move { body }))
+            .layer(axum::middleware::from_fn(move |req, next| {
+                api_key_middleware(validation.clone(), req, next)
+            }))
+    }
+
+    /**
+     * 按查询参数名排序拼接规范签名字符串
+     */
+    fn join_sorted(pairs: Vec<String>) -> String {
+        let mut pairs: Vec<String> = pairs.into_iter().filter(|pair| !pair.is_empty()).collect();
+        pairs.sort_by(|a, b| a.split_once('=').unwrap().0.cmp(b.split_once('=').unwrap().0));
+        pairs.join("&")
+    }
+
+    fn now_millis() -> i64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+    }
+
+    /**
+     * 测试中间件与签名计算共用查询字符串规范化用例
+     */
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_canonicalization_cases() {
+        use crate::sign::api_key::CANONICALIZATION_CASES;
+
+        let config = ComplexApiKeyConfig {
+            key_name: "AccessKeyId".to_string(),
+            timestamp_name: "t".to_string(),
+            nonce_name: "n".to_string(),
+            signature_name: "sign".to_string(),
+            body_digest_name: None,
+        };
+        let app = complex_app("/test/api-key-canonical", config);
+        let signer = ComplexApiKeyValidator::new(None);
+
+        for (index, (query, expected)) in CANONICALIZATION_CASES.iter().enumerate() {
+            let fixed =
+                format!("AccessKeyId=test-access-key&t={}&n=nonce-{}", now_millis(), index);
+            let canonical = expected.unwrap_or("");
+            let pairs = canonical.split('&').chain(fixed.split('&')).map(str::to_string).collect();
+            let signature = signer.calculate_signature(&join_sorted(pairs), "test-secret-key");
+            let uri = format!("/test/api-key-canonical?{}&{}&sign={}", query, fixed, signature);
+
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = if expected.is_some() { StatusCode::OK } else { StatusCode::BAD_REQUEST };
+            assert_eq!(response.status(), status, "{}", query);
+        }
+    }
+
+    /**
+     * 测试请求体仅在配置摘要参数时以摘要形式参与签名
+     */
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_body_digest_signing() {
+        let config = ComplexApiKeyConfig {
+            body_digest_name: Some("bodySha256".to_string()),
+            ..ComplexApiKeyConfig::default()
+        };
+        let app = complex_app("/test/api-key-body-digest", config);
+        let signer = ComplexApiKeyValidator::new(None);
+        let body = r#"{"amount":100}"#;
+        let send = |body: &'static str, digest: Option<String>, nonce: &'static str| {
+            let mut pairs = vec![
+                "AccessKeyId=test-access-key".to_string(),
+                format!("timestamp={}", now_millis()),
+                format!("nonce={}", nonce),
+            ];
+            if let Some(digest) = digest {
+                pairs.push(format!("bodySha256={}", digest));
+            }
+            let query = join_sorted(pairs);
+            let signature = signer.calculate_signature(&query, "test-secret-key");
+            let request = Request::builder()
+                .method("POST")
+                .uri(format!("/test/api-key-body-digest?{}&signature={}", query, signature))
+                .body(Body::from(body))
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+        let sha256 = |body: &str| hex::encode(digest::digest(&digest::SHA256, body.as_bytes()));
+
+        // 摘要一致时请求体原样传给处理器
+        let response = send(body, Some(sha256(body)), "n1").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&bytes[..], body.as_bytes());
+
+        // 篡改请求体、缺少摘要参数
+        let tampered = send(r#"{"amount":999}"#, Some(sha256(body)), "n2").await.unwrap();
+        assert_eq!(tampered.status(), StatusCode::UNAUTHORIZED);
+        let missing = send(body, None, "n3").await.unwrap();
+        assert_eq!(missing.status(), StatusCode::BAD_REQUEST);
+
+        // 摘要不一致时不消耗 nonce
+        let response = send(body, Some(sha256(body)), "n2").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 
     /**
      * 测试API密钥签名验证
@@ -360,7 +795,7 @@ mod tests {
             .as_millis() as i64;
         let nonce = format!("nonce_{}", timestamp);
 
-        let mut params = vec![
+        let params = vec![
             ("AccessKeyId".to_string(), "test-access-key".to_string()),
             ("param1".to_string(), "value1".to_string()),
             ("param2".to_string(), "value2".to_string()),
@@ -368,13 +803,7 @@ mod tests {
             ("n".to_string(), nonce.clone()),
         ];
 
-        params.sort_by(|a, b| a.0.cmp(&b.0));
-
-        let signing_string = params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join("&");
+        let signing_string = crate::sign::build_signing_string(&params);
 
         let signature = validator.calculate_signature(&signing_string, "test-secret-key");
 
@@ -5,16 +5,18 @@
  * 主要用于防止重放攻击，同时保持内存效率。
  */
 
-use moka::sync::Cache;
+use std::time::{Duration, Instant};
+
+use moka::{sync::Cache, Expiry};
 use async_trait::async_trait;
 
-use super::api_key::NONCE_TTL_SECS;
 use super::nonce_store::NonceStore;
+use crate::web::DependencyError;
 
 /**
  * 内存Nonce存储结构体
  *
- * 使用moka缓存来存储nonce，具有10分钟的TTL（生存时间）。
+ * 使用moka缓存来存储nonce，每个nonce按所属API密钥的设置过期。
  * 一旦nonce过期，它可以被重用。这有助于防止重放攻击，同时保持内存效率。
  */
 #[derive(Clone)]
@@ -22,9 +24,25 @@ pub struct MemoryNonceStore {
     /**
      * 使用moka缓存存储nonce
      * 
-     * 键为nonce字符串，值为空元组（仅用于标记存在性）
+     * 键为 `api_key:nonce`，值为该nonce的保留时间
      */
-    nonces: Cache<String, ()>,
+    nonces: Cache<String, Duration>,
+}
+
+/**
+ * 按写入时记录的保留时间过期
+ */
+struct NonceExpiry;
+
+impl Expiry<String, Duration> for NonceExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        ttl: &Duration,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(*ttl)
+    }
 }
 
 #[async_trait]
@@ -35,19 +53,27 @@ impl NonceStore for MemoryNonceStore {
      * 检查nonce是否已存在，如果不存在则存储它
      *
      * # 参数
+     * * `api_key` - 发起请求的API密钥
      * * `nonce` - 要验证和存储的nonce字符串
+     * * `ttl` - nonce 的保留时间
      *
      * # 返回
-     * * `true` - 如果nonce有效且未被使用过
-     * * `false` - 如果nonce无效或已被使用过
+     * * `Ok(true)` - 如果nonce有效且未被使用过
+     * * `Ok(false)` - 如果nonce无效或已被使用过
      */
     #[inline]
-    async fn check_and_set(&self, nonce: &str) -> bool {
-        if self.nonces.contains_key(nonce) {
-            false
+    async fn check_and_set(
+        &self,
+        api_key: &str,
+        nonce: &str,
+        ttl: Duration,
+    ) -> Result<bool, DependencyError> {
+        let key = format!("{}:{}", api_key, nonce);
+        if self.nonces.contains_key(&key) {
+            Ok(false)
         } else {
-            self.nonces.insert(nonce.to_string(), ());
-            true
+            self.nonces.insert(key, ttl);
+            Ok(true)
         }
     }
 }
@@ -56,7 +82,7 @@ impl MemoryNonceStore {
     /**
      * 创建新的MemoryNonceStore实例
      * 
-     * 使用10分钟的TTL初始化缓存
+     * 缓存条目按写入时传入的保留时间过期
      * 
      * # 返回
      * * `Self` - 新的MemoryNonceStore实例
@@ -64,9 +90,7 @@ impl MemoryNonceStore {
     #[inline]
     pub fn new() -> Self {
         Self {
-            nonces: Cache::builder()
-                .time_to_live(std::time::Duration::from_secs(NONCE_TTL_SECS))
-                .build(),
+            nonces: Cache::builder().expire_after(NonceExpiry).build(),
         }
     }
 }
@@ -6,7 +6,7 @@
  * - 简单和复杂的签名验证
  * - Nonce存储管理
  * - API密钥中间件
- * - 事件处理
+ * - 事件处理（验证通过事件和安全事件）
  */
 
 mod api_key;
@@ -16,11 +16,12 @@ mod nonce_store;
 mod redis_nonce_store;
 
 pub use api_key::{
-    ApiKeyConfig, ComplexApiKeyValidator, SignatureAlgorithm, SimpleApiKeyValidator,
+    build_signing_string, parse_allowed_ip, parse_signing_query, ApiKeyConfig, ApiKeySecret,
+    ComplexApiKeyValidator, SignatureAlgorithm, SimpleApiKeyValidator,
 };
 pub use api_key_middleware::{
-    api_key_middleware, protect_route, ApiKeySource, ApiKeyValidation, ComplexApiKeyConfig,
-    SimpleApiKeyConfig,
+    api_key_middleware, init_failure_limiter, protect_route, ApiKeyIdentity, ApiKeySource,
+    ApiKeyValidation, ComplexApiKeyConfig, SimpleApiKeyConfig,
 };
 pub use memory_nonce_store::{create_memory_nonce_store_factory, MemoryNonceStore};
 pub use nonce_store::{NonceStore, NonceStoreFactory};
@@ -89,20 +90,21 @@ pub async fn get_complex_validator() -> ComplexApiKeyValidator {
  * # 参数
  * * `validator_type` - 验证器类型
  * * `key` - API密钥
- * * `secret` - 可选的密钥（仅用于复杂验证器）
+ * * `secret` - 可选的密钥及验证设置，简单验证器只使用其中的IP白名单
  */
-pub async fn add_key(validator_type: ValidatorType, key: &str, secret: Option<&str>) {
+pub async fn add_key(validator_type: ValidatorType, key: &str, secret: Option<ApiKeySecret>) {
     match validator_type {
         ValidatorType::Simple => {
-            API_KEY_VALIDATORS.0.write().await.add_key(key.to_string());
+            let allowed_ips = secret.map(|secret| secret.allowed_ips).unwrap_or_default();
+            API_KEY_VALIDATORS
+                .0
+                .write()
+                .await
+                .add_key_with_allowed_ips(key.to_string(), allowed_ips);
         },
         ValidatorType::Complex => {
             if let Some(secret) = secret {
-                API_KEY_VALIDATORS
-                    .1
-                    .write()
-                    .await
-                    .add_key_secret(key.to_string(), secret.to_string());
+                API_KEY_VALIDATORS.1.write().await.add_key_secret(key.to_string(), secret);
             }
         },
     }
@@ -157,12 +159,97 @@ pub async fn init_validators_with_nonce_store(
 /**
  * API密钥事件结构体
  * 
- * 用于表示与API密钥相关的事件
+ * 签名中间件验证通过后发送，用于记录密钥的使用情况
  */
 #[derive(Debug, Clone)]
 pub struct ApiKeyEvent {
     /**
-     * API密钥
+     * 验证通过的API密钥ID（access_key_id）
      */
     pub api_key: String,
 }
+
+/**
+ * 安全事件
+ *
+ * 认证失败、异常登录、角色变更等需要留存审计记录的事件，写入 sys_security_event 表
+ */
+#[derive(Debug, Clone)]
+pub enum SecurityEvent {
+    /**
+     * API密钥验证失败
+     *
+     * 仅记录密钥前缀，不记录完整密钥
+     */
+    ApiKeyAuthFailed {
+        /** 客户端IP */
+        ip: String,
+        /** 所提交密钥的前缀 */
+        key_prefix: String,
+        /** 请求路径 */
+        path: String,
+    },
+
+    /**
+     * 异常登录
+     *
+     * 登录国家或用户代理在该用户最近的登录记录中未出现过
+     */
+    UnusualLogin {
+        /** 用户ID */
+        user_id: String,
+        /** 用户名 */
+        username: String,
+        /** 域代码 */
+        domain: String,
+        /** 客户端IP */
+        ip: String,
+        /** 上一次登录的IP归属地 */
+        previous_address: String,
+        /** 本次登录的IP归属地 */
+        address: String,
+        /** 上一次登录的用户代理 */
+        previous_user_agent: String,
+        /** 本次登录的用户代理 */
+        user_agent: String,
+        /** 登录国家是否从未出现过 */
+        new_location: bool,
+        /** 用户代理是否从未出现过 */
+        new_user_agent: bool,
+    },
+
+    /**
+     * 用户角色变更
+     *
+     * 分配角色用户时，为每个被授予或移除角色的用户记录一条
+     */
+    RoleChanged {
+        /** 被变更角色的用户ID */
+        user_id: String,
+        /** 角色所属域代码 */
+        domain: String,
+        /** 角色代码 */
+        role_code: String,
+        /** 授予角色为 true，移除角色为 false */
+        granted: bool,
+        /** 执行变更的用户ID */
+        operator_id: String,
+    },
+}
+
+impl SecurityEvent {
+    /**
+     * 事件类型
+     *
+     * # 返回
+     * * `&'static str` - 写入 sys_security_event.event_type 的值
+     */
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            SecurityEvent::ApiKeyAuthFailed { .. } => "api_key_auth_failed",
+            SecurityEvent::UnusualLogin { .. } => "unusual_login",
+            SecurityEvent::RoleChanged { granted: true, .. } => "role_granted",
+            SecurityEvent::RoleChanged { granted: false, .. } => "role_revoked",
+        }
+    }
+}
@@ -7,7 +7,9 @@
  * - 存储工厂函数
  */
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+
+use crate::web::DependencyError;
 
 /**
  * Nonce存储trait
@@ -19,16 +21,25 @@ pub trait NonceStore: Send + Sync {
     /**
      * 检查并设置nonce
      *
-     * 验证nonce是否有效且未被使用过，如果有效则存储它
+     * 验证nonce是否有效且未被使用过，如果有效则存储它。
+     * nonce 按API密钥区分，不同密钥可以使用相同的nonce
      *
      * # 参数
+     * * `api_key` - 发起请求的API密钥
      * * `nonce` - 要验证和存储的nonce字符串
+     * * `ttl` - nonce 的保留时间，由API密钥的设置决定
      *
      * # 返回
-     * * `true` - 如果nonce有效且未被使用过
-     * * `false` - 如果nonce无效或已被使用过
+     * * `Ok(true)` - 如果nonce有效且未被使用过
+     * * `Ok(false)` - 如果nonce无效或已被使用过
+     * * `Err(DependencyError)` - 如果存储后端在依赖超时时间内没有响应
      */
-    async fn check_and_set(&self, nonce: &str) -> bool;
+    async fn check_and_set(
+        &self,
+        api_key: &str,
+        nonce: &str,
+        ttl: Duration,
+    ) -> Result<bool, DependencyError>;
 }
 
 /**
@@ -54,10 +65,15 @@ pub enum NonceStoreImpl {
 
 #[async_trait::async_trait]
 impl NonceStore for NonceStoreImpl {
-    async fn check_and_set(&self, nonce: &str) -> bool {
+    async fn check_and_set(
+        &self,
+        api_key: &str,
+        nonce: &str,
+        ttl: Duration,
+    ) -> Result<bool, DependencyError> {
         match self {
-            NonceStoreImpl::Memory(store) => store.check_and_set(nonce).await,
-            NonceStoreImpl::Redis(store) => store.check_and_set(nonce).await,
+            NonceStoreImpl::Memory(store) => store.check_and_set(api_key, nonce, ttl).await,
+            NonceStoreImpl::Redis(store) => store.check_and_set(api_key, nonce, ttl).await,
         }
     }
 }
@@ -86,14 +102,13 @@ pub fn create_memory_store() -> NonceStoreImpl {
  * 
  * # 参数
  * * `client` - Redis客户端实例
- * * `ttl` - Nonce的过期时间
  * 
  * # 返回
  * * `NonceStoreImpl` - 使用Redis存储的NonceStore实例
  */
-pub fn create_redis_store(client: redis::Client, ttl: std::time::Duration) -> NonceStoreImpl {
+pub fn create_redis_store(client: redis::Client) -> NonceStoreImpl {
     NonceStoreImpl::Redis(Arc::new(
-        crate::sign::redis_nonce_store::RedisNonceStore::new(client, ttl),
+        crate::sign::redis_nonce_store::RedisNonceStore::new(client),
     ))
 }
 
@@ -112,14 +127,10 @@ pub fn create_memory_store_factory() -> NonceStoreFactory {
  * 
  * # 参数
  * * `client` - Redis客户端实例
- * * `ttl` - Nonce的过期时间
  * 
  * # 返回
  * * `NonceStoreFactory` - 创建Redis NonceStore的工厂函数
  */
-pub fn create_redis_store_factory(
-    client: redis::Client,
-    ttl: std::time::Duration,
-) -> NonceStoreFactory {
-    Arc::new(move || create_redis_store(client.clone(), ttl))
+pub fn create_redis_store_factory(client: redis::Client) -> NonceStoreFactory {
+    Arc::new(move || create_redis_store(client.clone()))
 }
@@ -2,11 +2,15 @@
  * Redis Nonce存储实现
  * 
  * 该模块提供了基于Redis的Nonce存储实现，用于防止重放攻击。
- * 使用Redis的TTL特性自动处理Nonce的过期。
+ * 使用Redis的TTL特性自动处理Nonce的过期，过期时间由API密钥的设置决定。
  */
 
-use crate::sign::nonce_store::NonceStore;
-use redis::{Client, RedisError};
+use crate::{
+    sign::nonce_store::NonceStore,
+    web::{with_dependency_timeout, DependencyError},
+};
+use redis::{aio::MultiplexedConnection, Client, RedisError};
+use server_global::redis_key::KeyBuilder;
 use std::time::Duration;
 use async_trait::async_trait;
 
@@ -20,10 +24,6 @@ pub struct RedisNonceStore {
      * Redis客户端实例
      */
     client: Client,
-    /**
-     * Nonce的过期时间
-     */
-    ttl: Duration,
 }
 
 impl RedisNonceStore {
@@ -32,23 +32,45 @@ impl RedisNonceStore {
      * 
      * # 参数
      * * `client` - Redis客户端实例
-     * * `ttl` - Nonce的过期时间
      * 
      * # 返回
      * * `Self` - Redis Nonce存储实例
      */
-    pub fn new(client: Client, ttl: Duration) -> Self {
-        Self { client, ttl }
+    pub fn new(client: Client) -> Self {
+        Self { client }
     }
 
     /**
      * 获取Redis连接
      * 
      * # 返回
-     * * `Result<redis::Connection, RedisError>` - Redis连接结果
+     * * `Result<MultiplexedConnection, RedisError>` - Redis连接结果
      */
-    fn get_connection(&self) -> Result<redis::Connection, RedisError> {
-        self.client.get_connection()
+    async fn get_connection(&self) -> Result<MultiplexedConnection, RedisError> {
+        self.client.get_multiplexed_async_connection().await
+    }
+
+    /**
+     * 执行SETNX并设置过期时间
+     * 
+     * # 返回
+     * * `Result<bool, RedisError>` - key不存在并写入成功时为true
+     */
+    async fn set_if_absent(&self, key: &str, ttl: Duration) -> Result<bool, RedisError> {
+        let mut conn = self.get_connection().await?;
+
+        // 使用SETNX命令，如果key不存在则设置
+        let result: bool = redis::cmd("SETNX").arg(key).arg("1").query_async(&mut conn).await?;
+        if result {
+            // 设置过期时间
+            redis::cmd("EXPIRE")
+                .arg(key)
+                .arg(ttl.as_secs() as usize)
+                .query_async::<()>(&mut conn)
+                .await?;
+        }
+
+        Ok(result)
     }
 }
 
@@ -58,42 +80,30 @@ impl NonceStore for RedisNonceStore {
      * 检查并设置Nonce
      * 
      * 如果Nonce不存在，则设置它并返回true；
-     * 如果Nonce已存在，则返回false。
+     * 如果Nonce已存在或Redis返回错误，则返回false。
+     * Redis在依赖超时时间内没有响应时返回 `DependencyError`，不再阻塞请求。
      * 
      * # 参数
+     * * `api_key` - 发起请求的API密钥
      * * `nonce` - 要检查的Nonce值
+     * * `ttl` - Nonce的过期时间
      * 
      * # 返回
-     * * `bool` - 如果Nonce有效且未被使用过返回true，否则返回false
+     * * `Result<bool, DependencyError>` - 如果Nonce有效且未被使用过返回true，否则返回false
      */
-    async fn check_and_set(&self, nonce: &str) -> bool {
-        let mut conn = match self.get_connection() {
-            Ok(conn) => conn,
-            Err(_) => return false,
+    async fn check_and_set(
+        &self,
+        api_key: &str,
+        nonce: &str,
+        ttl: Duration,
+    ) -> Result<bool, DependencyError> {
+        let key = match KeyBuilder::nonce(api_key, nonce) {
+            Ok(key) => key,
+            Err(_) => return Ok(false),
         };
-        
-        let key = format!("nonce:{}", nonce);
-        
-        // 使用SETNX命令，如果key不存在则设置
-        let result: bool = match redis::cmd("SETNX")
-            .arg(&key)
-            .arg("1")
-            .query(&mut conn) {
-                Ok(result) => result,
-                Err(_) => return false,
-            };
-            
-        if result {
-            // 设置过期时间
-            if let Err(_) = redis::cmd("EXPIRE")
-                .arg(&key)
-                .arg(self.ttl.as_secs() as usize)
-                .query::<()>(&mut conn) {
-                    return false;
-                }
-        }
-        
-        result
+
+        let result = with_dependency_timeout("redis", self.set_if_absent(&key, ttl)).await?;
+        Ok(result.unwrap_or(false))
     }
 }
 
@@ -102,14 +112,10 @@ impl NonceStore for RedisNonceStore {
  * 
  * # 参数
  * * `client` - Redis客户端实例
- * * `ttl` - Nonce的过期时间
  * 
  * # 返回
  * * `NonceStoreFactory` - 创建Redis Nonce存储的工厂函数
  */
-pub fn create_redis_nonce_store_factory(
-    client: Client,
-    ttl: Duration,
-) -> super::nonce_store::NonceStoreFactory {
-    super::nonce_store::create_redis_store_factory(client, ttl)
+pub fn create_redis_nonce_store_factory(client: Client) -> super::nonce_store::NonceStoreFactory {
+    super::nonce_store::create_redis_store_factory(client)
 }
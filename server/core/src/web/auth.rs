@@ -64,7 +64,8 @@ use crate::web::res::Res;
  * 
  * ## 自定义字段
  * * `username`: 用户名
- * * `role`: 用户角色列表
+ * * `role`: 用户角色列表，声明超过大小上限时为空
+ * * `roles_ref`: 角色引用，角色列表存放在Redis中时设置
  * * `domain`: 用户所属域
  * * `org`: 用户所属组织
  */
@@ -89,7 +90,11 @@ pub struct Claims {
     /// 用户名
     username: String,
     /// 用户角色列表
+    #[serde(default)]
     role: Vec<String>,
+    /// 角色引用
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    roles_ref: Option<String>,
     /// 用户所属域
     domain: String,
     /// 用户所属组织
@@ -132,6 +137,7 @@ impl Claims {
             jti: None,
             username,
             role,
+            roles_ref: None,
             domain,
             org,
         }
@@ -196,6 +202,61 @@ impl Claims {
     pub fn set_jti(&mut self, jti: String) {
         self.jti = Some(jti);
     }
+
+    /**
+     * 获取接收者
+     */
+    pub fn aud(&self) -> &str {
+        &self.aud
+    }
+
+    /**
+     * 获取过期时间（Unix时间戳）
+     */
+    pub fn exp(&self) -> Option<usize> {
+        self.exp
+    }
+
+    /**
+     * 获取签发时间（Unix时间戳）
+     */
+    pub fn iat(&self) -> Option<usize> {
+        self.iat
+    }
+
+    /**
+     * 获取JWT ID
+     */
+    pub fn jti(&self) -> Option<&str> {
+        self.jti.as_deref()
+    }
+
+    /**
+     * 获取用户角色列表
+     */
+    pub fn role(&self) -> &[String] {
+        &self.role
+    }
+
+    /**
+     * 获取角色引用
+     */
+    pub fn roles_ref(&self) -> Option<&str> {
+        self.roles_ref.as_deref()
+    }
+
+    /**
+     * 设置角色引用
+     * 
+     * 角色列表改为按引用获取，令牌中不再携带角色列表。
+     * 
+     * # 参数
+     * * `roles_ref` - 角色引用
+     */
+    pub fn set_roles_ref(&mut self, roles_ref: String) {
+        self.role.clear();
+        self.roles_ref = Some(roles_ref);
+    }
 }
 
 /**
@@ -209,8 +270,11 @@ impl Claims {
  * * `user_id`: 用户唯一标识
  * * `username`: 用户名称
  * * `role`: 用户角色列表
+ * * `roles_ref`: 令牌中的角色引用，角色列表尚未取回时设置
  * * `domain`: 用户所属域
  * * `org`: 用户所属组织
+ * * `jti`: 本次请求使用的令牌的JWT ID
+ * * `personal_token`: 本次请求使用的个人访问令牌ID
  */
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct User {
@@ -220,10 +284,19 @@ pub struct User {
     username: String,
     /// 用户角色列表
     role: Vec<String>,
+    /// 令牌中的角色引用
+    #[serde(default)]
+    roles_ref: Option<String>,
     /// 用户所属域
     domain: String,
     /// 用户所属组织
     org: Option<String>,
+    /// 本次请求使用的令牌的JWT ID
+    #[serde(default)]
+    jti: Option<String>,
+    /// 本次请求使用的个人访问令牌ID
+    #[serde(default)]
+    personal_token: Option<String>,
 }
 
 impl User {
@@ -246,8 +319,11 @@ impl User {
             user_id,
             username,
             role: Vec::new(),
+            roles_ref: None,
             domain,
             org: None,
+            jti: None,
+            personal_token: None,
         }
     }
 
@@ -281,6 +357,31 @@ impl User {
         self.role.clone()
     }
 
+    /**
+     * 获取令牌中的角色引用
+     * 
+     * # 返回
+     * * `Option<String>` - 令牌直接携带角色列表或角色已取回时为None
+     */
+    pub fn roles_ref(&self) -> Option<String> {
+        self.roles_ref.clone()
+    }
+
+    /**
+     * 设置按角色引用取回的角色列表
+     * 
+     * # 参数
+     * * `role` - 用户角色列表
+     * 
+     * # 返回
+     * * `Self` - 设置后的用户实例
+     */
+    pub fn with_roles(mut self, role: Vec<String>) -> Self {
+        self.role = role;
+        self.roles_ref = None;
+        self
+    }
+
     /**
      * 获取用户所属域
      * 
@@ -290,6 +391,40 @@ impl User {
     pub fn domain(&self) -> String {
         self.domain.to_string()
     }
+
+    /**
+     * 获取本次请求使用的令牌的JWT ID
+     * 
+     * # 返回
+     * * `Option<String>` - 令牌未携带JWT ID时为None
+     */
+    pub fn jti(&self) -> Option<String> {
+        self.jti.clone()
+    }
+
+    /**
+     * 获取本次请求使用的个人访问令牌ID
+     * 
+     * # 返回
+     * * `Option<String>` - 使用JWT访问时为None
+     */
+    pub fn personal_token(&self) -> Option<String> {
+        self.personal_token.clone()
+    }
+
+    /**
+     * 标记本次请求使用个人访问令牌
+     * 
+     * # 参数
+     * * `token_id` - 个人访问令牌ID
+     * 
+     * # 返回
+     * * `Self` - 标记后的用户实例
+     */
+    pub fn with_personal_token(mut self, token_id: String) -> Self {
+        self.personal_token = Some(token_id);
+        self
+    }
 }
 
 impl From<Claims> for User {
@@ -309,8 +444,11 @@ impl From<Claims> for User {
             user_id: claims.sub,
             username: claims.username,
             role: claims.role,
+            roles_ref: claims.roles_ref,
             domain: claims.domain,
             org: claims.org,
+            jti: claims.jti,
+            personal_token: None,
         }
     }
 }
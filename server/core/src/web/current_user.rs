@@ -0,0 +1,74 @@
+/**
+ * 当前用户上下文模块
+ * 
+ * 该模块保存当前请求的认证用户，供服务层在不传递参数的情况下读取操作人：
+ * - JWT认证中间件在认证成功后通过 `CurrentUserContext::scope` 设置
+ * - 服务通过 `CurrentUserContext::current` 读取，填充审计字段
+ * 
+ * 上下文使用 tokio task-local 保存，只在处理请求的任务内有效；
+ * 通过 `tokio::spawn` 启动的后台任务和事件处理器中读取不到当前用户。
+ */
+
+use std::future::Future;
+
+use crate::web::auth::User;
+
+tokio::task_local! {
+    /**
+     * 当前认证用户
+     */
+    static CURRENT_USER: User;
+}
+
+/**
+ * 当前用户上下文
+ */
+pub struct CurrentUserContext;
+
+impl CurrentUserContext {
+    /**
+     * 在当前用户上下文中执行
+     * 
+     * # 参数
+     * 
+     * * `user` - 当前认证用户
+     * * `f` - 在上下文中执行的异步任务
+     * 
+     * # 返回值
+     * 
+     * 返回异步任务的结果
+     */
+    pub async fn scope<F: Future>(user: User, f: F) -> F::Output {
+        CURRENT_USER.scope(user, f).await
+    }
+
+    /**
+     * 获取当前认证用户
+     * 
+     * # 返回值
+     * 
+     * 在请求上下文中返回当前用户，否则返回 None
+     */
+    pub fn current() -> Option<User> {
+        CURRENT_USER.try_with(|user| user.clone()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_current_user_only_inside_scope() {
+        assert!(CurrentUserContext::current().is_none());
+
+        let user = User::new("u1".to_string(), "alice".to_string(), "built-in".to_string());
+        let user_id = CurrentUserContext::scope(user, async {
+            CurrentUserContext::current().map(|user| user.user_id())
+        })
+        .await;
+
+        assert_eq!(user_id.as_deref(), Some("u1"));
+        assert!(CurrentUserContext::current().is_none());
+    }
+}
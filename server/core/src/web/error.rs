@@ -4,7 +4,7 @@
  * 该模块提供了统一的错误处理机制，包括：
  * - API错误接口定义：定义了统一的错误处理接口
  * - 应用错误类型：实现了通用的应用错误结构
- * - 数据库错误转换：支持SeaORM数据库错误转换
+ * - 数据库错误转换：支持SeaORM数据库错误转换，按SQLSTATE标记可重试错误
  * - Redis错误转换：支持Redis错误转换
  * - MongoDB错误转换：支持MongoDB错误转换
  * - JWT错误转换：支持JWT相关错误转换
@@ -22,14 +22,26 @@
  * - 404: 资源未找到
  * - 500: 服务器内部错误
  * - 503: 服务不可用
+ * 
+ * # HTTP状态码
+ * 
+ * 错误码到HTTP状态码的映射只在 `http_status` 中定义：错误码本身是合法的HTTP状态码时直接使用，
+ * 业务错误码（如 `3002`）默认映射为 400。业务错误可通过 `ApiError::status` 指定其他状态码，
+ * 转换为 `AppError` 时一并保留。
+ * 
+ * # 本地化
+ * 
+ * 错误码不随语言变化。响应时按当前请求语言在消息目录中查找 `error.<错误码>`，
+ * 找到时替换 message，`message_args` 依次填充模板中的 `{0}`、`{1}`；找不到时使用原始消息。
  */
 
+pub use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use mongodb::error::{Error as MongoError, ErrorKind};
 use redis::RedisError;
-use sea_orm::DbErr;
+use sea_orm::{DbErr, RuntimeErr};
 
-use crate::web::{jwt::JwtError, res::Res};
+use crate::web::{i18n::localize_error, jwt::JwtError, res::Res};
 
 /**
  * API错误接口
@@ -62,6 +74,51 @@ pub trait ApiError {
      * * `String` - 错误描述信息
      */
     fn message(&self) -> String;
+
+    /**
+     * 获取错误消息参数
+     * 
+     * 本地化消息模板中 `{0}`、`{1}` 等占位符的取值，默认没有参数。
+     * 
+     * # 返回
+     * * `Vec<String>` - 消息参数
+     */
+    fn message_args(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /**
+     * 获取HTTP状态码
+     * 
+     * 默认根据错误码通过 `http_status` 计算，业务错误可按错误类型覆盖（如资源不存在返回404）。
+     * 
+     * # 返回
+     * * `StatusCode` - HTTP状态码
+     */
+    fn status(&self) -> StatusCode {
+        http_status(self.code())
+    }
+}
+
+/**
+ * 将错误码映射为HTTP状态码
+ * 
+ * 唯一的映射位置，`AppError` 与 `Res` 的HTTP响应均使用该函数：
+ * - `0` 及合法的HTTP状态码（200-599）直接使用
+ * - 业务错误码及其他取值映射为 400
+ * 
+ * # 参数
+ * * `code` - 错误码
+ * 
+ * # 返回
+ * * `StatusCode` - HTTP状态码
+ */
+pub fn http_status(code: u16) -> StatusCode {
+    match code {
+        0 => StatusCode::OK,
+        200..=599 => StatusCode::from_u16(code).unwrap_or(StatusCode::BAD_REQUEST),
+        _ => StatusCode::BAD_REQUEST,
+    }
 }
 
 /**
@@ -72,31 +129,94 @@ pub trait ApiError {
  * 
  * # 字段
  * 
- * * `code`: HTTP状态码
+ * * `code`: 错误码，HTTP状态码或业务错误码
  * * `message`: 错误描述信息
+ * * `status`: 响应使用的HTTP状态码
+ * * `args`: 本地化消息参数
+ * * `retryable`: 是否为重新执行即可成功的临时性数据库错误
  */
 #[derive(Debug)]
 pub struct AppError {
-    /// HTTP状态码
+    /// 错误码
     pub code: u16,
     /// 错误描述信息
     pub message: String,
+    /// HTTP状态码
+    pub status: StatusCode,
+    /// 本地化消息参数
+    pub args: Vec<String>,
+    /// 是否可重试（序列化失败、死锁），仅由数据库错误转换时设置
+    pub retryable: bool,
+}
+
+impl AppError {
+    /**
+     * 创建应用错误
+     * 
+     * HTTP状态码由 `http_status` 根据错误码计算。
+     * 
+     * # 参数
+     * * `code` - 错误码
+     * * `message` - 错误描述信息
+     */
+    pub fn new(code: u16, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            status: http_status(code),
+            args: Vec::new(),
+            retryable: false,
+        }
+    }
+
+    /**
+     * 从实现了 `ApiError` 的错误创建应用错误
+     * 
+     * 保留错误的错误码、错误消息、消息参数和HTTP状态码，供各服务错误类型的 `From` 实现使用。
+     * 
+     * # 参数
+     * * `err` - 服务错误
+     */
+    pub fn from_api_error(err: &impl ApiError) -> Self {
+        Self {
+            code: err.code(),
+            message: err.message(),
+            status: err.status(),
+            args: err.message_args(),
+            retryable: false,
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     /**
      * 将错误转换为HTTP响应
      * 
-     * 使用Res结构体包装错误信息，并转换为HTTP响应。
+     * 使用Res结构体包装错误信息，并以 `status` 作为HTTP状态码。
+     * 消息目录中存在该错误码时，按当前请求语言返回本地化消息。
      * 
      * # 返回
      * * `Response` - HTTP响应
      */
     fn into_response(self) -> Response {
-        Res::<()>::new_error(self.code, self.message.as_str()).into_response()
+        let message = localize_error(self.code, &self.args).unwrap_or(self.message);
+        (self.status, Res::<()>::new_error(self.code, message.as_str())).into_response()
     }
 }
 
+impl std::fmt::Display for AppError {
+    /**
+     * 格式化错误信息
+     *
+     * 输出格式为 `[错误码] 错误消息`，便于日志记录及与依赖 `Display` 的库（如 SeaORM 事务）协作。
+     */
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for AppError {}
+
 impl ApiError for AppError {
     /**
      * 获取错误码
@@ -117,6 +237,26 @@ impl ApiError for AppError {
     fn message(&self) -> String {
         self.message.to_string()
     }
+
+    /**
+     * 获取错误消息参数
+     * 
+     * # 返回
+     * * `Vec<String>` - 本地化消息参数
+     */
+    fn message_args(&self) -> Vec<String> {
+        self.args.clone()
+    }
+
+    /**
+     * 获取HTTP状态码
+     * 
+     * # 返回
+     * * `StatusCode` - HTTP状态码
+     */
+    fn status(&self) -> StatusCode {
+        self.status
+    }
 }
 
 impl ApiError for DbErr {
@@ -160,11 +300,40 @@ impl ApiError for DbErr {
     }
 }
 
+/**
+ * 可重试的SQLSTATE
+ *
+ * 40001 序列化失败、40P01 死锁，重新执行整个事务通常即可成功
+ */
+const RETRYABLE_SQLSTATES: &[&str] = &["40001", "40P01"];
+
+/**
+ * 判断数据库错误是否可重试
+ *
+ * 只依据数据库返回的SQLSTATE判断，不匹配错误消息，
+ * 连接错误、约束冲突、记录不存在等均不可重试
+ *
+ * # 参数
+ * * `err` - 数据库错误
+ *
+ * # 返回
+ * * `bool` - 是否可重试
+ */
+pub fn is_retryable_db_err(err: &DbErr) -> bool {
+    let (DbErr::Exec(RuntimeErr::SqlxError(err)) | DbErr::Query(RuntimeErr::SqlxError(err))) = err
+    else {
+        return false;
+    };
+    err.as_database_error()
+        .and_then(|err| err.code())
+        .is_some_and(|code| RETRYABLE_SQLSTATES.contains(&code.as_ref()))
+}
+
 impl From<DbErr> for AppError {
     /**
      * 从数据库错误转换为应用错误
      * 
-     * 将SeaORM数据库错误转换为统一的AppError格式。
+     * 将SeaORM数据库错误转换为统一的AppError格式，并标记是否可重试。
      * 
      * # 参数
      * * `err` - 数据库错误
@@ -173,10 +342,9 @@ impl From<DbErr> for AppError {
      * * `Self` - 应用错误
      */
     fn from(err: DbErr) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        let mut error = AppError::from_api_error(&err);
+        error.retryable = is_retryable_db_err(&err);
+        error
     }
 }
 
@@ -193,10 +361,7 @@ impl From<JwtError> for AppError {
      * * `Self` - 应用错误
      */
     fn from(err: JwtError) -> Self {
-        AppError {
-            code: 400,
-            message: err.to_string(),
-        }
+        AppError::new(400, err.to_string())
     }
 }
 
@@ -233,7 +398,7 @@ impl From<RedisError> for AppError {
             format!("{}", err)
         };
 
-        AppError { code, message }
+        AppError::new(code, message)
     }
 }
 
@@ -275,9 +440,155 @@ impl From<MongoError> for AppError {
             _ => 500,                                       // 其他未知错误
         };
 
-        AppError {
-            code,
-            message: err.to_string(),
+        AppError::new(code, err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        extract::Request,
+        http::header::ACCEPT_LANGUAGE,
+        routing::get,
+        Router,
+    };
+    use sea_orm::sqlx::error::{DatabaseError, ErrorKind as DbErrorKind};
+    use serde_json::Value;
+    use std::borrow::Cow;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::web::i18n::LocaleLayer;
+
+    struct NotFoundError;
+
+    impl ApiError for NotFoundError {
+        fn code(&self) -> u16 {
+            3002
+        }
+
+        fn message(&self) -> String {
+            "Role not found".to_string()
+        }
+
+        fn status(&self) -> StatusCode {
+            StatusCode::NOT_FOUND
+        }
+    }
+
+    #[test]
+    fn test_http_status() {
+        assert_eq!(http_status(0), StatusCode::OK);
+        assert_eq!(http_status(200), StatusCode::OK);
+        assert_eq!(http_status(404), StatusCode::NOT_FOUND);
+        assert_eq!(http_status(503), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(http_status(3002), StatusCode::BAD_REQUEST);
+        assert_eq!(http_status(99), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_app_error_keeps_code_and_status() {
+        let err = AppError::from_api_error(&NotFoundError);
+        assert_eq!(err.code, 3002);
+        assert_eq!(err.message, "Role not found");
+
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_db_error_status() {
+        let err = AppError::from(DbErr::RecordNotFound("role".to_string()));
+        assert_eq!(err.status, StatusCode::NOT_FOUND);
+        assert_eq!(AppError::new(500, "boom").status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    /**
+     * 携带指定SQLSTATE的数据库错误
+     */
+    #[derive(Debug)]
+    struct SqlStateError(&'static str);
+
+    impl std::fmt::Display for SqlStateError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "error with sqlstate {}", self.0)
+        }
+    }
+
+    impl std::error::Error for SqlStateError {}
+
+    impl DatabaseError for SqlStateError {
+        fn message(&self) -> &str {
+            "error returned from database"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> DbErrorKind {
+            DbErrorKind::Other
+        }
+    }
+
+    fn sqlstate_error(code: &'static str) -> RuntimeErr {
+        RuntimeErr::SqlxError(sea_orm::sqlx::Error::Database(Box::new(SqlStateError(code))))
+    }
+
+    #[test]
+    fn test_retryable_db_error_classification() {
+        assert!(AppError::from(DbErr::Exec(sqlstate_error("40001"))).retryable);
+        assert!(AppError::from(DbErr::Query(sqlstate_error("40P01"))).retryable);
+
+        // 约束冲突、连接错误和记录不存在都不可重试
+        assert!(!AppError::from(DbErr::Exec(sqlstate_error("23505"))).retryable);
+        assert!(!AppError::from(DbErr::Conn(sqlstate_error("40001"))).retryable);
+        assert!(!AppError::from(DbErr::RecordNotFound("role".to_string())).retryable);
+
+        // 只按SQLSTATE判断，消息中出现相同字样不算
+        let message = RuntimeErr::Internal("deadlock detected (40P01)".to_string());
+        assert!(!AppError::from(DbErr::Query(message)).retryable);
+        assert!(!AppError::new(500, "deadlock detected").retryable);
+    }
+
+    #[tokio::test]
+    async fn test_response_message_is_localized() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { Err::<(), AppError>(AppError::new(9002, "Invalid credentials")) }),
+            )
+            .layer(LocaleLayer);
+
+        for (language, expected) in [("zh-CN", "用户名或密码错误"), ("en", "Invalid credentials")] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri("/")
+                        .header(ACCEPT_LANGUAGE, language)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(body["code"], 9002);
+            assert_eq!(body["message"], expected);
         }
     }
 }
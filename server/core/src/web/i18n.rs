@@ -0,0 +1,426 @@
+/**
+ * 国际化模块
+ *
+ * 该模块提供了请求级别的语言协商和消息目录，用于返回本地化的错误消息。
+ * 主要功能包括：
+ * - 解析 Accept-Language 请求头
+ * - 按错误码和验证消息键查找本地化消息
+ * - 在请求处理期间保存当前语言
+ *
+ * # 主要组件
+ *
+ * ## Locale
+ * 支持的语言，作为请求扩展注入，处理器可通过 `Extension<Locale>` 读取
+ *
+ * ## LocaleLayer
+ * 语言协商中间件层：
+ * - 解析 Accept-Language，未携带或不支持时使用简体中文
+ * - 注入请求扩展及任务本地变量
+ * - 响应携带 Content-Language 请求头
+ *
+ * # 消息目录
+ *
+ * 每种语言一个 JSON 文件（`locales/*.json`），编译时嵌入：
+ * - `error.<错误码>`：错误消息，`{0}`、`{1}` 依次替换为错误参数
+ * - `validation.<键>`：验证消息，`{field}` 替换为字段名，`{min}`、`{max}` 等替换为验证参数
+ *
+ * 错误码保持不变，只替换 message；目录中没有对应条目时保留原始消息。
+ */
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::Request,
+    http::{
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE},
+        HeaderValue,
+    },
+    response::Response,
+};
+use once_cell::sync::Lazy;
+use serde_json::Value as JsonValue;
+use tower::{Layer, Service};
+
+/** 简体中文消息目录 */
+const ZH_CN_BUNDLE: &str = include_str!("../../locales/zh-CN.json");
+
+/** 英文消息目录 */
+const EN_BUNDLE: &str = include_str!("../../locales/en.json");
+
+/**
+ * 请求上下文之外使用的语言
+ *
+ * 与源码中的错误消息保持一致，供后台任务和测试使用
+ */
+const FALLBACK_LOCALE: Locale = Locale::En;
+
+/** 消息目录，按语言和消息键索引 */
+static CATALOG: Lazy<HashMap<Locale, HashMap<String, String>>> = Lazy::new(|| {
+    [(Locale::ZhCn, ZH_CN_BUNDLE), (Locale::En, EN_BUNDLE)]
+        .into_iter()
+        .map(|(locale, bundle)| {
+            let messages = serde_json::from_str(bundle)
+                .unwrap_or_else(|e| panic!("invalid {} message bundle: {}", locale.tag(), e));
+            (locale, messages)
+        })
+        .collect()
+});
+
+tokio::task_local! {
+    /**
+     * 当前请求语言
+     *
+     * 在 LocaleMiddleware 处理请求期间有效，供 AppError 等无法访问请求上下文的位置读取
+     */
+    static CURRENT_LOCALE: Locale;
+}
+
+/**
+ * 支持的语言
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /** 简体中文 */
+    #[default]
+    ZhCn,
+    /** 英文 */
+    En,
+}
+
+impl Locale {
+    /**
+     * 获取语言标签
+     *
+     * # 返回值
+     *
+     * 返回 BCP 47 语言标签，如 `zh-CN`
+     */
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::En => "en",
+        }
+    }
+
+    /**
+     * 根据语言标签匹配支持的语言
+     *
+     * 只比较主语言，`zh-TW`、`en-US` 等分别匹配简体中文和英文
+     */
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next().unwrap_or_default();
+        if primary.eq_ignore_ascii_case("zh") {
+            Some(Locale::ZhCn)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(Locale::En)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * 解析 Accept-Language 请求头
+     *
+     * 按权重从高到低选择第一个支持的语言，权重相同时保持请求头中的顺序，
+     * `q=0` 的语言及通配符 `*` 被忽略。
+     *
+     * # 参数
+     *
+     * * `header` - Accept-Language 请求头的值
+     *
+     * # 返回值
+     *
+     * 返回协商出的语言，没有支持的语言时返回 None
+     */
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        candidates.into_iter().find_map(|(tag, _)| Self::from_tag(tag))
+    }
+
+    /**
+     * 查找消息模板
+     *
+     * # 参数
+     *
+     * * `key` - 消息键
+     *
+     * # 返回值
+     *
+     * 返回该语言的消息模板，目录中没有时返回 None
+     */
+    pub fn message(&self, key: &str) -> Option<&'static str> {
+        CATALOG.get(self)?.get(key).map(String::as_str)
+    }
+}
+
+/**
+ * 获取当前请求语言
+ *
+ * # 返回值
+ *
+ * 在请求上下文中返回协商出的语言，否则返回 None
+ */
+pub fn current_locale() -> Option<Locale> {
+    CURRENT_LOCALE.try_with(|locale| *locale).ok()
+}
+
+/**
+ * 查找本地化的错误消息
+ *
+ * 只在请求上下文中生效，请求之外（日志、后台任务）保留原始消息。
+ *
+ * # 参数
+ *
+ * * `code` - 错误码
+ * * `args` - 错误参数，依次替换 `{0}`、`{1}`
+ *
+ * # 返回值
+ *
+ * 返回本地化后的消息，不在请求上下文中或目录中没有该错误码时返回 None
+ */
+pub fn localize_error(code: u16, args: &[String]) -> Option<String> {
+    let template = current_locale()?.message(&format!("error.{}", code))?;
+    Some(render(template, |name| {
+        let index = name.parse::<usize>().ok()?;
+        args.get(index).map(|arg| Cow::Borrowed(arg.as_str()))
+    }))
+}
+
+/**
+ * 查找本地化的验证消息
+ *
+ * 请求上下文之外使用英文。消息键不在目录中时原样返回，兼容直接写在验证规则中的消息。
+ *
+ * # 参数
+ *
+ * * `key` - 验证消息键
+ * * `field` - 字段名，替换 `{field}`
+ * * `params` - 验证参数，替换同名占位符
+ *
+ * # 返回值
+ *
+ * 返回本地化后的验证消息
+ */
+pub fn localize_validation(
+    key: &str,
+    field: &str,
+    params: &HashMap<Cow<'static, str>, JsonValue>,
+) -> String {
+    let locale = current_locale().unwrap_or(FALLBACK_LOCALE);
+    let Some(template) = locale.message(key) else {
+        return key.to_string();
+    };
+
+    render(template, |name| match name {
+        "field" => Some(Cow::Borrowed(field)),
+        _ => params.get(name).map(|value| match value {
+            JsonValue::String(s) => Cow::Owned(s.clone()),
+            // 数值参数可能以浮点数保存，统一按 f64 输出，整数值不带小数部分
+            JsonValue::Number(n) => {
+                Cow::Owned(n.as_f64().map(|f| f.to_string()).unwrap_or_else(|| n.to_string()))
+            },
+            other => Cow::Owned(other.to_string()),
+        }),
+    })
+}
+
+/**
+ * 替换消息模板中的占位符
+ *
+ * 无法解析的占位符原样保留
+ */
+fn render<'a, F>(template: &str, resolve: F) -> String
+where
+    F: Fn(&str) -> Option<Cow<'a, str>>,
+{
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match resolve(name) {
+                    Some(value) => output.push_str(&value),
+                    None => output.push_str(&rest[start..start + end + 2]),
+                }
+                rest = &after[end + 1..];
+            },
+            None => {
+                output.push_str(&rest[start..]);
+                rest = "";
+            },
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/**
+ * 语言协商中间件层
+ */
+#[derive(Clone, Debug)]
+pub struct LocaleLayer;
+
+impl<S> Layer<S> for LocaleLayer {
+    type Service = LocaleMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        LocaleMiddleware { service }
+    }
+}
+
+/**
+ * 语言协商中间件
+ */
+#[derive(Clone, Debug)]
+pub struct LocaleMiddleware<S> {
+    service: S,
+}
+
+impl<S> Service<Request> for LocaleMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Send + Clone + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let locale = req
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(Locale::from_accept_language)
+            .unwrap_or_default();
+
+        req.extensions_mut().insert(locale);
+
+        let mut service = self.service.clone();
+        Box::pin(CURRENT_LOCALE.scope(locale, async move {
+            let mut response = service.call(req).await?;
+            response
+                .headers_mut()
+                .insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+            Ok(response)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        routing::get,
+        Extension, Router,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[test]
+    fn test_bundles_have_same_keys() {
+        let zh = &CATALOG[&Locale::ZhCn];
+        let en = &CATALOG[&Locale::En];
+        let mut missing: Vec<_> = zh.keys().filter(|key| !en.contains_key(*key)).collect();
+        missing.extend(en.keys().filter(|key| !zh.contains_key(*key)));
+        assert!(missing.is_empty(), "keys missing from one bundle: {:?}", missing);
+    }
+
+    #[test]
+    fn test_from_accept_language() {
+        assert_eq!(Locale::from_accept_language("en-US,en;q=0.9"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("zh-CN,zh;q=0.9,en;q=0.8"), Some(Locale::ZhCn));
+        assert_eq!(Locale::from_accept_language("fr;q=0.9,en;q=0.5,zh;q=0.7"), Some(Locale::ZhCn));
+        assert_eq!(Locale::from_accept_language("zh;q=0, en"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("fr-FR, *"), None);
+        assert_eq!(Locale::from_accept_language(""), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let args = ["boom".to_string()];
+        let resolve = |name: &str| {
+            name.parse::<usize>().ok().and_then(|i| args.get(i)).map(|a| Cow::Borrowed(a.as_str()))
+        };
+        assert_eq!(render("failed: {0}", resolve), "failed: boom");
+        assert_eq!(render("{1} and {", resolve), "{1} and {");
+    }
+
+    #[test]
+    fn test_localize_outside_request() {
+        assert_eq!(localize_error(9001, &[]), None);
+
+        let mut params = HashMap::new();
+        params.insert(Cow::Borrowed("min"), JsonValue::from(1));
+        params.insert(Cow::Borrowed("max"), JsonValue::from(50));
+        assert_eq!(
+            localize_validation("validation.length_between", "code", &params),
+            "code must be between 1 and 50 characters"
+        );
+        assert_eq!(localize_validation("Plain message", "code", &params), "Plain message");
+    }
+
+    #[tokio::test]
+    async fn test_localize_in_request() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|Extension(locale): Extension<Locale>| async move {
+                    format!(
+                        "{}|{}",
+                        locale.tag(),
+                        localize_error(9003, &["bad token".to_string()]).unwrap_or_default()
+                    )
+                }),
+            )
+            .layer(LocaleLayer);
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.headers()[CONTENT_LANGUAGE], "zh-CN");
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(String::from_utf8_lossy(&bytes), "zh-CN|认证失败：bad token");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(ACCEPT_LANGUAGE, "en-US,en;q=0.9")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(String::from_utf8_lossy(&bytes), "en|Authentication failed: bad token");
+    }
+}
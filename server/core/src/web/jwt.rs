@@ -136,8 +136,7 @@ impl JwtUtils {
     /**
      * 生成JWT访问令牌
      * 
-     * 根据提供的Claims生成JWT访问令牌。
-     * 自动设置令牌的过期时间、签发者、签发时间等字段。
+     * 根据提供的Claims生成JWT访问令牌，过期时间使用全局访问令牌配置。
      * 
      * # 参数
      * * `claims` - 包含用户信息的Claims对象
@@ -150,6 +149,32 @@ impl JwtUtils {
      * * `JwtError::TokenCreationError` - 令牌创建失败
      */
     pub async fn generate_token(claims: &Claims) -> Result<String, JwtError> {
+        let jwt_config = global::get_config::<JwtConfig>().await.unwrap();
+        Self::generate_token_with_expire(claims, jwt_config.access_token_expire).await
+    }
+
+    /**
+     * 按指定有效期生成JWT令牌
+     * 
+     * 自动设置令牌的过期时间、签发者、签发时间等字段。
+     * 用于按域覆盖的访问令牌和刷新令牌。
+     * Claims 未设置JWT ID时生成新的ID，已设置时保留，便于调用方记录令牌。
+     * 
+     * # 参数
+     * * `claims` - 包含用户信息的Claims对象
+     * * `expire_secs` - 有效期（秒）
+     * 
+     * # 返回
+     * * `Result<String, JwtError>` - 成功返回令牌字符串，失败返回错误
+     * 
+     * # 错误
+     * * `JwtError::KeysNotInitialized` - 密钥未初始化
+     * * `JwtError::TokenCreationError` - 令牌创建失败
+     */
+    pub async fn generate_token_with_expire(
+        claims: &Claims,
+        expire_secs: u64,
+    ) -> Result<String, JwtError> {
         let keys_arc = global::KEYS.get().ok_or(JwtError::KeysNotInitialized)?;
 
         let keys = keys_arc.lock().await;
@@ -159,11 +184,13 @@ impl JwtUtils {
         let now = Utc::now();
         let timestamp = now.timestamp() as usize;
         let jwt_config = global::get_config::<JwtConfig>().await.unwrap();
-        claims_clone.set_exp((now + Duration::seconds(jwt_config.access_token_expire as i64)).timestamp() as usize);
+        claims_clone.set_exp((now + Duration::seconds(expire_secs as i64)).timestamp() as usize);
         claims_clone.set_iss(jwt_config.issuer.to_string());
         claims_clone.set_iat(timestamp);
         claims_clone.set_nbf(timestamp);
-        claims_clone.set_jti(Ulid::new().to_string());
+        if claims_clone.jti().is_none() {
+            claims_clone.set_jti(Ulid::new().to_string());
+        }
 
         let token = encode(&Header::default(), &claims_clone, &keys.encoding)
             .map_err(|e| JwtError::TokenCreationError(e.to_string()))?;
@@ -177,7 +204,7 @@ impl JwtUtils {
      * 验证JWT令牌
      * 
      * 验证JWT令牌的有效性，包括签名验证和声明验证。
-     * 验证令牌的受众（audience）是否匹配。
+     * 验证令牌的受众（audience）是否为指定受众或按域覆盖配置的受众之一。
      * 
      * # 参数
      * * `token` - 要验证的令牌字符串
@@ -203,8 +230,13 @@ impl JwtUtils {
             .ok_or(JwtError::ValidationNotInitialized)?;
         let validation = validation_arc.lock().await;
 
+        let mut audiences = vec![audience.to_string()];
+        if let Some(jwt_config) = global::get_config::<JwtConfig>().await {
+            audiences.extend(jwt_config.domain_audiences().map(str::to_string));
+        }
+
         let mut validation_clone = validation.clone();
-        validation_clone.set_audience(&[audience.to_string()]);
+        validation_clone.set_audience(&audiences);
         decode::<Claims>(token, &keys.decoding, &validation_clone)
             .map_err(|e| JwtError::TokenValidationError(e.to_string()))
     }
@@ -73,6 +73,19 @@
  * - 请求ID传递
  * - 请求追踪
  * - 日志关联
+ * 
+ * ## rate_limit 模块
+ * 提供请求限流功能：
+ * - 令牌桶限流
+ * - 按IP、API密钥或用户名区分
+ * - 内存或Redis存储
+ * - 429 响应
+ * 
+ * ## i18n 模块
+ * 提供国际化功能：
+ * - Accept-Language 语言协商
+ * - 按错误码查找本地化错误消息
+ * - 验证消息键的本地化
  */
 
 /**
@@ -131,6 +144,20 @@ pub mod validator;
  */
 pub mod operation_log;
 
+/**
+ * 当前用户上下文模块
+ * 
+ * 提供当前请求认证用户的读取功能
+ */
+mod current_user;
+
+/**
+ * 导出当前用户上下文
+ * 
+ * - CurrentUserContext: 当前用户上下文
+ */
+pub use current_user::CurrentUserContext;
+
 /**
  * 请求ID模块
  * 
@@ -143,5 +170,91 @@ mod request_id;
  * 
  * - RequestId: 请求ID类型
  * - RequestIdLayer: 请求ID中间件层
+ * - current_request_id: 读取当前请求的请求ID
+ * - request_id_from_headers: 从请求头解析请求ID，gRPC服务同样使用
+ */
+pub use request_id::{current_request_id, request_id_from_headers, RequestId, RequestIdLayer};
+
+/**
+ * 速率限制模块
+ * 
+ * 提供请求限流功能
+ */
+mod rate_limit;
+
+/**
+ * 导出速率限制相关类型
+ * 
+ * - RateLimitLayer: 速率限制中间件层
+ * - RateLimitKey: 限流对象识别方式
+ * - RateLimitStorage: 令牌桶存储
+ */
+pub use rate_limit::{FailureLimiter, RateLimitKey, RateLimitLayer, RateLimitStorage};
+
+/**
+ * 国际化模块
+ * 
+ * 提供语言协商和本地化消息
+ */
+pub mod i18n;
+
+/**
+ * 导出国际化相关类型
+ * 
+ * - Locale: 支持的语言
+ * - LocaleLayer: 语言协商中间件层
+ * - current_locale: 读取当前请求的语言
+ */
+pub use i18n::{current_locale, Locale, LocaleLayer};
+
+/**
+ * 响应字段命名模块
+ * 
+ * 提供按请求头将JSON响应的字段名转换为 snake_case 的功能
+ */
+pub mod naming;
+
+/**
+ * 导出响应字段命名相关类型
+ * 
+ * - ResponseNamingLayer: 响应字段命名中间件层
+ */
+pub use naming::ResponseNamingLayer;
+
+/**
+ * 响应字段选择模块
+ * 
+ * 提供按查询参数 `fields` 裁剪树形结构等较大响应的功能
+ */
+mod projection;
+
+/**
+ * 导出响应字段选择相关类型
+ * 
+ * - Projectable: 可按字段裁剪的输出结构
+ * - FieldSelection: 字段选择
+ * - FieldsQuery: 字段选择查询参数
+ */
+pub use projection::{FieldSelection, FieldsQuery, Projectable};
+
+/**
+ * 超时模块
+ * 
+ * 提供请求处理时间和内部依赖调用等待时间的限制
+ */
+mod timeout;
+
+/**
+ * 导出超时相关类型
+ * 
+ * - TimeoutLayer: 请求超时中间件层
+ * - RequestDeadline: 请求截止时间
+ * - DependencyError: 内部依赖错误
+ * - with_dependency_timeout: 限制内部依赖调用的等待时间
+ * - init_dependency_timeout: 设置内部依赖调用的等待上限
+ * - timeout_response: 构建请求超时响应
  */
-pub use request_id::{RequestId, RequestIdLayer};
+pub use timeout::{
+    init_dependency_timeout, timeout_response, with_dependency_timeout, DependencyError,
+    RequestDeadline, TimeoutLayer,
+};
@@ -0,0 +1,276 @@
+/**
+ * 响应字段命名模块
+ *
+ * 输出结构统一使用 camelCase 字段名。部分集成方需要 snake_case，
+ * 可在请求中携带 `X-Naming: snake_case`，由 `ResponseNamingLayer` 在写出响应前
+ * 将JSON响应中所有对象的键转换为 snake_case。
+ *
+ * 转换需要重新解析并序列化响应体，因此只在配置开启时注册该中间件层。
+ *
+ * # 使用示例
+ *
+ * ```rust,ignore
+ * let router = router.layer(ResponseNamingLayer);
+ * ```
+ */
+
+use std::task::{Context, Poll};
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::Response,
+};
+use serde_json::{Map, Value};
+use tower::{Layer, Service};
+
+/** 选择响应字段命名的请求头 */
+pub const NAMING_HEADER: &str = "x-naming";
+
+/**
+ * 响应字段命名方式
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldNaming {
+    /** 小驼峰（默认，输出结构的原始命名） */
+    #[default]
+    CamelCase,
+    /** 蛇形 */
+    SnakeCase,
+}
+
+impl FieldNaming {
+    /**
+     * 解析请求头的值
+     *
+     * # 参数
+     * * `value` - `X-Naming` 请求头的值，不区分大小写
+     *
+     * # 返回
+     * 无法识别的值返回 None
+     */
+    pub fn from_header(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "camelcase" | "camel_case" => Some(Self::CamelCase),
+            "snake_case" | "snakecase" => Some(Self::SnakeCase),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * 将 camelCase 字段名转换为 snake_case
+ *
+ * 已是 snake_case 的字段名保持不变，数字不作为分词边界（`i18nKey` 转换为 `i18n_key`）
+ *
+ * # 参数
+ * * `key` - 字段名
+ *
+ * # 返回
+ * snake_case 字段名
+ */
+pub fn to_snake_case(key: &str) -> String {
+    let mut output = String::with_capacity(key.len() + 4);
+    for (index, c) in key.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 && !output.ends_with('_') {
+                output.push('_');
+            }
+            output.push(c.to_ascii_lowercase());
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+/**
+ * 递归将JSON值中所有对象的键转换为 snake_case
+ *
+ * # 参数
+ * * `value` - 待转换的JSON值
+ */
+pub fn snake_case_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let converted: Map<String, Value> = std::mem::take(map)
+                .into_iter()
+                .map(|(key, mut item)| {
+                    snake_case_keys(&mut item);
+                    (to_snake_case(&key), item)
+                })
+                .collect();
+            *map = converted;
+        },
+        Value::Array(items) => items.iter_mut().for_each(snake_case_keys),
+        _ => {},
+    }
+}
+
+/**
+ * 响应字段命名中间件层
+ */
+#[derive(Clone, Debug)]
+pub struct ResponseNamingLayer;
+
+impl<S> Layer<S> for ResponseNamingLayer {
+    type Service = ResponseNamingMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ResponseNamingMiddleware { service }
+    }
+}
+
+/**
+ * 响应字段命名中间件
+ *
+ * 仅处理 JSON 响应，响应体无法解析时原样返回
+ */
+#[derive(Clone, Debug)]
+pub struct ResponseNamingMiddleware<S> {
+    service: S,
+}
+
+impl<S> Service<Request> for ResponseNamingMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Send + Clone + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let naming = req
+            .headers()
+            .get(NAMING_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(FieldNaming::from_header)
+            .unwrap_or_default();
+
+        let mut service = self.service.clone();
+        Box::pin(async move {
+            let response = service.call(req).await?;
+            if naming == FieldNaming::CamelCase || !is_json(&response) {
+                return Ok(response);
+            }
+            Ok(rewrite_keys(response).await)
+        })
+    }
+}
+
+/**
+ * 判断响应是否为 JSON
+ */
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.starts_with(mime::APPLICATION_JSON.as_ref()))
+        .unwrap_or(false)
+}
+
+/**
+ * 将 JSON 响应体的键转换为 snake_case
+ *
+ * 响应体读取或解析失败时返回已读取的原始内容
+ */
+async fn rewrite_keys(response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
+
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(mut value) => {
+            snake_case_keys(&mut value);
+            match serde_json::to_vec(&value) {
+                Ok(converted) => {
+                    parts.headers.insert(CONTENT_LENGTH, HeaderValue::from(converted.len()));
+                    Body::from(converted)
+                },
+                Err(_) => Body::from(bytes),
+            }
+        },
+        Err(_) => Body::from(bytes),
+    };
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{routing::get, Router};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::web::res::Res;
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("refreshExpiresIn"), "refresh_expires_in");
+        assert_eq!(to_snake_case("i18nKey"), "i18n_key");
+        assert_eq!(to_snake_case("request_id"), "request_id");
+        assert_eq!(to_snake_case("id"), "id");
+    }
+
+    #[test]
+    fn test_snake_case_keys_nested() {
+        let mut value = json!({
+            "menuName": "home",
+            "children": [{ "routeName": "child", "keepAlive": true }],
+            "tags": ["camelCaseValue"],
+        });
+        snake_case_keys(&mut value);
+        assert_eq!(
+            value,
+            json!({
+                "menu_name": "home",
+                "children": [{ "route_name": "child", "keep_alive": true }],
+                "tags": ["camelCaseValue"],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_layer_rewrites_only_when_requested() {
+        let app = Router::new()
+            .route("/", get(|| async { Res::new_data(json!({ "userName": "admin" })) }))
+            .layer(ResponseNamingLayer);
+
+        let request = |naming: Option<&str>| {
+            let mut builder = Request::builder().uri("/");
+            if let Some(naming) = naming {
+                builder = builder.header(NAMING_HEADER, naming);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        let body = |response: Response| async move {
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        };
+
+        let value = body(app.clone().oneshot(request(None)).await.unwrap()).await;
+        assert_eq!(value["data"]["userName"], "admin");
+
+        let response = app.clone().oneshot(request(Some("snake_case"))).await.unwrap();
+        let length = response.headers()[CONTENT_LENGTH].clone();
+        let value = body(response).await;
+        assert_eq!(value["data"]["user_name"], "admin");
+        assert!(value["data"].get("userName").is_none());
+        assert_eq!(length, serde_json::to_vec(&value).unwrap().len().to_string());
+    }
+}
@@ -30,10 +30,18 @@
  * - 查询操作日志
  * - 导出操作日志
  * - 清理过期日志
+ * 
+ * ## 请求级上下文
+ * 中间件为每个请求建立独立的 `OperationLogContext` 范围（任务本地变量），
+ * 请求处理结束时写入该请求的上下文，并发请求互不影响；已处于范围内时沿用外层范围。
+ * 
+ * ## 敏感字段脱敏
+ * 请求参数、请求体和响应体在发送事件前按 `OperationLogConfig::redact_fields`
+ * 递归替换敏感字段的值，文件上传等路由可通过 `OperationLogLayer::without_body` 不记录请求体。
  */
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     convert::Infallible,
     fmt,
     net::SocketAddr,
@@ -46,16 +54,21 @@ use axum::{
     response::Response,
 };
 use bytes::BytesMut;
-use chrono::Local;
+use chrono::Utc;
 use futures::{future::BoxFuture, StreamExt};
 use http::{Extensions, HeaderMap, Uri};
+use once_cell::sync::OnceCell;
 use serde_json::Value;
-use server_constant::definition::consts::SystemEvent;
-use server_global::global::{self, OperationLogContext};
+use server_config::OperationLogConfig;
+use server_global::{
+    global::{self, OperationLogContext},
+    project_error,
+};
+use tokio::time::timeout_at;
 use tower_layer::Layer;
 use tower_service::Service;
 
-use super::{auth::User, RequestId};
+use super::{auth::User, timeout_response, util::ClientIp, RequestDeadline, RequestId};
 
 /**
  * 用户代理请求头名称
@@ -72,6 +85,14 @@ const UNKNOWN_REQUEST_ID: &str = "unknown";
  */
 const DEFAULT_BODY_CAPACITY: usize = 1024 * 16;
 
+/**
+ * 脱敏后的字段值
+ */
+const REDACTED_VALUE: &str = "***";
+
+/** 需要脱敏的字段名（已规范化），服务启动时设置 */
+static REDACT_FIELDS: OnceCell<HashSet<String>> = OnceCell::new();
+
 /**
  * 操作日志层，用于创建操作日志中间件
  * 
@@ -84,6 +105,10 @@ pub struct OperationLogLayer {
      * 是否启用操作日志
      */
     pub enabled: bool,
+    /**
+     * 是否记录请求体
+     */
+    pub capture_body: bool,
 }
 
 impl OperationLogLayer {
@@ -97,7 +122,35 @@ impl OperationLogLayer {
      * * `Self` - 新的操作日志层实例
      */
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            capture_body: true,
+        }
+    }
+
+    /**
+     * 不记录请求体
+     * 
+     * 用于文件上传等请求体较大或不适合记录的路由，请求体不会被缓冲，直接传给内部服务。
+     * 
+     * # 返回
+     * * `Self` - 不记录请求体的操作日志层
+     */
+    pub fn without_body(mut self) -> Self {
+        self.capture_body = false;
+        self
+    }
+
+    /**
+     * 设置需要脱敏的字段名
+     * 
+     * 未设置时使用 `OperationLogConfig` 的默认字段
+     * 
+     * # 参数
+     * * `fields` - 字段名列表
+     */
+    pub fn init_redact_fields(fields: &[String]) {
+        let _ = REDACT_FIELDS.set(normalize_fields(fields));
     }
 }
 
@@ -125,6 +178,7 @@ where
         OperationLogMiddleware {
             inner: service,
             enabled: self.enabled,
+            capture_body: self.capture_body,
         }
     }
 }
@@ -153,6 +207,10 @@ pub struct OperationLogMiddleware<S> {
      * 是否启用操作日志
      */
     enabled: bool,
+    /**
+     * 是否记录请求体
+     */
+    capture_body: bool,
 }
 
 impl<S> Service<Request<Body>> for OperationLogMiddleware<S>
@@ -190,7 +248,9 @@ where
      * - 收集请求信息（方法、URI、头信息等）
      * - 处理请求
      * - 记录响应信息
-     * - 计算处理时间
+     * - 计算处理时间，超过请求截止时间时返回504并标记为超时
+     * - 对敏感字段脱敏
+     * - 写入当前请求的操作日志上下文
      * - 发送操作日志事件
      * 
      * # 参数
@@ -206,8 +266,9 @@ where
         }
 
         let mut inner = self.inner.clone();
-        Box::pin(async move {
-            let start_time = Local::now().naive_local();
+        let capture_body = self.capture_body;
+        let future = async move {
+            let start_time = Utc::now().naive_utc();
             let (parts, body) = req.into_parts();
             let headers = &parts.headers;
             let extensions = &parts.extensions;
@@ -219,22 +280,40 @@ where
                 .map(ToString::to_string)
                 .unwrap_or_else(|| UNKNOWN_REQUEST_ID.to_string());
 
-            if let Ok(bytes) = buffer_body(body).await {
+            let (body, bytes) = if capture_body {
+                match buffer_body(body).await {
+                    Ok(bytes) => (Body::from(bytes.clone()), Ok(bytes)),
+                    Err(e) => (Body::empty(), Err(e)),
+                }
+            } else {
+                (body, Ok(Bytes::new()))
+            };
+
+            if let Ok(bytes) = bytes {
                 let method = parts.method.to_string();
                 let uri = parts.uri.to_string();
                 let ip = get_client_ip(extensions, headers);
                 let user_agent = get_user_agent(headers);
-                let params = parse_query_params(&parts.uri);
-
-                let req = Request::from_parts(parts, Body::from(bytes.clone()));
-                let response = inner.call(req).await?;
+                let params = parse_query_params(&parts.uri).map(redact);
+
+                let deadline = extensions.get::<RequestDeadline>().map(|deadline| deadline.0);
+
+                let req = Request::from_parts(parts, body);
+                // 外层设置了截止时间时在此结束处理，超时的请求同样记录日志
+                let (response, timed_out) = match deadline {
+                    Some(deadline) => match timeout_at(deadline, inner.call(req)).await {
+                        Ok(response) => (response?, false),
+                        Err(_) => (timeout_response(), true),
+                    },
+                    None => (inner.call(req).await?, false),
+                };
 
                 let (response_parts, response_body) = response.into_parts();
                 let response_bytes = to_bytes(response_body, usize::MAX)
                     .await
                     .unwrap_or_default();
 
-                let end_time = Local::now().naive_local();
+                let end_time = Utc::now().naive_utc();
                 let duration = (end_time - start_time).num_milliseconds() as i32;
 
                 let context = OperationLogContext {
@@ -251,18 +330,21 @@ where
                     params,
                     body: (!bytes.is_empty())
                         .then(|| serde_json::from_slice(&bytes).ok())
-                        .flatten(),
-                    response: serde_json::from_slice(&response_bytes).ok(),
+                        .flatten()
+                        .map(redact),
+                    response: serde_json::from_slice(&response_bytes).ok().map(redact),
                     start_time,
                     end_time,
                     duration,
+                    timed_out,
+                    status_code: response_parts.status.as_u16(),
                     created_at: start_time,
                 };
 
-                global::send_dyn_event(
-                    SystemEvent::AuditOperationLoggedEvent.as_ref(),
-                    Box::new(context),
-                );
+                OperationLogContext::set(context.clone());
+                if let Err(e) = global::send_typed_event(context) {
+                    project_error!("Failed to publish operation log of request {}", e.0.request_id);
+                }
 
                 Ok(Response::from_parts(
                     response_parts,
@@ -270,7 +352,15 @@ where
                 ))
             } else {
                 let mut inner = inner;
-                inner.call(Request::from_parts(parts, Body::empty())).await
+                inner.call(Request::from_parts(parts, body)).await
+            }
+        };
+
+        Box::pin(async move {
+            if OperationLogContext::in_scope() {
+                future.await
+            } else {
+                OperationLogContext::scope(future).await
             }
         })
     }
@@ -330,12 +420,9 @@ fn get_user_agent(headers: &HeaderMap) -> Option<String> {
 }
 
 /**
- * 获取客户端 IP，优先从扩展中获取
+ * 获取客户端 IP
  *
- * 按照以下优先级获取客户端IP：
- * 1. 从请求扩展中获取（如果存在）
- * 2. 从请求头中获取
- * 3. 从Socket地址中获取
+ * 以连接对端地址为准，仅在对端为可信代理时使用转发请求头
  *
  * # 参数
  * * `extensions` - 请求扩展
@@ -346,36 +433,10 @@ fn get_user_agent(headers: &HeaderMap) -> Option<String> {
  */
 #[inline(always)]
 fn get_client_ip(extensions: &Extensions, headers: &HeaderMap) -> String {
-    // 首先尝试从扩展中获取
-    if let Some(ConnectInfo(addr)) = extensions.get::<ConnectInfo<SocketAddr>>() {
-        return addr.ip().to_string();
-    }
-
-    // 然后尝试从请求头中获取
-    let ip_headers = [
-        "X-Real-IP",
-        "X-Forwarded-For",
-        "CF-Connecting-IP",
-        "True-Client-IP",
-        "X-Client-IP",
-        "Fastly-Client-IP",
-        "X-Cluster-Client-IP",
-        "X-Original-Forwarded-For",
-    ];
-
-    for header_name in ip_headers {
-        if let Some(ip_header) = headers.get(header_name) {
-            if let Ok(ip_str) = ip_header.to_str() {
-                let real_ip = ip_str.split(',').next().unwrap_or("").trim();
-                if !real_ip.is_empty() {
-                    return real_ip.to_string();
-                }
-            }
-        }
-    }
-
-    // 如果都没有找到，返回unknown
-    "unknown".to_string()
+    let peer = extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    ClientIp::get_real_ip(peer, headers)
 }
 
 /**
@@ -427,6 +488,64 @@ fn parse_query_params(uri: &Uri) -> Option<Value> {
     })
 }
 
+/**
+ * 规范化字段名
+ *
+ * 转为小写并去掉下划线和连字符，使 snake_case、camelCase 和 kebab-case 写法一致
+ */
+fn normalize_field(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/**
+ * 规范化字段名列表
+ */
+fn normalize_fields(fields: &[String]) -> HashSet<String> {
+    fields.iter().map(|field| normalize_field(field)).collect()
+}
+
+/**
+ * 使用已设置的字段名对JSON值脱敏
+ */
+fn redact(mut value: Value) -> Value {
+    let fields = REDACT_FIELDS
+        .get_or_init(|| normalize_fields(&OperationLogConfig::default().redact_fields));
+    redact_value(&mut value, fields);
+    value
+}
+
+/**
+ * 递归替换敏感字段的值
+ *
+ * 匹配的字段无论值的类型都替换为 `***`，未匹配的对象和数组继续向下处理。
+ *
+ * # 参数
+ * * `value` - 待脱敏的JSON值
+ * * `fields` - 规范化后的字段名
+ */
+fn redact_value(value: &mut Value, fields: &HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if fields.contains(&normalize_field(key)) {
+                    *item = Value::String(REDACTED_VALUE.to_string());
+                } else {
+                    redact_value(item, fields);
+                }
+            }
+        },
+        Value::Array(items) => {
+            for item in items {
+                redact_value(item, fields);
+            }
+        },
+        _ => {},
+    }
+}
+
 impl fmt::Display for RequestId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -437,8 +556,9 @@ impl fmt::Display for RequestId {
 mod tests {
     use super::*;
     use axum::http::Method;
-    use crate::web::auth::User;
+    use crate::web::{auth::User, TimeoutLayer};
     use serde_json::json;
+    use std::time::Duration;
 
     /**
      * 创建测试用户
@@ -486,31 +606,49 @@ mod tests {
     }
 
     /**
-     * 断言操作日志上下文
+     * 经过中间件处理请求并返回记录的操作日志上下文
      * 
      * # 参数
+     * * `layer` - 操作日志层
      * * `method` - HTTP方法
      * * `uri` - 请求URI
-     * * `params` - 查询参数
-     * * `body` - 请求体
+     * * `body` - 请求体，同时作为响应体返回
      */
-    async fn assert_context(method: &str, uri: &str, params: Option<Value>, body: Option<Value>) {
+    async fn capture_context(
+        layer: OperationLogLayer,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> OperationLogContext {
         let req = create_request(
             Method::from_bytes(method.as_bytes()).unwrap(),
             uri,
-            body.clone(),
+            body,
         );
 
-        let mut service = OperationLogMiddleware {
-            inner: tower::service_fn(|_req: Request<Body>| async move {
-                Ok::<_, Infallible>(Response::new(Body::empty()))
-            }),
-            enabled: true,
-        };
+        let mut service = layer.layer(tower::service_fn(|req: Request<Body>| async move {
+            Ok::<_, Infallible>(Response::new(req.into_body()))
+        }));
 
-        let _ = service.call(req).await;
+        OperationLogContext::scope(async move {
+            let _ = service.call(req).await;
+            OperationLogContext::get().unwrap()
+        })
+        .await
+    }
 
-        let context = global::OperationLogContext::get().await.unwrap();
+    /**
+     * 断言操作日志上下文
+     * 
+     * # 参数
+     * * `method` - HTTP方法
+     * * `uri` - 请求URI
+     * * `params` - 查询参数
+     * * `body` - 请求体
+     */
+    async fn assert_context(method: &str, uri: &str, params: Option<Value>, body: Option<Value>) {
+        let context =
+            capture_context(OperationLogLayer::new(true), method, uri, body.clone()).await;
         assert_eq!(context.method, method);
         assert_eq!(context.url, uri);
         assert_eq!(context.params, params);
@@ -558,5 +696,172 @@ mod tests {
 
         // 测试DELETE请求
         assert_context("DELETE", "/api/test/1", None, None).await;
+
+        // 登录请求的密码在请求体和响应体中都被脱敏
+        let context = capture_context(
+            OperationLogLayer::new(true),
+            "POST",
+            "/auth/login?token=abc",
+            Some(json!({
+                "username": "admin",
+                "password": "Plaintext#123"
+            })),
+        )
+        .await;
+        let expected = json!({
+            "username": "admin",
+            "password": "***"
+        });
+        assert_eq!(context.body, Some(expected.clone()));
+        assert_eq!(context.response, Some(expected));
+        assert_eq!(context.params, Some(json!({ "token": "***" })));
+        assert!(!serde_json::to_string(&context.body).unwrap().contains("Plaintext#123"));
+
+        // 不记录请求体的路由仍然把请求体传给内部服务
+        let context = capture_context(
+            OperationLogLayer::new(true).without_body(),
+            "POST",
+            "/api/upload",
+            Some(json!({
+                "name": "file"
+            })),
+        )
+        .await;
+        assert_eq!(context.body, None);
+        assert_eq!(context.response, Some(json!({ "name": "file" })));
+    }
+
+    /**
+     * 测试并发请求各自记录自己的操作日志上下文
+     */
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_requests_keep_own_context() {
+        let service = OperationLogLayer::new(true).layer(tower::service_fn(
+            |req: Request<Body>| async move {
+                // 让并发请求在处理过程中交错执行
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                Ok::<_, Infallible>(Response::new(req.into_body()))
+            },
+        ));
+
+        let tasks: Vec<_> = (0..50)
+            .map(|index| {
+                let mut service = service.clone();
+                tokio::spawn(OperationLogContext::scope(async move {
+                    let uri = format!("/api/test/{}?index={}", index, index);
+                    let body = json!({ "index": index });
+                    let req = create_request(Method::POST, &uri, Some(body.clone()));
+                    let _ = service.call(req).await;
+                    (uri, body, OperationLogContext::get())
+                }))
+            })
+            .collect();
+
+        for task in tasks {
+            let (uri, body, context) = task.await.unwrap();
+            let context = context.expect("context recorded for request");
+            assert_eq!(context.url, uri);
+            assert_eq!(context.body, Some(body));
+        }
+
+        // 范围之外没有操作日志上下文
+        assert!(OperationLogContext::get().is_none());
+    }
+
+    /**
+     * 测试超时请求返回504并记录超时标记和持续时间
+     */
+    #[tokio::test]
+    async fn test_timed_out_request_is_logged() {
+        let budget = Duration::from_millis(50);
+        let slow = tower::service_fn(|_req: Request<Body>| async move {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok::<_, Infallible>(Response::new(Body::from("done")))
+        });
+        let service = OperationLogLayer::new(true).layer(slow);
+        let mut service = TimeoutLayer::new(budget).layer(service);
+        let req = create_request(Method::GET, "/api/slow", None);
+
+        let (response, context) = OperationLogContext::scope(async move {
+            let response = service.call(req).await.unwrap();
+            (response, OperationLogContext::get().unwrap())
+        })
+        .await;
+
+        assert_eq!(response.status(), http::StatusCode::GATEWAY_TIMEOUT);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["code"], 504);
+
+        assert!(context.timed_out);
+        assert_eq!(context.status_code, 504);
+        assert!(context.duration >= budget.as_millis() as i32);
+        assert_eq!(context.url, "/api/slow");
+        assert_eq!(context.response.unwrap()["code"], 504);
+
+        // 未超时的请求不标记
+        let context = capture_context(OperationLogLayer::new(true), "GET", "/api/fast", None).await;
+        assert!(!context.timed_out);
+        assert_eq!(context.status_code, 200);
+    }
+
+    /**
+     * 测试记录响应的HTTP状态码
+     */
+    #[tokio::test]
+    async fn test_response_status_is_logged() {
+        let failing = tower::service_fn(|_req: Request<Body>| async move {
+            let mut response = Response::new(Body::from(r#"{"code":403}"#));
+            *response.status_mut() = http::StatusCode::FORBIDDEN;
+            Ok::<_, Infallible>(response)
+        });
+        let mut service = OperationLogLayer::new(true).layer(failing);
+        let req = create_request(Method::DELETE, "/api/test/1", None);
+
+        let (response, context) = OperationLogContext::scope(async move {
+            let response = service.call(req).await.unwrap();
+            (response, OperationLogContext::get().unwrap())
+        })
+        .await;
+
+        assert_eq!(response.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(context.status_code, 403);
+        assert_eq!(context.response.unwrap()["code"], 403);
+    }
+
+    /**
+     * 测试嵌套对象和数组的脱敏
+     */
+    #[test]
+    fn test_redact_value() {
+        let fields = normalize_fields(&OperationLogConfig::default().redact_fields);
+        let mut value = json!({
+            "users": [
+                { "username": "a", "password": "p1" },
+                {
+                    "username": "b",
+                    "profile": { "oldPassword": "p2", "ACCESS_KEY_SECRET": "s" }
+                }
+            ],
+            "token": { "nested": "t" },
+            "tokenType": "Bearer"
+        });
+
+        redact_value(&mut value, &fields);
+
+        assert_eq!(
+            value,
+            json!({
+                "users": [
+                    { "username": "a", "password": "***" },
+                    {
+                        "username": "b",
+                        "profile": { "oldPassword": "***", "ACCESS_KEY_SECRET": "***" }
+                    }
+                ],
+                "token": "***",
+                "tokenType": "Bearer"
+            })
+        );
     }
 }
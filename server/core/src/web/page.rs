@@ -17,6 +17,9 @@
  * - sort_by: 排序字段
  * - sort_order: 排序方向
  * 
+ * 未携带 `size` 时使用分页配置中的默认值，超过上限时按配置拒绝或按上限处理。
+ * 页码和每页数量同时接受数字和数字字符串，查询字符串经 `#[serde(flatten)]` 展开时同样可以解析。
+ * 
  * ## PageResult
  * 分页查询结果，包含以下字段：
  * - total: 总记录数
@@ -24,9 +27,38 @@
  * - size: 每页数量
  * - total_pages: 总页数
  * - records: 当前页数据
+ * 
+ * ## CursorPage
+ * 游标分页结果，包含以下字段：
+ * - records: 当前页数据
+ * - next_cursor: 下一页游标，没有更多数据时为空
+ * 
+ * ## KeywordFilter
+ * 关键字筛选条件，对 LIKE 通配符转义后在多个列上做模糊或精确匹配
+ */
+
+use sea_orm::{
+    sea_query::{Expr, LikeExpr},
+    ColumnTrait, Condition,
+};
+use once_cell::sync::OnceCell;
+use serde::{de, Deserialize, Deserializer, Serialize};
+use server_config::{OversizePolicy, PaginationConfig};
+
+/**
+ * 关键字最大长度
  */
+pub const KEYWORD_MAX_LENGTH: usize = 100;
 
-use serde::{Deserialize, Serialize};
+/**
+ * LIKE 模式的转义字符
+ */
+const LIKE_ESCAPE: char = '\\';
+
+/**
+ * 分页配置，服务启动时设置
+ */
+static PAGINATION: OnceCell<PaginationConfig> = OnceCell::new();
 
 /**
  * 分页查询参数
@@ -40,15 +72,15 @@ pub struct PageQuery {
      * 
      * 从1开始计数
      */
-    #[serde(default = "default_page")]
+    #[serde(default = "default_page", deserialize_with = "deserialize_page")]
     pub current: u64,
 
     /**
      * 每页数量
      * 
-     * 默认值为10
+     * 默认值和上限由分页配置决定
      */
-    #[serde(default = "default_page_size")]
+    #[serde(default = "default_page_size", deserialize_with = "deserialize_page_size")]
     pub size: u64,
 
     /**
@@ -111,6 +143,27 @@ pub struct PageResult<T> {
     pub records: Vec<T>,
 }
 
+/**
+ * 游标分页结果
+ * 
+ * 用于基于游标（keyset）的分页查询。与偏移分页相比不需要统计总数，
+ * 也不会因 OFFSET 扫描随页码增长而变慢，适合导出和滚动加载场景。
+ */
+#[derive(Debug, Serialize)]
+pub struct CursorPage<T> {
+    /**
+     * 当前页数据
+     */
+    pub records: Vec<T>,
+
+    /**
+     * 下一页游标
+     * 
+     * 作为下一次请求的 after 参数传入；为空表示没有更多数据
+     */
+    pub next_cursor: Option<String>,
+}
+
 /**
  * 分页数据
  * 
@@ -137,13 +190,117 @@ fn default_page() -> u64 {
 /**
  * 默认每页数量
  * 
- * 返回默认的每页数量：10
+ * 返回分页配置中的默认每页数量，不超过每页数量上限
  */
 fn default_page_size() -> u64 {
-    10
+    let config = pagination_config();
+    config.default_size.min(config.max_size)
+}
+
+/**
+ * 获取分页配置
+ * 
+ * 未设置时使用默认配置
+ */
+fn pagination_config() -> &'static PaginationConfig {
+    PAGINATION.get_or_init(PaginationConfig::default)
+}
+
+/**
+ * 反序列化页码
+ * 
+ * 页码必须大于0
+ */
+fn deserialize_page<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let page = deserialize_number(deserializer)?;
+    if page == 0 {
+        return Err(de::Error::custom("current must be greater than 0"));
+    }
+    Ok(page)
+}
+
+/**
+ * 反序列化每页数量
+ * 
+ * 按分页配置检查每页数量上限
+ */
+fn deserialize_page_size<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let size = deserialize_number(deserializer)?;
+    resolve_page_size(size, pagination_config()).map_err(de::Error::custom)
+}
+
+/**
+ * 按分页配置确定每页数量
+ * 
+ * 每页数量必须大于0；超过上限时按配置返回错误或返回上限
+ */
+fn resolve_page_size(size: u64, config: &PaginationConfig) -> Result<u64, String> {
+    if size == 0 {
+        return Err("size must be greater than 0".to_string());
+    }
+    if size <= config.max_size {
+        return Ok(size);
+    }
+    match config.oversize {
+        OversizePolicy::Reject => Err(format!("size must not exceed {}", config.max_size)),
+        OversizePolicy::Clamp => Ok(config.max_size),
+    }
+}
+
+/**
+ * 反序列化非负整数
+ * 
+ * 同时接受数字和数字字符串。查询字符串经 `#[serde(flatten)]` 展开后所有值都是字符串，
+ * 直接按 `u64` 反序列化会失败。
+ */
+fn deserialize_number<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct NumberVisitor;
+
+    impl de::Visitor<'_> for NumberVisitor {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a non-negative integer")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<u64, E> {
+            u64::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+            value.trim().parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+
+    deserializer.deserialize_any(NumberVisitor)
 }
 
 impl PageQuery {
+    /**
+     * 设置分页配置
+     * 
+     * 应在服务启动时、处理任何请求之前调用一次，未设置时使用默认配置
+     * 
+     * # 参数
+     * * `config` - 分页配置
+     */
+    pub fn init_pagination(config: PaginationConfig) {
+        let _ = PAGINATION.set(config);
+    }
+
     /**
      * 获取偏移量
      * 
@@ -293,9 +450,202 @@ impl<T> Default for PageResult<T> {
     }
 }
 
+/**
+ * 关键字筛选条件
+ * 
+ * 统一构建分页查询的关键字条件。关键字中的 `%`、`_` 和转义字符本身会被转义，
+ * 用户输入只按字面匹配，不会被当作通配符。多个列之间为或关系。
+ * 
+ * # 示例
+ * 
+ * let condition = KeywordFilter::new("a_b").condition(&[Column::Username, Column::NickName]);
+ */
+#[derive(Debug, Clone)]
+pub struct KeywordFilter {
+    keyword: String,
+    exact: bool,
+}
+
+impl KeywordFilter {
+    /**
+     * 创建关键字筛选条件，默认为包含匹配
+     * 
+     * # 参数
+     * 
+     * * `keyword` - 原始关键字
+     */
+    pub fn new(keyword: &str) -> Self {
+        Self {
+            keyword: keyword.to_string(),
+            exact: false,
+        }
+    }
+
+    /**
+     * 设置是否精确匹配
+     * 
+     * 精确匹配时使用等值比较，否则按包含匹配
+     */
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /**
+     * 转义关键字中的 LIKE 通配符
+     * 
+     * # 返回值
+     * 
+     * 返回可以安全拼入 LIKE 模式的字符串
+     */
+    pub fn escape(keyword: &str) -> String {
+        let mut escaped = String::with_capacity(keyword.len());
+        for c in keyword.chars() {
+            if matches!(c, '%' | '_' | LIKE_ESCAPE) {
+                escaped.push(LIKE_ESCAPE);
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /**
+     * 构建包含匹配的 LIKE 模式
+     */
+    pub fn pattern(&self) -> String {
+        format!("%{}%", Self::escape(&self.keyword))
+    }
+
+    /**
+     * 在指定列上构建筛选条件，任意一列匹配即满足
+     * 
+     * # 参数
+     * 
+     * * `columns` - 参与匹配的列
+     */
+    pub fn condition<C: ColumnTrait>(&self, columns: &[C]) -> Condition {
+        columns.iter().fold(Condition::any(), |condition, column| {
+            if self.exact {
+                condition.add(column.eq(self.keyword.as_str()))
+            } else {
+                condition.add(
+                    Expr::col((column.entity_name(), *column))
+                        .like(LikeExpr::new(self.pattern()).escape(LIKE_ESCAPE)),
+                )
+            }
+        })
+    }
+}
+
+/**
+ * 反序列化分页请求中的关键字
+ * 
+ * 去除首尾空白，空字符串视为未传入；超过 KEYWORD_MAX_LENGTH 个字符时拒绝请求。
+ * 用于 `#[serde(default, deserialize_with = "deserialize_keywords")]`。
+ */
+pub fn deserialize_keywords<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let keywords = Option::<String>::deserialize(deserializer)?;
+    let Some(keywords) = keywords.map(|value| value.trim().to_string()) else {
+        return Ok(None);
+    };
+    if keywords.is_empty() {
+        return Ok(None);
+    }
+    if keywords.chars().count() > KEYWORD_MAX_LENGTH {
+        return Err(de::Error::custom(format!(
+            "Keywords must not exceed {} characters",
+            KEYWORD_MAX_LENGTH
+        )));
+    }
+    Ok(Some(keywords))
+}
+
 #[macro_export]
 macro_rules! paginated_data {
     ($total:expr, $current:expr, $size:expr, $records:expr) => {
         $crate::web::page::PaginatedData::new($total, $current, $size, $records)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct KeywordsRequest {
+        #[serde(default, deserialize_with = "deserialize_keywords")]
+        keywords: Option<String>,
+    }
+
+    fn keywords(input: serde_json::Value) -> Result<Option<String>, serde_json::Error> {
+        serde_json::from_value::<KeywordsRequest>(input).map(|request| request.keywords)
+    }
+
+    #[test]
+    fn test_escape_wildcards() {
+        assert_eq!(KeywordFilter::escape("100%"), "100\\%");
+        assert_eq!(KeywordFilter::escape("a_b"), "a\\_b");
+        assert_eq!(KeywordFilter::escape("a\\%"), "a\\\\\\%");
+        assert_eq!(KeywordFilter::escape("%_%"), "\\%\\_\\%");
+        assert_eq!(KeywordFilter::escape("管理员"), "管理员");
+    }
+
+    #[test]
+    fn test_pattern_wraps_escaped_keyword() {
+        assert_eq!(KeywordFilter::new("%").pattern(), "%\\%%");
+        assert_eq!(KeywordFilter::new("admin").pattern(), "%admin%");
+    }
+
+    #[test]
+    fn test_deserialize_keywords() {
+        assert_eq!(keywords(json!({ "keywords": "  admin " })).unwrap(), Some("admin".to_string()));
+        assert_eq!(keywords(json!({ "keywords": "   " })).unwrap(), None);
+        assert_eq!(keywords(json!({})).unwrap(), None);
+        assert!(keywords(json!({ "keywords": "a".repeat(KEYWORD_MAX_LENGTH) })).is_ok());
+        assert!(keywords(json!({ "keywords": "a".repeat(KEYWORD_MAX_LENGTH + 1) })).is_err());
+    }
+
+    fn page(input: serde_json::Value) -> Result<PageQuery, serde_json::Error> {
+        serde_json::from_value::<PageQuery>(input)
+    }
+
+    #[test]
+    fn test_missing_size_uses_default() {
+        let query = page(json!({})).unwrap();
+        assert_eq!((query.current, query.size), (1, 10));
+
+        let query = page(json!({ "current": "2", "size": "20" })).unwrap();
+        assert_eq!((query.current, query.size), (2, 20));
+    }
+
+    #[test]
+    fn test_page_size_limit() {
+        let max_size = PaginationConfig::default().max_size;
+        assert_eq!(page(json!({ "size": max_size })).unwrap().size, max_size);
+
+        let err = page(json!({ "size": max_size + 1 })).unwrap_err();
+        assert!(err.to_string().contains("size must not exceed 100"));
+        assert!(page(json!({ "size": 100000 })).is_err());
+        assert!(page(json!({ "size": 0 })).is_err());
+        assert!(page(json!({ "current": 0 })).is_err());
+        assert!(page(json!({ "size": "x" })).is_err());
+    }
+
+    #[test]
+    fn test_resolve_page_size_clamp() {
+        let config = PaginationConfig {
+            default_size: 20,
+            max_size: 50,
+            oversize: OversizePolicy::Clamp,
+        };
+        assert_eq!(resolve_page_size(50, &config), Ok(50));
+        assert_eq!(resolve_page_size(51, &config), Ok(50));
+        assert_eq!(resolve_page_size(100000, &config), Ok(50));
+        assert!(resolve_page_size(0, &config).is_err());
+    }
+}
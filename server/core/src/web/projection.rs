@@ -0,0 +1,265 @@
+/**
+ * 响应字段选择模块
+ *
+ * 树形结构等较大的响应可通过查询参数 `fields` 只返回需要的字段，
+ * 例如 `GET /menu/tree?fields=menuName,routePath`。字段名与响应中的键一致，
+ * 同时接受 snake_case 写法；`id` 和 `children` 始终保留，子节点按同样的字段裁剪。
+ *
+ * 可选择的字段由输出结构实现 `Projectable` 声明，包含未声明的字段时
+ * 查询参数反序列化失败，由 `ValidatedQuery` 返回422并列出可选字段。
+ *
+ * # 使用示例
+ *
+ * ```rust,ignore
+ * pub async fn tree_menu(
+ *     ValidatedQuery(query): ValidatedQuery<FieldsQuery<MenuTree>>,
+ *     Extension(service): Extension<Arc<SysMenuService>>,
+ * ) -> Result<Res<Value>, AppError> {
+ *     let result = service.tree_menu().await?;
+ *     query.fields.project(&result).map(Res::new_data)
+ * }
+ * ```
+ */
+
+use std::{collections::HashSet, fmt, marker::PhantomData};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::{error::AppError, naming::to_snake_case};
+
+/** 始终保留的字段 */
+const ALWAYS_KEPT: [&str; 2] = ["id", "children"];
+
+/**
+ * 可按字段裁剪的输出结构
+ */
+pub trait Projectable {
+    /**
+     * 可选择的字段名，与序列化后的键一致
+     */
+    const FIELDS: &'static [&'static str];
+}
+
+/**
+ * 字段选择
+ *
+ * 未指定字段时返回完整输出
+ *
+ * # 类型参数
+ *
+ * * `T`: 被裁剪的输出结构
+ */
+pub struct FieldSelection<T> {
+    fields: Option<HashSet<&'static str>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for FieldSelection<T> {
+    fn default() -> Self {
+        Self {
+            fields: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for FieldSelection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldSelection").field("fields", &self.fields).finish()
+    }
+}
+
+impl<T: Projectable> FieldSelection<T> {
+    /**
+     * 解析逗号分隔的字段名
+     *
+     * 忽略空白和空项，全部为空时选择完整输出
+     *
+     * # 参数
+     * * `value` - 查询参数 `fields` 的值
+     *
+     * # 返回
+     * 包含未声明的字段时返回错误消息，列出这些字段和可选字段
+     */
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut fields = HashSet::new();
+        let mut unknown = Vec::new();
+        for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let snake_name = to_snake_case(name);
+            match T::FIELDS.iter().find(|field| to_snake_case(field) == snake_name) {
+                Some(field) => {
+                    fields.insert(*field);
+                },
+                None => unknown.push(name),
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(format!(
+                "unknown fields: {}; valid fields: {}",
+                unknown.join(", "),
+                T::FIELDS.join(", ")
+            ));
+        }
+        Ok(Self {
+            fields: (!fields.is_empty()).then_some(fields),
+            _marker: PhantomData,
+        })
+    }
+
+    /**
+     * 序列化输出并只保留选择的字段
+     *
+     * 对象和数组递归处理，`children` 下的节点同样裁剪
+     *
+     * # 参数
+     * * `output` - 输出数据，可以是单个结构或列表
+     *
+     * # 返回
+     * 裁剪后的JSON值，未指定字段时为完整输出
+     */
+    pub fn project<O: Serialize + ?Sized>(&self, output: &O) -> Result<Value, AppError> {
+        let mut value =
+            serde_json::to_value(output).map_err(|e| AppError::new(500, e.to_string()))?;
+        if let Some(fields) = &self.fields {
+            retain_fields(&mut value, fields);
+        }
+        Ok(value)
+    }
+}
+
+impl<'de, T: Projectable> Deserialize<'de> for FieldSelection<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).map_err(de::Error::custom)
+    }
+}
+
+/**
+ * 字段选择查询参数
+ *
+ * 可与其他查询参数提取器同时使用，只读取 `fields`
+ */
+#[derive(Debug, Deserialize)]
+#[serde(bound = "T: Projectable")]
+pub struct FieldsQuery<T> {
+    /** 逗号分隔的字段名，未携带时返回完整输出 */
+    #[serde(default)]
+    pub fields: FieldSelection<T>,
+}
+
+/**
+ * 递归保留选择的字段
+ */
+fn retain_fields(value: &mut Value, fields: &HashSet<&'static str>) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(|item| retain_fields(item, fields)),
+        Value::Object(map) => {
+            map.retain(|key, _| {
+                ALWAYS_KEPT.contains(&key.as_str()) || fields.contains(key.as_str())
+            });
+            if let Some(children) = map.get_mut("children") {
+                retain_fields(children, fields);
+            }
+        },
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{extract::Query, http::Uri};
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Node {
+        id: usize,
+        menu_name: String,
+        route_path: String,
+        component: String,
+        i18n_key: Option<String>,
+        children: Option<Vec<Node>>,
+    }
+
+    impl Projectable for Node {
+        const FIELDS: &'static [&'static str] =
+            &["id", "menuName", "routePath", "component", "i18nKey", "children"];
+    }
+
+    fn node(id: usize, children: Option<Vec<Node>>) -> Node {
+        Node {
+            id,
+            menu_name: format!("menu-{}", id),
+            route_path: format!("/manage/menu-{}", id),
+            component: format!("layout.base$view.manage_menu_{}", id),
+            i18n_key: Some(format!("route.manage_menu_{}", id)),
+            children,
+        }
+    }
+
+    #[test]
+    fn test_project_nested() {
+        let tree = vec![node(1, Some(vec![node(2, None)]))];
+
+        let selection = FieldSelection::<Node>::parse("menu_name").unwrap();
+        assert_eq!(
+            selection.project(&tree).unwrap(),
+            json!([{
+                "id": 1,
+                "menuName": "menu-1",
+                "children": [{"id": 2, "menuName": "menu-2", "children": null}],
+            }])
+        );
+
+        // 未指定字段时返回完整输出
+        let full = FieldSelection::<Node>::parse(" , ").unwrap().project(&tree).unwrap();
+        assert_eq!(full, serde_json::to_value(&tree).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_fields_rejected() {
+        let err = FieldSelection::<Node>::parse("menuName,password,secret").unwrap_err();
+        assert!(err.contains("unknown fields: password, secret"), "{}", err);
+        assert!(err.contains("valid fields: id, menuName, routePath"), "{}", err);
+
+        let uri: Uri = "/tree?fields=menuName,password&other=1".parse().unwrap();
+        let err = Query::<FieldsQuery<Node>>::try_from_uri(&uri).unwrap_err();
+        assert!(err.body_text().contains("unknown fields: password"), "{}", err.body_text());
+        let uri: Uri = "/tree?other=1".parse().unwrap();
+        let Query(query) = Query::<FieldsQuery<Node>>::try_from_uri(&uri).unwrap();
+        assert!(query.fields.fields.is_none());
+    }
+
+    #[test]
+    fn test_projection_shrinks_large_tree() {
+        // 1000个节点：10个根节点，每个根节点下9个子节点，每个子节点下10个孙节点
+        let tree: Vec<Node> = (0..10)
+            .map(|i| {
+                let children = (0..9)
+                    .map(|j| {
+                        let base = 1000 + i * 100 + j * 10;
+                        let leaves = (0..10).map(|k| node(base + k, None)).collect();
+                        node(100 + i * 10 + j, Some(leaves))
+                    })
+                    .collect();
+                node(i, Some(children))
+            })
+            .collect();
+
+        let full = FieldSelection::<Node>::default().project(&tree).unwrap().to_string();
+        let selection = FieldSelection::<Node>::parse("menuName").unwrap();
+        let pruned = selection.project(&tree).unwrap().to_string();
+        assert!(
+            pruned.len() * 2 < full.len(),
+            "pruned {} bytes, full {} bytes",
+            pruned.len(),
+            full.len()
+        );
+    }
+}
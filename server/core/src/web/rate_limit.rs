@@ -0,0 +1,783 @@
+/**
+ * 速率限制模块
+ *
+ * 该模块基于令牌桶算法提供请求限流功能，用于防止接口被脚本化请求滥用。
+ * 主要功能包括：
+ * - 按客户端IP、API密钥或登录用户名区分限流对象
+ * - 令牌桶存储于内存或Redis，Redis存储时多个副本共享同一份限制
+ * - 超出限制时返回 429，携带 `Retry-After` 响应头和统一响应结构
+ *
+ * # 主要组件
+ *
+ * ## RateLimitLayer
+ * 速率限制中间件层，每个路由组一个实例：
+ * - 从 `LimitConfig` 构建令牌桶参数
+ * - 选择限流对象的识别方式
+ * - 选择令牌桶存储
+ *
+ * ## FailureLimiter
+ * 失败次数限制器，复用令牌桶：
+ * - 每次认证失败消耗一个令牌
+ * - 令牌耗尽后按客户端IP拒绝请求，直至令牌补充
+ *
+ * # 使用示例
+ *
+ * ```rust,ignore
+ * let layer = RateLimitLayer::new("auth", &limit_config, RateLimitStorage::Memory)
+ *     .key_by(RateLimitKey::Username);
+ * let router = router.layer(layer);
+ * ```
+ */
+
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{ConnectInfo, Request},
+    http::{
+        header::{CONTENT_TYPE, RETRY_AFTER},
+        HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+use moka::sync::Cache;
+use parking_lot::Mutex;
+use redis::{RedisError, Script};
+use server_config::LimitConfig;
+use server_global::{global::RedisConnection, redis_key::KeyBuilder};
+use tower::{Layer, Service};
+
+use crate::{
+    sign::ApiKeyIdentity,
+    web::{res::Res, util::ClientIp},
+};
+
+/** 按用户名限流时读取的最大请求体大小 */
+const MAX_USERNAME_BODY_SIZE: usize = 64 * 1024;
+
+/** 内存存储最多保留的令牌桶数量 */
+const MAX_MEMORY_BUCKETS: u64 = 100_000;
+
+/**
+ * Redis 令牌桶脚本
+ *
+ * 使用 Redis 服务器时间计算令牌补充，避免多个副本之间的时钟偏差。
+ * 返回需要等待的毫秒数，0 表示放行。
+ */
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local rate = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(state[1]) or capacity
+local ts = tonumber(state[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - ts) * rate)
+local wait = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    wait = math.ceil((1 - tokens) / rate)
+end
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], math.ceil(capacity / rate) + 1000)
+return wait
+"#;
+
+/**
+ * Redis 令牌桶查询脚本
+ *
+ * 与 TOKEN_BUCKET_SCRIPT 计算方式相同，但不消耗令牌。
+ * 令牌桶不存在时视为已满，返回 0。
+ */
+const TOKEN_BUCKET_PEEK_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local rate = tonumber(ARGV[2])
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+if not state[1] then
+    return 0
+end
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local tokens = math.min(capacity, tonumber(state[1]) + math.max(0, now - tonumber(state[2])) * rate)
+if tokens >= 1 then
+    return 0
+end
+return math.ceil((1 - tokens) / rate)
+"#;
+
+/**
+ * 限流对象识别方式
+ *
+ * 无法识别对应对象时（如请求未携带API密钥）退回到客户端IP
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitKey {
+    /** 按客户端IP限流（默认） */
+    #[default]
+    ClientIp,
+    /** 按签名中间件识别出的API密钥限流 */
+    ApiKey,
+    /** 按请求体中的 `username` 字段限流，用于登录接口 */
+    Username,
+}
+
+/**
+ * 令牌桶存储
+ *
+ * - Memory: 进程内存储，仅对当前实例生效
+ * - Redis: 复用全局 Redis 连接，多个副本共享
+ */
+#[derive(Clone)]
+pub enum RateLimitStorage {
+    Memory,
+    Redis(RedisConnection),
+}
+
+/**
+ * 令牌桶参数
+ */
+#[derive(Debug, Clone, Copy)]
+struct TokenBucketRule {
+    /** 令牌桶容量（突发请求数） */
+    capacity: f64,
+    /** 每毫秒补充的令牌数 */
+    refill_per_ms: f64,
+}
+
+impl TokenBucketRule {
+    /**
+     * 从限制配置构建令牌桶参数
+     *
+     * 容量取 burst，未配置时取 max_requests；补充速率为 max_requests / window
+     */
+    fn from_config(config: &LimitConfig) -> Self {
+        let max_requests = config.max_requests.max(1) as f64;
+        let window_ms = (config.window.max(1) as f64) * 1000.0;
+        Self {
+            capacity: config.burst.unwrap_or(config.max_requests).max(1) as f64,
+            refill_per_ms: max_requests / window_ms,
+        }
+    }
+
+    /** 令牌桶从空到满所需的时间 */
+    fn full_refill(&self) -> Duration {
+        Duration::from_millis((self.capacity / self.refill_per_ms).ceil() as u64)
+    }
+}
+
+/**
+ * 内存令牌桶状态
+ */
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl BucketState {
+    fn full(rule: &TokenBucketRule, now: Instant) -> Self {
+        Self {
+            tokens: rule.capacity,
+            updated_at: now,
+        }
+    }
+
+    /**
+     * 尝试获取一个令牌
+     *
+     * # 返回值
+     *
+     * 放行返回 None，否则返回需要等待的时间
+     */
+    fn try_acquire(&mut self, rule: &TokenBucketRule, now: Instant) -> Option<Duration> {
+        self.tokens = self.available(rule, now);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Self::wait_for(self.tokens, rule)
+        }
+    }
+
+    /**
+     * 查询是否还有可用令牌，不消耗令牌
+     *
+     * # 返回值
+     *
+     * 有可用令牌返回 None，否则返回需要等待的时间
+     */
+    fn peek(&self, rule: &TokenBucketRule, now: Instant) -> Option<Duration> {
+        let tokens = self.available(rule, now);
+        if tokens >= 1.0 {
+            None
+        } else {
+            Self::wait_for(tokens, rule)
+        }
+    }
+
+    /** 补充到指定时间后的令牌数 */
+    fn available(&self, rule: &TokenBucketRule, now: Instant) -> f64 {
+        let elapsed_ms = now.saturating_duration_since(self.updated_at).as_secs_f64() * 1000.0;
+        (self.tokens + elapsed_ms * rule.refill_per_ms).min(rule.capacity)
+    }
+
+    /** 等待令牌补充到 1 个所需的时间 */
+    fn wait_for(tokens: f64, rule: &TokenBucketRule) -> Option<Duration> {
+        let wait_ms = ((1.0 - tokens) / rule.refill_per_ms).ceil();
+        Some(Duration::from_millis(wait_ms as u64))
+    }
+}
+
+/**
+ * 令牌桶后端
+ */
+enum BucketStore {
+    Memory(Cache<String, Arc<Mutex<BucketState>>>),
+    Redis(RedisConnection),
+}
+
+/**
+ * 速率限制器
+ *
+ * 由同一个 RateLimitLayer 创建的所有中间件实例共享
+ */
+struct RateLimiter {
+    group: String,
+    rule: TokenBucketRule,
+    store: BucketStore,
+}
+
+impl RateLimiter {
+    /**
+     * 创建速率限制器
+     *
+     * 内存存储时，空闲超过补满时间的令牌桶与新建的令牌桶等价，可以直接淘汰
+     */
+    fn new(group: String, config: &LimitConfig, storage: RateLimitStorage) -> Self {
+        let rule = TokenBucketRule::from_config(config);
+        let store = match storage {
+            RateLimitStorage::Memory => BucketStore::Memory(
+                Cache::builder()
+                    .max_capacity(MAX_MEMORY_BUCKETS)
+                    .time_to_idle(rule.full_refill())
+                    .build(),
+            ),
+            RateLimitStorage::Redis(connection) => BucketStore::Redis(connection),
+        };
+        Self { group, rule, store }
+    }
+
+    /**
+     * 尝试为指定对象获取一个令牌
+     *
+     * Redis 不可用时放行请求并记录警告，避免限流组件故障导致接口整体不可用
+     */
+    async fn acquire(&self, subject: &str) -> Option<Duration> {
+        match &self.store {
+            BucketStore::Memory(buckets) => {
+                let now = Instant::now();
+                let key = format!("{}:{}", self.group, subject);
+                let bucket = buckets.get_with(key, || {
+                    Arc::new(Mutex::new(BucketState::full(&self.rule, now)))
+                });
+                let mut bucket = bucket.lock();
+                bucket.try_acquire(&self.rule, now)
+            },
+            BucketStore::Redis(connection) => {
+                self.eval_redis(connection, subject, TOKEN_BUCKET_SCRIPT).await
+            },
+        }
+    }
+
+    /**
+     * 查询指定对象是否还有可用令牌，不消耗令牌
+     *
+     * Redis 不可用时视为有可用令牌
+     */
+    async fn peek(&self, subject: &str) -> Option<Duration> {
+        match &self.store {
+            BucketStore::Memory(buckets) => {
+                let key = format!("{}:{}", self.group, subject);
+                let bucket = buckets.get(&key)?;
+                let bucket = bucket.lock();
+                bucket.peek(&self.rule, Instant::now())
+            },
+            BucketStore::Redis(connection) => {
+                self.eval_redis(connection, subject, TOKEN_BUCKET_PEEK_SCRIPT).await
+            },
+        }
+    }
+
+    async fn eval_redis(
+        &self,
+        connection: &RedisConnection,
+        subject: &str,
+        script: &str,
+    ) -> Option<Duration> {
+        let key = match KeyBuilder::rate_limit(&self.group, subject) {
+            Ok(key) => key,
+            Err(e) => {
+                tracing::warn!("Rate limit check skipped for {}: {}", subject, e);
+                return None;
+            },
+        };
+        match self.invoke_redis(connection, &key, script).await {
+            Ok(0) => None,
+            Ok(wait_ms) => Some(Duration::from_millis(wait_ms)),
+            Err(e) => {
+                tracing::warn!("Rate limit check skipped for {}: {}", key, e);
+                None
+            },
+        }
+    }
+
+    async fn invoke_redis(
+        &self,
+        connection: &RedisConnection,
+        key: &str,
+        script: &str,
+    ) -> Result<u64, RedisError> {
+        let script = Script::new(script);
+        let mut invocation = script.key(key);
+        invocation
+            .arg(self.rule.capacity)
+            .arg(self.rule.refill_per_ms.to_string());
+
+        match connection {
+            RedisConnection::Single(client) => {
+                let mut conn = client.get_multiplexed_async_connection().await?;
+                invocation.invoke_async(&mut conn).await
+            },
+            RedisConnection::Cluster(client) => {
+                let mut conn = client.get_async_connection().await?;
+                invocation.invoke_async(&mut conn).await
+            },
+        }
+    }
+}
+
+/**
+ * 速率限制中间件层
+ *
+ * 每个路由组创建一个实例，同一实例下的路由共享令牌桶
+ */
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+    key: RateLimitKey,
+}
+
+impl RateLimitLayer {
+    /**
+     * 创建速率限制中间件层
+     *
+     * 默认按客户端IP限流，可通过 `key_by` 修改
+     *
+     * # 参数
+     *
+     * * `group` - 路由组名称，用于区分不同路由组的令牌桶
+     * * `config` - 路由组的限制参数
+     * * `storage` - 令牌桶存储
+     *
+     * # 返回值
+     *
+     * 返回速率限制中间件层实例
+     */
+    pub fn new(group: impl Into<String>, config: &LimitConfig, storage: RateLimitStorage) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(group.into(), config, storage)),
+            key: RateLimitKey::default(),
+        }
+    }
+
+    /**
+     * 设置限流对象识别方式
+     *
+     * # 参数
+     *
+     * * `key` - 限流对象识别方式
+     *
+     * # 返回值
+     *
+     * 返回修改后的中间件层
+     */
+    pub fn key_by(mut self, key: RateLimitKey) -> Self {
+        self.key = key;
+        self
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        RateLimitMiddleware {
+            service,
+            limiter: self.limiter.clone(),
+            key: self.key,
+        }
+    }
+}
+
+/**
+ * 速率限制中间件
+ */
+#[derive(Clone)]
+pub struct RateLimitMiddleware<S> {
+    service: S,
+    limiter: Arc<RateLimiter>,
+    key: RateLimitKey,
+}
+
+impl<S> Service<Request> for RateLimitMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Send + Clone + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let limiter = self.limiter.clone();
+        let key = self.key;
+        let mut service = self.service.clone();
+
+        Box::pin(async move {
+            let (subject, req) = match resolve_subject(key, req).await {
+                Ok(resolved) => resolved,
+                Err(response) => return Ok(response),
+            };
+
+            match limiter.acquire(&subject).await {
+                None => service.call(req).await,
+                Some(retry_after) => Ok(too_many_requests(retry_after)),
+            }
+        })
+    }
+}
+
+/**
+ * 失败次数限制器
+ *
+ * 与速率限制共用令牌桶：每次失败消耗一个令牌，令牌耗尽后拒绝该对象的后续请求，
+ * 直至令牌按配置补充。用于限制同一来源对API密钥等凭证的暴力尝试。
+ */
+#[derive(Clone)]
+pub struct FailureLimiter {
+    limiter: Arc<RateLimiter>,
+}
+
+impl FailureLimiter {
+    /**
+     * 创建失败次数限制器
+     *
+     * # 参数
+     *
+     * * `group` - 路由组名称，令牌桶与同名速率限制组相互独立
+     * * `config` - 限制参数，max_requests 为时间窗口内允许的失败次数
+     * * `storage` - 令牌桶存储
+     *
+     * # 返回值
+     *
+     * 返回失败次数限制器实例
+     */
+    pub fn new(group: impl Into<String>, config: &LimitConfig, storage: RateLimitStorage) -> Self {
+        Self { limiter: Arc::new(RateLimiter::new(group.into(), config, storage)) }
+    }
+
+    /**
+     * 检查客户端IP是否已被限制
+     *
+     * # 参数
+     *
+     * * `ip` - 客户端IP
+     *
+     * # 返回值
+     *
+     * 未被限制时返回 Ok，否则返回 429 响应
+     */
+    pub async fn check(&self, ip: &str) -> Result<(), Response> {
+        match self.limiter.peek(&format!("ip:{}", ip)).await {
+            None => Ok(()),
+            Some(retry_after) => Err(too_many_requests(retry_after)),
+        }
+    }
+
+    /**
+     * 记录一次失败
+     *
+     * # 参数
+     *
+     * * `ip` - 客户端IP
+     */
+    pub async fn record_failure(&self, ip: &str) {
+        self.limiter.acquire(&format!("ip:{}", ip)).await;
+    }
+}
+
+/**
+ * 识别限流对象
+ *
+ * 按用户名限流时需要读取请求体，读取后重新组装请求交给下游处理
+ *
+ * # 返回值
+ *
+ * 返回限流对象标识和（可能重新组装的）请求；请求体过大时返回 413 响应
+ */
+async fn resolve_subject(key: RateLimitKey, req: Request) -> Result<(String, Request), Response> {
+    let ip_subject = |req: &Request| {
+        let peer = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        format!("ip:{}", ClientIp::get_real_ip(peer, req.headers()))
+    };
+
+    match key {
+        RateLimitKey::ClientIp => Ok((ip_subject(&req), req)),
+        RateLimitKey::ApiKey => {
+            let subject = req
+                .extensions()
+                .get::<ApiKeyIdentity>()
+                .map(|identity| format!("key:{}", identity.0))
+                .unwrap_or_else(|| ip_subject(&req));
+            Ok((subject, req))
+        },
+        RateLimitKey::Username => {
+            let (parts, body) = req.into_parts();
+            let bytes = to_bytes(body, MAX_USERNAME_BODY_SIZE).await.map_err(|_| {
+                let status = StatusCode::PAYLOAD_TOO_LARGE;
+                (status, Res::<()>::new_error(status.as_u16(), "Request body too large"))
+                    .into_response()
+            })?;
+
+            let is_form = parts
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.starts_with(mime::APPLICATION_WWW_FORM_URLENCODED.as_ref()))
+                .unwrap_or(false);
+            let username = extract_username(&bytes, is_form);
+
+            let req = Request::from_parts(parts, Body::from(bytes));
+            let subject = match username {
+                Some(username) => format!("user:{}", username),
+                None => ip_subject(&req),
+            };
+            Ok((subject, req))
+        },
+    }
+}
+
+/**
+ * 从登录请求体中读取用户名
+ *
+ * 与 ValidatedForm 一致，同时支持 JSON 和表单格式的请求体
+ *
+ * # 参数
+ *
+ * * `body` - 请求体
+ * * `is_form` - 是否为表单格式
+ *
+ * # 返回值
+ *
+ * 返回去除首尾空白后的非空用户名
+ */
+fn extract_username(body: &[u8], is_form: bool) -> Option<String> {
+    let username = if is_form {
+        form_urlencoded::parse(body)
+            .find(|(key, _)| key == "username")
+            .map(|(_, value)| value.into_owned())
+    } else {
+        serde_json::from_slice::<serde_json::Value>(body)
+            .ok()
+            .and_then(|value| value.get("username")?.as_str().map(str::to_owned))
+    };
+
+    username
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/**
+ * 构建限流响应
+ *
+ * # 参数
+ *
+ * * `retry_after` - 下一个令牌可用前需要等待的时间
+ *
+ * # 返回值
+ *
+ * 返回 429 响应，`Retry-After` 以秒为单位向上取整
+ */
+fn too_many_requests(retry_after: Duration) -> Response {
+    let status = StatusCode::TOO_MANY_REQUESTS;
+    let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    let mut response = (
+        status,
+        Res::<()>::new_error(status.as_u16(), "Too many requests, please retry later"),
+    )
+        .into_response();
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(seconds.max(1)));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{routing::post, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn limit(max_requests: u32, burst: Option<u32>) -> LimitConfig {
+        LimitConfig {
+            window: 60,
+            max_requests,
+            burst,
+        }
+    }
+
+    fn login_request(username: &str) -> Request {
+        Request::builder()
+            .method("POST")
+            .uri("/login")
+            .header("content-type", "application/json")
+            .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 40000))))
+            .body(Body::from(format!(
+                r#"{{"username":"{}","password":"secret"}}"#,
+                username
+            )))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_extract_username() {
+        assert_eq!(
+            extract_username(br#"{"username":" admin ","password":"x"}"#, false),
+            Some("admin".to_string())
+        );
+        assert_eq!(
+            extract_username(b"username=admin&password=x", true),
+            Some("admin".to_string())
+        );
+        assert_eq!(extract_username(b"password=x", true), None);
+        assert_eq!(extract_username(b"not json", false), None);
+    }
+
+    #[test]
+    fn test_bucket_refill() {
+        let rule = TokenBucketRule::from_config(&limit(60, Some(2)));
+        let start = Instant::now();
+        let mut bucket = BucketState::full(&rule, start);
+
+        assert!(bucket.try_acquire(&rule, start).is_none());
+        assert!(bucket.try_acquire(&rule, start).is_none());
+        let wait = bucket.try_acquire(&rule, start).unwrap();
+        assert!(wait.as_millis().abs_diff(1000) <= 1);
+
+        // 每秒补充一个令牌，且不超过容量
+        let later = start + Duration::from_millis(1500);
+        assert!(bucket.try_acquire(&rule, later).is_none());
+        assert!(bucket.try_acquire(&rule, later).is_some());
+        bucket.try_acquire(&rule, start + Duration::from_secs(100));
+        assert!(bucket.tokens <= rule.capacity);
+    }
+
+    #[tokio::test]
+    async fn test_failure_limiter() {
+        let limiter =
+            FailureLimiter::new("api_key_failure", &limit(60, Some(2)), RateLimitStorage::Memory);
+
+        // 查询不消耗令牌
+        assert!(limiter.check("10.0.0.3").await.is_ok());
+        assert!(limiter.check("10.0.0.3").await.is_ok());
+
+        limiter.record_failure("10.0.0.3").await;
+        assert!(limiter.check("10.0.0.3").await.is_ok());
+        limiter.record_failure("10.0.0.3").await;
+        let response = limiter.check("10.0.0.3").await.unwrap_err();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        assert!(limiter.check("10.0.0.4").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_username_limit_returns_429() {
+        let app = Router::new()
+            .route(
+                "/login",
+                post(|body: String| async move { Res::new_data(body.len()) }),
+            )
+            .layer(
+                RateLimitLayer::new("auth", &limit(10, Some(2)), RateLimitStorage::Memory)
+                    .key_by(RateLimitKey::Username),
+            );
+
+        for _ in 0..2 {
+            let response = app.clone().oneshot(login_request("admin")).await.unwrap();
+            assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        }
+
+        let response = app.clone().oneshot(login_request("admin")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "6");
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["code"], 429);
+        assert!(value.get("request_id").is_some());
+
+        // 同一IP下的其他用户名使用独立的令牌桶，且请求体仍能被下游读取
+        let response = app.clone().oneshot(login_request("another")).await.unwrap();
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(value["data"].as_u64().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_api_key_falls_back_to_client_ip() {
+        let app = Router::new()
+            .route("/sandbox", post(|| async { Res::new_data("ok") }))
+            .layer(
+                RateLimitLayer::new("sandbox", &limit(1, None), RateLimitStorage::Memory)
+                    .key_by(RateLimitKey::ApiKey),
+            );
+
+        let request = |api_key: Option<&str>| {
+            let mut req = Request::builder()
+                .method("POST")
+                .uri("/sandbox")
+                .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 2], 40000))))
+                .body(Body::empty())
+                .unwrap();
+            if let Some(api_key) = api_key {
+                req.extensions_mut().insert(ApiKeyIdentity(api_key.to_string()));
+            }
+            req
+        };
+
+        let response = app.clone().oneshot(request(None)).await.unwrap();
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let response = app.clone().oneshot(request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let response = app.clone().oneshot(request(Some("key-1"))).await.unwrap();
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+}
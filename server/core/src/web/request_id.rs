@@ -21,17 +21,74 @@
  * - 生成请求ID
  * - 注入请求上下文
  * - 处理请求ID传递
+ * - 将非 JSON 的错误响应（如 Casbin 拒绝）包装为统一响应结构
+ * 
+ * 该层应作为最外层应用，这样认证、鉴权中间件以及 404 处理器生成的响应
+ * 同样能够携带请求ID。
  */
 
 use axum::{
+    body::{to_bytes, Body},
     extract::Request,
-    http::{HeaderName, HeaderValue},
-    response::Response,
+    http::{
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+        HeaderMap, HeaderName, HeaderValue,
+    },
+    response::{IntoResponse, Response},
 };
 use std::task::{Context, Poll};
 use tower::{Layer, Service};
 use uuid::Uuid;
 
+use crate::web::res::Res;
+
+/** 请求ID请求头名称 */
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/** 包装非 JSON 错误响应时读取的最大响应体大小 */
+const MAX_WRAPPED_BODY_SIZE: usize = 64 * 1024;
+
+tokio::task_local! {
+    /**
+     * 当前请求ID
+     * 
+     * 在 RequestIdMiddleware 处理请求期间有效，供 Res 等无法访问请求上下文的位置读取
+     */
+    pub static CURRENT_REQUEST_ID: String;
+}
+
+/**
+ * 获取当前请求ID
+ * 
+ * # 返回值
+ * 
+ * 在请求上下文中返回请求ID，否则返回 None
+ */
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/**
+ * 从请求头解析请求ID
+ * 
+ * HTTP请求ID中间件和gRPC服务共用，请求头缺失或无效时生成新的ID
+ * 
+ * # 参数
+ * 
+ * * `headers` - 请求头
+ * 
+ * # 返回值
+ * 
+ * 返回请求ID
+ */
+pub fn request_id_from_headers(headers: &HeaderMap) -> String {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
+
 /**
  * 请求ID类型
  * 
@@ -126,22 +183,151 @@ where
      * 返回服务调用结果
      */
     fn call(&mut self, mut req: Request) -> Self::Future {
+        // 外层已经分配过请求ID时直接复用，避免同一请求出现多个ID
         let request_id = req
-            .headers()
-            .get("x-request-id")
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
+            .extensions()
+            .get::<RequestId>()
+            .map(|id| id.0.clone())
+            .unwrap_or_else(|| request_id_from_headers(req.headers()));
 
         req.extensions_mut().insert(RequestId(request_id.clone()));
 
         let mut service = self.service.clone();
-        Box::pin(async move {
-            let mut response = service.call(req).await?;
-            response
-                .headers_mut()
-                .insert(HeaderName::from_static("x-request-id"), HeaderValue::from_str(&request_id).unwrap());
+        Box::pin(CURRENT_REQUEST_ID.scope(request_id.clone(), async move {
+            let response = service.call(req).await?;
+            let mut response = wrap_plain_error(response).await;
+            if let Ok(value) = HeaderValue::from_str(&request_id) {
+                response
+                    .headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
             Ok(response)
-        })
+        }))
+    }
+}
+
+/**
+ * 将非 JSON 的错误响应包装为统一响应结构
+ * 
+ * 部分中间件（如 Casbin）直接返回纯文本错误，这里将其转换为 Res 格式，
+ * 保留原始状态码，响应体文本作为 message。
+ * 
+ * # 参数
+ * 
+ * * `response` - 原始响应
+ * 
+ * # 返回值
+ * 
+ * 返回包装后的响应；成功响应或已是 JSON 的响应原样返回
+ */
+async fn wrap_plain_error(response: Response) -> Response {
+    let status = response.status();
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.starts_with("application/json"))
+        .unwrap_or(false);
+
+    if !(status.is_client_error() || status.is_server_error()) || is_json {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let message = match to_bytes(body, MAX_WRAPPED_BODY_SIZE).await {
+        Ok(bytes) if !bytes.is_empty() => String::from_utf8_lossy(&bytes).into_owned(),
+        _ => status.canonical_reason().unwrap_or_default().to_string(),
+    };
+
+    let mut wrapped = (status, Res::<()>::new_error(status.as_u16(), &message)).into_response();
+    for (name, value) in parts.headers.iter() {
+        if name != CONTENT_TYPE && name != CONTENT_LENGTH {
+            wrapped.headers_mut().insert(name.clone(), value.clone());
+        }
+    }
+    wrapped.map(Body::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::StatusCode, routing::get, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = to_bytes(response.into_body(), MAX_WRAPPED_BODY_SIZE)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_json_response_carries_request_id() {
+        let app = Router::new()
+            .route("/", get(|| async { Res::new_data("ok") }))
+            .layer(RequestIdLayer);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(REQUEST_ID_HEADER, "req-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "req-1");
+        let value = body_json(response).await;
+        assert_eq!(value["request_id"], "req-1");
+        assert_eq!(value["data"], "ok");
+        assert!(value["timestamp"].as_i64().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_plain_error_is_wrapped() {
+        let app = Router::new()
+            .route(
+                "/",
+                get(|| async { (StatusCode::FORBIDDEN, "no permission") }),
+            )
+            .layer(RequestIdLayer);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(REQUEST_ID_HEADER, "req-2")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let value = body_json(response).await;
+        assert_eq!(value["code"], 403);
+        assert_eq!(value["message"], "no permission");
+        assert_eq!(value["request_id"], "req-2");
+    }
+
+    #[tokio::test]
+    async fn test_fallback_is_wrapped() {
+        let app = Router::new()
+            .fallback(|| async { (StatusCode::NOT_FOUND, "nothing to see here") })
+            .layer(RequestIdLayer);
+
+        let response = app
+            .oneshot(Request::builder().uri("/missing").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let value = body_json(response).await;
+        assert_eq!(value["code"], 404);
+        assert!(value["request_id"].as_str().is_some());
     }
 }
@@ -15,6 +15,8 @@
  * - code: 响应状态码
  * - message: 响应消息
  * - data: 响应数据
+ * - request_id: 请求ID，用于关联前端问题与服务端日志
+ * - timestamp: 服务端时间戳（毫秒）
  * 
  * ## ResBuilder
  * 响应构建器，用于创建响应：
@@ -31,7 +33,7 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::web::page::PaginatedData;
+use crate::web::{error::http_status, page::PaginatedData, request_id::current_request_id};
 
 /**
  * 统一响应结构
@@ -64,6 +66,22 @@ pub struct Res<T: Serialize> {
      * 响应的具体数据内容
      */
     pub data: Option<T>,
+
+    /**
+     * 请求ID
+     * 
+     * 由 RequestIdLayer 生成或透传，在请求上下文之外创建的响应为空
+     */
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    /**
+     * 服务端时间戳
+     * 
+     * 响应创建时的 Unix 时间戳（毫秒）
+     */
+    #[serde(default)]
+    pub timestamp: i64,
 }
 
 /**
@@ -133,15 +151,36 @@ impl<T: Serialize> ResBuilder<T> {
      * 返回统一响应结构
      */
     pub fn build(self) -> Res<T> {
-        Res {
-            code: self.code,
-            message: self.message,
-            data: self.data,
-        }
+        Res::with_parts(self.code, self.message, self.data)
     }
 }
 
 impl<T: Serialize> Res<T> {
+    /**
+     * 根据响应码、消息和数据创建响应
+     * 
+     * 自动填充当前请求ID和服务端时间戳，所有构造函数都应通过该方法创建响应。
+     * 
+     * # 参数
+     * 
+     * * `code` - 响应状态码
+     * * `message` - 响应消息
+     * * `data` - 响应数据
+     * 
+     * # 返回值
+     * 
+     * 返回统一响应结构
+     */
+    pub fn with_parts(code: i32, message: impl Into<String>, data: Option<T>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data,
+            request_id: current_request_id(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+
     /**
      * 创建成功响应
      * 
@@ -178,16 +217,14 @@ impl<T: Serialize> IntoResponse for Res<T> {
     /**
      * 将响应转换为HTTP响应
      * 
+     * HTTP状态码由 `http_status` 根据响应码计算。
+     * 
      * # 返回值
      * 
      * 返回HTTP响应
      */
     fn into_response(self) -> Response {
-        let status = if self.code == 0 {
-            StatusCode::OK
-        } else {
-            StatusCode::BAD_REQUEST
-        };
+        let status = u16::try_from(self.code).map_or(StatusCode::BAD_REQUEST, http_status);
 
         let json = serde_json::to_string(&self).unwrap_or_else(|_| {
             serde_json::to_string(&Res::<()>::new_error(500, "Failed to serialize response")).unwrap()
@@ -216,11 +253,7 @@ impl<T: Serialize> Res<T> {
      * * `Res<PaginatedData<T>>` - 分页数据响应
      */
     pub fn new_paginated(data: PaginatedData<T>) -> Res<PaginatedData<T>> {
-        Res {
-            code: StatusCode::OK.as_u16() as i32,
-            data: Some(data),
-            message: "success".to_string(),
-        }
+        Res::with_parts(StatusCode::OK.as_u16() as i32, "success", Some(data))
     }
 
     /**
@@ -237,11 +270,7 @@ impl<T: Serialize> Res<T> {
      * * `Self` - 成功响应
      */
     pub fn new_success(data: T, msg: &str) -> Self {
-        Self {
-            code: StatusCode::OK.as_u16() as i32,
-            data: Some(data),
-            message: msg.to_string(),
-        }
+        Self::with_parts(StatusCode::OK.as_u16() as i32, msg, Some(data))
     }
 
     /**
@@ -257,11 +286,7 @@ impl<T: Serialize> Res<T> {
      * * `Self` - 错误响应
      */
     pub fn new_error(code: u16, msg: &str) -> Self {
-        Self {
-            code: code as i32,
-            data: None,
-            message: msg.to_string(),
-        }
+        Self::with_parts(code as i32, msg, None)
     }
 
     /**
@@ -277,11 +302,7 @@ impl<T: Serialize> Res<T> {
      * * `Self` - 消息响应
      */
     pub fn new_message(msg: &str) -> Self {
-        Self {
-            code: StatusCode::OK.as_u16() as i32,
-            data: None,
-            message: msg.to_string(),
-        }
+        Self::with_parts(StatusCode::OK.as_u16() as i32, msg, None)
     }
 
     /**
@@ -297,10 +318,67 @@ impl<T: Serialize> Res<T> {
      * * `Self` - 数据响应
      */
     pub fn new_data(data: T) -> Self {
-        Self {
-            code: StatusCode::OK.as_u16() as i32,
-            data: Some(data),
-            message: "success".to_string(),
-        }
+        Self::with_parts(StatusCode::OK.as_u16() as i32, "success", Some(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::web::request_id::CURRENT_REQUEST_ID;
+
+    fn keys(value: &Value) -> Vec<String> {
+        let mut keys: Vec<String> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn test_envelope_shape() {
+        let value = serde_json::to_value(Res::new_data(1)).unwrap();
+        assert_eq!(
+            keys(&value),
+            vec!["code", "data", "message", "request_id", "timestamp"]
+        );
+        assert_eq!(value["code"], 200);
+        assert_eq!(value["message"], "success");
+        assert_eq!(value["data"], 1);
+        assert_eq!(value["request_id"], Value::Null);
+        assert!(value["timestamp"].as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_error_envelope_shape() {
+        let value = serde_json::to_value(Res::<()>::new_error(404, "not found")).unwrap();
+        assert_eq!(
+            keys(&value),
+            vec!["code", "data", "message", "request_id", "timestamp"]
+        );
+        assert_eq!(value["code"], 404);
+        assert_eq!(value["data"], Value::Null);
+    }
+
+    #[test]
+    fn test_http_status_follows_code() {
+        assert_eq!(Res::new_data(1).into_response().status(), StatusCode::OK);
+        assert_eq!(Res::<()>::ok("ok").build().into_response().status(), StatusCode::OK);
+        assert_eq!(
+            Res::<()>::new_error(401, "unauthorized").into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            Res::<()>::new_error(3002, "business").into_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn test_envelope_carries_request_id_in_scope() {
+        let res = CURRENT_REQUEST_ID
+            .scope("req-123".to_string(), async { Res::<()>::new_message("ok") })
+            .await;
+        assert_eq!(res.request_id.as_deref(), Some("req-123"));
     }
 }
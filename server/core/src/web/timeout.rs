@@ -0,0 +1,305 @@
+/**
+ * 超时模块
+ *
+ * 该模块限制请求的处理时间和内部依赖调用的等待时间，避免依赖挂起时请求一直占用连接。
+ * 主要功能包括：
+ * - 按路由组限制请求的处理时间，超时返回 504 和统一响应结构
+ * - 在请求扩展中记录截止时间，操作日志中间件据此记录超时的请求
+ * - 限制Redis、IP归属地查询等内部依赖调用的等待时间，超时返回 503
+ *
+ * # 主要组件
+ *
+ * ## TimeoutLayer
+ * 请求超时中间件层，每个路由组一个实例：
+ * - 从 `TimeoutConfig::budget` 取得处理时间上限
+ * - 排除文件代理等长时间输出的路径
+ * - 嵌套应用时以较早的截止时间为准
+ *
+ * ## DependencyError
+ * 内部依赖调用超时的错误，由 `with_dependency_timeout` 返回
+ *
+ * # 使用示例
+ *
+ * ```rust,ignore
+ * let router = router.layer(TimeoutLayer::new(Duration::from_secs(10)));
+ *
+ * let result = with_dependency_timeout("redis", store.check_and_set(key, nonce, ttl)).await?;
+ * ```
+ */
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use axum::{
+    extract::Request,
+    response::{IntoResponse, Response},
+};
+use once_cell::sync::OnceCell;
+use thiserror::Error;
+use tokio::time::{timeout, timeout_at, Instant};
+use tower::{Layer, Service};
+
+use crate::web::error::{ApiError, AppError, StatusCode};
+
+/** 未初始化时内部依赖调用的等待上限 */
+const DEFAULT_DEPENDENCY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/** 内部依赖调用的等待上限，服务启动时设置 */
+static DEPENDENCY_TIMEOUT: OnceCell<Duration> = OnceCell::new();
+
+/**
+ * 设置内部依赖调用的等待上限
+ *
+ * 未设置时使用 3 秒
+ *
+ * # 参数
+ * * `timeout` - 等待上限
+ */
+pub fn init_dependency_timeout(timeout: Duration) {
+    let _ = DEPENDENCY_TIMEOUT.set(timeout);
+}
+
+/**
+ * 请求截止时间
+ *
+ * 由 `TimeoutLayer` 写入请求扩展，内层中间件可据此在超时前结束处理
+ */
+#[derive(Clone, Copy, Debug)]
+pub struct RequestDeadline(pub Instant);
+
+/**
+ * 请求超时中间件层
+ */
+#[derive(Clone)]
+pub struct TimeoutLayer {
+    budget: Duration,
+    exclude_paths: Arc<[String]>,
+}
+
+impl TimeoutLayer {
+    /**
+     * 创建请求超时中间件层
+     *
+     * # 参数
+     * * `budget` - 请求的处理时间上限
+     */
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            exclude_paths: Arc::from([]),
+        }
+    }
+
+    /**
+     * 设置不限制处理时间的路径前缀
+     *
+     * # 参数
+     * * `paths` - 路径前缀列表
+     */
+    pub fn exclude_paths(mut self, paths: &[String]) -> Self {
+        self.exclude_paths = paths.into();
+        self
+    }
+}
+
+impl<S> Layer<S> for TimeoutLayer {
+    type Service = TimeoutMiddleware<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        TimeoutMiddleware {
+            service,
+            budget: self.budget,
+            exclude_paths: self.exclude_paths.clone(),
+        }
+    }
+}
+
+/**
+ * 请求超时中间件
+ */
+#[derive(Clone)]
+pub struct TimeoutMiddleware<S> {
+    service: S,
+    budget: Duration,
+    exclude_paths: Arc<[String]>,
+}
+
+impl<S> Service<Request> for TimeoutMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Send + Clone + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request) -> Self::Future {
+        let path = req.uri().path();
+        if self.exclude_paths.iter().any(|prefix| path.starts_with(prefix.as_str())) {
+            return Box::pin(self.service.call(req));
+        }
+
+        // 外层已设置更早的截止时间时沿用外层的
+        let deadline = Instant::now() + self.budget;
+        let deadline = req
+            .extensions()
+            .get::<RequestDeadline>()
+            .map_or(deadline, |outer| outer.0.min(deadline));
+        req.extensions_mut().insert(RequestDeadline(deadline));
+
+        let future = self.service.call(req);
+        Box::pin(async move {
+            match timeout_at(deadline, future).await {
+                Ok(result) => result,
+                Err(_) => Ok(timeout_response()),
+            }
+        })
+    }
+}
+
+/**
+ * 构建请求超时响应
+ *
+ * # 返回值
+ *
+ * 返回 504 响应，响应体为统一响应结构
+ */
+pub fn timeout_response() -> Response {
+    AppError::new(StatusCode::GATEWAY_TIMEOUT.as_u16(), "Request timed out").into_response()
+}
+
+/**
+ * 内部依赖错误
+ */
+#[derive(Debug, Error)]
+pub enum DependencyError {
+    #[error("Dependency '{0}' timed out")]
+    Timeout(&'static str),
+}
+
+impl ApiError for DependencyError {
+    fn code(&self) -> u16 {
+        StatusCode::SERVICE_UNAVAILABLE.as_u16()
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            DependencyError::Timeout(dependency) => vec![dependency.to_string()],
+        }
+    }
+}
+
+impl From<DependencyError> for AppError {
+    fn from(err: DependencyError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl IntoResponse for DependencyError {
+    fn into_response(self) -> Response {
+        AppError::from(self).into_response()
+    }
+}
+
+/**
+ * 限制内部依赖调用的等待时间
+ *
+ * 超时后放弃等待并丢弃调用，调用方按 `DependencyError` 返回 503 或降级处理
+ *
+ * # 参数
+ * * `dependency` - 依赖名称，记录在日志和错误消息中
+ * * `future` - 依赖调用
+ */
+pub async fn with_dependency_timeout<F: Future>(
+    dependency: &'static str,
+    future: F,
+) -> Result<F::Output, DependencyError> {
+    let limit = DEPENDENCY_TIMEOUT.get().copied().unwrap_or(DEFAULT_DEPENDENCY_TIMEOUT);
+    timeout(limit, future).await.map_err(|_| {
+        tracing::warn!("Dependency '{}' did not respond within {:?}", dependency, limit);
+        DependencyError::Timeout(dependency)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, future::pending};
+
+    use axum::{body::to_bytes, routing::get, Router};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::web::res::Res;
+
+    async fn slow_handler() -> Res<&'static str> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Res::new_data("done")
+    }
+
+    fn request(uri: &str) -> Request {
+        Request::builder().uri(uri).body(axum::body::Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler_returns_504() {
+        let app = Router::new()
+            .route("/slow", get(slow_handler))
+            .route("/file/download", get(|| async { Res::new_data("file") }))
+            .layer(
+                TimeoutLayer::new(Duration::from_millis(50))
+                    .exclude_paths(&["/file".to_string()]),
+            );
+
+        let response = app.clone().oneshot(request("/slow")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["code"], 504);
+
+        let response = app.oneshot(request("/file/download")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_nested_layers_keep_earlier_deadline() {
+        let service = tower::service_fn(|req: Request| async move {
+            let deadline = req.extensions().get::<RequestDeadline>().unwrap().0;
+            assert!(deadline <= Instant::now() + Duration::from_secs(1));
+            Ok::<_, Infallible>(Response::new(axum::body::Body::empty()))
+        });
+        let service = TimeoutLayer::new(Duration::from_secs(30)).layer(service);
+        let service = TimeoutLayer::new(Duration::from_secs(1)).layer(service);
+
+        let response = service.oneshot(request("/")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_timeout() {
+        let result = with_dependency_timeout("redis", async { 1 }).await;
+        assert_eq!(result.unwrap(), 1);
+
+        let pending = with_dependency_timeout("redis", pending::<()>());
+        let result = timeout(Duration::from_secs(10), pending)
+            .await
+            .expect("dependency call should give up before the outer timeout");
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), 503);
+        assert_eq!(err.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(err.message_args(), vec!["redis"]);
+    }
+}
@@ -1,18 +1,44 @@
+use std::net::{IpAddr, SocketAddr};
+
 use axum::http::HeaderMap;
+use once_cell::sync::OnceCell;
+use server_config::{ForwardedHeader, TrustedProxyConfig};
+
+/** 无法获取客户端 IP 时返回的值 */
+const UNKNOWN_IP: &str = "unknown";
+
+/** 可信代理配置，服务启动时设置 */
+static TRUSTED_PROXY: OnceCell<TrustedProxyConfig> = OnceCell::new();
 
 /**
  * 客户端 IP 地址处理工具
  * 
- * 用于从 HTTP 请求头中获取真实的客户端 IP 地址。
- * 支持多种代理服务器和 CDN 的请求头格式。
+ * 用于获取真实的客户端 IP 地址。
+ * 只有请求来自可信代理时才读取转发请求头，支持 X-Forwarded-For、X-Real-IP 和 Forwarded。
  */
 pub struct ClientIp;
 
 impl ClientIp {
     /**
-     * 从请求头中获取真实的客户端 IP 地址
+     * 设置可信代理配置
+     * 
+     * 应在服务启动时调用一次，未设置时忽略所有转发请求头
+     * 
+     * # 参数
+     * * `config` - 可信代理配置
+     */
+    pub fn init_trusted_proxy(config: TrustedProxyConfig) {
+        let _ = TRUSTED_PROXY.set(config);
+    }
+
+    /**
+     * 获取真实的客户端 IP 地址
+     * 
+     * 只有直接对端位于可信代理网段内时才读取转发请求头，
+     * 防止客户端通过伪造 X-Forwarded-For 等请求头绕过登录日志和按 IP 限流。
      * 
      * # 参数
+     * * `peer` - 直接对端地址，来自 `ConnectInfo<SocketAddr>`
      * * `headers` - HTTP 请求头
      * 
      * # 返回值
@@ -20,45 +46,81 @@ impl ClientIp {
      * 
      * # 示例
      * 
-     * use axum::http::HeaderMap;
      * use server_core::web::util::ClientIp;
      * 
-     * let mut headers = HeaderMap::new();
-     * headers.insert("X-Real-IP", "192.168.1.1".parse().unwrap());
+     * let ip = ClientIp::get_real_ip(Some(addr.ip()), req.headers());
+     */
+    pub fn get_real_ip(peer: Option<IpAddr>, headers: &HeaderMap) -> String {
+        Self::client_ip(peer, headers)
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| UNKNOWN_IP.to_string())
+    }
+
+    /**
+     * 获取真实的客户端 IP 地址
+     * 
+     * 与 `get_real_ip` 规则相同，返回解析后的地址，供按网段匹配等场景使用
      * 
-     * let ip = ClientIp::get_real_ip(&headers);
-     * assert_ne!(ip, "unknown");
+     * # 参数
+     * * `peer` - 直接对端地址，来自 `ConnectInfo<SocketAddr>`
+     * * `headers` - HTTP 请求头
+     * 
+     * # 返回值
+     * 返回客户端 IP 地址，对端地址未知时返回 None
      */
-    pub fn get_real_ip(headers: &HeaderMap) -> String {
-        // 按优先级检查请求头
-        // 不同的代理服务器和 CDN 可能使用不同的请求头来传递客户端 IP
-        let ip_headers = [
-            "X-Real-IP",                // Nginx 代理
-            "X-Forwarded-For",          // 标准代理头
-            "CF-Connecting-IP",         // Cloudflare
-            "True-Client-IP",           // Akamai 和 Cloudflare
-            "X-Client-IP",              // 常用代理头
-            "Fastly-Client-IP",         // Fastly CDN
-            "X-Cluster-Client-IP",      // GCP/AWS Load Balancer
-            "X-Original-Forwarded-For", // AWS ALB/ELB
-        ];
+    pub fn client_ip(peer: Option<IpAddr>, headers: &HeaderMap) -> Option<IpAddr> {
+        match TRUSTED_PROXY.get() {
+            Some(config) => Self::resolve(config, peer, headers),
+            None => peer,
+        }
+    }
 
-        // 遍历所有可能的请求头
-        for header_name in ip_headers {
-            if let Some(ip_header) = headers.get(header_name) {
-                if let Ok(ip_str) = ip_header.to_str() {
-                    // X-Forwarded-For 等头可能包含多个 IP，格式如：client, proxy1, proxy2
-                    // 我们取第一个，因为它通常是最原始的客户端 IP
-                    let real_ip = ip_str.split(',').next().unwrap_or("").trim();
-                    if !real_ip.is_empty() {
-                        return real_ip.to_string();
-                    }
-                }
-            }
+    /**
+     * 按可信代理配置解析客户端 IP 地址
+     * 
+     * - 对端不可信时直接返回对端地址
+     * - X-Forwarded-For 和 Forwarded 从右向左跳过可信代理，取第一个不可信的地址
+     * - 请求头缺失或无法解析时返回对端地址
+     * 
+     * # 参数
+     * * `config` - 可信代理配置
+     * * `peer` - 直接对端地址
+     * * `headers` - HTTP 请求头
+     * 
+     * # 返回值
+     * 返回客户端 IP 地址，对端地址未知时返回 None
+     */
+    pub fn resolve(
+        config: &TrustedProxyConfig,
+        peer: Option<IpAddr>,
+        headers: &HeaderMap,
+    ) -> Option<IpAddr> {
+        let peer = peer?;
+        if !config.is_trusted(&peer) {
+            return Some(peer);
         }
 
-        // 如果没有找到任何有效的 IP，返回 unknown
-        "unknown".to_string()
+        let client = match config.header {
+            ForwardedHeader::XRealIp => headers
+                .get("X-Real-IP")
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_ip),
+            ForwardedHeader::XForwardedFor => {
+                let hops = header_values(headers, "X-Forwarded-For")
+                    .flat_map(|value| value.split(','))
+                    .map(parse_ip);
+                first_untrusted_hop(config, hops)
+            },
+            ForwardedHeader::Forwarded => {
+                let hops = header_values(headers, "Forwarded")
+                    .flat_map(|value| value.split(','))
+                    .filter_map(forwarded_for)
+                    .map(parse_ip);
+                first_untrusted_hop(config, hops)
+            },
+        };
+
+        Some(client.unwrap_or(peer))
     }
 
     /**
@@ -71,7 +133,7 @@ impl ClientIp {
      * 如果 IP 地址有效则返回 true，否则返回 false
      */
     pub fn is_valid_ip(ip: &str) -> bool {
-        if ip == "unknown" {
+        if ip == UNKNOWN_IP {
             return false;
         }
 
@@ -134,15 +196,133 @@ impl ClientIp {
     }
 }
 
+/**
+ * 按顺序获取同名请求头的所有值
+ */
+fn header_values<'a>(headers: &'a HeaderMap, name: &str) -> impl Iterator<Item = &'a str> {
+    headers.get_all(name).iter().filter_map(|value| value.to_str().ok())
+}
+
+/**
+ * 从右向左跳过可信代理，返回第一个不可信的地址
+ * 
+ * 遇到无法解析的地址时停止，返回最后一个可信代理；
+ * 全部为可信代理时返回最左侧的地址
+ */
+fn first_untrusted_hop(
+    config: &TrustedProxyConfig,
+    hops: impl Iterator<Item = Option<IpAddr>>,
+) -> Option<IpAddr> {
+    let hops: Vec<_> = hops.collect();
+    let mut last_trusted = None;
+    for hop in hops.into_iter().rev() {
+        match hop {
+            Some(ip) if config.is_trusted(&ip) => last_trusted = Some(ip),
+            Some(ip) => return Some(ip),
+            None => break,
+        }
+    }
+    last_trusted
+}
+
+/**
+ * 获取 Forwarded 请求头元素中的 for 参数
+ * 
+ * 例如 `for=192.0.2.60;proto=http;by=203.0.113.43` 返回 `192.0.2.60`
+ */
+fn forwarded_for(element: &str) -> Option<&str> {
+    element.split(';').find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        key.trim().eq_ignore_ascii_case("for").then_some(value.trim())
+    })
+}
+
+/**
+ * 解析 IP 地址
+ * 
+ * 支持带引号、带端口（`192.0.2.60:4711`、`[2001:db8::1]:4711`）和带方括号的 IPv6 地址
+ */
+fn parse_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+    value
+        .parse::<IpAddr>()
+        .ok()
+        .or_else(|| value.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        .or_else(|| value.strip_prefix('[')?.strip_suffix(']')?.parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn config(header: ForwardedHeader) -> TrustedProxyConfig {
+        TrustedProxyConfig {
+            enabled: true,
+            trusted_proxies: vec!["10.0.0.0/8".parse().unwrap(), "127.0.0.1/32".parse().unwrap()],
+            header,
+        }
+    }
+
+    fn ip(value: &str) -> Option<IpAddr> {
+        Some(value.parse().unwrap())
+    }
+
+    #[test]
+    fn test_resolve_ignores_spoofed_headers_from_untrusted_peer() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Forwarded-For", "1.1.1.1".parse().unwrap());
+        headers.insert("X-Real-IP", "1.1.1.1".parse().unwrap());
+        headers.insert("Forwarded", "for=1.1.1.1".parse().unwrap());
+
+        for header in [
+            ForwardedHeader::XForwardedFor,
+            ForwardedHeader::XRealIp,
+            ForwardedHeader::Forwarded,
+        ] {
+            let client = ClientIp::resolve(&config(header), ip("203.0.113.9"), &headers);
+            assert_eq!(client, ip("203.0.113.9"));
+        }
+
+        let disabled = TrustedProxyConfig { enabled: false, ..config(ForwardedHeader::XRealIp) };
+        assert_eq!(ClientIp::resolve(&disabled, ip("10.0.0.1"), &headers), ip("10.0.0.1"));
+        assert_eq!(ClientIp::resolve(&disabled, None, &headers), None);
+    }
+
+    #[test]
+    fn test_resolve_chain_behind_two_proxies() {
+        let config = config(ForwardedHeader::XForwardedFor);
+        let mut headers = HeaderMap::new();
+        // 客户端伪造了第一个地址，真实地址由第一层代理追加
+        headers.insert(
+            "X-Forwarded-For",
+            "9.9.9.9, 198.51.100.7, 10.0.0.2".parse().unwrap(),
+        );
+        assert_eq!(ClientIp::resolve(&config, ip("10.0.0.1"), &headers), ip("198.51.100.7"));
+
+        headers.insert("X-Forwarded-For", "10.0.0.3, 10.0.0.2".parse().unwrap());
+        assert_eq!(ClientIp::resolve(&config, ip("10.0.0.1"), &headers), ip("10.0.0.3"));
+
+        headers.insert("X-Forwarded-For", "garbage, 10.0.0.2".parse().unwrap());
+        assert_eq!(ClientIp::resolve(&config, ip("10.0.0.1"), &headers), ip("10.0.0.2"));
+
+        headers.remove("X-Forwarded-For");
+        assert_eq!(ClientIp::resolve(&config, ip("10.0.0.1"), &headers), ip("10.0.0.1"));
+    }
+
     #[test]
-    fn test_get_real_ip() {
+    fn test_resolve_forwarded_and_real_ip() {
         let mut headers = HeaderMap::new();
-        headers.insert("X-Real-IP", "192.168.1.1".parse().unwrap());
-        assert_eq!(ClientIp::get_real_ip(&headers), "192.168.1.1");
+        headers.insert(
+            "Forwarded",
+            "for=\"[2001:db8::1]:4711\";proto=https, for=10.0.0.2".parse().unwrap(),
+        );
+        headers.insert("X-Real-IP", "198.51.100.7".parse().unwrap());
+
+        let forwarded = config(ForwardedHeader::Forwarded);
+        assert_eq!(ClientIp::resolve(&forwarded, ip("127.0.0.1"), &headers), ip("2001:db8::1"));
+
+        let real_ip = config(ForwardedHeader::XRealIp);
+        assert_eq!(ClientIp::resolve(&real_ip, ip("127.0.0.1"), &headers), ip("198.51.100.7"));
     }
 
     #[test]
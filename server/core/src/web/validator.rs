@@ -21,42 +21,80 @@
  * - 错误消息
  * - 错误字段
  * - 错误代码
+ * 
+ * ## ValidatedQuery
+ * 查询参数提取器，参数无法反序列化（如每页数量超过上限）时返回422
+ * 
+ * ## ValidJson
+ * JSON请求体提取器，媒体类型错误、语法错误和未知字段都返回统一格式的响应
+ * 
+ * # 验证消息
+ * 
+ * 验证规则中的 `message` 填写消息目录中的键（如 `validation.code.length`），
+ * 响应时按当前请求语言解析，`{field}`、`{min}`、`{max}` 等占位符替换为字段名和验证参数。
+ * 未指定 `message` 时使用 `validation.<规则代码>`（如 `validation.required`）。
  */
 
 use async_trait::async_trait;
 use axum::{
-    extract::{rejection::JsonRejection, FromRequest, Request},
-    http::{header::CONTENT_TYPE, StatusCode},
+    body::Bytes,
+    extract::{rejection::FormRejection, FromRequest, FromRequestParts, Query, Request},
+    http::{header::CONTENT_TYPE, request::Parts, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    Form, Json,
+    Form,
 };
+use once_cell::sync::OnceCell;
 use serde::de::DeserializeOwned;
-use serde_json::Value as JsonValue;
+use serde_json::{error::Category, Value as JsonValue};
 use thiserror::Error;
 use validator::{Validate, ValidationErrors};
-use std::future::Future;
+use std::{borrow::Cow, collections::HashMap, future::Future};
 
-use crate::web::res::Res;
+use crate::web::{i18n::localize_validation, res::Res};
 
 /**
  * 验证错误类型枚举
  * 
  * 定义了验证过程中可能出现的各种错误类型：
- * - JsonError：JSON数据格式错误，包含具体的错误信息
+ * - JsonError：JSON请求体无法读取，包含具体的错误信息
+ * - JsonSyntax：JSON语法错误，包含错误所在的行和列
+ * - UnsupportedMediaType：请求的Content-Type不受支持
+ * - UnknownFields：开启未知字段检查时，请求体中包含目标类型没有的字段
  * - FormError：表单数据格式错误
+ * - InvalidData：数据格式正确但字段取值无法转换为目标类型（如ID格式错误）
  * - Validation：数据验证错误，包含详细的字段验证错误信息
  * - DataMissing：请求数据缺失错误
  */
 #[derive(Debug, Error)]
 pub enum ValidationError {
-    /// JSON数据格式错误
+    /// JSON请求体无法读取
     #[error("Invalid JSON data: {0}")]
     JsonError(String),
 
+    /// JSON语法错误
+    #[error("Invalid JSON syntax at line {line} column {column}: {detail}")]
+    JsonSyntax {
+        line: usize,
+        column: usize,
+        detail: String,
+    },
+
+    /// 请求的Content-Type不受支持
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    /// 请求体中包含未知字段
+    #[error("Unknown fields: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
+
     /// 表单数据格式错误
     #[error("Invalid form data")]
     FormError,
 
+    /// 字段取值无法转换为目标类型
+    #[error("Unprocessable data: {0}")]
+    InvalidData(String),
+
     /// 数据验证错误
     #[error("Validation error: {0}")]
     Validation(#[from] ValidationErrors),
@@ -79,6 +117,54 @@ pub enum ValidationError {
 #[derive(Debug, Clone)]
 pub struct ValidatedForm<T>(pub T);
 
+/**
+ * 查询参数提取器
+ * 
+ * 与 `Query` 相同，但查询参数无法反序列化时返回422和具体原因，
+ * 与请求体中字段取值无法转换时的响应一致。分页查询统一使用该提取器。
+ * 
+ * # 类型参数
+ * 
+ * * `T`: 实现了DeserializeOwned trait的类型
+ */
+#[derive(Debug, Clone)]
+pub struct ValidatedQuery<T>(pub T);
+
+/**
+ * JSON请求体提取器
+ * 
+ * 与 `Json` 相同，但所有失败都返回统一格式的响应（带请求ID）：
+ * - Content-Type 不是 JSON 时返回415
+ * - JSON语法错误时返回400，消息中带错误所在的行和列
+ * - 字段取值无法转换为目标类型时返回422，消息中带字段路径
+ * - 配置 `http.deny_unknown_fields` 开启后，包含未知字段时返回422
+ * 
+ * 不执行 `Validate` 验证，需要验证的输入使用 `ValidatedForm`，其JSON请求体同样按此解析。
+ * 
+ * # 类型参数
+ * 
+ * * `T`: 实现了DeserializeOwned trait的类型
+ */
+#[derive(Debug, Clone)]
+pub struct ValidJson<T>(pub T);
+
+/**
+ * 是否拒绝JSON请求体中的未知字段，服务启动时设置
+ */
+static DENY_UNKNOWN_FIELDS: OnceCell<bool> = OnceCell::new();
+
+/**
+ * 设置是否拒绝JSON请求体中的未知字段
+ * 
+ * 应在服务启动时、处理任何请求之前调用一次，未设置时忽略未知字段
+ * 
+ * # 参数
+ * * `enabled` - 是否拒绝未知字段
+ */
+pub fn init_deny_unknown_fields(enabled: bool) {
+    let _ = DENY_UNKNOWN_FIELDS.set(enabled);
+}
+
 /**
  * 验证宏，用于快速定义必填字段的验证规则
  * 
@@ -87,33 +173,30 @@ pub struct ValidatedForm<T>(pub T);
  * 2. 必填字符串字段
  * 3. 必填自定义类型字段
  * 
+ * 错误消息使用通用的验证消息键，`{field}` 替换为字段名。
+ * 
  * # 参数
  * 
  * * `$field`: 字段名
  * * `$min`: 最小长度（可选）
  * * `$max`: 最大长度（可选）
- * * `$message`: 错误消息
  * * `$ty`: 字段类型（可选）
  */
 #[macro_export]
 macro_rules! validate_required {
-    ($field:ident, $min:expr, $max:expr, $message:expr) => {
+    ($field:ident, $min:expr, $max:expr) => {
         #[validate(
-            required(message = concat!($message, " is required")),
-            length(
-                min = $min,
-                max = $max,
-                message = concat!($message, " must be between ", $min, " and ", $max, " characters")
-            )
+            required(message = "validation.required"),
+            length(min = $min, max = $max, message = "validation.length_between")
         )]
         pub $field: String,
     };
-    ($field:ident, $message:expr) => {
-        #[validate(required(message = concat!($message, " is required")))]
+    ($field:ident) => {
+        #[validate(required(message = "validation.required"))]
         pub $field: String,
     };
-    ($field:ident: $ty:ty, $message:expr) => {
-        #[validate(required(message = concat!($message, " is required")))]
+    ($field:ident: $ty:ty) => {
+        #[validate(required(message = "validation.required"))]
         pub $field: $ty,
     };
 }
@@ -129,13 +212,12 @@ macro_rules! validate_required {
  * 
  * * `$field`: 字段名
  * * `$max`: 最大长度（可选）
- * * `$message`: 错误消息（可选）
  * * `$ty`: 字段类型（可选）
  */
 #[macro_export]
 macro_rules! validate_optional {
-    ($field:ident, $max:expr, $message:expr) => {
-        #[validate(length(max = $max, message = concat!($message, " must not exceed ", $max, " characters")))]
+    ($field:ident, $max:expr) => {
+        #[validate(length(max = $max, message = "validation.max_length"))]
         pub $field: Option<String>,
     };
     ($field:ident: $ty:ty) => {
@@ -175,7 +257,7 @@ pub trait ValidateInput: Validate + Send + Sync {
      * 验证并返回错误信息
      * 
      * 验证数据并返回详细的错误信息列表。
-     * 错误信息包含每个字段的具体验证错误。
+     * 错误信息包含每个字段的具体验证错误，按当前请求语言本地化。
      * 
      * # 返回
      * * `Result<(), Vec<String>>` - 验证成功返回Ok(())，失败返回错误信息列表
@@ -187,14 +269,8 @@ pub trait ValidateInput: Validate + Send + Sync {
                 let error_messages: Vec<String> = errors
                     .field_errors()
                     .into_iter()
-                    .flat_map(|(_, errors)| {
-                        errors.iter().map(|error| {
-                            error
-                                .message
-                                .as_ref()
-                                .map(|cow| cow.to_string())
-                                .unwrap_or_else(|| "Unknown error".to_string())
-                        })
+                    .flat_map(|(field, errors)| {
+                        errors.iter().map(move |error| field_error_message(&field, error))
                     })
                     .collect();
                 Err(error_messages)
@@ -203,6 +279,26 @@ pub trait ValidateInput: Validate + Send + Sync {
     }
 }
 
+/**
+ * 生成字段验证错误的本地化消息
+ * 
+ * 消息键取验证规则中的 `message`，未指定时使用 `validation.<规则代码>`。
+ * 
+ * # 参数
+ * * `field` - 字段名
+ * * `error` - 字段验证错误
+ * 
+ * # 返回
+ * * `String` - 本地化后的错误消息
+ */
+fn field_error_message(field: &str, error: &validator::ValidationError) -> String {
+    let key = match &error.message {
+        Some(message) => message.to_string(),
+        None => format!("validation.{}", error.code),
+    };
+    localize_validation(&key, field, &error.params)
+}
+
 // 为所有实现了 Validate 的类型自动实现 ValidateInput
 #[async_trait]
 impl<T: Validate + Send + Sync> ValidateInput for T {}
@@ -222,8 +318,7 @@ impl<S, T> FromRequest<S> for ValidatedForm<T>
 where
     T: DeserializeOwned + Validate + Send + Sync + 'static,
     S: Send + Sync + 'static,
-    Json<T>: FromRequest<S, Rejection = JsonRejection>,
-    Form<T>: FromRequest<S>,
+    Form<T>: FromRequest<S, Rejection = FormRejection>,
 {
     type Rejection = ValidationError;
 
@@ -231,8 +326,11 @@ where
      * 从请求中提取并验证数据
      * 
      * 根据Content-Type头选择数据提取方式：
-     * - application/json：提取JSON数据
+     * - application/json：按 `ValidJson` 提取JSON数据
      * - application/x-www-form-urlencoded：提取表单数据
+     * - 其他类型返回 `UnsupportedMediaType`，未携带时返回 `DataMissing`
+     *
+     * 语法正确但字段取值无法反序列化（如类型化ID格式错误）时返回 `InvalidData`
      * 
      * # 参数
      * * `req` - HTTP请求
@@ -253,18 +351,21 @@ where
 
             let data = match content_type.as_deref() {
                 Some(ct) if ct.contains(mime::APPLICATION_JSON.as_ref()) => {
-                    let Json(data) = Json::<T>::from_request(req, state)
-                        .await
-                        .map_err(|e| ValidationError::JsonError(e.to_string()))?;
+                    let ValidJson(data) = ValidJson::<T>::from_request(req, state).await?;
                     data
                 },
                 Some(ct) if ct.contains(mime::APPLICATION_WWW_FORM_URLENCODED.as_ref()) => {
-                    let Form(data) = Form::<T>::from_request(req, state)
-                        .await
-                        .map_err(|_| ValidationError::FormError)?;
+                    let Form(data) =
+                        Form::<T>::from_request(req, state).await.map_err(|e| match e {
+                            FormRejection::FailedToDeserializeFormBody(e) => {
+                                ValidationError::InvalidData(e.body_text())
+                            },
+                            _ => ValidationError::FormError,
+                        })?;
                     data
                 },
-                _ => return Err(ValidationError::DataMissing),
+                Some(ct) => return Err(ValidationError::UnsupportedMediaType(ct.to_string())),
+                None => return Err(ValidationError::DataMissing),
             };
 
             Validate::validate(&data).map_err(ValidationError::from)?;
@@ -273,6 +374,142 @@ where
     }
 }
 
+/**
+ * 实现从请求中提取查询参数的功能
+ * 
+ * 查询参数无法反序列化时返回 `InvalidData`
+ */
+impl<S, T> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Send + Sync,
+{
+    type Rejection = ValidationError;
+
+    fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
+        async move {
+            let Query(data) = Query::<T>::from_request_parts(parts, state)
+                .await
+                .map_err(|e| ValidationError::InvalidData(e.body_text()))?;
+            Ok(ValidatedQuery(data))
+        }
+    }
+}
+
+/**
+ * 实现从请求中提取JSON请求体的功能
+ * 
+ * 先检查Content-Type，再读取请求体按 `parse_json` 解析
+ */
+impl<S, T> FromRequest<S> for ValidJson<T>
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Send + Sync,
+{
+    type Rejection = ValidationError;
+
+    fn from_request(
+        req: Request,
+        state: &S,
+    ) -> impl Future<Output = Result<Self, Self::Rejection>> + Send {
+        async move {
+            if !is_json_content_type(req.headers()) {
+                let content_type = req
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                return Err(ValidationError::UnsupportedMediaType(content_type));
+            }
+
+            let bytes = Bytes::from_request(req, state)
+                .await
+                .map_err(|e| ValidationError::JsonError(e.body_text()))?;
+            let deny_unknown_fields = DENY_UNKNOWN_FIELDS.get().copied().unwrap_or(false);
+            parse_json(&bytes, deny_unknown_fields).map(ValidJson)
+        }
+    }
+}
+
+/**
+ * 判断请求的Content-Type是否为JSON
+ * 
+ * 接受 `application/json` 和 `application/*+json`，与 `Json` 提取器一致
+ */
+fn is_json_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<mime::Mime>().ok())
+        .is_some_and(|mime| {
+            mime.type_() == "application"
+                && (mime.subtype() == "json" || mime.suffix().is_some_and(|s| s == "json"))
+        })
+}
+
+/**
+ * 解析JSON请求体
+ * 
+ * # 参数
+ * * `bytes` - 请求体
+ * * `deny_unknown_fields` - 是否拒绝目标类型没有的字段，`#[serde(flatten)]` 字段中的未知字段无法识别
+ * 
+ * # 返回
+ * * `Result<T, ValidationError>` - 语法错误返回 `JsonSyntax`，取值无法转换返回 `InvalidData`，
+ *   包含未知字段返回 `UnknownFields`
+ */
+fn parse_json<T: DeserializeOwned>(
+    bytes: &[u8],
+    deny_unknown_fields: bool,
+) -> Result<T, ValidationError> {
+    let mut unknown_fields = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let result = if deny_unknown_fields {
+        serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+            &mut deserializer,
+            |path| unknown_fields.push(path.to_string()),
+        ))
+    } else {
+        serde_path_to_error::deserialize(&mut deserializer)
+    };
+
+    let data = result.map_err(|e| {
+        let path = e.path().to_string();
+        json_error(&path, e.into_inner())
+    })?;
+    deserializer.end().map_err(|e| json_error(".", e))?;
+
+    if !unknown_fields.is_empty() {
+        return Err(ValidationError::UnknownFields(unknown_fields));
+    }
+    Ok(data)
+}
+
+/**
+ * 将JSON反序列化错误转换为验证错误
+ * 
+ * # 参数
+ * * `path` - 出错字段的路径，根节点为 `.`
+ * * `error` - 反序列化错误
+ */
+fn json_error(path: &str, error: serde_json::Error) -> ValidationError {
+    match error.classify() {
+        Category::Data if path == "." => ValidationError::InvalidData(error.to_string()),
+        Category::Data => ValidationError::InvalidData(format!("{}: {}", path, error)),
+        Category::Syntax | Category::Eof | Category::Io => {
+            let (line, column) = (error.line(), error.column());
+            let message = error.to_string();
+            let location = format!(" at line {} column {}", line, column);
+            let detail = message.strip_suffix(location.as_str()).unwrap_or(&message).to_string();
+            ValidationError::JsonSyntax { line, column, detail }
+        },
+    }
+}
+
 /**
  * 实现验证错误的响应转换
  * 
@@ -283,20 +520,62 @@ impl IntoResponse for ValidationError {
     /**
      * 将验证错误转换为HTTP响应
      * 
-     * 根据错误类型生成不同的响应：
+     * 根据错误类型生成不同的响应，错误消息按当前请求语言本地化：
      * - JsonError：返回400状态码和JSON错误信息
+     * - JsonSyntax：返回400状态码和错误所在的行、列
+     * - UnsupportedMediaType：返回415状态码
+     * - UnknownFields：返回422状态码和未知字段列表
      * - FormError：返回400状态码和表单错误信息
-     * - Validation：返回400状态码和详细的字段验证错误
+     * - InvalidData：返回422状态码和无法转换的字段信息
+     * - Validation：返回422状态码和详细的字段验证错误
      * - DataMissing：返回400状态码和数据缺失错误信息
      * 
      * # 返回
      * * `Response` - HTTP响应
      */
     fn into_response(self) -> Response {
+        let no_params = HashMap::new();
         let (status, error_message) = match self {
-            ValidationError::JsonError(msg) => (StatusCode::BAD_REQUEST, msg),
-            ValidationError::FormError => {
-                (StatusCode::BAD_REQUEST, "Invalid form data".to_string())
+            ValidationError::JsonError(msg) => {
+                let params = HashMap::from([(Cow::Borrowed("detail"), JsonValue::String(msg))]);
+                (
+                    StatusCode::BAD_REQUEST,
+                    localize_validation("validation.invalid_json", "", &params),
+                )
+            },
+            ValidationError::JsonSyntax { line, column, detail } => {
+                let params = HashMap::from([
+                    (Cow::Borrowed("line"), JsonValue::from(line)),
+                    (Cow::Borrowed("column"), JsonValue::from(column)),
+                    (Cow::Borrowed("detail"), JsonValue::String(detail)),
+                ]);
+                (
+                    StatusCode::BAD_REQUEST,
+                    localize_validation("validation.json_syntax", "", &params),
+                )
+            },
+            ValidationError::UnsupportedMediaType(_) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                localize_validation("validation.unsupported_media_type", "", &no_params),
+            ),
+            ValidationError::UnknownFields(fields) => {
+                let fields = JsonValue::String(fields.join(", "));
+                let params = HashMap::from([(Cow::Borrowed("fields"), fields)]);
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    localize_validation("validation.unknown_fields", "", &params),
+                )
+            },
+            ValidationError::FormError => (
+                StatusCode::BAD_REQUEST,
+                localize_validation("validation.invalid_form", "", &no_params),
+            ),
+            ValidationError::InvalidData(msg) => {
+                let params = HashMap::from([(Cow::Borrowed("detail"), JsonValue::String(msg))]);
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    localize_validation("validation.invalid_data", "", &params),
+                )
             },
             ValidationError::Validation(errors) => {
                 let error_messages: serde_json::Map<String, JsonValue> = errors
@@ -305,13 +584,7 @@ impl IntoResponse for ValidationError {
                     .map(|(field, errors)| {
                         let messages: Vec<String> = errors
                             .iter()
-                            .map(|error| {
-                                error
-                                    .message
-                                    .as_ref()
-                                    .map(|cow| cow.to_string())
-                                    .unwrap_or_else(|| "Unknown error".to_string())
-                            })
+                            .map(|error| field_error_message(&field, error))
                             .collect();
                         (
                             field.to_string(),
@@ -320,18 +593,180 @@ impl IntoResponse for ValidationError {
                     })
                     .collect();
                 (
-                    StatusCode::BAD_REQUEST,
+                    StatusCode::UNPROCESSABLE_ENTITY,
                     serde_json::to_string(
                         &serde_json::json!({ "validation_errors": error_messages }),
                     )
                     .unwrap(),
                 )
             },
-            ValidationError::DataMissing => {
-                (StatusCode::BAD_REQUEST, "Data is missing".to_string())
-            },
+            ValidationError::DataMissing => (
+                StatusCode::BAD_REQUEST,
+                localize_validation("validation.data_missing", "", &no_params),
+            ),
         };
 
         Res::<String>::new_error(status.as_u16(), &error_message).into_response()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        routing::{get, post},
+        Router,
+    };
+    use serde::Deserialize;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[derive(Validate)]
+    struct Input {
+        #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
+        code: String,
+        #[validate(length(min = 1))]
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn test_validation_messages_resolved_from_keys() {
+        let input = Input { code: String::new(), name: String::new() };
+
+        let mut messages = input.validate_with_errors().await.unwrap_err();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec!["Code must be between 1 and 50 characters", "name has an invalid length"]
+        );
+    }
+
+    #[derive(Deserialize, Validate)]
+    struct Payload {
+        #[allow(dead_code)]
+        count: u8,
+    }
+
+    #[tokio::test]
+    async fn test_undeserializable_values_rejected_with_422() {
+        let app = Router::new().route("/", post(|_: ValidatedForm<Payload>| async {}));
+        let status = |content_type: &'static str, body: &'static str| {
+            let app = app.clone();
+            async move {
+                let request = Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header(CONTENT_TYPE, content_type)
+                    .body(Body::from(body))
+                    .unwrap();
+                app.oneshot(request).await.unwrap().status()
+            }
+        };
+
+        assert_eq!(status("application/json", r#"{"count": 1}"#).await, StatusCode::OK);
+        assert_eq!(
+            status("application/json", r#"{"count": 300}"#).await,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(status("application/json", "{").await, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            status("application/x-www-form-urlencoded", "count=x").await,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        name: String,
+        count: u8,
+    }
+
+    #[tokio::test]
+    async fn test_json_rejections_use_envelope() {
+        let app = Router::new()
+            .route("/", post(|ValidJson(item): ValidJson<Item>| async move { item.name }))
+            .layer(crate::web::RequestIdLayer);
+        let send = |content_type: &'static str, body: &'static str| {
+            let app = app.clone();
+            async move {
+                let request = Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header(CONTENT_TYPE, content_type)
+                    .header("x-request-id", "req-1")
+                    .body(Body::from(body))
+                    .unwrap();
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<JsonValue>(&body).unwrap_or_default())
+            }
+        };
+
+        let (status, _) =
+            send("application/json; charset=utf-8", r#"{"name":"a","count":1}"#).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send("text/plain", r#"{"name":"a","count":1}"#).await;
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(body["code"], 415);
+        assert_eq!(body["request_id"], "req-1");
+
+        let (status, body) = send("application/json", "{\"name\": \"a\",\n\"count\": }").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["request_id"], "req-1");
+        assert!(body["message"].as_str().unwrap().contains("line 2 column"));
+
+        let (status, body) = send("application/json", r#"{"name":"a","count":300}"#).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["request_id"], "req-1");
+        assert!(body["message"].as_str().unwrap().starts_with("count: "));
+    }
+
+    #[test]
+    fn test_unknown_fields_rejected_when_enabled() {
+        let body = br#"{"name":"a","count":1,"extra":true}"#;
+        let item = Item { name: "a".to_string(), count: 1 };
+        assert_eq!(parse_json::<Item>(body, false).unwrap(), item);
+
+        match parse_json::<Item>(body, true) {
+            Err(ValidationError::UnknownFields(fields)) => assert_eq!(fields, ["extra"]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(parse_json::<Item>(br#"{"name":"a","count":1}"#, true).unwrap(), item);
+    }
+
+    #[derive(Deserialize)]
+    struct PageRequest {
+        #[serde(flatten)]
+        page_details: crate::web::page::PageRequest,
+    }
+
+    #[tokio::test]
+    async fn test_page_query_size_enforced() {
+        let app = Router::new().route(
+            "/",
+            get(|ValidatedQuery(params): ValidatedQuery<PageRequest>| async move {
+                params.page_details.size.to_string()
+            }),
+        );
+        let get = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, String::from_utf8(body.to_vec()).unwrap())
+            }
+        };
+
+        assert_eq!(get("/").await, (StatusCode::OK, "10".to_string()));
+        assert_eq!(get("/?current=2&size=100").await, (StatusCode::OK, "100".to_string()));
+
+        let (status, body) = get("/?size=100000").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("size must not exceed 100"));
+    }
+}
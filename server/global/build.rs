@@ -0,0 +1,57 @@
+/**
+ * 构建脚本
+ *
+ * 记录构建时的Git提交、构建时间和rustc版本，供 `server_version` 模块在编译期读取：
+ * - Git提交优先读取环境变量 `GIT_HASH`，用于不包含 `.git` 目录的构建环境（如Docker构建），
+ *   未设置时读取当前仓库的提交，均不可用时不记录
+ * - 构建时间优先读取 `SOURCE_DATE_EPOCH`，以支持可重复构建
+ */
+use std::{
+    env,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_HASH");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs");
+
+    let git_hash = env::var("GIT_HASH")
+        .ok()
+        .filter(|hash| !hash.trim().is_empty())
+        .or_else(|| command_output("git", &["rev-parse", "--short=12", "HEAD"]));
+    if let Some(git_hash) = git_hash {
+        println!("cargo:rustc-env=SERVER_GIT_HASH={}", git_hash.trim());
+    }
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    if let Some(rustc_version) = command_output(&rustc, &["--version"]) {
+        println!("cargo:rustc-env=SERVER_RUSTC_VERSION={}", rustc_version);
+    }
+
+    let build_timestamp = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.trim().parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default()
+        });
+    println!("cargo:rustc-env=SERVER_BUILD_TIMESTAMP={}", build_timestamp);
+}
+
+/**
+ * 执行命令并返回去掉首尾空白的标准输出，命令不存在或执行失败时返回None
+ */
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (!stdout.is_empty()).then(|| stdout.to_string())
+}
@@ -0,0 +1,316 @@
+/**
+ * 有界事件通道模块
+ *
+ * 提供带容量上限和溢出策略的事件通道，防止慢消费者导致队列无限增长。
+ * 每个通道统计当前队列深度和被丢弃的事件数量，供监控使用。
+ *
+ * # 溢出策略
+ * - DropOldest: 队列满时丢弃最旧的事件，适用于允许丢失历史数据的场景（如操作日志）
+ * - DropNewest: 队列满时丢弃新事件
+ * - BlockWithTimeout: 队列满时等待空位，超时后丢弃新事件；同步发送时直接丢弃新事件
+ */
+
+use std::{
+    collections::VecDeque,
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::{
+    sync::{mpsc::error::SendError, Notify},
+    time::{timeout_at, Instant},
+};
+
+/**
+ * 事件通道溢出策略
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// 丢弃最旧的事件
+    DropOldest,
+    /// 丢弃新事件
+    DropNewest,
+    /// 等待空位，超时后丢弃新事件
+    BlockWithTimeout(Duration),
+}
+
+/**
+ * 事件通道配置
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelConfig {
+    /// 队列容量，最小为1
+    pub capacity: usize,
+    /// 队列满时的溢出策略
+    pub overflow: OverflowPolicy,
+}
+
+/**
+ * 事件通道统计信息
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventChannelStats {
+    /// 通道名称
+    pub name: String,
+    /// 队列容量
+    pub capacity: usize,
+    /// 当前队列深度
+    pub depth: usize,
+    /// 累计丢弃的事件数量
+    pub dropped: u64,
+}
+
+struct Shared<T> {
+    name: String,
+    config: ChannelConfig,
+    queue: Mutex<VecDeque<T>>,
+    dropped: AtomicU64,
+    closed: AtomicBool,
+    item_ready: Notify,
+    space_ready: Notify,
+}
+
+/**
+ * 创建有界事件通道
+ *
+ * # 参数
+ * * `name` - 通道名称，用于统计信息
+ * * `config` - 通道配置
+ *
+ * # 返回
+ * * `(EventSender<T>, EventReceiver<T>)` - 发送端和接收端
+ */
+pub fn channel<T>(name: impl Into<String>, config: ChannelConfig) -> (EventSender<T>, EventReceiver<T>) {
+    let config = ChannelConfig {
+        capacity: config.capacity.max(1),
+        ..config
+    };
+    let shared = Arc::new(Shared {
+        name: name.into(),
+        config,
+        queue: Mutex::new(VecDeque::with_capacity(config.capacity)),
+        dropped: AtomicU64::new(0),
+        closed: AtomicBool::new(false),
+        item_ready: Notify::new(),
+        space_ready: Notify::new(),
+    });
+    (
+        EventSender {
+            shared: shared.clone(),
+        },
+        EventReceiver { shared },
+    )
+}
+
+/**
+ * 事件发送端
+ */
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> EventSender<T> {
+    /**
+     * 同步发送事件
+     *
+     * 不等待空位：DropOldest策略下替换最旧的事件，其余策略下丢弃新事件。
+     *
+     * # 返回
+     * * `Result<(), SendError<T>>` - 事件被丢弃或通道已关闭时返回原事件
+     */
+    pub fn try_send(&self, event: T) -> Result<(), SendError<T>> {
+        if self.shared.closed.load(Ordering::Acquire) {
+            return Err(SendError(event));
+        }
+
+        let mut queue = self.shared.queue.lock();
+        if queue.len() < self.shared.config.capacity {
+            queue.push_back(event);
+        } else if self.shared.config.overflow == OverflowPolicy::DropOldest {
+            queue.pop_front();
+            queue.push_back(event);
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(SendError(event));
+        }
+        drop(queue);
+
+        self.shared.item_ready.notify_one();
+        Ok(())
+    }
+
+    /**
+     * 异步发送事件
+     *
+     * BlockWithTimeout策略下等待空位直到超时，其余策略与 `try_send` 相同。
+     *
+     * # 返回
+     * * `Result<(), SendError<T>>` - 超时丢弃或通道已关闭时返回原事件
+     */
+    pub async fn send(&self, event: T) -> Result<(), SendError<T>> {
+        let OverflowPolicy::BlockWithTimeout(wait) = self.shared.config.overflow else {
+            return self.try_send(event);
+        };
+
+        let deadline = Instant::now() + wait;
+        let mut event = event;
+        loop {
+            let mut notified = pin!(self.shared.space_ready.notified());
+            notified.as_mut().enable();
+
+            if self.shared.closed.load(Ordering::Acquire) {
+                return Err(SendError(event));
+            }
+
+            let mut queue = self.shared.queue.lock();
+            if queue.len() < self.shared.config.capacity {
+                queue.push_back(event);
+                drop(queue);
+                self.shared.item_ready.notify_one();
+                return Ok(());
+            }
+            drop(queue);
+
+            if timeout_at(deadline, notified).await.is_err() {
+                self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                return Err(SendError(event));
+            }
+        }
+    }
+
+    /**
+     * 获取通道统计信息
+     */
+    pub fn stats(&self) -> EventChannelStats {
+        EventChannelStats {
+            name: self.shared.name.clone(),
+            capacity: self.shared.config.capacity,
+            depth: self.shared.queue.lock().len(),
+            dropped: self.shared.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /**
+     * 关闭通道
+     *
+     * 关闭后新事件将被拒绝，接收端处理完剩余事件后结束。
+     */
+    pub fn close(&self) {
+        self.shared.close();
+    }
+}
+
+/**
+ * 事件接收端
+ *
+ * 接收端被丢弃时通道自动关闭。
+ */
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventReceiver<T> {
+    /**
+     * 接收事件
+     *
+     * # 返回
+     * * `Option<T>` - 通道关闭且队列为空时返回None
+     */
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            let mut notified = pin!(self.shared.item_ready.notified());
+            notified.as_mut().enable();
+
+            if let Some(event) = self.shared.queue.lock().pop_front() {
+                self.shared.space_ready.notify_one();
+                return Some(event);
+            }
+            if self.shared.closed.load(Ordering::Acquire) {
+                return None;
+            }
+
+            notified.await;
+        }
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.close();
+    }
+}
+
+impl<T> Shared<T> {
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.item_ready.notify_waiters();
+        self.space_ready.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(capacity: usize, overflow: OverflowPolicy) -> ChannelConfig {
+        ChannelConfig { capacity, overflow }
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_keeps_latest_events() {
+        let (tx, mut rx) = channel("test", config(2, OverflowPolicy::DropOldest));
+        for i in 0..4 {
+            assert!(tx.try_send(i).is_ok());
+        }
+
+        let stats = tx.stats();
+        assert_eq!((stats.depth, stats.dropped), (2, 2));
+        assert_eq!(rx.recv().await, Some(2));
+        assert_eq!(rx.recv().await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_block_with_timeout_waits_for_capacity() {
+        let (tx, mut rx) = channel(
+            "test",
+            config(1, OverflowPolicy::BlockWithTimeout(Duration::from_millis(50))),
+        );
+        tx.send(1).await.unwrap();
+
+        // 队列已满且无人消费，超时后丢弃
+        assert!(tx.send(2).await.is_err());
+        assert_eq!(tx.stats().dropped, 1);
+
+        // 消费者腾出空位后发送成功
+        let sender = tx.clone();
+        let pending = tokio::spawn(async move { sender.send(3).await });
+        assert_eq!(rx.recv().await, Some(1));
+        assert!(pending.await.unwrap().is_ok());
+        assert_eq!(rx.recv().await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_recv_returns_none_after_close() {
+        let (tx, mut rx) = channel("test", config(4, OverflowPolicy::DropNewest));
+        tx.try_send(1).unwrap();
+        tx.close();
+
+        assert!(tx.try_send(2).is_err());
+        assert_eq!(rx.recv().await, Some(1));
+        assert_eq!(rx.recv().await, None);
+    }
+}
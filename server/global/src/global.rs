@@ -13,10 +13,11 @@
  * ## 连接池管理
  * 支持多种数据库和服务的连接池管理：
  * - 主数据库连接
- * - 多数据库连接池
+ * - 多数据库连接池（支持主库/只读副本角色及健康状态）
  * - Redis连接（单实例和集群）
  * - MongoDB连接
- * - S3客户端
+ * - S3客户端及对象存储（S3或本地文件系统）
+ * - SMTP邮件发送连接池
  * 
  * ## JWT管理
  * 提供JWT令牌的签名和验证功能：
@@ -24,9 +25,11 @@
  * - 验证器配置
  * 
  * ## 事件系统
- * 提供基于通道的事件通信机制：
+ * 提供基于有界通道的事件通信机制：
  * - 字符串事件通道
+ * - 类型化事件通道（按事件类型注册监听器，同一类型可有多个监听器）
  * - 动态类型事件通道
+ * - 队列深度与丢弃数量统计
  * 
  * ## 路由管理
  * 记录和管理API路由信息，包括：
@@ -41,28 +44,42 @@
  * - 请求信息
  * - 响应信息
  * - 时间信息
+ * 
+ * 操作日志上下文按请求隔离，保存在任务本地变量中，并发请求互不影响。
  */
 
 use std::{
-    any::{Any, TypeId},
+    any::{type_name, Any, TypeId},
+    cell::RefCell,
     collections::HashMap,
     future::Future,
     pin::Pin,
     sync::Arc,
+    time::Duration,
 };
 
 use aws_sdk_s3::Client as S3Client;
 use chrono::NaiveDateTime;
 use http::Method;
 use jsonwebtoken::{DecodingKey, EncodingKey, Validation};
+use lettre::{AsyncSmtpTransport, Tokio1Executor};
 use mongodb::Client as MongoClient;
 use once_cell::sync::Lazy;
 use redis::{cluster::ClusterClient, Client};
 use sea_orm::DatabaseConnection;
 use serde_json::Value;
-use tokio::sync::{mpsc, Mutex, OnceCell, RwLock};
+use server_storage::ObjectStore;
+use tokio::{
+    sync::{mpsc::error::SendError, Mutex, OnceCell, RwLock},
+    task::JoinHandle,
+    time::{timeout_at, Instant},
+};
+
+use crate::{event_channel, project_error, project_info};
 
-use crate::project_info;
+pub use crate::event_channel::{
+    ChannelConfig, EventChannelStats, EventReceiver, EventSender, OverflowPolicy,
+};
 
 //*****************************************************************************
 // 全局配置管理
@@ -85,13 +102,15 @@ pub static GLOBAL_CONFIG: Lazy<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>
  */
 pub static GLOBAL_ROUTES: Lazy<RwLock<Vec<RouteInfo>>> = Lazy::new(|| RwLock::new(Vec::new()));
 
-/**
- * 全局操作日志上下文
- * 
- * 存储当前请求的操作日志上下文。
- * 使用RwLock包装，支持并发访问。
- */
-pub static GLOBAL_OPERATION_LOG_CTX: Lazy<RwLock<Option<OperationLogContext>>> = Lazy::new(|| RwLock::new(None));
+tokio::task_local! {
+    /**
+     * 当前请求的操作日志上下文
+     * 
+     * 由 `OperationLogContext::scope` 为每个请求单独建立，
+     * 并发请求各自持有自己的上下文，互不覆盖。
+     */
+    static OPERATION_LOG_CTX: RefCell<Option<OperationLogContext>>;
+}
 
 /**
  * 初始化全局配置
@@ -146,6 +165,35 @@ pub static GLOBAL_PRIMARY_DB: Lazy<RwLock<Option<Arc<DatabaseConnection>>>> =
 pub static GLOBAL_DB_POOL: Lazy<RwLock<HashMap<String, Arc<DatabaseConnection>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+/**
+ * 数据库实例角色
+ * 
+ * 用于读写分离：写操作始终使用主库，读操作优先使用健康的只读副本。
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbRole {
+    /// 主库
+    Primary,
+    /// 主库的只读副本
+    Replica,
+}
+
+/**
+ * 数据库实例角色表
+ * 
+ * 使用连接池名称作为键，未登记的实例不参与读写分离。
+ */
+pub static GLOBAL_DB_ROLES: Lazy<RwLock<HashMap<String, DbRole>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/**
+ * 数据库实例健康状态
+ * 
+ * 由数据库健康检查任务维护，未记录的实例视为健康。
+ */
+pub static GLOBAL_DB_HEALTH: Lazy<RwLock<HashMap<String, bool>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
 /**
  * Redis连接类型
  * 
@@ -216,6 +264,36 @@ pub static GLOBAL_PRIMARY_S3: Lazy<RwLock<Option<Arc<S3Client>>>> = Lazy::new(||
 pub static GLOBAL_S3_POOL: Lazy<RwLock<HashMap<String, Arc<S3Client>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+/**
+ * 主对象存储
+ * 
+ * 按主S3配置的 `provider` 创建的对象存储实现，业务代码通过它访问对象存储，
+ * 不直接依赖S3客户端。
+ */
+pub static GLOBAL_PRIMARY_OBJECT_STORE: Lazy<RwLock<Option<Arc<dyn ObjectStore>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+/**
+ * 对象存储池
+ * 
+ * 使用S3实例名称作为键，存储按各实例配置创建的对象存储实现。
+ */
+pub static GLOBAL_OBJECT_STORE_POOL: Lazy<RwLock<HashMap<String, Arc<dyn ObjectStore>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+//*****************************************************************************
+// SMTP邮件发送管理
+//*****************************************************************************
+
+/**
+ * SMTP邮件发送器
+ * 
+ * 存储带连接池的异步SMTP传输实例，未配置邮件时为None。
+ * 使用RwLock包装，支持并发访问。
+ */
+pub static GLOBAL_MAILER: Lazy<RwLock<Option<Arc<AsyncSmtpTransport<Tokio1Executor>>>>> =
+    Lazy::new(|| RwLock::new(None));
+
 //*****************************************************************************
 // JWT密钥和验证管理
 //*****************************************************************************
@@ -269,6 +347,9 @@ pub static VALIDATION: OnceCell<Arc<Mutex<Validation>>> = OnceCell::const_new();
 // 事件通道管理
 //*****************************************************************************
 
+/** 字符串事件通道名称 */
+pub const STRING_EVENT_CHANNEL: &str = "string_event";
+
 /**
  * 动态类型事件通道条目
  * 
@@ -278,7 +359,50 @@ struct DynChannelEntry {
     /// 通道名称
     name: String,
     /// 事件发送器
-    tx: mpsc::UnboundedSender<Box<dyn Any + Send>>,
+    tx: EventSender<Box<dyn Any + Send>>,
+    /// 监听器任务
+    handle: JoinHandle<()>,
+}
+
+/**
+ * 类型化事件通道条目
+ * 
+ * 每个监听器独占一个通道，同一事件类型可以登记多个条目。
+ */
+struct TypedChannelEntry {
+    /// 事件类型
+    type_id: TypeId,
+    /// 通道名称
+    name: String,
+    /// 事件发送器，实际类型为 `EventSender<T>`
+    tx: Box<dyn TypedSender>,
+    /// 监听器任务
+    handle: JoinHandle<()>,
+}
+
+/**
+ * 擦除事件类型的发送器
+ * 
+ * 关闭通道和统计时不需要事件类型，发送时通过 `as_any` 取回 `EventSender<T>`。
+ */
+trait TypedSender: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn close(&self);
+    fn stats(&self) -> EventChannelStats;
+}
+
+impl<T: Send + 'static> TypedSender for EventSender<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn close(&self) {
+        EventSender::close(self);
+    }
+
+    fn stats(&self) -> EventChannelStats {
+        EventSender::stats(self)
+    }
 }
 
 /**
@@ -288,35 +412,51 @@ struct DynChannelEntry {
  */
 struct EventChannels {
     /// 字符串事件发送器
-    string_tx: mpsc::UnboundedSender<String>,
+    string_tx: EventSender<String>,
+    /// 字符串事件监听器任务
+    string_handle: Option<JoinHandle<()>>,
     /// 动态类型事件通道列表
     dyn_channels: Vec<DynChannelEntry>,
+    /// 类型化事件通道列表
+    typed_channels: Vec<TypedChannelEntry>,
 }
 
 /**
  * 全局事件通道管理器
  * 
  * 存储应用程序的事件通道管理器实例。
- * 使用Lazy和Mutex包装，支持并发访问。
+ * 使用同步读写锁包装，支持在同步上下文中发送事件。
  */
-static EVENT_CHANNELS: Lazy<Arc<Mutex<EventChannels>>> = Lazy::new(|| {
-    let (string_tx, _) = mpsc::unbounded_channel();
-    Arc::new(Mutex::new(EventChannels {
+static EVENT_CHANNELS: Lazy<parking_lot::RwLock<EventChannels>> = Lazy::new(|| {
+    let (string_tx, _) = event_channel::channel(
+        STRING_EVENT_CHANNEL,
+        ChannelConfig {
+            capacity: 1,
+            overflow: OverflowPolicy::DropNewest,
+        },
+    );
+    parking_lot::RwLock::new(EventChannels {
         string_tx,
+        string_handle: None,
         dyn_channels: Vec::new(),
-    }))
+        typed_channels: Vec::new(),
+    })
 });
 
 /// 动态Future类型别名
 type DynFuture = dyn Future<Output = ()> + Send + 'static;
 
 /// 字符串事件监听器类型别名
-type StringListener = Box<dyn FnOnce(mpsc::UnboundedReceiver<String>) -> Pin<Box<DynFuture>>>;
+pub type StringListener = (
+    ChannelConfig,
+    Box<dyn FnOnce(EventReceiver<String>) -> Pin<Box<DynFuture>>>,
+);
 
 /// 动态类型事件监听器类型别名
-type DynListener = (
+pub type DynListener = (
     String,
-    Box<dyn Fn(mpsc::UnboundedReceiver<Box<dyn Any + Send>>) -> Pin<Box<DynFuture>>>,
+    ChannelConfig,
+    Box<dyn Fn(EventReceiver<Box<dyn Any + Send>>) -> Pin<Box<DynFuture>>>,
 );
 
 /**
@@ -325,11 +465,11 @@ type DynListener = (
  * 返回全局字符串事件通道的发送器。
  * 
  * # 返回
- * * `mpsc::UnboundedSender<String>` - 字符串事件发送器
+ * * `EventSender<String>` - 字符串事件发送器
  */
 #[inline]
-pub async fn get_string_sender() -> mpsc::UnboundedSender<String> {
-    EVENT_CHANNELS.lock().await.string_tx.clone()
+pub async fn get_string_sender() -> EventSender<String> {
+    EVENT_CHANNELS.read().string_tx.clone()
 }
 
 /**
@@ -341,60 +481,239 @@ pub async fn get_string_sender() -> mpsc::UnboundedSender<String> {
  * * `name` - 通道名称
  * 
  * # 返回
- * * `Option<mpsc::UnboundedSender<Box<dyn Any + Send>>>` - 如果存在则返回发送器，否则返回None
+ * * `Option<EventSender<Box<dyn Any + Send>>>` - 如果存在则返回发送器，否则返回None
  */
 #[inline]
-pub async fn get_dyn_sender(name: &str) -> Option<mpsc::UnboundedSender<Box<dyn Any + Send>>> {
-    let channels = EVENT_CHANNELS.lock().await;
-    channels
+pub async fn get_dyn_sender(name: &str) -> Option<EventSender<Box<dyn Any + Send>>> {
+    find_dyn_sender(name)
+}
+
+fn find_dyn_sender(name: &str) -> Option<EventSender<Box<dyn Any + Send>>> {
+    EVENT_CHANNELS
+        .read()
         .dyn_channels
         .iter()
         .find(|entry| entry.name == name)
         .map(|entry| entry.tx.clone())
 }
 
+/**
+ * 注册类型化事件监听器
+ * 
+ * 为事件类型 `T` 创建独立的有界通道并启动监听器任务，发送端按事件类型而不是通道名称
+ * 查找监听器，监听器直接收到 `T`，不需要运行时类型转换。同一事件类型可以注册多个监听器，
+ * 每个监听器都收到一份事件。同名监听器重复注册时关闭旧通道，旧监听器处理完剩余事件后退出。
+ * 必须在Tokio运行时中调用。
+ * 
+ * # 参数
+ * * `name` - 通道名称，用于统计信息和停机日志
+ * * `config` - 通道配置
+ * * `listener` - 监听器，接收该事件类型的接收端
+ * 
+ * # 使用示例
+ * 
+ * ```
+ * use server_global::global::{self, ChannelConfig, EventReceiver, OverflowPolicy};
+ * 
+ * #[derive(Clone)]
+ * struct UserCreated {
+ *     user_id: String,
+ * }
+ * 
+ * async fn user_created_listener(mut rx: EventReceiver<UserCreated>) {
+ *     while let Some(event) = rx.recv().await {
+ *         println!("user {} created", event.user_id);
+ *     }
+ * }
+ * 
+ * # #[tokio::main(flavor = "current_thread")]
+ * # async fn main() {
+ * let config = ChannelConfig {
+ *     capacity: 16,
+ *     overflow: OverflowPolicy::DropNewest,
+ * };
+ * global::register_typed_listener("user_created_event", config, user_created_listener);
+ * assert!(global::send_typed_event(UserCreated { user_id: "1".to_string() }).is_ok());
+ * # }
+ * ```
+ * 
+ * 监听器的接收端类型必须与注册的事件类型一致，不一致时无法编译：
+ * 
+ * ```compile_fail
+ * use server_global::global::{self, ChannelConfig, EventReceiver, OverflowPolicy};
+ * 
+ * #[derive(Clone)]
+ * struct UserCreated;
+ * 
+ * async fn string_listener(_rx: EventReceiver<String>) {}
+ * 
+ * let config = ChannelConfig {
+ *     capacity: 16,
+ *     overflow: OverflowPolicy::DropNewest,
+ * };
+ * global::register_typed_listener::<UserCreated, _, _>("user_created", config, string_listener);
+ * ```
+ */
+pub fn register_typed_listener<T, F, Fut>(name: &str, config: ChannelConfig, listener: F)
+where
+    T: Send + 'static,
+    F: FnOnce(EventReceiver<T>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let (tx, rx) = event_channel::channel::<T>(name, config);
+    let handle = tokio::spawn(listener(rx));
+
+    let mut channels = EVENT_CHANNELS.write();
+    channels.typed_channels.retain(|entry| {
+        if entry.name == name {
+            entry.tx.close();
+        }
+        entry.name != name
+    });
+    channels.typed_channels.push(TypedChannelEntry {
+        type_id: TypeId::of::<T>(),
+        name: name.to_string(),
+        tx: Box::new(tx),
+        handle,
+    });
+    project_info!(
+        "Typed event listener '{}' for {} spawned (capacity {}, {:?})",
+        name,
+        type_name::<T>(),
+        config.capacity,
+        config.overflow
+    );
+}
+
+/**
+ * 获取事件类型 `T` 的所有监听器通道的发送器
+ */
+fn typed_senders<T: Send + 'static>() -> Vec<EventSender<T>> {
+    EVENT_CHANNELS
+        .read()
+        .typed_channels
+        .iter()
+        .filter(|entry| entry.type_id == TypeId::of::<T>())
+        .filter_map(|entry| entry.tx.as_any().downcast_ref::<EventSender<T>>().cloned())
+        .collect()
+}
+
 /**
  * 注册事件监听器
  * 
- * 注册字符串事件监听器和动态类型事件监听器。
+ * 注册字符串事件监听器和动态类型事件监听器，每个通道按配置创建有界队列。
+ * 重复注册时关闭旧通道，旧监听器处理完剩余事件后退出。
  * 
  * # 参数
- * * `string_listener` - 字符串事件监听器
- * * `dyn_listeners` - 动态类型事件监听器列表
+ * * `string_listener` - 字符串事件通道配置及监听器
+ * * `dyn_listeners` - 动态类型事件通道名称、配置及监听器列表
  */
 pub async fn register_event_listeners(
     string_listener: StringListener,
     dyn_listeners: &[DynListener],
 ) {
-    let mut channels = EVENT_CHANNELS.lock().await;
+    let mut channels = EVENT_CHANNELS.write();
 
     // 设置字符串事件通道
-    let (string_tx, string_rx) = mpsc::unbounded_channel();
+    let (string_config, string_listener) = string_listener;
+    let (string_tx, string_rx) = event_channel::channel(STRING_EVENT_CHANNEL, string_config);
+    channels.string_tx.close();
     channels.string_tx = string_tx;
 
     // 启动字符串事件监听器
-    tokio::spawn(string_listener(string_rx));
+    channels.string_handle = Some(tokio::spawn(string_listener(string_rx)));
     project_info!("String event listener spawned");
 
-    // 清空旧的发送器
-    channels.dyn_channels.clear();
+    // 关闭并清空旧的发送器
+    for entry in channels.dyn_channels.drain(..) {
+        entry.tx.close();
+    }
 
     // 为每个监听器创建独立通道
-    for (name, listener) in dyn_listeners {
-        let (tx, rx) = mpsc::unbounded_channel();
+    for (name, config, listener) in dyn_listeners {
+        let (tx, rx) = event_channel::channel(name.clone(), *config);
+        let handle = tokio::spawn(listener(rx));
         channels.dyn_channels.push(DynChannelEntry {
             name: name.clone(),
             tx,
+            handle,
         });
-        tokio::spawn(listener(rx));
-        project_info!("Dynamic event listener '{}' spawned", name);
+        project_info!(
+            "Dynamic event listener '{}' spawned (capacity {}, {:?})",
+            name,
+            config.capacity,
+            config.overflow
+        );
     }
 }
 
+/**
+ * 关闭事件通道并等待监听器退出
+ * 
+ * 用于优雅停机：关闭所有通道后不再接收新事件，监听器处理完队列中剩余的事件
+ * （批量写入的监听器同时写入缓冲区）后退出。超过等待时间仍未退出的监听器被放弃，
+ * 其未处理的事件随进程退出丢失。
+ * 
+ * # 参数
+ * * `wait` - 等待所有监听器退出的最长时间
+ */
+pub async fn shutdown_event_listeners(wait: Duration) {
+    let handles: Vec<(String, JoinHandle<()>)> = {
+        let mut channels = EVENT_CHANNELS.write();
+        channels.string_tx.close();
+        let string_handle = channels
+            .string_handle
+            .take()
+            .map(|handle| (STRING_EVENT_CHANNEL.to_string(), handle));
+        let dyn_handles: Vec<_> = channels
+            .dyn_channels
+            .drain(..)
+            .map(|entry| {
+                entry.tx.close();
+                (entry.name, entry.handle)
+            })
+            .collect();
+        let typed_handles: Vec<_> = channels
+            .typed_channels
+            .drain(..)
+            .map(|entry| {
+                entry.tx.close();
+                (entry.name, entry.handle)
+            })
+            .collect();
+        string_handle.into_iter().chain(dyn_handles).chain(typed_handles).collect()
+    };
+
+    let deadline = Instant::now() + wait;
+    for (name, handle) in handles {
+        if timeout_at(deadline, handle).await.is_err() {
+            project_error!("Event listener '{}' did not finish before shutdown", name);
+        }
+    }
+    project_info!("Event listeners stopped");
+}
+
+/**
+ * 获取事件通道统计信息
+ * 
+ * 返回所有事件通道的容量、当前队列深度和累计丢弃数量，供监控接口使用。
+ * 
+ * # 返回
+ * * `Vec<EventChannelStats>` - 各事件通道的统计信息
+ */
+pub fn get_event_channel_stats() -> Vec<EventChannelStats> {
+    let channels = EVENT_CHANNELS.read();
+    std::iter::once(channels.string_tx.stats())
+        .chain(channels.dyn_channels.iter().map(|entry| entry.tx.stats()))
+        .chain(channels.typed_channels.iter().map(|entry| entry.tx.stats()))
+        .collect()
+}
+
 /**
  * 路由信息
  * 
- * 记录API路由的详细信息，包括路径、方法、服务名称和描述。
+ * 记录API路由的详细信息，包括路径、方法、服务名称和描述，
+ * 以及用于生成OpenAPI文档的可选请求/响应结构。
  */
 #[derive(Clone)]
 pub struct RouteInfo {
@@ -406,6 +725,10 @@ pub struct RouteInfo {
     pub service_name: String,
     /// 路由描述
     pub summary: String,
+    /// 请求体JSON Schema
+    pub request_schema: Option<Value>,
+    /// 响应数据JSON Schema，不包含统一响应体
+    pub response_schema: Option<Value>,
 }
 
 impl RouteInfo {
@@ -424,8 +747,32 @@ impl RouteInfo {
             method,
             service_name: service_name.to_string(),
             summary: summary.to_string(),
+            request_schema: None,
+            response_schema: None,
         }
     }
+
+    /**
+     * 设置请求体结构
+     * 
+     * # 参数
+     * * `schema` - 请求体的JSON Schema
+     */
+    pub fn with_request_schema(mut self, schema: Value) -> Self {
+        self.request_schema = Some(schema);
+        self
+    }
+
+    /**
+     * 设置响应数据结构
+     * 
+     * # 参数
+     * * `schema` - 响应数据的JSON Schema，生成文档时包装在统一响应体的 `data` 字段中
+     */
+    pub fn with_response_schema(mut self, schema: Value) -> Self {
+        self.response_schema = Some(schema);
+        self
+    }
 }
 
 /**
@@ -502,72 +849,208 @@ pub struct OperationLogContext {
     pub end_time: NaiveDateTime,
     /// 持续时间（毫秒）
     pub duration: i32,
+    /// 是否超过请求处理时间上限
+    pub timed_out: bool,
+    /// 响应的HTTP状态码
+    pub status_code: u16,
     /// 创建时间
     pub created_at: NaiveDateTime,
 }
 
 impl OperationLogContext {
+    /**
+     * 在独立的操作日志上下文中执行
+     * 
+     * 操作日志中间件为每个请求调用一次，`set`、`get`、`clear` 只在该范围内生效。
+     * 
+     * # 参数
+     * * `future` - 请求处理过程
+     */
+    pub async fn scope<F: Future>(future: F) -> F::Output {
+        OPERATION_LOG_CTX.scope(RefCell::new(None), future).await
+    }
+
+    /**
+     * 当前任务是否处于操作日志上下文范围内
+     */
+    pub fn in_scope() -> bool {
+        OPERATION_LOG_CTX.try_with(|_| ()).is_ok()
+    }
+
     /**
      * 设置操作日志上下文
      * 
-     * 将操作日志上下文存储到全局上下文中。
+     * 将操作日志上下文存储到当前请求中，不在 `scope` 范围内时忽略。
      * 
      * # 参数
      * * `context` - 操作日志上下文
      */
-    pub async fn set(context: OperationLogContext) {
-        let mut ctx = GLOBAL_OPERATION_LOG_CTX.write().await;
-        *ctx = Some(context);
+    pub fn set(context: OperationLogContext) {
+        let _ = OPERATION_LOG_CTX.try_with(|ctx| *ctx.borrow_mut() = Some(context));
     }
 
     /**
      * 获取操作日志上下文
      * 
-     * 从全局上下文中获取操作日志上下文。
+     * 从当前请求中获取操作日志上下文。
+     * 操作日志事件处理器直接从事件中取得上下文，不依赖本方法。
      * 
      * # 返回
      * * `Option<OperationLogContext>` - 如果存在则返回操作日志上下文，否则返回None
      */
-    pub async fn get() -> Option<OperationLogContext> {
-        GLOBAL_OPERATION_LOG_CTX.read().await.clone()
+    pub fn get() -> Option<OperationLogContext> {
+        OPERATION_LOG_CTX.try_with(|ctx| ctx.borrow().clone()).ok().flatten()
     }
 
     /**
      * 清空操作日志上下文
      * 
-     * 清空全局上下文中的操作日志上下文。
+     * 清空当前请求中的操作日志上下文。
      */
-    pub async fn clear() {
-        let mut ctx = GLOBAL_OPERATION_LOG_CTX.write().await;
-        *ctx = None;
+    pub fn clear() {
+        let _ = OPERATION_LOG_CTX.try_with(|ctx| ctx.borrow_mut().take());
     }
 }
 
 /**
  * 发送字符串事件
  * 
- * 向全局字符串事件通道发送事件。
+ * 向全局字符串事件通道发送事件，按通道的溢出策略等待空位。
  * 
  * # 参数
  * * `msg` - 事件消息
  */
 pub async fn send_string_event(msg: String) {
-    let tx = EVENT_CHANNELS.lock().await.string_tx.clone();
-    let _ = tx.send(msg);
+    let tx = EVENT_CHANNELS.read().string_tx.clone();
+    let _ = tx.send(msg).await;
+}
+
+/**
+ * 发送类型化事件
+ * 
+ * 向事件类型 `T` 的每个监听器发送一份事件，不等待空位，队列已满时按各通道的溢出策略丢弃。
+ * 
+ * # 参数
+ * * `event` - 事件对象
+ * 
+ * # 返回
+ * * `Result<(), SendError<T>>` - 没有监听器接收该事件时返回原事件，包括该类型未注册监听器、
+ *   通道已关闭或全部按溢出策略丢弃
+ */
+pub fn send_typed_event<T: Clone + Send + 'static>(event: T) -> Result<(), SendError<T>> {
+    let senders = typed_senders::<T>();
+    let Some((last, others)) = senders.split_last() else {
+        return Err(SendError(event));
+    };
+
+    let mut delivered = false;
+    for tx in others {
+        delivered |= tx.try_send(event.clone()).is_ok();
+    }
+    last.try_send(event).or_else(|e| if delivered { Ok(()) } else { Err(e) })
+}
+
+/**
+ * 异步发送类型化事件
+ * 
+ * 向事件类型 `T` 的每个监听器发送一份事件，通道策略为等待超时时，等待队列空位直到超时。
+ * 
+ * # 参数
+ * * `event` - 事件对象
+ * 
+ * # 返回
+ * * `Result<(), SendError<T>>` - 没有监听器接收该事件时返回原事件
+ */
+pub async fn send_typed_event_async<T: Clone + Send + 'static>(
+    event: T,
+) -> Result<(), SendError<T>> {
+    let senders = typed_senders::<T>();
+    let Some((last, others)) = senders.split_last() else {
+        return Err(SendError(event));
+    };
+
+    let mut delivered = false;
+    for tx in others {
+        delivered |= tx.send(event.clone()).await.is_ok();
+    }
+    last.send(event).await.or_else(|e| if delivered { Ok(()) } else { Err(e) })
 }
 
 /**
  * 发送动态类型事件
  * 
- * 向指定的动态类型事件通道发送事件。
+ * 向指定的动态类型事件通道发送事件，不等待空位。
+ * 队列已满时按通道的溢出策略丢弃事件。
  * 
  * # 参数
  * * `event_name` - 事件通道名称
  * * `event` - 事件对象
  */
 pub fn send_dyn_event(event_name: &'static str, event: Box<dyn Any + Send>) {
-    let channels = EVENT_CHANNELS.blocking_lock();
-    if let Some(entry) = channels.dyn_channels.iter().find(|entry| entry.name == event_name) {
-        let _ = entry.tx.send(event);
+    if let Some(tx) = find_dyn_sender(event_name) {
+        let _ = tx.try_send(event);
+    }
+}
+
+/**
+ * 异步发送动态类型事件
+ * 
+ * 向指定的动态类型事件通道发送事件。
+ * 通道策略为等待超时时，等待队列空位直到超时。
+ * 
+ * # 参数
+ * * `event_name` - 事件通道名称
+ * * `event` - 事件对象
+ */
+pub async fn send_dyn_event_async(event_name: &'static str, event: Box<dyn Any + Send>) {
+    if let Some(tx) = find_dyn_sender(event_name) {
+        let _ = tx.send(event).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Registered(u32);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Unregistered(u32);
+
+    fn forward(
+        tx: tokio::sync::mpsc::UnboundedSender<(&'static str, u32)>,
+        name: &'static str,
+    ) -> impl FnOnce(EventReceiver<Registered>) -> Pin<Box<DynFuture>> {
+        move |mut rx| {
+            Box::pin(async move {
+                while let Some(Registered(value)) = rx.recv().await {
+                    let _ = tx.send((name, value));
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_typed_event_delivery() {
+        let config = ChannelConfig {
+            capacity: 4,
+            overflow: OverflowPolicy::DropNewest,
+        };
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        register_typed_listener("registered_a", config, forward(tx.clone(), "a"));
+        register_typed_listener("registered_b", config, forward(tx, "b"));
+
+        // 未注册监听器的事件类型返回原事件，而不是被静默丢弃
+        let err = send_typed_event(Unregistered(1)).unwrap_err();
+        assert_eq!(err.0, Unregistered(1));
+        let err = send_typed_event_async(Unregistered(2)).await.unwrap_err();
+        assert_eq!(err.0, Unregistered(2));
+
+        // 同一事件类型的每个监听器都收到一份事件
+        send_typed_event(Registered(3)).unwrap();
+        let mut received = vec![rx.recv().await.unwrap(), rx.recv().await.unwrap()];
+        received.sort();
+        assert_eq!(received, vec![("a", 3), ("b", 3)]);
     }
 }
@@ -9,9 +9,13 @@
  *   - MongoDB连接池：支持主MongoDB和多个MongoDB连接的管理
  *   - S3客户端池：支持主S3客户端和多个S3客户端的管理
  *   - JWT密钥管理：提供JWT令牌的签名和验证功能
- *   - 事件通道：支持字符串和动态类型的事件通信
+ *   - 事件通道：支持字符串、类型化和动态类型的事件通信
+ * - event_channel: 有界事件通道，支持溢出策略与队列统计
+ * - openapi: 根据收集的路由信息生成OpenAPI文档
+ * - redis_key: 带应用和环境前缀的Redis键生成
  *   - 路由信息收集：记录和管理API路由信息
  *   - 操作日志上下文：记录和管理操作日志信息
+ * - server_version: 编译期记录的版本号、Git提交、构建时间和rustc版本
  * 
  * # 主要功能
  * 
@@ -22,7 +26,7 @@
  * 提供统一的连接池管理接口，支持多种数据库和服务的连接管理。
  * 
  * ## 事件系统
- * 提供基于通道的事件通信机制，支持字符串和动态类型的事件处理。
+ * 提供基于通道的事件通信机制，支持字符串、类型化和动态类型的事件处理。
  * 
  * ## 日志系统
  * 提供两个重要的日志宏：
@@ -35,9 +39,21 @@
 /// 重新导出JWT验证器
 pub use jsonwebtoken::Validation;
 
+/// 有界事件通道模块
+pub mod event_channel;
+
 /// 全局状态管理模块
 pub mod global;
 
+/// OpenAPI文档生成模块
+pub mod openapi;
+
+/// Redis键命名模块
+pub mod redis_key;
+
+/// 服务版本信息模块
+pub mod server_version;
+
 /**
  * 项目信息日志宏
  * 
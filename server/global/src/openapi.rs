@@ -0,0 +1,228 @@
+/**
+ * OpenAPI文档模块
+ *
+ * 根据全局收集的路由信息生成 OpenAPI 3.0 文档，包括：
+ * - 每个已注册路由的路径、方法、摘要和所属服务（作为标签）
+ * - 从路径中提取的路径参数
+ * - 路由注册时附带的请求/响应结构（可选），响应结构会包装在统一响应体中
+ * - JWT Bearer 与复杂API密钥两种安全方案
+ */
+
+use std::collections::BTreeSet;
+
+use http::Method;
+use serde_json::{json, Map, Value};
+
+use crate::global::RouteInfo;
+
+/** OpenAPI规范版本 */
+pub const OPENAPI_VERSION: &str = "3.0.3";
+
+/** JWT Bearer 安全方案名称 */
+pub const JWT_SECURITY_SCHEME: &str = "bearerAuth";
+
+/** 复杂API密钥安全方案名称 */
+pub const COMPLEX_API_KEY_SECURITY_SCHEME: &str = "complexApiKey";
+
+/**
+ * 生成OpenAPI文档
+ *
+ * # 参数
+ * * `title` - 文档标题
+ * * `version` - 接口版本
+ * * `routes` - 收集的路由信息
+ *
+ * # 返回
+ * * `Value` - OpenAPI 3.0 JSON 文档
+ */
+pub fn build_openapi_document(title: &str, version: &str, routes: &[RouteInfo]) -> Value {
+    let mut paths = Map::new();
+    let mut tags = BTreeSet::new();
+
+    for route in routes {
+        let Some(method) = operation_method(&route.method) else {
+            continue;
+        };
+        tags.insert(route.service_name.clone());
+
+        let path_item = paths
+            .entry(route.path.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(item) = path_item {
+            item.insert(method.to_string(), build_operation(route));
+        }
+    }
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": title,
+            "version": version,
+        },
+        "tags": tags
+            .into_iter()
+            .map(|name| json!({ "name": name }))
+            .collect::<Vec<_>>(),
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                JWT_SECURITY_SCHEME: {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "bearerFormat": "JWT",
+                },
+                COMPLEX_API_KEY_SECURITY_SCHEME: {
+                    "type": "apiKey",
+                    "in": "query",
+                    "name": "AccessKeyId",
+                    "description": "Requests must also carry the timestamp `t`, nonce `n` and signature `sign` query parameters",
+                },
+            },
+        },
+        "security": [
+            { JWT_SECURITY_SCHEME: [] },
+            { COMPLEX_API_KEY_SECURITY_SCHEME: [] },
+        ],
+    })
+}
+
+/**
+ * 构建单个路由的操作对象
+ */
+fn build_operation(route: &RouteInfo) -> Value {
+    let mut operation = Map::new();
+    operation.insert("tags".to_string(), json!([route.service_name]));
+    operation.insert("summary".to_string(), json!(route.summary));
+
+    let parameters: Vec<Value> = path_parameters(&route.path)
+        .into_iter()
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            })
+        })
+        .collect();
+    if !parameters.is_empty() {
+        operation.insert("parameters".to_string(), Value::Array(parameters));
+    }
+
+    // GET/HEAD 请求体语义未定义，其请求结构不输出
+    if let Some(schema) = &route.request_schema {
+        if route.method != Method::GET && route.method != Method::HEAD {
+            operation.insert(
+                "requestBody".to_string(),
+                json!({
+                    "required": true,
+                    "content": { "application/json": { "schema": schema } },
+                }),
+            );
+        }
+    }
+
+    let data_schema = route.response_schema.clone().unwrap_or_else(|| json!({}));
+    operation.insert(
+        "responses".to_string(),
+        json!({
+            "200": {
+                "description": "OK",
+                "content": {
+                    "application/json": { "schema": response_envelope(data_schema) },
+                },
+            },
+        }),
+    );
+
+    Value::Object(operation)
+}
+
+/**
+ * 统一响应体结构
+ *
+ * 与 `Res<T>` 的序列化结构保持一致
+ */
+fn response_envelope(data_schema: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "code": { "type": "integer", "format": "int32" },
+            "message": { "type": "string" },
+            "data": data_schema,
+            "request_id": { "type": "string", "nullable": true },
+            "timestamp": { "type": "integer", "format": "int64" },
+        },
+        "required": ["code", "message"],
+    })
+}
+
+/**
+ * 转换为OpenAPI操作名称，不支持的方法返回None
+ */
+fn operation_method(method: &Method) -> Option<&'static str> {
+    match *method {
+        Method::GET => Some("get"),
+        Method::PUT => Some("put"),
+        Method::POST => Some("post"),
+        Method::DELETE => Some("delete"),
+        Method::OPTIONS => Some("options"),
+        Method::HEAD => Some("head"),
+        Method::PATCH => Some("patch"),
+        Method::TRACE => Some("trace"),
+        _ => None,
+    }
+}
+
+/**
+ * 提取路径中的 `{name}` 参数
+ */
+fn path_parameters(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|name| name.trim_start_matches('*'))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_contains_every_route() {
+        let routes = vec![
+            RouteInfo::new("/user", Method::GET, "SysUserApi", "获取用户列表"),
+            RouteInfo::new("/user", Method::POST, "SysUserApi", "创建用户")
+                .with_request_schema(json!({ "type": "object" })),
+            RouteInfo::new("/user/{id}", Method::DELETE, "SysUserApi", "删除用户"),
+            RouteInfo::new("/auth-key/{id}", Method::GET, "SysAccessKeyApi", "获取访问密钥详情")
+                .with_response_schema(json!({ "type": "object" })),
+        ];
+
+        let doc = build_openapi_document("alion-admin", "0.1.0", &routes);
+
+        assert_eq!(doc["openapi"], OPENAPI_VERSION);
+        for route in &routes {
+            let method = operation_method(&route.method).unwrap();
+            assert_eq!(doc["paths"][&route.path][method]["summary"], route.summary);
+        }
+        assert!(doc["paths"]["/user"]["post"]["requestBody"].is_object());
+        assert_eq!(
+            doc["paths"]["/user/{id}"]["delete"]["parameters"][0]["name"],
+            "id"
+        );
+        assert_eq!(
+            doc["paths"]["/auth-key/{id}"]["get"]["responses"]["200"]["content"]
+                ["application/json"]["schema"]["properties"]["data"]["type"],
+            "object"
+        );
+        assert!(doc["components"]["securitySchemes"][JWT_SECURITY_SCHEME].is_object());
+        assert!(doc["components"]["securitySchemes"][COMPLEX_API_KEY_SECURITY_SCHEME].is_object());
+    }
+
+    #[test]
+    fn test_path_parameters() {
+        assert_eq!(path_parameters("/user/{id}/roles/{roleId}"), vec!["id", "roleId"]);
+        assert!(path_parameters("/user").is_empty());
+    }
+}
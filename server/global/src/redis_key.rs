@@ -0,0 +1,204 @@
+/**
+ * Redis键命名模块
+ *
+ * 统一生成Redis键，所有键都带有 `{app}:{env}:` 前缀，
+ * 不同环境和应用共用一个Redis实例时不会互相覆盖。
+ *
+ * 键格式（前缀以 `alion:prod:` 为例）：
+ * - nonce: `alion:prod:nonce:{api_key}:{nonce}`
+ * - 登录失败计数: `alion:prod:login_attempts:{domain}:{username}`
+ * - 验证码: `alion:prod:captcha:{id}`
+ * - 限流令牌桶: `alion:prod:rate_limit:{group}:{subject}`
+ * - 维护模式: `alion:prod:system:maintenance`
+ * - 令牌角色引用: `alion:prod:token_roles:{roles_ref}`
+ *
+ * 前缀在Redis初始化时通过 `KeyBuilder::init` 设置一次，未设置时使用 `alion:dev:`。
+ */
+
+use std::fmt;
+
+use once_cell::sync::OnceCell;
+
+/** 默认应用名称 */
+pub const DEFAULT_KEY_APP: &str = "alion";
+
+/** 默认环境名称 */
+pub const DEFAULT_KEY_ENV: &str = "dev";
+
+/** 键的最大长度（字节） */
+pub const MAX_KEY_LENGTH: usize = 256;
+
+/** 前缀的最大长度（字节） */
+const MAX_PREFIX_LENGTH: usize = 64;
+
+/** 键前缀，形如 `alion:prod:` */
+static KEY_PREFIX: OnceCell<String> = OnceCell::new();
+
+/**
+ * Redis键生成错误
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedisKeyError {
+    /** 应用名称或环境名称为空、包含冒号或过长 */
+    InvalidPrefix(String),
+    /** 键长度超过 MAX_KEY_LENGTH */
+    KeyTooLong(usize),
+}
+
+impl fmt::Display for RedisKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedisKeyError::InvalidPrefix(prefix) => {
+                write!(f, "Invalid Redis key prefix: {}", prefix)
+            },
+            RedisKeyError::KeyTooLong(len) => {
+                write!(f, "Redis key length {} exceeds {} bytes", len, MAX_KEY_LENGTH)
+            },
+        }
+    }
+}
+
+impl std::error::Error for RedisKeyError {}
+
+/**
+ * Redis键生成器
+ *
+ * 各模块通过类型化的构造函数获取键，不再自行拼接
+ */
+pub struct KeyBuilder;
+
+impl KeyBuilder {
+    /**
+     * 设置键前缀
+     *
+     * 只在第一次调用时生效，之后的调用被忽略
+     *
+     * # 参数
+     * * `app` - 应用名称
+     * * `env` - 环境名称，例如 dev、staging、prod
+     */
+    pub fn init(app: &str, env: &str) -> Result<(), RedisKeyError> {
+        let prefix = Self::build_prefix(app, env)?;
+        let _ = KEY_PREFIX.set(prefix);
+        Ok(())
+    }
+
+    /**
+     * 获取当前键前缀
+     */
+    pub fn prefix() -> &'static str {
+        KEY_PREFIX.get_or_init(|| format!("{}:{}:", DEFAULT_KEY_APP, DEFAULT_KEY_ENV))
+    }
+
+    /**
+     * API签名nonce的键
+     */
+    pub fn nonce(api_key: &str, nonce: &str) -> Result<String, RedisKeyError> {
+        Self::build(Self::prefix(), &["nonce", api_key, nonce])
+    }
+
+    /**
+     * 登录失败计数的键
+     */
+    pub fn login_attempts(domain: &str, username: &str) -> Result<String, RedisKeyError> {
+        Self::build(Self::prefix(), &["login_attempts", domain, username])
+    }
+
+    /**
+     * 验证码的键
+     */
+    pub fn captcha(id: &str) -> Result<String, RedisKeyError> {
+        Self::build(Self::prefix(), &["captcha", id])
+    }
+
+    /**
+     * 限流令牌桶的键
+     *
+     * # 参数
+     * * `group` - 限流分组
+     * * `subject` - 限流对象，例如 `ip:127.0.0.1`
+     */
+    pub fn rate_limit(group: &str, subject: &str) -> Result<String, RedisKeyError> {
+        Self::build(Self::prefix(), &["rate_limit", group, subject])
+    }
+
+    /**
+     * 维护模式状态的键
+     */
+    pub fn maintenance() -> String {
+        format!("{}system:maintenance", Self::prefix())
+    }
+
+    /**
+     * 令牌角色列表的键
+     *
+     * # 参数
+     * * `roles_ref` - 令牌声明中的角色引用
+     */
+    pub fn token_roles(roles_ref: &str) -> Result<String, RedisKeyError> {
+        Self::build(Self::prefix(), &["token_roles", roles_ref])
+    }
+
+    fn build_prefix(app: &str, env: &str) -> Result<String, RedisKeyError> {
+        let prefix = format!("{}:{}:", app, env);
+        let valid = |part: &str| !part.is_empty() && !part.contains(':');
+        if !valid(app) || !valid(env) || prefix.len() > MAX_PREFIX_LENGTH {
+            return Err(RedisKeyError::InvalidPrefix(prefix));
+        }
+        Ok(prefix)
+    }
+
+    fn build(prefix: &str, parts: &[&str]) -> Result<String, RedisKeyError> {
+        let key = format!("{}{}", prefix, parts.join(":"));
+        if key.len() > MAX_KEY_LENGTH {
+            return Err(RedisKeyError::KeyTooLong(key.len()));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_prefix_keys() {
+        assert_eq!(KeyBuilder::prefix(), "alion:dev:");
+        assert_eq!(KeyBuilder::nonce("ak1", "n1").unwrap(), "alion:dev:nonce:ak1:n1");
+        assert_eq!(
+            KeyBuilder::login_attempts("built-in", "admin").unwrap(),
+            "alion:dev:login_attempts:built-in:admin"
+        );
+        assert_eq!(KeyBuilder::captcha("c1").unwrap(), "alion:dev:captcha:c1");
+        assert_eq!(
+            KeyBuilder::rate_limit("auth", "ip:127.0.0.1").unwrap(),
+            "alion:dev:rate_limit:auth:ip:127.0.0.1"
+        );
+        assert_eq!(KeyBuilder::maintenance(), "alion:dev:system:maintenance");
+        assert_eq!(KeyBuilder::token_roles("r1").unwrap(), "alion:dev:token_roles:r1");
+    }
+
+    #[test]
+    fn test_build_prefix() {
+        assert_eq!(KeyBuilder::build_prefix("alion", "staging").unwrap(), "alion:staging:");
+        assert!(KeyBuilder::build_prefix("alion", "").is_err());
+        assert!(KeyBuilder::build_prefix("alion", "prod:eu").is_err());
+        assert!(KeyBuilder::build_prefix("alion", &"e".repeat(MAX_PREFIX_LENGTH)).is_err());
+    }
+
+    #[test]
+    fn test_max_key_length() {
+        let prefix = "alion:prod:";
+        let fits = "a".repeat(MAX_KEY_LENGTH - prefix.len() - "captcha:".len());
+        assert_eq!(
+            KeyBuilder::build(prefix, &["captcha", &fits]).unwrap().len(),
+            MAX_KEY_LENGTH
+        );
+
+        let too_long = format!("{}a", fits);
+        assert_eq!(
+            KeyBuilder::build(prefix, &["captcha", &too_long]),
+            Err(RedisKeyError::KeyTooLong(MAX_KEY_LENGTH + 1))
+        );
+    }
+}
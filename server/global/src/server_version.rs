@@ -0,0 +1,72 @@
+/**
+ * 服务版本信息模块
+ *
+ * 提供编译期记录的构建信息，用于版本查询接口、启动日志和 `X-App-Version` 响应头：
+ * - 版本号: 工作区的crate版本
+ * - Git提交: 构建时的短提交哈希，由构建脚本写入，构建环境没有Git信息时为空
+ * - 构建时间: 构建脚本执行的时间（UTC）
+ * - rustc版本: 编译使用的rustc版本
+ *
+ * 版本字符串形如 `0.1.0+3f2a9c1d0b7e`，没有Git提交时只包含版本号。
+ */
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+
+/** crate版本号 */
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/** 构建时的Git短提交哈希 */
+pub const GIT_HASH: Option<&str> = option_env!("SERVER_GIT_HASH");
+
+/** 编译使用的rustc版本，如 `rustc 1.86.0 (05f9846f8 2025-03-31)` */
+pub const RUSTC_VERSION: Option<&str> = option_env!("SERVER_RUSTC_VERSION");
+
+/** 构建时间的Unix时间戳（秒） */
+const BUILD_TIMESTAMP: Option<&str> = option_env!("SERVER_BUILD_TIMESTAMP");
+
+/** 版本字符串，首次使用时生成 */
+static VERSION_STRING: Lazy<String> = Lazy::new(|| format_version(VERSION, GIT_HASH));
+
+/**
+ * 获取版本字符串
+ *
+ * # 返回
+ * * `&'static str` - 版本号，有Git提交时以 `+提交哈希` 结尾
+ */
+pub fn version_string() -> &'static str {
+    &VERSION_STRING
+}
+
+/**
+ * 获取构建时间
+ *
+ * # 返回
+ * * `Option<DateTime<Utc>>` - 构建时间，构建脚本未记录时为None
+ */
+pub fn build_time() -> Option<DateTime<Utc>> {
+    BUILD_TIMESTAMP?.parse::<i64>().ok().and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+}
+
+/**
+ * 拼接版本号与Git提交
+ */
+fn format_version(version: &str, git_hash: Option<&str>) -> String {
+    match git_hash {
+        Some(git_hash) => format!("{}+{}", version, git_hash),
+        None => version.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_version() {
+        assert_eq!(format_version("0.1.0", Some("3f2a9c1d0b7e")), "0.1.0+3f2a9c1d0b7e");
+        assert_eq!(format_version("0.1.0", None), "0.1.0");
+        assert!(version_string().starts_with(VERSION));
+        assert!(build_time().is_some());
+    }
+}
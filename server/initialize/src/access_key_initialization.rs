@@ -8,16 +8,25 @@
 use server_global::project_info;
 use server_service::admin::{SysAccessKeyService, TAccessKeyService};
 
+use crate::InitError;
+
 /**
  * 初始化访问密钥
  * 
- * 调用访问密钥服务初始化方法，创建系统所需的访问密钥。
- * 如果初始化失败，会记录错误日志但不会中断程序执行。
+ * 调用访问密钥服务初始化方法，将已有访问密钥加载到验证器中。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回访问密钥加载错误
  */
-pub async fn initialize_access_key() {
+pub async fn initialize_access_key() -> Result<(), InitError> {
     let access_key_service = SysAccessKeyService;
 
-    let _ = access_key_service.initialize_access_key().await;
+    access_key_service
+        .initialize_access_key()
+        .await
+        .map_err(|e| InitError::AccessKey(e.to_string()))?;
 
-    project_info!("Access key initialization completed successfully")
+    project_info!("Access key initialization completed successfully");
+    Ok(())
 }
@@ -6,9 +6,10 @@
  * - 初始化S3连接池
  * - 管理S3客户端连接
  * - 提供S3操作功能
+ * - 按 `provider` 创建对象存储实现（S3或本地文件系统）
  */
 #[allow(dead_code)]
-use std::{process, sync::Arc};
+use std::sync::Arc;
 use std::error::Error;
 
 use aws_config::BehaviorVersion;
@@ -16,30 +17,41 @@ use aws_sdk_s3::{
     config::{Credentials, Region},
     Client as S3Client,
 };
-use server_config::{OptionalConfigs, S3Config, S3InstancesConfig};
-use server_global::global::{get_config, GLOBAL_PRIMARY_S3, GLOBAL_S3_POOL};
+use server_config::{OptionalConfigs, S3Config, S3InstancesConfig, S3Provider};
+use server_global::global::{
+    get_config, GLOBAL_OBJECT_STORE_POOL, GLOBAL_PRIMARY_OBJECT_STORE, GLOBAL_PRIMARY_S3,
+    GLOBAL_S3_POOL,
+};
+use server_router::admin::SysStorageRouter;
+use server_storage::{LocalObjectStore, ObjectStore, S3ObjectStore, MIN_SIGNING_KEY_LEN};
 
-use crate::{project_error, project_info};
+use crate::{project_error, project_info, InitError};
 
 /**
  * 初始化主S3客户端
  * 
- * 根据配置创建并初始化主S3客户端连接。
- * 如果初始化失败，程序将退出。
+ * 根据配置创建主对象存储，`provider` 为 `aws` 时同时初始化主S3客户端连接。
+ * 未配置S3时直接跳过。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回客户端或存储目录创建错误
  */
-pub async fn init_primary_s3() {
+pub async fn init_primary_s3() -> Result<(), InitError> {
     if let Some(config) = get_config::<S3Config>().await {
-        match create_s3_client(&config).await {
-            Ok(client) => {
-                *GLOBAL_PRIMARY_S3.write().await = Some(Arc::new(client));
-                project_info!("Primary S3 client initialized");
+        match create_object_store(&config).await {
+            Ok((store, client)) => {
+                *GLOBAL_PRIMARY_S3.write().await = client;
+                *GLOBAL_PRIMARY_OBJECT_STORE.write().await = Some(store);
+                project_info!("Primary object store initialized ({:?})", config.provider);
             },
             Err(e) => {
-                project_error!("Failed to initialize primary S3 client: {}", e);
-                process::exit(1);
+                project_error!("Failed to initialize primary object store: {}", e);
+                return Err(InitError::S3(e.to_string()));
             },
         }
     }
+    Ok(())
 }
 
 /**
@@ -47,13 +59,20 @@ pub async fn init_primary_s3() {
  * 
  * 从配置中读取所有S3实例配置，
  * 并为每个实例创建S3客户端连接。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回首个客户端创建失败的错误
  */
-pub async fn init_s3_pools() {
+pub async fn init_s3_pools() -> Result<(), InitError> {
     if let Some(s3_instances_config) = get_config::<OptionalConfigs<S3InstancesConfig>>().await {
         if let Some(s3_instances) = &s3_instances_config.configs {
-            let _ = init_s3_pool(Some(s3_instances.clone())).await;
+            init_s3_pool(Some(s3_instances.clone()))
+                .await
+                .map_err(InitError::S3)?;
         }
     }
+    Ok(())
 }
 
 /**
@@ -89,14 +108,15 @@ pub async fn init_s3_pool(
  * - 失败：返回错误信息
  */
 async fn init_s3_connection(name: &str, config: &S3Config) -> Result<(), String> {
-    match create_s3_client(config).await {
-        Ok(client) => {
-            let client_arc = Arc::new(client);
-            GLOBAL_S3_POOL
-                .write()
-                .await
-                .insert(name.to_string(), client_arc);
-            project_info!("S3 client '{}' initialized", name);
+    match create_object_store(config).await {
+        Ok((store, client)) => {
+            let mut s3_pool = GLOBAL_S3_POOL.write().await;
+            match client {
+                Some(client) => s3_pool.insert(name.to_string(), client),
+                None => s3_pool.remove(name),
+            };
+            GLOBAL_OBJECT_STORE_POOL.write().await.insert(name.to_string(), store);
+            project_info!("S3 client '{}' initialized ({:?})", name, config.provider);
             Ok(())
         },
         Err(e) => {
@@ -107,6 +127,62 @@ async fn init_s3_connection(name: &str, config: &S3Config) -> Result<(), String>
     }
 }
 
+/**
+ * 按配置创建对象存储
+ * 
+ * # 参数
+ * - config: S3配置信息
+ * 
+ * # 返回
+ * - 成功：返回对象存储，`provider` 为 `aws` 时同时返回其使用的S3客户端
+ * - 失败：返回错误信息
+ */
+pub async fn create_object_store(
+    config: &S3Config,
+) -> Result<(Arc<dyn ObjectStore>, Option<Arc<S3Client>>), Box<dyn Error>> {
+    match config.provider {
+        S3Provider::Aws => {
+            let client = Arc::new(create_s3_client(config).await?);
+            let store = S3ObjectStore::new(client.clone(), config.bucket.clone());
+            Ok((Arc::new(store), Some(client)))
+        },
+        S3Provider::Local => Ok((Arc::new(create_local_object_store(config).await?), None)),
+    }
+}
+
+/**
+ * 创建本地文件系统对象存储
+ * 
+ * 基础目录不存在时自动创建；未配置 `public_url` 时预签名链接指向本服务的文件下载接口。
+ * 签名密钥缺失或过短时返回错误，服务启动失败，不使用随机密钥
+ * 
+ * # 参数
+ * - config: S3配置信息，`base_dir` 和 `signing_key` 必填
+ * 
+ * # 返回
+ * - 成功：返回本地对象存储
+ * - 失败：返回错误信息
+ */
+async fn create_local_object_store(config: &S3Config) -> Result<LocalObjectStore, Box<dyn Error>> {
+    let base_dir = config
+        .base_dir
+        .as_deref()
+        .filter(|dir| !dir.is_empty())
+        .ok_or("base_dir is required when provider is local")?;
+    let signing_key = config
+        .signing_key
+        .as_deref()
+        .filter(|key| !key.is_empty())
+        .ok_or("signing_key is required when provider is local")?;
+    if signing_key.len() < MIN_SIGNING_KEY_LEN {
+        return Err(format!("signing_key must be at least {} bytes", MIN_SIGNING_KEY_LEN).into());
+    }
+    tokio::fs::create_dir_all(base_dir).await?;
+
+    let public_url = config.public_url.clone().unwrap_or_else(SysStorageRouter::files_path);
+    Ok(LocalObjectStore::new(base_dir, public_url, signing_key.as_bytes()))
+}
+
 /**
  * 创建S3客户端
  * 
@@ -166,6 +242,18 @@ pub async fn get_primary_s3_client() -> Option<Arc<S3Client>> {
     GLOBAL_PRIMARY_S3.read().await.clone()
 }
 
+/**
+ * 获取主对象存储
+ * 
+ * # 返回
+ * - 成功：返回主对象存储
+ * - 失败：返回None
+ */
+#[allow(dead_code)]
+pub async fn get_primary_object_store() -> Option<Arc<dyn ObjectStore>> {
+    GLOBAL_PRIMARY_OBJECT_STORE.read().await.clone()
+}
+
 /**
  * 获取命名的S3客户端
  * 
@@ -209,8 +297,10 @@ pub async fn add_or_update_s3_pool(name: &str, config: &S3Config) -> Result<(),
  */
 #[allow(dead_code)]
 pub async fn remove_s3_pool(name: &str) -> Result<(), String> {
-    let mut s3_pool = GLOBAL_S3_POOL.write().await;
-    s3_pool
+    GLOBAL_S3_POOL.write().await.remove(name);
+    GLOBAL_OBJECT_STORE_POOL
+        .write()
+        .await
         .remove(name)
         .ok_or_else(|| format!("S3 client '{}' not found", name))?;
     project_info!("S3 client '{}' removed", name);
@@ -236,7 +326,7 @@ mod tests {
     async fn init() {
         let mut initialized = INITIALIZED.lock().await;
         if initialized.is_none() {
-            initialize_config("../resources/application.yaml").await;
+            initialize_config("../resources/application.yaml").await.unwrap();
             *initialized = Some(Arc::new(()));
         }
     }
@@ -322,7 +412,7 @@ mod tests {
         setup_logger();
         init().await;
 
-        init_primary_s3().await;
+        init_primary_s3().await.unwrap();
 
         let client = get_primary_s3_client().await;
         assert!(client.is_some(), "Primary S3 client does not exist");
@@ -353,6 +443,10 @@ mod tests {
                 bucket: "test-bucket".to_string(),
                 use_ssl: false,
                 custom_domain: None,
+                provider: S3Provider::Aws,
+                base_dir: None,
+                public_url: None,
+                signing_key: None,
             },
         };
 
@@ -402,9 +496,57 @@ mod tests {
             bucket: "test-bucket".to_string(),
             use_ssl: false,
             custom_domain: None,
+            provider: S3Provider::Aws,
+            base_dir: None,
+            public_url: None,
+            signing_key: None,
         };
 
         let result = create_s3_client(&config).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_local_object_store_initialization() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = S3Config {
+            endpoint: String::new(),
+            access_key_id: String::new(),
+            access_key_secret: String::new(),
+            region: None,
+            bucket: String::new(),
+            use_ssl: false,
+            custom_domain: None,
+            provider: S3Provider::Local,
+            base_dir: None,
+            public_url: None,
+            signing_key: None,
+        };
+        assert!(create_object_store(&config).await.is_err());
+
+        config.base_dir = Some(dir.path().join("objects").to_string_lossy().into_owned());
+        // 缺少签名密钥或密钥过短时拒绝启动，不退回随机密钥
+        assert!(create_object_store(&config).await.is_err());
+        config.signing_key = Some("short".to_string());
+        assert!(create_object_store(&config).await.is_err());
+        assert!(!dir.path().join("objects").exists());
+
+        config.signing_key = Some("k".repeat(MIN_SIGNING_KEY_LEN));
+        let (store, client) = create_object_store(&config).await.unwrap();
+        assert!(client.is_none());
+        assert!(dir.path().join("objects").is_dir());
+
+        store.put("a/b.txt", b"hello".to_vec(), Default::default()).await.unwrap();
+        assert_eq!(store.get("a/b.txt").await.unwrap(), b"hello");
+        assert_eq!(store.list("a/").await.unwrap(), ["a/b.txt"]);
+        let url = store.presign("a/b.txt", std::time::Duration::from_secs(60)).await.unwrap();
+        assert!(url.starts_with("/api/v1/storage/files/a/b.txt?expires="));
+
+        // 命名实例使用本地存储时只加入对象存储池
+        init_s3_connection("local_test", &config).await.unwrap();
+        assert!(GLOBAL_OBJECT_STORE_POOL.read().await.contains_key("local_test"));
+        assert!(get_s3_pool_connection("local_test").await.is_none());
+        remove_s3_pool("local_test").await.unwrap();
+        assert!(remove_s3_pool("local_test").await.is_err());
+    }
 }
@@ -0,0 +1,73 @@
+/**
+ * 初始管理员初始化模块
+ * 
+ * 本模块负责在启动时确保目标域内存在启用的超级管理员，
+ * 并检查数据迁移写入的默认密码是否仍在使用。
+ */
+
+use server_config::BootstrapConfig;
+use server_global::{global::get_config, project_error, project_info};
+use server_service::{admin::SysAdminAccountService, helper::db_helper};
+
+use crate::InitError;
+
+/**
+ * 初始化初始管理员
+ * 
+ * 配置了 `bootstrap` 时，如果目标域内没有拥有管理员角色的启用用户，
+ * 按配置创建管理员并分配角色；已存在时不做任何修改。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回密码未配置或管理员创建错误
+ */
+pub async fn initialize_bootstrap_admin() -> Result<(), InitError> {
+    let db = db_helper::get_db_connection()
+        .await
+        .map_err(|e| InitError::Database(e.to_string()))?;
+
+    match SysAdminAccountService::find_seed_password_users(db.as_ref()).await {
+        Ok(usernames) if !usernames.is_empty() => {
+            project_error!(
+                "SECURITY WARNING: users {:?} still use the default migration password, \
+                 change their passwords or disable them immediately",
+                usernames
+            );
+        },
+        Ok(_) => {},
+        Err(e) => project_error!("Failed to check default migration password: {}", e),
+    }
+
+    let Some(config) = get_config::<BootstrapConfig>().await else {
+        return Ok(());
+    };
+    let password = config
+        .admin_password()
+        .ok_or(InitError::MissingConfig("bootstrap.admin_password"))?;
+
+    let created = SysAdminAccountService::ensure_admin(
+        db.as_ref(),
+        &config.domain,
+        &config.admin_username,
+        &password,
+        &config.admin_role,
+    )
+    .await
+    .map_err(|e| InitError::Bootstrap(e.to_string()))?;
+
+    match created {
+        Some(user) => project_info!(
+            "Bootstrap admin '{}' ({}) created in domain '{}' with role {}",
+            user.username,
+            user.id,
+            config.domain,
+            config.admin_role
+        ),
+        None => project_info!(
+            "Enabled {} user already exists in domain '{}', bootstrap admin skipped",
+            config.admin_role,
+            config.domain
+        ),
+    }
+    Ok(())
+}
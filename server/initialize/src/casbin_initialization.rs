@@ -2,25 +2,35 @@
  * Casbin权限控制初始化模块
  * 
  * 本模块负责初始化Casbin权限控制系统，包括：
- * - 加载RBAC模型配置
+ * - 加载RBAC模型配置（文件不存在时使用内置模型）
  * - 创建数据库适配器
- * - 初始化Casbin中间件
+ * - 初始化Casbin中间件，按请求方法判定 read/write 操作
+ * 
+ * HTTP路由和gRPC鉴权服务共用 `init_casbin` 创建的同一个执行器。
  */
 
-use std::error::Error;
+use std::{error::Error, path::Path};
 
 use axum_casbin::CasbinAxumLayer;
 use casbin::DefaultModel;
 use sea_orm::Database;
 use sea_orm_adapter::SeaOrmAdapter;
+use server_config::{CasbinConfig, Config};
+use server_global::global::get_config;
+
+use crate::{project_info, InitError};
+
+/** 默认RBAC模型文件路径 */
+pub const DEFAULT_CASBIN_MODEL_PATH: &str = "server/resources/rbac_model.conf";
 
-use crate::project_info;
+/** 编译进程序的内置RBAC模型 */
+pub(crate) const EMBEDDED_CASBIN_MODEL: &str = include_str!("../../resources/rbac_model.conf");
 
 /**
  * 初始化Casbin权限控制系统
  * 
  * # 参数
- * - model_path: RBAC模型配置文件路径
+ * - model_path: RBAC模型配置文件路径，文件不存在时使用内置模型
  * - db_url: 数据库连接URL
  * 
  * # 返回
@@ -28,21 +38,85 @@ use crate::project_info;
  * - 失败：返回错误信息
  * 
  * # 处理流程
- * 1. 从文件加载RBAC模型
+ * 1. 从文件或内置模型加载RBAC模型
  * 2. 创建数据库连接
  * 3. 初始化数据库适配器
- * 4. 创建Casbin中间件
+ * 4. 创建Casbin中间件，GET/HEAD请求判定为 read，其他请求判定为 write
  */
 pub async fn initialize_casbin(
     model_path: &str,
     db_url: &str,
 ) -> Result<CasbinAxumLayer, Box<dyn Error>> {
-    project_info!("Initializing Casbin with model: {}", model_path);
-    let model = DefaultModel::from_file(model_path).await?;
+    let model = if Path::new(model_path).is_file() {
+        project_info!("Initializing Casbin with model file: {}", model_path);
+        DefaultModel::from_file(model_path).await?
+    } else {
+        project_info!(
+            "Casbin model file {} not found, initializing with embedded model",
+            model_path
+        );
+        DefaultModel::from_str(EMBEDDED_CASBIN_MODEL).await?
+    };
     let db = Database::connect(db_url).await?;
     let adapter = SeaOrmAdapter::new(db).await?;
 
-    let casbin_axum_layer = CasbinAxumLayer::new(model, adapter).await?;
+    // 策略的操作为 read 或 write，模型中 write 包含 read
+    let casbin_axum_layer = CasbinAxumLayer::new(model, adapter).await?.with_read_write_actions();
     project_info!("Casbin initialization completed successfully");
     Ok(casbin_axum_layer)
 }
+
+/**
+ * 按应用配置初始化Casbin权限控制系统
+ * 
+ * 模型文件路径读取Casbin配置，未配置时使用默认路径；策略存储于主数据库。
+ * 
+ * # 返回
+ * - 成功：返回CasbinAxumLayer实例，克隆后共享同一个执行器
+ * - 失败：返回 `InitError::Casbin`
+ */
+pub async fn init_casbin() -> Result<CasbinAxumLayer, InitError> {
+    let app_config = get_config::<Config>().await.ok_or(InitError::MissingConfig("database"))?;
+    let model_path = get_config::<CasbinConfig>()
+        .await
+        .and_then(|config| config.model_path.clone())
+        .unwrap_or_else(|| DEFAULT_CASBIN_MODEL_PATH.to_string());
+
+    initialize_casbin(&model_path, &app_config.database.url)
+        .await
+        .map_err(|e| InitError::Casbin(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use casbin::{CoreApi, Enforcer, MemoryAdapter, MgmtApi};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_embedded_model_is_valid() {
+        let model = DefaultModel::from_str(EMBEDDED_CASBIN_MODEL).await;
+        assert!(model.is_ok(), "Embedded model failed to parse: {:?}", model.err());
+    }
+
+    #[tokio::test]
+    async fn test_embedded_model_write_implies_read() {
+        let model = DefaultModel::from_str(EMBEDDED_CASBIN_MODEL).await.unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        enforcer
+            .add_policies(vec![
+                vec!["ROLE_A".into(), "built-in".into(), "/user/:id".into(), "write".into()],
+                vec!["ROLE_A".into(), "built-in".into(), "/role".into(), "read".into()],
+            ])
+            .await
+            .unwrap();
+
+        let enforce = |obj: &str, act: &str| {
+            enforcer.enforce(("ROLE_A", "built-in", obj, act)).unwrap()
+        };
+        assert!(enforce("/user/1", "read"));
+        assert!(enforce("/user/1", "write"));
+        assert!(enforce("/role", "read"));
+        assert!(!enforce("/role", "write"));
+    }
+}
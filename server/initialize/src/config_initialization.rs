@@ -5,7 +5,11 @@
  * 包括数据库、Redis、JWT等配置项。
  */
 
-use crate::{project_error, project_info};
+use server_config::{SecurityConfig, TimeConfig};
+use server_global::global;
+use server_utils::SecureUtil;
+
+use crate::{project_error, project_info, InitError};
 
 /**
  * 初始化系统配置
@@ -19,15 +23,48 @@ use crate::{project_error, project_info};
  * 1. 读取配置文件
  * 2. 解析配置内容
  * 3. 初始化全局配置
- * 4. 记录初始化结果
+ * 4. 设置密码哈希参数
+ * 5. 记录初始化结果
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回配置错误
  */
-pub async fn initialize_config(file_path: &str) {
+pub async fn initialize_config(file_path: &str) -> Result<(), InitError> {
     match server_config::init_from_file(file_path).await {
         Ok(_) => {
-            project_info!("Configuration initialized successfully from: {}", file_path)
+            project_info!("Configuration initialized successfully from: {}", file_path);
+            init_password_hash().await
         },
         Err(e) => {
             project_error!("Failed to initialize config from {}: {:?}", file_path, e);
+            Err(InitError::Config(e))
         },
     }
 }
+
+/**
+ * 按安全配置设置新密码哈希使用的 Argon2 参数
+ * 
+ * 参数超出 Argon2 允许范围时返回错误，避免启动后无法创建或修改密码
+ */
+async fn init_password_hash() -> Result<(), InitError> {
+    let config = global::get_config::<SecurityConfig>().await.unwrap_or_default();
+    let params = &config.password_hash;
+    SecureUtil::init_hash_params(params.memory_kib, params.iterations, params.parallelism)
+        .map_err(|e| {
+            project_error!("Invalid password hash parameters {:?}: {}", params, e);
+            InitError::InvalidConfig(format!("security.password_hash: {}", e))
+        })
+}
+
+/**
+ * 获取升级前数据写入时使用的UTC偏移（秒）
+ * 
+ * 未配置 `time.legacy_local_offset` 时返回None，已有数据视为按UTC写入。
+ * 供 `migrate` 子命令在迁移时将已有时间戳转换为UTC
+ */
+pub async fn get_legacy_local_offset() -> Option<i32> {
+    let config = global::get_config::<TimeConfig>().await.unwrap_or_default();
+    config.legacy_local_offset.map(|offset| offset.local_minus_utc())
+}
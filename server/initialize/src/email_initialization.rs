@@ -0,0 +1,86 @@
+/**
+ * 邮件初始化模块
+ * 
+ * 本模块负责初始化SMTP邮件发送服务，包括：
+ * - 校验发件人地址
+ * - 按加密方式创建带连接池的异步SMTP传输
+ * - 将传输实例存入全局状态供通知服务使用
+ */
+use std::sync::Arc;
+
+use lettre::{
+    message::Mailbox,
+    transport::smtp::{authentication::Credentials, PoolConfig},
+    AsyncSmtpTransport, Tokio1Executor,
+};
+use server_config::{EmailConfig, EmailTlsMode};
+use server_global::global::{get_config, GLOBAL_MAILER};
+
+use crate::{project_error, project_info, InitError};
+
+type Mailer = AsyncSmtpTransport<Tokio1Executor>;
+
+/**
+ * 初始化SMTP邮件发送器
+ * 
+ * 根据配置创建带连接池的SMTP传输实例。
+ * 未配置邮件时直接跳过，此时邮件通知不可用。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回发件人地址或SMTP服务器配置错误
+ */
+pub async fn initialize_email() -> Result<(), InitError> {
+    let Some(config) = get_config::<EmailConfig>().await else {
+        return Ok(());
+    };
+
+    match create_mailer(&config) {
+        Ok(mailer) => {
+            *GLOBAL_MAILER.write().await = Some(Arc::new(mailer));
+            project_info!("SMTP mailer initialized: {}:{}", config.host, config.port);
+            Ok(())
+        },
+        Err(e) => {
+            project_error!("Failed to initialize SMTP mailer: {}", e);
+            Err(InitError::Email(e))
+        },
+    }
+}
+
+/**
+ * 创建SMTP传输实例
+ * 
+ * 传输实例在首次发送时才建立连接，因此此处只校验配置本身。
+ * 
+ * # 参数
+ * - config: 邮件配置
+ * 
+ * # 返回
+ * - 成功：返回SMTP传输实例
+ * - 失败：返回错误信息
+ */
+fn create_mailer(config: &EmailConfig) -> Result<Mailer, String> {
+    config
+        .from
+        .parse::<Mailbox>()
+        .map_err(|e| format!("invalid sender address '{}': {}", config.from, e))?;
+
+    let mut builder = match config.tls {
+        EmailTlsMode::None => Mailer::builder_dangerous(&config.host),
+        EmailTlsMode::Starttls => {
+            Mailer::starttls_relay(&config.host).map_err(|e| e.to_string())?
+        },
+        EmailTlsMode::Tls => Mailer::relay(&config.host).map_err(|e| e.to_string())?,
+    };
+
+    builder = builder
+        .port(config.port)
+        .pool_config(PoolConfig::new().max_size(config.pool_size));
+
+    if let (Some(username), Some(password)) = (&config.username, &config.password) {
+        builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+
+    Ok(builder.build())
+}
@@ -2,43 +2,124 @@
  * 事件通道初始化模块
  * 
  * 本模块负责初始化系统的事件通道，注册各种事件监听器，
- * 包括认证、审计、API密钥验证等事件的处理。
+ * 包括认证、审计、API密钥验证、邮件投递、菜单分配变更、安全事件、合规审计导出等事件的处理。
+ * 每个通道使用有界队列，容量来自事件通道配置，溢出策略按事件类型确定。
+ * 登录、登录失败、操作日志和API密钥验证事件按事件类型注册监听器，其余事件仍使用动态类型通道。
+ * 停机时关闭所有通道，等待监听器处理完剩余事件后退出。
  */
 
+use std::time::Duration;
+
+use server_config::EventConfig;
 use server_constant::definition::consts::SystemEvent;
-use server_global::global;
+use server_global::global::{self, ChannelConfig, OverflowPolicy, STRING_EVENT_CHANNEL};
+
+/** 等待队列空位的最长时间 */
+const EVENT_SEND_TIMEOUT: Duration = Duration::from_secs(1);
+/** 停机时等待监听器处理剩余事件的最长时间 */
+const EVENT_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
 
 /**
  * 初始化事件通道
  * 
  * 注册系统所需的事件监听器，包括：
- * - JWT创建事件监听器
- * - 认证登录事件监听器
- * - 审计操作日志事件监听器
- * - API密钥验证事件监听器
+ * - JWT创建事件监听器（队列满时等待，超时丢弃）
+ * - 认证登录事件监听器（队列满时等待，超时丢弃）
+ * - 登录失败事件监听器（队列满时丢弃新事件，避免暴力尝试阻塞请求）
+ * - 审计操作日志事件监听器（队列满时丢弃最旧的日志）
+ * - API密钥验证事件监听器（队列满时丢弃新事件）
+ * - 邮件投递监听器（队列满时等待，超时丢弃）
+ * - 用户路由缓存失效监听器（队列满时等待，超时丢弃）
+ * - 安全事件监听器（队列满时丢弃新事件，避免暴力尝试阻塞请求）
+ * - 合规审计导出监听器（队列满时拒绝新的导出任务）
  */
 pub async fn initialize_event_channel() {
     use server_service::admin::{
-        api_key_validate_listener, auth_login_listener, jwt_created_listener,
+        api_key_validate_listener, auth_login_failed_listener, auth_login_listener,
+        compliance_export_listener, jwt_created_listener, security_event_listener,
         sys_operation_log_listener,
     };
+    use server_service::helper::notification_service::email_delivery_listener;
+    use server_service::helper::user_route_cache::user_route_cache_listener;
+
+    let event_config = global::get_config::<EventConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    let channel = |name: &str, overflow: OverflowPolicy| ChannelConfig {
+        capacity: event_config.capacity(name),
+        overflow,
+    };
+    let blocking = OverflowPolicy::BlockWithTimeout(EVENT_SEND_TIMEOUT);
+
+    let email_requested = SystemEvent::NotificationEmailRequestedEvent.to_string();
+    let menu_assignment_changed = SystemEvent::MenuAssignmentChangedEvent.to_string();
+    let security_event = SystemEvent::AuditSecurityEvent.to_string();
+    let compliance_export = SystemEvent::ComplianceExportRequestedEvent.to_string();
 
     global::register_event_listeners(
-        Box::new(|rx| Box::pin(jwt_created_listener(rx))),
+        (
+            channel(STRING_EVENT_CHANNEL, blocking),
+            Box::new(|rx| Box::pin(jwt_created_listener(rx))),
+        ),
         &[
             (
-                SystemEvent::AuthLoggedInEvent.to_string(),
-                Box::new(|rx| Box::pin(auth_login_listener(rx))),
+                email_requested.clone(),
+                channel(&email_requested, blocking),
+                Box::new(|rx| Box::pin(email_delivery_listener(rx))),
+            ),
+            (
+                menu_assignment_changed.clone(),
+                channel(&menu_assignment_changed, blocking),
+                Box::new(|rx| Box::pin(user_route_cache_listener(rx))),
             ),
             (
-                SystemEvent::AuditOperationLoggedEvent.to_string(),
-                Box::new(|rx| Box::pin(sys_operation_log_listener(rx))),
+                security_event.clone(),
+                channel(&security_event, OverflowPolicy::DropNewest),
+                Box::new(|rx| Box::pin(security_event_listener(rx))),
             ),
             (
-                SystemEvent::AuthApiKeyValidatedEvent.to_string(),
-                Box::new(|rx| Box::pin(api_key_validate_listener(rx))),
+                compliance_export.clone(),
+                channel(&compliance_export, OverflowPolicy::DropNewest),
+                Box::new(|rx| Box::pin(compliance_export_listener(rx))),
             ),
         ],
     )
     .await;
+
+    let auth_logged_in = SystemEvent::AuthLoggedInEvent.to_string();
+    let auth_login_failed = SystemEvent::AuthLoginFailedEvent.to_string();
+    let operation_logged = SystemEvent::AuditOperationLoggedEvent.to_string();
+    let api_key_validated = SystemEvent::AuthApiKeyValidatedEvent.to_string();
+
+    global::register_typed_listener(
+        &auth_logged_in,
+        channel(&auth_logged_in, blocking),
+        auth_login_listener,
+    );
+    global::register_typed_listener(
+        &auth_login_failed,
+        channel(&auth_login_failed, OverflowPolicy::DropNewest),
+        auth_login_failed_listener,
+    );
+    global::register_typed_listener(
+        &operation_logged,
+        channel(&operation_logged, OverflowPolicy::DropOldest),
+        sys_operation_log_listener,
+    );
+    global::register_typed_listener(
+        &api_key_validated,
+        channel(&api_key_validated, OverflowPolicy::DropNewest),
+        api_key_validate_listener,
+    );
+}
+
+/**
+ * 关闭事件通道
+ * 
+ * HTTP和gRPC服务停止后调用，不再接收新事件；等待各监听器处理完队列中的事件，
+ * 操作日志监听器同时写入缓冲区中尚未写入的日志，最多等待 `EVENT_DRAIN_TIMEOUT`
+ */
+pub async fn shutdown_event_channel() {
+    global::shutdown_event_listeners(EVENT_DRAIN_TIMEOUT).await;
 }
@@ -0,0 +1,85 @@
+/**
+ * 初始化错误模块
+ * 
+ * 汇总系统启动过程中各组件的初始化错误，
+ * 由入口统一记录日志并以非零状态码退出。
+ */
+
+use server_config::ConfigError;
+use thiserror::Error;
+
+/**
+ * 初始化错误类型
+ * 
+ * 覆盖配置、数据库、Redis、S3、JWT、XDB、访问密钥、初始管理员、邮件、Casbin、后台任务调度、服务器启动阶段及命令行维护命令的失败
+ */
+#[derive(Debug, Error)]
+pub enum InitError {
+    /** 配置文件加载或解析失败 */
+    #[error("Failed to initialize config: {0}")]
+    Config(#[from] ConfigError),
+
+    /** 配置项缺失 */
+    #[error("Missing config: {0}")]
+    MissingConfig(&'static str),
+
+    /** 配置项取值无效 */
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+
+    /** 数据库连接失败 */
+    #[error("Failed to initialize database: {0}")]
+    Database(String),
+
+    /** Redis连接失败 */
+    #[error("Failed to initialize Redis: {0}")]
+    Redis(String),
+
+    /** S3客户端创建失败 */
+    #[error("Failed to initialize S3: {0}")]
+    S3(String),
+
+    /** JWT密钥或验证规则设置失败 */
+    #[error("Failed to initialize JWT: {0}")]
+    Jwt(String),
+
+    /** IP地址解析数据库加载失败 */
+    #[error("Failed to initialize XDB: {0}")]
+    Xdb(String),
+
+    /** 访问密钥加载失败 */
+    #[error("Failed to initialize access keys: {0}")]
+    AccessKey(String),
+
+    /** 初始管理员创建失败 */
+    #[error("Failed to bootstrap admin: {0}")]
+    Bootstrap(String),
+
+    /** SMTP邮件发送器创建失败 */
+    #[error("Failed to initialize email: {0}")]
+    Email(String),
+
+    /** Casbin模型或策略加载失败 */
+    #[error("Failed to initialize Casbin: {0}")]
+    Casbin(String),
+
+    /** 后台任务注册失败 */
+    #[error("Failed to initialize scheduler: {0}")]
+    Scheduler(String),
+
+    /** HTTP或gRPC服务器启动失败 */
+    #[error("Failed to start server: {0}")]
+    Server(String),
+
+    /** 命令行维护命令执行失败 */
+    #[error("{0}")]
+    Command(String),
+
+    /** 初始化步骤失败，携带步骤名称 */
+    #[error("Initialization step '{step}' failed: {source}")]
+    Step {
+        step: &'static str,
+        #[source]
+        source: Box<InitError>,
+    },
+}
@@ -0,0 +1,40 @@
+/**
+ * 初始化步骤模块
+ * 
+ * 为各初始化步骤提供统一的耗时日志与错误包装，
+ * 便于并行执行时定位具体失败的步骤。
+ */
+
+use std::{future::Future, time::Instant};
+
+use crate::{project_info, InitError};
+
+/**
+ * 执行单个初始化步骤
+ * 
+ * 记录步骤耗时；失败时将错误包装为 `InitError::Step`，携带步骤名称。
+ * 
+ * # 参数
+ * - step: 步骤名称
+ * - fut: 步骤执行的异步任务
+ * 
+ * # 返回
+ * - 成功：返回步骤结果
+ * - 失败：返回带步骤名称的初始化错误
+ */
+pub async fn init_step<T, F>(step: &'static str, fut: F) -> Result<T, InitError>
+where
+    F: Future<Output = Result<T, InitError>>,
+{
+    let started = Instant::now();
+    match fut.await {
+        Ok(value) => {
+            project_info!("Init step '{}' completed in {:?}", step, started.elapsed());
+            Ok(value)
+        },
+        Err(e) => Err(InitError::Step {
+            step,
+            source: Box::new(e),
+        }),
+    }
+}
@@ -5,31 +5,48 @@
  * 进行IP地址到地理位置的映射。
  */
 
-use std::error::Error;
+use std::path::Path;
 
+use server_config::XdbConfig;
+use server_global::global::get_config;
 use xdb::searcher;
 
-use crate::project_info;
+use crate::{project_info, InitError};
+
+/** 默认xdb文件路径 */
+const DEFAULT_XDB_PATH: &str = "server/resources/ip2region.xdb";
 
 /**
  * 初始化IP地址解析数据库
  * 
  * 加载xdb数据库文件，初始化IP地址解析服务。
+ * 文件路径优先读取 `xdb.path` 配置，未配置时使用默认路径。
  * 
  * # 返回
  * - 成功：返回Ok(())
- * - 失败：返回错误信息
+ * - 失败：返回包含所尝试路径的错误信息
  * 
  * # 处理流程
- * 1. 异步加载xdb数据库文件
+ * 1. 确认xdb文件存在后异步加载
  * 2. 初始化IP地址解析器
  * 3. 记录初始化结果
  */
-pub async fn init_xdb() -> Result<(), Box<dyn Error>> {
-    tokio::task::spawn_blocking(|| {
-        searcher::searcher_init(Some("server/resources/ip2region.xdb".to_string()));
+pub async fn init_xdb() -> Result<(), InitError> {
+    let path = get_config::<XdbConfig>()
+        .await
+        .and_then(|config| config.path.clone())
+        .unwrap_or_else(|| DEFAULT_XDB_PATH.to_string());
+
+    if !Path::new(&path).is_file() {
+        return Err(InitError::Xdb(format!("xdb file not found: {}", path)));
+    }
+
+    let xdb_path = path.clone();
+    tokio::task::spawn_blocking(move || {
+        searcher::searcher_init(Some(xdb_path));
     })
-    .await?;
-    project_info!("XDB initialized successfully");
+    .await
+    .map_err(|e| InitError::Xdb(format!("failed to load xdb file {}: {}", path, e)))?;
+    project_info!("XDB initialized successfully from: {}", path);
     Ok(())
 }
@@ -6,12 +6,13 @@
  */
 
 use std::sync::Arc;
-use std::error::Error;
 
 use server_config::JwtConfig;
 use server_global::{global, Validation};
 use tokio::sync::Mutex;
 
+use crate::InitError;
+
 /**
  * 初始化JWT配置
  * 
@@ -27,9 +28,9 @@ use tokio::sync::Mutex;
  * 3. 设置验证规则（包括签发者、受众等）
  * 4. 初始化全局JWT配置
  */
-pub async fn init_jwt() -> Result<(), Box<dyn Error>> {
+pub async fn init_jwt() -> Result<(), InitError> {
     let jwt_config = global::get_config::<JwtConfig>().await
-        .ok_or_else(|| Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, "JWT config not found")))?;
+        .ok_or(InitError::MissingConfig("jwt"))?;
     
     let keys = global::Keys::new(jwt_config.secret.as_bytes());
     let mut validation = Validation::default();
@@ -38,10 +39,10 @@ pub async fn init_jwt() -> Result<(), Box<dyn Error>> {
     validation.set_audience(&[jwt_config.audience.clone()]);
 
     global::KEYS.set(Arc::new(Mutex::new(keys)))
-        .map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to set JWT keys: {}", e))))?;
+        .map_err(|e| InitError::Jwt(format!("Failed to set JWT keys: {}", e)))?;
     
     global::VALIDATION.set(Arc::new(Mutex::new(validation)))
-        .map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to set JWT validation: {}", e))))?;
+        .map_err(|e| InitError::Jwt(format!("Failed to set JWT validation: {}", e)))?;
 
     Ok(())
 }
@@ -5,8 +5,11 @@
  * - 配置初始化
  * - 数据库连接初始化
  * - Redis连接初始化
+ * - 初始管理员创建
  * - JWT配置初始化
+ * - 邮件发送器初始化
  * - 事件通道初始化
+ * - 后台任务调度（只读副本健康检查、操作日志保留）
  * - 路由初始化
  * - 日志系统初始化
  * - 其他系统组件的初始化
@@ -17,29 +20,40 @@
 
 pub use access_key_initialization::initialize_access_key;
 pub use aws_s3_initialization::{init_primary_s3, init_s3_pools};
-pub use casbin_initialization::initialize_casbin;
-pub use config_initialization::initialize_config;
+pub use bootstrap_initialization::initialize_bootstrap_admin;
+pub use casbin_initialization::{init_casbin, initialize_casbin};
+pub use config_initialization::{get_legacy_local_offset, initialize_config};
 pub use db_initialization::{init_db_pools, init_primary_connection};
-pub use event_channel_initialization::initialize_event_channel;
+pub use email_initialization::initialize_email;
+pub use event_channel_initialization::{initialize_event_channel, shutdown_event_channel};
+pub use init_error::InitError;
+pub use init_step::init_step;
 pub use ip2region_initialization::init_xdb;
 pub use jwt_initialization::init_jwt;
 pub use log_tracing_init::initialize_log_tracing;
 pub use redis_initialization::{init_primary_redis, init_redis_pools};
-pub use router_initialization::initialize_admin_router;
-pub use server_global::{project_error, project_info};
-pub use server_initialization::get_server_address;
+pub use router_initialization::{apply_compression, initialize_admin_router, make_request_span};
+pub use scheduler_initialization::initialize_scheduler;
+pub use server_global::{project_error, project_info, server_version};
+pub use server_initialization::{get_grpc_address, get_server_address};
 
 mod access_key_initialization;
 mod aws_s3_initialization;
+mod bootstrap_initialization;
 mod casbin_initialization;
 mod config_initialization;
 mod db_initialization;
+mod email_initialization;
 mod event_channel_initialization;
+mod init_error;
+mod init_step;
 mod ip2region_initialization;
 mod jwt_initialization;
 mod log_tracing_init;
+mod operation_log_initialization;
 mod redis_initialization;
 mod router_initialization;
+mod scheduler_initialization;
 mod server_initialization;
 
 // TODO: axum_test_helpers不兼容axum 0.8.x
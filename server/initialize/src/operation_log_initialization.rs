@@ -0,0 +1,66 @@
+/**
+ * 操作日志初始化模块
+ * 
+ * 本模块负责创建操作日志保留任务：
+ * - 按配置的保留天数每天清理过期的操作日志
+ * - 开启归档时清理前先归档到S3（由操作日志服务处理）
+ */
+
+use std::time::Duration;
+
+use chrono::Utc;
+use server_config::OperationLogConfig;
+use server_core::{
+    scheduler::{Job, Schedule},
+    web::error::AppError,
+};
+use server_global::global::get_config;
+use server_service::admin::{
+    OperationLogPurgeRequest, SysOperationLogService, TOperationLogService,
+};
+
+use crate::project_info;
+
+/** 操作日志保留任务名称 */
+const OPERATION_LOG_RETENTION_JOB: &str = "operation_log_retention";
+
+/** 操作日志清理间隔 */
+const OPERATION_LOG_RETENTION_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/** 操作日志清理的随机抖动，避免多个实例同时清理 */
+const OPERATION_LOG_RETENTION_JITTER: Duration = Duration::from_secs(10 * 60);
+
+/**
+ * 创建操作日志保留任务
+ * 
+ * 未配置 `retention_days` 时返回 None。任务启动后立即执行一次清理，之后每天执行一次。
+ */
+pub(crate) async fn operation_log_retention_job() -> Option<Job> {
+    let Some(retention_days) = get_config::<OperationLogConfig>()
+        .await
+        .and_then(|config| config.retention_days)
+    else {
+        project_info!("Operation log retention disabled");
+        return None;
+    };
+
+    project_info!("Operation log retention: {} days", retention_days);
+    let schedule = Schedule::every(OPERATION_LOG_RETENTION_INTERVAL);
+    let job = Job::new(OPERATION_LOG_RETENTION_JOB, schedule, move || async move {
+        let before = Utc::now().naive_utc() - chrono::Duration::days(i64::from(retention_days));
+        let params = OperationLogPurgeRequest {
+            before,
+            dry_run: false,
+        };
+        let output = SysOperationLogService.purge_operation_logs(params).await?;
+        project_info!(
+            "Operation log retention purged {} logs created before {}",
+            output.count,
+            before
+        );
+        Ok::<_, AppError>(())
+    })
+    .with_jitter(OPERATION_LOG_RETENTION_JITTER)
+    .run_on_start();
+    Some(job)
+}
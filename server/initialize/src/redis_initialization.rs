@@ -12,19 +12,30 @@
 
 use redis::{cluster::ClusterClient, Client};
 use server_config::{OptionalConfigs, RedisConfig, RedisInstancesConfig, RedisMode};
-use server_global::global::{get_config, RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL};
-use std::{process, sync::Arc};
+use server_global::{
+    global::{get_config, RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL},
+    redis_key::KeyBuilder,
+};
+use std::sync::Arc;
 
-use crate::{project_error, project_info};
+use crate::{project_error, project_info, InitError};
 
 /**
  * 初始化主Redis连接
  * 
  * 创建并初始化主Redis连接，支持集群和单机模式。
- * 如果初始化失败，程序将退出。
+ * 未配置Redis时直接跳过。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回连接错误
  */
-pub async fn init_primary_redis() {
+pub async fn init_primary_redis() -> Result<(), InitError> {
     if let Some(config) = get_config::<RedisConfig>().await {
+        if let Err(e) = KeyBuilder::init(&config.key_app, &config.key_env) {
+            project_error!("Failed to initialize Redis key prefix: {}", e);
+            return Err(InitError::Redis(e.to_string()));
+        }
         match create_redis_connection(&config).await {
             Ok(connection) => {
                 *GLOBAL_PRIMARY_REDIS.write().await = Some(connection);
@@ -39,10 +50,11 @@ pub async fn init_primary_redis() {
             },
             Err(e) => {
                 project_error!("Failed to initialize primary Redis: {}", e);
-                process::exit(1);
+                return Err(InitError::Redis(e));
             },
         }
     }
+    Ok(())
 }
 
 /**
@@ -220,15 +232,22 @@ async fn init_redis_connection(name: &str, config: &RedisConfig) -> Result<(), S
  * 
  * 从配置中读取所有Redis实例配置，
  * 并为每个实例创建Redis连接。
+ * 
+ * # 返回
+ * - 成功：返回Ok(())
+ * - 失败：返回首个连接失败的错误
  */
-pub async fn init_redis_pools() {
+pub async fn init_redis_pools() -> Result<(), InitError> {
     if let Some(redis_instances_config) =
         get_config::<OptionalConfigs<RedisInstancesConfig>>().await
     {
         if let Some(redis_instances) = &redis_instances_config.configs {
-            let _ = init_redis_pool(Some(redis_instances.clone())).await;
+            init_redis_pool(Some(redis_instances.clone()))
+                .await
+                .map_err(InitError::Redis)?;
         }
     }
+    Ok(())
 }
 
 /**
@@ -308,7 +327,7 @@ mod tests {
     async fn init() {
         let mut initialized = INITIALIZED.lock().await;
         if initialized.is_none() {
-            initialize_config("../resources/application.yaml").await;
+            initialize_config("../resources/application.yaml").await.unwrap();
             *initialized = Some(Arc::new(()));
         }
     }
@@ -363,7 +382,7 @@ mod tests {
         setup_logger();
         init().await;
 
-        init_primary_redis().await;
+        init_primary_redis().await.unwrap();
 
         let connection = get_primary_redis().await;
         assert!(
@@ -392,6 +411,8 @@ mod tests {
                 mode: RedisMode::Single,
                 url: Some("redis://:123456@bytebytebrew.local:26379/11".to_string()),
                 urls: None,
+                key_app: "alion".to_string(),
+                key_env: "test".to_string(),
             },
         };
 
@@ -6,124 +6,281 @@
  * - 配置路由中间件
  * - 管理路由权限
  * - 处理API密钥验证
+ * - 按配置注册沙箱路由，并按域功能开关限制访问
+ * - 根据收集的路由生成OpenAPI文档
+ * - 按配置压缩响应和解压请求体
+ * - 在响应头 `X-App-Version` 中返回服务版本
  */
 
 use std::sync::Arc;
 use std::error::Error;
 use tokio::sync::mpsc;
-use std::time::Duration;
 
-use axum::{body::Body, http::StatusCode, response::IntoResponse, Extension, Router};
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::get,
+    Extension, Json, Router,
+};
 use axum_casbin::CasbinAxumLayer;
-use chrono::Local;
-use http::Request;
+use http::{header, HeaderValue, Request, Uri};
 use sea_orm::DatabaseConnection;
-use server_config::Config;
+use server_config::{
+    CompressionAlgorithm, CompressionConfig, Config, EndpointSyncConfig, EndpointSyncMode,
+    HttpConfig, LimitStorage, MenuConfig, OperationLogConfig, RateLimitConfig, SandboxConfig,
+    ServerConfig, TrustedProxyConfig,
+};
 use server_constant::definition::Audience;
 use server_core::sign::{
-    api_key_middleware, protect_route, ApiKeySource, ApiKeyValidation, ComplexApiKeyConfig,
-    SimpleApiKeyConfig, ValidatorType,
+    api_key_middleware, init_failure_limiter, protect_route, ApiKeySource, ApiKeyValidation,
+    ComplexApiKeyConfig, SimpleApiKeyConfig, ValidatorType,
+};
+use server_core::web::{
+    operation_log::OperationLogLayer, page::PageQuery, res::Res, util::ClientIp,
+    validator::init_deny_unknown_fields, FailureLimiter, LocaleLayer,
+    RateLimitKey, RateLimitLayer, RateLimitStorage, RequestId, RequestIdLayer, ResponseNamingLayer,
+    init_dependency_timeout, TimeoutLayer,
+};
+use server_global::{
+    global::{clear_routes, get_collected_routes, get_config},
+    openapi::build_openapi_document,
+    server_version,
+};
+use server_middleware::{
+    jwt_auth_middleware, maintenance_middleware, require_feature_middleware, RequireFeature,
 };
-use server_core::web::{RequestId, RequestIdLayer};
-use server_global::global::{clear_routes, get_collected_routes, get_config};
-use server_middleware::jwt_auth_middleware;
+use server_model::admin::input::MenuRules;
 use server_router::admin::{
-    SysAccessKeyRouter, SysAuthenticationRouter, SysDomainRouter, SysEndpointRouter,
-    SysLoginLogRouter, SysMenuRouter, SysOperationLogRouter, SysOrganizationRouter, SysRoleRouter,
-    SysSandboxRouter, SysUserRouter,
+    route_constants::versioned_legacy_path, SysAccessKeyRouter, SysAuthenticationRouter, SysDomainFeatureRouter, SysDomainRouter,
+    SysComplianceRouter,
+    SysEmailRouter, SysEndpointRouter, SysEventRouter, SysInvitationRouter, SysJobRouter,
+    SysLoginLogRouter, SysMaintenanceRouter, SysMenuRouter, SysOperationLogRouter, SysOrganizationRouter, SysPolicyRouter, SysRoleRouter, SysRoleTemplateRouter,
+    SysSandboxRouter, SysStatisticsRouter, SysStorageRouter, SysSystemRouter, SysUserRouter,
 };
 use server_service::{
     admin::{
-        SysAccessKeyService, SysAuthService, SysAuthorizationService, SysDomainService,
-        SysEndpointService, SysLoginLogService, SysMenuService, SysOperationLogService,
-        SysOrganizationService, SysRoleService, SysUserService, TEndpointService,
+        endpoints_from_routes, SysAccessKeyService, SysAuthService, SysAuthorizationService, SysDomainFeatureService,
+        SysComplianceService,
+        SysDomainService,
+        SysDeadLetterService, SysEndpointService, SysInvitationService, SysJobService, SysLoginLogService, SysMaintenanceService,
+        SysMenuService,
+        SysOperationLogService, SysOrganizationService, SysPolicyService, SysRoleService,
+        SysRoleTemplateService,
+        SysPersonalTokenService, SysStatisticsService, SysStorageService, SysSystemService,
+        SysTokenService,
+        SysUserService,
+        TEndpointService, TMaintenanceService, ReadinessOutput,
     },
+    helper::db_helper,
     SysEndpoint,
 };
-use tower_http::trace::TraceLayer;
-use tracing::info_span;
+use tower_http::{
+    compression::{
+        predicate::{NotForContentType, Predicate, SizeAbove},
+        CompressionLayer,
+    },
+    decompression::RequestDecompressionLayer,
+    trace::TraceLayer,
+};
+use tracing::{info_span, Span};
 
-use crate::{initialize_casbin, project_error, project_info, db_initialization};
+use crate::{db_initialization, project_error, project_info};
 
-/**
- * 服务类型枚举
- * 
- * 用于表示路由服务类型：
- * - None: 无服务
- * - Single: 单个服务实例
- */
-#[derive(Clone)]
-pub enum Services<T: Send + Sync + 'static> {
-    None(std::marker::PhantomData<T>),
-    Single(Arc<T>),
-}
+/** 沙箱接口对应的域功能标识 */
+const SANDBOX_FEATURE: &str = "sandbox";
+
+/** 服务版本响应头 */
+const APP_VERSION_HEADER: &str = "x-app-version";
+
+/** 向路由注入一个请求扩展 */
+type ExtensionLayer = Box<dyn FnOnce(Router) -> Router + Send>;
 
 /**
- * 应用路由中间件
+ * 路由中间件计划
  * 
- * # 参数
- * - router: 原始路由
- * - services: 服务实例
- * - need_casbin: 是否需要Casbin权限控制
- * - need_auth: 是否需要JWT认证
- * - api_validation: API密钥验证配置
- * - casbin: Casbin层配置
- * - audience: 认证受众
+ * 描述一组路由需要注入的服务和中间件，由 `apply` 按固定顺序应用到路由上：
+ * 服务扩展位于最内层，其次为请求追踪、Casbin鉴权、速率限制、API密钥验证、JWT认证，
+ * 请求超时位于最外层。每组路由只应用一次请求追踪层，请求ID层由外层统一应用。
  * 
- * # 返回
- * 返回配置了所有必要中间件的路由
+ * ```rust,ignore
+ * let router = LayerPlan::new()
+ *     .service(SysRoleService)
+ *     .service(SysAuthorizationService::default())
+ *     .casbin(casbin_layer.clone())
+ *     .auth(Audience::ManagementPlatform)
+ *     .apply(SysRoleRouter::init_role_router().await);
+ * ```
  */
-async fn apply_layers<T: Send + Sync + 'static>(
-    router: Router,
-    services: Services<T>,
-    need_casbin: bool,
-    need_auth: bool,
-    api_validation: Option<ApiKeyValidation>,
+#[derive(Default)]
+pub struct LayerPlan {
+    extensions: Vec<ExtensionLayer>,
     casbin: Option<CasbinAxumLayer>,
-    audience: Audience,
-) -> Router {
-    let mut router = match services {
-        Services::None(_) => router,
-        Services::Single(service) => router.layer(Extension(service)),
-    };
+    audience: Option<Audience>,
+    api_validation: Option<ApiKeyValidation>,
+    rate_limit: Option<RateLimitLayer>,
+    timeout: Option<TimeoutLayer>,
+}
 
-    router = router
-        .layer(
-            TraceLayer::new_for_http().make_span_with(|request: &Request<Body>| {
-                let request_id = request
-                    .extensions()
-                    .get::<RequestId>()
-                    .map(ToString::to_string)
-                    .unwrap_or_else(|| "unknown".into());
-                info_span!(
-                    "[alion-admin] >>>>>> request",
-                    id = %request_id,
-                    method = %request.method(),
-                    uri = %request.uri(),
-                )
-            }),
-        )
-        .layer(RequestIdLayer);
+impl LayerPlan {
+    /**
+     * 创建不注入任何服务和中间件的计划
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    if need_casbin {
-        if let Some(casbin) = casbin {
-            router = router.layer(Extension(casbin.clone())).layer(casbin);
-        }
+    /**
+     * 注入服务实例
+     * 
+     * 服务包装为 `Arc<T>` 注入，处理函数通过 `Extension<Arc<T>>` 取得；可多次调用注入多个服务
+     * 
+     * # 参数
+     * - service: 服务实例
+     */
+    pub fn service<T: Send + Sync + 'static>(self, service: T) -> Self {
+        self.extension(Arc::new(service))
     }
 
-    if let Some(validation) = api_validation {
-        router = router.layer(axum::middleware::from_fn(move |req, next| {
-            api_key_middleware(validation.clone(), req, next)
-        }));
+    /**
+     * 注入任意请求扩展
+     * 
+     * # 参数
+     * - extension: 扩展值，处理函数通过 `Extension<E>` 取得
+     */
+    pub fn extension<E: Clone + Send + Sync + 'static>(mut self, extension: E) -> Self {
+        self.extensions.push(Box::new(move |router: Router| router.layer(Extension(extension))));
+        self
     }
 
-    if need_auth {
-        router = router.layer(axum::middleware::from_fn(move |req, next| {
-            jwt_auth_middleware(req, next, audience.as_str())
-        }));
+    /**
+     * 启用Casbin鉴权
+     * 
+     * 执行器同时作为请求扩展注入，处理函数可据此维护策略
+     * 
+     * # 参数
+     * - casbin: Casbin层
+     */
+    pub fn casbin(mut self, casbin: CasbinAxumLayer) -> Self {
+        self.casbin = Some(casbin);
+        self
     }
 
-    router
+    /**
+     * 启用JWT认证
+     * 
+     * # 参数
+     * - audience: 认证受众
+     */
+    pub fn auth(mut self, audience: Audience) -> Self {
+        self.audience = Some(audience);
+        self
+    }
+
+    /**
+     * 启用API密钥验证
+     * 
+     * # 参数
+     * - validation: API密钥验证配置
+     */
+    pub fn api_key(mut self, validation: ApiKeyValidation) -> Self {
+        self.api_validation = Some(validation);
+        self
+    }
+
+    /**
+     * 设置速率限制
+     * 
+     * 位于API密钥验证之内以便按API密钥限流
+     * 
+     * # 参数
+     * - rate_limit: 速率限制层，未配置该路由组时为None
+     */
+    pub fn rate_limit(mut self, rate_limit: Option<RateLimitLayer>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /**
+     * 设置请求超时
+     * 
+     * 位于最外层，认证和限流等待的时间同样计入
+     * 
+     * # 参数
+     * - timeout: 请求超时层，该路由组不限制处理时间时为None
+     */
+    pub fn timeout(mut self, timeout: Option<TimeoutLayer>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /**
+     * 应用路由中间件
+     * 
+     * # 参数
+     * - router: 原始路由
+     * 
+     * # 返回
+     * 返回配置了所有必要中间件的路由
+     */
+    pub fn apply(self, router: Router) -> Router {
+        let mut router = self.extensions.into_iter().fold(router, |router, layer| layer(router));
+
+        router = router.layer(TraceLayer::new_for_http().make_span_with(make_request_span::<Body>));
+
+        if let Some(casbin) = self.casbin {
+            router = router.layer(Extension(casbin.clone())).layer(casbin);
+        }
+
+        if let Some(rate_limit) = self.rate_limit {
+            router = router.layer(rate_limit);
+        }
+
+        if let Some(validation) = self.api_validation {
+            router = router.layer(axum::middleware::from_fn(move |req, next| {
+                api_key_middleware(validation.clone(), req, next)
+            }));
+        }
+
+        if let Some(audience) = self.audience {
+            router = router.layer(axum::middleware::from_fn(move |req, next| {
+                jwt_auth_middleware(req, next, audience.as_str())
+            }));
+        }
+
+        if let Some(timeout) = self.timeout {
+            router = router.layer(timeout);
+        }
+
+        router
+    }
+}
+
+/**
+ * 创建请求追踪Span
+ * 
+ * HTTP路由和gRPC服务共用，Span中记录请求ID、方法和URI，
+ * 请求ID由外层的请求ID中间件写入请求扩展。
+ * 
+ * # 参数
+ * - request: 请求
+ * 
+ * # 返回
+ * 返回请求级别的追踪Span
+ */
+pub fn make_request_span<B>(request: &Request<B>) -> Span {
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "unknown".into());
+    info_span!(
+        "[alion-admin] >>>>>> request",
+        id = %request_id,
+        method = %request.method(),
+        uri = %request.uri(),
+    )
 }
 
 /**
@@ -132,26 +289,24 @@ async fn apply_layers<T: Send + Sync + 'static>(
  * 配置并初始化所有管理后台相关的路由，
  * 包括认证、授权、用户管理等功能。
  * 
+ * # 参数
+ * - casbin_layer: Casbin层，与gRPC鉴权服务共用同一个执行器
+ * 
  * # 返回
  * 返回配置完整的路由实例
  */
-pub async fn initialize_admin_router() -> Router {
+pub async fn initialize_admin_router(casbin_layer: CasbinAxumLayer) -> Router {
     clear_routes().await;
     project_info!("Initializing admin router");
 
-    let app_config = get_config::<Config>().await.unwrap();
     let db = db_initialization::init_primary_connection().await
         .expect("Failed to connect to database");
-    
-    let db_url = app_config.database.url.clone();
-    
-    let casbin_layer = initialize_casbin(
-        "server/resources/rbac_model.conf",
-        &db_url,
-    )
-    .await
-    .unwrap();
 
+    // 恢复上次运行时的维护模式状态，需在Redis初始化之后执行
+    if let Err(e) = SysMaintenanceService.load_maintenance_state().await {
+        project_error!("Failed to restore maintenance state: {}", e);
+    }
+    
     // 初始化验证器
     // 根据是否配置了 Redis 来选择 nonce 存储实现
     let nonce_store_factory =
@@ -160,10 +315,8 @@ pub async fn initialize_admin_router() -> Router {
             project_info!("Using Redis for nonce storage");
             match redis::Client::open("redis://127.0.0.1/") {
                 Ok(redis_client) => {
-                    server_core::sign::create_redis_nonce_store_factory(
-                        redis_client,
-                        Duration::from_secs(300) // 5 minutes nonce expiration
-                    )
+                    // nonce 过期时间由各访问密钥的设置决定
+                    server_core::sign::create_redis_nonce_store_factory(redis_client)
                 },
                 Err(e) => {
                     project_error!("Failed to create Redis client: {}", e);
@@ -179,204 +332,280 @@ pub async fn initialize_admin_router() -> Router {
 
     server_core::sign::init_validators_with_nonce_store(None, nonce_store_factory.clone()).await;
 
-    let simple_validation = {
-        let validator = server_core::sign::get_simple_validator().await;
-        server_core::sign::add_key(ValidatorType::Simple, "test-api-key", None).await;
-        ApiKeyValidation::Simple(
-            validator,
-            SimpleApiKeyConfig {
-                source: ApiKeySource::Header,
-                key_name: "x-api-key".to_string(),
-            },
-        )
+    // 初始化可信代理，客户端IP解析（登录日志、操作日志、按IP限流）依赖该配置
+    if let Some(trusted_proxy_config) = get_config::<TrustedProxyConfig>().await {
+        ClientIp::init_trusted_proxy(trusted_proxy_config.as_ref().clone());
+    }
+
+    // 初始化分页配置，所有分页查询的每页数量按该配置检查
+    if let Some(server_config) = get_config::<ServerConfig>().await {
+        PageQuery::init_pagination(server_config.pagination.clone());
+    }
+
+    // 初始化菜单验证规则，创建和更新菜单时按该规则检查组件
+    if let Some(menu_config) = get_config::<MenuConfig>().await {
+        MenuRules::new(menu_config.component_patterns.clone()).init();
+    }
+
+    // 初始化JSON请求体的未知字段检查
+    let http_config = get_config::<HttpConfig>().await.unwrap_or_default();
+    init_deny_unknown_fields(http_config.deny_unknown_fields);
+
+    // 初始化请求超时，内部依赖调用的等待上限对所有路由生效
+    let timeout_config = &http_config.timeout;
+    init_dependency_timeout(timeout_config.dependency_timeout());
+    let timeout = |group: &str| {
+        timeout_config
+            .budget(group)
+            .map(|budget| TimeoutLayer::new(budget).exclude_paths(&timeout_config.exclude_paths))
     };
+    let default_timeout = timeout("default");
 
-    let complex_validation = {
-        let validator = server_core::sign::get_complex_validator().await;
-        server_core::sign::add_key(
-            ValidatorType::Complex,
-            "test-access-key",
-            Some("test-secret-key"),
-        )
-        .await;
-        ApiKeyValidation::Complex(
-            validator,
-            ComplexApiKeyConfig {
-                key_name: "AccessKeyId".to_string(),
-                timestamp_name: "t".to_string(),
-                nonce_name: "n".to_string(),
-                signature_name: "sign".to_string(),
+    // 初始化操作日志脱敏字段，需在任何请求记录操作日志之前完成
+    if let Some(operation_log_config) = get_config::<OperationLogConfig>().await {
+        OperationLogLayer::init_redact_fields(&operation_log_config.redact_fields);
+    }
+
+    // 注册主库重新连接函数，供主库重新连接接口调用
+    db_helper::init_primary_reconnector(|config| {
+        Box::pin(async move {
+            db_initialization::reconnect_primary_db(&config).await.map_err(|e| e.to_string())
+        })
+    });
+
+    // 初始化速率限制，Redis 不可用时退回内存存储
+    let rate_limit_config = get_config::<RateLimitConfig>().await;
+    let rate_limit_storage = match rate_limit_config.as_ref().map(|config| config.storage) {
+        Some(LimitStorage::Redis) => match crate::redis_initialization::get_primary_redis().await {
+            Some(connection) => RateLimitStorage::Redis(connection),
+            None => {
+                project_error!("Redis is not initialized, using memory for rate limit storage");
+                RateLimitStorage::Memory
             },
-        )
+        },
+        _ => RateLimitStorage::Memory,
+    };
+    let rate_limit = |group: &str, key: RateLimitKey| {
+        rate_limit_config
+            .as_ref()
+            .and_then(|config| config.group(group))
+            .map(|limit| RateLimitLayer::new(group, limit, rate_limit_storage.clone()).key_by(key))
     };
+    let default_rate_limit = rate_limit("default", RateLimitKey::ClientIp);
 
-    // 保护路由
-    protect_route("/sandbox/simple-api-key");
-    protect_route("/sandbox/complex-api-key");
+    // API密钥验证失败按客户端IP计数，超出限制后拒绝该IP的请求直至令牌补充
+    let api_key_failure_limit =
+        rate_limit_config.as_ref().and_then(|config| config.group("api_key_failure"));
+    if let Some(limit) = api_key_failure_limit {
+        let storage = rate_limit_storage.clone();
+        init_failure_limiter(FailureLimiter::new("api_key_failure", limit, storage));
+    }
+    let sandbox_rate_limit = rate_limit("sandbox", RateLimitKey::ApiKey);
 
     let audience = Audience::ManagementPlatform;
-    let casbin = Some(casbin_layer);
     let mut app = Router::new();
 
+    // 各路由组默认按IP限流并使用默认超时；受保护的路由另需JWT认证和Casbin授权
+    let public = || {
+        LayerPlan::new().rate_limit(default_rate_limit.clone()).timeout(default_timeout.clone())
+    };
+    let authenticated = || public().auth(audience);
+    let protected = || authenticated().casbin(casbin_layer.clone());
+
     macro_rules! merge_router {
-        ($router:expr, None, $need_casbin:expr, $need_auth:expr, $api_validation:expr) => {
-            app = app.merge(
-                apply_layers(
-                    $router,
-                    Services::None(std::marker::PhantomData::<()>),
-                    $need_casbin,
-                    $need_auth,
-                    $api_validation,
-                    casbin.clone(),
-                    audience,
-                )
-                .await,
-            );
-        };
-        ($router:expr, $service:expr, $need_casbin:expr, $need_auth:expr, $api_validation:expr) => {
-            app = app.merge(
-                apply_layers(
-                    $router,
-                    Services::Single(Arc::new($service)),
-                    $need_casbin,
-                    $need_auth,
-                    $api_validation,
-                    casbin.clone(),
-                    audience,
-                )
-                .await,
-            );
+        ($router:expr, $plan:expr) => {
+            app = app.merge($plan.apply($router));
         };
     }
 
+    // 登录与注册接口按用户名限流，防止脚本化的暴力登录和批量注册；域列表接口按IP限流。
+    // 登录与注册使用 auth 组的超时，密码哈希和IP归属地查询挂起时尽快失败；
+    // 注册时使用执行器写入新用户的分组规则
     merge_router!(
         SysAuthenticationRouter::init_authentication_router().await,
-        SysAuthService,
-        false,
-        false,
-        None
+        LayerPlan::new()
+            .service(SysAuthService::default())
+            .service(SysDomainService)
+            .extension(casbin_layer.clone())
+            .rate_limit(rate_limit("auth", RateLimitKey::Username))
+            .timeout(timeout("auth"))
     );
 
-    let auth_service = Arc::new(SysAuthService);
-    let auth_router = SysAuthenticationRouter::init_authorization_router()
-        .await
-        .layer(Extension(auth_service.clone()))
-        .layer(Extension(
-            Arc::new(SysAuthorizationService::new(db.clone())) as Arc<SysAuthorizationService>
-        ));
-
-    let auth_router = apply_layers(
-        auth_router,
-        Services::None(std::marker::PhantomData::<()>),
-        true,
-        true,
-        None,
-        casbin.clone(),
-        audience,
-    )
-    .await;
-
-    app = app.merge(auth_router);
-
     merge_router!(
-        SysAuthenticationRouter::init_protected_router().await,
-        SysAuthService,
-        false,
-        true,
-        None
+        SysAuthenticationRouter::init_authorization_router().await,
+        protected()
+            .service(SysAuthService::default())
+            .service(SysAuthorizationService::default())
     );
 
+    // 登录历史和登录令牌接口位于认证路由下，额外注入登录日志服务和登录令牌服务
     merge_router!(
-        SysMenuRouter::init_menu_router().await,
-        SysMenuService,
-        false,
-        false,
-        None
+        SysAuthenticationRouter::init_protected_router().await,
+        authenticated()
+            .service(SysAuthService::default())
+            .service(SysLoginLogService)
+            .service(SysTokenService)
     );
 
+    merge_router!(SysMenuRouter::init_menu_router().await, public().service(SysMenuService));
     merge_router!(
         SysMenuRouter::init_protected_menu_router().await,
-        SysMenuService,
-        true,
-        true,
-        None
+        protected().service(SysMenuService)
     );
-
     merge_router!(
         SysUserRouter::init_user_router().await,
-        SysUserService,
-        true,
-        true,
-        None
+        protected().service(SysUserService::default())
+    );
+
+    // 个人访问令牌接口只需认证，但拒绝使用个人访问令牌认证的请求；
+    // 创建和撤销时使用执行器维护令牌的权限策略
+    merge_router!(
+        SysUserRouter::init_personal_token_router().await,
+        authenticated()
+            .service(SysPersonalTokenService::default())
+            .extension(casbin_layer.clone())
     );
     merge_router!(
         SysDomainRouter::init_domain_router().await,
-        SysDomainService,
-        true,
-        true,
-        None
+        protected().service(SysDomainService)
+    );
+    merge_router!(
+        SysDomainFeatureRouter::init_domain_feature_router().await,
+        protected().service(SysDomainFeatureService)
     );
     merge_router!(
         SysRoleRouter::init_role_router().await,
-        SysRoleService,
-        true,
-        true,
-        None
+        protected()
+            .service(SysRoleService)
+            .service(SysAuthorizationService::default())
+    );
+    merge_router!(
+        SysRoleTemplateRouter::init_role_template_router().await,
+        protected().service(SysRoleTemplateService)
     );
     merge_router!(
         SysEndpointRouter::init_endpoint_router().await,
-        SysEndpointService::new(db.clone()),
-        true,
-        true,
-        None
+        protected().service(SysEndpointService::new(db.clone()))
     );
     merge_router!(
         SysAccessKeyRouter::init_access_key_router().await,
-        SysAccessKeyService,
-        true,
-        true,
-        None
+        protected().service(SysAccessKeyService)
+    );
+    merge_router!(
+        SysMaintenanceRouter::init_maintenance_router().await,
+        protected().service(SysMaintenanceService)
+    );
+    merge_router!(SysEmailRouter::init_email_router().await, protected());
+    merge_router!(
+        SysEventRouter::init_event_router().await,
+        protected().service(SysDeadLetterService)
+    );
+    merge_router!(SysJobRouter::init_job_router().await, protected().service(SysJobService));
+    merge_router!(
+        SysSystemRouter::init_system_router().await,
+        protected().service(SysSystemService)
     );
     merge_router!(
         SysLoginLogRouter::init_login_log_router().await,
-        SysLoginLogService,
-        true,
-        true,
-        None
+        protected().service(SysLoginLogService)
     );
     merge_router!(
         SysOperationLogRouter::init_operation_log_router().await,
-        SysOperationLogService,
-        true,
-        true,
-        None
+        protected().service(SysOperationLogService)
+    );
+    merge_router!(
+        SysPolicyRouter::init_policy_router().await,
+        protected().service(SysPolicyService)
+    );
+    merge_router!(
+        SysStatisticsRouter::init_statistics_router().await,
+        protected().service(SysStatisticsService)
+    );
+    merge_router!(
+        SysComplianceRouter::init_compliance_router().await,
+        protected().service(SysComplianceService)
+    );
+    merge_router!(
+        SysInvitationRouter::init_invitation_router().await,
+        protected().service(SysInvitationService)
     );
 
+    // 组织列表保持公开，增删改需要认证和Casbin授权
     merge_router!(
         SysOrganizationRouter::init_organization_router().await,
-        SysOrganizationService,
-        false,
-        false,
-        None
+        public().service(SysOrganizationService)
+    );
+    merge_router!(
+        SysOrganizationRouter::init_protected_organization_router().await,
+        protected().service(SysOrganizationService)
     );
 
-    // sandbox
+    // 本地存储的预签名链接本身即为访问凭证，文件下载接口不需要认证；
+    // 代理下载需要认证和Casbin授权
     merge_router!(
-        SysSandboxRouter::init_simple_sandbox_router().await,
-        None,
-        false,
-        false,
-        Some(simple_validation)
+        SysStorageRouter::init_storage_router().await,
+        public().service(SysStorageService)
     );
     merge_router!(
-        SysSandboxRouter::init_complex_sandbox_router().await,
-        None,
-        false,
-        false,
-        Some(complex_validation)
+        SysStorageRouter::init_protected_storage_router().await,
+        protected().service(SysStorageService)
+    );
+
+    // sandbox，未启用时不注册路由，也不添加测试密钥
+    let sandbox_config = get_config::<SandboxConfig>().await.unwrap_or_default();
+    let sandbox = || {
+        LayerPlan::new().rate_limit(sandbox_rate_limit.clone()).timeout(default_timeout.clone())
+    };
+    app = app.merge(init_sandbox_router(&sandbox_config, sandbox).await);
+
+    // OpenAPI文档在所有路由注册完成后生成，文档接口本身不注册到路由表
+    let openapi = Arc::new(build_openapi_document(
+        "alion-admin-api",
+        server_version::VERSION,
+        &get_collected_routes().await,
+    ));
+    app = app.route(
+        "/openapi.json",
+        get(move || async move { Json(openapi.as_ref().clone()) }),
     );
 
-    app = app.fallback(handler_404);
+    // 就绪检查接口同样不注册到路由表，供负载均衡和部署探针使用
+    app = app.route("/ready", get(readiness_handler));
+
+    // 维护模式位于认证和鉴权之外，维护期间写操作在进入业务路由前即被拒绝
+    let maintenance_path: Arc<str> = SysMaintenanceRouter::maintenance_path().into();
+    app = app.layer(axum::middleware::from_fn(move |req, next| {
+        let maintenance_path = maintenance_path.clone();
+        async move {
+            maintenance_middleware(req, next, audience.as_str(), &maintenance_path).await
+        }
+    }));
+
+    // LocaleLayer 位于认证和鉴权之外，认证失败等错误同样按请求语言返回消息
+    app = app.fallback(handler_404).layer(LocaleLayer);
+
+    // 开启后按 X-Naming 请求头转换JSON响应的字段名，错误响应同样转换
+    let server_config = get_config::<ServerConfig>().await;
+    if server_config.as_ref().is_some_and(|config| config.snake_case_response) {
+        app = app.layer(ResponseNamingLayer);
+    }
+
+    // 压缩位于路由级别的操作日志和字段命名转换之外，两者处理的都是未压缩的内容
+    app = apply_compression(app, &http_config.compression);
+
+    // 兼容旧客户端时，未带版本前缀的请求在路由前改写为当前版本的路径，
+    // 路由中间件在匹配之后执行，因此把完整路由作为回退服务包在改写中间件之内
+    if server_config.as_ref().is_some_and(|config| config.legacy_unversioned_routes) {
+        app = Router::new()
+            .fallback_service(app)
+            .layer(axum::middleware::from_fn(legacy_path_middleware));
+    }
+
+    // 所有响应（包括旧路径改写、认证失败和 404）都携带服务版本
+    app = app.layer(axum::middleware::map_response(app_version_header));
+
+    // RequestIdLayer 作为最外层，使认证/鉴权拒绝和 404 响应同样携带请求ID
+    app = app.layer(RequestIdLayer);
 
     process_collected_routes(db.clone()).await;
     project_info!("Admin router initialization completed");
@@ -385,77 +614,279 @@ pub async fn initialize_admin_router() -> Router {
 }
 
 /**
- * 404处理器
+ * 初始化沙箱路由
  * 
- * 处理未找到的路由请求
+ * 沙箱未启用时返回空路由，不向验证器添加测试密钥，也不保护沙箱路径。
+ * 启用时添加配置中的测试密钥，签名调试接口使用与复杂验证相同的参数名配置；
+ * 功能开关位于API密钥验证之内，按访问密钥所属的域判断。
+ * 
+ * # 参数
+ * - config: 沙箱配置
+ * - plan: 沙箱路由组的中间件计划
  * 
  * # 返回
- * 返回404状态码和提示信息
+ * 返回沙箱路由，未启用时为空路由
  */
-async fn handler_404() -> impl IntoResponse {
-    (StatusCode::NOT_FOUND, "nothing to see here")
+async fn init_sandbox_router(config: &SandboxConfig, plan: impl Fn() -> LayerPlan) -> Router {
+    if !config.enabled {
+        return Router::new();
+    }
+
+    let simple_validation = {
+        let validator = server_core::sign::get_simple_validator().await;
+        for key in &config.api_keys {
+            server_core::sign::add_key(ValidatorType::Simple, key, None).await;
+        }
+        ApiKeyValidation::Simple(
+            validator,
+            SimpleApiKeyConfig {
+                source: ApiKeySource::Header,
+                key_name: "x-api-key".to_string(),
+            },
+        )
+    };
+
+    let complex_api_key_config = ComplexApiKeyConfig {
+        key_name: "AccessKeyId".to_string(),
+        timestamp_name: "t".to_string(),
+        nonce_name: "n".to_string(),
+        signature_name: "sign".to_string(),
+        body_digest_name: None,
+    };
+
+    let complex_validation = {
+        let validator = server_core::sign::get_complex_validator().await;
+        for access_key in &config.access_keys {
+            server_core::sign::add_key(
+                ValidatorType::Complex,
+                &access_key.access_key_id,
+                Some(access_key.secret.as_str().into()),
+            )
+            .await;
+        }
+        ApiKeyValidation::Complex(validator, complex_api_key_config.clone())
+    };
+
+    // 保护路由，按带版本前缀的完整路径匹配
+    for path in SysSandboxRouter::protected_paths() {
+        protect_route(&path);
+    }
+
+    let simple = plan()
+        .service(complex_api_key_config)
+        .api_key(simple_validation)
+        .apply(require_sandbox_feature(SysSandboxRouter::init_simple_sandbox_router().await));
+    let complex = plan()
+        .api_key(complex_validation)
+        .apply(require_sandbox_feature(SysSandboxRouter::init_complex_sandbox_router().await));
+    simple.merge(complex)
 }
 
 /**
- * 处理收集的路由
+ * 为沙箱路由添加域功能开关检查
+ * 
+ * # 参数
+ * - router: 沙箱路由
+ * 
+ * # 返回
+ * 返回沙箱功能在请求所属域被禁用时拒绝访问的路由
+ */
+fn require_sandbox_feature(router: Router) -> Router {
+    router.layer(axum::middleware::from_fn(|req, next| {
+        require_feature_middleware(RequireFeature(SANDBOX_FEATURE), req, next)
+    }))
+}
+
+/**
+ * 应用响应压缩和请求解压
  * 
- * 将收集到的路由信息同步到数据库
+ * 按请求的 Accept-Encoding 压缩响应，按 Content-Encoding 解压请求体。
+ * 应包在路由级别的操作日志中间件之外，使操作日志记录解压后的请求体和压缩前的响应体。
+ * 流式响应（`text/event-stream`、`application/octet-stream`）和配置中排除的路径不压缩。
  * 
  * # 参数
- * - db: 数据库连接
+ * - router: 路由
+ * - config: 压缩配置
+ * 
+ * # 返回
+ * 返回配置了压缩和解压的路由，未启用时原样返回
  */
-async fn process_collected_routes(db: DatabaseConnection) {
-    let routes = get_collected_routes().await;
-    let endpoints: Vec<SysEndpoint> = routes
-        .into_iter()
-        .map(|route| {
-            let resource = route.path.split('/').nth(1).unwrap_or("").to_string();
-            SysEndpoint {
-                id: generate_id(&route.path, &route.method.to_string()),
-                path: route.path.clone(),
-                method: route.method.to_string(),
-                action: "rw".to_string(),
-                resource,
-                controller: route.service_name,
-                summary: Some(route.summary),
-                created_at: Local::now().naive_local(),
-                updated_at: None,
-            }
-        })
-        .collect();
+pub fn apply_compression(router: Router, config: &CompressionConfig) -> Router {
+    if !config.enabled {
+        return router;
+    }
 
-    let endpoint_service = SysEndpointService::new(db.clone());
-    match endpoint_service.sync_endpoints(endpoints).await {
-        Ok(_) => {
-            project_info!("Endpoints synced successfully")
-        },
-        Err(e) => {
-            project_error!("Failed to sync endpoints: {:?}", e)
-        },
+    let gzip = config.supports(CompressionAlgorithm::Gzip);
+    let br = config.supports(CompressionAlgorithm::Br);
+    let predicate = SizeAbove::new(config.min_size)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE)
+        .and(NotForContentType::const_new("application/octet-stream"));
+    let mut router = router
+        .layer(CompressionLayer::new().gzip(gzip).br(br).compress_when(predicate))
+        .layer(RequestDecompressionLayer::new().gzip(gzip).br(br));
+
+    // 排除的路径在压缩层之前去掉 Accept-Encoding，响应按原文返回
+    if !config.exclude_paths.is_empty() {
+        let config = Arc::new(config.clone());
+        router = router.layer(axum::middleware::from_fn(move |req, next| {
+            skip_compression_middleware(config.clone(), req, next)
+        }));
+    }
+
+    router
+}
+
+/**
+ * 跳过压缩中间件
+ * 
+ * 请求路径位于排除列表中时移除 Accept-Encoding 请求头
+ */
+async fn skip_compression_middleware(
+    config: Arc<CompressionConfig>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    if config.is_excluded(req.uri().path()) {
+        req.headers_mut().remove(header::ACCEPT_ENCODING);
     }
+    next.run(req).await
 }
 
 /**
- * 生成路由ID
+ * 旧路径兼容中间件
  * 
- * 根据路径和方法生成唯一的路由ID
+ * 将 `/api/...` 的请求改写为 `/api/v1/...`，并在响应中添加 `Deprecation: true`，
+ * 提示客户端迁移到带版本前缀的路径
+ */
+async fn legacy_path_middleware(mut req: Request<Body>, next: Next) -> Response {
+    let Some(path) = versioned_legacy_path(req.uri().path()) else {
+        return next.run(req).await;
+    };
+    let path_and_query = match req.uri().query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path,
+    };
+    let mut parts = req.uri().clone().into_parts();
+    parts.path_and_query = path_and_query.parse().ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *req.uri_mut() = uri;
+    }
+
+    let mut response = next.run(req).await;
+    response.headers_mut().insert("deprecation", HeaderValue::from_static("true"));
+    response
+}
+
+/**
+ * 在响应头中添加服务版本
+ */
+async fn app_version_header(mut response: Response) -> Response {
+    if let Ok(version) = HeaderValue::from_str(server_version::version_string()) {
+        response.headers_mut().insert(APP_VERSION_HEADER, version);
+    }
+    response
+}
+
+/**
+ * 就绪检查处理器
  * 
- * # 参数
- * - path: 路由路径
- * - method: HTTP方法
+ * 每次检查当前的主库连接，主库连接被替换后检查新连接
  * 
  * # 返回
- * 返回生成的唯一ID
+ * 主数据库可用时返回200，否则返回503；响应中包含当前维护模式状态和慢查询统计
  */
-fn generate_id(path: &str, method: &str) -> String {
-    use std::{
-        collections::hash_map::DefaultHasher,
-        hash::{Hash, Hasher},
+async fn readiness_handler() -> impl IntoResponse {
+    let database = match db_helper::get_db_connection().await {
+        Ok(db) => db.ping().await.is_ok(),
+        Err(_) => false,
+    };
+    let output = ReadinessOutput {
+        ready: database,
+        database,
+        maintenance: SysMaintenanceService.get_maintenance_state().await,
+        queries: db_helper::query_stats(),
     };
+    let status = if output.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(Res::new_data(output)))
+}
 
-    let mut hasher = DefaultHasher::new();
-    format!("{}{}", path, method).hash(&mut hasher);
-    format!("{:x}", hasher.finish())
+/**
+ * 404处理器
+ * 
+ * 处理未找到的路由请求
+ * 
+ * # 返回
+ * 返回404状态码和统一格式的提示信息
+ */
+async fn handler_404() -> impl IntoResponse {
+    (
+        StatusCode::NOT_FOUND,
+        Res::<()>::new_error(StatusCode::NOT_FOUND.as_u16(), "nothing to see here"),
+    )
+}
+
+/**
+ * 处理收集的路由
+ * 
+ * 自动同步时将收集到的路由信息同步到数据库，按请求方法写入 read/write 操作；
+ * 手动同步时只记录同步预览，由管理员通过同步接口执行
+ * 
+ * # 参数
+ * - db: 数据库连接
+ */
+async fn process_collected_routes(db: DatabaseConnection) {
+    let endpoints = endpoints_from_routes(get_collected_routes().await);
+    let mode = get_config::<EndpointSyncConfig>()
+        .await
+        .map(|config| config.mode)
+        .unwrap_or_default();
+
+    let endpoint_service = SysEndpointService::new(db.clone());
+    match mode {
+        EndpointSyncMode::Auto => match endpoint_service.sync_endpoints(endpoints).await {
+            Ok(report) => {
+                project_info!(
+                    "Endpoints synced successfully: {} added, {} updated, {} deleted",
+                    report.to_add.len(),
+                    report.to_update.len(),
+                    report.to_delete.len()
+                )
+            },
+            Err(e) => {
+                project_error!("Failed to sync endpoints: {:?}", e)
+            },
+        },
+        EndpointSyncMode::Manual => match endpoint_service.sync_endpoints_dry_run(endpoints).await {
+            Ok(report) if report.is_empty() => {
+                project_info!("Endpoints are up to date")
+            },
+            Ok(report) => {
+                let paths = |endpoints: &[SysEndpoint]| {
+                    endpoints
+                        .iter()
+                        .map(|e| format!("{} {}", e.method, e.path))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                project_info!(
+                    "Endpoint sync pending, apply it via the sync-apply endpoint. \
+                     To add: [{}]; to update: [{}]; to delete: [{}]",
+                    paths(&report.to_add),
+                    paths(&report.to_update),
+                    paths(&report.to_delete)
+                )
+            },
+            Err(e) => {
+                project_error!("Failed to preview endpoint sync: {:?}", e)
+            },
+        },
+    }
 }
 
 /**
@@ -477,7 +908,7 @@ pub async fn init_router(_app_config: &Config) -> Result<Router, Box<dyn Error>>
     #[allow(unused_variables)]
     let (event_sender, _event_receiver) = mpsc::unbounded_channel::<Box<dyn std::any::Any + Send>>();
     #[allow(unused_variables)]
-    let auth_service = Arc::new(SysAuthService);
+    let auth_service = Arc::new(SysAuthService::default());
 
     // Initialize router
     let router = Router::new()
@@ -489,11 +920,250 @@ pub async fn init_router(_app_config: &Config) -> Result<Router, Box<dyn Error>>
         .merge(SysUserRouter::init_user_router().await)
         .merge(SysDomainRouter::init_domain_router().await)
         .merge(SysRoleRouter::init_role_router().await)
+        .merge(SysRoleTemplateRouter::init_role_template_router().await)
         .merge(SysEndpointRouter::init_endpoint_router().await)
         .merge(SysAccessKeyRouter::init_access_key_router().await)
+        .merge(SysEmailRouter::init_email_router().await)
+        .merge(SysEventRouter::init_event_router().await)
+        .merge(SysJobRouter::init_job_router().await)
+        .merge(SysSystemRouter::init_system_router().await)
+        .merge(SysStorageRouter::init_storage_router().await)
+        .merge(SysStorageRouter::init_protected_storage_router().await)
         .merge(SysLoginLogRouter::init_login_log_router().await)
         .merge(SysOperationLogRouter::init_operation_log_router().await)
-        .merge(SysOrganizationRouter::init_organization_router().await);
+        .merge(SysStatisticsRouter::init_statistics_router().await)
+        .merge(SysComplianceRouter::init_compliance_router().await)
+        .merge(SysOrganizationRouter::init_organization_router().await)
+        .merge(SysOrganizationRouter::init_protected_organization_router().await)
+        .merge(SysInvitationRouter::init_invitation_router().await);
 
     Ok(router)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum_casbin::test_support::{fake_auth, policy, status, vals, CasbinHarness};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use server_config::SandboxAccessKey;
+    use server_core::web::auth::User;
+    use server_global::global::GLOBAL_PRIMARY_OBJECT_STORE;
+    use server_storage::{LocalObjectStore, ObjectStore, PutOptions};
+
+    use super::*;
+    use crate::casbin_initialization::EMBEDDED_CASBIN_MODEL;
+
+    /**
+     * 按 initialize_admin_router 中的参数组装菜单和组织路由
+     *
+     * 认证中间件由注入固定 CasbinVals 的假认证层代替，路由未注入服务，
+     * 通过鉴权的请求在处理函数中因缺少扩展而失败，不会返回403。
+     */
+    async fn admin_routes(casbin: CasbinAxumLayer) -> Router {
+        let routers = [
+            (SysMenuRouter::init_menu_router().await, false),
+            (SysMenuRouter::init_protected_menu_router().await, true),
+            (SysOrganizationRouter::init_organization_router().await, false),
+            (SysOrganizationRouter::init_protected_organization_router().await, true),
+        ];
+
+        let mut app = Router::new();
+        for (router, need_casbin) in routers {
+            let plan = if need_casbin {
+                LayerPlan::new().casbin(casbin.clone())
+            } else {
+                LayerPlan::new()
+            };
+            app = app.merge(plan.apply(router));
+        }
+        app.layer(fake_auth(vals(&["ROLE_A"], Some("built-in"))))
+    }
+
+    #[tokio::test]
+    async fn test_public_routes_skip_casbin() {
+        let casbin = CasbinHarness::new(EMBEDDED_CASBIN_MODEL)
+            .with_read_write_actions()
+            .build()
+            .await
+            .unwrap();
+        let app = admin_routes(casbin).await;
+
+        // 没有任何策略，公开路由仍可访问，受保护路由全部被拒绝
+        assert_ne!(
+            status(&app, "GET", "/api/v1/menu/constant-routes").await,
+            StatusCode::FORBIDDEN
+        );
+        assert_ne!(status(&app, "GET", "/api/v1/org").await, StatusCode::FORBIDDEN);
+        assert_eq!(status(&app, "GET", "/api/v1/menu/tree").await, StatusCode::FORBIDDEN);
+        assert_eq!(status(&app, "DELETE", "/api/v1/org/1").await, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_protected_routes_enforce_policies() {
+        let casbin = CasbinHarness::new(EMBEDDED_CASBIN_MODEL)
+            .with_read_write_actions()
+            .policy(
+                policy()
+                    .role("ROLE_A")
+                    .domain("built-in")
+                    .endpoint("GET", "/api/v1/menu/tree")
+                    .endpoint("DELETE", "/api/v1/org/:id"),
+            )
+            .build()
+            .await
+            .unwrap();
+        let app = admin_routes(casbin).await;
+
+        assert_ne!(status(&app, "GET", "/api/v1/menu/tree").await, StatusCode::FORBIDDEN);
+        assert_eq!(status(&app, "POST", "/api/v1/menu").await, StatusCode::FORBIDDEN);
+        // 写权限同时授予读权限
+        assert_ne!(status(&app, "GET", "/api/v1/org/1").await, StatusCode::FORBIDDEN);
+        assert_ne!(status(&app, "DELETE", "/api/v1/org/1").await, StatusCode::FORBIDDEN);
+    }
+
+    struct FirstService;
+
+    struct SecondService;
+
+    #[tokio::test]
+    async fn test_layer_plan_injects_every_service() {
+        let router = Router::new().route(
+            "/services",
+            get(
+                |Extension(_first): Extension<Arc<FirstService>>,
+                 Extension(_second): Extension<Arc<SecondService>>,
+                 Extension(name): Extension<&'static str>| async move { name },
+            ),
+        );
+        let app = LayerPlan::new()
+            .service(FirstService)
+            .service(SecondService)
+            .extension("extension")
+            .apply(router);
+
+        // 缺少任一扩展时处理函数返回500
+        assert_eq!(status(&app, "GET", "/services").await, StatusCode::OK);
+
+        let app = LayerPlan::new().service(FirstService).apply(Router::new().route(
+            "/services",
+            get(|Extension(_second): Extension<Arc<SecondService>>| async {}),
+        ));
+        assert_eq!(status(&app, "GET", "/services").await, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    /**
+     * 代理下载按块流式返回文件，单段 Range 返回206，超出文件大小返回416
+     */
+    #[tokio::test]
+    async fn test_storage_proxy_streams_ranges() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalObjectStore::new(dir.path(), "/files", b"test-signing-key");
+        let object: Vec<u8> = (0..8 * 1024 * 1024).map(|i: u32| (i % 251) as u8).collect();
+        let key = "tenant-a/exports/large.bin";
+        store.put(key, object.clone(), PutOptions::default()).await.unwrap();
+        store.put("tenant-b/a.bin", b"other".to_vec(), PutOptions::default()).await.unwrap();
+        *GLOBAL_PRIMARY_OBJECT_STORE.write().await = Some(Arc::new(store) as Arc<dyn ObjectStore>);
+
+        let user = User::new("1".to_string(), "alice".to_string(), "tenant-a".to_string());
+        let app = LayerPlan::new()
+            .service(SysStorageService)
+            .apply(SysStorageRouter::init_protected_storage_router().await)
+            .layer(Extension(user));
+        let get = |path: &str, range: Option<&str>| {
+            let mut request = Request::get(format!("/api/v1/storage/proxy/{}", path));
+            if let Some(range) = range {
+                request = request.header(header::RANGE, range);
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        // 逐帧读取响应体并统计帧数和最大帧大小，确认文件按块返回而不是一次读入内存
+        let response = get(key, None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_LENGTH], object.len().to_string());
+        assert_eq!(response.headers()[header::ACCEPT_RANGES], "bytes");
+        let mut body = response.into_body();
+        let (mut received, mut frames, mut largest_frame) = (Vec::new(), 0, 0);
+        while let Some(frame) = body.frame().await {
+            let data = frame.unwrap().into_data().unwrap();
+            frames += 1;
+            largest_frame = largest_frame.max(data.len());
+            received.extend_from_slice(&data);
+        }
+        assert_eq!(received, object);
+        assert!(frames > 100, "{}", frames);
+        assert!(largest_frame <= 64 * 1024, "{}", largest_frame);
+
+        let response = get(key, Some("bytes=1048576-2097151")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes 1048576-2097151/8388608");
+        assert_eq!(response.headers()[header::CONTENT_LENGTH], "1048576");
+        let received = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(received, &object[1048576..2097152]);
+
+        let response = get(key, Some("bytes=-100")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes 8388508-8388607/8388608");
+
+        // 多段范围被忽略，返回整个文件
+        let response = get(key, Some("bytes=0-1,5-6")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = get(key, Some("bytes=8388608-")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */8388608");
+
+        // 非内置域的用户不能下载其他域的文件
+        let response = get("tenant-b/a.bin", None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = get("tenant-a/none.bin", None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_app_version_header() {
+        let app = Router::new()
+            .route("/ping", get(|| async {}))
+            .layer(axum::middleware::map_response(app_version_header));
+
+        let request = Request::get("/ping").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.headers()[APP_VERSION_HEADER], server_version::version_string());
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_disabled_by_default() {
+        let config = SandboxConfig::default();
+        let app = init_sandbox_router(&config, LayerPlan::new).await.fallback(handler_404);
+
+        for path in SysSandboxRouter::protected_paths() {
+            assert_eq!(status(&app, "GET", &path).await, StatusCode::NOT_FOUND, "{}", path);
+            assert_eq!(status(&app, "POST", &path).await, StatusCode::NOT_FOUND, "{}", path);
+        }
+        assert!(!server_core::sign::get_simple_validator().await.validate_key("test-api-key"));
+        let complex_validator = server_core::sign::get_complex_validator().await;
+        assert!(complex_validator.expected_signature("test-access-key", "t=1").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_enabled_registers_configured_keys() {
+        let config = SandboxConfig {
+            enabled: true,
+            debug_enabled: false,
+            api_keys: vec!["sandbox-api-key".to_string()],
+            access_keys: vec![SandboxAccessKey {
+                access_key_id: "sandbox-access-key".to_string(),
+                secret: "sandbox-secret".to_string(),
+            }],
+        };
+        let app = init_sandbox_router(&config, LayerPlan::new).await.fallback(handler_404);
+
+        // 路由已注册，未携带密钥的请求由API密钥验证拒绝
+        let path = "/api/v1/sandbox/simple-api-key";
+        assert_ne!(status(&app, "GET", path).await, StatusCode::NOT_FOUND);
+        assert!(server_core::sign::get_simple_validator().await.validate_key("sandbox-api-key"));
+        let complex_validator = server_core::sign::get_complex_validator().await;
+        assert!(complex_validator.expected_signature("sandbox-access-key", "t=1").is_some());
+    }
+}
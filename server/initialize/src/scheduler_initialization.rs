@@ -0,0 +1,36 @@
+/**
+ * 后台任务调度初始化模块
+ * 
+ * 本模块负责向全局调度器注册周期任务并启动调度：
+ * - 只读副本健康检查
+ * - 操作日志保留清理（配置了保留天数时）
+ * 
+ * 任务的执行状态可通过 `GET /api/v1/system/jobs` 查询，
+ * 并可通过 `POST /api/v1/system/jobs/{name}/run` 立即执行。
+ */
+
+use server_core::scheduler::scheduler;
+
+use crate::{
+    db_initialization::db_health_job, operation_log_initialization::operation_log_retention_job,
+    project_info, InitError,
+};
+
+/**
+ * 注册周期任务并启动调度器
+ * 
+ * # 返回
+ * - 成功：返回 ()
+ * - 失败：任务重复注册时返回错误
+ */
+pub async fn initialize_scheduler() -> Result<(), InitError> {
+    let jobs = [Some(db_health_job()), operation_log_retention_job().await];
+
+    for job in jobs.into_iter().flatten() {
+        scheduler().register(job).map_err(|e| InitError::Scheduler(e.to_string()))?;
+    }
+
+    scheduler().start();
+    project_info!("Scheduler started with {} jobs", scheduler().statuses().len());
+    Ok(())
+}
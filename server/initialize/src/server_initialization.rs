@@ -2,15 +2,13 @@
  * 服务器初始化模块
  * 
  * 本模块负责初始化HTTP服务器的基本配置，
- * 包括服务器地址、端口等设置。
+ * 包括服务器地址、端口以及内部gRPC服务地址等设置。
  */
 
-use std::error::Error;
-
 use server_config::ServerConfig;
 use server_global::global;
 
-use crate::project_info;
+use crate::{project_info, InitError};
 
 /**
  * 获取服务器地址
@@ -27,9 +25,33 @@ use crate::project_info;
  * 2. 组合主机地址和端口
  * 3. 记录配置的服务器地址
  */
-pub async fn get_server_address() -> Result<String, Box<dyn Error>> {
-    let server_config = global::get_config::<ServerConfig>().await.unwrap();
+pub async fn get_server_address() -> Result<String, InitError> {
+    let server_config = global::get_config::<ServerConfig>()
+        .await
+        .ok_or(InitError::MissingConfig("server"))?;
     let addr = format!("{}:{}", server_config.host, server_config.port);
     project_info!("Server address configured: {}", addr);
     Ok(addr)
 }
+
+/**
+ * 获取gRPC服务地址
+ * 
+ * 使用服务器配置中的主机地址和 `grpc_port` 组合gRPC服务地址。
+ * 
+ * # 返回
+ * - 成功：配置了gRPC端口时返回地址（host:grpc_port），否则返回None
+ * - 失败：返回错误信息
+ */
+pub async fn get_grpc_address() -> Result<Option<String>, InitError> {
+    let server_config = global::get_config::<ServerConfig>()
+        .await
+        .ok_or(InitError::MissingConfig("server"))?;
+    let addr = server_config
+        .grpc_port
+        .map(|port| format!("{}:{}", server_config.host, port));
+    if let Some(addr) = &addr {
+        project_info!("gRPC server address configured: {}", addr);
+    }
+    Ok(addr)
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Request, StatusCode},
+        routing::{get, post},
+        Json, Router,
+    };
+    use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+    use serde_json::{json, Value};
+    use server_config::CompressionConfig;
+    use server_core::web::{operation_log::OperationLogLayer, res::Res};
+    use server_global::global::OperationLogContext;
+    use server_initialize::apply_compression;
+    use tower::ServiceExt;
+
+    /** 生成与菜单树大小相当的响应 */
+    fn large_tree() -> Value {
+        let children: Vec<Value> = (0..200)
+            .map(|id| json!({ "id": id, "name": format!("menu-{}", id), "children": [] }))
+            .collect();
+        json!([{ "id": 0, "name": "root", "children": children }])
+    }
+
+    async fn tree_handler() -> Res<Value> {
+        Res::new_data(large_tree())
+    }
+
+    async fn echo_handler(Json(body): Json<Value>) -> Res<Value> {
+        Res::new_data(body)
+    }
+
+    fn app(config: &CompressionConfig) -> Router {
+        let router = Router::new()
+            .route("/menu/tree", get(tree_handler).layer(OperationLogLayer::new(true)))
+            .route("/metrics", get(tree_handler))
+            .route("/echo", post(echo_handler).layer(OperationLogLayer::new(true)));
+        apply_compression(router, config)
+    }
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn gunzip(bytes: &[u8]) -> Vec<u8> {
+        let mut decoded = Vec::new();
+        GzDecoder::new(bytes).read_to_end(&mut decoded).unwrap();
+        decoded
+    }
+
+    #[tokio::test]
+    async fn test_tree_response_is_gzipped_and_logged_as_json() {
+        let request = Request::builder()
+            .uri("/menu/tree")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .body(Body::empty())
+            .unwrap();
+
+        let (response, context) = OperationLogContext::scope(async move {
+            let response = app(&CompressionConfig::default()).oneshot(request).await.unwrap();
+            (response, OperationLogContext::get().unwrap())
+        })
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&gunzip(&bytes)).unwrap();
+        assert_eq!(body["data"], large_tree());
+
+        // 操作日志记录的是压缩前的响应
+        assert_eq!(context.response.unwrap()["data"], large_tree());
+    }
+
+    #[tokio::test]
+    async fn test_gzipped_request_body_is_logged_as_json() {
+        let payload = json!({ "name": "menu", "children": large_tree() });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/echo")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONTENT_ENCODING, "gzip")
+            .body(Body::from(gzip(payload.to_string().as_bytes())))
+            .unwrap();
+
+        let (response, context) = OperationLogContext::scope(async move {
+            let response = app(&CompressionConfig::default()).oneshot(request).await.unwrap();
+            (response, OperationLogContext::get().unwrap())
+        })
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        // 请求未声明 Accept-Encoding 时响应不压缩
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        assert_eq!(context.body, Some(payload));
+    }
+
+    #[tokio::test]
+    async fn test_excluded_and_disabled_responses_are_not_compressed() {
+        let request = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .header(header::ACCEPT_ENCODING, "gzip, br")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let excluded = CompressionConfig {
+            exclude_paths: vec!["/metrics".to_string()],
+            ..Default::default()
+        };
+        let response = app(&excluded).oneshot(request("/metrics")).await.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        let response = app(&excluded).oneshot(request("/menu/tree")).await.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_some());
+
+        let disabled = CompressionConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        let response = app(&disabled).oneshot(request("/menu/tree")).await.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+}
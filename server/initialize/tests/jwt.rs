@@ -27,7 +27,7 @@ mod tests {
     async fn init() {
         let mut initialized = INITIALIZED.lock().await;
         if initialized.is_none() {
-            initialize_config("../resources/application.yaml").await;
+            initialize_config("../resources/application.yaml").await.unwrap();
             init_jwt().await.unwrap();
             *initialized = Some(Arc::new(()));
         }
@@ -1,2 +1,3 @@
+mod compression;
 mod jwt;
 mod jwt_auth_middleware;
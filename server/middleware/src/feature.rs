@@ -0,0 +1,103 @@
+/**
+ * 域功能开关中间件模块
+ * 
+ * 根据请求所属域的功能开关决定是否放行请求。
+ * 
+ * 域的确定顺序：
+ * - JWT认证注入的用户信息中的域
+ * - API密钥验证注入的访问密钥所属的域
+ * 
+ * 无法确定域的请求（如未登记的测试密钥）直接放行。
+ */
+
+use axum::{
+    body::Body, extract::Request, http::StatusCode, middleware::Next, response::IntoResponse,
+};
+use server_core::{
+    sign::ApiKeyIdentity,
+    web::{auth::User, error::AppError, res::Res},
+};
+use server_service::admin::{
+    DomainFeatureError, SysAccessKeyService, SysDomainFeatureService, TAccessKeyService,
+    TDomainFeatureService,
+};
+
+/**
+ * 功能要求
+ * 
+ * 包装功能标识，配合 `require_feature_middleware` 使用：
+ * 
+ * ```rust
+ * router.layer(axum::middleware::from_fn(|req, next| {
+ *     require_feature_middleware(RequireFeature("sandbox"), req, next)
+ * }))
+ * ```
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct RequireFeature(pub &'static str);
+
+/**
+ * 域功能开关中间件
+ * 
+ * 需位于JWT认证或API密钥验证中间件之内，以便读取请求所属的域。
+ * 
+ * # 参数
+ * - feature: 要求启用的功能
+ * - req: 原始HTTP请求
+ * - next: 下一个中间件或处理函数
+ * 
+ * # 返回
+ * - 功能已启用或无法确定域时，返回下一个中间件的响应
+ * - 功能在当前域被禁用时，返回403 Forbidden错误
+ */
+pub async fn require_feature_middleware(
+    feature: RequireFeature,
+    req: Request<Body>,
+    next: Next,
+) -> impl IntoResponse {
+    let user_domain = req.extensions().get::<User>().map(User::domain);
+    let access_key_id = req
+        .extensions()
+        .get::<ApiKeyIdentity>()
+        .map(|identity| identity.0.clone());
+
+    let domain = match resolve_domain(user_domain, access_key_id).await {
+        Ok(Some(domain)) => domain,
+        Ok(None) => return next.run(req).await.into_response(),
+        Err(e) => return e.into_response(),
+    };
+
+    match SysDomainFeatureService.is_enabled(&domain, feature.0).await {
+        Ok(true) => next.run(req).await.into_response(),
+        Ok(false) => {
+            let error = DomainFeatureError::FeatureDisabled {
+                domain,
+                feature: feature.0.to_string(),
+            };
+            (
+                StatusCode::FORBIDDEN,
+                Res::<()>::new_error(StatusCode::FORBIDDEN.as_u16(), &error.to_string()),
+            )
+                .into_response()
+        },
+        Err(e) => e.into_response(),
+    }
+}
+
+/**
+ * 确定请求所属的域，用户信息优先于访问密钥
+ */
+async fn resolve_domain(
+    user_domain: Option<String>,
+    access_key_id: Option<String>,
+) -> Result<Option<String>, AppError> {
+    match (user_domain, access_key_id) {
+        (Some(domain), _) => Ok(Some(domain)),
+        (None, Some(access_key_id)) => {
+            SysAccessKeyService
+                .find_access_key_domain(&access_key_id)
+                .await
+        },
+        (None, None) => Ok(None),
+    }
+}
@@ -8,7 +8,12 @@
  * - 从请求头中提取Bearer令牌
  * - 验证令牌的有效性
  * - 解析令牌中的用户信息
+ * - 令牌只携带角色引用时从Redis取回角色列表
+ * - 按配置复查用户和域状态
+ * - 拒绝已撤销的令牌并记录令牌最近使用时间
+ * - 识别 `pat_` 前缀的个人访问令牌，并将权限限定在令牌的权限范围内
  * - 将用户信息注入到请求上下文中
+ * - 设置当前用户上下文，供服务层填充审计字段
  */
 
 use axum::{
@@ -16,7 +21,11 @@ use axum::{
 };
 use axum_casbin::CasbinVals;
 use headers::{authorization::Bearer, Authorization, HeaderMapExt};
-use server_core::web::{auth::User, jwt::JwtUtils, res::Res};
+use server_core::web::{auth::User, jwt::JwtUtils, res::Res, CurrentUserContext};
+use server_service::admin::{
+    personal_token_subject, SysAuthService, SysPersonalTokenService, SysTokenService,
+    TAuthService, TPersonalTokenService, TTokenService, PERSONAL_TOKEN_PREFIX,
+};
 
 /**
  * JWT认证中间件
@@ -31,13 +40,19 @@ use server_core::web::{auth::User, jwt::JwtUtils, res::Res};
  * # 返回
  * - 如果令牌有效，返回下一个中间件的响应
  * - 如果令牌无效或缺失，返回401 Unauthorized错误
+ * - 开启状态复查且用户或域已禁用时，返回401 Unauthorized错误
+ * - 令牌已被撤销时，返回401 Unauthorized错误
+ * - 个人访问令牌无效、已过期或用户已禁用时，返回401 Unauthorized错误
+ * - 令牌的角色引用无法取回时，返回401 Unauthorized错误
  * 
  * # 处理流程
- * 1. 从请求头中提取Bearer令牌
+ * 1. 从请求头中提取Bearer令牌，`pat_` 前缀的令牌按个人访问令牌认证后跳到第6步
  * 2. 验证令牌的有效性
- * 3. 解析令牌中的用户信息
- * 4. 将用户信息注入到请求上下文中
- * 5. 调用下一个中间件或处理函数
+ * 3. 解析令牌中的用户信息，令牌携带角色引用时取回角色列表
+ * 4. JWT配置开启 `strict_status_check` 时查询用户和域状态
+ * 5. 令牌带有JWT ID时检查令牌是否已撤销，并记录最近使用时间
+ * 6. 将用户信息注入到请求上下文中，个人访问令牌的主体作为 casbin 的附加判定主体
+ * 7. 在当前用户上下文中调用下一个中间件或处理函数
  */
 pub async fn jwt_auth_middleware(
     mut req: Request<Body>,
@@ -55,21 +70,69 @@ pub async fn jwt_auth_middleware(
         },
     };
 
-    match JwtUtils::validate_token(&token, audience).await {
-        Ok(data) => {
-            let claims = data.claims;
-            let user = User::from(claims);
-            let vals = CasbinVals {
-                subject: user.subject(),
-                domain: Option::from(user.domain()),
-            };
-            req.extensions_mut().insert(user);
-            req.extensions_mut().insert(vals);
-            next.run(req).await.into_response()
-        },
-        Err(err) => {
-            Res::<String>::new_error(StatusCode::UNAUTHORIZED.as_u16(), err.to_string().as_str())
-                .into_response()
-        },
-    }
+    let user = if token.starts_with(PERSONAL_TOKEN_PREFIX) {
+        match SysPersonalTokenService::default().authenticate(&token, audience).await {
+            Ok(user) => user,
+            Err(err) => {
+                return Res::<String>::new_error(
+                    StatusCode::UNAUTHORIZED.as_u16(),
+                    err.to_string().as_str(),
+                )
+                .into_response();
+            },
+        }
+    } else {
+        match JwtUtils::validate_token(&token, audience).await {
+            Ok(data) => {
+                let claims = data.claims;
+                let user = match SysAuthService::resolve_token_roles(User::from(claims)).await {
+                    Ok(user) => user,
+                    Err(err) => {
+                        return Res::<String>::new_error(
+                            StatusCode::UNAUTHORIZED.as_u16(),
+                            err.to_string().as_str(),
+                        )
+                        .into_response();
+                    },
+                };
+                if SysAuthService::strict_status_check_enabled().await {
+                    let status =
+                        SysAuthService::default().verify_user_status(&user.user_id()).await;
+                    if let Err(err) = status {
+                        return Res::<String>::new_error(
+                            StatusCode::UNAUTHORIZED.as_u16(),
+                            err.to_string().as_str(),
+                        )
+                        .into_response();
+                    }
+                }
+                if let Some(jti) = user.jti() {
+                    if let Err(err) = SysTokenService.touch_token(&jti).await {
+                        return Res::<String>::new_error(
+                            StatusCode::UNAUTHORIZED.as_u16(),
+                            err.to_string().as_str(),
+                        )
+                        .into_response();
+                    }
+                }
+                user
+            },
+            Err(err) => {
+                return Res::<String>::new_error(
+                    StatusCode::UNAUTHORIZED.as_u16(),
+                    err.to_string().as_str(),
+                )
+                .into_response();
+            },
+        }
+    };
+
+    let vals = CasbinVals {
+        subject: user.subject(),
+        domain: Option::from(user.domain()),
+        scope: user.personal_token().as_deref().map(personal_token_subject),
+    };
+    req.extensions_mut().insert(user.clone());
+    req.extensions_mut().insert(vals);
+    CurrentUserContext::scope(user, next.run(req)).await.into_response()
 }
@@ -1,3 +1,7 @@
+mod feature;
 mod jwt;
+mod maintenance;
 
+pub use feature::{require_feature_middleware, RequireFeature};
 pub use jwt::jwt_auth_middleware;
+pub use maintenance::maintenance_middleware;
@@ -0,0 +1,108 @@
+/**
+ * 维护模式中间件模块
+ * 
+ * 维护模式开启时拒绝所有写操作请求（POST/PUT/PATCH/DELETE），返回503。
+ * 
+ * 以下请求不受限制：
+ * - 读操作请求
+ * - 维护模式设置接口本身，保证维护期间仍可关闭维护模式
+ * - 令牌中的角色位于允许列表中的用户
+ */
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+};
+use headers::{authorization::Bearer, Authorization, HeaderMapExt};
+use server_core::web::{auth::User, jwt::JwtUtils, res::Res};
+use server_service::admin::{SysAuthService, SysMaintenanceService, TMaintenanceService};
+
+/**
+ * 维护模式中间件
+ * 
+ * 位于JWT认证中间件之外，需自行解析令牌以获取用户角色；
+ * 令牌缺失、无效或角色引用无法取回时视为不在允许列表中，认证错误仍由内层中间件返回。
+ * 
+ * # 参数
+ * - req: 原始HTTP请求
+ * - next: 下一个中间件或处理函数
+ * - audience: JWT令牌的目标受众
+ * - exempt_path: 维护模式设置接口的完整路径
+ * 
+ * # 返回
+ * - 未处于维护模式、读操作或被放行的请求，返回下一个中间件的响应
+ * - 其余写操作请求返回503 Service Unavailable及维护提示信息
+ */
+pub async fn maintenance_middleware(
+    req: Request<Body>,
+    next: Next,
+    audience: &str,
+    exempt_path: &str,
+) -> impl IntoResponse {
+    if !is_mutating(req.method()) || req.uri().path() == exempt_path {
+        return next.run(req).await.into_response();
+    }
+
+    let state = SysMaintenanceService.get_maintenance_state().await;
+    if !state.enabled {
+        return next.run(req).await.into_response();
+    }
+
+    let token = req
+        .headers()
+        .typed_get::<Authorization<Bearer>>()
+        .map(|auth| auth.token().to_string());
+    let user = match token {
+        Some(token) => JwtUtils::validate_token(&token, audience)
+            .await
+            .ok()
+            .map(|data| User::from(data.claims)),
+        None => None,
+    };
+    let roles = match user {
+        Some(user) => SysAuthService::resolve_token_roles(user)
+            .await
+            .map(|user| user.subject())
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    if state.allows_any(&roles) {
+        return next.run(req).await.into_response();
+    }
+
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Res::<()>::new_error(StatusCode::SERVICE_UNAVAILABLE.as_u16(), state.message()),
+    )
+        .into_response()
+}
+
+/**
+ * 判断请求方法是否为写操作
+ */
+fn is_mutating(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mutating() {
+        assert!(is_mutating(&Method::POST));
+        assert!(is_mutating(&Method::PUT));
+        assert!(is_mutating(&Method::PATCH));
+        assert!(is_mutating(&Method::DELETE));
+        assert!(!is_mutating(&Method::GET));
+        assert!(!is_mutating(&Method::HEAD));
+        assert!(!is_mutating(&Method::OPTIONS));
+    }
+}
@@ -6,13 +6,19 @@ pub mod casbin_rule;
 pub mod sea_orm_active_enums;
 pub mod sys_access_key;
 pub mod sys_domain;
+pub mod sys_domain_feature;
 pub mod sys_endpoint;
+pub mod sys_invitation;
 pub mod sys_login_log;
 pub mod sys_menu;
 pub mod sys_operation_log;
 pub mod sys_organization;
+pub mod sys_personal_token;
 pub mod sys_role;
 pub mod sys_role_menu;
+pub mod sys_role_template;
+pub mod sys_security_event;
 pub mod sys_tokens;
 pub mod sys_user;
+pub mod sys_user_domain;
 pub mod sys_user_role;
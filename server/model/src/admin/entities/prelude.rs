@@ -2,10 +2,14 @@
 
 pub use super::{
     casbin_rule::Entity as CasbinRule, sys_access_key::Entity as SysAccessKey,
-    sys_domain::Entity as SysDomain, sys_endpoint::Entity as SysEndpoint,
+    sys_domain::Entity as SysDomain, sys_domain_feature::Entity as SysDomainFeature,
+    sys_endpoint::Entity as SysEndpoint, sys_invitation::Entity as SysInvitation,
     sys_login_log::Entity as SysLoginLog, sys_menu::Entity as SysMenu,
     sys_operation_log::Entity as SysOperationLog, sys_organization::Entity as SysOrganization,
+    sys_personal_token::Entity as SysPersonalToken,
     sys_role::Entity as SysRole, sys_role_menu::Entity as SysRoleMenu,
-    sys_tokens::Entity as SysTokens, sys_user::Entity as SysUser,
+    sys_role_template::Entity as SysRoleTemplate,
+    sys_security_event::Entity as SysSecurityEvent, sys_tokens::Entity as SysTokens,
+    sys_user::Entity as SysUser, sys_user_domain::Entity as SysUserDomain,
     sys_user_role::Entity as SysUserRole,
 };
@@ -12,6 +12,9 @@ pub enum MenuType {
     #[sea_orm(string_value = "menu")]
     #[serde(rename = "menu")]
     Menu,
+    #[sea_orm(string_value = "button")]
+    #[serde(rename = "button")]
+    Button,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
@@ -2,6 +2,7 @@
 
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
+use serde_json::Value as JsonValue;
 
 use super::sea_orm_active_enums::Status;
 
@@ -19,9 +20,16 @@ pub struct Model {
     pub status: Status,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: DateTime,
     #[sea_orm(column_type = "Text")]
     pub created_by: String,
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub last_used_at: Option<DateTime>,
+    pub timestamp_tolerance_ms: Option<i64>,
+    pub nonce_ttl_secs: Option<i64>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub allowed_ips: Option<JsonValue>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
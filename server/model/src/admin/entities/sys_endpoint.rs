@@ -3,6 +3,8 @@
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
 
+use crate::admin::timestamps::timestamped_behavior;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
 #[sea_orm(table_name = "sys_endpoint")]
 pub struct Model {
@@ -20,11 +22,13 @@ pub struct Model {
     pub controller: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub summary: Option<String>,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: DateTime,
+    #[serde(with = "crate::admin::timestamps::utc::option")]
     pub updated_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
-impl ActiveModelBehavior for ActiveModel {}
+timestamped_behavior!();
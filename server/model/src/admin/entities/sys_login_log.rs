@@ -15,6 +15,7 @@ pub struct Model {
     pub username: String,
     #[sea_orm(column_type = "Text")]
     pub domain: String,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub login_time: DateTime,
     #[sea_orm(column_type = "Text")]
     pub ip: String,
@@ -27,9 +28,13 @@ pub struct Model {
     pub request_id: String,
     #[sea_orm(column_type = "Text")]
     pub r#type: String,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: DateTime,
     #[sea_orm(column_type = "Text")]
     pub created_by: String,
+    pub success: bool,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub failure_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
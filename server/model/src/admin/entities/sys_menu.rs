@@ -3,6 +3,8 @@
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
 
+use crate::admin::timestamps::timestamped_behavior;
+
 use super::sea_orm_active_enums::{MenuType, Status};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
@@ -30,9 +32,12 @@ pub struct Model {
     pub constant: bool,
     pub href: Option<String>,
     pub multi_tab: Option<bool>,
+    pub domain: Option<String>,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: DateTime,
     #[sea_orm(column_type = "Text")]
     pub created_by: String,
+    #[serde(with = "crate::admin::timestamps::utc::option")]
     pub updated_at: Option<DateTime>,
     #[sea_orm(column_type = "Text", nullable)]
     pub updated_by: Option<String>,
@@ -50,4 +55,4 @@ impl Related<super::sys_role_menu::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+timestamped_behavior!();
@@ -36,9 +36,14 @@ pub struct Model {
     pub body: Option<JsonValue>,
     #[sea_orm(column_type = "JsonBinary", nullable)]
     pub response: Option<JsonValue>,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub start_time: DateTime,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub end_time: DateTime,
     pub duration: i32,
+    pub timed_out: bool,
+    pub status_code: Option<i32>,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: DateTime,
 }
 
@@ -0,0 +1,221 @@
+/**
+ * 类型化标识符定义
+ *
+ * 用户ID、角色ID、域代码和菜单ID各自使用独立的类型，避免把角色代码等字符串误传为ID。
+ * 序列化时与内部的字符串或整数相同；反序列化和 `FromStr` 解析时校验格式，
+ * 格式错误的请求在提取参数时即被拒绝。
+ */
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use server_core::web::error::AppError;
+
+/** 用户ID、角色ID等字符串标识符的最大长度 */
+const MAX_ID_LENGTH: usize = 64;
+
+/**
+ * 标识符格式错误
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidId(&'static str);
+
+impl fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for InvalidId {}
+
+impl From<InvalidId> for AppError {
+    fn from(err: InvalidId) -> Self {
+        AppError::new(400, err.to_string())
+    }
+}
+
+/**
+ * 判断是否为合法的字符串标识符
+ *
+ * 长度为 1-64 个字符，只包含字母、数字、下划线和连字符
+ */
+pub fn is_valid_id(value: &str) -> bool {
+    (1..=MAX_ID_LENGTH).contains(&value.len())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/**
+ * 判断是否为合法的域代码
+ *
+ * 以字母开头，只包含字母、数字、下划线和连字符
+ */
+fn is_valid_domain_code(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/**
+ * 定义字符串标识符类型
+ */
+macro_rules! string_id {
+    ($(#[$meta:meta])* $name:ident, $is_valid:path, $message:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            /** 获取字符串形式 */
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /** 取出内部字符串 */
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = InvalidId;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                if $is_valid(&value) {
+                    Ok(Self(value))
+                } else {
+                    Err(InvalidId($message))
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidId;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::try_from(value.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+string_id!(
+    /** 用户ID */
+    UserId,
+    is_valid_id,
+    "User ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens"
+);
+
+string_id!(
+    /** 角色ID（不是角色代码） */
+    RoleId,
+    is_valid_id,
+    "Role ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens"
+);
+
+string_id!(
+    /** 域代码 */
+    DomainCode,
+    is_valid_domain_code,
+    "Domain code must start with a letter and contain only letters, numbers, underscores, and hyphens"
+);
+
+/**
+ * 菜单ID，即分配路由时的路由ID，必须为正整数
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
+pub struct MenuId(i32);
+
+impl MenuId {
+    /** 获取整数值 */
+    pub fn value(self) -> i32 {
+        self.0
+    }
+}
+
+impl TryFrom<i32> for MenuId {
+    type Error = InvalidId;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value > 0 {
+            Ok(Self(value))
+        } else {
+            Err(InvalidId("Menu ID must be a positive integer"))
+        }
+    }
+}
+
+impl FromStr for MenuId {
+    type Err = InvalidId;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .parse::<i32>()
+            .map_err(|_| InvalidId("Menu ID must be a positive integer"))
+            .and_then(Self::try_from)
+    }
+}
+
+impl From<MenuId> for i32 {
+    fn from(id: MenuId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for MenuId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ids() {
+        assert!("01JABCDEFGHJKMNPQRSTVWXYZ".parse::<UserId>().is_ok());
+        assert!("".parse::<UserId>().is_err());
+        assert!("a".repeat(MAX_ID_LENGTH + 1).parse::<RoleId>().is_err());
+        assert!("R_ADMIN code".parse::<RoleId>().is_err());
+
+        assert!("built-in".parse::<DomainCode>().is_ok());
+        assert!("1domain".parse::<DomainCode>().is_err());
+        assert!("".parse::<DomainCode>().is_err());
+
+        assert_eq!("3".parse::<MenuId>().map(MenuId::value), Ok(3));
+        assert!("0".parse::<MenuId>().is_err());
+        assert!("x".parse::<MenuId>().is_err());
+    }
+
+    #[test]
+    fn test_serde_is_transparent_and_validated() {
+        let role_id: RoleId = serde_json::from_str("\"r1\"").unwrap();
+        assert_eq!(serde_json::to_string(&role_id).unwrap(), "\"r1\"");
+        assert!(serde_json::from_str::<RoleId>("\"r 1\"").is_err());
+
+        let menu_ids: Vec<MenuId> = serde_json::from_str("[1, 2]").unwrap();
+        assert_eq!(serde_json::to_string(&menu_ids).unwrap(), "[1,2]");
+        let err = serde_json::from_str::<Vec<MenuId>>("[1, -2]").unwrap_err();
+        assert!(err.to_string().contains("Menu ID must be a positive integer"));
+    }
+}
@@ -6,31 +6,85 @@
  * 
  * 主要包含：
  * - 访问密钥相关输入
- * - 认证/登录相关输入
+ * - 认证/登录/注册相关输入
+ * - 邀请码相关输入
  * - 授权相关输入
- * - 域名、接口、菜单、角色、用户等管理输入
+ * - 合规审计导出输入
+ * - 域名、域功能开关、接口、菜单、角色、角色模板、用户等管理输入
+ * - 维护模式设置输入
+ * - 测试邮件发送输入
+ * - 策略查看与权限判定试算输入
+ * - 本人登录令牌查询输入
+ * - 个人访问令牌创建输入
+ * - 预签名文件下载输入
+ * - 统计概览查询输入
+ * - 主库重新连接输入
  */
 
-pub use sys_access_key::{AccessKeyPageRequest, CreateAccessKeyInput};
-pub use sys_authentication::LoginInput;
-pub use sys_authorization::{AssignPermissionDto, AssignRouteDto, AssignUserDto};
-pub use sys_domain::{CreateDomainInput, DomainPageRequest, UpdateDomainInput};
-pub use sys_endpoint::EndpointPageRequest;
-pub use sys_login_log::LoginLogPageRequest;
-pub use sys_menu::{MenuInput, MenuPageRequest, CreateMenuInput, UpdateMenuInput};
-pub use sys_operation_log::OperationLogPageRequest;
-pub use sys_organization::OrganizationPageRequest;
-pub use sys_role::{CreateRoleInput, RolePageRequest, UpdateRoleInput};
+pub use sys_access_key::{
+    normalize_allowed_ips, AccessKeyPageRequest, CreateAccessKeyInput, UnusedAccessKeyRequest,
+    UpdateAccessKeyInput,
+};
+pub use sys_authentication::{LoginInput, RegisterInput, SwitchDomainInput};
+pub use sys_authorization::{
+    AssignPermissionDto, AssignRouteDto, AssignUserDto, EndpointSelector, PreviewRoutesRequest,
+};
+pub use sys_compliance::ComplianceExportInput;
+pub use sys_domain::{
+    CreateDomainInput, DomainPageRequest, DomainTemplate, ProvisionDomainInput, UpdateDomainInput,
+};
+pub use sys_domain_feature::UpsertDomainFeatureInput;
+pub use sys_email::SendTestEmailInput;
+pub use sys_endpoint::{EndpointGroupBy, EndpointPageRequest, EndpointTreeRequest};
+pub use sys_invitation::{CreateInvitationInput, InvitationPageRequest};
+pub use sys_login_log::{LoginHistoryRequest, LoginLogPageRequest};
+pub use sys_maintenance::UpdateMaintenanceInput;
+pub use sys_menu::{
+    normalize_route_path, ConstantRoutesQuery, CreateMenuInput, MenuDeleteRequest, MenuInput,
+    MenuPageRequest, MenuRules, UpdateMenuInput,
+};
+pub use sys_operation_log::{
+    OperationLogCursorRequest, OperationLogPageRequest, OperationLogPurgeRequest, StatusClass,
+};
+pub use sys_organization::{
+    CreateOrganizationInput, OrganizationDeleteRequest, OrganizationInput,
+    OrganizationPageRequest, TransferOrganizationUsersInput, UpdateOrganizationInput,
+};
+pub use sys_personal_token::CreatePersonalTokenInput;
+pub use sys_policy::{PolicyCheckRequest, PolicyListRequest};
+pub use sys_role::{
+    CreateRoleFromTemplateInput, CreateRoleInput, ImportRolePermissionInput, RolePageRequest,
+    RoleUserPageRequest, UpdateRoleInput,
+};
+pub use sys_role_template::{CreateRoleTemplateInput, RoleTemplateInput, UpdateRoleTemplateInput};
+pub use sys_sandbox::SignDebugInput;
+pub use sys_statistics::StatisticsOverviewRequest;
+pub use sys_storage::PresignedFileRequest;
+pub use sys_system::DbReconnectInput;
+pub use sys_token::TokenPageRequest;
 pub use sys_user::{CreateUserInput, UpdateUserInput, UserPageRequest};
 
 mod sys_access_key;
 mod sys_authentication;
 mod sys_authorization;
+mod sys_compliance;
 mod sys_domain;
+mod sys_domain_feature;
+mod sys_email;
 mod sys_endpoint;
+mod sys_invitation;
 mod sys_login_log;
+mod sys_maintenance;
 mod sys_menu;
 mod sys_operation_log;
 mod sys_organization;
+mod sys_personal_token;
+mod sys_policy;
 mod sys_role;
+mod sys_role_template;
+mod sys_sandbox;
+mod sys_statistics;
+mod sys_storage;
+mod sys_system;
+mod sys_token;
 mod sys_user;
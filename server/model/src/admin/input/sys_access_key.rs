@@ -1,12 +1,17 @@
 /**
  * 访问密钥相关输入参数定义
  * 
- * 包含访问密钥分页请求与创建输入结构体。
+ * 包含访问密钥分页请求、创建、更新输入与未使用密钥查询结构体。
  */
 
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
-use validator::Validate;
+use server_core::{
+    sign::parse_allowed_ip,
+    web::page::{deserialize_keywords, PageRequest},
+};
+use validator::{Validate, ValidationError};
 
 use crate::admin::entities::sea_orm_active_enums::Status;
 
@@ -19,6 +24,7 @@ use crate::admin::entities::sea_orm_active_enums::Status;
 pub struct AccessKeyPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
 }
 
@@ -31,11 +37,135 @@ pub struct AccessKeyPageRequest {
 pub struct AccessKeyInput {
     pub domain: String,
     pub status: Status,
-    #[validate(length(max = 200, message = "Description must not exceed 200 characters"))]
+    #[validate(length(max = 200, message = "validation.description.max_length"))]
     pub description: Option<String>,
+    /** 签名验证允许的时间戳偏差（毫秒），未设置时为5分钟 */
+    #[validate(range(
+        min = 1000,
+        max = 3600000,
+        message = "validation.timestamp_tolerance_ms.range"
+    ))]
+    pub timestamp_tolerance_ms: Option<i64>,
+    /** nonce 的保留时间（秒），未设置时为10分钟且不短于时间戳窗口 */
+    #[validate(range(min = 1, max = 86400, message = "validation.nonce_ttl_secs.range"))]
+    pub nonce_ttl_secs: Option<i64>,
+    /** 允许调用的客户端IP网段（CIDR格式，单个地址可省略前缀长度），为空时不限制 */
+    #[serde(default)]
+    #[validate(
+        length(max = 50, message = "validation.allowed_ips.max_length"),
+        custom(function = "validate_allowed_ips", message = "validation.allowed_ips.format")
+    )]
+    pub allowed_ips: Vec<String>,
 }
 
 /**
  * 访问密钥创建输入类型别名
  */
 pub type CreateAccessKeyInput = AccessKeyInput;
+
+/**
+ * 访问密钥更新输入参数
+ * 
+ * 用于更新访问密钥的状态、描述和IP白名单，密钥本身不可修改。
+ */
+#[derive(Deserialize, Validate)]
+pub struct UpdateAccessKeyInput {
+    pub id: String,
+    pub status: Status,
+    #[validate(length(max = 200, message = "validation.description.max_length"))]
+    pub description: Option<String>,
+    /** 允许调用的客户端IP网段，未提交时保持不变，提交空列表时取消限制 */
+    #[validate(
+        length(max = 50, message = "validation.allowed_ips.max_length"),
+        custom(function = "validate_allowed_ips", message = "validation.allowed_ips.format")
+    )]
+    pub allowed_ips: Option<Vec<String>>,
+}
+
+/**
+ * 验证IP白名单中的每一项为合法的网段或地址
+ *
+ * 错误参数 `entry` 为第一个格式错误的项
+ */
+fn validate_allowed_ips(allowed_ips: &[String]) -> Result<(), ValidationError> {
+    match allowed_ips.iter().find(|value| parse_allowed_ip(value).is_none()) {
+        None => Ok(()),
+        Some(invalid) => {
+            let mut error = ValidationError::new("allowed_ips");
+            error.add_param(Cow::Borrowed("entry"), invalid);
+            Err(error)
+        },
+    }
+}
+
+/**
+ * 将IP白名单规范化为CIDR格式
+ *
+ * 单个地址补全为 /32 或 /128，去除重复项，格式错误的项被忽略（已由输入验证拒绝）
+ *
+ * # 参数
+ * * `allowed_ips` - 输入的IP白名单
+ */
+pub fn normalize_allowed_ips(allowed_ips: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(allowed_ips.len());
+    for net in allowed_ips.iter().filter_map(|value| parse_allowed_ip(value)) {
+        let net = net.to_string();
+        if !normalized.contains(&net) {
+            normalized.push(net);
+        }
+    }
+    normalized
+}
+
+/**
+ * 未使用访问密钥查询参数
+ * 
+ * 用于查询指定天数内未被使用的访问密钥，默认90天。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnusedAccessKeyRequest {
+    #[serde(default = "default_unused_days")]
+    pub days: u32,
+}
+
+fn default_unused_days() -> u32 {
+    90
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update_input(allowed_ips: &[&str]) -> UpdateAccessKeyInput {
+        UpdateAccessKeyInput {
+            id: "1".to_string(),
+            status: Status::Enabled,
+            description: None,
+            allowed_ips: Some(allowed_ips.iter().map(|value| value.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_allowed_ips_validation() {
+        assert!(update_input(&["203.0.113.0/24", "2001:db8::/32", "198.51.100.7"])
+            .validate()
+            .is_ok());
+        assert!(update_input(&[]).validate().is_ok());
+
+        for invalid in ["203.0.113.0/33", "2001:db8::/129", "example.com", "10.0.0.0/8/8"] {
+            let errors = update_input(&["10.0.0.0/8", invalid]).validate().unwrap_err();
+            let error = &errors.field_errors()["allowed_ips"][0];
+            assert_eq!(error.params["entry"], invalid, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_allowed_ips() {
+        let allowed_ips = ["198.51.100.7", "2001:db8::1", " 203.0.113.0/24 ", "198.51.100.7/32"]
+            .map(String::from);
+        assert_eq!(
+            normalize_allowed_ips(&allowed_ips),
+            vec!["198.51.100.7/32", "2001:db8::1/128", "203.0.113.0/24"]
+        );
+    }
+}
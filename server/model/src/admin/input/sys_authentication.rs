@@ -1,7 +1,7 @@
 /**
  * 认证/登录相关输入参数定义
  * 
- * 包含登录请求输入结构体。
+ * 包含登录请求、邀请码注册请求与切换域请求输入结构体。
  */
 
 use serde::Deserialize;
@@ -14,8 +14,37 @@ use validator::Validate;
  */
 #[derive(Deserialize, Validate)]
 pub struct LoginInput {
-    #[validate(length(min = 5, message = "Username cannot be empty"))]
+    #[validate(length(min = 5, message = "validation.username.required"))]
     pub username: String,
-    #[validate(length(min = 6, message = "Password cannot be empty"))]
+    #[validate(length(min = 6, message = "validation.password.required"))]
     pub password: String,
 }
+
+/**
+ * 自助注册输入参数
+ * 
+ * 用于凭邀请码自助注册，用户名与密码规则与后台创建用户一致。
+ */
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterInput {
+    #[validate(length(min = 1, max = 50, message = "validation.username.length"))]
+    pub username: String,
+    #[validate(length(min = 6, max = 100, message = "validation.password.length"))]
+    pub password: String,
+    #[validate(length(min = 1, max = 50, message = "validation.nick_name.length"))]
+    pub nick_name: String,
+    #[validate(length(min = 1, message = "validation.invitation_code.required"))]
+    pub invitation_code: String,
+}
+
+/**
+ * 切换域输入参数
+ * 
+ * 用于已登录用户切换当前操作的域，目标域必须是用户所属或已加入的域。
+ */
+#[derive(Deserialize, Validate)]
+pub struct SwitchDomainInput {
+    #[validate(length(min = 1, message = "validation.domain.required"))]
+    pub code: String,
+}
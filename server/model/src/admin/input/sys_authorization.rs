@@ -1,28 +1,128 @@
 /**
  * 授权相关输入参数定义
  * 
- * 包含分配权限、分配路由、分配用户等DTO。
+ * 包含分配权限、分配路由、分配用户等DTO，以及按角色预览路由的查询参数。
+ * 域代码、角色ID等使用类型化标识符，格式错误时在反序列化阶段拒绝请求。
  */
 
-use serde::{Deserialize, Serialize};
-use validator::Validate;
+use std::{borrow::Cow, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::admin::ids::{DomainCode, MenuId, RoleId, UserId};
 
 /**
  * 分配权限DTO
  * 
- * 用于为角色分配权限。
+ * 用于为角色分配权限。接口可以直接列出ID，也可以用选择器按控制器、资源和请求方法批量选择，
+ * 两者合并去重后作为角色的全部接口权限；两者不能同时为空。
  */
-#[derive(Debug, Deserialize, Serialize, Validate)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssignPermissionDto {
-    #[validate(length(min = 1, message = "domain cannot be empty"))]
-    pub domain: String,
+    pub domain: DomainCode,
+    pub role_id: RoleId,
 
-    #[validate(length(min = 1, message = "Role ID cannot be empty"))]
-    pub role_id: String,
-
-    #[validate(length(min = 1, message = "Permissions array cannot be empty"))]
+    #[serde(default)]
     pub permissions: Vec<String>,
+
+    #[serde(default)]
+    pub selectors: Vec<EndpointSelector>,
+}
+
+impl Validate for AssignPermissionDto {
+    /**
+     * 接口ID与选择器至少提供一项，且选择器不能没有任何条件
+     */
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.permissions.is_empty() && self.selectors.is_empty() {
+            let mut error = ValidationError::new("required");
+            error.message = Some(Cow::Borrowed("validation.permissions.required"));
+            errors.add("permissions", error);
+        }
+        if self.selectors.iter().any(EndpointSelector::is_empty) {
+            let mut error = ValidationError::new("empty");
+            error.message = Some(Cow::Borrowed("validation.selectors.empty"));
+            errors.add("selectors", error);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/**
+ * 接口选择器
+ * 
+ * 各字段内的值之间为“或”，字段之间为“且”，为空的字段不限制；
+ * 如 `{"controllers": ["SysUserApi"], "methods": ["GET"]}` 选择用户控制器的全部 GET 接口。
+ * 请求方法不区分大小写。
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointSelector {
+    /** 控制器名称，如 `SysUserApi` */
+    #[serde(default)]
+    pub controllers: Vec<String>,
+    /** 资源名，即路径中版本前缀后的第一段，如 `user` */
+    #[serde(default)]
+    pub resources: Vec<String>,
+    /** 请求方法，如 `GET` */
+    #[serde(default)]
+    pub methods: Vec<String>,
+}
+
+impl EndpointSelector {
+    /**
+     * 是否没有任何条件
+     * 
+     * 没有条件的选择器会选中全部接口，不允许使用
+     */
+    pub fn is_empty(&self) -> bool {
+        self.controllers.is_empty() && self.resources.is_empty() && self.methods.is_empty()
+    }
+
+    /**
+     * 接口是否符合选择器
+     * 
+     * # 参数
+     * * `controller` - 接口的控制器名称
+     * * `resource` - 接口的资源名
+     * * `method` - 接口的请求方法
+     */
+    pub fn matches(&self, controller: &str, resource: &str, method: &str) -> bool {
+        let any_of = |values: &[String], value: &str| {
+            values.is_empty() || values.iter().any(|candidate| candidate == value)
+        };
+        any_of(&self.controllers, controller)
+            && any_of(&self.resources, resource)
+            && (self.methods.is_empty()
+                || self.methods.iter().any(|candidate| candidate.eq_ignore_ascii_case(method)))
+    }
+}
+
+impl fmt::Display for EndpointSelector {
+    /**
+     * 以 `controllers=[..] resources=[..] methods=[..]` 的形式输出非空条件，用于错误消息
+     */
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            ("controllers", &self.controllers),
+            ("resources", &self.resources),
+            ("methods", &self.methods),
+        ];
+        let conditions: Vec<String> = parts
+            .iter()
+            .filter(|(_, values)| !values.is_empty())
+            .map(|(name, values)| format!("{}=[{}]", name, values.join(",")))
+            .collect();
+        write!(f, "{}", conditions.join(" "))
+    }
 }
 
 /**
@@ -33,14 +133,11 @@ pub struct AssignPermissionDto {
 #[derive(Debug, Deserialize, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AssignRouteDto {
-    #[validate(length(min = 1, message = "domain cannot be empty"))]
-    pub domain: String,
-
-    #[validate(length(min = 1, message = "Role ID cannot be empty"))]
-    pub role_id: String,
+    pub domain: DomainCode,
+    pub role_id: RoleId,
 
-    #[validate(length(min = 1, message = "Routes array cannot be empty"))]
-    pub route_ids: Vec<i32>,
+    #[validate(length(min = 1, message = "validation.routes.required"))]
+    pub route_ids: Vec<MenuId>,
 }
 
 /**
@@ -51,9 +148,77 @@ pub struct AssignRouteDto {
 #[derive(Debug, Deserialize, Serialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct AssignUserDto {
-    #[validate(length(min = 1, message = "Role ID cannot be empty"))]
-    pub role_id: String,
+    pub role_id: RoleId,
+
+    #[validate(length(min = 1, message = "validation.users.required"))]
+    pub user_ids: Vec<UserId>,
+}
+
+/**
+ * 路由预览查询参数
+ * 
+ * 用于预览拥有一组角色的用户可见的路由，`role_ids` 为逗号分隔的角色ID，
+ * 如 `?role_ids=r1,r2&domain=built-in`。
+ */
+#[derive(Debug, Deserialize, Validate)]
+pub struct PreviewRoutesRequest {
+    #[serde(deserialize_with = "deserialize_role_ids")]
+    #[validate(length(min = 1, message = "validation.roles.required"))]
+    pub role_ids: Vec<RoleId>,
+    pub domain: DomainCode,
+}
+
+/**
+ * 解析逗号分隔的角色ID，忽略空项
+ */
+fn deserialize_role_ids<'de, D>(deserializer: D) -> Result<Vec<RoleId>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| RoleId::from_str(id).map_err(de::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_permission_validation() {
+        let dto = |body: serde_json::Value| -> AssignPermissionDto {
+            let mut value = serde_json::json!({"domain": "built-in", "roleId": "r1"});
+            value.as_object_mut().unwrap().extend(body.as_object().unwrap().clone());
+            serde_json::from_value(value).unwrap()
+        };
+
+        assert!(dto(serde_json::json!({"permissions": ["e1"]})).validate().is_ok());
+        assert!(dto(serde_json::json!({"selectors": [{"resources": ["user"]}]}))
+            .validate()
+            .is_ok());
+
+        let errors = dto(serde_json::json!({})).validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("permissions"));
+        let errors = dto(serde_json::json!({"selectors": [{"controllers": []}]}))
+            .validate()
+            .unwrap_err();
+        assert!(errors.field_errors().contains_key("selectors"));
+    }
 
-    #[validate(length(min = 1, message = "Users array cannot be empty"))]
-    pub user_ids: Vec<String>,
+    #[test]
+    fn test_endpoint_selector_matches() {
+        let selector = EndpointSelector {
+            controllers: vec!["SysUserApi".to_string(), "SysRoleApi".to_string()],
+            resources: vec![],
+            methods: vec!["get".to_string()],
+        };
+        assert!(selector.matches("SysUserApi", "user", "GET"));
+        assert!(selector.matches("SysRoleApi", "role", "GET"));
+        assert!(!selector.matches("SysUserApi", "user", "POST"));
+        assert!(!selector.matches("SysMenuApi", "menu", "GET"));
+        assert_eq!(selector.to_string(), "controllers=[SysUserApi,SysRoleApi] methods=[get]");
+    }
 }
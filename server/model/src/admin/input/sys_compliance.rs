@@ -0,0 +1,25 @@
+/**
+ * 合规审计相关输入参数定义
+ * 
+ * 包含合规审计导出请求结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 合规审计导出输入参数
+ * 
+ * 导出指定用户在 `[start_time, end_time)` 内的审计数据，时间格式为 `2024-01-01T00:00:00`。
+ */
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplianceExportInput {
+    #[validate(length(min = 1, message = "validation.user_id.required"))]
+    pub user_id: String,
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub start_time: NaiveDateTime,
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub end_time: NaiveDateTime,
+}
@@ -5,7 +5,7 @@
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 use validator::Validate;
 
 /**
@@ -17,6 +17,7 @@ use validator::Validate;
 pub struct DomainPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
 }
 
@@ -27,19 +28,11 @@ pub struct DomainPageRequest {
  */
 #[derive(Deserialize, Validate)]
 pub struct DomainInput {
-    #[validate(length(
-        min = 1,
-        max = 50,
-        message = "Code must be between 1 and 50 characters"
-    ))]
+    #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
     pub code: String,
-    #[validate(length(
-        min = 1,
-        max = 100,
-        message = "Name must be between 1 and 100 characters"
-    ))]
+    #[validate(length(min = 1, max = 100, message = "validation.name.length"))]
     pub name: String,
-    #[validate(length(max = 500, message = "Description must not exceed 500 characters"))]
+    #[validate(length(max = 500, message = "validation.description.max_length"))]
     pub description: Option<String>,
 }
 
@@ -59,3 +52,37 @@ pub struct UpdateDomainInput {
     #[serde(flatten)]
     pub domain: DomainInput,
 }
+
+/**
+ * 域初始化模板
+ * 
+ * 决定新域从哪些角色复制角色、菜单和接口权限。
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DomainTemplate {
+    /** 复制内置域的管理员和普通用户角色，管理员角色分配给新域的管理员 */
+    #[default]
+    Default,
+}
+
+/**
+ * 域开通输入参数
+ * 
+ * 用于一次性创建域、角色、菜单分配、管理员用户和权限策略。
+ */
+#[derive(Deserialize, Validate)]
+pub struct ProvisionDomainInput {
+    #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 100, message = "validation.name.length"))]
+    pub name: String,
+    #[validate(length(max = 500, message = "validation.description.max_length"))]
+    pub description: Option<String>,
+    #[validate(length(min = 1, max = 50, message = "validation.username.length"))]
+    pub admin_username: String,
+    #[validate(length(min = 6, max = 100, message = "validation.password.length"))]
+    pub admin_password: String,
+    #[serde(default)]
+    pub template: DomainTemplate,
+}
@@ -0,0 +1,20 @@
+/**
+ * 域功能开关相关输入参数定义
+ * 
+ * 包含域功能开关的设置输入结构体。
+ */
+
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 域功能开关设置输入参数
+ * 
+ * 用于创建或更新指定域下某个功能的开关，记录不存在时创建。
+ */
+#[derive(Deserialize, Validate)]
+pub struct UpsertDomainFeatureInput {
+    #[validate(length(min = 1, max = 64, message = "validation.feature_key.length"))]
+    pub feature_key: String,
+    pub enabled: bool,
+}
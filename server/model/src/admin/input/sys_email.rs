@@ -0,0 +1,17 @@
+/**
+ * 邮件相关输入参数定义
+ * 
+ * 包含测试邮件发送的输入结构体。
+ */
+
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 测试邮件发送输入参数
+ */
+#[derive(Deserialize, Validate)]
+pub struct SendTestEmailInput {
+    #[validate(email(message = "validation.email.format"))]
+    pub to: String,
+}
@@ -1,11 +1,11 @@
 /**
  * 接口相关输入参数定义
  * 
- * 包含接口分页请求结构体。
+ * 包含接口分页请求和接口树查询请求结构体。
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 
 /**
  * 接口分页请求参数
@@ -16,5 +16,35 @@ use server_core::web::page::PageRequest;
 pub struct EndpointPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
 }
+
+/**
+ * 接口树查询参数
+ * 
+ * 同时指定角色ID和域代码时，接口树节点会标记该角色在该域下是否已被授权。
+ */
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EndpointTreeRequest {
+    /** 角色ID */
+    pub role_id: Option<String>,
+    /** 域代码 */
+    pub domain: Option<String>,
+    /** 分组方式，默认按控制器分组 */
+    #[serde(default)]
+    pub group_by: EndpointGroupBy,
+}
+
+/**
+ * 接口树分组方式
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointGroupBy {
+    /** 按控制器分组 */
+    #[default]
+    Controller,
+    /** 按资源分组，资源下再按控制器分组 */
+    Resource,
+}
@@ -0,0 +1,40 @@
+/**
+ * 邀请码相关输入参数定义
+ * 
+ * 包含邀请码分页请求、创建输入结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+/**
+ * 邀请码分页请求参数
+ * 
+ * 用于分页查询邀请码，可按域过滤。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InvitationPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub domain: Option<String>,
+}
+
+/**
+ * 邀请码创建输入参数
+ * 
+ * 指定注册用户所属的域、授予的角色、可使用次数及过期时间，邀请码本身由服务端生成。
+ */
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInvitationInput {
+    #[validate(length(min = 1, message = "validation.domain.required"))]
+    pub domain: String,
+    #[validate(length(min = 1, message = "validation.role_id.required"))]
+    pub role_id: String,
+    #[validate(range(min = 1, max = 10000, message = "validation.max_uses.range"))]
+    pub max_uses: i32,
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub expires_at: NaiveDateTime,
+}
@@ -5,7 +5,7 @@
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 
 /**
  * 登录日志分页请求参数
@@ -16,5 +16,16 @@ use server_core::web::page::PageRequest;
 pub struct LoginLogPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
 }
+
+/**
+ * 登录历史查询参数
+ * 
+ * 用于查询当前用户本人最近的登录记录，默认20条，最多100条。
+ */
+#[derive(Debug, Deserialize)]
+pub struct LoginHistoryRequest {
+    pub limit: Option<u64>,
+}
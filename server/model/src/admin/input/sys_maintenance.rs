@@ -0,0 +1,22 @@
+/**
+ * 维护模式相关输入参数定义
+ * 
+ * 包含维护模式开关的设置输入结构体。
+ */
+
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 维护模式设置输入参数
+ * 
+ * 开启后拒绝写操作请求，`allow_roles` 中的角色不受限制。
+ */
+#[derive(Deserialize, Validate)]
+pub struct UpdateMaintenanceInput {
+    pub enabled: bool,
+    #[validate(length(max = 200, message = "validation.message.max_length"))]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub allow_roles: Vec<String>,
+}
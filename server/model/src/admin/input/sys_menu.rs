@@ -4,9 +4,11 @@
  * 包含菜单分页、创建、更新等输入结构体。
  */
 
+use std::{borrow::Cow, collections::BTreeSet, sync::OnceLock};
+
 use serde::{Deserialize, Serialize};
-use validator::Validate;
-use server_core::web::page::PageRequest;
+use validator::{Validate, ValidateArgs, ValidateUrl, ValidationError, ValidationErrors};
+use server_core::web::page::{deserialize_keywords, PageRequest};
 use crate::admin::entities::sea_orm_active_enums::{Status, MenuType};
 
 /**
@@ -18,7 +20,7 @@ use crate::admin::entities::sea_orm_active_enums::{Status, MenuType};
 pub struct MenuPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
     #[serde(default)]
     pub status: Option<Status>,
@@ -27,47 +29,289 @@ pub struct MenuPageRequest {
 /**
  * 菜单输入参数
  * 
- * 用于创建和更新菜单。
+ * 用于创建和更新菜单。除字段长度外还检查：
+ * - `route_path` 以 `/` 开头，各段只包含URL安全字符或 `:参数`
+ * - `path_param` 填写时与 `route_path` 中的参数一致
+ * - `component` 的各部分（以 `$` 分隔）符合 `MenuRules` 中的组件模式
+ * - `href` 填写时为 http/https 绝对地址
  */
 #[derive(Debug, Serialize, Deserialize, Validate)]
+#[validate(context = MenuRules)]
 pub struct MenuInput {
-    #[validate(length(min = 1, max = 50, message = "Menu name must be between 1 and 50 characters"))]
+    #[validate(length(min = 1, max = 50, message = "validation.menu_name.length"))]
     pub menu_name: String,
     
-    #[validate(length(max = 100, message = "Icon must not exceed 100 characters"))]
+    #[validate(length(max = 100, message = "validation.icon.max_length"))]
     pub icon: Option<String>,
     
-    #[validate(length(min = 1, max = 100, message = "Route name must be between 1 and 100 characters"))]
+    #[validate(length(min = 1, max = 100, message = "validation.route_name.length"))]
     pub route_name: String,
     
-    #[validate(length(min = 1, max = 200, message = "Route path must be between 1 and 200 characters"))]
+    #[validate(
+        length(min = 1, max = 200, message = "validation.route_path.length"),
+        custom(function = "validate_route_path", message = "validation.route_path.format")
+    )]
     pub route_path: String,
     
-    #[validate(length(max = 200, message = "Component must not exceed 200 characters"))]
+    #[validate(
+        length(max = 200, message = "validation.component.max_length"),
+        custom(
+            function = "validate_component",
+            use_context,
+            message = "validation.component.not_allowed"
+        )
+    )]
     pub component: String,
     
-    #[validate(range(min = 0, max = 999, message = "Icon type must be between 0 and 999"))]
+    #[validate(range(min = 0, max = 999, message = "validation.icon_type.range"))]
     pub icon_type: Option<i32>,
     
-    #[validate(length(max = 200, message = "Path parameter must not exceed 200 characters"))]
+    #[validate(length(max = 200, message = "validation.path_param.max_length"))]
     pub path_param: Option<String>,
     
-    #[validate(length(max = 200, message = "Active menu must not exceed 200 characters"))]
+    #[validate(length(max = 200, message = "validation.active_menu.max_length"))]
     pub active_menu: Option<String>,
     
     pub pid: String,
-    #[validate(range(min = 0, max = 999, message = "Sequence must be between 0 and 999"))]
+    #[validate(range(min = 0, max = 999, message = "validation.sequence.range"))]
     pub sequence: i32,
     pub status: Status,
     pub menu_type: MenuType,
     pub hide_in_menu: Option<bool>,
-    #[validate(length(max = 100, message = "I18n key must not exceed 100 characters"))]
+    #[validate(length(max = 100, message = "validation.i18n_key.max_length"))]
     pub i18n_key: Option<String>,
     pub keep_alive: Option<bool>,
     pub constant: bool,
-    #[validate(length(max = 200, message = "Href must not exceed 200 characters"))]
+    #[validate(
+        length(max = 200, message = "validation.href.max_length"),
+        custom(function = "validate_href", message = "validation.href.format")
+    )]
     pub href: Option<String>,
     pub multi_tab: Option<bool>,
+    /** 所属域代码，为空时所有域共用 */
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50, message = "validation.domain.length"))]
+    pub domain: Option<String>,
+}
+
+impl Validate for MenuInput {
+    /**
+     * 按 `MenuRules::current` 验证字段，并检查 `path_param` 与路由路径中的参数是否一致
+     */
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = match self.validate_with_args(MenuRules::current()) {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        let route_path_valid = !errors.field_errors().contains_key("route_path");
+        if let (true, Some(path_param)) = (route_path_valid, self.path_param.as_deref()) {
+            if !path_params_match(&self.route_path, path_param) {
+                let mut error = ValidationError::new("path_param");
+                error.message = Some(Cow::Borrowed("validation.path_param.mismatch"));
+                error.add_param(Cow::Borrowed("value"), &path_param);
+                errors.add("path_param", error);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/** 默认允许的菜单组件模式 */
+const DEFAULT_COMPONENT_PATTERNS: [&str; 2] = ["layout.*", "view.*"];
+
+/** 服务启动时设置的菜单验证规则 */
+static MENU_RULES: OnceLock<MenuRules> = OnceLock::new();
+
+/**
+ * 菜单验证规则
+ * 
+ * 组件模式中的 `*` 匹配任意字符，其余字符按原样匹配，如 `view.*` 匹配 `view.manage_user`。
+ */
+#[derive(Debug, Clone)]
+pub struct MenuRules {
+    component_patterns: Vec<String>,
+}
+
+impl Default for MenuRules {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPONENT_PATTERNS.iter().map(|pattern| pattern.to_string()).collect())
+    }
+}
+
+impl MenuRules {
+    /**
+     * 创建菜单验证规则
+     * 
+     * # 参数
+     * * `component_patterns` - 允许的组件模式，为空时使用默认的 `layout.*` 和 `view.*`
+     */
+    pub fn new(component_patterns: Vec<String>) -> Self {
+        if component_patterns.is_empty() {
+            return Self::default();
+        }
+        Self { component_patterns }
+    }
+
+    /**
+     * 设置全局的菜单验证规则
+     * 
+     * 应在服务启动时、处理任何请求之前调用一次，未设置时使用默认规则
+     */
+    pub fn init(self) {
+        let _ = MENU_RULES.set(self);
+    }
+
+    /**
+     * 获取全局的菜单验证规则
+     */
+    pub fn current() -> &'static MenuRules {
+        MENU_RULES.get_or_init(MenuRules::default)
+    }
+
+    /**
+     * 组件是否允许使用
+     * 
+     * 组件为空时允许；`layout.base$view.home` 这类组合组件的每一部分都需匹配某个模式
+     * 
+     * # 参数
+     * * `component` - 组件标识
+     */
+    pub fn allows_component(&self, component: &str) -> bool {
+        component.is_empty()
+            || component.split('$').all(|part| {
+                self.component_patterns.iter().any(|pattern| wildcard_match(pattern, part))
+            })
+    }
+}
+
+/**
+ * 按 `*` 通配符匹配
+ */
+fn wildcard_match(pattern: &str, value: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == value,
+        Some((prefix, rest)) => {
+            let Some(value) = value.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=value.len())
+                .filter(|&index| value.is_char_boundary(index))
+                .any(|index| wildcard_match(rest, &value[index..]))
+        },
+    }
+}
+
+/**
+ * 去掉路由路径末尾的 `/`，根路径 `/` 保持不变
+ * 
+ * # 参数
+ * * `route_path` - 路由路径
+ */
+pub fn normalize_route_path(route_path: &str) -> String {
+    let trimmed = route_path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/**
+ * 路由路径中的参数名
+ * 
+ * 参数段形如 `:id`、`:id?` 或 `:module(pwd-login|code-login)?`；路径格式不正确时返回None
+ */
+fn route_params(route_path: &str) -> Option<Vec<&str>> {
+    let rest = route_path.strip_prefix('/')?;
+    let rest = rest.strip_suffix('/').unwrap_or(rest);
+    if rest.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut params = Vec::new();
+    for segment in rest.split('/') {
+        match segment.strip_prefix(':') {
+            Some(param) => params.push(parse_param(param)?),
+            None if !segment.is_empty() && segment.chars().all(is_path_char) => {},
+            None => return None,
+        }
+    }
+    Some(params)
+}
+
+/**
+ * 解析参数段，返回参数名
+ */
+fn parse_param(segment: &str) -> Option<&str> {
+    let segment = segment.strip_suffix(['?', '*', '+']).unwrap_or(segment);
+    let (name, constraint) = match segment.split_once('(') {
+        Some((name, constraint)) => (name, Some(constraint.strip_suffix(')')?)),
+        None => (segment, None),
+    };
+
+    let name_valid =
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    let constraint_valid = constraint.is_none_or(|constraint| {
+        !constraint.is_empty() && constraint.chars().all(|c| is_path_char(c) || c == '|')
+    });
+    (name_valid && constraint_valid).then_some(name)
+}
+
+/**
+ * 路径段中允许的字符
+ */
+fn is_path_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~')
+}
+
+/**
+ * `path_param` 与路由路径中的参数是否一致
+ * 
+ * `path_param` 为逗号分隔的参数名，可带 `:` 前缀，为空时要求路由路径没有参数
+ */
+fn path_params_match(route_path: &str, path_param: &str) -> bool {
+    let Some(route_params) = route_params(route_path) else {
+        return false;
+    };
+    let declared: BTreeSet<&str> = path_param
+        .split(',')
+        .map(|name| name.trim().trim_start_matches(':'))
+        .filter(|name| !name.is_empty())
+        .collect();
+    declared == route_params.into_iter().collect()
+}
+
+/** 验证路由路径格式 */
+fn validate_route_path(route_path: &str) -> Result<(), ValidationError> {
+    match route_params(route_path) {
+        Some(_) => Ok(()),
+        None => Err(ValidationError::new("route_path")),
+    }
+}
+
+/** 验证组件是否在允许的模式内 */
+fn validate_component(component: &str, rules: &MenuRules) -> Result<(), ValidationError> {
+    if rules.allows_component(component) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("component"))
+    }
+}
+
+/** 验证外链地址为 http/https 绝对地址 */
+fn validate_href(href: &str) -> Result<(), ValidationError> {
+    let absolute = href.starts_with("https://") || href.starts_with("http://");
+    if href.is_empty() || (absolute && href.validate_url()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("href"))
+    }
 }
 
 /**
@@ -81,12 +325,158 @@ pub type CreateMenuInput = MenuInput;
  * 
  * 用于更新菜单。
  */
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct UpdateMenuInput {
     pub id: i32,
     
     #[serde(flatten)]
-    #[validate(nested)]
     pub menu: MenuInput,
 }
+
+impl Validate for UpdateMenuInput {
+    /**
+     * 菜单字段展开在请求体顶层，验证错误同样按字段名返回
+     */
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.menu.validate()
+    }
+}
+
+/**
+ * 删除菜单请求参数
+ * 
+ * `cascade` 为 true 时级联删除子孙菜单及其角色菜单关联。
+ */
+#[derive(Debug, Default, Deserialize)]
+pub struct MenuDeleteRequest {
+    #[serde(default)]
+    pub cascade: bool,
+}
+
+/**
+ * 常量路由查询参数
+ * 
+ * 指定 `domain` 时同时返回该域的常量路由。
+ */
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct ConstantRoutesQuery {
+    #[validate(length(min = 1, max = 50, message = "validation.domain.length"))]
+    pub domain: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menu_input() -> MenuInput {
+        MenuInput {
+            menu_name: "user".to_string(),
+            icon: None,
+            route_name: "manage_user".to_string(),
+            route_path: "/manage/user".to_string(),
+            component: "view.manage_user".to_string(),
+            icon_type: None,
+            path_param: None,
+            active_menu: None,
+            pid: "0".to_string(),
+            sequence: 1,
+            status: Status::Enabled,
+            menu_type: MenuType::Menu,
+            hide_in_menu: None,
+            i18n_key: None,
+            keep_alive: None,
+            constant: false,
+            href: None,
+            multi_tab: None,
+            domain: None,
+        }
+    }
+
+    fn error_codes(errors: &ValidationErrors, field: &str) -> Vec<String> {
+        errors.field_errors()[field].iter().map(|error| error.code.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_menus_pass() {
+        assert!(menu_input().validate().is_ok());
+
+        let mut input = menu_input();
+        input.route_path = "/login/:module(pwd-login|code-login)?".to_string();
+        input.component = "layout.blank$view.login".to_string();
+        input.path_param = Some(":module".to_string());
+        input.href = Some("https://github.com/ya-team".to_string());
+        assert!(input.validate().is_ok());
+
+        let mut input = menu_input();
+        input.component = String::new();
+        input.route_path = "/".to_string();
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_route_path_rejected() {
+        for route_path in ["manage/user", "/manage//user", "/manage/user list", "/manage/:"] {
+            let mut input = menu_input();
+            input.route_path = route_path.to_string();
+            let errors = input.validate().unwrap_err();
+            assert_eq!(error_codes(&errors, "route_path"), vec!["route_path"], "{route_path}");
+        }
+    }
+
+    #[test]
+    fn test_path_param_mismatch_rejected() {
+        let mut input = menu_input();
+        input.route_path = "/manage/user-detail/:id".to_string();
+        input.path_param = Some("id".to_string());
+        assert!(input.validate().is_ok());
+
+        input.path_param = Some("uid".to_string());
+        let errors = input.validate().unwrap_err();
+        assert_eq!(error_codes(&errors, "path_param"), vec!["path_param"]);
+
+        input.route_path = "/manage/user-detail".to_string();
+        input.path_param = Some(String::new());
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_component_outside_patterns_rejected() {
+        let mut input = menu_input();
+        input.component = "layout.base$page.home".to_string();
+        let errors = input.validate().unwrap_err();
+        assert_eq!(error_codes(&errors, "component"), vec!["component"]);
+
+        let rules = MenuRules::new(vec!["page.*".to_string(), "layout.*".to_string()]);
+        assert!(input.validate_with_args(&rules).is_ok());
+        input.component = "view.home".to_string();
+        assert!(input.validate_with_args(&rules).is_err());
+    }
+
+    #[test]
+    fn test_invalid_href_rejected() {
+        for href in ["/relative/path", "javascript:alert(1)", "ftp://example.com", "https://"] {
+            let mut input = menu_input();
+            input.href = Some(href.to_string());
+            let errors = input.validate().unwrap_err();
+            assert_eq!(error_codes(&errors, "href"), vec!["href"], "{href}");
+        }
+    }
+
+    #[test]
+    fn test_update_input_reports_top_level_fields() {
+        let input = UpdateMenuInput {
+            id: 1,
+            menu: MenuInput { route_path: "user".to_string(), ..menu_input() },
+        };
+        let errors = input.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("route_path"));
+    }
+
+    #[test]
+    fn test_normalize_route_path() {
+        assert_eq!(normalize_route_path("/manage/user/"), "/manage/user");
+        assert_eq!(normalize_route_path("/manage/user"), "/manage/user");
+        assert_eq!(normalize_route_path("/"), "/");
+    }
+}
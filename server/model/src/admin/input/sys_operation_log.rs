@@ -1,20 +1,93 @@
 /**
  * 操作日志相关输入参数定义
  * 
- * 包含操作日志分页请求结构体。
+ * 包含操作日志分页请求、游标分页请求和清理请求结构体。
  */
 
+use std::ops::Range;
+
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 
 /**
  * 操作日志分页请求参数
  * 
- * 用于分页查询操作日志。
+ * 用于分页查询操作日志，status_class 按响应状态码分类过滤。
  */
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OperationLogPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
+    pub status_class: Option<StatusClass>,
+}
+
+/**
+ * 操作日志游标分页请求参数
+ * 
+ * 用于基于游标的操作日志查询，适合导出和滚动加载。
+ * after 的格式为 `<created_at>,<id>`，取自上一页返回的 next_cursor。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OperationLogCursorRequest {
+    #[serde(default = "default_cursor_limit")]
+    pub limit: u64,
+    pub after: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
+    pub keywords: Option<String>,
+    pub status_class: Option<StatusClass>,
+}
+
+/**
+ * 响应状态码分类
+ * 
+ * 未记录状态码的历史日志不属于任何分类。
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusClass {
+    /** 成功，2xx */
+    #[serde(rename = "2xx")]
+    Success,
+    /** 客户端错误，4xx */
+    #[serde(rename = "4xx")]
+    ClientError,
+    /** 服务端错误，5xx */
+    #[serde(rename = "5xx")]
+    ServerError,
+}
+
+impl StatusClass {
+    /**
+     * 分类对应的状态码范围
+     */
+    pub fn status_range(self) -> Range<i32> {
+        match self {
+            StatusClass::Success => 200..300,
+            StatusClass::ClientError => 400..500,
+            StatusClass::ServerError => 500..600,
+        }
+    }
+}
+
+/**
+ * 默认游标分页大小
+ */
+fn default_cursor_limit() -> u64 {
+    100
+}
+
+/**
+ * 操作日志清理请求参数
+ * 
+ * 删除 before 之前创建的操作日志，before 格式为 `2024-01-01T00:00:00`。
+ * dry_run 为 true 时只返回将被删除的记录数，不执行删除。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OperationLogPurgeRequest {
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub before: NaiveDateTime,
+    #[serde(default)]
+    pub dry_run: bool,
 }
@@ -1,11 +1,14 @@
 /**
  * 组织相关输入参数定义
  * 
- * 包含组织分页请求结构体。
+ * 包含组织分页、创建、更新等输入结构体。
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::Status;
 
 /**
  * 组织分页请求参数
@@ -16,5 +19,65 @@ use server_core::web::page::PageRequest;
 pub struct OrganizationPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
 }
+
+/**
+ * 组织创建/更新输入参数
+ * 
+ * `pid` 为上级组织ID，顶级组织为 `0`。
+ */
+#[derive(Deserialize, Validate)]
+pub struct OrganizationInput {
+    #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 100, message = "validation.name.length"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "validation.parent_id.required"))]
+    pub pid: String,
+    pub status: Status,
+    #[validate(length(max = 500, message = "validation.description.max_length"))]
+    pub description: Option<String>,
+}
+
+/**
+ * 组织创建输入类型别名
+ */
+pub type CreateOrganizationInput = OrganizationInput;
+
+/**
+ * 组织更新输入参数
+ * 
+ * 用于更新组织。
+ */
+#[derive(Deserialize, Validate)]
+pub struct UpdateOrganizationInput {
+    pub id: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub organization: OrganizationInput,
+}
+
+/**
+ * 组织用户转移输入参数
+ * 
+ * 将组织下的全部用户转移到目标组织。
+ */
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferOrganizationUsersInput {
+    #[validate(length(min = 1, message = "validation.organization_id.required"))]
+    pub target_organization_id: String,
+}
+
+/**
+ * 删除组织请求参数
+ * 
+ * 指定 `transfer_to` 时先将组织下的用户转移到该组织再删除。
+ */
+#[derive(Debug, Default, Deserialize)]
+pub struct OrganizationDeleteRequest {
+    #[serde(default)]
+    pub transfer_to: Option<String>,
+}
@@ -0,0 +1,28 @@
+/**
+ * 个人访问令牌相关输入参数定义
+ * 
+ * 包含用户创建本人个人访问令牌的输入结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::admin::output::EndpointDescriptor;
+
+/**
+ * 个人访问令牌创建输入参数
+ * 
+ * 令牌的权限范围以接口路径和请求方法列出，只能是当前用户已有权限的子集；
+ * 不指定过期时间时令牌长期有效，直到被撤销。
+ */
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonalTokenInput {
+    #[validate(length(min = 1, max = 64, message = "validation.name.length"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "validation.scopes.required"))]
+    pub scopes: Vec<EndpointDescriptor>,
+    #[serde(default, with = "crate::admin::timestamps::utc::option")]
+    pub expires_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,34 @@
+/**
+ * 策略查看相关输入参数定义
+ * 
+ * 包含策略列表查询和权限判定试算的输入结构体。
+ */
+
+use serde::Deserialize;
+
+/**
+ * 策略列表查询参数
+ * 
+ * `filter` 为逗号分隔的按位置过滤值，从第一个字段开始匹配，空值表示该位置不过滤。
+ * 例如 `ptype=p&filter=,built-in` 查询域为 `built-in` 的所有策略。
+ */
+#[derive(Debug, Deserialize)]
+pub struct PolicyListRequest {
+    /** 策略类型，默认为 `p` */
+    pub ptype: Option<String>,
+    /** 按位置过滤的值 */
+    pub filter: Option<String>,
+}
+
+/**
+ * 权限判定试算参数
+ * 
+ * 与Casbin请求定义 `r = sub, dom, obj, act` 一一对应。
+ */
+#[derive(Debug, Deserialize)]
+pub struct PolicyCheckRequest {
+    pub sub: String,
+    pub dom: String,
+    pub obj: String,
+    pub act: String,
+}
@@ -6,13 +6,16 @@
  * - 角色列表分页查询
  * - 创建新角色
  * - 更新现有角色
+ * - 从模板创建角色
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 use validator::Validate;
 
-use crate::admin::entities::sea_orm_active_enums::Status;
+use crate::admin::{
+    entities::sea_orm_active_enums::Status, ids::DomainCode, output::RolePermissionDocument,
+};
 
 /**
  * 角色分页请求参数
@@ -26,12 +29,25 @@ use crate::admin::entities::sea_orm_active_enums::Status;
 pub struct RolePageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
     #[serde(default)]
     pub status: Option<Status>,
 }
 
+/**
+ * 角色用户分页请求参数
+ * 
+ * 用于分页查询分配了某个角色的用户，关键字匹配用户名和昵称。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleUserPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
+    pub keywords: Option<String>,
+}
+
 /**
  * 角色创建/更新输入参数
  * 
@@ -48,23 +64,15 @@ pub struct RoleInput {
     /** 父级角色ID */
     pub pid: String,
     /** 角色编码，1-50个字符 */
-    #[validate(length(
-        min = 1,
-        max = 50,
-        message = "Code must be between 1 and 50 characters"
-    ))]
+    #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
     pub code: String,
     /** 角色名称，1-50个字符 */
-    #[validate(length(
-        min = 1,
-        max = 50,
-        message = "Name must be between 1 and 50 characters"
-    ))]
+    #[validate(length(min = 1, max = 50, message = "validation.name.length"))]
     pub name: String,
     /** 角色状态 */
     pub status: Status,
     /** 角色描述，最多200个字符 */
-    #[validate(length(max = 200, message = "Description must not exceed 200 characters"))]
+    #[validate(length(max = 200, message = "validation.description.max_length"))]
     pub description: Option<String>,
 }
 
@@ -91,3 +99,38 @@ pub struct UpdateRoleInput {
     #[serde(flatten)]
     pub role: RoleInput,
 }
+
+/**
+ * 角色权限导入输入参数
+ * 
+ * 按文档中的描述在当前环境中查找接口和菜单，`dry_run` 为 true 时只返回计划的变更。
+ */
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRolePermissionInput {
+    /** 角色权限文档 */
+    pub document: RolePermissionDocument,
+    /** 是否只预览变更 */
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/**
+ * 从模板创建角色输入参数
+ * 
+ * 在指定域中创建角色并授予模板中的权限。
+ */
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRoleFromTemplateInput {
+    /** 角色模板ID */
+    pub template_id: String,
+    /** 角色所属域 */
+    pub domain_code: DomainCode,
+    /** 角色编码，1-50个字符 */
+    #[validate(length(min = 1, max = 50, message = "validation.code.length"))]
+    pub role_code: String,
+    /** 角色名称，1-50个字符 */
+    #[validate(length(min = 1, max = 50, message = "validation.name.length"))]
+    pub role_name: String,
+}
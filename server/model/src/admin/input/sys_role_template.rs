@@ -0,0 +1,46 @@
+/**
+ * 角色模板相关输入参数定义
+ * 
+ * 包含角色模板的创建、更新输入结构体。
+ */
+
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::admin::output::RoleTemplatePermissions;
+
+/**
+ * 角色模板创建/更新输入参数
+ * 
+ * 权限按接口路径、请求方法和菜单路由名称描述，保存时不要求条目在当前环境中存在。
+ */
+#[derive(Debug, Deserialize, Validate)]
+pub struct RoleTemplateInput {
+    /** 模板名称，1-50个字符 */
+    #[validate(length(min = 1, max = 50, message = "validation.name.length"))]
+    pub name: String,
+    /** 模板描述，最多200个字符 */
+    #[validate(length(max = 200, message = "validation.description.max_length"))]
+    pub description: Option<String>,
+    /** 模板包含的权限 */
+    #[serde(default)]
+    pub permissions: RoleTemplatePermissions,
+}
+
+/**
+ * 角色模板创建输入类型别名
+ */
+pub type CreateRoleTemplateInput = RoleTemplateInput;
+
+/**
+ * 角色模板更新输入参数
+ */
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateRoleTemplateInput {
+    /** 模板ID */
+    pub id: String,
+    /** 模板详细信息 */
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub template: RoleTemplateInput,
+}
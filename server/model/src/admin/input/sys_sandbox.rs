@@ -0,0 +1,27 @@
+/**
+ * 沙箱相关输入参数定义
+ * 
+ * 包含签名调试请求结构体。
+ */
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 签名调试输入参数
+ * 
+ * 用于计算复杂API密钥请求的规范签名字符串和期望签名。
+ * access_key、timestamp、nonce 会按服务端配置的参数名加入签名参数。
+ */
+#[derive(Debug, Deserialize, Validate)]
+pub struct SignDebugInput {
+    #[validate(length(min = 1, message = "validation.access_key.required"))]
+    pub access_key: String,
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    pub timestamp: i64,
+    #[validate(length(min = 1, message = "validation.nonce.required"))]
+    pub nonce: String,
+}
@@ -0,0 +1,16 @@
+/**
+ * 统计相关输入参数定义
+ * 
+ * 包含统计概览查询请求结构体。
+ */
+
+use serde::Deserialize;
+
+/**
+ * 统计概览查询参数
+ */
+#[derive(Debug, Default, Deserialize)]
+pub struct StatisticsOverviewRequest {
+    /** 统计的域代码，为空时统计当前用户所属域；只有内置域用户可以查询其他域 */
+    pub domain: Option<String>,
+}
@@ -0,0 +1,20 @@
+/**
+ * 对象存储相关输入参数定义
+ * 
+ * 包含预签名文件下载请求结构体。
+ */
+
+use serde::Deserialize;
+
+/**
+ * 预签名文件下载参数
+ * 
+ * 本地存储生成的预签名链接携带的查询参数
+ */
+#[derive(Debug, Deserialize)]
+pub struct PresignedFileRequest {
+    /** 链接过期时间（UNIX纪元以来的秒数） */
+    pub expires: u64,
+    /** 链接签名 */
+    pub signature: String,
+}
@@ -0,0 +1,19 @@
+/**
+ * 系统管理相关输入参数定义
+ * 
+ * 包含主库重新连接的输入结构体。
+ */
+
+use serde::Deserialize;
+use validator::Validate;
+
+/**
+ * 主库重新连接输入参数
+ * 
+ * 连接池等其他参数沿用当前主库配置。
+ */
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct DbReconnectInput {
+    /** 新的主库连接URL，为空时使用配置的 `failover_url`，未配置时重新连接当前地址 */
+    pub url: Option<String>,
+}
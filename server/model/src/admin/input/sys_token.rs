@@ -0,0 +1,21 @@
+/**
+ * 登录令牌相关输入参数定义
+ * 
+ * 包含用户本人登录令牌（登录设备）分页请求结构体。
+ */
+
+use serde::{Deserialize, Serialize};
+use server_core::web::page::{deserialize_keywords, PageRequest};
+
+/**
+ * 登录令牌分页请求参数
+ * 
+ * 用于分页查询当前用户本人的有效令牌，关键字匹配IP、地址和用户代理。
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
+    pub keywords: Option<String>,
+}
@@ -5,21 +5,32 @@
  */
 
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{deserialize_keywords, PageRequest};
 use validator::Validate;
 
-use crate::admin::entities::sea_orm_active_enums::Status;
+use crate::admin::{
+    entities::sea_orm_active_enums::Status,
+    ids::{DomainCode, UserId},
+};
 
 /**
  * 用户分页请求参数
  * 
- * 用于分页查询用户。
+ * 用于分页查询用户，各筛选条件同时生效。
+ * `include_sub_orgs` 为 true 时按 `organization_id` 及其全部下级组织筛选。
  */
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
+    #[serde(default, deserialize_with = "deserialize_keywords")]
     pub keywords: Option<String>,
+    pub role_id: Option<String>,
+    pub organization_id: Option<String>,
+    #[serde(default)]
+    pub include_sub_orgs: bool,
+    pub status: Option<Status>,
+    pub domain_code: Option<String>,
 }
 
 /**
@@ -30,29 +41,17 @@ pub struct UserPageRequest {
 #[derive(Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct UserInput {
-    pub domain: String,
-    #[validate(length(
-        min = 1,
-        max = 50,
-        message = "Username must be between 1 and 50 characters"
-    ))]
+    pub domain: DomainCode,
+    #[validate(length(min = 1, max = 50, message = "validation.username.length"))]
     pub username: String,
-    #[validate(length(
-        min = 6,
-        max = 100,
-        message = "Password must be between 6 and 100 characters"
-    ))]
+    #[validate(length(min = 6, max = 100, message = "validation.password.length"))]
     pub password: String,
-    #[validate(length(
-        min = 1,
-        max = 50,
-        message = "Nick name must be between 1 and 50 characters"
-    ))]
+    #[validate(length(min = 1, max = 50, message = "validation.nick_name.length"))]
     pub nick_name: String,
     pub avatar: Option<String>,
-    #[validate(email(message = "Invalid email format"))]
+    #[validate(email(message = "validation.email.format"))]
     pub email: Option<String>,
-    #[validate(length(max = 20, message = "Phone number must not exceed 20 characters"))]
+    #[validate(length(max = 20, message = "validation.phone_number.max_length"))]
     pub phone_number: Option<String>,
     pub status: Status,
 }
@@ -69,7 +68,7 @@ pub type CreateUserInput = UserInput;
  */
 #[derive(Deserialize, Validate)]
 pub struct UpdateUserInput {
-    pub id: String,
+    pub id: UserId,
     #[serde(flatten)]
     pub user: UserInput,
 }
@@ -1,5 +1,7 @@
 pub mod enums;
+pub mod ids;
 pub mod input;
+pub mod timestamps;
 
 pub mod entities;
 pub mod output;
@@ -5,21 +5,76 @@
  * 用于接口响应数据的结构化。
  * 
  * 主要包含：
+ * - 访问密钥相关输出（密钥脱敏）
  * - 认证相关输出（登录信息、用户信息、路由信息）
- * - 域名相关输出
- * - 接口树形结构输出
+ * - 合规审计导出任务输出
+ * - 域名相关输出（登录页域选项、域开通结果）
+ * - 接口树形结构与接口同步报告输出
+ * - 死信事件输出
+ * - 登录日志与登录历史输出
+ * - 维护模式状态输出
+ * - 就绪检查与版本信息输出
  * - 菜单相关输出（路由、树形结构、元数据）
+ * - 操作日志与操作日志清理结果输出
+ * - 组织用户转移结果输出
+ * - 带创建人、更新人用户名的实体输出
+ * - 角色相关输出（角色详情、角色下的用户、用户的角色）
+ * - 策略规则与权限判定试算结果输出
+ * - 本人登录令牌（登录设备）输出
+ * - 个人访问令牌输出
+ * - 域统计概览输出
  * - 用户相关输出（带域和组织信息、无密码信息）
  */
 
-pub use sys_authentication::{AuthOutput, UserInfoOutput, UserRoute};
-pub use sys_domain::DomainOutput;
-pub use sys_endpoint::EndpointTree;
-pub use sys_menu::{MenuRoute, MenuTree, RouteMeta};
+pub use sys_access_key::AccessKeyOutput;
+pub use sys_authentication::{
+    AssignmentResult, AuthOutput, RoutePreviewOutput, SelectorMatch, UserInfoOutput, UserRoute,
+    TOKEN_TYPE_BEARER,
+};
+pub use sys_compliance::{
+    ComplianceExportOutput, ComplianceExportStatus, ComplianceRecordCounts,
+};
+pub use sys_domain::{
+    DomainOptionOutput, DomainOutput, DomainProvisionOutput, UserDomainOutput,
+};
+pub use sys_endpoint::{EndpointTree, SyncReport};
+pub use sys_event::DeadLetterEvent;
+pub use sys_login_log::{LoginHistoryOutput, LoginLogOutput};
+pub use sys_maintenance::{MaintenanceState, DEFAULT_MAINTENANCE_MESSAGE};
+pub use sys_menu::{MenuDeleteOutput, MenuRoute, MenuTree, RouteMeta};
+pub use sys_operation_log::{OperationLogOutput, OperationLogPurgeOutput};
+pub use sys_operator::WithOperatorNames;
+pub use sys_organization::OrganizationTransferOutput;
+pub use sys_personal_token::{PersonalTokenCreatedOutput, PersonalTokenOutput};
+pub use sys_policy::{PolicyCheckOutput, PolicyRuleOutput};
+pub use sys_role::{
+    EndpointDescriptor, RoleDetailOutput, RoleFromTemplateOutput, RolePermissionDocument,
+    RolePermissionImportOutput, RoleTemplatePermissions, RoleUserOutput, UserRoleOutput,
+    ROLE_PERMISSION_DOCUMENT_VERSION,
+};
+pub use sys_sandbox::SignDebugOutput;
+pub use sys_statistics::{DailyCount, OverviewOutput, StatusCount};
+pub use sys_system::{DatabaseQueryStats, ReadinessOutput, SubsystemStatus, VersionOutput};
+pub use sys_token::TokenOutput;
 pub use sys_user::{UserWithDomainAndOrgOutput, UserWithoutPassword};
 
+mod sys_access_key;
 mod sys_authentication;
+mod sys_compliance;
 mod sys_domain;
 mod sys_endpoint;
+mod sys_event;
+mod sys_login_log;
+mod sys_maintenance;
 mod sys_menu;
+mod sys_operation_log;
+mod sys_operator;
+mod sys_organization;
+mod sys_personal_token;
+mod sys_policy;
+mod sys_role;
+mod sys_sandbox;
+mod sys_statistics;
+mod sys_system;
+mod sys_token;
 mod sys_user;
@@ -0,0 +1,115 @@
+/**
+ * 访问密钥相关输出参数定义
+ * 
+ * 包含脱敏后的访问密钥输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::admin::entities::{
+    sea_orm_active_enums::Status, sys_access_key::Model as SysAccessKeyModel,
+};
+
+/** 密钥脱敏后保留的明文字符数 */
+const VISIBLE_SECRET_CHARS: usize = 4;
+
+/**
+ * 访问密钥输出参数
+ * 
+ * 用于列表和详情接口，密钥仅保留前4位，其余以 `****` 替代。
+ * 明文密钥只在创建接口的响应中返回。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessKeyOutput {
+    /** 访问密钥记录ID */
+    pub id: String,
+    /** 所属域 */
+    pub domain: String,
+    /** 访问密钥ID */
+    pub access_key_id: String,
+    /** 脱敏后的访问密钥 */
+    pub access_key_secret: String,
+    /** 状态 */
+    pub status: Status,
+    /** 描述 */
+    pub description: Option<String>,
+    /** 创建时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub created_at: NaiveDateTime,
+    /** 创建人 */
+    pub created_by: String,
+    /** 最近使用时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub last_used_at: Option<NaiveDateTime>,
+    /** 签名验证允许的时间戳偏差（毫秒），为空时使用默认值 */
+    pub timestamp_tolerance_ms: Option<i64>,
+    /** nonce 的保留时间（秒），为空时使用默认值 */
+    pub nonce_ttl_secs: Option<i64>,
+    /** 允许调用的客户端IP网段，为空时不限制 */
+    pub allowed_ips: Vec<String>,
+}
+
+impl From<SysAccessKeyModel> for AccessKeyOutput {
+    fn from(model: SysAccessKeyModel) -> Self {
+        Self {
+            id: model.id,
+            domain: model.domain,
+            access_key_id: model.access_key_id,
+            access_key_secret: mask_secret(&model.access_key_secret),
+            status: model.status,
+            description: model.description,
+            created_at: model.created_at,
+            created_by: model.created_by,
+            last_used_at: model.last_used_at,
+            timestamp_tolerance_ms: model.timestamp_tolerance_ms,
+            nonce_ttl_secs: model.nonce_ttl_secs,
+            allowed_ips: model
+                .allowed_ips
+                .and_then(|value| serde_json::from_value(value).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/**
+ * 密钥脱敏
+ * 
+ * 保留前4个字符，其余以 `****` 替代
+ */
+fn mask_secret(secret: &str) -> String {
+    let visible: String = secret.chars().take(VISIBLE_SECRET_CHARS).collect();
+    format!("{}****", visible)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_payload_contains_no_secret() {
+        let secret = "SK01JABCDEFGHJKMNPQRSTVWXYZ";
+        let model = SysAccessKeyModel {
+            id: "1".to_string(),
+            domain: "built-in".to_string(),
+            access_key_id: "AK01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+            access_key_secret: secret.to_string(),
+            status: Status::Enabled,
+            description: None,
+            created_at: NaiveDateTime::default(),
+            created_by: "system".to_string(),
+            last_used_at: None,
+            timestamp_tolerance_ms: None,
+            nonce_ttl_secs: None,
+            allowed_ips: None,
+        };
+
+        let payload =
+            serde_json::to_string(&vec![AccessKeyOutput::from(model)]).unwrap();
+
+        assert!(!payload.contains(secret));
+        assert!(!payload.contains(&secret[VISIBLE_SECRET_CHARS..]));
+        assert!(payload.contains("\"accessKeySecret\":\"SK01****\""));
+    }
+}
@@ -7,13 +7,19 @@
 use serde::Serialize;
 
 use super::MenuRoute;
+use crate::admin::input::EndpointSelector;
+
+/** 令牌类型 */
+pub const TOKEN_TYPE_BEARER: &str = "Bearer";
 
 /**
  * 认证输出参数
  * 
  * 用于返回用户登录认证的结果信息。
+ * 有效期按签发时生效的JWT配置（包括域覆盖）返回，前端无需解析令牌即可安排刷新。
  */
 #[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthOutput {
     /** 访问令牌 */
     pub token: String,
@@ -21,6 +27,15 @@ pub struct AuthOutput {
     // pub access_token: String,
     /** 刷新令牌 */
     pub refresh_token: String,
+    /** 访问令牌有效期（秒） */
+    pub expires_in: u64,
+    /** 刷新令牌有效期（秒） */
+    pub refresh_expires_in: u64,
+    /** 令牌类型，固定为 Bearer */
+    pub token_type: String,
+    /** 访问令牌的JWT ID，用于记录登录设备，不返回给客户端 */
+    #[serde(skip)]
+    pub jti: String,
 }
 
 /**
@@ -29,15 +44,16 @@ pub struct AuthOutput {
  * 用于返回用户的基本信息。
  */
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UserInfoOutput {
     /** 用户ID */
-    #[serde(rename = "userId")]
     pub user_id: String,
     /** 用户名 */
-    #[serde(rename = "userName")]
     pub user_name: String,
     /** 用户角色列表 */
     pub roles: Vec<String>,
+    /** 按钮权限标识列表，即授予角色的按钮的路由名称 */
+    pub permissions: Vec<String>,
 }
 
 /**
@@ -45,10 +61,87 @@ pub struct UserInfoOutput {
  * 
  * 用于返回用户可访问的路由信息。
  */
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserRoute {
     /** 路由列表 */
     pub routes: Vec<MenuRoute>,
     /** 首页路由 */
     pub home: String,
 }
+
+/**
+ * 路由预览输出
+ * 
+ * 与用户登录后获取的路由结构相同，另外列出已分配给角色但未启用的菜单ID，
+ * 用于排查菜单没有出现的原因。
+ */
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutePreviewOutput {
+    /** 路由列表和首页路由 */
+    #[serde(flatten)]
+    pub route: UserRoute,
+    /** 已分配但未启用的菜单ID */
+    pub disabled_menu_ids: Vec<i32>,
+}
+
+/**
+ * 接口权限分配结果
+ * 
+ * 返回去重后实际授予角色的接口数量，以及每个选择器展开出的接口数量。
+ */
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentResult {
+    /** 授予角色的接口总数（显式ID与选择器展开结果合并去重后） */
+    pub endpoint_count: usize,
+    /** 各选择器的展开情况，顺序与请求一致 */
+    pub selectors: Vec<SelectorMatch>,
+}
+
+/**
+ * 选择器展开情况
+ */
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectorMatch {
+    /** 请求中的选择器 */
+    pub selector: EndpointSelector,
+    /** 选择器匹配的接口数量，与其他选择器重叠的接口同样计入 */
+    pub matched: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use server_core::web::naming::snake_case_keys;
+
+    use super::*;
+
+    #[test]
+    fn test_auth_output_field_naming() {
+        let output = AuthOutput {
+            token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_in: 7200,
+            refresh_expires_in: 604800,
+            token_type: TOKEN_TYPE_BEARER.to_string(),
+            jti: "01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+        };
+
+        let mut value = serde_json::to_value(&output).unwrap();
+        assert_eq!(value["token"], "access");
+        assert_eq!(value["refreshToken"], "refresh");
+        assert_eq!(value["expiresIn"], 7200);
+        assert_eq!(value["refreshExpiresIn"], 604800);
+        assert_eq!(value["tokenType"], "Bearer");
+        assert!(value.get("jti").is_none());
+
+        snake_case_keys(&mut value);
+        assert_eq!(value["token"], "access");
+        assert_eq!(value["refresh_token"], "refresh");
+        assert_eq!(value["expires_in"], 7200);
+        assert_eq!(value["refresh_expires_in"], 604800);
+        assert_eq!(value["token_type"], "Bearer");
+    }
+}
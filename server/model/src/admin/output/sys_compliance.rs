@@ -0,0 +1,74 @@
+/**
+ * 合规审计相关输出参数定义
+ * 
+ * 包含合规审计导出任务状态的输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/**
+ * 合规审计导出任务状态
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComplianceExportStatus {
+    /** 等待执行 */
+    Pending,
+    /** 正在生成导出文件 */
+    Running,
+    /** 已上传到对象存储 */
+    Completed,
+    /** 执行失败 */
+    Failed,
+}
+
+/**
+ * 合规审计导出任务
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplianceExportOutput {
+    /** 任务ID */
+    pub job_id: String,
+    /** 任务状态 */
+    pub status: ComplianceExportStatus,
+    /** 导出的用户ID */
+    pub user_id: String,
+    /** 时间范围开始（包含） */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub start_time: NaiveDateTime,
+    /** 时间范围结束（不包含） */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub end_time: NaiveDateTime,
+    /** 发起导出的用户名 */
+    pub requested_by: String,
+    /** 任务创建时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub created_at: NaiveDateTime,
+    /** 任务结束时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub finished_at: Option<NaiveDateTime>,
+    /** 各文件的记录数，任务完成后返回 */
+    pub record_counts: Option<ComplianceRecordCounts>,
+    /** 导出文件的预签名下载链接，任务完成后每次查询重新生成 */
+    pub download_url: Option<String>,
+    /** 失败原因 */
+    pub error: Option<String>,
+}
+
+/**
+ * 导出文件中各类记录的数量
+ */
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplianceRecordCounts {
+    /** 登录日志 */
+    pub login_logs: u64,
+    /** 操作日志 */
+    pub operation_logs: u64,
+    /** 安全事件 */
+    pub security_events: u64,
+    /** 登录令牌签发记录 */
+    pub tokens: u64,
+}
@@ -5,6 +5,15 @@
  */
 
 use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+use crate::admin::{
+    entities::{
+        sea_orm_active_enums::Status, sys_domain::Model as SysDomainModel,
+        sys_role::Model as SysRoleModel,
+    },
+    output::UserWithoutPassword,
+};
 
 /**
  * 域名输出参数
@@ -22,3 +31,61 @@ pub struct DomainOutput {
     /** 域名描述 */
     pub description: Option<String>,
 }
+
+/**
+ * 域选项输出参数
+ * 
+ * 用于登录页的域下拉列表，只包含公开字段。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize, FromQueryResult)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainOptionOutput {
+    /** 域名编码 */
+    pub code: String,
+    /** 域名名称 */
+    pub name: String,
+}
+
+/**
+ * 用户可操作的域输出参数
+ * 
+ * 包括用户所属的域和额外加入的域，用于切换当前操作的域。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDomainOutput {
+    /** 域名编码 */
+    pub code: String,
+    /** 域名名称 */
+    pub name: String,
+    /** 域名状态，禁用的域不能切换 */
+    pub status: Status,
+    /** 是否为用户所属的域 */
+    pub home: bool,
+    /** 是否为当前令牌所在的域 */
+    pub current: bool,
+}
+
+/**
+ * 域开通结果输出参数
+ * 
+ * 列出开通域时创建的全部资源。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainProvisionOutput {
+    /** 新建的域 */
+    pub domain: SysDomainModel,
+    /** 从模板复制的角色 */
+    pub roles: Vec<SysRoleModel>,
+    /** 管理员用户 */
+    pub admin: UserWithoutPassword,
+    /** 分配给管理员的角色代码 */
+    pub admin_role_code: String,
+    /** 分配给各角色的菜单数量 */
+    pub menu_count: usize,
+    /** 写入的接口权限策略数量 */
+    pub policy_count: usize,
+    /** 写入的分组规则数量 */
+    pub grouping_policy_count: usize,
+}
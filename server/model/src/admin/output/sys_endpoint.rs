@@ -1,10 +1,13 @@
 /**
  * 接口相关输出参数定义
  * 
- * 包含接口树形结构的输出结构体。
+ * 包含接口树形结构和接口同步预览的输出结构体。
  */
 
 use serde::Serialize;
+use server_core::web::Projectable;
+
+use crate::admin::entities::sys_endpoint::Model as SysEndpointModel;
 
 /**
  * 接口树形结构输出参数
@@ -12,6 +15,7 @@ use serde::Serialize;
  * 用于返回接口的树形结构信息。
  */
 #[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct EndpointTree {
     /** 接口ID */
     pub id: String,
@@ -27,6 +31,57 @@ pub struct EndpointTree {
     pub controller: String,
     /** 接口描述 */
     pub summary: Option<String>,
+    /** 是否已授权给查询的角色，未指定角色时为false */
+    pub checked: bool,
+    /** 分组下的接口总数，只有分组节点有 */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    /** 分组下的读接口数，只有分组节点有 */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_count: Option<usize>,
+    /** 分组下的写接口数，只有分组节点有 */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_count: Option<usize>,
     /** 子接口列表 */
     pub children: Option<Vec<EndpointTree>>,
 }
+
+impl Projectable for EndpointTree {
+    const FIELDS: &'static [&'static str] = &[
+        "id",
+        "path",
+        "method",
+        "action",
+        "resource",
+        "controller",
+        "summary",
+        "checked",
+        "total",
+        "readCount",
+        "writeCount",
+        "children",
+    ];
+}
+
+/**
+ * 接口同步报告
+ * 
+ * 收集到的路由与接口表的差异。预览时为将要执行的变更，执行同步后为已执行的变更。
+ */
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    /** 新增的接口 */
+    pub to_add: Vec<SysEndpointModel>,
+    /** 更新的接口，为更新后的内容，保留原接口ID和创建时间 */
+    pub to_update: Vec<SysEndpointModel>,
+    /** 删除的接口 */
+    pub to_delete: Vec<SysEndpointModel>,
+}
+
+impl SyncReport {
+    /** 是否没有任何变更 */
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_update.is_empty() && self.to_delete.is_empty()
+    }
+}
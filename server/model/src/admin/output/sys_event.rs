@@ -0,0 +1,28 @@
+/**
+ * 事件相关输出参数定义
+ * 
+ * 包含死信事件结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::Value;
+
+/**
+ * 死信事件
+ * 
+ * 重试后仍处理失败的事件，保留事件内容和最后一次错误，供管理员排查和补录。
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetterEvent {
+    /** 事件类型 */
+    pub event_type: String,
+    /** 事件内容，不包含令牌等敏感字段 */
+    pub payload: Value,
+    /** 最后一次处理失败的错误信息 */
+    pub error: String,
+    /** 进入死信缓冲区的时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub failed_at: NaiveDateTime,
+}
@@ -0,0 +1,167 @@
+/**
+ * 登录日志相关输出参数定义
+ * 
+ * 包含登录日志和用户本人登录历史的输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::admin::entities::sys_login_log::Model as SysLoginLogModel;
+
+/**
+ * 登录日志输出参数
+ * 
+ * 用于管理端查询登录日志，IP解析出的地址额外拆分为国家、省份、城市和运营商，
+ * 不包含请求ID、创建时间和创建人等与其他字段重复的内部字段。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginLogOutput {
+    /** 日志ID */
+    pub id: String,
+    /** 用户ID */
+    pub user_id: String,
+    /** 用户名 */
+    pub username: String,
+    /** 用户所属域 */
+    pub domain: String,
+    /** 登录时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub login_time: NaiveDateTime,
+    /** 登录IP */
+    pub ip: String,
+    /** 客户端端口 */
+    pub port: Option<i32>,
+    /** IP解析出的地址 */
+    pub address: String,
+    /** 国家，无法解析时为空 */
+    pub country: Option<String>,
+    /** 省份，无法解析时为空 */
+    pub province: Option<String>,
+    /** 城市，无法解析时为空 */
+    pub city: Option<String>,
+    /** 运营商，无法解析时为空 */
+    pub isp: Option<String>,
+    /** 用户代理 */
+    pub user_agent: String,
+    /** 登录类型 */
+    pub login_type: String,
+    /** 是否登录成功 */
+    pub success: bool,
+    /** 失败原因，登录成功时为空 */
+    pub failure_reason: Option<String>,
+}
+
+impl From<SysLoginLogModel> for LoginLogOutput {
+    fn from(model: SysLoginLogModel) -> Self {
+        let part = |index| address_part(&model.address, index);
+        let (country, province, city, isp) = (part(0), part(2), part(3), part(4));
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            username: model.username,
+            domain: model.domain,
+            login_time: model.login_time,
+            ip: model.ip,
+            port: model.port,
+            address: model.address,
+            country,
+            province,
+            city,
+            isp,
+            user_agent: model.user_agent,
+            login_type: model.r#type,
+            success: model.success,
+            failure_reason: model.failure_reason,
+        }
+    }
+}
+
+/**
+ * 取出IP解析出的地址中的一项
+ * 
+ * 地址格式为 `国家|区域|省份|城市|ISP`，未知的项为 `0`；
+ * 不是该格式的地址（如 `localhost`）各项都为None
+ */
+fn address_part(address: &str, index: usize) -> Option<String> {
+    if !address.contains('|') {
+        return None;
+    }
+    address
+        .split('|')
+        .nth(index)
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && *part != "0")
+        .map(str::to_string)
+}
+
+/**
+ * 登录历史输出参数
+ * 
+ * 用于用户查看本人最近的登录记录，不包含用户和域等内部字段。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginHistoryOutput {
+    /** 登录时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub login_time: NaiveDateTime,
+    /** 登录IP */
+    pub ip: String,
+    /** IP解析出的地址 */
+    pub address: String,
+    /** 用户代理 */
+    pub user_agent: String,
+    /** 登录类型 */
+    pub login_type: String,
+    /** 登录国家与本人常用国家不一致时为true */
+    pub suspicious: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn login_log(address: &str) -> SysLoginLogModel {
+        let now = NaiveDateTime::default();
+        SysLoginLogModel {
+            id: "1".to_string(),
+            user_id: "u1".to_string(),
+            username: "admin".to_string(),
+            domain: "built-in".to_string(),
+            login_time: now,
+            ip: "1.2.3.4".to_string(),
+            port: Some(443),
+            address: address.to_string(),
+            user_agent: "curl".to_string(),
+            request_id: "req-1".to_string(),
+            r#type: "PC".to_string(),
+            created_at: now,
+            created_by: "admin".to_string(),
+            success: true,
+            failure_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_login_log_output_resolves_address() {
+        let output = LoginLogOutput::from(login_log("中国|0|广东省|深圳市|电信"));
+        assert_eq!(output.country.as_deref(), Some("中国"));
+        assert_eq!(output.province.as_deref(), Some("广东省"));
+        assert_eq!(output.city.as_deref(), Some("深圳市"));
+        assert_eq!(output.isp.as_deref(), Some("电信"));
+        assert_eq!(output.login_type, "PC");
+
+        let output = LoginLogOutput::from(login_log("0|0|0|内网IP|内网IP"));
+        assert_eq!(output.country, None);
+        assert_eq!(output.city.as_deref(), Some("内网IP"));
+
+        let output = LoginLogOutput::from(login_log("localhost"));
+        assert_eq!((output.country, output.isp), (None, None));
+
+        let json = serde_json::to_value(LoginLogOutput::from(login_log(""))).unwrap();
+        assert_eq!(json["loginType"], "PC");
+        assert!(json.get("requestId").is_none());
+    }
+}
@@ -0,0 +1,80 @@
+/**
+ * 维护模式相关输出参数定义
+ * 
+ * 包含维护模式状态结构体，同时作为持久化到Redis的数据格式。
+ */
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/** 未设置提示信息时返回的默认提示 */
+pub const DEFAULT_MAINTENANCE_MESSAGE: &str = "System is under maintenance, please try again later";
+
+/**
+ * 维护模式状态
+ */
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceState {
+    /** 是否处于维护模式 */
+    pub enabled: bool,
+    /** 拒绝请求时返回的提示信息 */
+    pub message: Option<String>,
+    /** 维护期间允许执行写操作的角色 */
+    #[serde(default)]
+    pub allow_roles: Vec<String>,
+    /** 更新人 */
+    pub updated_by: Option<String>,
+    /** 更新时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+impl MaintenanceState {
+    /**
+     * 获取拒绝请求时的提示信息，未设置时使用默认提示
+     */
+    pub fn message(&self) -> &str {
+        self.message
+            .as_deref()
+            .filter(|message| !message.is_empty())
+            .unwrap_or(DEFAULT_MAINTENANCE_MESSAGE)
+    }
+
+    /**
+     * 判断持有给定角色的用户在维护期间是否仍可执行写操作
+     */
+    pub fn allows_any(&self, roles: &[String]) -> bool {
+        roles.iter().any(|role| self.allow_roles.contains(role))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_any_matches_allow_list() {
+        let state = MaintenanceState {
+            enabled: true,
+            allow_roles: vec!["R_SUPER".to_string()],
+            ..Default::default()
+        };
+
+        assert!(state.allows_any(&["R_USER".to_string(), "R_SUPER".to_string()]));
+        assert!(!state.allows_any(&["R_USER".to_string()]));
+        assert!(!state.allows_any(&[]));
+    }
+
+    #[test]
+    fn test_message_falls_back_to_default() {
+        let mut state = MaintenanceState::default();
+        assert_eq!(state.message(), DEFAULT_MAINTENANCE_MESSAGE);
+
+        state.message = Some(String::new());
+        assert_eq!(state.message(), DEFAULT_MAINTENANCE_MESSAGE);
+
+        state.message = Some("Upgrading database".to_string());
+        assert_eq!(state.message(), "Upgrading database");
+    }
+}
@@ -6,6 +6,7 @@
 
 use chrono::NaiveDateTime;
 use serde::Serialize;
+use server_core::web::Projectable;
 
 use crate::admin::entities::sea_orm_active_enums::{MenuType, Status};
 
@@ -15,6 +16,7 @@ use crate::admin::entities::sea_orm_active_enums::{MenuType, Status};
  * 用于返回菜单的路由信息。
  */
 #[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct MenuRoute {
     /** 路由名称 */
     pub name: String,
@@ -39,14 +41,15 @@ pub struct MenuRoute {
  * 用于返回路由的元数据信息。
  */
 #[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct RouteMeta {
     /** 标题 */
     pub title: String,
     /** 国际化键值 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "i18nKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub i18n_key: Option<String>,
     /** 是否保持活跃 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "keepAlive")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub keep_alive: Option<bool>,
     /** 是否常量路由 */
     pub constant: bool,
@@ -59,13 +62,13 @@ pub struct RouteMeta {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub href: Option<String>,
     /** 是否在菜单中隐藏 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "hideInMenu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_in_menu: Option<bool>,
     /** 激活菜单 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "activeMenu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active_menu: Option<String>,
     /** 是否支持多标签 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "multiTab")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub multi_tab: Option<bool>,
 }
 
@@ -75,49 +78,46 @@ pub struct RouteMeta {
  * 用于返回菜单的树形结构信息。
  */
 #[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct MenuTree {
     /** 菜单ID */
     pub id: i32,
     /** 父级菜单ID */
     pub pid: String,
     /** 菜单类型 */
-    #[serde(rename = "menuType")]
     pub menu_type: MenuType,
     /** 菜单名称 */
-    #[serde(rename = "menuName")]
     pub menu_name: String,
     /** 图标类型 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "iconType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_type: Option<i32>,
     /** 图标 */
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
     /** 路由名称 */
-    #[serde(rename = "routeName")]
     pub route_name: String,
     /** 路由路径 */
-    #[serde(rename = "routePath")]
     pub route_path: String,
     /** 组件路径 */
     pub component: String,
     /** 路径参数 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "pathParam")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub path_param: Option<String>,
     /** 状态 */
     pub status: Status,
     /** 激活菜单 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "activeMenu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active_menu: Option<String>,
     /** 是否在菜单中隐藏 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "hideInMenu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_in_menu: Option<bool>,
     /** 排序 */
     pub sequence: i32,
     /** 国际化键值 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "i18nKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub i18n_key: Option<String>,
     /** 是否保持活跃 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "keepAlive")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub keep_alive: Option<bool>,
     /** 是否常量路由 */
     pub constant: bool,
@@ -125,21 +125,146 @@ pub struct MenuTree {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub href: Option<String>,
     /** 是否支持多标签 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "multiTab")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub multi_tab: Option<bool>,
+    /** 所属域代码，为空时所有域共用 */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
     /** 创建时间 */
-    #[serde(rename = "createdAt")]
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: NaiveDateTime,
     /** 创建人 */
-    #[serde(rename = "createdBy")]
     pub created_by: String,
     /** 更新时间 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "updatedAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "crate::admin::timestamps::utc::option")]
     pub updated_at: Option<NaiveDateTime>,
     /** 更新人 */
-    #[serde(skip_serializing_if = "Option::is_none", rename = "updatedBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_by: Option<String>,
+    /** 创建人用户名，用户已删除或不存在时为空 */
+    pub created_by_name: Option<String>,
+    /** 更新人用户名，用户已删除或不存在时为空 */
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by_name: Option<String>,
     /** 子菜单列表 */
     #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<MenuTree>>,
 }
+
+impl Projectable for MenuTree {
+    const FIELDS: &'static [&'static str] = &[
+        "id",
+        "pid",
+        "menuType",
+        "menuName",
+        "iconType",
+        "icon",
+        "routeName",
+        "routePath",
+        "component",
+        "pathParam",
+        "status",
+        "activeMenu",
+        "hideInMenu",
+        "sequence",
+        "i18nKey",
+        "keepAlive",
+        "constant",
+        "href",
+        "multiTab",
+        "domain",
+        "createdAt",
+        "createdBy",
+        "updatedAt",
+        "updatedBy",
+        "createdByName",
+        "updatedByName",
+        "children",
+    ];
+}
+
+/**
+ * 菜单级联删除结果
+ * 
+ * 用于返回级联删除的菜单及失去菜单的角色。
+ */
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuDeleteOutput {
+    /** 被删除的菜单ID，子菜单在前 */
+    pub deleted_ids: Vec<i32>,
+    /** 失去菜单的角色代码 */
+    pub affected_roles: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use server_core::web::naming::snake_case_keys;
+
+    use super::*;
+
+    fn menu_tree() -> MenuTree {
+        MenuTree {
+            id: 1,
+            pid: "0".to_string(),
+            menu_type: MenuType::Menu,
+            menu_name: "home".to_string(),
+            icon_type: None,
+            icon: None,
+            route_name: "home".to_string(),
+            route_path: "/home".to_string(),
+            component: "layout.base$view.home".to_string(),
+            path_param: None,
+            status: Status::Enabled,
+            active_menu: None,
+            hide_in_menu: Some(false),
+            sequence: 1,
+            i18n_key: Some("route.home".to_string()),
+            keep_alive: None,
+            constant: false,
+            href: None,
+            multi_tab: None,
+            domain: Some("tenant".to_string()),
+            created_at: NaiveDate::from_ymd_opt(2024, 5, 15)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            created_by: "admin".to_string(),
+            updated_at: None,
+            updated_by: None,
+            created_by_name: None,
+            updated_by_name: None,
+            children: None,
+        }
+    }
+
+    #[test]
+    fn test_menu_tree_field_naming() {
+        let mut value = serde_json::to_value(menu_tree()).unwrap();
+        assert_eq!(value["menuName"], "home");
+        assert_eq!(value["routePath"], "/home");
+        assert_eq!(value["hideInMenu"], false);
+        assert_eq!(value["i18nKey"], "route.home");
+        assert!(value.get("createdByName").is_some());
+
+        snake_case_keys(&mut value);
+        assert_eq!(value["menu_name"], "home");
+        assert_eq!(value["route_path"], "/home");
+        assert_eq!(value["hide_in_menu"], false);
+        assert_eq!(value["i18n_key"], "route.home");
+        assert!(value.get("created_by_name").is_some());
+        assert!(value.get("menuName").is_none());
+    }
+
+    #[test]
+    fn test_menu_tree_projectable_fields() {
+        let mut menu = menu_tree();
+        menu.children = Some(vec![menu_tree()]);
+        let value = serde_json::to_value(menu).unwrap();
+        for key in value.as_object().unwrap().keys() {
+            assert!(MenuTree::FIELDS.contains(&key.as_str()), "{}", key);
+        }
+    }
+}
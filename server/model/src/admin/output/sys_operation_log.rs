@@ -0,0 +1,108 @@
+/**
+ * 操作日志相关输出参数定义
+ * 
+ * 包含操作日志和操作日志清理结果的输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::admin::entities::sys_operation_log::Model as SysOperationLogModel;
+
+/**
+ * 操作日志输出参数
+ * 
+ * 用于管理端查询操作日志，字段与表结构解耦，表结构调整时接口保持不变
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLogOutput {
+    /** 日志ID */
+    pub id: String,
+    /** 用户ID */
+    pub user_id: String,
+    /** 用户名 */
+    pub username: String,
+    /** 用户所属域 */
+    pub domain: String,
+    /** 模块名称 */
+    pub module_name: String,
+    /** 操作描述 */
+    pub description: String,
+    /** 请求ID，用于关联请求日志 */
+    pub request_id: String,
+    /** 请求方法 */
+    pub method: String,
+    /** 请求路径 */
+    pub url: String,
+    /** 客户端IP */
+    pub ip: String,
+    /** 用户代理 */
+    pub user_agent: Option<String>,
+    /** 请求参数，敏感字段已脱敏 */
+    pub params: Option<JsonValue>,
+    /** 请求体，敏感字段已脱敏 */
+    pub body: Option<JsonValue>,
+    /** 响应体，敏感字段已脱敏 */
+    pub response: Option<JsonValue>,
+    /** 请求开始时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub start_time: NaiveDateTime,
+    /** 请求结束时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub end_time: NaiveDateTime,
+    /** 耗时（毫秒） */
+    pub duration: i32,
+    /** 是否超过请求处理时间上限，超时的请求返回504 */
+    pub timed_out: bool,
+    /** 响应的HTTP状态码，记录状态码之前的历史日志为空 */
+    pub status_code: Option<i32>,
+    /** 请求是否成功，状态码小于400时为true，未记录状态码时为空 */
+    pub success: Option<bool>,
+    /** 记录时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<SysOperationLogModel> for OperationLogOutput {
+    fn from(model: SysOperationLogModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            username: model.username,
+            domain: model.domain,
+            module_name: model.module_name,
+            description: model.description,
+            request_id: model.request_id,
+            method: model.method,
+            url: model.url,
+            ip: model.ip,
+            user_agent: model.user_agent,
+            params: model.params,
+            body: model.body,
+            response: model.response,
+            start_time: model.start_time,
+            end_time: model.end_time,
+            duration: model.duration,
+            timed_out: model.timed_out,
+            status_code: model.status_code,
+            success: model.status_code.map(|status| status < 400),
+            created_at: model.created_at,
+        }
+    }
+}
+
+/**
+ * 操作日志清理结果
+ */
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationLogPurgeOutput {
+    /** 已删除的记录数，试运行时为将被删除的记录数 */
+    pub count: u64,
+    /** 是否为试运行 */
+    pub dry_run: bool,
+    /** 删除前是否已归档到S3 */
+    pub archived: bool,
+}
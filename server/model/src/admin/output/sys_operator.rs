@@ -0,0 +1,25 @@
+/**
+ * 操作人相关输出参数定义
+ * 
+ * 为实体输出补充创建人和更新人的用户名。
+ */
+
+use serde::Serialize;
+
+/**
+ * 带操作人用户名的输出参数
+ * 
+ * 实体中的 created_by/updated_by 保存的是用户ID，该结构在实体字段之外
+ * 返回对应的用户名；用户已删除或不存在时为 None。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithOperatorNames<T> {
+    /** 实体信息 */
+    #[serde(flatten)]
+    pub inner: T,
+    /** 创建人用户名 */
+    pub created_by_name: Option<String>,
+    /** 更新人用户名 */
+    pub updated_by_name: Option<String>,
+}
@@ -0,0 +1,23 @@
+/**
+ * 组织相关输出参数定义
+ * 
+ * 包含组织用户转移结果的输出结构体。
+ */
+
+use serde::Serialize;
+
+/**
+ * 组织用户转移结果
+ * 
+ * 用于返回转移的来源、目标组织及转移的用户数量，便于记录操作日志。
+ */
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationTransferOutput {
+    /** 来源组织ID */
+    pub source_organization_id: String,
+    /** 目标组织ID */
+    pub target_organization_id: String,
+    /** 转移的用户数量 */
+    pub transferred_users: u64,
+}
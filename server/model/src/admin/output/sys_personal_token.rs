@@ -0,0 +1,52 @@
+/**
+ * 个人访问令牌相关输出参数定义
+ * 
+ * 包含个人访问令牌列表和创建结果的输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use super::EndpointDescriptor;
+
+/**
+ * 个人访问令牌输出参数
+ * 
+ * 不包含令牌内容，只返回令牌前缀用于辨认。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalTokenOutput {
+    /** 令牌记录ID，撤销时使用 */
+    pub id: String,
+    /** 令牌名称 */
+    pub name: String,
+    /** 令牌前缀 */
+    pub token_prefix: String,
+    /** 权限范围 */
+    pub scopes: Vec<EndpointDescriptor>,
+    /** 过期时间，为空时长期有效 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub expires_at: Option<NaiveDateTime>,
+    /** 最近使用时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub last_used_at: Option<NaiveDateTime>,
+    /** 创建时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub created_at: NaiveDateTime,
+}
+
+/**
+ * 个人访问令牌创建结果
+ * 
+ * 令牌明文只在创建时返回一次，服务端只保存其哈希。
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalTokenCreatedOutput {
+    /** 令牌明文 */
+    pub token: String,
+    /** 令牌信息 */
+    #[serde(flatten)]
+    pub info: PersonalTokenOutput,
+}
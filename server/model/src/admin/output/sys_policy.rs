@@ -0,0 +1,31 @@
+/**
+ * 策略查看相关输出参数定义
+ * 
+ * 包含策略规则和权限判定试算结果的输出结构体。
+ */
+
+use serde::Serialize;
+
+/**
+ * 策略规则输出参数
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyRuleOutput {
+    /** 策略类型，如 `p`、`g` */
+    pub ptype: String,
+    /** 规则各字段的值，顺序与模型定义一致 */
+    pub rule: Vec<String>,
+}
+
+/**
+ * 权限判定试算结果
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyCheckOutput {
+    /** 是否允许访问 */
+    pub allowed: bool,
+    /** 命中的策略规则，未命中任何策略时为空 */
+    pub matched: Vec<Vec<String>>,
+}
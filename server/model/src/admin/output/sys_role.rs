@@ -0,0 +1,160 @@
+/**
+ * 角色相关输出参数定义
+ * 
+ * 包含角色详情、角色下的用户、用户的角色和角色权限导入导出等输出结构体。
+ */
+
+use sea_orm::FromQueryResult;
+use serde::{Deserialize, Serialize};
+
+use crate::admin::entities::{sea_orm_active_enums::Status, sys_role::Model as SysRoleModel};
+
+/**
+ * 角色详情输出参数
+ * 
+ * 在角色信息之外包含分配了该角色的用户数量。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleDetailOutput {
+    /** 角色信息 */
+    #[serde(flatten)]
+    pub role: SysRoleModel,
+    /** 当前域中分配了该角色的用户数量 */
+    pub user_count: u64,
+}
+
+/**
+ * 角色用户输出参数
+ * 
+ * 用于列出分配了某个角色的用户，不包含密码等敏感信息。
+ */
+#[derive(Clone, Debug, PartialEq, FromQueryResult, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleUserOutput {
+    /** 用户ID */
+    pub id: String,
+    pub domain: String,
+    pub username: String,
+    pub nick_name: String,
+    pub avatar: Option<String>,
+    pub email: Option<String>,
+    /** 用户状态 */
+    pub status: Status,
+}
+
+/**
+ * 用户角色输出参数
+ * 
+ * 用于列出用户被分配的角色，包含用户所属域的代码和名称。
+ */
+#[derive(Clone, Debug, PartialEq, FromQueryResult, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRoleOutput {
+    /** 角色ID */
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    /** 角色状态 */
+    pub status: Status,
+    pub domain_code: String,
+    pub domain_name: String,
+}
+
+/** 当前角色权限文档格式版本 */
+pub const ROLE_PERMISSION_DOCUMENT_VERSION: u32 = 1;
+
+/**
+ * 角色权限文档
+ * 
+ * 用于在不同环境之间迁移角色的权限配置，只包含代码、路径等可移植的描述，
+ * 不包含数据库ID。新增字段时需提供默认值，以便旧版本文档仍可导入。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermissionDocument {
+    /** 文档格式版本 */
+    pub version: u32,
+    /** 角色编码 */
+    pub role_code: String,
+    /** 域编码 */
+    pub domain_code: String,
+    /** 接口权限 */
+    #[serde(default)]
+    pub endpoints: Vec<EndpointDescriptor>,
+    /** 菜单路由名称 */
+    #[serde(default)]
+    pub menus: Vec<String>,
+}
+
+/**
+ * 接口描述
+ * 
+ * 以路径和请求方法标识一个接口
+ */
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointDescriptor {
+    /** 接口路径 */
+    pub path: String,
+    /** 请求方法 */
+    pub method: String,
+}
+
+/**
+ * 角色权限导入结果
+ * 
+ * 包含无法在目标环境中找到的条目和计划（或已执行）的变更。
+ * 存在无法解析的条目时不执行导入。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermissionImportOutput {
+    /** 是否已写入数据库 */
+    pub applied: bool,
+    /** 目标环境中不存在的接口 */
+    pub unresolved_endpoints: Vec<EndpointDescriptor>,
+    /** 目标环境中不存在的菜单路由名称 */
+    pub unresolved_menus: Vec<String>,
+    /** 需要新增的接口权限 */
+    pub endpoints_to_add: Vec<EndpointDescriptor>,
+    /** 需要移除的接口权限 */
+    pub endpoints_to_remove: Vec<EndpointDescriptor>,
+    /** 需要新增的菜单 */
+    pub menus_to_add: Vec<String>,
+    /** 需要移除的菜单 */
+    pub menus_to_remove: Vec<String>,
+}
+
+/**
+ * 角色模板权限描述
+ * 
+ * 与角色权限文档中的接口和菜单格式相同，不绑定具体的角色和域，
+ * 从模板创建角色时在当前环境中解析。
+ */
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleTemplatePermissions {
+    /** 接口权限 */
+    #[serde(default)]
+    pub endpoints: Vec<EndpointDescriptor>,
+    /** 菜单路由名称 */
+    #[serde(default)]
+    pub menus: Vec<String>,
+}
+
+/**
+ * 从模板创建角色的结果
+ * 
+ * 模板中在当前环境找不到的条目不会授予，列在结果中供核对。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleFromTemplateOutput {
+    /** 新创建的角色 */
+    pub role: SysRoleModel,
+    /** 当前环境中不存在的接口 */
+    pub unresolved_endpoints: Vec<EndpointDescriptor>,
+    /** 当前环境中不存在的菜单路由名称 */
+    pub unresolved_menus: Vec<String>,
+}
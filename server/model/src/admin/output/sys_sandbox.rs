@@ -0,0 +1,21 @@
+/**
+ * 沙箱相关输出参数定义
+ * 
+ * 包含签名调试结果结构体。
+ */
+
+use serde::Serialize;
+
+/**
+ * 签名调试输出参数
+ * 
+ * 返回服务端构建的规范签名字符串和期望签名，不包含密钥本身。
+ */
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignDebugOutput {
+    /** 规范签名字符串（不含密钥） */
+    pub signing_string: String,
+    /** 期望的签名 */
+    pub signature: String,
+}
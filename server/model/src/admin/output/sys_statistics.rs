@@ -0,0 +1,58 @@
+/**
+ * 统计相关输出参数定义
+ * 
+ * 包含域统计概览的输出结构体。
+ */
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::admin::entities::sea_orm_active_enums::Status;
+
+/**
+ * 域统计概览
+ * 
+ * 按天统计的数据覆盖包括当天在内的最近7天，按日期升序排列，没有记录的日期计数为0。
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewOutput {
+    /** 统计的域代码 */
+    pub domain: String,
+    /** 按状态分组的用户数量，只包含数量大于0的状态 */
+    pub users_by_status: Vec<StatusCount>,
+    /** 每天的登录次数 */
+    pub logins_per_day: Vec<DailyCount>,
+    /** 每天的操作日志数量 */
+    pub operations_per_day: Vec<DailyCount>,
+    /** 未吊销且未过期的登录会话数量 */
+    pub active_sessions: u64,
+    /** 角色数量 */
+    pub role_count: u64,
+    /** 已分配给角色的菜单数量 */
+    pub menu_count: u64,
+}
+
+/**
+ * 按状态分组的数量
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusCount {
+    /** 状态 */
+    pub status: Status,
+    /** 数量 */
+    pub count: u64,
+}
+
+/**
+ * 单日数量
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyCount {
+    /** 日期，按UTC划分 */
+    pub date: NaiveDate,
+    /** 数量 */
+    pub count: u64,
+}
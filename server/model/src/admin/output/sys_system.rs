@@ -0,0 +1,80 @@
+/**
+ * 系统状态相关输出参数定义
+ * 
+ * 包含就绪检查接口、数据库查询统计和版本信息的输出结构体。
+ */
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::MaintenanceState;
+
+/**
+ * 就绪检查输出参数
+ * 
+ * 维护模式不影响就绪状态，读操作在维护期间仍可正常处理。
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessOutput {
+    /** 服务是否就绪 */
+    pub ready: bool,
+    /** 主数据库是否可用 */
+    pub database: bool,
+    /** 当前维护模式状态 */
+    pub maintenance: MaintenanceState,
+    /** 进程启动以来的数据库慢查询统计 */
+    pub queries: DatabaseQueryStats,
+}
+
+/**
+ * 数据库查询统计
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseQueryStats {
+    /** 超过慢查询阈值的查询次数，包含超时的查询 */
+    pub slow_queries: u64,
+    /** 因语句超时被取消的查询次数 */
+    pub timed_out_queries: u64,
+}
+
+/**
+ * 版本信息输出参数
+ * 
+ * 构建信息在编译期记录，构建环境缺少对应信息时为null。
+ */
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionOutput {
+    /** 版本字符串，与 `X-App-Version` 响应头相同 */
+    pub version: String,
+    /** crate版本号 */
+    pub crate_version: String,
+    /** 构建时的Git短提交哈希 */
+    pub git_hash: Option<String>,
+    /** 构建时间（UTC） */
+    pub build_time: Option<DateTime<Utc>>,
+    /** 编译使用的rustc版本 */
+    pub rustc_version: Option<String>,
+    /** 可选子系统的初始化情况 */
+    pub subsystems: SubsystemStatus,
+}
+
+/**
+ * 可选子系统初始化情况
+ * 
+ * 按全局连接或缓存是否存在判断，未配置或初始化失败时为false。
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsystemStatus {
+    /** 主Redis连接 */
+    pub redis: bool,
+    /** 主MongoDB客户端 */
+    pub mongo: bool,
+    /** 主S3客户端 */
+    pub s3: bool,
+    /** IP归属地数据库 */
+    pub xdb: bool,
+}
@@ -0,0 +1,39 @@
+/**
+ * 登录令牌相关输出参数定义
+ * 
+ * 包含用户本人登录令牌（登录设备）的输出结构体。
+ */
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/**
+ * 登录令牌输出参数
+ * 
+ * 用于用户查看持有有效令牌的设备，不包含令牌内容。
+ */
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenOutput {
+    /** 令牌记录ID，撤销时使用 */
+    pub id: String,
+    /** 登录IP */
+    pub ip: String,
+    /** IP解析出的地址 */
+    pub address: String,
+    /** 用户代理 */
+    pub user_agent: String,
+    /** 登录类型 */
+    pub login_type: String,
+    /** 签发时间 */
+    #[serde(with = "crate::admin::timestamps::utc")]
+    pub created_at: NaiveDateTime,
+    /** 最近使用时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub last_seen: Option<NaiveDateTime>,
+    /** 过期时间 */
+    #[serde(with = "crate::admin::timestamps::utc::option")]
+    pub expires_at: Option<NaiveDateTime>,
+    /** 是否为本次请求使用的令牌 */
+    pub current: bool,
+}
@@ -26,6 +26,10 @@ pub struct UserWithDomainAndOrgOutput {
     pub avatar: Option<String>,
     pub domain_code: String,
     pub domain_name: String,
+    /** 用户状态 */
+    pub status: Status,
+    /** 所属域状态 */
+    pub domain_status: Status,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -39,8 +43,10 @@ pub struct UserWithoutPassword {
     pub email: Option<String>,
     pub phone_number: Option<String>,
     pub status: Status,
+    #[serde(with = "crate::admin::timestamps::utc")]
     pub created_at: NaiveDateTime,
     pub created_by: String,
+    #[serde(with = "crate::admin::timestamps::utc::option")]
     pub updated_at: Option<NaiveDateTime>,
     pub updated_by: Option<String>,
 }
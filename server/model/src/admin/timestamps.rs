@@ -0,0 +1,219 @@
+/**
+ * 实体时间戳定义
+ *
+ * 带有 created_at/updated_at 字段的实体通过 `timestamped_behavior!` 实现 `ActiveModelBehavior`，
+ * 在 `before_save` 中统一填充时间戳，服务层只需设置 created_by/updated_by：
+ * - 插入时写入 created_at
+ * - 更新时写入 updated_at
+ *
+ * 时间统一使用 UTC，以不带时区的 `NaiveDateTime` 保存。
+ * 钩子只对 ActiveModel 的 insert/update/save 生效，`insert_many`、`update_many`
+ * 等批量操作需要自行使用 `now()` 设置时间戳。
+ *
+ * 接口输出的时间字段通过 `#[serde(with = "utc")]` 序列化为带 `Z` 后缀的 RFC3339 格式，
+ * 可为空的字段使用 `utc::option`。
+ */
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::ActiveValue;
+
+/**
+ * 获取当前 UTC 时间
+ */
+pub fn now() -> NaiveDateTime {
+    Utc::now().naive_utc()
+}
+
+/**
+ * 按保存类型填充时间戳
+ *
+ * @param created_at 创建时间字段
+ * @param updated_at 更新时间字段
+ * @param insert 是否为插入操作
+ */
+pub fn fill(
+    created_at: &mut ActiveValue<NaiveDateTime>,
+    updated_at: &mut ActiveValue<Option<NaiveDateTime>>,
+    insert: bool,
+) {
+    if insert {
+        *created_at = ActiveValue::Set(now());
+    } else {
+        *updated_at = ActiveValue::Set(Some(now()));
+    }
+}
+
+/**
+ * 为当前模块的 ActiveModel 实现填充时间戳的 `ActiveModelBehavior`
+ */
+macro_rules! timestamped_behavior {
+    () => {
+        #[async_trait::async_trait]
+        impl sea_orm::ActiveModelBehavior for ActiveModel {
+            async fn before_save<C>(
+                mut self,
+                _db: &C,
+                insert: bool,
+            ) -> Result<Self, sea_orm::DbErr>
+            where
+                C: sea_orm::ConnectionTrait,
+            {
+                crate::admin::timestamps::fill(&mut self.created_at, &mut self.updated_at, insert);
+                Ok(self)
+            }
+        }
+    };
+}
+
+pub(crate) use timestamped_behavior;
+
+/**
+ * UTC 时间的序列化与反序列化
+ *
+ * 序列化为 RFC3339 格式并以 `Z` 结尾，如 `2024-05-15T08:00:00Z`。
+ * 反序列化接受带时区偏移的 RFC3339 时间并转换为 UTC，不带时区的时间视为 UTC。
+ */
+pub mod utc {
+    use chrono::{DateTime, NaiveDateTime, SecondsFormat};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /** 不带时区时接受的时间格式 */
+    const NAIVE_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+    /**
+     * 格式化为带 `Z` 后缀的 RFC3339 时间
+     */
+    pub fn format(value: &NaiveDateTime) -> String {
+        value.and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+
+    /**
+     * 解析 RFC3339 时间或不带时区的时间
+     */
+    pub fn parse(value: &str) -> Option<NaiveDateTime> {
+        DateTime::parse_from_rfc3339(value).map(|time| time.naive_utc()).ok().or_else(|| {
+            NAIVE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        })
+    }
+
+    pub fn serialize<S>(value: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format(value))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        parse(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid datetime '{}'", value)))
+    }
+
+    /**
+     * 可为空的 UTC 时间
+     */
+    pub mod option {
+        use chrono::NaiveDateTime;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S>(value: &Option<NaiveDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDateTime>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|value| {
+                    super::parse(&value).ok_or_else(|| {
+                        serde::de::Error::custom(format!("invalid datetime '{}'", value))
+                    })
+                })
+                .transpose()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::admin::entities::{sea_orm_active_enums::Status, sys_role};
+
+    fn time(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 15).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_fill_sets_timestamp_by_operation() {
+        let mut created_at = ActiveValue::NotSet;
+        let mut updated_at = ActiveValue::NotSet;
+        fill(&mut created_at, &mut updated_at, true);
+        assert!(matches!(created_at, ActiveValue::Set(_)));
+        assert!(matches!(updated_at, ActiveValue::NotSet));
+
+        let created = now();
+        let mut created_at = ActiveValue::Unchanged(created);
+        let mut updated_at = ActiveValue::Unchanged(None);
+        fill(&mut created_at, &mut updated_at, false);
+        assert_eq!(created_at, ActiveValue::Unchanged(created));
+        assert!(matches!(updated_at, ActiveValue::Set(Some(_))));
+    }
+
+    #[test]
+    fn test_outputs_serialize_as_utc() {
+        let role = sys_role::Model {
+            id: "1".to_string(),
+            code: "ROLE_SUPER".to_string(),
+            domain: "built-in".to_string(),
+            name: "super".to_string(),
+            description: None,
+            pid: "0".to_string(),
+            status: Status::Enabled,
+            created_at: time(8),
+            created_by: "-1".to_string(),
+            updated_at: None,
+            updated_by: None,
+        };
+
+        let json = serde_json::to_value(&role).unwrap();
+        assert_eq!(json["created_at"], "2024-05-15T08:00:00Z");
+        assert!(json["updated_at"].is_null());
+
+        let role = sys_role::Model { updated_at: Some(time(9)), ..role };
+        let json = serde_json::to_value(&role).unwrap();
+        assert_eq!(json["updated_at"], "2024-05-15T09:00:00Z");
+    }
+
+    #[test]
+    fn test_inputs_accept_offset_and_naive_times() {
+        #[derive(Deserialize)]
+        struct Input {
+            #[serde(with = "utc")]
+            at: NaiveDateTime,
+        }
+
+        let parse = |value: &str| {
+            serde_json::from_value::<Input>(serde_json::json!({ "at": value }))
+                .map(|input| input.at)
+        };
+        assert_eq!(parse("2024-05-15T16:00:00+08:00").unwrap(), time(8));
+        assert_eq!(parse("2024-05-15T08:00:00Z").unwrap(), time(8));
+        assert_eq!(parse("2024-05-15 08:00:00").unwrap(), time(8));
+        assert!(parse("15/05/2024").is_err());
+    }
+}
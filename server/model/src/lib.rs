@@ -7,20 +7,12 @@
  *   - input: 输入模型定义
  *   - entities: 数据库实体定义
  *   - output: 输出模型定义
- * - error: 应用程序错误类型定义
  * 
  * 这些模型用于：
  * 1. 定义数据库表结构
  * 2. 处理API请求和响应
  * 3. 定义业务逻辑中的数据结构
- * 4. 处理错误情况
  */
 
 /// 管理后台相关的数据模型
 pub mod admin;
-
-/// 应用程序错误类型定义
-pub mod error;
-
-/// 重新导出应用程序错误类型
-pub use error::AppError;
@@ -0,0 +1,135 @@
+/**
+ * 邮件模板
+ *
+ * 邮件正文由 `templates/email` 下的 HTML 模板渲染，所有模板继承同一布局。
+ * 调用方按模板键传入 JSON 上下文，上下文字段与模板结构体字段一一对应，
+ * 缺少字段或模板键未知时返回错误，变量内容会做 HTML 转义。
+ *
+ * 内置模板：
+ * - `password_reset`: 重置密码，字段 `user_name`、`reset_link`、`expires_in_minutes`
+ * - `lockout_alert`: 账号锁定提醒，字段 `user_name`、`ip`、`locked_until`
+ * - `unusual_login`: 异常登录提醒，字段 `user_name`、`ip`、`address`、`user_agent`
+ * - `test`: 测试邮件，字段 `sent_at`
+ */
+use anyhow::{anyhow, Result};
+use askama::Template;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+/** 重置密码模板键 */
+pub const EMAIL_TEMPLATE_PASSWORD_RESET: &str = "password_reset";
+/** 账号锁定提醒模板键 */
+pub const EMAIL_TEMPLATE_LOCKOUT_ALERT: &str = "lockout_alert";
+/** 异常登录提醒模板键 */
+pub const EMAIL_TEMPLATE_UNUSUAL_LOGIN: &str = "unusual_login";
+/** 测试邮件模板键 */
+pub const EMAIL_TEMPLATE_TEST: &str = "test";
+
+/**
+ * 渲染后的邮件
+ */
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub body: String,
+}
+
+#[derive(Template, Deserialize)]
+#[template(path = "email/password_reset.html.askama", escape = "html")]
+struct PasswordResetEmail {
+    user_name: String,
+    reset_link: String,
+    expires_in_minutes: u32,
+}
+
+#[derive(Template, Deserialize)]
+#[template(path = "email/lockout_alert.html.askama", escape = "html")]
+struct LockoutAlertEmail {
+    user_name: String,
+    ip: String,
+    locked_until: String,
+}
+
+#[derive(Template, Deserialize)]
+#[template(path = "email/unusual_login.html.askama", escape = "html")]
+struct UnusualLoginEmail {
+    user_name: String,
+    ip: String,
+    address: String,
+    user_agent: String,
+}
+
+#[derive(Template, Deserialize)]
+#[template(path = "email/test.html.askama", escape = "html")]
+struct TestEmail {
+    sent_at: String,
+}
+
+/**
+ * 按模板键渲染邮件主题和正文
+ */
+pub fn render_email(template_key: &str, context: &Value) -> Result<RenderedEmail> {
+    let (subject, body) = match template_key {
+        EMAIL_TEMPLATE_PASSWORD_RESET => ("重置密码", render::<PasswordResetEmail>(context)?),
+        EMAIL_TEMPLATE_LOCKOUT_ALERT => ("账号锁定提醒", render::<LockoutAlertEmail>(context)?),
+        EMAIL_TEMPLATE_UNUSUAL_LOGIN => ("异常登录提醒", render::<UnusualLoginEmail>(context)?),
+        EMAIL_TEMPLATE_TEST => ("测试邮件", render::<TestEmail>(context)?),
+        _ => return Err(anyhow!("unknown email template: {}", template_key)),
+    };
+    Ok(RenderedEmail {
+        subject: subject.to_string(),
+        body,
+    })
+}
+
+fn render<T: Template + DeserializeOwned>(context: &Value) -> Result<String> {
+    let template: T = serde_json::from_value(context.clone())?;
+    Ok(template.render()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_render_password_reset() {
+        let email = render_email(
+            EMAIL_TEMPLATE_PASSWORD_RESET,
+            &json!({
+                "user_name": "Soybean",
+                "reset_link": "https://example.com/reset?token=abc",
+                "expires_in_minutes": 30,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(email.subject, "重置密码");
+        assert!(email.body.contains("Soybean"));
+        assert!(email.body.contains("30 分钟"));
+    }
+
+    #[test]
+    fn test_render_escapes_context() {
+        let email = render_email(
+            EMAIL_TEMPLATE_LOCKOUT_ALERT,
+            &json!({
+                "user_name": "<script>",
+                "ip": "127.0.0.1",
+                "locked_until": "2026-10-16 12:00:00",
+            }),
+        )
+        .unwrap();
+
+        assert!(!email.body.contains("<script>"));
+        assert!(email.body.contains("&#60;script&#62;") || email.body.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_render_rejects_unknown_key_and_missing_field() {
+        assert!(render_email("unknown", &json!({})).is_err());
+        assert!(render_email(EMAIL_TEMPLATE_TEST, &json!({})).is_err());
+    }
+}
@@ -1,17 +1,96 @@
 use anyhow::Result;
 use askama::Template;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-#[allow(dead_code)]
+pub mod email;
+
+/**
+ * 模板过滤器
+ *
+ * 资源名称按大驼峰输入，模板中通过过滤器派生其他命名风格：
+ * - `snake`: 蛇形命名，用于文件名、函数名，如 `LoginPolicy` -> `login_policy`
+ * - `pascal`: 大驼峰命名，用于类型名
+ * - `plural`: 英文复数形式，如 `login_policy` -> `login_policies`
+ * - `kebab`: 短横线命名，用于路由路径，如 `LoginPolicy` -> `login-policy`
+ *
+ * 常量名使用 `snake|upper` 组合得到 `LOGIN_POLICY`
+ */
 mod filters {
-    #[allow(dead_code)]
-    pub fn lower(s: &str) -> ::askama::Result<String> {
-        Ok(s.to_lowercase())
+    use std::fmt::Display;
+
+    use convert_case::{Case, Casing};
+
+    pub fn snake<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<String> {
+        Ok(to_snake(&s.to_string()))
+    }
+
+    pub fn pascal<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<String> {
+        Ok(to_pascal(&s.to_string()))
+    }
+
+    pub fn plural<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<String> {
+        Ok(pluralize(&s.to_string()))
+    }
+
+    pub fn kebab<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<String> {
+        Ok(s.to_string().to_case(Case::Kebab))
+    }
+
+    pub(crate) fn to_snake(s: &str) -> String {
+        s.to_case(Case::Snake)
+    }
+
+    pub(crate) fn to_pascal(s: &str) -> String {
+        s.to_case(Case::Pascal)
     }
 
-    #[allow(dead_code)]
-    pub fn title(s: &str) -> ::askama::Result<String> {
-        Ok(s.to_string())
+    /** 按常见英文规则转换复数，只处理最后一个单词 */
+    pub(crate) fn pluralize(s: &str) -> String {
+        let lower = s.to_lowercase();
+        if ["s", "x", "z", "ch", "sh"]
+            .iter()
+            .any(|suffix| lower.ends_with(suffix))
+        {
+            return format!("{}es", s);
+        }
+
+        let mut chars = lower.chars().rev();
+        if let (Some('y'), Some(before)) = (chars.next(), chars.next()) {
+            if !"aeiou".contains(before) {
+                return format!("{}ies", &s[..s.len() - 1]);
+            }
+        }
+        format!("{}s", s)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_snake() {
+            assert_eq!(to_snake("Role"), "role");
+            assert_eq!(to_snake("LoginPolicy"), "login_policy");
+            assert_eq!(to_snake("AccessKey"), "access_key");
+        }
+
+        #[test]
+        fn test_pascal() {
+            assert_eq!(to_pascal("Role"), "Role");
+            assert_eq!(to_pascal("LoginPolicy"), "LoginPolicy");
+            assert_eq!(to_pascal("login_policy"), "LoginPolicy");
+        }
+
+        #[test]
+        fn test_plural() {
+            assert_eq!(pluralize("role"), "roles");
+            assert_eq!(pluralize("login_policy"), "login_policies");
+            assert_eq!(pluralize("access_key"), "access_keys");
+            assert_eq!(pluralize("day"), "days");
+            assert_eq!(pluralize("address"), "addresses");
+            assert_eq!(pluralize("box"), "boxes");
+            assert_eq!(pluralize("branch"), "branches");
+        }
     }
 }
 
@@ -21,103 +100,282 @@ struct ErrorTemplate {
     name: String,
 }
 
-impl ErrorTemplate {
-    fn lower(&self) -> String {
-        self.name.to_lowercase()
-    }
-}
-
 #[derive(Template)]
 #[template(path = "input.rs.askama", escape = "none")]
 struct InputTemplate {
     name: String,
 }
 
-impl InputTemplate {
-    fn lower(&self) -> String {
-        self.name.to_lowercase()
-    }
-}
-
 #[derive(Template)]
 #[template(path = "service.rs.askama", escape = "none")]
 struct ServiceTemplate {
     name: String,
 }
 
-impl ServiceTemplate {
-    fn lower(&self) -> String {
-        self.name.to_lowercase()
-    }
-}
-
 #[derive(Template)]
 #[template(path = "api.rs.askama", escape = "none")]
 struct ApiTemplate {
     name: String,
 }
 
-impl ApiTemplate {
-    fn lower(&self) -> String {
-        self.name.to_lowercase()
-    }
+#[derive(Template)]
+#[template(path = "router.rs.askama", escape = "none")]
+struct RouterTemplate {
+    name: String,
+}
 
-    fn title(&self) -> String {
-        self.name.to_string()
-    }
+#[derive(Template)]
+#[template(path = "migration.rs.askama", escape = "none")]
+struct MigrationTemplate {
+    name: String,
 }
 
+/**
+ * 生成的文件
+ *
+ * 包含目标路径、完整内容以及该文件是否已存在（已存在表示更新）
+ */
+#[derive(Debug, Clone)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub content: String,
+    pub exists: bool,
+}
+
+/**
+ * 生成资源代码并写入文件
+ *
+ * 依次生成错误、输入、服务、API、路由文件和建表迁移骨架，
+ * 并把新模块登记到对应的 `mod.rs` 与迁移列表中。重复执行不会产生重复的声明或迁移。
+ */
 pub fn generate_code(name: &str, base_path: impl Into<PathBuf>) -> Result<()> {
+    for file in plan_code(name, base_path)? {
+        std::fs::create_dir_all(file.path.parent().unwrap())?;
+        std::fs::write(&file.path, &file.content)?;
+    }
+    Ok(())
+}
+
+/**
+ * 计算需要写入的文件，不修改磁盘
+ *
+ * 内容与磁盘上一致的文件不会出现在结果中，用于 `--dry-run`
+ */
+pub fn plan_code(name: &str, base_path: impl Into<PathBuf>) -> Result<Vec<GeneratedFile>> {
     let base_path = base_path.into();
-    let name = name.to_string();
-
-    let error_template = ErrorTemplate { name: name.clone() };
-    let error_code = error_template.render()?;
-    let error_path = base_path
-        .join("server")
-        .join("service")
-        .join("src")
-        .join("admin")
-        .join("errors")
-        .join(format!("sys_{}_error.rs", name.to_lowercase()));
-    std::fs::create_dir_all(error_path.parent().unwrap())?;
-    std::fs::write(error_path, error_code)?;
-
-    let input_template = InputTemplate { name: name.clone() };
-    let input_code = input_template.render()?;
-    let input_path = base_path
-        .join("server")
-        .join("model")
-        .join("src")
-        .join("admin")
-        .join("input")
-        .join(format!("sys_{}.rs", name.to_lowercase()));
-    std::fs::create_dir_all(input_path.parent().unwrap())?;
-    std::fs::write(input_path, input_code)?;
-
-    let service_template = ServiceTemplate { name: name.clone() };
-    let service_code = service_template.render()?;
-    let service_path = base_path
-        .join("server")
-        .join("service")
-        .join("src")
-        .join("admin")
-        .join(format!("sys_{}_service.rs", name.to_lowercase()));
-    std::fs::create_dir_all(service_path.parent().unwrap())?;
-    std::fs::write(service_path, service_code)?;
-
-    let api_template = ApiTemplate { name: name.clone() };
-    let api_code = api_template.render()?;
-    let api_path = base_path
-        .join("server")
-        .join("api")
-        .join("src")
-        .join("admin")
-        .join(format!("sys_{}_api.rs", name.to_lowercase()));
-    std::fs::create_dir_all(api_path.parent().unwrap())?;
-    std::fs::write(api_path, api_code)?;
+    let name = filters::to_pascal(name);
+    let snake = filters::to_snake(&name);
 
-    Ok(())
+    let server = base_path.join("server");
+    let service_dir = server.join("service").join("src").join("admin");
+    let input_dir = server.join("model").join("src").join("admin").join("input");
+    let api_dir = server.join("api").join("src").join("admin");
+    let router_dir = server.join("router").join("src").join("admin");
+    let migration_dir = base_path.join("migration").join("src");
+    let schemas_dir = migration_dir.join("schemas");
+
+    let migration_module = existing_migration(&schemas_dir, &snake)?.unwrap_or_else(|| {
+        format!(
+            "{}_create_sys_{}",
+            chrono::Local::now().format("m%Y%m%d_%H%M%S"),
+            snake
+        )
+    });
+
+    let mut files = Vec::new();
+    let mut push = |path: PathBuf, content: String| -> Result<()> {
+        let current = read_optional(&path)?;
+        if current.as_deref() != Some(content.as_str()) {
+            files.push(GeneratedFile {
+                path,
+                content,
+                exists: current.is_some(),
+            });
+        }
+        Ok(())
+    };
+
+    // 生成文件
+    push(
+        service_dir.join("errors").join(format!("sys_{}_error.rs", snake)),
+        ErrorTemplate { name: name.clone() }.render()?,
+    )?;
+    push(
+        input_dir.join(format!("sys_{}.rs", snake)),
+        InputTemplate { name: name.clone() }.render()?,
+    )?;
+    push(
+        service_dir.join(format!("sys_{}_service.rs", snake)),
+        ServiceTemplate { name: name.clone() }.render()?,
+    )?;
+    push(
+        api_dir.join(format!("sys_{}_api.rs", snake)),
+        ApiTemplate { name: name.clone() }.render()?,
+    )?;
+    push(
+        router_dir.join(format!("sys_{}_route.rs", snake)),
+        RouterTemplate { name: name.clone() }.render()?,
+    )?;
+    let migration_path = schemas_dir.join(format!("{}.rs", migration_module));
+    if !migration_path.exists() {
+        push(migration_path, MigrationTemplate { name: name.clone() }.render()?)?;
+    }
+
+    // 模块声明
+    let declarations = [
+        (
+            service_dir.join("errors").join("mod.rs"),
+            vec![
+                format!("pub mod sys_{}_error;", snake),
+                format!("pub use sys_{}_error::{}Error;", snake, name),
+            ],
+        ),
+        (
+            input_dir.join("mod.rs"),
+            vec![
+                format!(
+                    "pub use sys_{}::{{Create{}Input, {}PageRequest, Update{}Input}};",
+                    snake, name, name, name
+                ),
+                format!("mod sys_{};", snake),
+            ],
+        ),
+        (
+            service_dir.join("mod.rs"),
+            vec![
+                format!(
+                    "pub use sys_{}_service::{{Sys{}Service, T{}Service}};",
+                    snake, name, name
+                ),
+                format!("mod sys_{}_service;", snake),
+            ],
+        ),
+        (
+            api_dir.join("mod.rs"),
+            vec![
+                format!("pub mod sys_{}_api;", snake),
+                format!("pub use sys_{}_api::Sys{}Api;", snake, name),
+            ],
+        ),
+        (
+            router_dir.join("mod.rs"),
+            vec![
+                format!("pub use sys_{}_route::Sys{}Router;", snake, name),
+                format!("mod sys_{}_route;", snake),
+            ],
+        ),
+        (
+            schemas_dir.join("mod.rs"),
+            vec![format!("pub mod {};", migration_module)],
+        ),
+    ];
+    for (path, lines) in declarations {
+        let mut content = read_optional(&path)?.unwrap_or_default();
+        for line in &lines {
+            content = insert_declaration(&content, line);
+        }
+        push(path, content)?;
+    }
+
+    // 迁移列表
+    let lib_path = migration_dir.join("lib.rs");
+    if let Some(content) = read_optional(&lib_path)? {
+        let entry = format!("Box::new(schemas::{}::Migration),", migration_module);
+        push(lib_path, register_migration(&content, &entry))?;
+    }
+
+    Ok(files)
+}
+
+/**
+ * 查找已生成的同名建表迁移，返回模块名
+ */
+fn existing_migration(schemas_dir: &Path, snake: &str) -> Result<Option<String>> {
+    if !schemas_dir.is_dir() {
+        return Ok(None);
+    }
+    let suffix = format!("_create_sys_{}.rs", snake);
+    for entry in std::fs::read_dir(schemas_dir)? {
+        let file_name = entry?.file_name().to_string_lossy().into_owned();
+        if file_name.starts_with('m') && file_name.ends_with(&suffix) {
+            return Ok(Some(file_name.trim_end_matches(".rs").to_string()));
+        }
+    }
+    Ok(None)
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/**
+ * 插入模块声明
+ *
+ * 已存在时原样返回；否则按字母顺序插入到同类声明（`pub use sys_`、`pub mod sys_`、`mod sys_`、`pub mod m`）中，
+ * 没有同类声明时追加到文件末尾
+ */
+fn insert_declaration(content: &str, line: &str) -> String {
+    if content.lines().any(|existing| existing.trim() == line) {
+        return content.to_string();
+    }
+
+    let prefix = ["pub use sys_", "pub mod sys_", "mod sys_", "pub mod m"]
+        .into_iter()
+        .find(|prefix| line.starts_with(prefix))
+        .unwrap_or(line);
+    let mut lines: Vec<&str> = content.lines().collect();
+    let siblings: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.starts_with(prefix))
+        .map(|(index, _)| index)
+        .collect();
+
+    let position = match siblings.iter().find(|&&index| lines[index] > line) {
+        Some(&index) => index,
+        None => match siblings.last() {
+            // 跳过多行声明的剩余部分
+            Some(&last) => {
+                let mut index = last;
+                while index < lines.len() && !lines[index].trim_end().ends_with(';') {
+                    index += 1;
+                }
+                (index + 1).min(lines.len())
+            },
+            None => lines.len(),
+        },
+    };
+    lines.insert(position, line);
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
+/**
+ * 在迁移列表中登记架构迁移，位于数据迁移之前
+ */
+fn register_migration(content: &str, entry: &str) -> String {
+    if content.contains(entry) {
+        return content.to_string();
+    }
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let position = lines
+        .iter()
+        .position(|line| line.contains("// 数据迁移"))
+        .or_else(|| lines.iter().position(|line| line.trim() == "]"));
+    if let Some(position) = position {
+        lines.insert(position, format!("            {}", entry));
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
 }
 
 #[cfg(test)]
@@ -127,7 +385,8 @@ mod tests {
 
     #[test]
     fn test_code_generation() -> Result<()> {
-        let current_dir = std::env::current_dir()?;
+        let temp_dir = tempfile::tempdir()?;
+        let current_dir = temp_dir.path().to_path_buf();
         println!("当前目录: {:?}", current_dir);
 
         generate_code("Role", &current_dir)?;
@@ -159,6 +418,12 @@ mod tests {
                 .join("src")
                 .join("admin")
                 .join("sys_role_api.rs"),
+            current_dir
+                .join("server")
+                .join("router")
+                .join("src")
+                .join("admin")
+                .join("sys_role_route.rs"),
         ];
 
         println!("检查以下文件:");
@@ -192,9 +457,73 @@ mod tests {
             } else if file.ends_with("sys_role_api.rs") {
                 assert!(content.contains("RoleApi"));
                 assert!(content.contains("get_role"));
+            } else if file.ends_with("sys_role_route.rs") {
+                assert!(content.contains("SysRoleRouter"));
+                assert!(content.contains("init_role_router"));
             }
         }
 
         Ok(())
     }
+
+    #[test]
+    fn test_generation_is_idempotent() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let base = temp_dir.path();
+        let router_mod = base.join("server/router/src/admin/mod.rs");
+        let migration_lib = base.join("migration/src/lib.rs");
+        fs::create_dir_all(router_mod.parent().unwrap())?;
+        fs::create_dir_all(base.join("migration/src/schemas"))?;
+        fs::write(
+            &router_mod,
+            "pub use sys_user_route::SysUserRouter;\n\nmod sys_user_route;\n\npub mod route_constants;\n",
+        )?;
+        fs::write(
+            &migration_lib,
+            "        vec![\n            // 架构迁移\n            // 数据迁移\n        ]\n",
+        )?;
+
+        // 预览不写入任何文件
+        let planned = plan_code("Tag", base)?;
+        assert!(planned.iter().any(|file| file.path.ends_with("sys_tag_route.rs")));
+        assert!(!base.join("server/router/src/admin/sys_tag_route.rs").exists());
+
+        generate_code("Tag", base)?;
+        let content = fs::read_to_string(&router_mod)?;
+        assert_eq!(
+            content,
+            "pub use sys_tag_route::SysTagRouter;\npub use sys_user_route::SysUserRouter;\n\nmod sys_tag_route;\nmod sys_user_route;\n\npub mod route_constants;\n"
+        );
+        let lib = fs::read_to_string(&migration_lib)?;
+        assert!(lib.contains("_create_sys_tag::Migration),\n            // 数据迁移"));
+
+        // 重复执行不产生任何改动
+        assert!(plan_code("Tag", base)?.is_empty());
+        generate_code("Tag", base)?;
+        assert_eq!(fs::read_to_string(&router_mod)?, content);
+        assert_eq!(fs::read_to_string(&migration_lib)?, lib);
+        assert_eq!(fs::read_dir(base.join("migration/src/schemas"))?.count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_word_name() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let base = temp_dir.path();
+
+        generate_code("LoginPolicy", base)?;
+
+        let error = fs::read_to_string(
+            base.join("server/service/src/admin/errors/sys_login_policy_error.rs"),
+        )?;
+        assert!(error.contains("pub enum LoginPolicyError"));
+        let router =
+            fs::read_to_string(base.join("server/router/src/admin/sys_login_policy_route.rs"))?;
+        assert!(router.contains("const LOGIN_POLICY_PATH: &str = \"/login-policy\""));
+        assert!(router.contains("get_paginated_login_policies"));
+        assert!(base.join("server/model/src/admin/input/sys_login_policy.rs").exists());
+
+        Ok(())
+    }
 }
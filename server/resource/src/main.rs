@@ -0,0 +1,46 @@
+/**
+ * 资源代码生成器
+ * 
+ * 用法：`cargo run -p server-resource -- <Name> [base_path] [--dry-run]`
+ * 
+ * - `Name`: 资源名称（大驼峰），如 `Tag`
+ * - `base_path`: 项目根目录，默认为当前目录
+ * - `--dry-run`: 仅打印将要写入的文件，不修改磁盘
+ */
+use std::process::ExitCode;
+
+use server_resource::{generate_code, plan_code};
+
+/** 仅预览生成结果的命令行参数 */
+const DRY_RUN_FLAG: &str = "--dry-run";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let dry_run = args.iter().any(|arg| arg == DRY_RUN_FLAG);
+    let mut positional = args.iter().filter(|arg| !arg.starts_with("--"));
+
+    let Some(name) = positional.next() else {
+        eprintln!("Usage: server-resource <Name> [base_path] [{}]", DRY_RUN_FLAG);
+        return ExitCode::FAILURE;
+    };
+    let base_path = positional.next().map(String::as_str).unwrap_or(".");
+
+    let result = if dry_run {
+        plan_code(name, base_path).map(|files| {
+            for file in files {
+                let action = if file.exists { "update" } else { "create" };
+                println!("{} {}", action, file.path.display());
+            }
+        })
+    } else {
+        generate_code(name, base_path)
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to generate {}: {:#}", name, e);
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -4,39 +4,75 @@
  * 该模块包含了所有管理后台相关的路由定义，包括：
  * - 认证相关路由（登录、用户信息等）
  * - 访问密钥管理路由
+ * - 合规审计导出路由
  * - 域名管理路由
+ * - 域功能开关路由
+ * - 邮件路由
  * - 接口管理路由
+ * - 事件路由
+ * - 邀请码管理路由
+ * - 后台任务路由
  * - 登录日志路由
+ * - 维护模式路由
  * - 菜单管理路由
  * - 操作日志路由
  * - 组织管理路由
+ * - 策略查看路由
  * - 角色管理路由
+ * - 角色模板管理路由
  * - 沙箱测试路由
+ * - 统计概览路由
+ * - 对象存储路由
+ * - 系统信息路由
  * - 用户管理路由
  */
 
 pub use sys_access_key_route::SysAccessKeyRouter;
 pub use sys_authentication_route::SysAuthenticationRouter;
+pub use sys_compliance_route::SysComplianceRouter;
+pub use sys_domain_feature_route::SysDomainFeatureRouter;
 pub use sys_domain_route::SysDomainRouter;
+pub use sys_email_route::SysEmailRouter;
 pub use sys_endpoint_route::SysEndpointRouter;
+pub use sys_event_route::SysEventRouter;
+pub use sys_invitation_route::SysInvitationRouter;
+pub use sys_job_route::SysJobRouter;
 pub use sys_login_log_route::SysLoginLogRouter;
+pub use sys_maintenance_route::SysMaintenanceRouter;
 pub use sys_menu_route::SysMenuRouter;
 pub use sys_operation_log_route::SysOperationLogRouter;
 pub use sys_organization_route::SysOrganizationRouter;
+pub use sys_policy_route::SysPolicyRouter;
 pub use sys_role_route::SysRoleRouter;
+pub use sys_role_template_route::SysRoleTemplateRouter;
 pub use sys_sandbox_route::SysSandboxRouter;
+pub use sys_statistics_route::SysStatisticsRouter;
+pub use sys_storage_route::SysStorageRouter;
+pub use sys_system_route::SysSystemRouter;
 pub use sys_user_route::SysUserRouter;
 
 mod sys_access_key_route;
 mod sys_authentication_route;
+mod sys_compliance_route;
+mod sys_domain_feature_route;
 mod sys_domain_route;
+mod sys_email_route;
 mod sys_endpoint_route;
+mod sys_event_route;
+mod sys_invitation_route;
+mod sys_job_route;
 mod sys_login_log_route;
+mod sys_maintenance_route;
 mod sys_menu_route;
 mod sys_operation_log_route;
 mod sys_organization_route;
+mod sys_policy_route;
 mod sys_role_route;
+mod sys_role_template_route;
 mod sys_sandbox_route;
+mod sys_statistics_route;
+mod sys_storage_route;
+mod sys_system_route;
 mod sys_user_route;
 
 pub mod route_constants;
@@ -10,23 +10,49 @@
 
 /** API基础路径 */
 pub const API_BASE_PATH: &str = "/api";
+/** 当前API版本 */
+pub const API_VERSION: &str = "v1";
 
 /** 认证服务名称 */
 pub const SERVICE_NAME_AUTH: &str = "SysAuthenticationApi";
 /** 访问密钥服务名称 */
 pub const SERVICE_NAME_AUTH_KEY: &str = "SysAccessKeyApi";
+/** 合规审计服务名称 */
+pub const SERVICE_NAME_COMPLIANCE: &str = "SysComplianceApi";
 /** 域名服务名称 */
 pub const SERVICE_NAME_DOMAIN: &str = "SysDomainApi";
+/** 域功能开关服务名称 */
+pub const SERVICE_NAME_DOMAIN_FEATURE: &str = "SysDomainFeatureApi";
+/** 邮件服务名称 */
+pub const SERVICE_NAME_EMAIL: &str = "SysEmailApi";
+/** 事件服务名称 */
+pub const SERVICE_NAME_EVENT: &str = "SysEventApi";
 /** 接口服务名称 */
 pub const SERVICE_NAME_ENDPOINT: &str = "SysEndpointApi";
+/** 邀请码服务名称 */
+pub const SERVICE_NAME_INVITATION: &str = "SysInvitationApi";
+/** 后台任务服务名称 */
+pub const SERVICE_NAME_JOB: &str = "SysJobApi";
 /** 登录日志服务名称 */
 pub const SERVICE_NAME_LOGIN_LOG: &str = "SysLoginLogApi";
+/** 维护模式服务名称 */
+pub const SERVICE_NAME_MAINTENANCE: &str = "SysMaintenanceApi";
 /** 菜单服务名称 */
 pub const SERVICE_NAME_MENU: &str = "SysMenuApi";
 /** 操作日志服务名称 */
 pub const SERVICE_NAME_OPERATION_LOG: &str = "SysOperationLogApi";
+/** 策略查看服务名称 */
+pub const SERVICE_NAME_POLICY: &str = "SysPolicyApi";
 /** 角色服务名称 */
 pub const SERVICE_NAME_ROLE: &str = "SysRoleApi";
+/** 角色模板服务名称 */
+pub const SERVICE_NAME_ROLE_TEMPLATE: &str = "SysRoleTemplateApi";
+/** 统计服务名称 */
+pub const SERVICE_NAME_STATISTICS: &str = "SysStatisticsApi";
+/** 对象存储服务名称 */
+pub const SERVICE_NAME_STORAGE: &str = "SysStorageApi";
+/** 系统信息服务名称 */
+pub const SERVICE_NAME_SYSTEM: &str = "SysSystemApi";
 
 /** 根路由路径 */
 pub const ROUTE_ROOT: &str = "/";
@@ -38,28 +64,116 @@ pub const ROUTE_TREE: &str = "/tree";
 pub const ROUTE_AUTH_ROUTE: &str = "/auth-route";
 /** 常量路由路径 */
 pub const ROUTE_CONSTANT_ROUTES: &str = "/constant-routes";
+/** 未使用资源路由路径 */
+pub const ROUTE_UNUSED: &str = "/unused";
+/** 功能标识路由路径 */
+pub const ROUTE_FEATURE_KEY: &str = "/{key}";
+/** 维护模式路由路径 */
+pub const ROUTE_MAINTENANCE: &str = "/maintenance";
+/** 权限判定试算路由路径 */
+pub const ROUTE_CHECK: &str = "/check";
+/** 重新加载路由路径 */
+pub const ROUTE_RELOAD: &str = "/reload";
+/** 测试邮件路由路径 */
+pub const ROUTE_EMAIL_TEST: &str = "/email/test";
+/** 死信事件路由路径 */
+pub const ROUTE_EVENTS_DEAD_LETTER: &str = "/events/dead-letter";
+/** 后台任务路由路径 */
+pub const ROUTE_JOBS: &str = "/jobs";
+/** 立即执行后台任务路由路径 */
+pub const ROUTE_JOB_RUN: &str = "/jobs/{name}/run";
+/** 开通域路由路径 */
+pub const ROUTE_PROVISION: &str = "/provision";
+/** 版本信息路由路径 */
+pub const ROUTE_VERSION: &str = "/version";
+/** 主库重新连接路由路径 */
+pub const ROUTE_DB_RECONNECT: &str = "/db/reconnect";
+/** 文件下载路由路径 */
+pub const ROUTE_FILES: &str = "/files";
+/** 按对象键下载文件路由路径 */
+pub const ROUTE_FILE_KEY: &str = "/files/{*key}";
+/** 按对象键代理下载文件路由路径 */
+pub const ROUTE_PROXY_KEY: &str = "/proxy/{*key}";
+/** 统计概览路由路径 */
+pub const ROUTE_OVERVIEW: &str = "/overview";
+/** 导出路由路径 */
+pub const ROUTE_EXPORT: &str = "/export";
+/** 导出任务路由路径 */
+pub const ROUTE_EXPORT_JOB: &str = "/export/{job_id}";
 
 /** 认证模块路径 */
 pub const AUTH_PATH: &str = "/auth";
 /** 访问密钥模块路径 */
 pub const AUTH_KEY_PATH: &str = "/auth-key";
+/** 合规审计模块路径 */
+pub const COMPLIANCE_PATH: &str = "/compliance";
 /** 域名模块路径 */
 pub const DOMAIN_PATH: &str = "/domain";
+/** 域功能开关模块路径 */
+pub const DOMAIN_FEATURE_PATH: &str = "/domain/{code}/features";
 /** 接口模块路径 */
 pub const ENDPOINT_PATH: &str = "/endpoint";
+/** 邀请码模块路径 */
+pub const INVITATION_PATH: &str = "/invitation";
 /** 登录日志模块路径 */
 pub const LOGIN_LOG_PATH: &str = "/login-log";
 /** 菜单模块路径 */
 pub const MENU_PATH: &str = "/menu";
 /** 操作日志模块路径 */
 pub const OPERATION_LOG_PATH: &str = "/operation-log";
+/** 策略查看模块路径 */
+pub const POLICY_PATH: &str = "/policy";
 /** 角色模块路径 */
 pub const ROLE_PATH: &str = "/role";
+/** 角色模板模块路径 */
+pub const ROLE_TEMPLATE_PATH: &str = "/role-template";
+/** 统计模块路径 */
+pub const STATISTICS_PATH: &str = "/statistics";
+/** 对象存储模块路径 */
+pub const STORAGE_PATH: &str = "/storage";
+/** 系统模块路径 */
+pub const SYSTEM_PATH: &str = "/system";
+
+/**
+ * 为路径添加API基础路径和版本前缀
+ * 
+ * # 参数
+ * * `path` - 以 `/` 开头的模块内路径，如 `/user/users`
+ * 
+ * # 返回
+ * * `String` - 带版本前缀的路径，如 `/api/v1/user/users`
+ */
+pub fn versioned(path: &str) -> String {
+    format!("{}/{}{}", API_BASE_PATH, API_VERSION, path)
+}
+
+/**
+ * 将未带版本前缀的旧路径转换为当前版本的路径
+ * 
+ * 用于兼容模式下把 `/api/...` 的请求转发到 `/api/v1/...`。
+ * 
+ * # 参数
+ * * `path` - 请求路径
+ * 
+ * # 返回
+ * * `Option<String>` - 路径位于 API 基础路径下且未带版本前缀时返回带版本的路径，否则返回 None
+ */
+pub fn versioned_legacy_path(path: &str) -> Option<String> {
+    let rest = path.strip_prefix(API_BASE_PATH)?;
+    if !rest.starts_with('/') {
+        return None;
+    }
+    let version = rest[1..].split('/').next().unwrap_or_default();
+    if version == API_VERSION {
+        return None;
+    }
+    Some(versioned(rest))
+}
 
 /**
  * 构建完整路由路径
  * 
- * 将基础路径、模块路径和路由路径组合成完整的API路径。
+ * 将API基础路径、版本前缀、模块路径和路由路径组合成完整的API路径。
  * 
  * # 参数
  * * `base_path` - 模块基础路径
@@ -69,5 +183,27 @@ pub const ROLE_PATH: &str = "/role";
  * * `String` - 完整的API路径
  */
 pub fn build_route_path(base_path: &str, route_path: &str) -> String {
-    format!("{}{}{}", API_BASE_PATH, base_path, route_path)
+    versioned(&format!("{}{}", base_path, route_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_route_path_is_versioned() {
+        assert_eq!(versioned("/user"), "/api/v1/user");
+        assert_eq!(build_route_path(ROLE_PATH, ROUTE_ID), "/api/v1/role/{id}");
+        assert_eq!(build_route_path(ROLE_PATH, ""), "/api/v1/role");
+    }
+
+    #[test]
+    fn test_versioned_legacy_path() {
+        assert_eq!(versioned_legacy_path("/api/user/users").as_deref(), Some("/api/v1/user/users"));
+        assert_eq!(versioned_legacy_path("/api/v1-user").as_deref(), Some("/api/v1/v1-user"));
+        assert_eq!(versioned_legacy_path("/api/v1/user/users"), None);
+        assert_eq!(versioned_legacy_path("/api/v1"), None);
+        assert_eq!(versioned_legacy_path("/apix/user"), None);
+        assert_eq!(versioned_legacy_path("/ready"), None);
+    }
 } 
\ No newline at end of file
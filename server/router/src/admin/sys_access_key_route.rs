@@ -3,19 +3,23 @@
  * 
  * 该模块提供了访问密钥相关的路由功能，包括：
  * - 获取访问密钥列表
+ * - 获取访问密钥详情
  * - 创建访问密钥
+ * - 更新访问密钥
  * - 删除访问密钥
+ * - 获取未使用的访问密钥
  */
 
 use axum::{
     http::Method,
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use server_api::admin::SysAccessKeyApi;
 use server_global::global::{add_route, RouteInfo};
 use super::route_constants::{
-    AUTH_KEY_PATH, SERVICE_NAME_AUTH_KEY, ROUTE_ROOT, ROUTE_ID, build_route_path,
+    AUTH_KEY_PATH, SERVICE_NAME_AUTH_KEY, ROUTE_ROOT, ROUTE_ID, ROUTE_UNUSED,
+    build_route_path,
 };
 
 /**
@@ -43,6 +47,9 @@ impl SysAccessKeyRouter {
         let router = Router::new()
             .route(ROUTE_ROOT, get(SysAccessKeyApi::get_paginated_access_keys))
             .route(ROUTE_ROOT, post(SysAccessKeyApi::create_access_key))
+            .route(ROUTE_ROOT, put(SysAccessKeyApi::update_access_key))
+            .route(ROUTE_UNUSED, get(SysAccessKeyApi::get_unused_access_keys))
+            .route(ROUTE_ID, get(SysAccessKeyApi::get_access_key))
             .route(ROUTE_ID, delete(SysAccessKeyApi::delete_access_key));
 
         Router::new().nest(&build_route_path(AUTH_KEY_PATH, ""), router)
@@ -57,6 +64,9 @@ impl SysAccessKeyRouter {
         let routes = [
             (ROUTE_ROOT, Method::GET, "获取访问密钥列表"),
             (ROUTE_ROOT, Method::POST, "创建访问密钥"),
+            (ROUTE_ROOT, Method::PUT, "更新访问密钥"),
+            (ROUTE_UNUSED, Method::GET, "获取未使用的访问密钥"),
+            (ROUTE_ID, Method::GET, "获取访问密钥详情"),
             (ROUTE_ID, Method::DELETE, "删除访问密钥"),
         ];
 
@@ -3,15 +3,22 @@
  * 
  * 该模块提供了认证相关的路由功能，包括：
  * - 用户登录
+ * - 邀请码自助注册
+ * - 获取登录页可选域列表
  * - 获取用户信息
  * - 获取用户路由
+ * - 获取本人登录历史
+ * - 查看、撤销本人的登录令牌
+ * - 查看本人可操作的域、切换当前域
  * - 分配权限
  * - 分配路由
+ * - 分配用户
+ * - 按角色预览路由
  */
 
 use axum::{
     http::Method,
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
 };
 use server_api::admin::SysAuthenticationApi;
@@ -22,14 +29,32 @@ use super::route_constants::{
 
 /** 登录路由路径 */
 const ROUTE_LOGIN: &str = "/login";
+/** 注册路由路径 */
+const ROUTE_REGISTER: &str = "/register";
+/** 登录页域列表路由路径 */
+const ROUTE_DOMAINS: &str = "/domains";
 /** 用户信息路由路径 */
 const ROUTE_USER_INFO: &str = "/user-info";
 /** 用户路由路由路径 */
 const ROUTE_USER_ROUTES: &str = "/user-routes";
+/** 登录历史路由路径 */
+const ROUTE_LOGIN_HISTORY: &str = "/login-history";
+/** 登录令牌路由路径 */
+const ROUTE_TOKENS: &str = "/tokens";
+/** 撤销登录令牌路由路径 */
+const ROUTE_TOKEN: &str = "/tokens/{id}";
+/** 本人可操作域列表路由路径 */
+const ROUTE_MY_DOMAINS: &str = "/my-domains";
+/** 切换域路由路径 */
+const ROUTE_SWITCH_DOMAIN: &str = "/switch-domain";
 /** 分配权限路由路径 */
 const ROUTE_ASSIGN_PERMISSION: &str = "/assign-permission";
 /** 分配路由路由路径 */
 const ROUTE_ASSIGN_ROUTES: &str = "/assign-routes";
+/** 分配用户路由路径 */
+const ROUTE_ASSIGN_USERS: &str = "/assign-users";
+/** 路由预览路由路径 */
+const ROUTE_PREVIEW_ROUTES: &str = "/preview-routes";
 
 /**
  * 认证路由结构体
@@ -50,7 +75,9 @@ impl SysAuthenticationRouter {
      */
     pub async fn init_authentication_router() -> Router {
         let auth_router = Router::new()
-            .route(ROUTE_LOGIN, post(SysAuthenticationApi::login_handler));
+            .route(ROUTE_LOGIN, post(SysAuthenticationApi::login_handler))
+            .route(ROUTE_REGISTER, post(SysAuthenticationApi::register_handler))
+            .route(ROUTE_DOMAINS, get(SysAuthenticationApi::list_domains));
 
         Router::new().nest(&build_route_path(AUTH_PATH, ""), auth_router)
     }
@@ -66,7 +93,12 @@ impl SysAuthenticationRouter {
     pub async fn init_protected_router() -> Router {
         let auth_router = Router::new()
             .route(ROUTE_USER_INFO, get(SysAuthenticationApi::get_user_info))
-            .route(ROUTE_USER_ROUTES, get(SysAuthenticationApi::get_user_routes));
+            .route(ROUTE_USER_ROUTES, get(SysAuthenticationApi::get_user_routes))
+            .route(ROUTE_LOGIN_HISTORY, get(SysAuthenticationApi::get_login_history))
+            .route(ROUTE_TOKENS, get(SysAuthenticationApi::list_tokens))
+            .route(ROUTE_TOKEN, delete(SysAuthenticationApi::revoke_token))
+            .route(ROUTE_MY_DOMAINS, get(SysAuthenticationApi::my_domains))
+            .route(ROUTE_SWITCH_DOMAIN, post(SysAuthenticationApi::switch_domain));
 
         Router::new().nest(&build_route_path(AUTH_PATH, ""), auth_router)
     }
@@ -86,7 +118,9 @@ impl SysAuthenticationRouter {
         // 构建授权路由
         let auth_router = Router::new()
             .route(ROUTE_ASSIGN_PERMISSION, post(SysAuthenticationApi::assign_permissions))
-            .route(ROUTE_ASSIGN_ROUTES, post(SysAuthenticationApi::assign_routes));
+            .route(ROUTE_ASSIGN_ROUTES, post(SysAuthenticationApi::assign_routes))
+            .route(ROUTE_ASSIGN_USERS, post(SysAuthenticationApi::assign_users))
+            .route(ROUTE_PREVIEW_ROUTES, get(SysAuthenticationApi::preview_routes));
 
         Router::new().nest(&build_route_path(AUTH_PATH, ""), auth_router)
     }
@@ -98,14 +132,16 @@ impl SysAuthenticationRouter {
      */
     async fn register_authorization_routes() {
         let routes = [
-            (ROUTE_ASSIGN_PERMISSION, "分配权限"),
-            (ROUTE_ASSIGN_ROUTES, "分配路由"),
+            (ROUTE_ASSIGN_PERMISSION, Method::POST, "分配权限"),
+            (ROUTE_ASSIGN_ROUTES, Method::POST, "分配路由"),
+            (ROUTE_ASSIGN_USERS, Method::POST, "分配用户"),
+            (ROUTE_PREVIEW_ROUTES, Method::GET, "预览角色路由"),
         ];
 
-        for (path, description) in routes {
+        for (path, method, description) in routes {
             let route_info = RouteInfo::new(
                 &build_route_path(AUTH_PATH, path),
-                Method::POST,
+                method,
                 SERVICE_NAME_AUTH,
                 description,
             );
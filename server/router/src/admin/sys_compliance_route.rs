@@ -0,0 +1,74 @@
+/**
+ * 合规审计路由模块
+ * 
+ * 该模块提供了合规审计相关的路由功能，包括：
+ * - 创建导出任务
+ * - 查询导出任务
+ */
+
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysComplianceApi;
+use server_core::web::operation_log::OperationLogLayer;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_COMPLIANCE, ROUTE_EXPORT, ROUTE_EXPORT_JOB, COMPLIANCE_PATH, build_route_path,
+};
+
+/**
+ * 合规审计路由结构体
+ * 
+ * 用于管理和注册合规审计相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysComplianceRouter;
+
+impl SysComplianceRouter {
+    /**
+     * 初始化合规审计路由
+     * 
+     * 注册并返回合规审计相关的所有路由，创建导出任务的请求记录到操作日志。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_compliance_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_compliance_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(
+                ROUTE_EXPORT,
+                post(SysComplianceApi::create_export).layer(OperationLogLayer::new(true)),
+            )
+            .route(ROUTE_EXPORT_JOB, get(SysComplianceApi::get_export));
+
+        Router::new().nest(&build_route_path(COMPLIANCE_PATH, ""), router)
+    }
+
+    /**
+     * 注册合规审计相关的路由信息
+     * 
+     * 将合规审计相关的路由信息注册到全局路由表中。
+     */
+    async fn register_compliance_routes() {
+        let routes = [
+            (ROUTE_EXPORT, Method::POST, "创建合规审计导出任务"),
+            (ROUTE_EXPORT_JOB, Method::GET, "查询合规审计导出任务"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(COMPLIANCE_PATH, path),
+                method,
+                SERVICE_NAME_COMPLIANCE,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
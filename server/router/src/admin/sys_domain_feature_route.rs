@@ -0,0 +1,74 @@
+/**
+ * 域功能开关路由模块
+ * 
+ * 该模块提供了域功能开关相关的路由功能，包括：
+ * - 获取域功能开关列表
+ * - 设置域功能开关
+ * - 删除域功能开关
+ */
+
+use axum::{
+    http::Method,
+    routing::{delete, get, put},
+    Router,
+};
+use server_api::admin::SysDomainFeatureApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    DOMAIN_FEATURE_PATH, SERVICE_NAME_DOMAIN_FEATURE, ROUTE_ROOT, ROUTE_FEATURE_KEY,
+    build_route_path,
+};
+
+/**
+ * 域功能开关路由结构体
+ * 
+ * 用于管理和注册域功能开关相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysDomainFeatureRouter;
+
+impl SysDomainFeatureRouter {
+    /**
+     * 初始化域功能开关路由
+     * 
+     * 注册并返回域功能开关相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_domain_feature_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_domain_feature_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_ROOT, get(SysDomainFeatureApi::get_domain_features))
+            .route(ROUTE_ROOT, put(SysDomainFeatureApi::upsert_domain_feature))
+            .route(ROUTE_FEATURE_KEY, delete(SysDomainFeatureApi::delete_domain_feature));
+
+        Router::new().nest(&build_route_path(DOMAIN_FEATURE_PATH, ""), router)
+    }
+
+    /**
+     * 注册域功能开关相关的路由信息
+     * 
+     * 将域功能开关相关的路由信息注册到全局路由表中。
+     */
+    async fn register_domain_feature_routes() {
+        let routes = [
+            (ROUTE_ROOT, Method::GET, "获取域功能开关列表"),
+            (ROUTE_ROOT, Method::PUT, "设置域功能开关"),
+            (ROUTE_FEATURE_KEY, Method::DELETE, "删除域功能开关"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(DOMAIN_FEATURE_PATH, path),
+                method,
+                SERVICE_NAME_DOMAIN_FEATURE,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -7,6 +7,7 @@
  * - 获取域名详情
  * - 更新域名
  * - 删除域名
+ * - 开通域名
  */
 
 use axum::{
@@ -17,7 +18,7 @@ use axum::{
 use server_api::admin::SysDomainApi;
 use server_global::global::{add_route, RouteInfo};
 use super::route_constants::{
-    DOMAIN_PATH, SERVICE_NAME_DOMAIN, ROUTE_ROOT, ROUTE_ID, build_route_path,
+    DOMAIN_PATH, SERVICE_NAME_DOMAIN, ROUTE_ROOT, ROUTE_ID, ROUTE_PROVISION, build_route_path,
 };
 
 /**
@@ -47,7 +48,8 @@ impl SysDomainRouter {
             .route(ROUTE_ROOT, post(SysDomainApi::create_domain))
             .route(ROUTE_ID, get(SysDomainApi::get_domain))
             .route(ROUTE_ROOT, put(SysDomainApi::update_domain))
-            .route(ROUTE_ID, delete(SysDomainApi::delete_domain));
+            .route(ROUTE_ID, delete(SysDomainApi::delete_domain))
+            .route(ROUTE_PROVISION, post(SysDomainApi::provision_domain));
 
         Router::new().nest(&build_route_path(DOMAIN_PATH, ""), router)
     }
@@ -64,6 +66,7 @@ impl SysDomainRouter {
             (ROUTE_ID, Method::GET, "获取域名详情"),
             (ROUTE_ROOT, Method::PUT, "更新域名"),
             (ROUTE_ID, Method::DELETE, "删除域名"),
+            (ROUTE_PROVISION, Method::POST, "开通域名"),
         ];
 
         for (path, method, description) in routes {
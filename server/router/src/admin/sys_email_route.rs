@@ -0,0 +1,60 @@
+/**
+ * 邮件路由模块
+ * 
+ * 该模块提供了邮件通知相关的路由功能，包括：
+ * - 发送测试邮件
+ */
+
+use axum::{http::Method, routing::post, Router};
+use server_api::admin::SysEmailApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_EMAIL, ROUTE_EMAIL_TEST, SYSTEM_PATH, build_route_path,
+};
+
+/**
+ * 邮件路由结构体
+ * 
+ * 用于管理和注册邮件相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysEmailRouter;
+
+impl SysEmailRouter {
+    /**
+     * 初始化邮件路由
+     * 
+     * 注册并返回邮件相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_email_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_email_routes().await;
+
+        // 构建路由
+        let router = Router::new().route(ROUTE_EMAIL_TEST, post(SysEmailApi::send_test_email));
+
+        Router::new().nest(&build_route_path(SYSTEM_PATH, ""), router)
+    }
+
+    /**
+     * 注册邮件相关的路由信息
+     * 
+     * 将邮件相关的路由信息注册到全局路由表中。
+     */
+    async fn register_email_routes() {
+        let routes = [(ROUTE_EMAIL_TEST, Method::POST, "发送测试邮件")];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(SYSTEM_PATH, path),
+                method,
+                SERVICE_NAME_EMAIL,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
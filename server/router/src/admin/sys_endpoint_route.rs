@@ -5,9 +5,14 @@
  * - 获取接口列表
  * - 获取角色API权限
  * - 获取接口树
+ * - 预览和执行接口同步
  */
 
-use axum::{http::Method, routing::get, Router};
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
 use server_api::admin::SysEndpointApi;
 use server_global::global::{add_route, RouteInfo};
 use super::route_constants::{
@@ -16,6 +21,10 @@ use super::route_constants::{
 
 /** 角色API权限路由路径 */
 const ROUTE_AUTH_ENDPOINT: &str = "/auth-endpoint/{roleCode}";
+/** 接口同步预览路由路径 */
+const ROUTE_SYNC_PREVIEW: &str = "/sync-preview";
+/** 执行接口同步路由路径 */
+const ROUTE_SYNC_APPLY: &str = "/sync-apply";
 
 /**
  * 接口路由结构体
@@ -42,7 +51,9 @@ impl SysEndpointRouter {
         let router = Router::new()
             .route(ROUTE_ROOT, get(SysEndpointApi::get_paginated_endpoints))
             .route(ROUTE_AUTH_ENDPOINT, get(SysEndpointApi::get_auth_endpoints))
-            .route(ROUTE_TREE, get(SysEndpointApi::tree_endpoint));
+            .route(ROUTE_TREE, get(SysEndpointApi::tree_endpoint))
+            .route(ROUTE_SYNC_PREVIEW, get(SysEndpointApi::sync_preview))
+            .route(ROUTE_SYNC_APPLY, post(SysEndpointApi::sync_apply));
 
         Router::new().nest(&build_route_path(ENDPOINT_PATH, ""), router)
     }
@@ -57,6 +68,8 @@ impl SysEndpointRouter {
             (ROUTE_ROOT, Method::GET, "获取接口列表"),
             (ROUTE_AUTH_ENDPOINT, Method::GET, "获取角色API权限"),
             (ROUTE_TREE, Method::GET, "获取接口树"),
+            (ROUTE_SYNC_PREVIEW, Method::GET, "预览接口同步"),
+            (ROUTE_SYNC_APPLY, Method::POST, "执行接口同步"),
         ];
 
         for (path, method, description) in routes {
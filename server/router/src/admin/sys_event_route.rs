@@ -0,0 +1,61 @@
+/**
+ * 事件路由模块
+ * 
+ * 该模块提供了事件处理相关的路由功能，包括：
+ * - 查询死信事件
+ */
+
+use axum::{http::Method, routing::get, Router};
+use server_api::admin::SysEventApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_EVENT, ROUTE_EVENTS_DEAD_LETTER, SYSTEM_PATH, build_route_path,
+};
+
+/**
+ * 事件路由结构体
+ * 
+ * 用于管理和注册事件相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysEventRouter;
+
+impl SysEventRouter {
+    /**
+     * 初始化事件路由
+     * 
+     * 注册并返回事件相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_event_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_event_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_EVENTS_DEAD_LETTER, get(SysEventApi::list_dead_letters));
+
+        Router::new().nest(&build_route_path(SYSTEM_PATH, ""), router)
+    }
+
+    /**
+     * 注册事件相关的路由信息
+     * 
+     * 将事件相关的路由信息注册到全局路由表中。
+     */
+    async fn register_event_routes() {
+        let routes = [(ROUTE_EVENTS_DEAD_LETTER, Method::GET, "查询死信事件")];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(SYSTEM_PATH, path),
+                method,
+                SERVICE_NAME_EVENT,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/**
+ * 邀请码路由模块
+ * 
+ * 该模块提供了域邀请码管理相关的路由功能，包括：
+ * - 获取邀请码列表
+ * - 创建邀请码
+ * - 撤销邀请码
+ */
+
+use axum::{
+    http::Method,
+    routing::{delete, get, post},
+    Router,
+};
+use server_api::admin::SysInvitationApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    INVITATION_PATH, SERVICE_NAME_INVITATION, ROUTE_ROOT, ROUTE_ID, build_route_path,
+};
+
+/**
+ * 邀请码路由结构体
+ * 
+ * 用于管理和注册邀请码相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysInvitationRouter;
+
+impl SysInvitationRouter {
+    /**
+     * 初始化邀请码路由
+     * 
+     * 注册并返回邀请码管理相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_invitation_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_invitation_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_ROOT, get(SysInvitationApi::get_paginated_invitations))
+            .route(ROUTE_ROOT, post(SysInvitationApi::create_invitation))
+            .route(ROUTE_ID, delete(SysInvitationApi::revoke_invitation));
+
+        Router::new().nest(&build_route_path(INVITATION_PATH, ""), router)
+    }
+
+    /**
+     * 注册邀请码相关的路由信息
+     * 
+     * 将邀请码相关的路由信息注册到全局路由表中。
+     */
+    async fn register_invitation_routes() {
+        let routes = [
+            (ROUTE_ROOT, Method::GET, "获取邀请码列表"),
+            (ROUTE_ROOT, Method::POST, "创建邀请码"),
+            (ROUTE_ID, Method::DELETE, "撤销邀请码"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(INVITATION_PATH, path),
+                method,
+                SERVICE_NAME_INVITATION,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/**
+ * 后台任务路由模块
+ * 
+ * 该模块提供了后台周期任务相关的路由功能，包括：
+ * - 查询任务执行状态
+ * - 立即执行任务
+ */
+
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysJobApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_JOB, ROUTE_JOBS, ROUTE_JOB_RUN, SYSTEM_PATH, build_route_path,
+};
+
+/**
+ * 后台任务路由结构体
+ * 
+ * 用于管理和注册后台任务相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysJobRouter;
+
+impl SysJobRouter {
+    /**
+     * 初始化后台任务路由
+     * 
+     * 注册并返回后台任务相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_job_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_job_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_JOBS, get(SysJobApi::list_jobs))
+            .route(ROUTE_JOB_RUN, post(SysJobApi::run_job));
+
+        Router::new().nest(&build_route_path(SYSTEM_PATH, ""), router)
+    }
+
+    /**
+     * 注册后台任务相关的路由信息
+     * 
+     * 将后台任务相关的路由信息注册到全局路由表中。
+     */
+    async fn register_job_routes() {
+        let routes = [
+            (ROUTE_JOBS, Method::GET, "查询后台任务"),
+            (ROUTE_JOB_RUN, Method::POST, "立即执行后台任务"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(SYSTEM_PATH, path),
+                method,
+                SERVICE_NAME_JOB,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/**
+ * 维护模式路由模块
+ * 
+ * 该模块提供了维护模式相关的路由功能，包括：
+ * - 获取维护模式状态
+ * - 设置维护模式
+ */
+
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysMaintenanceApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_MAINTENANCE, ROUTE_MAINTENANCE, SYSTEM_PATH, build_route_path,
+};
+
+/**
+ * 维护模式路由结构体
+ * 
+ * 用于管理和注册维护模式相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysMaintenanceRouter;
+
+impl SysMaintenanceRouter {
+    /**
+     * 维护模式设置接口的完整路径
+     * 
+     * 维护模式中间件据此放行该接口，保证维护期间仍可关闭维护模式。
+     * 
+     * # 返回
+     * * `String` - 完整的API路径
+     */
+    pub fn maintenance_path() -> String {
+        build_route_path(SYSTEM_PATH, ROUTE_MAINTENANCE)
+    }
+
+    /**
+     * 初始化维护模式路由
+     * 
+     * 注册并返回维护模式相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_maintenance_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_maintenance_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_MAINTENANCE, get(SysMaintenanceApi::get_maintenance))
+            .route(ROUTE_MAINTENANCE, post(SysMaintenanceApi::update_maintenance));
+
+        Router::new().nest(&build_route_path(SYSTEM_PATH, ""), router)
+    }
+
+    /**
+     * 注册维护模式相关的路由信息
+     * 
+     * 将维护模式相关的路由信息注册到全局路由表中。
+     */
+    async fn register_maintenance_routes() {
+        let routes = [
+            (ROUTE_MAINTENANCE, Method::GET, "获取维护模式状态"),
+            (ROUTE_MAINTENANCE, Method::POST, "设置维护模式"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(SYSTEM_PATH, path),
+                method,
+                SERVICE_NAME_MAINTENANCE,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
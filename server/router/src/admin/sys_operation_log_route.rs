@@ -3,15 +3,26 @@
  * 
  * 该模块提供了操作日志相关的路由功能，包括：
  * - 获取操作日志列表
+ * - 游标分页获取操作日志列表
+ * - 清理操作日志
  */
 
-use axum::{http::Method, routing::get, Router};
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
 use server_api::admin::SysOperationLogApi;
 use server_global::global::{add_route, RouteInfo};
 use super::route_constants::{
     OPERATION_LOG_PATH, SERVICE_NAME_OPERATION_LOG, ROUTE_ROOT, build_route_path,
 };
 
+/** 游标分页路由路径 */
+const ROUTE_CURSOR: &str = "/cursor";
+/** 清理路由路径 */
+const ROUTE_PURGE: &str = "/purge";
+
 /**
  * 操作日志路由结构体
  * 
@@ -35,7 +46,9 @@ impl SysOperationLogRouter {
 
         // 构建路由
         let router = Router::new()
-            .route(ROUTE_ROOT, get(SysOperationLogApi::get_paginated_operation_logs));
+            .route(ROUTE_ROOT, get(SysOperationLogApi::get_paginated_operation_logs))
+            .route(ROUTE_CURSOR, get(SysOperationLogApi::get_operation_logs_by_cursor))
+            .route(ROUTE_PURGE, post(SysOperationLogApi::purge_operation_logs));
 
         Router::new().nest(&build_route_path(OPERATION_LOG_PATH, ""), router)
     }
@@ -48,6 +61,8 @@ impl SysOperationLogRouter {
     async fn register_operation_log_routes() {
         let routes = [
             (ROUTE_ROOT, Method::GET, "获取操作日志列表"),
+            (ROUTE_CURSOR, Method::GET, "游标分页获取操作日志列表"),
+            (ROUTE_PURGE, Method::POST, "清理操作日志"),
         ];
 
         for (path, method, description) in routes {
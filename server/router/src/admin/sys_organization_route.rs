@@ -2,20 +2,31 @@
  * 组织路由模块
  * 
  * 该模块提供了组织管理相关的路由功能，包括：
- * - 获取组织列表
+ * - 获取组织列表（无需认证）
+ * - 获取组织详情
+ * - 创建组织
+ * - 更新组织
+ * - 删除组织
+ * - 转移组织用户
  */
 
-use axum::{http::Method, routing::get, Router};
+use axum::{
+    http::Method,
+    routing::{delete, get, post, put},
+    Router,
+};
 use server_api::admin::SysOrganizationApi;
 use server_global::global::{add_route, RouteInfo};
 use super::route_constants::{
-    ROUTE_ROOT, build_route_path,
+    ROUTE_ROOT, ROUTE_ID, build_route_path,
 };
 
 /** 组织模块路径 */
 const ORG_PATH: &str = "/org";
 /** 组织服务名称 */
 const SERVICE_NAME_ORG: &str = "SysOrganizationApi";
+/** 转移组织用户路由路径 */
+const ROUTE_TRANSFER_USERS: &str = "/{id}/transfer-users";
 
 /**
  * 组织路由结构体
@@ -29,18 +40,37 @@ impl SysOrganizationRouter {
     /**
      * 初始化组织路由
      * 
-     * 注册并返回组织相关的所有路由。
+     * 注册并返回无需认证即可访问的组织列表路由。
      * 
      * # 返回
      * * `Router` - 配置好的路由实例
      */
     pub async fn init_organization_router() -> Router {
+        let router = Router::new()
+            .route(ROUTE_ROOT, get(SysOrganizationApi::get_paginated_organizations));
+
+        Router::new().nest(&build_route_path(ORG_PATH, ""), router)
+    }
+
+    /**
+     * 初始化需要认证的路由
+     * 
+     * 注册并返回需要用户认证才能访问的组织管理路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_protected_organization_router() -> Router {
         // 注册路由信息到全局路由表
         Self::register_organization_routes().await;
 
         // 构建路由
         let router = Router::new()
-            .route(ROUTE_ROOT, get(SysOrganizationApi::get_paginated_organizations));
+            .route(ROUTE_ROOT, post(SysOrganizationApi::create_organization))
+            .route(ROUTE_ROOT, put(SysOrganizationApi::update_organization))
+            .route(ROUTE_ID, get(SysOrganizationApi::get_organization))
+            .route(ROUTE_ID, delete(SysOrganizationApi::delete_organization))
+            .route(ROUTE_TRANSFER_USERS, post(SysOrganizationApi::transfer_users));
 
         Router::new().nest(&build_route_path(ORG_PATH, ""), router)
     }
@@ -53,6 +83,11 @@ impl SysOrganizationRouter {
     async fn register_organization_routes() {
         let routes = [
             (ROUTE_ROOT, Method::GET, "获取组织列表"),
+            (ROUTE_ROOT, Method::POST, "创建组织"),
+            (ROUTE_ROOT, Method::PUT, "更新组织"),
+            (ROUTE_ID, Method::GET, "获取组织详情"),
+            (ROUTE_ID, Method::DELETE, "删除组织"),
+            (ROUTE_TRANSFER_USERS, Method::POST, "转移组织用户"),
         ];
 
         for (path, method, description) in routes {
@@ -0,0 +1,77 @@
+/**
+ * 策略查看路由模块
+ * 
+ * 该模块提供了策略查看相关的路由功能，包括：
+ * - 查询策略规则
+ * - 权限判定试算（记录操作日志）
+ * - 重新加载策略
+ */
+
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysPolicyApi;
+use server_core::web::operation_log::OperationLogLayer;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    POLICY_PATH, SERVICE_NAME_POLICY, ROUTE_ROOT, ROUTE_CHECK, ROUTE_RELOAD, build_route_path,
+};
+
+/**
+ * 策略查看路由结构体
+ * 
+ * 用于管理和注册策略查看相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysPolicyRouter;
+
+impl SysPolicyRouter {
+    /**
+     * 初始化策略查看路由
+     * 
+     * 注册并返回策略查看相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_policy_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_policy_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_ROOT, get(SysPolicyApi::get_policies))
+            .route(
+                ROUTE_CHECK,
+                get(SysPolicyApi::check_policy).layer(OperationLogLayer::new(true)),
+            )
+            .route(ROUTE_RELOAD, post(SysPolicyApi::reload_policies));
+
+        Router::new().nest(&build_route_path(POLICY_PATH, ""), router)
+    }
+
+    /**
+     * 注册策略查看相关的路由信息
+     * 
+     * 将策略查看相关的路由信息注册到全局路由表中。
+     */
+    async fn register_policy_routes() {
+        let routes = [
+            (ROUTE_ROOT, Method::GET, "获取策略列表"),
+            (ROUTE_CHECK, Method::GET, "权限判定试算"),
+            (ROUTE_RELOAD, Method::POST, "重新加载策略"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(POLICY_PATH, path),
+                method,
+                SERVICE_NAME_POLICY,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -7,6 +7,9 @@
  * - 获取角色详情
  * - 更新角色
  * - 删除角色
+ * - 获取角色下的用户
+ * - 导出、导入角色权限
+ * - 从角色模板创建角色
  */
 
 use axum::{
@@ -20,6 +23,15 @@ use super::route_constants::{
     ROLE_PATH, SERVICE_NAME_ROLE, ROUTE_ROOT, ROUTE_ID, build_route_path,
 };
 
+/** 角色用户路由路径 */
+const ROUTE_ROLE_USERS: &str = "/{id}/users";
+/** 角色权限导出路由路径 */
+const ROUTE_ROLE_PERMISSIONS_EXPORT: &str = "/{id}/permissions/export";
+/** 角色权限导入路由路径 */
+const ROUTE_ROLE_PERMISSIONS_IMPORT: &str = "/permissions/import";
+/** 从模板创建角色路由路径 */
+const ROUTE_ROLE_FROM_TEMPLATE: &str = "/from-template";
+
 /**
  * 角色路由结构体
  * 
@@ -47,7 +59,11 @@ impl SysRoleRouter {
             .route(ROUTE_ROOT, post(SysRoleApi::create_role))
             .route(ROUTE_ID, get(SysRoleApi::get_role))
             .route(ROUTE_ROOT, put(SysRoleApi::update_role))
-            .route(ROUTE_ID, delete(SysRoleApi::delete_role));
+            .route(ROUTE_ID, delete(SysRoleApi::delete_role))
+            .route(ROUTE_ROLE_USERS, get(SysRoleApi::find_paginated_role_users))
+            .route(ROUTE_ROLE_PERMISSIONS_EXPORT, get(SysRoleApi::export_role_permissions))
+            .route(ROUTE_ROLE_PERMISSIONS_IMPORT, post(SysRoleApi::import_role_permissions))
+            .route(ROUTE_ROLE_FROM_TEMPLATE, post(SysRoleApi::create_role_from_template));
 
         Router::new().nest(&build_route_path(ROLE_PATH, ""), router)
     }
@@ -64,6 +80,10 @@ impl SysRoleRouter {
             (ROUTE_ID, Method::GET, "获取角色详情"),
             (ROUTE_ROOT, Method::PUT, "更新角色"),
             (ROUTE_ID, Method::DELETE, "删除角色"),
+            (ROUTE_ROLE_USERS, Method::GET, "获取角色下的用户"),
+            (ROUTE_ROLE_PERMISSIONS_EXPORT, Method::GET, "导出角色权限"),
+            (ROUTE_ROLE_PERMISSIONS_IMPORT, Method::POST, "导入角色权限"),
+            (ROUTE_ROLE_FROM_TEMPLATE, Method::POST, "从模板创建角色"),
         ];
 
         for (path, method, description) in routes {
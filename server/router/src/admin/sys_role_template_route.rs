@@ -0,0 +1,79 @@
+/**
+ * 角色模板路由模块
+ * 
+ * 该模块提供了角色模板管理相关的路由功能，包括：
+ * - 获取角色模板列表
+ * - 创建角色模板
+ * - 获取角色模板详情
+ * - 更新角色模板
+ * - 删除角色模板
+ */
+
+use axum::{
+    http::Method,
+    routing::{delete, get, post, put},
+    Router,
+};
+use server_api::admin::SysRoleTemplateApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    ROLE_TEMPLATE_PATH, SERVICE_NAME_ROLE_TEMPLATE, ROUTE_ROOT, ROUTE_ID, build_route_path,
+};
+
+/**
+ * 角色模板路由结构体
+ * 
+ * 用于管理和注册角色模板相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysRoleTemplateRouter;
+
+impl SysRoleTemplateRouter {
+    /**
+     * 初始化角色模板路由
+     * 
+     * 注册并返回角色模板相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_role_template_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_role_template_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_ROOT, get(SysRoleTemplateApi::find_role_templates))
+            .route(ROUTE_ROOT, post(SysRoleTemplateApi::create_role_template))
+            .route(ROUTE_ID, get(SysRoleTemplateApi::get_role_template))
+            .route(ROUTE_ROOT, put(SysRoleTemplateApi::update_role_template))
+            .route(ROUTE_ID, delete(SysRoleTemplateApi::delete_role_template));
+
+        Router::new().nest(&build_route_path(ROLE_TEMPLATE_PATH, ""), router)
+    }
+
+    /**
+     * 注册角色模板相关的路由信息
+     * 
+     * 将角色模板相关的路由信息注册到全局路由表中。
+     */
+    async fn register_role_template_routes() {
+        let routes = [
+            (ROUTE_ROOT, Method::GET, "获取角色模板列表"),
+            (ROUTE_ROOT, Method::POST, "创建角色模板"),
+            (ROUTE_ID, Method::GET, "获取角色模板详情"),
+            (ROUTE_ROOT, Method::PUT, "更新角色模板"),
+            (ROUTE_ID, Method::DELETE, "删除角色模板"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(ROLE_TEMPLATE_PATH, path),
+                method,
+                SERVICE_NAME_ROLE_TEMPLATE,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
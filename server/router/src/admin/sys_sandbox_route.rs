@@ -4,9 +4,13 @@
  * 该模块提供了沙箱测试相关的路由功能，包括：
  * - 简单API密钥测试
  * - 复杂API密钥测试
+ * - 复杂API密钥签名调试
  */
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use server_api::admin::SysSandboxApi;
 use super::route_constants::build_route_path;
 
@@ -16,6 +20,8 @@ const SANDBOX_PATH: &str = "/sandbox";
 const ROUTE_SIMPLE_API_KEY: &str = "/simple-api-key";
 /** 复杂API密钥测试路由路径 */
 const ROUTE_COMPLEX_API_KEY: &str = "/complex-api-key";
+/** 签名调试路由路径 */
+const ROUTE_SIGN_DEBUG: &str = "/sign-debug";
 
 /**
  * 沙箱路由结构体
@@ -26,17 +32,34 @@ const ROUTE_COMPLEX_API_KEY: &str = "/complex-api-key";
 pub struct SysSandboxRouter;
 
 impl SysSandboxRouter {
+    /**
+     * 需要API密钥验证的沙箱接口完整路径
+     * 
+     * API密钥中间件按完整请求路径判断是否需要验证。
+     * 
+     * # 返回
+     * * `Vec<String>` - 完整的API路径列表
+     */
+    pub fn protected_paths() -> Vec<String> {
+        [ROUTE_SIMPLE_API_KEY, ROUTE_COMPLEX_API_KEY, ROUTE_SIGN_DEBUG]
+            .into_iter()
+            .map(|route| build_route_path(SANDBOX_PATH, route))
+            .collect()
+    }
+
     /**
      * 初始化简单沙箱路由
      * 
-     * 注册并返回简单API密钥测试相关的路由。
+     * 注册并返回简单API密钥保护的路由，包括简单API密钥测试和签名调试。
+     * 签名调试接口需要 `Extension<Arc<ComplexApiKeyConfig>>`。
      * 
      * # 返回
      * * `Router` - 配置好的路由实例
      */
     pub async fn init_simple_sandbox_router() -> Router {
         let router = Router::new()
-            .route(ROUTE_SIMPLE_API_KEY, get(SysSandboxApi::test_simple_api_key));
+            .route(ROUTE_SIMPLE_API_KEY, get(SysSandboxApi::test_simple_api_key))
+            .route(ROUTE_SIGN_DEBUG, post(SysSandboxApi::sign_debug));
 
         Router::new().nest(&build_route_path(SANDBOX_PATH, ""), router)
     }
@@ -59,10 +82,17 @@ impl SysSandboxRouter {
 
 #[cfg(test)]
 mod tests {
-    #[allow(unused_variables)]
+    use super::*;
+
     #[test]
     fn test_route_paths() {
-        // 这里可以添加路由测试逻辑
-        // 例如验证路由是否正确注册，路径是否正确等
+        assert_eq!(
+            SysSandboxRouter::protected_paths(),
+            [
+                "/api/v1/sandbox/simple-api-key",
+                "/api/v1/sandbox/complex-api-key",
+                "/api/v1/sandbox/sign-debug",
+            ]
+        );
     }
 }
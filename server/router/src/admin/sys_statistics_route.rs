@@ -0,0 +1,60 @@
+/**
+ * 统计路由模块
+ * 
+ * 该模块提供了统计相关的路由功能，包括：
+ * - 获取域统计概览
+ */
+
+use axum::{http::Method, routing::get, Router};
+use server_api::admin::SysStatisticsApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_STATISTICS, ROUTE_OVERVIEW, STATISTICS_PATH, build_route_path,
+};
+
+/**
+ * 统计路由结构体
+ * 
+ * 用于管理和注册统计相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysStatisticsRouter;
+
+impl SysStatisticsRouter {
+    /**
+     * 初始化统计路由
+     * 
+     * 注册并返回统计相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_statistics_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_statistics_routes().await;
+
+        // 构建路由
+        let router = Router::new().route(ROUTE_OVERVIEW, get(SysStatisticsApi::get_overview));
+
+        Router::new().nest(&build_route_path(STATISTICS_PATH, ""), router)
+    }
+
+    /**
+     * 注册统计相关的路由信息
+     * 
+     * 将统计相关的路由信息注册到全局路由表中。
+     */
+    async fn register_statistics_routes() {
+        let routes = [(ROUTE_OVERVIEW, Method::GET, "获取域统计概览")];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(STATISTICS_PATH, path),
+                method,
+                SERVICE_NAME_STATISTICS,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
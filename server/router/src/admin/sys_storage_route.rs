@@ -0,0 +1,91 @@
+/**
+ * 对象存储路由模块
+ * 
+ * 该模块提供了对象存储相关的路由功能，包括：
+ * - 按预签名链接下载本地存储的文件
+ * - 代理下载文件，支持按范围读取
+ */
+
+use axum::{http::Method, routing::get, Router};
+use server_api::admin::SysStorageApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_STORAGE, ROUTE_FILES, ROUTE_FILE_KEY, ROUTE_PROXY_KEY, STORAGE_PATH,
+    build_route_path,
+};
+
+/**
+ * 对象存储路由结构体
+ * 
+ * 用于管理和注册对象存储相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysStorageRouter;
+
+impl SysStorageRouter {
+    /**
+     * 文件下载接口的完整路径
+     * 
+     * 本地存储未配置 `public_url` 时，预签名链接以该路径为前缀。
+     * 
+     * # 返回
+     * * `String` - 完整的API路径
+     */
+    pub fn files_path() -> String {
+        build_route_path(STORAGE_PATH, ROUTE_FILES)
+    }
+
+    /**
+     * 初始化对象存储路由
+     * 
+     * 返回无需认证即可访问的预签名链接下载路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_storage_router() -> Router {
+        let router = Router::new().route(ROUTE_FILE_KEY, get(SysStorageApi::download_file));
+
+        Router::new().nest(&build_route_path(STORAGE_PATH, ""), router)
+    }
+
+    /**
+     * 初始化需要认证的路由
+     * 
+     * 注册对象存储相关的所有路由信息，返回需要用户认证才能访问的代理下载路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_protected_storage_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_storage_routes().await;
+
+        // 构建路由
+        let router = Router::new().route(ROUTE_PROXY_KEY, get(SysStorageApi::proxy_file));
+
+        Router::new().nest(&build_route_path(STORAGE_PATH, ""), router)
+    }
+
+    /**
+     * 注册对象存储相关的路由信息
+     * 
+     * 将对象存储相关的路由信息注册到全局路由表中。
+     */
+    async fn register_storage_routes() {
+        let routes = [
+            (ROUTE_FILE_KEY, Method::GET, "按预签名链接下载文件"),
+            (ROUTE_PROXY_KEY, Method::GET, "代理下载文件"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(STORAGE_PATH, path),
+                method,
+                SERVICE_NAME_STORAGE,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
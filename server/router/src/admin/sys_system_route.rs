@@ -0,0 +1,70 @@
+/**
+ * 系统信息路由模块
+ * 
+ * 该模块提供了系统信息相关的路由功能，包括：
+ * - 查询版本信息
+ * - 重新连接主库
+ */
+
+use axum::{
+    http::Method,
+    routing::{get, post},
+    Router,
+};
+use server_api::admin::SysSystemApi;
+use server_global::global::{add_route, RouteInfo};
+use super::route_constants::{
+    SERVICE_NAME_SYSTEM, ROUTE_DB_RECONNECT, ROUTE_VERSION, SYSTEM_PATH, build_route_path,
+};
+
+/**
+ * 系统信息路由结构体
+ * 
+ * 用于管理和注册系统信息相关的路由。
+ */
+#[derive(Debug)]
+pub struct SysSystemRouter;
+
+impl SysSystemRouter {
+    /**
+     * 初始化系统信息路由
+     * 
+     * 注册并返回系统信息相关的所有路由。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_system_router() -> Router {
+        // 注册路由信息到全局路由表
+        Self::register_system_routes().await;
+
+        // 构建路由
+        let router = Router::new()
+            .route(ROUTE_VERSION, get(SysSystemApi::get_version))
+            .route(ROUTE_DB_RECONNECT, post(SysSystemApi::reconnect_db));
+
+        Router::new().nest(&build_route_path(SYSTEM_PATH, ""), router)
+    }
+
+    /**
+     * 注册系统信息相关的路由信息
+     * 
+     * 将系统信息相关的路由信息注册到全局路由表中。
+     */
+    async fn register_system_routes() {
+        let routes = [
+            (ROUTE_VERSION, Method::GET, "查询版本信息"),
+            (ROUTE_DB_RECONNECT, Method::POST, "重新连接主库"),
+        ];
+
+        for (path, method, description) in routes {
+            let route_info = RouteInfo::new(
+                &build_route_path(SYSTEM_PATH, path),
+                method,
+                SERVICE_NAME_SYSTEM,
+                description,
+            );
+            add_route(route_info).await;
+        }
+    }
+}
@@ -8,8 +8,10 @@
  * - 获取用户详情
  * - 更新用户
  * - 删除用户
+ * - 获取用户的角色
  * - 添加用户策略
  * - 删除用户策略
+ * - 创建、查看、撤销本人的个人访问令牌
  */
 
 use axum::{
@@ -29,10 +31,16 @@ const USER_PATH: &str = "/user";
 const SERVICE_NAME_USER: &str = "SysUserApi";
 /** 所有用户路由路径 */
 const ROUTE_USERS: &str = "/users";
+/** 用户角色路由路径 */
+const ROUTE_USER_ROLES: &str = "/{id}/roles";
 /** 添加策略路由路径 */
 const ROUTE_ADD_POLICIES: &str = "/add_policies";
 /** 删除策略路由路径 */
 const ROUTE_REMOVE_POLICIES: &str = "/remove_policies";
+/** 个人访问令牌路由路径 */
+const ROUTE_TOKENS: &str = "/tokens";
+/** 撤销个人访问令牌路由路径 */
+const ROUTE_TOKEN: &str = "/tokens/{id}";
 
 /**
  * 用户路由结构体
@@ -63,12 +71,31 @@ impl SysUserRouter {
             .route(ROUTE_ID, get(SysUserApi::get_user))
             .route(ROUTE_ROOT, put(SysUserApi::update_user))
             .route(ROUTE_ID, delete(SysUserApi::delete_user))
+            .route(ROUTE_USER_ROLES, get(SysUserApi::get_user_roles))
             .route(ROUTE_ADD_POLICIES, get(SysUserApi::add_policies))
             .route(ROUTE_REMOVE_POLICIES, get(SysUserApi::remove_policies));
 
         Router::new().nest(&build_route_path(USER_PATH, ""), router)
     }
 
+    /**
+     * 初始化个人访问令牌路由
+     * 
+     * 只需用户认证，不经过权限校验，也不登记到全局路由表，
+     * 因此这些接口不能作为个人访问令牌的权限范围。
+     * 
+     * # 返回
+     * * `Router` - 配置好的路由实例
+     */
+    pub async fn init_personal_token_router() -> Router {
+        let router = Router::new()
+            .route(ROUTE_TOKENS, post(SysUserApi::create_personal_token))
+            .route(ROUTE_TOKENS, get(SysUserApi::list_personal_tokens))
+            .route(ROUTE_TOKEN, delete(SysUserApi::revoke_personal_token));
+
+        Router::new().nest(&build_route_path(USER_PATH, ""), router)
+    }
+
     /**
      * 注册用户相关的路由信息
      * 
@@ -82,6 +109,7 @@ impl SysUserRouter {
             (ROUTE_ID, Method::GET, "获取用户详情"),
             (ROUTE_ROOT, Method::PUT, "更新用户"),
             (ROUTE_ID, Method::DELETE, "删除用户"),
+            (ROUTE_USER_ROLES, Method::GET, "获取用户的角色"),
             (ROUTE_ADD_POLICIES, Method::GET, "添加用户策略"),
             (ROUTE_REMOVE_POLICIES, Method::GET, "删除用户策略"),
         ];
@@ -19,14 +19,30 @@
  * * `UserError`: 用户服务错误
  * * `RoleError`: 角色服务错误
  * * `DomainError`: 域名服务错误
+ * * `DomainFeatureError`: 域功能开关服务错误
  * * `AccessKeyError`: 访问密钥服务错误
  * * `AuthorizationError`: 授权服务错误
+ * * `PolicyError`: 策略查看服务错误
+ * * `OrganizationError`: 组织服务错误
+ * * `InvitationError`: 邀请码服务错误
+ * * `JobError`: 后台任务服务错误
+ * * `TokenError`: 登录令牌服务错误
+ * * `RoleTemplateError`: 角色模板服务错误
+ * * `PersonalTokenError`: 个人访问令牌服务错误
+ * * `StatisticsError`: 统计服务错误
+ * * `SystemError`: 系统管理服务错误
+ * * `ComplianceError`: 合规审计导出服务错误
  * 
  * 错误处理宏
  * --------
  * * `impl_from_common_error`: 实现从通用错误转换
  * * `impl_from_db_error`: 实现从数据库错误转换
  * 
+ * 本地化
+ * --------
+ * 响应消息按请求语言从 `server_core` 的消息目录（`error.<错误码>`）中查找，
+ * 带参数的错误通过 `ApiError::message_args` 提供模板参数。新增错误码时需同时补充各语言的消息。
+ * 
  * 使用示例
  * --------
  * /* 创建服务错误
@@ -48,11 +64,22 @@ pub mod sys_user_error;
 pub mod sys_role_error;
 pub mod sys_menu_error;
 pub mod sys_domain_error;
+pub mod sys_domain_feature_error;
 pub mod sys_endpoint_error;
 pub mod sys_operation_log_error;
 pub mod sys_login_log_error;
 pub mod sys_access_key_error;
 pub mod sys_authorization_error;
+pub mod sys_organization_error;
+pub mod sys_policy_error;
+pub mod sys_invitation_error;
+pub mod sys_job_error;
+pub mod sys_token_error;
+pub mod sys_role_template_error;
+pub mod sys_personal_token_error;
+pub mod sys_statistics_error;
+pub mod sys_system_error;
+pub mod sys_compliance_error;
 
 // Re-export base types and macros
 pub use base_error::{CommonError, ServiceError};
@@ -63,5 +90,16 @@ pub use sys_auth_error::AuthError;
 pub use sys_user_error::UserError;
 pub use sys_role_error::RoleError;
 pub use sys_domain_error::DomainError;
+pub use sys_domain_feature_error::DomainFeatureError;
 pub use sys_access_key_error::AccessKeyError;
 pub use sys_authorization_error::AuthorizationError;
+pub use sys_organization_error::OrganizationError;
+pub use sys_policy_error::PolicyError;
+pub use sys_invitation_error::InvitationError;
+pub use sys_job_error::JobError;
+pub use sys_token_error::TokenError;
+pub use sys_role_template_error::RoleTemplateError;
+pub use sys_personal_token_error::PersonalTokenError;
+pub use sys_statistics_error::StatisticsError;
+pub use sys_system_error::SystemError;
+pub use sys_compliance_error::ComplianceError;
@@ -11,6 +11,7 @@
  * - 密钥过期
  * - 密钥被撤销
  * - 密钥使用限制
+ * - nonce 保留时间不足
  * - 数据库操作错误
  * 
  * 错误代码
@@ -21,6 +22,7 @@
  * - 2004: 访问密钥已撤销
  * - 2005: 访问密钥使用限制
  * - 2006: 数据库操作失败
+ * - 2007: nonce 保留时间短于时间戳窗口
  * 
  * 使用示例
  * --------
@@ -39,7 +41,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -63,6 +65,9 @@ pub enum AccessKeyError {
 
     #[error("Database operation failed: {0}")]
     DatabaseOperationFailed(String),
+
+    #[error("Nonce TTL must be at least {0} seconds to cover the timestamp window")]
+    NonceTtlTooShort(u64),
 }
 
 impl ApiError for AccessKeyError {
@@ -74,20 +79,26 @@ impl ApiError for AccessKeyError {
             AccessKeyError::AccessKeyRevoked => 2004,
             AccessKeyError::AccessKeyLimitExceeded => 2005,
             AccessKeyError::DatabaseOperationFailed(_) => 2006,
+            AccessKeyError::NonceTtlTooShort(_) => 2007,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            AccessKeyError::AccessKeyNotFound => StatusCode::NOT_FOUND,
+            AccessKeyError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<AccessKeyError> for AppError {
     fn from(err: AccessKeyError) -> Self {
-        AppError {
-            code: err.code() as u16,
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
@@ -15,6 +15,12 @@
  * - JWT令牌验证失败
  * - JWT令牌刷新失败
  * - 数据库操作失败
+ * - 登录失败次数过多
+ * - 注册用户名已存在
+ * - 用户已禁用
+ * - 用户所属域已禁用
+ * - 用户不属于要切换的域
+ * - 邀请码错误（原样透传邀请码错误码）
  * 
  * 错误代码
  * --------
@@ -27,6 +33,11 @@
  * - 9007: JWT令牌验证失败
  * - 9008: JWT令牌刷新失败
  * - 9009: 数据库操作失败
+ * - 9010: 登录失败次数过多
+ * - 9011: 注册用户名已存在
+ * - 9012: 用户已禁用
+ * - 9013: 用户所属域已禁用
+ * - 9014: 用户不属于要切换的域
  * 
  * 使用示例
  * --------
@@ -45,9 +56,12 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
-use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
+use crate::admin::errors::{
+    CommonError, InvitationError, impl_from_common_error, impl_from_db_error,
+};
+use crate::admin::event_handlers::auth_event_handler::AuthEvent;
 use sea_orm::DbErr;
 
 #[derive(Debug, Error)]
@@ -62,7 +76,7 @@ pub enum AuthError {
     AuthenticationFailed(String),
 
     #[error("Failed to send event: {0}")]
-    SendError(#[from] tokio::sync::mpsc::error::SendError<Box<dyn std::any::Any + Send>>),
+    SendError(#[from] tokio::sync::mpsc::error::SendError<AuthEvent>),
 
     #[error("Failed to handle login event: {0}")]
     LoginHandlerError(String),
@@ -78,6 +92,30 @@ pub enum AuthError {
 
     #[error("Database operation failed: {0}")]
     DatabaseOperationFailed(String),
+
+    #[error("Too many failed login attempts, please try again later")]
+    TooManyLoginAttempts,
+
+    #[error("Username already exists")]
+    UsernameAlreadyExists,
+
+    #[error("User is disabled")]
+    UserDisabled,
+
+    #[error("Domain is disabled")]
+    DomainDisabled,
+
+    #[error("User is not a member of domain '{0}'")]
+    DomainNotMember(String),
+
+    #[error("Token roles are unavailable")]
+    TokenRolesUnavailable,
+
+    #[error("Personal access tokens cannot be used for this operation")]
+    PersonalTokenNotAllowed,
+
+    #[error(transparent)]
+    Invitation(#[from] InvitationError),
 }
 
 impl ApiError for AuthError {
@@ -92,20 +130,50 @@ impl ApiError for AuthError {
             AuthError::JwtValidationFailed => 9007,
             AuthError::JwtRefreshFailed => 9008,
             AuthError::DatabaseOperationFailed(_) => 9009,
+            AuthError::TooManyLoginAttempts => 9010,
+            AuthError::UsernameAlreadyExists => 9011,
+            AuthError::UserDisabled => 9012,
+            AuthError::DomainDisabled => 9013,
+            AuthError::DomainNotMember(_) => 9014,
+            AuthError::TokenRolesUnavailable => 9015,
+            AuthError::PersonalTokenNotAllowed => 9016,
+            AuthError::Invitation(err) => err.code(),
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            AuthError::AuthenticationFailed(msg)
+            | AuthError::LoginHandlerError(msg)
+            | AuthError::JwtGenerationFailed(msg)
+            | AuthError::DatabaseOperationFailed(msg)
+            | AuthError::DomainNotMember(msg) => vec![msg.clone()],
+            AuthError::SendError(err) => vec![err.to_string()],
+            AuthError::Invitation(err) => err.message_args(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            AuthError::Invitation(err) => err.status(),
+            AuthError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AuthError::DomainNotMember(_) | AuthError::PersonalTokenNotAllowed => {
+                StatusCode::FORBIDDEN
+            },
+            AuthError::TokenRolesUnavailable => StatusCode::UNAUTHORIZED,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<AuthError> for AppError {
     fn from(err: AuthError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
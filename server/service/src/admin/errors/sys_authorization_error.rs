@@ -18,6 +18,9 @@
  * - 资源不存在
  * - 验证错误
  * - 内部错误
+ * - 接口选择器未匹配任何接口
+ * - 分配当前用户未持有的接口或路由（越权分配）
+ * - 其他服务返回的应用错误（原样透传错误码和HTTP状态码）
  * 
  * 错误代码
  * --------
@@ -33,6 +36,9 @@
  * - 3010: 资源不存在
  * - 3011: 验证失败
  * - 3012: 内部错误
+ * - 3013: 接口选择器未匹配任何接口
+ * - 3014: 分配当前用户未持有的接口
+ * - 3015: 分配当前用户未持有的路由
  * 
  * 使用示例
  * --------
@@ -58,7 +64,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -76,6 +82,9 @@ pub const ERROR_AUTHORIZATION: u16 = 3009;
 pub const ERROR_NOT_FOUND: u16 = 3010;
 pub const ERROR_VALIDATION: u16 = 3011;
 pub const ERROR_INTERNAL: u16 = 3012;
+pub const ERROR_SELECTORS_MATCHED_NOTHING: u16 = 3013;
+pub const ERROR_ENDPOINTS_NOT_HELD: u16 = 3014;
+pub const ERROR_ROUTES_NOT_HELD: u16 = 3015;
 
 #[derive(Error, Debug)]
 pub enum AuthorizationError {
@@ -107,7 +116,7 @@ pub enum AuthorizationError {
     PermissionDenied { reason: String },
 
     #[error("Database operation failed: {0}")]
-    DatabaseError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    DatabaseError(String),
 
     #[error("Authentication failed: {0}")]
     AuthenticationError(String),
@@ -123,6 +132,18 @@ pub enum AuthorizationError {
 
     #[error("Internal server error: {0}")]
     InternalError(String),
+
+    #[error("Endpoint selectors matched no endpoints: {}", selectors.join("; "))]
+    SelectorsMatchedNothing { selectors: Vec<String> },
+
+    #[error("Cannot assign endpoints not held by the current user: {endpoint_ids:?}")]
+    EndpointsNotHeld { endpoint_ids: Vec<String> },
+
+    #[error("Cannot assign routes not held by the current user: {route_ids:?}")]
+    RoutesNotHeld { route_ids: Vec<i32> },
+
+    #[error(transparent)]
+    App(#[from] AppError),
 }
 
 impl ApiError for AuthorizationError {
@@ -140,19 +161,48 @@ impl ApiError for AuthorizationError {
             AuthorizationError::NotFoundError(_) => ERROR_NOT_FOUND,
             AuthorizationError::ValidationError(_) => ERROR_VALIDATION,
             AuthorizationError::InternalError(_) => ERROR_INTERNAL,
+            AuthorizationError::SelectorsMatchedNothing { .. } => ERROR_SELECTORS_MATCHED_NOTHING,
+            AuthorizationError::EndpointsNotHeld { .. } => ERROR_ENDPOINTS_NOT_HELD,
+            AuthorizationError::RoutesNotHeld { .. } => ERROR_ROUTES_NOT_HELD,
+            AuthorizationError::App(err) => err.code,
         }
     }
 
     fn message(&self) -> String {
-        format!("{}", self)
+        match self {
+            AuthorizationError::App(err) => err.message.clone(),
+            _ => format!("{}", self),
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            AuthorizationError::DomainNotFound { .. }
+            | AuthorizationError::RoleNotFound { .. }
+            | AuthorizationError::PermissionsNotFound { .. }
+            | AuthorizationError::RoutesNotFound { .. }
+            | AuthorizationError::UsersNotFound { .. }
+            | AuthorizationError::NotFoundError(_) => StatusCode::NOT_FOUND,
+            AuthorizationError::PermissionDenied { .. }
+            | AuthorizationError::AuthorizationError(_)
+            | AuthorizationError::EndpointsNotHeld { .. }
+            | AuthorizationError::RoutesNotHeld { .. } => StatusCode::FORBIDDEN,
+            AuthorizationError::AuthenticationError(_) => StatusCode::UNAUTHORIZED,
+            AuthorizationError::ValidationError(_) => StatusCode::BAD_REQUEST,
+            AuthorizationError::SelectorsMatchedNothing { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            AuthorizationError::DatabaseError(_) | AuthorizationError::InternalError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            },
+            AuthorizationError::App(err) => err.status,
+        }
     }
 }
 
 impl From<AuthorizationError> for AppError {
     fn from(err: AuthorizationError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
+        match err {
+            AuthorizationError::App(err) => err,
+            err => AppError::from_api_error(&err),
         }
     }
 }
@@ -184,10 +234,7 @@ impl AuthorizationError {
     }
 
     pub fn database_error(msg: String) -> Self {
-        Self::DatabaseError(Box::new(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            msg,
-        )))
+        Self::DatabaseError(msg)
     }
 
     pub fn authentication_error(msg: String) -> Self {
@@ -209,10 +256,55 @@ impl AuthorizationError {
     pub fn internal_error(msg: String) -> Self {
         Self::InternalError(msg)
     }
+
+    pub fn selectors_matched_nothing(selectors: Vec<String>) -> Self {
+        Self::SelectorsMatchedNothing { selectors }
+    }
+
+    pub fn endpoints_not_held(endpoint_ids: Vec<String>) -> Self {
+        Self::EndpointsNotHeld { endpoint_ids }
+    }
+
+    pub fn routes_not_held(route_ids: Vec<i32>) -> Self {
+        Self::RoutesNotHeld { route_ids }
+    }
 }
 
 // Implement From<CommonError> for AuthorizationError
 impl_from_common_error!(AuthorizationError);
 
 // Implement From<DbErr> for AuthorizationError
-impl_from_db_error!(AuthorizationError); 
\ No newline at end of file
+impl_from_db_error!(AuthorizationError); 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_not_found_maps_to_404() {
+        let err = AuthorizationError::role_not_found("r1".to_string(), "ROLE".to_string());
+        let app_error = AppError::from(err);
+        assert_eq!(app_error.code, ERROR_ROLE_NOT_FOUND);
+        assert_eq!(app_error.status, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_app_error_round_trip() {
+        let source = AppError::new(503, "service unavailable");
+        let err = AuthorizationError::from(source);
+        assert_eq!(err.code(), 503);
+        assert_eq!(err.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let app_error = AppError::from(err);
+        assert_eq!(app_error.code, 503);
+        assert_eq!(app_error.message, "service unavailable");
+        assert_eq!(app_error.status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn test_db_error_is_database_error() {
+        let err = AuthorizationError::from(DbErr::Custom("boom".to_string()));
+        assert!(matches!(err, AuthorizationError::DatabaseError(_)));
+        assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,137 @@
+/*! 合规审计错误模块
+ *
+ * 该模块定义了合规审计导出时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * ComplianceError 定义了合规审计导出相关的所有错误情况，包括：
+ * - 非内置域用户发起或查询导出
+ * - 时间范围无效
+ * - 未配置对象存储
+ * - 导出队列已满
+ * - 导出任务不存在
+ * - 数据库操作失败
+ * - 生成或上传导出文件失败
+ *
+ * 错误代码
+ * --------
+ * - 19001: 只有内置域用户可以导出审计数据
+ * - 19002: 开始时间必须早于结束时间
+ * - 19003: 未配置对象存储
+ * - 19004: 导出队列已满
+ * - 19005: 导出任务不存在
+ * - 19006: 数据库操作失败
+ * - 19007: 生成导出文件失败
+ *
+ * 使用示例
+ * --------
+ * /* 查询不存在的导出任务
+ *  * let error = ComplianceError::JobNotFound(job_id.to_string());
+ *  */
+ */
+
+use sea_orm::DbErr;
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use server_storage::StorageError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ComplianceError {
+    #[error("Only users of the built-in domain can export audit data")]
+    BuiltInDomainOnly,
+
+    #[error("Start time must be earlier than end time")]
+    InvalidTimeRange,
+
+    #[error("Object storage is not configured")]
+    StorageNotConfigured,
+
+    #[error("Too many export jobs are queued, please try again later")]
+    QueueFull,
+
+    #[error("Export job not found: {0}")]
+    JobNotFound(String),
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+
+    #[error("Failed to generate export file: {0}")]
+    ExportFailed(String),
+}
+
+impl ApiError for ComplianceError {
+    fn code(&self) -> u16 {
+        match self {
+            ComplianceError::BuiltInDomainOnly => 19001,
+            ComplianceError::InvalidTimeRange => 19002,
+            ComplianceError::StorageNotConfigured => 19003,
+            ComplianceError::QueueFull => 19004,
+            ComplianceError::JobNotFound(_) => 19005,
+            ComplianceError::DatabaseOperationFailed(_) => 19006,
+            ComplianceError::ExportFailed(_) => 19007,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            ComplianceError::JobNotFound(msg)
+            | ComplianceError::DatabaseOperationFailed(msg)
+            | ComplianceError::ExportFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            ComplianceError::BuiltInDomainOnly => StatusCode::FORBIDDEN,
+            ComplianceError::InvalidTimeRange => StatusCode::BAD_REQUEST,
+            ComplianceError::StorageNotConfigured | ComplianceError::QueueFull => {
+                StatusCode::SERVICE_UNAVAILABLE
+            },
+            ComplianceError::JobNotFound(_) => StatusCode::NOT_FOUND,
+            ComplianceError::DatabaseOperationFailed(_) | ComplianceError::ExportFailed(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            },
+        }
+    }
+}
+
+impl From<ComplianceError> for AppError {
+    fn from(err: ComplianceError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<DbErr> for ComplianceError {
+    fn from(err: DbErr) -> Self {
+        ComplianceError::DatabaseOperationFailed(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for ComplianceError {
+    fn from(err: std::io::Error) -> Self {
+        ComplianceError::ExportFailed(err.to_string())
+    }
+}
+
+impl From<zip::result::ZipError> for ComplianceError {
+    fn from(err: zip::result::ZipError) -> Self {
+        ComplianceError::ExportFailed(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for ComplianceError {
+    fn from(err: serde_json::Error) -> Self {
+        ComplianceError::ExportFailed(err.to_string())
+    }
+}
+
+impl From<StorageError> for ComplianceError {
+    fn from(err: StorageError) -> Self {
+        ComplianceError::ExportFailed(err.to_string())
+    }
+}
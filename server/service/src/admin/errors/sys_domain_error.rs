@@ -15,6 +15,8 @@
  * - 域名称重复
  * - 域操作失败
  * - 数据库操作失败
+ * - 域模板中的角色不存在
+ * - 权限策略同步失败
  * 
  * 错误代码
  * --------
@@ -27,6 +29,8 @@
  * - 3007: 域名称重复
  * - 3008: 域操作失败
  * - 3009: 数据库操作失败
+ * - 3010: 域模板中的角色不存在
+ * - 3011: 权限策略同步失败
  * 
  * 使用示例
  * --------
@@ -47,7 +51,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -80,6 +84,12 @@ pub enum DomainError {
 
     #[error("Database operation failed: {0}")]
     DatabaseOperationFailed(String),
+
+    #[error("Template role not found: {0}")]
+    TemplateRoleNotFound(String),
+
+    #[error("Policy sync failed: {0}")]
+    PolicySyncFailed(String),
 }
 
 impl ApiError for DomainError {
@@ -94,20 +104,29 @@ impl ApiError for DomainError {
             DomainError::DuplicateName => 3007,
             DomainError::DomainOperationFailed(_) => 3008,
             DomainError::DatabaseOperationFailed(_) => 3009,
+            DomainError::TemplateRoleNotFound(_) => 3010,
+            DomainError::PolicySyncFailed(_) => 3011,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            DomainError::DomainNotFound => StatusCode::NOT_FOUND,
+            DomainError::DatabaseOperationFailed(_)
+            | DomainError::TemplateRoleNotFound(_)
+            | DomainError::PolicySyncFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<DomainError> for AppError {
     fn from(err: DomainError) -> Self {
-        AppError {
-            code: err.code() as u16,
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
@@ -0,0 +1,112 @@
+/*! 域功能开关错误模块
+ * 
+ * 该模块定义了与域功能开关（Domain Feature）相关的错误类型。
+ * 
+ * 错误类型
+ * --------
+ * DomainFeatureError 定义了域功能开关相关的所有错误情况，包括：
+ * - 功能开关不存在
+ * - 无效的功能标识
+ * - 功能已在当前域禁用
+ * - 数据库操作失败
+ * 
+ * 错误代码
+ * --------
+ * - 6001: 功能开关不存在
+ * - 6002: 无效的功能标识
+ * - 6003: 功能已在当前域禁用
+ * - 6004: 数据库操作失败
+ * 
+ * 使用示例
+ * --------
+ * /* 功能已禁用
+ *  * let error = DomainFeatureError::FeatureDisabled {
+ *  *     domain: "built-in".to_string(),
+ *  *     feature: "sandbox".to_string(),
+ *  * };
+ *  */
+ */
+
+#![allow(unused_imports)]
+
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
+use sea_orm::DbErr;
+
+#[derive(Error, Debug)]
+pub enum DomainFeatureError {
+    #[error("Domain feature not found")]
+    FeatureNotFound,
+
+    #[error("Invalid feature key")]
+    InvalidFeatureKey,
+
+    #[error("Feature '{feature}' is disabled for domain '{domain}'")]
+    FeatureDisabled { domain: String, feature: String },
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+}
+
+impl ApiError for DomainFeatureError {
+    fn code(&self) -> u16 {
+        match self {
+            DomainFeatureError::FeatureNotFound => 6001,
+            DomainFeatureError::InvalidFeatureKey => 6002,
+            DomainFeatureError::FeatureDisabled { .. } => 6003,
+            DomainFeatureError::DatabaseOperationFailed(_) => 6004,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            DomainFeatureError::FeatureNotFound => StatusCode::NOT_FOUND,
+            DomainFeatureError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl From<DomainFeatureError> for AppError {
+    fn from(err: DomainFeatureError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+// Helper methods for creating specific error types
+impl DomainFeatureError {
+    pub fn database_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+
+    pub fn authentication_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authentication failed".to_string())
+    }
+
+    pub fn authorization_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authorization failed".to_string())
+    }
+
+    pub fn not_found_error(_msg: String) -> Self {
+        Self::FeatureNotFound
+    }
+
+    pub fn validation_error(_msg: String) -> Self {
+        Self::InvalidFeatureKey
+    }
+
+    pub fn internal_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+}
+
+// Implement From<CommonError> for DomainFeatureError
+impl_from_common_error!(DomainFeatureError);
+
+// Implement From<DbErr> for DomainFeatureError
+impl_from_db_error!(DomainFeatureError);
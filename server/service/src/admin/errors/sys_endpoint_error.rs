@@ -1,8 +1,8 @@
 /*! 端点错误模块
- * 
+ *
  * 该模块定义了与API端点（Endpoint）相关的错误类型。
  * 包括端点的创建、修改、删除等操作相关的错误。
- * 
+ *
  * 错误类型
  * --------
  * EndpointError 定义了端点相关的所有错误情况，包括：
@@ -14,7 +14,8 @@
  * - 资源不存在
  * - 验证错误
  * - 内部错误
- * 
+ * - 其他服务返回的应用错误（原样透传错误码和HTTP状态码）
+ *
  * 错误代码
  * --------
  * - 404: 端点不存在
@@ -25,26 +26,27 @@
  * - 404: 资源不存在
  * - 400: 验证错误
  * - 500: 内部错误
- * 
+ *
  * 使用示例
  * --------
  * /* 创建端点不存在错误
  *  * let error = EndpointError::EndpointNotFound("/api/users".to_string());
  *  */
- * 
+ *
  * /* 处理多个端点不存在错误
  *  * let error = EndpointError::EndpointsNotFound(vec![1, 2, 3]);
  *  */
- * 
+ *
  * /* 处理数据库错误
- *  * let db_error = EndpointError::DatabaseError(Box::new(
- *  *     std::io::Error::new(std::io::ErrorKind::Other, "Failed to save endpoint")
- *  * ));
+ *  * let db_error = EndpointError::database_error("Failed to save endpoint".to_string());
  *  */
  */
 
-use server_core::web::error::AppError;
+#![allow(unused_imports)]
+
+use server_core::web::error::{ApiError, AppError};
 use thiserror::Error;
+use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 
 #[derive(Error, Debug)]
 pub enum EndpointError {
@@ -53,7 +55,7 @@ pub enum EndpointError {
     #[error("One or more endpoints not found: {0:?}")]
     EndpointsNotFound(Vec<i32>),
     #[error("Database error: {0}")]
-    DatabaseError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    DatabaseError(String),
     #[error("Authentication error: {0}")]
     AuthenticationError(String),
     #[error("Authorization error: {0}")]
@@ -64,25 +66,70 @@ pub enum EndpointError {
     ValidationError(String),
     #[error("Internal error: {0}")]
     InternalError(String),
+    #[error(transparent)]
+    App(#[from] AppError),
+}
+
+impl ApiError for EndpointError {
+    fn code(&self) -> u16 {
+        match self {
+            EndpointError::EndpointNotFound(_) => 404,
+            EndpointError::EndpointsNotFound(_) => 404,
+            EndpointError::DatabaseError(_) => 500,
+            EndpointError::AuthenticationError(_) => 401,
+            EndpointError::AuthorizationError(_) => 403,
+            EndpointError::NotFoundError(_) => 404,
+            EndpointError::ValidationError(_) => 400,
+            EndpointError::InternalError(_) => 500,
+            EndpointError::App(err) => err.code,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            EndpointError::App(err) => err.message.clone(),
+            _ => self.to_string(),
+        }
+    }
 }
 
 impl From<EndpointError> for AppError {
-    fn from(error: EndpointError) -> Self {
-        match error {
-            EndpointError::EndpointNotFound(_) => AppError { code: 404, message: error.to_string() },
-            EndpointError::EndpointsNotFound(_) => AppError { code: 404, message: error.to_string() },
-            EndpointError::DatabaseError(e) => AppError { code: 500, message: e.to_string() },
-            EndpointError::AuthenticationError(msg) => AppError { code: 401, message: msg },
-            EndpointError::AuthorizationError(msg) => AppError { code: 403, message: msg },
-            EndpointError::NotFoundError(msg) => AppError { code: 404, message: msg },
-            EndpointError::ValidationError(msg) => AppError { code: 400, message: msg },
-            EndpointError::InternalError(msg) => AppError { code: 500, message: msg },
+    fn from(err: EndpointError) -> Self {
+        match err {
+            EndpointError::App(err) => err,
+            err => AppError::from_api_error(&err),
         }
     }
 }
 
-impl From<sea_orm::DbErr> for EndpointError {
-    fn from(error: sea_orm::DbErr) -> Self {
-        EndpointError::DatabaseError(Box::new(error))
+impl EndpointError {
+    pub fn database_error(msg: String) -> Self {
+        Self::DatabaseError(msg)
     }
-} 
\ No newline at end of file
+
+    pub fn authentication_error(msg: String) -> Self {
+        Self::AuthenticationError(msg)
+    }
+
+    pub fn authorization_error(msg: String) -> Self {
+        Self::AuthorizationError(msg)
+    }
+
+    pub fn not_found_error(msg: String) -> Self {
+        Self::NotFoundError(msg)
+    }
+
+    pub fn validation_error(msg: String) -> Self {
+        Self::ValidationError(msg)
+    }
+
+    pub fn internal_error(msg: String) -> Self {
+        Self::InternalError(msg)
+    }
+}
+
+// Implement From<CommonError> for EndpointError
+impl_from_common_error!(EndpointError);
+
+// Implement From<DbErr> for EndpointError
+impl_from_db_error!(EndpointError);
@@ -0,0 +1,129 @@
+/*! 邀请码错误模块
+ *
+ * 该模块定义了与域邀请码（Invitation）相关的错误类型。
+ * 包括邀请码的创建、撤销以及注册时核销等操作相关的错误。
+ *
+ * 错误类型
+ * --------
+ * InvitationError 定义了邀请码相关的所有错误情况，包括：
+ * - 邀请码不存在
+ * - 邀请码已过期
+ * - 邀请码使用次数已用尽
+ * - 目标域不存在
+ * - 目标角色不存在
+ * - 数据库操作失败
+ * - 无权管理其他域的邀请码
+ *
+ * 错误代码
+ * --------
+ * - 12001: 邀请码不存在
+ * - 12002: 邀请码已过期
+ * - 12003: 邀请码使用次数已用尽
+ * - 12004: 目标域不存在
+ * - 12005: 目标角色不存在
+ * - 12006: 数据库操作失败
+ * - 12007: 无权管理其他域的邀请码
+ *
+ * 使用示例
+ * --------
+ * /* 核销已过期的邀请码
+ *  * let error = InvitationError::InvitationExpired;
+ *  */
+ */
+
+#![allow(unused_imports)]
+
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
+use sea_orm::DbErr;
+
+#[derive(Error, Debug)]
+pub enum InvitationError {
+    #[error("Invitation not found")]
+    InvitationNotFound,
+
+    #[error("Invitation has expired")]
+    InvitationExpired,
+
+    #[error("Invitation has no remaining uses")]
+    InvitationExhausted,
+
+    #[error("Domain not found")]
+    DomainNotFound,
+
+    #[error("Role not found")]
+    RoleNotFound,
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+
+    #[error("Cannot manage invitations of another domain")]
+    DomainNotAllowed,
+}
+
+impl ApiError for InvitationError {
+    fn code(&self) -> u16 {
+        match self {
+            InvitationError::InvitationNotFound => 12001,
+            InvitationError::InvitationExpired => 12002,
+            InvitationError::InvitationExhausted => 12003,
+            InvitationError::DomainNotFound => 12004,
+            InvitationError::RoleNotFound => 12005,
+            InvitationError::DatabaseOperationFailed(_) => 12006,
+            InvitationError::DomainNotAllowed => 12007,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            InvitationError::InvitationNotFound => StatusCode::NOT_FOUND,
+            InvitationError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            InvitationError::DomainNotAllowed => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl From<InvitationError> for AppError {
+    fn from(err: InvitationError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+// Helper methods for creating specific error types
+impl InvitationError {
+    pub fn database_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+
+    pub fn authentication_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authentication failed".to_string())
+    }
+
+    pub fn authorization_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authorization failed".to_string())
+    }
+
+    pub fn not_found_error(_msg: String) -> Self {
+        Self::InvitationNotFound
+    }
+
+    pub fn validation_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+
+    pub fn internal_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+}
+
+// Implement From<CommonError> for InvitationError
+impl_from_common_error!(InvitationError);
+
+// Implement From<DbErr> for InvitationError
+impl_from_db_error!(InvitationError);
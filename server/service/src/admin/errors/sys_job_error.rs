@@ -0,0 +1,84 @@
+/*! 后台任务错误模块
+ * 
+ * 该模块定义了与后台周期任务管理相关的错误类型。
+ * 
+ * 错误类型
+ * --------
+ * JobError 定义了后台任务相关的所有错误情况，包括：
+ * - 任务不存在
+ * - 任务正在执行
+ * 
+ * 错误代码
+ * --------
+ * - 13001: 任务不存在
+ * - 13002: 任务正在执行
+ * 
+ * 使用示例
+ * --------
+ * /* 任务不存在
+ *  * let error = JobError::JobNotFound("operation_log_retention".to_string());
+ *  */
+ */
+
+use server_core::{
+    scheduler::SchedulerError,
+    web::error::{ApiError, AppError, StatusCode},
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum JobError {
+    #[error("Job '{0}' not found")]
+    JobNotFound(String),
+
+    #[error("Job '{0}' is already running")]
+    JobRunning(String),
+
+    #[error("Scheduler error: {0}")]
+    Scheduler(String),
+}
+
+impl ApiError for JobError {
+    fn code(&self) -> u16 {
+        match self {
+            JobError::JobNotFound(_) => 13001,
+            JobError::JobRunning(_) => 13002,
+            JobError::Scheduler(_) => 13003,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            JobError::JobNotFound(name) | JobError::JobRunning(name) => vec![name.clone()],
+            JobError::Scheduler(msg) => vec![msg.clone()],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            JobError::JobNotFound(_) => StatusCode::NOT_FOUND,
+            JobError::JobRunning(_) => StatusCode::CONFLICT,
+            JobError::Scheduler(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<JobError> for AppError {
+    fn from(err: JobError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<SchedulerError> for JobError {
+    fn from(err: SchedulerError) -> Self {
+        match err {
+            SchedulerError::JobNotFound(name) => JobError::JobNotFound(name),
+            SchedulerError::JobRunning(name) => JobError::JobRunning(name),
+            err => JobError::Scheduler(err.to_string()),
+        }
+    }
+}
@@ -37,7 +37,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -70,13 +70,17 @@ impl ApiError for LoginLogError {
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            LoginLogError::LogNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<LoginLogError> for AppError {
     fn from(err: LoginLogError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 } 
\ No newline at end of file
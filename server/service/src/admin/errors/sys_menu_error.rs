@@ -14,6 +14,7 @@
  * - 菜单名称重复
  * - 菜单操作失败
  * - 数据库操作失败
+ * - 级联删除后策略刷新失败
  * 
  * 错误代码
  * --------
@@ -43,7 +44,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -62,6 +63,9 @@ pub enum MenuError {
     #[error("Parent menu must be a directory")]
     ParentNotDirectory,
 
+    #[error("Button must belong to a menu")]
+    ParentNotMenu,
+
     #[error("Menu has children, cannot delete")]
     HasChildren,
 
@@ -71,8 +75,14 @@ pub enum MenuError {
     #[error("Cannot move menu to its own submenu")]
     CircularReference,
 
+    #[error("Domain not found or disabled")]
+    DomainNotFound,
+
     #[error("Database operation failed: {0}")]
     DatabaseOperationFailed(String),
+
+    #[error("Menus deleted but casbin policy refresh failed: {0}")]
+    PolicyRefreshFailed(String),
 }
 
 impl ApiError for MenuError {
@@ -86,20 +96,39 @@ impl ApiError for MenuError {
             MenuError::InUse => 5006,
             MenuError::CircularReference => 5007,
             MenuError::DatabaseOperationFailed(_) => 5008,
+            MenuError::PolicyRefreshFailed(_) => 5009,
+            MenuError::ParentNotMenu => 5010,
+            MenuError::DomainNotFound => 5011,
         }
     }
 
     fn message(&self) -> String {
         self.to_string()
     }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            MenuError::DatabaseOperationFailed(msg) | MenuError::PolicyRefreshFailed(msg) => {
+                vec![msg.clone()]
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            MenuError::MenuNotFound => StatusCode::NOT_FOUND,
+            MenuError::DatabaseOperationFailed(_) | MenuError::PolicyRefreshFailed(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            },
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<MenuError> for AppError {
     fn from(err: MenuError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
@@ -19,6 +19,8 @@
  * - 4003: 日志查询失败
  * - 4004: 日志删除失败
  * - 4005: 数据库操作失败
+ * - 7006: 已开启归档但未配置S3
+ * - 7007: 归档上传失败
  * 
  * 使用示例
  * --------
@@ -37,7 +39,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -55,6 +57,15 @@ pub enum OperationLogError {
 
     #[error("Invalid operation log data")]
     InvalidData,
+
+    #[error("Invalid operation log cursor: {0}")]
+    InvalidCursor(String),
+
+    #[error("Operation log archiving is enabled but S3 is not configured")]
+    ArchiveUnavailable,
+
+    #[error("Failed to archive operation logs: {0}")]
+    ArchiveFailed(String),
 }
 
 impl ApiError for OperationLogError {
@@ -64,19 +75,26 @@ impl ApiError for OperationLogError {
             OperationLogError::CreateFailed => 7002,
             OperationLogError::EventHandleFailed => 7003,
             OperationLogError::InvalidData => 7004,
+            OperationLogError::InvalidCursor(_) => 7005,
+            OperationLogError::ArchiveUnavailable => 7006,
+            OperationLogError::ArchiveFailed(_) => 7007,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            OperationLogError::LogNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<OperationLogError> for AppError {
     fn from(err: OperationLogError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 } 
\ No newline at end of file
@@ -0,0 +1,144 @@
+/*! 组织错误模块
+ * 
+ * 该模块定义了与系统组织（Organization）相关的错误类型。
+ * 包括组织的创建、修改、删除等操作相关的错误。
+ * 
+ * 错误类型
+ * --------
+ * OrganizationError 定义了组织相关的所有错误情况，包括：
+ * - 组织不存在
+ * - 上级组织不存在
+ * - 组织层级存在循环引用
+ * - 组织代码重复
+ * - 组织下存在子组织
+ * - 组织下存在用户
+ * - 数据库操作失败
+ * - 用户转移的目标组织不存在、与来源组织相同或是来源组织的下级组织
+ * 
+ * 错误代码
+ * --------
+ * - 11001: 组织不存在
+ * - 11002: 上级组织不存在
+ * - 11003: 组织层级存在循环引用
+ * - 11004: 组织代码重复
+ * - 11005: 组织下存在子组织
+ * - 11006: 组织下存在用户
+ * - 11007: 数据库操作失败
+ * - 11008: 转移目标组织不存在
+ * - 11009: 转移目标组织与来源组织相同
+ * - 11010: 转移目标组织是来源组织的下级组织
+ * 
+ * 使用示例
+ * --------
+ * /* 删除仍有子组织的组织
+ *  * let error = OrganizationError::HasChildren;
+ *  */
+ */
+
+#![allow(unused_imports)]
+
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
+use sea_orm::DbErr;
+
+#[derive(Error, Debug)]
+pub enum OrganizationError {
+    #[error("Organization not found")]
+    OrganizationNotFound,
+
+    #[error("Parent organization not found")]
+    ParentNotFound,
+
+    #[error("Circular reference detected in organization hierarchy")]
+    CircularReference,
+
+    #[error("Duplicate organization code")]
+    DuplicateCode,
+
+    #[error("Organization has child organizations")]
+    HasChildren,
+
+    #[error("Organization has attached users")]
+    HasUsers,
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+
+    #[error("Target organization not found")]
+    TransferTargetNotFound,
+
+    #[error("Target organization is the same as the source organization")]
+    TransferToSelf,
+
+    #[error("Target organization is a descendant of the source organization")]
+    TransferToDescendant,
+}
+
+impl ApiError for OrganizationError {
+    fn code(&self) -> u16 {
+        match self {
+            OrganizationError::OrganizationNotFound => 11001,
+            OrganizationError::ParentNotFound => 11002,
+            OrganizationError::CircularReference => 11003,
+            OrganizationError::DuplicateCode => 11004,
+            OrganizationError::HasChildren => 11005,
+            OrganizationError::HasUsers => 11006,
+            OrganizationError::DatabaseOperationFailed(_) => 11007,
+            OrganizationError::TransferTargetNotFound => 11008,
+            OrganizationError::TransferToSelf => 11009,
+            OrganizationError::TransferToDescendant => 11010,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            OrganizationError::OrganizationNotFound => StatusCode::NOT_FOUND,
+            OrganizationError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl From<OrganizationError> for AppError {
+    fn from(err: OrganizationError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+// Helper methods for creating specific error types
+impl OrganizationError {
+    pub fn database_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+
+    pub fn authentication_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authentication failed".to_string())
+    }
+
+    pub fn authorization_error(_msg: String) -> Self {
+        Self::DatabaseOperationFailed("Authorization failed".to_string())
+    }
+
+    pub fn not_found_error(_msg: String) -> Self {
+        Self::OrganizationNotFound
+    }
+
+    pub fn validation_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+
+    pub fn internal_error(msg: String) -> Self {
+        Self::DatabaseOperationFailed(msg)
+    }
+}
+
+// Implement From<CommonError> for OrganizationError
+impl_from_common_error!(OrganizationError);
+
+// Implement From<DbErr> for OrganizationError
+impl_from_db_error!(OrganizationError);
@@ -0,0 +1,118 @@
+/*! 个人访问令牌错误模块
+ *
+ * 该模块定义了用户管理本人个人访问令牌及使用令牌认证时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * PersonalTokenError 定义了个人访问令牌相关的所有错误情况，包括：
+ * - 令牌不存在（包括属于其他用户的令牌）
+ * - 令牌无效或已过期
+ * - 权限范围中的接口不存在或超出用户已有的权限
+ * - 使用个人访问令牌管理令牌
+ * - 权限规则更新失败
+ * - 数据库操作失败
+ *
+ * 错误代码
+ * --------
+ * - 16001: 令牌不存在
+ * - 16002: 令牌无效
+ * - 16003: 令牌已过期
+ * - 16004: 接口不存在
+ * - 16005: 权限范围超出用户已有的权限
+ * - 16006: 不能使用个人访问令牌管理令牌
+ * - 16007: 权限规则更新失败
+ * - 16008: 数据库操作失败
+ *
+ * 使用示例
+ * --------
+ * /* 令牌的权限范围超出用户已有的权限
+ *  * let error = PersonalTokenError::ScopeNotPermitted("GET /api/v1/user/".to_string());
+ *  */
+ */
+
+use sea_orm::DbErr;
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PersonalTokenError {
+    #[error("Personal access token not found")]
+    TokenNotFound,
+
+    #[error("Invalid personal access token")]
+    InvalidToken,
+
+    #[error("Personal access token has expired")]
+    TokenExpired,
+
+    #[error("Endpoint not found: {0}")]
+    ScopeNotFound(String),
+
+    #[error("Scope exceeds the user's permissions: {0}")]
+    ScopeNotPermitted(String),
+
+    #[error("Personal access tokens cannot be managed with a personal access token")]
+    ManagedWithPersonalToken,
+
+    #[error("Failed to update policies: {0}")]
+    PolicyUpdateFailed(String),
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+}
+
+impl ApiError for PersonalTokenError {
+    fn code(&self) -> u16 {
+        match self {
+            PersonalTokenError::TokenNotFound => 16001,
+            PersonalTokenError::InvalidToken => 16002,
+            PersonalTokenError::TokenExpired => 16003,
+            PersonalTokenError::ScopeNotFound(_) => 16004,
+            PersonalTokenError::ScopeNotPermitted(_) => 16005,
+            PersonalTokenError::ManagedWithPersonalToken => 16006,
+            PersonalTokenError::PolicyUpdateFailed(_) => 16007,
+            PersonalTokenError::DatabaseOperationFailed(_) => 16008,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            PersonalTokenError::ScopeNotFound(scope)
+            | PersonalTokenError::ScopeNotPermitted(scope) => vec![scope.clone()],
+            PersonalTokenError::PolicyUpdateFailed(msg)
+            | PersonalTokenError::DatabaseOperationFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            PersonalTokenError::TokenNotFound | PersonalTokenError::ScopeNotFound(_) => {
+                StatusCode::NOT_FOUND
+            },
+            PersonalTokenError::InvalidToken | PersonalTokenError::TokenExpired => {
+                StatusCode::UNAUTHORIZED
+            },
+            PersonalTokenError::ScopeNotPermitted(_)
+            | PersonalTokenError::ManagedWithPersonalToken => StatusCode::FORBIDDEN,
+            PersonalTokenError::PolicyUpdateFailed(_)
+            | PersonalTokenError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<PersonalTokenError> for AppError {
+    fn from(err: PersonalTokenError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<DbErr> for PersonalTokenError {
+    fn from(err: DbErr) -> Self {
+        PersonalTokenError::DatabaseOperationFailed(err.to_string())
+    }
+}
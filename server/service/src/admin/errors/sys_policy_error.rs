@@ -0,0 +1,104 @@
+/*! 策略查看错误模块
+ * 
+ * 该模块定义了与Casbin策略查看（Policy）相关的错误类型。
+ * 
+ * 错误类型
+ * --------
+ * PolicyError 定义了策略查看相关的所有错误情况，包括：
+ * - 无效的策略类型
+ * - 权限判定失败
+ * - 策略重新加载失败
+ * 
+ * 错误代码
+ * --------
+ * - 10001: 无效的策略类型
+ * - 10002: 权限判定失败
+ * - 10003: 策略重新加载失败
+ * 
+ * 使用示例
+ * --------
+ * /* 无效的策略类型
+ *  * let error = PolicyError::InvalidPolicyType("x".to_string());
+ *  */
+ */
+
+#![allow(unused_imports)]
+
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
+use sea_orm::DbErr;
+
+#[derive(Error, Debug)]
+pub enum PolicyError {
+    #[error("Invalid policy type: {0}")]
+    InvalidPolicyType(String),
+
+    #[error("Policy enforcement failed: {0}")]
+    EnforceFailed(String),
+
+    #[error("Policy reload failed: {0}")]
+    ReloadFailed(String),
+}
+
+impl ApiError for PolicyError {
+    fn code(&self) -> u16 {
+        match self {
+            PolicyError::InvalidPolicyType(_) => 10001,
+            PolicyError::EnforceFailed(_) => 10002,
+            PolicyError::ReloadFailed(_) => 10003,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            PolicyError::InvalidPolicyType(_) => StatusCode::BAD_REQUEST,
+            PolicyError::EnforceFailed(_) | PolicyError::ReloadFailed(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            },
+        }
+    }
+}
+
+impl From<PolicyError> for AppError {
+    fn from(err: PolicyError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+// Helper methods for creating specific error types
+impl PolicyError {
+    pub fn database_error(msg: String) -> Self {
+        Self::ReloadFailed(msg)
+    }
+
+    pub fn authentication_error(msg: String) -> Self {
+        Self::EnforceFailed(msg)
+    }
+
+    pub fn authorization_error(msg: String) -> Self {
+        Self::EnforceFailed(msg)
+    }
+
+    pub fn not_found_error(msg: String) -> Self {
+        Self::InvalidPolicyType(msg)
+    }
+
+    pub fn validation_error(msg: String) -> Self {
+        Self::InvalidPolicyType(msg)
+    }
+
+    pub fn internal_error(msg: String) -> Self {
+        Self::EnforceFailed(msg)
+    }
+}
+
+// Implement From<CommonError> for PolicyError
+impl_from_common_error!(PolicyError);
+
+// Implement From<DbErr> for PolicyError
+impl_from_db_error!(PolicyError);
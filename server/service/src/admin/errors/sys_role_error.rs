@@ -43,7 +43,7 @@
 
 #![allow(unused_imports, unused_variables)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -80,14 +80,19 @@ impl ApiError for RoleError {
     fn message(&self) -> String {
         self.to_string()
     }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            RoleError::RoleNotFound => StatusCode::NOT_FOUND,
+            RoleError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<RoleError> for AppError {
     fn from(err: RoleError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
@@ -125,3 +130,66 @@ impl RoleError {
 
 // Implement From<CommonError> for RoleError
 impl_from_common_error!(RoleError);
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+        routing::get,
+        Router,
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::admin::errors::AuthorizationError;
+
+    async fn request(app: Router) -> (StatusCode, Value) {
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_role_not_found_responds_404() {
+        let app = Router::new().route(
+            "/",
+            get(|| async { Err::<(), AppError>(RoleError::RoleNotFound.into()) }),
+        );
+
+        let (status, body) = request(app).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], 4001);
+        assert_eq!(body["message"], "Role not found");
+    }
+
+    #[tokio::test]
+    async fn test_role_not_found_through_authorization_error_responds_404() {
+        let app = Router::new().route(
+            "/",
+            get(|| async {
+                let err = AuthorizationError::from(AppError::from(RoleError::RoleNotFound));
+                Err::<(), AppError>(err.into())
+            }),
+        );
+
+        let (status, body) = request(app).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], 4001);
+    }
+
+    #[test]
+    fn test_role_error_status() {
+        assert_eq!(RoleError::RoleNotFound.status(), StatusCode::NOT_FOUND);
+        assert_eq!(RoleError::DuplicateRoleCode.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            RoleError::DatabaseOperationFailed("boom".to_string()).status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,95 @@
+/*! 角色模板错误模块
+ *
+ * 该模块定义了角色模板（Role Template）管理及从模板创建角色时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * RoleTemplateError 定义了角色模板相关的所有错误情况，包括：
+ * - 模板不存在
+ * - 模板名称重复
+ * - 非内置域用户管理模板
+ * - 模板中保存的权限无法解析
+ * - 数据库操作失败
+ *
+ * 错误代码
+ * --------
+ * - 15001: 模板不存在
+ * - 15002: 模板名称重复
+ * - 15003: 只有内置域用户可以管理角色模板
+ * - 15004: 模板权限无法解析
+ * - 15005: 数据库操作失败
+ *
+ * 使用示例
+ * --------
+ * /* 租户域用户修改模板
+ *  * let error = RoleTemplateError::BuiltInDomainOnly;
+ *  */
+ */
+
+use sea_orm::DbErr;
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RoleTemplateError {
+    #[error("Role template not found")]
+    TemplateNotFound,
+
+    #[error("Duplicate role template name")]
+    DuplicateTemplateName,
+
+    #[error("Only users of the built-in domain can manage role templates")]
+    BuiltInDomainOnly,
+
+    #[error("Invalid role template permissions: {0}")]
+    InvalidPermissions(String),
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+}
+
+impl ApiError for RoleTemplateError {
+    fn code(&self) -> u16 {
+        match self {
+            RoleTemplateError::TemplateNotFound => 15001,
+            RoleTemplateError::DuplicateTemplateName => 15002,
+            RoleTemplateError::BuiltInDomainOnly => 15003,
+            RoleTemplateError::InvalidPermissions(_) => 15004,
+            RoleTemplateError::DatabaseOperationFailed(_) => 15005,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            RoleTemplateError::InvalidPermissions(msg)
+            | RoleTemplateError::DatabaseOperationFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            RoleTemplateError::TemplateNotFound => StatusCode::NOT_FOUND,
+            RoleTemplateError::DuplicateTemplateName => StatusCode::BAD_REQUEST,
+            RoleTemplateError::BuiltInDomainOnly => StatusCode::FORBIDDEN,
+            RoleTemplateError::InvalidPermissions(_)
+            | RoleTemplateError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<RoleTemplateError> for AppError {
+    fn from(err: RoleTemplateError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<DbErr> for RoleTemplateError {
+    fn from(err: DbErr) -> Self {
+        RoleTemplateError::DatabaseOperationFailed(err.to_string())
+    }
+}
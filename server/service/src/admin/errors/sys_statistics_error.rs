@@ -0,0 +1,73 @@
+/*! 统计错误模块
+ *
+ * 该模块定义了统计概览查询时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * StatisticsError 定义了统计相关的所有错误情况，包括：
+ * - 非内置域用户查询其他域
+ * - 数据库操作失败
+ *
+ * 错误代码
+ * --------
+ * - 17001: 只有内置域用户可以查询其他域的统计
+ * - 17002: 数据库操作失败
+ *
+ * 使用示例
+ * --------
+ * /* 租户域用户查询其他域
+ *  * let error = StatisticsError::CrossDomainForbidden;
+ *  */
+ */
+
+use sea_orm::DbErr;
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StatisticsError {
+    #[error("Only users of the built-in domain can view statistics of other domains")]
+    CrossDomainForbidden,
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+}
+
+impl ApiError for StatisticsError {
+    fn code(&self) -> u16 {
+        match self {
+            StatisticsError::CrossDomainForbidden => 17001,
+            StatisticsError::DatabaseOperationFailed(_) => 17002,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            StatisticsError::DatabaseOperationFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            StatisticsError::CrossDomainForbidden => StatusCode::FORBIDDEN,
+            StatisticsError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<StatisticsError> for AppError {
+    fn from(err: StatisticsError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<DbErr> for StatisticsError {
+    fn from(err: DbErr) -> Self {
+        StatisticsError::DatabaseOperationFailed(err.to_string())
+    }
+}
@@ -0,0 +1,73 @@
+/*! 系统管理错误模块
+ *
+ * 该模块定义了系统管理操作时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * SystemError 定义了系统管理相关的所有错误情况，包括：
+ * - 非内置域用户执行系统管理操作
+ * - 主库重新连接不可用
+ * - 主库重新连接失败
+ *
+ * 错误代码
+ * --------
+ * - 18001: 只有内置域用户可以执行该操作
+ * - 18002: 未启用主库重新连接
+ * - 18003: 主库重新连接失败
+ *
+ * 使用示例
+ * --------
+ * /* 租户域用户重新连接主库
+ *  * let error = SystemError::BuiltInDomainOnly;
+ *  */
+ */
+
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SystemError {
+    #[error("Only users of the built-in domain can perform this operation")]
+    BuiltInDomainOnly,
+
+    #[error("Primary database reconnection is not available")]
+    ReconnectUnavailable,
+
+    #[error("Failed to reconnect primary database: {0}")]
+    ReconnectFailed(String),
+}
+
+impl ApiError for SystemError {
+    fn code(&self) -> u16 {
+        match self {
+            SystemError::BuiltInDomainOnly => 18001,
+            SystemError::ReconnectUnavailable => 18002,
+            SystemError::ReconnectFailed(_) => 18003,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            SystemError::ReconnectFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            SystemError::BuiltInDomainOnly => StatusCode::FORBIDDEN,
+            SystemError::ReconnectUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            SystemError::ReconnectFailed(_) => StatusCode::BAD_GATEWAY,
+        }
+    }
+}
+
+impl From<SystemError> for AppError {
+    fn from(err: SystemError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
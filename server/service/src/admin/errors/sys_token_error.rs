@@ -0,0 +1,87 @@
+/*! 登录令牌错误模块
+ *
+ * 该模块定义了用户查看和撤销本人登录令牌（登录设备）时的错误类型。
+ *
+ * 错误类型
+ * --------
+ * TokenError 定义了登录令牌相关的所有错误情况，包括：
+ * - 令牌不存在（包括属于其他用户或已撤销的令牌）
+ * - 令牌已撤销
+ * - 数据库操作失败
+ * - 使用个人访问令牌撤销登录令牌
+ *
+ * 错误代码
+ * --------
+ * - 14001: 令牌不存在
+ * - 14002: 令牌已撤销
+ * - 14003: 数据库操作失败
+ * - 14004: 不能使用个人访问令牌撤销登录令牌
+ *
+ * 使用示例
+ * --------
+ * /* 撤销其他用户的令牌
+ *  * let error = TokenError::TokenNotFound;
+ *  */
+ */
+
+use sea_orm::DbErr;
+use server_core::web::error::{ApiError, AppError, StatusCode};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TokenError {
+    #[error("Token not found")]
+    TokenNotFound,
+
+    #[error("Token has been revoked")]
+    TokenRevoked,
+
+    #[error("Database operation failed: {0}")]
+    DatabaseOperationFailed(String),
+
+    #[error("Personal access tokens cannot be used for this operation")]
+    PersonalTokenNotAllowed,
+}
+
+impl ApiError for TokenError {
+    fn code(&self) -> u16 {
+        match self {
+            TokenError::TokenNotFound => 14001,
+            TokenError::TokenRevoked => 14002,
+            TokenError::DatabaseOperationFailed(_) => 14003,
+            TokenError::PersonalTokenNotAllowed => 14004,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            TokenError::DatabaseOperationFailed(msg) => vec![msg.clone()],
+            _ => vec![],
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            TokenError::TokenNotFound => StatusCode::NOT_FOUND,
+            TokenError::TokenRevoked => StatusCode::UNAUTHORIZED,
+            TokenError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            TokenError::PersonalTokenNotAllowed => StatusCode::FORBIDDEN,
+        }
+    }
+}
+
+impl From<TokenError> for AppError {
+    fn from(err: TokenError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
+
+impl From<DbErr> for TokenError {
+    fn from(err: DbErr) -> Self {
+        TokenError::DatabaseOperationFailed(err.to_string())
+    }
+}
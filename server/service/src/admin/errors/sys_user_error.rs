@@ -45,7 +45,7 @@
 
 #![allow(unused_imports)]
 
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, StatusCode};
 use thiserror::Error;
 use crate::admin::errors::{CommonError, impl_from_common_error, impl_from_db_error};
 use sea_orm::DbErr;
@@ -69,6 +69,12 @@ pub enum UserError {
 
     #[error("Database operation failed: {0}")]
     DatabaseOperationFailed(String),
+
+    #[error("Domain not found")]
+    DomainNotFound,
+
+    #[error("Role not found")]
+    RoleNotFound,
 }
 
 impl ApiError for UserError {
@@ -80,20 +86,38 @@ impl ApiError for UserError {
             UserError::UsernameAlreadyExists => 1004,
             UserError::InvalidUserStatus => 1005,
             UserError::DatabaseOperationFailed(_) => 1006,
+            UserError::DomainNotFound => 1007,
+            UserError::RoleNotFound => 1008,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            UserError::AuthenticationFailed(msg) | UserError::DatabaseOperationFailed(msg) => {
+                vec![msg.clone()]
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            UserError::UserNotFound | UserError::DomainNotFound | UserError::RoleNotFound => {
+                StatusCode::NOT_FOUND
+            },
+            UserError::DatabaseOperationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
 }
 
 impl From<UserError> for AppError {
     fn from(err: UserError) -> Self {
-        AppError {
-            code: err.code() as u16,
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
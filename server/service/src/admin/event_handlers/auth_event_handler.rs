@@ -6,6 +6,10 @@
  * - 记录登录日志
  * - 管理访问令牌
  * 
+ * 登录日志和令牌记录在同一事务中写入，遇到可重试的数据库错误时自动重试，
+ * 重试后仍失败的事件放入死信缓冲区，不会被静默丢弃。
+ * 写入成功后与该用户最近的登录记录比较，登录国家或设备从未出现过时发布异常登录安全事件。
+ * 
  * # 主要组件
  * 
  * ## 事件结构
@@ -25,6 +29,8 @@
  *     domain: "example.com".to_string(),
  *     access_token: "token123".to_string(),
  *     refresh_token: "refresh456".to_string(),
+ *     jti: "01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+ *     expires_in: 7200,
  *     client_ip: "127.0.0.1".to_string(),
  *     client_port: Some(8080),
  *     address: "localhost".to_string(),
@@ -37,13 +43,36 @@
  * AuthEventHandler::handle_login(event).await?;
  */
 
-use server_core::web::error::AppError;
+use std::collections::HashSet;
+
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use serde::Serialize;
+use server_config::{SecurityConfig, UnusualLoginConfig};
+use server_constant::definition::consts::SystemEvent;
+use server_core::{sign::SecurityEvent, web::error::AppError};
+use server_global::{
+    global::{self, get_config},
+    project_error,
+};
+use server_model::admin::entities::{
+    prelude::SysLoginLog,
+    sys_login_log::{Column as SysLoginLogColumn, Model as SysLoginLogModel},
+};
 
 use crate::{
-    admin::events::{access_token_event::AccessTokenEvent, login_log_event::LoginLogEvent},
-    helper::db_helper,
+    admin::{
+        events::{access_token_event::AccessTokenEvent, login_log_event::LoginLogEvent},
+        sys_login_log_service::country_of,
+        SysDeadLetterService,
+    },
+    helper::{db_helper, execute_in_transaction, execute_with_retry},
 };
 
+/** 登录记录写入的最大重试次数 */
+const LOGIN_EVENT_MAX_RETRIES: u32 = 3;
+
 /**
  * 认证事件
  * 
@@ -58,6 +87,8 @@ use crate::{
  * * `domain`: 域名
  * * `access_token`: 访问令牌
  * * `refresh_token`: 刷新令牌
+ * * `jti`: 访问令牌的JWT ID
+ * * `expires_in`: 访问令牌有效期（秒）
  * * `client_ip`: 客户端IP地址
  * * `client_port`: 客户端端口号（可选）
  * * `address`: 访问地址
@@ -73,6 +104,8 @@ use crate::{
  *     domain: "example.com".to_string(),
  *     access_token: "token123".to_string(),
  *     refresh_token: "refresh456".to_string(),
+ *     jti: "01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+ *     expires_in: 7200,
  *     client_ip: "127.0.0.1".to_string(),
  *     client_port: Some(8080),
  *     address: "localhost".to_string(),
@@ -81,6 +114,7 @@ use crate::{
  *     login_type: "password".to_string(),
  * };
  */
+#[derive(Clone, Debug, Serialize)]
 pub struct AuthEvent {
     /** 用户ID */
     pub user_id: String,
@@ -88,10 +122,16 @@ pub struct AuthEvent {
     pub username: String,
     /** 域名 */
     pub domain: String,
-    /** 访问令牌 */
+    /** 访问令牌，不进入死信内容 */
+    #[serde(skip_serializing)]
     pub access_token: String,
-    /** 刷新令牌 */
+    /** 刷新令牌，不进入死信内容 */
+    #[serde(skip_serializing)]
     pub refresh_token: String,
+    /** 访问令牌的JWT ID */
+    pub jti: String,
+    /** 访问令牌有效期（秒） */
+    pub expires_in: u64,
     /** 客户端IP地址 */
     pub client_ip: String,
     /** 客户端端口号（可选） */
@@ -128,6 +168,10 @@ impl AuthEventHandler {
      * - 记录登录日志
      * - 创建访问令牌
      * 
+     * 两条记录在同一事务中写入，遇到可重试的数据库错误时最多重试
+     * `LOGIN_EVENT_MAX_RETRIES` 次，仍失败时放入死信缓冲区并返回错误。
+     * 写入成功后检查是否为异常登录，检查失败只记录日志，不影响登录结果。
+     * 
      * # 参数
      * * `event` - 认证事件
      * 
@@ -140,40 +184,384 @@ impl AuthEventHandler {
      * AuthEventHandler::handle_login(event).await?;
      */
     pub async fn handle_login(event: AuthEvent) -> Result<(), AppError> {
+        let result = Self::persist_login(&event).await;
+
+        match result {
+            Ok(()) => Self::check_unusual_login(&event).await,
+            Err(ref e) => {
+                SysDeadLetterService::park(
+                    SystemEvent::AuthLoggedInEvent.as_ref(),
+                    serde_json::to_value(&event).unwrap_or_default(),
+                    e.to_string(),
+                )
+                .await;
+            },
+        }
+
+        result
+    }
+
+    /**
+     * 检查本次登录是否为异常登录，是则发布安全事件
+     */
+    async fn check_unusual_login(event: &AuthEvent) {
+        let config = get_config::<SecurityConfig>().await.unwrap_or_default();
+        let config = &config.unusual_login;
+        if !config.enabled {
+            return;
+        }
+
+        let history = match db_helper::get_db_connection().await {
+            Ok(db) => Self::find_recent_logins(db.as_ref(), event, config.history_size).await,
+            Err(e) => Err(AppError::from(e)),
+        };
+        match history {
+            Ok(history) => {
+                if let Some(security_event) = detect_unusual_login(event, &history, config) {
+                    global::send_dyn_event_async(
+                        SystemEvent::AuditSecurityEvent.as_ref(),
+                        Box::new(security_event),
+                    )
+                    .await;
+                }
+            },
+            Err(e) => {
+                project_error!("Failed to check unusual login for user {}: {}", event.user_id, e);
+            },
+        }
+    }
+
+    /**
+     * 查询用户最近成功的登录记录，不含本次登录
+     */
+    async fn find_recent_logins(
+        db: &DatabaseConnection,
+        event: &AuthEvent,
+        limit: u64,
+    ) -> Result<Vec<SysLoginLogModel>, AppError> {
+        SysLoginLog::find()
+            .filter(SysLoginLogColumn::UserId.eq(&event.user_id))
+            .filter(SysLoginLogColumn::Success.eq(true))
+            .filter(SysLoginLogColumn::RequestId.ne(&event.request_id))
+            .order_by_desc(SysLoginLogColumn::LoginTime)
+            .limit(limit)
+            .all(db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /**
+     * 带重试地写入登录记录
+     */
+    async fn persist_login(event: &AuthEvent) -> Result<(), AppError> {
         let db = db_helper::get_db_connection().await?;
 
-        // 处理登录日志
-        let login_log_event = LoginLogEvent {
-            user_id: event.user_id.clone(),
-            username: event.username.clone(),
-            domain: event.domain.clone(),
-            ip: event.client_ip.clone(),
-            port: event.client_port,
-            address: event.address.clone(),
-            user_agent: event.user_agent.clone(),
-            request_id: event.request_id.clone(),
-            login_type: event.login_type.clone(),
+        execute_with_retry(
+            db.as_ref(),
+            |db| {
+                let db = db.clone();
+                let event = event.clone();
+                Box::pin(async move { Self::write_login_records(&db, event).await })
+            },
+            LOGIN_EVENT_MAX_RETRIES,
+        )
+        .await
+    }
+
+    /**
+     * 在同一事务中写入登录日志和令牌记录
+     * 
+     * 任一记录写入失败时整个事务回滚，不会只留下其中一条记录
+     * 
+     * # 参数
+     * * `db` - 数据库连接
+     * * `event` - 认证事件
+     * 
+     * # 返回
+     * * `Result<(), AppError>` - 成功返回 `()`，失败返回错误
+     */
+    async fn write_login_records(
+        db: &DatabaseConnection,
+        event: AuthEvent,
+    ) -> Result<(), AppError> {
+        execute_in_transaction(db, move |txn| {
+            Box::pin(async move {
+                event.login_log_event().handle(&txn).await?;
+                event.access_token_event().handle(&txn).await?;
+                txn.commit().await?;
+                Ok(())
+            })
+        })
+        .await
+    }
+}
+
+impl AuthEvent {
+    /**
+     * 转换为登录日志事件
+     */
+    fn login_log_event(&self) -> LoginLogEvent {
+        LoginLogEvent {
+            user_id: self.user_id.clone(),
+            username: self.username.clone(),
+            domain: self.domain.clone(),
+            ip: self.client_ip.clone(),
+            port: self.client_port,
+            address: self.address.clone(),
+            user_agent: self.user_agent.clone(),
+            request_id: self.request_id.clone(),
+            login_type: self.login_type.clone(),
+            success: true,
+            failure_reason: None,
+        }
+    }
+
+    /**
+     * 转换为访问令牌事件
+     */
+    fn access_token_event(self) -> AccessTokenEvent {
+        AccessTokenEvent {
+            access_token: self.access_token,
+            refresh_token: self.refresh_token,
+            jti: self.jti,
+            expires_in: self.expires_in,
+            user_id: self.user_id,
+            username: self.username,
+            domain: self.domain,
+            ip: self.client_ip,
+            port: self.client_port,
+            address: self.address,
+            user_agent: self.user_agent,
+            request_id: self.request_id,
+            login_type: self.login_type,
+        }
+    }
+}
+
+/**
+ * 判断本次登录是否为异常登录
+ *
+ * 用户没有历史登录记录时不视为异常；本次或历史登录的国家都无法解析（如内网IP）时不比较国家，
+ * 用户代理按去除版本号后的指纹比较，浏览器升级不视为新设备。
+ *
+ * # 参数
+ * * `event` - 认证事件
+ * * `history` - 最近的登录记录，按登录时间倒序
+ * * `config` - 异常登录提醒配置
+ *
+ * # 返回
+ * * `Option<SecurityEvent>` - 异常登录时返回安全事件
+ */
+fn detect_unusual_login(
+    event: &AuthEvent,
+    history: &[SysLoginLogModel],
+    config: &UnusualLoginConfig,
+) -> Option<SecurityEvent> {
+    let previous = history.first()?;
+
+    let new_location = config.check_location
+        && country_of(&event.address).is_some_and(|country| {
+            let known: HashSet<&str> =
+                history.iter().filter_map(|log| country_of(&log.address)).collect();
+            !known.is_empty() && !known.contains(country)
+        });
+
+    let new_user_agent = config.check_user_agent
+        && user_agent_fingerprint(&event.user_agent).is_some_and(|fingerprint| {
+            let known: HashSet<String> = history
+                .iter()
+                .filter_map(|log| user_agent_fingerprint(&log.user_agent))
+                .collect();
+            !known.contains(&fingerprint)
+        });
+
+    if !new_location && !new_user_agent {
+        return None;
+    }
+
+    Some(SecurityEvent::UnusualLogin {
+        user_id: event.user_id.clone(),
+        username: event.username.clone(),
+        domain: event.domain.clone(),
+        ip: event.client_ip.clone(),
+        previous_address: previous.address.clone(),
+        address: event.address.clone(),
+        previous_user_agent: previous.user_agent.clone(),
+        user_agent: event.user_agent.clone(),
+        new_location,
+        new_user_agent,
+    })
+}
+
+/**
+ * 计算用户代理指纹
+ *
+ * 转为小写并去掉版本号中的数字、点和下划线，连续空白合并为一个空格；
+ * 结果为空时返回 None
+ */
+fn user_agent_fingerprint(user_agent: &str) -> Option<String> {
+    let stripped: String = user_agent
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && *c != '.' && *c != '_')
+        .collect();
+    let fingerprint = stripped.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!fingerprint.is_empty()).then_some(fingerprint)
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ConnectionTrait, Database, EntityTrait, PaginatorTrait, Schema};
+    use server_model::admin::entities::prelude::{SysLoginLog, SysTokens};
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    fn event() -> AuthEvent {
+        AuthEvent {
+            user_id: "user".to_string(),
+            username: "soybean".to_string(),
+            domain: "built-in".to_string(),
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            jti: "jti".to_string(),
+            expires_in: 7200,
+            client_ip: "127.0.0.1".to_string(),
+            client_port: None,
+            address: "localhost".to_string(),
+            user_agent: "test".to_string(),
+            request_id: "req".to_string(),
+            login_type: "PC".to_string(),
+        }
+    }
+
+    async fn create_table<E: EntityTrait>(db: &DatabaseConnection, entity: E) {
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(entity)))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_login_records_are_written_together() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        create_table(&db, SysLoginLog).await;
+
+        // 令牌表不存在时登录日志随事务回滚
+        assert!(AuthEventHandler::write_login_records(&db, event()).await.is_err());
+        assert_eq!(SysLoginLog::find().count(&db).await.unwrap(), 0);
+
+        create_table(&db, SysTokens).await;
+        AuthEventHandler::write_login_records(&db, event()).await.unwrap();
+        assert_eq!(SysLoginLog::find().count(&db).await.unwrap(), 1);
+        assert_eq!(SysTokens::find().count(&db).await.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_dead_letter_payload_omits_tokens() {
+        let payload = serde_json::to_value(event()).unwrap();
+        assert_eq!(payload["username"], "soybean");
+        assert!(payload.get("access_token").is_none());
+        assert!(payload.get("refresh_token").is_none());
+    }
+
+    fn login_log(address: &str, user_agent: &str) -> SysLoginLogModel {
+        let now = timestamps::now();
+        SysLoginLogModel {
+            id: "log".to_string(),
+            user_id: "user".to_string(),
+            username: "soybean".to_string(),
+            domain: "built-in".to_string(),
+            login_time: now,
+            ip: "127.0.0.1".to_string(),
+            port: None,
+            address: address.to_string(),
+            user_agent: user_agent.to_string(),
+            request_id: "earlier".to_string(),
+            r#type: "PC".to_string(),
+            created_at: now,
+            created_by: "user".to_string(),
+            success: true,
+            failure_reason: None,
+        }
+    }
+
+    fn login_from(address: &str, user_agent: &str) -> AuthEvent {
+        AuthEvent {
+            address: address.to_string(),
+            user_agent: user_agent.to_string(),
+            ..event()
+        }
+    }
+
+    const CHINA: &str = "中国|0|上海|上海市|电信";
+    const USA: &str = "美国|0|加利福尼亚|0|0";
+    const CHROME_128: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) Chrome/128.0.6613.84";
+    const CHROME_129: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) Chrome/129.0.6668.59";
+    const FIREFOX: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0";
+
+    #[test]
+    fn test_unusual_login_requires_history() {
+        let config = UnusualLoginConfig::default();
+        assert!(detect_unusual_login(&login_from(USA, FIREFOX), &[], &config).is_none());
+    }
+
+    #[test]
+    fn test_unusual_login_detects_new_country_and_device() {
+        let config = UnusualLoginConfig::default();
+        let history = vec![login_log(CHINA, CHROME_128), login_log("0|0|0|内网IP|内网IP", "")];
+
+        // 同一国家且仅浏览器版本变化不视为异常
+        assert!(detect_unusual_login(&login_from(CHINA, CHROME_129), &history, &config).is_none());
+        // 内网IP无法解析国家，不比较国家
+        let lan = login_from("0|0|0|内网IP|内网IP", CHROME_129);
+        assert!(detect_unusual_login(&lan, &history, &config).is_none());
+
+        let Some(SecurityEvent::UnusualLogin {
+            previous_address,
+            new_location,
+            new_user_agent,
+            ..
+        }) = detect_unusual_login(&login_from(USA, CHROME_129), &history, &config)
+        else {
+            panic!("login from a new country should be unusual");
         };
+        assert_eq!(previous_address, CHINA);
+        assert!(new_location);
+        assert!(!new_user_agent);
 
-        login_log_event.handle(&db).await?;
-
-        // 处理访问令牌
-        let access_token_event = AccessTokenEvent {
-            access_token: event.access_token,
-            refresh_token: event.refresh_token,
-            user_id: event.user_id,
-            username: event.username,
-            domain: event.domain,
-            ip: event.client_ip,
-            port: event.client_port,
-            address: event.address.clone(),
-            user_agent: event.user_agent,
-            request_id: event.request_id,
-            login_type: event.login_type,
+        let Some(SecurityEvent::UnusualLogin { new_location, new_user_agent, .. }) =
+            detect_unusual_login(&login_from(CHINA, FIREFOX), &history, &config)
+        else {
+            panic!("login from a new device should be unusual");
         };
+        assert!(!new_location);
+        assert!(new_user_agent);
 
-        access_token_event.handle(&db).await?;
+        let config = UnusualLoginConfig {
+            check_user_agent: false,
+            ..UnusualLoginConfig::default()
+        };
+        assert!(detect_unusual_login(&login_from(CHINA, FIREFOX), &history, &config).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_recent_logins_exclude_current_login() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        create_table(&db, SysLoginLog).await;
+        create_table(&db, SysTokens).await;
+        AuthEventHandler::write_login_records(&db, event()).await.unwrap();
 
-        Ok(())
+        let history = AuthEventHandler::find_recent_logins(&db, &event(), 10).await.unwrap();
+        assert!(history.is_empty());
+
+        let next = AuthEvent {
+            request_id: "req-2".to_string(),
+            jti: "jti-2".to_string(),
+            ..event()
+        };
+        let history = AuthEventHandler::find_recent_logins(&db, &next, 10).await.unwrap();
+        assert_eq!(history.len(), 1);
     }
 }
@@ -18,6 +18,8 @@
  *  * let event = AccessTokenEvent {
  *  *     access_token: "token123".to_string(),
  *  *     refresh_token: "refresh456".to_string(),
+ *  *     jti: "01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+ *  *     expires_in: 7200,
  *  *     user_id: "user1".to_string(),
  *  *     username: "admin".to_string(),
  *  *     domain: "example.com".to_string(),
@@ -35,11 +37,12 @@
  *  */
  */
 
-use chrono::Local;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use chrono::Duration;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
 use server_constant::definition::consts::TokenStatus;
 use server_core::web::error::AppError;
 use server_model::admin::entities::sys_tokens::ActiveModel as SysTokensActiveModel;
+use server_model::admin::timestamps;
 use ulid::Ulid;
 
 /** 访问令牌事件
@@ -53,6 +56,8 @@ use ulid::Ulid;
  * --------
  * * `access_token`: 访问令牌
  * * `refresh_token`: 刷新令牌
+ * * `jti`: 访问令牌的JWT ID
+ * * `expires_in`: 访问令牌有效期（秒）
  * * `user_id`: 用户ID
  * * `username`: 用户名
  * * `domain`: 域名
@@ -69,6 +74,8 @@ use ulid::Ulid;
  *  * let event = AccessTokenEvent {
  *  *     access_token: "token123".to_string(),
  *  *     refresh_token: "refresh456".to_string(),
+ *  *     jti: "01JABCDEFGHJKMNPQRSTVWXYZ".to_string(),
+ *  *     expires_in: 7200,
  *  *     user_id: "user1".to_string(),
  *  *     username: "admin".to_string(),
  *  *     domain: "example.com".to_string(),
@@ -87,6 +94,10 @@ pub struct AccessTokenEvent {
     pub access_token: String,
     /** 刷新令牌 */
     pub refresh_token: String,
+    /** 访问令牌的JWT ID */
+    pub jti: String,
+    /** 访问令牌有效期（秒） */
+    pub expires_in: u64,
     /** 用户ID */
     pub user_id: String,
     /** 用户名 */
@@ -112,12 +123,12 @@ impl AccessTokenEvent {
      * 
      * 将访问令牌事件信息保存到数据库，包括：
      * - 生成唯一ID
-     * - 设置令牌状态
+     * - 设置令牌状态和过期时间
      * - 记录创建时间和创建者
      * 
      * 参数
      * --------
-     * * `db` - 数据库连接
+     * * `db` - 数据库连接或事务
      * 
      * 返回
      * --------
@@ -130,8 +141,8 @@ impl AccessTokenEvent {
      *  * event.handle(&db).await?;
      *  */
      */
-    pub async fn handle(self, db: &DatabaseConnection) -> Result<(), AppError> {
-        let now = Local::now().naive_local();
+    pub async fn handle<C: ConnectionTrait>(self, db: &C) -> Result<(), AppError> {
+        let now = timestamps::now();
 
         SysTokensActiveModel {
             id: Set(Ulid::new().to_string()),
@@ -150,6 +161,9 @@ impl AccessTokenEvent {
             r#type: Set(self.login_type),
             created_at: Set(now),
             created_by: Set(self.username),
+            jti: Set(Some(self.jti)),
+            expires_at: Set(Some(now + Duration::seconds(self.expires_in as i64))),
+            last_seen_at: Set(Some(now)),
         }
         .insert(db)
         .await
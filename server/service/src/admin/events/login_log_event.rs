@@ -2,6 +2,7 @@
  * 
  * 该模块定义了登录日志相关的事件类型和处理逻辑，用于：
  * - 记录用户登录信息
+ * - 记录失败的登录尝试及失败原因
  * - 跟踪登录状态
  * - 分析登录行为
  * 
@@ -25,6 +26,8 @@
  *  *     user_agent: "Mozilla/5.0".to_string(),
  *  *     request_id: "req-123".to_string(),
  *  *     login_type: "password".to_string(),
+ *  *     success: true,
+ *  *     failure_reason: None,
  *  * };
  *  */
  * 
@@ -33,18 +36,19 @@
  *  */
  */
 
-use chrono::Local;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
 use server_core::web::error::AppError;
 use server_model::admin::entities::sys_login_log::ActiveModel as SysLoginLogActiveModel;
+use server_model::admin::timestamps;
 use ulid::Ulid;
 
 /** 登录日志事件
  * 
- * 表示一个用户登录事件，包含：
- * - 用户信息（用户ID、用户名）
+ * 表示一次登录尝试，包含：
+ * - 用户信息（用户ID、用户名），用户不存在或密码错误时用户ID为空
  * - 登录上下文（域名、IP、端口等）
  * - 请求信息（请求ID、用户代理等）
+ * - 登录结果（是否成功、失败原因）
  * 
  * 字段
  * --------
@@ -57,6 +61,8 @@ use ulid::Ulid;
  * * `user_agent`: 用户代理信息
  * * `request_id`: 请求ID
  * * `login_type`: 登录类型
+ * * `success`: 是否登录成功
+ * * `failure_reason`: 失败原因，登录成功时为None
  * 
  * 使用示例
  * --------
@@ -71,6 +77,8 @@ use ulid::Ulid;
  *  *     user_agent: "Mozilla/5.0".to_string(),
  *  *     request_id: "req-123".to_string(),
  *  *     login_type: "password".to_string(),
+ *  *     success: true,
+ *  *     failure_reason: None,
  *  * };
  *  */
  */
@@ -94,6 +102,10 @@ pub struct LoginLogEvent {
     pub request_id: String,
     /** 登录类型 */
     pub login_type: String,
+    /** 是否登录成功 */
+    pub success: bool,
+    /** 失败原因，登录成功时为None */
+    pub failure_reason: Option<String>,
 }
 
 impl LoginLogEvent {
@@ -106,7 +118,7 @@ impl LoginLogEvent {
      * 
      * 参数
      * --------
-     * * `db` - 数据库连接
+     * * `db` - 数据库连接或事务
      * 
      * 返回
      * --------
@@ -119,8 +131,8 @@ impl LoginLogEvent {
      *  * event.handle(&db).await?;
      *  */
      */
-    pub async fn handle(self, db: &DatabaseConnection) -> Result<(), AppError> {
-        let now = Local::now().naive_local();
+    pub async fn handle<C: ConnectionTrait>(self, db: &C) -> Result<(), AppError> {
+        let now = timestamps::now();
 
         SysLoginLogActiveModel {
             id: Set(Ulid::new().to_string()),
@@ -136,6 +148,8 @@ impl LoginLogEvent {
             r#type: Set(self.login_type),
             created_at: Set(now),
             created_by: Set(self.username),
+            success: Set(self.success),
+            failure_reason: Set(self.failure_reason),
         }
         .insert(db)
         .await
@@ -3,11 +3,22 @@
  * 该模块实现了管理后台的核心业务逻辑服务，包括：
  * - 用户管理：用户CRUD、认证、授权等
  * - 角色管理：角色CRUD、权限分配等
+ * - 角色模板：预设权限的角色模板管理
  * - 菜单管理：菜单CRUD、权限控制等
  * - 组织管理：组织CRUD、层级关系等
  * - 域名管理：域名CRUD、配置等
+ * - 域功能开关：按域启用或关闭功能
+ * - 邀请码管理：域邀请码的创建、撤销与注册核销
  * - 访问密钥管理：API密钥CRUD、验证等
+ * - 维护模式：全局维护模式开关
+ * - 账号维护：供命令行工具创建管理员和重置密码
+ * - 死信事件：重试后仍处理失败的事件
+ * - 后台任务：周期任务的状态查询与立即执行
+ * - 策略查看：运行中策略的只读查看与判定试算
  * - 日志管理：登录日志、操作日志等
+ * - 登录令牌：本人登录设备的查看与撤销
+ * - 个人访问令牌：本人自动化脚本使用的长期令牌的创建、查看与撤销
+ * - 统计概览：管理后台首页使用的域统计数据
  * 
  * 每个服务都实现了相应的trait接口，提供了统一的错误处理和事件通知机制。
  * 
@@ -25,10 +36,28 @@
  * 辅助服务
  * --------
  * * `SysDomainService`: 域名管理服务，处理多租户域名配置
+ * * `SysDomainFeatureService`: 域功能开关服务，处理按域的功能启用状态
  * * `SysAccessKeyService`: 访问密钥服务，处理API密钥管理
+ * * `SysInvitationService`: 邀请码服务，处理域邀请码的管理与核销
+ * * `SysMaintenanceService`: 维护模式服务，处理全局维护模式开关
+ * * `SysAdminAccountService`: 管理员账号维护服务，供命令行工具绕过HTTP接口维护账号
+ * * `SysDeadLetterService`: 死信事件服务，保存重试后仍处理失败的事件
+ * * `SysJobService`: 后台任务服务，查询周期任务状态并立即执行任务
+ * * `SysPolicyService`: 策略查看服务，处理运行中策略的只读查看
+ * * `SysRoleTemplateService`: 角色模板服务，维护从模板创建角色时使用的预设权限
  * * `SysLoginLogService`: 登录日志服务，记录用户登录信息
  * * `SysOperationLogService`: 操作日志服务，记录用户操作历史
  * * `SysOrganizationService`: 组织管理服务，处理组织架构
+ * * `SysTokenService`: 登录令牌服务，处理本人登录令牌的查看与撤销
+ * * `SysPersonalTokenService`: 个人访问令牌服务，处理本人个人访问令牌的管理与认证
+ * * `SysSystemService`: 系统信息服务，查询版本信息和可选子系统的初始化情况，重新连接主库
+ * * `SysStorageService`: 对象存储服务，校验预签名链接并提供本地存储文件下载
+ * * `SysStatisticsService`: 统计服务，查询并缓存域统计概览
+ * * `SysComplianceService`: 合规审计导出服务，异步导出用户的审计数据并上传到对象存储
+ * 
+ * 数据访问
+ * --------
+ * * `repositories`: 仓储接口及实现，用户和认证服务通过构造函数注入用户仓储
  * 
  * 事件处理
  * --------
@@ -38,13 +67,13 @@
  * 使用示例
  * --------
  * /* 创建用户服务实例
- *  * let user_service = SysUserService::new();
+ *  * let user_service = SysUserService::default();
  *  * 
  *  * // 创建角色服务实例
  *  * let role_service = SysRoleService::new();
  *  * 
  *  * // 创建认证服务实例
- *  * let auth_service = SysAuthService::new();
+ *  * let auth_service = SysAuthService::default();
  *  */
  */
 
@@ -54,46 +83,86 @@ pub use server_model::admin::{
         prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysUser},
         sys_access_key::Model as SysAccessKeyModel,
         sys_domain::Model as SysDomainModel,
+        sys_domain_feature::Model as SysDomainFeatureModel,
         sys_endpoint::Model as SysEndpointModel,
+        sys_invitation::Model as SysInvitationModel,
         sys_login_log::Model as SysLoginLogModel,
         sys_menu::Model as SysMenuModel,
         sys_operation_log::Model as SysOperationLogModel,
         sys_organization::Model as SysOrganizationModel,
         sys_role::Model as SysRoleModel,
+        sys_role_template::Model as SysRoleTemplateModel,
     },
     input::*,
     output::*,
 };
+pub use server_storage::{ByteRange, ObjectRead};
 pub use sys_access_key_service::{
     api_key_validate_listener, SysAccessKeyService, TAccessKeyService,
 };
+pub use sys_admin_account_service::SysAdminAccountService;
 pub use sys_auth_service::{
-    auth_login_listener, jwt_created_listener, SysAuthService, TAuthService,
+    auth_login_failed_listener, auth_login_listener, jwt_created_listener, SysAuthService,
+    TAuthService,
 };
 pub use sys_authorization_service::{SysAuthorizationService, TAuthorizationService};
+pub use sys_compliance_service::{
+    compliance_export_listener, SysComplianceService, TComplianceService,
+};
+pub use sys_dead_letter_service::{SysDeadLetterService, TDeadLetterService};
+pub use sys_domain_feature_service::{SysDomainFeatureService, TDomainFeatureService};
 pub use sys_domain_service::{SysDomainService, TDomainService};
-pub use sys_endpoint_service::{SysEndpointService, TEndpointService};
+pub use sys_endpoint_service::{endpoints_from_routes, SysEndpointService, TEndpointService};
+pub use sys_invitation_service::{SysInvitationService, TInvitationService};
+pub use sys_job_service::{SysJobService, TJobService};
 pub use sys_login_log_service::{SysLoginLogService, TLoginLogService};
+pub use sys_maintenance_service::{SysMaintenanceService, TMaintenanceService};
 pub use sys_menu_service::{SysMenuService, TMenuService};
 pub use sys_operation_log_service::{
     sys_operation_log_listener, SysOperationLogService, TOperationLogService,
 };
 pub use sys_organization_service::{SysOrganizationService, TOrganizationService};
+pub use sys_personal_token_service::{
+    personal_token_subject, SysPersonalTokenService, TPersonalTokenService, PERSONAL_TOKEN_PREFIX,
+};
+pub use sys_policy_service::{SysPolicyService, TPolicyService};
 pub use sys_role_service::{SysRoleService, TRoleService};
+pub use sys_role_template_service::{SysRoleTemplateService, TRoleTemplateService};
+pub use sys_security_event_service::{security_event_listener, SysSecurityEventService};
+pub use sys_statistics_service::{SysStatisticsService, TStatisticsService};
+pub use sys_storage_service::{SysStorageService, TStorageService};
+pub use sys_system_service::{SysSystemService, TSystemService};
+pub use sys_token_service::{SysTokenService, TTokenService};
 pub use sys_user_service::{SysUserService, TUserService};
 pub mod dto;
 pub mod errors;
 pub mod helper;
+pub mod repositories;
 mod sys_access_key_service;
+mod sys_admin_account_service;
 mod sys_auth_service;
 mod sys_authorization_service;
+mod sys_compliance_service;
+mod sys_dead_letter_service;
+mod sys_domain_feature_service;
 mod sys_domain_service;
 mod sys_endpoint_service;
+mod sys_invitation_service;
+mod sys_job_service;
 mod sys_login_log_service;
+mod sys_maintenance_service;
 mod sys_menu_service;
 mod sys_operation_log_service;
 mod sys_organization_service;
+mod sys_personal_token_service;
+mod sys_policy_service;
 mod sys_role_service;
+mod sys_role_template_service;
+mod sys_security_event_service;
+mod sys_statistics_service;
+mod sys_storage_service;
+mod sys_system_service;
+mod sys_token_service;
 mod sys_user_service;
 
 mod event_handlers;
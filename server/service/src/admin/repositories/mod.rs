@@ -0,0 +1,15 @@
+/**
+ * 数据访问模块
+ *
+ * 该模块将服务中的数据库访问抽象为仓储 trait，服务通过构造函数注入仓储实现：
+ * - UserRepository: 用户数据访问接口
+ * - SeaOrmUserRepository: 基于 SeaORM 的用户数据访问实现，服务默认使用
+ *
+ * 测试中使用 `test_support` 下的内存实现替换数据库访问。
+ */
+pub use sys_user_repository::{SeaOrmUserRepository, UserRepository};
+
+mod sys_user_repository;
+
+#[cfg(test)]
+pub(crate) mod test_support;
@@ -0,0 +1,702 @@
+/**
+ * 用户数据访问模块
+ *
+ * 该模块将用户相关的数据库访问从服务中分离出来，包括：
+ * - UserRepository: 用户数据访问 trait，服务只依赖该接口
+ * - SeaOrmUserRepository: 基于 SeaORM 的实现，通过 db_helper 获取连接
+ *
+ * 服务通过构造函数注入仓储，单元测试可以替换为内存实现而无需数据库。
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, IntoActiveModel,
+    JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select,
+};
+use server_core::web::page::KeywordFilter;
+use server_model::admin::{
+    entities::{
+        prelude::{SysDomain, SysRole, SysUser, SysUserDomain},
+        sys_domain::Column as SysDomainColumn,
+        sys_role::{Column as SysRoleColumn, Relation as SysRoleRelation},
+        sys_user::{Column as SysUserColumn, Model as SysUserModel, Relation as SysUserRelation},
+        sys_user_domain::Column as SysUserDomainColumn,
+        sys_user_role::{Column as SysUserRoleColumn, Relation as SysUserRoleRelation},
+    },
+    input::UserPageRequest,
+    output::{UserDomainOutput, UserRoleOutput, UserWithDomainAndOrgOutput},
+};
+
+use crate::{admin::SysOrganizationService, helper::db_helper};
+
+/**
+ * 用户数据访问 trait
+ *
+ * 定义了服务所需的用户数据操作，错误统一使用 `DbErr`，由服务转换为各自的错误类型
+ */
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /**
+     * 按用户名和域代码查询用户，包含域信息和密码哈希
+     *
+     * @param username 用户名
+     * @param domain 域代码
+     * @return Result<Option<UserWithDomainAndOrgOutput>, DbErr> 用户信息，域或用户不存在时为None
+     */
+    async fn find_by_username_and_domain(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr>;
+
+    /**
+     * 按ID查询用户，包含域信息
+     *
+     * @param id 用户ID
+     * @return Result<Option<UserWithDomainAndOrgOutput>, DbErr> 用户信息，不存在时为None
+     */
+    async fn find_with_domain_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr>;
+
+    /**
+     * 按ID查询用户
+     *
+     * @param id 用户ID
+     * @return Result<Option<SysUserModel>, DbErr> 用户，不存在时为None
+     */
+    async fn find_by_id(&self, id: &str) -> Result<Option<SysUserModel>, DbErr>;
+
+    /**
+     * 按域和用户名查询用户，用于域内用户名唯一性检查
+     *
+     * @param username 用户名
+     * @param domain 域代码
+     * @return Result<Option<SysUserModel>, DbErr> 用户，不存在时为None
+     */
+    async fn find_by_username(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<SysUserModel>, DbErr>;
+
+    /**
+     * 查询所有用户
+     *
+     * @return Result<Vec<SysUserModel>, DbErr> 用户列表
+     */
+    async fn find_all(&self) -> Result<Vec<SysUserModel>, DbErr>;
+
+    /**
+     * 按筛选条件分页查询用户
+     *
+     * @param params 分页查询参数
+     * @return Result<(u64, Vec<SysUserModel>), DbErr> 满足条件的总数和当前页用户
+     */
+    async fn find_page(&self, params: &UserPageRequest) -> Result<(u64, Vec<SysUserModel>), DbErr>;
+
+    /**
+     * 新增用户
+     *
+     * @param user 完整的用户数据
+     * @return Result<SysUserModel, DbErr> 新增后的用户
+     */
+    async fn insert(&self, user: SysUserModel) -> Result<SysUserModel, DbErr>;
+
+    /**
+     * 更新用户，覆盖全部字段
+     *
+     * @param user 完整的用户数据
+     * @return Result<SysUserModel, DbErr> 更新后的用户
+     */
+    async fn update(&self, user: SysUserModel) -> Result<SysUserModel, DbErr>;
+
+    /**
+     * 替换用户的密码哈希
+     *
+     * 只在当前哈希仍为 `old_hash` 时更新，不修改更新时间和更新人，
+     * 用于登录后重新哈希，不会覆盖期间修改的新密码
+     *
+     * @param id 用户ID
+     * @param old_hash 验证通过的原密码哈希
+     * @param new_hash 新密码哈希
+     * @return Result<bool, DbErr> 是否更新了密码哈希
+     */
+    async fn replace_password_hash(
+        &self,
+        id: &str,
+        old_hash: &str,
+        new_hash: &str,
+    ) -> Result<bool, DbErr>;
+
+    /**
+     * 删除用户
+     *
+     * @param id 用户ID
+     * @return Result<bool, DbErr> 是否删除了用户
+     */
+    async fn delete(&self, id: &str) -> Result<bool, DbErr>;
+
+    /**
+     * 查询用户在指定域中的角色代码
+     *
+     * 用户可以加入多个域，只返回属于该域的角色分配
+     *
+     * @param user_id 用户ID
+     * @param domain 域代码
+     * @return Result<Vec<String>, DbErr> 角色代码列表
+     */
+    async fn find_role_codes(&self, user_id: &str, domain: &str) -> Result<Vec<String>, DbErr>;
+
+    /**
+     * 查询用户可操作的域，包括所属的域和额外加入的域
+     *
+     * @param user_id 用户ID
+     * @return Result<Vec<UserDomainOutput>, DbErr> 按代码排序的域列表，current 均为 false
+     */
+    async fn find_member_domains(&self, user_id: &str) -> Result<Vec<UserDomainOutput>, DbErr>;
+
+    /**
+     * 查询用户的角色，包含用户所属域的代码和名称
+     *
+     * @param user_id 用户ID
+     * @return Result<Vec<UserRoleOutput>, DbErr> 角色列表
+     */
+    async fn find_roles_with_domain(&self, user_id: &str) -> Result<Vec<UserRoleOutput>, DbErr>;
+}
+
+/**
+ * 基于 SeaORM 的用户数据访问实现
+ *
+ * 写操作使用主库连接，列表查询使用只读连接
+ */
+#[derive(Clone, Default)]
+pub struct SeaOrmUserRepository;
+
+impl SeaOrmUserRepository {
+    /**
+     * 构建包含域信息的用户查询
+     *
+     * 查询用户基本信息、密码哈希、用户状态以及所属域的代码、名称和状态
+     */
+    fn select_with_domain() -> Select<SysUser> {
+        SysUser::find()
+            .select_only()
+            .column_as(SysUserColumn::Id, "id")
+            .column_as(SysUserColumn::Domain, "domain")
+            .column_as(SysUserColumn::Username, "username")
+            .column_as(SysUserColumn::Password, "password")
+            .column_as(SysUserColumn::NickName, "nick_name")
+            .column_as(SysUserColumn::Avatar, "avatar")
+            .column_as(SysUserColumn::Status, "status")
+            .column_as(SysDomainColumn::Code, "domain_code")
+            .column_as(SysDomainColumn::Name, "domain_name")
+            .column_as(SysDomainColumn::Status, "domain_status")
+            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
+    }
+
+    /**
+     * 构建查询用户角色的查询
+     *
+     * 通过用户角色关联表连接用户和用户所属域，查询角色信息及域的代码和名称
+     */
+    pub(crate) fn select_roles_with_domain(user_id: &str) -> Select<SysRole> {
+        SysRole::find()
+            .select_only()
+            .column_as(SysRoleColumn::Id, "id")
+            .column_as(SysRoleColumn::Code, "code")
+            .column_as(SysRoleColumn::Name, "name")
+            .column_as(SysRoleColumn::Status, "status")
+            .column_as(SysDomainColumn::Code, "domain_code")
+            .column_as(SysDomainColumn::Name, "domain_name")
+            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
+            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
+            .filter(SysUserColumn::Id.eq(user_id))
+    }
+
+    /**
+     * 构建分页查询用户的查询条件
+     *
+     * 各筛选条件以 AND 组合，分页数据与总数使用同一查询，保证统计口径一致。
+     * 角色筛选通过用户角色关联表连接，组织筛选可展开为组织及其全部下级组织，
+     * 用户表中的域字段即域代码，直接按列筛选。
+     *
+     * @param db 数据库连接
+     * @param params 分页查询参数
+     * @return Result<Select<SysUser>, DbErr> 查询或错误
+     */
+    pub(crate) async fn build_page_query<C: ConnectionTrait>(
+        db: &C,
+        params: &UserPageRequest,
+    ) -> Result<Select<SysUser>, DbErr> {
+        let mut query = SysUser::find();
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = KeywordFilter::new(keywords).condition(&[SysUserColumn::Username]);
+            query = query.filter(condition);
+        }
+
+        if let Some(ref role_id) = params.role_id {
+            query = query
+                .join(JoinType::InnerJoin, SysUserRelation::SysUserRole.def())
+                .filter(SysUserRoleColumn::RoleId.eq(role_id.as_str()));
+        }
+
+        if let Some(ref organization_id) = params.organization_id {
+            if params.include_sub_orgs {
+                let org_ids =
+                    SysOrganizationService::collect_subtree_ids(db, organization_id).await?;
+                query = query.filter(SysUserColumn::OrgId.is_in(org_ids));
+            } else {
+                query = query.filter(SysUserColumn::OrgId.eq(organization_id.as_str()));
+            }
+        }
+
+        if let Some(ref status) = params.status {
+            query = query.filter(SysUserColumn::Status.eq(status.clone()));
+        }
+
+        if let Some(ref domain_code) = params.domain_code {
+            query = query.filter(SysUserColumn::Domain.eq(domain_code.as_str()));
+        }
+
+        Ok(query)
+    }
+}
+
+#[async_trait]
+impl UserRepository for SeaOrmUserRepository {
+    async fn find_by_username_and_domain(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        Self::select_with_domain()
+            .filter(SysUserColumn::Username.eq(username))
+            .filter(SysDomainColumn::Code.eq(domain))
+            .into_model::<UserWithDomainAndOrgOutput>()
+            .one(db.as_ref())
+            .await
+    }
+
+    async fn find_with_domain_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        Self::select_with_domain()
+            .filter(SysUserColumn::Id.eq(id))
+            .into_model::<UserWithDomainAndOrgOutput>()
+            .one(db.as_ref())
+            .await
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<SysUserModel>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        SysUser::find_by_id(id).one(db.as_ref()).await
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<SysUserModel>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        SysUser::find()
+            .filter(SysUserColumn::Username.eq(username))
+            .filter(SysUserColumn::Domain.eq(domain))
+            .one(db.as_ref())
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<SysUserModel>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        SysUser::find().all(db.as_ref()).await
+    }
+
+    async fn find_page(&self, params: &UserPageRequest) -> Result<(u64, Vec<SysUserModel>), DbErr> {
+        let db = db_helper::get_read_connection().await?;
+        let query = Self::build_page_query(db.as_ref(), params).await?;
+
+        let total = query.clone().count(db.as_ref()).await?;
+        let records = query
+            .paginate(db.as_ref(), params.page_details.size)
+            .fetch_page(params.page_details.current - 1)
+            .await?;
+
+        Ok((total, records))
+    }
+
+    async fn insert(&self, user: SysUserModel) -> Result<SysUserModel, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        user.into_active_model().reset_all().insert(db.as_ref()).await
+    }
+
+    async fn update(&self, user: SysUserModel) -> Result<SysUserModel, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        user.into_active_model().reset_all().update(db.as_ref()).await
+    }
+
+    async fn replace_password_hash(
+        &self,
+        id: &str,
+        old_hash: &str,
+        new_hash: &str,
+    ) -> Result<bool, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        let result = SysUser::update_many()
+            .col_expr(SysUserColumn::Password, Expr::value(new_hash))
+            .filter(SysUserColumn::Id.eq(id))
+            .filter(SysUserColumn::Password.eq(old_hash))
+            .exec(db.as_ref())
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        let result = SysUser::delete_by_id(id).exec(db.as_ref()).await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_role_codes(&self, user_id: &str, domain: &str) -> Result<Vec<String>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        SysRole::find()
+            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
+            .filter(SysUserColumn::Id.eq(user_id))
+            .filter(SysRoleColumn::Domain.eq(domain))
+            .select_only()
+            .column(SysRoleColumn::Code)
+            .into_tuple()
+            .all(db.as_ref())
+            .await
+    }
+
+    async fn find_member_domains(&self, user_id: &str) -> Result<Vec<UserDomainOutput>, DbErr> {
+        let db = db_helper::get_db_connection().await?;
+        let Some(user) = SysUser::find_by_id(user_id).one(db.as_ref()).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut codes: Vec<String> = SysUserDomain::find()
+            .filter(SysUserDomainColumn::UserId.eq(user_id))
+            .select_only()
+            .column(SysUserDomainColumn::Domain)
+            .into_tuple()
+            .all(db.as_ref())
+            .await?;
+        codes.push(user.domain.clone());
+
+        let domains = SysDomain::find()
+            .filter(SysDomainColumn::Code.is_in(codes))
+            .order_by_asc(SysDomainColumn::Code)
+            .all(db.as_ref())
+            .await?;
+        Ok(domains
+            .into_iter()
+            .map(|domain| UserDomainOutput {
+                home: domain.code == user.domain,
+                code: domain.code,
+                name: domain.name,
+                status: domain.status,
+                current: false,
+            })
+            .collect())
+    }
+
+    async fn find_roles_with_domain(&self, user_id: &str) -> Result<Vec<UserRoleOutput>, DbErr> {
+        let db = db_helper::get_read_connection().await?;
+        Self::select_roles_with_domain(user_id)
+            .into_model::<UserRoleOutput>()
+            .all(db.as_ref())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection, Schema, Set};
+    use server_core::web::page::PageRequest;
+    use server_model::admin::entities::{
+        prelude::{SysDomain, SysOrganization, SysUserRole},
+        sea_orm_active_enums::Status,
+        sys_domain::ActiveModel as SysDomainActiveModel,
+        sys_organization::ActiveModel as SysOrganizationActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+        sys_user::ActiveModel as SysUserActiveModel,
+        sys_user_role::ActiveModel as SysUserRoleActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 测试只关注筛选条件，不创建域表和角色表
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysOrganization)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+
+        // root -> child
+        for (id, pid) in [("root", "0"), ("child", "root")] {
+            SysOrganizationActiveModel {
+                id: Set(id.to_string()),
+                code: Set(id.to_string()),
+                name: Set(id.to_string()),
+                description: Set(None),
+                pid: Set(pid.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                updated_at: Set(None),
+                updated_by: Set(None),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let users = [
+            ("u1", Some("root"), Status::Enabled, "d1", vec!["r1"]),
+            ("u2", Some("child"), Status::Disabled, "d1", vec!["r1"]),
+            ("u3", Some("child"), Status::Enabled, "d2", vec!["r2"]),
+            ("u4", None, Status::Enabled, "d1", vec!["r1", "r2"]),
+        ];
+        for (id, org_id, status, domain, roles) in users {
+            SysUserActiveModel {
+                id: Set(id.to_string()),
+                username: Set(id.to_string()),
+                password: Set("secret".to_string()),
+                domain: Set(domain.to_string()),
+                built_in: Set(false),
+                nick_name: Set(id.to_string()),
+                status: Set(status),
+                org_id: Set(org_id.map(str::to_string)),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+
+            for role_id in roles {
+                SysUserRoleActiveModel {
+                    user_id: Set(id.to_string()),
+                    role_id: Set(role_id.to_string()),
+                }
+                .insert(&db)
+                .await
+                .unwrap();
+            }
+        }
+        db
+    }
+
+    fn request() -> UserPageRequest {
+        UserPageRequest {
+            page_details: PageRequest {
+                current: 1,
+                size: 10,
+                sort_by: None,
+                sort_order: None,
+            },
+            keywords: None,
+            role_id: None,
+            organization_id: None,
+            include_sub_orgs: false,
+            status: None,
+            domain_code: None,
+        }
+    }
+
+    async fn count(db: &DatabaseConnection, params: UserPageRequest) -> u64 {
+        SeaOrmUserRepository::build_page_query(db, &params)
+            .await
+            .unwrap()
+            .count(db)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_single_filters() {
+        let db = setup_db().await;
+
+        assert_eq!(count(&db, request()).await, 4);
+        assert_eq!(
+            count(&db, UserPageRequest { role_id: Some("r1".to_string()), ..request() }).await,
+            3
+        );
+        assert_eq!(
+            count(
+                &db,
+                UserPageRequest { organization_id: Some("root".to_string()), ..request() }
+            )
+            .await,
+            1
+        );
+        assert_eq!(
+            count(
+                &db,
+                UserPageRequest {
+                    organization_id: Some("root".to_string()),
+                    include_sub_orgs: true,
+                    ..request()
+                }
+            )
+            .await,
+            3
+        );
+        assert_eq!(
+            count(&db, UserPageRequest { status: Some(Status::Enabled), ..request() }).await,
+            3
+        );
+        assert_eq!(
+            count(&db, UserPageRequest { domain_code: Some("d1".to_string()), ..request() })
+                .await,
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_combined_filters() {
+        let db = setup_db().await;
+
+        assert_eq!(
+            count(
+                &db,
+                UserPageRequest {
+                    role_id: Some("r1".to_string()),
+                    organization_id: Some("root".to_string()),
+                    include_sub_orgs: true,
+                    ..request()
+                }
+            )
+            .await,
+            2
+        );
+        assert_eq!(
+            count(
+                &db,
+                UserPageRequest {
+                    role_id: Some("r1".to_string()),
+                    organization_id: Some("root".to_string()),
+                    include_sub_orgs: true,
+                    status: Some(Status::Enabled),
+                    ..request()
+                }
+            )
+            .await,
+            1
+        );
+        assert_eq!(
+            count(
+                &db,
+                UserPageRequest {
+                    role_id: Some("r2".to_string()),
+                    domain_code: Some("d1".to_string()),
+                    ..request()
+                }
+            )
+            .await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keywords_match_wildcards_literally() {
+        let db = setup_db().await;
+        let keywords =
+            |value: &str| UserPageRequest { keywords: Some(value.to_string()), ..request() };
+
+        assert_eq!(count(&db, keywords("u1")).await, 1);
+        assert_eq!(count(&db, keywords("u")).await, 4);
+        assert_eq!(count(&db, keywords("%")).await, 0);
+        assert_eq!(count(&db, keywords("u_")).await, 0);
+        assert_eq!(count(&db, keywords("\\")).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_page_matches_count() {
+        let db = setup_db().await;
+        let params = UserPageRequest { role_id: Some("r1".to_string()), ..request() };
+
+        let query = SeaOrmUserRepository::build_page_query(&db, &params).await.unwrap();
+        let records = query.clone().paginate(&db, 2).fetch_page(1).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(query.count(&db).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_select_roles_with_domain() {
+        let db = setup_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+
+        SysDomainActiveModel {
+            id: Set("d1".to_string()),
+            code: Set("d1".to_string()),
+            name: Set("Domain One".to_string()),
+            description: Set(None),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for id in ["r1", "r2"] {
+            SysRoleActiveModel {
+                id: Set(id.to_string()),
+                code: Set(format!("ROLE_{}", id.to_uppercase())),
+                domain: Set("built-in".to_string()),
+                name: Set(id.to_string()),
+                description: Set(None),
+                pid: Set("0".to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                updated_at: Set(None),
+                updated_by: Set(None),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let mut roles = SeaOrmUserRepository::select_roles_with_domain("u4")
+            .into_model::<UserRoleOutput>()
+            .all(&db)
+            .await
+            .unwrap();
+        roles.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(roles.len(), 2);
+        assert_eq!(roles[0].code, "ROLE_R1");
+        assert_eq!(roles[1].code, "ROLE_R2");
+        assert!(roles.iter().all(|role| role.domain_name == "Domain One"));
+
+        // u3 所属的域不存在，内连接不返回角色
+        let roles = SeaOrmUserRepository::select_roles_with_domain("u3")
+            .into_model::<UserRoleOutput>()
+            .all(&db)
+            .await
+            .unwrap();
+        assert!(roles.is_empty());
+    }
+}
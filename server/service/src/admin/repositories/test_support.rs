@@ -0,0 +1,290 @@
+/**
+ * 用户数据访问的内存实现
+ *
+ * 仅用于单元测试，服务逻辑可以在不连接数据库的情况下验证。
+ * 分页查询只支持关键字、状态和域筛选，角色和组织筛选由 SeaORM 实现的测试覆盖。
+ */
+
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+use server_model::admin::{
+    entities::{sea_orm_active_enums::Status, sys_user::Model as SysUserModel},
+    input::UserPageRequest,
+    output::{UserDomainOutput, UserRoleOutput, UserWithDomainAndOrgOutput},
+    timestamps,
+};
+use server_utils::SecureUtil;
+
+use super::UserRepository;
+
+/**
+ * 内存用户仓储
+ */
+#[derive(Default)]
+pub struct InMemoryUserRepository {
+    /** 域代码到域名称和域状态 */
+    domains: HashMap<String, (String, Status)>,
+    users: Mutex<Vec<SysUserModel>>,
+    /** 用户ID到所属域中的角色代码 */
+    roles: HashMap<String, Vec<String>>,
+    /** 用户ID和加入的域代码到该域中的角色代码 */
+    memberships: HashMap<(String, String), Vec<String>>,
+}
+
+impl InMemoryUserRepository {
+    /**
+     * 添加域
+     */
+    pub fn with_domain(mut self, code: &str, name: &str, status: Status) -> Self {
+        self.domains.insert(code.to_string(), (name.to_string(), status));
+        self
+    }
+
+    /**
+     * 添加用户，密码以哈希形式保存
+     */
+    pub fn with_user(self, username: &str, password: &str, domain: &str, status: Status) -> Self {
+        let user = SysUserModel {
+            id: username.to_string(),
+            username: username.to_string(),
+            password: SecureUtil::hash_password(password.as_bytes()).unwrap(),
+            domain: domain.to_string(),
+            built_in: false,
+            avatar: None,
+            email: None,
+            phone_number: None,
+            nick_name: username.to_string(),
+            status,
+            org_id: None,
+            created_at: timestamps::now(),
+            created_by: "test".to_string(),
+            updated_at: None,
+            updated_by: None,
+        };
+        self.users.lock().unwrap().push(user);
+        self
+    }
+
+    /**
+     * 设置用户的角色代码
+     */
+    pub fn with_roles(mut self, user_id: &str, role_codes: &[&str]) -> Self {
+        self.roles.insert(
+            user_id.to_string(),
+            role_codes.iter().map(|code| code.to_string()).collect(),
+        );
+        self
+    }
+
+    /**
+     * 让用户加入所属域以外的域，并设置用户在该域中的角色代码
+     */
+    pub fn with_membership(mut self, user_id: &str, domain: &str, role_codes: &[&str]) -> Self {
+        self.memberships.insert(
+            (user_id.to_string(), domain.to_string()),
+            role_codes.iter().map(|code| code.to_string()).collect(),
+        );
+        self
+    }
+
+    /**
+     * 拼接用户所属域的信息，域不存在时返回None
+     */
+    fn with_domain_info(&self, user: &SysUserModel) -> Option<UserWithDomainAndOrgOutput> {
+        let (domain_name, domain_status) = self.domains.get(&user.domain)?;
+        Some(UserWithDomainAndOrgOutput {
+            id: user.id.clone(),
+            domain: user.domain.clone(),
+            username: user.username.clone(),
+            password: user.password.clone(),
+            nick_name: user.nick_name.clone(),
+            avatar: user.avatar.clone(),
+            domain_code: user.domain.clone(),
+            domain_name: domain_name.clone(),
+            status: user.status.clone(),
+            domain_status: domain_status.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn find_by_username_and_domain(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr> {
+        let users = self.users.lock().unwrap();
+        Ok(users
+            .iter()
+            .find(|user| user.username == username && user.domain == domain)
+            .and_then(|user| self.with_domain_info(user)))
+    }
+
+    async fn find_with_domain_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<UserWithDomainAndOrgOutput>, DbErr> {
+        let users = self.users.lock().unwrap();
+        Ok(users
+            .iter()
+            .find(|user| user.id == id)
+            .and_then(|user| self.with_domain_info(user)))
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<SysUserModel>, DbErr> {
+        let users = self.users.lock().unwrap();
+        Ok(users.iter().find(|user| user.id == id).cloned())
+    }
+
+    async fn find_by_username(
+        &self,
+        username: &str,
+        domain: &str,
+    ) -> Result<Option<SysUserModel>, DbErr> {
+        let users = self.users.lock().unwrap();
+        Ok(users
+            .iter()
+            .find(|user| user.username == username && user.domain == domain)
+            .cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<SysUserModel>, DbErr> {
+        Ok(self.users.lock().unwrap().clone())
+    }
+
+    async fn find_page(&self, params: &UserPageRequest) -> Result<(u64, Vec<SysUserModel>), DbErr> {
+        let users = self.users.lock().unwrap();
+        let matched: Vec<SysUserModel> = users
+            .iter()
+            .filter(|user| {
+                params
+                    .keywords
+                    .as_ref()
+                    .is_none_or(|keywords| user.username.contains(keywords.as_str()))
+            })
+            .filter(|user| params.status.as_ref().is_none_or(|status| &user.status == status))
+            .filter(|user| {
+                params
+                    .domain_code
+                    .as_ref()
+                    .is_none_or(|domain| &user.domain == domain)
+            })
+            .cloned()
+            .collect();
+
+        let size = params.page_details.size as usize;
+        let skip = (params.page_details.current.saturating_sub(1) as usize) * size;
+        let records = matched.iter().skip(skip).take(size).cloned().collect();
+        Ok((matched.len() as u64, records))
+    }
+
+    async fn insert(&self, user: SysUserModel) -> Result<SysUserModel, DbErr> {
+        self.users.lock().unwrap().push(user.clone());
+        Ok(user)
+    }
+
+    async fn update(&self, user: SysUserModel) -> Result<SysUserModel, DbErr> {
+        let mut users = self.users.lock().unwrap();
+        let existing = users
+            .iter_mut()
+            .find(|existing| existing.id == user.id)
+            .ok_or_else(|| DbErr::RecordNotFound(user.id.clone()))?;
+        *existing = user.clone();
+        Ok(user)
+    }
+
+    async fn replace_password_hash(
+        &self,
+        id: &str,
+        old_hash: &str,
+        new_hash: &str,
+    ) -> Result<bool, DbErr> {
+        let mut users = self.users.lock().unwrap();
+        match users.iter_mut().find(|user| user.id == id && user.password == old_hash) {
+            Some(user) => {
+                user.password = new_hash.to_string();
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, DbErr> {
+        let mut users = self.users.lock().unwrap();
+        let before = users.len();
+        users.retain(|user| user.id != id);
+        Ok(users.len() < before)
+    }
+
+    async fn find_role_codes(&self, user_id: &str, domain: &str) -> Result<Vec<String>, DbErr> {
+        let users = self.users.lock().unwrap();
+        let home = users.iter().any(|user| user.id == user_id && user.domain == domain);
+        let roles = if home {
+            self.roles.get(user_id)
+        } else {
+            self.memberships.get(&(user_id.to_string(), domain.to_string()))
+        };
+        Ok(roles.cloned().unwrap_or_default())
+    }
+
+    async fn find_member_domains(&self, user_id: &str) -> Result<Vec<UserDomainOutput>, DbErr> {
+        let users = self.users.lock().unwrap();
+        let Some(user) = users.iter().find(|user| user.id == user_id) else {
+            return Ok(Vec::new());
+        };
+
+        let mut codes: Vec<&String> = self
+            .memberships
+            .keys()
+            .filter(|(member, _)| member == user_id)
+            .map(|(_, domain)| domain)
+            .collect();
+        codes.push(&user.domain);
+        codes.sort();
+
+        Ok(codes
+            .into_iter()
+            .filter_map(|code| {
+                let (name, status) = self.domains.get(code)?;
+                Some(UserDomainOutput {
+                    code: code.clone(),
+                    name: name.clone(),
+                    status: status.clone(),
+                    home: *code == user.domain,
+                    current: false,
+                })
+            })
+            .collect())
+    }
+
+    /**
+     * 角色只保存了代码，ID和名称都使用角色代码
+     */
+    async fn find_roles_with_domain(&self, user_id: &str) -> Result<Vec<UserRoleOutput>, DbErr> {
+        let users = self.users.lock().unwrap();
+        let Some(user) = users.iter().find(|user| user.id == user_id) else {
+            return Ok(Vec::new());
+        };
+        let Some((domain_name, _)) = self.domains.get(&user.domain) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self
+            .roles
+            .get(user_id)
+            .into_iter()
+            .flatten()
+            .map(|code| UserRoleOutput {
+                id: code.clone(),
+                code: code.clone(),
+                name: code.clone(),
+                status: Status::Enabled,
+                domain_code: user.domain.clone(),
+                domain_name: domain_name.clone(),
+            })
+            .collect())
+    }
+}
@@ -1,9 +1,12 @@
 /** 访问密钥服务模块
  * 
  * 该模块提供了访问密钥（API Key）的管理功能，包括：
- * - 访问密钥的创建和删除
+ * - 访问密钥的创建、查询、更新和删除
+ * - 列表与详情中的密钥脱敏，明文密钥仅在创建时返回
  * - 访问密钥的验证和授权
  * - 访问密钥的分页查询
+ * - 访问密钥最近使用时间记录与未使用密钥查询
+ * - 访问密钥的客户端IP白名单
  * 
  * 主要组件
  * --------
@@ -15,7 +18,7 @@
  * 
  * 事件处理
  * --------
- * * `api_key_validate_listener`: API密钥验证事件监听器
+ * * `api_key_validate_listener`: API密钥验证事件监听器，按密钥去抖后记录最近使用时间
  * 
  * 使用示例
  * --------
@@ -29,20 +32,26 @@
  *  */
  */
 
-use std::any::Any;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
-    QueryFilter, Set, TransactionTrait,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
 };
+use serde_json::Value as JsonValue;
 use server_core::{
-    sign::{ApiKeyEvent, ValidatorType},
-    web::{error::AppError, page::PaginatedData},
+    sign::{parse_allowed_ip, ApiKeyEvent, ApiKeySecret, ValidatorType},
+    web::{
+        error::AppError,
+        page::{KeywordFilter, PaginatedData},
+    },
     paginated_data,
 };
-use server_global::project_info;
+use server_global::{global::EventReceiver, project_error, project_info};
 use server_model::admin::{
     entities::{
         prelude::SysAccessKey,
@@ -51,7 +60,12 @@ use server_model::admin::{
             Model as SysAccessKeyModel,
         },
     },
-    input::{AccessKeyPageRequest, CreateAccessKeyInput},
+    input::{
+        normalize_allowed_ips, AccessKeyPageRequest, CreateAccessKeyInput, UnusedAccessKeyRequest,
+        UpdateAccessKeyInput,
+    },
+    output::AccessKeyOutput,
+    timestamps,
 };
 use tracing::instrument;
 use ulid::Ulid;
@@ -60,19 +74,26 @@ use crate::helper::db_helper;
 
 use super::sys_access_key_error::AccessKeyError;
 
+/** 同一访问密钥两次写入最近使用时间的最小间隔 */
+const LAST_USED_DEBOUNCE: Duration = Duration::from_secs(60);
+
 /** 访问密钥服务接口
  * 
  * 定义了访问密钥管理的核心接口，包括：
  * - 分页查询访问密钥
+ * - 获取访问密钥详情
  * - 创建访问密钥
+ * - 更新访问密钥
  * - 删除访问密钥
  * - 初始化访问密钥
+ * - 查询未使用的访问密钥
+ * - 查询访问密钥所属域
  */
 #[async_trait]
 pub trait TAccessKeyService {
     /** 分页查询访问密钥
      * 
-     * 根据查询条件分页获取访问密钥列表
+     * 根据查询条件分页获取访问密钥列表，密钥已脱敏
      * 
      * 参数
      * --------
@@ -80,12 +101,26 @@ pub trait TAccessKeyService {
      * 
      * 返回
      * --------
-     * * `Result<PaginatedData<SysAccessKeyModel>, AppError>` - 分页访问密钥数据或错误
+     * * `Result<PaginatedData<AccessKeyOutput>, AppError>` - 分页访问密钥数据或错误
      */
     async fn find_paginated_access_keys(
         &self,
         params: AccessKeyPageRequest,
-    ) -> Result<PaginatedData<SysAccessKeyModel>, AppError>;
+    ) -> Result<PaginatedData<AccessKeyOutput>, AppError>;
+
+    /** 获取访问密钥详情
+     * 
+     * 根据ID获取访问密钥，密钥已脱敏
+     * 
+     * 参数
+     * --------
+     * * `id` - 访问密钥记录ID
+     * 
+     * 返回
+     * --------
+     * * `Result<AccessKeyOutput, AppError>` - 访问密钥详情或错误
+     */
+    async fn get_access_key(&self, id: &str) -> Result<AccessKeyOutput, AppError>;
 
     /** 创建访问密钥
      * 
@@ -107,6 +142,24 @@ pub trait TAccessKeyService {
         input: CreateAccessKeyInput,
     ) -> Result<SysAccessKeyModel, AppError>;
 
+    /** 更新访问密钥
+     * 
+     * 更新访问密钥的状态、描述和IP白名单，密钥本身不可修改；
+     * 白名单变更后立即对后续请求生效
+     * 
+     * 参数
+     * --------
+     * * `input` - 访问密钥更新参数
+     * 
+     * 返回
+     * --------
+     * * `Result<AccessKeyOutput, AppError>` - 更新后的访问密钥信息（密钥已脱敏）或错误
+     */
+    async fn update_access_key(
+        &self,
+        input: UpdateAccessKeyInput,
+    ) -> Result<AccessKeyOutput, AppError>;
+
     /** 删除访问密钥
      * 
      * 根据ID删除访问密钥，包括：
@@ -134,6 +187,38 @@ pub trait TAccessKeyService {
      * * `Result<(), AppError>` - 初始化结果
      */
     async fn initialize_access_key(&self) -> Result<(), AppError>;
+
+    /** 查询未使用的访问密钥
+     * 
+     * 返回最近 `days` 天内未被使用的访问密钥，包括：
+     * - 最近使用时间早于时间窗口的密钥
+     * - 从未使用且创建时间早于时间窗口的密钥
+     * 
+     * 参数
+     * --------
+     * * `params` - 查询参数，包含时间窗口天数
+     * 
+     * 返回
+     * --------
+     * * `Result<Vec<AccessKeyOutput>, AppError>` - 未使用的访问密钥列表（密钥已脱敏）或错误
+     */
+    async fn find_unused_access_keys(
+        &self,
+        params: UnusedAccessKeyRequest,
+    ) -> Result<Vec<AccessKeyOutput>, AppError>;
+
+    /** 查询访问密钥所属域
+     * 
+     * 参数
+     * --------
+     * * `access_key_id` - 访问密钥ID（AK）
+     * 
+     * 返回
+     * --------
+     * * `Result<Option<String>, AppError>` - 所属域代码，密钥不存在时返回None
+     */
+    async fn find_access_key_domain(&self, access_key_id: &str)
+        -> Result<Option<String>, AppError>;
 }
 
 /** 访问密钥服务实现
@@ -166,13 +251,7 @@ impl SysAccessKeyService {
         let result = access_key.insert(txn).await.map_err(AppError::from)?;
 
         // 添加到验证器
-        server_core::sign::add_key(ValidatorType::Simple, &result.access_key_id, None).await;
-        server_core::sign::add_key(
-            ValidatorType::Complex,
-            &result.access_key_id,
-            Some(&result.access_key_secret),
-        )
-        .await;
+        register_access_key(&result).await;
 
         Ok(result)
     }
@@ -212,6 +291,32 @@ impl SysAccessKeyService {
 
         Ok(())
     }
+
+    /** 更新访问密钥的最近使用时间
+     * 
+     * 参数
+     * --------
+     * * `access_key_id` - 访问密钥ID
+     * 
+     * 返回
+     * --------
+     * * `Result<(), AppError>` - 更新结果
+     */
+    async fn touch_last_used(access_key_id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        SysAccessKey::update_many()
+            .col_expr(
+                SysAccessKeyColumn::LastUsedAt,
+                Expr::value(timestamps::now()),
+            )
+            .filter(SysAccessKeyColumn::AccessKeyId.eq(access_key_id))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -219,12 +324,12 @@ impl TAccessKeyService for SysAccessKeyService {
     async fn find_paginated_access_keys(
         &self,
         params: AccessKeyPageRequest,
-    ) -> Result<PaginatedData<SysAccessKeyModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PaginatedData<AccessKeyOutput>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysAccessKey::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any().add(SysAccessKeyColumn::Domain.contains(keywords));
+            let condition = KeywordFilter::new(keywords).condition(&[SysAccessKeyColumn::Domain]);
             query = query.filter(condition);
         }
 
@@ -235,10 +340,13 @@ impl TAccessKeyService for SysAccessKeyService {
             .map_err(AppError::from)?;
 
         let paginator = query.paginate(db.as_ref(), params.page_details.size);
-        let records = paginator
+        let records: Vec<AccessKeyOutput> = paginator
             .fetch_page(params.page_details.current - 1)
             .await
-            .map_err(AppError::from)?;
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(AccessKeyOutput::from)
+            .collect();
 
         Ok(paginated_data!(
             total,
@@ -248,10 +356,23 @@ impl TAccessKeyService for SysAccessKeyService {
         ))
     }
 
+    async fn get_access_key(&self, id: &str) -> Result<AccessKeyOutput, AppError> {
+        let db = db_helper::get_read_connection().await?;
+
+        SysAccessKey::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .map(AccessKeyOutput::from)
+            .ok_or_else(|| AccessKeyError::AccessKeyNotFound.into())
+    }
+
     async fn create_access_key(
         &self,
         input: CreateAccessKeyInput,
     ) -> Result<SysAccessKeyModel, AppError> {
+        check_verification_window(input.timestamp_tolerance_ms, input.nonce_ttl_secs)?;
+
         let db = db_helper::get_db_connection().await?;
         let txn = db.begin().await.map_err(AppError::from)?;
 
@@ -265,7 +386,10 @@ impl TAccessKeyService for SysAccessKeyService {
             description: Set(input.description),
             access_key_id: Set(access_key_id),
             access_key_secret: Set(access_key_secret),
-            created_at: Set(Local::now().naive_local()),
+            timestamp_tolerance_ms: Set(input.timestamp_tolerance_ms),
+            nonce_ttl_secs: Set(input.nonce_ttl_secs),
+            allowed_ips: Set(allowed_ips_value(&input.allowed_ips)),
+            created_at: Set(timestamps::now()),
             created_by: Set("TODO".to_string()),
             ..Default::default()
         };
@@ -287,6 +411,34 @@ impl TAccessKeyService for SysAccessKeyService {
         Ok(result)
     }
 
+    async fn update_access_key(
+        &self,
+        input: UpdateAccessKeyInput,
+    ) -> Result<AccessKeyOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        let existing = SysAccessKey::find_by_id(&input.id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(AccessKeyError::AccessKeyNotFound))?;
+
+        let mut access_key: SysAccessKeyActiveModel = existing.into();
+        access_key.status = Set(input.status);
+        access_key.description = Set(input.description);
+        let allowed_ips_changed = input.allowed_ips.is_some();
+        if let Some(allowed_ips) = input.allowed_ips {
+            access_key.allowed_ips = Set(allowed_ips_value(&allowed_ips));
+        }
+
+        let updated = access_key.update(db.as_ref()).await.map_err(AppError::from)?;
+        // 白名单变更后替换验证器中的密钥设置，后续请求立即按新白名单检查
+        if allowed_ips_changed {
+            register_access_key(&updated).await;
+        }
+        Ok(AccessKeyOutput::from(updated))
+    }
+
     async fn delete_access_key(&self, id: &str) -> Result<(), AppError> {
         let db = db_helper::get_db_connection().await?;
         let txn = db.begin().await.map_err(AppError::from)?;
@@ -312,37 +464,254 @@ impl TAccessKeyService for SysAccessKeyService {
             .map_err(AppError::from)?;
 
         for access_key in access_keys {
-            server_core::sign::add_key(ValidatorType::Simple, &access_key.access_key_id, None)
-                .await;
-            server_core::sign::add_key(
-                ValidatorType::Complex,
-                &access_key.access_key_id,
-                Some(&access_key.access_key_secret),
-            )
-            .await;
+            register_access_key(&access_key).await;
         }
 
         Ok(())
     }
+
+    async fn find_unused_access_keys(
+        &self,
+        params: UnusedAccessKeyRequest,
+    ) -> Result<Vec<AccessKeyOutput>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        let cutoff = timestamps::now() - chrono::Duration::days(i64::from(params.days.max(1)));
+
+        let condition = Condition::any()
+            .add(SysAccessKeyColumn::LastUsedAt.lt(cutoff))
+            .add(
+                Condition::all()
+                    .add(SysAccessKeyColumn::LastUsedAt.is_null())
+                    .add(SysAccessKeyColumn::CreatedAt.lt(cutoff)),
+            );
+
+        SysAccessKey::find()
+            .filter(condition)
+            .order_by_asc(SysAccessKeyColumn::LastUsedAt)
+            .order_by_asc(SysAccessKeyColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map(|keys| keys.into_iter().map(AccessKeyOutput::from).collect())
+            .map_err(AppError::from)
+    }
+
+    async fn find_access_key_domain(
+        &self,
+        access_key_id: &str,
+    ) -> Result<Option<String>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+
+        SysAccessKey::find()
+            .filter(SysAccessKeyColumn::AccessKeyId.eq(access_key_id))
+            .one(db.as_ref())
+            .await
+            .map(|access_key| access_key.map(|access_key| access_key.domain))
+            .map_err(AppError::from)
+    }
+}
+
+/** 访问密钥使用记录去抖器
+ * 
+ * 记录每个访问密钥最近一次写库的时间，窗口内的重复事件直接丢弃，
+ * 避免高频调用造成写放大
+ */
+struct LastUsedDebouncer {
+    window: Duration,
+    written_at: HashMap<String, Instant>,
+}
+
+impl LastUsedDebouncer {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            written_at: HashMap::new(),
+        }
+    }
+
+    /** 判断是否需要写入，需要写入时同时记录本次写入时间 */
+    fn should_write(&mut self, access_key_id: &str, now: Instant) -> bool {
+        if let Some(written_at) = self.written_at.get(access_key_id) {
+            if now.saturating_duration_since(*written_at) < self.window {
+                return false;
+            }
+        }
+        self.written_at.insert(access_key_id.to_string(), now);
+        true
+    }
 }
 
 /** API密钥验证事件监听器
  * 
  * 监听并处理API密钥验证事件，用于：
  * - 记录密钥验证日志
- * - 监控密钥使用情况
+ * - 更新密钥最近使用时间（每个密钥每分钟最多写库一次）
  * 
  * 参数
  * --------
  * * `rx` - 事件接收器
  */
 #[instrument(skip(rx))]
-pub async fn api_key_validate_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
-    while let Some(event) = rx.recv().await {
-        if let Some(api_key_event) = event.downcast_ref::<ApiKeyEvent>() {
-            project_info!("API key validated: {:?}", api_key_event);
+pub async fn api_key_validate_listener(mut rx: EventReceiver<ApiKeyEvent>) {
+    let mut debouncer = LastUsedDebouncer::new(LAST_USED_DEBOUNCE);
+
+    while let Some(api_key_event) = rx.recv().await {
+        project_info!("API key validated: {:?}", api_key_event);
+
+        if !debouncer.should_write(&api_key_event.api_key, Instant::now()) {
+            continue;
         }
+        if let Err(e) = SysAccessKeyService::touch_last_used(&api_key_event.api_key).await {
+            project_error!("Failed to update access key last used time: {:?}", e);
+        }
+    }
+}
+
+/**
+ * 将访问密钥添加到简单和复杂验证器
+ *
+ * 密钥已存在时替换其验证设置和IP白名单
+ */
+async fn register_access_key(access_key: &SysAccessKeyModel) {
+    let key = &access_key.access_key_id;
+    let secret = api_key_secret(access_key);
+    server_core::sign::add_key(ValidatorType::Simple, key, Some(secret.clone())).await;
+    server_core::sign::add_key(ValidatorType::Complex, key, Some(secret)).await;
+}
+
+/**
+ * 由访问密钥记录生成签名验证使用的密钥设置
+ *
+ * 未配置时间戳窗口或 nonce 保留时间的密钥使用默认值，未配置IP白名单的密钥不限制调用方地址
+ */
+fn api_key_secret(access_key: &SysAccessKeyModel) -> ApiKeySecret {
+    let allowed_ips: Vec<String> = access_key
+        .allowed_ips
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+    ApiKeySecret::new(
+        access_key.access_key_secret.clone(),
+        access_key.timestamp_tolerance_ms,
+        access_key.nonce_ttl_secs.and_then(|secs| u64::try_from(secs).ok()),
+    )
+    .with_allowed_ips(allowed_ips.iter().filter_map(|value| parse_allowed_ip(value)).collect())
+}
+
+/**
+ * 生成IP白名单的存储值
+ *
+ * 规范化为CIDR格式，白名单为空时存储为NULL
+ */
+fn allowed_ips_value(allowed_ips: &[String]) -> Option<JsonValue> {
+    let normalized = normalize_allowed_ips(allowed_ips);
+    (!normalized.is_empty()).then(|| JsonValue::from(normalized))
+}
+
+/**
+ * 检查 nonce 保留时间能否覆盖时间戳窗口
+ *
+ * nonce 在时间戳仍有效时过期会导致同一请求可以被重放
+ */
+fn check_verification_window(
+    timestamp_tolerance_ms: Option<i64>,
+    nonce_ttl_secs: Option<i64>,
+) -> Result<(), AccessKeyError> {
+    let Some(nonce_ttl_secs) = nonce_ttl_secs else {
+        return Ok(());
+    };
+    let secret = ApiKeySecret::new(String::new(), timestamp_tolerance_ms, None);
+    let min_secs = ApiKeySecret::min_nonce_ttl_secs(secret.timestamp_tolerance_ms);
+    if u64::try_from(nonce_ttl_secs).unwrap_or(0) < min_secs {
+        return Err(AccessKeyError::NonceTtlTooShort(min_secs));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use server_model::admin::entities::sea_orm_active_enums::Status;
+
+    use super::*;
+
+    #[test]
+    fn test_check_verification_window() {
+        assert!(check_verification_window(None, None).is_ok());
+        assert!(check_verification_window(Some(900_000), None).is_ok());
+        assert!(check_verification_window(None, Some(600)).is_ok());
+        assert!(check_verification_window(Some(60_000), Some(120)).is_ok());
+        assert!(matches!(
+            check_verification_window(Some(60_000), Some(119)),
+            Err(AccessKeyError::NonceTtlTooShort(120))
+        ));
+        assert!(matches!(
+            check_verification_window(None, Some(60)),
+            Err(AccessKeyError::NonceTtlTooShort(600))
+        ));
+    }
+
+    #[test]
+    fn test_api_key_secret_uses_per_key_window() {
+        let mut access_key = SysAccessKeyModel {
+            id: "1".to_string(),
+            domain: "built-in".to_string(),
+            access_key_id: "AK1".to_string(),
+            access_key_secret: "SK1".to_string(),
+            status: Status::Enabled,
+            description: None,
+            created_at: timestamps::now(),
+            created_by: "system".to_string(),
+            last_used_at: None,
+            timestamp_tolerance_ms: None,
+            nonce_ttl_secs: None,
+            allowed_ips: None,
+        };
+        assert_eq!(api_key_secret(&access_key), ApiKeySecret::from("SK1"));
+
+        access_key.timestamp_tolerance_ms = Some(900_000);
+        access_key.nonce_ttl_secs = Some(1_800);
+        let secret = api_key_secret(&access_key);
+        assert_eq!(secret.timestamp_tolerance_ms, 900_000);
+        assert_eq!(secret.nonce_ttl_secs, 1_800);
+        assert!(secret.allowed_ips.is_empty());
+    }
+
+    #[test]
+    fn test_api_key_secret_loads_allowed_ips() {
+        let allowed_ips = ["203.0.113.9", "2001:db8::/32"].map(String::from);
+        let stored = allowed_ips_value(&allowed_ips);
+        assert_eq!(stored, Some(serde_json::json!(["203.0.113.9/32", "2001:db8::/32"])));
+        assert_eq!(allowed_ips_value(&[]), None);
+
+        let access_key = SysAccessKeyModel {
+            id: "1".to_string(),
+            domain: "built-in".to_string(),
+            access_key_id: "AK1".to_string(),
+            access_key_secret: "SK1".to_string(),
+            status: Status::Enabled,
+            description: None,
+            created_at: timestamps::now(),
+            created_by: "system".to_string(),
+            last_used_at: None,
+            timestamp_tolerance_ms: None,
+            nonce_ttl_secs: None,
+            allowed_ips: stored,
+        };
+        let secret = api_key_secret(&access_key);
+        assert_eq!(
+            secret.allowed_ips,
+            vec!["203.0.113.9/32".parse().unwrap(), "2001:db8::/32".parse().unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_last_used_debouncer() {
+        let mut debouncer = LastUsedDebouncer::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(debouncer.should_write("AK1", start));
+        assert!(!debouncer.should_write("AK1", start + Duration::from_secs(59)));
+        assert!(debouncer.should_write("AK2", start + Duration::from_secs(59)));
+        assert!(debouncer.should_write("AK1", start + Duration::from_secs(60)));
+        assert!(!debouncer.should_write("AK1", start + Duration::from_secs(90)));
     }
 }
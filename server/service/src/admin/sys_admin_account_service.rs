@@ -0,0 +1,496 @@
+/**
+ * 管理员账号维护服务模块
+ *
+ * 供命令行维护工具和启动流程直接操作数据库，不经过HTTP接口和权限校验，包括：
+ * - 创建管理员账号并分配角色
+ * - 域内没有启用的管理员时按配置创建初始管理员
+ * - 重置用户密码
+ * - 检查仍在使用数据迁移默认密码的用户
+ *
+ * 用于超级管理员密码丢失等无法通过接口处理的场景，只应由运维人员在服务器上执行。
+ *
+ * 主要组件
+ * --------
+ * - SysAdminAccountService: 管理员账号维护服务
+ */
+
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbErr, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+};
+use server_model::admin::{
+    entities::{
+        casbin_rule::ActiveModel as CasbinRuleActiveModel,
+        prelude::{SysDomain, SysRole, SysUser, SysUserRole},
+        sea_orm_active_enums::Status,
+        sys_domain::Column as SysDomainColumn,
+        sys_role::{Column as SysRoleColumn, Model as SysRoleModel},
+        sys_user::{ActiveModel as SysUserActiveModel, Column as SysUserColumn},
+        sys_user_role::{ActiveModel as SysUserRoleActiveModel, Column as SysUserRoleColumn},
+    },
+    output::UserWithoutPassword,
+};
+use server_utils::SecureUtil;
+use ulid::Ulid;
+
+use crate::admin::errors::UserError;
+
+/** 命令行操作记录的操作人 */
+const CLI_OPERATOR: &str = "cli";
+
+/** 数据迁移写入的内置用户的默认密码哈希 */
+const SEED_PASSWORD_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$8TC8kz2KUf0ytBWeFn5CZA$UgL+qvhpeNyijDBfL4A90KjdXOJ7tNP77RrufQhOkgg";
+
+/**
+ * 管理员账号维护服务
+ */
+pub struct SysAdminAccountService;
+
+impl SysAdminAccountService {
+    /**
+     * 创建管理员账号
+     *
+     * 在一个事务内创建启用状态的用户，分配指定角色并写入Casbin分组规则
+     *
+     * @param db 数据库连接
+     * @param domain 域代码
+     * @param username 用户名
+     * @param password 明文密码
+     * @param role_code 分配的角色代码
+     * @return Result<UserWithoutPassword, UserError> 创建的用户或错误
+     *
+     * 错误
+     * -----
+     * - DomainNotFound: 域不存在
+     * - RoleNotFound: 角色不存在
+     * - UsernameAlreadyExists: 用户名已存在
+     */
+    pub async fn create_admin<C: TransactionTrait>(
+        db: &C,
+        domain: &str,
+        username: &str,
+        password: &str,
+        role_code: &str,
+    ) -> Result<UserWithoutPassword, UserError> {
+        let txn = db.begin().await?;
+        let user = Self::insert_admin(&txn, domain, username, password, role_code).await?;
+        txn.commit().await?;
+        Ok(user)
+    }
+
+    /**
+     * 确保域内存在启用的管理员
+     *
+     * 域内已有拥有指定角色的启用用户时不做任何修改，否则创建管理员账号
+     *
+     * @param db 数据库连接
+     * @param domain 域代码
+     * @param username 用户名
+     * @param password 明文密码
+     * @param role_code 管理员角色代码
+     * @return Result<Option<UserWithoutPassword>, UserError> 新创建的用户，已存在管理员时为None
+     *
+     * 错误
+     * -----
+     * - DomainNotFound: 域不存在
+     * - RoleNotFound: 角色不存在
+     * - UsernameAlreadyExists: 需要创建时用户名已被占用
+     */
+    pub async fn ensure_admin<C: TransactionTrait>(
+        db: &C,
+        domain: &str,
+        username: &str,
+        password: &str,
+        role_code: &str,
+    ) -> Result<Option<UserWithoutPassword>, UserError> {
+        let txn = db.begin().await?;
+
+        let role = Self::find_role(&txn, domain, role_code).await?;
+        let role_user_ids = SysUserRole::find()
+            .select_only()
+            .column(SysUserRoleColumn::UserId)
+            .filter(SysUserRoleColumn::RoleId.eq(role.id))
+            .into_tuple::<String>()
+            .all(&txn)
+            .await?;
+        let admin_count = SysUser::find()
+            .filter(SysUserColumn::Domain.eq(domain))
+            .filter(SysUserColumn::Status.eq(Status::Enabled))
+            .filter(SysUserColumn::Id.is_in(role_user_ids))
+            .count(&txn)
+            .await?;
+        if admin_count > 0 {
+            return Ok(None);
+        }
+
+        let user = Self::insert_admin(&txn, domain, username, password, role_code).await?;
+        txn.commit().await?;
+        Ok(Some(user))
+    }
+
+    /**
+     * 查询仍在使用数据迁移默认密码的启用用户
+     *
+     * @param db 数据库连接
+     * @return Result<Vec<String>, DbErr> 用户名列表
+     */
+    pub async fn find_seed_password_users<C: ConnectionTrait>(
+        db: &C,
+    ) -> Result<Vec<String>, DbErr> {
+        SysUser::find()
+            .select_only()
+            .column(SysUserColumn::Username)
+            .filter(SysUserColumn::Password.eq(SEED_PASSWORD_HASH))
+            .filter(SysUserColumn::Status.eq(Status::Enabled))
+            .into_tuple()
+            .all(db)
+            .await
+    }
+
+    /**
+     * 重置用户密码
+     *
+     * @param db 数据库连接
+     * @param domain 域代码
+     * @param username 用户名
+     * @param password 新的明文密码
+     * @return Result<(), UserError> 重置结果
+     *
+     * 错误
+     * -----
+     * - UserNotFound: 用户在该域中不存在
+     */
+    pub async fn reset_password<C: TransactionTrait>(
+        db: &C,
+        domain: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), UserError> {
+        let txn = db.begin().await?;
+
+        let user = SysUser::find()
+            .filter(SysUserColumn::Domain.eq(domain))
+            .filter(SysUserColumn::Username.eq(username))
+            .one(&txn)
+            .await?
+            .ok_or(UserError::UserNotFound)?;
+
+        let mut user = user.into_active_model();
+        user.password = Set(Self::hash_password(password)?);
+        user.updated_by = Set(Some(CLI_OPERATOR.to_string()));
+        user.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /**
+     * 在事务内创建启用状态的管理员，分配角色并写入Casbin分组规则
+     */
+    async fn insert_admin(
+        txn: &DatabaseTransaction,
+        domain: &str,
+        username: &str,
+        password: &str,
+        role_code: &str,
+    ) -> Result<UserWithoutPassword, UserError> {
+        SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(domain))
+            .one(txn)
+            .await?
+            .ok_or(UserError::DomainNotFound)?;
+
+        let role = Self::find_role(txn, domain, role_code).await?;
+
+        // 用户名在域内唯一
+        let existing_user = SysUser::find()
+            .filter(SysUserColumn::Domain.eq(domain))
+            .filter(SysUserColumn::Username.eq(username))
+            .one(txn)
+            .await?;
+        if existing_user.is_some() {
+            return Err(UserError::UsernameAlreadyExists);
+        }
+
+        let user = SysUserActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.to_string()),
+            username: Set(username.to_string()),
+            password: Set(Self::hash_password(password)?),
+            built_in: Set(false),
+            nick_name: Set(username.to_string()),
+            status: Set(Status::Enabled),
+            created_by: Set(CLI_OPERATOR.to_string()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        SysUserRoleActiveModel {
+            user_id: Set(user.id.clone()),
+            role_id: Set(role.id),
+        }
+        .insert(txn)
+        .await?;
+
+        // 分组规则 g = 用户, 角色, 域
+        CasbinRuleActiveModel {
+            ptype: Set("g".to_string()),
+            v0: Set(Some(user.id.clone())),
+            v1: Set(Some(role.code)),
+            v2: Set(Some(domain.to_string())),
+            v3: Set(Some(String::new())),
+            v4: Set(Some(String::new())),
+            v5: Set(Some(String::new())),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+
+        Ok(UserWithoutPassword::from(user))
+    }
+
+    /**
+     * 查询域内的角色，角色代码只在域内唯一
+     */
+    async fn find_role<C: ConnectionTrait>(
+        db: &C,
+        domain: &str,
+        role_code: &str,
+    ) -> Result<SysRoleModel, UserError> {
+        SysRole::find()
+            .filter(SysRoleColumn::Domain.eq(domain))
+            .filter(SysRoleColumn::Code.eq(role_code))
+            .one(db)
+            .await?
+            .ok_or(UserError::RoleNotFound)
+    }
+
+    fn hash_password(password: &str) -> Result<String, UserError> {
+        SecureUtil::hash_password(password.as_bytes())
+            .map_err(|e| UserError::internal_error(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        prelude::CasbinRule, sys_domain::ActiveModel as SysDomainActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(CasbinRule))).await.unwrap();
+
+        let now = timestamps::now();
+        SysDomainActiveModel {
+            id: Set("1".to_string()),
+            code: Set("built-in".to_string()),
+            name: Set("built-in".to_string()),
+            description: Set(None),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysRoleActiveModel {
+            id: Set("1".to_string()),
+            code: Set("ROLE_SUPER".to_string()),
+            domain: Set("built-in".to_string()),
+            name: Set("super".to_string()),
+            description: Set(None),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        db
+    }
+
+    #[tokio::test]
+    async fn test_create_admin_assigns_role() {
+        let db = setup_db().await;
+
+        let user =
+            SysAdminAccountService::create_admin(&db, "built-in", "ops", "secret", "ROLE_SUPER")
+                .await
+                .unwrap();
+        assert_eq!(user.status, Status::Enabled);
+
+        let roles = SysUserRole::find()
+            .filter(SysUserRoleColumn::UserId.eq(user.id.as_str()))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].role_id, "1");
+
+        let rules = CasbinRule::find().all(&db).await.unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].ptype, "g");
+        assert_eq!(rules[0].v0.as_deref(), Some(user.id.as_str()));
+        assert_eq!(rules[0].v1.as_deref(), Some("ROLE_SUPER"));
+        assert_eq!(rules[0].v2.as_deref(), Some("built-in"));
+
+        assert!(matches!(
+            SysAdminAccountService::create_admin(&db, "built-in", "ops", "secret", "ROLE_SUPER")
+                .await,
+            Err(UserError::UsernameAlreadyExists)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_admin_rejects_unknown_domain_and_role() {
+        let db = setup_db().await;
+
+        assert!(matches!(
+            SysAdminAccountService::create_admin(&db, "missing", "ops", "secret", "ROLE_SUPER")
+                .await,
+            Err(UserError::DomainNotFound)
+        ));
+        assert!(matches!(
+            SysAdminAccountService::create_admin(&db, "built-in", "ops", "secret", "ROLE_NONE")
+                .await,
+            Err(UserError::RoleNotFound)
+        ));
+        assert!(SysUser::find().all(&db).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_admin_uses_domain_role() {
+        let db = setup_db().await;
+        let now = timestamps::now();
+        SysDomainActiveModel {
+            id: Set("2".to_string()),
+            code: Set("tenant-a".to_string()),
+            name: Set("tenant-a".to_string()),
+            description: Set(None),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysRoleActiveModel {
+            id: Set("2".to_string()),
+            code: Set("ROLE_SUPER".to_string()),
+            domain: Set("tenant-a".to_string()),
+            name: Set("super".to_string()),
+            description: Set(None),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        // 用户名和角色代码只在域内唯一，同名账号分配本域的角色
+        SysAdminAccountService::create_admin(&db, "built-in", "ops", "secret", "ROLE_SUPER")
+            .await
+            .unwrap();
+        let user =
+            SysAdminAccountService::create_admin(&db, "tenant-a", "ops", "secret", "ROLE_SUPER")
+                .await
+                .unwrap();
+        let roles = SysUserRole::find()
+            .filter(SysUserRoleColumn::UserId.eq(user.id.as_str()))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].role_id, "2");
+    }
+
+    #[tokio::test]
+    async fn test_ensure_admin_only_creates_when_missing() {
+        let db = setup_db().await;
+
+        let created =
+            SysAdminAccountService::ensure_admin(&db, "built-in", "boot", "secret", "ROLE_SUPER")
+                .await
+                .unwrap();
+        assert_eq!(created.map(|user| user.username).as_deref(), Some("boot"));
+
+        let skipped =
+            SysAdminAccountService::ensure_admin(&db, "built-in", "other", "secret", "ROLE_SUPER")
+                .await
+                .unwrap();
+        assert!(skipped.is_none());
+        assert_eq!(SysUser::find().count(&db).await.unwrap(), 1);
+
+        // 已有管理员被禁用后重新创建
+        let mut admin = SysUser::find().one(&db).await.unwrap().unwrap().into_active_model();
+        admin.status = Set(Status::Disabled);
+        admin.update(&db).await.unwrap();
+        let created =
+            SysAdminAccountService::ensure_admin(&db, "built-in", "other", "secret", "ROLE_SUPER")
+                .await
+                .unwrap();
+        assert!(created.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_find_seed_password_users() {
+        let db = setup_db().await;
+        SysAdminAccountService::create_admin(&db, "built-in", "ops", "secret", "ROLE_SUPER")
+            .await
+            .unwrap();
+        assert!(SysAdminAccountService::find_seed_password_users(&db).await.unwrap().is_empty());
+
+        let mut user = SysUser::find().one(&db).await.unwrap().unwrap().into_active_model();
+        user.password = Set(SEED_PASSWORD_HASH.to_string());
+        user.update(&db).await.unwrap();
+        assert_eq!(
+            SysAdminAccountService::find_seed_password_users(&db).await.unwrap(),
+            ["ops".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reset_password() {
+        let db = setup_db().await;
+        SysAdminAccountService::create_admin(&db, "built-in", "ops", "old", "ROLE_SUPER")
+            .await
+            .unwrap();
+
+        SysAdminAccountService::reset_password(&db, "built-in", "ops", "new").await.unwrap();
+        let user = SysUser::find().one(&db).await.unwrap().unwrap();
+        assert!(SecureUtil::verify_password(b"new", &user.password).is_ok());
+        assert_eq!(user.updated_by.as_deref(), Some(CLI_OPERATOR));
+
+        assert!(matches!(
+            SysAdminAccountService::reset_password(&db, "other", "ops", "new").await,
+            Err(UserError::UserNotFound)
+        ));
+    }
+}
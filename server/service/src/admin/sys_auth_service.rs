@@ -2,8 +2,10 @@
  * 
  * 该模块提供了用户认证相关的核心功能，包括：
  * - 用户登录认证
+ * - 邀请码自助注册
  * - 用户角色和权限验证
  * - 用户路由获取
+ * - 多域用户切换当前域
  * - 登录事件处理
  * 
  * 主要组件
@@ -18,6 +20,7 @@
  * --------
  * * `AuthEvent`: 认证事件，用于处理登录相关的异步事件
  * * `auth_login_listener`: 登录事件监听器
+ * * `auth_login_failed_listener`: 登录失败事件监听器
  * * `jwt_created_listener`: JWT创建事件监听器
  * 
  * 辅助功能
@@ -29,11 +32,10 @@
  * 使用示例
  * --------
  * /* 创建认证服务实例
- *  * let auth_service = SysAuthService;
+ *  * let auth_service = SysAuthService::default();
  *  * 
  *  * // 执行密码登录
  *  * let output = auth_service.pwd_login(
- *  *     db,
  *  *     LoginInput {
  *  *         username: "admin".to_string(),
  *  *         password: "password".to_string(),
@@ -52,83 +54,76 @@
  *  */
  */
 
-use std::{any::Any, sync::Arc};
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
+use axum_casbin::casbin::{CoreApi, MgmtApi, RbacApi};
 #[allow(unused_imports)]
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect,
-    RelationTrait, Set,
+    RelationTrait, Set, TransactionTrait,
+};
+use server_config::JwtConfig;
+use server_constant::definition::{
+    consts::TokenStatus,
+    Audience,
 };
-use server_constant::definition::{Audience};
 use server_core::web::{
-    auth::Claims,
+    auth::{Claims, User},
+    error::AppError,
     jwt::{JwtUtils},
 };
-use server_global::{project_error, project_info};
+use server_global::{
+    global::{self, EventReceiver},
+    project_error, project_info,
+    redis_key::KeyBuilder,
+};
 use server_model::admin::{
     entities::{
-        prelude::{SysRole, SysUser},
-        sea_orm_active_enums::Status,
-        sys_domain::Column as SysDomainColumn,
+        prelude::{SysRole, SysTokens, SysUser},
+        sea_orm_active_enums::{MenuType, Status},
         sys_menu::{Column as SysMenuColumn, Entity as SysMenuEntity, Model as SysMenuModel},
-        sys_role::{Column as SysRoleColumn, Entity as SysRoleEntity, Relation as SysRoleRelation},
+        sys_role::{Column as SysRoleColumn, Entity as SysRoleEntity},
         sys_role_menu::{Column as SysRoleMenuColumn, Entity as SysRoleMenuEntity},
-        sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
-        sys_user_role::Relation as SysUserRoleRelation,
+        sys_tokens::Column as SysTokensColumn,
+        sys_user::{ActiveModel as SysUserActiveModel, Column as SysUserColumn},
+        sys_user_role::ActiveModel as SysUserRoleActiveModel,
     },
-    input::LoginInput,
-    output::{AuthOutput, MenuRoute, RouteMeta, UserRoute, UserWithDomainAndOrgOutput},
+    input::{LoginInput, RegisterInput},
+    output::{
+        AuthOutput, MenuRoute, RouteMeta, UserDomainOutput, UserRoute, UserWithDomainAndOrgOutput,
+        TOKEN_TYPE_BEARER,
+    },
+    timestamps,
 };
 use server_utils::{SecureUtil, TreeBuilder};
-use tokio::sync::mpsc;
+use tokio::sync::RwLock;
 use tracing::instrument;
+use ulid::Ulid;
 use crate::admin::dto::sys_auth_dto::LoginContext;
 use crate::admin::event_handlers::auth_event_handler::{AuthEvent, AuthEventHandler};
-use crate::admin::errors::AuthError;
+use crate::admin::events::login_log_event::LoginLogEvent;
+use crate::admin::errors::{AuthError, InvitationError};
+use crate::admin::repositories::{SeaOrmUserRepository, UserRepository};
+use crate::admin::SysInvitationService;
+use crate::helper::db_helper;
+use crate::helper::redis_helper::{RedisOps, RedisSource};
+use crate::helper::user_route_cache::{user_route_cache, UserRouteCache};
 
-/** 用户查询宏
- * 
- * 用于构建包含域和组织信息的用户查询，包括：
- * - 用户基本信息（ID、用户名、密码等）
- * - 域信息（域代码、域名称）
- * 
- * 参数
- * --------
- * * `$query` - 查询构建器
- * 
- * 返回
- * --------
- * * 构建好的查询，包含用户和域信息
- * 
- * 使用示例
- * --------
- * /* 构建用户查询
- *  * let query = select_user_with_domain_and_org_info!(SysUser::find());
- *  */
- */
-macro_rules! select_user_with_domain_and_org_info {
-    ($query:expr) => {{
-        $query
-            .select_only()
-            .column_as(SysUserColumn::Id, "id")
-            .column_as(SysUserColumn::Domain, "domain")
-            .column_as(SysUserColumn::Username, "username")
-            .column_as(SysUserColumn::Password, "password")
-            .column_as(SysUserColumn::NickName, "nick_name")
-            .column_as(SysUserColumn::Avatar, "avatar")
-            .column_as(SysDomainColumn::Code, "domain_code")
-            .column_as(SysDomainColumn::Name, "domain_name")
-    }};
-}
+/** 最大连续登录失败次数 */
+const MAX_LOGIN_ATTEMPTS: u64 = 5;
+/** 登录锁定时间，同时也是失败计数的统计窗口 */
+const LOGIN_LOCKOUT: Duration = Duration::from_secs(15 * 60);
 
 /** 认证服务 trait
  * 
  * 定义了系统认证相关的核心接口，包括：
  * - 密码登录认证
+ * - 邀请码自助注册
  * - 用户路由获取
  * - 用户基本信息验证
  * - 用户角色获取
+ * - 多域用户切换当前域
  */
 #[async_trait]
 pub trait TAuthService: Send + Sync {
@@ -136,7 +131,6 @@ pub trait TAuthService: Send + Sync {
      * 
      * 参数
      * --------
-     * * `db` - 数据库连接
      * * `input` - 登录输入信息
      * * `context` - 登录上下文信息
      * 
@@ -146,11 +140,35 @@ pub trait TAuthService: Send + Sync {
      */
     async fn pwd_login(
         &self,
-        db: Arc<DatabaseConnection>,
         input: LoginInput,
         context: LoginContext,
     ) -> Result<AuthOutput, AuthError>;
 
+    /** 凭邀请码自助注册
+     * 
+     * 在同一事务中核销邀请码并创建用户，用户归属邀请码的域并授予邀请码的角色，
+     * 同时在执行器中写入分组规则 `g, 用户ID, 角色代码, 域`，
+     * 注册成功后直接返回与登录相同的认证输出。
+     * 
+     * 参数
+     * --------
+     * * `db` - 数据库连接
+     * * `input` - 注册输入信息
+     * * `context` - 登录上下文信息
+     * * `enforcer` - 权限执行器
+     * 
+     * 返回
+     * --------
+     * * `Result<AuthOutput, AuthError>` - 认证输出或错误
+     */
+    async fn register(
+        &self,
+        db: Arc<DatabaseConnection>,
+        input: RegisterInput,
+        context: LoginContext,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<AuthOutput, AuthError>;
+
     /** 获取用户路由信息
      * 
      * 参数
@@ -170,11 +188,39 @@ pub trait TAuthService: Send + Sync {
         domain: &str,
     ) -> Result<UserRoute, AuthError>;
 
-    /** 验证用户基本信息
+    /** 获取用户按钮权限
+     * 
+     * 返回角色被授予的按钮类型菜单的路由名称，即界面上的权限标识
      * 
      * 参数
      * --------
      * * `db` - 数据库连接
+     * * `role_codes` - 角色代码列表
+     * * `domain` - 域代码
+     * 
+     * 返回
+     * --------
+     * * `Result<Vec<String>, AuthError>` - 权限标识列表或错误
+     */
+    async fn get_user_permissions(
+        &self,
+        db: Arc<DatabaseConnection>,
+        role_codes: &[String],
+        domain: &str,
+    ) -> Result<Vec<String>, AuthError>;
+
+    /** 验证用户基本信息
+     * 
+     * 验证用户的登录凭证，包括：
+     * - 用户和域存在性检查
+     * - 密码验证
+     * - 用户状态和域状态检查
+     * 
+     * 状态检查失败时优先返回状态错误，但密码校验仍会执行，
+     * 避免通过响应时间区分失败原因。
+     * 
+     * 参数
+     * --------
      * * `identifier` - 用户标识（用户名）
      * * `password` - 密码
      * * `domain` - 域代码
@@ -182,31 +228,105 @@ pub trait TAuthService: Send + Sync {
      * 返回
      * --------
      * * `Result<UserWithDomainAndOrgOutput, AuthError>` - 用户信息或错误
+     * 
+     * 错误
+     * --------
+     * * `UserNotFound` - 用户不存在或域不存在
+     * * `UserDisabled` - 用户已禁用或封禁
+     * * `DomainDisabled` - 用户所属域已禁用
+     * * `InvalidCredentials` - 密码错误
      */
     async fn verify_user_basic(
         &self,
-        db: &Arc<DatabaseConnection>,
         identifier: &str,
         password: &str,
         domain: &str,
     ) -> Result<UserWithDomainAndOrgOutput, AuthError>;
 
-    /** 获取用户角色列表
+    /** 复查用户状态
+     * 
+     * 用于已登录用户的请求，检查用户及其所属域仍处于启用状态
      * 
      * 参数
      * --------
      * * `user_id` - 用户ID
-     * * `db` - 数据库连接
+     * 
+     * 返回
+     * --------
+     * * `Result<(), AuthError>` - 检查结果
+     * 
+     * 错误
+     * --------
+     * * `UserNotFound` - 用户已删除
+     * * `UserDisabled` - 用户已禁用或封禁
+     * * `DomainDisabled` - 用户所属域已禁用
+     */
+    async fn verify_user_status(&self, user_id: &str) -> Result<(), AuthError>;
+
+    /** 获取用户在指定域中的角色列表
+     * 
+     * 用户加入多个域时，只返回分配在该域中的角色
+     * 
+     * 参数
+     * --------
+     * * `user_id` - 用户ID
+     * * `domain` - 域代码
      * 
      * 返回
      * --------
      * * `Result<Vec<String>, AuthError>` - 角色代码列表或错误
      */
-    async fn get_user_roles(
+    async fn get_user_roles(&self, user_id: &str, domain: &str) -> Result<Vec<String>, AuthError>;
+
+    /** 获取用户可操作的域
+     * 
+     * 包括用户所属的域和通过 `sys_user_domain` 加入的域
+     * 
+     * 参数
+     * --------
+     * * `user_id` - 用户ID
+     * * `current_domain` - 当前令牌所在的域，对应的域标记为 current
+     * 
+     * 返回
+     * --------
+     * * `Result<Vec<UserDomainOutput>, AuthError>` - 按代码排序的域列表或错误
+     */
+    async fn get_user_domains(
         &self,
         user_id: &str,
-        db: &Arc<DatabaseConnection>,
-    ) -> Result<Vec<String>, AuthError>;
+        current_domain: &str,
+    ) -> Result<Vec<UserDomainOutput>, AuthError>;
+
+    /** 切换当前操作的域
+     * 
+     * 校验用户属于目标域后，按用户在目标域中的角色签发新的令牌对，
+     * 无需重新登录；已签发的其他令牌不受影响，仍可使用到过期或被撤销。
+     * 个人访问令牌的权限范围有限制，不能借此换取不受限制的令牌对。
+     * 
+     * 参数
+     * --------
+     * * `user` - 当前认证用户
+     * * `domain` - 目标域代码
+     * * `context` - 登录上下文信息
+     * 
+     * 返回
+     * --------
+     * * `Result<AuthOutput, AuthError>` - 认证输出或错误
+     * 
+     * 错误
+     * --------
+     * * `PersonalTokenNotAllowed` - 使用个人访问令牌调用
+     * * `UserNotFound` - 用户已删除
+     * * `UserDisabled` - 用户已禁用或封禁
+     * * `DomainNotMember` - 用户不属于目标域
+     * * `DomainDisabled` - 目标域已禁用
+     */
+    async fn switch_domain(
+        &self,
+        user: &User,
+        domain: &str,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AuthError>;
 }
 
 /** 系统认证服务实现
@@ -215,11 +335,164 @@ pub trait TAuthService: Send + Sync {
  * - 用户登录认证
  * - 用户角色和权限验证
  * - 用户路由获取
+ * 
+ * 登录时的用户和角色查询通过 `UserRepository` 完成，
+ * 注册需要与邀请码核销处于同一事务，仍直接使用数据库连接。
  */
 #[derive(Clone)]
-pub struct SysAuthService;
+pub struct SysAuthService {
+    users: Arc<dyn UserRepository>,
+    routes: Arc<UserRouteCache>,
+}
+
+impl Default for SysAuthService {
+    fn default() -> Self {
+        Self::new(Arc::new(SeaOrmUserRepository))
+    }
+}
 
 impl SysAuthService {
+    /** 创建认证服务
+     * 
+     * 参数
+     * --------
+     * * `users` - 用户数据访问实现
+     */
+    pub fn new(users: Arc<dyn UserRepository>) -> Self {
+        Self { users, routes: user_route_cache() }
+    }
+
+    /** 使用指定的用户路由缓存
+     * 
+     * 默认使用全局缓存，由菜单分配变更事件失效；测试中可传入独立的缓存
+     * 
+     * 参数
+     * --------
+     * * `routes` - 用户路由缓存
+     */
+    pub fn with_route_cache(mut self, routes: Arc<UserRouteCache>) -> Self {
+        self.routes = routes;
+        self
+    }
+
+    /** 是否开启请求时的用户状态复查
+     * 
+     * 读取JWT配置中的 `strict_status_check`，未初始化配置时视为关闭
+     */
+    pub async fn strict_status_check_enabled() -> bool {
+        global::get_config::<JwtConfig>()
+            .await
+            .is_some_and(|config| config.strict_status_check)
+    }
+
+    /** 按令牌中的角色引用取回角色列表
+     * 
+     * 令牌声明超过大小上限时角色列表存放在Redis中，令牌只携带角色引用；
+     * 未携带引用的用户原样返回
+     * 
+     * 参数
+     * --------
+     * * `user` - 令牌解析出的用户
+     * 
+     * 错误
+     * --------
+     * * `TokenRolesUnavailable` - Redis不可用，或角色引用不存在、已过期
+     */
+    pub async fn resolve_token_roles(user: User) -> Result<User, AuthError> {
+        let Some(roles_ref) = user.roles_ref() else {
+            return Ok(user);
+        };
+        let key = KeyBuilder::token_roles(&roles_ref)
+            .map_err(|_| AuthError::TokenRolesUnavailable)?;
+        let ops = RedisOps::new(RedisSource::Primary).await.map_err(|e| {
+            project_error!("Failed to load token roles: {}", e);
+            AuthError::TokenRolesUnavailable
+        })?;
+        match ops.get_json::<Vec<String>>(&key).await {
+            Ok(Some(roles)) => Ok(user.with_roles(roles)),
+            Ok(None) => Err(AuthError::TokenRolesUnavailable),
+            Err(e) => {
+                project_error!("Failed to load token roles: {}", e);
+                Err(AuthError::TokenRolesUnavailable)
+            },
+        }
+    }
+
+    /** 查询访问令牌是否已被撤销
+     * 
+     * 令牌签发记录由JWT创建事件异步写入，尚未写入时视为未撤销；
+     * 已刷新或已撤销的令牌均视为不可用
+     * 
+     * 参数
+     * --------
+     * * `db` - 数据库连接
+     * * `access_token` - 访问令牌
+     */
+    pub async fn is_token_revoked(
+        db: &DatabaseConnection,
+        access_token: &str,
+    ) -> Result<bool, AuthError> {
+        let token = SysTokens::find()
+            .filter(SysTokensColumn::AccessToken.eq(access_token))
+            .one(db)
+            .await?;
+        Ok(token.is_some_and(|token| {
+            !token.status.parse::<TokenStatus>().is_ok_and(|status| status.is_valid())
+        }))
+    }
+
+    /** 在后台用当前参数重新哈希密码
+     * 
+     * 只在密码哈希仍为验证通过的哈希时写入，期间修改过的新密码不会被覆盖；
+     * 失败只记录日志，用户下次登录时重试
+     * 
+     * 参数
+     * --------
+     * * `user_id` - 用户ID
+     * * `old_hash` - 验证通过的密码哈希
+     * * `password` - 明文密码
+     */
+    fn rehash_password_in_background(&self, user_id: &str, old_hash: &str, password: &str) {
+        let users = self.users.clone();
+        let user_id = user_id.to_string();
+        let old_hash = old_hash.to_string();
+        let password = password.to_string();
+
+        tokio::spawn(async move {
+            let hashed = tokio::task::spawn_blocking(move || {
+                SecureUtil::hash_password(password.as_bytes()).map_err(|e| e.to_string())
+            })
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|result| result);
+
+            let new_hash = match hashed {
+                Ok(new_hash) => new_hash,
+                Err(e) => {
+                    project_error!("Failed to rehash password of user {}: {}", user_id, e);
+                    return;
+                },
+            };
+            if let Err(e) = users.replace_password_hash(&user_id, &old_hash, &new_hash).await {
+                project_error!("Failed to save rehashed password of user {}: {}", user_id, e);
+            }
+        });
+    }
+
+    /** 检查用户及其所属域的状态
+     * 
+     * 禁用和封禁的用户均视为不可用，先检查用户状态再检查域状态
+     */
+    fn check_status(user: &UserWithDomainAndOrgOutput) -> Result<(), AuthError> {
+        if user.status != Status::Enabled {
+            return Err(AuthError::UserDisabled);
+        }
+        if user.domain_status != Status::Enabled {
+            return Err(AuthError::DomainDisabled);
+        }
+        Ok(())
+    }
+
     /** 查找第一个有效的路由路径
      * 
      * 递归遍历路由树，返回第一个非空且非根路径的路由路径
@@ -247,154 +520,108 @@ impl SysAuthService {
         None
     }
 
-    /** 检查登录安全性
+    /** 生成登录失败计数键
      * 
-     * 执行登录相关的安全检查，包括：
-     * - 登录失败次数检查
-     * - IP 黑名单检查
-     * - 账号锁定检查
-     * - 登录时间范围检查
+     * 键过长时记录错误并返回 None，此时跳过登录失败次数限制
      * 
      * 参数
      * --------
+     * * `domain` - 域代码
      * * `username` - 用户名
-     * * `client_ip` - 客户端IP
+     */
+    fn login_attempts_key(domain: &str, username: &str) -> Option<String> {
+        KeyBuilder::login_attempts(domain, username)
+            .map_err(|e| project_error!("Failed to build login attempts key: {}", e))
+            .ok()
+    }
+
+    /** 获取登录失败计数使用的 Redis 操作实例
      * 
-     * 返回
-     * --------
-     * * `Result<(), AuthError>` - 检查结果
+     * Redis 为可选组件，未初始化时返回 None，此时跳过登录失败次数限制
      */
-    async fn check_login_security(
-        &self,
-        _username: &str,
-        _client_ip: &str,
-    ) -> Result<(), AuthError> {
-        // TODO: 实现登录安全检查
-        // 1. 检查登录失败次数
-        // 2. 检查 IP 黑名单
-        // 3. 检查账号是否被锁定
-        // 4. 检查是否在允许的时间范围内
-        Ok(())
+    async fn login_attempts_ops() -> Option<RedisOps> {
+        RedisOps::new(RedisSource::Primary).await.ok()
     }
 
-    /** 带安全检查的密码登录
+    /** 检查登录安全性
+     * 
+     * 执行登录相关的安全检查，当前包括：
+     * - 登录失败次数检查（超过上限后在锁定时间内拒绝登录）
      * 
-     * 在执行密码登录前先进行安全检查
+     * Redis 不可用时不阻断登录，仅记录错误日志。
      * 
      * 参数
      * --------
-     * * `db` - 数据库连接
-     * * `input` - 登录输入信息
-     * * `context` - 登录上下文信息
+     * * `domain` - 域代码
+     * * `username` - 用户名
      * 
      * 返回
      * --------
-     * * `Result<AuthOutput, AuthError>` - 认证输出或错误
+     * * `Result<(), AuthError>` - 检查结果
      */
-    #[allow(dead_code)]
-    async fn pwd_login_with_security(
-        &self,
-        db: Arc<DatabaseConnection>,
-        input: LoginInput,
-        context: LoginContext,
-    ) -> Result<AuthOutput, AuthError> {
-        self.check_login_security(&input.username, &context.client_ip)
-            .await?;
+    async fn check_login_security(&self, domain: &str, username: &str) -> Result<(), AuthError> {
+        let Some(key) = Self::login_attempts_key(domain, username) else {
+            return Ok(());
+        };
+        let Some(ops) = Self::login_attempts_ops().await else {
+            return Ok(());
+        };
 
-        self.pwd_login(db, input, context).await
+        match ops.get_json::<u64>(&key).await {
+            Ok(Some(attempts)) if attempts >= MAX_LOGIN_ATTEMPTS => {
+                Err(AuthError::TooManyLoginAttempts)
+            },
+            Ok(_) => Ok(()),
+            Err(e) => {
+                project_error!("Failed to read login attempts: {}", e);
+                Ok(())
+            },
+        }
     }
 
-    /** 验证用户基本信息
-     * 
-     * 验证用户的登录凭证，包括：
-     * - 用户名和密码验证
-     * - 用户状态检查
-     * - 域信息验证
+    /** 记录一次登录失败
      * 
      * 参数
      * --------
-     * * `db` - 数据库连接
-     * * `identifier` - 用户标识（用户名）
-     * * `password` - 密码
      * * `domain` - 域代码
-     * 
-     * 返回
-     * --------
-     * * `Result<UserWithDomainAndOrgOutput, AuthError>` - 用户信息或错误
-     * 
-     * 错误
-     * --------
-     * * `InvalidCredentials` - 用户名或密码错误
-     * * `UserDisabled` - 用户已禁用
-     * * `DomainNotFound` - 域不存在
+     * * `username` - 用户名
      */
-    #[instrument(skip(self, db, password), fields(identifier = %identifier, domain = %domain))]
-    async fn verify_user_basic(
-        &self,
-        db: &Arc<DatabaseConnection>,
-        identifier: &str,
-        password: &str,
-        domain: &str,
-    ) -> Result<UserWithDomainAndOrgOutput, AuthError> {
-        let user = select_user_with_domain_and_org_info!(SysUser::find())
-            .filter(SysUserColumn::Username.eq(identifier))
-            .filter(SysDomainColumn::Code.eq(domain))
-            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
-            .into_model::<UserWithDomainAndOrgOutput>()
-            .one(db.as_ref())
-            .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?
-            .ok_or_else(|| AuthError::UserNotFound)?;
-
-        //TODO validate user status and domain status
-
-        // 验证密码
-        if !SecureUtil::verify_password(password.as_bytes(), &user.password)
-            .map_err(|_| AuthError::AuthenticationFailed("Password verification failed".to_string()))?
-        {
-            return Err(AuthError::InvalidCredentials);
+    async fn record_login_failure(&self, domain: &str, username: &str) {
+        let Some(key) = Self::login_attempts_key(domain, username) else {
+            return;
+        };
+        if let Some(ops) = Self::login_attempts_ops().await {
+            if let Err(e) = ops.incr_with_ttl(&key, LOGIN_LOCKOUT).await {
+                project_error!("Failed to record login failure: {}", e);
+            }
         }
-
-        Ok(user)
     }
 
-    /** 获取用户角色列表
-     * 
-     * 查询用户关联的所有角色代码
+    /** 清除登录失败记录
      * 
      * 参数
      * --------
-     * * `user_id` - 用户ID
-     * * `db` - 数据库连接
-     * 
-     * 返回
-     * --------
-     * * `Result<Vec<String>, AuthError>` - 角色代码列表或错误
+     * * `domain` - 域代码
+     * * `username` - 用户名
      */
-    #[instrument(skip(self, db), fields(user_id = %user_id))]
-    async fn get_user_roles(
-        &self,
-        user_id: &str,
-        db: &Arc<DatabaseConnection>,
-    ) -> Result<Vec<String>, AuthError> {
-        SysRole::find()
-            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
-            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
-            .filter(SysUserColumn::Id.eq(user_id))
-            .select_only()
-            .column(SysRoleColumn::Code)
-            .into_tuple()
-            .all(db.as_ref())
-            .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))
+    async fn clear_login_failures(&self, domain: &str, username: &str) {
+        let Some(key) = Self::login_attempts_key(domain, username) else {
+            return;
+        };
+        if let Some(ops) = Self::login_attempts_ops().await {
+            if let Err(e) = ops.delete(&key).await {
+                project_error!("Failed to clear login failures: {}", e);
+            }
+        }
     }
 
-    /** 获取用户路由信息
+    /** 查询并构建用户路由信息
      * 
-     * 根据用户角色获取可访问的路由信息，包括：
+     * 不经过用户路由缓存，直接根据用户角色查询可访问的路由信息，包括：
      * - 菜单路由
      * - 路由元数据
-     * - 权限信息
+     * 
+     * 按钮类型的菜单不生成路由，见 `load_user_permissions`
      * 
      * 参数
      * --------
@@ -406,44 +633,22 @@ impl SysAuthService {
      * --------
      * * `Result<UserRoute, AuthError>` - 用户路由信息或错误
      */
-    #[instrument(skip(self, db, role_codes), fields(domain = %domain))]
-    #[allow(dead_code)]
-    async fn get_user_routes(
-        &self,
-        db: Arc<DatabaseConnection>,
+    #[instrument(skip(db, role_codes), fields(domain = %domain))]
+    pub(crate) async fn load_user_routes(
+        db: &DatabaseConnection,
         role_codes: &[String],
         domain: &str,
     ) -> Result<UserRoute, AuthError> {
-        if role_codes.is_empty() {
-            return Ok(UserRoute {
-                routes: vec![],
-                home: "/home".to_string(),
-            });
-        }
+        let menu_ids = Self::find_role_menu_ids(db, role_codes, domain).await?;
 
-        // 获取角色关联的菜单ID
-        let menu_ids = SysRoleMenuEntity::find()
-            .select_only()
-            .column(SysRoleMenuColumn::MenuId)
-            .join_rev(
-                JoinType::InnerJoin,
-                SysRoleEntity::has_many(SysRoleMenuEntity).into(),
-            )
-            .filter(SysRoleColumn::Code.is_in(role_codes.to_vec()))
-            .filter(SysRoleMenuColumn::Domain.eq(domain))
-            .distinct()
-            .into_tuple::<i32>()
-            .all(db.as_ref())
-            .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
-
-        // 查询菜单信息
+        // 查询菜单信息，按钮只用于权限控制，不生成路由
         let menus = SysMenuEntity::find()
             .filter(SysMenuColumn::Id.is_in(menu_ids))
+            .filter(SysMenuColumn::MenuType.ne(MenuType::Button))
             .filter(SysMenuColumn::Status.eq(Status::Enabled))
             .order_by_asc(SysMenuColumn::Sequence)
             .into_model::<SysMenuModel>()
-            .all(db.as_ref())
+            .all(db)
             .await
             .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
 
@@ -498,32 +703,196 @@ impl SysAuthService {
 
         Ok(UserRoute { routes, home })
     }
+
+    /** 查询用户按钮权限
+     * 
+     * 返回角色在域中被授予、且处于启用状态的按钮的路由名称，按排序号排列
+     * 
+     * 参数
+     * --------
+     * * `db` - 数据库连接
+     * * `role_codes` - 角色代码列表
+     * * `domain` - 域代码
+     * 
+     * 返回
+     * --------
+     * * `Result<Vec<String>, AuthError>` - 权限标识列表或错误
+     */
+    #[instrument(skip(db, role_codes), fields(domain = %domain))]
+    async fn load_user_permissions(
+        db: &DatabaseConnection,
+        role_codes: &[String],
+        domain: &str,
+    ) -> Result<Vec<String>, AuthError> {
+        let menu_ids = Self::find_role_menu_ids(db, role_codes, domain).await?;
+
+        SysMenuEntity::find()
+            .select_only()
+            .column(SysMenuColumn::RouteName)
+            .filter(SysMenuColumn::Id.is_in(menu_ids))
+            .filter(SysMenuColumn::MenuType.eq(MenuType::Button))
+            .filter(SysMenuColumn::Status.eq(Status::Enabled))
+            .order_by_asc(SysMenuColumn::Sequence)
+            .into_tuple::<String>()
+            .all(db)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))
+    }
+
+    /** 查询角色在域中关联的菜单ID
+     * 
+     * 参数
+     * --------
+     * * `db` - 数据库连接
+     * * `role_codes` - 角色代码列表
+     * * `domain` - 域代码
+     * 
+     * 返回
+     * --------
+     * * `Result<Vec<i32>, AuthError>` - 菜单ID列表或错误
+     */
+    pub(crate) async fn find_role_menu_ids(
+        db: &DatabaseConnection,
+        role_codes: &[String],
+        domain: &str,
+    ) -> Result<Vec<i32>, AuthError> {
+        SysRoleMenuEntity::find()
+            .select_only()
+            .column(SysRoleMenuColumn::MenuId)
+            .join_rev(
+                JoinType::InnerJoin,
+                SysRoleEntity::has_many(SysRoleMenuEntity).into(),
+            )
+            .filter(SysRoleColumn::Code.is_in(role_codes.to_vec()))
+            .filter(SysRoleMenuColumn::Domain.eq(domain))
+            .distinct()
+            .into_tuple::<i32>()
+            .all(db)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))
+    }
 }
 
 #[async_trait]
 impl TAuthService for SysAuthService {
     async fn pwd_login(
         &self,
-        db: Arc<DatabaseConnection>,
         input: LoginInput,
         context: LoginContext,
     ) -> Result<AuthOutput, AuthError> {
+        if let Err(e) = self.check_login_security(&context.domain, &input.username).await {
+            publish_login_failure(&input.username, &context, &e).await;
+            return Err(e);
+        }
+
         // 验证用户信息
-        let user = self.verify_user_basic(&db, &input.username, &input.password, &context.domain).await?;
+        let user = match self.verify_user_basic(&input.username, &input.password, &context.domain).await {
+            Ok(user) => user,
+            Err(e) => {
+                if matches!(e, AuthError::InvalidCredentials) {
+                    self.record_login_failure(&context.domain, &input.username).await;
+                }
+                publish_login_failure(&input.username, &context, &e).await;
+                return Err(e);
+            },
+        };
+        self.clear_login_failures(&context.domain, &input.username).await;
 
         // 获取用户角色
-        let role_codes = self.get_user_roles(&user.id, &db).await?;
+        let role_codes = self.get_user_roles(&user.id, &user.domain_code).await?;
 
         // 生成认证输出
         let auth_output = generate_auth_output(
-            user.id,
-            user.username,
+            user.id.clone(),
+            user.username.clone(),
             role_codes,
-            user.domain_code,
+            user.domain_code.clone(),
             None,
             context.audience,
         ).await?;
 
+        publish_login_event(user.id, user.username, user.domain_code, &auth_output, context)
+            .await;
+
+        Ok(auth_output)
+    }
+
+    async fn register(
+        &self,
+        db: Arc<DatabaseConnection>,
+        input: RegisterInput,
+        context: LoginContext,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<AuthOutput, AuthError> {
+        let now = timestamps::now();
+        let txn = db.begin().await?;
+
+        let invitation =
+            SysInvitationService::consume_invitation(&txn, &input.invitation_code, now).await?;
+
+        // 用户名在邀请码所属的域内唯一
+        let existing_user = SysUser::find()
+            .filter(SysUserColumn::Domain.eq(invitation.domain.as_str()))
+            .filter(SysUserColumn::Username.eq(input.username.as_str()))
+            .one(&txn)
+            .await?;
+        if existing_user.is_some() {
+            return Err(AuthError::UsernameAlreadyExists);
+        }
+
+        let role = SysRole::find_by_id(&invitation.role_id)
+            .one(&txn)
+            .await?
+            .ok_or(InvitationError::RoleNotFound)?;
+
+        let password = SecureUtil::hash_password(input.password.as_bytes())
+            .map_err(|e| AuthError::AuthenticationFailed(e.to_string()))?;
+
+        let user = SysUserActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(invitation.domain.clone()),
+            username: Set(input.username.clone()),
+            password: Set(password),
+            built_in: Set(false),
+            nick_name: Set(input.nick_name),
+            status: Set(Status::Enabled),
+            created_by: Set(input.username),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        SysUserRoleActiveModel {
+            user_id: Set(user.id.clone()),
+            role_id: Set(role.id),
+        }
+        .insert(&txn)
+        .await?;
+
+        // 与用户角色关联同步写入分组规则，写入失败时回滚注册
+        let rule = vec![user.id.clone(), role.code.clone(), invitation.domain.clone()];
+        enforcer
+            .write()
+            .await
+            .add_grouping_policy(rule)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
+
+        txn.commit().await?;
+
+        let auth_output = generate_auth_output(
+            user.id.clone(),
+            user.username.clone(),
+            vec![role.code],
+            invitation.domain.clone(),
+            None,
+            context.audience,
+        )
+        .await?;
+
+        publish_login_event(user.id, user.username, invitation.domain, &auth_output, context)
+            .await;
+
         Ok(auth_output)
     }
 
@@ -540,155 +909,205 @@ impl TAuthService for SysAuthService {
             });
         }
 
-        // 获取角色关联的菜单ID
-        let menu_ids = SysRoleMenuEntity::find()
-            .select_only()
-            .column(SysRoleMenuColumn::MenuId)
-            .join_rev(
-                JoinType::InnerJoin,
-                SysRoleEntity::has_many(SysRoleMenuEntity).into(),
-            )
-            .filter(SysRoleColumn::Code.is_in(role_codes.to_vec()))
-            .filter(SysRoleMenuColumn::Domain.eq(domain))
-            .distinct()
-            .into_tuple::<i32>()
-            .all(db.as_ref())
-            .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
-
-        // 查询菜单信息
-        let menus = SysMenuEntity::find()
-            .filter(SysMenuColumn::Id.is_in(menu_ids))
-            .filter(SysMenuColumn::Status.eq(Status::Enabled))
-            .order_by_asc(SysMenuColumn::Sequence)
-            .into_model::<SysMenuModel>()
-            .all(db.as_ref())
-            .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
-
-        // 构建菜单路由
-        let menu_routes: Vec<MenuRoute> = menus
-            .into_iter()
-            .map(|menu| MenuRoute {
-                name: menu.route_name,
-                path: menu.route_path,
-                component: menu.component,
-                meta: RouteMeta {
-                    title: menu.menu_name,
-                    i18n_key: menu.i18n_key,
-                    keep_alive: menu.keep_alive,
-                    constant: menu.constant,
-                    icon: menu.icon,
-                    order: menu.sequence,
-                    href: menu.href,
-                    hide_in_menu: menu.hide_in_menu,
-                    active_menu: menu.active_menu,
-                    multi_tab: menu.multi_tab,
-                },
-                children: Some(vec![]),
-                id: menu.id,
-                pid: menu.pid,
-            })
-            .collect();
+        if let Some(routes) = self.routes.get(domain, role_codes) {
+            return Ok(routes);
+        }
 
-        let menu_routes_ref = menu_routes.clone();
+        let routes = Self::load_user_routes(db.as_ref(), role_codes, domain).await?;
+        self.routes.insert(domain, role_codes, routes.clone());
+        Ok(routes)
+    }
 
-        // 构建路由树
-        let routes = TreeBuilder::build(
-            menu_routes,
-            |route| route.name.clone(),
-            |route| {
-                if route.pid == "0" {
-                    None
-                } else {
-                    menu_routes_ref
-                        .iter()
-                        .find(|m| m.id.to_string() == route.pid)
-                        .map(|m| m.name.clone())
-                }
-            },
-            |route| route.meta.order,
-            |route, children| {
-                route.children = Some(children);
-            },
-        );
+    async fn get_user_permissions(
+        &self,
+        db: Arc<DatabaseConnection>,
+        role_codes: &[String],
+        domain: &str,
+    ) -> Result<Vec<String>, AuthError> {
+        if role_codes.is_empty() {
+            return Ok(vec![]);
+        }
 
-        Ok(UserRoute { 
-            routes, 
-            home: "home".to_string() 
-        })
+        Self::load_user_permissions(db.as_ref(), role_codes, domain).await
     }
 
+    #[instrument(skip(self, password), fields(identifier = %identifier, domain = %domain))]
     async fn verify_user_basic(
         &self,
-        db: &Arc<DatabaseConnection>,
         identifier: &str,
         password: &str,
         domain: &str,
     ) -> Result<UserWithDomainAndOrgOutput, AuthError> {
-        let user = select_user_with_domain_and_org_info!(SysUser::find())
-            .filter(SysUserColumn::Username.eq(identifier))
-            .filter(SysDomainColumn::Code.eq(domain))
-            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
-            .into_model::<UserWithDomainAndOrgOutput>()
-            .one(db.as_ref())
+        let user = self
+            .users
+            .find_by_username_and_domain(identifier, domain)
             .await
             .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?
-            .ok_or_else(|| AuthError::UserNotFound)?;
+            .ok_or(AuthError::UserNotFound)?;
+
+        // 无论状态如何都先验证密码，状态错误与密码错误的响应时间一致
+        let password_matches = SecureUtil::verify_password(password.as_bytes(), &user.password)
+            .map_err(|_| AuthError::AuthenticationFailed("Password verification failed".to_string()))?;
 
-        // 验证密码
-        if !SecureUtil::verify_password(password.as_bytes(), &user.password)
-            .map_err(|_| AuthError::AuthenticationFailed("Password verification failed".to_string()))?
-        {
+        Self::check_status(&user)?;
+
+        if !password_matches {
             return Err(AuthError::InvalidCredentials);
         }
 
+        // 哈希参数低于当前配置时在后台重新哈希，不增加登录耗时
+        if SecureUtil::needs_rehash(&user.password) {
+            self.rehash_password_in_background(&user.id, &user.password, password);
+        }
+
         Ok(user)
     }
 
-    async fn get_user_roles(
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    async fn verify_user_status(&self, user_id: &str) -> Result<(), AuthError> {
+        let user = self
+            .users
+            .find_with_domain_by_id(user_id)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?
+            .ok_or(AuthError::UserNotFound)?;
+
+        Self::check_status(&user)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id, domain = %domain))]
+    async fn get_user_roles(&self, user_id: &str, domain: &str) -> Result<Vec<String>, AuthError> {
+        self.users
+            .find_role_codes(user_id, domain)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id))]
+    async fn get_user_domains(
         &self,
         user_id: &str,
-        db: &Arc<DatabaseConnection>,
-    ) -> Result<Vec<String>, AuthError> {
-        SysRole::find()
-            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
-            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
-            .filter(SysUserColumn::Id.eq(user_id))
-            .select_only()
-            .column(SysRoleColumn::Code)
-            .into_tuple()
-            .all(db.as_ref())
+        current_domain: &str,
+    ) -> Result<Vec<UserDomainOutput>, AuthError> {
+        let mut domains = self
+            .users
+            .find_member_domains(user_id)
             .await
-            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?;
+        for domain in &mut domains {
+            domain.current = domain.code == current_domain;
+        }
+        Ok(domains)
+    }
+
+    #[instrument(skip(self, user, context), fields(user_id = %user.user_id(), domain = %domain))]
+    async fn switch_domain(
+        &self,
+        user: &User,
+        domain: &str,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AuthError> {
+        if user.personal_token().is_some() {
+            return Err(AuthError::PersonalTokenNotAllowed);
+        }
+        let user_id = &user.user_id();
+        let user = self
+            .users
+            .find_with_domain_by_id(user_id)
+            .await
+            .map_err(|e| AuthError::DatabaseOperationFailed(e.to_string()))?
+            .ok_or(AuthError::UserNotFound)?;
+        // 只检查用户状态，所属域被禁用时仍可切换到其他启用的域
+        if user.status != Status::Enabled {
+            return Err(AuthError::UserDisabled);
+        }
+
+        let target = self
+            .get_user_domains(user_id, domain)
+            .await?
+            .into_iter()
+            .find(|member| member.current)
+            .ok_or_else(|| AuthError::DomainNotMember(domain.to_string()))?;
+        if target.status != Status::Enabled {
+            return Err(AuthError::DomainDisabled);
+        }
+
+        let role_codes = self.get_user_roles(&user.id, &target.code).await?;
+        let auth_output = generate_auth_output(
+            user.id.clone(),
+            user.username.clone(),
+            role_codes,
+            target.code.clone(),
+            None,
+            context.audience,
+        )
+        .await?;
+
+        publish_login_event(user.id, user.username, target.code, &auth_output, context).await;
+
+        Ok(auth_output)
     }
 }
 
 /** 发送认证事件
  * 
- * 将认证事件发送到事件通道，用于异步处理
+ * 将认证事件发送给登录事件监听器，用于异步处理
  * 
  * 参数
  * --------
- * * `sender` - 事件发送器
  * * `auth_event` - 认证事件
  * 
  * 返回
  * --------
- * * `Result<(), AuthError>` - 发送结果
+ * * `Result<(), AuthError>` - 发送结果，未注册监听器或通道已关闭时返回错误
  */
-#[instrument(skip(sender, auth_event))]
-#[allow(dead_code)]
-async fn send_auth_event(
-    sender: mpsc::UnboundedSender<Box<dyn std::any::Any + Send>>,
-    auth_event: AuthEvent,
-) -> Result<(), AuthError> {
-    sender
-        .send(Box::new(auth_event))
+#[instrument(skip(auth_event))]
+async fn send_auth_event(auth_event: AuthEvent) -> Result<(), AuthError> {
+    global::send_typed_event_async(auth_event)
+        .await
         .map_err(AuthError::SendError)?;
     Ok(())
 }
 
+/** 发布登录事件
+ * 
+ * 由登录事件监听器异步写入登录日志和令牌记录，发送失败只记录错误日志，不影响登录结果
+ * 
+ * 参数
+ * --------
+ * * `user_id` - 用户ID
+ * * `username` - 用户名
+ * * `domain` - 域代码
+ * * `auth_output` - 本次登录签发的令牌
+ * * `context` - 登录上下文
+ */
+async fn publish_login_event(
+    user_id: String,
+    username: String,
+    domain: String,
+    auth_output: &AuthOutput,
+    context: LoginContext,
+) {
+    let auth_event = AuthEvent {
+        user_id,
+        username,
+        domain,
+        access_token: auth_output.token.clone(),
+        refresh_token: auth_output.refresh_token.clone(),
+        jti: auth_output.jti.clone(),
+        expires_in: auth_output.expires_in,
+        client_ip: context.client_ip,
+        address: context.address,
+        client_port: context.client_port,
+        user_agent: context.user_agent,
+        request_id: context.request_id,
+        login_type: context.login_type,
+    };
+    if let Err(e) = send_auth_event(auth_event).await {
+        project_error!("Failed to publish login event: {}", e);
+    }
+}
+
 /** 生成认证输出
  * 
  * 根据用户信息和角色生成认证输出，包括：
@@ -697,6 +1116,12 @@ async fn send_auth_event(
  * - 用户信息
  * - 角色信息
  * 
+ * 用户所属域配置了JWT覆盖时，按覆盖值设置令牌有效期和受众，
+ * 未覆盖的字段回退到全局JWT配置。返回的有效期与令牌中的实际有效期一致。
+ * 
+ * 角色代码去重并排序后写入令牌。声明序列化后超过 `max_claims_bytes` 时，
+ * 角色列表以随机引用为键存入Redis，有效期与令牌一致，令牌中只携带引用。
+ * 
  * 参数
  * --------
  * * `user_id` - 用户ID
@@ -704,7 +1129,7 @@ async fn send_auth_event(
  * * `role_codes` - 角色代码列表
  * * `domain_code` - 域代码
  * * `organization_name` - 组织名称（可选）
- * * `audience` - 认证受众
+ * * `audience` - 认证受众，域未覆盖受众时使用
  * 
  * 返回
  * --------
@@ -714,34 +1139,155 @@ async fn send_auth_event(
 pub async fn generate_auth_output(
     user_id: String,
     username: String,
-    role_codes: Vec<String>,
+    mut role_codes: Vec<String>,
     domain_code: String,
     organization_name: Option<String>,
     audience: Audience,
 ) -> Result<AuthOutput, AuthError> {
-    let claims = Claims::new(
+    let jwt_config = global::get_config::<JwtConfig>()
+        .await
+        .ok_or_else(|| AuthError::JwtGenerationFailed("JWT config not initialized".to_string()))?;
+    let access_token_expire = jwt_config.access_token_expire_for(&domain_code);
+    let refresh_token_expire = jwt_config.refresh_token_expire_for(&domain_code);
+    let audience = jwt_config
+        .audience_for(&domain_code)
+        .unwrap_or(audience.as_str())
+        .to_string();
+
+    role_codes.sort_unstable();
+    role_codes.dedup();
+    let mut claims = Claims::new(
         user_id,
-        audience.as_str().to_string(),
+        audience,
         username,
         role_codes,
         domain_code,
         organization_name,
     );
 
-    let token = JwtUtils::generate_token(&claims)
+    // 访问令牌使用预先生成的JWT ID，登录记录据此识别当前请求所用的令牌
+    let jti = Ulid::new().to_string();
+    let mut access_claims = claims.clone();
+    access_claims.set_jti(jti.clone());
+
+    // 两个令牌携带同一角色引用，按较长的有效期保存角色列表
+    if claims_size(&access_claims) > jwt_config.max_claims_bytes {
+        let roles_ref = Ulid::new().to_string();
+        let ttl = Duration::from_secs(access_token_expire.max(refresh_token_expire));
+        store_token_roles(&roles_ref, access_claims.role(), ttl).await?;
+        claims.set_roles_ref(roles_ref.clone());
+        access_claims.set_roles_ref(roles_ref);
+    }
+    let token = JwtUtils::generate_token_with_expire(&access_claims, access_token_expire)
         .await
         .map_err(|e| AuthError::JwtGenerationFailed(e.to_string()))?;
 
-    let refresh_token = JwtUtils::generate_token(&claims)
+    let refresh_token = JwtUtils::generate_token_with_expire(&claims, refresh_token_expire)
         .await
         .map_err(|e| AuthError::JwtGenerationFailed(e.to_string()))?;
 
     Ok(AuthOutput {
         token,
         refresh_token,
+        expires_in: access_token_expire,
+        refresh_expires_in: refresh_token_expire,
+        token_type: TOKEN_TYPE_BEARER.to_string(),
+        jti,
+    })
+}
+
+/** 计算令牌声明序列化后的字节数
+ * 
+ * 不含签发时写入的签发者和时间字段
+ */
+fn claims_size(claims: &Claims) -> usize {
+    serde_json::to_vec(claims).map(|bytes| bytes.len()).unwrap_or(usize::MAX)
+}
+
+/** 把令牌的角色列表存入Redis
+ * 
+ * 参数
+ * --------
+ * * `roles_ref` - 角色引用
+ * * `roles` - 角色代码列表
+ * * `ttl` - 有效期
+ */
+async fn store_token_roles(
+    roles_ref: &str,
+    roles: &[String],
+    ttl: Duration,
+) -> Result<(), AuthError> {
+    let key = KeyBuilder::token_roles(roles_ref)
+        .map_err(|e| AuthError::JwtGenerationFailed(e.to_string()))?;
+    let ops = RedisOps::new(RedisSource::Primary)
+        .await
+        .map_err(|e| AuthError::JwtGenerationFailed(e.to_string()))?;
+    ops.set_json(&key, roles, Some(ttl))
+        .await
+        .map_err(|e| AuthError::JwtGenerationFailed(e.to_string()))
+}
+
+/** 生成登录失败的登录日志事件
+ * 
+ * 只记录认证失败，数据库等内部错误不视为登录失败；
+ * 用户不存在和密码错误不区分，用户ID均为空
+ * 
+ * 参数
+ * --------
+ * * `username` - 登录时输入的用户名
+ * * `context` - 登录上下文
+ * * `error` - 登录失败的原因
+ * 
+ * 返回
+ * --------
+ * * `Option<LoginLogEvent>` - 不属于认证失败时为None
+ */
+fn login_failure_event(
+    username: &str,
+    context: &LoginContext,
+    error: &AuthError,
+) -> Option<LoginLogEvent> {
+    let is_auth_failure = matches!(
+        error,
+        AuthError::UserNotFound
+            | AuthError::InvalidCredentials
+            | AuthError::TooManyLoginAttempts
+            | AuthError::UserDisabled
+            | AuthError::DomainDisabled
+    );
+    is_auth_failure.then(|| LoginLogEvent {
+        user_id: String::new(),
+        username: username.to_string(),
+        domain: context.domain.clone(),
+        ip: context.client_ip.clone(),
+        port: context.client_port,
+        address: context.address.clone(),
+        user_agent: context.user_agent.clone(),
+        request_id: context.request_id.clone(),
+        login_type: context.login_type.clone(),
+        success: false,
+        failure_reason: Some(error.to_string()),
     })
 }
 
+/** 发布登录失败事件
+ * 
+ * 由登录失败事件监听器异步写入登录日志
+ * 
+ * 参数
+ * --------
+ * * `username` - 登录时输入的用户名
+ * * `context` - 登录上下文
+ * * `error` - 登录失败的原因
+ */
+async fn publish_login_failure(username: &str, context: &LoginContext, error: &AuthError) {
+    if let Some(event) = login_failure_event(username, context, error) {
+        if let Err(e) = global::send_typed_event_async(event).await {
+            project_error!("Failed to publish failed login of {}", e.0.username);
+        }
+    }
+}
+
 /** 登录事件监听器
  * 
  * 监听并处理登录相关事件，包括：
@@ -752,14 +1298,31 @@ pub async fn generate_auth_output(
  * --------
  * * `rx` - 事件接收器
  */
-pub async fn auth_login_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
-    while let Some(event) = rx.recv().await {
-        if let Some(auth_event) = event.downcast_ref::<AuthEvent>() {
-            if let Err(e) = handle_auth_event(auth_event).await {
-                project_error!("Failed to handle AuthEvent: {:?}", e);
-            }
+pub async fn auth_login_listener(mut rx: EventReceiver<AuthEvent>) {
+    while let Some(auth_event) = rx.recv().await {
+        if let Err(e) = handle_auth_event(auth_event).await {
+            project_error!("Failed to handle AuthEvent: {:?}", e);
+        }
+    }
+}
+
+/** 登录失败事件监听器
+ * 
+ * 把失败的登录尝试写入登录日志，写入失败只记录错误日志
+ * 
+ * 参数
+ * --------
+ * * `rx` - 事件接收器
+ */
+pub async fn auth_login_failed_listener(mut rx: EventReceiver<LoginLogEvent>) {
+    while let Some(login_log_event) = rx.recv().await {
+        let username = login_log_event.username.clone();
+        let result = match db_helper::get_db_connection().await {
+            Ok(db) => login_log_event.handle(db.as_ref()).await,
+            Err(e) => Err(AppError::from(e)),
+        };
+        if let Err(e) = result {
+            project_error!("Failed to record failed login of {}: {}", username, e);
         }
     }
 }
@@ -779,22 +1342,10 @@ pub async fn auth_login_listener(
  * * `Result<(), AuthError>` - 处理结果
  */
 #[instrument(skip(auth_event))]
-async fn handle_auth_event(auth_event: &AuthEvent) -> Result<(), AuthError> {
-    AuthEventHandler::handle_login(AuthEvent {
-        user_id: auth_event.user_id.clone(),
-        username: auth_event.username.clone(),
-        domain: auth_event.domain.clone(),
-        access_token: auth_event.access_token.clone(),
-        refresh_token: auth_event.refresh_token.clone(),
-        client_ip: auth_event.client_ip.clone(),
-        address: auth_event.address.clone(),
-        client_port: auth_event.client_port,
-        user_agent: auth_event.user_agent.clone(),
-        request_id: auth_event.request_id.clone(),
-        login_type: auth_event.login_type.clone(),
-    })
-    .await
-    .map_err(|e| AuthError::LoginHandlerError(format!("{:?}", e)))
+async fn handle_auth_event(auth_event: AuthEvent) -> Result<(), AuthError> {
+    AuthEventHandler::handle_login(auth_event)
+        .await
+        .map_err(|e| AuthError::LoginHandlerError(format!("{:?}", e)))
 }
 
 /** JWT创建事件监听器
@@ -807,9 +1358,697 @@ async fn handle_auth_event(auth_event: &AuthEvent) -> Result<(), AuthError> {
  * --------
  * * `rx` - 事件接收器
  */
-pub async fn jwt_created_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<String>) {
+pub async fn jwt_created_listener(mut rx: EventReceiver<String>) {
     while let Some(jwt) = rx.recv().await {
         project_info!("JWT created: {}", jwt);
         // TODO: Consider storing the token into the database
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum_casbin::casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use server_config::JwtDomainOverride;
+    use server_core::web::error::{ApiError, StatusCode};
+    use server_global::Validation;
+    use server_model::admin::entities::{
+        prelude::{SysInvitation, SysLoginLog, SysMenu, SysRoleMenu, SysUserRole},
+        sys_invitation::ActiveModel as SysInvitationActiveModel,
+        sys_menu::ActiveModel as SysMenuActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+        sys_role_menu::ActiveModel as SysRoleMenuActiveModel,
+        sys_tokens::ActiveModel as SysTokensActiveModel,
+    };
+    use tokio::sync::Mutex;
+
+    use super::*;
+    use crate::admin::repositories::test_support::InMemoryUserRepository;
+
+    const ISSUER: &str = "issuer";
+    const SECRET: &[u8] = b"secret";
+
+    async fn setup() {
+        global::init_config(JwtConfig {
+            secret: "secret".to_string(),
+            access_token_expire: 7200,
+            refresh_token_expire: 604800,
+            issuer: ISSUER.to_string(),
+            audience: "audience".to_string(),
+            domains: vec![JwtDomainOverride {
+                code: "tenant-a".to_string(),
+                expire_secs: Some(900),
+                refresh_expire_secs: Some(3600),
+                audience: Some("tenant-a-portal".to_string()),
+            }],
+            strict_status_check: false,
+            max_claims_bytes: 4096,
+        })
+        .await;
+
+        let mut validation = Validation::default();
+        validation.set_issuer(&[ISSUER]);
+        let _ = global::KEYS.set(Arc::new(Mutex::new(global::Keys::new(SECRET))));
+        let _ = global::VALIDATION.set(Arc::new(Mutex::new(validation)));
+    }
+
+    async fn issue(domain: &str) -> AuthOutput {
+        generate_auth_output(
+            "user".to_string(),
+            "user".to_string(),
+            vec!["R_USER".to_string()],
+            domain.to_string(),
+            None,
+            Audience::ManagementPlatform,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn issue_roles(role_codes: Vec<String>) -> Result<AuthOutput, AuthError> {
+        generate_auth_output(
+            "user".to_string(),
+            "user".to_string(),
+            role_codes,
+            "tenant-b".to_string(),
+            None,
+            Audience::ManagementPlatform,
+        )
+        .await
+    }
+
+    /** 序列化后超过默认声明大小上限的角色列表 */
+    fn many_roles() -> Vec<String> {
+        (0..400).map(|i| format!("R_ROLE_{:03}", i)).collect()
+    }
+
+    async fn decode(token: &str) -> Claims {
+        JwtUtils::validate_token(token, Audience::ManagementPlatform.as_str())
+            .await
+            .unwrap()
+            .claims
+    }
+
+    fn lifetime(claims: &Claims) -> usize {
+        claims.exp().unwrap() - claims.iat().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_domain_override_sets_expiry_and_audience() {
+        setup().await;
+        let output = issue("tenant-a").await;
+
+        let access = decode(&output.token).await;
+        assert_eq!(lifetime(&access), 900);
+        assert_eq!(access.aud(), "tenant-a-portal");
+        assert_eq!(output.expires_in, 900);
+        assert_eq!(access.jti(), Some(output.jti.as_str()));
+
+        let refresh = decode(&output.refresh_token).await;
+        assert_eq!(lifetime(&refresh), 3600);
+        assert_eq!(output.refresh_expires_in, 3600);
+        assert_ne!(refresh.jti(), access.jti());
+    }
+
+    #[tokio::test]
+    async fn test_domain_without_override_falls_back_to_global() {
+        setup().await;
+        let output = issue("tenant-b").await;
+
+        let access = decode(&output.token).await;
+        assert_eq!(lifetime(&access), 7200);
+        assert_eq!(access.aud(), Audience::ManagementPlatform.as_str());
+        assert_eq!(output.expires_in, 7200);
+
+        let refresh = decode(&output.refresh_token).await;
+        assert_eq!(lifetime(&refresh), 604800);
+        assert_eq!(output.refresh_expires_in, 604800);
+    }
+
+    #[tokio::test]
+    async fn test_role_codes_deduplicated_and_sorted() {
+        setup().await;
+        let roles = ["R_USER", "R_ADMIN", "R_USER", "R_AUDITOR", "R_ADMIN"];
+        let output = issue_roles(roles.iter().map(|role| role.to_string()).collect())
+            .await
+            .unwrap();
+
+        for token in [&output.token, &output.refresh_token] {
+            let claims = decode(token).await;
+            assert_eq!(claims.role(), ["R_ADMIN", "R_AUDITOR", "R_USER"]);
+            assert_eq!(claims.roles_ref(), None);
+        }
+    }
+
+    #[cfg(not(feature = "redis-tests"))]
+    #[tokio::test]
+    async fn test_oversized_claims_require_redis() {
+        setup().await;
+        let result = issue_roles(many_roles()).await;
+        assert!(matches!(result, Err(AuthError::JwtGenerationFailed(_))));
+    }
+
+    #[cfg(feature = "redis-tests")]
+    #[tokio::test]
+    async fn test_oversized_claims_store_roles_ref() {
+        use server_global::global::{RedisConnection, GLOBAL_PRIMARY_REDIS};
+
+        setup().await;
+        let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+        let client = redis::Client::open(url).expect("invalid REDIS_URL");
+        *GLOBAL_PRIMARY_REDIS.write().await = Some(RedisConnection::Single(Arc::new(client)));
+
+        let mut roles = many_roles();
+        roles.reverse();
+        let output = issue_roles(roles).await.unwrap();
+        let access = decode(&output.token).await;
+        let refresh = decode(&output.refresh_token).await;
+        assert!(access.role().is_empty());
+        assert!(access.roles_ref().is_some());
+        assert_eq!(access.roles_ref(), refresh.roles_ref());
+
+        let user = SysAuthService::resolve_token_roles(User::from(access)).await.unwrap();
+        assert_eq!(user.subject(), many_roles());
+        assert_eq!(user.roles_ref(), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_token_roles_failure() {
+        let claims = || {
+            Claims::new(
+                "user".to_string(),
+                "audience".to_string(),
+                "user".to_string(),
+                vec!["R_USER".to_string()],
+                "built-in".to_string(),
+                None,
+            )
+        };
+        let user = SysAuthService::resolve_token_roles(User::from(claims())).await.unwrap();
+        assert_eq!(user.subject(), vec!["R_USER"]);
+
+        // 引用不存在或Redis不可用时都不能按空角色放行
+        let mut claims = claims();
+        claims.set_roles_ref(Ulid::new().to_string());
+        let result = SysAuthService::resolve_token_roles(User::from(claims)).await;
+        assert!(matches!(result, Err(AuthError::TokenRolesUnavailable)));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_audience_is_rejected() {
+        setup().await;
+        let claims = Claims::new(
+            "user".to_string(),
+            "unknown".to_string(),
+            "user".to_string(),
+            vec![],
+            "tenant-b".to_string(),
+            None,
+        );
+        let token = JwtUtils::generate_token(&claims).await.unwrap();
+
+        let result = JwtUtils::validate_token(&token, Audience::ManagementPlatform.as_str()).await;
+        assert!(result.is_err());
+    }
+
+    fn auth_service() -> SysAuthService {
+        SysAuthService::new(Arc::new(
+            InMemoryUserRepository::default()
+                .with_domain("built-in", "Built-in", Status::Enabled)
+                .with_domain("archived", "Archived", Status::Disabled)
+                .with_domain("tenant-b", "Tenant B", Status::Enabled)
+                .with_user("soybean", "123456", "built-in", Status::Enabled)
+                .with_user("disabled", "123456", "built-in", Status::Disabled)
+                .with_user("archived", "123456", "archived", Status::Enabled)
+                .with_roles("soybean", &["R_SUPER"])
+                .with_membership("soybean", "tenant-b", &["R_AUDITOR"])
+                .with_membership("soybean", "archived", &["R_AUDITOR"])
+                .with_membership("archived", "tenant-b", &["R_AUDITOR"]),
+        ))
+    }
+
+    async fn login(username: &str, password: &str, domain: &str) -> Result<AuthOutput, AuthError> {
+        auth_service()
+            .pwd_login(
+                LoginInput {
+                    username: username.to_string(),
+                    password: password.to_string(),
+                },
+                context(domain),
+            )
+            .await
+    }
+
+    fn context(domain: &str) -> LoginContext {
+        LoginContext {
+            client_ip: "127.0.0.1".to_string(),
+            client_port: None,
+            address: "localhost".to_string(),
+            user_agent: "test".to_string(),
+            request_id: "req".to_string(),
+            audience: Audience::ManagementPlatform,
+            login_type: "PC".to_string(),
+            domain: domain.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_user_basic_accepts_valid_credentials() {
+        let service = auth_service();
+        let user = service.verify_user_basic("soybean", "123456", "built-in").await.unwrap();
+
+        assert_eq!(user.domain_code, "built-in");
+        assert_eq!(service.get_user_roles(&user.id, "built-in").await.unwrap(), vec!["R_SUPER"]);
+        assert_eq!(service.get_user_roles(&user.id, "tenant-b").await.unwrap(), vec!["R_AUDITOR"]);
+        assert!(service.get_user_roles(&user.id, "tenant-c").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_domains_marks_home_and_current() {
+        let domains = auth_service().get_user_domains("soybean", "tenant-b").await.unwrap();
+
+        let codes: Vec<_> = domains.iter().map(|domain| domain.code.as_str()).collect();
+        assert_eq!(codes, vec!["archived", "built-in", "tenant-b"]);
+        assert!(domains[1].home && !domains[1].current);
+        assert!(!domains[2].home && domains[2].current);
+        assert_eq!(domains[0].status, Status::Disabled);
+    }
+
+    fn caller(user_id: &str) -> User {
+        User::new(user_id.to_string(), user_id.to_string(), "built-in".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_switch_domain_issues_tokens_for_member_domain() {
+        setup().await;
+        let output = auth_service()
+            .switch_domain(&caller("soybean"), "tenant-b", context("built-in"))
+            .await
+            .unwrap();
+
+        let user = User::from(decode(&output.token).await);
+        assert_eq!(user.domain(), "tenant-b");
+        assert_eq!(user.subject(), vec!["R_AUDITOR"]);
+        let refresh = User::from(decode(&output.refresh_token).await);
+        assert_eq!(refresh.domain(), "tenant-b");
+
+        // 所属域被禁用的用户仍可切换到加入的启用域
+        let output = auth_service()
+            .switch_domain(&caller("archived"), "tenant-b", context("archived"))
+            .await
+            .unwrap();
+        assert_eq!(User::from(decode(&output.token).await).domain(), "tenant-b");
+    }
+
+    #[tokio::test]
+    async fn test_switch_domain_rejects_non_member_and_disabled_domains() {
+        setup().await;
+        let service = auth_service();
+
+        let result = service
+            .switch_domain(&caller("soybean"), "tenant-c", context("built-in"))
+            .await;
+        assert!(matches!(result, Err(AuthError::DomainNotMember(code)) if code == "tenant-c"));
+
+        let result = service
+            .switch_domain(&caller("disabled"), "built-in", context("built-in"))
+            .await;
+        assert!(matches!(result, Err(AuthError::UserDisabled)));
+
+        let result = service
+            .switch_domain(&caller("soybean"), "archived", context("built-in"))
+            .await;
+        assert!(matches!(result, Err(AuthError::DomainDisabled)));
+
+        let result = service
+            .switch_domain(&caller("deleted"), "built-in", context("built-in"))
+            .await;
+        assert!(matches!(result, Err(AuthError::UserNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_switch_domain_rejects_personal_token() {
+        setup().await;
+        let service = auth_service();
+
+        // 个人访问令牌不能换取不受权限范围限制的令牌对，切换到所属域也不行
+        let user = caller("soybean").with_personal_token("pat-1".to_string());
+        for domain in ["built-in", "tenant-b"] {
+            let result = service.switch_domain(&user, domain, context("built-in")).await;
+            let err = result.err().unwrap();
+            assert!(matches!(err, AuthError::PersonalTokenNotAllowed));
+            assert_eq!(err.code(), 9016);
+            assert_eq!(err.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_returns_expiry_metadata() {
+        setup().await;
+        let output = login("soybean", "123456", "built-in").await.unwrap();
+
+        assert_eq!(output.expires_in, 7200);
+        assert_eq!(output.refresh_expires_in, 604800);
+        assert_eq!(output.token_type, "Bearer");
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["expiresIn"], 7200);
+        assert_eq!(json["refreshExpiresIn"], 604800);
+        assert_eq!(json["tokenType"], "Bearer");
+        assert!(json["refreshToken"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_login_rejects_wrong_password() {
+        let result = login("soybean", "wrong-password", "built-in").await;
+        assert!(matches!(result, Err(AuthError::InvalidCredentials)));
+    }
+
+    #[tokio::test]
+    async fn test_login_rejects_disabled_user() {
+        let result = login("disabled", "123456", "built-in").await;
+        assert!(matches!(result, Err(AuthError::UserDisabled)));
+
+        // 状态错误优先于密码错误
+        let result = login("disabled", "wrong-password", "built-in").await;
+        assert!(matches!(result, Err(AuthError::UserDisabled)));
+    }
+
+    #[tokio::test]
+    async fn test_login_rejects_disabled_domain() {
+        let result = login("archived", "123456", "archived").await;
+        assert!(matches!(result, Err(AuthError::DomainDisabled)));
+    }
+
+    #[tokio::test]
+    async fn test_login_failure_is_logged() {
+        let context = context("built-in");
+        let internal = AuthError::DatabaseOperationFailed("connection reset".to_string());
+        assert!(login_failure_event("soybean", &context, &internal).is_none());
+
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysLoginLog))).await.unwrap();
+        for error in [AuthError::InvalidCredentials, AuthError::TooManyLoginAttempts] {
+            let event = login_failure_event("soybean", &context, &error).unwrap();
+            event.handle(&db).await.unwrap();
+        }
+
+        let logs = SysLoginLog::find().all(&db).await.unwrap();
+        let reasons: Vec<_> = logs.iter().map(|log| log.failure_reason.as_deref()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                Some("Invalid credentials"),
+                Some("Too many failed login attempts, please try again later")
+            ]
+        );
+        assert!(logs.iter().all(|log| !log.success && log.user_id.is_empty()));
+        assert_eq!(logs[0].username, "soybean");
+        assert_eq!(logs[0].ip, "127.0.0.1");
+    }
+
+    #[tokio::test]
+    async fn test_verify_user_basic_rehashes_weak_password_hash() {
+        let users = Arc::new(
+            InMemoryUserRepository::default()
+                .with_domain("built-in", "Built-in", Status::Enabled)
+                .with_user("legacy", "123456", "built-in", Status::Enabled),
+        );
+        let mut user = users.find_by_id("legacy").await.unwrap().unwrap();
+        let weak_hash = SecureUtil::hash_password_with(b"123456", 8, 1, 1).unwrap();
+        user.password = weak_hash.clone();
+        users.update(user).await.unwrap();
+        assert!(SecureUtil::needs_rehash(&weak_hash));
+
+        let service = SysAuthService::new(users.clone());
+        service.verify_user_basic("legacy", "123456", "built-in").await.unwrap();
+
+        let mut stored = weak_hash.clone();
+        for _ in 0..100 {
+            stored = users.find_by_id("legacy").await.unwrap().unwrap().password;
+            if stored != weak_hash {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert_ne!(stored, weak_hash);
+        assert!(!SecureUtil::needs_rehash(&stored));
+        assert!(SecureUtil::verify_password(b"123456", &stored).unwrap());
+
+        // 重新哈希期间密码已被修改时不覆盖
+        assert!(!users.replace_password_hash("legacy", &weak_hash, "other").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_user_status() {
+        let service = auth_service();
+
+        assert!(service.verify_user_status("soybean").await.is_ok());
+        assert!(matches!(
+            service.verify_user_status("disabled").await,
+            Err(AuthError::UserDisabled)
+        ));
+        assert!(matches!(
+            service.verify_user_status("archived").await,
+            Err(AuthError::DomainDisabled)
+        ));
+        assert!(matches!(
+            service.verify_user_status("deleted").await,
+            Err(AuthError::UserNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_login_rejects_unknown_domain() {
+        let result = login("soybean", "123456", "unknown").await;
+        assert!(matches!(result, Err(AuthError::UserNotFound)));
+    }
+
+    async fn setup_route_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysMenu))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleMenu)))
+            .await
+            .unwrap();
+
+        SysRoleActiveModel {
+            id: Set("r1".to_string()),
+            code: Set("R_USER".to_string()),
+            name: Set("User".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        for id in 1..=2 {
+            insert_menu(&db, id, MenuType::Menu, &format!("route-{}", id), "0").await;
+        }
+        assign_menu(&db, 1).await;
+        db
+    }
+
+    async fn insert_menu(
+        db: &DatabaseConnection,
+        id: i32,
+        menu_type: MenuType,
+        route_name: &str,
+        pid: &str,
+    ) {
+        SysMenuActiveModel {
+            id: Set(id),
+            menu_type: Set(menu_type),
+            menu_name: Set(format!("menu-{}", id)),
+            route_name: Set(route_name.to_string()),
+            route_path: Set(format!("/{}", route_name)),
+            component: Set("layout.base".to_string()),
+            status: Set(Status::Enabled),
+            pid: Set(pid.to_string()),
+            sequence: Set(id),
+            constant: Set(false),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn assign_menu(db: &DatabaseConnection, menu_id: i32) {
+        SysRoleMenuActiveModel {
+            role_id: Set("r1".to_string()),
+            menu_id: Set(menu_id),
+            domain: Set("built-in".to_string()),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    fn route_names(routes: &UserRoute) -> Vec<String> {
+        routes.routes.iter().map(|route| route.name.clone()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_user_routes_cached_until_invalidated() {
+        let db = Arc::new(setup_route_db().await);
+        let cache = Arc::new(UserRouteCache::new(10, Duration::from_secs(60)));
+        let service = auth_service().with_route_cache(cache.clone());
+        let roles = vec!["R_USER".to_string()];
+
+        let routes = service.get_user_routes(db.clone(), &roles, "built-in").await.unwrap();
+        assert_eq!(route_names(&routes), vec!["route-1".to_string()]);
+
+        // 未失效前读取缓存，看不到新分配的菜单
+        assign_menu(&db, 2).await;
+        let routes = service.get_user_routes(db.clone(), &roles, "built-in").await.unwrap();
+        assert_eq!(route_names(&routes), vec!["route-1".to_string()]);
+
+        cache.invalidate(Some("built-in"), &roles);
+        let routes = service.get_user_routes(db.clone(), &roles, "built-in").await.unwrap();
+        assert_eq!(route_names(&routes), vec!["route-1".to_string(), "route-2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_buttons_are_permissions_not_routes() {
+        let db = Arc::new(setup_route_db().await);
+        insert_menu(&db, 3, MenuType::Button, "route-1:add", "1").await;
+        insert_menu(&db, 4, MenuType::Button, "route-1:delete", "1").await;
+        insert_menu(&db, 5, MenuType::Button, "route-2:add", "2").await;
+        assign_menu(&db, 3).await;
+        assign_menu(&db, 5).await;
+        let cache = Arc::new(UserRouteCache::new(10, Duration::from_secs(60)));
+        let service = auth_service().with_route_cache(cache);
+        let roles = vec!["R_USER".to_string()];
+
+        let routes = service.get_user_routes(db.clone(), &roles, "built-in").await.unwrap();
+        assert_eq!(route_names(&routes), vec!["route-1".to_string()]);
+        assert!(routes.routes[0].children.as_deref().unwrap_or_default().is_empty());
+
+        let permissions =
+            service.get_user_permissions(db.clone(), &roles, "built-in").await.unwrap();
+        assert_eq!(permissions, vec!["route-1:add".to_string(), "route-2:add".to_string()]);
+
+        let permissions =
+            service.get_user_permissions(db.clone(), &roles, "other").await.unwrap();
+        assert!(permissions.is_empty());
+    }
+
+    async fn insert_token(db: &DatabaseConnection, access_token: &str, status: TokenStatus) {
+        let now = timestamps::now();
+        SysTokensActiveModel {
+            id: Set(Ulid::new().to_string()),
+            access_token: Set(access_token.to_string()),
+            refresh_token: Set(format!("{}-refresh", access_token)),
+            status: Set(status.to_string()),
+            user_id: Set("u1".to_string()),
+            username: Set("soybean".to_string()),
+            domain: Set("built-in".to_string()),
+            login_time: Set(now),
+            ip: Set("127.0.0.1".to_string()),
+            port: Set(None),
+            address: Set("".to_string()),
+            user_agent: Set("".to_string()),
+            request_id: Set("".to_string()),
+            r#type: Set("password".to_string()),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_register_adds_grouping_rule() {
+        setup().await;
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysInvitation))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+        let now = timestamps::now();
+        SysRoleActiveModel {
+            id: Set("r1".to_string()),
+            code: Set("R_USER".to_string()),
+            domain: Set("tenant-b".to_string()),
+            name: Set("user".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysInvitationActiveModel {
+            id: Set("i1".to_string()),
+            code: Set("INVITE".to_string()),
+            domain: Set("tenant-b".to_string()),
+            role_id: Set("r1".to_string()),
+            max_uses: Set(1),
+            used_count: Set(0),
+            expires_at: Set(now + chrono::Duration::hours(1)),
+            created_at: Set(now),
+            created_by: Set("test".to_string()),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        let enforcer = Arc::new(RwLock::new(enforcer));
+
+        let input = RegisterInput {
+            username: "bob".to_string(),
+            password: "Secret123!".to_string(),
+            nick_name: "Bob".to_string(),
+            invitation_code: "INVITE".to_string(),
+        };
+        let db = Arc::new(db);
+        SysAuthService::default()
+            .register(db.clone(), input, context("tenant-b"), enforcer.clone())
+            .await
+            .unwrap();
+
+        // 注册的用户与分配角色的用户一样拥有分组规则
+        let user = SysUser::find().one(db.as_ref()).await.unwrap().unwrap();
+        let rules = enforcer.read().await.get_filtered_grouping_policy(0, vec![user.id.clone()]);
+        assert_eq!(rules, vec![vec![user.id, "R_USER".to_string(), "tenant-b".to_string()]]);
+    }
+
+    #[tokio::test]
+    async fn test_is_token_revoked() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysTokens))).await.unwrap();
+        insert_token(&db, "active", TokenStatus::Active).await;
+        insert_token(&db, "refreshed", TokenStatus::Refreshed).await;
+        insert_token(&db, "revoked", TokenStatus::Revoked).await;
+
+        assert!(!SysAuthService::is_token_revoked(&db, "active").await.unwrap());
+        assert!(SysAuthService::is_token_revoked(&db, "refreshed").await.unwrap());
+        assert!(SysAuthService::is_token_revoked(&db, "revoked").await.unwrap());
+        // 签发记录尚未写入
+        assert!(!SysAuthService::is_token_revoked(&db, "unknown").await.unwrap());
+    }
+}
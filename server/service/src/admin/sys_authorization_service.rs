@@ -5,6 +5,12 @@
  * - 角色权限管理
  * - 菜单权限控制
  * - 端点权限验证
+ * - 角色权限导入导出
+ * - 从角色模板创建角色
+ * - 按角色预览用户路由
+ * 
+ * 分配接口权限和路由时，除内置域超级管理员外，只能分配当前用户在目标域中已持有的接口和路由，
+ * 防止通过给角色授权提升自身权限。
  * 
  * 主要组件
  * --------
@@ -28,37 +34,64 @@
  *  */
  */
 
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use axum_casbin::casbin::{CoreApi, MgmtApi, RbacApi};
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, DbErr,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    Set, DbErr, TransactionTrait,
 };
-use server_core::web::error::AppError;
-use server_model::admin::entities::{
-    prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysRoleMenu, SysUser, SysUserRole},
-    sys_domain::Column as SysDomainColumn,
-    sys_endpoint::Column as SysEndpointColumn,
-    sys_menu::Column as SysMenuColumn,
-    sys_role::Column as SysRoleColumn,
-    sys_role_menu::{ActiveModel as SysRoleMenuActiveModel, Column as SysRoleMenuColumn},
-    sys_user_role::{ActiveModel as SysUserRoleActiveModel, Column as SysUserRoleColumn},
+use server_config::BootstrapConfig;
+use server_core::{
+    sign::SecurityEvent,
+    web::{auth::User, error::AppError, CurrentUserContext},
+};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysRoleMenu, SysUser, SysUserRole},
+        sea_orm_active_enums::Status,
+        sys_domain::Column as SysDomainColumn,
+        sys_endpoint::{Column as SysEndpointColumn, Model as SysEndpointModel},
+        sys_menu::Column as SysMenuColumn,
+        sys_role::{
+            ActiveModel as SysRoleActiveModel, Column as SysRoleColumn, Model as SysRoleModel,
+        },
+        sys_role_menu::{ActiveModel as SysRoleMenuActiveModel, Column as SysRoleMenuColumn},
+        sys_user_role::{ActiveModel as SysUserRoleActiveModel, Column as SysUserRoleColumn},
+    },
+    ids::{is_valid_id, DomainCode, MenuId, RoleId, UserId},
+    input::{CreateRoleFromTemplateInput, EndpointSelector, ImportRolePermissionInput},
+    output::{
+        AssignmentResult, EndpointDescriptor, RoleFromTemplateOutput, RolePermissionDocument,
+        RolePermissionImportOutput, RoutePreviewOutput, SelectorMatch,
+        ROLE_PERMISSION_DOCUMENT_VERSION,
+    },
 };
 use tokio::sync::RwLock;
 use tracing::{error, info, instrument};
-use regex::Regex;
+use ulid::Ulid;
 
+use crate::helper::{audit_fields, db_helper};
 use crate::helper::transaction_helper::execute_in_transaction;
-use crate::admin::errors::sys_authorization_error::AuthorizationError;
+use crate::helper::user_route_cache::notify_menu_assignment_changed;
+use crate::admin::errors::{sys_authorization_error::AuthorizationError, sys_role_error::RoleError};
+use crate::admin::sys_auth_service::SysAuthService;
+use crate::admin::sys_domain_service::BUILT_IN_DOMAIN_CODE;
+use crate::admin::sys_role_template_service::{template_permissions, SysRoleTemplateService};
+use crate::admin::sys_security_event_service::SysSecurityEventService;
+
+/** 内置域超级管理员角色代码 */
+const SUPER_ADMIN_ROLE_CODE: &str = "ROLE_SUPER";
 
 /// 验证参数是否为空
 fn validate_not_empty<T: AsRef<str>>(value: T, field_name: &str) -> Result<(), AppError> {
     if value.as_ref().trim().is_empty() {
-        return Err(AppError {
-            code: 400,
-            message: format!("{} cannot be empty", field_name),
-        });
+        return Err(AppError::new(400, format!("{} cannot be empty", field_name)));
     }
     Ok(())
 }
@@ -66,71 +99,70 @@ fn validate_not_empty<T: AsRef<str>>(value: T, field_name: &str) -> Result<(), A
 /// 验证ID列表是否为空
 fn validate_ids_not_empty<T>(ids: &[T], field_name: &str) -> Result<(), AppError> {
     if ids.is_empty() {
-        return Err(AppError {
-            code: 400,
-            message: format!("{} list cannot be empty", field_name),
-        });
+        return Err(AppError::new(400, format!("{} list cannot be empty", field_name)));
     }
     Ok(())
 }
 
-/// 验证域代码格式
-fn validate_domain_code(code: &str) -> Result<(), AppError> {
-    let re = Regex::new(r"^[a-zA-Z][a-zA-Z0-9_-]*$").unwrap();
-    if !re.is_match(code) {
-        return Err(AppError {
-            code: 400,
-            message: "Domain code must start with a letter and contain only letters, numbers, underscores, and hyphens".to_string(),
-        });
+/// 验证权限ID格式
+fn validate_permission_id(id: &str) -> Result<(), AppError> {
+    if !is_valid_id(id) {
+        return Err(AppError::new(
+            400,
+            "Permission ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens",
+        ));
     }
     Ok(())
 }
 
-/// 验证角色ID格式
-fn validate_role_id(id: &str) -> Result<(), AppError> {
-    let re = Regex::new(r"^[a-zA-Z0-9_-]{1,64}$").unwrap();
-    if !re.is_match(id) {
-        return Err(AppError {
-            code: 400,
-            message: "Role ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens".to_string(),
-        });
+/// 验证角色权限文档版本，只接受当前及更早的版本
+fn validate_document_version(version: u32) -> Result<(), AppError> {
+    if version == 0 || version > ROLE_PERMISSION_DOCUMENT_VERSION {
+        let err = AuthorizationError::validation_error(format!(
+            "Unsupported role permission document version: {} (supported: 1-{})",
+            version, ROLE_PERMISSION_DOCUMENT_VERSION
+        ));
+        return Err(AppError::from(err));
     }
     Ok(())
 }
 
-/// 验证用户ID格式
-fn validate_user_id(id: &str) -> Result<(), AppError> {
-    let re = Regex::new(r"^[a-zA-Z0-9_-]{1,64}$").unwrap();
-    if !re.is_match(id) {
-        return Err(AppError {
-            code: 400,
-            message: "User ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens".to_string(),
-        });
+/// 将选择器展开为接口，按ID合并到 `expanded` 中
+/// 
+/// 返回每个选择器匹配的接口数量；存在未匹配任何接口的选择器时返回错误并列出这些选择器
+fn expand_selectors(
+    selectors: Vec<EndpointSelector>,
+    endpoints: &[SysEndpointModel],
+    expanded: &mut BTreeMap<String, SysEndpointModel>,
+) -> Result<Vec<SelectorMatch>, AuthorizationError> {
+    let mut matches = Vec::with_capacity(selectors.len());
+    let mut unmatched = Vec::new();
+    for selector in selectors {
+        let mut matched = 0;
+        for endpoint in endpoints.iter().filter(|endpoint| {
+            selector.matches(&endpoint.controller, &endpoint.resource, &endpoint.method)
+        }) {
+            matched += 1;
+            expanded.entry(endpoint.id.clone()).or_insert_with(|| endpoint.clone());
+        }
+        if matched == 0 {
+            unmatched.push(selector.to_string());
+        }
+        matches.push(SelectorMatch { selector, matched });
     }
-    Ok(())
-}
 
-/// 验证权限ID格式
-fn validate_permission_id(id: &str) -> Result<(), AppError> {
-    let re = Regex::new(r"^[a-zA-Z0-9_-]{1,64}$").unwrap();
-    if !re.is_match(id) {
-        return Err(AppError {
-            code: 400,
-            message: "Permission ID must be 1-64 characters long and contain only letters, numbers, underscores, and hyphens".to_string(),
-        });
+    if !unmatched.is_empty() {
+        return Err(AuthorizationError::selectors_matched_nothing(unmatched));
     }
-    Ok(())
+    Ok(matches)
 }
 
-/// 验证路由ID格式
-fn validate_route_id(id: i32) -> Result<(), AppError> {
-    if id <= 0 {
-        return Err(AppError {
-            code: 400,
-            message: "Route ID must be a positive integer".to_string(),
-        });
-    }
-    Ok(())
+/// 计算从当前集合变更为目标集合需要新增和移除的元素
+fn diff<T: Ord + Clone>(current: &BTreeSet<T>, target: &BTreeSet<T>) -> (Vec<T>, Vec<T>) {
+    (
+        target.difference(current).cloned().collect(),
+        current.difference(target).cloned().collect(),
+    )
 }
 
 /** 授权服务 trait
@@ -161,30 +193,36 @@ pub trait TAuthorizationService: Send + Sync {
      */
     async fn verify_permission(
         &self,
-        user_id: &str,
+        user_id: &UserId,
         permission: &str,
-        domain: &str,
+        domain: &DomainCode,
     ) -> Result<bool, AppError>;
 
     /// 为角色分配权限
     /// 
+    /// 选择器展开为 `sys_endpoint` 中符合条件的接口，与显式ID合并去重后同步为角色的全部接口权限。
+    /// 
     /// # Arguments
     /// * `domain_code` - 域代码
     /// * `role_id` - 角色ID
     /// * `permission_ids` - 权限ID列表
+    /// * `selectors` - 接口选择器列表
+    /// * `actor` - 当前用户
     /// * `enforcer` - 权限执行器
     /// 
     /// # Validation Rules
-    /// * domain_code 不能为空，且必须符合域代码格式
-    /// * role_id 不能为空，且必须符合角色ID格式
-    /// * permission_ids 不能为空，且每个ID必须符合权限ID格式
+    /// * permission_ids 与 selectors 不能同时为空，且每个ID必须符合权限ID格式
+    /// * 每个选择器至少匹配一个接口，否则返回 422 并列出未匹配的选择器
+    /// * 非内置域超级管理员只能分配自己在目标域中持有的接口，否则返回 403 并列出这些接口ID
     async fn assign_permissions(
         &self,
-        domain_code: String,
-        role_id: String,
+        domain_code: DomainCode,
+        role_id: RoleId,
         permission_ids: Vec<String>,
+        selectors: Vec<EndpointSelector>,
+        actor: &User,
         enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
-    ) -> Result<(), AppError>;
+    ) -> Result<AssignmentResult, AppError>;
 
     /// 为角色分配路由
     /// 
@@ -192,42 +230,151 @@ pub trait TAuthorizationService: Send + Sync {
     /// * `domain_code` - 域代码
     /// * `role_id` - 角色ID
     /// * `route_ids` - 路由ID列表
+    /// * `actor` - 当前用户
+    /// * `enforcer` - 权限执行器，用于查询当前用户在目标域中的角色
     /// 
     /// # Validation Rules
-    /// * domain_code 不能为空，且必须符合域代码格式
-    /// * role_id 不能为空，且必须符合角色ID格式
-    /// * route_ids 不能为空，且每个ID必须为正整数
+    /// * route_ids 不能为空
+    /// * 非内置域超级管理员只能分配自己在目标域中持有的路由，否则返回 403 并列出这些路由ID
     async fn assign_routes(
         &self,
-        domain_code: String,
-        role_id: String,
-        route_ids: Vec<i32>,
+        domain_code: DomainCode,
+        role_id: RoleId,
+        route_ids: Vec<MenuId>,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
     ) -> Result<(), AppError>;
 
     /// 为角色分配用户
     /// 
+    /// 同时同步用户角色关联和执行器中的分组规则 `g, 用户ID, 角色代码, 域`，
+    /// 变更后立即生效，不依赖用户重新登录。
+    /// 
     /// # Arguments
     /// * `role_id` - 角色ID
     /// * `user_ids` - 用户ID列表
+    /// * `actor` - 当前用户
+    /// * `enforcer` - 权限执行器
     /// 
     /// # Validation Rules
-    /// * role_id 不能为空，且必须符合角色ID格式
-    /// * user_ids 不能为空，且每个ID必须符合用户ID格式
+    /// * user_ids 不能为空
+    /// * 分配用户即授予角色的全部权限，非内置域超级管理员只能分配自己在角色所在域中
+    ///   持有全部接口和路由的角色，否则返回 403
     async fn assign_users(
         &self,
-        role_id: String,
-        user_ids: Vec<String>,
+        role_id: RoleId,
+        user_ids: Vec<UserId>,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
     ) -> Result<(), AppError>;
+
+    /// 导出角色权限
+    /// 
+    /// 导出的文档只包含角色编码、域编码、接口路径和方法以及菜单路由名称，
+    /// 不包含数据库ID，可导入到其他环境。
+    /// 
+    /// # Arguments
+    /// * `domain_code` - 域代码
+    /// * `role_id` - 角色ID
+    /// * `enforcer` - 权限执行器
+    async fn export_role_permissions(
+        &self,
+        domain_code: DomainCode,
+        role_id: RoleId,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RolePermissionDocument, AppError>;
+
+    /// 导入角色权限
+    /// 
+    /// 按路径和方法查找接口、按路由名称查找菜单，并与角色当前的权限比较。
+    /// 存在无法解析的条目或 `dry_run` 为 true 时只返回计划的变更，不写入数据库。
+    /// 
+    /// # Arguments
+    /// * `input` - 角色权限文档及是否只预览
+    /// * `actor` - 当前用户
+    /// * `enforcer` - 权限执行器
+    /// 
+    /// # Validation Rules
+    /// * 文档版本不能高于当前支持的版本
+    /// * 非内置域超级管理员只能导入当前所在域的角色
+    /// * 文档中的域和角色必须存在
+    /// * 非内置域超级管理员只能授予自己在该域中持有的接口和路由，否则返回 403，预览时同样检查
+    async fn import_role_permissions(
+        &self,
+        input: ImportRolePermissionInput,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RolePermissionImportOutput, AppError>;
+
+    /// 从角色模板创建角色
+    /// 
+    /// 在一个操作中创建角色并授予模板中的接口和菜单权限，任一步骤失败时不保留角色。
+    /// 模板中在当前环境找不到的条目不授予，在结果中列出。
+    /// 
+    /// # Arguments
+    /// * `input` - 模板ID、目标域及角色编码和名称
+    /// * `actor` - 当前用户
+    /// * `enforcer` - 权限执行器
+    /// 
+    /// # Validation Rules
+    /// * 非内置域用户只能在本域中创建角色
+    /// * 模板和目标域必须存在，角色编码在目标域中不能重复
+    /// * 非内置域超级管理员只能授予自己在目标域中持有的接口和路由，否则返回 403
+    async fn create_role_from_template(
+        &self,
+        input: CreateRoleFromTemplateInput,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RoleFromTemplateOutput, AppError>;
+
+    /// 预览一组角色可见的路由
+    /// 
+    /// 与用户登录后获取路由使用相同的逻辑，不读写路由缓存，
+    /// 同时列出已分配给角色但未启用的菜单，用于排查菜单没有出现的原因。
+    /// 
+    /// # Arguments
+    /// * `role_ids` - 角色ID列表
+    /// * `domain_code` - 域代码
+    /// * `operator_domain` - 当前用户所属域
+    /// 
+    /// # Validation Rules
+    /// * 角色ID列表不能为空，且所有角色都必须属于目标域
+    /// * 非内置域用户只能预览本域的角色
+    async fn preview_routes(
+        &self,
+        role_ids: Vec<RoleId>,
+        domain_code: DomainCode,
+        operator_domain: &str,
+    ) -> Result<RoutePreviewOutput, AppError>;
 }
 
-#[derive(Clone)]
+/**
+ * 授权服务
+ * 
+ * 未指定连接时每次调用通过 `db_helper::get_db_connection` 获取主库连接，
+ * 主库连接被替换后新的调用使用新连接
+ */
+#[derive(Clone, Default)]
 pub struct SysAuthorizationService {
-    db: Arc<DatabaseConnection>,
+    db: Option<Arc<DatabaseConnection>>,
 }
 
 impl SysAuthorizationService {
+    /**
+     * 使用指定的数据库连接创建授权服务
+     */
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db: Arc::new(db) }
+        Self { db: Some(Arc::new(db)) }
+    }
+
+    /**
+     * 获取数据库连接
+     */
+    async fn db(&self) -> Result<Arc<DatabaseConnection>, DbErr> {
+        match &self.db {
+            Some(db) => Ok(db.clone()),
+            None => db_helper::get_db_connection().await,
+        }
     }
 
     /// 获取 domain 和 role，domain 可选
@@ -236,9 +383,10 @@ impl SysAuthorizationService {
         domain: Option<&str>,
         role_id: &str,
     ) -> Result<(Option<server_model::admin::entities::sys_domain::Model>, server_model::admin::entities::sys_role::Model), AppError> {
+        let db = self.db().await?;
         // Get role first
         let role = SysRole::find_by_id(role_id)
-            .one(&*self.db)
+            .one(&*db)
             .await
             .map_err(DbErr::from)
             .map_err(AppError::from)?
@@ -251,7 +399,7 @@ impl SysAuthorizationService {
         let domain = if let Some(domain) = domain {
             let domain = SysDomain::find()
                 .filter(SysDomainColumn::Code.eq(domain))
-                .one(&*self.db)
+                .one(&*db)
                 .await
                 .map_err(DbErr::from)
                 .map_err(AppError::from)?
@@ -279,21 +427,19 @@ impl SysAuthorizationService {
         let existing_permissions =
             enforcer_write.get_filtered_policy(0, vec![role_code.to_string(), domain.to_string()]);
 
-        println!("existing_permissions: {:?}", existing_permissions);
-
-        let new_policies: Vec<Vec<String>> = new_permissions
-            .iter()
-            .map(|perm| {
-                vec![
-                    role_code.to_string(),
-                    domain.to_string(),
-                    perm.path.clone(),
-                    perm.method.clone(),
-                ]
-            })
-            .collect();
-
-        println!("new_policies: {:?}", new_policies);
+        // 同一路径下的多个同类端点对应同一条策略
+        let mut new_policies: Vec<Vec<String>> = Vec::new();
+        for perm in &new_permissions {
+            let policy = vec![
+                role_code.to_string(),
+                domain.to_string(),
+                perm.path.clone(),
+                perm.action.clone(),
+            ];
+            if !new_policies.contains(&policy) {
+                new_policies.push(policy);
+            }
+        }
 
         let existing_policies: Vec<Vec<String>> = existing_permissions
             .iter()
@@ -307,8 +453,6 @@ impl SysAuthorizationService {
             })
             .collect();
 
-        println!("existing_policies: {:?}", existing_policies);
-
         let policies_to_remove: Vec<Vec<String>> = existing_policies
             .iter()
             .filter(|policy| !new_policies.contains(policy))
@@ -325,20 +469,447 @@ impl SysAuthorizationService {
             let _ = enforcer_write
                 .remove_policies(policies_to_remove)
                 .await
-                .map_err(|e| AppError {
-                    code: 500,
-                    message: e.to_string(),
-                })?;
+                .map_err(|e| AppError::new(500, e.to_string()))?;
         }
 
         if !policies_to_add.is_empty() {
             let _ = enforcer_write
                 .add_policies(policies_to_add)
                 .await
-                .map_err(|e| AppError {
-                    code: 500,
-                    message: e.to_string(),
-                })?;
+                .map_err(|e| AppError::new(500, e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// 同步角色的分组规则
+    ///
+    /// 执行器中该角色在域中的分组规则与用户列表比较，只新增和移除有差异的规则
+    async fn sync_role_users(
+        &self,
+        role_code: &str,
+        domain: &str,
+        user_ids: &[String],
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync>>,
+    ) -> Result<(), AppError> {
+        let mut enforcer_write = enforcer.write().await;
+        let existing_rules: BTreeSet<Vec<String>> = enforcer_write
+            .get_filtered_grouping_policy(1, vec![role_code.to_string(), domain.to_string()])
+            .into_iter()
+            .map(|rule| rule.into_iter().take(3).collect())
+            .collect();
+        let target_rules: BTreeSet<Vec<String>> = user_ids
+            .iter()
+            .map(|user_id| vec![user_id.clone(), role_code.to_string(), domain.to_string()])
+            .collect();
+        let (rules_to_add, rules_to_remove) = diff(&existing_rules, &target_rules);
+
+        if !rules_to_remove.is_empty() {
+            let _ = enforcer_write
+                .remove_grouping_policies(rules_to_remove)
+                .await
+                .map_err(|e| AppError::new(500, e.to_string()))?;
+        }
+
+        if !rules_to_add.is_empty() {
+            let _ = enforcer_write
+                .add_grouping_policies(rules_to_add)
+                .await
+                .map_err(|e| AppError::new(500, e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// 将角色关联的用户同步为指定的用户列表
+    ///
+    /// 返回新增和移除关联的用户ID，用于记录角色变更
+    async fn sync_user_roles<C: ConnectionTrait>(
+        db: &C,
+        role_id: &str,
+        user_ids: &[String],
+    ) -> Result<(Vec<String>, Vec<String>), DbErr> {
+        let existing_user_roles = SysUserRole::find()
+            .filter(SysUserRoleColumn::RoleId.eq(role_id))
+            .all(db)
+            .await?;
+
+        let existing_user_ids: Vec<String> =
+            existing_user_roles.iter().map(|r| r.user_id.clone()).collect();
+        let new_user_ids: Vec<String> = user_ids
+            .iter()
+            .filter(|id| !existing_user_ids.contains(id))
+            .cloned()
+            .collect();
+        let user_ids_to_delete: Vec<String> = existing_user_ids
+            .iter()
+            .filter(|id| !user_ids.contains(id))
+            .cloned()
+            .collect();
+
+        // 批量插入
+        if !new_user_ids.is_empty() {
+            let user_roles: Vec<SysUserRoleActiveModel> = new_user_ids
+                .iter()
+                .map(|user_id| SysUserRoleActiveModel {
+                    role_id: Set(role_id.to_string()),
+                    user_id: Set(user_id.clone()),
+                })
+                .collect();
+            SysUserRole::insert_many(user_roles).exec(db).await?;
+        }
+
+        // 批量删除
+        if !user_ids_to_delete.is_empty() {
+            SysUserRole::delete_many()
+                .filter(
+                    SysUserRoleColumn::RoleId
+                        .eq(role_id)
+                        .and(SysUserRoleColumn::UserId.is_in(user_ids_to_delete.clone())),
+                )
+                .exec(db)
+                .await?;
+        }
+
+        Ok((new_user_ids, user_ids_to_delete))
+    }
+
+    /// 为被授予和移除角色的用户记录角色变更安全事件，与关联变更在同一事务中写入
+    async fn record_role_changes<C: ConnectionTrait>(
+        db: &C,
+        role: &SysRoleModel,
+        actor: &User,
+        granted: Vec<String>,
+        revoked: Vec<String>,
+    ) -> Result<(), DbErr> {
+        let granted = granted.into_iter().map(|user_id| (user_id, true));
+        let revoked = revoked.into_iter().map(|user_id| (user_id, false));
+        for (user_id, is_granted) in granted.chain(revoked) {
+            let event = SecurityEvent::RoleChanged {
+                user_id,
+                domain: role.domain.clone(),
+                role_code: role.code.clone(),
+                granted: is_granted,
+                operator_id: actor.user_id(),
+            };
+            SysSecurityEventService::record_security_event(db, &event).await?;
+        }
+        Ok(())
+    }
+
+    /// 判断当前用户是否为内置域超级管理员
+    ///
+    /// 持有 `ROLE_SUPER` 或初始管理员配置中的角色视为超级管理员
+    async fn is_super_admin(actor: &User) -> bool {
+        if actor.domain() != BUILT_IN_DOMAIN_CODE {
+            return false;
+        }
+        let bootstrap_role =
+            global::get_config::<BootstrapConfig>().await.map(|config| config.admin_role.clone());
+        actor
+            .subject()
+            .iter()
+            .any(|role| role == SUPER_ADMIN_ROLE_CODE || bootstrap_role.as_ref() == Some(role))
+    }
+
+    /// 按执行器中的分组规则查询当前用户在域中的角色代码
+    async fn find_actor_roles(
+        actor: &User,
+        domain: &str,
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> BTreeSet<String> {
+        enforcer
+            .read()
+            .await
+            .get_filtered_grouping_policy(0, vec![actor.user_id()])
+            .into_iter()
+            .filter(|rule| rule.len() >= 3 && rule[2] == domain)
+            .map(|rule| rule[1].clone())
+            .collect()
+    }
+
+    /// 检查待分配的接口是否都由当前用户持有
+    ///
+    /// 当前用户在目标域中的角色存在路径相同、操作相同的策略，或以 write 策略覆盖 read 接口时视为持有
+    async fn ensure_endpoints_held(
+        actor: &User,
+        domain: &str,
+        endpoints: &BTreeMap<String, SysEndpointModel>,
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> Result<(), AuthorizationError> {
+        if Self::is_super_admin(actor).await {
+            return Ok(());
+        }
+
+        let roles = Self::find_actor_roles(actor, domain, enforcer).await;
+        let held: BTreeSet<(String, String)> = {
+            let enforcer = enforcer.read().await;
+            roles
+                .into_iter()
+                .flat_map(|role| enforcer.get_filtered_policy(0, vec![role, domain.to_string()]))
+                .filter(|policy| policy.len() >= 4)
+                .map(|policy| (policy[2].clone(), policy[3].clone()))
+                .collect()
+        };
+
+        let not_held: Vec<String> = endpoints
+            .values()
+            .filter(|endpoint| {
+                let covered_by = |action: &str| {
+                    held.contains(&(endpoint.path.clone(), action.to_string()))
+                };
+                !covered_by(&endpoint.action) && !(endpoint.action == "read" && covered_by("write"))
+            })
+            .map(|endpoint| endpoint.id.clone())
+            .collect();
+        if !not_held.is_empty() {
+            error!("Actor {} cannot assign endpoints not held: {:?}", actor.user_id(), not_held);
+            return Err(AuthorizationError::endpoints_not_held(not_held));
+        }
+        Ok(())
+    }
+
+    /// 检查待分配的路由是否都由当前用户持有
+    ///
+    /// 当前用户在目标域中的角色已分配的菜单视为持有
+    async fn ensure_routes_held<C: ConnectionTrait>(
+        db: &C,
+        actor: &User,
+        domain: &str,
+        route_ids: &[i32],
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> Result<(), AuthorizationError> {
+        if Self::is_super_admin(actor).await {
+            return Ok(());
+        }
+
+        let roles = Self::find_actor_roles(actor, domain, enforcer).await;
+        let role_ids: Vec<String> = SysRole::find()
+            .filter(SysRoleColumn::Code.is_in(roles))
+            .filter(SysRoleColumn::Domain.eq(domain))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|role| role.id)
+            .collect();
+        let held: BTreeSet<i32> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.is_in(role_ids))
+            .filter(SysRoleMenuColumn::Domain.eq(domain))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|role_menu| role_menu.menu_id)
+            .collect();
+
+        let not_held: Vec<i32> =
+            route_ids.iter().filter(|id| !held.contains(*id)).copied().collect();
+        if !not_held.is_empty() {
+            error!("Actor {} cannot assign routes not held: {:?}", actor.user_id(), not_held);
+            return Err(AuthorizationError::routes_not_held(not_held));
+        }
+        Ok(())
+    }
+
+    /// 检查批量授予的接口和路由是否都由当前用户持有
+    ///
+    /// 导入角色权限和从模板创建角色与分别分配接口和路由使用相同的检查
+    async fn ensure_grants_held<C: ConnectionTrait>(
+        db: &C,
+        actor: &User,
+        domain: &str,
+        endpoints: &BTreeMap<EndpointDescriptor, SysEndpointModel>,
+        route_ids: &[i32],
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> Result<(), AuthorizationError> {
+        let endpoints: BTreeMap<String, SysEndpointModel> = endpoints
+            .values()
+            .map(|endpoint| (endpoint.id.clone(), endpoint.clone()))
+            .collect();
+        Self::ensure_endpoints_held(actor, domain, &endpoints, enforcer).await?;
+        Self::ensure_routes_held(db, actor, domain, route_ids, enforcer).await
+    }
+
+    /// 检查角色的全部接口和路由是否都由当前用户持有
+    ///
+    /// 为角色分配用户即授予用户该角色的全部权限
+    async fn ensure_role_held<C: ConnectionTrait>(
+        db: &C,
+        actor: &User,
+        role: &SysRoleModel,
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> Result<(), AuthorizationError> {
+        if Self::is_super_admin(actor).await {
+            return Ok(());
+        }
+
+        let descriptors = Self::find_role_endpoints(db, &role.code, &role.domain, enforcer).await?;
+        let endpoints = Self::resolve_endpoints(db, &descriptors).await?;
+        let route_ids: Vec<i32> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.eq(&role.id))
+            .filter(SysRoleMenuColumn::Domain.eq(&role.domain))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|role_menu| role_menu.menu_id)
+            .collect();
+        Self::ensure_grants_held(db, actor, &role.domain, &endpoints, &route_ids, enforcer).await
+    }
+
+    /// 查询角色在域中的接口权限
+    ///
+    /// 策略按路径和操作（read/write）授权，展开为操作相同的已登记接口
+    async fn find_role_endpoints<C: ConnectionTrait>(
+        db: &C,
+        role_code: &str,
+        domain: &str,
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+    ) -> Result<BTreeSet<EndpointDescriptor>, DbErr> {
+        let policies: BTreeSet<(String, String)> = enforcer
+            .read()
+            .await
+            .get_filtered_policy(0, vec![role_code.to_string(), domain.to_string()])
+            .into_iter()
+            .filter(|policy| policy.len() >= 4)
+            .map(|policy| (policy[2].clone(), policy[3].clone()))
+            .collect();
+
+        if policies.is_empty() {
+            return Ok(BTreeSet::new());
+        }
+
+        let paths: BTreeSet<&str> = policies.iter().map(|(path, _)| path.as_str()).collect();
+        let endpoints = SysEndpoint::find()
+            .filter(SysEndpointColumn::Path.is_in(paths))
+            .all(db)
+            .await?;
+
+        Ok(endpoints
+            .into_iter()
+            .filter(|endpoint| policies.contains(&(endpoint.path.clone(), endpoint.action.clone())))
+            .map(|endpoint| EndpointDescriptor { path: endpoint.path, method: endpoint.method })
+            .collect())
+    }
+
+    /// 查询角色在域中的菜单路由名称
+    async fn find_role_menu_names<C: ConnectionTrait>(
+        db: &C,
+        role_id: &str,
+        domain: &str,
+    ) -> Result<BTreeSet<String>, DbErr> {
+        let menu_ids: Vec<i32> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.eq(role_id))
+            .filter(SysRoleMenuColumn::Domain.eq(domain))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|role_menu| role_menu.menu_id)
+            .collect();
+
+        if menu_ids.is_empty() {
+            return Ok(BTreeSet::new());
+        }
+
+        let menus = SysMenu::find().filter(SysMenuColumn::Id.is_in(menu_ids)).all(db).await?;
+        Ok(menus.into_iter().map(|menu| menu.route_name).collect())
+    }
+
+    /// 按路径和方法查找接口，同一接口存在多条记录时取第一条
+    async fn resolve_endpoints<C: ConnectionTrait>(
+        db: &C,
+        descriptors: &BTreeSet<EndpointDescriptor>,
+    ) -> Result<BTreeMap<EndpointDescriptor, SysEndpointModel>, DbErr> {
+        if descriptors.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let paths: BTreeSet<&str> = descriptors.iter().map(|d| d.path.as_str()).collect();
+        let endpoints = SysEndpoint::find()
+            .filter(SysEndpointColumn::Path.is_in(paths))
+            .all(db)
+            .await?;
+
+        let mut resolved = BTreeMap::new();
+        for endpoint in endpoints {
+            let descriptor = EndpointDescriptor {
+                path: endpoint.path.clone(),
+                method: endpoint.method.clone(),
+            };
+            if descriptors.contains(&descriptor) {
+                resolved.entry(descriptor).or_insert(endpoint);
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// 按路由名称查找菜单，返回路由名称到菜单ID的映射
+    async fn resolve_menus<C: ConnectionTrait>(
+        db: &C,
+        route_names: &BTreeSet<String>,
+    ) -> Result<BTreeMap<String, i32>, DbErr> {
+        if route_names.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let menus = SysMenu::find()
+            .filter(SysMenuColumn::RouteName.is_in(route_names.iter().map(String::as_str)))
+            .all(db)
+            .await?;
+        Ok(menus.into_iter().map(|menu| (menu.route_name, menu.id)).collect())
+    }
+
+    /// 将角色在域中的菜单同步为指定的菜单列表
+    async fn sync_role_routes<C: ConnectionTrait>(
+        db: &C,
+        role_id: &str,
+        domain: &str,
+        route_ids: &[i32],
+    ) -> Result<(), DbErr> {
+        let existing_routes = SysRoleMenu::find()
+            .filter(
+                SysRoleMenuColumn::RoleId
+                    .eq(role_id)
+                    .and(SysRoleMenuColumn::Domain.eq(domain)),
+            )
+            .all(db)
+            .await?;
+
+        let existing_route_ids: Vec<i32> = existing_routes.iter().map(|r| r.menu_id).collect();
+        let new_route_ids: Vec<i32> = route_ids
+            .iter()
+            .filter(|id| !existing_route_ids.contains(id))
+            .cloned()
+            .collect();
+        let route_ids_to_delete: Vec<i32> = existing_route_ids
+            .iter()
+            .filter(|id| !route_ids.contains(id))
+            .cloned()
+            .collect();
+
+        // 批量插入
+        if !new_route_ids.is_empty() {
+            let role_menus: Vec<SysRoleMenuActiveModel> = new_route_ids
+                .iter()
+                .map(|route_id| SysRoleMenuActiveModel {
+                    role_id: Set(role_id.to_string()),
+                    menu_id: Set(*route_id),
+                    domain: Set(domain.to_string()),
+                    ..Default::default()
+                })
+                .collect();
+            SysRoleMenu::insert_many(role_menus).exec(db).await?;
+        }
+
+        // 批量删除
+        if !route_ids_to_delete.is_empty() {
+            SysRoleMenu::delete_many()
+                .filter(
+                    SysRoleMenuColumn::RoleId
+                        .eq(role_id)
+                        .and(SysRoleMenuColumn::Domain.eq(domain))
+                        .and(SysRoleMenuColumn::MenuId.is_in(route_ids_to_delete)),
+                )
+                .exec(db)
+                .await?;
         }
 
         Ok(())
@@ -346,12 +917,12 @@ impl SysAuthorizationService {
 
     #[allow(dead_code)]
     async fn assign_permissions(&self, _domain: &str, role_id: &str, permission_ids: Vec<String>) -> Result<(), AuthorizationError> {
+        let db = self.db().await?;
         // 检查所有权限是否存在
         let permissions = SysEndpoint::find()
             .filter(SysEndpointColumn::Id.is_in(permission_ids.clone()))
-            .all(&*self.db)
-            .await
-            .map_err(|e| AuthorizationError::DatabaseError(Box::new(e)))?;
+            .all(&*db)
+            .await?;
 
         if permissions.len() != permission_ids.len() {
             let found_ids: Vec<String> = permissions.iter().map(|p| p.id.to_string()).collect();
@@ -368,7 +939,7 @@ impl SysAuthorizationService {
         let permission_ids = permission_ids.clone();
 
         // 在事务中执行权限分配
-        execute_in_transaction(&self.db, move |txn| {
+        execute_in_transaction(&db, move |txn| {
             let role_id = role_id.clone();
             let permission_ids = permission_ids.clone();
             
@@ -377,8 +948,7 @@ impl SysAuthorizationService {
                 let existing_permissions = SysRoleMenu::find()
                     .filter(SysRoleMenuColumn::RoleId.eq(&role_id))
                     .all(&txn)
-                    .await
-                    .map_err(|e| AuthorizationError::DatabaseError(Box::new(e)))?;
+                    .await?;
 
                 // 计算需要添加和删除的权限
                 let existing_ids: Vec<String> = existing_permissions
@@ -414,8 +984,7 @@ impl SysAuthorizationService {
                     if !role_menus.is_empty() {
                         SysRoleMenu::insert_many(role_menus)
                             .exec(&txn)
-                            .await
-                            .map_err(|e| AuthorizationError::DatabaseError(Box::new(e)))?;
+                            .await?;
                     }
                 }
 
@@ -432,8 +1001,7 @@ impl SysAuthorizationService {
                             .filter(SysRoleMenuColumn::RoleId.eq(&role_id))
                             .filter(SysRoleMenuColumn::MenuId.is_in(delete_ids))
                             .exec(&txn)
-                            .await
-                            .map_err(|e| AuthorizationError::DatabaseError(Box::new(e)))?;
+                            .await?;
                     }
                 }
 
@@ -442,35 +1010,32 @@ impl SysAuthorizationService {
                     role_id, add_count, delete_count
                 );
 
+                txn.commit().await?;
                 Ok(())
             })
         })
         .await
-        .map_err(|e| match e {
-            AppError { code, message } => AuthorizationError::DatabaseError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Database error (code {}): {}", code, message),
-            ))),
-        })
     }
 
     /** 获取用户角色列表
      * 
-     * 查询用户关联的所有角色代码
+     * 查询用户在域中关联的角色代码，角色代码只在域内唯一
      * 
      * 参数
      * --------
      * * `user_id` - 用户ID
+     * * `domain` - 域代码
      * * `db` - 数据库连接
      * 
      * 返回
      * --------
      * * `Result<Vec<String>, AppError>` - 角色代码列表或错误
      */
-    #[instrument(skip(self, db), fields(user_id = %user_id))]
+    #[instrument(skip(self, db), fields(user_id = %user_id, domain = %domain))]
     async fn get_user_roles(
         &self,
         user_id: &str,
+        domain: &str,
         db: &Arc<DatabaseConnection>,
     ) -> Result<Vec<String>, AppError> {
         let user_roles = SysUserRole::find()
@@ -486,6 +1051,7 @@ impl SysAuthorizationService {
 
         let roles = SysRole::find()
             .filter(SysRoleColumn::Id.is_in(role_ids))
+            .filter(SysRoleColumn::Domain.eq(domain))
             .all(db.as_ref())
             .await
             .map_err(DbErr::from)
@@ -517,9 +1083,10 @@ impl SysAuthorizationService {
         domain: &str,
         db: &Arc<DatabaseConnection>,
     ) -> Result<bool, AppError> {
-        // 获取角色ID
+        // 获取角色ID，其他域中可能存在同代码的角色
         let role = SysRole::find()
             .filter(SysRoleColumn::Code.eq(role_code))
+            .filter(SysRoleColumn::Domain.eq(domain))
             .one(db.as_ref())
             .await
             .map_err(DbErr::from)
@@ -552,16 +1119,17 @@ impl SysAuthorizationService {
 impl TAuthorizationService for SysAuthorizationService {
     async fn verify_permission(
         &self,
-        user_id: &str,
+        user_id: &UserId,
         permission: &str,
-        domain: &str,
+        domain: &DomainCode,
     ) -> Result<bool, AppError> {
+        let db = self.db().await?;
         // 获取用户角色
-        let roles = self.get_user_roles(user_id, &self.db).await?;
+        let roles = self.get_user_roles(user_id.as_str(), domain.as_str(), &db).await?;
         
         // 检查每个角色是否有权限
         for role in roles {
-            if self.verify_role_permission(&role, permission, domain, &self.db).await? {
+            if self.verify_role_permission(&role, permission, domain.as_str(), &db).await? {
                 return Ok(true);
             }
         }
@@ -571,71 +1139,94 @@ impl TAuthorizationService for SysAuthorizationService {
 
     async fn assign_permissions(
         &self,
-        domain_code: String,
-        role_id: String,
+        domain_code: DomainCode,
+        role_id: RoleId,
         permission_ids: Vec<String>,
+        selectors: Vec<EndpointSelector>,
+        actor: &User,
         enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
-    ) -> Result<(), AppError> {
+    ) -> Result<AssignmentResult, AppError> {
+        let db = self.db().await?;
         // 参数验证
-        validate_not_empty(&domain_code, "domain_code")?;
-        validate_domain_code(&domain_code)?;
-        validate_not_empty(&role_id, "role_id")?;
-        validate_role_id(&role_id)?;
-        validate_ids_not_empty(&permission_ids, "permission_ids")?;
+        if selectors.is_empty() {
+            validate_ids_not_empty(&permission_ids, "permission_ids")?;
+        }
         for id in &permission_ids {
             validate_permission_id(id)?;
         }
 
         // Check domain and role first
-        let (domain_opt, role) = self.get_domain_and_role(Some(&domain_code), &role_id).await?;
+        let (domain_opt, role) =
+            self.get_domain_and_role(Some(domain_code.as_str()), role_id.as_str()).await?;
         let domain_code = domain_opt.as_ref().unwrap().code.clone();
         let role_code = role.code.clone();
 
-        // Get permissions
-        let permissions = SysEndpoint::find()
-            .filter(SysEndpointColumn::Id.is_in(permission_ids.clone()))
-            .all(&*self.db)
-            .await
-            .map_err(DbErr::from)
-            .map_err(AppError::from)?;
+        // 显式ID与选择器展开的接口按ID合并去重
+        let mut endpoints: BTreeMap<String, SysEndpointModel> = BTreeMap::new();
+        if !permission_ids.is_empty() {
+            let permissions = SysEndpoint::find()
+                .filter(SysEndpointColumn::Id.is_in(permission_ids.clone()))
+                .all(&*db)
+                .await
+                .map_err(DbErr::from)
+                .map_err(AppError::from)?;
 
-        if permissions.is_empty() {
-            let found_ids: Vec<String> = permissions.iter().map(|p| p.id.to_string()).collect();
-            let err = AuthorizationError::permissions_not_found(vec![], found_ids);
-            return Err(AppError::from(err));
+            if permissions.is_empty() {
+                let err = AuthorizationError::permissions_not_found(permission_ids, vec![]);
+                return Err(AppError::from(err));
+            }
+            endpoints.extend(permissions.into_iter().map(|p| (p.id.clone(), p)));
         }
 
-        // Sync permissions with enforcer
-        self.sync_role_permissions(&role_code, &domain_code, permissions, enforcer)
-            .await?;
+        let selector_matches = if selectors.is_empty() {
+            Vec::new()
+        } else {
+            let all_endpoints = SysEndpoint::find()
+                .all(&*db)
+                .await
+                .map_err(DbErr::from)
+                .map_err(AppError::from)?;
+            expand_selectors(selectors, &all_endpoints, &mut endpoints)?
+        };
 
-        Ok(())
+        Self::ensure_endpoints_held(actor, &domain_code, &endpoints, &enforcer).await?;
+        let endpoint_count = endpoints.len();
+
+        // Sync permissions with enforcer
+        self.sync_role_permissions(
+            &role_code,
+            &domain_code,
+            endpoints.into_values().collect(),
+            enforcer,
+        )
+        .await?;
+
+        Ok(AssignmentResult { endpoint_count, selectors: selector_matches })
     }
 
     async fn assign_routes(
         &self,
-        domain_code: String,
-        role_id: String,
-        route_ids: Vec<i32>,
+        domain_code: DomainCode,
+        role_id: RoleId,
+        route_ids: Vec<MenuId>,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
     ) -> Result<(), AppError> {
+        let db = self.db().await?;
         // 参数验证
-        validate_not_empty(&domain_code, "domain_code")?;
-        validate_domain_code(&domain_code)?;
-        validate_not_empty(&role_id, "role_id")?;
-        validate_role_id(&role_id)?;
         validate_ids_not_empty(&route_ids, "route_ids")?;
-        for &id in &route_ids {
-            validate_route_id(id)?;
-        }
+        let route_ids: Vec<i32> = route_ids.into_iter().map(MenuId::value).collect();
 
-        let (domain_opt, role) = self.get_domain_and_role(Some(&domain_code), &role_id).await?;
+        let (domain_opt, role) =
+            self.get_domain_and_role(Some(domain_code.as_str()), role_id.as_str()).await?;
         let domain_code = domain_opt.unwrap().code;
         let role_id = role.id;
+        let role_code = role.code;
         
         // 先批量检查所有 route_ids 是否存在
         let routes = SysMenu::find()
             .filter(SysMenuColumn::Id.is_in(route_ids.clone()))
-            .all(&*self.db)
+            .all(&*db)
             .await
             .map_err(DbErr::from)
             .map_err(AppError::from)?;
@@ -646,89 +1237,40 @@ impl TAuthorizationService for SysAuthorizationService {
             let err = AuthorizationError::routes_not_found(missing_ids, found_ids);
             return Err(AppError::from(err));
         }
+        Self::ensure_routes_held(&*db, actor, &domain_code, &route_ids, &enforcer).await?;
 
-        execute_in_transaction(&self.db, move |mut txn| {
-            let domain_code = domain_code.clone();
-            let role_id = role_id.clone();
-            let route_ids = route_ids.clone();
+        let changed_domain = domain_code.clone();
+        execute_in_transaction(&db, move |txn| {
             Box::pin(async move {
-                let existing_routes = SysRoleMenu::find()
-                    .filter(
-                        SysRoleMenuColumn::RoleId
-                            .eq(&role_id)
-                            .and(SysRoleMenuColumn::Domain.eq(&domain_code)),
-                    )
-                    .all(&mut txn)
-                    .await?;
-
-                let existing_route_ids: Vec<i32> = existing_routes.iter().map(|r| r.menu_id).collect();
-                let new_route_ids: Vec<i32> = route_ids
-                    .iter()
-                    .filter(|id| !existing_route_ids.contains(id))
-                    .cloned()
-                    .collect();
-                let route_ids_to_delete: Vec<i32> = existing_route_ids
-                    .iter()
-                    .filter(|id| !route_ids.contains(id))
-                    .cloned()
-                    .collect();
-
-                // 批量插入
-                if !new_route_ids.is_empty() {
-                    let role_menus: Vec<SysRoleMenuActiveModel> = new_route_ids
-                        .iter()
-                        .map(|route_id| SysRoleMenuActiveModel {
-                            role_id: sea_orm::Set(role_id.clone()),
-                            menu_id: sea_orm::Set(*route_id),
-                            domain: sea_orm::Set(domain_code.clone()),
-                            ..Default::default()
-                        })
-                        .collect();
-                    SysRoleMenu::insert_many(role_menus)
-                        .exec(&mut txn)
-                        .await?;
-                }
-
-                // 批量删除
-                if !route_ids_to_delete.is_empty() {
-                    SysRoleMenu::delete_many()
-                        .filter(
-                            SysRoleMenuColumn::RoleId
-                                .eq(&role_id)
-                                .and(SysRoleMenuColumn::Domain.eq(&domain_code))
-                                .and(SysRoleMenuColumn::MenuId.is_in(route_ids_to_delete)),
-                        )
-                        .exec(&mut txn)
-                        .await?;
-                }
-
+                Self::sync_role_routes(&txn, &role_id, &domain_code, &route_ids).await?;
                 txn.commit().await?;
                 Ok(())
             })
         })
-        .await
+        .await?;
+
+        notify_menu_assignment_changed(Some(changed_domain), vec![role_code]).await;
+        Ok(())
     }
 
     async fn assign_users(
         &self,
-        role_id: String,
-        user_ids: Vec<String>,
+        role_id: RoleId,
+        user_ids: Vec<UserId>,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
     ) -> Result<(), AppError> {
+        let db = self.db().await?;
         // 参数验证
-        validate_not_empty(&role_id, "role_id")?;
-        validate_role_id(&role_id)?;
         validate_ids_not_empty(&user_ids, "user_ids")?;
-        for id in &user_ids {
-            validate_user_id(id)?;
-        }
+        let user_ids: Vec<String> = user_ids.into_iter().map(UserId::into_inner).collect();
 
-        let (_, role) = self.get_domain_and_role(None, &role_id).await?;
-        let role_id = role.id;
+        let (_, role) = self.get_domain_and_role(None, role_id.as_str()).await?;
 
         // 先批量检查所有 user_ids 是否存在
         let users = SysUser::find()
             .filter(server_model::admin::entities::sys_user::Column::Id.is_in(user_ids.clone()))
-            .all(&*self.db)
+            .all(&*db)
             .await
             .map_err(DbErr::from)
             .map_err(AppError::from)?;
@@ -739,62 +1281,1135 @@ impl TAuthorizationService for SysAuthorizationService {
             let err = AuthorizationError::users_not_found(missing_ids, found_ids);
             return Err(AppError::from(err));
         }
+        Self::ensure_role_held(db.as_ref(), actor, &role, &enforcer).await?;
 
-        execute_in_transaction(&self.db, move |mut txn| {
-            let role_id = role_id.clone();
-            let user_ids = user_ids.clone();
-            Box::pin(async move {
-                let existing_user_roles = SysUserRole::find()
-                    .filter(SysUserRoleColumn::RoleId.eq(&role_id))
-                    .all(&mut txn)
-                    .await?;
+        // 用户角色关联在事务中同步，分组规则同步失败时回滚关联变更
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let (granted, revoked) =
+            Self::sync_user_roles(&txn, &role.id, &user_ids).await.map_err(AppError::from)?;
+        Self::record_role_changes(&txn, &role, actor, granted, revoked)
+            .await
+            .map_err(AppError::from)?;
+        self.sync_role_users(&role.code, &role.domain, &user_ids, enforcer).await?;
+        txn.commit().await.map_err(AppError::from)?;
 
-                let existing_user_ids: Vec<String> = existing_user_roles
-                    .iter()
-                    .map(|r| r.user_id.clone())
-                    .collect();
-                let new_user_ids: Vec<String> = user_ids
-                    .iter()
-                    .filter(|id| !existing_user_ids.contains(id))
-                    .cloned()
-                    .collect();
-                let user_ids_to_delete: Vec<String> = existing_user_ids
-                    .iter()
-                    .filter(|id| !user_ids.contains(id))
-                    .cloned()
-                    .collect();
+        Ok(())
+    }
 
-                // 批量插入
-                if !new_user_ids.is_empty() {
-                    let user_roles: Vec<SysUserRoleActiveModel> = new_user_ids
-                        .iter()
-                        .map(|user_id| SysUserRoleActiveModel {
-                            role_id: sea_orm::Set(role_id.clone()),
-                            user_id: sea_orm::Set(user_id.clone()),
-                            ..Default::default()
-                        })
-                        .collect();
-                    SysUserRole::insert_many(user_roles)
-                        .exec(&mut txn)
-                        .await?;
-                }
+    async fn export_role_permissions(
+        &self,
+        domain_code: DomainCode,
+        role_id: RoleId,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RolePermissionDocument, AppError> {
+        let db = self.db().await?;
+        let (domain_opt, role) =
+            self.get_domain_and_role(Some(domain_code.as_str()), role_id.as_str()).await?;
+        let domain_code = domain_opt.unwrap().code;
 
-                // 批量删除
-                if !user_ids_to_delete.is_empty() {
-                    SysUserRole::delete_many()
-                        .filter(
-                            SysUserRoleColumn::RoleId
-                                .eq(&role_id)
-                                .and(SysUserRoleColumn::UserId.is_in(user_ids_to_delete)),
-                        )
-                        .exec(&mut txn)
-                        .await?;
-                }
+        let endpoints = Self::find_role_endpoints(db.as_ref(), &role.code, &domain_code, &enforcer)
+            .await
+            .map_err(AppError::from)?;
+        let menus = Self::find_role_menu_names(db.as_ref(), &role.id, &domain_code)
+            .await
+            .map_err(AppError::from)?;
 
-                txn.commit().await?;
-                Ok(())
-            })
+        Ok(RolePermissionDocument {
+            version: ROLE_PERMISSION_DOCUMENT_VERSION,
+            role_code: role.code,
+            domain_code,
+            endpoints: endpoints.into_iter().collect(),
+            menus: menus.into_iter().collect(),
         })
+    }
+
+    async fn import_role_permissions(
+        &self,
+        input: ImportRolePermissionInput,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RolePermissionImportOutput, AppError> {
+        let db = self.db().await?;
+        let ImportRolePermissionInput { document, dry_run } = input;
+
+        // 参数验证
+        validate_document_version(document.version)?;
+        document.domain_code.parse::<DomainCode>()?;
+        validate_not_empty(&document.role_code, "role_code")?;
+
+        // 文档中的域由调用方指定，非内置域超级管理员只能导入当前所在域
+        if document.domain_code != actor.domain() && !Self::is_super_admin(actor).await {
+            return Err(AuthorizationError::permission_denied(format!(
+                "Cannot import role permissions into domain '{}'",
+                document.domain_code
+            ))
+            .into());
+        }
+
+        let domain = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(&document.domain_code))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| {
+                AppError::from(AuthorizationError::domain_not_found(
+                    document.domain_code.clone(),
+                    "".to_string(),
+                ))
+            })?;
+        let role = SysRole::find()
+            .filter(SysRoleColumn::Code.eq(&document.role_code))
+            .filter(SysRoleColumn::Domain.eq(&domain.code))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| {
+                AppError::from(AuthorizationError::role_not_found(
+                    document.role_code.clone(),
+                    "".to_string(),
+                ))
+            })?;
+
+        let wanted_endpoints: BTreeSet<EndpointDescriptor> =
+            document.endpoints.into_iter().collect();
+        let wanted_menus: BTreeSet<String> = document.menus.into_iter().collect();
+
+        // 在当前环境中解析接口和菜单
+        let endpoints = Self::resolve_endpoints(db.as_ref(), &wanted_endpoints)
+            .await
+            .map_err(AppError::from)?;
+        let menus = Self::resolve_menus(db.as_ref(), &wanted_menus)
+            .await
+            .map_err(AppError::from)?;
+        let resolved_endpoints: BTreeSet<EndpointDescriptor> = endpoints.keys().cloned().collect();
+        let resolved_menus: BTreeSet<String> = menus.keys().cloned().collect();
+        let route_ids: Vec<i32> = menus.into_values().collect();
+        Self::ensure_grants_held(
+            db.as_ref(),
+            actor,
+            &domain.code,
+            &endpoints,
+            &route_ids,
+            &enforcer,
+        )
+        .await?;
+
+        // 与角色当前的权限比较
+        let current_endpoints =
+            Self::find_role_endpoints(db.as_ref(), &role.code, &domain.code, &enforcer)
+                .await
+                .map_err(AppError::from)?;
+        let current_menus = Self::find_role_menu_names(db.as_ref(), &role.id, &domain.code)
+            .await
+            .map_err(AppError::from)?;
+        let (endpoints_to_add, endpoints_to_remove) = diff(&current_endpoints, &resolved_endpoints);
+        let (menus_to_add, menus_to_remove) = diff(&current_menus, &resolved_menus);
+
+        let mut output = RolePermissionImportOutput {
+            applied: false,
+            unresolved_endpoints: wanted_endpoints.difference(&resolved_endpoints).cloned().collect(),
+            unresolved_menus: wanted_menus.difference(&resolved_menus).cloned().collect(),
+            endpoints_to_add,
+            endpoints_to_remove,
+            menus_to_add,
+            menus_to_remove,
+        };
+
+        if dry_run || !output.unresolved_endpoints.is_empty() || !output.unresolved_menus.is_empty()
+        {
+            return Ok(output);
+        }
+
+        // 菜单在事务中同步，接口权限同步失败时回滚菜单变更
+        let txn = db.begin().await.map_err(AppError::from)?;
+        Self::sync_role_routes(&txn, &role.id, &domain.code, &route_ids)
+            .await
+            .map_err(AppError::from)?;
+        self.sync_role_permissions(
+            &role.code,
+            &domain.code,
+            endpoints.into_values().collect(),
+            enforcer,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+        notify_menu_assignment_changed(Some(domain.code.clone()), vec![role.code.clone()]).await;
+
+        info!(
+            "Imported role permissions: role={}, domain={}, endpoints=+{}/-{}, menus=+{}/-{}",
+            role.code,
+            domain.code,
+            output.endpoints_to_add.len(),
+            output.endpoints_to_remove.len(),
+            output.menus_to_add.len(),
+            output.menus_to_remove.len()
+        );
+
+        output.applied = true;
+        Ok(output)
+    }
+
+    async fn create_role_from_template(
+        &self,
+        input: CreateRoleFromTemplateInput,
+        actor: &User,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<RoleFromTemplateOutput, AppError> {
+        let db = self.db().await?;
+        let CreateRoleFromTemplateInput { template_id, domain_code, role_code, role_name } = input;
+
+        // 非内置域用户只能在本域中创建角色
+        let operator_domain = actor.domain();
+        if operator_domain != BUILT_IN_DOMAIN_CODE && domain_code.as_str() != operator_domain {
+            return Err(AuthorizationError::permission_denied(format!(
+                "Cannot create roles in domain '{}'",
+                domain_code
+            ))
+            .into());
+        }
+
+        let template = SysRoleTemplateService::find_template(db.as_ref(), &template_id).await?;
+        let permissions = template_permissions(&template)?;
+
+        let domain = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(domain_code.as_str()))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| {
+                AppError::from(AuthorizationError::domain_not_found(
+                    domain_code.to_string(),
+                    "".to_string(),
+                ))
+            })?;
+        let duplicate = SysRole::find()
+            .filter(SysRoleColumn::Code.eq(&role_code))
+            .filter(SysRoleColumn::Domain.eq(&domain.code))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        if duplicate.is_some() {
+            return Err(RoleError::DuplicateRoleCode.into());
+        }
+
+        // 在当前环境中解析模板的接口和菜单
+        let wanted_endpoints: BTreeSet<EndpointDescriptor> =
+            permissions.endpoints.into_iter().collect();
+        let wanted_menus: BTreeSet<String> = permissions.menus.into_iter().collect();
+        let endpoints = Self::resolve_endpoints(db.as_ref(), &wanted_endpoints)
+            .await
+            .map_err(AppError::from)?;
+        let menus = Self::resolve_menus(db.as_ref(), &wanted_menus)
+            .await
+            .map_err(AppError::from)?;
+        let unresolved_endpoints: Vec<EndpointDescriptor> = wanted_endpoints
+            .into_iter()
+            .filter(|descriptor| !endpoints.contains_key(descriptor))
+            .collect();
+        let unresolved_menus: Vec<String> =
+            wanted_menus.into_iter().filter(|name| !menus.contains_key(name)).collect();
+        let route_ids: Vec<i32> = menus.into_values().collect();
+        Self::ensure_grants_held(
+            db.as_ref(),
+            actor,
+            &domain.code,
+            &endpoints,
+            &route_ids,
+            &enforcer,
+        )
+        .await?;
+
+        // 角色和菜单在事务中写入，接口权限同步失败时一并回滚
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let role = SysRoleActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(role_code),
+            domain: Set(domain.code.clone()),
+            name: Set(role_name),
+            description: Set(template.description.clone()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_by: Set(audit.created_by),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+        Self::sync_role_routes(&txn, &role.id, &domain.code, &route_ids)
+            .await
+            .map_err(AppError::from)?;
+        self.sync_role_permissions(
+            &role.code,
+            &domain.code,
+            endpoints.into_values().collect(),
+            enforcer,
+        )
+        .await?;
+        txn.commit().await.map_err(AppError::from)?;
+        notify_menu_assignment_changed(Some(domain.code.clone()), vec![role.code.clone()]).await;
+
+        info!(
+            "Created role from template: role={}, domain={}, template={}, unresolved={}",
+            role.code,
+            domain.code,
+            template.name,
+            unresolved_endpoints.len() + unresolved_menus.len()
+        );
+
+        Ok(RoleFromTemplateOutput { role, unresolved_endpoints, unresolved_menus })
+    }
+
+    async fn preview_routes(
+        &self,
+        role_ids: Vec<RoleId>,
+        domain_code: DomainCode,
+        operator_domain: &str,
+    ) -> Result<RoutePreviewOutput, AppError> {
+        let db = self.db().await?;
+        validate_ids_not_empty(&role_ids, "role_ids")?;
+
+        // 非内置域用户只能预览本域的角色
+        if operator_domain != BUILT_IN_DOMAIN_CODE && domain_code.as_str() != operator_domain {
+            return Err(AuthorizationError::permission_denied(format!(
+                "Cannot preview routes in domain '{}'",
+                domain_code
+            ))
+            .into());
+        }
+
+        let role_ids: BTreeSet<String> = role_ids.into_iter().map(String::from).collect();
+        let roles = SysRole::find()
+            .filter(SysRoleColumn::Id.is_in(role_ids.iter().cloned()))
+            .filter(SysRoleColumn::Domain.eq(domain_code.as_str()))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let found: BTreeSet<&str> = roles.iter().map(|role| role.id.as_str()).collect();
+        if let Some(missing) = role_ids.iter().find(|id| !found.contains(id.as_str())) {
+            return Err(AuthorizationError::role_not_found(missing.clone(), "".to_string()).into());
+        }
+
+        let domain = domain_code.as_str();
+        let role_codes: Vec<String> = roles.iter().map(|role| role.code.clone()).collect();
+        let route = SysAuthService::load_user_routes(db.as_ref(), &role_codes, domain).await?;
+
+        let menu_ids = SysAuthService::find_role_menu_ids(db.as_ref(), &role_codes, domain).await?;
+        let mut disabled_menu_ids: Vec<i32> = SysMenu::find()
+            .filter(SysMenuColumn::Id.is_in(menu_ids))
+            .filter(SysMenuColumn::Status.ne(Status::Enabled))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|menu| menu.id)
+            .collect();
+        disabled_menu_ids.sort_unstable();
+
+        Ok(RoutePreviewOutput { route, disabled_menu_ids })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_casbin::casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use sea_orm::{ActiveModelTrait, Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        prelude::{SysRoleTemplate, SysSecurityEvent},
+        sea_orm_active_enums::{MenuType, Status},
+        sys_domain::ActiveModel as SysDomainActiveModel,
+        sys_endpoint::ActiveModel as SysEndpointActiveModel,
+        sys_menu::ActiveModel as SysMenuActiveModel,
+        sys_role_menu::Model as SysRoleMenuModel,
+        sys_role_template::ActiveModel as SysRoleTemplateActiveModel,
+        sys_user::ActiveModel as SysUserActiveModel,
+    };
+    use server_core::web::error::StatusCode;
+    use server_model::admin::timestamps;
+
+    use super::*;
+    use crate::admin::errors::sys_authorization_error::{
+        ERROR_ENDPOINTS_NOT_HELD, ERROR_PERMISSION_DENIED, ERROR_ROUTES_NOT_HELD,
+        ERROR_SELECTORS_MATCHED_NOTHING,
+    };
+    use crate::admin::sys_auth_service::TAuthService;
+    use crate::helper::user_route_cache::UserRouteCache;
+
+    fn endpoint(path: &str, method: &str) -> EndpointDescriptor {
+        EndpointDescriptor {
+            path: path.to_string(),
+            method: method.to_string(),
+        }
+    }
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        create_menus(&db).await;
+        db
+    }
+
+    async fn create_menus(db: &DatabaseConnection) {
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysMenu))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleMenu)))
+            .await
+            .unwrap();
+
+        for id in 1..=3 {
+            SysMenuActiveModel {
+                id: Set(id),
+                menu_type: Set(MenuType::Menu),
+                menu_name: Set(format!("menu-{}", id)),
+                route_name: Set(format!("route-{}", id)),
+                route_path: Set(format!("/route-{}", id)),
+                component: Set("layout.base".to_string()),
+                status: Set(Status::Enabled),
+                pid: Set("0".to_string()),
+                sequence: Set(id),
+                constant: Set(false),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+    }
+
+    async fn setup_user_role_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysSecurityEvent)))
+            .await
+            .unwrap();
+
+        SysDomainActiveModel {
+            id: Set("d1".to_string()),
+            code: Set("built-in".to_string()),
+            name: Set("built-in".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysRoleActiveModel {
+            id: Set("r1".to_string()),
+            code: Set("ROLE_A".to_string()),
+            domain: Set("built-in".to_string()),
+            name: Set("Role A".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        for id in ["u1", "u2"] {
+            SysUserActiveModel {
+                id: Set(id.to_string()),
+                username: Set(id.to_string()),
+                password: Set("secret".to_string()),
+                domain: Set("built-in".to_string()),
+                built_in: Set(false),
+                nick_name: Set(id.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    #[test]
+    fn test_diff() {
+        let current: BTreeSet<_> = [endpoint("/user", "GET"), endpoint("/user", "POST")].into();
+        let target: BTreeSet<_> = [endpoint("/user", "GET"), endpoint("/role", "GET")].into();
+
+        let (to_add, to_remove) = diff(&current, &target);
+        assert_eq!(to_add, vec![endpoint("/role", "GET")]);
+        assert_eq!(to_remove, vec![endpoint("/user", "POST")]);
+    }
+
+    #[test]
+    fn test_document_version() {
+        assert!(validate_document_version(ROLE_PERMISSION_DOCUMENT_VERSION).is_ok());
+        assert!(validate_document_version(0).is_err());
+        assert!(validate_document_version(ROLE_PERMISSION_DOCUMENT_VERSION + 1).is_err());
+
+        // 缺少的列表按空处理，未知字段被忽略
+        let document: RolePermissionDocument = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "roleCode": "R_ADMIN",
+            "domainCode": "built-in",
+            "menus": ["home"],
+            "comment": "exported from staging"
+        }))
+        .unwrap();
+        assert!(document.endpoints.is_empty());
+        assert_eq!(document.menus, vec!["home".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_role_routes_by_route_name() {
+        let db = setup_db().await;
+        SysAuthorizationService::sync_role_routes(&db, "r1", "built-in", &[1, 2]).await.unwrap();
+        SysAuthorizationService::sync_role_routes(&db, "r1", "other", &[3]).await.unwrap();
+
+        let wanted: BTreeSet<String> =
+            ["route-2".to_string(), "route-3".to_string(), "missing".to_string()].into();
+        let menus = SysAuthorizationService::resolve_menus(&db, &wanted).await.unwrap();
+        assert_eq!(menus.len(), 2);
+
+        let route_ids: Vec<i32> = menus.into_values().collect();
+        SysAuthorizationService::sync_role_routes(&db, "r1", "built-in", &route_ids)
+            .await
+            .unwrap();
+
+        let names = SysAuthorizationService::find_role_menu_names(&db, "r1", "built-in")
+            .await
+            .unwrap();
+        assert_eq!(names, ["route-2".to_string(), "route-3".to_string()].into());
+
+        // 其他域的菜单不受影响
+        let other: Vec<SysRoleMenuModel> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::Domain.eq("other"))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(other.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_role_permissions_uses_document_domain() {
+        let db = setup_user_role_db().await;
+        create_menus(&db).await;
+        SysDomainActiveModel {
+            id: Set("d2".to_string()),
+            code: Set("tenant-a".to_string()),
+            name: Set("tenant-a".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysRoleActiveModel {
+            id: Set("r9".to_string()),
+            code: Set("ROLE_A".to_string()),
+            domain: Set("tenant-a".to_string()),
+            name: Set("Role A".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        let service = SysAuthorizationService::new(db);
+        let admin = User::new("u1".to_string(), "u1".to_string(), "built-in".to_string())
+            .with_roles(vec![SUPER_ADMIN_ROLE_CODE.to_string()]);
+
+        // 同代码的角色存在于多个域时只修改文档所在域的角色
+        let document = RolePermissionDocument {
+            version: 1,
+            role_code: "ROLE_A".to_string(),
+            domain_code: "tenant-a".to_string(),
+            endpoints: vec![],
+            menus: vec!["route-1".to_string()],
+        };
+        let output = service
+            .import_role_permissions(
+                ImportRolePermissionInput { document, dry_run: false },
+                &admin,
+                Arc::new(RwLock::new(enforcer)),
+            )
+            .await
+            .unwrap();
+        assert!(output.applied);
+        let db = service.db().await.unwrap();
+        let role_menus = SysRoleMenu::find().all(db.as_ref()).await.unwrap();
+        assert_eq!(role_menus.len(), 1);
+        assert_eq!(role_menus[0].role_id, "r9");
+        assert_eq!(role_menus[0].domain, "tenant-a");
+    }
+
+    #[tokio::test]
+    async fn test_assign_users_updates_enforcer() {
+        let service = SysAuthorizationService::new(setup_user_role_db().await);
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        enforcer
+            .add_policy(vec!["ROLE_A".into(), "built-in".into(), "/user".into(), "read".into()])
+            .await
+            .unwrap();
+        let enforcer = Arc::new(RwLock::new(enforcer));
+        let admin = User::new("u1".to_string(), "u1".to_string(), "built-in".to_string())
+            .with_roles(vec![SUPER_ADMIN_ROLE_CODE.to_string()]);
+        let allowed = |user_id: &'static str| {
+            let enforcer = enforcer.clone();
+            async move {
+                enforcer.read().await.enforce((user_id, "built-in", "/user", "read")).unwrap()
+            }
+        };
+        let assign = |user_ids: &[&str]| {
+            let user_ids = user_ids.iter().map(|id| id.parse().unwrap()).collect();
+            service.assign_users("r1".parse().unwrap(), user_ids, &admin, enforcer.clone())
+        };
+
+        assign(&["u1"]).await.unwrap();
+        assert!(allowed("u1").await);
+        assert!(!allowed("u2").await);
+
+        assign(&["u2"]).await.unwrap();
+        assert!(!allowed("u1").await);
+        assert!(allowed("u2").await);
+
+        let db = service.db().await.unwrap();
+        let user_roles = SysUserRole::find().all(db.as_ref()).await.unwrap();
+        assert_eq!(user_roles.len(), 1);
+        assert_eq!(user_roles[0].user_id, "u2");
+
+        // 每次授予和移除都记录角色变更事件
+        let mut events: Vec<(String, Option<String>)> = SysSecurityEvent::find()
+            .all(db.as_ref())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| (event.event_type, event.user_id))
+            .collect();
+        events.sort();
+        let event = |event_type: &str, user_id: &str| {
+            (event_type.to_string(), Some(user_id.to_string()))
+        };
+        assert_eq!(
+            events,
+            [event("role_granted", "u1"), event("role_granted", "u2"), event("role_revoked", "u1")]
+        );
+
+        // 用户不存在时不修改关联和分组规则
+        assert!(assign(&["u1", "missing"]).await.is_err());
+        assert!(allowed("u2").await);
+        assert!(!allowed("u1").await);
+    }
+
+    #[tokio::test]
+    async fn test_create_role_from_template() {
+        let db = setup_user_role_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(SysMenu),
+            schema.create_table_from_entity(SysRoleMenu),
+            schema.create_table_from_entity(SysEndpoint),
+            schema.create_table_from_entity(SysRoleTemplate),
+        ] {
+            db.execute(backend.build(&statement)).await.unwrap();
+        }
+        SysMenuActiveModel {
+            id: Set(1),
+            menu_type: Set(MenuType::Menu),
+            menu_name: Set("home".to_string()),
+            route_name: Set("home".to_string()),
+            route_path: Set("/home".to_string()),
+            component: Set("layout.base".to_string()),
+            status: Set(Status::Enabled),
+            pid: Set("0".to_string()),
+            sequence: Set(1),
+            constant: Set(false),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysEndpointActiveModel {
+            id: Set("e1".to_string()),
+            path: Set("/api/v1/user/".to_string()),
+            method: Set("GET".to_string()),
+            action: Set("read".to_string()),
+            resource: Set("api".to_string()),
+            controller: Set("SysUserApi".to_string()),
+            created_at: Set(timestamps::now()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        SysRoleTemplateActiveModel {
+            id: Set("t1".to_string()),
+            name: Set("viewer".to_string()),
+            permissions: Set(serde_json::json!({
+                "endpoints": [
+                    {"path": "/api/v1/user/", "method": "GET"},
+                    {"path": "/api/v1/missing", "method": "GET"}
+                ],
+                "menus": ["home", "missing"]
+            })),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let service = SysAuthorizationService::new(db);
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        let enforcer = Arc::new(RwLock::new(enforcer));
+        let admin = User::new("u1".to_string(), "u1".to_string(), "built-in".to_string())
+            .with_roles(vec![SUPER_ADMIN_ROLE_CODE.to_string()]);
+        let tenant = User::new("u2".to_string(), "u2".to_string(), "tenant-a".to_string());
+        let input = |role_code: &str| CreateRoleFromTemplateInput {
+            template_id: "t1".to_string(),
+            domain_code: "built-in".parse().unwrap(),
+            role_code: role_code.to_string(),
+            role_name: "Viewer".to_string(),
+        };
+
+        let output = service
+            .create_role_from_template(input("ROLE_VIEWER"), &admin, enforcer.clone())
+            .await
+            .unwrap();
+        assert_eq!(output.role.domain, "built-in");
+        assert_eq!(output.unresolved_endpoints, vec![endpoint("/api/v1/missing", "GET")]);
+        assert_eq!(output.unresolved_menus, vec!["missing".to_string()]);
+        let policy = ["ROLE_VIEWER", "built-in", "/api/v1/user/", "read"].map(String::from);
+        assert!(enforcer.read().await.has_policy(policy.to_vec()));
+        let db = service.db().await.unwrap();
+        let menus = SysAuthorizationService::find_role_menu_names(
+            db.as_ref(),
+            &output.role.id,
+            "built-in",
+        )
+        .await
+        .unwrap();
+        assert_eq!(menus, ["home".to_string()].into());
+
+        // 角色编码重复或跨域创建时不创建角色
+        assert!(service
+            .create_role_from_template(input("ROLE_VIEWER"), &admin, enforcer.clone())
+            .await
+            .is_err());
+        assert!(service
+            .create_role_from_template(input("ROLE_OTHER"), &tenant, enforcer.clone())
+            .await
+            .is_err());
+        let roles = SysRole::find().all(db.as_ref()).await.unwrap();
+        assert_eq!(roles.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_assign_permissions_expands_selectors() {
+        let db = setup_user_role_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysEndpoint))).await.unwrap();
+        for (id, path, method, action, resource, controller) in [
+            ("e1", "/api/v1/user", "GET", "read", "user", "SysUserApi"),
+            ("e2", "/api/v1/user", "POST", "create", "user", "SysUserApi"),
+            ("e3", "/api/v1/user/{id}", "DELETE", "delete", "user", "SysUserApi"),
+            ("e4", "/api/v1/role", "GET", "read", "role", "SysRoleApi"),
+            ("e5", "/api/v1/menu", "GET", "read", "menu", "SysMenuApi"),
+        ] {
+            SysEndpointActiveModel {
+                id: Set(id.to_string()),
+                path: Set(path.to_string()),
+                method: Set(method.to_string()),
+                action: Set(action.to_string()),
+                resource: Set(resource.to_string()),
+                controller: Set(controller.to_string()),
+                created_at: Set(timestamps::now()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let service = SysAuthorizationService::new(db);
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        let enforcer = Arc::new(RwLock::new(enforcer));
+        let admin = User::new("u1".to_string(), "u1".to_string(), "built-in".to_string())
+            .with_roles(vec![SUPER_ADMIN_ROLE_CODE.to_string()]);
+        let selector = |controllers: &[&str], resources: &[&str], methods: &[&str]| {
+            let values = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+            EndpointSelector {
+                controllers: values(controllers),
+                resources: values(resources),
+                methods: values(methods),
+            }
+        };
+
+        // 选择器之间以及与显式ID重叠的接口只授予一次
+        let result = service
+            .assign_permissions(
+                "built-in".parse().unwrap(),
+                "r1".parse().unwrap(),
+                vec!["e1".to_string(), "e4".to_string()],
+                vec![
+                    selector(&["SysUserApi"], &[], &[]),
+                    selector(&[], &["user", "role"], &["get"]),
+                ],
+                &admin,
+                enforcer.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.endpoint_count, 4);
+        let matched: Vec<usize> = result.selectors.iter().map(|m| m.matched).collect();
+        assert_eq!(matched, vec![3, 2]);
+        let mut policies = enforcer.read().await.get_filtered_policy(0, vec!["ROLE_A".into()]);
+        policies.sort();
+        let actions: Vec<(String, String)> =
+            policies.into_iter().map(|p| (p[2].clone(), p[3].clone())).collect();
+        assert_eq!(
+            actions,
+            vec![
+                ("/api/v1/role".to_string(), "read".to_string()),
+                ("/api/v1/user".to_string(), "create".to_string()),
+                ("/api/v1/user".to_string(), "read".to_string()),
+                ("/api/v1/user/{id}".to_string(), "delete".to_string()),
+            ]
+        );
+
+        // 未匹配任何接口的选择器返回 422，且不修改已有策略
+        let err = service
+            .assign_permissions(
+                "built-in".parse().unwrap(),
+                "r1".parse().unwrap(),
+                vec![],
+                vec![selector(&["SysMenuApi"], &[], &[]), selector(&["SysMissingApi"], &[], &[])],
+                &admin,
+                enforcer.clone(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ERROR_SELECTORS_MATCHED_NOTHING);
+        assert_eq!(err.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(err.message.contains("controllers=[SysMissingApi]"));
+        assert!(!err.message.contains("SysMenuApi"));
+        assert_eq!(enforcer.read().await.get_filtered_policy(0, vec!["ROLE_A".into()]).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_assign_rejects_privilege_escalation() {
+        let db = setup_user_role_db().await;
+        create_menus(&db).await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysEndpoint))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleTemplate)))
+            .await
+            .unwrap();
+        for (id, path, method, action) in [
+            ("e1", "/api/v1/user", "GET", "read"),
+            ("e2", "/api/v1/user", "POST", "write"),
+            ("e3", "/api/v1/role", "GET", "read"),
+            ("e4", "/api/v1/role", "POST", "write"),
+            ("e5", "/api/v1/menu", "GET", "read"),
+        ] {
+            SysEndpointActiveModel {
+                id: Set(id.to_string()),
+                path: Set(path.to_string()),
+                method: Set(method.to_string()),
+                action: Set(action.to_string()),
+                resource: Set("test".to_string()),
+                controller: Set("TestApi".to_string()),
+                created_at: Set(timestamps::now()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        SysRoleActiveModel {
+            id: Set("r2".to_string()),
+            code: Set("ROLE_B".to_string()),
+            domain: Set("built-in".to_string()),
+            name: Set("Role B".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
         .await
+        .unwrap();
+        SysAuthorizationService::sync_role_routes(&db, "r2", "built-in", &[1, 2]).await.unwrap();
+
+        // 当前用户 u2 通过 ROLE_B 持有用户接口的写权限、角色接口的读权限以及菜单1、2
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        for (path, action) in [("/api/v1/user", "write"), ("/api/v1/role", "read")] {
+            enforcer
+                .add_policy(vec!["ROLE_B".into(), "built-in".into(), path.into(), action.into()])
+                .await
+                .unwrap();
+        }
+        enforcer
+            .add_grouping_policy(vec!["u2".into(), "ROLE_B".into(), "built-in".into()])
+            .await
+            .unwrap();
+        let enforcer = Arc::new(RwLock::new(enforcer));
+        let service = SysAuthorizationService::new(db);
+        let actor = User::new("u2".to_string(), "u2".to_string(), "built-in".to_string())
+            .with_roles(vec!["ROLE_B".to_string()]);
+        let admin = User::new("u1".to_string(), "u1".to_string(), "built-in".to_string())
+            .with_roles(vec![SUPER_ADMIN_ROLE_CODE.to_string()]);
+        let assign_permissions = |ids: &[&str], actor: &User| {
+            let ids = ids.iter().map(|id| id.to_string()).collect();
+            let (service, actor, enforcer) = (service.clone(), actor.clone(), enforcer.clone());
+            async move {
+                service
+                    .assign_permissions(
+                        "built-in".parse().unwrap(),
+                        "r1".parse().unwrap(),
+                        ids,
+                        vec![],
+                        &actor,
+                        enforcer,
+                    )
+                    .await
+            }
+        };
+        let assign_routes = |ids: &[i32], actor: &User| {
+            let ids = ids.iter().map(|id| MenuId::try_from(*id).unwrap()).collect();
+            let (service, actor, enforcer) = (service.clone(), actor.clone(), enforcer.clone());
+            async move {
+                service
+                    .assign_routes(
+                        "built-in".parse().unwrap(),
+                        "r1".parse().unwrap(),
+                        ids,
+                        &actor,
+                        enforcer,
+                    )
+                    .await
+            }
+        };
+        let role_a_policies = || {
+            let enforcer = enforcer.clone();
+            async move { enforcer.read().await.get_filtered_policy(0, vec!["ROLE_A".into()]).len() }
+        };
+
+        // 写权限覆盖同一路径的读接口
+        let result = assign_permissions(&["e1", "e2", "e3"], &actor).await.unwrap();
+        assert_eq!(result.endpoint_count, 3);
+        assert_eq!(role_a_policies().await, 3);
+
+        // 包含未持有的接口时返回 403 并列出这些接口，角色权限不做修改
+        let err = assign_permissions(&["e1", "e4", "e5"], &actor).await.unwrap_err();
+        assert_eq!(err.code, ERROR_ENDPOINTS_NOT_HELD);
+        assert_eq!(err.status, StatusCode::FORBIDDEN);
+        assert!(err.message.contains("[\"e4\", \"e5\"]"), "{}", err.message);
+        assert_eq!(role_a_policies().await, 3);
+
+        // 在目标域中没有角色的用户不能分配任何接口
+        let outsider = User::new("u3".to_string(), "u3".to_string(), "built-in".to_string());
+        let err = assign_permissions(&["e1"], &outsider).await.unwrap_err();
+        assert_eq!(err.code, ERROR_ENDPOINTS_NOT_HELD);
+
+        assign_routes(&[1, 2], &actor).await.unwrap();
+        let err = assign_routes(&[2, 3], &actor).await.unwrap_err();
+        assert_eq!(err.code, ERROR_ROUTES_NOT_HELD);
+        assert_eq!(err.status, StatusCode::FORBIDDEN);
+        assert!(err.message.contains("[3]"), "{}", err.message);
+
+        // 内置域超级管理员不受限制
+        assign_permissions(&["e4", "e5"], &admin).await.unwrap();
+        assert_eq!(role_a_policies().await, 2);
+        assign_routes(&[3], &admin).await.unwrap();
+
+        // 分配用户即授予角色的全部权限，角色 ROLE_A 包含未持有的接口
+        let assign_users = |role_id: &str, user_ids: &[&str], actor: &User| {
+            let role_id = role_id.parse().unwrap();
+            let user_ids = user_ids.iter().map(|id| id.parse().unwrap()).collect();
+            let (service, actor, enforcer) = (service.clone(), actor.clone(), enforcer.clone());
+            async move { service.assign_users(role_id, user_ids, &actor, enforcer).await }
+        };
+        let err = assign_users("r1", &["u2"], &actor).await.unwrap_err();
+        assert_eq!(err.code, ERROR_ENDPOINTS_NOT_HELD);
+        assert!(!enforcer.read().await.has_grouping_policy(
+            ["u2", "ROLE_A", "built-in"].map(String::from).to_vec()
+        ));
+        assign_users("r2", &["u1", "u2"], &actor).await.unwrap();
+
+        // 导入文档同样只能授予持有的接口和路由，预览时也检查
+        let import = |endpoints: &[(&str, &str)], menus: &[&str], domain: &str, dry_run: bool| {
+            let document = RolePermissionDocument {
+                version: ROLE_PERMISSION_DOCUMENT_VERSION,
+                role_code: "ROLE_A".to_string(),
+                domain_code: domain.to_string(),
+                endpoints: endpoints.iter().map(|(path, method)| endpoint(path, method)).collect(),
+                menus: menus.iter().map(|name| name.to_string()).collect(),
+            };
+            let (service, actor, enforcer) = (service.clone(), actor.clone(), enforcer.clone());
+            async move {
+                let input = ImportRolePermissionInput { document, dry_run };
+                service.import_role_permissions(input, &actor, enforcer).await
+            }
+        };
+        for dry_run in [true, false] {
+            let err = import(&[("/api/v1/menu", "GET")], &[], "built-in", dry_run)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code, ERROR_ENDPOINTS_NOT_HELD);
+        }
+        let err = import(&[("/api/v1/user", "GET")], &["route-3"], "built-in", false)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ERROR_ROUTES_NOT_HELD);
+        assert_eq!(role_a_policies().await, 2);
+
+        // 非内置域超级管理员不能导入其他域的角色
+        let err = import(&[], &[], "tenant-a", true).await.unwrap_err();
+        assert_eq!(err.code, ERROR_PERMISSION_DENIED);
+        assert_eq!(err.status, StatusCode::FORBIDDEN);
+
+        let output = import(&[("/api/v1/user", "GET")], &["route-1"], "built-in", false)
+            .await
+            .unwrap();
+        assert!(output.applied);
+        assert_eq!(role_a_policies().await, 1);
+
+        // 模板中包含未持有的接口时不创建角色
+        SysRoleTemplateActiveModel {
+            id: Set("t1".to_string()),
+            name: Set("menu-viewer".to_string()),
+            permissions: Set(serde_json::json!({
+                "endpoints": [{"path": "/api/v1/menu", "method": "GET"}],
+                "menus": ["route-1"]
+            })),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(service.db().await.unwrap().as_ref())
+        .await
+        .unwrap();
+        let input = CreateRoleFromTemplateInput {
+            template_id: "t1".to_string(),
+            domain_code: "built-in".parse().unwrap(),
+            role_code: "ROLE_MENU".to_string(),
+            role_name: "Menu viewer".to_string(),
+        };
+        let err = service
+            .create_role_from_template(input, &actor, enforcer.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ERROR_ENDPOINTS_NOT_HELD);
+        let db = service.db().await.unwrap();
+        let created = SysRole::find()
+            .filter(SysRoleColumn::Code.eq("ROLE_MENU"))
+            .one(db.as_ref())
+            .await
+            .unwrap();
+        assert!(created.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_preview_routes_matches_login_routes() {
+        let db = setup_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        for (id, code, domain) in [
+            ("r1", "ROLE_A", "built-in"),
+            ("r2", "ROLE_B", "built-in"),
+            ("r3", "ROLE_C", "tenant-a"),
+        ] {
+            SysRoleActiveModel {
+                id: Set(id.to_string()),
+                code: Set(code.to_string()),
+                domain: Set(domain.to_string()),
+                name: Set(code.to_string()),
+                pid: Set("0".to_string()),
+                status: Set(Status::Enabled),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        SysMenuActiveModel {
+            id: Set(3),
+            status: Set(Status::Disabled),
+            ..Default::default()
+        }
+        .update(&db)
+        .await
+        .unwrap();
+        SysAuthorizationService::sync_role_routes(&db, "r1", "built-in", &[1, 3]).await.unwrap();
+        SysAuthorizationService::sync_role_routes(&db, "r2", "built-in", &[2]).await.unwrap();
+
+        let service = SysAuthorizationService::new(db);
+        let preview = |role_ids: &[&str], operator_domain: &'static str| {
+            let role_ids = role_ids.iter().map(|id| id.parse().unwrap()).collect();
+            service.preview_routes(role_ids, "built-in".parse().unwrap(), operator_domain)
+        };
+
+        // 与拥有相同角色的用户登录后获取的路由一致
+        let output = preview(&["r1", "r2"], "built-in").await.unwrap();
+        let cache = Arc::new(UserRouteCache::new(10, std::time::Duration::from_secs(60)));
+        let login = SysAuthService::default()
+            .with_route_cache(cache)
+            .get_user_routes(
+                service.db().await.unwrap(),
+                &["ROLE_A".to_string(), "ROLE_B".to_string()],
+                "built-in",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&output.route).unwrap(),
+            serde_json::to_value(&login).unwrap()
+        );
+        assert_eq!(output.route.routes.len(), 2);
+        assert_eq!(output.disabled_menu_ids, vec![3]);
+
+        // 角色不存在、不属于目标域或跨域预览时拒绝
+        assert!(preview(&["r1", "missing"], "built-in").await.is_err());
+        assert!(preview(&["r3"], "built-in").await.is_err());
+        assert!(preview(&["r1"], "tenant-a").await.is_err());
+        assert!(preview(&[], "built-in").await.is_err());
     }
 }
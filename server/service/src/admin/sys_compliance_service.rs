@@ -0,0 +1,741 @@
+/**
+ * 合规审计导出服务模块
+ *
+ * 该模块提供了按用户导出审计数据的功能，包括：
+ * - 创建导出任务，任务放入导出事件通道后立即返回
+ * - 查询导出任务状态，完成后返回导出文件的预签名下载链接
+ * - 导出监听器，逐个执行导出任务
+ *
+ * 导出文件为zip压缩包，包含以下NDJSON文件和 `manifest.json`：
+ * - login_logs.ndjson: 登录日志（`sys_login_log`）
+ * - operation_logs.ndjson: 用户执行的操作（`sys_operation_log`）
+ * - security_events.ndjson: 认证与授权相关的安全事件（`sys_security_event`），
+ *   包括异常登录和角色的授予、移除（`role_granted`、`role_revoked`）
+ * - tokens.ndjson: 登录令牌签发记录（`sys_tokens`），不包含令牌内容
+ *
+ * 记录从只读连接分页读取，每页序列化后经有界通道交给阻塞线程写入临时文件中的压缩包，
+ * 再从文件上传到主对象存储的 `compliance/` 前缀下。压缩和文件写入不占用异步运行时的线程，
+ * 导出过程不在内存中保存全部记录。任务状态保存在内存中，
+ * 保留7天，服务重启后清空。
+ *
+ * 主要组件
+ * --------
+ * - TComplianceService: 合规审计导出服务 trait
+ * - SysComplianceService: 合规审计导出服务实现
+ * - compliance_export_listener: 导出事件监听器
+ */
+
+use std::{
+    any::Any,
+    convert::identity,
+    fs::File,
+    io::{Seek, Write},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Select,
+};
+use tokio::sync::mpsc;
+use serde::Serialize;
+use serde_json::json;
+use server_constant::definition::consts::SystemEvent;
+use server_global::{
+    global::{self, EventReceiver, GLOBAL_PRIMARY_OBJECT_STORE},
+    project_error, project_info,
+};
+use server_model::admin::{
+    entities::{
+        prelude::{SysLoginLog, SysOperationLog, SysSecurityEvent, SysTokens},
+        sys_login_log::Column as SysLoginLogColumn,
+        sys_operation_log::Column as SysOperationLogColumn,
+        sys_security_event::Column as SysSecurityEventColumn,
+        sys_tokens::{Column as SysTokensColumn, Model as SysTokensModel},
+    },
+    input::ComplianceExportInput,
+    output::{ComplianceExportOutput, ComplianceExportStatus, ComplianceRecordCounts},
+    timestamps,
+};
+use server_storage::PutOptions;
+use ulid::Ulid;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    admin::{
+        errors::sys_compliance_error::ComplianceError, sys_domain_service::BUILT_IN_DOMAIN_CODE,
+    },
+    helper::db_helper,
+};
+
+/** 导出文件的对象键前缀 */
+const COMPLIANCE_EXPORT_PREFIX: &str = "compliance";
+
+/** 每次从数据库读取的记录数 */
+const EXPORT_PAGE_SIZE: u64 = 1000;
+
+/** 等待写入压缩包的分页数，限制读取快于写入时占用的内存 */
+const BUNDLE_CHANNEL_CAPACITY: usize = 4;
+
+/** 下载链接有效期 */
+const DOWNLOAD_URL_EXPIRES_IN: Duration = Duration::from_secs(60 * 60);
+
+/** 导出任务状态的保留时间 */
+const EXPORT_JOB_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/** 导出任务状态，按任务ID保存 */
+static EXPORT_JOBS: Lazy<Cache<String, ExportJob>> =
+    Lazy::new(|| Cache::builder().time_to_live(EXPORT_JOB_TTL).build());
+
+/**
+ * 导出任务
+ */
+#[derive(Clone, Debug)]
+struct ExportJob {
+    output: ComplianceExportOutput,
+    /** 导出文件的对象键，任务完成后设置 */
+    object_key: Option<String>,
+}
+
+/**
+ * 导出请求事件
+ */
+#[derive(Clone, Debug)]
+struct ComplianceExportRequest {
+    job_id: String,
+    user_id: String,
+    start_time: NaiveDateTime,
+    end_time: NaiveDateTime,
+    requested_by: String,
+}
+
+/**
+ * 导出文件中的登录令牌签发记录
+ *
+ * 不包含访问令牌和刷新令牌
+ */
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenIssuance {
+    id: String,
+    status: String,
+    user_id: String,
+    username: String,
+    domain: String,
+    #[serde(with = "timestamps::utc")]
+    login_time: NaiveDateTime,
+    ip: String,
+    address: String,
+    user_agent: String,
+    request_id: String,
+    login_type: String,
+    jti: Option<String>,
+    #[serde(with = "timestamps::utc")]
+    created_at: NaiveDateTime,
+    #[serde(with = "timestamps::utc::option")]
+    expires_at: Option<NaiveDateTime>,
+    #[serde(with = "timestamps::utc::option")]
+    last_seen_at: Option<NaiveDateTime>,
+}
+
+impl From<SysTokensModel> for TokenIssuance {
+    fn from(token: SysTokensModel) -> Self {
+        Self {
+            id: token.id,
+            status: token.status,
+            user_id: token.user_id,
+            username: token.username,
+            domain: token.domain,
+            login_time: token.login_time,
+            ip: token.ip,
+            address: token.address,
+            user_agent: token.user_agent,
+            request_id: token.request_id,
+            login_type: token.r#type,
+            jti: token.jti,
+            created_at: token.created_at,
+            expires_at: token.expires_at,
+            last_seen_at: token.last_seen_at,
+        }
+    }
+}
+
+/**
+ * 合规审计导出服务 trait
+ */
+#[async_trait]
+pub trait TComplianceService {
+    /**
+     * 创建导出任务
+     *
+     * @param input 导出的用户ID和时间范围
+     * @param operator_domain 操作用户所属域，只有内置域用户可以导出
+     * @param requested_by 操作用户名，记录在任务和导出文件中
+     * @return Result<ComplianceExportOutput, ComplianceError> 等待执行的任务；
+     *         未配置对象存储或导出队列已满时返回503
+     */
+    async fn create_export(
+        &self,
+        input: ComplianceExportInput,
+        operator_domain: &str,
+        requested_by: &str,
+    ) -> Result<ComplianceExportOutput, ComplianceError>;
+
+    /**
+     * 查询导出任务
+     *
+     * @param job_id 任务ID
+     * @param operator_domain 操作用户所属域，只有内置域用户可以查询
+     * @return Result<ComplianceExportOutput, ComplianceError> 任务状态，已完成的任务包含下载链接
+     */
+    async fn get_export(
+        &self,
+        job_id: &str,
+        operator_domain: &str,
+    ) -> Result<ComplianceExportOutput, ComplianceError>;
+}
+
+/**
+ * 合规审计导出服务
+ */
+#[derive(Clone)]
+pub struct SysComplianceService;
+
+/**
+ * 检查操作用户是否属于内置域
+ */
+fn ensure_built_in(operator_domain: &str) -> Result<(), ComplianceError> {
+    if operator_domain == BUILT_IN_DOMAIN_CODE {
+        Ok(())
+    } else {
+        Err(ComplianceError::BuiltInDomainOnly)
+    }
+}
+
+#[async_trait]
+impl TComplianceService for SysComplianceService {
+    async fn create_export(
+        &self,
+        input: ComplianceExportInput,
+        operator_domain: &str,
+        requested_by: &str,
+    ) -> Result<ComplianceExportOutput, ComplianceError> {
+        ensure_built_in(operator_domain)?;
+        if input.start_time >= input.end_time {
+            return Err(ComplianceError::InvalidTimeRange);
+        }
+        if GLOBAL_PRIMARY_OBJECT_STORE.read().await.is_none() {
+            return Err(ComplianceError::StorageNotConfigured);
+        }
+
+        let request = ComplianceExportRequest {
+            job_id: Ulid::new().to_string(),
+            user_id: input.user_id,
+            start_time: input.start_time,
+            end_time: input.end_time,
+            requested_by: requested_by.to_string(),
+        };
+        let output = ComplianceExportOutput {
+            job_id: request.job_id.clone(),
+            status: ComplianceExportStatus::Pending,
+            user_id: request.user_id.clone(),
+            start_time: request.start_time,
+            end_time: request.end_time,
+            requested_by: request.requested_by.clone(),
+            created_at: timestamps::now(),
+            finished_at: None,
+            record_counts: None,
+            download_url: None,
+            error: None,
+        };
+        EXPORT_JOBS.insert(
+            output.job_id.clone(),
+            ExportJob {
+                output: output.clone(),
+                object_key: None,
+            },
+        );
+
+        // 队列已满时不等待，直接拒绝
+        let sender =
+            global::get_dyn_sender(SystemEvent::ComplianceExportRequestedEvent.as_ref()).await;
+        let queued = match sender {
+            Some(tx) => tx.try_send(Box::new(request)).is_ok(),
+            None => false,
+        };
+        if !queued {
+            EXPORT_JOBS.invalidate(&output.job_id);
+            return Err(ComplianceError::QueueFull);
+        }
+        Ok(output)
+    }
+
+    async fn get_export(
+        &self,
+        job_id: &str,
+        operator_domain: &str,
+    ) -> Result<ComplianceExportOutput, ComplianceError> {
+        ensure_built_in(operator_domain)?;
+        let job = EXPORT_JOBS
+            .get(job_id)
+            .ok_or_else(|| ComplianceError::JobNotFound(job_id.to_string()))?;
+
+        let mut output = job.output;
+        if let Some(key) = job.object_key {
+            let store = GLOBAL_PRIMARY_OBJECT_STORE
+                .read()
+                .await
+                .clone()
+                .ok_or(ComplianceError::StorageNotConfigured)?;
+            output.download_url = Some(store.presign(&key, DOWNLOAD_URL_EXPIRES_IN).await?);
+        }
+        Ok(output)
+    }
+}
+
+/**
+ * 更新导出任务状态
+ *
+ * 任务已过期时忽略
+ */
+fn update_job(job_id: &str, update: impl FnOnce(&mut ExportJob)) {
+    if let Some(mut job) = EXPORT_JOBS.get(job_id) {
+        update(&mut job);
+        EXPORT_JOBS.insert(job_id.to_string(), job);
+    }
+}
+
+/**
+ * 生成导出文件的对象键
+ *
+ * 格式为 `compliance/<user_id>/<job_id>.zip`
+ */
+fn export_key(user_id: &str, job_id: &str) -> String {
+    format!("{}/{}/{}.zip", COMPLIANCE_EXPORT_PREFIX, user_id, job_id)
+}
+
+/**
+ * 执行导出任务
+ *
+ * 压缩包写入临时文件，上传后删除
+ *
+ * @return Result<(ComplianceRecordCounts, String), ComplianceError> 各文件的记录数和对象键
+ */
+async fn run_export(
+    request: &ComplianceExportRequest,
+) -> Result<(ComplianceRecordCounts, String), ComplianceError> {
+    let store = GLOBAL_PRIMARY_OBJECT_STORE
+        .read()
+        .await
+        .clone()
+        .ok_or(ComplianceError::StorageNotConfigured)?;
+    let db = db_helper::get_read_connection().await?;
+
+    let path = std::env::temp_dir().join(format!("compliance-export-{}.zip", request.job_id));
+    let result = async {
+        let (counts, _) = write_bundle(db.as_ref(), request, File::create(&path)?).await?;
+        let key = export_key(&request.user_id, &request.job_id);
+        let options = PutOptions {
+            content_type: Some("application/zip".to_string()),
+            content_encoding: None,
+        };
+        store.put_file(&key, &path, options).await?;
+        Ok::<_, ComplianceError>((counts, key))
+    }
+    .await;
+
+    if let Err(e) = tokio::fs::remove_file(&path).await {
+        project_error!("Failed to remove compliance export file {}: {}", path.display(), e);
+    }
+    result
+}
+
+/**
+ * 写入压缩包的内容
+ */
+enum BundleChunk {
+    /** 开始写入压缩包中的文件 */
+    File(&'static str),
+    /** 当前文件的内容 */
+    Data(Vec<u8>),
+}
+
+/**
+ * 写入导出压缩包
+ *
+ * 当前任务读取记录并按页发送，压缩包由阻塞线程写入
+ *
+ * @param db 数据库连接
+ * @param request 导出请求
+ * @param writer 压缩包的写入目标
+ * @return Result<(ComplianceRecordCounts, W), ComplianceError> 各文件的记录数和写入完成的目标
+ */
+async fn write_bundle<W: Write + Seek + Send + 'static>(
+    db: &DatabaseConnection,
+    request: &ComplianceExportRequest,
+    writer: W,
+) -> Result<(ComplianceRecordCounts, W), ComplianceError> {
+    let (tx, rx) = mpsc::channel(BUNDLE_CHANNEL_CAPACITY);
+    let zip_task = tokio::task::spawn_blocking(move || write_zip(writer, rx));
+    let sent = send_bundle(db, request, &tx).await;
+    drop(tx);
+
+    // 写入失败时通道提前关闭，发送端的错误只是结果，优先返回写入错误
+    let writer = zip_task.await.map_err(|e| ComplianceError::ExportFailed(e.to_string()))??;
+    Ok((sent?, writer))
+}
+
+/**
+ * 在阻塞线程中把收到的内容写入压缩包，通道关闭后完成压缩包
+ */
+fn write_zip<W: Write + Seek>(
+    writer: W,
+    mut rx: mpsc::Receiver<BundleChunk>,
+) -> Result<W, ComplianceError> {
+    let mut zip = ZipWriter::new(writer);
+    while let Some(chunk) = rx.blocking_recv() {
+        match chunk {
+            BundleChunk::File(name) => zip.start_file(name, file_options())?,
+            BundleChunk::Data(data) => zip.write_all(&data)?,
+        }
+    }
+    Ok(zip.finish()?)
+}
+
+/**
+ * 读取导出记录并按页发送给压缩包写入线程
+ *
+ * @return Result<ComplianceRecordCounts, ComplianceError> 各文件的记录数
+ */
+async fn send_bundle(
+    db: &DatabaseConnection,
+    request: &ComplianceExportRequest,
+    tx: &mpsc::Sender<BundleChunk>,
+) -> Result<ComplianceRecordCounts, ComplianceError> {
+    let (user_id, start, end) = (request.user_id.as_str(), request.start_time, request.end_time);
+
+    let login_logs = SysLoginLog::find()
+        .filter(SysLoginLogColumn::UserId.eq(user_id))
+        .filter(SysLoginLogColumn::LoginTime.gte(start))
+        .filter(SysLoginLogColumn::LoginTime.lt(end))
+        .order_by_asc(SysLoginLogColumn::LoginTime)
+        .order_by_asc(SysLoginLogColumn::Id);
+    let operation_logs = SysOperationLog::find()
+        .filter(SysOperationLogColumn::UserId.eq(user_id))
+        .filter(SysOperationLogColumn::CreatedAt.gte(start))
+        .filter(SysOperationLogColumn::CreatedAt.lt(end))
+        .order_by_asc(SysOperationLogColumn::CreatedAt)
+        .order_by_asc(SysOperationLogColumn::Id);
+    let security_events = SysSecurityEvent::find()
+        .filter(SysSecurityEventColumn::UserId.eq(user_id))
+        .filter(SysSecurityEventColumn::CreatedAt.gte(start))
+        .filter(SysSecurityEventColumn::CreatedAt.lt(end))
+        .order_by_asc(SysSecurityEventColumn::CreatedAt)
+        .order_by_asc(SysSecurityEventColumn::Id);
+    let tokens = SysTokens::find()
+        .filter(SysTokensColumn::UserId.eq(user_id))
+        .filter(SysTokensColumn::CreatedAt.gte(start))
+        .filter(SysTokensColumn::CreatedAt.lt(end))
+        .order_by_asc(SysTokensColumn::CreatedAt)
+        .order_by_asc(SysTokensColumn::Id);
+
+    let login_logs = send_ndjson(tx, "login_logs.ndjson", db, login_logs, identity).await?;
+    let operation_logs =
+        send_ndjson(tx, "operation_logs.ndjson", db, operation_logs, identity).await?;
+    let security_events =
+        send_ndjson(tx, "security_events.ndjson", db, security_events, identity).await?;
+    let tokens = send_ndjson(tx, "tokens.ndjson", db, tokens, TokenIssuance::from).await?;
+    let counts = ComplianceRecordCounts {
+        login_logs,
+        operation_logs,
+        security_events,
+        tokens,
+    };
+
+    let manifest = json!({
+        "jobId": request.job_id,
+        "userId": request.user_id,
+        "startTime": timestamps::utc::format(&request.start_time),
+        "endTime": timestamps::utc::format(&request.end_time),
+        "requestedBy": request.requested_by,
+        "generatedAt": timestamps::utc::format(&timestamps::now()),
+        "recordCounts": counts,
+    });
+    send_chunk(tx, BundleChunk::File("manifest.json")).await?;
+    send_chunk(tx, BundleChunk::Data(serde_json::to_vec_pretty(&manifest)?)).await?;
+    Ok(counts)
+}
+
+/**
+ * 压缩包中文件的选项
+ *
+ * 启用ZIP64，单个文件可以超过4GiB
+ */
+fn file_options() -> SimpleFileOptions {
+    SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true)
+}
+
+/**
+ * 发送压缩包内容，写入线程已退出时返回错误
+ */
+async fn send_chunk(
+    tx: &mpsc::Sender<BundleChunk>,
+    chunk: BundleChunk,
+) -> Result<(), ComplianceError> {
+    tx.send(chunk)
+        .await
+        .map_err(|_| ComplianceError::ExportFailed("Export file writer stopped".to_string()))
+}
+
+/**
+ * 分页读取记录，按页序列化为NDJSON后发送
+ *
+ * @param tx 压缩包内容的发送端
+ * @param name 文件名
+ * @param db 数据库连接
+ * @param select 查询，需要按稳定的顺序排序
+ * @param to_entry 把记录转换为写入文件的结构
+ * @return Result<u64, ComplianceError> 写入的记录数
+ */
+async fn send_ndjson<E, T>(
+    tx: &mpsc::Sender<BundleChunk>,
+    name: &'static str,
+    db: &DatabaseConnection,
+    select: Select<E>,
+    to_entry: fn(E::Model) -> T,
+) -> Result<u64, ComplianceError>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+    T: Serialize,
+{
+    send_chunk(tx, BundleChunk::File(name)).await?;
+    let mut pages = select.paginate(db, EXPORT_PAGE_SIZE);
+    let mut count = 0;
+    while let Some(records) = pages.fetch_and_next().await? {
+        let mut page = Vec::new();
+        for record in records {
+            serde_json::to_writer(&mut page, &to_entry(record))?;
+            page.push(b'\n');
+            count += 1;
+        }
+        send_chunk(tx, BundleChunk::Data(page)).await?;
+    }
+    Ok(count)
+}
+
+/**
+ * 合规审计导出监听器
+ *
+ * 按入队顺序逐个执行导出任务，失败原因记录在任务状态中
+ *
+ * @param rx 事件接收器
+ */
+pub async fn compliance_export_listener(mut rx: EventReceiver<Box<dyn Any + Send>>) {
+    while let Some(event) = rx.recv().await {
+        let Some(request) = event.downcast_ref::<ComplianceExportRequest>() else {
+            project_error!("Received unknown event type in compliance export listener");
+            continue;
+        };
+
+        update_job(&request.job_id, |job| job.output.status = ComplianceExportStatus::Running);
+        let result = run_export(request).await;
+        update_job(&request.job_id, |job| {
+            job.output.finished_at = Some(timestamps::now());
+            match result {
+                Ok((counts, key)) => {
+                    project_info!(
+                        "Compliance export {} for user {} completed",
+                        request.job_id,
+                        request.user_id
+                    );
+                    job.output.status = ComplianceExportStatus::Completed;
+                    job.output.record_counts = Some(counts);
+                    job.object_key = Some(key);
+                },
+                Err(e) => {
+                    project_error!("Compliance export {} failed: {}", request.job_id, e);
+                    job.output.status = ComplianceExportStatus::Failed;
+                    job.output.error = Some(e.to_string());
+                },
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use chrono::NaiveDate;
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, IntoActiveModel, Schema};
+    use server_model::admin::entities::{
+        sys_login_log::Model as SysLoginLogModel,
+        sys_security_event::Model as SysSecurityEventModel,
+    };
+    use zip::ZipArchive;
+
+    use super::*;
+
+    fn time(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(SysLoginLog),
+            schema.create_table_from_entity(SysOperationLog),
+            schema.create_table_from_entity(SysSecurityEvent),
+            schema.create_table_from_entity(SysTokens),
+        ] {
+            db.execute(backend.build(&statement)).await.unwrap();
+        }
+
+        // 第1天和第2天在导出范围内，第5天不在
+        for (i, (user_id, day)) in [("u1", 1), ("u1", 2), ("u2", 1), ("u1", 5)].iter().enumerate() {
+            SysLoginLogModel {
+                id: format!("login-{}", i),
+                user_id: user_id.to_string(),
+                username: user_id.to_string(),
+                domain: "built-in".to_string(),
+                login_time: time(*day),
+                ip: "127.0.0.1".to_string(),
+                port: None,
+                address: "".to_string(),
+                user_agent: "test".to_string(),
+                request_id: format!("req-{}", i),
+                r#type: "PC".to_string(),
+                created_at: time(*day),
+                created_by: user_id.to_string(),
+                success: true,
+                failure_reason: None,
+            }
+            .into_active_model()
+            .insert(&db)
+            .await
+            .unwrap();
+
+            SysTokensModel {
+                id: format!("token-{}", i),
+                access_token: format!("access-token-{}", i),
+                refresh_token: format!("refresh-token-{}", i),
+                status: "active".to_string(),
+                user_id: user_id.to_string(),
+                username: user_id.to_string(),
+                domain: "built-in".to_string(),
+                login_time: time(*day),
+                ip: "127.0.0.1".to_string(),
+                port: None,
+                address: "".to_string(),
+                user_agent: "test".to_string(),
+                request_id: format!("req-{}", i),
+                r#type: "PC".to_string(),
+                created_at: time(*day),
+                created_by: user_id.to_string(),
+                jti: None,
+                expires_at: None,
+                last_seen_at: None,
+            }
+            .into_active_model()
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let events = [("event-1", "unusual_login", 2), ("event-2", "role_granted", 3)];
+        for (id, event_type, day) in events {
+            SysSecurityEventModel {
+                id: id.to_string(),
+                event_type: event_type.to_string(),
+                ip: "127.0.0.1".to_string(),
+                key_prefix: None,
+                path: "/auth/login".to_string(),
+                user_id: Some("u1".to_string()),
+                domain: Some("built-in".to_string()),
+                detail: None,
+                created_at: time(day),
+            }
+            .into_active_model()
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    fn read_file(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+        let mut content = String::new();
+        archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[tokio::test]
+    async fn test_write_bundle() {
+        let db = setup_db().await;
+        let request = ComplianceExportRequest {
+            job_id: "job-1".to_string(),
+            user_id: "u1".to_string(),
+            start_time: time(1),
+            end_time: time(5),
+            requested_by: "admin".to_string(),
+        };
+
+        let (counts, buffer) = write_bundle(&db, &request, Cursor::new(Vec::new())).await.unwrap();
+        assert_eq!(
+            counts,
+            ComplianceRecordCounts {
+                login_logs: 2,
+                operation_logs: 0,
+                security_events: 2,
+                tokens: 2,
+            }
+        );
+
+        let mut archive = ZipArchive::new(Cursor::new(buffer.into_inner())).unwrap();
+        let login_logs = read_file(&mut archive, "login_logs.ndjson");
+        let ids: Vec<String> = login_logs
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .map(|record| record["id"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(ids, ["login-0", "login-1"]);
+
+        assert!(read_file(&mut archive, "operation_logs.ndjson").is_empty());
+        let security_events = read_file(&mut archive, "security_events.ndjson");
+        assert!(security_events.lines().nth(1).unwrap().contains("role_granted"));
+        let tokens = read_file(&mut archive, "tokens.ndjson");
+        assert_eq!(tokens.lines().count(), 2);
+        assert!(!tokens.contains("access-token") && !tokens.contains("refresh-token"));
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&read_file(&mut archive, "manifest.json")).unwrap();
+        assert_eq!(manifest["userId"], "u1");
+        assert_eq!(manifest["recordCounts"]["tokens"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_export_checks_operator_and_range() {
+        let input = |start: u32, end: u32| ComplianceExportInput {
+            user_id: "u1".to_string(),
+            start_time: time(start),
+            end_time: time(end),
+        };
+
+        let err = SysComplianceService.create_export(input(1, 5), "tenant", "admin").await;
+        assert!(matches!(err, Err(ComplianceError::BuiltInDomainOnly)));
+        let err =
+            SysComplianceService.create_export(input(5, 1), BUILT_IN_DOMAIN_CODE, "admin").await;
+        assert!(matches!(err, Err(ComplianceError::InvalidTimeRange)));
+
+        let err = SysComplianceService.get_export("missing", BUILT_IN_DOMAIN_CODE).await;
+        assert!(matches!(err, Err(ComplianceError::JobNotFound(_))));
+    }
+}
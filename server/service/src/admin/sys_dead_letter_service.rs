@@ -0,0 +1,140 @@
+/**
+ * 死信事件服务模块
+ *
+ * 该模块保存重试后仍处理失败的事件，包括：
+ * - 将失败事件放入死信缓冲区
+ * - 查询死信缓冲区中的事件
+ *
+ * 缓冲区保存在内存中，容量固定，超出后丢弃最早的事件；服务重启后清空。
+ * 死信事件用于排查和补录审计记录，不会被自动重放。
+ *
+ * 主要组件
+ * --------
+ * - TDeadLetterService: 死信事件服务 trait
+ * - SysDeadLetterService: 死信事件服务实现
+ */
+
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use server_global::project_error;
+use server_model::admin::output::DeadLetterEvent;
+use server_model::admin::timestamps;
+use tokio::sync::RwLock;
+
+/** 死信缓冲区容量 */
+const DEAD_LETTER_CAPACITY: usize = 1000;
+
+/** 死信缓冲区 */
+static DEAD_LETTERS: Lazy<RwLock<DeadLetterBuffer>> =
+    Lazy::new(|| RwLock::new(DeadLetterBuffer::new(DEAD_LETTER_CAPACITY)));
+
+/**
+ * 固定容量的死信缓冲区
+ */
+struct DeadLetterBuffer {
+    capacity: usize,
+    events: VecDeque<DeadLetterEvent>,
+}
+
+impl DeadLetterBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /**
+     * 放入事件，缓冲区已满时丢弃最早的事件
+     */
+    fn push(&mut self, event: DeadLetterEvent) {
+        if self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /**
+     * 按失败时间倒序列出事件
+     */
+    fn list(&self) -> Vec<DeadLetterEvent> {
+        self.events.iter().rev().cloned().collect()
+    }
+}
+
+/**
+ * 死信事件服务 trait
+ */
+#[async_trait]
+pub trait TDeadLetterService {
+    /**
+     * 查询死信事件
+     *
+     * @return Vec<DeadLetterEvent> 死信事件列表，最近失败的在前
+     */
+    async fn list_dead_letters(&self) -> Vec<DeadLetterEvent>;
+}
+
+/**
+ * 死信事件服务
+ */
+#[derive(Clone)]
+pub struct SysDeadLetterService;
+
+impl SysDeadLetterService {
+    /**
+     * 将处理失败的事件放入死信缓冲区
+     *
+     * 同时记录错误日志，payload 中不应包含令牌、密码等敏感字段
+     *
+     * @param event_type 事件类型
+     * @param payload 事件内容
+     * @param error 最后一次处理失败的错误信息
+     */
+    pub async fn park(event_type: &str, payload: Value, error: String) {
+        project_error!("Event '{}' moved to dead-letter buffer: {}", event_type, error);
+        DEAD_LETTERS.write().await.push(DeadLetterEvent {
+            event_type: event_type.to_string(),
+            payload,
+            error,
+            failed_at: timestamps::now(),
+        });
+    }
+}
+
+#[async_trait]
+impl TDeadLetterService for SysDeadLetterService {
+    async fn list_dead_letters(&self) -> Vec<DeadLetterEvent> {
+        DEAD_LETTERS.read().await.list()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(index: u32) -> DeadLetterEvent {
+        DeadLetterEvent {
+            event_type: "test".to_string(),
+            payload: json!({ "index": index }),
+            error: "error".to_string(),
+            failed_at: timestamps::now(),
+        }
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest_when_full() {
+        let mut buffer = DeadLetterBuffer::new(2);
+        for index in 0..3 {
+            buffer.push(event(index));
+        }
+
+        let payloads: Vec<Value> = buffer.list().into_iter().map(|event| event.payload).collect();
+        assert_eq!(payloads, vec![json!({ "index": 2 }), json!({ "index": 1 })]);
+    }
+}
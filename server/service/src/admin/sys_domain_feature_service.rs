@@ -0,0 +1,303 @@
+/**
+ * 域功能开关服务模块
+ *
+ * 该模块提供了按域（租户）开启或关闭功能的能力，包括：
+ * - 域功能开关的查询、设置和删除
+ * - 判断功能在指定域是否启用（带TTL的内存缓存）
+ *
+ * 未设置开关的功能视为启用，只有显式关闭的功能才会被拒绝访问。
+ *
+ * 主要组件
+ * --------
+ * - TDomainFeatureService: 域功能开关服务 trait
+ * - SysDomainFeatureService: 域功能开关服务实现
+ */
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use server_core::web::error::AppError;
+use server_model::admin::{
+    entities::{
+        prelude::{SysDomain, SysDomainFeature},
+        sys_domain::Column as SysDomainColumn,
+        sys_domain_feature::{
+            ActiveModel as SysDomainFeatureActiveModel, Column as SysDomainFeatureColumn,
+            Model as SysDomainFeatureModel,
+        },
+    },
+    input::UpsertDomainFeatureInput,
+    timestamps,
+};
+use tokio::sync::RwLock;
+use ulid::Ulid;
+
+use crate::{
+    admin::{sys_domain_error::DomainError, sys_domain_feature_error::DomainFeatureError},
+    helper::db_helper,
+};
+
+/** 功能开关缓存有效期 */
+const FEATURE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/** 功能开关缓存，键为 (域代码, 功能标识) */
+static FEATURE_CACHE: Lazy<RwLock<FeatureCache>> =
+    Lazy::new(|| RwLock::new(FeatureCache::new(FEATURE_CACHE_TTL)));
+
+/**
+ * 域功能开关服务 trait
+ *
+ * 定义了域功能开关相关的核心接口，包括：
+ * - 查询域下的功能开关
+ * - 设置功能开关
+ * - 删除功能开关
+ * - 判断功能是否启用
+ */
+#[async_trait]
+pub trait TDomainFeatureService {
+    /**
+     * 查询域下的功能开关
+     *
+     * @param domain_code 域代码
+     * @return Result<Vec<SysDomainFeatureModel>, AppError> 功能开关列表或错误
+     */
+    async fn find_domain_features(
+        &self,
+        domain_code: &str,
+    ) -> Result<Vec<SysDomainFeatureModel>, AppError>;
+
+    /**
+     * 设置功能开关
+     *
+     * 开关记录不存在时创建，存在时更新启用状态
+     *
+     * @param domain_code 域代码
+     * @param input 功能开关参数
+     * @param operator 操作人
+     * @return Result<SysDomainFeatureModel, AppError> 设置后的功能开关或错误
+     */
+    async fn upsert_domain_feature(
+        &self,
+        domain_code: &str,
+        input: UpsertDomainFeatureInput,
+        operator: &str,
+    ) -> Result<SysDomainFeatureModel, AppError>;
+
+    /**
+     * 删除功能开关
+     *
+     * 删除后该功能在域内恢复为默认启用
+     *
+     * @param domain_code 域代码
+     * @param feature_key 功能标识
+     * @return Result<(), AppError> 删除结果
+     */
+    async fn delete_domain_feature(
+        &self,
+        domain_code: &str,
+        feature_key: &str,
+    ) -> Result<(), AppError>;
+
+    /**
+     * 判断功能在指定域是否启用
+     *
+     * 结果缓存30秒，设置或删除开关时立即失效
+     *
+     * @param domain_code 域代码
+     * @param feature_key 功能标识
+     * @return Result<bool, AppError> 是否启用，未设置开关时返回true
+     */
+    async fn is_enabled(&self, domain_code: &str, feature_key: &str) -> Result<bool, AppError>;
+}
+
+/**
+ * 域功能开关服务
+ */
+#[derive(Clone)]
+pub struct SysDomainFeatureService;
+
+impl SysDomainFeatureService {
+    /**
+     * 查询功能开关记录
+     */
+    async fn find_feature(
+        domain_code: &str,
+        feature_key: &str,
+    ) -> Result<Option<SysDomainFeatureModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysDomainFeature::find()
+            .filter(SysDomainFeatureColumn::DomainCode.eq(domain_code))
+            .filter(SysDomainFeatureColumn::FeatureKey.eq(feature_key))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+}
+
+#[async_trait]
+impl TDomainFeatureService for SysDomainFeatureService {
+    async fn find_domain_features(
+        &self,
+        domain_code: &str,
+    ) -> Result<Vec<SysDomainFeatureModel>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        SysDomainFeature::find()
+            .filter(SysDomainFeatureColumn::DomainCode.eq(domain_code))
+            .order_by_asc(SysDomainFeatureColumn::FeatureKey)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn upsert_domain_feature(
+        &self,
+        domain_code: &str,
+        input: UpsertDomainFeatureInput,
+        operator: &str,
+    ) -> Result<SysDomainFeatureModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        let domain_exists = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(domain_code))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+        if !domain_exists {
+            return Err(DomainError::DomainNotFound.into());
+        }
+
+        let now = timestamps::now();
+        let feature = match Self::find_feature(domain_code, &input.feature_key).await? {
+            Some(existing) => {
+                let mut feature: SysDomainFeatureActiveModel = existing.into();
+                feature.enabled = Set(input.enabled);
+                feature.updated_at = Set(Some(now));
+                feature.updated_by = Set(Some(operator.to_string()));
+                feature.update(db.as_ref()).await.map_err(AppError::from)?
+            },
+            None => SysDomainFeatureActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain_code: Set(domain_code.to_string()),
+                feature_key: Set(input.feature_key),
+                enabled: Set(input.enabled),
+                updated_at: Set(Some(now)),
+                updated_by: Set(Some(operator.to_string())),
+            }
+            .insert(db.as_ref())
+            .await
+            .map_err(AppError::from)?,
+        };
+
+        FEATURE_CACHE.write().await.invalidate_domain(domain_code);
+        Ok(feature)
+    }
+
+    async fn delete_domain_feature(
+        &self,
+        domain_code: &str,
+        feature_key: &str,
+    ) -> Result<(), AppError> {
+        let feature = Self::find_feature(domain_code, feature_key)
+            .await?
+            .ok_or_else(|| AppError::from(DomainFeatureError::FeatureNotFound))?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysDomainFeature::delete_by_id(feature.id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        FEATURE_CACHE.write().await.invalidate_domain(domain_code);
+        Ok(())
+    }
+
+    async fn is_enabled(&self, domain_code: &str, feature_key: &str) -> Result<bool, AppError> {
+        if let Some(enabled) = FEATURE_CACHE
+            .read()
+            .await
+            .get(domain_code, feature_key, Instant::now())
+        {
+            return Ok(enabled);
+        }
+
+        let db = db_helper::get_read_connection().await?;
+        let enabled = SysDomainFeature::find()
+            .filter(SysDomainFeatureColumn::DomainCode.eq(domain_code))
+            .filter(SysDomainFeatureColumn::FeatureKey.eq(feature_key))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .map(|feature| feature.enabled)
+            .unwrap_or(true);
+
+        FEATURE_CACHE
+            .write()
+            .await
+            .insert(domain_code, feature_key, enabled, Instant::now());
+        Ok(enabled)
+    }
+}
+
+/**
+ * 功能开关缓存
+ *
+ * 记录每个 (域代码, 功能标识) 的启用状态和写入时间，超过有效期的条目视为未命中
+ */
+struct FeatureCache {
+    ttl: Duration,
+    entries: HashMap<(String, String), (bool, Instant)>,
+}
+
+impl FeatureCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&self, domain_code: &str, feature_key: &str, now: Instant) -> Option<bool> {
+        self.entries
+            .get(&(domain_code.to_string(), feature_key.to_string()))
+            .filter(|(_, cached_at)| now.saturating_duration_since(*cached_at) < self.ttl)
+            .map(|(enabled, _)| *enabled)
+    }
+
+    fn insert(&mut self, domain_code: &str, feature_key: &str, enabled: bool, now: Instant) {
+        self.entries
+            .retain(|_, (_, cached_at)| now.saturating_duration_since(*cached_at) < self.ttl);
+        self.entries
+            .insert((domain_code.to_string(), feature_key.to_string()), (enabled, now));
+    }
+
+    fn invalidate_domain(&mut self, domain_code: &str) {
+        self.entries.retain(|(domain, _), _| domain != domain_code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_cache_expires_and_invalidates() {
+        let mut cache = FeatureCache::new(Duration::from_secs(30));
+        let start = Instant::now();
+
+        cache.insert("built-in", "sandbox", false, start);
+        cache.insert("other", "sandbox", true, start);
+        assert_eq!(cache.get("built-in", "sandbox", start), Some(false));
+        assert_eq!(cache.get("built-in", "export", start), None);
+        assert_eq!(cache.get("built-in", "sandbox", start + Duration::from_secs(30)), None);
+
+        cache.invalidate_domain("built-in");
+        assert_eq!(cache.get("built-in", "sandbox", start), None);
+        assert_eq!(cache.get("other", "sandbox", start), Some(true));
+    }
+}
@@ -5,6 +5,8 @@
  * - 域CRUD操作
  * - 域分页查询
  * - 域代码和名称唯一性检查
+ * - 登录页可选域列表（带TTL的内存缓存）
+ * - 按模板开通域（域、角色、菜单、管理员和权限策略）
  *
  * 主要组件
  * --------
@@ -43,28 +45,104 @@
  * }).await?;
  */
 
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
 use async_trait::async_trait;
-use chrono::Local;
+use axum_casbin::casbin::{CoreApi, MgmtApi, RbacApi};
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
+use server_config::LoginConfig;
 use server_core::{
-    web::{error::AppError, page::PaginatedData},
+    web::{
+        error::AppError,
+        page::{KeywordFilter, PaginatedData},
+        CurrentUserContext,
+    },
     paginated_data,
 };
+use server_global::{global::get_config, project_error};
 use server_model::admin::{
     entities::{
-        prelude::SysDomain,
+        prelude::{SysDomain, SysRole, SysRoleMenu, SysUser, SysUserRole},
         sea_orm_active_enums::Status,
         sys_domain::{
             ActiveModel as SysDomainActiveModel, Column as SysDomainColumn, Model as SysDomainModel,
         },
+        sys_role::{ActiveModel as SysRoleActiveModel, Column as SysRoleColumn},
+        sys_role_menu::{ActiveModel as SysRoleMenuActiveModel, Column as SysRoleMenuColumn},
+        sys_user::ActiveModel as SysUserActiveModel,
+        sys_user_role::ActiveModel as SysUserRoleActiveModel,
+    },
+    ids::DomainCode,
+    input::{
+        CreateDomainInput, DomainPageRequest, DomainTemplate, ProvisionDomainInput,
+        UpdateDomainInput,
     },
-    input::{CreateDomainInput, DomainPageRequest, UpdateDomainInput},
+    output::{DomainOptionOutput, DomainProvisionOutput, UserWithoutPassword, WithOperatorNames},
 };
+use server_utils::SecureUtil;
+use tokio::sync::RwLock;
 use ulid::Ulid;
 
-use crate::{admin::sys_domain_error::DomainError, helper::db_helper};
+use crate::{
+    admin::sys_domain_error::DomainError,
+    helper::{
+        audit_fields::{self, CreateAudit},
+        db_helper,
+        user_name_resolver::resolve_user_names,
+    },
+};
+
+/** 内置域代码，不可修改或删除 */
+pub(crate) const BUILT_IN_DOMAIN_CODE: &str = "built-in";
+
+/** 可选域列表缓存有效期 */
+const ENABLED_DOMAINS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/** 可选域列表缓存，键为是否包含内置域；域创建、更新、删除时失效 */
+static ENABLED_DOMAINS_CACHE: Lazy<Cache<bool, Vec<DomainOptionOutput>>> =
+    Lazy::new(|| Cache::builder().time_to_live(ENABLED_DOMAINS_CACHE_TTL).build());
+
+/**
+ * 域初始化模板的内容
+ *
+ * 模板角色从来源域中按角色代码查找，角色的菜单和接口权限随运行时的配置变化
+ */
+struct TemplateDefinition {
+    /** 复制角色的来源域 */
+    source_domain: &'static str,
+    /** 复制的角色代码 */
+    role_codes: &'static [&'static str],
+    /** 分配给新域管理员的角色代码，必须包含在 `role_codes` 中 */
+    admin_role_code: &'static str,
+}
+
+impl TemplateDefinition {
+    fn of(template: DomainTemplate) -> Self {
+        match template {
+            DomainTemplate::Default => Self {
+                source_domain: BUILT_IN_DOMAIN_CODE,
+                role_codes: &["ROLE_ADMIN", "ROLE_USER"],
+                admin_role_code: "ROLE_ADMIN",
+            },
+        }
+    }
+}
+
+/**
+ * 开通域时写入执行器的策略
+ */
+#[derive(Debug, Default, PartialEq)]
+struct ProvisionPolicies {
+    /** 接口权限策略 `p, 角色代码, 域, 路径, 操作` */
+    policies: Vec<Vec<String>>,
+    /** 分组规则 `g, 用户ID, 角色代码, 域` */
+    grouping_policies: Vec<Vec<String>>,
+}
 
 /**
  * 域服务 trait
@@ -96,15 +174,15 @@ pub trait TDomainService {
     /**
      * 分页查询域
      *
-     * 根据查询条件分页获取域列表
+     * 根据查询条件分页获取域列表，包含创建人和更新人用户名
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysDomainModel>, AppError> 分页域数据或错误
+     * @return Result<PaginatedData<WithOperatorNames<SysDomainModel>>, AppError> 分页域数据或错误
      */
     async fn find_paginated_domains(
         &self,
         params: DomainPageRequest,
-    ) -> Result<PaginatedData<SysDomainModel>, AppError>;
+    ) -> Result<PaginatedData<WithOperatorNames<SysDomainModel>>, AppError>;
 
     /**
      * 创建域
@@ -145,6 +223,40 @@ pub trait TDomainService {
      * @return Result<(), AppError> 删除结果
      */
     async fn delete_domain(&self, id: &str) -> Result<(), AppError>;
+
+    /**
+     * 获取登录页可选域列表
+     *
+     * 只返回启用的域的代码和名称，结果缓存60秒；
+     * 配置 `login.hide_built_in_domain` 时不包含内置域
+     *
+     * @return Result<Vec<DomainOptionOutput>, AppError> 可选域列表或错误
+     */
+    async fn list_enabled_domains(&self) -> Result<Vec<DomainOptionOutput>, AppError>;
+
+    /**
+     * 开通域
+     *
+     * 在一个事务内创建域，复制模板角色及其菜单，创建管理员用户并分配管理员角色，
+     * 同时向执行器写入新角色的接口权限和管理员的分组规则。
+     * 任一步骤失败时回滚数据库变更，并撤销已写入执行器的策略
+     *
+     * @param input 域开通参数
+     * @param enforcer 权限执行器
+     * @return Result<DomainProvisionOutput, AppError> 创建的资源或错误
+     *
+     * 错误
+     * -----
+     * - DuplicateCode: 域代码已存在
+     * - DuplicateName: 域名称已存在
+     * - TemplateRoleNotFound: 来源域中缺少模板角色
+     * - PolicySyncFailed: 写入执行器失败
+     */
+    async fn provision_domain(
+        &self,
+        input: ProvisionDomainInput,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<DomainProvisionOutput, AppError>;
 }
 
 /**
@@ -178,6 +290,7 @@ impl SysDomainService {
      *
      * 检查域代码和名称是否已存在，支持排除当前域
      *
+     * @param db 数据库连接
      * @param id 当前域ID（可选）
      * @param code 域代码
      * @param name 域名称
@@ -188,19 +301,18 @@ impl SysDomainService {
      * - DuplicateCode: 域代码已存在
      * - DuplicateName: 域名称已存在
      */
-    async fn check_domain_exists(
-        &self,
+    async fn check_domain_exists<C: ConnectionTrait>(
+        db: &C,
         id: Option<&str>,
         code: &str,
         name: &str,
     ) -> Result<(), AppError> {
         let id_str = id.unwrap_or("-1");
-        let db = db_helper::get_db_connection().await?;
 
         let code_exists = SysDomain::find()
             .filter(SysDomainColumn::Code.eq(code))
             .filter(SysDomainColumn::Id.ne(id_str))
-            .one(db.as_ref())
+            .one(db)
             .await
             .map_err(AppError::from)?
             .is_some();
@@ -212,7 +324,7 @@ impl SysDomainService {
         let name_exists = SysDomain::find()
             .filter(SysDomainColumn::Name.eq(name))
             .filter(SysDomainColumn::Id.ne(id_str))
-            .one(db.as_ref())
+            .one(db)
             .await
             .map_err(AppError::from)?
             .is_some();
@@ -223,6 +335,288 @@ impl SysDomainService {
 
         Ok(())
     }
+
+    /**
+     * 查询启用的域
+     *
+     * @param db 数据库连接
+     * @param include_built_in 是否包含内置域
+     * @return Result<Vec<DomainOptionOutput>, DbErr> 按代码排序的域列表
+     */
+    pub(crate) async fn query_enabled_domains<C: ConnectionTrait>(
+        db: &C,
+        include_built_in: bool,
+    ) -> Result<Vec<DomainOptionOutput>, DbErr> {
+        let mut query = SysDomain::find()
+            .select_only()
+            .column(SysDomainColumn::Code)
+            .column(SysDomainColumn::Name)
+            .filter(SysDomainColumn::Status.eq(Status::Enabled));
+        if !include_built_in {
+            query = query.filter(SysDomainColumn::Code.ne(BUILT_IN_DOMAIN_CODE));
+        }
+
+        query
+            .order_by_asc(SysDomainColumn::Code)
+            .into_model::<DomainOptionOutput>()
+            .all(db)
+            .await
+    }
+
+    /**
+     * 开通域
+     *
+     * 数据库写入在事务中完成，执行器策略在提交前写入；
+     * 写入策略或提交事务失败时撤销本次写入的策略，事务随之回滚
+     */
+    async fn provision_domain_in(
+        db: &DatabaseConnection,
+        input: ProvisionDomainInput,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<DomainProvisionOutput, AppError> {
+        input.code.parse::<DomainCode>()?;
+        let password_hash = SecureUtil::hash_password(input.admin_password.as_bytes())
+            .map_err(|e| DomainError::DomainOperationFailed(e.to_string()))?;
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let (output, policies) =
+            Self::insert_provisioned_domain(&txn, input, password_hash, &audit, &enforcer).await?;
+        let applied = Self::apply_policies(&enforcer, policies).await?;
+        if let Err(err) = txn.commit().await {
+            Self::undo_policies(&enforcer, applied).await;
+            return Err(AppError::from(err));
+        }
+
+        Ok(output)
+    }
+
+    /**
+     * 写入开通域所需的数据
+     *
+     * 复制模板角色（保留角色间的上下级关系）及其在来源域中的菜单，
+     * 创建管理员用户并分配管理员角色，返回需要写入执行器的策略
+     */
+    async fn insert_provisioned_domain<C: ConnectionTrait>(
+        db: &C,
+        input: ProvisionDomainInput,
+        password_hash: String,
+        audit: &CreateAudit,
+        enforcer: &Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<(DomainProvisionOutput, ProvisionPolicies), AppError> {
+        Self::check_domain_exists(db, None, &input.code, &input.name).await?;
+
+        let template = TemplateDefinition::of(input.template);
+        let source_roles = SysRole::find()
+            .filter(SysRoleColumn::Domain.eq(template.source_domain))
+            .filter(SysRoleColumn::Code.is_in(template.role_codes.iter().copied()))
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+        let source_roles = template
+            .role_codes
+            .iter()
+            .map(|code| {
+                source_roles
+                    .iter()
+                    .find(|role| role.code == *code)
+                    .ok_or_else(|| DomainError::TemplateRoleNotFound(code.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let domain = SysDomainActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(input.code),
+            name: Set(input.name),
+            description: Set(input.description),
+            status: Set(Status::Enabled),
+            created_by: Set(audit.created_by.clone()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .map_err(AppError::from)?;
+
+        // 来源角色ID到新角色ID的映射，上级角色不在模板中时作为顶级角色
+        let role_ids: HashMap<&str, String> = source_roles
+            .iter()
+            .map(|role| (role.id.as_str(), Ulid::new().to_string()))
+            .collect();
+        let mut roles = Vec::with_capacity(source_roles.len());
+        for source in &source_roles {
+            let role = SysRoleActiveModel {
+                id: Set(role_ids[source.id.as_str()].clone()),
+                code: Set(source.code.clone()),
+                domain: Set(domain.code.clone()),
+                name: Set(source.name.clone()),
+                description: Set(source.description.clone()),
+                pid: Set(role_ids
+                    .get(source.pid.as_str())
+                    .cloned()
+                    .unwrap_or_else(|| "0".to_string())),
+                status: Set(source.status.clone()),
+                created_by: Set(audit.created_by.clone()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .map_err(AppError::from)?;
+            roles.push(role);
+        }
+
+        let role_menus: Vec<SysRoleMenuActiveModel> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.is_in(role_ids.keys().copied()))
+            .filter(SysRoleMenuColumn::Domain.eq(template.source_domain))
+            .all(db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|role_menu| SysRoleMenuActiveModel {
+                role_id: Set(role_ids[role_menu.role_id.as_str()].clone()),
+                menu_id: Set(role_menu.menu_id),
+                domain: Set(domain.code.clone()),
+            })
+            .collect();
+        let menu_count = role_menus.len();
+        if !role_menus.is_empty() {
+            SysRoleMenu::insert_many(role_menus).exec(db).await.map_err(AppError::from)?;
+        }
+
+        let mut policies = ProvisionPolicies::default();
+        {
+            let enforcer_read = enforcer.read().await;
+            for source in &source_roles {
+                let source_policies = enforcer_read.get_filtered_policy(
+                    0,
+                    vec![source.code.clone(), template.source_domain.to_string()],
+                );
+                for policy in source_policies.into_iter().filter(|policy| policy.len() >= 4) {
+                    let policy = vec![
+                        source.code.clone(),
+                        domain.code.clone(),
+                        policy[2].clone(),
+                        policy[3].clone(),
+                    ];
+                    if !policies.policies.contains(&policy) {
+                        policies.policies.push(policy);
+                    }
+                }
+            }
+        }
+
+        let admin_role = roles
+            .iter()
+            .find(|role| role.code == template.admin_role_code)
+            .ok_or_else(|| {
+                DomainError::TemplateRoleNotFound(template.admin_role_code.to_string())
+            })?;
+        let admin = SysUserActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain.code.clone()),
+            username: Set(input.admin_username.clone()),
+            password: Set(password_hash),
+            built_in: Set(false),
+            nick_name: Set(input.admin_username),
+            status: Set(Status::Enabled),
+            created_by: Set(audit.created_by.clone()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .map_err(AppError::from)?;
+        SysUserRoleActiveModel {
+            user_id: Set(admin.id.clone()),
+            role_id: Set(admin_role.id.clone()),
+        }
+        .insert(db)
+        .await
+        .map_err(AppError::from)?;
+        policies.grouping_policies.push(vec![
+            admin.id.clone(),
+            admin_role.code.clone(),
+            domain.code.clone(),
+        ]);
+
+        let output = DomainProvisionOutput {
+            admin_role_code: admin_role.code.clone(),
+            menu_count,
+            policy_count: policies.policies.len(),
+            grouping_policy_count: policies.grouping_policies.len(),
+            admin: UserWithoutPassword::from(admin),
+            roles,
+            domain,
+        };
+        Ok((output, policies))
+    }
+
+    /**
+     * 向执行器写入策略，返回实际新增的策略
+     *
+     * 执行器中已存在的策略（例如删除同代码的域后遗留的策略）不再写入，撤销时也不会删除；
+     * 写入失败时撤销本次已写入的策略
+     */
+    async fn apply_policies(
+        enforcer: &Arc<RwLock<impl MgmtApi + Send + Sync>>,
+        policies: ProvisionPolicies,
+    ) -> Result<ProvisionPolicies, AppError> {
+        let mut enforcer_write = enforcer.write().await;
+        let new_policies: Vec<Vec<String>> = policies
+            .policies
+            .into_iter()
+            .filter(|policy| !enforcer_write.has_policy(policy.clone()))
+            .collect();
+        let new_grouping_policies: Vec<Vec<String>> = policies
+            .grouping_policies
+            .into_iter()
+            .filter(|rule| !enforcer_write.has_grouping_policy(rule.clone()))
+            .collect();
+
+        let mut applied = ProvisionPolicies::default();
+        if !new_policies.is_empty() {
+            if let Err(e) = enforcer_write.add_policies(new_policies.clone()).await {
+                return Err(DomainError::PolicySyncFailed(e.to_string()).into());
+            }
+            applied.policies = new_policies;
+        }
+        if !new_grouping_policies.is_empty() {
+            if let Err(e) = enforcer_write
+                .add_grouping_policies(new_grouping_policies.clone())
+                .await
+            {
+                drop(enforcer_write);
+                Self::undo_policies(enforcer, applied).await;
+                return Err(DomainError::PolicySyncFailed(e.to_string()).into());
+            }
+            applied.grouping_policies = new_grouping_policies;
+        }
+
+        Ok(applied)
+    }
+
+    /**
+     * 撤销本次开通写入执行器的策略
+     *
+     * 撤销失败时只记录日志，返回开通失败的原始错误
+     */
+    async fn undo_policies(
+        enforcer: &Arc<RwLock<impl MgmtApi + Send + Sync>>,
+        applied: ProvisionPolicies,
+    ) {
+        let mut enforcer_write = enforcer.write().await;
+        if !applied.grouping_policies.is_empty() {
+            if let Err(e) = enforcer_write
+                .remove_grouping_policies(applied.grouping_policies)
+                .await
+            {
+                project_error!("Failed to undo grouping policies of provisioned domain: {}", e);
+            }
+        }
+        if !applied.policies.is_empty() {
+            if let Err(e) = enforcer_write.remove_policies(applied.policies).await {
+                project_error!("Failed to undo policies of provisioned domain: {}", e);
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -230,21 +624,20 @@ impl TDomainService for SysDomainService {
     /**
      * 分页查询域
      *
-     * 根据查询条件分页获取域列表，支持关键字搜索
+     * 根据查询条件分页获取域列表，支持关键字搜索，包含创建人和更新人用户名
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysDomainModel>, AppError> 分页域数据或错误
+     * @return Result<PaginatedData<WithOperatorNames<SysDomainModel>>, AppError> 分页域数据或错误
      */
     async fn find_paginated_domains(
         &self,
         params: DomainPageRequest,
-    ) -> Result<PaginatedData<SysDomainModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PaginatedData<WithOperatorNames<SysDomainModel>>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysDomain::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any().add(SysDomainColumn::Name.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(KeywordFilter::new(keywords).condition(&[SysDomainColumn::Name]));
         }
 
         let total = query
@@ -254,11 +647,27 @@ impl TDomainService for SysDomainService {
             .map_err(AppError::from)?;
 
         let paginator = query.paginate(db.as_ref(), params.page_details.size);
-        let records = paginator
+        let domains = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?;
 
+        let operator_ids = domains
+            .iter()
+            .flat_map(|domain| [Some(domain.created_by.as_str()), domain.updated_by.as_deref()])
+            .flatten();
+        let names = resolve_user_names(db.as_ref(), operator_ids)
+            .await
+            .map_err(AppError::from)?;
+        let records = domains
+            .into_iter()
+            .map(|domain| WithOperatorNames {
+                created_by_name: names.get(&domain.created_by).cloned(),
+                updated_by_name: domain.updated_by.as_ref().and_then(|id| names.get(id).cloned()),
+                inner: domain,
+            })
+            .collect();
+
         Ok(paginated_data!(
             total,
             params.page_details.current,
@@ -276,10 +685,10 @@ impl TDomainService for SysDomainService {
      * @return Result<SysDomainModel, AppError> 创建的域信息或错误
      */
     async fn create_domain(&self, input: CreateDomainInput) -> Result<SysDomainModel, AppError> {
-        self.check_domain_exists(None, &input.code, &input.name)
-            .await?;
-
         let db = db_helper::get_db_connection().await?;
+        Self::check_domain_exists(db.as_ref(), None, &input.code, &input.name).await?;
+
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
 
         let domain = SysDomainActiveModel {
             id: Set(Ulid::new().to_string()),
@@ -287,12 +696,12 @@ impl TDomainService for SysDomainService {
             name: Set(input.name),
             description: Set(input.description),
             status: Set(Status::Enabled),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
+            created_by: Set(audit.created_by),
             ..Default::default()
         };
 
         let result = domain.insert(db.as_ref()).await.map_err(AppError::from)?;
+        ENABLED_DOMAINS_CACHE.invalidate_all();
         Ok(result)
     }
 
@@ -325,19 +734,27 @@ impl TDomainService for SysDomainService {
         let db = db_helper::get_db_connection().await?;
         let existing_domain = self.get_domain(&input.id).await?;
 
-        if existing_domain.code == "built-in" {
+        if existing_domain.code == BUILT_IN_DOMAIN_CODE {
             return Err(DomainError::BuiltInDomain.into());
         }
 
-        self.check_domain_exists(Some(&input.id), &input.domain.code, &input.domain.name)
-            .await?;
+        Self::check_domain_exists(
+            db.as_ref(),
+            Some(&input.id),
+            &input.domain.code,
+            &input.domain.name,
+        )
+        .await?;
 
         let mut domain: SysDomainActiveModel = existing_domain.into();
         domain.code = Set(input.domain.code);
         domain.name = Set(input.domain.name);
         domain.description = Set(input.domain.description);
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        domain.updated_by = Set(audit.updated_by);
 
         let updated_domain = domain.update(db.as_ref()).await.map_err(AppError::from)?;
+        ENABLED_DOMAINS_CACHE.invalidate_all();
         Ok(updated_domain)
     }
 
@@ -352,7 +769,7 @@ impl TDomainService for SysDomainService {
     async fn delete_domain(&self, id: &str) -> Result<(), AppError> {
         let domain = self.get_domain(id).await?;
 
-        if domain.code == "built-in" {
+        if domain.code == BUILT_IN_DOMAIN_CODE {
             return Err(DomainError::BuiltInDomain.into());
         }
 
@@ -361,6 +778,299 @@ impl TDomainService for SysDomainService {
             .exec(db.as_ref())
             .await
             .map_err(AppError::from)?;
+        ENABLED_DOMAINS_CACHE.invalidate_all();
         Ok(())
     }
+
+    async fn list_enabled_domains(&self) -> Result<Vec<DomainOptionOutput>, AppError> {
+        let include_built_in = !get_config::<LoginConfig>()
+            .await
+            .map(|config| config.hide_built_in_domain)
+            .unwrap_or(false);
+        if let Some(domains) = ENABLED_DOMAINS_CACHE.get(&include_built_in) {
+            return Ok(domains);
+        }
+
+        let db = db_helper::get_read_connection().await?;
+        let domains = Self::query_enabled_domains(db.as_ref(), include_built_in)
+            .await
+            .map_err(AppError::from)?;
+        ENABLED_DOMAINS_CACHE.insert(include_built_in, domains.clone());
+        Ok(domains)
+    }
+
+    async fn provision_domain(
+        &self,
+        input: ProvisionDomainInput,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<DomainProvisionOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let output = Self::provision_domain_in(db.as_ref(), input, enforcer).await?;
+        ENABLED_DOMAINS_CACHE.invalidate_all();
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_casbin::casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        sys_role_menu::Model as SysRoleMenuModel, sys_user_role::Column as SysUserRoleColumn,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+
+        let domains = [
+            ("1", BUILT_IN_DOMAIN_CODE, "Built-in", Status::Enabled),
+            ("2", "tenant-b", "Tenant B", Status::Enabled),
+            ("3", "tenant-a", "Tenant A", Status::Enabled),
+            ("4", "tenant-c", "Tenant C", Status::Disabled),
+        ];
+        for (id, code, name, status) in domains {
+            SysDomainActiveModel {
+                id: Set(id.to_string()),
+                code: Set(code.to_string()),
+                name: Set(name.to_string()),
+                description: Set(Some("internal".to_string())),
+                status: Set(status),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    #[tokio::test]
+    async fn test_query_enabled_domains() {
+        let db = setup_db().await;
+
+        let domains = SysDomainService::query_enabled_domains(&db, true).await.unwrap();
+        let codes: Vec<_> = domains.iter().map(|domain| domain.code.as_str()).collect();
+        assert_eq!(codes, [BUILT_IN_DOMAIN_CODE, "tenant-a", "tenant-b"]);
+        assert_eq!(domains[1].name, "Tenant A");
+
+        let domains = SysDomainService::query_enabled_domains(&db, false).await.unwrap();
+        let codes: Vec<_> = domains.iter().map(|domain| domain.code.as_str()).collect();
+        assert_eq!(codes, ["tenant-a", "tenant-b"]);
+    }
+
+    async fn setup_provision_db() -> DatabaseConnection {
+        let db = setup_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleMenu)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+
+        let roles = [
+            ("1", "ROLE_SUPER", "0"),
+            ("2", "ROLE_ADMIN", "1"),
+            ("3", "ROLE_USER", "2"),
+        ];
+        for (id, code, pid) in roles {
+            SysRoleActiveModel {
+                id: Set(id.to_string()),
+                code: Set(code.to_string()),
+                domain: Set(BUILT_IN_DOMAIN_CODE.to_string()),
+                name: Set(code.to_string()),
+                pid: Set(pid.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (role_id, menu_id) in [("1", 1), ("2", 1), ("2", 2), ("3", 1)] {
+            SysRoleMenuActiveModel {
+                role_id: Set(role_id.to_string()),
+                menu_id: Set(menu_id),
+                domain: Set(BUILT_IN_DOMAIN_CODE.to_string()),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    async fn setup_enforcer() -> Arc<RwLock<Enforcer>> {
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        for (role, path, action) in [
+            ("ROLE_SUPER", "/domain", "write"),
+            ("ROLE_ADMIN", "/user", "write"),
+            ("ROLE_USER", "/user", "read"),
+        ] {
+            enforcer
+                .add_policy(vec![
+                    role.into(),
+                    BUILT_IN_DOMAIN_CODE.into(),
+                    path.into(),
+                    action.into(),
+                ])
+                .await
+                .unwrap();
+        }
+        Arc::new(RwLock::new(enforcer))
+    }
+
+    fn provision_input(code: &str) -> ProvisionDomainInput {
+        ProvisionDomainInput {
+            code: code.to_string(),
+            name: format!("Tenant {}", code),
+            description: None,
+            admin_username: "admin".to_string(),
+            admin_password: "secret".to_string(),
+            template: DomainTemplate::Default,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provision_domain_clones_template() {
+        let db = setup_provision_db().await;
+        let enforcer = setup_enforcer().await;
+
+        let output = SysDomainService::provision_domain_in(
+            &db,
+            provision_input("tenant-x"),
+            enforcer.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output.domain.code, "tenant-x");
+        assert_eq!(output.admin_role_code, "ROLE_ADMIN");
+        assert_eq!((output.menu_count, output.policy_count), (3, 2));
+        assert_eq!(output.grouping_policy_count, 1);
+
+        // 角色在新域中保留上下级关系，来源域的上级角色不在模板中时作为顶级角色
+        let codes: Vec<_> = output.roles.iter().map(|role| role.code.as_str()).collect();
+        assert_eq!(codes, ["ROLE_ADMIN", "ROLE_USER"]);
+        assert!(output.roles.iter().all(|role| role.domain == "tenant-x"));
+        assert_eq!(output.roles[0].pid, "0");
+        assert_eq!(output.roles[1].pid, output.roles[0].id);
+
+        let menus: Vec<SysRoleMenuModel> = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.eq(output.roles[0].id.as_str()))
+            .filter(SysRoleMenuColumn::Domain.eq("tenant-x"))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(menus.len(), 2);
+        let user_roles = SysUserRole::find()
+            .filter(SysUserRoleColumn::UserId.eq(output.admin.id.as_str()))
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(user_roles.len(), 1);
+        assert_eq!(user_roles[0].role_id, output.roles[0].id);
+
+        let enforcer = enforcer.read().await;
+        let admin_id = output.admin.id.as_str();
+        assert!(enforcer.enforce((admin_id, "tenant-x", "/user", "write")).unwrap());
+        assert!(!enforcer.enforce((admin_id, "tenant-x", "/domain", "read")).unwrap());
+        assert!(!enforcer.enforce((admin_id, BUILT_IN_DOMAIN_CODE, "/user", "read")).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_provision_domain_rolls_back_on_failure() {
+        let db = setup_provision_db().await;
+        let enforcer = setup_enforcer().await;
+
+        // 分配管理员角色时失败，此前写入的域、角色、菜单和用户全部回滚
+        db.execute_unprepared("DROP TABLE sys_user_role").await.unwrap();
+        let result = SysDomainService::provision_domain_in(
+            &db,
+            provision_input("tenant-x"),
+            enforcer.clone(),
+        )
+        .await;
+        assert!(result.is_err());
+        let domain = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq("tenant-x"))
+            .one(&db)
+            .await
+            .unwrap();
+        assert!(domain.is_none());
+        assert_eq!(SysRole::find().count(&db).await.unwrap(), 3);
+        assert_eq!(SysRoleMenu::find().count(&db).await.unwrap(), 4);
+        assert!(SysUser::find().one(&db).await.unwrap().is_none());
+        assert!(enforcer.read().await.get_filtered_policy(1, vec!["tenant-x".into()]).is_empty());
+
+        // 来源域缺少模板角色或域代码已存在时不写入任何数据
+        SysRole::delete_by_id("3").exec(&db).await.unwrap();
+        for code in ["tenant-y", "tenant-a"] {
+            let result =
+                SysDomainService::provision_domain_in(&db, provision_input(code), enforcer.clone())
+                    .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(SysDomain::find().count(&db).await.unwrap(), 4);
+        assert_eq!(SysRole::find().count(&db).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_undo_policies_keeps_existing_policies() {
+        let enforcer = setup_enforcer().await;
+        let existing = vec![
+            "ROLE_USER".to_string(),
+            BUILT_IN_DOMAIN_CODE.to_string(),
+            "/user".to_string(),
+            "read".to_string(),
+        ];
+        let added = vec![
+            "ROLE_USER".to_string(),
+            BUILT_IN_DOMAIN_CODE.to_string(),
+            "/role".to_string(),
+            "read".to_string(),
+        ];
+        let rule = vec![
+            "u1".to_string(),
+            "ROLE_USER".to_string(),
+            BUILT_IN_DOMAIN_CODE.to_string(),
+        ];
+
+        let applied = SysDomainService::apply_policies(
+            &enforcer,
+            ProvisionPolicies {
+                policies: vec![existing.clone(), added.clone()],
+                grouping_policies: vec![rule.clone()],
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(applied.policies, vec![added.clone()]);
+        assert!(enforcer
+            .read()
+            .await
+            .enforce(("u1", BUILT_IN_DOMAIN_CODE, "/role", "read"))
+            .unwrap());
+
+        SysDomainService::undo_policies(&enforcer, applied).await;
+        let enforcer = enforcer.read().await;
+        assert!(enforcer.has_policy(existing));
+        assert!(!enforcer.has_policy(added));
+        assert!(!enforcer.has_grouping_policy(rule));
+    }
 }
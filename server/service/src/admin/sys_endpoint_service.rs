@@ -1,25 +1,38 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
-use chrono::Local;
+use axum_casbin::{action_for_method, casbin::MgmtApi, ACTION_READ, ACTION_WRITE};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DeleteResult, EntityTrait, IntoActiveModel,
-    PaginatorTrait, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DeleteResult, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, Set,
 };
 use server_core::{
-    web::{error::AppError, page::PaginatedData},
+    web::{
+        error::AppError,
+        page::{KeywordFilter, PaginatedData},
+    },
     paginated_data,
 };
+use server_global::global::RouteInfo;
 use server_model::admin::entities::{
-    prelude::{SysEndpoint, SysRoleMenu},
+    prelude::{SysDomain, SysEndpoint, SysRole, SysRoleMenu},
+    sys_domain::Column as SysDomainColumn,
     sys_endpoint::{ActiveModel as SysEndpointActiveModel, Column as SysEndpointColumn, Model as SysEndpointModel},
     sys_role_menu::{ActiveModel as SysRoleMenuActiveModel, Column as SysRoleMenuColumn},
 };
-use server_model::admin::input::EndpointPageRequest;
-use server_model::admin::output::EndpointTree;
+use server_model::admin::input::{EndpointGroupBy, EndpointPageRequest, EndpointTreeRequest};
+use server_model::admin::output::{EndpointTree, SyncReport};
+use server_model::admin::timestamps;
+use tokio::sync::RwLock;
 use tracing::{error, info};
 
-use crate::helper::{db_helper, transaction_helper::execute_in_transaction};
+use crate::helper::{
+    db_helper,
+    transaction_helper::{execute_in_transaction, execute_in_transaction_with, TxnOptions},
+};
 use crate::admin::errors::sys_endpoint_error::EndpointError;
 
 /**
@@ -40,7 +53,7 @@ use crate::admin::errors::sys_endpoint_error::EndpointError;
  * --------
  * - 端点同步：批量同步接口定义
  * - 端点查询：支持分页查询和关键字搜索
- * - 端点树：按 controller 组织的树结构
+ * - 端点树：按 controller 或 resource 组织的树结构，分组节点带读写接口数
  * - 端点分配：支持为角色分配端点权限
  *
  * 使用示例
@@ -56,19 +69,52 @@ use crate::admin::errors::sys_endpoint_error::EndpointError;
  *     page_details: PageDetails { current: 1, size: 10 },
  * }).await?;
  *
- * // 获取端点树
- * let tree = endpoint_service.tree_endpoint().await?;
+ * // 获取端点树，并标记角色在指定域下已授权的端点
+ * let tree = endpoint_service.tree_endpoint(EndpointTreeRequest {
+ *     role_id: Some("role-id".to_string()),
+ *     domain: Some("built-in".to_string()),
+ *     group_by: EndpointGroupBy::Controller,
+ * }, enforcer).await?;
  */
 
 #[async_trait]
 pub trait TEndpointService {
-    async fn sync_endpoints(&self, endpoints: Vec<SysEndpointModel>) -> Result<(), AppError>;
+    /**
+     * 按收集到的路由同步接口表
+     *
+     * 返回已执行的变更
+     */
+    async fn sync_endpoints(
+        &self,
+        endpoints: Vec<SysEndpointModel>,
+    ) -> Result<SyncReport, AppError>;
+
+    /**
+     * 预览接口表同步
+     *
+     * 只比较收集到的路由与接口表，不修改数据，返回同步时将要执行的变更
+     */
+    async fn sync_endpoints_dry_run(
+        &self,
+        endpoints: Vec<SysEndpointModel>,
+    ) -> Result<SyncReport, AppError>;
+
     async fn find_paginated_endpoints(
         &self,
         params: EndpointPageRequest,
     ) -> Result<PaginatedData<SysEndpointModel>, AppError>;
 
-    async fn tree_endpoint(&self) -> Result<Vec<EndpointTree>, AppError>;
+    /**
+     * 获取端点树
+     *
+     * 同时指定 role_id 和 domain 时，根据 Casbin 中该角色在该域下的策略
+     * 设置叶子节点的 checked 字段；角色或域不存在时返回 404
+     */
+    async fn tree_endpoint(
+        &self,
+        params: EndpointTreeRequest,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync + 'static>>,
+    ) -> Result<Vec<EndpointTree>, AppError>;
 }
 
 #[derive(Clone)]
@@ -88,7 +134,8 @@ impl SysEndpointService {
         db: &DatabaseConnection,
         endpoints: Vec<SysEndpointModel>,
     ) -> Result<(), AppError> {
-        let now = Local::now().naive_local();
+        // insert_many 不触发 before_save，需要自行设置更新时间
+        let now = timestamps::now();
         let active_models: Vec<SysEndpointActiveModel> = endpoints
             .into_iter()
             .map(|endpoint| {
@@ -132,53 +179,166 @@ impl SysEndpointService {
             .map_err(AppError::from)
     }
 
-    fn create_endpoint_tree(&self, endpoints: &[SysEndpointModel]) -> Vec<EndpointTree> {
-        let mut controller_map: BTreeMap<String, EndpointTree> = BTreeMap::new();
-
-        for endpoint in endpoints {
-            let controller = endpoint.controller.clone();
-
-            let controller_node =
-                controller_map
-                    .entry(controller.clone())
-                    .or_insert(EndpointTree {
-                        id: format!("controller-{}", controller),
-                        path: String::new(),
-                        method: String::new(),
-                        action: String::new(),
-                        resource: String::new(),
-                        controller: controller.clone(),
-                        summary: None,
-                        children: Some(Vec::new()),
-                    });
-
-            if let Some(children) = &mut controller_node.children {
-                children.push(EndpointTree {
-                    id: endpoint.id.to_string(),
-                    path: endpoint.path.clone(),
-                    method: endpoint.method.clone(),
-                    action: endpoint.action.clone(),
-                    resource: endpoint.resource.clone(),
-                    controller: endpoint.controller.clone(),
-                    summary: endpoint.summary.clone(),
-                    children: Some(Vec::new()),
-                });
+    /**
+     * 比较接口表与收集到的接口
+     *
+     * 按路径和请求方法匹配：接口表中没有的新增；操作、资源、控制器或描述变化的更新，
+     * 保留原接口ID和创建时间；收集到的接口中没有的删除
+     */
+    async fn diff_endpoints<C: ConnectionTrait>(
+        db: &C,
+        endpoints: &[SysEndpointModel],
+    ) -> Result<SyncReport, AppError> {
+        let mut existing: BTreeMap<(String, String), SysEndpointModel> = SysEndpoint::find()
+            .all(db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|e| ((e.path.clone(), e.method.clone()), e))
+            .collect();
+        let collected: BTreeMap<(&str, &str), &SysEndpointModel> = endpoints
+            .iter()
+            .map(|e| ((e.path.as_str(), e.method.as_str()), e))
+            .collect();
+
+        let mut report = SyncReport::default();
+        for ((path, method), endpoint) in collected {
+            match existing.remove(&(path.to_string(), method.to_string())) {
+                None => report.to_add.push(endpoint.clone()),
+                Some(current) => {
+                    let changed = current.action != endpoint.action
+                        || current.resource != endpoint.resource
+                        || current.controller != endpoint.controller
+                        || current.summary != endpoint.summary;
+                    if changed {
+                        report.to_update.push(SysEndpointModel {
+                            id: current.id,
+                            created_at: current.created_at,
+                            ..endpoint.clone()
+                        });
+                    }
+                },
             }
         }
+        report.to_delete = existing.into_values().collect();
+        Ok(report)
+    }
+
+    /**
+     * 执行同步报告中的变更
+     */
+    async fn apply_sync_report<C: ConnectionTrait>(
+        db: &C,
+        report: &SyncReport,
+    ) -> Result<(), AppError> {
+        if !report.to_add.is_empty() {
+            let active_models: Vec<SysEndpointActiveModel> =
+                report.to_add.iter().cloned().map(|e| e.into_active_model()).collect();
+            SysEndpoint::insert_many(active_models).exec(db).await.map_err(AppError::from)?;
+        }
+
+        for endpoint in &report.to_update {
+            let active_model = endpoint.clone().into_active_model().reset_all();
+            active_model.update(db).await.map_err(AppError::from)?;
+        }
+
+        if !report.to_delete.is_empty() {
+            let ids: Vec<String> = report.to_delete.iter().map(|e| e.id.clone()).collect();
+            SysEndpoint::delete_many()
+                .filter(SysEndpointColumn::Id.is_in(ids))
+                .exec(db)
+                .await
+                .map_err(AppError::from)?;
+        }
 
-        controller_map.into_values().collect()
+        Ok(())
+    }
+
+    fn create_endpoint_tree(
+        &self,
+        endpoints: &[SysEndpointModel],
+        granted: &HashSet<(String, String)>,
+        group_by: EndpointGroupBy,
+    ) -> Vec<EndpointTree> {
+        let endpoints: Vec<&SysEndpointModel> = endpoints.iter().collect();
+        match group_by {
+            EndpointGroupBy::Controller => controller_nodes(&endpoints, granted),
+            EndpointGroupBy::Resource => {
+                let mut resource_map: BTreeMap<&str, Vec<&SysEndpointModel>> = BTreeMap::new();
+                for endpoint in endpoints {
+                    resource_map.entry(endpoint.resource.as_str()).or_default().push(endpoint);
+                }
+
+                resource_map
+                    .into_iter()
+                    .map(|(resource, endpoints)| {
+                        group_node(
+                            format!("resource-{}", resource),
+                            resource,
+                            "",
+                            controller_nodes(&endpoints, granted),
+                        )
+                    })
+                    .collect()
+            },
+        }
+    }
+
+    /**
+     * 获取角色在指定域下已授权的端点
+     *
+     * 与 sync_role_permissions 读取策略的方式一致，按 (角色代码, 域代码) 过滤策略，
+     * 返回 (路径, 操作) 集合；未指定角色和域时返回空集合
+     */
+    async fn granted_endpoints(
+        &self,
+        db: &DatabaseConnection,
+        params: &EndpointTreeRequest,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<HashSet<(String, String)>, AppError> {
+        let (role_id, domain) = match (&params.role_id, &params.domain) {
+            (Some(role_id), Some(domain)) => (role_id, domain),
+            (None, None) => return Ok(HashSet::new()),
+            _ => {
+                return Err(EndpointError::ValidationError(
+                    "role_id and domain must be provided together".to_string(),
+                )
+                .into())
+            },
+        };
+
+        let role = SysRole::find_by_id(role_id.as_str())
+            .one(db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| EndpointError::NotFoundError(format!("Role not found: {}", role_id)))?;
+
+        let domain = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(domain.as_str()))
+            .one(db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| EndpointError::NotFoundError(format!("Domain not found: {}", domain)))?;
+
+        let enforcer_read = enforcer.read().await;
+        let policies = enforcer_read.get_filtered_policy(0, vec![role.code, domain.code]);
+
+        Ok(policies
+            .into_iter()
+            .filter(|policy| policy.len() >= 4)
+            .map(|policy| (policy[2].clone(), policy[3].clone()))
+            .collect())
     }
 
     #[allow(dead_code)]
     async fn assign_endpoints(&self, role_id: &str, endpoint_ids: Vec<String>) -> Result<(), EndpointError> {
         let role_id = role_id.to_string();
-        let endpoint_ids = std::sync::Arc::new(endpoint_ids);
+        let endpoint_ids = Arc::new(endpoint_ids);
         // 检查所有端点是否存在
         let endpoints = SysEndpoint::find()
             .filter(SysEndpointColumn::Id.is_in(endpoint_ids.as_ref().clone()))
             .all(&self.db)
-            .await
-            .map_err(|e| EndpointError::DatabaseError(Box::new(e)))?;
+            .await?;
 
         if endpoints.len() != endpoint_ids.len() {
             let found_ids: Vec<String> = endpoints.iter().map(|e| e.id.to_string()).collect();
@@ -200,8 +360,7 @@ impl SysEndpointService {
                 let existing_endpoints = SysRoleMenu::find()
                     .filter(SysRoleMenuColumn::RoleId.eq(&role_id))
                     .all(&txn)
-                    .await
-                    .map_err(|e| EndpointError::DatabaseError(Box::new(e)))?;
+                    .await?;
 
                 // 计算需要添加和删除的端点
                 let existing_ids: Vec<String> = existing_endpoints
@@ -231,8 +390,7 @@ impl SysEndpointService {
                     if !role_menus.is_empty() {
                         SysRoleMenu::insert_many(role_menus)
                             .exec(&txn)
-                            .await
-                            .map_err(|e| EndpointError::DatabaseError(Box::new(e)))?;
+                            .await?;
                     }
                 }
 
@@ -247,8 +405,7 @@ impl SysEndpointService {
                             .filter(SysRoleMenuColumn::RoleId.eq(&role_id))
                             .filter(SysRoleMenuColumn::MenuId.is_in(delete_ids))
                             .exec(&txn)
-                            .await
-                            .map_err(|e| EndpointError::DatabaseError(Box::new(e)))?;
+                            .await?;
                     }
                 }
 
@@ -259,112 +416,56 @@ impl SysEndpointService {
                     to_delete.len()
                 );
 
+                txn.commit().await?;
                 Ok(())
             })
         })
         .await
-        .map_err(|e| match e {
-            AppError { code, message } => EndpointError::DatabaseError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Database error (code {}): {}", code, message),
-            ))),
-        })
     }
 }
 
 #[async_trait]
 impl TEndpointService for SysEndpointService {
-    async fn sync_endpoints(&self, endpoints: Vec<SysEndpointModel>) -> Result<(), AppError> {
+    async fn sync_endpoints(
+        &self,
+        endpoints: Vec<SysEndpointModel>,
+    ) -> Result<SyncReport, AppError> {
         let db = db_helper::get_db_connection().await?;
+        let endpoints = Arc::new(endpoints);
 
-        execute_in_transaction(&db, move |mut txn| {
+        // 端点同步与授权分配可能并发修改相关数据，使用可串行化隔离级别并在冲突时重试
+        execute_in_transaction_with(&db, TxnOptions::serializable(), move |txn| {
+            let endpoints = endpoints.clone();
             Box::pin(async move {
-                // Get existing endpoints
-                let existing_endpoints = SysEndpoint::find()
-                    .all(&mut txn)
-                    .await
-                    .map_err(AppError::from)?;
-
-                // Create maps for easier lookup
-                let existing_map: BTreeMap<String, SysEndpointModel> = existing_endpoints
-                    .into_iter()
-                    .map(|e| (e.path.clone(), e))
-                    .collect();
-
-                let new_map: BTreeMap<String, SysEndpointModel> = endpoints
-                    .into_iter()
-                    .map(|e| (e.path.clone(), e))
-                    .collect();
-
-                // Find endpoints to add, update, and delete
-                let to_add: Vec<SysEndpointModel> = new_map
-                    .values()
-                    .filter(|e| !existing_map.contains_key(&e.path))
-                    .cloned()
-                    .collect();
-
-                let to_update: Vec<SysEndpointModel> = new_map
-                    .values()
-                    .filter(|e| {
-                        existing_map
-                            .get(&e.path)
-                            .map(|existing| existing.method != e.method)
-                            .unwrap_or(false)
-                    })
-                    .cloned()
-                    .collect();
-
-                let to_delete: Vec<String> = existing_map
-                    .keys()
-                    .filter(|path| !new_map.contains_key(*path))
-                    .cloned()
-                    .collect();
-
-                // Perform operations
-                if !to_add.is_empty() {
-                    let active_models: Vec<SysEndpointActiveModel> = to_add
-                        .into_iter()
-                        .map(|e| e.into_active_model())
-                        .collect();
-                    SysEndpoint::insert_many(active_models)
-                        .exec(&mut txn)
-                        .await
-                        .map_err(AppError::from)?;
-                }
-
-                for endpoint in to_update {
-                    let active_model = endpoint.into_active_model();
-                    active_model.update(&mut txn).await.map_err(AppError::from)?;
-                }
-
-                if !to_delete.is_empty() {
-                    SysEndpoint::delete_many()
-                        .filter(SysEndpointColumn::Path.is_in(to_delete))
-                        .exec(&mut txn)
-                        .await
-                        .map_err(AppError::from)?;
-                }
-
-                txn.commit().await.map_err(AppError::from)?;
-                Ok(())
+                let report = Self::diff_endpoints(txn, &endpoints).await?;
+                Self::apply_sync_report(txn, &report).await?;
+                Ok(report)
             })
         })
         .await
     }
 
+    async fn sync_endpoints_dry_run(
+        &self,
+        endpoints: Vec<SysEndpointModel>,
+    ) -> Result<SyncReport, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        Self::diff_endpoints(db.as_ref(), &endpoints).await
+    }
+
     async fn find_paginated_endpoints(
         &self,
         params: EndpointPageRequest,
     ) -> Result<PaginatedData<SysEndpointModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysEndpoint::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any()
-                .add(SysEndpointColumn::Path.contains(keywords))
-                .add(SysEndpointColumn::Method.contains(keywords))
-                .add(SysEndpointColumn::Controller.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(KeywordFilter::new(keywords).condition(&[
+                SysEndpointColumn::Path,
+                SysEndpointColumn::Method,
+                SysEndpointColumn::Controller,
+            ]));
         }
 
         let total = query.clone().count(db.as_ref()).await.map_err(AppError::from)?;
@@ -383,10 +484,452 @@ impl TEndpointService for SysEndpointService {
         ))
     }
 
-    async fn tree_endpoint(&self) -> Result<Vec<EndpointTree>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    async fn tree_endpoint(
+        &self,
+        params: EndpointTreeRequest,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync + 'static>>,
+    ) -> Result<Vec<EndpointTree>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        let granted = self.granted_endpoints(db.as_ref(), &params, enforcer).await?;
         let endpoints = SysEndpoint::find().all(db.as_ref()).await.map_err(AppError::from)?;
 
-        Ok(self.create_endpoint_tree(&endpoints))
+        Ok(self.create_endpoint_tree(&endpoints, &granted, params.group_by))
+    }
+}
+
+/**
+ * 由收集到的路由生成接口记录
+ *
+ * 接口ID由路径和请求方法生成，操作按请求方法写入 read/write
+ */
+pub fn endpoints_from_routes(routes: Vec<RouteInfo>) -> Vec<SysEndpointModel> {
+    let now = timestamps::now();
+    routes
+        .into_iter()
+        .map(|route| {
+            let method = route.method.to_string();
+            SysEndpointModel {
+                id: generate_id(&route.path, &method),
+                resource: resource_of(&route.path).to_string(),
+                action: action_for_method(route.method.as_str()).to_string(),
+                path: route.path,
+                method,
+                controller: route.service_name,
+                summary: Some(route.summary),
+                created_at: now,
+                updated_at: None,
+            }
+        })
+        .collect()
+}
+
+/**
+ * 取接口路径中的资源名
+ *
+ * 资源名为 API 基础路径和版本前缀之后的第一段，如 `/api/v1/user/{id}` 的资源名为 `user`
+ */
+fn resource_of(path: &str) -> &str {
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty()).peekable();
+    segments.next_if_eq(&"api");
+    segments.next_if(|segment| {
+        segment.strip_prefix('v').is_some_and(|version| {
+            !version.is_empty() && version.chars().all(|c| c.is_ascii_digit())
+        })
+    });
+    segments.next().unwrap_or("")
+}
+
+/**
+ * 生成按控制器分组的节点
+ */
+fn controller_nodes(
+    endpoints: &[&SysEndpointModel],
+    granted: &HashSet<(String, String)>,
+) -> Vec<EndpointTree> {
+    let mut controller_map: BTreeMap<&str, Vec<EndpointTree>> = BTreeMap::new();
+    for endpoint in endpoints {
+        controller_map
+            .entry(endpoint.controller.as_str())
+            .or_default()
+            .push(EndpointTree {
+                id: endpoint.id.to_string(),
+                path: endpoint.path.clone(),
+                method: endpoint.method.clone(),
+                action: endpoint.action.clone(),
+                resource: endpoint.resource.clone(),
+                controller: endpoint.controller.clone(),
+                summary: endpoint.summary.clone(),
+                checked: is_granted(granted, &endpoint.path, &endpoint.action),
+                total: None,
+                read_count: None,
+                write_count: None,
+                children: Some(Vec::new()),
+            });
+    }
+
+    controller_map
+        .into_iter()
+        .map(|(controller, children)| {
+            group_node(format!("controller-{}", controller), "", controller, children)
+        })
+        .collect()
+}
+
+/**
+ * 生成分组节点
+ *
+ * 按子节点统计分组下的接口总数和读写接口数，子节点为分组时累加其统计
+ */
+fn group_node(
+    id: String,
+    resource: &str,
+    controller: &str,
+    children: Vec<EndpointTree>,
+) -> EndpointTree {
+    let (mut total, mut read_count, mut write_count) = (0, 0, 0);
+    for child in &children {
+        match child.total {
+            Some(child_total) => {
+                total += child_total;
+                read_count += child.read_count.unwrap_or(0);
+                write_count += child.write_count.unwrap_or(0);
+            },
+            None => {
+                total += 1;
+                if child.action == ACTION_WRITE {
+                    write_count += 1;
+                } else {
+                    read_count += 1;
+                }
+            },
+        }
+    }
+
+    EndpointTree {
+        id,
+        path: String::new(),
+        method: String::new(),
+        action: String::new(),
+        resource: resource.to_string(),
+        controller: controller.to_string(),
+        summary: None,
+        checked: false,
+        total: Some(total),
+        read_count: Some(read_count),
+        write_count: Some(write_count),
+        children: Some(children),
+    }
+}
+
+/**
+ * 生成接口ID
+ *
+ * 根据路径和请求方法生成唯一的接口ID
+ */
+fn generate_id(path: &str, method: &str) -> String {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    let mut hasher = DefaultHasher::new();
+    format!("{}{}", path, method).hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+/**
+ * 判断端点是否已授权
+ *
+ * 策略的操作为 read 或 write，write 策略同时授权同一路径下的 read 端点
+ */
+fn is_granted(granted: &HashSet<(String, String)>, path: &str, action: &str) -> bool {
+    let has = |action: &str| granted.contains(&(path.to_string(), action.to_string()));
+    has(action) || (action == ACTION_READ && has(ACTION_WRITE))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use axum_casbin::casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::Status, sys_domain::ActiveModel as SysDomainActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+    };
+
+    use super::*;
+
+    fn route(path: &str, method: Method, summary: &str) -> RouteInfo {
+        RouteInfo::new(path, method, "SysUserApi", summary)
+    }
+
+    async fn setup_db(routes: Vec<RouteInfo>) -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysEndpoint))).await.unwrap();
+
+        let endpoints = endpoints_from_routes(routes);
+        let report = SysEndpointService::diff_endpoints(&db, &endpoints).await.unwrap();
+        SysEndpointService::apply_sync_report(&db, &report).await.unwrap();
+        db
+    }
+
+    fn paths(endpoints: &[SysEndpointModel]) -> Vec<String> {
+        endpoints.iter().map(|e| format!("{} {}", e.method, e.path)).collect()
+    }
+
+    #[tokio::test]
+    async fn test_sync_report_against_mutated_routes() {
+        let db = setup_db(vec![
+            route("/api/v1/user", Method::GET, "获取用户列表"),
+            route("/api/v1/user", Method::POST, "创建用户"),
+            route("/api/v1/user/{id}", Method::DELETE, "删除用户"),
+        ])
+        .await;
+        let seeded = SysEndpoint::find().all(&db).await.unwrap();
+        assert_eq!(seeded.len(), 3);
+
+        let endpoints = endpoints_from_routes(vec![
+            route("/api/v1/user", Method::GET, "获取用户列表"),
+            route("/api/v1/user", Method::POST, "新建用户"),
+            route("/api/v1/user", Method::PUT, "更新用户"),
+        ]);
+        let report = SysEndpointService::diff_endpoints(&db, &endpoints).await.unwrap();
+        assert_eq!(paths(&report.to_add), ["PUT /api/v1/user"]);
+        assert_eq!(paths(&report.to_update), ["POST /api/v1/user"]);
+        assert_eq!(paths(&report.to_delete), ["DELETE /api/v1/user/{id}"]);
+
+        // 更新保留原接口ID和创建时间
+        let original = seeded.iter().find(|e| e.method == "POST").unwrap();
+        assert_eq!(report.to_update[0].id, original.id);
+        assert_eq!(report.to_update[0].created_at, original.created_at);
+        assert_eq!(report.to_update[0].summary.as_deref(), Some("新建用户"));
+
+        // 预览不修改数据
+        assert_eq!(SysEndpoint::find().all(&db).await.unwrap(), seeded);
+
+        SysEndpointService::apply_sync_report(&db, &report).await.unwrap();
+        let report = SysEndpointService::diff_endpoints(&db, &endpoints).await.unwrap();
+        assert!(report.is_empty());
+
+        let updated = SysEndpoint::find_by_id(original.id.clone()).one(&db).await.unwrap().unwrap();
+        assert_eq!(updated.summary.as_deref(), Some("新建用户"));
+        assert!(updated.updated_at.is_some());
+    }
+
+    async fn seeded_endpoints() -> (SysEndpointService, Vec<SysEndpointModel>) {
+        let routes = vec![
+            RouteInfo::new("/api/v1/user", Method::GET, "SysUserApi", "获取用户列表"),
+            RouteInfo::new("/api/v1/user", Method::POST, "SysUserApi", "创建用户"),
+            RouteInfo::new("/api/v1/user/{id}", Method::DELETE, "SysUserApi", "删除用户"),
+            RouteInfo::new("/api/v1/user/tokens", Method::GET, "SysPersonalTokenApi", "获取令牌"),
+            RouteInfo::new("/api/v1/role", Method::GET, "SysRoleApi", "获取角色列表"),
+            RouteInfo::new("/api/v1/role/{id}", Method::PUT, "SysRoleApi", "更新角色"),
+        ];
+        let db = setup_db(routes).await;
+        let endpoints = SysEndpoint::find().all(&db).await.unwrap();
+        (SysEndpointService::new(db), endpoints)
+    }
+
+    fn summary(node: &EndpointTree) -> (String, Option<usize>, Option<usize>, Option<usize>) {
+        let name = if node.resource.is_empty() { &node.controller } else { &node.resource };
+        (name.clone(), node.total, node.read_count, node.write_count)
+    }
+
+    #[tokio::test]
+    async fn test_tree_grouped_by_controller() {
+        let (service, endpoints) = seeded_endpoints().await;
+        let tree =
+            service.create_endpoint_tree(&endpoints, &HashSet::new(), EndpointGroupBy::Controller);
+
+        let summaries: Vec<_> = tree.iter().map(summary).collect();
+        assert_eq!(
+            summaries,
+            [
+                ("SysPersonalTokenApi".to_string(), Some(1), Some(1), Some(0)),
+                ("SysRoleApi".to_string(), Some(2), Some(1), Some(1)),
+                ("SysUserApi".to_string(), Some(3), Some(1), Some(2)),
+            ]
+        );
+
+        let leaf = &tree[2].children.as_ref().unwrap()[0];
+        assert_eq!(leaf.resource, "user");
+        assert_eq!(leaf.total, None);
+    }
+
+    #[tokio::test]
+    async fn test_tree_grouped_by_resource() {
+        let (service, endpoints) = seeded_endpoints().await;
+        let tree =
+            service.create_endpoint_tree(&endpoints, &HashSet::new(), EndpointGroupBy::Resource);
+
+        let summaries: Vec<_> = tree.iter().map(summary).collect();
+        assert_eq!(
+            summaries,
+            [
+                ("role".to_string(), Some(2), Some(1), Some(1)),
+                ("user".to_string(), Some(4), Some(2), Some(2)),
+            ]
+        );
+
+        // 资源下按控制器分组，控制器节点同样带统计
+        let controllers: Vec<_> = tree[1].children.as_ref().unwrap().iter().map(summary).collect();
+        assert_eq!(
+            controllers,
+            [
+                ("SysPersonalTokenApi".to_string(), Some(1), Some(1), Some(0)),
+                ("SysUserApi".to_string(), Some(3), Some(1), Some(2)),
+            ]
+        );
+    }
+
+    /**
+     * 在接口表所在的库中创建域 built-in、tenant-a 和 built-in 域下的角色 r1
+     */
+    async fn seed_role(db: &DatabaseConnection) {
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+
+        for (id, code) in [("d1", "built-in"), ("d2", "tenant-a")] {
+            SysDomainActiveModel {
+                id: Set(id.to_string()),
+                code: Set(code.to_string()),
+                name: Set(code.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+        SysRoleActiveModel {
+            id: Set("r1".to_string()),
+            code: Set("ROLE_A".to_string()),
+            domain: Set("built-in".to_string()),
+            name: Set("Role A".to_string()),
+            pid: Set("0".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn enforcer(policies: &[[&str; 4]]) -> Arc<RwLock<Enforcer>> {
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        for policy in policies {
+            enforcer.add_policy(policy.iter().map(|v| v.to_string()).collect()).await.unwrap();
+        }
+        Arc::new(RwLock::new(enforcer))
+    }
+
+    fn tree_request(role_id: Option<&str>, domain: Option<&str>) -> EndpointTreeRequest {
+        EndpointTreeRequest {
+            role_id: role_id.map(str::to_string),
+            domain: domain.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn checked_leaves(tree: &[EndpointTree]) -> Vec<String> {
+        let mut leaves: Vec<String> = tree
+            .iter()
+            .flat_map(|node| node.children.iter().flatten())
+            .filter(|leaf| leaf.checked)
+            .map(|leaf| format!("{} {}", leaf.method, leaf.path))
+            .collect();
+        leaves.sort();
+        leaves
+    }
+
+    #[tokio::test]
+    async fn test_tree_marks_endpoints_granted_in_domain() {
+        let (service, endpoints) = seeded_endpoints().await;
+        seed_role(&service.db).await;
+        let enforcer = enforcer(&[
+            ["ROLE_A", "built-in", "/api/v1/user", "write"],
+            ["ROLE_A", "built-in", "/api/v1/role", "read"],
+            // 同一角色代码在其他域下的策略不计入
+            ["ROLE_A", "tenant-a", "/api/v1/role/{id}", "write"],
+        ])
+        .await;
+
+        let params = tree_request(Some("r1"), Some("built-in"));
+        let granted =
+            service.granted_endpoints(&service.db, &params, enforcer.clone()).await.unwrap();
+        let tree = service.create_endpoint_tree(&endpoints, &granted, EndpointGroupBy::Controller);
+        // write 策略同时授予同一路径的读接口
+        assert_eq!(
+            checked_leaves(&tree),
+            ["GET /api/v1/role", "GET /api/v1/user", "POST /api/v1/user"]
+        );
+
+        // 未指定角色和域时不标记
+        let granted = service
+            .granted_endpoints(&service.db, &tree_request(None, None), enforcer)
+            .await
+            .unwrap();
+        assert!(granted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tree_unknown_role_or_domain_is_not_found() {
+        let (service, _) = seeded_endpoints().await;
+        seed_role(&service.db).await;
+        let enforcer = enforcer(&[]).await;
+        let granted = |role_id: Option<&'static str>, domain: Option<&'static str>| {
+            let (service, enforcer) = (service.clone(), enforcer.clone());
+            async move {
+                let params = tree_request(role_id, domain);
+                service.granted_endpoints(&service.db, &params, enforcer).await.unwrap_err()
+            }
+        };
+
+        for err in [
+            granted(Some("missing"), Some("built-in")).await,
+            granted(Some("r1"), Some("missing")).await,
+        ] {
+            assert_eq!(err.code, 404);
+            assert_eq!(err.status, StatusCode::NOT_FOUND);
+        }
+
+        // 角色和域必须同时指定
+        assert_eq!(granted(Some("r1"), None).await.code, 400);
+        assert_eq!(granted(None, Some("built-in")).await.code, 400);
+    }
+
+    #[test]
+    fn test_resource_of() {
+        assert_eq!(resource_of("/api/v1/user/{id}"), "user");
+        assert_eq!(resource_of("/api/v2/role"), "role");
+        assert_eq!(resource_of("/api/version"), "version");
+        assert_eq!(resource_of("/user"), "user");
+        assert_eq!(resource_of("/"), "");
+    }
+
+    #[test]
+    fn test_write_policy_grants_read_endpoint() {
+        let granted: HashSet<(String, String)> = [
+            ("/user".to_string(), ACTION_WRITE.to_string()),
+            ("/role".to_string(), ACTION_READ.to_string()),
+        ]
+        .into();
+
+        assert!(is_granted(&granted, "/user", ACTION_READ));
+        assert!(is_granted(&granted, "/user", ACTION_WRITE));
+        assert!(is_granted(&granted, "/role", ACTION_READ));
+        assert!(!is_granted(&granted, "/role", ACTION_WRITE));
+        assert!(!is_granted(&granted, "/menu", ACTION_READ));
     }
 }
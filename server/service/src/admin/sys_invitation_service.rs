@@ -0,0 +1,493 @@
+/**
+ * 系统邀请码服务模块
+ *
+ * 该模块提供了域邀请码管理相关的核心功能，包括：
+ * - 邀请码分页查询
+ * - 邀请码创建与撤销
+ * - 注册时核销邀请码
+ *
+ * 主要组件
+ * --------
+ * - TInvitationService: 邀请码服务 trait，定义了邀请码管理相关的核心接口
+ * - SysInvitationService: 邀请码服务实现，提供了具体的邀请码管理逻辑
+ *
+ * 功能特性
+ * --------
+ * - 邀请码绑定域和角色，凭邀请码注册的用户归属该域并授予该角色
+ * - 非内置域的操作人只能查询、创建和撤销本域的邀请码
+ * - 核销通过单条条件更新完成，并发注册不会超出可使用次数
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::sys_invitation_service::*;
+ *
+ * // 创建邀请码服务实例
+ * let invitation_service = SysInvitationService;
+ *
+ * // 分页查询邀请码
+ * let invitations = invitation_service.find_paginated_invitations(InvitationPageRequest {
+ *     domain: Some("built-in".to_string()),
+ *     page_details: PageDetails {
+ *         current: 1,
+ *         size: 10,
+ *     },
+ * }, &user).await?;
+ */
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+use server_core::{
+    web::{auth::User, error::AppError, page::PaginatedData},
+    paginated_data,
+};
+use server_model::admin::{
+    entities::{
+        prelude::{SysDomain, SysInvitation, SysRole},
+        sys_domain::Column as SysDomainColumn,
+        sys_role::Column as SysRoleColumn,
+        sys_invitation::{
+            ActiveModel as SysInvitationActiveModel, Column as SysInvitationColumn,
+            Model as SysInvitationModel,
+        },
+    },
+    input::{CreateInvitationInput, InvitationPageRequest},
+    timestamps,
+};
+use ulid::Ulid;
+
+use crate::{
+    admin::{sys_domain_service::BUILT_IN_DOMAIN_CODE, sys_invitation_error::InvitationError},
+    helper::db_helper,
+};
+
+/**
+ * 邀请码服务 trait
+ *
+ * 定义了邀请码管理相关的核心接口，包括：
+ * - 邀请码分页查询
+ * - 邀请码创建与撤销
+ */
+#[async_trait]
+pub trait TInvitationService {
+    /**
+     * 分页查询邀请码
+     *
+     * 按创建时间倒序返回，可按域过滤；非内置域的操作人只能查询本域的邀请码
+     *
+     * @param params 分页查询参数，包含域和分页信息
+     * @param operator 操作人
+     * @return Result<PaginatedData<SysInvitationModel>, AppError> 分页邀请码数据或错误
+     */
+    async fn find_paginated_invitations(
+        &self,
+        params: InvitationPageRequest,
+        operator: &User,
+    ) -> Result<PaginatedData<SysInvitationModel>, AppError>;
+
+    /**
+     * 创建邀请码
+     *
+     * 检查目标域存在且角色属于目标域，邀请码由服务端生成；
+     * 非内置域的操作人只能创建本域的邀请码
+     *
+     * @param input 邀请码创建参数
+     * @param operator 操作人，用户ID记录为创建人
+     * @return Result<SysInvitationModel, AppError> 创建的邀请码或错误
+     */
+    async fn create_invitation(
+        &self,
+        input: CreateInvitationInput,
+        operator: &User,
+    ) -> Result<SysInvitationModel, AppError>;
+
+    /**
+     * 撤销邀请码
+     *
+     * 撤销后邀请码立即失效，已注册的用户不受影响；
+     * 非内置域的操作人撤销其他域的邀请码时与邀请码不存在相同
+     *
+     * @param id 邀请码ID
+     * @param operator 操作人
+     * @return Result<(), AppError> 撤销结果
+     */
+    async fn revoke_invitation(&self, id: &str, operator: &User) -> Result<(), AppError>;
+}
+
+/**
+ * 系统邀请码服务
+ *
+ * 实现了邀请码管理相关的核心功能
+ */
+pub struct SysInvitationService;
+
+impl SysInvitationService {
+    /**
+     * 核销一次邀请码
+     *
+     * 在一条条件更新中完成“未过期、仍有剩余次数”的校验与使用次数加一，
+     * 并发注册时由数据库保证不会超出可使用次数。
+     * 更新未命中时再查询一次邀请码以区分不存在、已过期与次数用尽。
+     *
+     * @param db 数据库连接或事务
+     * @param code 邀请码
+     * @param now 当前时间
+     * @return Result<SysInvitationModel, InvitationError> 核销后的邀请码或错误
+     */
+    pub(crate) async fn consume_invitation<C: ConnectionTrait>(
+        db: &C,
+        code: &str,
+        now: NaiveDateTime,
+    ) -> Result<SysInvitationModel, InvitationError> {
+        let result = SysInvitation::update_many()
+            .col_expr(
+                SysInvitationColumn::UsedCount,
+                Expr::col(SysInvitationColumn::UsedCount).add(1),
+            )
+            .filter(SysInvitationColumn::Code.eq(code))
+            .filter(SysInvitationColumn::ExpiresAt.gt(now))
+            .filter(
+                Expr::col(SysInvitationColumn::UsedCount)
+                    .lt(Expr::col(SysInvitationColumn::MaxUses)),
+            )
+            .exec(db)
+            .await?;
+
+        let invitation = SysInvitation::find()
+            .filter(SysInvitationColumn::Code.eq(code))
+            .one(db)
+            .await?
+            .ok_or(InvitationError::InvitationNotFound)?;
+
+        if result.rows_affected == 0 {
+            return Err(if invitation.expires_at <= now {
+                InvitationError::InvitationExpired
+            } else {
+                InvitationError::InvitationExhausted
+            });
+        }
+        Ok(invitation)
+    }
+
+    /**
+     * 操作人可管理的域，内置域的操作人可管理所有域
+     */
+    fn operator_scope(operator: &User) -> Option<String> {
+        let domain = operator.domain();
+        (domain != BUILT_IN_DOMAIN_CODE).then_some(domain)
+    }
+
+    async fn create_invitation_in<C: ConnectionTrait>(
+        db: &C,
+        input: CreateInvitationInput,
+        operator: &User,
+    ) -> Result<SysInvitationModel, InvitationError> {
+        if Self::operator_scope(operator).is_some_and(|domain| domain != input.domain) {
+            return Err(InvitationError::DomainNotAllowed);
+        }
+
+        let domain = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(input.domain.as_str()))
+            .one(db)
+            .await?;
+        if domain.is_none() {
+            return Err(InvitationError::DomainNotFound);
+        }
+
+        // 角色必须属于邀请码的域，否则注册的用户会获得其他域的角色
+        let role = SysRole::find_by_id(&input.role_id)
+            .filter(SysRoleColumn::Domain.eq(input.domain.as_str()))
+            .one(db)
+            .await?;
+        if role.is_none() {
+            return Err(InvitationError::RoleNotFound);
+        }
+
+        let invitation = SysInvitationActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(Ulid::new().to_string()),
+            domain: Set(input.domain),
+            role_id: Set(input.role_id),
+            max_uses: Set(input.max_uses),
+            used_count: Set(0),
+            expires_at: Set(input.expires_at),
+            created_at: Set(timestamps::now()),
+            created_by: Set(operator.user_id()),
+        };
+
+        Ok(invitation.insert(db).await?)
+    }
+
+    async fn revoke_invitation_in<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+        operator: &User,
+    ) -> Result<(), InvitationError> {
+        let mut delete = SysInvitation::delete_by_id(id);
+        if let Some(domain) = Self::operator_scope(operator) {
+            delete = delete.filter(SysInvitationColumn::Domain.eq(domain));
+        }
+        let result = delete.exec(db).await?;
+
+        if result.rows_affected == 0 {
+            return Err(InvitationError::InvitationNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TInvitationService for SysInvitationService {
+    async fn find_paginated_invitations(
+        &self,
+        params: InvitationPageRequest,
+        operator: &User,
+    ) -> Result<PaginatedData<SysInvitationModel>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        let mut query = SysInvitation::find().order_by_desc(SysInvitationColumn::CreatedAt);
+
+        if let Some(ref domain) = params.domain {
+            query = query.filter(SysInvitationColumn::Domain.eq(domain.as_str()));
+        }
+        if let Some(domain) = Self::operator_scope(operator) {
+            query = query.filter(SysInvitationColumn::Domain.eq(domain));
+        }
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(paginated_data!(
+            total,
+            params.page_details.current,
+            params.page_details.size,
+            records
+        ))
+    }
+
+    async fn create_invitation(
+        &self,
+        input: CreateInvitationInput,
+        operator: &User,
+    ) -> Result<SysInvitationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        Ok(Self::create_invitation_in(db.as_ref(), input, operator).await?)
+    }
+
+    async fn revoke_invitation(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        Ok(Self::revoke_invitation_in(db.as_ref(), id, operator).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::Status, sys_domain::ActiveModel as SysDomainActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+    };
+
+    use server_core::web::error::{ApiError, StatusCode};
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysInvitation)))
+            .await
+            .unwrap();
+        db
+    }
+
+    async fn setup_domain_db() -> DatabaseConnection {
+        let db = setup_db().await;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysDomain))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        for (domain, role_id) in [("built-in", "role-admin"), ("tenant-a", "role-member")] {
+            SysDomainActiveModel {
+                id: Set(domain.to_string()),
+                code: Set(domain.to_string()),
+                name: Set(domain.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+            SysRoleActiveModel {
+                id: Set(role_id.to_string()),
+                code: Set(role_id.to_uppercase()),
+                domain: Set(domain.to_string()),
+                name: Set(role_id.to_string()),
+                pid: Set("0".to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    fn operator(user_id: &str, domain: &str) -> User {
+        User::new(user_id.to_string(), format!("{}-name", user_id), domain.to_string())
+    }
+
+    fn create_input(domain: &str, role_id: &str) -> CreateInvitationInput {
+        CreateInvitationInput {
+            domain: domain.to_string(),
+            role_id: role_id.to_string(),
+            max_uses: 1,
+            expires_at: timestamps::now() + Duration::days(1),
+        }
+    }
+
+    async fn insert_invitation<C: ConnectionTrait>(
+        db: &C,
+        code: &str,
+        max_uses: i32,
+        expires_at: NaiveDateTime,
+    ) {
+        SysInvitationActiveModel {
+            id: Set(code.to_string()),
+            code: Set(code.to_string()),
+            domain: Set("built-in".to_string()),
+            role_id: Set("role".to_string()),
+            max_uses: Set(max_uses),
+            used_count: Set(0),
+            expires_at: Set(expires_at),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_consume_invitation_until_exhausted() {
+        let db = setup_db().await;
+        let now = timestamps::now();
+        insert_invitation(&db, "twice", 2, now + Duration::days(1)).await;
+
+        let first = SysInvitationService::consume_invitation(&db, "twice", now).await.unwrap();
+        assert_eq!(first.used_count, 1);
+        let second = SysInvitationService::consume_invitation(&db, "twice", now).await.unwrap();
+        assert_eq!(second.used_count, 2);
+
+        assert!(matches!(
+            SysInvitationService::consume_invitation(&db, "twice", now).await,
+            Err(InvitationError::InvitationExhausted)
+        ));
+        let stored = SysInvitation::find_by_id("twice").one(&db).await.unwrap().unwrap();
+        assert_eq!(stored.used_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_consume_invitation_rejects_expired_and_unknown() {
+        let db = setup_db().await;
+        let now = timestamps::now();
+        insert_invitation(&db, "expired", 5, now - Duration::minutes(1)).await;
+
+        assert!(matches!(
+            SysInvitationService::consume_invitation(&db, "expired", now).await,
+            Err(InvitationError::InvitationExpired)
+        ));
+        assert!(matches!(
+            SysInvitationService::consume_invitation(&db, "unknown", now).await,
+            Err(InvitationError::InvitationNotFound)
+        ));
+
+        let stored = SysInvitation::find_by_id("expired").one(&db).await.unwrap().unwrap();
+        assert_eq!(stored.used_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_invitation_scopes_role_and_domain() {
+        let db = setup_domain_db().await;
+        let admin = operator("u-admin", "built-in");
+        let tenant = operator("u-tenant", "tenant-a");
+
+        // 角色必须属于邀请码的域
+        assert!(matches!(
+            SysInvitationService::create_invitation_in(
+                &db,
+                create_input("tenant-a", "role-admin"),
+                &admin
+            )
+            .await,
+            Err(InvitationError::RoleNotFound)
+        ));
+
+        // 非内置域的操作人只能创建本域的邀请码
+        let err = SysInvitationService::create_invitation_in(
+            &db,
+            create_input("built-in", "role-admin"),
+            &tenant,
+        )
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(err, InvitationError::DomainNotAllowed));
+        assert_eq!(err.status(), StatusCode::FORBIDDEN);
+
+        let invitation = SysInvitationService::create_invitation_in(
+            &db,
+            create_input("tenant-a", "role-member"),
+            &tenant,
+        )
+        .await
+        .unwrap();
+        assert_eq!(invitation.created_by, "u-tenant");
+        SysInvitationService::create_invitation_in(
+            &db,
+            create_input("tenant-a", "role-member"),
+            &admin,
+        )
+        .await
+        .unwrap();
+        assert_eq!(SysInvitation::find().count(&db).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_invitation_scoped_to_operator_domain() {
+        let db = setup_db().await;
+        insert_invitation(&db, "builtin", 1, timestamps::now() + Duration::days(1)).await;
+        let tenant = operator("u-tenant", "tenant-a");
+
+        // 其他域的邀请码与不存在的邀请码返回相同的错误，且不被删除
+        assert!(matches!(
+            SysInvitationService::revoke_invitation_in(&db, "builtin", &tenant).await,
+            Err(InvitationError::InvitationNotFound)
+        ));
+        assert!(SysInvitation::find_by_id("builtin").one(&db).await.unwrap().is_some());
+
+        SysInvitationService::revoke_invitation_in(&db, "builtin", &operator("u1", "built-in"))
+            .await
+            .unwrap();
+        assert!(SysInvitation::find_by_id("builtin").one(&db).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,65 @@
+/**
+ * 后台任务服务模块
+ *
+ * 该模块提供了后台周期任务的管理功能，包括：
+ * - 查询已注册任务的执行状态
+ * - 立即执行指定任务
+ *
+ * 任务由 `server_core::scheduler` 的全局调度器调度，本服务只读取状态和触发执行。
+ *
+ * 主要组件
+ * --------
+ * - TJobService: 后台任务服务 trait
+ * - SysJobService: 后台任务服务实现
+ */
+
+use async_trait::async_trait;
+use server_core::{
+    scheduler::{scheduler, JobStatus},
+    web::error::AppError,
+};
+
+use crate::admin::errors::sys_job_error::JobError;
+
+/**
+ * 后台任务服务 trait
+ */
+#[async_trait]
+pub trait TJobService {
+    /**
+     * 查询所有任务的执行状态
+     *
+     * @return Vec<JobStatus> 任务状态列表，按任务名称排序
+     */
+    async fn list_jobs(&self) -> Vec<JobStatus>;
+
+    /**
+     * 立即执行任务
+     *
+     * 任务在后台执行，不等待执行结束；执行结果通过任务状态查询
+     *
+     * @param name 任务名称
+     * @return Result<JobStatus, AppError> 触发后的任务状态，任务不存在或正在执行时返回错误
+     */
+    async fn run_job(&self, name: &str) -> Result<JobStatus, AppError>;
+}
+
+/**
+ * 后台任务服务
+ */
+#[derive(Clone)]
+pub struct SysJobService;
+
+#[async_trait]
+impl TJobService for SysJobService {
+    async fn list_jobs(&self) -> Vec<JobStatus> {
+        scheduler().statuses()
+    }
+
+    async fn run_job(&self, name: &str) -> Result<JobStatus, AppError> {
+        scheduler().run_now(name).map_err(JobError::from)?;
+        scheduler()
+            .status(name)
+            .ok_or_else(|| JobError::JobNotFound(name.to_string()).into())
+    }
+}
@@ -4,6 +4,7 @@
  * 该模块提供了登录日志管理相关的核心功能，包括：
  * - 登录日志分页查询
  * - 关键字搜索
+ * - 用户本人登录历史查询（标记异地登录）
  *
  * 主要组件
  * --------
@@ -24,10 +25,17 @@
  * }).await?;
  */
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
 use server_core::{
-    web::{error::AppError, page::PaginatedData},
+    web::{
+        error::AppError,
+        page::{KeywordFilter, PaginatedData},
+    },
     paginated_data,
 };
 use server_model::admin::{
@@ -35,16 +43,25 @@ use server_model::admin::{
         prelude::SysLoginLog,
         sys_login_log::{Column as SysLoginLogColumn, Model as SysLoginLogModel},
     },
-    input::LoginLogPageRequest,
+    input::{LoginHistoryRequest, LoginLogPageRequest},
+    output::{LoginHistoryOutput, LoginLogOutput},
 };
 
 use crate::helper::db_helper;
 
+/** 登录历史默认返回条数 */
+const DEFAULT_LOGIN_HISTORY_LIMIT: u64 = 20;
+/** 登录历史最大返回条数 */
+const MAX_LOGIN_HISTORY_LIMIT: u64 = 100;
+/** 计算常用国家时参考的最近登录记录数 */
+const LOGIN_HISTORY_WINDOW: u64 = 100;
+
 /**
  * 登录日志服务 trait
  *
  * 定义了登录日志管理相关的核心接口，包括：
  * - 日志分页查询
+ * - 用户本人登录历史查询
  *
  * 使用示例：
  * let log_service = SysLoginLogService;
@@ -55,12 +72,28 @@ pub trait TLoginLogService {
     /**
      * 分页查询登录日志
      * @param params 分页查询参数
-     * @return Result<PaginatedData<SysLoginLogModel>, AppError>
+     * @return Result<PaginatedData<LoginLogOutput>, AppError>
      */
     async fn find_paginated_login_logs(
         &self,
         params: LoginLogPageRequest,
-    ) -> Result<PaginatedData<SysLoginLogModel>, AppError>;
+    ) -> Result<PaginatedData<LoginLogOutput>, AppError>;
+
+    /**
+     * 查询用户本人最近的登录历史
+     *
+     * 只按用户ID过滤，与调用者的角色无关，不含失败的登录尝试。登录国家与最近登录记录中
+     * 最常见的国家不一致时标记为可疑。
+     *
+     * @param user_id 当前认证用户ID
+     * @param params 查询参数
+     * @return Result<Vec<LoginHistoryOutput>, AppError>
+     */
+    async fn find_login_history(
+        &self,
+        user_id: &str,
+        params: LoginHistoryRequest,
+    ) -> Result<Vec<LoginHistoryOutput>, AppError>;
 }
 
 /**
@@ -75,18 +108,18 @@ impl TLoginLogService for SysLoginLogService {
     async fn find_paginated_login_logs(
         &self,
         params: LoginLogPageRequest,
-    ) -> Result<PaginatedData<SysLoginLogModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PaginatedData<LoginLogOutput>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysLoginLog::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any()
-                .add(SysLoginLogColumn::Domain.contains(keywords))
-                .add(SysLoginLogColumn::Username.contains(keywords))
-                .add(SysLoginLogColumn::Ip.contains(keywords))
-                .add(SysLoginLogColumn::Address.contains(keywords))
-                .add(SysLoginLogColumn::UserAgent.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(KeywordFilter::new(keywords).condition(&[
+                SysLoginLogColumn::Domain,
+                SysLoginLogColumn::Username,
+                SysLoginLogColumn::Ip,
+                SysLoginLogColumn::Address,
+                SysLoginLogColumn::UserAgent,
+            ]));
         }
 
         query = query.order_by_desc(SysLoginLogColumn::CreatedAt);
@@ -101,7 +134,10 @@ impl TLoginLogService for SysLoginLogService {
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
-            .map_err(AppError::from)?;
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(LoginLogOutput::from)
+            .collect();
 
         Ok(paginated_data!(
             total,
@@ -110,4 +146,130 @@ impl TLoginLogService for SysLoginLogService {
             records
         ))
     }
+    async fn find_login_history(
+        &self,
+        user_id: &str,
+        params: LoginHistoryRequest,
+    ) -> Result<Vec<LoginHistoryOutput>, AppError> {
+        let limit = params
+            .limit
+            .unwrap_or(DEFAULT_LOGIN_HISTORY_LIMIT)
+            .clamp(1, MAX_LOGIN_HISTORY_LIMIT);
+
+        let db = db_helper::get_read_connection().await?;
+        let records = SysLoginLog::find()
+            .filter(SysLoginLogColumn::UserId.eq(user_id))
+            .filter(SysLoginLogColumn::Success.eq(true))
+            .order_by_desc(SysLoginLogColumn::LoginTime)
+            .limit(limit.max(LOGIN_HISTORY_WINDOW))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(to_login_history(records, limit as usize))
+    }
+}
+
+/**
+ * 从IP解析出的地址中提取国家
+ *
+ * 地址格式为 `国家|区域|省份|城市|ISP`，无法解析的地址返回 None
+ */
+pub(crate) fn country_of(address: &str) -> Option<&str> {
+    address
+        .split('|')
+        .next()
+        .map(str::trim)
+        .filter(|country| !country.is_empty() && *country != "0" && address.contains('|'))
+}
+
+/**
+ * 转换为登录历史并标记可疑登录
+ *
+ * 常用国家取所有记录中出现次数最多的国家，次数相同时取最近出现的国家；
+ * 无法解析国家的记录不参与统计，也不会被标记为可疑
+ */
+fn to_login_history(records: Vec<SysLoginLogModel>, limit: usize) -> Vec<LoginHistoryOutput> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut usual_country: Option<(&str, usize)> = None;
+    for country in records.iter().rev().filter_map(|record| country_of(&record.address)) {
+        let count = counts.entry(country).or_default();
+        *count += 1;
+        if usual_country.map(|(_, max)| *count >= max).unwrap_or(true) {
+            usual_country = Some((country, *count));
+        }
+    }
+    let usual_country = usual_country.map(|(country, _)| country.to_string());
+
+    records
+        .into_iter()
+        .take(limit)
+        .map(|record| {
+            let suspicious = match (country_of(&record.address), usual_country.as_deref()) {
+                (Some(country), Some(usual)) => country != usual,
+                _ => false,
+            };
+            LoginHistoryOutput {
+                login_time: record.login_time,
+                ip: record.ip,
+                address: record.address,
+                user_agent: record.user_agent,
+                login_type: record.r#type,
+                suspicious,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn login_log(address: &str) -> SysLoginLogModel {
+        let now = NaiveDateTime::default();
+        SysLoginLogModel {
+            id: String::new(),
+            user_id: "1".to_string(),
+            username: "admin".to_string(),
+            domain: "built-in".to_string(),
+            login_time: now,
+            ip: "127.0.0.1".to_string(),
+            port: None,
+            address: address.to_string(),
+            user_agent: String::new(),
+            request_id: String::new(),
+            r#type: "PC".to_string(),
+            created_at: now,
+            created_by: "admin".to_string(),
+            success: true,
+            failure_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_country_of() {
+        assert_eq!(country_of("中国|0|广东省|深圳市|电信"), Some("中国"));
+        assert_eq!(country_of("0|0|0|内网IP|内网IP"), None);
+        assert_eq!(country_of("localhost"), None);
+        assert_eq!(country_of(""), None);
+    }
+
+    #[test]
+    fn test_to_login_history_marks_foreign_country() {
+        let records = vec![
+            login_log("美国|0|加利福尼亚|洛杉矶|0"),
+            login_log("中国|0|广东省|深圳市|电信"),
+            login_log("0|0|0|内网IP|内网IP"),
+            login_log("中国|0|北京|北京市|联通"),
+        ];
+
+        let history = to_login_history(records, 3);
+
+        assert_eq!(history.len(), 3);
+        assert!(history[0].suspicious);
+        assert!(!history[1].suspicious);
+        assert!(!history[2].suspicious);
+    }
 }
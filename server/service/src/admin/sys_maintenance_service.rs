@@ -0,0 +1,132 @@
+/**
+ * 维护模式服务模块
+ *
+ * 该模块提供了全局维护模式开关，包括：
+ * - 查询当前维护模式状态
+ * - 开启或关闭维护模式
+ * - 启动时从Redis恢复维护模式状态
+ *
+ * 状态保存在内存中供中间件快速读取，Redis可用时同时持久化，
+ * 使单实例重启后维护模式保持不变；Redis不可用时仅保存在内存中。
+ *
+ * 主要组件
+ * --------
+ * - TMaintenanceService: 维护模式服务 trait
+ * - SysMaintenanceService: 维护模式服务实现
+ */
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use server_core::web::error::AppError;
+use server_global::{project_info, redis_key::KeyBuilder};
+use server_model::admin::{input::UpdateMaintenanceInput, output::MaintenanceState, timestamps};
+use tokio::sync::RwLock;
+
+use crate::helper::redis_helper::{RedisOps, RedisSource};
+
+/** 当前维护模式状态 */
+static MAINTENANCE_STATE: Lazy<RwLock<MaintenanceState>> =
+    Lazy::new(|| RwLock::new(MaintenanceState::default()));
+
+/**
+ * 维护模式服务 trait
+ *
+ * 定义了维护模式相关的核心接口，包括：
+ * - 查询维护模式状态
+ * - 设置维护模式
+ * - 从Redis恢复维护模式状态
+ */
+#[async_trait]
+pub trait TMaintenanceService {
+    /**
+     * 查询当前维护模式状态
+     *
+     * 只读取内存中的状态，可在每个请求中调用
+     *
+     * @return MaintenanceState 维护模式状态
+     */
+    async fn get_maintenance_state(&self) -> MaintenanceState;
+
+    /**
+     * 设置维护模式
+     *
+     * Redis可用时先写入Redis，写入失败则返回错误且不修改当前状态
+     *
+     * @param input 维护模式参数
+     * @param operator 操作人
+     * @return Result<MaintenanceState, AppError> 设置后的维护模式状态或错误
+     */
+    async fn update_maintenance_state(
+        &self,
+        input: UpdateMaintenanceInput,
+        operator: &str,
+    ) -> Result<MaintenanceState, AppError>;
+
+    /**
+     * 从Redis恢复维护模式状态
+     *
+     * 应在Redis初始化之后、接收请求之前调用，Redis不可用或无记录时保持关闭
+     *
+     * @return Result<(), AppError> 恢复结果
+     */
+    async fn load_maintenance_state(&self) -> Result<(), AppError>;
+}
+
+/**
+ * 维护模式服务
+ */
+#[derive(Clone)]
+pub struct SysMaintenanceService;
+
+impl SysMaintenanceService {
+    async fn maintenance_ops() -> Option<RedisOps> {
+        RedisOps::new(RedisSource::Primary).await.ok()
+    }
+}
+
+#[async_trait]
+impl TMaintenanceService for SysMaintenanceService {
+    async fn get_maintenance_state(&self) -> MaintenanceState {
+        MAINTENANCE_STATE.read().await.clone()
+    }
+
+    async fn update_maintenance_state(
+        &self,
+        input: UpdateMaintenanceInput,
+        operator: &str,
+    ) -> Result<MaintenanceState, AppError> {
+        let state = MaintenanceState {
+            enabled: input.enabled,
+            message: input.message,
+            allow_roles: input.allow_roles,
+            updated_by: Some(operator.to_string()),
+            updated_at: Some(timestamps::now()),
+        };
+
+        if let Some(ops) = Self::maintenance_ops().await {
+            ops.set_json(&KeyBuilder::maintenance(), &state, None).await?;
+        }
+
+        *MAINTENANCE_STATE.write().await = state.clone();
+        project_info!(
+            "Maintenance mode {} by {}",
+            if state.enabled { "enabled" } else { "disabled" },
+            operator
+        );
+        Ok(state)
+    }
+
+    async fn load_maintenance_state(&self) -> Result<(), AppError> {
+        let Some(ops) = Self::maintenance_ops().await else {
+            return Ok(());
+        };
+
+        if let Some(state) = ops.get_json::<MaintenanceState>(&KeyBuilder::maintenance()).await? {
+            if state.enabled {
+                project_info!("Maintenance mode restored from Redis");
+            }
+            *MAINTENANCE_STATE.write().await = state;
+        }
+        Ok(())
+    }
+}
@@ -15,10 +15,10 @@
  * 功能特性
  * --------
  * - 菜单树：支持构建菜单树结构
- * - 菜单查询：支持获取菜单列表和常量路由
+ * - 菜单查询：支持获取菜单列表和常量路由，常量路由可按域返回全局及该域的路由
  * - 菜单创建：支持创建新菜单，包括路由名称唯一性检查
  * - 菜单更新：支持更新菜单信息，包括父菜单和循环引用检查
- * - 菜单删除：支持删除菜单
+ * - 菜单删除：支持删除菜单，可选级联删除子孙菜单及角色菜单关联
  * - 角色菜单：支持获取角色关联的菜单ID
  *
  * 使用示例
@@ -52,30 +52,43 @@
  *     constant: false,
  *     href: None,
  *     multi_tab: false,
+ *     domain: None,
  * }).await?;
  */
 
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
 use async_trait::async_trait;
-use chrono::Local;
+use axum_casbin::casbin::CoreApi;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set,
-    IntoActiveModel, QuerySelect,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, Set, IntoActiveModel, QuerySelect, TransactionTrait,
 };
 use server_model::admin::{
     entities::{
-        prelude::{SysMenu, SysRoleMenu},
+        prelude::{SysDomain, SysMenu, SysRole, SysRoleMenu},
         sea_orm_active_enums::{MenuType, Status},
+        sys_domain::Column as SysDomainColumn,
         sys_menu::{ActiveModel as SysMenuActiveModel, Column as SysMenuColumn, Model as SysMenuModel},
+        sys_role::Column as SysRoleColumn,
         sys_role_menu::Column as SysRoleMenuColumn,
     },
-    input::{CreateMenuInput, UpdateMenuInput},
-    output::{MenuRoute, MenuTree, RouteMeta},
+    input::{normalize_route_path, CreateMenuInput, UpdateMenuInput},
+    output::{MenuDeleteOutput, MenuRoute, MenuTree, RouteMeta},
 };
+use server_core::web::CurrentUserContext;
 use server_utils::TreeBuilder;
+use tokio::sync::RwLock;
 
 use crate::{
-    admin::errors::sys_menu_error::MenuError,
-    helper::db_helper,
+    admin::{errors::sys_menu_error::MenuError, SysDomainService, TDomainService},
+    helper::{
+        audit_fields, db_helper, user_name_resolver::resolve_user_names,
+        user_route_cache::notify_menu_assignment_changed,
+    },
 };
 
 /**
@@ -115,7 +128,7 @@ pub trait TMenuService {
     /**
      * 获取菜单列表
      *
-     * 获取所有菜单的树形结构
+     * 获取所有菜单的树形结构，包含创建人和更新人用户名
      *
      * @return Result<Vec<MenuTree>, MenuError> 菜单树或错误
      */
@@ -124,11 +137,13 @@ pub trait TMenuService {
     /**
      * 获取常量路由
      *
-     * 获取所有启用状态的常量路由
+     * 获取启用状态的全局常量路由；指定域时同时返回该域的常量路由，
+     * 域必须在登录页可选域列表中
      *
+     * @param domain 域代码（可选）
      * @return Result<Vec<MenuRoute>, MenuError> 常量路由列表或错误
      */
-    async fn get_constant_routes(&self) -> Result<Vec<MenuRoute>, MenuError>;
+    async fn get_constant_routes(&self, domain: Option<&str>) -> Result<Vec<MenuRoute>, MenuError>;
 
     /**
      * 创建菜单
@@ -171,6 +186,22 @@ pub trait TMenuService {
      */
     async fn delete_menu(&self, id: i32) -> Result<(), MenuError>;
 
+    /**
+     * 级联删除菜单
+     *
+     * 在同一事务中删除菜单及其全部子孙菜单，并删除它们的角色菜单关联；
+     * 若有角色失去菜单，提交后重新加载权限策略
+     *
+     * @param id 菜单ID
+     * @param enforcer 权限执行器
+     * @return Result<MenuDeleteOutput, MenuError> 被删除的菜单ID及受影响的角色代码
+     */
+    async fn delete_menu_cascade(
+        &self,
+        id: i32,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+    ) -> Result<MenuDeleteOutput, MenuError>;
+
     /**
      * 获取角色菜单ID列表
      *
@@ -222,6 +253,7 @@ pub trait TMenuService {
  *     constant: false,
  *     href: None,
  *     multi_tab: false,
+ *     domain: None,
  * }).await?;
  */
 #[derive(Clone)]
@@ -234,9 +266,10 @@ impl SysMenuService {
      * 将菜单模型转换为菜单树节点
      *
      * @param menu 菜单模型
+     * @param names 用户ID到用户名，用于填充创建人和更新人用户名
      * @return MenuTree 菜单树节点
      */
-    fn build_menu_tree(menu: &SysMenuModel) -> MenuTree {
+    fn build_menu_tree(menu: &SysMenuModel, names: &HashMap<String, String>) -> MenuTree {
         MenuTree {
             id: menu.id,
             pid: menu.pid.clone(),
@@ -257,10 +290,13 @@ impl SysMenuService {
             constant: menu.constant,
             href: menu.href.clone(),
             multi_tab: menu.multi_tab,
+            domain: menu.domain.clone(),
             created_at: menu.created_at,
             created_by: menu.created_by.clone(),
             updated_at: menu.updated_at,
             updated_by: menu.updated_by.clone(),
+            created_by_name: names.get(&menu.created_by).cloned(),
+            updated_by_name: menu.updated_by.as_ref().and_then(|id| names.get(id).cloned()),
             children: None,
         }
     }
@@ -320,17 +356,22 @@ impl SysMenuService {
     /**
      * 检查父菜单
      *
-     * 检查父菜单是否存在且为目录类型
+     * 按钮的父菜单必须存在且为菜单类型，其他菜单的父菜单必须存在且为目录类型
      *
      * @param pid 父菜单ID
+     * @param menu_type 当前菜单类型
      * @return Result<(), MenuError> 检查结果
      *
      * 错误
      * -----
      * - ParentMenuNotFound: 父菜单不存在
      * - ParentNotDirectory: 父菜单不是目录类型
+     * - ParentNotMenu: 按钮的父菜单不是菜单类型
      */
-    async fn check_parent_menu(&self, pid: &str) -> Result<(), MenuError> {
+    async fn check_parent_menu(&self, pid: &str, menu_type: &MenuType) -> Result<(), MenuError> {
+        if *menu_type == MenuType::Button {
+            return self.check_button_parent(pid).await;
+        }
         if pid == "0" {
             return Ok(());
         }
@@ -349,6 +390,28 @@ impl SysMenuService {
         }
     }
 
+    /**
+     * 检查按钮的父菜单
+     *
+     * @param pid 父菜单ID
+     * @return Result<(), MenuError> 检查结果
+     */
+    async fn check_button_parent(&self, pid: &str) -> Result<(), MenuError> {
+        let db = db_helper::get_db_connection().await?;
+        let parent = SysMenu::find()
+            .filter(SysMenuColumn::Id.eq(pid.parse::<i32>().unwrap_or(0)))
+            .one(db.as_ref())
+            .await
+            .map_err(MenuError::from)?;
+
+        match parent {
+            Some(menu) if menu.menu_type == MenuType::Menu => Ok(()),
+            Some(_) => Err(MenuError::ParentNotMenu),
+            None if pid == "0" => Err(MenuError::ParentNotMenu),
+            None => Err(MenuError::ParentMenuNotFound),
+        }
+    }
+
     /**
      * 检查循环引用
      *
@@ -391,6 +454,124 @@ impl SysMenuService {
         }
         Ok(())
     }
+
+    /**
+     * 检查菜单所属域
+     *
+     * 全局菜单不检查，指定域时域必须存在
+     *
+     * @param domain 域代码（可选）
+     * @return Result<(), MenuError> 检查结果
+     *
+     * 错误
+     * -----
+     * - DomainNotFound: 域不存在
+     */
+    async fn check_menu_domain(&self, domain: Option<&str>) -> Result<(), MenuError> {
+        let Some(domain) = domain else {
+            return Ok(());
+        };
+
+        let db = db_helper::get_db_connection().await?;
+        let exists = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(domain))
+            .one(db.as_ref())
+            .await
+            .map_err(MenuError::from)?;
+        if exists.is_none() {
+            return Err(MenuError::DomainNotFound);
+        }
+        Ok(())
+    }
+
+    /**
+     * 查询启用的常量菜单
+     *
+     * 返回全局常量菜单，指定域时同时返回该域的常量菜单，按排序号排列
+     *
+     * @param db 数据库连接
+     * @param domain 域代码（可选）
+     * @return Result<Vec<SysMenuModel>, DbErr> 常量菜单列表
+     */
+    async fn query_constant_menus<C: ConnectionTrait>(
+        db: &C,
+        domain: Option<&str>,
+    ) -> Result<Vec<SysMenuModel>, DbErr> {
+        let mut scope = Condition::any().add(SysMenuColumn::Domain.is_null());
+        if let Some(domain) = domain {
+            scope = scope.add(SysMenuColumn::Domain.eq(domain));
+        }
+
+        SysMenu::find()
+            .filter(SysMenuColumn::Constant.eq(true))
+            .filter(SysMenuColumn::MenuType.ne(MenuType::Button))
+            .filter(SysMenuColumn::Status.eq(Status::Enabled))
+            .filter(scope)
+            .order_by(SysMenuColumn::Sequence, sea_orm::Order::Asc)
+            .all(db)
+            .await
+    }
+
+    /**
+     * 在给定连接上级联删除菜单
+     *
+     * 删除菜单及全部子孙菜单（子菜单先于父菜单删除）和它们的角色菜单关联，
+     * 返回被删除的菜单ID以及失去菜单的角色代码
+     *
+     * @param db 数据库连接或事务
+     * @param id 菜单ID
+     * @return Result<MenuDeleteOutput, MenuError> 删除结果或错误
+     */
+    async fn cascade_delete<C: ConnectionTrait>(
+        db: &C,
+        id: i32,
+    ) -> Result<MenuDeleteOutput, MenuError> {
+        let menus: Vec<(i32, String)> = SysMenu::find()
+            .select_only()
+            .column(SysMenuColumn::Id)
+            .column(SysMenuColumn::Pid)
+            .into_tuple()
+            .all(db)
+            .await?;
+        if !menus.iter().any(|(menu_id, _)| *menu_id == id) {
+            return Err(MenuError::MenuNotFound);
+        }
+        let deleted_ids = collect_descendants(&menus, id);
+
+        let role_ids: Vec<String> = SysRoleMenu::find()
+            .select_only()
+            .column(SysRoleMenuColumn::RoleId)
+            .filter(SysRoleMenuColumn::MenuId.is_in(deleted_ids.clone()))
+            .distinct()
+            .into_tuple()
+            .all(db)
+            .await?;
+        let mut affected_roles: Vec<String> = if role_ids.is_empty() {
+            Vec::new()
+        } else {
+            SysRole::find()
+                .select_only()
+                .column(SysRoleColumn::Code)
+                .filter(SysRoleColumn::Id.is_in(role_ids))
+                .into_tuple()
+                .all(db)
+                .await?
+        };
+        affected_roles.sort();
+
+        SysRoleMenu::delete_many()
+            .filter(SysRoleMenuColumn::MenuId.is_in(deleted_ids.clone()))
+            .exec(db)
+            .await?;
+        for menu_id in &deleted_ids {
+            SysMenu::delete_by_id(*menu_id).exec(db).await?;
+        }
+
+        Ok(MenuDeleteOutput {
+            deleted_ids,
+            affected_roles,
+        })
+    }
 }
 
 #[async_trait]
@@ -403,7 +584,7 @@ impl TMenuService for SysMenuService {
      * @return Result<Vec<MenuTree>, MenuError> 菜单树或错误
      */
     async fn tree_menu(&self) -> Result<Vec<MenuTree>, MenuError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_read_connection().await?;
         let menus = SysMenu::find()
             .filter(SysMenuColumn::Status.eq(Status::Enabled))
             .order_by(SysMenuColumn::Sequence, sea_orm::Order::Asc)
@@ -411,14 +592,16 @@ impl TMenuService for SysMenuService {
             .await
             .map_err(MenuError::from)?;
         
-        let menu_trees: Vec<MenuTree> = menus.iter().map(Self::build_menu_tree).collect();
+        let names = HashMap::new();
+        let menu_trees: Vec<MenuTree> =
+            menus.iter().map(|menu| Self::build_menu_tree(menu, &names)).collect();
         Ok(Self::build_tree_structure(menu_trees))
     }
 
     /**
      * 获取菜单列表
      *
-     * 获取所有菜单的树形结构
+     * 获取所有菜单的树形结构，包含创建人和更新人用户名
      *
      * @return Result<Vec<MenuTree>, MenuError> 菜单树或错误
      */
@@ -429,25 +612,39 @@ impl TMenuService for SysMenuService {
             .all(db.as_ref())
             .await
             .map_err(MenuError::from)?;
-        
-        let menu_trees: Vec<MenuTree> = menus.iter().map(Self::build_menu_tree).collect();
+
+        let operator_ids = menus
+            .iter()
+            .flat_map(|menu| [Some(menu.created_by.as_str()), menu.updated_by.as_deref()])
+            .flatten();
+        let names = resolve_user_names(db.as_ref(), operator_ids).await?;
+        let menu_trees: Vec<MenuTree> =
+            menus.iter().map(|menu| Self::build_menu_tree(menu, &names)).collect();
         Ok(Self::build_tree_structure(menu_trees))
     }
 
     /**
      * 获取常量路由
      *
-     * 获取所有启用状态的常量路由
+     * 获取启用状态的全局常量路由；指定域时同时返回该域的常量路由，
+     * 域按登录页可选域列表（缓存）检查，不在列表中时返回错误
      *
+     * @param domain 域代码（可选）
      * @return Result<Vec<MenuRoute>, MenuError> 常量路由列表或错误
      */
-    async fn get_constant_routes(&self) -> Result<Vec<MenuRoute>, MenuError> {
+    async fn get_constant_routes(&self, domain: Option<&str>) -> Result<Vec<MenuRoute>, MenuError> {
+        if let Some(domain) = domain {
+            let domains = SysDomainService
+                .list_enabled_domains()
+                .await
+                .map_err(|e| MenuError::DatabaseOperationFailed(e.to_string()))?;
+            if !domains.iter().any(|option| option.code == domain) {
+                return Err(MenuError::DomainNotFound);
+            }
+        }
+
         let db = db_helper::get_db_connection().await?;
-        let menus = SysMenu::find()
-            .filter(SysMenuColumn::Constant.eq(true))
-            .filter(SysMenuColumn::Status.eq(Status::Enabled))
-            .order_by(SysMenuColumn::Sequence, sea_orm::Order::Asc)
-            .all(db.as_ref())
+        let menus = Self::query_constant_menus(db.as_ref(), domain)
             .await
             .map_err(MenuError::from)?;
         
@@ -489,15 +686,18 @@ impl TMenuService for SysMenuService {
     async fn create_menu(&self, input: CreateMenuInput) -> Result<SysMenuModel, MenuError> {
         // 检查路由名称唯一性
         self.check_route_name_unique(&input.route_name, None).await?;
+
+        // 检查所属域
+        self.check_menu_domain(input.domain.as_deref()).await?;
         
         // 检查父菜单
-        self.check_parent_menu(&input.pid).await?;
+        self.check_parent_menu(&input.pid, &input.menu_type).await?;
         
         // 检查循环引用
         self.check_circular_reference(0, &input.pid).await?;
         
         let db = db_helper::get_db_connection().await?;
-        let now = Local::now().naive_local();
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
         
         let menu = SysMenuActiveModel {
             menu_type: Set(input.menu_type),
@@ -505,7 +705,7 @@ impl TMenuService for SysMenuService {
             icon_type: Set(input.icon_type),
             icon: Set(input.icon),
             route_name: Set(input.route_name),
-            route_path: Set(input.route_path),
+            route_path: Set(normalize_route_path(&input.route_path)),
             component: Set(input.component),
             path_param: Set(input.path_param),
             status: Set(input.status),
@@ -518,14 +718,14 @@ impl TMenuService for SysMenuService {
             constant: Set(input.constant),
             href: Set(input.href),
             multi_tab: Set(input.multi_tab),
-            created_at: Set(now),
-            created_by: Set("system".to_string()),
+            domain: Set(input.domain),
+            created_by: Set(audit.created_by),
             ..Default::default()
         };
         
-        menu.insert(db.as_ref())
-            .await
-            .map_err(MenuError::from)
+        let menu = menu.insert(db.as_ref()).await.map_err(MenuError::from)?;
+        notify_menu_assignment_changed(None, vec![]).await;
+        Ok(menu)
     }
 
     /**
@@ -562,11 +762,16 @@ impl TMenuService for SysMenuService {
             self.check_route_name_unique(&input.menu.route_name, Some(id)).await?;
         }
         
-        // 检查父菜单
+        // 检查父菜单，改为按钮或从按钮改为其他类型时同样需要检查
+        if input.menu.pid != menu.pid || input.menu.menu_type != menu.menu_type {
+            self.check_parent_menu(&input.menu.pid, &input.menu.menu_type).await?;
+        }
         if input.menu.pid != menu.pid {
-            self.check_parent_menu(&input.menu.pid).await?;
             self.check_circular_reference(id, &input.menu.pid).await?;
         }
+        if input.menu.domain != menu.domain {
+            self.check_menu_domain(input.menu.domain.as_deref()).await?;
+        }
         
         let db = db_helper::get_db_connection().await?;
         let mut menu = menu.into_active_model();
@@ -576,7 +781,7 @@ impl TMenuService for SysMenuService {
         menu.icon_type = Set(input.menu.icon_type);
         menu.icon = Set(input.menu.icon);
         menu.route_name = Set(input.menu.route_name);
-        menu.route_path = Set(input.menu.route_path);
+        menu.route_path = Set(normalize_route_path(&input.menu.route_path));
         menu.component = Set(input.menu.component);
         menu.path_param = Set(input.menu.path_param);
         menu.status = Set(input.menu.status);
@@ -589,12 +794,13 @@ impl TMenuService for SysMenuService {
         menu.constant = Set(input.menu.constant);
         menu.href = Set(input.menu.href);
         menu.multi_tab = Set(input.menu.multi_tab);
-        menu.updated_at = Set(Some(Local::now().naive_local()));
-        menu.updated_by = Set(Some("system".to_string()));
+        menu.domain = Set(input.menu.domain);
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        menu.updated_by = Set(audit.updated_by);
         
-        menu.update(db.as_ref())
-            .await
-            .map_err(MenuError::from)
+        let menu = menu.update(db.as_ref()).await.map_err(MenuError::from)?;
+        notify_menu_assignment_changed(None, vec![]).await;
+        Ok(menu)
     }
 
     /**
@@ -636,10 +842,44 @@ impl TMenuService for SysMenuService {
             .exec(db.as_ref())
             .await
             .map_err(MenuError::from)?;
+        notify_menu_assignment_changed(None, vec![]).await;
         
         Ok(())
     }
 
+    /**
+     * 级联删除菜单
+     *
+     * 在同一事务中删除菜单及其全部子孙菜单，并删除它们的角色菜单关联；
+     * 若有角色失去菜单，提交后重新加载权限策略
+     *
+     * @param id 菜单ID
+     * @param enforcer 权限执行器
+     * @return Result<MenuDeleteOutput, MenuError> 被删除的菜单ID及受影响的角色代码
+     */
+    async fn delete_menu_cascade(
+        &self,
+        id: i32,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+    ) -> Result<MenuDeleteOutput, MenuError> {
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await?;
+        let output = Self::cascade_delete(&txn, id).await?;
+        txn.commit().await?;
+
+        if !output.affected_roles.is_empty() {
+            notify_menu_assignment_changed(None, output.affected_roles.clone()).await;
+            enforcer
+                .write()
+                .await
+                .load_policy()
+                .await
+                .map_err(|e| MenuError::PolicyRefreshFailed(e.to_string()))?;
+        }
+
+        Ok(output)
+    }
+
     /**
      * 获取角色菜单ID列表
      *
@@ -662,3 +902,207 @@ impl TMenuService for SysMenuService {
             .map_err(MenuError::from)
     }
 }
+
+/**
+ * 收集菜单及其全部子孙菜单ID
+ *
+ * 按层级广度优先遍历后倒序返回，保证子菜单排在父菜单之前；
+ * 数据中存在环时每个菜单只会出现一次
+ *
+ * @param menus 全部菜单的 (ID, 父级ID) 列表
+ * @param id 起始菜单ID
+ * @return Vec<i32> 菜单ID列表，子菜单在前
+ */
+fn collect_descendants(menus: &[(i32, String)], id: i32) -> Vec<i32> {
+    let mut ordered = vec![id];
+    let mut visited = HashSet::from([id]);
+    let mut index = 0;
+    while index < ordered.len() {
+        let parent = ordered[index].to_string();
+        for (menu_id, pid) in menus {
+            if *pid == parent && visited.insert(*menu_id) {
+                ordered.push(*menu_id);
+            }
+        }
+        index += 1;
+    }
+    ordered.reverse();
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection, PaginatorTrait, Schema};
+    use server_model::admin::entities::{
+        sys_role::ActiveModel as SysRoleActiveModel,
+        sys_role_menu::ActiveModel as SysRoleMenuActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn insert_menu(db: &DatabaseConnection, id: i32, pid: &str) {
+        insert_scoped_menu(db, id, pid, false, None).await;
+    }
+
+    async fn insert_scoped_menu(
+        db: &DatabaseConnection,
+        id: i32,
+        pid: &str,
+        constant: bool,
+        domain: Option<&str>,
+    ) {
+        SysMenuActiveModel {
+            id: Set(id),
+            menu_type: Set(MenuType::Menu),
+            menu_name: Set(format!("menu-{}", id)),
+            icon_type: Set(None),
+            icon: Set(None),
+            route_name: Set(format!("route-{}", id)),
+            route_path: Set(format!("/route-{}", id)),
+            component: Set("layout.base".to_string()),
+            path_param: Set(None),
+            status: Set(Status::Enabled),
+            active_menu: Set(None),
+            hide_in_menu: Set(None),
+            pid: Set(pid.to_string()),
+            sequence: Set(id),
+            i18n_key: Set(None),
+            keep_alive: Set(None),
+            constant: Set(constant),
+            href: Set(None),
+            multi_tab: Set(None),
+            domain: Set(domain.map(str::to_string)),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            updated_at: Set(None),
+            updated_by: Set(None),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 测试只关注菜单层级与角色关联，不创建域表
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysMenu))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRole))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleMenu)))
+            .await
+            .unwrap();
+
+        // 1 -> 2 -> 3, 1 -> 4, 5
+        for (id, pid) in [(1, "0"), (2, "1"), (3, "2"), (4, "1"), (5, "0")] {
+            insert_menu(&db, id, pid).await;
+        }
+        for (id, code) in [("r1", "R_ADMIN"), ("r2", "R_USER")] {
+            SysRoleActiveModel {
+                id: Set(id.to_string()),
+                code: Set(code.to_string()),
+                domain: Set("built-in".to_string()),
+                name: Set(code.to_string()),
+                description: Set(None),
+                pid: Set("0".to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                updated_at: Set(None),
+                updated_by: Set(None),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (role_id, menu_id) in [("r1", 3), ("r1", 4), ("r2", 5)] {
+            SysRoleMenuActiveModel {
+                role_id: Set(role_id.to_string()),
+                menu_id: Set(menu_id),
+                domain: Set("built-in".to_string()),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    #[test]
+    fn test_collect_descendants_is_bottom_up() {
+        let menus = vec![
+            (1, "0".to_string()),
+            (2, "1".to_string()),
+            (3, "2".to_string()),
+            (4, "1".to_string()),
+        ];
+        assert_eq!(collect_descendants(&menus, 1), vec![3, 4, 2, 1]);
+        assert_eq!(collect_descendants(&menus, 4), vec![4]);
+
+        // 脏数据中的环不会导致死循环
+        let cyclic = vec![(1, "2".to_string()), (2, "1".to_string())];
+        assert_eq!(collect_descendants(&cyclic, 1), vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_cascade_delete() {
+        let db = setup_db().await;
+
+        let output = SysMenuService::cascade_delete(&db, 1).await.unwrap();
+        assert_eq!(output.deleted_ids, vec![3, 4, 2, 1]);
+        assert_eq!(output.affected_roles, vec!["R_ADMIN".to_string()]);
+
+        let remaining: Vec<i32> = SysMenu::find()
+            .select_only()
+            .column(SysMenuColumn::Id)
+            .into_tuple()
+            .all(&db)
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec![5]);
+        assert_eq!(SysRoleMenu::find().count(&db).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cascade_delete_leaf_without_roles() {
+        let db = setup_db().await;
+        SysRoleMenu::delete_many().exec(&db).await.unwrap();
+
+        let output = SysMenuService::cascade_delete(&db, 5).await.unwrap();
+        assert_eq!(output.deleted_ids, vec![5]);
+        assert!(output.affected_roles.is_empty());
+
+        assert!(matches!(
+            SysMenuService::cascade_delete(&db, 42).await,
+            Err(MenuError::MenuNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_constant_menus_merge_global_and_domain() {
+        let db = setup_db().await;
+        insert_scoped_menu(&db, 10, "0", true, None).await;
+        insert_scoped_menu(&db, 11, "0", true, Some("tenant-a")).await;
+        insert_scoped_menu(&db, 12, "0", true, Some("tenant-b")).await;
+        insert_scoped_menu(&db, 13, "0", true, None).await;
+
+        let ids = |menus: Vec<SysMenuModel>| -> Vec<i32> {
+            menus.into_iter().map(|menu| menu.id).collect()
+        };
+
+        // 未指定域时只返回全局常量菜单，非常量菜单不返回
+        let global = SysMenuService::query_constant_menus(&db, None).await.unwrap();
+        assert_eq!(ids(global), vec![10, 13]);
+
+        // 指定域时合并全局和该域的常量菜单，不包含其他域的菜单
+        let tenant_a = SysMenuService::query_constant_menus(&db, Some("tenant-a")).await.unwrap();
+        assert_eq!(ids(tenant_a), vec![10, 11, 13]);
+        let tenant_b = SysMenuService::query_constant_menus(&db, Some("tenant-b")).await.unwrap();
+        assert_eq!(ids(tenant_b), vec![10, 12, 13]);
+
+        let unknown = SysMenuService::query_constant_menus(&db, Some("unknown")).await.unwrap();
+        assert_eq!(ids(unknown), vec![10, 13]);
+    }
+}
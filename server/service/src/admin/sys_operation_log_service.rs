@@ -3,8 +3,10 @@
  *
  * 该模块提供了操作日志管理相关的核心功能，包括：
  * - 操作日志分页查询
- * - 操作日志事件处理
- * - 操作日志监听器
+ * - 操作日志游标分页查询
+ * - 操作日志按时间清理（可选归档到S3）
+ * - 操作日志事件批量处理
+ * - 操作日志监听器，缓冲事件后批量写入
  *
  * 主要组件
  * --------
@@ -49,27 +51,38 @@
  *     params: "{}".to_string(),
  *     body: "{}".to_string(),
  *     response: "{}".to_string(),
- *     start_time: chrono::Local::now(),
- *     end_time: chrono::Local::now(),
+ *     start_time: chrono::Utc::now().naive_utc(),
+ *     end_time: chrono::Utc::now().naive_utc(),
  *     duration: 100,
- *     created_at: chrono::Local::now(),
+ *     timed_out: false,
+ *     status_code: 200,
+ *     created_at: chrono::Utc::now().naive_utc(),
  * };
  *
- * SysOperationLogService::handle_operation_log_event(&event).await?;
+ * SysOperationLogService::handle_operation_log_events(vec![event]).await?;
  */
 
-use std::any::Any;
+use std::{io::Write, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use flate2::{write::GzEncoder, Compression};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter,
-    Set,
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
 };
+use server_config::OperationLogConfig;
 use server_core::{
-    web::{error::AppError, page::PaginatedData},
+    web::{
+        error::AppError,
+        page::{CursorPage, KeywordFilter, PaginatedData},
+    },
     paginated_data,
 };
-use server_global::{global::OperationLogContext, project_error};
+use server_global::{
+    global::{get_config, EventReceiver, OperationLogContext, GLOBAL_PRIMARY_OBJECT_STORE},
+    project_error, project_info,
+};
 use server_model::admin::{
     entities::{
         prelude::SysOperationLog,
@@ -78,14 +91,26 @@ use server_model::admin::{
             Model as SysOperationLogModel,
         },
     },
-    input::OperationLogPageRequest,
+    input::{
+        OperationLogCursorRequest, OperationLogPageRequest, OperationLogPurgeRequest, StatusClass,
+    },
+    output::{OperationLogOutput, OperationLogPurgeOutput},
 };
+use server_storage::{ObjectStore, PutOptions};
+use tokio::time::MissedTickBehavior;
 use tracing::instrument;
 use ulid::Ulid;
 
 use crate::helper::db_helper;
 use super::errors::sys_operation_log_error::OperationLogError;
 
+/** 游标中时间部分的格式 */
+const CURSOR_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+/** 游标分页单页最大记录数 */
+const MAX_CURSOR_LIMIT: u64 = 1000;
+/** 单批清理的记录数，避免长时间锁表 */
+const PURGE_BATCH_SIZE: u64 = 10_000;
+
 /**
  * 操作日志服务 trait
  *
@@ -114,25 +139,56 @@ pub trait TOperationLogService {
     /**
      * 分页查询操作日志
      *
-     * 根据查询条件分页获取操作日志列表
+     * 根据查询条件分页获取操作日志列表。
+     * 基于 OFFSET 实现，页码越大越慢，仅用于界面跳页；导出和滚动加载请使用游标分页。
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysOperationLogModel>, AppError> 分页操作日志数据或错误
+     * @return Result<PaginatedData<OperationLogOutput>, AppError> 分页操作日志数据或错误
      */
     async fn find_paginated_operation_logs(
         &self,
         params: OperationLogPageRequest,
-    ) -> Result<PaginatedData<SysOperationLogModel>, AppError>;
+    ) -> Result<PaginatedData<OperationLogOutput>, AppError>;
+
+    /**
+     * 游标分页查询操作日志
+     *
+     * 按 (created_at, id) 倒序返回 after 游标之后的记录，查询耗时与翻页深度无关，
+     * 是导出和滚动加载的首选方式。
+     *
+     * @param params 游标分页参数，包含游标、数量和关键字
+     * @return Result<CursorPage<OperationLogOutput>, AppError> 当前页数据及下一页游标
+     */
+    async fn find_operation_logs_by_cursor(
+        &self,
+        params: OperationLogCursorRequest,
+    ) -> Result<CursorPage<OperationLogOutput>, AppError>;
+
+    /**
+     * 清理操作日志
+     *
+     * 分批删除 before 之前创建的操作日志，每批10000条。配置了 `archive_to_s3` 时，
+     * 每批删除前先以gzip压缩的NDJSON格式上传到主对象存储，上传失败则停止清理。
+     *
+     * @param params 清理参数，dry_run 为 true 时只统计将被删除的记录数
+     * @return Result<OperationLogPurgeOutput, AppError> 清理结果或错误
+     */
+    async fn purge_operation_logs(
+        &self,
+        params: OperationLogPurgeRequest,
+    ) -> Result<OperationLogPurgeOutput, AppError>;
 
     /**
-     * 处理操作日志事件
+     * 批量处理操作日志事件
      *
-     * 处理操作日志事件，记录用户操作信息
+     * 用一条 INSERT 写入整批操作日志，失败时逐条写入，单条异常数据不会导致整批丢失
      *
-     * @param event 操作日志上下文
-     * @return Result<(), AppError> 处理结果
+     * @param events 操作日志上下文列表
+     * @return Result<usize, AppError> 写入失败的条数；获取数据库连接失败时返回错误
      */
-    async fn handle_operation_log_event(event: &OperationLogContext) -> Result<(), AppError>;
+    async fn handle_operation_log_events(
+        events: Vec<OperationLogContext>,
+    ) -> Result<usize, AppError>;
 }
 
 /**
@@ -160,6 +216,329 @@ pub trait TOperationLogService {
  */
 pub struct SysOperationLogService;
 
+impl SysOperationLogService {
+    /**
+     * 写入一批操作日志
+     *
+     * 先用一条 INSERT 写入整批日志；失败时逐条写入，只丢弃写入失败的日志
+     *
+     * @param db 数据库连接
+     * @param events 操作日志上下文列表
+     * @return usize 写入失败的条数
+     */
+    async fn insert_operation_logs<C: ConnectionTrait>(
+        db: &C,
+        events: Vec<OperationLogContext>,
+    ) -> usize {
+        if events.is_empty() {
+            return 0;
+        }
+
+        let models: Vec<SysOperationLogActiveModel> =
+            events.iter().map(Self::to_active_model).collect();
+        let batch = SysOperationLog::insert_many(models).exec_without_returning(db).await;
+        let Err(batch_error) = batch else {
+            return 0;
+        };
+        project_error!(
+            "Failed to batch insert {} operation logs, retrying one by one: {}",
+            events.len(),
+            batch_error
+        );
+
+        let mut failed = 0;
+        for event in &events {
+            let row = SysOperationLog::insert(Self::to_active_model(event));
+            if let Err(e) = row.exec_without_returning(db).await {
+                project_error!(
+                    "Failed to insert operation log for request {}: {}",
+                    event.request_id,
+                    e
+                );
+                failed += 1;
+            }
+        }
+        failed
+    }
+
+    /**
+     * 转换为操作日志记录
+     */
+    fn to_active_model(event: &OperationLogContext) -> SysOperationLogActiveModel {
+        SysOperationLogActiveModel {
+            id: Set(Ulid::new().to_string()),
+            user_id: Set(event.user_id.clone().unwrap_or_default()),
+            username: Set(event.username.clone().unwrap_or_default()),
+            domain: Set(event.domain.clone().unwrap_or_default()),
+            module_name: Set(event.module_name.clone()),
+            description: Set(event.description.clone()),
+            request_id: Set(event.request_id.clone()),
+            method: Set(event.method.clone()),
+            url: Set(event.url.clone()),
+            ip: Set(event.ip.clone()),
+            user_agent: Set(event.user_agent.clone()),
+            params: Set(event.params.clone()),
+            body: Set(event.body.clone()),
+            response: Set(event.response.clone()),
+            start_time: Set(event.start_time),
+            end_time: Set(event.end_time),
+            duration: Set(event.duration),
+            timed_out: Set(event.timed_out),
+            status_code: Set(Some(i32::from(event.status_code))),
+            created_at: Set(event.created_at),
+        }
+    }
+
+    /**
+     * 生成记录对应的游标
+     *
+     * 格式为 `<created_at>,<id>`
+     */
+    fn encode_cursor(record: &SysOperationLogModel) -> String {
+        format!("{},{}", record.created_at.format(CURSOR_TIME_FORMAT), record.id)
+    }
+
+    /**
+     * 解析游标
+     *
+     * @param cursor `<created_at>,<id>` 格式的游标
+     * @return Result<(NaiveDateTime, String), OperationLogError> 创建时间和ID
+     */
+    fn decode_cursor(cursor: &str) -> Result<(NaiveDateTime, String), OperationLogError> {
+        let (created_at, id) = cursor
+            .split_once(',')
+            .ok_or_else(|| OperationLogError::InvalidCursor(cursor.to_string()))?;
+        let created_at = NaiveDateTime::parse_from_str(created_at, CURSOR_TIME_FORMAT)
+            .map_err(|_| OperationLogError::InvalidCursor(cursor.to_string()))?;
+        if id.is_empty() {
+            return Err(OperationLogError::InvalidCursor(cursor.to_string()));
+        }
+        Ok((created_at, id.to_string()))
+    }
+
+    /**
+     * 执行游标分页查询
+     *
+     * 多取一条记录用于判断是否存在下一页。
+     *
+     * @param db 数据库连接
+     * @param params 游标分页参数
+     * @return Result<CursorPage<OperationLogOutput>, AppError> 当前页数据及下一页游标
+     */
+    async fn fetch_cursor_page<C: ConnectionTrait>(
+        db: &C,
+        params: OperationLogCursorRequest,
+    ) -> Result<CursorPage<OperationLogOutput>, AppError> {
+        let limit = params.limit.clamp(1, MAX_CURSOR_LIMIT);
+        let mut query = SysOperationLog::find();
+
+        if let Some(ref keywords) = params.keywords {
+            query = query.filter(KeywordFilter::new(keywords).condition(&[
+                SysOperationLogColumn::Username,
+                SysOperationLogColumn::Description,
+            ]));
+        }
+
+        if let Some(status_class) = params.status_class {
+            query = query.filter(status_class_condition(status_class));
+        }
+
+        if let Some(ref after) = params.after {
+            let (created_at, id) = Self::decode_cursor(after)?;
+            query = query.filter(
+                Condition::any()
+                    .add(SysOperationLogColumn::CreatedAt.lt(created_at))
+                    .add(
+                        Condition::all()
+                            .add(SysOperationLogColumn::CreatedAt.eq(created_at))
+                            .add(SysOperationLogColumn::Id.lt(id)),
+                    ),
+            );
+        }
+
+        let mut records = query
+            .order_by_desc(SysOperationLogColumn::CreatedAt)
+            .order_by_desc(SysOperationLogColumn::Id)
+            .limit(limit + 1)
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+
+        let next_cursor = if records.len() as u64 > limit {
+            records.truncate(limit as usize);
+            records.last().map(Self::encode_cursor)
+        } else {
+            None
+        };
+
+        Ok(CursorPage {
+            records: records.into_iter().map(OperationLogOutput::from).collect(),
+            next_cursor,
+        })
+    }
+
+    /**
+     * 分批删除 before 之前创建的操作日志
+     *
+     * 按 (created_at, id) 正序逐批处理，传入归档目标时每批先归档再删除。
+     *
+     * @param db 数据库连接
+     * @param before 截止时间，不含
+     * @param batch_size 单批记录数
+     * @param archive 归档目标
+     * @return Result<u64, AppError> 删除的记录总数
+     */
+    async fn purge_before<C: ConnectionTrait>(
+        db: &C,
+        before: NaiveDateTime,
+        batch_size: u64,
+        archive: Option<&OperationLogArchive>,
+    ) -> Result<u64, AppError> {
+        let mut total = 0;
+        let mut batch = 0;
+        loop {
+            let query = SysOperationLog::find()
+                .filter(SysOperationLogColumn::CreatedAt.lt(before))
+                .order_by_asc(SysOperationLogColumn::CreatedAt)
+                .order_by_asc(SysOperationLogColumn::Id)
+                .limit(batch_size);
+
+            let ids: Vec<String> = match archive {
+                Some(archive) => {
+                    let records = query.all(db).await.map_err(AppError::from)?;
+                    if !records.is_empty() {
+                        archive.upload(before, batch, &records).await?;
+                    }
+                    records.into_iter().map(|record| record.id).collect()
+                },
+                None => query
+                    .select_only()
+                    .column(SysOperationLogColumn::Id)
+                    .into_tuple()
+                    .all(db)
+                    .await
+                    .map_err(AppError::from)?,
+            };
+            if ids.is_empty() {
+                break;
+            }
+
+            let result = SysOperationLog::delete_many()
+                .filter(SysOperationLogColumn::Id.is_in(ids))
+                .exec(db)
+                .await
+                .map_err(AppError::from)?;
+            total += result.rows_affected;
+            batch += 1;
+            project_info!(
+                "Purged {} operation logs created before {} ({} in total)",
+                result.rows_affected,
+                before,
+                total
+            );
+        }
+        Ok(total)
+    }
+}
+
+/**
+ * 响应状态码分类对应的查询条件
+ */
+fn status_class_condition(status_class: StatusClass) -> Condition {
+    let range = status_class.status_range();
+    Condition::all()
+        .add(SysOperationLogColumn::StatusCode.gte(range.start))
+        .add(SysOperationLogColumn::StatusCode.lt(range.end))
+}
+
+/**
+ * 操作日志归档目标
+ *
+ * 使用主对象存储，按 `s3.provider` 上传到S3存储桶或本地存储目录
+ */
+struct OperationLogArchive {
+    store: Arc<dyn ObjectStore>,
+    prefix: String,
+}
+
+impl OperationLogArchive {
+    /**
+     * 根据操作日志配置创建归档目标
+     *
+     * @return Result<Option<Self>, OperationLogError> 未开启归档时返回 None，开启但未配置S3时返回错误
+     */
+    async fn from_config() -> Result<Option<Self>, OperationLogError> {
+        let config = get_config::<OperationLogConfig>().await.unwrap_or_default();
+        if !config.archive_to_s3 {
+            return Ok(None);
+        }
+
+        let store = GLOBAL_PRIMARY_OBJECT_STORE
+            .read()
+            .await
+            .clone()
+            .ok_or(OperationLogError::ArchiveUnavailable)?;
+
+        Ok(Some(Self {
+            store,
+            prefix: config.archive_prefix.clone(),
+        }))
+    }
+
+    /**
+     * 上传一批操作日志
+     *
+     * @param before 清理截止时间，用于组织对象键
+     * @param batch 批次序号
+     * @param records 操作日志记录
+     */
+    async fn upload(
+        &self,
+        before: NaiveDateTime,
+        batch: u64,
+        records: &[SysOperationLogModel],
+    ) -> Result<(), OperationLogError> {
+        let body = encode_ndjson_gz(records)
+            .map_err(|e| OperationLogError::ArchiveFailed(e.to_string()))?;
+
+        let options = PutOptions {
+            content_type: Some("application/x-ndjson".to_string()),
+            content_encoding: Some("gzip".to_string()),
+        };
+        self.store
+            .put(&archive_key(&self.prefix, before, batch), body, options)
+            .await
+            .map_err(|e| OperationLogError::ArchiveFailed(e.to_string()))
+    }
+}
+
+/**
+ * 生成归档对象键
+ *
+ * 格式为 `<prefix>/<before>/<batch>-<ulid>.ndjson.gz`，ULID 避免重复清理时覆盖已有归档
+ */
+fn archive_key(prefix: &str, before: NaiveDateTime, batch: u64) -> String {
+    format!(
+        "{}/{}/{:05}-{}.ndjson.gz",
+        prefix.trim_end_matches('/'),
+        before.format("%Y%m%dT%H%M%S"),
+        batch,
+        Ulid::new()
+    )
+}
+
+/**
+ * 将操作日志编码为gzip压缩的NDJSON
+ */
+fn encode_ndjson_gz(records: &[SysOperationLogModel]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for record in records {
+        serde_json::to_writer(&mut encoder, record)?;
+        encoder.write_all(b"\n")?;
+    }
+    encoder.finish()
+}
+
 #[async_trait]
 impl TOperationLogService for SysOperationLogService {
     /**
@@ -168,20 +547,24 @@ impl TOperationLogService for SysOperationLogService {
      * 根据查询条件分页获取操作日志列表
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysOperationLogModel>, AppError> 分页操作日志数据或错误
+     * @return Result<PaginatedData<OperationLogOutput>, AppError> 分页操作日志数据或错误
      */
     async fn find_paginated_operation_logs(
         &self,
         params: OperationLogPageRequest,
-    ) -> Result<PaginatedData<SysOperationLogModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PaginatedData<OperationLogOutput>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysOperationLog::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any()
-                .add(SysOperationLogColumn::Username.contains(keywords))
-                .add(SysOperationLogColumn::Description.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(KeywordFilter::new(keywords).condition(&[
+                SysOperationLogColumn::Username,
+                SysOperationLogColumn::Description,
+            ]));
+        }
+
+        if let Some(status_class) = params.status_class {
+            query = query.filter(status_class_condition(status_class));
         }
 
         let total = query
@@ -194,7 +577,10 @@ impl TOperationLogService for SysOperationLogService {
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
-            .map_err(|_| OperationLogError::CreateFailed)?;
+            .map_err(|_| OperationLogError::CreateFailed)?
+            .into_iter()
+            .map(OperationLogOutput::from)
+            .collect();
 
         Ok(paginated_data!(
             total,
@@ -205,67 +591,395 @@ impl TOperationLogService for SysOperationLogService {
     }
 
     /**
-     * 处理操作日志事件
+     * 游标分页查询操作日志
      *
-     * 处理操作日志事件，记录用户操作信息
+     * 按 (created_at, id) 倒序返回 after 游标之后的记录
      *
-     * @param event 操作日志上下文
-     * @return Result<(), AppError> 处理结果
+     * @param params 游标分页参数，包含游标、数量和关键字
+     * @return Result<CursorPage<OperationLogOutput>, AppError> 当前页数据及下一页游标
      */
-    async fn handle_operation_log_event(event: &OperationLogContext) -> Result<(), AppError> {
+    async fn find_operation_logs_by_cursor(
+        &self,
+        params: OperationLogCursorRequest,
+    ) -> Result<CursorPage<OperationLogOutput>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        Self::fetch_cursor_page(db.as_ref(), params).await
+    }
+
+    /**
+     * 清理操作日志
+     *
+     * 分批删除 before 之前创建的操作日志
+     *
+     * @param params 清理参数
+     * @return Result<OperationLogPurgeOutput, AppError> 清理结果或错误
+     */
+    async fn purge_operation_logs(
+        &self,
+        params: OperationLogPurgeRequest,
+    ) -> Result<OperationLogPurgeOutput, AppError> {
         let db = db_helper::get_db_connection().await?;
 
-        let result = SysOperationLogActiveModel {
-            id: Set(Ulid::new().to_string()),
-            user_id: Set(event.user_id.clone().unwrap_or_default()),
-            username: Set(event.username.clone().unwrap_or_default()),
-            domain: Set(event.domain.clone().unwrap_or_default()),
-            module_name: Set(event.module_name.clone()),
-            description: Set(event.description.clone()),
-            request_id: Set(event.request_id.clone()),
-            method: Set(event.method.clone()),
-            url: Set(event.url.clone()),
-            ip: Set(event.ip.clone()),
-            user_agent: Set(event.user_agent.clone()),
-            params: Set(event.params.clone()),
-            body: Set(event.body.clone()),
-            response: Set(event.response.clone()),
-            start_time: Set(event.start_time),
-            end_time: Set(event.end_time),
-            duration: Set(event.duration),
-            created_at: Set(event.created_at),
+        if params.dry_run {
+            let count = SysOperationLog::find()
+                .filter(SysOperationLogColumn::CreatedAt.lt(params.before))
+                .count(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+            return Ok(OperationLogPurgeOutput {
+                count,
+                dry_run: true,
+                archived: false,
+            });
         }
-        .insert(db.as_ref())
-        .await;
 
-        match result {
-            Ok(_) => Ok(()),
-            Err(_) => Err(OperationLogError::CreateFailed.into()),
-        }
+        let archive = OperationLogArchive::from_config().await?;
+        let count =
+            Self::purge_before(db.as_ref(), params.before, PURGE_BATCH_SIZE, archive.as_ref())
+                .await?;
+
+        Ok(OperationLogPurgeOutput {
+            count,
+            dry_run: false,
+            archived: archive.is_some(),
+        })
+    }
+
+    async fn handle_operation_log_events(
+        events: Vec<OperationLogContext>,
+    ) -> Result<usize, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        Ok(Self::insert_operation_logs(db.as_ref(), events).await)
     }
 }
 
 /**
  * 系统操作日志监听器
  *
- * 监听并处理操作日志事件，用于：
- * - 接收操作日志事件
- * - 异步处理操作日志
- * - 错误处理和日志记录
+ * 将收到的操作日志事件放入缓冲区，攒满 `batch_size` 条或每隔 `flush_interval_ms`
+ * 毫秒（以先到者为准）批量写入一次。通道关闭（停机）时写入缓冲区中剩余的日志后退出。
  *
  * @param rx 事件接收器
  */
 #[instrument(skip(rx))]
-pub async fn sys_operation_log_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
-    while let Some(event) = rx.recv().await {
-        if let Some(operation_log_context) = event.downcast_ref::<OperationLogContext>() {
-            if let Err(e) = SysOperationLogService::handle_operation_log_event(operation_log_context).await {
-                project_error!("Failed to handle operation log event: {:?}", e);
+pub async fn sys_operation_log_listener(mut rx: EventReceiver<OperationLogContext>) {
+    let config = get_config::<OperationLogConfig>().await.unwrap_or_default();
+    let batch_size = config.batch_size.max(1);
+    let mut ticker = tokio::time::interval(Duration::from_millis(config.flush_interval_ms.max(1)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let mut buffer: Vec<OperationLogContext> = Vec::with_capacity(batch_size);
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                let Some(operation_log_context) = event else {
+                    break;
+                };
+                buffer.push(operation_log_context);
+                if buffer.len() >= batch_size {
+                    flush_operation_logs(&mut buffer).await;
+                }
+            },
+            _ = ticker.tick() => flush_operation_logs(&mut buffer).await,
+        }
+    }
+
+    flush_operation_logs(&mut buffer).await;
+}
+
+/**
+ * 写入并清空缓冲区中的操作日志
+ */
+async fn flush_operation_logs(buffer: &mut Vec<OperationLogContext>) {
+    if buffer.is_empty() {
+        return;
+    }
+
+    let count = buffer.len();
+    match SysOperationLogService::handle_operation_log_events(std::mem::take(buffer)).await {
+        Ok(0) => {},
+        Ok(failed) => {
+            project_error!("Failed to write {} of {} operation logs", failed, count);
+        },
+        Err(e) => {
+            project_error!("Failed to write {} operation logs: {:?}", count, e);
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::{Duration, NaiveDate};
+    use sea_orm::{
+        ActiveModelTrait, ConnectionTrait, Database, DatabaseBackend, DatabaseConnection, DbErr,
+        MockDatabase, MockExecResult, Schema,
+    };
+
+    use super::*;
+
+    async fn setup_db(count: usize) -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let schema = Schema::new(db.get_database_backend());
+        db.execute(
+            db.get_database_backend()
+                .build(&schema.create_table_from_entity(SysOperationLog)),
+        )
+        .await
+        .unwrap();
+
+        let base = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        for i in 0..count {
+            // 每三条记录共享同一时间，验证 id 作为第二排序键
+            let created_at = base + Duration::seconds((i / 3) as i64);
+            SysOperationLogActiveModel {
+                id: Set(format!("{:04}", i)),
+                user_id: Set("1".to_string()),
+                username: Set("admin".to_string()),
+                domain: Set("built-in".to_string()),
+                module_name: Set("user".to_string()),
+                description: Set("test".to_string()),
+                request_id: Set(format!("req-{}", i)),
+                method: Set("GET".to_string()),
+                url: Set("/api/user".to_string()),
+                ip: Set("127.0.0.1".to_string()),
+                user_agent: Set(None),
+                params: Set(None),
+                body: Set(None),
+                response: Set(None),
+                start_time: Set(created_at),
+                end_time: Set(created_at),
+                duration: Set(0),
+                timed_out: Set(false),
+                status_code: Set(Some(match i % 4 {
+                    2 => 403,
+                    3 => 500,
+                    _ => 200,
+                })),
+                created_at: Set(created_at),
             }
-        } else {
-            project_error!("Received unknown event type in operation log listener");
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let created_at = NaiveDate::from_ymd_opt(2024, 5, 6)
+            .unwrap()
+            .and_hms_micro_opt(7, 8, 9, 123_456)
+            .unwrap();
+        let cursor = format!("{},01HXYZ", created_at.format(CURSOR_TIME_FORMAT));
+        let (decoded_at, decoded_id) = SysOperationLogService::decode_cursor(&cursor).unwrap();
+        assert_eq!(decoded_at, created_at);
+        assert_eq!(decoded_id, "01HXYZ");
+
+        assert!(SysOperationLogService::decode_cursor("not-a-cursor").is_err());
+        assert!(SysOperationLogService::decode_cursor("2024-01-01T00:00:00,").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cursor_pages_have_no_gaps_or_duplicates() {
+        let total = 20;
+        let db = setup_db(total).await;
+
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = SysOperationLogService::fetch_cursor_page(
+                &db,
+                OperationLogCursorRequest {
+                    limit: 3,
+                    after: after.clone(),
+                    keywords: None,
+                    status_class: None,
+                },
+            )
+            .await
+            .unwrap();
+            assert!(page.records.len() <= 3);
+            seen.extend(page.records.into_iter().map(|r| r.id));
+            match page.next_cursor {
+                Some(cursor) => after = Some(cursor),
+                None => break,
+            }
+        }
+
+        let unique: HashSet<_> = seen.iter().cloned().collect();
+        assert_eq!(seen.len(), total);
+        assert_eq!(unique.len(), total);
+
+        let expected: Vec<String> = (0..total).rev().map(|i| format!("{:04}", i)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[tokio::test]
+    async fn test_purge_before_deletes_in_batches() {
+        let db = setup_db(10).await;
+        // setup_db 每三条记录共享同一时间，截止到第3秒删除前9条
+        let before = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 3)
+            .unwrap();
+
+        let deleted = SysOperationLogService::purge_before(&db, before, 4, None)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 9);
+
+        let remaining = SysOperationLog::find().all(&db).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, "0009");
+    }
+
+    #[tokio::test]
+    async fn test_encode_ndjson_gz_roundtrip() {
+        use std::io::Read;
+
+        use flate2::read::GzDecoder;
+
+        let db = setup_db(2).await;
+        let records = SysOperationLog::find().all(&db).await.unwrap();
+
+        let encoded = encode_ndjson_gz(&records).unwrap();
+        let mut decoded = String::new();
+        GzDecoder::new(encoded.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+
+        let lines: Vec<serde_json::Value> = decoded
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["id"], records[0].id);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_last_page_has_no_next_cursor() {
+        let db = setup_db(4).await;
+
+        let page = SysOperationLogService::fetch_cursor_page(
+            &db,
+            OperationLogCursorRequest {
+                limit: 4,
+                after: None,
+                keywords: None,
+                status_class: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.records.len(), 4);
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cursor_filters_by_status_class() {
+        let db = setup_db(8).await;
+        let ids = |status_class| {
+            let db = db.clone();
+            async move {
+                let request = OperationLogCursorRequest {
+                    limit: 10,
+                    after: None,
+                    keywords: None,
+                    status_class: Some(status_class),
+                };
+                let page = SysOperationLogService::fetch_cursor_page(&db, request).await.unwrap();
+                page.records.into_iter().map(|r| (r.id, r.success)).collect::<Vec<_>>()
+            }
+        };
+
+        let failed = |id: &str| (id.to_string(), Some(false));
+        assert_eq!(ids(StatusClass::ClientError).await, vec![failed("0006"), failed("0002")]);
+        assert_eq!(ids(StatusClass::ServerError).await, vec![failed("0007"), failed("0003")]);
+        assert_eq!(ids(StatusClass::Success).await.len(), 4);
+        assert!(ids(StatusClass::Success).await.iter().all(|(_, success)| *success == Some(true)));
+    }
+
+    fn operation_log_context(i: usize) -> OperationLogContext {
+        let now = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        OperationLogContext {
+            user_id: Some("1".to_string()),
+            username: Some("admin".to_string()),
+            domain: Some("built-in".to_string()),
+            module_name: "user".to_string(),
+            description: "test".to_string(),
+            request_id: format!("req-{}", i),
+            method: "POST".to_string(),
+            url: "/api/user".to_string(),
+            ip: "127.0.0.1".to_string(),
+            user_agent: None,
+            params: None,
+            body: None,
+            response: None,
+            start_time: now,
+            end_time: now,
+            duration: 0,
+            timed_out: false,
+            status_code: 200,
+            created_at: now,
         }
     }
+
+    fn insert_statement_count(db: DatabaseConnection) -> usize {
+        db.into_transaction_log()
+            .iter()
+            .flat_map(|transaction| transaction.statements())
+            .filter(|statement| statement.sql.starts_with("INSERT"))
+            .count()
+    }
+
+    fn exec_result(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operation_logs_are_inserted_in_one_statement() {
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_exec_results([exec_result(5)])
+            .into_connection();
+
+        let events = (0..5).map(operation_log_context).collect();
+        let failed = SysOperationLogService::insert_operation_logs(&db, events).await;
+
+        assert_eq!(failed, 0);
+        assert_eq!(insert_statement_count(db), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_falls_back_to_row_inserts() {
+        // 整批写入失败后逐条写入，第二条仍然失败
+        let db = MockDatabase::new(DatabaseBackend::Sqlite)
+            .append_exec_errors([DbErr::Custom("batch failed".to_string())])
+            .append_exec_results([exec_result(1)])
+            .append_exec_errors([DbErr::Custom("malformed row".to_string())])
+            .append_exec_results([exec_result(1)])
+            .into_connection();
+
+        let events = (0..3).map(operation_log_context).collect();
+        let failed = SysOperationLogService::insert_operation_logs(&db, events).await;
+
+        assert_eq!(failed, 1);
+        assert_eq!(insert_statement_count(db), 4);
+    }
+
+    #[tokio::test]
+    async fn test_batch_insert_writes_all_rows() {
+        let db = setup_db(0).await;
+
+        let events = (0..3).map(operation_log_context).collect();
+        assert_eq!(SysOperationLogService::insert_operation_logs(&db, events).await, 0);
+        assert_eq!(SysOperationLog::find().count(&db).await.unwrap(), 3);
+    }
 }
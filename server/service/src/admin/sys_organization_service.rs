@@ -4,6 +4,8 @@
  * 该模块提供了组织管理相关的核心功能，包括：
  * - 组织分页查询
  * - 组织信息管理
+ * - 组织层级校验（上级组织存在性、循环引用）
+ * - 组织用户转移
  *
  * 主要组件
  * --------
@@ -14,6 +16,8 @@
  * --------
  * - 组织查询：支持分页查询和关键字搜索
  * - 组织管理：支持组织信息的增删改查
+ * - 删除保护：存在子组织或关联用户的组织不可删除，删除时可指定组织先转移用户
+ * - 用户转移：在一个事务中将组织下的全部用户转移到其他组织
  *
  * 使用示例
  * --------
@@ -33,21 +37,41 @@
  * }).await?;
  */
 
+use std::collections::HashSet;
+
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+};
 use server_core::{
-    web::{error::AppError, page::PaginatedData},
+    web::{error::AppError, page::PaginatedData, CurrentUserContext},
     paginated_data,
 };
 use server_model::admin::{
     entities::{
-        prelude::SysOrganization,
-        sys_organization::{Column as SysOrganizationColumn, Model as SysOrganizationModel},
+        prelude::{SysOrganization, SysUser},
+        sys_organization::{
+            ActiveModel as SysOrganizationActiveModel, Column as SysOrganizationColumn,
+            Model as SysOrganizationModel,
+        },
+        sys_user::Column as SysUserColumn,
+    },
+    input::{
+        CreateOrganizationInput, OrganizationPageRequest, TransferOrganizationUsersInput,
+        UpdateOrganizationInput,
     },
-    input::OrganizationPageRequest,
+    output::OrganizationTransferOutput,
 };
+use ulid::Ulid;
 
-use crate::helper::db_helper;
+use crate::{
+    admin::sys_organization_error::OrganizationError,
+    helper::{audit_fields, db_helper},
+};
+
+/** 顶级组织的上级组织ID */
+const ROOT_PID: &str = "0";
 
 /**
  * 组织服务 trait
@@ -85,6 +109,76 @@ pub trait TOrganizationService {
         &self,
         params: OrganizationPageRequest,
     ) -> Result<PaginatedData<SysOrganizationModel>, AppError>;
+
+    /**
+     * 获取组织
+     *
+     * @param id 组织ID
+     * @return Result<SysOrganizationModel, AppError> 组织信息或错误
+     */
+    async fn get_organization(&self, id: &str) -> Result<SysOrganizationModel, AppError>;
+
+    /**
+     * 创建组织
+     *
+     * 检查组织代码唯一性及上级组织是否存在
+     *
+     * 创建人取当前认证用户
+     *
+     * @param input 组织创建参数
+     * @return Result<SysOrganizationModel, AppError> 创建的组织信息或错误
+     */
+    async fn create_organization(
+        &self,
+        input: CreateOrganizationInput,
+    ) -> Result<SysOrganizationModel, AppError>;
+
+    /**
+     * 更新组织
+     *
+     * 检查组织代码唯一性、上级组织是否存在，以及修改上级后是否形成循环引用
+     *
+     * 更新人取当前认证用户
+     *
+     * @param input 组织更新参数
+     * @return Result<SysOrganizationModel, AppError> 更新后的组织信息或错误
+     */
+    async fn update_organization(
+        &self,
+        input: UpdateOrganizationInput,
+    ) -> Result<SysOrganizationModel, AppError>;
+
+    /**
+     * 删除组织
+     *
+     * 存在子组织的组织不可删除；指定 `transfer_to` 时在同一事务中先将用户转移到该组织，
+     * 否则存在关联用户的组织不可删除
+     *
+     * @param id 组织ID
+     * @param transfer_to 用户转移的目标组织ID
+     * @return Result<Option<OrganizationTransferOutput>, AppError> 指定目标组织时返回转移结果
+     */
+    async fn delete_organization(
+        &self,
+        id: &str,
+        transfer_to: Option<String>,
+    ) -> Result<Option<OrganizationTransferOutput>, AppError>;
+
+    /**
+     * 转移组织用户
+     *
+     * 在一个事务中将组织下的全部用户转移到目标组织。
+     * 目标组织必须存在，且不能是来源组织自身或其下级组织
+     *
+     * @param id 来源组织ID
+     * @param input 目标组织
+     * @return Result<OrganizationTransferOutput, AppError> 转移的用户数量
+     */
+    async fn transfer_users(
+        &self,
+        id: &str,
+        input: TransferOrganizationUsersInput,
+    ) -> Result<OrganizationTransferOutput, AppError>;
 }
 
 /**
@@ -111,6 +205,171 @@ pub trait TOrganizationService {
  */
 pub struct SysOrganizationService;
 
+impl SysOrganizationService {
+    /**
+     * 检查组织代码唯一性，支持排除当前组织
+     */
+    async fn check_code_unique<C: ConnectionTrait>(
+        db: &C,
+        id: Option<&str>,
+        code: &str,
+    ) -> Result<(), OrganizationError> {
+        let mut query = SysOrganization::find().filter(SysOrganizationColumn::Code.eq(code));
+        if let Some(id) = id {
+            query = query.filter(SysOrganizationColumn::Id.ne(id));
+        }
+
+        if query.one(db).await?.is_some() {
+            return Err(OrganizationError::DuplicateCode);
+        }
+        Ok(())
+    }
+
+    /**
+     * 检查上级组织是否存在，顶级组织不做检查
+     */
+    async fn check_parent_exists<C: ConnectionTrait>(
+        db: &C,
+        pid: &str,
+    ) -> Result<(), OrganizationError> {
+        if pid == ROOT_PID {
+            return Ok(());
+        }
+
+        if SysOrganization::find_by_id(pid).one(db).await?.is_none() {
+            return Err(OrganizationError::ParentNotFound);
+        }
+        Ok(())
+    }
+
+    /**
+     * 检查将组织的上级设置为 `pid` 后是否形成循环引用
+     *
+     * 从 `pid` 沿上级链向上查找，遇到组织自身即为循环引用
+     */
+    async fn check_circular_reference<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+        pid: &str,
+    ) -> Result<(), OrganizationError> {
+        let mut visited = HashSet::new();
+        visited.insert(id.to_string());
+
+        let mut current_id = pid.to_string();
+        while current_id != ROOT_PID {
+            if !visited.insert(current_id.clone()) {
+                return Err(OrganizationError::CircularReference);
+            }
+
+            match SysOrganization::find_by_id(current_id.as_str()).one(db).await? {
+                Some(parent) => current_id = parent.pid,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /**
+     * 获取组织及其全部下级组织的ID
+     *
+     * 一次加载所有组织的上下级关系后在内存中逐层展开，结果包含组织自身
+     */
+    pub(crate) async fn collect_subtree_ids<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+    ) -> Result<Vec<String>, DbErr> {
+        let organizations: Vec<(String, String)> = SysOrganization::find()
+            .select_only()
+            .column(SysOrganizationColumn::Id)
+            .column(SysOrganizationColumn::Pid)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        Ok(collect_subtree(&organizations, id))
+    }
+
+    /**
+     * 检查组织是否可以删除，存在子组织或关联用户时不可删除
+     */
+    async fn check_deletable<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+    ) -> Result<(), OrganizationError> {
+        let children = SysOrganization::find()
+            .filter(SysOrganizationColumn::Pid.eq(id))
+            .count(db)
+            .await?;
+        if children > 0 {
+            return Err(OrganizationError::HasChildren);
+        }
+
+        let users = SysUser::find().filter(SysUserColumn::OrgId.eq(id)).count(db).await?;
+        if users > 0 {
+            return Err(OrganizationError::HasUsers);
+        }
+        Ok(())
+    }
+
+    /**
+     * 将组织下的全部用户转移到目标组织
+     *
+     * 目标组织为来源组织的下级组织时拒绝，解散来源组织时下级组织同样需要处理
+     */
+    async fn transfer_users_in<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+        target_id: &str,
+    ) -> Result<OrganizationTransferOutput, OrganizationError> {
+        if SysOrganization::find_by_id(id).one(db).await?.is_none() {
+            return Err(OrganizationError::OrganizationNotFound);
+        }
+        if id == target_id {
+            return Err(OrganizationError::TransferToSelf);
+        }
+        if SysOrganization::find_by_id(target_id).one(db).await?.is_none() {
+            return Err(OrganizationError::TransferTargetNotFound);
+        }
+        if Self::collect_subtree_ids(db, id).await?.iter().any(|org_id| org_id == target_id) {
+            return Err(OrganizationError::TransferToDescendant);
+        }
+
+        let result = SysUser::update_many()
+            .col_expr(SysUserColumn::OrgId, Expr::value(target_id))
+            .filter(SysUserColumn::OrgId.eq(id))
+            .exec(db)
+            .await?;
+
+        Ok(OrganizationTransferOutput {
+            source_organization_id: id.to_string(),
+            target_organization_id: target_id.to_string(),
+            transferred_users: result.rows_affected,
+        })
+    }
+
+    /**
+     * 删除组织，指定目标组织时先转移用户
+     */
+    async fn delete_organization_in<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+        transfer_to: Option<&str>,
+    ) -> Result<Option<OrganizationTransferOutput>, OrganizationError> {
+        if SysOrganization::find_by_id(id).one(db).await?.is_none() {
+            return Err(OrganizationError::OrganizationNotFound);
+        }
+
+        let transfer = match transfer_to {
+            Some(target_id) => Some(Self::transfer_users_in(db, id, target_id).await?),
+            None => None,
+        };
+        Self::check_deletable(db, id).await?;
+
+        SysOrganization::delete_by_id(id).exec(db).await?;
+        Ok(transfer)
+    }
+}
+
 #[async_trait]
 impl TOrganizationService for SysOrganizationService {
     /**
@@ -125,7 +384,7 @@ impl TOrganizationService for SysOrganizationService {
         &self,
         params: OrganizationPageRequest,
     ) -> Result<PaginatedData<SysOrganizationModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysOrganization::find();
 
         if let Some(ref keywords) = params.keywords {
@@ -155,4 +414,309 @@ impl TOrganizationService for SysOrganizationService {
             records
         ))
     }
+
+    async fn get_organization(&self, id: &str) -> Result<SysOrganizationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysOrganization::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| OrganizationError::OrganizationNotFound.into())
+    }
+
+    async fn create_organization(
+        &self,
+        input: CreateOrganizationInput,
+    ) -> Result<SysOrganizationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        Self::check_code_unique(db.as_ref(), None, &input.code).await?;
+        Self::check_parent_exists(db.as_ref(), &input.pid).await?;
+
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+        let organization = SysOrganizationActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(input.code),
+            name: Set(input.name),
+            description: Set(input.description),
+            pid: Set(input.pid),
+            status: Set(input.status),
+            created_by: Set(audit.created_by),
+            ..Default::default()
+        };
+
+        organization.insert(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn update_organization(
+        &self,
+        input: UpdateOrganizationInput,
+    ) -> Result<SysOrganizationModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let existing = self.get_organization(&input.id).await?;
+        let fields = input.organization;
+
+        Self::check_code_unique(db.as_ref(), Some(&input.id), &fields.code).await?;
+        if fields.pid != existing.pid {
+            Self::check_parent_exists(db.as_ref(), &fields.pid).await?;
+            Self::check_circular_reference(db.as_ref(), &input.id, &fields.pid).await?;
+        }
+
+        let mut organization: SysOrganizationActiveModel = existing.into();
+        organization.code = Set(fields.code);
+        organization.name = Set(fields.name);
+        organization.description = Set(fields.description);
+        organization.pid = Set(fields.pid);
+        organization.status = Set(fields.status);
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        organization.updated_by = Set(audit.updated_by);
+
+        organization.update(db.as_ref()).await.map_err(AppError::from)
+    }
+
+    async fn delete_organization(
+        &self,
+        id: &str,
+        transfer_to: Option<String>,
+    ) -> Result<Option<OrganizationTransferOutput>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let transfer = Self::delete_organization_in(&txn, id, transfer_to.as_deref()).await?;
+        txn.commit().await.map_err(AppError::from)?;
+        Ok(transfer)
+    }
+
+    async fn transfer_users(
+        &self,
+        id: &str,
+        input: TransferOrganizationUsersInput,
+    ) -> Result<OrganizationTransferOutput, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let output = Self::transfer_users_in(&txn, id, &input.target_organization_id).await?;
+        txn.commit().await.map_err(AppError::from)?;
+        Ok(output)
+    }
+}
+
+/**
+ * 在 `(id, pid)` 列表中按层级展开组织子树，结果包含根组织自身
+ *
+ * 通过已访问集合避开脏数据中的循环引用
+ */
+fn collect_subtree(organizations: &[(String, String)], id: &str) -> Vec<String> {
+    let mut visited = HashSet::from([id.to_string()]);
+    let mut ids = vec![id.to_string()];
+    let mut index = 0;
+
+    while index < ids.len() {
+        let current = ids[index].clone();
+        for (child_id, pid) in organizations {
+            if *pid == current && visited.insert(child_id.clone()) {
+                ids.push(child_id.clone());
+            }
+        }
+        index += 1;
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::Status, sys_user::ActiveModel as SysUserActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 测试只关注组织层级，不创建用户关联的域表
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysOrganization)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+
+        // root -> child -> grandchild
+        for (id, pid) in [("root", ROOT_PID), ("child", "root"), ("grandchild", "child")] {
+            SysOrganizationActiveModel {
+                id: Set(id.to_string()),
+                code: Set(id.to_string()),
+                name: Set(id.to_string()),
+                description: Set(None),
+                pid: Set(pid.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                updated_at: Set(None),
+                updated_by: Set(None),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    #[tokio::test]
+    async fn test_check_circular_reference() {
+        let db = setup_db().await;
+
+        assert!(SysOrganizationService::check_circular_reference(&db, "child", "root")
+            .await
+            .is_ok());
+        assert!(matches!(
+            SysOrganizationService::check_circular_reference(&db, "root", "grandchild").await,
+            Err(OrganizationError::CircularReference)
+        ));
+        assert!(matches!(
+            SysOrganizationService::check_circular_reference(&db, "child", "child").await,
+            Err(OrganizationError::CircularReference)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_check_parent_and_code() {
+        let db = setup_db().await;
+
+        assert!(SysOrganizationService::check_parent_exists(&db, ROOT_PID).await.is_ok());
+        assert!(matches!(
+            SysOrganizationService::check_parent_exists(&db, "missing").await,
+            Err(OrganizationError::ParentNotFound)
+        ));
+        assert!(matches!(
+            SysOrganizationService::check_code_unique(&db, None, "child").await,
+            Err(OrganizationError::DuplicateCode)
+        ));
+        assert!(SysOrganizationService::check_code_unique(&db, Some("child"), "child")
+            .await
+            .is_ok());
+    }
+
+    async fn insert_user(db: &DatabaseConnection, id: &str, org_id: &str) {
+        SysUserActiveModel {
+            id: Set(id.to_string()),
+            username: Set(id.to_string()),
+            password: Set("secret".to_string()),
+            domain: Set("built-in".to_string()),
+            built_in: Set(false),
+            nick_name: Set(id.to_string()),
+            status: Set(Status::Enabled),
+            org_id: Set(Some(org_id.to_string())),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn org_users(db: &DatabaseConnection, org_id: &str) -> u64 {
+        SysUser::find().filter(SysUserColumn::OrgId.eq(org_id)).count(db).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_check_deletable() {
+        let db = setup_db().await;
+
+        assert!(matches!(
+            SysOrganizationService::check_deletable(&db, "child").await,
+            Err(OrganizationError::HasChildren)
+        ));
+        assert!(SysOrganizationService::check_deletable(&db, "grandchild").await.is_ok());
+
+        insert_user(&db, "u1", "grandchild").await;
+
+        assert!(matches!(
+            SysOrganizationService::check_deletable(&db, "grandchild").await,
+            Err(OrganizationError::HasUsers)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_users() {
+        let db = setup_db().await;
+        insert_user(&db, "u1", "child").await;
+        insert_user(&db, "u2", "child").await;
+        insert_user(&db, "u3", "grandchild").await;
+
+        assert!(matches!(
+            SysOrganizationService::transfer_users_in(&db, "child", "child").await,
+            Err(OrganizationError::TransferToSelf)
+        ));
+        assert!(matches!(
+            SysOrganizationService::transfer_users_in(&db, "child", "missing").await,
+            Err(OrganizationError::TransferTargetNotFound)
+        ));
+        assert!(matches!(
+            SysOrganizationService::transfer_users_in(&db, "missing", "root").await,
+            Err(OrganizationError::OrganizationNotFound)
+        ));
+        // 下级组织会随来源组织一同解散，不能作为目标组织
+        assert!(matches!(
+            SysOrganizationService::transfer_users_in(&db, "child", "grandchild").await,
+            Err(OrganizationError::TransferToDescendant)
+        ));
+        assert_eq!(org_users(&db, "child").await, 2);
+
+        let output = SysOrganizationService::transfer_users_in(&db, "child", "root").await.unwrap();
+        assert_eq!(output.transferred_users, 2);
+        assert_eq!(output.source_organization_id, "child");
+        assert_eq!(output.target_organization_id, "root");
+        assert_eq!(org_users(&db, "child").await, 0);
+        assert_eq!(org_users(&db, "root").await, 2);
+        assert_eq!(org_users(&db, "grandchild").await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_organization_with_transfer() {
+        let db = setup_db().await;
+        insert_user(&db, "u1", "grandchild").await;
+
+        assert!(matches!(
+            SysOrganizationService::delete_organization_in(&db, "grandchild", None).await,
+            Err(OrganizationError::HasUsers)
+        ));
+        // 存在子组织时即使指定目标组织也不可删除
+        assert!(matches!(
+            SysOrganizationService::delete_organization_in(&db, "child", Some("root")).await,
+            Err(OrganizationError::HasChildren)
+        ));
+
+        let output = SysOrganizationService::delete_organization_in(&db, "grandchild", Some("root"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.transferred_users, 1);
+        assert_eq!(org_users(&db, "root").await, 1);
+        assert!(SysOrganization::find_by_id("grandchild").one(&db).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_collect_subtree_ids() {
+        let db = setup_db().await;
+
+        assert_eq!(
+            SysOrganizationService::collect_subtree_ids(&db, "root").await.unwrap(),
+            vec!["root", "child", "grandchild"]
+        );
+        assert_eq!(
+            SysOrganizationService::collect_subtree_ids(&db, "grandchild").await.unwrap(),
+            vec!["grandchild"]
+        );
+    }
+
+    #[test]
+    fn test_collect_subtree_ignores_cycles() {
+        let organizations = vec![
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "a".to_string()),
+        ];
+        assert_eq!(collect_subtree(&organizations, "a"), vec!["a", "b"]);
+    }
 }
@@ -0,0 +1,683 @@
+/**
+ * 个人访问令牌服务模块
+ *
+ * 该模块提供了用户管理本人个人访问令牌的功能，供自动化脚本代替会过期的登录令牌使用，包括：
+ * - 创建令牌，令牌明文只在创建时返回一次，服务端只保存其 SHA-256 摘要
+ * - 查询本人的令牌
+ * - 撤销本人的令牌
+ * - 请求认证时按摘要校验令牌并记录最近使用时间
+ *
+ * 令牌的权限范围是用户已有接口权限的子集，创建时以 `pat:{令牌ID}` 为主体写入 casbin 策略。
+ * 使用令牌的请求除了按用户当前的角色判定外，还需通过令牌主体的判定，
+ * 因此令牌的权限始终是角色权限与权限范围的交集。
+ *
+ * 主要组件
+ * --------
+ * - TPersonalTokenService: 个人访问令牌服务 trait
+ * - SysPersonalTokenService: 个人访问令牌服务实现
+ * - personal_token_subject: 令牌在 casbin 策略中的主体
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::{SysPersonalTokenService, TPersonalTokenService};
+ *
+ * // 撤销本人的令牌，令牌属于其他用户时返回 TokenNotFound
+ * SysPersonalTokenService::default().revoke_token(&user.user_id(), &id, enforcer).await?;
+ */
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use axum_casbin::casbin::{CoreApi, MgmtApi, RbacApi};
+use chrono::{Duration, NaiveDateTime};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use server_core::web::auth::{Claims, User};
+use server_model::admin::{
+    entities::{
+        prelude::{SysEndpoint, SysPersonalToken},
+        sea_orm_active_enums::Status,
+        sys_endpoint::{Column as SysEndpointColumn, Model as SysEndpointModel},
+        sys_personal_token::{
+            ActiveModel as SysPersonalTokenActiveModel, Column as SysPersonalTokenColumn,
+            Model as SysPersonalTokenModel,
+        },
+    },
+    input::CreatePersonalTokenInput,
+    output::{EndpointDescriptor, PersonalTokenCreatedOutput, PersonalTokenOutput},
+    timestamps,
+};
+use server_utils::SecureUtil;
+use tokio::sync::RwLock;
+use ulid::Ulid;
+
+use crate::helper::db_helper;
+
+use super::{
+    errors::sys_personal_token_error::PersonalTokenError,
+    repositories::{SeaOrmUserRepository, UserRepository},
+};
+
+/** 个人访问令牌的前缀，认证中间件据此区分个人访问令牌和JWT */
+pub const PERSONAL_TOKEN_PREFIX: &str = "pat_";
+
+/** 令牌前缀之后的随机字符数 */
+const TOKEN_RANDOM_LENGTH: usize = 40;
+
+/** 保存用于辨认令牌的明文前缀长度，包含 `pat_` */
+const TOKEN_DISPLAY_LENGTH: usize = 12;
+
+/** 同一令牌两次写入最近使用时间的最小间隔（秒） */
+const LAST_USED_DEBOUNCE_SECS: i64 = 60;
+
+/**
+ * 个人访问令牌在 casbin 策略中的主体
+ *
+ * @param token_id 令牌记录ID
+ * @return String 策略主体
+ */
+pub fn personal_token_subject(token_id: &str) -> String {
+    format!("pat:{}", token_id)
+}
+
+/**
+ * 个人访问令牌服务 trait
+ *
+ * 令牌的管理操作都限定在当前用户本人的令牌范围内
+ */
+#[async_trait]
+pub trait TPersonalTokenService {
+    /**
+     * 创建用户本人的个人访问令牌
+     *
+     * 权限范围中的接口必须已登记，且用户的角色在当前域中拥有对应的权限
+     * （拥有 write 权限时也可以授予同一路径的 read 权限）。
+     * 使用个人访问令牌认证的请求不能创建令牌，否则权限范围有限的令牌可以换取拥有角色全部权限的令牌
+     *
+     * @param user 当前认证用户
+     * @param input 令牌名称、权限范围和过期时间
+     * @param enforcer 权限执行器
+     * @return Result<PersonalTokenCreatedOutput, PersonalTokenError> 包含令牌明文的创建结果
+     */
+    async fn create_token(
+        &self,
+        user: &User,
+        input: CreatePersonalTokenInput,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<PersonalTokenCreatedOutput, PersonalTokenError>;
+
+    /**
+     * 查询用户本人的个人访问令牌，包括已过期的令牌
+     *
+     * @param user_id 当前认证用户ID
+     * @return Result<Vec<PersonalTokenOutput>, PersonalTokenError> 按创建时间倒序的令牌列表
+     */
+    async fn find_user_tokens(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<PersonalTokenOutput>, PersonalTokenError>;
+
+    /**
+     * 撤销用户本人的个人访问令牌
+     *
+     * 删除令牌记录及其权限策略。令牌不存在或属于其他用户时均返回 `TokenNotFound`
+     *
+     * @param user_id 当前认证用户ID
+     * @param id 令牌记录ID
+     * @param enforcer 权限执行器
+     * @return Result<(), PersonalTokenError>
+     */
+    async fn revoke_token(
+        &self,
+        user_id: &str,
+        id: &str,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<(), PersonalTokenError>;
+
+    /**
+     * 使用个人访问令牌认证
+     *
+     * 按令牌摘要查找令牌，检查过期时间以及用户和域的状态，
+     * 返回带有用户当前角色并标记了令牌ID的用户信息。
+     * 最近使用时间至多每分钟写入一次。
+     *
+     * @param token 令牌明文
+     * @param audience 认证受众
+     * @return Result<User, PersonalTokenError>
+     */
+    async fn authenticate(&self, token: &str, audience: &str) -> Result<User, PersonalTokenError>;
+}
+
+/**
+ * 个人访问令牌服务实现
+ *
+ * 通过构造函数注入用户仓储，用于认证时查询用户状态和角色
+ */
+#[derive(Clone)]
+pub struct SysPersonalTokenService {
+    users: Arc<dyn UserRepository>,
+}
+
+impl Default for SysPersonalTokenService {
+    fn default() -> Self {
+        Self::new(Arc::new(SeaOrmUserRepository))
+    }
+}
+
+impl SysPersonalTokenService {
+    /**
+     * 创建个人访问令牌服务
+     *
+     * @param users 用户数据访问实现
+     */
+    pub fn new(users: Arc<dyn UserRepository>) -> Self {
+        Self { users }
+    }
+
+    async fn create_token_in<C: ConnectionTrait>(
+        db: &C,
+        user: &User,
+        input: CreatePersonalTokenInput,
+        enforcer: &Arc<RwLock<impl MgmtApi + Send + Sync>>,
+        now: NaiveDateTime,
+    ) -> Result<PersonalTokenCreatedOutput, PersonalTokenError> {
+        if user.personal_token().is_some() {
+            return Err(PersonalTokenError::ManagedWithPersonalToken);
+        }
+
+        let domain = user.domain();
+        let scopes: BTreeSet<EndpointDescriptor> = input.scopes.into_iter().collect();
+        let endpoints = Self::resolve_scopes(db, &scopes).await?;
+
+        let granted = Self::find_granted_permissions(enforcer, &user.subject(), &domain).await;
+        if let Some(endpoint) = endpoints.iter().find(|endpoint| !is_granted(&granted, endpoint)) {
+            return Err(PersonalTokenError::ScopeNotPermitted(format!(
+                "{} {}",
+                endpoint.method, endpoint.path
+            )));
+        }
+
+        let id = Ulid::new().to_string();
+        let token = format!(
+            "{}{}",
+            PERSONAL_TOKEN_PREFIX,
+            SecureUtil::generate_password(TOKEN_RANDOM_LENGTH)
+        );
+        let scopes: Vec<EndpointDescriptor> = scopes.into_iter().collect();
+        let scopes = serde_json::to_value(&scopes)
+            .map_err(|e| PersonalTokenError::DatabaseOperationFailed(e.to_string()))?;
+
+        let record = SysPersonalTokenActiveModel {
+            id: Set(id.clone()),
+            user_id: Set(user.user_id()),
+            domain: Set(domain.clone()),
+            name: Set(input.name),
+            token_hash: Set(SecureUtil::hash_token(&token)),
+            token_prefix: Set(token[..TOKEN_DISPLAY_LENGTH].to_string()),
+            scopes: Set(scopes),
+            expires_at: Set(input.expires_at),
+            last_used_at: Set(None),
+            created_at: Set(now),
+            created_by: Set(user.username()),
+        }
+        .insert(db)
+        .await?;
+
+        // 同一路径和操作的多个接口只需一条策略
+        let subject = personal_token_subject(&id);
+        let rules: BTreeSet<Vec<String>> = endpoints
+            .iter()
+            .map(|endpoint| {
+                let (path, action) = (endpoint.path.clone(), endpoint.action.clone());
+                vec![subject.clone(), domain.clone(), path, action]
+            })
+            .collect();
+        let added = enforcer.write().await.add_policies(rules.into_iter().collect()).await;
+        if let Err(err) = added {
+            SysPersonalToken::delete_by_id(id).exec(db).await?;
+            return Err(PersonalTokenError::PolicyUpdateFailed(err.to_string()));
+        }
+
+        Ok(PersonalTokenCreatedOutput { token, info: to_token_output(record) })
+    }
+
+    async fn find_user_tokens_in<C: ConnectionTrait>(
+        db: &C,
+        user_id: &str,
+    ) -> Result<Vec<PersonalTokenOutput>, PersonalTokenError> {
+        let tokens = SysPersonalToken::find()
+            .filter(SysPersonalTokenColumn::UserId.eq(user_id))
+            .order_by_desc(SysPersonalTokenColumn::CreatedAt)
+            .all(db)
+            .await?;
+        Ok(tokens.into_iter().map(to_token_output).collect())
+    }
+
+    async fn revoke_token_in<C: ConnectionTrait>(
+        db: &C,
+        user_id: &str,
+        id: &str,
+        enforcer: &Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<(), PersonalTokenError> {
+        let result = SysPersonalToken::delete_many()
+            .filter(SysPersonalTokenColumn::Id.eq(id))
+            .filter(SysPersonalTokenColumn::UserId.eq(user_id))
+            .exec(db)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(PersonalTokenError::TokenNotFound);
+        }
+
+        enforcer
+            .write()
+            .await
+            .remove_filtered_policy(0, vec![personal_token_subject(id)])
+            .await
+            .map_err(|e| PersonalTokenError::PolicyUpdateFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn authenticate_in<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        token: &str,
+        audience: &str,
+        now: NaiveDateTime,
+    ) -> Result<User, PersonalTokenError> {
+        let Some(record) = SysPersonalToken::find()
+            .filter(SysPersonalTokenColumn::TokenHash.eq(SecureUtil::hash_token(token)))
+            .one(db)
+            .await?
+        else {
+            return Err(PersonalTokenError::InvalidToken);
+        };
+
+        if record.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(PersonalTokenError::TokenExpired);
+        }
+
+        // 用户被禁用、删除或转移到其他域后令牌不再可用
+        let user = self
+            .users
+            .find_with_domain_by_id(&record.user_id)
+            .await?
+            .filter(|user| {
+                user.status == Status::Enabled
+                    && user.domain_status == Status::Enabled
+                    && user.domain_code == record.domain
+            })
+            .ok_or(PersonalTokenError::InvalidToken)?;
+        let role_codes = self.users.find_role_codes(&user.id, &user.domain_code).await?;
+
+        let stale = record.last_used_at.is_none_or(|last_used| {
+            now - last_used >= Duration::seconds(LAST_USED_DEBOUNCE_SECS)
+        });
+        if stale {
+            SysPersonalToken::update_many()
+                .col_expr(SysPersonalTokenColumn::LastUsedAt, Expr::value(now))
+                .filter(SysPersonalTokenColumn::Id.eq(record.id.as_str()))
+                .exec(db)
+                .await?;
+        }
+
+        let claims = Claims::new(
+            user.id,
+            audience.to_string(),
+            user.username,
+            role_codes,
+            user.domain_code,
+            None,
+        );
+        Ok(User::from(claims).with_personal_token(record.id))
+    }
+
+    /**
+     * 按路径和方法查找权限范围中的接口
+     *
+     * 同一接口存在多条记录时取第一条，存在未登记的接口时返回 `ScopeNotFound`
+     */
+    async fn resolve_scopes<C: ConnectionTrait>(
+        db: &C,
+        scopes: &BTreeSet<EndpointDescriptor>,
+    ) -> Result<Vec<SysEndpointModel>, PersonalTokenError> {
+        let paths: BTreeSet<&str> = scopes.iter().map(|scope| scope.path.as_str()).collect();
+        let endpoints = SysEndpoint::find()
+            .filter(SysEndpointColumn::Path.is_in(paths))
+            .all(db)
+            .await?;
+
+        let mut resolved = BTreeMap::new();
+        for endpoint in endpoints {
+            let descriptor = EndpointDescriptor {
+                path: endpoint.path.clone(),
+                method: endpoint.method.clone(),
+            };
+            if scopes.contains(&descriptor) {
+                resolved.entry(descriptor).or_insert(endpoint);
+            }
+        }
+
+        if let Some(missing) = scopes.iter().find(|scope| !resolved.contains_key(*scope)) {
+            return Err(PersonalTokenError::ScopeNotFound(format!(
+                "{} {}",
+                missing.method, missing.path
+            )));
+        }
+        Ok(resolved.into_values().collect())
+    }
+
+    /**
+     * 查询角色在域中拥有的路径和操作
+     */
+    async fn find_granted_permissions(
+        enforcer: &Arc<RwLock<impl MgmtApi>>,
+        role_codes: &[String],
+        domain: &str,
+    ) -> BTreeSet<(String, String)> {
+        let enforcer = enforcer.read().await;
+        role_codes
+            .iter()
+            .flat_map(|role| {
+                enforcer.get_filtered_policy(0, vec![role.clone(), domain.to_string()])
+            })
+            .filter(|policy| policy.len() >= 4)
+            .map(|policy| (policy[2].clone(), policy[3].clone()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TPersonalTokenService for SysPersonalTokenService {
+    async fn create_token(
+        &self,
+        user: &User,
+        input: CreatePersonalTokenInput,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<PersonalTokenCreatedOutput, PersonalTokenError> {
+        let db = db_helper::get_db_connection().await?;
+        Self::create_token_in(db.as_ref(), user, input, &enforcer, timestamps::now()).await
+    }
+
+    async fn find_user_tokens(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<PersonalTokenOutput>, PersonalTokenError> {
+        let db = db_helper::get_read_connection().await?;
+        Self::find_user_tokens_in(db.as_ref(), user_id).await
+    }
+
+    async fn revoke_token(
+        &self,
+        user_id: &str,
+        id: &str,
+        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync + 'static>>,
+    ) -> Result<(), PersonalTokenError> {
+        let db = db_helper::get_db_connection().await?;
+        Self::revoke_token_in(db.as_ref(), user_id, id, &enforcer).await
+    }
+
+    async fn authenticate(&self, token: &str, audience: &str) -> Result<User, PersonalTokenError> {
+        let db = db_helper::get_db_connection().await?;
+        self.authenticate_in(db.as_ref(), token, audience, timestamps::now()).await
+    }
+}
+
+/**
+ * 角色权限是否包含接口，write 权限同时包含同一路径的 read 权限
+ */
+fn is_granted(granted: &BTreeSet<(String, String)>, endpoint: &SysEndpointModel) -> bool {
+    let permitted = |action: &str| granted.contains(&(endpoint.path.clone(), action.to_string()));
+    permitted(&endpoint.action) || (endpoint.action == "read" && permitted("write"))
+}
+
+/**
+ * 转换为令牌输出，不包含令牌摘要
+ */
+fn to_token_output(token: SysPersonalTokenModel) -> PersonalTokenOutput {
+    PersonalTokenOutput {
+        id: token.id,
+        name: token.name,
+        token_prefix: token.token_prefix,
+        scopes: serde_json::from_value(token.scopes).unwrap_or_default(),
+        expires_at: token.expires_at,
+        last_used_at: token.last_used_at,
+        created_at: token.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_casbin::casbin::{DefaultModel, Enforcer, MemoryAdapter};
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::sys_endpoint::ActiveModel as SysEndpointActiveModel;
+
+    use super::*;
+    use crate::admin::repositories::test_support::InMemoryUserRepository;
+
+    const AUDIENCE: &str = "management-platform";
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysPersonalToken)))
+            .await
+            .unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysEndpoint)))
+            .await
+            .unwrap();
+
+        for (id, path, method, action) in [
+            ("e1", "/api/v1/user/", "GET", "read"),
+            ("e2", "/api/v1/user/", "POST", "write"),
+            ("e3", "/api/v1/role/", "GET", "read"),
+        ] {
+            SysEndpointActiveModel {
+                id: Set(id.to_string()),
+                path: Set(path.to_string()),
+                method: Set(method.to_string()),
+                action: Set(action.to_string()),
+                resource: Set("api".to_string()),
+                controller: Set("SysUserApi".to_string()),
+                created_at: Set(timestamps::now()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        db
+    }
+
+    async fn setup_enforcer() -> Arc<RwLock<Enforcer>> {
+        let model = DefaultModel::from_str(include_str!("../../../resources/rbac_model.conf"))
+            .await
+            .unwrap();
+        let mut enforcer = Enforcer::new(model, MemoryAdapter::default()).await.unwrap();
+        enforcer
+            .add_policy(vec![
+                "ROLE_A".into(),
+                "built-in".into(),
+                "/api/v1/user/".into(),
+                "write".into(),
+            ])
+            .await
+            .unwrap();
+        Arc::new(RwLock::new(enforcer))
+    }
+
+    fn service() -> SysPersonalTokenService {
+        SysPersonalTokenService::new(Arc::new(
+            InMemoryUserRepository::default()
+                .with_domain("built-in", "Built-in", Status::Enabled)
+                .with_user("alice", "123456", "built-in", Status::Enabled)
+                .with_roles("alice", &["ROLE_A"]),
+        ))
+    }
+
+    fn alice() -> User {
+        let claims = Claims::new(
+            "alice".to_string(),
+            AUDIENCE.to_string(),
+            "alice".to_string(),
+            vec!["ROLE_A".to_string()],
+            "built-in".to_string(),
+            None,
+        );
+        User::from(claims)
+    }
+
+    fn input(
+        scopes: &[(&str, &str)],
+        expires_at: Option<NaiveDateTime>,
+    ) -> CreatePersonalTokenInput {
+        CreatePersonalTokenInput {
+            name: "ci".to_string(),
+            scopes: scopes
+                .iter()
+                .map(|(method, path)| EndpointDescriptor {
+                    path: path.to_string(),
+                    method: method.to_string(),
+                })
+                .collect(),
+            expires_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_token_narrows_permissions() {
+        let db = setup_db().await;
+        let enforcer = setup_enforcer().await;
+        let user = alice();
+        let now = timestamps::now();
+        let create = |scopes: &[(&str, &str)]| {
+            let input = input(scopes, None);
+            SysPersonalTokenService::create_token_in(&db, &user, input, &enforcer, now)
+        };
+
+        // 权限范围不能超出角色已有的权限
+        assert!(matches!(
+            create(&[("GET", "/api/v1/role/")]).await,
+            Err(PersonalTokenError::ScopeNotPermitted(_))
+        ));
+        assert!(matches!(
+            create(&[("GET", "/api/v1/missing/")]).await,
+            Err(PersonalTokenError::ScopeNotFound(_))
+        ));
+
+        let created = create(&[("GET", "/api/v1/user/")]).await.unwrap();
+        assert!(created.token.starts_with(PERSONAL_TOKEN_PREFIX));
+        assert!(created.token.starts_with(&created.info.token_prefix));
+
+        let subject = personal_token_subject(&created.info.id);
+        let allowed = |subject: &str, action: &'static str| {
+            let subject = subject.to_string();
+            let enforcer = enforcer.clone();
+            async move {
+                enforcer
+                    .read()
+                    .await
+                    .enforce((subject, "built-in", "/api/v1/user/", action))
+                    .unwrap()
+            }
+        };
+        assert!(allowed("ROLE_A", "write").await);
+        assert!(allowed(&subject, "read").await);
+        assert!(!allowed(&subject, "write").await);
+
+        let user = service().authenticate_in(&db, &created.token, AUDIENCE, now).await.unwrap();
+        assert_eq!(user.user_id(), "alice");
+        assert_eq!(user.subject(), vec!["ROLE_A".to_string()]);
+        assert_eq!(user.personal_token(), Some(created.info.id.clone()));
+
+        let tokens = SysPersonalTokenService::find_user_tokens_in(&db, "alice").await.unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].scopes, created.info.scopes);
+        assert_eq!(tokens[0].last_used_at, Some(now));
+    }
+
+    #[tokio::test]
+    async fn test_personal_token_cannot_create_wider_token() {
+        let db = setup_db().await;
+        let enforcer = setup_enforcer().await;
+        let now = timestamps::now();
+        let scoped = SysPersonalTokenService::create_token_in(
+            &db,
+            &alice(),
+            input(&[("GET", "/api/v1/user/")], None),
+            &enforcer,
+            now,
+        )
+        .await
+        .unwrap();
+        let user = service().authenticate_in(&db, &scoped.token, AUDIENCE, now).await.unwrap();
+
+        // 只读令牌不能换取拥有角色写权限的令牌
+        let wider = input(&[("GET", "/api/v1/user/"), ("POST", "/api/v1/user/")], None);
+        assert!(matches!(
+            SysPersonalTokenService::create_token_in(&db, &user, wider, &enforcer, now).await,
+            Err(PersonalTokenError::ManagedWithPersonalToken)
+        ));
+        let tokens = SysPersonalTokenService::find_user_tokens_in(&db, "alice").await.unwrap();
+        assert_eq!(tokens.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_revoked_tokens_are_rejected() {
+        let db = setup_db().await;
+        let enforcer = setup_enforcer().await;
+        let service = service();
+        let now = timestamps::now();
+        let expires_at = Some(now + Duration::hours(1));
+        let created = SysPersonalTokenService::create_token_in(
+            &db,
+            &alice(),
+            input(&[("GET", "/api/v1/user/")], expires_at),
+            &enforcer,
+            now,
+        )
+        .await
+        .unwrap();
+        let id = created.info.id.as_str();
+
+        assert!(service.authenticate_in(&db, &created.token, AUDIENCE, now).await.is_ok());
+        let later = now + Duration::hours(2);
+        assert!(matches!(
+            service.authenticate_in(&db, &created.token, AUDIENCE, later).await,
+            Err(PersonalTokenError::TokenExpired)
+        ));
+        assert!(matches!(
+            service.authenticate_in(&db, "pat_unknown", AUDIENCE, now).await,
+            Err(PersonalTokenError::InvalidToken)
+        ));
+
+        // 其他用户的令牌与不存在的令牌返回相同的错误
+        assert!(matches!(
+            SysPersonalTokenService::revoke_token_in(&db, "bob", id, &enforcer).await,
+            Err(PersonalTokenError::TokenNotFound)
+        ));
+
+        SysPersonalTokenService::revoke_token_in(&db, "alice", id, &enforcer)
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.authenticate_in(&db, &created.token, AUDIENCE, now).await,
+            Err(PersonalTokenError::InvalidToken)
+        ));
+        assert!(enforcer
+            .read()
+            .await
+            .get_filtered_policy(0, vec![personal_token_subject(id)])
+            .is_empty());
+        assert!(SysPersonalTokenService::find_user_tokens_in(&db, "alice")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+/**
+ * 策略查看服务模块
+ *
+ * 该模块提供了对运行中Casbin执行器的只读查看能力，包括：
+ * - 按类型和位置过滤查询策略规则
+ * - 权限判定试算，返回判定结果及命中的策略
+ * - 从适配器重新加载策略
+ *
+ * 该模块不提供任何修改策略的接口，策略的分配仍由授权服务负责。
+ *
+ * 主要组件
+ * --------
+ * - TPolicyService: 策略查看服务 trait
+ * - SysPolicyService: 策略查看服务实现
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum_casbin::{
+    casbin::{CoreApi, MgmtApi},
+    normalize_action,
+};
+use server_core::web::error::AppError;
+use server_global::project_info;
+use server_model::admin::{
+    input::{PolicyCheckRequest, PolicyListRequest},
+    output::{PolicyCheckOutput, PolicyRuleOutput},
+};
+use tokio::sync::RwLock;
+
+use crate::admin::errors::sys_policy_error::PolicyError;
+
+/** 未指定策略类型时查询的类型 */
+const DEFAULT_PTYPE: &str = "p";
+
+/**
+ * 策略查看服务 trait
+ *
+ * 定义了策略查看相关的核心接口，包括：
+ * - 查询策略规则
+ * - 权限判定试算
+ * - 重新加载策略
+ */
+#[async_trait]
+pub trait TPolicyService {
+    /**
+     * 查询策略规则
+     *
+     * `p` 开头的类型查询权限策略，`g` 开头的类型查询角色继承规则
+     *
+     * @param params 查询参数
+     * @param enforcer 权限执行器
+     * @return Result<Vec<PolicyRuleOutput>, AppError> 策略规则列表或错误
+     */
+    async fn find_policies(
+        &self,
+        params: PolicyListRequest,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync + 'static>>,
+    ) -> Result<Vec<PolicyRuleOutput>, AppError>;
+
+    /**
+     * 权限判定试算
+     *
+     * 只读取执行器，不写入判定缓存
+     *
+     * @param params 判定参数
+     * @param enforcer 权限执行器
+     * @return Result<PolicyCheckOutput, AppError> 判定结果及命中的策略或错误
+     */
+    async fn check_policy(
+        &self,
+        params: PolicyCheckRequest,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+    ) -> Result<PolicyCheckOutput, AppError>;
+
+    /**
+     * 从适配器重新加载策略
+     *
+     * @param enforcer 权限执行器
+     * @param operator 操作人
+     * @return Result<(), AppError> 加载结果
+     */
+    async fn reload_policies(
+        &self,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+        operator: &str,
+    ) -> Result<(), AppError>;
+}
+
+/**
+ * 策略查看服务
+ */
+#[derive(Clone)]
+pub struct SysPolicyService;
+
+/**
+ * 校验策略类型，只允许 `p`、`g` 开头的类型
+ */
+fn parse_ptype(ptype: Option<&str>) -> Result<String, PolicyError> {
+    let ptype = ptype
+        .map(str::trim)
+        .filter(|ptype| !ptype.is_empty())
+        .unwrap_or(DEFAULT_PTYPE);
+    if ptype.starts_with('p') || ptype.starts_with('g') {
+        Ok(ptype.to_string())
+    } else {
+        Err(PolicyError::InvalidPolicyType(ptype.to_string()))
+    }
+}
+
+/**
+ * 解析逗号分隔的按位置过滤值，全部为空时返回 None
+ */
+fn parse_filter(filter: Option<&str>) -> Option<Vec<String>> {
+    let values: Vec<String> = filter?
+        .split(',')
+        .map(|value| value.trim().to_string())
+        .collect();
+    if values.iter().all(String::is_empty) {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+#[async_trait]
+impl TPolicyService for SysPolicyService {
+    async fn find_policies(
+        &self,
+        params: PolicyListRequest,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync + 'static>>,
+    ) -> Result<Vec<PolicyRuleOutput>, AppError> {
+        let ptype = parse_ptype(params.ptype.as_deref())?;
+        let filter = parse_filter(params.filter.as_deref());
+        let is_grouping = ptype.starts_with('g');
+
+        let enforcer = enforcer.read().await;
+        let rules = match (is_grouping, filter) {
+            (false, None) => enforcer.get_named_policy(&ptype),
+            (false, Some(values)) => enforcer.get_filtered_named_policy(&ptype, 0, values),
+            (true, None) => enforcer.get_named_grouping_policy(&ptype),
+            (true, Some(values)) => enforcer.get_filtered_named_grouping_policy(&ptype, 0, values),
+        };
+
+        Ok(rules
+            .into_iter()
+            .map(|rule| PolicyRuleOutput { ptype: ptype.clone(), rule })
+            .collect())
+    }
+
+    async fn check_policy(
+        &self,
+        params: PolicyCheckRequest,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+    ) -> Result<PolicyCheckOutput, AppError> {
+        // 兼容传入HTTP方法的调用方
+        let act = normalize_action(&params.act).to_string();
+        let enforcer = enforcer.read().await;
+        let (allowed, matched) = enforcer
+            .enforce_ex((params.sub, params.dom, params.obj, act))
+            .map_err(|e| PolicyError::EnforceFailed(e.to_string()))?;
+
+        Ok(PolicyCheckOutput { allowed, matched })
+    }
+
+    async fn reload_policies(
+        &self,
+        enforcer: Arc<RwLock<impl CoreApi + Send + Sync + 'static>>,
+        operator: &str,
+    ) -> Result<(), AppError> {
+        enforcer
+            .write()
+            .await
+            .load_policy()
+            .await
+            .map_err(|e| PolicyError::ReloadFailed(e.to_string()))?;
+
+        project_info!("Casbin policies reloaded by {}", operator);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ptype() {
+        assert_eq!(parse_ptype(None).unwrap(), "p");
+        assert_eq!(parse_ptype(Some(" ")).unwrap(), "p");
+        assert_eq!(parse_ptype(Some("g2")).unwrap(), "g2");
+        assert!(parse_ptype(Some("r")).is_err());
+    }
+
+    #[test]
+    fn test_parse_filter_keeps_positional_gaps() {
+        assert_eq!(parse_filter(None), None);
+        assert_eq!(parse_filter(Some(",,")), None);
+        assert_eq!(
+            parse_filter(Some(",built-in")),
+            Some(vec!["".to_string(), "built-in".to_string()])
+        );
+    }
+}
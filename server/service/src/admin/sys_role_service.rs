@@ -5,6 +5,7 @@
  * - 角色分页查询
  * - 角色CRUD操作
  * - 角色菜单关联
+ * - 查询分配了角色的用户
  *
  * 主要组件
  * --------
@@ -17,6 +18,7 @@
  * - 角色创建：支持创建新角色，包括角色代码唯一性检查
  * - 角色更新：支持更新角色信息
  * - 角色删除：支持删除角色，包括子角色和菜单关联检查
+ * - 角色用户：按当前用户所属域分页列出角色下的用户，角色详情包含用户数量
  *
  * 使用示例
  * --------
@@ -36,30 +38,39 @@
  * }).await?;
  */
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, JoinType, PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Select,
+    Set,
 };
 use server_core::{
-    web::page::PaginatedData,
+    web::{
+        page::{KeywordFilter, PaginatedData},
+        CurrentUserContext,
+    },
     paginated_data,
 };
 use server_model::admin::{
     entities::{
-        prelude::{SysRole, SysRoleMenu},
+        prelude::{SysRole, SysRoleMenu, SysUser, SysUserRole},
         sys_role::{
             ActiveModel as SysRoleActiveModel, Column as SysRoleColumn, Model as SysRoleModel,
         },
         sys_role_menu::Column as SysRoleMenuColumn,
+        sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
+        sys_user_role::{Column as SysUserRoleColumn, Relation as SysUserRoleRelation},
     },
-    input::{CreateRoleInput, RolePageRequest, UpdateRoleInput},
+    input::{CreateRoleInput, RolePageRequest, RoleUserPageRequest, UpdateRoleInput},
+    output::{RoleDetailOutput, RoleUserOutput, WithOperatorNames},
 };
 use ulid::Ulid;
 
 use crate::{
     admin::errors::sys_role_error::RoleError,
-    helper::db_helper,
+    helper::{audit_fields, db_helper, user_name_resolver::resolve_user_names},
 };
 
 /**
@@ -90,25 +101,30 @@ pub trait TRoleService {
     /**
      * 分页查询角色
      *
-     * 根据查询条件分页获取角色列表
+     * 根据查询条件分页获取角色列表，包含创建人和更新人用户名
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysRoleModel>, RoleError> 分页角色数据或错误
+     * @return Result<PaginatedData<WithOperatorNames<SysRoleModel>>, RoleError> 分页角色数据或错误
      */
     async fn find_paginated_roles(
         &self,
         params: RolePageRequest,
-    ) -> Result<PaginatedData<SysRoleModel>, RoleError>;
+    ) -> Result<PaginatedData<WithOperatorNames<SysRoleModel>>, RoleError>;
 
     /**
      * 创建角色
      *
-     * 创建新角色，包括角色代码唯一性检查
+     * 创建新角色，包括角色代码唯一性检查。角色归属当前用户所属域，角色代码在域内唯一
      *
      * @param input 角色创建参数
+     * @param domain 当前用户所属域
      * @return Result<SysRoleModel, RoleError> 创建的角色信息或错误
      */
-    async fn create_role(&self, input: CreateRoleInput) -> Result<SysRoleModel, RoleError>;
+    async fn create_role(
+        &self,
+        input: CreateRoleInput,
+        domain: &str,
+    ) -> Result<SysRoleModel, RoleError>;
 
     /**
      * 获取角色
@@ -120,6 +136,38 @@ pub trait TRoleService {
      */
     async fn get_role(&self, id: &str) -> Result<SysRoleModel, RoleError>;
 
+    /**
+     * 获取角色详情
+     *
+     * 在角色信息之外返回指定域中分配了该角色的用户数量
+     *
+     * @param id 角色ID
+     * @param domain 当前用户所属域
+     * @return Result<RoleDetailOutput, RoleError> 角色详情或错误
+     */
+    async fn get_role_detail(
+        &self,
+        id: &str,
+        domain: &str,
+    ) -> Result<RoleDetailOutput, RoleError>;
+
+    /**
+     * 分页查询角色下的用户
+     *
+     * 只返回指定域中的用户，关键字匹配用户名和昵称
+     *
+     * @param id 角色ID
+     * @param domain 当前用户所属域
+     * @param params 分页查询参数
+     * @return Result<PaginatedData<RoleUserOutput>, RoleError> 分页用户数据或错误
+     */
+    async fn find_paginated_role_users(
+        &self,
+        id: &str,
+        domain: &str,
+        params: RoleUserPageRequest,
+    ) -> Result<PaginatedData<RoleUserOutput>, RoleError>;
+
     /**
      * 更新角色
      *
@@ -172,19 +220,27 @@ impl SysRoleService {
     /**
      * 检查角色是否存在
      *
-     * 检查角色代码是否已存在，支持排除当前角色
+     * 检查角色代码在域内是否已存在，支持排除当前角色
      *
      * @param id 角色ID（可选）
      * @param code 角色代码
+     * @param domain 角色所属域
      * @return Result<(), RoleError> 检查结果
      *
      * 错误
      * -----
      * - DuplicateRoleCode: 角色代码已存在
      */
-    async fn check_role_exists(&self, id: Option<&str>, code: &str) -> Result<(), RoleError> {
+    async fn check_role_exists(
+        &self,
+        id: Option<&str>,
+        code: &str,
+        domain: &str,
+    ) -> Result<(), RoleError> {
         let db = db_helper::get_db_connection().await?;
-        let mut query = SysRole::find().filter(SysRoleColumn::Code.eq(code));
+        let mut query = SysRole::find()
+            .filter(SysRoleColumn::Code.eq(code))
+            .filter(SysRoleColumn::Domain.eq(domain));
 
         if let Some(id) = id {
             query = query.filter(SysRoleColumn::Id.ne(id));
@@ -198,6 +254,65 @@ impl SysRoleService {
 
         Ok(())
     }
+
+    /**
+     * 构建查询角色下用户的查询条件
+     *
+     * 通过用户角色关联表连接，分页数据与总数使用同一查询
+     *
+     * @param id 角色ID
+     * @param domain 用户所属域
+     * @param keywords 关键字，匹配用户名和昵称
+     * @return Select<SysUser> 查询
+     */
+    pub(crate) fn build_role_users_query(
+        id: &str,
+        domain: &str,
+        keywords: Option<&str>,
+    ) -> Select<SysUser> {
+        let mut query = SysUser::find()
+            .join(JoinType::InnerJoin, SysUserRelation::SysUserRole.def())
+            .filter(SysUserRoleColumn::RoleId.eq(id))
+            .filter(SysUserColumn::Domain.eq(domain));
+
+        if let Some(keywords) = keywords {
+            let condition = KeywordFilter::new(keywords)
+                .condition(&[SysUserColumn::Username, SysUserColumn::NickName]);
+            query = query.filter(condition);
+        }
+
+        query
+    }
+
+    /**
+     * 统计角色的用户数量
+     *
+     * 按角色分组一次查询全部角色的用户数量，没有用户的角色不出现在结果中
+     *
+     * @param db 数据库连接
+     * @param ids 角色ID列表
+     * @param domain 用户所属域
+     * @return Result<HashMap<String, u64>, DbErr> 角色ID到用户数量
+     */
+    pub(crate) async fn count_users_by_role<C: ConnectionTrait>(
+        db: &C,
+        ids: &[String],
+        domain: &str,
+    ) -> Result<HashMap<String, u64>, DbErr> {
+        let counts: Vec<(String, i64)> = SysUserRole::find()
+            .select_only()
+            .column(SysUserRoleColumn::RoleId)
+            .column_as(Expr::col((SysUserRole, SysUserRoleColumn::UserId)).count(), "user_count")
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
+            .filter(SysUserRoleColumn::RoleId.is_in(ids.iter().map(String::as_str)))
+            .filter(SysUserColumn::Domain.eq(domain))
+            .group_by(SysUserRoleColumn::RoleId)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        Ok(counts.into_iter().map(|(id, count)| (id, count as u64)).collect())
+    }
 }
 
 #[async_trait]
@@ -205,21 +320,20 @@ impl TRoleService for SysRoleService {
     /**
      * 分页查询角色
      *
-     * 根据查询条件分页获取角色列表
+     * 根据查询条件分页获取角色列表，包含创建人和更新人用户名
      *
      * @param params 分页查询参数，包含关键字和分页信息
-     * @return Result<PaginatedData<SysRoleModel>, RoleError> 分页角色数据或错误
+     * @return Result<PaginatedData<WithOperatorNames<SysRoleModel>>, RoleError> 分页角色数据或错误
      */
     async fn find_paginated_roles(
         &self,
         params: RolePageRequest,
-    ) -> Result<PaginatedData<SysRoleModel>, RoleError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PaginatedData<WithOperatorNames<SysRoleModel>>, RoleError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysRole::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any().add(SysRoleColumn::Code.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(KeywordFilter::new(keywords).condition(&[SysRoleColumn::Code]));
         }
 
         let total = query
@@ -228,10 +342,24 @@ impl TRoleService for SysRoleService {
             .await?;
 
         let paginator = query.paginate(db.as_ref(), params.page_details.size);
-        let records = paginator
+        let roles = paginator
             .fetch_page(params.page_details.current - 1)
             .await?;
 
+        let operator_ids = roles
+            .iter()
+            .flat_map(|role| [Some(role.created_by.as_str()), role.updated_by.as_deref()])
+            .flatten();
+        let names = resolve_user_names(db.as_ref(), operator_ids).await?;
+        let records = roles
+            .into_iter()
+            .map(|role| WithOperatorNames {
+                created_by_name: names.get(&role.created_by).cloned(),
+                updated_by_name: role.updated_by.as_ref().and_then(|id| names.get(id).cloned()),
+                inner: role,
+            })
+            .collect();
+
         Ok(paginated_data!(
             total,
             params.page_details.current,
@@ -243,24 +371,30 @@ impl TRoleService for SysRoleService {
     /**
      * 创建角色
      *
-     * 创建新角色，包括角色代码唯一性检查
+     * 创建新角色，包括角色代码唯一性检查。角色归属当前用户所属域，角色代码在域内唯一
      *
      * @param input 角色创建参数
+     * @param domain 当前用户所属域
      * @return Result<SysRoleModel, RoleError> 创建的角色信息或错误
      */
-    async fn create_role(&self, input: CreateRoleInput) -> Result<SysRoleModel, RoleError> {
-        self.check_role_exists(None, &input.code).await?;
+    async fn create_role(
+        &self,
+        input: CreateRoleInput,
+        domain: &str,
+    ) -> Result<SysRoleModel, RoleError> {
+        self.check_role_exists(None, &input.code, domain).await?;
 
         let db = db_helper::get_db_connection().await?;
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
         let role = SysRoleActiveModel {
             id: Set(Ulid::new().to_string()),
             code: Set(input.code),
+            domain: Set(domain.to_string()),
             name: Set(input.name),
             description: Set(input.description),
             pid: Set(input.pid),
             status: Set(input.status),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("system".to_string()),
+            created_by: Set(audit.created_by),
             ..Default::default()
         };
 
@@ -284,6 +418,69 @@ impl TRoleService for SysRoleService {
             .ok_or(RoleError::RoleNotFound)
     }
 
+    /**
+     * 获取角色详情
+     *
+     * 在角色信息之外返回指定域中分配了该角色的用户数量
+     *
+     * @param id 角色ID
+     * @param domain 当前用户所属域
+     * @return Result<RoleDetailOutput, RoleError> 角色详情或错误
+     */
+    async fn get_role_detail(
+        &self,
+        id: &str,
+        domain: &str,
+    ) -> Result<RoleDetailOutput, RoleError> {
+        let role = self.get_role(id).await?;
+
+        let db = db_helper::get_read_connection().await?;
+        let counts = Self::count_users_by_role(db.as_ref(), &[role.id.clone()], domain).await?;
+        let user_count = counts.get(&role.id).copied().unwrap_or(0);
+
+        Ok(RoleDetailOutput { role, user_count })
+    }
+
+    /**
+     * 分页查询角色下的用户
+     *
+     * 只返回指定域中的用户，关键字匹配用户名和昵称
+     *
+     * @param id 角色ID
+     * @param domain 当前用户所属域
+     * @param params 分页查询参数
+     * @return Result<PaginatedData<RoleUserOutput>, RoleError> 分页用户数据或错误
+     *
+     * 错误
+     * -----
+     * - RoleNotFound: 角色不存在
+     */
+    async fn find_paginated_role_users(
+        &self,
+        id: &str,
+        domain: &str,
+        params: RoleUserPageRequest,
+    ) -> Result<PaginatedData<RoleUserOutput>, RoleError> {
+        self.get_role(id).await?;
+
+        let db = db_helper::get_read_connection().await?;
+        let query = Self::build_role_users_query(id, domain, params.keywords.as_deref());
+
+        let total = query.clone().count(db.as_ref()).await?;
+        let records = query
+            .into_model::<RoleUserOutput>()
+            .paginate(db.as_ref(), params.page_details.size)
+            .fetch_page(params.page_details.current - 1)
+            .await?;
+
+        Ok(paginated_data!(
+            total,
+            params.page_details.current,
+            params.page_details.size,
+            records
+        ))
+    }
+
     /**
      * 更新角色
      *
@@ -296,7 +493,8 @@ impl TRoleService for SysRoleService {
         let mut role = self.get_role(&input.id).await?.into_active_model();
 
         if input.role.code != *role.code.as_ref() {
-            self.check_role_exists(Some(&input.id), &input.role.code).await?;
+            self.check_role_exists(Some(&input.id), &input.role.code, role.domain.as_ref())
+                .await?;
         }
 
         role.code = Set(input.role.code);
@@ -304,6 +502,8 @@ impl TRoleService for SysRoleService {
         role.description = Set(input.role.description);
         role.pid = Set(input.role.pid);
         role.status = Set(input.role.status);
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        role.updated_by = Set(audit.updated_by);
 
         let db = db_helper::get_db_connection().await?;
         let updated_role = role.update(db.as_ref()).await?;
@@ -360,3 +560,92 @@ impl TRoleService for SysRoleService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::Status, sys_user::ActiveModel as SysUserActiveModel,
+        sys_user_role::ActiveModel as SysUserRoleActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 测试只关注用户和关联表，不创建域表和角色表
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(SysUserRole)))
+            .await
+            .unwrap();
+
+        let users = [
+            ("alice", "Alice", "d1", vec!["r1", "r2"]),
+            ("bob", "Bob", "d1", vec!["r1"]),
+            ("carol", "Carol", "d2", vec!["r1"]),
+        ];
+        for (id, nick_name, domain, roles) in users {
+            SysUserActiveModel {
+                id: Set(id.to_string()),
+                username: Set(id.to_string()),
+                password: Set("secret".to_string()),
+                domain: Set(domain.to_string()),
+                built_in: Set(false),
+                nick_name: Set(nick_name.to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(timestamps::now()),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+
+            for role_id in roles {
+                SysUserRoleActiveModel {
+                    user_id: Set(id.to_string()),
+                    role_id: Set(role_id.to_string()),
+                }
+                .insert(&db)
+                .await
+                .unwrap();
+            }
+        }
+        db
+    }
+
+    #[tokio::test]
+    async fn test_role_users_query_filters_by_domain_and_keywords() {
+        let db = setup_db().await;
+
+        let users = SysRoleService::build_role_users_query("r1", "d1", None)
+            .into_model::<RoleUserOutput>()
+            .all(&db)
+            .await
+            .unwrap();
+        let mut usernames: Vec<&str> = users.iter().map(|user| user.username.as_str()).collect();
+        usernames.sort();
+        assert_eq!(usernames, vec!["alice", "bob"]);
+
+        let count = |keywords| {
+            SysRoleService::build_role_users_query("r1", "d1", Some(keywords)).count(&db)
+        };
+        assert_eq!(count("Bo").await.unwrap(), 1);
+        assert_eq!(count("carol").await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_count_users_by_role() {
+        let db = setup_db().await;
+        let ids = vec!["r1".to_string(), "r2".to_string(), "r3".to_string()];
+
+        let counts = SysRoleService::count_users_by_role(&db, &ids, "d1").await.unwrap();
+        assert_eq!(counts.get("r1"), Some(&2));
+        assert_eq!(counts.get("r2"), Some(&1));
+        assert_eq!(counts.get("r3"), None);
+    }
+}
@@ -0,0 +1,382 @@
+/**
+ * 角色模板服务模块
+ *
+ * 该模块提供了角色模板管理相关的核心功能，包括：
+ * - 角色模板列表查询
+ * - 角色模板CRUD操作
+ *
+ * 角色模板保存一组可移植的权限描述（接口路径、请求方法和菜单路由名称），
+ * 格式与角色权限导出文档相同。模板由内置域统一维护，从模板创建角色由授权服务完成。
+ *
+ * 主要组件
+ * --------
+ * - TRoleTemplateService: 角色模板服务 trait
+ * - SysRoleTemplateService: 角色模板服务实现
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::{SysRoleTemplateService, TRoleTemplateService};
+ *
+ * // 非内置域用户调用时返回 BuiltInDomainOnly
+ * let templates = SysRoleTemplateService.find_role_templates(&user.domain()).await?;
+ */
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder, Set,
+};
+use server_core::web::CurrentUserContext;
+use server_model::admin::{
+    entities::{
+        prelude::SysRoleTemplate,
+        sys_role_template::{
+            ActiveModel as SysRoleTemplateActiveModel, Column as SysRoleTemplateColumn,
+            Model as SysRoleTemplateModel,
+        },
+    },
+    input::{CreateRoleTemplateInput, UpdateRoleTemplateInput},
+    output::RoleTemplatePermissions,
+};
+use ulid::Ulid;
+
+use crate::{
+    admin::{
+        errors::sys_role_template_error::RoleTemplateError,
+        sys_domain_service::BUILT_IN_DOMAIN_CODE,
+    },
+    helper::{audit_fields, db_helper},
+};
+
+/**
+ * 角色模板服务 trait
+ *
+ * 所有操作都要求当前用户属于内置域
+ */
+#[async_trait]
+pub trait TRoleTemplateService {
+    /**
+     * 查询全部角色模板
+     *
+     * 按名称排序返回
+     *
+     * @param operator_domain 当前用户所属域
+     * @return Result<Vec<SysRoleTemplateModel>, RoleTemplateError>
+     */
+    async fn find_role_templates(
+        &self,
+        operator_domain: &str,
+    ) -> Result<Vec<SysRoleTemplateModel>, RoleTemplateError>;
+
+    /**
+     * 获取角色模板
+     *
+     * @param id 模板ID
+     * @param operator_domain 当前用户所属域
+     * @return Result<SysRoleTemplateModel, RoleTemplateError>
+     */
+    async fn get_role_template(
+        &self,
+        id: &str,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError>;
+
+    /**
+     * 创建角色模板
+     *
+     * 模板名称不可重复，权限条目不要求在当前环境中存在
+     *
+     * @param input 模板创建参数
+     * @param operator_domain 当前用户所属域
+     * @return Result<SysRoleTemplateModel, RoleTemplateError>
+     */
+    async fn create_role_template(
+        &self,
+        input: CreateRoleTemplateInput,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError>;
+
+    /**
+     * 更新角色模板
+     *
+     * 只影响之后从模板创建的角色，已创建的角色权限不变
+     *
+     * @param input 模板更新参数
+     * @param operator_domain 当前用户所属域
+     * @return Result<SysRoleTemplateModel, RoleTemplateError>
+     */
+    async fn update_role_template(
+        &self,
+        input: UpdateRoleTemplateInput,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError>;
+
+    /**
+     * 删除角色模板
+     *
+     * @param id 模板ID
+     * @param operator_domain 当前用户所属域
+     * @return Result<(), RoleTemplateError>
+     */
+    async fn delete_role_template(
+        &self,
+        id: &str,
+        operator_domain: &str,
+    ) -> Result<(), RoleTemplateError>;
+}
+
+/**
+ * 角色模板服务实现
+ */
+#[derive(Clone, Default)]
+pub struct SysRoleTemplateService;
+
+/**
+ * 检查当前用户是否属于内置域
+ */
+fn ensure_built_in(operator_domain: &str) -> Result<(), RoleTemplateError> {
+    if operator_domain == BUILT_IN_DOMAIN_CODE {
+        Ok(())
+    } else {
+        Err(RoleTemplateError::BuiltInDomainOnly)
+    }
+}
+
+/**
+ * 解析模板中保存的权限描述
+ */
+pub(crate) fn template_permissions(
+    template: &SysRoleTemplateModel,
+) -> Result<RoleTemplatePermissions, RoleTemplateError> {
+    serde_json::from_value(template.permissions.clone())
+        .map_err(|e| RoleTemplateError::InvalidPermissions(e.to_string()))
+}
+
+/**
+ * 将权限描述转换为保存的JSON，接口和菜单去重并排序
+ */
+fn permissions_to_json(
+    mut permissions: RoleTemplatePermissions,
+) -> Result<serde_json::Value, RoleTemplateError> {
+    permissions.endpoints.sort();
+    permissions.endpoints.dedup();
+    permissions.menus.sort();
+    permissions.menus.dedup();
+    serde_json::to_value(permissions)
+        .map_err(|e| RoleTemplateError::InvalidPermissions(e.to_string()))
+}
+
+impl SysRoleTemplateService {
+    /**
+     * 按ID查找模板
+     */
+    pub(crate) async fn find_template<C: ConnectionTrait>(
+        db: &C,
+        id: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        SysRoleTemplate::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or(RoleTemplateError::TemplateNotFound)
+    }
+
+    /**
+     * 检查模板名称是否已被其他模板使用
+     */
+    async fn check_name_unique<C: ConnectionTrait>(
+        db: &C,
+        id: Option<&str>,
+        name: &str,
+    ) -> Result<(), RoleTemplateError> {
+        let mut query = SysRoleTemplate::find().filter(SysRoleTemplateColumn::Name.eq(name));
+        if let Some(id) = id {
+            query = query.filter(SysRoleTemplateColumn::Id.ne(id));
+        }
+
+        if query.one(db).await?.is_some() {
+            return Err(RoleTemplateError::DuplicateTemplateName);
+        }
+        Ok(())
+    }
+
+    async fn create_role_template_in<C: ConnectionTrait>(
+        db: &C,
+        input: CreateRoleTemplateInput,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        Self::check_name_unique(db, None, &input.name).await?;
+
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+        let template = SysRoleTemplateActiveModel {
+            id: Set(Ulid::new().to_string()),
+            name: Set(input.name),
+            description: Set(input.description),
+            permissions: Set(permissions_to_json(input.permissions)?),
+            created_by: Set(audit.created_by),
+            ..Default::default()
+        };
+
+        Ok(template.insert(db).await?)
+    }
+
+    async fn update_role_template_in<C: ConnectionTrait>(
+        db: &C,
+        input: UpdateRoleTemplateInput,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        let mut template = Self::find_template(db, &input.id).await?.into_active_model();
+        Self::check_name_unique(db, Some(&input.id), &input.template.name).await?;
+
+        template.name = Set(input.template.name);
+        template.description = Set(input.template.description);
+        template.permissions = Set(permissions_to_json(input.template.permissions)?);
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        template.updated_by = Set(audit.updated_by);
+
+        Ok(template.update(db).await?)
+    }
+}
+
+#[async_trait]
+impl TRoleTemplateService for SysRoleTemplateService {
+    async fn find_role_templates(
+        &self,
+        operator_domain: &str,
+    ) -> Result<Vec<SysRoleTemplateModel>, RoleTemplateError> {
+        ensure_built_in(operator_domain)?;
+
+        let db = db_helper::get_db_connection().await?;
+        let templates = SysRoleTemplate::find()
+            .order_by_asc(SysRoleTemplateColumn::Name)
+            .all(db.as_ref())
+            .await?;
+        Ok(templates)
+    }
+
+    async fn get_role_template(
+        &self,
+        id: &str,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        ensure_built_in(operator_domain)?;
+
+        let db = db_helper::get_db_connection().await?;
+        Self::find_template(db.as_ref(), id).await
+    }
+
+    async fn create_role_template(
+        &self,
+        input: CreateRoleTemplateInput,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        ensure_built_in(operator_domain)?;
+
+        let db = db_helper::get_db_connection().await?;
+        Self::create_role_template_in(db.as_ref(), input).await
+    }
+
+    async fn update_role_template(
+        &self,
+        input: UpdateRoleTemplateInput,
+        operator_domain: &str,
+    ) -> Result<SysRoleTemplateModel, RoleTemplateError> {
+        ensure_built_in(operator_domain)?;
+
+        let db = db_helper::get_db_connection().await?;
+        Self::update_role_template_in(db.as_ref(), input).await
+    }
+
+    async fn delete_role_template(
+        &self,
+        id: &str,
+        operator_domain: &str,
+    ) -> Result<(), RoleTemplateError> {
+        ensure_built_in(operator_domain)?;
+
+        let db = db_helper::get_db_connection().await?;
+        let result = SysRoleTemplate::delete_by_id(id).exec(db.as_ref()).await?;
+        if result.rows_affected == 0 {
+            return Err(RoleTemplateError::TemplateNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, DatabaseConnection, Schema};
+    use server_model::admin::{input::RoleTemplateInput, output::EndpointDescriptor, timestamps};
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysRoleTemplate)))
+            .await
+            .unwrap();
+        db
+    }
+
+    fn template_input(name: &str, menus: &[&str]) -> RoleTemplateInput {
+        RoleTemplateInput {
+            name: name.to_string(),
+            description: None,
+            permissions: RoleTemplatePermissions {
+                endpoints: vec![
+                    EndpointDescriptor {
+                        path: "/api/v1/user/".to_string(),
+                        method: "GET".to_string(),
+                    };
+                    2
+                ],
+                menus: menus.iter().map(|menu| menu.to_string()).collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_only_built_in_domain_manages_templates() {
+        assert!(ensure_built_in(BUILT_IN_DOMAIN_CODE).is_ok());
+        assert!(matches!(
+            ensure_built_in("tenant-a"),
+            Err(RoleTemplateError::BuiltInDomainOnly)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_and_update_role_template() {
+        let db = setup_db().await;
+
+        let created = SysRoleTemplateService::create_role_template_in(
+            &db,
+            template_input("auditor", &["log", "home", "log"]),
+        )
+        .await
+        .unwrap();
+        let permissions = template_permissions(&created).unwrap();
+        assert_eq!(permissions.endpoints.len(), 1);
+        assert_eq!(permissions.menus, ["home", "log"]);
+        // 时间戳由实体钩子以 UTC 填充，服务不设置
+        assert!((timestamps::now() - created.created_at).num_seconds().abs() < 5);
+        assert!(created.updated_at.is_none());
+
+        let duplicate =
+            SysRoleTemplateService::create_role_template_in(&db, template_input("auditor", &[]))
+                .await;
+        assert!(matches!(duplicate, Err(RoleTemplateError::DuplicateTemplateName)));
+
+        // 保留原名称更新不视为重复
+        let updated = SysRoleTemplateService::update_role_template_in(
+            &db,
+            UpdateRoleTemplateInput {
+                id: created.id.clone(),
+                template: template_input("auditor", &["manage"]),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(template_permissions(&updated).unwrap().menus, ["manage"]);
+        assert_eq!(updated.created_at, created.created_at);
+        assert!(updated.updated_at.is_some_and(|updated_at| updated_at >= created.created_at));
+    }
+}
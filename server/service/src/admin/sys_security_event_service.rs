@@ -0,0 +1,268 @@
+/**
+ * 安全事件服务模块
+ *
+ * 负责持久化认证失败、异常登录、角色变更等安全事件，供排查暴力尝试、账号盗用和合规审计使用：
+ * - record_security_event: 将安全事件写入 sys_security_event 表
+ * - security_event_listener: 安全事件监听器，异常登录时按配置发送邮件提醒用户
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::SysSecurityEventService;
+ *
+ * SysSecurityEventService::record_security_event(db.as_ref(), &event).await?;
+ */
+
+use std::any::Any;
+
+use sea_orm::{ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, Set};
+use serde_json::json;
+use server_config::SecurityConfig;
+use server_core::{sign::SecurityEvent, web::error::AppError};
+use server_global::{
+    global::{get_config, EventReceiver},
+    project_error,
+};
+use server_model::admin::entities::{
+    prelude::SysUser, sys_security_event::ActiveModel as SysSecurityEventActiveModel,
+};
+use server_model::admin::timestamps;
+use server_resource::email::EMAIL_TEMPLATE_UNUSUAL_LOGIN;
+use tracing::instrument;
+use ulid::Ulid;
+
+use crate::helper::{
+    db_helper,
+    notification_service::{NotificationError, NotificationService},
+};
+
+/**
+ * 安全事件服务
+ */
+pub struct SysSecurityEventService;
+
+impl SysSecurityEventService {
+    /**
+     * 写入安全事件
+     *
+     * @param db 数据库连接
+     * @param event 安全事件
+     * @return Result<(), DbErr> 写入结果
+     */
+    pub async fn record_security_event<C: ConnectionTrait>(
+        db: &C,
+        event: &SecurityEvent,
+    ) -> Result<(), DbErr> {
+        let mut model = SysSecurityEventActiveModel {
+            id: Set(Ulid::new().to_string()),
+            event_type: Set(event.event_type().to_string()),
+            created_at: Set(timestamps::now()),
+            ..Default::default()
+        };
+
+        match event {
+            SecurityEvent::ApiKeyAuthFailed { ip, key_prefix, path } => {
+                model.ip = Set(ip.clone());
+                model.key_prefix = Set(Some(key_prefix.clone()));
+                model.path = Set(path.clone());
+            },
+            SecurityEvent::UnusualLogin {
+                user_id,
+                domain,
+                ip,
+                previous_address,
+                address,
+                previous_user_agent,
+                user_agent,
+                new_location,
+                new_user_agent,
+                ..
+            } => {
+                // 登录事件没有请求路径，详情中保留前后两次登录的归属地和用户代理
+                let detail = json!({
+                    "previousAddress": previous_address,
+                    "address": address,
+                    "previousUserAgent": previous_user_agent,
+                    "userAgent": user_agent,
+                    "newLocation": new_location,
+                    "newUserAgent": new_user_agent,
+                });
+                model.ip = Set(ip.clone());
+                model.key_prefix = Set(None);
+                model.path = Set(String::new());
+                model.user_id = Set(Some(user_id.clone()));
+                model.domain = Set(Some(domain.clone()));
+                model.detail = Set(Some(detail.to_string()));
+            },
+            SecurityEvent::RoleChanged { user_id, domain, role_code, operator_id, .. } => {
+                // 角色变更由管理接口触发，不记录请求来源，详情中保留角色和操作人
+                let detail = json!({
+                    "roleCode": role_code,
+                    "operatorId": operator_id,
+                });
+                model.ip = Set(String::new());
+                model.key_prefix = Set(None);
+                model.path = Set(String::new());
+                model.user_id = Set(Some(user_id.clone()));
+                model.domain = Set(Some(domain.clone()));
+                model.detail = Set(Some(detail.to_string()));
+            },
+        }
+
+        model.insert(db).await?;
+        Ok(())
+    }
+
+    /**
+     * 发送异常登录提醒邮件
+     *
+     * 用户未设置邮箱或未配置邮件服务时跳过
+     *
+     * @param db 数据库连接
+     * @param event 安全事件，非异常登录事件时忽略
+     * @return Result<(), AppError> 发送结果
+     */
+    async fn notify_unusual_login<C: ConnectionTrait>(
+        db: &C,
+        event: &SecurityEvent,
+    ) -> Result<(), AppError> {
+        let SecurityEvent::UnusualLogin { user_id, username, ip, address, user_agent, .. } = event
+        else {
+            return Ok(());
+        };
+
+        let user = SysUser::find_by_id(user_id.clone()).one(db).await?;
+        let Some(email) = user.and_then(|user| user.email).filter(|email| !email.is_empty())
+        else {
+            return Ok(());
+        };
+
+        match NotificationService::send_mail(
+            EMAIL_TEMPLATE_UNUSUAL_LOGIN,
+            &email,
+            &json!({
+                "user_name": username,
+                "ip": ip,
+                "address": address,
+                "user_agent": user_agent,
+            }),
+        )
+        .await
+        {
+            Ok(()) | Err(NotificationError::NotConfigured) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/**
+ * 安全事件监听器
+ *
+ * 将收到的安全事件写入 sys_security_event 表，写入失败时记录错误日志
+ *
+ * @param rx 事件接收器
+ */
+#[instrument(skip(rx))]
+pub async fn security_event_listener(mut rx: EventReceiver<Box<dyn Any + Send>>) {
+    while let Some(event) = rx.recv().await {
+        let Some(security_event) = event.downcast_ref::<SecurityEvent>() else {
+            project_error!("Received unknown event type in security event listener");
+            continue;
+        };
+
+        let db = match db_helper::get_db_connection().await {
+            Ok(db) => db,
+            Err(e) => {
+                project_error!("Failed to record security event {:?}: {}", security_event, e);
+                continue;
+            },
+        };
+        if let Err(e) =
+            SysSecurityEventService::record_security_event(db.as_ref(), security_event).await
+        {
+            project_error!("Failed to record security event {:?}: {}", security_event, e);
+        }
+
+        let config = get_config::<SecurityConfig>().await.unwrap_or_default();
+        if config.unusual_login.notify_user {
+            if let Err(e) =
+                SysSecurityEventService::notify_unusual_login(db.as_ref(), security_event).await
+            {
+                project_error!("Failed to notify security event {:?}: {}", security_event, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Database, EntityTrait, Schema};
+    use server_model::admin::entities::prelude::SysSecurityEvent;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_api_key_auth_failed() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let schema = Schema::new(db.get_database_backend());
+        db.execute(
+            db.get_database_backend()
+                .build(&schema.create_table_from_entity(SysSecurityEvent)),
+        )
+        .await
+        .unwrap();
+
+        let event = SecurityEvent::ApiKeyAuthFailed {
+            ip: "10.0.0.1".to_string(),
+            key_prefix: "AK12".to_string(),
+            path: "/sandbox/simple-api-key".to_string(),
+        };
+        SysSecurityEventService::record_security_event(&db, &event).await.unwrap();
+
+        let records = SysSecurityEvent::find().all(&db).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].event_type, "api_key_auth_failed");
+        assert_eq!(records[0].ip, "10.0.0.1");
+        assert_eq!(records[0].key_prefix.as_deref(), Some("AK12"));
+        assert_eq!(records[0].path, "/sandbox/simple-api-key");
+    }
+
+    #[tokio::test]
+    async fn test_record_unusual_login() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let schema = Schema::new(db.get_database_backend());
+        db.execute(
+            db.get_database_backend()
+                .build(&schema.create_table_from_entity(SysSecurityEvent)),
+        )
+        .await
+        .unwrap();
+
+        let event = SecurityEvent::UnusualLogin {
+            user_id: "u1".to_string(),
+            username: "Soybean".to_string(),
+            domain: "built-in".to_string(),
+            ip: "203.0.113.7".to_string(),
+            previous_address: "中国|0|上海|上海市|电信".to_string(),
+            address: "美国|0|加利福尼亚|0|0".to_string(),
+            previous_user_agent: "Mozilla/5.0 Chrome/128.0".to_string(),
+            user_agent: "Mozilla/5.0 Chrome/129.0".to_string(),
+            new_location: true,
+            new_user_agent: false,
+        };
+        SysSecurityEventService::record_security_event(&db, &event).await.unwrap();
+
+        let records = SysSecurityEvent::find().all(&db).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].event_type, "unusual_login");
+        assert_eq!(records[0].ip, "203.0.113.7");
+        assert_eq!(records[0].key_prefix, None);
+        assert_eq!(records[0].user_id.as_deref(), Some("u1"));
+        assert_eq!(records[0].domain.as_deref(), Some("built-in"));
+        let detail: serde_json::Value =
+            serde_json::from_str(records[0].detail.as_deref().unwrap()).unwrap();
+        assert_eq!(detail["address"], "美国|0|加利福尼亚|0|0");
+        assert_eq!(detail["newLocation"], true);
+        assert_eq!(detail["newUserAgent"], false);
+    }
+}
@@ -0,0 +1,525 @@
+/**
+ * 统计服务模块
+ *
+ * 该模块提供了管理后台首页使用的域统计概览，包括：
+ * - 按状态分组的用户数量
+ * - 最近7天每天的成功登录次数和操作日志数量
+ * - 有效登录会话、角色和已分配菜单的数量
+ *
+ * 统计查询使用只读连接，配置了只读副本时由副本承担。结果按域缓存60秒，
+ * 统计数据允许短时间内不是最新的。
+ *
+ * 主要组件
+ * --------
+ * - TStatisticsService: 统计服务 trait
+ * - SysStatisticsService: 统计服务实现
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::{SysStatisticsService, TStatisticsService};
+ *
+ * // 不指定域时统计当前用户所属域
+ * let overview = SysStatisticsService
+ *     .get_overview(StatisticsOverviewRequest::default(), &user.domain())
+ *     .await?;
+ */
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime};
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+use server_constant::definition::consts::TokenStatus;
+use server_model::admin::{
+    entities::{
+        prelude::{SysLoginLog, SysOperationLog, SysRole, SysRoleMenu, SysTokens, SysUser},
+        sea_orm_active_enums::Status,
+        sys_login_log::Column as SysLoginLogColumn,
+        sys_operation_log::Column as SysOperationLogColumn,
+        sys_role::Column as SysRoleColumn,
+        sys_role_menu::Column as SysRoleMenuColumn,
+        sys_tokens::Column as SysTokensColumn,
+        sys_user::Column as SysUserColumn,
+    },
+    input::StatisticsOverviewRequest,
+    output::{DailyCount, OverviewOutput, StatusCount},
+    timestamps,
+};
+
+use crate::{
+    admin::{
+        errors::sys_statistics_error::StatisticsError, sys_domain_service::BUILT_IN_DOMAIN_CODE,
+    },
+    helper::db_helper,
+};
+
+/** 按天统计覆盖的天数，包括当天 */
+const STATISTICS_DAYS: u64 = 7;
+
+/** 统计概览缓存有效期 */
+const OVERVIEW_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/** 统计概览缓存，键为域代码 */
+static OVERVIEW_CACHE: Lazy<Cache<String, OverviewOutput>> =
+    Lazy::new(|| Cache::builder().time_to_live(OVERVIEW_CACHE_TTL).build());
+
+/**
+ * 统计服务 trait
+ */
+#[async_trait]
+pub trait TStatisticsService {
+    /**
+     * 获取域统计概览
+     *
+     * 只有内置域用户可以查询其他域，结果缓存60秒
+     *
+     * @param request 查询参数，未指定域时统计当前用户所属域
+     * @param operator_domain 当前用户所属域
+     * @return Result<OverviewOutput, StatisticsError>
+     */
+    async fn get_overview(
+        &self,
+        request: StatisticsOverviewRequest,
+        operator_domain: &str,
+    ) -> Result<OverviewOutput, StatisticsError>;
+}
+
+/**
+ * 统计服务实现
+ */
+#[derive(Clone, Default)]
+pub struct SysStatisticsService;
+
+impl SysStatisticsService {
+    /**
+     * 确定统计的域
+     *
+     * 非内置域用户只能查询本域
+     */
+    fn resolve_domain(
+        request: StatisticsOverviewRequest,
+        operator_domain: &str,
+    ) -> Result<String, StatisticsError> {
+        let domain = request.domain.unwrap_or_else(|| operator_domain.to_string());
+        if operator_domain != BUILT_IN_DOMAIN_CODE && domain != operator_domain {
+            return Err(StatisticsError::CrossDomainForbidden);
+        }
+        Ok(domain)
+    }
+
+    /**
+     * 查询域统计概览
+     *
+     * 按天统计的窗口为 `now` 所在日期及之前6天，日期按UTC划分
+     *
+     * @param db 数据库连接
+     * @param domain 域代码
+     * @param now 当前时间（UTC）
+     */
+    pub(crate) async fn query_overview<C: ConnectionTrait>(
+        db: &C,
+        domain: &str,
+        now: NaiveDateTime,
+    ) -> Result<OverviewOutput, StatisticsError> {
+        let today = now.date();
+        let first_day = today - Days::new(STATISTICS_DAYS - 1);
+        let start = first_day.and_time(NaiveTime::MIN);
+        let end = (today + Days::new(1)).and_time(NaiveTime::MIN);
+
+        let users_by_status: Vec<(Status, i64)> = SysUser::find()
+            .select_only()
+            .column(SysUserColumn::Status)
+            .column_as(SysUserColumn::Id.count(), "count")
+            .filter(SysUserColumn::Domain.eq(domain))
+            .group_by(SysUserColumn::Status)
+            .order_by_asc(SysUserColumn::Status)
+            .into_tuple()
+            .all(db)
+            .await?;
+        let users_by_status = users_by_status
+            .into_iter()
+            .map(|(status, count)| StatusCount {
+                status,
+                count: count as u64,
+            })
+            .collect();
+
+        let login_day = day_bucket(db.get_database_backend(), "login_time");
+        let logins: Vec<(String, i64)> = SysLoginLog::find()
+            .select_only()
+            .column_as(login_day.clone(), "day")
+            .column_as(SysLoginLogColumn::Id.count(), "count")
+            .filter(SysLoginLogColumn::Domain.eq(domain))
+            .filter(SysLoginLogColumn::Success.eq(true))
+            .filter(SysLoginLogColumn::LoginTime.gte(start))
+            .filter(SysLoginLogColumn::LoginTime.lt(end))
+            .group_by(login_day)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let operation_day = day_bucket(db.get_database_backend(), "created_at");
+        let operations: Vec<(String, i64)> = SysOperationLog::find()
+            .select_only()
+            .column_as(operation_day.clone(), "day")
+            .column_as(SysOperationLogColumn::Id.count(), "count")
+            .filter(SysOperationLogColumn::Domain.eq(domain))
+            .filter(SysOperationLogColumn::CreatedAt.gte(start))
+            .filter(SysOperationLogColumn::CreatedAt.lt(end))
+            .group_by(operation_day)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let active_sessions = SysTokens::find()
+            .filter(SysTokensColumn::Domain.eq(domain))
+            .filter(SysTokensColumn::Status.eq(TokenStatus::Active.to_string()))
+            .filter(
+                Condition::any()
+                    .add(SysTokensColumn::ExpiresAt.is_null())
+                    .add(SysTokensColumn::ExpiresAt.gt(now)),
+            )
+            .count(db)
+            .await?;
+
+        let role_count = SysRole::find().filter(SysRoleColumn::Domain.eq(domain)).count(db).await?;
+
+        // 菜单不区分域，统计本域角色已分配的菜单
+        let menu_count = SysRoleMenu::find()
+            .select_only()
+            .column(SysRoleMenuColumn::MenuId)
+            .filter(SysRoleMenuColumn::Domain.eq(domain))
+            .distinct()
+            .count(db)
+            .await?;
+
+        Ok(OverviewOutput {
+            domain: domain.to_string(),
+            users_by_status,
+            logins_per_day: fill_days(first_day, logins)?,
+            operations_per_day: fill_days(first_day, operations)?,
+            active_sessions,
+            role_count,
+            menu_count,
+        })
+    }
+}
+
+/**
+ * 把时间列截断到天并格式化为 `YYYY-MM-DD` 的表达式
+ *
+ * 各数据库的日期函数不同，统一格式化为字符串后再解析
+ */
+fn day_bucket(backend: DatabaseBackend, column: &str) -> SimpleExpr {
+    match backend {
+        DatabaseBackend::Postgres => {
+            Expr::cust(format!("to_char(date_trunc('day', \"{}\"), 'YYYY-MM-DD')", column))
+        },
+        DatabaseBackend::MySql => Expr::cust(format!("DATE_FORMAT(`{}`, '%Y-%m-%d')", column)),
+        DatabaseBackend::Sqlite => Expr::cust(format!("strftime('%Y-%m-%d', \"{}\")", column)),
+    }
+}
+
+/**
+ * 把按天分组的查询结果展开为连续日期，没有记录的日期计数为0
+ */
+fn fill_days(
+    first_day: NaiveDate,
+    rows: Vec<(String, i64)>,
+) -> Result<Vec<DailyCount>, StatisticsError> {
+    let mut days: Vec<DailyCount> = first_day
+        .iter_days()
+        .take(STATISTICS_DAYS as usize)
+        .map(|date| DailyCount { date, count: 0 })
+        .collect();
+    for (day, count) in rows {
+        let date = NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+            .map_err(|e| StatisticsError::DatabaseOperationFailed(e.to_string()))?;
+        if let Some(item) = days.iter_mut().find(|item| item.date == date) {
+            item.count += count as u64;
+        }
+    }
+    Ok(days)
+}
+
+#[async_trait]
+impl TStatisticsService for SysStatisticsService {
+    async fn get_overview(
+        &self,
+        request: StatisticsOverviewRequest,
+        operator_domain: &str,
+    ) -> Result<OverviewOutput, StatisticsError> {
+        let domain = Self::resolve_domain(request, operator_domain)?;
+        if let Some(overview) = OVERVIEW_CACHE.get(&domain) {
+            return Ok(overview);
+        }
+
+        let db = db_helper::get_read_connection().await?;
+        let overview = Self::query_overview(db.as_ref(), &domain, timestamps::now()).await?;
+        OVERVIEW_CACHE.insert(domain, overview.clone());
+        Ok(overview)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, Database, DatabaseConnection, Schema, Set};
+    use server_model::admin::entities::{
+        sys_login_log::ActiveModel as SysLoginLogActiveModel,
+        sys_operation_log::ActiveModel as SysOperationLogActiveModel,
+        sys_role::ActiveModel as SysRoleActiveModel,
+        sys_role_menu::ActiveModel as SysRoleMenuActiveModel,
+        sys_tokens::ActiveModel as SysTokensActiveModel,
+        sys_user::ActiveModel as SysUserActiveModel,
+    };
+
+    use super::*;
+
+    fn at(date: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    async fn create_table<E: EntityTrait>(db: &DatabaseConnection, entity: E) {
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(entity))).await.unwrap();
+    }
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        create_table(&db, SysUser).await;
+        create_table(&db, SysLoginLog).await;
+        create_table(&db, SysOperationLog).await;
+        create_table(&db, SysTokens).await;
+        create_table(&db, SysRole).await;
+        create_table(&db, SysRoleMenu).await;
+        db
+    }
+
+    async fn insert_user(db: &DatabaseConnection, id: &str, domain: &str, status: Status) {
+        SysUserActiveModel {
+            id: Set(id.to_string()),
+            username: Set(id.to_string()),
+            password: Set(String::new()),
+            domain: Set(domain.to_string()),
+            built_in: Set(false),
+            nick_name: Set(id.to_string()),
+            status: Set(status),
+            created_at: Set(at("2026-01-01 00:00:00")),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_login(
+        db: &DatabaseConnection,
+        id: &str,
+        domain: &str,
+        login_time: &str,
+        success: bool,
+    ) {
+        SysLoginLogActiveModel {
+            id: Set(id.to_string()),
+            user_id: Set("1".to_string()),
+            username: Set("admin".to_string()),
+            domain: Set(domain.to_string()),
+            login_time: Set(at(login_time)),
+            ip: Set("127.0.0.1".to_string()),
+            port: Set(None),
+            address: Set(String::new()),
+            user_agent: Set(String::new()),
+            request_id: Set(String::new()),
+            r#type: Set("password".to_string()),
+            created_at: Set(at(login_time)),
+            created_by: Set("admin".to_string()),
+            success: Set(success),
+            failure_reason: Set((!success).then(|| "Invalid credentials".to_string())),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_operation(db: &DatabaseConnection, id: &str, domain: &str, created_at: &str) {
+        SysOperationLogActiveModel {
+            id: Set(id.to_string()),
+            user_id: Set("1".to_string()),
+            username: Set("admin".to_string()),
+            domain: Set(domain.to_string()),
+            module_name: Set("user".to_string()),
+            description: Set("test".to_string()),
+            request_id: Set(String::new()),
+            method: Set("GET".to_string()),
+            url: Set("/user".to_string()),
+            ip: Set("127.0.0.1".to_string()),
+            user_agent: Set(None),
+            params: Set(None),
+            body: Set(None),
+            response: Set(None),
+            start_time: Set(at(created_at)),
+            end_time: Set(at(created_at)),
+            duration: Set(0),
+            timed_out: Set(false),
+            status_code: Set(Some(200)),
+            created_at: Set(at(created_at)),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_token(
+        db: &DatabaseConnection,
+        id: &str,
+        status: TokenStatus,
+        expires_at: Option<&str>,
+    ) {
+        SysTokensActiveModel {
+            id: Set(id.to_string()),
+            access_token: Set(id.to_string()),
+            refresh_token: Set(id.to_string()),
+            status: Set(status.to_string()),
+            user_id: Set("1".to_string()),
+            username: Set("admin".to_string()),
+            domain: Set("tenant".to_string()),
+            login_time: Set(at("2026-03-01 00:00:00")),
+            ip: Set("127.0.0.1".to_string()),
+            port: Set(None),
+            address: Set(String::new()),
+            user_agent: Set(String::new()),
+            request_id: Set(String::new()),
+            created_at: Set(at("2026-03-01 00:00:00")),
+            created_by: Set("admin".to_string()),
+            jti: Set(None),
+            expires_at: Set(expires_at.map(at)),
+            last_seen_at: Set(None),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    /**
+     * 测试按天统计在跨月时的分组和补零
+     */
+    #[tokio::test]
+    async fn test_query_overview_buckets_across_month_boundary() {
+        let db = setup_db().await;
+        insert_user(&db, "u1", "tenant", Status::Enabled).await;
+        insert_user(&db, "u2", "tenant", Status::Enabled).await;
+        insert_user(&db, "u3", "tenant", Status::Banned).await;
+        insert_user(&db, "u4", "other", Status::Disabled).await;
+
+        // 窗口为 2026-02-24 至 2026-03-02
+        insert_login(&db, "l1", "tenant", "2026-02-23 23:59:59", true).await;
+        insert_login(&db, "l2", "tenant", "2026-02-24 00:00:00", true).await;
+        insert_login(&db, "l3", "tenant", "2026-02-28 23:59:59", true).await;
+        insert_login(&db, "l4", "tenant", "2026-03-01 00:00:00", true).await;
+        insert_login(&db, "l5", "tenant", "2026-03-01 12:30:00", true).await;
+        insert_login(&db, "l6", "other", "2026-03-01 12:30:00", true).await;
+        insert_login(&db, "l7", "tenant", "2026-03-03 00:00:00", true).await;
+        // 失败的登录尝试不计入登录次数
+        insert_login(&db, "l8", "tenant", "2026-03-01 12:30:00", false).await;
+        insert_operation(&db, "o1", "tenant", "2026-02-28 00:00:00").await;
+        insert_operation(&db, "o2", "tenant", "2026-03-02 08:00:00").await;
+
+        insert_token(&db, "t1", TokenStatus::Active, None).await;
+        insert_token(&db, "t2", TokenStatus::Active, Some("2026-03-02 10:00:00")).await;
+        insert_token(&db, "t3", TokenStatus::Active, Some("2026-03-02 08:00:00")).await;
+        insert_token(&db, "t4", TokenStatus::Revoked, None).await;
+
+        for (id, domain) in [("r1", "tenant"), ("r2", "tenant"), ("r3", "other")] {
+            SysRoleActiveModel {
+                id: Set(id.to_string()),
+                code: Set(id.to_string()),
+                domain: Set(domain.to_string()),
+                name: Set(id.to_string()),
+                description: Set(None),
+                pid: Set("0".to_string()),
+                status: Set(Status::Enabled),
+                created_at: Set(at("2026-01-01 00:00:00")),
+                created_by: Set("test".to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+        for (role_id, menu_id, domain) in
+            [("r1", 1, "tenant"), ("r2", 1, "tenant"), ("r2", 2, "tenant"), ("r3", 3, "other")]
+        {
+            SysRoleMenuActiveModel {
+                role_id: Set(role_id.to_string()),
+                menu_id: Set(menu_id),
+                domain: Set(domain.to_string()),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let now = at("2026-03-02 09:00:00");
+        let overview = SysStatisticsService::query_overview(&db, "tenant", now).await.unwrap();
+
+        assert_eq!(overview.domain, "tenant");
+        assert_eq!(
+            overview.users_by_status,
+            [
+                StatusCount {
+                    status: Status::Banned,
+                    count: 1
+                },
+                StatusCount {
+                    status: Status::Enabled,
+                    count: 2
+                },
+            ]
+        );
+
+        let counts = |days: &[DailyCount]| -> Vec<(String, u64)> {
+            days.iter().map(|day| (day.date.to_string(), day.count)).collect()
+        };
+        let expected = |values: [u64; 7]| -> Vec<(String, u64)> {
+            ["02-24", "02-25", "02-26", "02-27", "02-28", "03-01", "03-02"]
+                .iter()
+                .zip(values)
+                .map(|(day, count)| (format!("2026-{}", day), count))
+                .collect()
+        };
+        assert_eq!(counts(&overview.logins_per_day), expected([1, 0, 0, 0, 1, 2, 0]));
+        assert_eq!(counts(&overview.operations_per_day), expected([0, 0, 0, 0, 1, 0, 1]));
+
+        assert_eq!(overview.active_sessions, 2);
+        assert_eq!(overview.role_count, 2);
+        assert_eq!(overview.menu_count, 2);
+    }
+
+    /**
+     * 测试非内置域用户不能查询其他域
+     */
+    #[tokio::test]
+    async fn test_get_overview_rejects_cross_domain() {
+        let request = |domain: &str| StatisticsOverviewRequest {
+            domain: Some(domain.to_string()),
+        };
+        let result = SysStatisticsService.get_overview(request("other"), "tenant").await;
+        assert!(matches!(result, Err(StatisticsError::CrossDomainForbidden)));
+
+        let domain = SysStatisticsService::resolve_domain(request("other"), BUILT_IN_DOMAIN_CODE);
+        assert_eq!(domain.unwrap(), "other");
+        let domain = SysStatisticsService::resolve_domain(request("tenant"), "tenant");
+        assert_eq!(domain.unwrap(), "tenant");
+        let domain =
+            SysStatisticsService::resolve_domain(StatisticsOverviewRequest::default(), "tenant");
+        assert_eq!(domain.unwrap(), "tenant");
+    }
+}
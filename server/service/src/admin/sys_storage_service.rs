@@ -0,0 +1,167 @@
+/**
+ * 对象存储服务模块
+ *
+ * 该模块提供了由本服务提供下载的对象存储文件访问功能，包括：
+ * - 校验本地存储生成的预签名链接并打开文件
+ * - 为无法直接访问存储服务的前端代理下载文件，支持按范围读取
+ *
+ * 对象存储实现由初始化模块按 `s3.provider` 创建，本服务只依赖 `ObjectStore` 接口。
+ * S3的预签名链接直接访问存储服务，不经过本服务；S3未对外开放时由代理下载接口转发。
+ *
+ * 代理下载的对象键以所属域的编码开头（如 `<domain>/avatars/a.png`），
+ * 非内置域的用户只能下载本域的文件。
+ *
+ * 主要组件
+ * --------
+ * - TStorageService: 对象存储服务 trait
+ * - SysStorageService: 对象存储服务实现
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use server_core::web::{auth::User, error::AppError};
+use server_global::global::GLOBAL_PRIMARY_OBJECT_STORE;
+use server_model::admin::input::PresignedFileRequest;
+use server_storage::{ByteRange, ObjectRead, ObjectReader, ObjectStore, StorageError};
+
+use crate::admin::sys_domain_service::BUILT_IN_DOMAIN_CODE;
+
+/**
+ * 对象存储服务 trait
+ */
+#[async_trait]
+pub trait TStorageService {
+    /**
+     * 按预签名链接打开文件
+     *
+     * @param key 对象键
+     * @param request 预签名链接携带的过期时间和签名
+     * @return Result<ObjectReader, AppError> 文件内容的读取器；未配置存储、存储不由本服务提供下载
+     *         或文件不存在时返回404，链接过期或签名错误时返回403
+     */
+    async fn read_presigned_file(
+        &self,
+        key: &str,
+        request: PresignedFileRequest,
+    ) -> Result<ObjectReader, AppError>;
+
+    /**
+     * 代理下载文件
+     *
+     * @param user 当前用户，非内置域的用户只能下载以本域编码开头的对象键
+     * @param key 对象键
+     * @param range 读取的字节范围，为 None 时读取整个文件
+     * @return Result<ObjectRead, AppError> 打开的文件；对象键不属于用户所在的域时返回403，
+     *         未配置存储或文件不存在时返回404，范围超出文件大小时返回416，
+     *         此时 `args[0]` 为文件大小，用于填写416响应的 Content-Range
+     */
+    async fn open_file(
+        &self,
+        user: &User,
+        key: &str,
+        range: Option<ByteRange>,
+    ) -> Result<ObjectRead, AppError>;
+}
+
+/**
+ * 对象存储服务
+ */
+#[derive(Clone)]
+pub struct SysStorageService;
+
+#[async_trait]
+impl TStorageService for SysStorageService {
+    async fn read_presigned_file(
+        &self,
+        key: &str,
+        request: PresignedFileRequest,
+    ) -> Result<ObjectReader, AppError> {
+        primary_store()
+            .await?
+            .get_presigned(key, request.expires, &request.signature)
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn open_file(
+        &self,
+        user: &User,
+        key: &str,
+        range: Option<ByteRange>,
+    ) -> Result<ObjectRead, AppError> {
+        let domain = user.domain();
+        if domain != BUILT_IN_DOMAIN_CODE && key.split('/').next() != Some(domain.as_str()) {
+            return Err(AppError::new(403, "Forbidden"));
+        }
+        primary_store().await?.open(key, range).await.map_err(storage_error)
+    }
+}
+
+/**
+ * 主对象存储，未配置时返回404
+ */
+async fn primary_store() -> Result<Arc<dyn ObjectStore>, AppError> {
+    GLOBAL_PRIMARY_OBJECT_STORE
+        .read()
+        .await
+        .clone()
+        .ok_or_else(|| AppError::new(404, "Not Found"))
+}
+
+/**
+ * 把对象存储错误转换为应用错误
+ *
+ * 不支持预签名下载与文件不存在使用同一响应，不暴露存储实现
+ */
+fn storage_error(err: StorageError) -> AppError {
+    match err {
+        StorageError::NotFound(_) | StorageError::Unsupported => AppError::new(404, "Not Found"),
+        StorageError::InvalidSignature => AppError::new(403, err.to_string()),
+        StorageError::InvalidKey(_) => AppError::new(400, err.to_string()),
+        StorageError::RangeNotSatisfiable { size } => AppError {
+            args: vec![size.to_string()],
+            ..AppError::new(416, err.to_string())
+        },
+        StorageError::Backend(_) => AppError::new(500, err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use server_storage::{LocalObjectStore, PutOptions};
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_presigned_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Arc::new(LocalObjectStore::new(dir.path(), "/files", b"test-signing-key"));
+        store.put("avatars/a.png", b"image".to_vec(), PutOptions::default()).await.unwrap();
+        let url = store.presign("avatars/a.png", Duration::from_secs(60)).await.unwrap();
+        *GLOBAL_PRIMARY_OBJECT_STORE.write().await = Some(store as Arc<dyn ObjectStore>);
+
+        let query = url.split_once('?').unwrap().1;
+        let params: Vec<&str> =
+            query.split('&').filter_map(|pair| pair.split('=').nth(1)).collect();
+        let request = |signature: &str| PresignedFileRequest {
+            expires: params[0].parse().unwrap(),
+            signature: signature.to_string(),
+        };
+
+        let service = SysStorageService;
+        let mut reader =
+            service.read_presigned_file("avatars/a.png", request(params[1])).await.unwrap();
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body).await.unwrap();
+        assert_eq!(body, b"image");
+
+        let err = service.read_presigned_file("avatars/b.png", request(params[1])).await.err();
+        assert_eq!(err.unwrap().status.as_u16(), 403);
+        let err = service.read_presigned_file("avatars/a.png", request("00")).await.err();
+        assert_eq!(err.unwrap().status.as_u16(), 403);
+    }
+}
@@ -0,0 +1,187 @@
+/**
+ * 系统信息服务模块
+ *
+ * 该模块提供了服务自身信息的查询功能，包括：
+ * - 查询版本号、Git提交、构建时间和rustc版本
+ * - 查询可选子系统（Redis、MongoDB、S3、XDB）的初始化情况
+ * - 主库故障转移后重新连接主库
+ *
+ * 构建信息来自 `server_global::server_version`，子系统情况按对应的全局连接是否存在判断，
+ * S3按主对象存储（S3或本地存储）是否已创建判断。
+ * 主库重新连接调用初始化模块注册的 `db_helper::primary_reconnector`，只有内置域用户可以执行。
+ *
+ * 主要组件
+ * --------
+ * - TSystemService: 系统信息服务 trait
+ * - SysSystemService: 系统信息服务实现
+ */
+
+use async_trait::async_trait;
+use server_config::DatabaseConfig;
+use server_global::{
+    global::{get_config, GLOBAL_PRIMARY_MONGO, GLOBAL_PRIMARY_OBJECT_STORE, GLOBAL_PRIMARY_REDIS},
+    project_info, server_version,
+};
+use server_model::admin::{
+    input::DbReconnectInput,
+    output::{SubsystemStatus, VersionOutput},
+};
+
+use crate::{
+    admin::{errors::sys_system_error::SystemError, sys_domain_service::BUILT_IN_DOMAIN_CODE},
+    helper::db_helper,
+};
+
+/**
+ * 系统信息服务 trait
+ */
+#[async_trait]
+pub trait TSystemService {
+    /**
+     * 查询版本信息
+     *
+     * @return VersionOutput 构建信息及可选子系统的初始化情况
+     */
+    async fn get_version(&self) -> VersionOutput;
+
+    /**
+     * 重新连接主库
+     *
+     * 新连接验证可用后替换主库连接，已开始的查询在旧连接上继续完成
+     *
+     * @param input 新的主库连接URL，为空时使用配置的故障转移地址或当前地址
+     * @param operator_domain 操作用户所属域，只有内置域用户可以执行
+     * @return Result<(), SystemError> 新连接无法建立时返回错误，主库连接保持不变
+     */
+    async fn reconnect_primary_db(
+        &self,
+        input: DbReconnectInput,
+        operator_domain: &str,
+    ) -> Result<(), SystemError>;
+}
+
+/**
+ * 系统信息服务
+ */
+#[derive(Clone)]
+pub struct SysSystemService;
+
+#[async_trait]
+impl TSystemService for SysSystemService {
+    async fn get_version(&self) -> VersionOutput {
+        VersionOutput {
+            version: server_version::version_string().to_string(),
+            crate_version: server_version::VERSION.to_string(),
+            git_hash: server_version::GIT_HASH.map(str::to_string),
+            build_time: server_version::build_time(),
+            rustc_version: server_version::RUSTC_VERSION.map(str::to_string),
+            subsystems: subsystem_status().await,
+        }
+    }
+
+    async fn reconnect_primary_db(
+        &self,
+        input: DbReconnectInput,
+        operator_domain: &str,
+    ) -> Result<(), SystemError> {
+        if operator_domain != BUILT_IN_DOMAIN_CODE {
+            return Err(SystemError::BuiltInDomainOnly);
+        }
+        let reconnect = db_helper::primary_reconnector().ok_or(SystemError::ReconnectUnavailable)?;
+        let db_config =
+            get_config::<DatabaseConfig>().await.ok_or(SystemError::ReconnectUnavailable)?;
+
+        let new_config = reconnect_config(&db_config, input);
+        reconnect(new_config).await.map_err(SystemError::ReconnectFailed)?;
+        project_info!("Primary database reconnected by a built-in domain user");
+        Ok(())
+    }
+}
+
+/**
+ * 确定重新连接使用的主库配置
+ *
+ * URL依次取请求中的地址、配置的 `failover_url` 和当前地址，其余参数沿用当前配置
+ */
+fn reconnect_config(db_config: &DatabaseConfig, input: DbReconnectInput) -> DatabaseConfig {
+    let url = [input.url, db_config.failover_url.clone()]
+        .into_iter()
+        .flatten()
+        .find(|url| !url.trim().is_empty())
+        .unwrap_or_else(|| db_config.url.clone());
+    DatabaseConfig {
+        url,
+        ..db_config.clone()
+    }
+}
+
+/**
+ * 读取可选子系统的初始化情况
+ */
+async fn subsystem_status() -> SubsystemStatus {
+    SubsystemStatus {
+        redis: GLOBAL_PRIMARY_REDIS.read().await.is_some(),
+        mongo: GLOBAL_PRIMARY_MONGO.read().await.is_some(),
+        s3: GLOBAL_PRIMARY_OBJECT_STORE.read().await.is_some(),
+        xdb: xdb::is_initialized(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_version_reports_build_info() {
+        let output = SysSystemService.get_version().await;
+        assert!(output.version.starts_with(&output.crate_version));
+        assert_eq!(output.git_hash.is_some(), output.version.contains('+'));
+        assert!(output.build_time.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_primary_db_requires_built_in_domain() {
+        let err = SysSystemService
+            .reconnect_primary_db(DbReconnectInput::default(), "tenant")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SystemError::BuiltInDomainOnly));
+
+        // 未注册重新连接函数时不可用
+        let err = SysSystemService
+            .reconnect_primary_db(DbReconnectInput::default(), BUILT_IN_DOMAIN_CODE)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SystemError::ReconnectUnavailable));
+    }
+
+    #[test]
+    fn test_reconnect_config_url() {
+        let db_config = DatabaseConfig {
+            url: "postgres://u:p@primary/db".to_string(),
+            max_connections: 10,
+            min_idle: None,
+            connect_timeout: None,
+            idle_timeout: None,
+            max_lifetime: None,
+            statement_timeout_ms: None,
+            slow_query_threshold_ms: None,
+            failover_url: Some("postgres://u:p@standby/db".to_string()),
+            failover_threshold: None,
+        };
+        let input = |url: Option<&str>| DbReconnectInput {
+            url: url.map(str::to_string),
+        };
+
+        let config = reconnect_config(&db_config, input(Some("postgres://u:p@other/db")));
+        assert_eq!(config.url, "postgres://u:p@other/db");
+        assert_eq!(config.max_connections, 10);
+        assert_eq!(reconnect_config(&db_config, input(Some(" "))).url, "postgres://u:p@standby/db");
+
+        let db_config = DatabaseConfig {
+            failover_url: None,
+            ..db_config
+        };
+        assert_eq!(reconnect_config(&db_config, input(None)).url, "postgres://u:p@primary/db");
+    }
+}
@@ -0,0 +1,456 @@
+/**
+ * 登录令牌服务模块
+ *
+ * 该模块提供了用户管理本人登录令牌（登录设备）的功能，包括：
+ * - 分页查询本人未撤销且未过期的令牌，标记本次请求使用的令牌
+ * - 撤销本人的指定令牌
+ * - 请求认证时检查令牌是否已撤销并记录最近使用时间
+ *
+ * 令牌记录在登录时由登录事件写入，按访问令牌的 JWT ID 与请求关联。
+ *
+ * 主要组件
+ * --------
+ * - TTokenService: 登录令牌服务 trait
+ * - SysTokenService: 登录令牌服务实现
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::admin::{SysTokenService, TTokenService};
+ *
+ * // 撤销本人的令牌，令牌属于其他用户时返回 TokenNotFound
+ * SysTokenService.revoke_user_token(&user, &id).await?;
+ */
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, Condition, ConnectionTrait, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder,
+};
+use server_constant::definition::consts::TokenStatus;
+use server_core::{
+    paginated_data,
+    web::{
+        auth::User,
+        page::{KeywordFilter, PaginatedData},
+    },
+};
+use server_model::admin::{
+    entities::{
+        prelude::SysTokens,
+        sys_tokens::{Column as SysTokensColumn, Model as SysTokensModel},
+    },
+    input::TokenPageRequest,
+    output::TokenOutput,
+    timestamps,
+};
+
+use crate::helper::db_helper;
+
+use super::errors::sys_token_error::TokenError;
+
+/** 同一令牌两次写入最近使用时间的最小间隔（秒） */
+const LAST_SEEN_DEBOUNCE_SECS: i64 = 60;
+
+/**
+ * 登录令牌服务 trait
+ *
+ * 所有操作都限定在当前用户本人的令牌范围内
+ */
+#[async_trait]
+pub trait TTokenService {
+    /**
+     * 分页查询用户本人的有效令牌
+     *
+     * 只返回未撤销且未过期的令牌，JWT ID 与 `current_jti` 相同的令牌标记为当前令牌
+     *
+     * @param user_id 当前认证用户ID
+     * @param current_jti 本次请求使用的令牌的 JWT ID
+     * @param params 分页和关键字参数
+     * @return Result<PaginatedData<TokenOutput>, TokenError>
+     */
+    async fn find_user_tokens(
+        &self,
+        user_id: &str,
+        current_jti: Option<&str>,
+        params: TokenPageRequest,
+    ) -> Result<PaginatedData<TokenOutput>, TokenError>;
+
+    /**
+     * 撤销用户本人的令牌
+     *
+     * 令牌不存在、属于其他用户或已撤销时均返回 `TokenNotFound`，不暴露其他用户的令牌是否存在；
+     * 使用个人访问令牌调用时返回 `PersonalTokenNotAllowed`
+     *
+     * @param user 当前认证用户
+     * @param id 令牌记录ID
+     * @return Result<(), TokenError>
+     */
+    async fn revoke_user_token(&self, user: &User, id: &str) -> Result<(), TokenError>;
+
+    /**
+     * 检查请求使用的令牌并记录最近使用时间
+     *
+     * 令牌已撤销时返回 `TokenRevoked`；签发记录尚未写入时视为有效。
+     * 最近使用时间至多每分钟写入一次。
+     *
+     * @param jti 令牌的 JWT ID
+     * @return Result<(), TokenError>
+     */
+    async fn touch_token(&self, jti: &str) -> Result<(), TokenError>;
+}
+
+/**
+ * 登录令牌服务实现
+ */
+#[derive(Clone, Default)]
+pub struct SysTokenService;
+
+impl SysTokenService {
+    async fn find_user_tokens_in<C: ConnectionTrait>(
+        db: &C,
+        user_id: &str,
+        current_jti: Option<&str>,
+        params: TokenPageRequest,
+        now: NaiveDateTime,
+    ) -> Result<PaginatedData<TokenOutput>, TokenError> {
+        let mut query = SysTokens::find()
+            .filter(SysTokensColumn::UserId.eq(user_id))
+            .filter(SysTokensColumn::Status.eq(TokenStatus::Active.to_string()))
+            .filter(
+                Condition::any()
+                    .add(SysTokensColumn::ExpiresAt.is_null())
+                    .add(SysTokensColumn::ExpiresAt.gt(now)),
+            );
+
+        if let Some(ref keywords) = params.keywords {
+            query = query.filter(KeywordFilter::new(keywords).condition(&[
+                SysTokensColumn::Ip,
+                SysTokensColumn::Address,
+                SysTokensColumn::UserAgent,
+            ]));
+        }
+
+        query = query.order_by_desc(SysTokensColumn::CreatedAt);
+
+        let total = query.clone().count(db).await?;
+        let paginator = query.paginate(db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current.saturating_sub(1))
+            .await?
+            .into_iter()
+            .map(|token| to_token_output(token, current_jti))
+            .collect();
+
+        Ok(paginated_data!(
+            total,
+            params.page_details.current,
+            params.page_details.size,
+            records
+        ))
+    }
+
+    async fn revoke_user_token_in<C: ConnectionTrait>(
+        db: &C,
+        user_id: &str,
+        id: &str,
+    ) -> Result<(), TokenError> {
+        let result = SysTokens::update_many()
+            .col_expr(
+                SysTokensColumn::Status,
+                Expr::value(TokenStatus::Revoked.to_string()),
+            )
+            .filter(SysTokensColumn::Id.eq(id))
+            .filter(SysTokensColumn::UserId.eq(user_id))
+            .filter(SysTokensColumn::Status.eq(TokenStatus::Active.to_string()))
+            .exec(db)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(TokenError::TokenNotFound);
+        }
+        Ok(())
+    }
+
+    async fn touch_token_in<C: ConnectionTrait>(
+        db: &C,
+        jti: &str,
+        now: NaiveDateTime,
+    ) -> Result<(), TokenError> {
+        let Some(token) = SysTokens::find()
+            .filter(SysTokensColumn::Jti.eq(jti))
+            .one(db)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        if !token
+            .status
+            .parse::<TokenStatus>()
+            .is_ok_and(|status| status.is_valid())
+        {
+            return Err(TokenError::TokenRevoked);
+        }
+
+        let stale = token
+            .last_seen_at
+            .is_none_or(|last_seen| now - last_seen >= Duration::seconds(LAST_SEEN_DEBOUNCE_SECS));
+        if stale {
+            SysTokens::update_many()
+                .col_expr(SysTokensColumn::LastSeenAt, Expr::value(now))
+                .filter(SysTokensColumn::Id.eq(token.id))
+                .exec(db)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TTokenService for SysTokenService {
+    async fn find_user_tokens(
+        &self,
+        user_id: &str,
+        current_jti: Option<&str>,
+        params: TokenPageRequest,
+    ) -> Result<PaginatedData<TokenOutput>, TokenError> {
+        let db = db_helper::get_read_connection().await?;
+        let now = timestamps::now();
+        Self::find_user_tokens_in(db.as_ref(), user_id, current_jti, params, now).await
+    }
+
+    async fn revoke_user_token(&self, user: &User, id: &str) -> Result<(), TokenError> {
+        if user.personal_token().is_some() {
+            return Err(TokenError::PersonalTokenNotAllowed);
+        }
+        let db = db_helper::get_db_connection().await?;
+        Self::revoke_user_token_in(db.as_ref(), &user.user_id(), id).await
+    }
+
+    async fn touch_token(&self, jti: &str) -> Result<(), TokenError> {
+        let db = db_helper::get_db_connection().await?;
+        Self::touch_token_in(db.as_ref(), jti, timestamps::now()).await
+    }
+}
+
+/**
+ * 转换为令牌输出，不包含令牌内容
+ */
+fn to_token_output(token: SysTokensModel, current_jti: Option<&str>) -> TokenOutput {
+    let current = current_jti.is_some() && token.jti.as_deref() == current_jti;
+    TokenOutput {
+        id: token.id,
+        ip: token.ip,
+        address: token.address,
+        user_agent: token.user_agent,
+        login_type: token.r#type,
+        created_at: token.created_at,
+        last_seen: token.last_seen_at,
+        expires_at: token.expires_at,
+        current,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, Database, DatabaseConnection, Schema, Set};
+    use server_core::web::{
+        error::{ApiError, StatusCode},
+        page::PageRequest,
+    };
+    use server_model::admin::entities::sys_tokens::ActiveModel as SysTokensActiveModel;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysTokens)))
+            .await
+            .unwrap();
+        db
+    }
+
+    async fn insert_token(
+        db: &DatabaseConnection,
+        id: &str,
+        user_id: &str,
+        status: TokenStatus,
+        expires_at: NaiveDateTime,
+    ) {
+        let now = timestamps::now();
+        SysTokensActiveModel {
+            id: Set(id.to_string()),
+            access_token: Set(format!("{}-access", id)),
+            refresh_token: Set(format!("{}-refresh", id)),
+            status: Set(status.to_string()),
+            user_id: Set(user_id.to_string()),
+            username: Set(user_id.to_string()),
+            domain: Set("built-in".to_string()),
+            login_time: Set(now),
+            ip: Set("127.0.0.1".to_string()),
+            port: Set(None),
+            address: Set("".to_string()),
+            user_agent: Set(format!("agent-{}", id)),
+            request_id: Set("".to_string()),
+            r#type: Set("PC".to_string()),
+            created_at: Set(now),
+            created_by: Set(user_id.to_string()),
+            jti: Set(Some(format!("jti-{}", id))),
+            expires_at: Set(Some(expires_at)),
+            last_seen_at: Set(None),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+    }
+
+    fn request(keywords: Option<&str>) -> TokenPageRequest {
+        TokenPageRequest {
+            page_details: PageRequest {
+                current: 1,
+                size: 10,
+                sort_by: None,
+                sort_order: None,
+            },
+            keywords: keywords.map(str::to_string),
+        }
+    }
+
+    async fn list(
+        db: &DatabaseConnection,
+        user_id: &str,
+        keywords: Option<&str>,
+    ) -> Vec<TokenOutput> {
+        let now = timestamps::now();
+        SysTokenService::find_user_tokens_in(db, user_id, Some("jti-phone"), request(keywords), now)
+            .await
+            .unwrap()
+            .records
+    }
+
+    #[tokio::test]
+    async fn test_find_user_tokens() {
+        let db = setup_db().await;
+        let later = timestamps::now() + Duration::hours(1);
+        let earlier = timestamps::now() - Duration::hours(1);
+        insert_token(&db, "phone", "alice", TokenStatus::Active, later).await;
+        insert_token(&db, "laptop", "alice", TokenStatus::Active, later).await;
+        insert_token(&db, "old", "alice", TokenStatus::Active, earlier).await;
+        insert_token(&db, "gone", "alice", TokenStatus::Revoked, later).await;
+        insert_token(&db, "other", "bob", TokenStatus::Active, later).await;
+
+        let mut tokens = list(&db, "alice", None).await;
+        tokens.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(
+            (tokens[0].id.as_str(), tokens[0].current),
+            ("laptop", false)
+        );
+        assert_eq!((tokens[1].id.as_str(), tokens[1].current), ("phone", true));
+
+        let tokens = list(&db, "alice", Some("agent-lap")).await;
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].id, "laptop");
+    }
+
+    #[tokio::test]
+    async fn test_revoke_current_token_logs_out() {
+        let db = setup_db().await;
+        let later = timestamps::now() + Duration::hours(1);
+        insert_token(&db, "phone", "alice", TokenStatus::Active, later).await;
+        insert_token(&db, "other", "bob", TokenStatus::Active, later).await;
+        let now = timestamps::now();
+
+        // 其他用户的令牌与不存在的令牌返回相同的错误
+        assert!(matches!(
+            SysTokenService::revoke_user_token_in(&db, "alice", "other").await,
+            Err(TokenError::TokenNotFound)
+        ));
+        assert!(matches!(
+            SysTokenService::revoke_user_token_in(&db, "alice", "missing").await,
+            Err(TokenError::TokenNotFound)
+        ));
+        assert!(SysTokenService::touch_token_in(&db, "jti-other", now)
+            .await
+            .is_ok());
+
+        assert!(SysTokenService::touch_token_in(&db, "jti-phone", now)
+            .await
+            .is_ok());
+        SysTokenService::revoke_user_token_in(&db, "alice", "phone")
+            .await
+            .unwrap();
+        assert!(matches!(
+            SysTokenService::touch_token_in(&db, "jti-phone", now).await,
+            Err(TokenError::TokenRevoked)
+        ));
+        assert!(list(&db, "alice", None).await.is_empty());
+
+        // 已撤销的令牌不能再次撤销
+        assert!(matches!(
+            SysTokenService::revoke_user_token_in(&db, "alice", "phone").await,
+            Err(TokenError::TokenNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_user_token_rejects_personal_token() {
+        // 在访问数据库之前拒绝，个人访问令牌不能撤销登录令牌
+        let user = User::new("alice".to_string(), "alice".to_string(), "built-in".to_string())
+            .with_personal_token("pat-1".to_string());
+        let err = SysTokenService.revoke_user_token(&user, "phone").await.err().unwrap();
+        assert!(matches!(err, TokenError::PersonalTokenNotAllowed));
+        assert_eq!(err.code(), 14004);
+        assert_eq!(err.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_touch_token_debounces_last_seen() {
+        let db = setup_db().await;
+        let start = timestamps::now();
+        insert_token(
+            &db,
+            "phone",
+            "alice",
+            TokenStatus::Active,
+            start + Duration::hours(1),
+        )
+        .await;
+        let last_seen = |db: &DatabaseConnection| {
+            let db = db.clone();
+            async move {
+                SysTokens::find_by_id("phone")
+                    .one(&db)
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .last_seen_at
+            }
+        };
+
+        SysTokenService::touch_token_in(&db, "jti-phone", start)
+            .await
+            .unwrap();
+        assert_eq!(last_seen(&db).await, Some(start));
+
+        let soon = start + Duration::seconds(LAST_SEEN_DEBOUNCE_SECS - 1);
+        SysTokenService::touch_token_in(&db, "jti-phone", soon)
+            .await
+            .unwrap();
+        assert_eq!(last_seen(&db).await, Some(start));
+
+        let later = start + Duration::seconds(LAST_SEEN_DEBOUNCE_SECS);
+        SysTokenService::touch_token_in(&db, "jti-phone", later)
+            .await
+            .unwrap();
+        assert_eq!(last_seen(&db).await, Some(later));
+
+        // 签发记录尚未写入的令牌视为有效
+        assert!(SysTokenService::touch_token_in(&db, "unknown", later)
+            .await
+            .is_ok());
+    }
+}
@@ -5,6 +5,7 @@
  * - 用户CRUD操作
  * - 用户分页查询
  * - 用户名唯一性检查
+ * - 查询用户的角色
  *
  * 主要组件
  * --------
@@ -14,9 +15,10 @@
  * 功能特性
  * --------
  * - 用户创建：支持创建新用户，包括密码加密
- * - 用户查询：支持单个查询和分页查询
+ * - 用户查询：支持单个查询和分页查询，分页查询可按角色、组织（含下级组织）、状态和域筛选
  * - 用户更新：支持更新用户信息，包括用户名唯一性检查
  * - 用户删除：支持删除用户
+ * - 数据访问：通过注入的 `UserRepository` 完成，默认使用 SeaORM 实现
  *
  * 使用示例
  * --------
@@ -24,7 +26,7 @@
  * use server_service::admin::sys_user_service::*;
  *
  * // 创建用户服务实例
- * let user_service = SysUserService;
+ * let user_service = SysUserService::default();
  *
  * // 创建新用户
  * let user = user_service.create_user(CreateUserInput {
@@ -48,31 +50,27 @@
  * }).await?;
  */
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use chrono::Local;
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel, PaginatorTrait,
-    QueryFilter, Set,
-};
 use server_core::{
-    web::page::PaginatedData,
+    web::{page::PaginatedData, CurrentUserContext},
     paginated_data,
 };
 use server_model::admin::{
-    entities::{
-        prelude::SysUser,
-        sys_user::{
-            ActiveModel as SysUserActiveModel, Column as SysUserColumn, Model as SysUserModel,
-        },
-    },
+    entities::sys_user::Model as SysUserModel,
+    ids::UserId,
     input::{CreateUserInput, UpdateUserInput, UserPageRequest},
-    output::UserWithoutPassword,
+    output::{UserRoleOutput, UserWithoutPassword},
 };
 use server_utils::SecureUtil;
 use ulid::Ulid;
 
-use super::sys_user_error::UserError;
-use crate::helper::db_helper;
+use super::{
+    repositories::{SeaOrmUserRepository, UserRepository},
+    sys_user_error::UserError,
+};
+use crate::helper::audit_fields;
 
 /**
  * 用户服务 trait
@@ -88,7 +86,7 @@ use crate::helper::db_helper;
  *
  * use server_service::admin::sys_user_service::*;
  *
- * let user_service = SysUserService;
+ * let user_service = SysUserService::default();
  *
  * // 查询所有用户
  * let users = user_service.find_all().await?;
@@ -118,7 +116,7 @@ pub trait TUserService {
      *
      * 根据查询条件分页获取用户列表
      *
-     * @param params 分页查询参数，包含关键字和分页信息
+     * @param params 分页查询参数，包含关键字、角色、组织、状态、域筛选条件和分页信息
      * @return Result<PaginatedData<UserWithoutPassword>, UserError> 分页用户数据或错误
      */
     async fn find_paginated_users(
@@ -144,7 +142,7 @@ pub trait TUserService {
      * @param id 用户ID
      * @return Result<UserWithoutPassword, UserError> 用户信息或错误
      */
-    async fn get_user(&self, id: &str) -> Result<UserWithoutPassword, UserError>;
+    async fn get_user(&self, id: &UserId) -> Result<UserWithoutPassword, UserError>;
 
     /**
      * 更新用户
@@ -164,7 +162,22 @@ pub trait TUserService {
      * @param id 用户ID
      * @return Result<(), UserError> 删除结果
      */
-    async fn delete_user(&self, id: &str) -> Result<(), UserError>;
+    async fn delete_user(&self, id: &UserId) -> Result<(), UserError>;
+
+    /**
+     * 查询用户的角色
+     *
+     * 只能查询指定域中的用户，其他域的用户视为不存在
+     *
+     * @param id 用户ID
+     * @param domain 当前用户所属域
+     * @return Result<Vec<UserRoleOutput>, UserError> 角色列表或错误
+     */
+    async fn find_user_roles(
+        &self,
+        id: &UserId,
+        domain: &str,
+    ) -> Result<Vec<UserRoleOutput>, UserError>;
 }
 
 /**
@@ -175,12 +188,14 @@ pub trait TUserService {
  * - 用户分页查询
  * - 用户名唯一性检查
  *
+ * 用户数据通过 `UserRepository` 访问，测试时可通过 `new` 注入其他实现
+ *
  * 使用示例
  * --------
  *
  * use server_service::admin::sys_user_service::*;
  *
- * let user_service = SysUserService;
+ * let user_service = SysUserService::default();
  *
  * // 创建用户
  * let user = user_service.create_user(CreateUserInput {
@@ -195,29 +210,43 @@ pub trait TUserService {
  * }).await?;
  */
 #[derive(Clone)]
-pub struct SysUserService;
+pub struct SysUserService {
+    users: Arc<dyn UserRepository>,
+}
+
+impl Default for SysUserService {
+    fn default() -> Self {
+        Self::new(Arc::new(SeaOrmUserRepository))
+    }
+}
 
 impl SysUserService {
+    /**
+     * 创建用户服务
+     *
+     * @param users 用户数据访问实现
+     */
+    pub fn new(users: Arc<dyn UserRepository>) -> Self {
+        Self { users }
+    }
+
     /**
      * 检查用户名唯一性
      *
-     * 检查用户名是否已存在
+     * 检查用户名在域内是否已存在
+     *
+     * 用户名按域唯一，不同域可以使用相同的用户名
      *
      * @param username 用户名
+     * @param domain 域代码
      * @return Result<(), UserError> 检查结果
      *
      * 错误
      * -----
      * - UsernameAlreadyExists: 用户名已存在
      */
-    async fn check_username_unique(&self, username: &str) -> Result<(), UserError> {
-        let db = db_helper::get_db_connection().await?;
-        let existing_user = SysUser::find()
-            .filter(SysUserColumn::Username.eq(username))
-            .one(db.as_ref())
-            .await?;
-
-        if existing_user.is_some() {
+    async fn check_username_unique(&self, username: &str, domain: &str) -> Result<(), UserError> {
+        if self.users.find_by_username(username, domain).await?.is_some() {
             return Err(UserError::UsernameAlreadyExists);
         }
         Ok(())
@@ -235,12 +264,8 @@ impl SysUserService {
      * -----
      * - UserNotFound: 用户不存在
      */
-    async fn get_user_by_id(&self, id: String) -> Result<SysUserModel, UserError> {
-        let db = db_helper::get_db_connection().await?;
-        SysUser::find_by_id(id)
-            .one(db.as_ref())
-            .await?
-            .ok_or(UserError::UserNotFound)
+    async fn get_user_by_id(&self, id: &str) -> Result<SysUserModel, UserError> {
+        self.users.find_by_id(id).await?.ok_or(UserError::UserNotFound)
     }
 }
 
@@ -254,12 +279,8 @@ impl TUserService for SysUserService {
      * @return Result<Vec<UserWithoutPassword>, UserError> 用户列表或错误
      */
     async fn find_all(&self) -> Result<Vec<UserWithoutPassword>, UserError> {
-        let db = db_helper::get_db_connection().await?;
-        SysUser::find()
-            .all(db.as_ref())
-            .await
-            .map(|users| users.into_iter().map(UserWithoutPassword::from).collect())
-            .map_err(UserError::from)
+        let users = self.users.find_all().await?;
+        Ok(users.into_iter().map(UserWithoutPassword::from).collect())
     }
 
     /**
@@ -267,33 +288,15 @@ impl TUserService for SysUserService {
      *
      * 根据查询条件分页获取用户列表
      *
-     * @param params 分页查询参数，包含关键字和分页信息
+     * @param params 分页查询参数，包含关键字、角色、组织、状态、域筛选条件和分页信息
      * @return Result<PaginatedData<UserWithoutPassword>, UserError> 分页用户数据或错误
      */
     async fn find_paginated_users(
         &self,
         params: UserPageRequest,
     ) -> Result<PaginatedData<UserWithoutPassword>, UserError> {
-        let db = db_helper::get_db_connection().await?;
-        let mut query = SysUser::find();
-
-        if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any().add(SysUserColumn::Username.contains(keywords));
-            query = query.filter(condition);
-        }
-
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await?;
-
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
-        let records = paginator
-            .fetch_page(params.page_details.current - 1)
-            .await?
-            .into_iter()
-            .map(UserWithoutPassword::from)
-            .collect();
+        let (total, users) = self.users.find_page(&params).await?;
+        let records = users.into_iter().map(UserWithoutPassword::from).collect();
 
         Ok(paginated_data!(
             total,
@@ -312,26 +315,29 @@ impl TUserService for SysUserService {
      * @return Result<UserWithoutPassword, UserError> 创建的用户信息或错误
      */
     async fn create_user(&self, input: CreateUserInput) -> Result<UserWithoutPassword, UserError> {
-        self.check_username_unique(&input.username).await?;
-
-        let db = db_helper::get_db_connection().await?;
-        let user = SysUserActiveModel {
-            id: Set(Ulid::new().to_string()),
-            domain: Set(input.domain),
-            username: Set(input.username),
-            password: Set(SecureUtil::hash_password(input.password.as_bytes()).unwrap()),
-            built_in: Set(false),
-            nick_name: Set(input.nick_name),
-            avatar: Set(input.avatar),
-            email: Set(input.email),
-            phone_number: Set(input.phone_number),
-            status: Set(input.status),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
-            ..Default::default()
+        self.check_username_unique(&input.username, input.domain.as_str()).await?;
+
+        let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+        let user = SysUserModel {
+            id: Ulid::new().to_string(),
+            domain: input.domain.into_inner(),
+            username: input.username,
+            password: SecureUtil::hash_password(input.password.as_bytes()).unwrap(),
+            built_in: false,
+            nick_name: input.nick_name,
+            avatar: input.avatar,
+            email: input.email,
+            phone_number: input.phone_number,
+            status: input.status,
+            org_id: None,
+            // 插入时由实体的 before_save 钩子填充
+            created_at: Default::default(),
+            created_by: audit.created_by,
+            updated_at: None,
+            updated_by: None,
         };
 
-        let user_model = user.insert(db.as_ref()).await?;
+        let user_model = self.users.insert(user).await?;
         Ok(UserWithoutPassword::from(user_model))
     }
 
@@ -343,13 +349,8 @@ impl TUserService for SysUserService {
      * @param id 用户ID
      * @return Result<UserWithoutPassword, UserError> 用户信息或错误
      */
-    async fn get_user(&self, id: &str) -> Result<UserWithoutPassword, UserError> {
-        let db = db_helper::get_db_connection().await?;
-        SysUser::find_by_id(id)
-            .one(db.as_ref())
-            .await?
-            .map(UserWithoutPassword::from)
-            .ok_or(UserError::UserNotFound)
+    async fn get_user(&self, id: &UserId) -> Result<UserWithoutPassword, UserError> {
+        self.get_user_by_id(id.as_str()).await.map(UserWithoutPassword::from)
     }
 
     /**
@@ -361,23 +362,26 @@ impl TUserService for SysUserService {
      * @return Result<UserWithoutPassword, UserError> 更新后的用户信息或错误
      */
     async fn update_user(&self, input: UpdateUserInput) -> Result<UserWithoutPassword, UserError> {
-        let mut user = self.get_user_by_id(input.id).await?.into_active_model();
+        let mut user = self.get_user_by_id(input.id.as_str()).await?;
 
-        if input.user.username != *user.username.as_ref() {
-            self.check_username_unique(&input.user.username).await?;
+        let domain = input.user.domain.into_inner();
+        if input.user.username != user.username || domain != user.domain {
+            self.check_username_unique(&input.user.username, &domain).await?;
         }
 
-        user.domain = Set(input.user.domain);
-        user.username = Set(input.user.username);
-        user.password = Set(input.user.password); // TODO: Note: In a real application, you should hash the password
-        user.nick_name = Set(input.user.nick_name);
-        user.avatar = Set(input.user.avatar);
-        user.email = Set(input.user.email);
-        user.phone_number = Set(input.user.phone_number);
-        user.status = Set(input.user.status);
-
-        let db = db_helper::get_db_connection().await?;
-        let updated_user = user.update(db.as_ref()).await?;
+        user.domain = domain;
+        user.username = input.user.username;
+        user.password = input.user.password; // TODO: Note: In a real application, you should hash the password
+        user.nick_name = input.user.nick_name;
+        user.avatar = input.user.avatar;
+        user.email = input.user.email;
+        user.phone_number = input.user.phone_number;
+        user.status = input.user.status;
+
+        let audit = audit_fields::on_update(CurrentUserContext::current().as_ref());
+        user.updated_by = audit.updated_by;
+
+        let updated_user = self.users.update(user).await?;
         Ok(UserWithoutPassword::from(updated_user))
     }
 
@@ -389,17 +393,118 @@ impl TUserService for SysUserService {
      * @param id 用户ID
      * @return Result<(), UserError> 删除结果
      */
-    async fn delete_user(&self, id: &str) -> Result<(), UserError> {
-        let db = db_helper::get_db_connection().await?;
+    async fn delete_user(&self, id: &UserId) -> Result<(), UserError> {
+        if !self.users.delete(id.as_str()).await? {
+            return Err(UserError::UserNotFound);
+        }
 
-        let result = SysUser::delete_by_id(id)
-            .exec(db.as_ref())
-            .await?;
+        Ok(())
+    }
 
-        if result.rows_affected == 0 {
+    /**
+     * 查询用户的角色
+     *
+     * 只能查询指定域中的用户，其他域的用户视为不存在
+     *
+     * @param id 用户ID
+     * @param domain 当前用户所属域
+     * @return Result<Vec<UserRoleOutput>, UserError> 角色列表或错误
+     *
+     * 错误
+     * -----
+     * - UserNotFound: 用户不存在或不属于指定域
+     */
+    async fn find_user_roles(
+        &self,
+        id: &UserId,
+        domain: &str,
+    ) -> Result<Vec<UserRoleOutput>, UserError> {
+        let user = self.get_user_by_id(id.as_str()).await?;
+        if user.domain != domain {
             return Err(UserError::UserNotFound);
         }
 
-        Ok(())
+        Ok(self.users.find_roles_with_domain(id.as_str()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use server_constant::definition::consts::SYSTEM_ACTOR;
+    use server_core::web::auth::User;
+    use server_model::admin::entities::sea_orm_active_enums::Status;
+
+    use super::*;
+    use crate::admin::repositories::test_support::InMemoryUserRepository;
+
+    fn user_id(id: &str) -> UserId {
+        id.parse().unwrap()
+    }
+
+    fn create_input(username: &str) -> CreateUserInput {
+        CreateUserInput {
+            domain: "d1".parse().unwrap(),
+            username: username.to_string(),
+            password: "secret".to_string(),
+            nick_name: username.to_string(),
+            avatar: None,
+            email: None,
+            phone_number: None,
+            status: Status::Enabled,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_user_records_actor() {
+        let service = SysUserService::new(Arc::new(InMemoryUserRepository::default()));
+        let actor = User::new("actor-1".to_string(), "admin".to_string(), "d1".to_string());
+
+        let created = CurrentUserContext::scope(actor, service.create_user(create_input("bob")))
+            .await
+            .unwrap();
+        let stored = service.get_user_by_id(&created.id).await.unwrap();
+        assert_eq!(stored.created_by, "actor-1");
+
+        let created = service.create_user(create_input("carol")).await.unwrap();
+        let stored = service.get_user_by_id(&created.id).await.unwrap();
+        assert_eq!(stored.created_by, SYSTEM_ACTOR);
+    }
+
+    #[tokio::test]
+    async fn test_username_unique_within_domain() {
+        let service = SysUserService::new(Arc::new(InMemoryUserRepository::default()));
+        service.create_user(create_input("bob")).await.unwrap();
+
+        assert!(matches!(
+            service.create_user(create_input("bob")).await,
+            Err(UserError::UsernameAlreadyExists)
+        ));
+
+        let other_domain = CreateUserInput { domain: "d2".parse().unwrap(), ..create_input("bob") };
+        let created = service.create_user(other_domain).await.unwrap();
+        assert_eq!(created.domain, "d2");
+    }
+
+    #[tokio::test]
+    async fn test_find_user_roles_scoped_to_domain() {
+        let users = InMemoryUserRepository::default()
+            .with_domain("d1", "Domain One", Status::Enabled)
+            .with_user("alice", "secret", "d1", Status::Enabled)
+            .with_roles("alice", &["ROLE_ADMIN"]);
+        let service = SysUserService::new(Arc::new(users));
+
+        let roles = service.find_user_roles(&user_id("alice"), "d1").await.unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].code, "ROLE_ADMIN");
+        assert_eq!(roles[0].domain_name, "Domain One");
+
+        assert!(matches!(
+            service.find_user_roles(&user_id("alice"), "d2").await,
+            Err(UserError::UserNotFound)
+        ));
+        assert!(matches!(
+            service.find_user_roles(&user_id("bob"), "d1").await,
+            Err(UserError::UserNotFound)
+        ));
     }
 }
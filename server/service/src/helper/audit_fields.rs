@@ -0,0 +1,85 @@
+/**
+ * 审计字段辅助模块
+ *
+ * 该模块统一生成实体的 created_by 和 updated_by：
+ * - 操作人为认证用户的用户ID，可由 `user_name_resolver` 解析为用户名
+ * - 没有认证用户时（迁移、事件处理器、后台任务等）使用系统操作人 `SYSTEM_ACTOR`
+ *
+ * created_at/updated_at 由实体的 `before_save` 钩子填充，见 `server_model::admin::timestamps`
+ *
+ * 使用示例
+ * --------
+ *
+ * let audit = audit_fields::on_create(CurrentUserContext::current().as_ref());
+ * let role = SysRoleActiveModel {
+ *     created_by: Set(audit.created_by),
+ *     ..Default::default()
+ * };
+ */
+
+use server_constant::definition::consts::SYSTEM_ACTOR;
+use server_core::web::auth::User;
+
+/**
+ * 创建时的审计字段
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateAudit {
+    /** 创建人用户ID */
+    pub created_by: String,
+}
+
+/**
+ * 更新时的审计字段
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateAudit {
+    /** 更新人用户ID */
+    pub updated_by: Option<String>,
+}
+
+/**
+ * 生成创建时的审计字段
+ *
+ * @param user 操作人，为 None 时使用系统操作人
+ * @return CreateAudit 创建人
+ */
+pub fn on_create(user: Option<&User>) -> CreateAudit {
+    CreateAudit {
+        created_by: actor_id(user),
+    }
+}
+
+/**
+ * 生成更新时的审计字段
+ *
+ * @param user 操作人，为 None 时使用系统操作人
+ * @return UpdateAudit 更新人
+ */
+pub fn on_update(user: Option<&User>) -> UpdateAudit {
+    UpdateAudit {
+        updated_by: Some(actor_id(user)),
+    }
+}
+
+/**
+ * 获取操作人ID
+ */
+fn actor_id(user: Option<&User>) -> String {
+    user.map(User::user_id).unwrap_or_else(|| SYSTEM_ACTOR.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_fields_use_actor_id() {
+        let user = User::new("u1".to_string(), "alice".to_string(), "built-in".to_string());
+
+        assert_eq!(on_create(Some(&user)).created_by, "u1");
+        assert_eq!(on_update(Some(&user)).updated_by.as_deref(), Some("u1"));
+        assert_eq!(on_create(None).created_by, SYSTEM_ACTOR);
+        assert_eq!(on_update(None).updated_by.as_deref(), Some(SYSTEM_ACTOR));
+    }
+}
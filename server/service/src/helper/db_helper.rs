@@ -4,6 +4,9 @@
  * 该模块提供了关系型数据库操作的辅助函数，包括：
  * - 数据库连接池管理
  * - 连接获取和验证
+ * - 读写分离（读连接轮询健康的只读副本，写连接始终使用主库）
+ * - 语句超时和慢查询日志，慢查询与超时次数可通过 `query_stats` 读取
+ * - 主库重新连接入口，由初始化模块注册实际的重新连接实现
  * - 错误处理和日志记录
  *
  * 所有函数都提供了详细的错误处理和日志记录，便于问题诊断和监控。
@@ -24,11 +27,50 @@
  */
 
 #[allow(dead_code)]
-use sea_orm::{DatabaseConnection, DbErr, ConnAcquireErr, Database};
-use server_global::global::GLOBAL_DB_POOL;
-use std::sync::Arc;
+use sea_orm::{metric::Info, DatabaseConnection, DbErr, ConnAcquireErr, Database};
+use once_cell::sync::OnceCell;
+use server_config::DatabaseConfig;
+use server_core::web::current_request_id;
+use server_global::{
+    global::{DbRole, GLOBAL_DB_HEALTH, GLOBAL_DB_POOL, GLOBAL_DB_ROLES},
+    project_error,
+};
+use server_model::admin::output::DatabaseQueryStats;
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tracing::{info, error, warn};
 
+/** 读连接轮询计数器 */
+static READ_ROUND_ROBIN: AtomicUsize = AtomicUsize::new(0);
+
+/** 慢查询次数 */
+static SLOW_QUERIES: AtomicU64 = AtomicU64::new(0);
+
+/** 超时查询次数 */
+static TIMED_OUT_QUERIES: AtomicU64 = AtomicU64::new(0);
+
+/** 慢查询日志中SQL的最大字符数 */
+const MAX_LOGGED_SQL_CHARS: usize = 500;
+
+/**
+ * 主库重新连接函数
+ *
+ * 参数为新的主库配置，新连接无法建立时返回错误描述
+ */
+pub type PrimaryReconnector =
+    fn(DatabaseConfig) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/** 主库重新连接函数，服务启动时注册 */
+static PRIMARY_RECONNECTOR: OnceCell<PrimaryReconnector> = OnceCell::new();
+
 /**
  * 从连接池获取数据库连接
  *
@@ -62,6 +104,73 @@ pub async fn get_db_connection() -> Result<Arc<DatabaseConnection>, sea_orm::DbE
     }
 }
 
+/**
+ * 获取读连接
+ *
+ * 在健康的只读副本间轮询；没有可用副本时回退到主库。
+ *
+ * @return Result<Arc<DatabaseConnection>, sea_orm::DbErr> 成功返回数据库连接的Arc包装，失败返回错误
+ *
+ * 使用示例
+ * --------
+ *
+ * let conn = get_read_connection().await?;
+ */
+pub async fn get_read_connection() -> Result<Arc<DatabaseConnection>, sea_orm::DbErr> {
+    let replicas = {
+        let roles = GLOBAL_DB_ROLES.read().await;
+        let health = GLOBAL_DB_HEALTH.read().await;
+        healthy_replicas(&roles, &health)
+    };
+
+    if !replicas.is_empty() {
+        let index = READ_ROUND_ROBIN.fetch_add(1, Ordering::Relaxed) % replicas.len();
+        let pools = GLOBAL_DB_POOL.read().await;
+        if let Some(conn) = pools.get(&replicas[index]) {
+            info!("Acquired read replica connection: {}", replicas[index]);
+            return Ok(conn.clone());
+        }
+    }
+
+    get_write_connection().await
+}
+
+/**
+ * 获取写连接
+ *
+ * 始终返回主库连接。
+ *
+ * @return Result<Arc<DatabaseConnection>, sea_orm::DbErr> 成功返回数据库连接的Arc包装，失败返回错误
+ *
+ * 使用示例
+ * --------
+ *
+ * let conn = get_write_connection().await?;
+ */
+pub async fn get_write_connection() -> Result<Arc<DatabaseConnection>, sea_orm::DbErr> {
+    get_db_connection().await
+}
+
+/**
+ * 筛选健康的只读副本
+ *
+ * 未记录健康状态的副本视为健康，结果按名称排序以保证轮询顺序稳定。
+ *
+ * @param roles 数据库实例角色表
+ * @param health 数据库实例健康状态
+ * @return Vec<String> 健康的只读副本名称
+ */
+fn healthy_replicas(roles: &HashMap<String, DbRole>, health: &HashMap<String, bool>) -> Vec<String> {
+    let mut replicas: Vec<String> = roles
+        .iter()
+        .filter(|(_, role)| **role == DbRole::Replica)
+        .filter(|(name, _)| health.get(*name).copied().unwrap_or(true))
+        .map(|(name, _)| name.clone())
+        .collect();
+    replicas.sort();
+    replicas
+}
+
 /**
  * 初始化数据库连接池
  *
@@ -163,3 +272,183 @@ pub async fn validate_connection(conn: &DatabaseConnection) -> Result<(), DbErr>
         }
     }
 }
+
+/**
+ * 注册主库重新连接函数
+ *
+ * 连接的建立和替换由初始化模块实现，服务层通过 `primary_reconnector` 调用
+ *
+ * @param reconnector 主库重新连接函数
+ */
+pub fn init_primary_reconnector(reconnector: PrimaryReconnector) {
+    let _ = PRIMARY_RECONNECTOR.set(reconnector);
+}
+
+/**
+ * 获取主库重新连接函数
+ *
+ * @return Option<PrimaryReconnector> 未注册时返回None
+ */
+pub fn primary_reconnector() -> Option<PrimaryReconnector> {
+    PRIMARY_RECONNECTOR.get().copied()
+}
+
+/**
+ * 为PostgreSQL连接URL添加语句超时
+ *
+ * 通过连接参数 `options=-c statement_timeout=<ms>` 在建立每个连接时设置，
+ * 超时的语句由数据库取消并返回错误。非PostgreSQL的URL原样返回。
+ *
+ * @param url 数据库连接URL
+ * @param timeout 语句超时时间
+ * @return String 添加了语句超时参数的URL
+ */
+pub fn with_statement_timeout(url: &str, timeout: Duration) -> String {
+    if !url.starts_with("postgres://") && !url.starts_with("postgresql://") {
+        warn!("statement_timeout_ms is only supported for PostgreSQL, ignored");
+        return url.to_string();
+    }
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!(
+        "{}{}options=-c%20statement_timeout%3D{}",
+        url,
+        separator,
+        timeout.as_millis()
+    )
+}
+
+/**
+ * 创建慢查询日志回调
+ *
+ * 通过 `DatabaseConnection::set_metric_callback` 注册到连接上。
+ * 执行时间超过阈值的查询记录错误日志，包含截断后的SQL、耗时和当前请求ID；
+ * 执行失败且耗时达到语句超时的查询视为超时。
+ *
+ * @param threshold 慢查询阈值
+ * @param statement_timeout 语句超时时间，未配置时不统计超时
+ * @return 查询指标回调
+ */
+pub fn slow_query_logger(
+    threshold: Duration,
+    statement_timeout: Option<Duration>,
+) -> impl Fn(&Info<'_>) + Send + Sync + 'static {
+    move |info: &Info<'_>| {
+        if info.elapsed < threshold {
+            return;
+        }
+        let timed_out = info.failed && statement_timeout.is_some_and(|t| info.elapsed >= t);
+        let stats = record_slow_query(timed_out);
+        project_error!(
+            "{} query took {}ms (request_id: {}, slow: {}, timed out: {}): {}",
+            if timed_out { "Timed out" } else { "Slow" },
+            info.elapsed.as_millis(),
+            current_request_id().as_deref().unwrap_or("-"),
+            stats.slow_queries,
+            stats.timed_out_queries,
+            truncate_sql(&info.statement.sql)
+        );
+    }
+}
+
+/**
+ * 获取进程启动以来的慢查询统计
+ *
+ * @return DatabaseQueryStats 慢查询和超时查询次数
+ */
+pub fn query_stats() -> DatabaseQueryStats {
+    DatabaseQueryStats {
+        slow_queries: SLOW_QUERIES.load(Ordering::Relaxed),
+        timed_out_queries: TIMED_OUT_QUERIES.load(Ordering::Relaxed),
+    }
+}
+
+/**
+ * 记录一次慢查询并返回记录后的统计
+ */
+fn record_slow_query(timed_out: bool) -> DatabaseQueryStats {
+    let slow_queries = SLOW_QUERIES.fetch_add(1, Ordering::Relaxed) + 1;
+    let timed_out_queries = if timed_out {
+        TIMED_OUT_QUERIES.fetch_add(1, Ordering::Relaxed) + 1
+    } else {
+        TIMED_OUT_QUERIES.load(Ordering::Relaxed)
+    };
+    DatabaseQueryStats { slow_queries, timed_out_queries }
+}
+
+/**
+ * 按字符数截断SQL
+ */
+fn truncate_sql(sql: &str) -> String {
+    match sql.char_indices().nth(MAX_LOGGED_SQL_CHARS) {
+        Some((index, _)) => format!("{}...", &sql[..index]),
+        None => sql.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DatabaseBackend, Statement};
+
+    use super::*;
+
+    #[test]
+    fn test_with_statement_timeout() {
+        let timeout = Duration::from_millis(5000);
+        assert_eq!(
+            with_statement_timeout("postgres://u:p@localhost/db", timeout),
+            "postgres://u:p@localhost/db?options=-c%20statement_timeout%3D5000"
+        );
+        assert_eq!(
+            with_statement_timeout("postgres://u:p@localhost/db?sslmode=disable", timeout),
+            "postgres://u:p@localhost/db?sslmode=disable&options=-c%20statement_timeout%3D5000"
+        );
+        assert_eq!(with_statement_timeout("sqlite::memory:", timeout), "sqlite::memory:");
+    }
+
+    #[test]
+    fn test_truncate_sql() {
+        assert_eq!(truncate_sql("SELECT 1"), "SELECT 1");
+
+        let long = "查".repeat(MAX_LOGGED_SQL_CHARS + 1);
+        let truncated = truncate_sql(&long);
+        assert_eq!(truncated.chars().count(), MAX_LOGGED_SQL_CHARS + 3);
+        assert!(truncated.ends_with("..."));
+    }
+
+    #[test]
+    fn test_slow_query_logger_counts() {
+        let logger =
+            slow_query_logger(Duration::from_millis(100), Some(Duration::from_millis(500)));
+        let statement = Statement::from_string(DatabaseBackend::Postgres, "SELECT pg_sleep(1)");
+        let info = |elapsed: u64, failed: bool| Info {
+            elapsed: Duration::from_millis(elapsed),
+            statement: &statement,
+            failed,
+        };
+
+        let before = query_stats();
+        logger(&info(10, false));
+        logger(&info(200, false));
+        logger(&info(600, true));
+        let after = query_stats();
+
+        assert!(after.slow_queries >= before.slow_queries + 2);
+        assert!(after.timed_out_queries > before.timed_out_queries);
+    }
+
+    #[test]
+    fn test_healthy_replicas_skips_primary_and_unhealthy() {
+        let roles = HashMap::from([
+            ("default".to_string(), DbRole::Primary),
+            ("replica_b".to_string(), DbRole::Replica),
+            ("replica_a".to_string(), DbRole::Replica),
+            ("replica_c".to_string(), DbRole::Replica),
+        ]);
+        let health = HashMap::from([
+            ("replica_b".to_string(), true),
+            ("replica_c".to_string(), false),
+        ]);
+
+        assert_eq!(healthy_replicas(&roles, &health), vec!["replica_a", "replica_b"]);
+    }
+}
@@ -2,10 +2,14 @@
  * 辅助服务模块
  *
  * 该模块提供了各种数据库和事务操作的辅助功能，包括：
+ * - audit_fields: 创建、更新时的审计字段生成
  * - db_helper: 关系型数据库操作辅助函数
  * - mongo_helper: MongoDB数据库操作辅助函数
+ * - notification_service: 邮件模板渲染与异步投递
  * - redis_helper: Redis缓存操作辅助函数
  * - transaction_helper: 事务处理辅助函数
+ * - user_name_resolver: 创建人、更新人用户名批量解析
+ * - user_route_cache: 用户路由缓存及菜单分配变更后的失效
  *
  * 这些辅助函数封装了常用的数据库操作，提供了更简洁和统一的接口，
  * 同时处理了错误、连接管理和事务等底层细节。
@@ -25,14 +29,21 @@
  * }).await?;
  */
 
+pub mod audit_fields;
 pub mod db_helper;
 pub mod mongo_helper;
+pub mod notification_service;
 pub mod redis_helper;
 pub mod transaction_helper;
+pub mod user_name_resolver;
+pub mod user_route_cache;
 
 // Remove unused imports
 // pub use db_helper::*;
 // pub use db_pool::*;
 
 pub use db_helper::*;
-pub use transaction_helper::{execute_in_transaction, execute_with_retry};
+pub use transaction_helper::{
+    execute_in_savepoint, execute_in_transaction, execute_in_transaction_with, execute_with_retry,
+    is_retryable_error, TxnOptions,
+};
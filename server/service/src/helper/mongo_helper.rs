@@ -73,10 +73,7 @@ pub async fn get_primary_client() -> Result<Client, AppError> {
         .read()
         .await
         .clone()
-        .ok_or_else(|| AppError {
-            code: 500,
-            message: "Primary MongoDB not initialized".to_string(),
-        })?;
+        .ok_or_else(|| AppError::new(500, "Primary MongoDB not initialized"))?;
     Ok(client.as_ref().clone())
 }
 
@@ -129,10 +126,9 @@ pub async fn get_primary_collection<T: Send + Sync>(
  */
 pub async fn get_named_client(name: &str) -> Result<Client, AppError> {
     let pools = GLOBAL_MONGO_POOL.read().await;
-    let client = pools.get(name).ok_or_else(|| AppError {
-        code: 500,
-        message: format!("MongoDB pool '{}' not found", name),
-    })?;
+    let client = pools
+        .get(name)
+        .ok_or_else(|| AppError::new(500, format!("MongoDB pool '{}' not found", name)))?;
     Ok(client.as_ref().clone())
 }
 
@@ -0,0 +1,259 @@
+/**
+ * 邮件通知服务模块
+ *
+ * 该模块负责渲染邮件模板并异步投递，包括：
+ * - NotificationService: 按模板键渲染邮件并放入投递通道
+ * - email_delivery_listener: 投递通道的工作者，发送失败时按指数退避重试
+ *
+ * 调用方只等待邮件入队，SMTP 发送在工作者中完成，不阻塞请求。
+ * 重试次数用尽后通过 `project_error!` 记录失败，不再向调用方反馈。
+ *
+ * 使用示例
+ * --------
+ *
+ * use server_service::helper::notification_service::NotificationService;
+ *
+ * NotificationService::send_mail(
+ *     EMAIL_TEMPLATE_LOCKOUT_ALERT,
+ *     "user@example.com",
+ *     &json!({ "user_name": "Soybean", "ip": "127.0.0.1", "locked_until": "2026-10-16 12:00:00" }),
+ * )
+ * .await?;
+ */
+
+use std::{any::Any, time::Duration};
+
+use chrono::Utc;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    AsyncTransport, Message,
+};
+use serde_json::{json, Value};
+use server_config::{EmailConfig, TimeConfig};
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::error::AppError;
+use server_global::{
+    global::{self, get_config, EventReceiver, GLOBAL_MAILER},
+    project_error, project_info,
+};
+use server_resource::email::{render_email, EMAIL_TEMPLATE_TEST};
+use thiserror::Error;
+
+/** 首次重试前的等待时间 */
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+/** 单次重试的最长等待时间 */
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/**
+ * 邮件通知错误
+ */
+#[derive(Debug, Error)]
+pub enum NotificationError {
+    /** 未配置邮件或邮件发送器未初始化 */
+    #[error("Email is not configured")]
+    NotConfigured,
+
+    /** 收件人或发件人地址格式错误 */
+    #[error("Invalid email address: {0}")]
+    InvalidAddress(String),
+
+    /** 模板不存在或上下文缺少字段 */
+    #[error("Failed to render email template: {0}")]
+    Template(String),
+
+    /** 邮件内容构建失败 */
+    #[error("Failed to build email: {0}")]
+    Build(String),
+}
+
+impl From<NotificationError> for AppError {
+    fn from(err: NotificationError) -> Self {
+        match err {
+            NotificationError::NotConfigured => AppError::new(503, err.to_string()),
+            NotificationError::InvalidAddress(_) => AppError::new(400, err.to_string()),
+            NotificationError::Template(_) | NotificationError::Build(_) => {
+                AppError::new(500, err.to_string())
+            },
+        }
+    }
+}
+
+/**
+ * 待投递的邮件
+ *
+ * 通过动态事件通道传递给投递工作者
+ */
+#[derive(Debug, Clone)]
+pub struct EmailDelivery {
+    pub template_key: String,
+    pub to: String,
+    pub message: Message,
+}
+
+/**
+ * 邮件通知服务
+ */
+pub struct NotificationService;
+
+impl NotificationService {
+    /**
+     * 渲染模板并将邮件放入投递通道
+     *
+     * 模板渲染和地址校验在入队前完成，错误直接返回给调用方；
+     * 投递通道已满时按通道策略等待，超时后丢弃。
+     *
+     * @param template_key 模板键，见 `server_resource::email`
+     * @param to 收件人地址
+     * @param context 模板上下文
+     * @return Result<(), NotificationError> 入队结果
+     */
+    pub async fn send_mail(
+        template_key: &str,
+        to: &str,
+        context: &Value,
+    ) -> Result<(), NotificationError> {
+        let config = get_config::<EmailConfig>()
+            .await
+            .ok_or(NotificationError::NotConfigured)?;
+        if GLOBAL_MAILER.read().await.is_none() {
+            return Err(NotificationError::NotConfigured);
+        }
+
+        let from = config
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| NotificationError::InvalidAddress(format!("{}: {}", config.from, e)))?;
+        let recipient = to
+            .parse::<Mailbox>()
+            .map_err(|e| NotificationError::InvalidAddress(format!("{}: {}", to, e)))?;
+        let email = render_email(template_key, context)
+            .map_err(|e| NotificationError::Template(e.to_string()))?;
+
+        let message = Message::builder()
+            .from(from)
+            .to(recipient)
+            .subject(email.subject)
+            .header(ContentType::TEXT_HTML)
+            .body(email.body)
+            .map_err(|e| NotificationError::Build(e.to_string()))?;
+
+        global::send_dyn_event_async(
+            SystemEvent::NotificationEmailRequestedEvent.as_ref(),
+            Box::new(EmailDelivery {
+                template_key: template_key.to_string(),
+                to: to.to_string(),
+                message,
+            }),
+        )
+        .await;
+        Ok(())
+    }
+
+    /**
+     * 发送测试邮件
+     *
+     * 用于确认SMTP配置可用，正文包含按展示时区格式化的发送时间
+     *
+     * @param to 收件人地址
+     * @return Result<(), NotificationError> 入队结果
+     */
+    pub async fn send_test_mail(to: &str) -> Result<(), NotificationError> {
+        let offset = get_config::<TimeConfig>().await.unwrap_or_default().display_timezone;
+        let sent_at = Utc::now()
+            .with_timezone(&offset)
+            .format("%Y-%m-%d %H:%M:%S %:z")
+            .to_string();
+        Self::send_mail(EMAIL_TEMPLATE_TEST, to, &json!({ "sent_at": sent_at })).await
+    }
+}
+
+/**
+ * 计算第 `attempt` 次重试前的等待时间
+ *
+ * 从 `RETRY_BASE_DELAY` 开始每次翻倍，不超过 `RETRY_MAX_DELAY`
+ */
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .checked_mul(2u32.saturating_pow(attempt))
+        .map_or(RETRY_MAX_DELAY, |delay| delay.min(RETRY_MAX_DELAY))
+}
+
+/**
+ * 发送一封邮件，失败时按指数退避重试
+ *
+ * @param delivery 待投递的邮件
+ * @param max_retries 最大重试次数
+ * @return Result<(), String> 最后一次发送的结果
+ */
+async fn deliver(delivery: &EmailDelivery, max_retries: u32) -> Result<(), String> {
+    let mut attempt = 0;
+    loop {
+        let mailer = GLOBAL_MAILER.read().await.clone();
+        let result = match mailer {
+            Some(mailer) => mailer
+                .send(delivery.message.clone())
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+            None => Err(NotificationError::NotConfigured.to_string()),
+        };
+
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= max_retries => return Err(e),
+            Err(_) => {
+                tokio::time::sleep(retry_delay(attempt)).await;
+                attempt += 1;
+            },
+        }
+    }
+}
+
+/**
+ * 邮件投递监听器
+ *
+ * 按入队顺序逐封发送，重试期间后续邮件在通道中排队。
+ * 重试次数用尽后记录错误日志并继续处理下一封。
+ *
+ * @param rx 事件接收器
+ */
+pub async fn email_delivery_listener(mut rx: EventReceiver<Box<dyn Any + Send>>) {
+    while let Some(event) = rx.recv().await {
+        let Some(delivery) = event.downcast_ref::<EmailDelivery>() else {
+            project_error!("Received unknown event type in email delivery listener");
+            continue;
+        };
+
+        let max_retries = get_config::<EmailConfig>()
+            .await
+            .map_or(0, |config| config.max_retries);
+        match deliver(delivery, max_retries).await {
+            Ok(()) => {
+                project_info!("Email '{}' sent to {}", delivery.template_key, delivery.to);
+            },
+            Err(e) => {
+                project_error!(
+                    "Failed to send email '{}' to {} after {} retries: {}",
+                    delivery.template_key,
+                    delivery.to,
+                    max_retries,
+                    e
+                );
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles_until_cap() {
+        assert_eq!(retry_delay(0), Duration::from_secs(1));
+        assert_eq!(retry_delay(1), Duration::from_secs(2));
+        assert_eq!(retry_delay(3), Duration::from_secs(8));
+        assert_eq!(retry_delay(6), RETRY_MAX_DELAY);
+        assert_eq!(retry_delay(40), RETRY_MAX_DELAY);
+    }
+}
@@ -9,11 +9,237 @@
  * 
  * The module provides functions to get both single-instance and cluster-mode connections,
  * with proper error handling and type safety.
+ * 
+ * For everyday key/value work prefer `RedisOps`, which hides the Single/Cluster split and
+ * offers typed JSON get/set, counters with TTL, `delete` and `exists`:
+ * 
+ * let ops = RedisOps::new(RedisSource::Primary).await?;
+ * ops.set_json("user:1", &user, Some(Duration::from_secs(60))).await?;
+ * let cached: Option<User> = ops.get_json("user:1").await?;
  */
 
-use redis::{aio::MultiplexedConnection, cluster_async::ClusterConnection, ErrorKind, RedisError};
+use std::time::Duration;
+
+use redis::{
+    aio::MultiplexedConnection, cluster_async::ClusterConnection, Cmd, ErrorKind, FromRedisValue,
+    RedisError, Script,
+};
+use serde::{de::DeserializeOwned, Serialize};
 use server_core::web::error::AppError;
 use server_global::global::{RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL};
+use thiserror::Error;
+
+/**
+ * Atomically increments a counter and sets its expiration on first increment
+ * 
+ * Running both commands in a script keeps the key from living forever when the
+ * process dies between `INCR` and `EXPIRE`, and works in cluster mode since only
+ * one key is touched.
+ */
+const INCR_WITH_TTL_SCRIPT: &str = r#"
+local value = redis.call('INCR', KEYS[1])
+if value == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+return value
+"#;
+
+/**
+ * Errors returned by `RedisOps`
+ */
+#[derive(Debug, Error)]
+pub enum RedisHelperError {
+    /** The requested Redis instance has not been initialized */
+    #[error("Redis instance not initialized: {0}")]
+    NotInitialized(String),
+
+    /** A Redis command failed */
+    #[error("Redis command failed: {0}")]
+    Command(#[from] RedisError),
+
+    /** A value could not be serialized or deserialized */
+    #[error("Redis value serialization failed: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl From<RedisHelperError> for AppError {
+    fn from(err: RedisHelperError) -> Self {
+        match err {
+            RedisHelperError::Command(e) => AppError::from(e),
+            RedisHelperError::NotInitialized(_) => AppError::new(503, err.to_string()),
+            RedisHelperError::Serialization(_) => AppError::new(500, err.to_string()),
+        }
+    }
+}
+
+/**
+ * A mode-specific Redis connection
+ * 
+ * Both variants implement `ConnectionLike`; this enum lets `RedisOps` run the same
+ * command against either of them.
+ */
+enum OpsConnection {
+    Single(MultiplexedConnection),
+    Cluster(ClusterConnection),
+}
+
+impl OpsConnection {
+    async fn query<T: FromRedisValue>(&mut self, cmd: &Cmd) -> Result<T, RedisError> {
+        match self {
+            OpsConnection::Single(conn) => cmd.query_async(conn).await,
+            OpsConnection::Cluster(conn) => cmd.query_async(conn).await,
+        }
+    }
+
+    async fn invoke<T: FromRedisValue>(
+        &mut self,
+        script: &Script,
+        key: &str,
+        arg: u64,
+    ) -> Result<T, RedisError> {
+        let mut invocation = script.prepare_invoke();
+        invocation.key(key).arg(arg);
+        match self {
+            OpsConnection::Single(conn) => invocation.invoke_async(conn).await,
+            OpsConnection::Cluster(conn) => invocation.invoke_async(conn).await,
+        }
+    }
+}
+
+/**
+ * Typed Redis operations working identically in single and cluster modes
+ * 
+ * `RedisOps` wraps a `RedisConnection` (cheap to clone, it only holds `Arc`s) and
+ * opens a connection per call, so it can be stored in services or created on demand.
+ * 
+ * # Example
+ * 
+ * let ops = RedisOps::new(RedisSource::Primary).await?;
+ * let attempts = ops.incr_with_ttl("login_attempts:admin", Duration::from_secs(900)).await?;
+ * if ops.exists("blocked:admin").await? {
+ *     ops.delete("blocked:admin").await?;
+ * }
+ */
+#[derive(Clone)]
+pub struct RedisOps {
+    connection: RedisConnection,
+}
+
+impl RedisOps {
+    /**
+     * Creates `RedisOps` for the given source
+     * 
+     * # Errors
+     * * `RedisHelperError::NotInitialized` if the primary Redis or the named pool entry is missing
+     */
+    pub async fn new(source: RedisSource) -> Result<Self, RedisHelperError> {
+        let connection = match source {
+            RedisSource::Primary => GLOBAL_PRIMARY_REDIS
+                .read()
+                .await
+                .clone()
+                .ok_or_else(|| RedisHelperError::NotInitialized("primary".to_string()))?,
+            RedisSource::Named(name) => GLOBAL_REDIS_POOL
+                .read()
+                .await
+                .get(&name)
+                .cloned()
+                .ok_or(RedisHelperError::NotInitialized(name))?,
+        };
+        Ok(Self::from_connection(connection))
+    }
+
+    /**
+     * Creates `RedisOps` from an existing connection
+     */
+    pub fn from_connection(connection: RedisConnection) -> Self {
+        Self { connection }
+    }
+
+    async fn connection(&self) -> Result<OpsConnection, RedisHelperError> {
+        Ok(match &self.connection {
+            RedisConnection::Single(client) => {
+                OpsConnection::Single(client.get_multiplexed_async_connection().await?)
+            },
+            RedisConnection::Cluster(client) => {
+                OpsConnection::Cluster(client.get_async_connection().await?)
+            },
+        })
+    }
+
+    /**
+     * Reads a JSON value
+     * 
+     * # Returns
+     * * `Ok(None)` if the key does not exist
+     */
+    pub async fn get_json<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, RedisHelperError> {
+        let mut conn = self.connection().await?;
+        let raw: Option<String> = conn.query(redis::cmd("GET").arg(key)).await?;
+        match raw {
+            Some(raw) => Ok(Some(serde_json::from_str(&raw)?)),
+            None => Ok(None),
+        }
+    }
+
+    /**
+     * Stores a value as JSON
+     * 
+     * # Arguments
+     * * `ttl` - Expiration of the key; `None` keeps it until deleted
+     */
+    pub async fn set_json<T: Serialize + ?Sized>(
+        &self,
+        key: &str,
+        value: &T,
+        ttl: Option<Duration>,
+    ) -> Result<(), RedisHelperError> {
+        let raw = serde_json::to_string(value)?;
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg(raw);
+        if let Some(ttl) = ttl {
+            cmd.arg("PX").arg(ttl.as_millis().max(1) as u64);
+        }
+        let mut conn = self.connection().await?;
+        conn.query::<()>(&cmd).await?;
+        Ok(())
+    }
+
+    /**
+     * Increments a counter, setting `ttl` when the counter is created
+     * 
+     * The expiration is not refreshed by later increments, which makes this suitable
+     * for fixed-window counters such as login attempts.
+     * 
+     * # Returns
+     * * The counter value after incrementing
+     */
+    pub async fn incr_with_ttl(&self, key: &str, ttl: Duration) -> Result<u64, RedisHelperError> {
+        let script = Script::new(INCR_WITH_TTL_SCRIPT);
+        let mut conn = self.connection().await?;
+        Ok(conn.invoke(&script, key, ttl.as_secs().max(1)).await?)
+    }
+
+    /**
+     * Deletes a key
+     * 
+     * # Returns
+     * * `true` if the key existed
+     */
+    pub async fn delete(&self, key: &str) -> Result<bool, RedisHelperError> {
+        let mut conn = self.connection().await?;
+        let removed: u64 = conn.query(redis::cmd("DEL").arg(key)).await?;
+        Ok(removed > 0)
+    }
+
+    /**
+     * Checks whether a key exists
+     */
+    pub async fn exists(&self, key: &str) -> Result<bool, RedisHelperError> {
+        let mut conn = self.connection().await?;
+        Ok(conn.query(redis::cmd("EXISTS").arg(key)).await?)
+    }
+}
 
 /**
  * Redis connection source type
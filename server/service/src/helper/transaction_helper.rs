@@ -3,10 +3,13 @@
  * 
  * 该模块提供了数据库事务操作的辅助函数，包括：
  * - 事务执行
+ * - 可配置隔离级别/访问模式的事务执行
+ * - 保存点（嵌套事务）
  * - 重试机制
  * 
  * 提供了事务管理和错误处理的统一接口，支持事务的自动提交和回滚，
- * 以及操作失败时的自动重试机制。
+ * 以及操作失败时的自动重试机制。重试只针对可重试错误（序列化失败、死锁等），
+ * 其余错误会被直接返回。
  * 
  * # 使用示例
  * 
@@ -14,8 +17,9 @@
  * 
  * // 在事务中执行操作
  * let result = execute_in_transaction(&db, |tx| Box::pin(async move {
- *     // 执行数据库操作
- *     Ok(())
+ *     // 执行数据库操作，完成后手动提交
+ *     tx.commit().await?;
+ *     Ok::<_, AppError>(())
  * })).await?;
  * 
  * // 带重试机制的执行
@@ -23,19 +27,116 @@
  *     // 执行数据库操作
  *     Ok(())
  * }), 3).await?;
+ * 
+ * // 以可串行化隔离级别执行，并在序列化失败/死锁时自动重试
+ * let result = execute_in_transaction_with(&db, TxnOptions::serializable(), |txn| Box::pin(async move {
+ *     // 执行数据库操作，无需手动提交
+ *     Ok(())
+ * })).await?;
  */
 
-use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use sea_orm::{
+    AccessMode, DatabaseConnection, DatabaseTransaction, DbErr, IsolationLevel, TransactionError,
+    TransactionTrait,
+};
 use server_core::web::error::AppError;
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, time::Duration};
+
+/**
+ * 事务配置选项
+ *
+ * 用于 `execute_in_transaction_with`，控制事务的隔离级别、访问模式以及重试策略。
+ *
+ * # 字段
+ * * `isolation` - 事务隔离级别，`None` 表示使用数据库默认值
+ * * `access_mode` - 事务访问模式（只读/读写），`None` 表示使用数据库默认值
+ * * `retries` - 遇到可重试错误时的最大重试次数
+ * * `backoff` - 重试退避基数，第 n 次重试前等待 `backoff * n`
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct TxnOptions {
+    pub isolation: Option<IsolationLevel>,
+    pub access_mode: Option<AccessMode>,
+    pub retries: u32,
+    pub backoff: Duration,
+}
+
+impl Default for TxnOptions {
+    fn default() -> Self {
+        Self {
+            isolation: None,
+            access_mode: None,
+            retries: 0,
+            backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+impl TxnOptions {
+    /**
+     * 可串行化事务配置
+     *
+     * 使用 `Serializable` 隔离级别，并在序列化失败或死锁时最多重试 3 次。
+     */
+    pub fn serializable() -> Self {
+        Self {
+            isolation: Some(IsolationLevel::Serializable),
+            retries: 3,
+            ..Default::default()
+        }
+    }
+
+    /**
+     * 只读事务配置
+     */
+    pub fn read_only() -> Self {
+        Self {
+            access_mode: Some(AccessMode::ReadOnly),
+            ..Default::default()
+        }
+    }
+}
+
+/**
+ * 判断错误是否可重试
+ *
+ * 序列化失败（SQLSTATE 40001）和死锁（SQLSTATE 40P01）属于临时性错误，
+ * 重新执行整个事务通常即可成功；其余错误（约束冲突、记录不存在等）属于永久性错误。
+ * 分类在数据库错误转换为 `AppError` 时按SQLSTATE完成（见 `is_retryable_db_err`），
+ * 不匹配错误消息；业务代码自行构造的错误不会被重试。
+ *
+ * # 参数
+ * * `err` - 应用错误
+ *
+ * # 返回
+ * * `bool` - 是否可重试
+ */
+pub fn is_retryable_error(err: &AppError) -> bool {
+    err.retryable
+}
+
+/**
+ * 将 SeaORM 事务错误转换为应用错误
+ */
+fn from_transaction_error(err: TransactionError<AppError>) -> AppError {
+    match err {
+        TransactionError::Connection(e) => AppError::from(e),
+        TransactionError::Transaction(e) => e,
+    }
+}
 
 /**
  * 在事务中执行数据库操作
  * 
- * 创建一个数据库事务，执行指定的操作，并根据操作结果自动提交或回滚事务。
+ * 创建一个数据库事务并交给指定的操作。事务对象的所有权转移给操作，
+ * 操作成功时需自行调用 `txn.commit()` 提交；返回错误或未提交时事务在释放时回滚。
+ * 
+ * 错误类型由调用方决定，只要求能够从 `DbErr` 转换，
+ * 因此操作内部可以直接返回领域错误（如 `AuthorizationError`），并对数据库操作使用 `?`。
  * 
  * # 类型参数
  * * `T` - 操作返回值的类型
+ * * `E` - 错误类型，需实现 `From<DbErr>`
  * * `F` - 异步闭包类型，接收事务对象并返回Future
  * 
  * # 参数
@@ -43,7 +144,7 @@ use std::{future::Future, pin::Pin};
  * * `operation` - 异步闭包，接收事务对象并执行数据库操作
  * 
  * # 返回
- * * `Result<T, AppError>` - 操作结果，成功返回操作返回值，失败返回错误
+ * * `Result<T, E>` - 操作结果，成功返回操作返回值，失败返回错误
  * 
  * # 使用示例
  * 
@@ -51,31 +152,118 @@ use std::{future::Future, pin::Pin};
  *     // 执行数据库操作
  *     let user = User::find_by_id(1).one(&tx).await?;
  *     // 更多操作...
+ *     tx.commit().await?;
+ *     Ok::<_, AppError>(user)
+ * })).await?;
+ */
+pub async fn execute_in_transaction<T, E, F>(db: &DatabaseConnection, operation: F) -> Result<T, E>
+where
+    E: From<DbErr>,
+    F: FnOnce(DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>>,
+{
+    let txn = db.begin().await?;
+    operation(txn).await
+}
+
+/**
+ * 以指定配置在事务中执行数据库操作
+ * 
+ * 基于 SeaORM 的 `transaction_with_config` 实现：操作成功时自动提交，失败时自动回滚，
+ * 调用方无需（也不应）手动提交事务。若错误可重试（见 `is_retryable_error`），
+ * 会按照 `options.retries` 与 `options.backoff` 重新执行整个事务。
+ * 
+ * # 类型参数
+ * * `T` - 操作返回值的类型
+ * * `F` - 异步闭包类型，接收事务引用并返回Future；重试时会被多次调用
+ * 
+ * # 参数
+ * * `db` - 数据库连接
+ * * `options` - 事务配置选项
+ * * `operation` - 异步闭包，接收事务引用并执行数据库操作
+ * 
+ * # 返回
+ * * `Result<T, AppError>` - 操作结果，成功返回操作返回值，失败返回错误
+ * 
+ * # 使用示例
+ * 
+ * let user = execute_in_transaction_with(&db, TxnOptions::serializable(), |txn| Box::pin(async move {
+ *     let user = User::find_by_id(1).one(txn).await?;
  *     Ok(user)
  * })).await?;
  */
-pub async fn execute_in_transaction<T, F>(db: &DatabaseConnection, operation: F) -> Result<T, AppError>
+pub async fn execute_in_transaction_with<T, F>(
+    db: &DatabaseConnection,
+    options: TxnOptions,
+    operation: F,
+) -> Result<T, AppError>
 where
-    F: FnOnce(DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, AppError>> + Send>>,
+    F: for<'c> Fn(&'c DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, AppError>> + Send + 'c>>
+        + Send
+        + Sync,
+    T: Send,
 {
-    let txn = db.begin().await.map_err(AppError::from)?;
+    let mut retries = 0;
+    loop {
+        let result = db
+            .transaction_with_config(&operation, options.isolation, options.access_mode)
+            .await
+            .map_err(from_transaction_error);
 
-    match operation(txn).await {
-        Ok(result) => {
-            // Transaction is consumed by the operation
-            Ok(result)
-        }
-        Err(e) => {
-            // Transaction is consumed by the operation
-            Err(e)
+        match result {
+            Ok(result) => return Ok(result),
+            Err(e) => {
+                if retries >= options.retries || !is_retryable_error(&e) {
+                    return Err(e);
+                }
+                retries += 1;
+                tokio::time::sleep(options.backoff * retries).await;
+            }
         }
     }
 }
 
+/**
+ * 在保存点中执行数据库操作
+ * 
+ * 在已有事务内创建保存点（嵌套事务）执行操作。操作成功时释放保存点，
+ * 失败时仅回滚到保存点，外层事务可以继续执行。
+ * 
+ * # 类型参数
+ * * `T` - 操作返回值的类型
+ * * `F` - 异步闭包类型，接收嵌套事务引用并返回Future
+ * 
+ * # 参数
+ * * `txn` - 外层事务
+ * * `operation` - 异步闭包，接收嵌套事务引用并执行数据库操作
+ * 
+ * # 返回
+ * * `Result<T, AppError>` - 操作结果，成功返回操作返回值，失败返回错误
+ * 
+ * # 使用示例
+ * 
+ * execute_in_transaction_with(&db, TxnOptions::default(), |txn| Box::pin(async move {
+ *     // 保存点内的失败不会影响外层事务
+ *     let _ = execute_in_savepoint(txn, |sp| Box::pin(async move {
+ *         // 执行可能失败的操作
+ *         Ok(())
+ *     })).await;
+ *     Ok(())
+ * })).await?;
+ */
+pub async fn execute_in_savepoint<T, F>(txn: &DatabaseTransaction, operation: F) -> Result<T, AppError>
+where
+    F: for<'c> FnOnce(&'c DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, AppError>> + Send + 'c>>
+        + Send,
+    T: Send,
+{
+    txn.transaction(operation).await.map_err(from_transaction_error)
+}
+
 /**
  * 带重试机制的数据库操作执行
  * 
- * 执行数据库操作，如果失败则进行重试，直到成功或达到最大重试次数。
+ * 执行数据库操作，如果因可重试错误（序列化失败、死锁）失败则进行重试，
+ * 直到成功或达到最大重试次数；永久性错误会被立即返回。
  * 
  * # 类型参数
  * * `T` - 操作返回值的类型
@@ -110,7 +298,7 @@ where
         match operation(db).await {
             Ok(result) => return Ok(result),
             Err(e) => {
-                if retries >= max_retries {
+                if retries >= max_retries || !is_retryable_error(&e) {
                     return Err(e);
                 }
                 retries += 1;
@@ -118,4 +306,171 @@ where
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ConnectionTrait, Database, Statement};
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db.execute_unprepared("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .await
+            .unwrap();
+        db
+    }
+
+    async fn count_rows(db: &DatabaseConnection) -> i64 {
+        let row = db
+            .query_one(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT COUNT(*) AS cnt FROM t",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        row.try_get::<i64>("", "cnt").unwrap()
+    }
+
+    fn app_error(message: &str) -> AppError {
+        AppError::new(500, message.to_string())
+    }
+
+    /**
+     * 数据库返回序列化失败或死锁时转换出的错误
+     */
+    fn retryable_error() -> AppError {
+        let mut err = app_error("Query Error: deadlock detected");
+        err.retryable = true;
+        err
+    }
+
+    #[test]
+    fn test_retryable_error_classification() {
+        assert!(is_retryable_error(&retryable_error()));
+        // 消息中包含SQLSTATE或死锁字样的普通错误不可重试
+        assert!(!is_retryable_error(&app_error("SQLSTATE 40P01")));
+        assert!(!is_retryable_error(&app_error("Query Error: deadlock detected")));
+        assert!(!is_retryable_error(&AppError::from(DbErr::RecordNotFound("t".to_string()))));
+    }
+
+    #[tokio::test]
+    async fn test_constraint_violation_is_not_retryable() {
+        let db = setup_db().await;
+        db.execute_unprepared("INSERT INTO t (id) VALUES (1)").await.unwrap();
+
+        let err = AppError::from(
+            db.execute_unprepared("INSERT INTO t (id) VALUES (1)").await.unwrap_err(),
+        );
+        assert!(!is_retryable_error(&err));
+    }
+
+    #[tokio::test]
+    async fn test_retry_only_on_retryable_errors() {
+        use std::sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        };
+
+        let db = setup_db().await;
+
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+        let result: Result<(), AppError> = execute_in_transaction_with(
+            &db,
+            TxnOptions {
+                retries: 2,
+                backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+            move |_txn| {
+                let counter = counter.clone();
+                Box::pin(async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Err(retryable_error())
+                })
+            },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = attempts.clone();
+        let result: Result<(), AppError> = execute_in_transaction_with(
+            &db,
+            TxnOptions {
+                retries: 2,
+                backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+            move |_txn| {
+                let counter = counter.clone();
+                Box::pin(async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Err(app_error("Record not found"))
+                })
+            },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_savepoint_rollback_keeps_outer_transaction() {
+        let db = setup_db().await;
+
+        execute_in_transaction_with(&db, TxnOptions::default(), |txn| {
+            Box::pin(async move {
+                txn.execute_unprepared("INSERT INTO t (id) VALUES (1)").await?;
+
+                let nested: Result<(), AppError> = execute_in_savepoint(txn, |sp| {
+                    Box::pin(async move {
+                        sp.execute_unprepared("INSERT INTO t (id) VALUES (2)").await?;
+                        Err(app_error("abort savepoint"))
+                    })
+                })
+                .await;
+                assert!(nested.is_err());
+
+                execute_in_savepoint(txn, |sp| {
+                    Box::pin(async move {
+                        sp.execute_unprepared("INSERT INTO t (id) VALUES (3)").await?;
+                        Ok(())
+                    })
+                })
+                .await
+            })
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(count_rows(&db).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_outer_failure_rolls_back_savepoints() {
+        let db = setup_db().await;
+
+        let result: Result<(), AppError> =
+            execute_in_transaction_with(&db, TxnOptions::default(), |txn| {
+                Box::pin(async move {
+                    execute_in_savepoint(txn, |sp| {
+                        Box::pin(async move {
+                            sp.execute_unprepared("INSERT INTO t (id) VALUES (1)").await?;
+                            Ok(())
+                        })
+                    })
+                    .await?;
+                    Err(app_error("abort outer"))
+                })
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(count_rows(&db).await, 0);
+    }
+}
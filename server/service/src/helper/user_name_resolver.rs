@@ -0,0 +1,194 @@
+/**
+ * 用户名解析模块
+ *
+ * 将输出数据中的创建人、更新人用户ID批量解析为用户名：
+ * - 未缓存的ID通过一次 `IN` 查询获取
+ * - 解析结果保存在容量有限的缓存中，过期后重新查询
+ * - 已删除或不存在的用户ID解析为 None，不返回错误
+ *
+ * 使用示例
+ * --------
+ *
+ * let names = resolve_user_names(db.as_ref(), ids).await?;
+ * let created_by_name = names.get(&menu.created_by).cloned();
+ */
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QuerySelect};
+use server_model::admin::entities::{prelude::SysUser, sys_user::Column as SysUserColumn};
+
+/** 缓存的最大用户数 */
+const USER_NAME_CACHE_CAPACITY: u64 = 1000;
+
+/** 缓存有效期，用户改名后最多延迟该时间生效 */
+const USER_NAME_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/** 全局用户名解析器 */
+static USER_NAME_RESOLVER: Lazy<UserNameResolver> =
+    Lazy::new(|| UserNameResolver::new(USER_NAME_CACHE_CAPACITY, USER_NAME_CACHE_TTL));
+
+/**
+ * 用户名解析器
+ *
+ * 不存在的用户ID同样缓存为 None，避免 `-1` 等无效ID反复查询数据库
+ */
+pub struct UserNameResolver {
+    cache: Cache<String, Option<String>>,
+}
+
+impl UserNameResolver {
+    /**
+     * 创建用户名解析器
+     *
+     * @param capacity 缓存的最大用户数
+     * @param ttl 缓存有效期
+     */
+    pub fn new(capacity: u64, ttl: Duration) -> Self {
+        Self {
+            cache: Cache::builder().max_capacity(capacity).time_to_live(ttl).build(),
+        }
+    }
+
+    /**
+     * 批量解析用户名
+     *
+     * @param db 数据库连接
+     * @param ids 用户ID，可以重复
+     * @return Result<HashMap<String, String>, DbErr> 用户ID到用户名，不存在的用户不在结果中
+     */
+    pub async fn resolve<'a, C, I>(&self, db: &C, ids: I) -> Result<HashMap<String, String>, DbErr>
+    where
+        C: ConnectionTrait,
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut names = HashMap::new();
+        let mut missing = HashSet::new();
+
+        for id in ids {
+            match self.cache.get(id) {
+                Some(Some(name)) => {
+                    names.insert(id.to_string(), name);
+                },
+                Some(None) => {},
+                None => {
+                    missing.insert(id.to_string());
+                },
+            }
+        }
+
+        if missing.is_empty() {
+            return Ok(names);
+        }
+
+        let found: Vec<(String, String)> = SysUser::find()
+            .select_only()
+            .column(SysUserColumn::Id)
+            .column(SysUserColumn::Username)
+            .filter(SysUserColumn::Id.is_in(missing.iter().map(String::as_str)))
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        for (id, name) in found {
+            missing.remove(&id);
+            self.cache.insert(id.clone(), Some(name.clone()));
+            names.insert(id, name);
+        }
+        for id in missing {
+            self.cache.insert(id, None);
+        }
+
+        Ok(names)
+    }
+}
+
+/**
+ * 使用全局解析器批量解析用户名
+ *
+ * @param db 数据库连接
+ * @param ids 用户ID，可以重复
+ * @return Result<HashMap<String, String>, DbErr> 用户ID到用户名，不存在的用户不在结果中
+ */
+pub async fn resolve_user_names<'a, C, I>(db: &C, ids: I) -> Result<HashMap<String, String>, DbErr>
+where
+    C: ConnectionTrait,
+    I: IntoIterator<Item = &'a str>,
+{
+    USER_NAME_RESOLVER.resolve(db, ids).await
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, Database, DatabaseConnection, ModelTrait, Schema, Set};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::Status, sys_user::ActiveModel as SysUserActiveModel,
+    };
+    use server_model::admin::timestamps;
+
+    use super::*;
+
+    async fn setup_db() -> DatabaseConnection {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(SysUser))).await.unwrap();
+
+        SysUserActiveModel {
+            id: Set("u1".to_string()),
+            username: Set("alice".to_string()),
+            password: Set("secret".to_string()),
+            domain: Set("built-in".to_string()),
+            built_in: Set(false),
+            nick_name: Set("Alice".to_string()),
+            status: Set(Status::Enabled),
+            created_at: Set(timestamps::now()),
+            created_by: Set("test".to_string()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+        db
+    }
+
+    fn resolver() -> UserNameResolver {
+        UserNameResolver::new(10, Duration::from_secs(60))
+    }
+
+    #[tokio::test]
+    async fn test_resolve_uses_cache() {
+        let db = setup_db().await;
+        let cached = resolver();
+
+        let names = cached.resolve(&db, ["u1", "u1"]).await.unwrap();
+        assert_eq!(names.get("u1").map(String::as_str), Some("alice"));
+
+        // 用户删除后仍从缓存返回，说明没有再次查询数据库
+        let user = SysUser::find_by_id("u1").one(&db).await.unwrap().unwrap();
+        user.delete(&db).await.unwrap();
+        let names = cached.resolve(&db, ["u1"]).await.unwrap();
+        assert_eq!(names.get("u1").map(String::as_str), Some("alice"));
+
+        assert!(resolver().resolve(&db, ["u1"]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_unknown_ids() {
+        let db = setup_db().await;
+        let resolver = resolver();
+
+        let names = resolver.resolve(&db, ["u1", "-1", "deleted"]).await.unwrap();
+        assert_eq!(names.len(), 1);
+        assert_eq!(names.get("-1"), None);
+        assert_eq!(resolver.cache.get("-1"), Some(None));
+
+        let names = resolver.resolve(&db, Vec::<&str>::new()).await.unwrap();
+        assert!(names.is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+/**
+ * 用户路由缓存模块
+ *
+ * 缓存按角色构建好的用户路由树，避免每次刷新页面都查询角色菜单并重建路由树：
+ * - 键为域代码和排序去重后的角色代码
+ * - 缓存有效期内由菜单分配变更事件主动失效，过期后重新查询
+ * - 缓存位于进程内，多实例部署时其他实例最多延迟一个有效期生效
+ * - user_route_cache_listener: 菜单分配变更事件的监听器，负责失效缓存
+ *
+ * 使用示例
+ * --------
+ *
+ * let cache = user_route_cache();
+ * if let Some(routes) = cache.get(domain, &role_codes) {
+ *     return Ok(routes);
+ * }
+ *
+ * // 角色菜单变更后通知失效
+ * notify_menu_assignment_changed(Some(domain), vec![role_code]).await;
+ */
+
+use std::{any::Any, sync::Arc, time::Duration};
+
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use server_constant::definition::consts::SystemEvent;
+use server_global::{
+    global::{self, EventReceiver},
+    project_error,
+};
+use server_model::admin::output::UserRoute;
+
+/** 缓存的最大角色组合数 */
+const USER_ROUTE_CACHE_CAPACITY: u64 = 1000;
+
+/** 缓存有效期，未收到失效事件时最多延迟该时间生效 */
+const USER_ROUTE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/** 全局用户路由缓存 */
+static USER_ROUTE_CACHE: Lazy<Arc<UserRouteCache>> = Lazy::new(|| {
+    Arc::new(UserRouteCache::new(USER_ROUTE_CACHE_CAPACITY, USER_ROUTE_CACHE_TTL))
+});
+
+/**
+ * 用户路由缓存键
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct UserRouteKey {
+    domain: String,
+    role_codes: Vec<String>,
+}
+
+impl UserRouteKey {
+    fn new(domain: &str, role_codes: &[String]) -> Self {
+        let mut role_codes = role_codes.to_vec();
+        role_codes.sort();
+        role_codes.dedup();
+        Self { domain: domain.to_string(), role_codes }
+    }
+}
+
+/**
+ * 用户路由缓存
+ */
+pub struct UserRouteCache {
+    cache: Cache<UserRouteKey, UserRoute>,
+}
+
+impl UserRouteCache {
+    /**
+     * 创建用户路由缓存
+     *
+     * @param capacity 缓存的最大角色组合数
+     * @param ttl 缓存有效期
+     */
+    pub fn new(capacity: u64, ttl: Duration) -> Self {
+        Self {
+            cache: Cache::builder().max_capacity(capacity).time_to_live(ttl).build(),
+        }
+    }
+
+    /**
+     * 读取缓存的用户路由
+     *
+     * @param domain 域代码
+     * @param role_codes 角色代码，顺序和重复不影响结果
+     * @return Option<UserRoute> 未缓存或已过期时返回 None
+     */
+    pub fn get(&self, domain: &str, role_codes: &[String]) -> Option<UserRoute> {
+        self.cache.get(&UserRouteKey::new(domain, role_codes))
+    }
+
+    /**
+     * 缓存用户路由
+     *
+     * @param domain 域代码
+     * @param role_codes 角色代码
+     * @param routes 构建好的用户路由
+     */
+    pub fn insert(&self, domain: &str, role_codes: &[String], routes: UserRoute) {
+        self.cache.insert(UserRouteKey::new(domain, role_codes), routes);
+    }
+
+    /**
+     * 使缓存失效
+     *
+     * 失效包含任一指定角色的角色组合
+     *
+     * @param domain 域代码，为 None 时匹配所有域
+     * @param role_codes 角色代码，为空时匹配所有角色
+     */
+    pub fn invalidate(&self, domain: Option<&str>, role_codes: &[String]) {
+        for (key, _) in self.cache.iter() {
+            let domain_matches = domain.is_none_or(|domain| key.domain == domain);
+            let roles_match =
+                role_codes.is_empty() || key.role_codes.iter().any(|code| role_codes.contains(code));
+            if domain_matches && roles_match {
+                self.cache.invalidate(key.as_ref());
+            }
+        }
+    }
+}
+
+/**
+ * 获取全局用户路由缓存
+ *
+ * @return Arc<UserRouteCache> 认证服务和失效事件监听器共用的缓存
+ */
+pub fn user_route_cache() -> Arc<UserRouteCache> {
+    USER_ROUTE_CACHE.clone()
+}
+
+/**
+ * 菜单分配变更事件
+ *
+ * 角色菜单分配或菜单本身变更后发出，由 `user_route_cache_listener` 失效对应缓存
+ */
+#[derive(Clone, Debug, Default)]
+pub struct MenuAssignmentChanged {
+    /** 域代码，为 None 时表示所有域 */
+    pub domain: Option<String>,
+    /** 角色代码，为空时表示所有角色 */
+    pub role_codes: Vec<String>,
+}
+
+/**
+ * 发出菜单分配变更事件
+ *
+ * 菜单本身变更时影响所有角色，传入 `None` 和空角色列表
+ *
+ * @param domain 域代码，为 None 时表示所有域
+ * @param role_codes 角色代码，为空时表示所有角色
+ */
+pub async fn notify_menu_assignment_changed(domain: Option<String>, role_codes: Vec<String>) {
+    global::send_dyn_event_async(
+        SystemEvent::MenuAssignmentChangedEvent.as_ref(),
+        Box::new(MenuAssignmentChanged { domain, role_codes }),
+    )
+    .await;
+}
+
+/**
+ * 菜单分配变更事件监听器
+ *
+ * 按事件中的域和角色失效全局用户路由缓存
+ *
+ * @param rx 事件接收器
+ */
+pub async fn user_route_cache_listener(mut rx: EventReceiver<Box<dyn Any + Send>>) {
+    while let Some(event) = rx.recv().await {
+        let Some(changed) = event.downcast_ref::<MenuAssignmentChanged>() else {
+            project_error!("Received unknown event type in user route cache listener");
+            continue;
+        };
+
+        USER_ROUTE_CACHE.invalidate(changed.domain.as_deref(), &changed.role_codes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routes(home: &str) -> UserRoute {
+        UserRoute { routes: vec![], home: home.to_string() }
+    }
+
+    fn codes(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|code| code.to_string()).collect()
+    }
+
+    #[test]
+    fn test_key_ignores_role_order() {
+        let cache = UserRouteCache::new(10, Duration::from_secs(60));
+        cache.insert("built-in", &codes(&["ROLE_B", "ROLE_A"]), routes("home"));
+
+        let cached = cache.get("built-in", &codes(&["ROLE_A", "ROLE_B", "ROLE_A"]));
+        assert_eq!(cached.map(|r| r.home), Some("home".to_string()));
+        assert!(cache.get("other", &codes(&["ROLE_A", "ROLE_B"])).is_none());
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let cache = UserRouteCache::new(10, Duration::from_secs(60));
+        cache.insert("built-in", &codes(&["ROLE_A"]), routes("a"));
+        cache.insert("built-in", &codes(&["ROLE_A", "ROLE_B"]), routes("ab"));
+        cache.insert("built-in", &codes(&["ROLE_C"]), routes("c"));
+        cache.insert("other", &codes(&["ROLE_A"]), routes("other"));
+
+        cache.invalidate(Some("built-in"), &codes(&["ROLE_A"]));
+        assert!(cache.get("built-in", &codes(&["ROLE_A"])).is_none());
+        assert!(cache.get("built-in", &codes(&["ROLE_A", "ROLE_B"])).is_none());
+        assert!(cache.get("built-in", &codes(&["ROLE_C"])).is_some());
+        assert!(cache.get("other", &codes(&["ROLE_A"])).is_some());
+
+        cache.invalidate(None, &[]);
+        assert!(cache.get("built-in", &codes(&["ROLE_C"])).is_none());
+        assert!(cache.get("other", &codes(&["ROLE_A"])).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+//! RedisOps 集成测试
+//!
+//! 需要可访问的 Redis 实例，通过 `cargo test -p server-service --features redis-tests` 运行。
+//! - REDIS_URL: 单机地址，默认 redis://127.0.0.1/
+//! - REDIS_CLUSTER_URLS: 集群节点地址（逗号分隔），未设置时跳过集群用例
+#![cfg(feature = "redis-tests")]
+
+use std::{sync::Arc, time::Duration};
+
+use redis::{cluster::ClusterClient, Client};
+use serde::{Deserialize, Serialize};
+use server_global::global::RedisConnection;
+use server_service::helper::redis_helper::RedisOps;
+use ulid::Ulid;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CachedUser {
+    id: String,
+    name: String,
+}
+
+fn single_ops() -> RedisOps {
+    let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string());
+    let client = Client::open(url).expect("invalid REDIS_URL");
+    RedisOps::from_connection(RedisConnection::Single(Arc::new(client)))
+}
+
+fn cluster_ops() -> Option<RedisOps> {
+    let urls = std::env::var("REDIS_CLUSTER_URLS").ok()?;
+    let nodes: Vec<String> = urls.split(',').map(|u| u.trim().to_string()).collect();
+    let client = ClusterClient::new(nodes).expect("invalid REDIS_CLUSTER_URLS");
+    Some(RedisOps::from_connection(RedisConnection::Cluster(Arc::new(client))))
+}
+
+fn unique_key(name: &str) -> String {
+    format!("redis-tests:{}:{}", name, Ulid::new())
+}
+
+async fn assert_ops(ops: &RedisOps) {
+    let key = unique_key("json");
+    let user = CachedUser {
+        id: "1".to_string(),
+        name: "admin".to_string(),
+    };
+
+    assert_eq!(ops.get_json::<CachedUser>(&key).await.unwrap(), None);
+    ops.set_json(&key, &user, Some(Duration::from_secs(30)))
+        .await
+        .unwrap();
+    assert_eq!(ops.get_json::<CachedUser>(&key).await.unwrap(), Some(user));
+    assert!(ops.exists(&key).await.unwrap());
+    assert!(ops.delete(&key).await.unwrap());
+    assert!(!ops.exists(&key).await.unwrap());
+    assert!(!ops.delete(&key).await.unwrap());
+
+    let counter = unique_key("counter");
+    assert_eq!(ops.incr_with_ttl(&counter, Duration::from_secs(1)).await.unwrap(), 1);
+    assert_eq!(ops.incr_with_ttl(&counter, Duration::from_secs(1)).await.unwrap(), 2);
+    assert_eq!(ops.get_json::<u64>(&counter).await.unwrap(), Some(2));
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    assert!(!ops.exists(&counter).await.unwrap());
+
+    let expiring = unique_key("ttl");
+    ops.set_json(&expiring, &1u8, Some(Duration::from_millis(200)))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(ops.get_json::<u8>(&expiring).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_redis_ops_single() {
+    assert_ops(&single_ops()).await;
+}
+
+#[tokio::test]
+async fn test_redis_ops_cluster() {
+    if let Some(ops) = cluster_ops() {
+        assert_ops(&ops).await;
+    }
+}
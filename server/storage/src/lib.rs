@@ -0,0 +1,23 @@
+/**
+ * 对象存储模块
+ * 
+ * 该模块提供了与具体存储服务无关的对象存储抽象，包括：
+ * - object_store: 对象存储接口（上传、下载、按范围读取、删除、预签名、按前缀列举）及错误类型
+ * - s3_object_store: 基于AWS SDK的S3实现，兼容MinIO等S3协议服务
+ * - local_object_store: 基于本地文件系统的实现，用于测试和无法访问S3的隔离部署
+ * 
+ * 使用哪种实现由配置 `s3.provider`（`aws` 或 `local`）决定，业务代码只依赖 `ObjectStore` 接口。
+ */
+
+/** 对象存储接口模块 */
+mod object_store;
+
+/** S3对象存储模块 */
+mod s3_object_store;
+
+/** 本地文件系统对象存储模块 */
+mod local_object_store;
+
+pub use local_object_store::{LocalObjectStore, MIN_SIGNING_KEY_LEN};
+pub use object_store::{ByteRange, ObjectRead, ObjectReader, ObjectStore, PutOptions, StorageError};
+pub use s3_object_store::S3ObjectStore;
@@ -0,0 +1,400 @@
+/**
+ * 本地文件系统对象存储
+ *
+ * 对象保存在基础目录下，对象键按 `/` 拆分为子目录。预签名链接指向本服务的文件下载路由，
+ * 由 `get_presigned` 校验过期时间和签名后打开文件，由下载路由流式返回。
+ *
+ * 签名密钥由配置提供，服务重启或多个实例共享同一目录时此前生成的链接仍然有效。
+ */
+
+use std::{
+    io::{ErrorKind, SeekFrom},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use ring::hmac;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::{ByteRange, ObjectRead, ObjectReader, ObjectStore, PutOptions, StorageError};
+
+/**
+ * 签名密钥的最短长度（字节），与HMAC-SHA256的输出长度一致
+ */
+pub const MIN_SIGNING_KEY_LEN: usize = 32;
+
+/**
+ * 本地文件系统对象存储
+ */
+pub struct LocalObjectStore {
+    base_dir: PathBuf,
+    public_url: String,
+    signing_key: hmac::Key,
+}
+
+impl LocalObjectStore {
+    /**
+     * 创建本地对象存储
+     *
+     * 多个实例共享同一目录时使用相同的密钥，生成的链接可由任一实例提供下载
+     *
+     * # 参数
+     * * `base_dir` - 保存对象的基础目录
+     * * `public_url` - 文件下载路由的地址，预签名链接为 `<public_url>/<key>?expires=..&signature=..`
+     * * `signing_key` - 预签名链接的签名密钥，长度校验由调用方完成（见 `MIN_SIGNING_KEY_LEN`）
+     */
+    pub fn new(
+        base_dir: impl Into<PathBuf>,
+        public_url: impl Into<String>,
+        signing_key: &[u8],
+    ) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+            signing_key: hmac::Key::new(hmac::HMAC_SHA256, signing_key),
+        }
+    }
+
+    /**
+     * 保存对象的基础目录
+     */
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    /**
+     * 把对象键转换为基础目录下的文件路径
+     *
+     * 拒绝空键、绝对路径、反斜杠以及空、`.`、`..` 路径片段，保证路径不会逃出基础目录
+     */
+    fn object_path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        let invalid = key.is_empty()
+            || key.contains(['\\', '\0'])
+            || key.split('/').any(|segment| matches!(segment, "" | "." | ".."));
+        if invalid {
+            return Err(StorageError::InvalidKey(key.to_string()));
+        }
+        Ok(key.split('/').fold(self.base_dir.clone(), |path, segment| path.join(segment)))
+    }
+
+    /**
+     * 计算预签名参数的签名
+     */
+    fn sign(&self, key: &str, expires: u64) -> hmac::Tag {
+        hmac::sign(&self.signing_key, format!("{}\n{}", key, expires).as_bytes())
+    }
+}
+
+#[inline]
+fn io_error(key: &str, err: std::io::Error) -> StorageError {
+    match err.kind() {
+        ErrorKind::NotFound => StorageError::NotFound(key.to_string()),
+        _ => StorageError::Backend(err.to_string()),
+    }
+}
+
+#[inline]
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
+
+#[async_trait]
+impl ObjectStore for LocalObjectStore {
+    async fn put(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        _options: PutOptions,
+    ) -> Result<(), StorageError> {
+        let path = self.object_path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| io_error(key, e))?;
+        }
+        tokio::fs::write(&path, body).await.map_err(|e| io_error(key, e))
+    }
+
+    async fn put_file(
+        &self,
+        key: &str,
+        source: &Path,
+        _options: PutOptions,
+    ) -> Result<(), StorageError> {
+        let path = self.object_path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| io_error(key, e))?;
+        }
+        tokio::fs::copy(source, &path).await.map(|_| ()).map_err(|e| io_error(key, e))
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, StorageError> {
+        let path = self.object_path(key)?;
+        // 目录不是对象
+        if tokio::fs::metadata(&path).await.is_ok_and(|metadata| metadata.is_dir()) {
+            return Err(StorageError::NotFound(key.to_string()));
+        }
+        tokio::fs::read(&path).await.map_err(|e| io_error(key, e))
+    }
+
+    async fn open(&self, key: &str, range: Option<ByteRange>) -> Result<ObjectRead, StorageError> {
+        let path = self.object_path(key)?;
+        let mut file = tokio::fs::File::open(&path).await.map_err(|e| io_error(key, e))?;
+        let metadata = file.metadata().await.map_err(|e| io_error(key, e))?;
+        // 目录不是对象
+        if metadata.is_dir() {
+            return Err(StorageError::NotFound(key.to_string()));
+        }
+
+        let size = metadata.len();
+        let range = range
+            .map(|range| range.resolve(size).ok_or(StorageError::RangeNotSatisfiable { size }))
+            .transpose()?;
+        let reader: ObjectReader = match range {
+            Some((start, end)) => {
+                file.seek(SeekFrom::Start(start)).await.map_err(|e| io_error(key, e))?;
+                Box::pin(file.take(end - start + 1))
+            },
+            None => Box::pin(file),
+        };
+        Ok(ObjectRead {
+            reader,
+            size,
+            range,
+            content_type: None,
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let path = self.object_path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(io_error(key, e)),
+            _ => Ok(()),
+        }
+    }
+
+    async fn presign(&self, key: &str, expires_in: Duration) -> Result<String, StorageError> {
+        self.object_path(key)?;
+        let expires = now_secs().saturating_add(expires_in.as_secs());
+        let encoded_key = key.split('/').map(urlencoding::encode).collect::<Vec<_>>().join("/");
+        Ok(format!(
+            "{}/{}?expires={}&signature={}",
+            self.public_url,
+            encoded_key,
+            expires,
+            hex::encode(self.sign(key, expires))
+        ))
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let mut keys = Vec::new();
+        let mut pending = vec![(self.base_dir.clone(), String::new())];
+        while let Some((dir, dir_key)) = pending.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                // 尚未写入任何对象时基础目录可能不存在
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(StorageError::Backend(e.to_string())),
+            };
+            while let Some(entry) =
+                entries.next_entry().await.map_err(|e| StorageError::Backend(e.to_string()))?
+            {
+                let Some(name) = entry.file_name().to_str().map(String::from) else {
+                    continue;
+                };
+                let key = format!("{}{}", dir_key, name);
+                let file_type =
+                    entry.file_type().await.map_err(|e| StorageError::Backend(e.to_string()))?;
+                if file_type.is_dir() {
+                    pending.push((entry.path(), format!("{}/", key)));
+                } else if file_type.is_file() && key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    async fn get_presigned(
+        &self,
+        key: &str,
+        expires: u64,
+        signature: &str,
+    ) -> Result<ObjectReader, StorageError> {
+        let signature = hex::decode(signature).map_err(|_| StorageError::InvalidSignature)?;
+        if expires < now_secs() {
+            return Err(StorageError::InvalidSignature);
+        }
+        hmac::verify(&self.signing_key, format!("{}\n{}", key, expires).as_bytes(), &signature)
+            .map_err(|_| StorageError::InvalidSignature)?;
+
+        let path = self.object_path(key)?;
+        let file = tokio::fs::File::open(&path).await.map_err(|e| io_error(key, e))?;
+        // 目录不是对象
+        if file.metadata().await.map_err(|e| io_error(key, e))?.is_dir() {
+            return Err(StorageError::NotFound(key.to_string()));
+        }
+        Ok(Box::pin(file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(dir: &Path) -> LocalObjectStore {
+        LocalObjectStore::new(dir, "/api/v1/storage/files/", b"test-signing-key")
+    }
+
+    async fn read_all(mut reader: ObjectReader) -> Vec<u8> {
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body).await.unwrap();
+        body
+    }
+
+    /**
+     * 从预签名链接中解析对象键、过期时间和签名
+     */
+    fn parse_presigned(url: &str) -> (String, u64, String) {
+        let (path, query) = url.split_once('?').unwrap();
+        let key = path.strip_prefix("/api/v1/storage/files/").unwrap();
+        let key = urlencoding::decode(key).unwrap().into_owned();
+        let mut expires = 0;
+        let mut signature = String::new();
+        for pair in query.split('&') {
+            match pair.split_once('=').unwrap() {
+                ("expires", value) => expires = value.parse().unwrap(),
+                ("signature", value) => signature = value.to_string(),
+                _ => {},
+            }
+        }
+        (key, expires, signature)
+    }
+
+    /**
+     * 测试上传、读取、列举和删除
+     */
+    #[tokio::test]
+    async fn test_put_get_list_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        assert!(store.list("").await.unwrap().is_empty());
+
+        for key in ["avatars/a.png", "avatars/b/c.png", "archive/1.ndjson.gz"] {
+            store.put(key, key.as_bytes().to_vec(), PutOptions::default()).await.unwrap();
+        }
+        assert_eq!(store.get("avatars/b/c.png").await.unwrap(), b"avatars/b/c.png");
+        assert_eq!(store.list("avatars/").await.unwrap(), ["avatars/a.png", "avatars/b/c.png"]);
+        assert_eq!(store.list("a").await.unwrap().len(), 3);
+
+        store.put("avatars/a.png", b"new".to_vec(), PutOptions::default()).await.unwrap();
+        assert_eq!(store.get("avatars/a.png").await.unwrap(), b"new");
+
+        let source = dir.path().join("upload.zip");
+        std::fs::write(&source, b"zip").unwrap();
+        store.put_file("exports/a.zip", &source, PutOptions::default()).await.unwrap();
+        assert_eq!(store.get("exports/a.zip").await.unwrap(), b"zip");
+
+        store.delete("avatars/a.png").await.unwrap();
+        store.delete("avatars/a.png").await.unwrap();
+        assert!(matches!(store.get("avatars/a.png").await, Err(StorageError::NotFound(_))));
+        assert!(matches!(store.get("avatars/b").await, Err(StorageError::NotFound(_))));
+    }
+
+    /**
+     * 测试按范围读取较大的对象
+     */
+    #[tokio::test]
+    async fn test_open_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        let body: Vec<u8> = (0..4 * 1024 * 1024).map(|i: u32| (i % 251) as u8).collect();
+        store.put("exports/large.bin", body.clone(), PutOptions::default()).await.unwrap();
+
+        let object = store.open("exports/large.bin", None).await.unwrap();
+        assert_eq!((object.size, object.range), (body.len() as u64, None));
+        assert_eq!(object.content_length(), body.len() as u64);
+        assert_eq!(read_all(object.reader).await, body);
+
+        let range = ByteRange::From { start: 1_000_000, end: Some(2_999_999) };
+        let object = store.open("exports/large.bin", Some(range)).await.unwrap();
+        assert_eq!(object.range, Some((1_000_000, 2_999_999)));
+        assert_eq!(object.content_length(), 2_000_000);
+        assert_eq!(read_all(object.reader).await, &body[1_000_000..3_000_000]);
+
+        let object = store.open("exports/large.bin", Some(ByteRange::Suffix(10))).await.unwrap();
+        assert_eq!(read_all(object.reader).await, &body[body.len() - 10..]);
+
+        let size = body.len() as u64;
+        let range = ByteRange::From { start: size, end: None };
+        let result = store.open("exports/large.bin", Some(range)).await;
+        assert!(matches!(result, Err(StorageError::RangeNotSatisfiable { size: s }) if s == size));
+        for key in ["exports/none.bin", "exports"] {
+            let result = store.open(key, None).await;
+            assert!(matches!(result, Err(StorageError::NotFound(_))), "{:?}", key);
+        }
+    }
+
+    /**
+     * 测试对象键不能逃出基础目录
+     */
+    #[tokio::test]
+    async fn test_rejects_invalid_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        for key in ["", "../secret", "a/../../b", "/etc/passwd", "a//b", "a\\b", "./a", "a/"] {
+            let result = store.put(key, Vec::new(), PutOptions::default()).await;
+            assert!(matches!(result, Err(StorageError::InvalidKey(_))), "{:?}", key);
+            assert!(matches!(store.get(key).await, Err(StorageError::InvalidKey(_))), "{:?}", key);
+        }
+    }
+
+    /**
+     * 测试预签名链接由本服务校验后提供下载
+     */
+    #[tokio::test]
+    async fn test_presign_semantics() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        let key = "avatars/user 1/头像.png";
+        store.put(key, b"image".to_vec(), PutOptions::default()).await.unwrap();
+
+        let url = store.presign(key, Duration::from_secs(300)).await.unwrap();
+        assert!(url.starts_with("/api/v1/storage/files/avatars/user%201/%E5%A4%B4"));
+        let (parsed_key, expires, signature) = parse_presigned(&url);
+        assert_eq!(parsed_key, key);
+        assert!(expires >= now_secs() + 299);
+        let reader = store.get_presigned(key, expires, &signature).await.unwrap();
+        assert_eq!(read_all(reader).await, b"image");
+
+        // 签名绑定对象键和过期时间，篡改任意一项都无法下载
+        let result = store.get_presigned("avatars/other.png", expires, &signature).await;
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+        let result = store.get_presigned(key, expires + 3600, &signature).await;
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+        let result = store.get_presigned(key, expires, "not-hex").await;
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+
+        // 签名正确但对象键是目录
+        let signature = hex::encode(store.sign("avatars/user 1", expires));
+        let result = store.get_presigned("avatars/user 1", expires, &signature).await;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+
+        // 签名正确但已过期的链接
+        let expired = now_secs() - 10;
+        let signature = hex::encode(store.sign(key, expired));
+        let result = store.get_presigned(key, expired, &signature).await;
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+
+        // 不同密钥生成的链接互不认可，对象删除后链接不再可用
+        let other = LocalObjectStore::new(dir.path(), "/api/v1/storage/files", b"other-key");
+        let url = other.presign(key, Duration::from_secs(300)).await.unwrap();
+        let (_, expires, signature) = parse_presigned(&url);
+        let result = store.get_presigned(key, expires, &signature).await;
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+        assert!(other.get_presigned(key, expires, &signature).await.is_ok());
+        other.delete(key).await.unwrap();
+        let result = other.get_presigned(key, expires, &signature).await;
+        assert!(matches!(result, Err(StorageError::NotFound(_))));
+    }
+}
@@ -0,0 +1,284 @@
+/**
+ * 对象存储接口
+ *
+ * 定义对象存储的统一操作和错误类型，S3与本地文件系统实现共用
+ */
+
+use std::{path::Path, pin::Pin, time::Duration};
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::io::AsyncRead;
+
+/**
+ * 对象存储错误
+ */
+#[derive(Error, Debug)]
+pub enum StorageError {
+    /** 对象键为空或包含不允许的路径片段 */
+    #[error("Invalid object key: {0}")]
+    InvalidKey(String),
+
+    /** 对象不存在 */
+    #[error("Object not found: {0}")]
+    NotFound(String),
+
+    /** 预签名链接已过期或签名不正确 */
+    #[error("Invalid or expired signature")]
+    InvalidSignature,
+
+    /** 当前实现不支持该操作 */
+    #[error("Operation not supported by this storage provider")]
+    Unsupported,
+
+    /** 请求的字节范围超出对象大小 */
+    #[error("Range not satisfiable, object size is {size} bytes")]
+    RangeNotSatisfiable { size: u64 },
+
+    /** 存储服务或文件系统返回的错误 */
+    #[error("Storage backend error: {0}")]
+    Backend(String),
+}
+
+/**
+ * 对象内容的异步读取器
+ *
+ * 下载路由按块读取并写入响应，不把整个对象读入内存
+ */
+pub type ObjectReader = Pin<Box<dyn AsyncRead + Send>>;
+
+/**
+ * 字节范围，对应单段的 HTTP `Range` 请求头
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /** `bytes=start-` 或 `bytes=start-end`，`end` 包含在范围内 */
+    From { start: u64, end: Option<u64> },
+    /** `bytes=-len`，对象的最后 `len` 个字节 */
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /**
+     * 解析 `Range` 请求头
+     *
+     * 只支持单段的 `bytes` 范围。多段范围、其他单位和格式错误的请求头返回 `None`，
+     * 调用方按 RFC 9110 忽略该请求头并返回完整对象
+     */
+    pub fn parse(header: &str) -> Option<Self> {
+        let spec = header.trim().strip_prefix("bytes=")?;
+        if spec.contains(',') {
+            return None;
+        }
+        let (start, end) = spec.split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+        if start.is_empty() {
+            return end.parse().ok().map(Self::Suffix);
+        }
+        let start = start.parse().ok()?;
+        let end = match end {
+            "" => None,
+            end => Some(end.parse().ok()?),
+        };
+        if end.is_some_and(|end| end < start) {
+            return None;
+        }
+        Some(Self::From { start, end })
+    }
+
+    /**
+     * 按对象大小计算实际读取的范围
+     *
+     * # 返回
+     * * `Some((start, end))` - 首尾字节的位置，`end` 包含在范围内且不超过对象末尾
+     * * `None` - 范围不可满足：起始位置不小于对象大小、后缀长度为0或对象为空
+     */
+    pub fn resolve(self, size: u64) -> Option<(u64, u64)> {
+        let last = size.checked_sub(1)?;
+        match self {
+            Self::From { start, end } => {
+                (start <= last).then(|| (start, end.map_or(last, |end| end.min(last))))
+            },
+            Self::Suffix(0) => None,
+            Self::Suffix(len) => Some((size.saturating_sub(len), last)),
+        }
+    }
+}
+
+/**
+ * 打开的对象
+ */
+pub struct ObjectRead {
+    /** 对象内容的读取器，请求了范围时只返回该范围内的字节 */
+    pub reader: ObjectReader,
+    /** 对象的总大小（字节） */
+    pub size: u64,
+    /** 实际读取的范围，首尾字节的位置均包含在内，未请求范围时为 `None` */
+    pub range: Option<(u64, u64)>,
+    /** 对象的 Content-Type，存储未记录时为 `None` */
+    pub content_type: Option<String>,
+}
+
+impl ObjectRead {
+    /**
+     * 读取器返回的字节数
+     */
+    pub fn content_length(&self) -> u64 {
+        self.range.map_or(self.size, |(start, end)| end - start + 1)
+    }
+}
+
+/**
+ * 上传对象的可选元数据
+ */
+#[derive(Debug, Clone, Default)]
+pub struct PutOptions {
+    /** 对象的 Content-Type */
+    pub content_type: Option<String>,
+    /** 对象的 Content-Encoding，如 `gzip` */
+    pub content_encoding: Option<String>,
+}
+
+/**
+ * 对象存储接口
+ *
+ * 对象键使用 `/` 分隔的相对路径，如 `avatars/2026/user-1.png`
+ */
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    /**
+     * 上传对象，已存在时覆盖
+     *
+     * # 参数
+     * * `key` - 对象键
+     * * `body` - 对象内容
+     * * `options` - 对象元数据
+     */
+    async fn put(&self, key: &str, body: Vec<u8>, options: PutOptions) -> Result<(), StorageError>;
+
+    /**
+     * 上传本地文件为对象，已存在时覆盖
+     *
+     * 用于导出文件等较大的对象。默认读入内存后调用 `put`，实现可改为流式上传
+     *
+     * # 参数
+     * * `key` - 对象键
+     * * `path` - 本地文件路径
+     * * `options` - 对象元数据
+     */
+    async fn put_file(
+        &self,
+        key: &str,
+        path: &Path,
+        options: PutOptions,
+    ) -> Result<(), StorageError> {
+        let body =
+            tokio::fs::read(path).await.map_err(|e| StorageError::Backend(e.to_string()))?;
+        self.put(key, body, options).await
+    }
+
+    /**
+     * 读取对象内容
+     *
+     * # 返回
+     * * `Err(StorageError::NotFound)` - 对象不存在
+     */
+    async fn get(&self, key: &str) -> Result<Vec<u8>, StorageError>;
+
+    /**
+     * 打开对象，按块读取内容
+     *
+     * 供文件代理下载使用，不把整个对象读入内存
+     *
+     * # 参数
+     * * `key` - 对象键
+     * * `range` - 读取的字节范围，为 `None` 时读取整个对象
+     *
+     * # 返回
+     * * `Ok(ObjectRead)` - 对象的读取器、大小和实际读取的范围
+     * * `Err(StorageError::NotFound)` - 对象不存在
+     * * `Err(StorageError::RangeNotSatisfiable)` - 范围超出对象大小
+     */
+    async fn open(&self, key: &str, range: Option<ByteRange>) -> Result<ObjectRead, StorageError>;
+
+    /**
+     * 删除对象，对象不存在时同样返回成功
+     */
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+
+    /**
+     * 生成对象的限时下载链接
+     *
+     * # 参数
+     * * `key` - 对象键
+     * * `expires_in` - 链接有效期
+     *
+     * # 返回
+     * * `Ok(String)` - 预签名链接，本地存储返回由本服务提供下载的链接
+     */
+    async fn presign(&self, key: &str, expires_in: Duration) -> Result<String, StorageError>;
+
+    /**
+     * 列举以指定前缀开头的对象键，按字典序返回
+     *
+     * # 参数
+     * * `prefix` - 对象键前缀，按字符串前缀匹配，为空时列举全部对象
+     */
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError>;
+
+    /**
+     * 校验预签名参数并打开对象
+     *
+     * 供本服务的文件下载路由使用。S3的预签名链接直接访问存储服务，默认不支持
+     *
+     * # 参数
+     * * `key` - 对象键
+     * * `expires` - 链接过期时间（UNIX纪元以来的秒数）
+     * * `signature` - 链接中的签名
+     *
+     * # 返回
+     * * `Ok(ObjectReader)` - 对象内容的读取器
+     * * `Err(StorageError::NotFound)` - 对象不存在
+     * * `Err(StorageError::InvalidSignature)` - 链接已过期或签名不正确
+     * * `Err(StorageError::Unsupported)` - 当前实现不由本服务提供下载
+     */
+    async fn get_presigned(
+        &self,
+        key: &str,
+        expires: u64,
+        signature: &str,
+    ) -> Result<ObjectReader, StorageError> {
+        let _ = (key, expires, signature);
+        Err(StorageError::Unsupported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * 测试 `Range` 请求头的解析和按对象大小计算范围
+     */
+    #[test]
+    fn test_byte_range() {
+        let from = |start, end| Some(ByteRange::From { start, end });
+        assert_eq!(ByteRange::parse("bytes=0-99"), from(0, Some(99)));
+        assert_eq!(ByteRange::parse("bytes= 100 - "), from(100, None));
+        assert_eq!(ByteRange::parse("bytes=-500"), Some(ByteRange::Suffix(500)));
+        // 多段范围、其他单位和格式错误的请求头被忽略
+        for header in ["bytes=0-1,4-5", "items=0-1", "bytes=5-1", "bytes=-", "bytes=a-b", "0-1"] {
+            assert_eq!(ByteRange::parse(header), None, "{:?}", header);
+        }
+
+        let range = |header: &str| ByteRange::parse(header).unwrap();
+        assert_eq!(range("bytes=0-99").resolve(1000), Some((0, 99)));
+        assert_eq!(range("bytes=900-2000").resolve(1000), Some((900, 999)));
+        assert_eq!(range("bytes=999-").resolve(1000), Some((999, 999)));
+        assert_eq!(range("bytes=-100").resolve(1000), Some((900, 999)));
+        assert_eq!(range("bytes=-2000").resolve(1000), Some((0, 999)));
+        assert_eq!(range("bytes=1000-").resolve(1000), None);
+        assert_eq!(range("bytes=-0").resolve(1000), None);
+        assert_eq!(range("bytes=0-").resolve(0), None);
+    }
+}
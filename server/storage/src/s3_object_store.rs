@@ -0,0 +1,200 @@
+/**
+ * S3对象存储
+ *
+ * 使用AWS SDK访问S3或兼容S3协议的存储服务，所有对象位于同一存储桶
+ */
+
+use std::{path::Path, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use aws_sdk_s3::{presigning::PresigningConfig, primitives::ByteStream, Client as S3Client};
+
+use crate::{ByteRange, ObjectRead, ObjectStore, PutOptions, StorageError};
+
+/**
+ * S3对象存储
+ */
+#[derive(Clone)]
+pub struct S3ObjectStore {
+    client: Arc<S3Client>,
+    bucket: String,
+}
+
+impl S3ObjectStore {
+    /**
+     * 创建S3对象存储
+     *
+     * # 参数
+     * * `client` - 已初始化的S3客户端
+     * * `bucket` - 存储桶名称
+     */
+    pub fn new(client: Arc<S3Client>, bucket: impl Into<String>) -> Self {
+        Self {
+            client,
+            bucket: bucket.into(),
+        }
+    }
+
+    /**
+     * 底层S3客户端
+     */
+    pub fn client(&self) -> &Arc<S3Client> {
+        &self.client
+    }
+}
+
+#[inline]
+fn backend_error(err: impl std::fmt::Display) -> StorageError {
+    StorageError::Backend(err.to_string())
+}
+
+#[async_trait]
+impl ObjectStore for S3ObjectStore {
+    async fn put(&self, key: &str, body: Vec<u8>, options: PutOptions) -> Result<(), StorageError> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .set_content_type(options.content_type)
+            .set_content_encoding(options.content_encoding)
+            .body(ByteStream::from(body))
+            .send()
+            .await
+            .map_err(backend_error)?;
+        Ok(())
+    }
+
+    async fn put_file(
+        &self,
+        key: &str,
+        path: &Path,
+        options: PutOptions,
+    ) -> Result<(), StorageError> {
+        // 按块读取文件上传，不把整个文件读入内存
+        let body = ByteStream::from_path(path).await.map_err(backend_error)?;
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .set_content_type(options.content_type)
+            .set_content_encoding(options.content_encoding)
+            .body(body)
+            .send()
+            .await
+            .map_err(backend_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, StorageError> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| match e.as_service_error() {
+                Some(service_error) if service_error.is_no_such_key() => {
+                    StorageError::NotFound(key.to_string())
+                },
+                _ => backend_error(e),
+            })?;
+        let data = output.body.collect().await.map_err(backend_error)?;
+        Ok(data.into_bytes().to_vec())
+    }
+
+    async fn open(&self, key: &str, range: Option<ByteRange>) -> Result<ObjectRead, StorageError> {
+        // 先读取元数据得到对象大小，用于校验范围和返回 Content-Range
+        let head = self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| match e.as_service_error() {
+                Some(service_error) if service_error.is_not_found() => {
+                    StorageError::NotFound(key.to_string())
+                },
+                _ => backend_error(e),
+            })?;
+        let size = head.content_length().and_then(|len| u64::try_from(len).ok()).unwrap_or(0);
+        let range = range
+            .map(|range| range.resolve(size).ok_or(StorageError::RangeNotSatisfiable { size }))
+            .transpose()?;
+
+        // 范围转发给S3，ETag保证两次请求读取的是同一版本的对象
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .set_range(range.map(|(start, end)| format!("bytes={}-{}", start, end)))
+            .set_if_match(head.e_tag().map(String::from))
+            .send()
+            .await
+            .map_err(|e| match e.as_service_error() {
+                Some(service_error) if service_error.is_no_such_key() => {
+                    StorageError::NotFound(key.to_string())
+                },
+                _ => backend_error(e),
+            })?;
+        Ok(ObjectRead {
+            reader: Box::pin(output.body.into_async_read()),
+            size,
+            range,
+            content_type: head.content_type().map(String::from),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(backend_error)?;
+        Ok(())
+    }
+
+    async fn presign(&self, key: &str, expires_in: Duration) -> Result<String, StorageError> {
+        let config = PresigningConfig::expires_in(expires_in).map_err(backend_error)?;
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .presigned(config)
+            .await
+            .map_err(backend_error)?;
+        Ok(request.uri().to_string())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let output = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .map_err(backend_error)?;
+            let page = output.contents().iter().filter_map(|object| object.key());
+            keys.extend(page.map(String::from));
+
+            match output.next_continuation_token() {
+                Some(token) if output.is_truncated().unwrap_or(false) => {
+                    continuation_token = Some(token.to_string());
+                },
+                _ => break,
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+}
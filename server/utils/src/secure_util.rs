@@ -3,20 +3,33 @@
  * 
  * 该模块提供了密码哈希和验证的安全工具函数，使用 Argon2 算法进行密码处理。
  * Argon2 是一个现代化的密码哈希算法，被选为密码哈希竞赛（PHC）的获胜者。
+ * 
+ * 新哈希使用 Argon2id 和启动时设置的参数（内存、迭代次数、并行度）；
+ * 验证时使用哈希中记录的参数，调整参数后已有的哈希仍可验证，可在登录成功后按需重新哈希。
  */
 
-use std::error::Error;
+use std::{error::Error, sync::OnceLock};
 
 use argon2::{
-    password_hash::{rand_core::OsRng, SaltString},
-    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+    password_hash::{
+        rand_core::{OsRng, RngCore},
+        SaltString,
+    },
+    Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version,
 };
 use lazy_static::lazy_static;
+use ring::digest;
 
 lazy_static! {
     static ref ARGON2: Argon2<'static> = Argon2::default();
 }
 
+/** 新密码哈希使用的参数，服务启动时设置，未设置时使用 Argon2 默认参数 */
+static HASH_PARAMS: OnceLock<Params> = OnceLock::new();
+
+/** 随机密码使用的字符集，去掉了容易混淆的字符 */
+const PASSWORD_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz23456789";
+
 /**
  * 安全工具结构体
  * 
@@ -25,10 +38,40 @@ lazy_static! {
 pub struct SecureUtil;
 
 impl SecureUtil {
+    /**
+     * 设置新密码哈希使用的 Argon2 参数
+     * 
+     * 应在服务启动时调用一次，重复调用时保留第一次设置的参数
+     * 
+     * # 参数
+     * * `memory_kib` - 内存开销（KiB）
+     * * `iterations` - 迭代次数
+     * * `parallelism` - 并行度
+     * 
+     * # 返回
+     * * `Result<(), Box<dyn Error>>` - 参数超出 Argon2 允许范围时返回错误
+     */
+    pub fn init_hash_params(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        let params = Params::new(memory_kib, iterations, parallelism, None)?;
+        let _ = HASH_PARAMS.set(params);
+        Ok(())
+    }
+
+    /**
+     * 获取新密码哈希使用的参数
+     */
+    fn hash_params() -> Params {
+        HASH_PARAMS.get().cloned().unwrap_or_default()
+    }
+
     /**
      * 对密码进行哈希处理
      * 
-     * 使用 Argon2 算法对密码进行哈希处理，生成安全的密码哈希值。
+     * 使用 Argon2id 和启动时设置的参数对密码进行哈希处理，生成安全的密码哈希值。
      * 每次调用都会生成新的随机盐值，确保相同的密码会产生不同的哈希值。
      * 
      * # 参数
@@ -44,8 +87,40 @@ impl SecureUtil {
      * ```
      */
     pub fn hash_password(password: &[u8]) -> Result<String, Box<dyn Error>> {
+        Self::hash_with_params(password, Self::hash_params())
+    }
+
+    /**
+     * 使用指定的 Argon2 参数对密码进行哈希处理
+     * 
+     * 用于评估参数调整后的耗时，或生成指定参数的哈希
+     * 
+     * # 参数
+     * * `password` - 需要哈希的密码字节数组
+     * * `memory_kib` - 内存开销（KiB）
+     * * `iterations` - 迭代次数
+     * * `parallelism` - 并行度
+     * 
+     * # 返回
+     * * `Result<String, Box<dyn Error>>` - 成功返回密码哈希字符串，参数无效或哈希失败返回错误
+     */
+    pub fn hash_password_with(
+        password: &[u8],
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<String, Box<dyn Error>> {
+        let params = Params::new(memory_kib, iterations, parallelism, None)?;
+        Self::hash_with_params(password, params)
+    }
+
+    /**
+     * 使用 Argon2id 和指定参数对密码进行哈希处理
+     */
+    fn hash_with_params(password: &[u8], params: Params) -> Result<String, Box<dyn Error>> {
         let salt = SaltString::generate(&mut OsRng);
-        let password_hash = ARGON2.hash_password(password, &salt)?.to_string();
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+        let password_hash = argon2.hash_password(password, &salt)?.to_string();
         Ok(password_hash)
     }
 
@@ -76,6 +151,92 @@ impl SecureUtil {
             Err(e) => Err(Box::new(e)),
         }
     }
+
+    /**
+     * 判断密码哈希是否需要重新生成
+     * 
+     * 哈希不是 Argon2id 当前版本，或内存、迭代次数、并行度任一低于启动时设置的参数时返回 true。
+     * 应在密码验证成功后调用，此时才能用明文重新哈希。
+     * 
+     * # 参数
+     * * `password_hash` - 存储的密码哈希字符串
+     * 
+     * # 返回
+     * * `bool` - 需要重新哈希返回 true；哈希无法解析时返回 false
+     * 
+     * # 示例
+     * ```
+     * if SecureUtil::verify_password(password, &hash)? && SecureUtil::needs_rehash(&hash) {
+     *     let new_hash = SecureUtil::hash_password(password)?;
+     * }
+     * ```
+     */
+    pub fn needs_rehash(password_hash: &str) -> bool {
+        Self::needs_rehash_with(password_hash, &Self::hash_params())
+    }
+
+    /**
+     * 按指定参数判断密码哈希是否需要重新生成
+     */
+    fn needs_rehash_with(password_hash: &str, params: &Params) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(password_hash) else {
+            return false;
+        };
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident()
+            || parsed_hash.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+        match Params::try_from(&parsed_hash) {
+            Ok(current) => {
+                current.m_cost() < params.m_cost()
+                    || current.t_cost() < params.t_cost()
+                    || current.p_cost() < params.p_cost()
+            },
+            Err(_) => true,
+        }
+    }
+
+    /**
+     * 生成随机密码
+     * 
+     * 使用操作系统随机数生成器，从去掉易混淆字符的字母数字字符集中均匀取值。
+     * 
+     * # 参数
+     * * `length` - 密码长度
+     * 
+     * # 返回
+     * * `String` - 随机密码
+     */
+    pub fn generate_password(length: usize) -> String {
+        // 丢弃超出字符集整数倍范围的随机数，避免取模偏差
+        let charset_len = PASSWORD_CHARSET.len() as u32;
+        let limit = u32::MAX - u32::MAX % charset_len;
+        let mut password = String::with_capacity(length);
+        while password.len() < length {
+            let value = OsRng.next_u32();
+            if value < limit {
+                password.push(PASSWORD_CHARSET[(value % charset_len) as usize] as char);
+            }
+        }
+        password
+    }
+
+    /**
+     * 计算令牌摘要
+     * 
+     * 用于存储和查找随机生成的高熵令牌（如个人访问令牌），
+     * 这类令牌无需加盐和慢哈希，SHA-256 摘要即可按值直接查询。
+     * 
+     * # 参数
+     * * `token` - 令牌明文
+     * 
+     * # 返回
+     * * `String` - 小写十六进制的 SHA-256 摘要
+     */
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(digest::digest(&digest::SHA256, token.as_bytes()))
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +268,70 @@ mod tests {
         );
     }
 
+    /**
+     * 测试随机密码的长度和字符集
+     */
+    #[test]
+    fn test_generate_password() {
+        let password = SecureUtil::generate_password(16);
+        assert_eq!(password.len(), 16);
+        assert!(password.bytes().all(|c| PASSWORD_CHARSET.contains(&c)));
+        assert_ne!(password, SecureUtil::generate_password(16));
+    }
+
+    /**
+     * 测试令牌摘要
+     */
+    #[test]
+    fn test_hash_token() {
+        let hash = SecureUtil::hash_token("abc");
+        assert_eq!(hash, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_ne!(hash, SecureUtil::hash_token("abd"));
+    }
+
+    fn params(memory_kib: u32, iterations: u32, parallelism: u32) -> Params {
+        Params::new(memory_kib, iterations, parallelism, None).unwrap()
+    }
+
+    /**
+     * 测试调整参数后的重新哈希判断
+     * 
+     * 任一参数调高后旧哈希需要重新哈希，参数不变或调低时不需要，
+     * 旧哈希仍可验证
+     */
+    #[test]
+    fn test_needs_rehash_across_parameter_changes() {
+        let password = b"example_password";
+        let old = params(8192, 2, 1);
+        let hash = SecureUtil::hash_password_with(password, 8192, 2, 1).unwrap();
+
+        assert!(!SecureUtil::needs_rehash_with(&hash, &old));
+        assert!(!SecureUtil::needs_rehash_with(&hash, &params(4096, 1, 1)));
+        assert!(SecureUtil::needs_rehash_with(&hash, &params(19456, 2, 1)));
+        assert!(SecureUtil::needs_rehash_with(&hash, &params(8192, 3, 1)));
+        assert!(SecureUtil::needs_rehash_with(&hash, &params(8192, 2, 2)));
+
+        assert!(SecureUtil::verify_password(password, &hash).unwrap());
+        let rehashed = SecureUtil::hash_password_with(password, 19456, 3, 2).unwrap();
+        assert!(!SecureUtil::needs_rehash_with(&rehashed, &params(19456, 3, 2)));
+        assert!(SecureUtil::verify_password(password, &rehashed).unwrap());
+    }
+
+    /**
+     * 测试非 Argon2id 哈希和无法解析的哈希
+     */
+    #[test]
+    fn test_needs_rehash_other_algorithms() {
+        let password = b"example_password";
+        let salt = SaltString::generate(&mut OsRng);
+        let argon2i = Argon2::new(Algorithm::Argon2i, Version::V0x13, Params::default())
+            .hash_password(password, &salt)
+            .unwrap()
+            .to_string();
+        assert!(SecureUtil::needs_rehash_with(&argon2i, &Params::default()));
+        assert!(!SecureUtil::needs_rehash_with("not a hash", &Params::default()));
+    }
+
     /**
      * 测试密码哈希输出
      * 
@@ -9,4 +9,4 @@
 mod ip_value;
 pub use self::ip_value::ToUIntIP;
 pub mod searcher;
-pub use searcher::{search_by_ip, searcher_init};
+pub use searcher::{is_initialized, search_by_ip, searcher_init};
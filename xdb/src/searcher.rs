@@ -201,6 +201,16 @@ pub fn get_full_cache() -> &'static Vec<u8> {
     })
 }
 
+/**
+ * 搜索器是否已初始化
+ * 
+ * # 返回
+ * * `bool` - XDB文件已加载到缓存时返回true
+ */
+pub fn is_initialized() -> bool {
+    CACHE.get().is_some()
+}
+
 /**
  * 初始化搜索器
  * 